@@ -0,0 +1,14 @@
+//! xTrader 공개 API 타입
+//!
+//! WebSocket 스트림 메시지와 시세 프레임(호가창 Delta/Snapshot, BBO, 티커, 봉, 펀딩, 만기 상품 상태)을
+//! 담습니다. 서버(`xtrader-server`)뿐 아니라 MQ(`xtrader-mq`)와 시장 데이터(`xtrader-mdp`)가 같은
+//! 타입으로 직렬화하므로, 이 크레이트는 HTTP 서버나 DB에 의존하지 않습니다. REST 핸들러와 서버 상태에
+//! 묶인 요청/응답 모델은 `xtrader-server`의 `api` 모듈에 있습니다.
+
+pub mod models;
+
+pub use models::{
+    BboUpdate, CandleData, ContractState, FundingEvent, FuturesContractStatus, IndexPriceUpdate, OrderBookChange,
+    OrderBookChangeType, OrderBookDelta, OrderBookSnapshot, PrivateEvent, SettlementMethod, ThrottleUpdate, TickerData,
+    WebSocketMessage,
+};
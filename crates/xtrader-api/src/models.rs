@@ -0,0 +1,387 @@
+//! REST/WebSocket 공개 메시지 타입
+//!
+//! `/ws` 스트림과 시장 데이터 MQ가 주고받는 프레임입니다. 서버, MQ Producer/Consumer, 시장 데이터
+//! 발행기가 같은 타입을 쓰도록 이 크레이트에 둡니다.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+use xtrader_engine::model::ExecutionReport;
+
+/// 만기 정산 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementMethod {
+    /// 인덱스 가격 현금 정산
+    Cash,
+    /// 기초 자산 인도 (아직 지원하지 않음)
+    Physical,
+}
+
+impl SettlementMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettlementMethod::Cash => "cash",
+            SettlementMethod::Physical => "physical",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "cash" => Some(SettlementMethod::Cash),
+            "physical" => Some(SettlementMethod::Physical),
+            _ => None,
+        }
+    }
+}
+
+/// 만기 상품 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ContractState {
+    /// 거래 중
+    Active,
+    /// 만기 임박 (거래 중)
+    Expiring,
+    /// 만기 도래로 거래 중단, 정산 대기
+    Halted,
+    /// 정산 완료
+    Settled,
+}
+
+/// 봉차트 데이터
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CandleData {
+    pub open_time: u64,
+    pub close_time: u64,
+    pub open: u64,
+    pub high: u64,
+    pub low: u64,
+    pub close: u64,
+    pub volume: u64,
+    pub trade_count: u64,
+}
+
+/// 심볼별 24시간 롤링 티커
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct TickerData {
+    pub symbol: String,
+    pub last_price: Option<u64>,
+    /// 24시간 윈도 첫 체결가
+    pub open_price_24h: Option<u64>,
+    pub high_price_24h: Option<u64>,
+    pub low_price_24h: Option<u64>,
+    pub volume_24h: u64,
+    pub trade_count_24h: u64,
+    /// 24시간 변동률 (%)
+    pub price_change_pct_24h: Option<f64>,
+}
+
+/// 호가창 변경 타입
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum OrderBookChangeType {
+    /// 추가
+    Add,
+    /// 업데이트
+    Update,
+    /// 제거
+    Remove,
+}
+
+/// 호가창 변경 사항
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderBookChange {
+    /// 변경 타입
+    pub change_type: OrderBookChangeType,
+    /// 가격
+    pub price: u64,
+    /// 수량
+    pub quantity: u64,
+}
+
+/// 호가창 Delta 업데이트
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderBookDelta {
+    /// 심볼
+    pub symbol: String,
+    /// 매수 변경사항
+    pub bid_changes: Vec<OrderBookChange>,
+    /// 매도 변경사항
+    pub ask_changes: Vec<OrderBookChange>,
+    /// 타임스탬프
+    pub timestamp: u64,
+    /// 시퀀스 번호 (동기화용)
+    pub sequence: u64,
+}
+
+/// 최우선 호가(BBO) 변경
+///
+/// 최우선 매수/매도 호가의 가격이나 잔량이 바뀔 때만 발행됩니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BboUpdate {
+    /// 심볼
+    pub symbol: String,
+    /// 최우선 매수 호가 (없으면 None)
+    pub bid_price: Option<u64>,
+    /// 최우선 매수 잔량
+    pub bid_quantity: u64,
+    /// 최우선 매도 호가 (없으면 None)
+    pub ask_price: Option<u64>,
+    /// 최우선 매도 잔량
+    pub ask_quantity: u64,
+    /// 타임스탬프
+    pub timestamp: u64,
+    /// 심볼별 BBO 시퀀스 번호 (1부터 연속, 호가창 Delta 시퀀스와 별개)
+    pub sequence: u64,
+}
+
+/// 호가창 Snapshot 업데이트
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct OrderBookSnapshot {
+    /// 심볼
+    pub symbol: String,
+    /// 매수 호가
+    pub bids: Vec<(u64, u64)>,
+    /// 매도 호가
+    pub asks: Vec<(u64, u64)>,
+    /// 타임스탬프
+    pub timestamp: u64,
+    /// 시퀀스 번호 (동기화용)
+    pub sequence: u64,
+}
+
+/// 계정별 주문/체결 이벤트 (비공개 채널)
+///
+/// `sequence` 는 계정마다 1부터 빈틈없이 증가하며, 재연결 시 마지막으로 받은 번호를 보내면
+/// 그 뒤의 이벤트를 다시 받습니다.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PrivateEvent {
+    pub client_id: String,
+    /// 계정별 이벤트 시퀀스
+    pub sequence: u64,
+    pub execution_report: ExecutionReport,
+    pub order_status: String,
+}
+
+/// 계정 주문 속도 제한 시작/해제 알림 (비공개 채널)
+///
+/// 최근 구간의 취소/주문 비율이 기준을 넘으면 `until`까지 초당 `max_orders_per_sec` 건을 넘는
+/// 신규 주문이 거부됩니다. 시각은 Unix 타임스탬프(밀리초)입니다.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ThrottleUpdate {
+    pub client_id: String,
+    /// true 이면 제한 시작(또는 진행 중), false 이면 해제
+    pub throttled: bool,
+    pub max_orders_per_sec: u32,
+    /// 판정 시점 구간 내 신규 주문 수
+    pub orders: u64,
+    /// 판정 시점 구간 내 취소 수
+    pub cancels: u64,
+    pub cancel_ratio: f64,
+    pub started_at: u64,
+    pub until: u64,
+    /// 제한 중 거부된 신규 주문 수
+    pub rejected_orders: u64,
+    pub timestamp: u64,
+}
+
+/// 심볼별 인덱스 가격과 공정 가격 (외부 거래소 시세 기준)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct IndexPriceUpdate {
+    pub symbol: String,
+    /// 인덱스 가격 (유효 거래소 시세의 중앙값)
+    pub index_price: f64,
+    /// 공정 가격 (내부 호가 중간값을 인덱스 허용 범위로 제한, 호가가 없으면 인덱스)
+    pub fair_price: f64,
+    /// 내부 호가 중간값 (매수/매도 호가가 모두 있을 때만)
+    pub internal_mid: Option<f64>,
+    /// 인덱스 계산에 쓴 거래소
+    pub sources: Vec<String>,
+    /// 중앙값에서 벗어나 제외한 거래소
+    pub outliers: Vec<String>,
+    /// 시세가 오래되어 제외한 거래소
+    pub stale_sources: Vec<String>,
+    /// 유효 거래소가 부족해 직전 인덱스를 유지 중인지
+    pub stale: bool,
+    /// 인덱스를 마지막으로 새로 계산한 시각 (밀리초)
+    pub index_updated_at: u64,
+    /// 발행 시각 (밀리초)
+    pub timestamp: u64,
+}
+
+/// 심볼 펀딩 정산 결과
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct FundingEvent {
+    pub symbol: String,
+    /// 정산 시각 (Unix 초)
+    pub funding_time: u64,
+    /// 펀딩 비율 (양수면 롱이 숏에게 지급)
+    pub funding_rate: f64,
+    pub index_price: f64,
+    /// 정산 시점 최근 체결가
+    pub last_price: u64,
+    /// 지급액 계산에 쓴 공정 가격
+    pub mark_price: f64,
+    /// 정산한 롱/숏 포지션 계정 수
+    pub long_positions: u64,
+    pub short_positions: u64,
+    /// 계정들이 낸 펀딩 합계 (호가 자산 최소 단위)
+    pub total_paid: u64,
+    /// 계정들이 받은 펀딩 합계 (호가 자산 최소 단위, 끝수 버림으로 지급 합계보다 작을 수 있음)
+    pub total_received: u64,
+    /// 다음 정산 시각 (Unix 초)
+    pub next_funding_time: u64,
+}
+
+/// 만기 상품 메타데이터와 상태
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct FuturesContractStatus {
+    pub symbol: String,
+    /// 기초 인덱스 심볼
+    pub underlying: String,
+    /// 만기 시각 (Unix 초)
+    pub expiry: u64,
+    pub settlement_method: SettlementMethod,
+    pub state: ContractState,
+    /// 정산 가격 (정산 완료 후, 호가 자산 최소 단위)
+    pub settlement_price: Option<u64>,
+    /// 정산한 포지션 계정 수 (정산 완료 후)
+    pub settled_positions: Option<u64>,
+    /// 정산 시각 (정산 완료 후, Unix 초)
+    pub settled_at: Option<u64>,
+    /// 마지막 상태 변경 시각 (밀리초, 시작 후 변경이 없으면 시작 시각)
+    pub timestamp: u64,
+}
+
+/// WebSocket 메시지 타입
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type")]
+pub enum WebSocketMessage {
+    /// 체결 결과 (하이브리드 방식 - 상태 정보 포함)
+    Execution {
+        execution_report: ExecutionReport,
+        order_status: String, // "Filled", "PartiallyFilled", "Pending"
+    },
+    /// 호가창 Delta 업데이트 (변경된 부분만)
+    OrderBookDelta(OrderBookDelta),
+    /// 호가창 Snapshot 업데이트 (전체 호가창)
+    OrderBookSnapshot(OrderBookSnapshot),
+    /// 최우선 호가 변경 (가격/잔량이 바뀔 때만)
+    Bbo(BboUpdate),
+    /// 인덱스/공정 가격 (계산 주기마다)
+    IndexPrice(IndexPriceUpdate),
+    /// 펀딩 정산 결과 (정산 주기마다)
+    Funding(FundingEvent),
+    /// 만기 상품 상태 변경 (만기 임박, 거래 중단, 정산 완료)
+    ContractLifecycle(FuturesContractStatus),
+    /// 호가창 업데이트 (기존 호환성 유지)
+    OrderBookUpdate {
+        symbol: String,
+        bids: Vec<(u64, u64)>,
+        asks: Vec<(u64, u64)>,
+        timestamp: u64,
+    },
+    /// 시장 통계 업데이트
+    MarketStatistics {
+        symbol: String,
+        timestamp: u64,
+        last_price: Option<u64>,
+        price_change_24h: Option<f64>,
+        volume_24h: u64,
+        high_price_24h: Option<u64>,
+        low_price_24h: Option<u64>,
+    },
+    /// 전 심볼 24시간 티커 (설정된 주기로 발행)
+    Ticker {
+        timestamp: u64,
+        tickers: Vec<TickerData>,
+    },
+    /// 봉차트 업데이트 (진행 중인 봉은 체결마다, 봉이 끝나면 `is_closed` 프레임 한 번)
+    CandlestickUpdate {
+        symbol: String,
+        interval: String,
+        candle: CandleData,
+        /// 마감된 봉인지 (false면 진행 중인 봉으로 이후 값이 바뀔 수 있음)
+        #[serde(default)]
+        is_closed: bool,
+    },
+    /// 동기화 요청 응답
+    SyncResponse {
+        symbol: String,
+        snapshot: OrderBookSnapshot,
+    },
+    /// 계정별 주문/체결 이벤트
+    PrivateEvent(PrivateEvent),
+    /// 계정 주문 속도 제한 시작/해제
+    ThrottleUpdate(ThrottleUpdate),
+    /// 재연결 재전송 완료 (이후는 실시간 이벤트)
+    ReplayComplete {
+        client_id: String,
+        /// 재전송한 이벤트 수
+        replayed: usize,
+        /// 마지막으로 전달한 시퀀스
+        last_sequence: u64,
+        /// 요청 이후 이벤트를 빠짐없이 보냈는지 (false 이면 일부를 복구하지 못함)
+        complete: bool,
+    },
+    /// 에러 메시지
+    Error {
+        message: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_websocket_message_is_tagged_by_type() {
+        let message = WebSocketMessage::Bbo(BboUpdate {
+            symbol: "BTC-KRW".to_string(),
+            bid_price: Some(100),
+            bid_quantity: 3,
+            ask_price: None,
+            ask_quantity: 0,
+            timestamp: 1,
+            sequence: 7,
+        });
+
+        let json = serde_json::to_value(&message).unwrap();
+        assert_eq!(json["type"], "Bbo");
+        assert_eq!(json["sequence"], 7);
+
+        let decoded: WebSocketMessage = serde_json::from_value(json).unwrap();
+        assert!(matches!(decoded, WebSocketMessage::Bbo(bbo) if bbo.bid_price == Some(100)));
+    }
+
+    #[test]
+    fn test_candlestick_update_defaults_is_closed() {
+        let json = serde_json::json!({
+            "type": "CandlestickUpdate",
+            "symbol": "BTC-KRW",
+            "interval": "1m",
+            "candle": {
+                "open_time": 0, "close_time": 59_999, "open": 1, "high": 2, "low": 1, "close": 2,
+                "volume": 5, "trade_count": 2
+            }
+        });
+
+        let decoded: WebSocketMessage = serde_json::from_value(json).unwrap();
+        assert!(matches!(decoded, WebSocketMessage::CandlestickUpdate { is_closed: false, .. }));
+    }
+
+    #[test]
+    fn test_contract_enums_use_snake_case_names() {
+        assert_eq!(serde_json::to_value(ContractState::Halted).unwrap(), "halted");
+        assert_eq!(SettlementMethod::from_name(SettlementMethod::Cash.as_str()), Some(SettlementMethod::Cash));
+        assert_eq!(SettlementMethod::from_name("swap"), None);
+    }
+}
@@ -0,0 +1,196 @@
+//! 단일가 경매 체결
+//!
+//! 교차한 호가(최우선 매수가 >= 최우선 매도가)를 하나의 가격으로 청산합니다.
+//! 체결 가능 수량이 가장 큰 가격을 고르고, 같으면 잔량 불균형이 작은 가격, 그래도 같으면
+//! 기준가(직전 경매가, 없으면 최우선 호가 중간값)에 가까운 가격을 고릅니다.
+//! 주기적 배치 경매가 쓰며, 장 시작 단일가 경매도 같은 알고리즘을 쓰도록 매칭 엔진과 분리해 둡니다.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::model::Order;
+use crate::order_book::OrderBook;
+
+/// 경매 체결가와 체결 수량
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuctionCross {
+  pub price: u64,
+  /// 체결가에서 체결되는 수량
+  pub volume: u64,
+  /// 체결가에서 매수 수요 - 매도 공급 (양수면 매수 잔량이 남음)
+  pub imbalance: i64,
+}
+
+/// 경매 체결 한 건 (체결 후 매수/매도 주문 상태)
+#[derive(Debug, Clone)]
+pub struct AuctionFill {
+  pub buy: Order,
+  pub sell: Order,
+  pub price: u64,
+  pub quantity: u64,
+}
+
+/// 체결가 산정 (호가가 교차하지 않으면 None)
+pub fn find_clearing_price(book: &OrderBook, reference_price: Option<u64>) -> Option<AuctionCross> {
+  let best_bid = book.get_best_bid()?.0;
+  let best_ask = book.get_best_ask()?.0;
+  if best_bid < best_ask {
+    return None;
+  }
+  let reference = reference_price.unwrap_or(best_ask + (best_bid - best_ask) / 2);
+
+  // 교차 구간의 호가 가격만 후보 (그 사이 가격은 수요/공급이 같으므로 생략)
+  let mut candidates: Vec<u64> = book
+    .bids
+    .keys()
+    .map(|price| price.0)
+    .take_while(|price| *price >= best_ask)
+    .chain(book.asks.keys().copied().take_while(|price| *price <= best_bid))
+    .collect();
+  candidates.sort_unstable();
+  candidates.dedup();
+
+  candidates
+    .into_iter()
+    .map(|price| {
+      let demand: u64 = book.bids.range(..=Reverse(price)).map(|(_, level)| level.total_volume).sum();
+      let supply: u64 = book.asks.range(..=price).map(|(_, level)| level.total_volume).sum();
+      AuctionCross {
+        price,
+        volume: demand.min(supply),
+        imbalance: demand as i64 - supply as i64,
+      }
+    })
+    .filter(|cross| cross.volume > 0)
+    .min_by_key(|cross| (Reverse(cross.volume), cross.imbalance.unsigned_abs(), cross.price.abs_diff(reference), cross.price))
+}
+
+/// 체결가로 교차한 주문 청산 (가격-시간 우선순위)
+///
+/// 체결된 주문은 주문장에서 빠지고, 부분 체결된 주문은 남은 수량으로 주문장에 남습니다.
+pub fn uncross(book: &mut OrderBook, cross: &AuctionCross) -> Vec<AuctionFill> {
+  let mut fills = Vec::new();
+  let mut remaining = cross.volume;
+
+  while remaining > 0 {
+    let (Some((&Reverse(bid_price), bid_level)), Some((&ask_price, ask_level))) =
+      (book.bids.iter().next(), book.asks.iter().next())
+    else {
+      break;
+    };
+    if bid_price < cross.price || ask_price > cross.price {
+      break;
+    }
+    let (Some(bid_quantity), Some(ask_quantity)) = (bid_level.get_front_quantity(), ask_level.get_front_quantity()) else {
+      break;
+    };
+    let quantity = remaining.min(bid_quantity).min(ask_quantity);
+
+    let buy = book.bids.get_mut(&Reverse(bid_price)).and_then(|level| level.match_partial(quantity));
+    let sell = book.asks.get_mut(&ask_price).and_then(|level| level.match_partial(quantity));
+    let (Some((_, buy, _)), Some((_, sell, _))) = (buy, sell) else {
+      break;
+    };
+
+    if book.bids.get(&Reverse(bid_price)).is_some_and(|level| level.is_empty()) {
+      book.bids.remove(&Reverse(bid_price));
+    }
+    if book.asks.get(&ask_price).is_some_and(|level| level.is_empty()) {
+      book.asks.remove(&ask_price);
+    }
+    if buy.is_filled() {
+      book.orders.remove(&buy.id);
+    }
+    if sell.is_filled() {
+      book.orders.remove(&sell.id);
+    }
+
+    remaining -= quantity;
+    fills.push(AuctionFill { buy, sell, price: cross.price, quantity });
+  }
+  fills
+}
+
+/// `BTC-KRW:500,ETH-KRW:1000` (심볼:경매 주기 밀리초) 형식 파싱
+pub fn parse_batch_auctions(spec: &str) -> Result<HashMap<String, Duration>, String> {
+  spec
+    .split(',')
+    .map(str::trim)
+    .filter(|entry| !entry.is_empty())
+    .map(|entry| {
+      entry
+        .rsplit_once(':')
+        .and_then(|(symbol, ms)| {
+          let ms = ms.trim().parse::<u64>().ok().filter(|ms| *ms > 0)?;
+          Some((symbol.trim().to_string(), Duration::from_millis(ms))).filter(|(symbol, _)| !symbol.is_empty())
+        })
+        .ok_or_else(|| format!("배치 경매 설정 형식 오류: {} (예: BTC-KRW:500)", entry))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::model::{OrderType, Side};
+
+  fn order(id: &str, side: Side, price: u64, quantity: u64) -> Order {
+    Order::new(id.to_string(), "BTC-KRW".to_string(), side, OrderType::Limit, price, quantity, "trader".to_string())
+  }
+
+  #[test]
+  fn test_clearing_price_maximizes_volume() {
+    let mut book = OrderBook::new("BTC-KRW".to_string());
+    book.add_order(order("b1", Side::Buy, 105, 5));
+    book.add_order(order("b2", Side::Buy, 102, 5));
+    book.add_order(order("b3", Side::Buy, 99, 10));
+    book.add_order(order("a1", Side::Sell, 100, 4));
+    book.add_order(order("a2", Side::Sell, 103, 6));
+
+    // 100/102는 4, 103/105는 5 체결 가능하고 103/105는 불균형도 같으므로
+    // 기준가(최우선 호가 중간값 102)에 가까운 103
+    let cross = find_clearing_price(&book, None).unwrap();
+    assert_eq!(cross, AuctionCross { price: 103, volume: 5, imbalance: -5 });
+
+    // 교차하지 않으면 경매 없음
+    let mut quiet = OrderBook::new("BTC-KRW".to_string());
+    quiet.add_order(order("b1", Side::Buy, 99, 5));
+    quiet.add_order(order("a1", Side::Sell, 100, 5));
+    assert!(find_clearing_price(&quiet, None).is_none());
+  }
+
+  #[test]
+  fn test_uncross_fills_in_price_time_priority() {
+    let mut book = OrderBook::new("BTC-KRW".to_string());
+    book.add_order(order("b1", Side::Buy, 101, 3));
+    book.add_order(order("b2", Side::Buy, 101, 4));
+    book.add_order(order("a1", Side::Sell, 100, 2));
+    book.add_order(order("a2", Side::Sell, 101, 10));
+
+    // 100은 2, 101은 7 체결 가능 (기준가보다 체결 수량 우선)
+    let cross = find_clearing_price(&book, Some(100)).unwrap();
+    assert_eq!((cross.price, cross.volume), (101, 7));
+
+    let fills = uncross(&mut book, &cross);
+    let summary: Vec<(&str, &str, u64)> =
+      fills.iter().map(|fill| (fill.buy.id.as_str(), fill.sell.id.as_str(), fill.quantity)).collect();
+    assert_eq!(summary, vec![("b1", "a1", 2), ("b1", "a2", 1), ("b2", "a2", 4)]);
+    assert!(fills.iter().all(|fill| fill.price == 101));
+
+    // 매수는 모두 체결, 매도 a2는 5 남음
+    assert!(book.bids.is_empty());
+    assert_eq!(book.asks[&101].total_volume, 5);
+    assert_eq!(book.order_count(), 1);
+    assert!(book.orders.contains_key("a2") && !book.orders.contains_key("a1"));
+  }
+
+  #[test]
+  fn test_parse_batch_auctions() {
+    let auctions = parse_batch_auctions("BTC-KRW:500, ETH-KRW:1000").unwrap();
+    assert_eq!(auctions["BTC-KRW"], Duration::from_millis(500));
+    assert_eq!(auctions["ETH-KRW"], Duration::from_secs(1));
+    assert!(parse_batch_auctions("BTC-KRW").is_err());
+    assert!(parse_batch_auctions("BTC-KRW:0").is_err());
+  }
+}
@@ -0,0 +1,157 @@
+//! 시각 소스 추상화 (결정적 시뮬레이션 테스트용 가상 시간)
+//!
+//! 벽시계(`SystemTime`)와 대기(`tokio::time::sleep`)를 [`Clock`] 뒤로 숨깁니다. 운영에서는
+//! [`SystemClock`]을 쓰고, 시뮬레이션 테스트에서는 [`SimClock`]을 주입해 봉 마감, GTD 만료,
+//! 주문 속도 제한, 헬스체크 주기를 실제로 기다리지 않고 `advance`로 시간을 넘기며 확인합니다.
+//!
+//! `SimClock`은 테스트 빌드나 `sim-test` 기능에서만 제공됩니다.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures_core::future::BoxFuture;
+
+/// 시각 소스
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// 현재 Unix 타임스탬프 (밀리초)
+    fn now_millis(&self) -> u64;
+
+    /// 주어진 시간만큼 대기
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+    /// 현재 Unix 타임스탬프 (초)
+    fn now_secs(&self) -> u64 {
+        self.now_millis() / 1000
+    }
+}
+
+/// 여러 구성 요소가 공유하는 시각 소스
+pub type SharedClock = Arc<dyn Clock>;
+
+/// 시스템 시각 (운영 기본값)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// 공유 시각 소스로 생성
+    pub fn shared() -> SharedClock {
+        Arc::new(SystemClock)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+#[cfg(any(test, feature = "sim-test"))]
+pub use sim::SimClock;
+
+#[cfg(any(test, feature = "sim-test"))]
+mod sim {
+    use super::*;
+    use tokio::sync::watch;
+
+    /// 가상 시각 (`advance`/`set`으로만 흐름)
+    ///
+    /// 복제본은 같은 시각을 공유합니다. `sleep`은 가상 시각이 기한에 도달하면 깨어나며,
+    /// 깨어난 태스크가 실제로 실행되도록 `advance` 뒤에 [`SimClock::settle`]을 호출합니다.
+    #[derive(Debug, Clone)]
+    pub struct SimClock {
+        now: Arc<watch::Sender<u64>>,
+    }
+
+    impl SimClock {
+        /// `start_millis` (Unix 밀리초)에서 멈춘 시각 생성
+        pub fn new(start_millis: u64) -> Self {
+            Self { now: Arc::new(watch::channel(start_millis).0) }
+        }
+
+        /// 공유 시각 소스로 변환 (같은 가상 시각을 봄)
+        pub fn shared(&self) -> SharedClock {
+            Arc::new(self.clone())
+        }
+
+        /// 시간 진행
+        pub fn advance(&self, duration: Duration) {
+            self.now.send_modify(|now| *now = now.saturating_add(duration.as_millis() as u64));
+        }
+
+        /// 시각 지정 (과거로는 되돌리지 않음)
+        pub fn set(&self, millis: u64) {
+            self.now.send_if_modified(|now| {
+                let moved = millis > *now;
+                if moved {
+                    *now = millis;
+                }
+                moved
+            });
+        }
+
+        /// 깨어난 태스크가 다음 대기 지점까지 실행되도록 양보
+        ///
+        /// 단일 스레드 런타임(`#[tokio::test]` 기본값)에서는 실행 순서가 결정적입니다.
+        pub async fn settle(&self) {
+            for _ in 0..32 {
+                tokio::task::yield_now().await;
+            }
+        }
+    }
+
+    impl Clock for SimClock {
+        fn now_millis(&self) -> u64 {
+            *self.now.borrow()
+        }
+
+        fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+            let deadline = self.now_millis().saturating_add(duration.as_millis() as u64);
+            let mut now = self.now.subscribe();
+            Box::pin(async move {
+                // 시각 소스가 모두 사라지면 더 기다릴 시각이 없으므로 종료
+                let _ = now.wait_for(|now| *now >= deadline).await;
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sim_clock_sleep_wakes_on_advance() {
+        let clock = SimClock::new(1_000);
+        let shared = clock.shared();
+        let woke = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let task = {
+            let woke = woke.clone();
+            tokio::spawn(async move {
+                shared.sleep(Duration::from_secs(5)).await;
+                woke.store(true, std::sync::atomic::Ordering::SeqCst);
+            })
+        };
+
+        clock.settle().await;
+        clock.advance(Duration::from_millis(4_999));
+        clock.settle().await;
+        assert!(!woke.load(std::sync::atomic::Ordering::SeqCst));
+
+        clock.advance(Duration::from_millis(1));
+        task.await.unwrap();
+        assert!(woke.load(std::sync::atomic::Ordering::SeqCst));
+        assert_eq!((clock.now_millis(), clock.now_secs()), (6_000, 6));
+
+        // 과거로는 되돌리지 않음
+        clock.set(10);
+        assert_eq!(clock.now_millis(), 6_000);
+    }
+}
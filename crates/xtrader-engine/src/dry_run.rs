@@ -0,0 +1,187 @@
+//! 모의(dry-run) 주문 체결
+//!
+//! 주문장을 바꾸지 않고 매칭 엔진과 같은 규칙(가격-시간 우선, 지정가 교차 조건,
+//! 시장가 보호 한도)으로 주문을 체결해 보고 예상 체결 내역을 돌려줍니다.
+//! 주문 전에 슬리피지를 추정하거나 전략을 시험할 때 씁니다.
+
+use serde::Serialize;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+use crate::model::{MarketProtection, Order, OrderType, Side};
+use crate::order_ack::OrderAckStatus;
+use crate::order_book::OrderBook;
+
+/// 가격별 예상 체결
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SimulatedFill {
+  pub price: u64,
+  pub quantity: u64,
+}
+
+/// 모의 체결 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct DryRunResult {
+  /// 실제로 제출했을 때의 처리 상태
+  pub status: OrderAckStatus,
+  /// 가격별 예상 체결 (체결 순서)
+  pub fills: Vec<SimulatedFill>,
+  pub filled_quantity: u64,
+  /// 주문장에 남을 수량 (시장가 주문은 0)
+  pub remaining_quantity: u64,
+  /// 매칭 시작 시 반대편 최우선 호가
+  pub reference_price: Option<u64>,
+  /// 체결 수량 가중 평균 가격
+  pub average_price: Option<f64>,
+  /// 최우선 호가 대비 평균 체결가의 불리한 방향 차이 (bp)
+  pub slippage_bps: Option<f64>,
+}
+
+/// 반대편 호가를 우선순위순으로 (가격, 메이커 주문 수량) 나열
+fn opposite_makers(book: &OrderBook, side: &Side) -> Vec<(u64, u64)> {
+  let levels: Vec<(u64, Vec<u64>)> = match side {
+    Side::Buy => book.asks.iter().map(|(price, level)| (*price, level.resting_quantities())).collect(),
+    Side::Sell => book.bids.iter().map(|(price, level)| (price.0, level.resting_quantities())).collect(),
+  };
+  levels
+    .into_iter()
+    .flat_map(|(price, quantities)| quantities.into_iter().map(move |quantity| (price, quantity)))
+    .collect()
+}
+
+/// 주문장을 바꾸지 않고 주문을 체결해 봄
+///
+/// 시장가 주문의 `max_levels`는 엔진과 같이 체결한 가격 레벨 수와 비교합니다.
+pub fn simulate(book: &OrderBook, order: &Order, protection: &MarketProtection) -> DryRunResult {
+  let makers = opposite_makers(book, &order.side);
+  let reference_price = makers.first().map(|(price, _)| *price);
+
+  let mut fills: Vec<SimulatedFill> = Vec::new();
+  let mut remaining = order.remaining_quantity;
+  for (price, maker_quantity) in makers {
+    if remaining == 0 {
+      break;
+    }
+    // 이미 체결한 가격 레벨 수 (같은 가격의 메이커 여러 건은 한 레벨)
+    let levels_swept = fills.len();
+    let new_level = fills.last().is_none_or(|fill| fill.price != price);
+    let allowed = match order.order_type {
+      OrderType::Limit => match order.side {
+        Side::Buy => order.price >= price,
+        Side::Sell => order.price <= price,
+      },
+      OrderType::Market => {
+        let reference = reference_price.unwrap_or(price);
+        (!new_level || protection.allows_level(levels_swept)) && protection.allows_price(&order.side, reference, price)
+      }
+    };
+    if !allowed {
+      break;
+    }
+
+    let quantity = remaining.min(maker_quantity);
+    remaining -= quantity;
+    match fills.last_mut() {
+      Some(fill) if fill.price == price => fill.quantity += quantity,
+      _ => fills.push(SimulatedFill { price, quantity }),
+    }
+  }
+
+  let filled_quantity = order.remaining_quantity - remaining;
+  let average_price = (filled_quantity > 0).then(|| {
+    let notional: f64 = fills.iter().map(|fill| fill.price as f64 * fill.quantity as f64).sum();
+    notional / filled_quantity as f64
+  });
+  let slippage_bps = reference_price.zip(average_price).map(|(reference, average)| {
+    let difference = match order.side {
+      Side::Buy => average - reference as f64,
+      Side::Sell => reference as f64 - average,
+    };
+    difference / reference as f64 * 10_000.0
+  });
+
+  let (status, remaining_quantity) = match order.order_type {
+    OrderType::Market if remaining == 0 => (OrderAckStatus::Filled, 0),
+    OrderType::Market => (OrderAckStatus::Canceled, 0),
+    OrderType::Limit if remaining == 0 => (OrderAckStatus::Filled, 0),
+    OrderType::Limit if filled_quantity > 0 => (OrderAckStatus::PartiallyFilled, remaining),
+    OrderType::Limit => (OrderAckStatus::Accepted, remaining),
+  };
+
+  DryRunResult {
+    status,
+    fills,
+    filled_quantity,
+    remaining_quantity,
+    reference_price,
+    average_price,
+    slippage_bps,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn order(id: &str, side: Side, order_type: OrderType, price: u64, quantity: u64) -> Order {
+    Order::new(id.to_string(), "BTC-KRW".to_string(), side, order_type, price, quantity, "trader".to_string())
+  }
+
+  fn book() -> OrderBook {
+    let mut book = OrderBook::new("BTC-KRW".to_string());
+    book.add_order(order("a1", Side::Sell, OrderType::Limit, 100, 5));
+    book.add_order(order("a2", Side::Sell, OrderType::Limit, 100, 3));
+    book.add_order(order("a3", Side::Sell, OrderType::Limit, 110, 10));
+    book.add_order(order("b1", Side::Buy, OrderType::Limit, 90, 4));
+    book
+  }
+
+  #[test]
+  fn test_limit_order_stops_at_limit_price_without_mutating_book() {
+    let book = book();
+    let result = simulate(&book, &order("t1", Side::Buy, OrderType::Limit, 105, 10), &MarketProtection::default());
+
+    assert_eq!(result.status, OrderAckStatus::PartiallyFilled);
+    assert_eq!(result.fills, vec![SimulatedFill { price: 100, quantity: 8 }]);
+    assert_eq!((result.filled_quantity, result.remaining_quantity), (8, 2));
+    assert_eq!(result.slippage_bps, Some(0.0));
+
+    // 주문장은 그대로
+    assert_eq!(book.asks[&100].total_volume, 8);
+    assert_eq!(book.order_count(), 4);
+  }
+
+  #[test]
+  fn test_market_order_sweeps_levels_within_protection() {
+    let book = book();
+    let result = simulate(&book, &order("t2", Side::Buy, OrderType::Market, 0, 12), &MarketProtection::default());
+
+    assert_eq!(result.status, OrderAckStatus::Filled);
+    assert_eq!(
+      result.fills,
+      vec![SimulatedFill { price: 100, quantity: 8 }, SimulatedFill { price: 110, quantity: 4 }]
+    );
+    assert_eq!(result.reference_price, Some(100));
+    let average = (100.0 * 8.0 + 110.0 * 4.0) / 12.0;
+    assert_eq!(result.average_price, Some(average));
+    assert!((result.slippage_bps.unwrap() - (average - 100.0) / 100.0 * 10_000.0).abs() < 1e-9);
+
+    // 슬리피지 5% 한도면 110 레벨은 체결하지 않고 남은 수량 취소
+    let protection = MarketProtection { max_slippage_pct: Some(5.0), max_levels: None };
+    let result = simulate(&book, &order("t3", Side::Buy, OrderType::Market, 0, 12), &protection);
+    assert_eq!(result.status, OrderAckStatus::Canceled);
+    assert_eq!((result.filled_quantity, result.remaining_quantity), (8, 0));
+
+    // 최대 레벨은 가격 레벨 수 (100 레벨의 메이커 2건을 모두 체결)
+    let protection = MarketProtection { max_slippage_pct: None, max_levels: Some(1) };
+    let result = simulate(&book, &order("t5", Side::Buy, OrderType::Market, 0, 12), &protection);
+    assert_eq!(result.fills, vec![SimulatedFill { price: 100, quantity: 8 }]);
+    assert_eq!((result.status, result.filled_quantity), (OrderAckStatus::Canceled, 8));
+
+    // 매도 시장가, 매수 호가 없으면 체결 없음
+    let empty = OrderBook::new("BTC-KRW".to_string());
+    let result = simulate(&empty, &order("t4", Side::Sell, OrderType::Market, 0, 1), &MarketProtection::default());
+    assert_eq!((result.status, result.reference_price, result.slippage_bps), (OrderAckStatus::Canceled, None, None));
+  }
+}
@@ -0,0 +1,24 @@
+//! xTrader 주문장/매칭 핵심
+//!
+//! 주문/체결 모델, 가격-시간 우선 주문장과 연속 매칭, 단일가 경매, 모의 체결, 주문 처리 결과 응답
+//! 채널, 시각 소스([`Clock`])를 담습니다. Kafka/RabbitMQ/Redis, DB, HTTP 서버에 의존하지 않으므로
+//! 백테스트나 다른 서비스에서 주문장만 라이브러리로 쓸 수 있습니다. 서버의 `MatchingEngine`(MQ 발행,
+//! 킬 스위치, 복제 연동)은 `xtrader-server` 크레이트에 있고 이 크레이트의 주문장과 매칭을 씁니다.
+
+pub mod auction;
+pub mod clock;
+pub mod dry_run;
+pub mod linked_list;
+pub mod matching;
+pub mod model;
+pub mod order_ack;
+pub mod order_book;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use clock::{Clock, SharedClock, SystemClock};
+pub use dry_run::DryRunResult;
+pub use linked_list::{DoublyLinkedList, Node};
+pub use model::{ExecType, ExecutionReport, MarketProtection, Order, OrderBookSnapshot, OrderType, Side};
+pub use order_ack::{OrderAck, OrderAckRegistry, OrderAckStatus, OrderRejectReason};
+pub use order_book::{BookMemory, LevelCompaction, LevelLifecyclePolicy, OrderBook};
@@ -1,12 +1,11 @@
-/**
+/*
 * filename : linked_list
 * author : HAMA
 * date: 2025. 5. 11.
 * description: 
-**/
+*/
 
 use std::sync::{Arc, Mutex, Weak};
-use std::collections::HashMap;
 
 type Link<T> = Option<Arc<Mutex<Node<T>>>>;
 
@@ -24,6 +23,12 @@ pub struct DoublyLinkedList<T> {
   count: usize,
 }
 
+impl<T> Default for DoublyLinkedList<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 impl<T> DoublyLinkedList<T> {
   pub fn new() -> Self {
     DoublyLinkedList { head: None, tail: None , count: 0}
@@ -87,7 +92,7 @@ impl<T> DoublyLinkedList<T> {
   }
   
   pub fn pop_front(&mut self) -> Option<Arc<Mutex<Node<T>>>> {
-    self.head.take().map(|old_head| {
+    self.head.take().inspect(|old_head| {
       if let Ok(mut head_guard) = old_head.lock() {
         if let Some(next) = head_guard.next.take() {
           if let Ok(mut next_guard) = next.lock() {
@@ -99,7 +104,6 @@ impl<T> DoublyLinkedList<T> {
         }
       }
       self.count = self.count.saturating_sub(1);
-      old_head
     })
   }
   
@@ -115,6 +119,20 @@ impl<T> DoublyLinkedList<T> {
   pub fn len(&self) -> usize {
     self.count
   }
+  
+  /// 앞에서부터 각 값에 `f`를 적용한 결과 (리스트는 바꾸지 않음)
+  pub fn map_values<R>(&self, f: impl Fn(&T) -> R) -> Vec<R> {
+    let mut values = Vec::with_capacity(self.count);
+    let mut current = self.head.clone();
+    while let Some(node) = current {
+      let Ok(guard) = node.lock() else {
+        break;
+      };
+      values.push(f(&guard.value));
+      current = guard.next.clone();
+    }
+    values
+  }
 }
 
 #[cfg(test)]
@@ -167,6 +185,20 @@ mod tests {
     assert!(node3.lock().unwrap().next.is_none());
   }
   
+  #[test]
+  fn test_map_values_in_order() {
+    let mut list = DoublyLinkedList::new();
+    assert!(list.map_values(|v: &i32| *v).is_empty());
+    
+    list.push_back(10);
+    let node2 = list.push_back(20);
+    list.push_back(30);
+    list.remove(node2);
+    
+    assert_eq!(list.map_values(|v| v * 2), vec![20, 60]);
+    assert_eq!(list.len(), 2);
+  }
+  
   #[test]
   fn test_peek_front() {
     let mut list = DoublyLinkedList::new();
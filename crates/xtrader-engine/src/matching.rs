@@ -0,0 +1,209 @@
+//! 연속 매칭 (가격-시간 우선)
+//!
+//! 테이커 주문을 반대편 호가와 체결하고 체결 내역을 돌려줍니다. 서버의 `MatchingEngine`과
+//! WASM 주문장이 같은 함수를 쓰므로 체결 규칙이 한 곳에만 있습니다. 주문 저장소, 체결 보고서,
+//! 남은 지정가 수량의 주문장 등록, 시장가 잔량 취소는 호출하는 쪽이 처리합니다.
+
+use std::cmp::Reverse;
+
+use log::debug;
+
+use crate::model::{MarketProtection, Order, OrderType, Side};
+use crate::order_book::OrderBook;
+
+/// 체결 한 건 (메이커 주문 하나와의 체결)
+#[derive(Debug, Clone)]
+pub struct Fill {
+  /// 체결 후 메이커 주문 (남은 수량 반영)
+  pub maker: Order,
+  pub price: u64,
+  pub quantity: u64,
+  /// 이 체결 직후 테이커 주문의 남은 수량
+  pub taker_remaining: u64,
+}
+
+/// 매칭 결과
+#[derive(Debug, Clone, Default)]
+pub struct MatchOutcome {
+  /// 체결 순서대로
+  pub fills: Vec<Fill>,
+  /// 시장가 보호 한도(슬리피지, 최대 레벨)로 매칭을 멈췄는지
+  pub protection_triggered: bool,
+}
+
+impl MatchOutcome {
+  /// 체결 수량 합계
+  pub fn filled_quantity(&self) -> u64 {
+    self.fills.iter().map(|fill| fill.quantity).sum()
+  }
+}
+
+/// 반대편 최우선 호가
+fn best_opposite_price(book: &OrderBook, side: &Side) -> Option<u64> {
+  match side {
+    Side::Buy => book.get_best_ask().map(|(price, _)| price),
+    Side::Sell => book.get_best_bid().map(|(price, _)| price),
+  }
+}
+
+/// 테이커 주문을 주문장과 매칭 (`order.remaining_quantity`를 체결만큼 줄임)
+///
+/// 지정가 주문은 지정가를 넘지 않는 호가까지, 시장가 주문은 `protection` 한도 안에서 체결합니다.
+/// 시장가의 `max_levels`는 체결한 가격 레벨 수와 비교하며, 같은 가격의 메이커 여러 건은 한 레벨로 셉니다 (모의 체결과 같음).
+pub fn match_order(book: &mut OrderBook, order: &mut Order, protection: &MarketProtection) -> MatchOutcome {
+  let mut outcome = MatchOutcome::default();
+  let mut reference: Option<u64> = None;
+  // 이미 체결한 가격 레벨 수 (같은 가격의 메이커 여러 건은 한 레벨)
+  let mut levels_swept = 0usize;
+  let mut last_price: Option<u64> = None;
+
+  while !order.is_filled() {
+    let Some(price) = best_opposite_price(book, &order.side) else {
+      debug!("매칭할 반대편 호가 없음: {}", order.id);
+      break;
+    };
+
+    match order.order_type {
+      OrderType::Limit => {
+        let crosses = match order.side {
+          Side::Buy => order.price >= price,
+          Side::Sell => order.price <= price,
+        };
+        if !crosses {
+          debug!("매칭 불가: 주문가({}) / 최우선 호가({})", order.price, price);
+          break;
+        }
+      }
+      OrderType::Market => {
+        let reference = *reference.get_or_insert(price);
+        let new_level = last_price != Some(price);
+        if (new_level && !protection.allows_level(levels_swept)) || !protection.allows_price(&order.side, reference, price) {
+          debug!("시장가 보호 한도 도달: {} (기준가: {}, 현재가: {}, 레벨: {})",
+                 order.id, reference, price, levels_swept);
+          outcome.protection_triggered = true;
+          break;
+        }
+      }
+    }
+
+    let Some(fill) = match_at_price_level(book, order, price) else {
+      break;
+    };
+    if last_price != Some(price) {
+      levels_swept += 1;
+      last_price = Some(price);
+    }
+    outcome.fills.push(fill);
+  }
+
+  outcome
+}
+
+/// 가격 레벨의 맨 앞 메이커 주문과 체결 (빈 레벨과 완전 체결된 메이커는 주문장에서 제거)
+fn match_at_price_level(book: &mut OrderBook, order: &mut Order, price: u64) -> Option<Fill> {
+  let (maker_id, maker, quantity) = match order.side {
+    Side::Buy => {
+      let level = book.asks.get_mut(&price)?;
+      let result = level.match_partial(order.remaining_quantity);
+      if level.is_empty() {
+        book.asks.remove(&price);
+        debug!("빈 가격 레벨 제거 (매도): {}", price);
+      }
+      result?
+    }
+    Side::Sell => {
+      let level = book.bids.get_mut(&Reverse(price))?;
+      let result = level.match_partial(order.remaining_quantity);
+      if level.is_empty() {
+        book.bids.remove(&Reverse(price));
+        debug!("빈 가격 레벨 제거 (매수): {}", price);
+      }
+      result?
+    }
+  };
+
+  order.fill(quantity);
+  if maker.is_filled() {
+    book.orders.remove(&maker_id);
+  }
+  debug!("주문 체결: {} <-> {}, 가격: {}, 수량: {}", order.id, maker_id, price, quantity);
+
+  Some(Fill { maker, price, quantity, taker_remaining: order.remaining_quantity })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn order(id: &str, side: Side, order_type: OrderType, price: u64, quantity: u64) -> Order {
+    Order::new(id.to_string(), "BTC-KRW".to_string(), side, order_type, price, quantity, "trader".to_string())
+  }
+
+  fn book() -> OrderBook {
+    let mut book = OrderBook::new("BTC-KRW".to_string());
+    book.add_order(order("a1", Side::Sell, OrderType::Limit, 100, 5));
+    book.add_order(order("a2", Side::Sell, OrderType::Limit, 100, 3));
+    book.add_order(order("a3", Side::Sell, OrderType::Limit, 110, 10));
+    book.add_order(order("b1", Side::Buy, OrderType::Limit, 90, 4));
+    book
+  }
+
+  #[test]
+  fn test_limit_order_fills_in_price_time_priority() {
+    let mut book = book();
+    let mut taker = order("t1", Side::Buy, OrderType::Limit, 105, 10);
+    let outcome = match_order(&mut book, &mut taker, &MarketProtection::default());
+
+    let fills: Vec<(&str, u64, u64, u64)> = outcome
+      .fills
+      .iter()
+      .map(|fill| (fill.maker.id.as_str(), fill.price, fill.quantity, fill.taker_remaining))
+      .collect();
+    assert_eq!(fills, vec![("a1", 100, 5, 5), ("a2", 100, 3, 2)]);
+    assert_eq!((outcome.filled_quantity(), taker.remaining_quantity), (8, 2));
+
+    // 체결된 메이커와 빈 레벨은 주문장에서 제거 (남은 지정가 수량 등록은 호출하는 쪽)
+    assert!(!book.asks.contains_key(&100));
+    assert!(!book.orders.contains_key("a1") && !book.orders.contains_key("a2"));
+    assert_eq!(book.order_count(), 2);
+  }
+
+  #[test]
+  fn test_market_order_respects_protection_and_partial_maker() {
+    let mut book = book();
+    let protection = MarketProtection { max_slippage_pct: Some(5.0), max_levels: None };
+    let mut taker = order("t2", Side::Buy, OrderType::Market, 0, 12);
+    let outcome = match_order(&mut book, &mut taker, &protection);
+    assert!(outcome.protection_triggered);
+    assert_eq!((outcome.filled_quantity(), taker.remaining_quantity), (8, 4));
+
+    // 메이커 부분 체결은 주문장에 남은 수량으로 유지
+    let mut taker = order("t3", Side::Buy, OrderType::Market, 0, 4);
+    let outcome = match_order(&mut book, &mut taker, &MarketProtection::default());
+    assert_eq!((outcome.fills[0].maker.remaining_quantity, outcome.protection_triggered), (6, false));
+    assert_eq!(book.get_order_book_snapshot(5).asks, vec![(110, 6)]);
+
+    // 매도 지정가가 매수 호가보다 높으면 체결 없음
+    let mut taker = order("t4", Side::Sell, OrderType::Limit, 95, 1);
+    assert!(match_order(&mut book, &mut taker, &MarketProtection::default()).fills.is_empty());
+  }
+
+  #[test]
+  fn test_max_levels_counts_price_levels_not_makers() {
+    let mut book = OrderBook::new("BTC-KRW".to_string());
+    for (id, quantity) in [("a1", 2), ("a2", 2), ("a3", 2)] {
+      book.add_order(order(id, Side::Sell, OrderType::Limit, 100, quantity));
+    }
+    book.add_order(order("a4", Side::Sell, OrderType::Limit, 101, 5));
+    book.add_order(order("a5", Side::Sell, OrderType::Limit, 102, 5));
+
+    // 한 레벨의 메이커 3건을 모두 체결한 뒤 두 번째 레벨까지만
+    let protection = MarketProtection { max_slippage_pct: None, max_levels: Some(2) };
+    let mut taker = order("t5", Side::Buy, OrderType::Market, 0, 15);
+    let outcome = match_order(&mut book, &mut taker, &protection);
+    let makers: Vec<&str> = outcome.fills.iter().map(|fill| fill.maker.id.as_str()).collect();
+    assert_eq!(makers, vec!["a1", "a2", "a3", "a4"]);
+    assert_eq!((outcome.filled_quantity(), taker.remaining_quantity, outcome.protection_triggered), (11, 4, true));
+    assert_eq!(book.get_order_book_snapshot(5).asks, vec![(102, 5)]);
+  }
+}
@@ -0,0 +1,306 @@
+//! 주문 매칭 엔진의 기본 모델
+//!
+//! 이 모듈은 주문, 주문 타입, 매수/매도 방향, 체결 보고서 등
+//! 매칭 엔진의 핵심 데이터 모델을 정의합니다.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// 매수/매도 방향
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum Side {
+  /// 매수 주문
+  Buy,
+  /// 매도 주문
+  Sell,
+}
+
+/// 주문 타입
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum OrderType {
+  /// 시장가 주문 - 현재 시장 가격에 즉시 체결
+  Market,
+  /// 지정가 주문 - 지정된 가격에서만 체결
+  Limit,
+}
+
+/// 시장가 주문 보호 설정
+///
+/// 시장가 주문이 반대편 호가를 과도하게 쓸어가지 않도록 제한합니다.
+/// 한도에 도달하면 남은 수량은 취소됩니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketProtection {
+  /// 최우선 호가 대비 최대 허용 슬리피지 (퍼센트, 예: 1.5 = 1.5%)
+  pub max_slippage_pct: Option<f64>,
+  /// 최대 체결 가능 가격 레벨 수
+  pub max_levels: Option<usize>,
+}
+
+impl MarketProtection {
+  /// 제한이 하나도 설정되지 않았는지 확인
+  pub fn is_unbounded(&self) -> bool {
+    self.max_slippage_pct.is_none() && self.max_levels.is_none()
+  }
+
+  /// 최우선 호가 기준 허용 가능한 가격인지 확인
+  pub fn allows_price(&self, side: &Side, best_price: u64, price: u64) -> bool {
+    match self.max_slippage_pct {
+      Some(pct) => {
+        let offset = (best_price as f64 * pct / 100.0) as u64;
+        match side {
+          Side::Buy => price <= best_price.saturating_add(offset),
+          Side::Sell => price >= best_price.saturating_sub(offset),
+        }
+      }
+      None => true,
+    }
+  }
+
+  /// 체결한 가격 레벨 수가 한도 이내인지 확인
+  pub fn allows_level(&self, levels_swept: usize) -> bool {
+    self.max_levels.is_none_or(|max| levels_swept < max)
+  }
+
+  /// 필드별로 `limit`(거래소 심볼 기본값)보다 느슨해지지 않게 제한
+  ///
+  /// 주문이 설정하지 않은 필드는 `limit` 값을 쓰고, 둘 다 있으면 더 엄격한 값을 씁니다.
+  pub fn clamp_to(&self, limit: &MarketProtection) -> MarketProtection {
+    MarketProtection {
+      max_slippage_pct: match (self.max_slippage_pct, limit.max_slippage_pct) {
+        (Some(own), Some(max)) => Some(own.min(max)),
+        (own, max) => own.or(max),
+      },
+      max_levels: match (self.max_levels, limit.max_levels) {
+        (Some(own), Some(max)) => Some(own.min(max)),
+        (own, max) => own.or(max),
+      },
+    }
+  }
+}
+
+/// 주문 정보
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Order {
+  /// 주문 고유 ID
+  pub id: String,
+  /// 거래 대상 심볼 (예: "AAPL", "MSFT")
+  pub symbol: String,
+  /// 매수/매도 방향
+  pub side: Side,
+  /// 주문 타입 (시장가/지정가)
+  pub order_type: OrderType,
+  /// 주문 가격 (지정가 주문에만 의미 있음)
+  pub price: u64,
+  /// 주문 총 수량
+  pub quantity: u64,
+  /// 남은 미체결 수량
+  pub remaining_quantity: u64,
+  /// 고객 ID
+  pub client_id: String,
+  /// 주문 생성 시간 (Unix 타임스탬프)
+  pub timestamp: u64,
+  /// 취소 주문 여부
+  pub is_cancel: bool,
+  /// 취소 대상 주문 ID (취소 주문인 경우에만 있음)
+  pub target_order_id: Option<String>,
+  /// 시장가 주문 보호 설정 (없으면 심볼 기본값 적용)
+  #[serde(default)]
+  pub protection: Option<MarketProtection>,
+  /// 주문 만료 시간 (Unix 타임스탬프, GTD 주문에만 있음)
+  #[serde(default)]
+  pub expire_time: Option<u64>,
+  /// 전역 시퀀스 번호 (시퀀서가 받아들일 때 부여, 0이면 아직 없음)
+  #[serde(default)]
+  pub sequence: u64,
+  /// 사용자 지정 태그 (전략 ID 등, 이 주문의 체결 보고서에 그대로 실림)
+  #[serde(default)]
+  pub tag: Option<String>,
+}
+
+impl Order {
+  /// 새 주문 생성
+  pub fn new(
+    id: String,
+    symbol: String,
+    side: Side,
+    order_type: OrderType,
+    price: u64,
+    quantity: u64,
+    client_id: String,
+  ) -> Self {
+    let now = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .unwrap()
+      .as_secs();
+    
+    Self {
+      id,
+      symbol,
+      side,
+      order_type,
+      price,
+      quantity,
+      remaining_quantity: quantity,
+      client_id,
+      timestamp: now,
+      is_cancel: false,
+      target_order_id: None,
+      protection: None,
+      expire_time: None,
+      sequence: 0,
+      tag: None,
+    }
+  }
+  
+  /// 취소 주문 생성
+  pub fn new_cancel(target_order_id: String) -> Self {
+    let now = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .unwrap()
+      .as_secs();
+    
+    Self {
+      id: format!("cancel-{}", target_order_id),
+      symbol: String::new(),
+      side: Side::Buy,  // 취소 주문은 side가 의미 없음
+      order_type: OrderType::Market,  // 취소 주문은 order_type이 의미 없음
+      price: 0,
+      quantity: 0,
+      remaining_quantity: 0,
+      client_id: "system".to_string(),
+      timestamp: now,
+      is_cancel: true,
+      target_order_id: Some(target_order_id),
+      protection: None,
+      expire_time: None,
+      sequence: 0,
+      tag: None,
+    }
+  }
+  
+  /// 시장가 주문 보호 설정 지정
+  pub fn with_protection(mut self, protection: MarketProtection) -> Self {
+    self.protection = Some(protection);
+    self
+  }
+  
+  /// 주문 만료 시간 지정 (GTD)
+  pub fn with_expire_time(mut self, expire_time: u64) -> Self {
+    self.expire_time = Some(expire_time);
+    self
+  }
+  
+  /// 사용자 지정 태그 지정
+  pub fn with_tag(mut self, tag: String) -> Self {
+    self.tag = Some(tag);
+    self
+  }
+  
+  /// 주어진 시각 기준으로 만료되었는지 확인
+  pub fn is_expired(&self, now: u64) -> bool {
+    self.expire_time.is_some_and(|t| t <= now)
+  }
+  
+  /// 주문 체결 처리
+  pub fn fill(&mut self, quantity: u64) {
+    self.remaining_quantity = self.remaining_quantity.saturating_sub(quantity);
+  }
+  
+  /// 주문이 완전히 체결되었는지 확인
+  pub fn is_filled(&self) -> bool {
+    self.remaining_quantity == 0
+  }
+}
+
+impl AsRef<Order> for Order {
+  fn as_ref(&self) -> &Order {
+    self
+  }
+}
+
+/// 체결 보고서 유형
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum ExecType {
+  /// 체결
+  #[default]
+  Trade,
+  /// 취소 (사용자 요청, 보호 한도 또는 시장가 잔량)
+  Canceled,
+  /// 만료 (GTD 또는 장기 미체결)
+  Expired,
+  /// 매칭 엔진이 거부 (주문장이 없는 심볼)
+  Rejected,
+}
+
+/// 체결 보고서
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ExecutionReport {
+  /// 체결 고유 ID
+  pub execution_id: String,
+  /// 주문 ID
+  pub order_id: String,
+  /// 거래 대상 심볼
+  pub symbol: String,
+  /// 매수/매도 방향
+  pub side: Side,
+  /// 체결 가격
+  pub price: u64,
+  /// 체결 수량
+  pub quantity: u64,
+  /// 남은 미체결 수량
+  pub remaining_quantity: u64,
+  /// 체결 시간 (Unix 타임스탬프)
+  pub timestamp: u64,
+  /// 상대방 주문 ID
+  pub counterparty_id: String,
+  /// 메이커(호가에 있던 주문) 여부
+  pub is_maker: bool,
+  /// 보고서 유형
+  #[serde(default)]
+  pub exec_type: ExecType,
+  /// 전역 시퀀스 번호 (시퀀서가 발행할 때 부여, 주문 번호와 같은 순서)
+  #[serde(default)]
+  pub sequence: u64,
+  /// 주문의 사용자 지정 태그 (없으면 null)
+  #[serde(default)]
+  pub tag: Option<String>,
+}
+
+/// 주문장 스냅샷
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct OrderBookSnapshot {
+  /// 심볼
+  pub symbol: String,
+  /// 매수 호가 목록 [(가격, 수량)]
+  pub bids: Vec<(u64, u64)>,
+  /// 매도 호가 목록 [(가격, 수량)]
+  pub asks: Vec<(u64, u64)>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_protection_clamp_keeps_exchange_limits() {
+    let exchange = MarketProtection { max_slippage_pct: Some(1.0), max_levels: Some(5) };
+
+    // 주문이 레벨만 지정해도 거래소 슬리피지 한도는 유지
+    let own = MarketProtection { max_slippage_pct: None, max_levels: Some(2) };
+    assert_eq!(own.clamp_to(&exchange), MarketProtection { max_slippage_pct: Some(1.0), max_levels: Some(2) });
+
+    // 거래소 한도보다 느슨한 값은 한도로 제한
+    let own = MarketProtection { max_slippage_pct: Some(3.0), max_levels: Some(10) };
+    assert_eq!(own.clamp_to(&exchange), exchange);
+
+    // 심볼 기본값이 없으면 주문 설정 그대로
+    assert_eq!(own.clamp_to(&MarketProtection::default()), own);
+  }
+}
@@ -0,0 +1,168 @@
+//! 주문 처리 결과 응답
+//!
+//! REST 핸들러는 주문을 큐에 넣기 전에 주문 ID로 응답 채널(oneshot)을 등록하고,
+//! 매칭 엔진은 주문을 처리한 직후 확정 상태(접수, 체결 수량, 거부 사유)를 그 채널로 돌려줍니다.
+//! 등록되지 않은 주문(복제, 백테스트 등)은 응답 없이 처리됩니다.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::sync::oneshot;
+
+/// 기본 응답 대기 시간
+pub const DEFAULT_ORDER_ACK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// 매칭 엔진 처리 후 주문 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderAckStatus {
+  /// 체결 없이 주문장에 등록
+  Accepted,
+  /// 일부 체결, 남은 수량은 주문장에 등록
+  PartiallyFilled,
+  /// 전량 체결
+  Filled,
+  /// 남은 수량 취소 (시장가 주문의 호가 부족 또는 보호 한도)
+  Canceled,
+  /// 매칭 엔진이 거부
+  Rejected(OrderRejectReason),
+}
+
+impl OrderAckStatus {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      OrderAckStatus::Accepted => "ACCEPTED",
+      OrderAckStatus::PartiallyFilled => "PARTIALLY_FILLED",
+      OrderAckStatus::Filled => "FILLED",
+      OrderAckStatus::Canceled => "CANCELED",
+      OrderAckStatus::Rejected(_) => "REJECTED",
+    }
+  }
+}
+
+/// 매칭 엔진 거부 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderRejectReason {
+  /// 주문장이 없는 심볼
+  UnknownSymbol,
+  /// 엔진 도착 시 이미 만료된 GTD 주문
+  Expired,
+  /// 킬 스위치로 차단된 계정
+  ClientBlocked,
+  /// 킬 스위치로 거래 중단된 심볼
+  SymbolHalted,
+  /// 취소 비율 과다로 주문 속도 제한 중인 계정의 초당 한도 초과
+  Throttled,
+  /// 배치 경매 심볼의 시장가 주문
+  MarketOrderInAuction,
+}
+
+/// 매칭 엔진 처리 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderAck {
+  pub order_id: String,
+  pub status: OrderAckStatus,
+  /// 즉시 체결된 수량
+  pub filled_quantity: u64,
+  /// 주문장에 남은 수량 (취소/거부되면 0)
+  pub remaining_quantity: u64,
+}
+
+/// 응답 대기 중인 주문 (REST 핸들러와 매칭 엔진이 공유)
+pub struct OrderAckRegistry {
+  pending: Mutex<HashMap<String, oneshot::Sender<OrderAck>>>,
+  timeout: Duration,
+}
+
+impl Default for OrderAckRegistry {
+  fn default() -> Self {
+    Self::new(DEFAULT_ORDER_ACK_TIMEOUT)
+  }
+}
+
+impl OrderAckRegistry {
+  pub fn new(timeout: Duration) -> Self {
+    Self {
+      pending: Mutex::new(HashMap::new()),
+      timeout,
+    }
+  }
+
+  /// 대기 중인 주문 수
+  pub fn pending_count(&self) -> usize {
+    self.pending.lock().unwrap().len()
+  }
+
+  /// 주문 응답 채널 등록 (주문을 큐에 넣기 전에 호출)
+  pub fn register(&self, order_id: &str) -> oneshot::Receiver<OrderAck> {
+    let (tx, rx) = oneshot::channel();
+    self.pending.lock().unwrap().insert(order_id.to_string(), tx);
+    rx
+  }
+
+  /// 등록 해제 (큐에 넣지 못했거나 응답을 기다리지 않을 때)
+  pub fn forget(&self, order_id: &str) {
+    self.pending.lock().unwrap().remove(order_id);
+  }
+
+  /// 매칭 엔진 처리 결과 전달 (등록되지 않은 주문은 무시)
+  pub fn complete(&self, ack: OrderAck) {
+    let waiter = self.pending.lock().unwrap().remove(&ack.order_id);
+    if let Some(waiter) = waiter {
+      // 핸들러가 이미 시간 초과로 돌아갔으면 버림
+      let _ = waiter.send(ack);
+    }
+  }
+
+  /// 처리 결과 대기 (시간 초과 시 등록을 지우고 None)
+  pub async fn wait(&self, order_id: &str, rx: oneshot::Receiver<OrderAck>) -> Option<OrderAck> {
+    match tokio::time::timeout(self.timeout, rx).await {
+      Ok(Ok(ack)) => Some(ack),
+      _ => {
+        self.forget(order_id);
+        None
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn ack(order_id: &str, status: OrderAckStatus) -> OrderAck {
+    OrderAck {
+      order_id: order_id.to_string(),
+      status,
+      filled_quantity: 0,
+      remaining_quantity: 0,
+    }
+  }
+
+  #[tokio::test]
+  async fn test_complete_wakes_registered_waiter() {
+    let registry = OrderAckRegistry::default();
+    let rx = registry.register("o1");
+    assert_eq!(registry.pending_count(), 1);
+
+    // 등록되지 않은 주문은 무시
+    registry.complete(ack("other", OrderAckStatus::Filled));
+    registry.complete(ack("o1", OrderAckStatus::Rejected(OrderRejectReason::Expired)));
+
+    let received = registry.wait("o1", rx).await.unwrap();
+    assert_eq!(received.status, OrderAckStatus::Rejected(OrderRejectReason::Expired));
+    assert_eq!(received.status.as_str(), "REJECTED");
+    assert_eq!(registry.pending_count(), 0);
+  }
+
+  #[tokio::test]
+  async fn test_wait_times_out_and_forgets() {
+    let registry = OrderAckRegistry::new(Duration::from_millis(10));
+    let rx = registry.register("o1");
+
+    assert!(registry.wait("o1", rx).await.is_none());
+    assert_eq!(registry.pending_count(), 0);
+    // 늦게 도착한 결과는 버림
+    registry.complete(ack("o1", OrderAckStatus::Accepted));
+  }
+}
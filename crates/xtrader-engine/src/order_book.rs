@@ -2,14 +2,78 @@
 //!
 //! 이 모듈은 주문장(Order Book)과 가격 레벨(Price Level)을 구현합니다.
 //! 주문장은 매수/매도 호가를 관리하고, 가격 레벨은 특정 가격의 주문들을 처리합니다.
+//!
+//! 빈 가격 레벨은 주문이 빠지는 즉시 제거되지만, 레벨과 주문장의 주문 맵은 한 번 늘어난
+//! 용량을 돌려주지 않습니다. 오래 떠 있는 서버에서 몰렸던 주문이 빠진 뒤에도 메모리가 남지 않도록
+//! 최우선 호가에서 먼 레벨부터 남는 용량을 정리하는 정책(`LevelLifecyclePolicy`)을 둡니다.
 
 use std::collections::{BTreeMap, HashMap};
+use std::mem::size_of;
 use std::sync::{Arc, Mutex};
 use std::cmp::Reverse;
 use log::{debug, trace};
 
-use crate::matching_engine::model::{Order, Side, OrderBookSnapshot};
-use crate::util::linked_list::{DoublyLinkedList, Node};
+use crate::model::{Order, Side, OrderBookSnapshot};
+use crate::linked_list::{DoublyLinkedList, Node};
+
+/// 가격 레벨 주문 맵 항목 크기 (키, 노드 참조, 제어 바이트)
+const LEVEL_ENTRY_BYTES: usize = size_of::<String>() + size_of::<Arc<Mutex<Node<Order>>>>() + 1;
+/// 주문 노드 크기 (Arc 참조 카운트 포함)
+const ORDER_NODE_BYTES: usize = size_of::<Mutex<Node<Order>>>() + 2 * size_of::<usize>();
+/// 주문장 주문 맵 항목 크기 (키, 방향/가격, 제어 바이트)
+const BOOK_ENTRY_BYTES: usize = size_of::<String>() + size_of::<(Side, u64)>() + 1;
+/// 가격 레벨 트리 항목 크기
+const LEVEL_BYTES: usize = size_of::<u64>() + size_of::<PriceLevel>();
+
+/// 가격 레벨 정리 정책
+///
+/// 최우선 호가 근처 레벨은 주문이 계속 드나드므로 건드리지 않고, 그 밖의 레벨과 주문장 주문 맵의
+/// 남는 용량이 `min_slack` 이상이면서 주문 수의 `slack_ratio`배를 넘으면 줄입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelLifecyclePolicy {
+  /// 정리하지 않는 최우선 호가 근처 레벨 수 (방향별)
+  pub near_touch_levels: usize,
+  /// 정리하는 최소 여유 용량 (항목 수)
+  pub min_slack: usize,
+  /// 정리하는 여유 용량 비율 (주문 수 대비)
+  pub slack_ratio: usize,
+}
+
+impl Default for LevelLifecyclePolicy {
+  fn default() -> Self {
+    Self { near_touch_levels: 10, min_slack: 64, slack_ratio: 4 }
+  }
+}
+
+impl LevelLifecyclePolicy {
+  /// 용량을 줄여야 하는지 여부
+  fn should_shrink(&self, capacity: usize, len: usize) -> bool {
+    let slack = capacity.saturating_sub(len);
+    slack >= self.min_slack && slack > len.saturating_mul(self.slack_ratio)
+  }
+}
+
+/// 주문장 메모리 사용량 (추정치)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BookMemory {
+  /// 매수 가격 레벨 수
+  pub bid_levels: usize,
+  /// 매도 가격 레벨 수
+  pub ask_levels: usize,
+  /// 주문 수
+  pub orders: usize,
+  /// 추정 바이트 (레벨/주문 구조체, 맵 용량, 주문 ID 문자열)
+  pub bytes: usize,
+}
+
+/// 가격 레벨 정리 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LevelCompaction {
+  /// 용량을 줄인 가격 레벨 수
+  pub levels: usize,
+  /// 돌려준 추정 바이트
+  pub reclaimed_bytes: usize,
+}
 
 /// 가격 레벨(Price Level) 구현
 /// 특정 가격에 대한 모든 주문을 관리합니다.
@@ -23,6 +87,12 @@ pub struct PriceLevel {
   pub total_volume: u64,
 }
 
+impl Default for PriceLevel {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 impl PriceLevel {
   /// 새 가격 레벨 생성
   pub fn new() -> Self {
@@ -154,6 +224,39 @@ impl PriceLevel {
   pub fn len(&self) -> usize {
     self.orders.len()
   }
+  
+  /// 대기 주문의 남은 수량 (시간 우선순위순)
+  pub fn resting_quantities(&self) -> Vec<u64> {
+    self.orders.map_values(|order| order.remaining_quantity)
+  }
+
+  /// 추정 메모리 사용량 (주문 맵 용량, 주문 노드, 주문 ID 문자열)
+  pub fn memory_bytes(&self) -> usize {
+    let id_bytes: usize = self.order_map.keys().map(|id| id.capacity()).sum();
+    self.order_map.capacity() * LEVEL_ENTRY_BYTES + self.orders.len() * ORDER_NODE_BYTES + id_bytes * 2
+  }
+
+  /// 남는 주문 맵 용량 정리 (돌려준 추정 바이트)
+  fn shrink(&mut self, policy: &LevelLifecyclePolicy) -> Option<usize> {
+    let capacity = self.order_map.capacity();
+    if !policy.should_shrink(capacity, self.order_map.len()) {
+      return None;
+    }
+    self.order_map.shrink_to_fit();
+    Some(capacity.saturating_sub(self.order_map.capacity()) * LEVEL_ENTRY_BYTES)
+  }
+
+  /// 계정별 대기 수량 합 (계정이 처음 나타난 시간 우선순위순)
+  pub fn resting_by_client(&self) -> Vec<(String, u64)> {
+    let mut by_client: Vec<(String, u64)> = Vec::new();
+    for (client_id, quantity) in self.orders.map_values(|order| (order.client_id.clone(), order.remaining_quantity)) {
+      match by_client.iter_mut().find(|(id, _)| *id == client_id) {
+        Some((_, total)) => *total += quantity,
+        None => by_client.push((client_id, quantity)),
+      }
+    }
+    by_client
+  }
 }
 
 /// 주문장(Order Book) 구현
@@ -193,7 +296,7 @@ impl OrderBook {
     match side {
       Side::Buy => {
         // 매수 주문 - 내림차순으로 저장 (Reverse 사용)
-        let price_level = self.bids.entry(Reverse(price)).or_insert_with(PriceLevel::new);
+        let price_level = self.bids.entry(Reverse(price)).or_default();
         price_level.add_order(order);
         self.orders.insert(order_id.clone(), (Side::Buy, price));
         
@@ -201,7 +304,7 @@ impl OrderBook {
       },
       Side::Sell => {
         // 매도 주문 - 오름차순으로 저장
-        let price_level = self.asks.entry(price).or_insert_with(PriceLevel::new);
+        let price_level = self.asks.entry(price).or_default();
         price_level.add_order(order);
         self.orders.insert(order_id.clone(), (Side::Sell, price));
         
@@ -320,12 +423,50 @@ impl OrderBook {
   pub fn order_count(&self) -> usize {
     self.bid_count() + self.ask_count()
   }
+
+  /// 추정 메모리 사용량
+  pub fn memory_usage(&self) -> BookMemory {
+    let level_bytes: usize = self.bids.values().chain(self.asks.values()).map(|level| level.memory_bytes()).sum();
+    let id_bytes: usize = self.orders.keys().map(|id| id.capacity()).sum();
+    BookMemory {
+      bid_levels: self.bids.len(),
+      ask_levels: self.asks.len(),
+      orders: self.orders.len(),
+      bytes: (self.bids.len() + self.asks.len()) * LEVEL_BYTES
+        + level_bytes
+        + self.orders.capacity() * BOOK_ENTRY_BYTES
+        + id_bytes,
+    }
+  }
+
+  /// 최우선 호가에서 먼 가격 레벨과 주문 맵의 남는 용량 정리
+  pub fn compact_levels(&mut self, policy: &LevelLifecyclePolicy) -> LevelCompaction {
+    let mut result = LevelCompaction::default();
+    let far_bids = self.bids.values_mut().skip(policy.near_touch_levels);
+    let far_asks = self.asks.values_mut().skip(policy.near_touch_levels);
+    for level in far_bids.chain(far_asks) {
+      if let Some(reclaimed) = level.shrink(policy) {
+        result.levels += 1;
+        result.reclaimed_bytes += reclaimed;
+      }
+    }
+
+    let capacity = self.orders.capacity();
+    if policy.should_shrink(capacity, self.orders.len()) {
+      self.orders.shrink_to_fit();
+      result.reclaimed_bytes += capacity.saturating_sub(self.orders.capacity()) * BOOK_ENTRY_BYTES;
+    }
+    if result.reclaimed_bytes > 0 {
+      debug!("주문장 용량 정리 ({}): 레벨 {}개, {}바이트", self.symbol, result.levels, result.reclaimed_bytes);
+    }
+    result
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::matching_engine::model::{Order, Side, OrderType};
+  use crate::model::{Order, Side, OrderType};
   use uuid::Uuid;
   
   // 테스트용 주문 생성 헬퍼 함수
@@ -342,6 +483,10 @@ mod tests {
       timestamp: 0,
       is_cancel: false,
       target_order_id: None,
+      protection: None,
+      expire_time: None,
+      sequence: 0,
+      tag: None,
     }
   }
   
@@ -351,7 +496,6 @@ mod tests {
     
     // 주문 추가
     let order = create_test_order(Side::Buy, 1000, 100);
-    let order_id = order.id.clone();
     price_level.add_order(order);
     
     // 검증
@@ -386,6 +530,21 @@ mod tests {
     assert!(price_level.is_empty());
   }
   
+  #[test]
+  fn test_price_level_resting_by_client() {
+    let mut price_level = PriceLevel::new();
+    for (client_id, quantity) in [("mm_a", 100), ("mm_b", 50), ("mm_a", 30)] {
+      let mut order = create_test_order(Side::Buy, 1000, quantity);
+      order.client_id = client_id.to_string();
+      price_level.add_order(order);
+    }
+
+    assert_eq!(
+      price_level.resting_by_client(),
+      vec![("mm_a".to_string(), 130), ("mm_b".to_string(), 50)]
+    );
+  }
+  
   #[test]
   fn test_price_level_match_partial() {
     let mut price_level = PriceLevel::new();
@@ -427,12 +586,10 @@ mod tests {
     
     // 매수 주문 추가
     let buy_order = create_test_order(Side::Buy, 9000, 100);
-    let buy_id = buy_order.id.clone();
     order_book.add_order(buy_order);
     
     // 매도 주문 추가
     let sell_order = create_test_order(Side::Sell, 10000, 200);
-    let sell_id = sell_order.id.clone();
     order_book.add_order(sell_order);
     
     // 검증
@@ -515,6 +672,59 @@ mod tests {
     assert_eq!(snapshot.asks[2].0, 10100);
   }
   
+  #[test]
+  fn test_memory_usage_tracks_levels_and_orders() {
+    let mut order_book = OrderBook::new("BTC-KRW".to_string());
+    assert_eq!(order_book.memory_usage(), BookMemory::default());
+
+    let buy_order = create_test_order(Side::Buy, 9000, 100);
+    let buy_id = buy_order.id.clone();
+    order_book.add_order(buy_order);
+    order_book.add_order(create_test_order(Side::Buy, 8900, 100));
+    order_book.add_order(create_test_order(Side::Sell, 10000, 100));
+
+    let usage = order_book.memory_usage();
+    assert_eq!((usage.bid_levels, usage.ask_levels, usage.orders), (2, 1, 3));
+    assert!(usage.bytes >= 3 * (LEVEL_BYTES + ORDER_NODE_BYTES));
+
+    order_book.cancel_order(&buy_id);
+    let after = order_book.memory_usage();
+    assert_eq!((after.bid_levels, after.orders), (1, 2));
+    assert!(after.bytes < usage.bytes);
+  }
+
+  #[test]
+  fn test_compact_levels_skips_near_touch() {
+    let mut order_book = OrderBook::new("BTC-KRW".to_string());
+    // 최우선 레벨과 먼 레벨에 주문이 몰렸다가 한 건만 남음
+    for price in [9000, 8000] {
+      let ids: Vec<String> = (0..500)
+        .map(|_| {
+          let order = create_test_order(Side::Buy, price, 1);
+          let id = order.id.clone();
+          order_book.add_order(order);
+          id
+        })
+        .collect();
+      for id in &ids[1..] {
+        order_book.cancel_order(id);
+      }
+    }
+    let before = order_book.memory_usage();
+
+    let policy = LevelLifecyclePolicy { near_touch_levels: 1, min_slack: 16, slack_ratio: 4 };
+    let result = order_book.compact_levels(&policy);
+    assert_eq!(result.levels, 1);
+    assert!(result.reclaimed_bytes > 0);
+    assert!(order_book.get_bid_price_level(9000).unwrap().order_map.capacity() >= 500);
+    assert!(order_book.get_bid_price_level(8000).unwrap().order_map.capacity() < 16);
+    assert!(order_book.memory_usage().bytes < before.bytes);
+    assert_eq!(order_book.order_count(), 2);
+
+    // 이미 정리한 레벨은 다시 정리하지 않음
+    assert_eq!(order_book.compact_levels(&policy), LevelCompaction::default());
+  }
+
   #[test]
   fn test_same_price_level() {
     let mut order_book = OrderBook::new("BTC-KRW".to_string());
@@ -0,0 +1,269 @@
+//! WebAssembly 주문장 (`wasm` 기능, 브라우저 모의 체결/교육용 데모)
+//!
+//! 서버와 같은 주문장([`OrderBook`])과 연속 매칭([`matching::match_order`])을 JS에서 쓰도록
+//! `wasm-bindgen`으로 내보냅니다. 주문과 결과는 일반 JS 객체(camelCase)로 주고받습니다.
+//!
+//! ```js
+//! import init, { OrderBook } from "./pkg/xtrader_engine.js";
+//! await init();
+//! const book = new OrderBook("BTC-KRW");
+//! book.submit({ id: "s1", side: "sell", type: "limit", price: 1000, quantity: 5 });
+//! const result = book.submit({ id: "b1", side: "buy", type: "market", quantity: 3 });
+//! // { status: "FILLED", filledQuantity: 3, remainingQuantity: 0, trades: [{ makerOrderId: "s1", ... }] }
+//! book.snapshot(10); // { symbol: "BTC-KRW", bids: [], asks: [[1000, 2]] }
+//! ```
+//!
+//! 빌드: `wasm-pack build crates/xtrader-engine --target web -- --features wasm`
+//!
+//! `wasm32-unknown-unknown`에는 시스템 시각이 없으므로 주문 시각은 JS가 `timestamp`로 넘깁니다.
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::matching::{self, MatchOutcome};
+use crate::model::{MarketProtection, Order, OrderType, Side};
+use crate::order_ack::OrderAckStatus;
+use crate::order_book::OrderBook;
+
+/// JS 주문 입력
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OrderInput {
+  id: String,
+  /// "buy" / "sell"
+  side: String,
+  /// "limit" / "market" (기본값 limit)
+  #[serde(rename = "type", default)]
+  order_type: Option<String>,
+  /// 지정가 (시장가 주문은 생략)
+  #[serde(default)]
+  price: u64,
+  quantity: u64,
+  #[serde(default)]
+  client_id: Option<String>,
+  /// 주문 시각 (Unix 초, 생략하면 0)
+  #[serde(default)]
+  timestamp: u64,
+}
+
+/// 시장가 보호 설정 입력
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProtectionInput {
+  #[serde(default)]
+  max_slippage_pct: Option<f64>,
+  #[serde(default)]
+  max_levels: Option<usize>,
+}
+
+/// 체결 한 건
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TradeOutput {
+  maker_order_id: String,
+  price: u64,
+  quantity: u64,
+  /// 체결 후 메이커 주문 남은 수량
+  maker_remaining: u64,
+  /// 체결 후 테이커 주문 남은 수량
+  taker_remaining: u64,
+}
+
+/// 주문 처리 결과
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SubmitOutput {
+  /// "ACCEPTED" / "PARTIALLY_FILLED" / "FILLED" / "CANCELED" (REST 주문 응답과 같음)
+  status: &'static str,
+  filled_quantity: u64,
+  /// 주문장에 남은 수량 (시장가 주문은 0)
+  remaining_quantity: u64,
+  trades: Vec<TradeOutput>,
+}
+
+/// 브라우저용 주문장 (JS `OrderBook`)
+#[wasm_bindgen(js_name = OrderBook)]
+pub struct WasmOrderBook {
+  book: OrderBook,
+  protection: MarketProtection,
+}
+
+#[wasm_bindgen(js_class = OrderBook)]
+impl WasmOrderBook {
+  /// 빈 주문장 생성
+  #[wasm_bindgen(constructor)]
+  pub fn new(symbol: String) -> Self {
+    Self { book: OrderBook::new(symbol), protection: MarketProtection::default() }
+  }
+
+  /// 심볼
+  #[wasm_bindgen(getter)]
+  pub fn symbol(&self) -> String {
+    self.book.symbol().to_string()
+  }
+
+  /// 주문장에 있는 주문 수
+  #[wasm_bindgen(getter, js_name = orderCount)]
+  pub fn order_count(&self) -> usize {
+    self.book.order_count()
+  }
+
+  /// 시장가 주문 보호 기본값 (`{ maxSlippagePct?, maxLevels? }`)
+  #[wasm_bindgen(js_name = setMarketProtection)]
+  pub fn set_market_protection(&mut self, protection: JsValue) -> Result<(), JsError> {
+    let input: ProtectionInput = serde_wasm_bindgen::from_value(protection)?;
+    self.protection = MarketProtection { max_slippage_pct: input.max_slippage_pct, max_levels: input.max_levels };
+    Ok(())
+  }
+
+  /// 주문 제출 (매칭 후 남은 지정가 수량은 주문장에 등록)
+  pub fn submit(&mut self, order: JsValue) -> Result<JsValue, JsError> {
+    let input: OrderInput = serde_wasm_bindgen::from_value(order)?;
+    let output = self.submit_input(input).map_err(|e| JsError::new(&e))?;
+    Ok(serde_wasm_bindgen::to_value(&output)?)
+  }
+
+  /// 주문 취소 (주문장에 있었으면 true)
+  pub fn cancel(&mut self, order_id: &str) -> bool {
+    self.book.cancel_order(order_id).is_some()
+  }
+
+  /// 호가창 스냅샷 (`{ symbol, bids: [[가격, 수량]], asks }`)
+  pub fn snapshot(&self, depth: usize) -> Result<JsValue, JsError> {
+    Ok(serde_wasm_bindgen::to_value(&self.book.get_order_book_snapshot(depth))?)
+  }
+
+  /// 최우선 매수 호가 (`[가격, 수량]`, 없으면 undefined)
+  #[wasm_bindgen(js_name = bestBid)]
+  pub fn best_bid(&self) -> Option<Vec<u64>> {
+    self.book.get_best_bid().map(|(price, level)| vec![price, level.total_volume])
+  }
+
+  /// 최우선 매도 호가 (`[가격, 수량]`, 없으면 undefined)
+  #[wasm_bindgen(js_name = bestAsk)]
+  pub fn best_ask(&self) -> Option<Vec<u64>> {
+    self.book.get_best_ask().map(|(price, level)| vec![price, level.total_volume])
+  }
+}
+
+impl WasmOrderBook {
+  /// 주문 검증, 매칭, 잔량 처리 (서버 `MatchingEngine`의 주문 처리와 같은 상태 규칙)
+  fn submit_input(&mut self, input: OrderInput) -> Result<SubmitOutput, String> {
+    let mut order = into_order(input, self.book.symbol())?;
+    if self.book.orders.contains_key(&order.id) {
+      return Err(format!("이미 주문장에 있는 주문 ID: {}", order.id));
+    }
+
+    let outcome = matching::match_order(&mut self.book, &mut order, &self.protection);
+    let filled_quantity = outcome.filled_quantity();
+    let (status, remaining_quantity) = match order.order_type {
+      OrderType::Market if order.is_filled() => (OrderAckStatus::Filled, 0),
+      OrderType::Market => (OrderAckStatus::Canceled, 0),
+      OrderType::Limit if order.is_filled() => (OrderAckStatus::Filled, 0),
+      OrderType::Limit if filled_quantity > 0 => (OrderAckStatus::PartiallyFilled, order.remaining_quantity),
+      OrderType::Limit => (OrderAckStatus::Accepted, order.remaining_quantity),
+    };
+    if order.order_type == OrderType::Limit && !order.is_filled() {
+      self.book.add_order(order);
+    }
+
+    Ok(SubmitOutput { status: status.as_str(), filled_quantity, remaining_quantity, trades: trades(outcome) })
+  }
+}
+
+fn trades(outcome: MatchOutcome) -> Vec<TradeOutput> {
+  outcome
+    .fills
+    .into_iter()
+    .map(|fill| TradeOutput {
+      maker_remaining: fill.maker.remaining_quantity,
+      maker_order_id: fill.maker.id,
+      price: fill.price,
+      quantity: fill.quantity,
+      taker_remaining: fill.taker_remaining,
+    })
+    .collect()
+}
+
+/// JS 입력을 주문으로 변환 (`Order::new`는 시스템 시각을 읽으므로 쓰지 않음)
+fn into_order(input: OrderInput, symbol: &str) -> Result<Order, String> {
+  let side = match input.side.to_ascii_lowercase().as_str() {
+    "buy" => Side::Buy,
+    "sell" => Side::Sell,
+    other => return Err(format!("side는 buy/sell 중 하나여야 합니다: {}", other)),
+  };
+  let order_type = match input.order_type.as_deref().map(str::to_ascii_lowercase).as_deref() {
+    None | Some("limit") => OrderType::Limit,
+    Some("market") => OrderType::Market,
+    Some(other) => return Err(format!("type은 limit/market 중 하나여야 합니다: {}", other)),
+  };
+  if input.quantity == 0 {
+    return Err("quantity는 0보다 커야 합니다".to_string());
+  }
+  if order_type == OrderType::Limit && input.price == 0 {
+    return Err("지정가 주문은 price가 0보다 커야 합니다".to_string());
+  }
+
+  Ok(Order {
+    id: input.id,
+    symbol: symbol.to_string(),
+    side,
+    order_type,
+    price: input.price,
+    quantity: input.quantity,
+    remaining_quantity: input.quantity,
+    client_id: input.client_id.unwrap_or_else(|| "browser".to_string()),
+    timestamp: input.timestamp,
+    is_cancel: false,
+    target_order_id: None,
+    protection: None,
+    expire_time: None,
+    sequence: 0,
+    tag: None,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn input(id: &str, side: &str, order_type: &str, price: u64, quantity: u64) -> OrderInput {
+    OrderInput {
+      id: id.to_string(),
+      side: side.to_string(),
+      order_type: Some(order_type.to_string()),
+      price,
+      quantity,
+      client_id: None,
+      timestamp: 0,
+    }
+  }
+
+  #[test]
+  fn test_submit_rests_matches_and_cancels() {
+    let mut book = WasmOrderBook::new("BTC-KRW".to_string());
+    let rested = book.submit_input(input("s1", "sell", "limit", 1_000, 5)).unwrap();
+    assert_eq!((rested.status, rested.remaining_quantity), ("ACCEPTED", 5));
+
+    let partial = book.submit_input(input("b1", "BUY", "limit", 1_000, 8)).unwrap();
+    assert_eq!((partial.status, partial.filled_quantity, partial.remaining_quantity), ("PARTIALLY_FILLED", 5, 3));
+    assert_eq!(
+      partial.trades,
+      vec![TradeOutput { maker_order_id: "s1".to_string(), price: 1_000, quantity: 5, maker_remaining: 0, taker_remaining: 3 }]
+    );
+    assert_eq!((book.best_bid(), book.best_ask()), (Some(vec![1_000, 3]), None));
+
+    // 시장가 잔량은 주문장에 남지 않고 취소
+    let market = book.submit_input(input("s2", "sell", "market", 0, 4)).unwrap();
+    assert_eq!((market.status, market.filled_quantity, market.remaining_quantity), ("CANCELED", 3, 0));
+    assert_eq!(book.order_count(), 0);
+
+    book.submit_input(input("s3", "sell", "limit", 1_100, 1)).unwrap();
+    assert!(book.submit_input(input("s3", "sell", "limit", 1_100, 1)).is_err());
+    assert!(book.cancel("s3") && !book.cancel("s3"));
+
+    assert!(book.submit_input(input("x", "hold", "limit", 1, 1)).is_err());
+    assert!(book.submit_input(input("x", "buy", "limit", 0, 1)).is_err());
+    assert!(book.submit_input(input("x", "buy", "stop", 1, 1)).is_err());
+  }
+}
@@ -4,7 +4,7 @@
 //! REST API 엔드포인트를 구현합니다.
 
 use axum::{
-    extract::{Path, Query},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
     routing::{get, post},
@@ -14,9 +14,9 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
-use tokio::sync::RwLock;
 use log::{info, error, debug};
-use crate::mdp::consumer::{MDPConsumer, CandlestickData, MarketStatistics, MDPConsumerStats};
+use crate::cache::{CacheConfig, MDPCacheManager};
+use crate::consumer::{MDPConsumer, CandlestickData, MarketStatistics, MDPConsumerStats};
 
 /// API 응답 구조
 #[derive(Debug, Serialize, Deserialize)]
@@ -55,24 +55,32 @@ pub struct MarketStatisticsResponse {
     pub statistics: HashMap<String, MarketStatistics>,
 }
 
+/// MDP API 핸들러 상태
+#[derive(Clone)]
+pub struct MDPApiState {
+    pub consumer: Arc<MDPConsumer>,
+    /// 봉차트/통계 조회 캐시 (만료 시 갱신은 키당 한 번)
+    pub cache: Arc<MDPCacheManager>,
+}
+
 /// MDP API 서버
 pub struct MDPApiServer {
-    consumer: Arc<MDPConsumer>,
     router: Router,
 }
 
 impl MDPApiServer {
     /// 새 MDP API 서버 생성
-    pub fn new(consumer: Arc<MDPConsumer>) -> Self {
+    pub fn new(consumer: Arc<MDPConsumer>, cache: Arc<MDPCacheManager>) -> Self {
         let router = Router::new()
             .route("/health", get(Self::health_check))
             .route("/stats", get(Self::get_consumer_stats))
             .route("/candlestick/:symbol/:timeframe", get(Self::get_candlestick))
             .route("/candlesticks", post(Self::get_candlesticks))
             .route("/statistics", get(Self::get_market_statistics))
-            .route("/statistics/:symbol", get(Self::get_symbol_statistics));
+            .route("/statistics/:symbol", get(Self::get_symbol_statistics))
+            .with_state(MDPApiState { consumer, cache });
 
-        Self { consumer, router }
+        Self { router }
     }
 
     /// 라우터 반환
@@ -97,9 +105,9 @@ impl MDPApiServer {
 
     /// Consumer 통계 조회
     async fn get_consumer_stats(
-        consumer: Arc<MDPConsumer>,
+        State(state): State<MDPApiState>,
     ) -> Result<Json<ApiResponse<MDPConsumerStats>>, StatusCode> {
-        let stats = consumer.get_consumer_stats().await;
+        let stats = state.consumer.get_consumer_stats().await;
         
         let response = ApiResponse {
             success: true,
@@ -117,11 +125,11 @@ impl MDPApiServer {
     /// 특정 심볼의 특정 타임프레임 봉차트 조회
     async fn get_candlestick(
         Path((symbol, timeframe)): Path<(String, String)>,
-        consumer: Arc<MDPConsumer>,
+        State(state): State<MDPApiState>,
     ) -> Result<Json<ApiResponse<CandlestickResponse>>, StatusCode> {
         debug!("봉차트 조회 요청: {} {}", symbol, timeframe);
         
-        match consumer.get_candlestick_data(&symbol, &timeframe).await {
+        match Self::cached_candlestick(&state, &symbol, &timeframe).await {
             Some(candlestick) => {
                 let response = CandlestickResponse {
                     symbol: symbol.clone(),
@@ -142,7 +150,7 @@ impl MDPApiServer {
                 Ok(Json(api_response))
             }
             None => {
-                let response: ApiResponse<CandlestickResponse> = ApiResponse {
+                let _response: ApiResponse<CandlestickResponse> = ApiResponse {
                     success: false,
                     data: None,
                     error: Some(format!("봉차트 데이터를 찾을 수 없습니다: {} {}", symbol, timeframe)),
@@ -160,14 +168,14 @@ impl MDPApiServer {
     /// 여러 봉차트 조회
     async fn get_candlesticks(
         Query(request): Query<CandlestickRequest>,
-        consumer: Arc<MDPConsumer>,
+        State(state): State<MDPApiState>,
     ) -> Result<Json<ApiResponse<Vec<CandlestickResponse>>>, StatusCode> {
         debug!("봉차트 조회 요청: {:?}", request);
         
         let mut responses = Vec::new();
         
         // 특정 심볼과 타임프레임 조회
-        if let Some(candlestick) = consumer.get_candlestick_data(&request.symbol, &request.timeframe).await {
+        if let Some(candlestick) = Self::cached_candlestick(&state, &request.symbol, &request.timeframe).await {
             responses.push(CandlestickResponse {
                 symbol: request.symbol.clone(),
                 timeframe: request.timeframe.clone(),
@@ -190,11 +198,22 @@ impl MDPApiServer {
 
     /// 모든 시장 통계 조회
     async fn get_market_statistics(
-        consumer: Arc<MDPConsumer>,
+        State(state): State<MDPApiState>,
     ) -> Result<Json<ApiResponse<MarketStatisticsResponse>>, StatusCode> {
         debug!("시장 통계 조회 요청");
         
-        let statistics = consumer.get_all_market_statistics().await;
+        let consumer = state.consumer.clone();
+        let statistics = match state
+            .cache
+            .get_all_statistics_or_load(move || async move { Ok(Some(consumer.get_all_market_statistics().await)) })
+            .await
+        {
+            Ok(statistics) => statistics.unwrap_or_default(),
+            Err(e) => {
+                error!("시장 통계 캐시 조회 실패, 원본 조회: {}", e);
+                state.consumer.get_all_market_statistics().await
+            }
+        };
         
         let response = MarketStatisticsResponse { statistics };
         
@@ -214,11 +233,27 @@ impl MDPApiServer {
     /// 특정 심볼의 시장 통계 조회
     async fn get_symbol_statistics(
         Path(symbol): Path<String>,
-        consumer: Arc<MDPConsumer>,
+        State(state): State<MDPApiState>,
     ) -> Result<Json<ApiResponse<MarketStatistics>>, StatusCode> {
         debug!("심볼 통계 조회 요청: {}", symbol);
         
-        match consumer.get_market_statistics(&symbol).await {
+        let consumer = state.consumer.clone();
+        let owned_symbol = symbol.clone();
+        let cached = state
+            .cache
+            .get_statistics_or_load(&symbol, move || async move {
+                Ok(consumer.get_market_statistics(&owned_symbol).await)
+            })
+            .await;
+        let statistics = match cached {
+            Ok(statistics) => statistics,
+            Err(e) => {
+                error!("시장 통계 캐시 조회 실패, 원본 조회: {}", e);
+                state.consumer.get_market_statistics(&symbol).await
+            }
+        };
+
+        match statistics {
             Some(statistics) => {
                 let api_response = ApiResponse {
                     success: true,
@@ -233,7 +268,7 @@ impl MDPApiServer {
                 Ok(Json(api_response))
             }
             None => {
-                let response: ApiResponse<MarketStatistics> = ApiResponse {
+                let _response: ApiResponse<MarketStatistics> = ApiResponse {
                     success: false,
                     data: None,
                     error: Some(format!("시장 통계를 찾을 수 없습니다: {}", symbol)),
@@ -247,11 +282,32 @@ impl MDPApiServer {
             }
         }
     }
+
+    /// 캐시를 거친 봉차트 조회 (캐시 오류 시 Consumer 직접 조회)
+    async fn cached_candlestick(state: &MDPApiState, symbol: &str, timeframe: &str) -> Option<CandlestickData> {
+        let consumer = state.consumer.clone();
+        let (owned_symbol, owned_timeframe) = (symbol.to_string(), timeframe.to_string());
+        let cached = state
+            .cache
+            .get_candlestick_or_load(symbol, timeframe, move || async move {
+                Ok(consumer.get_candlestick_data(&owned_symbol, &owned_timeframe).await)
+            })
+            .await;
+
+        match cached {
+            Ok(candlestick) => candlestick,
+            Err(e) => {
+                error!("봉차트 캐시 조회 실패, 원본 조회: {}", e);
+                state.consumer.get_candlestick_data(symbol, timeframe).await
+            }
+        }
+    }
 }
 
 /// MDP API 서버 빌더
 pub struct MDPApiServerBuilder {
     consumer: Option<Arc<MDPConsumer>>,
+    cache: Option<Arc<MDPCacheManager>>,
     port: u16,
     host: String,
 }
@@ -261,6 +317,7 @@ impl MDPApiServerBuilder {
     pub fn new() -> Self {
         Self {
             consumer: None,
+            cache: None,
             port: 3001,
             host: "0.0.0.0".to_string(),
         }
@@ -272,6 +329,12 @@ impl MDPApiServerBuilder {
         self
     }
 
+    /// 캐시 관리자 설정 (없으면 기본 설정으로 생성)
+    pub fn cache_manager(mut self, cache: Arc<MDPCacheManager>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// 포트 설정
     pub fn port(mut self, port: u16) -> Self {
         self.port = port;
@@ -287,8 +350,11 @@ impl MDPApiServerBuilder {
     /// API 서버 빌드
     pub fn build(self) -> Result<MDPApiServer, String> {
         let consumer = self.consumer.ok_or("Consumer가 설정되지 않았습니다")?;
+        let cache = self
+            .cache
+            .unwrap_or_else(|| Arc::new(MDPCacheManager::new(CacheConfig::default())));
         
-        Ok(MDPApiServer::new(consumer))
+        Ok(MDPApiServer::new(consumer, cache))
     }
 
     /// API 서버 실행
@@ -329,6 +395,11 @@ impl MDPApiClient {
         Self { base_url }
     }
 
+    /// 요청 대상 서버 주소
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
     /// 헬스체크 (Mock)
     pub async fn health_check(&self) -> Result<ApiResponse<String>, String> {
         let response = ApiResponse {
@@ -449,7 +520,7 @@ impl MDPApiClient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::mdp::consumer::MDPConsumerConfig;
+    use crate::consumer::MDPConsumerConfig;
 
     #[tokio::test]
     async fn test_api_response_creation() {
@@ -493,6 +564,6 @@ mod tests {
     #[tokio::test]
     async fn test_mdp_api_client_creation() {
         let client = MDPApiClient::new("http://localhost:3001".to_string());
-        assert_eq!(client.base_url, "http://localhost:3001");
+        assert_eq!(client.base_url(), "http://localhost:3001");
     }
 }
\ No newline at end of file
@@ -0,0 +1,876 @@
+//! MDP 캐싱 시스템
+//!
+//! 이 모듈은 봉차트와 시장 통계 데이터를 Redis에 캐싱하여
+//! 고성능 데이터 조회를 제공합니다.
+//!
+//! 조회는 메모리 → Redis 순서의 2단계이며, 같은 키의 메모리 미스가 동시에 몰리면
+//! Redis 조회는 한 번만 하고 결과를 나눠 씁니다(single-flight). 만료는 Redis TTL이 처리하고,
+//! 메모리 항목은 값에 기록된 `cached_at + ttl`까지만 유효합니다.
+//!
+//! `get_*_or_load` 조회는 원본(Consumer 등) 로더를 받아 캐시 스탬피드를 막습니다. 만료 뒤
+//! `stale_while_revalidate_seconds` 동안은 이전 값을 바로 돌려주고 백그라운드에서 한 번만 갱신하며,
+//! 그보다 오래됐거나 없으면 같은 키의 요청들이 로더 한 번의 결과를 함께 기다립니다.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex as StdMutex};
+use tokio::sync::RwLock;
+use std::time::{SystemTime, UNIX_EPOCH, Duration};
+use futures::future::{BoxFuture, FutureExt, Shared};
+use log::{info, error, debug};
+use tokio::time::interval;
+use crate::cache_backend::{CacheBackend, CacheEntry, MockCacheBackend, RedisCacheBackend};
+use crate::consumer::{CandlestickData, MarketStatistics};
+
+/// 캐시 설정
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    pub redis_url: String,
+    pub candlestick_ttl_seconds: u64,
+    pub statistics_ttl_seconds: u64,
+    pub update_interval_ms: u64,
+    pub max_cache_size: usize,
+    /// 만료 뒤 이전 값을 내주며 갱신하는 시간 (초)
+    pub stale_while_revalidate_seconds: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            redis_url: "redis://localhost:6379".to_string(),
+            candlestick_ttl_seconds: 300,    // 5분
+            statistics_ttl_seconds: 60,       // 1분
+            update_interval_ms: 1000,         // 1초마다 업데이트
+            max_cache_size: 10000,
+            stale_while_revalidate_seconds: 30,
+        }
+    }
+}
+
+/// 캐시 키 타입
+#[derive(Debug, Clone)]
+pub enum CacheKey {
+    Candlestick(String, String), // symbol, timeframe
+    Statistics(String),          // symbol
+    AllStatistics,
+    AllCandlesticks(String),     // symbol
+}
+
+/// 캐시 키 문자열 (`to_string()`으로 변환)
+impl std::fmt::Display for CacheKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CacheKey::Candlestick(symbol, timeframe) => {
+                write!(f, "candlestick:{}:{}", symbol, timeframe)
+            }
+            CacheKey::Statistics(symbol) => {
+                write!(f, "statistics:{}", symbol)
+            }
+            CacheKey::AllStatistics => {
+                write!(f, "statistics:all")
+            }
+            CacheKey::AllCandlesticks(symbol) => {
+                write!(f, "candlesticks:{}", symbol)
+            }
+        }
+    }
+}
+
+/// 캐시된 봉차트 데이터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedCandlestickData {
+    pub data: CandlestickData,
+    pub cached_at: u64,
+    pub ttl: u64,
+}
+
+/// 캐시된 시장 통계 데이터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedMarketStatistics {
+    pub data: MarketStatistics,
+    pub cached_at: u64,
+    pub ttl: u64,
+}
+
+/// 캐시된 전체 통계 데이터
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedAllStatistics {
+    pub data: HashMap<String, MarketStatistics>,
+    pub cached_at: u64,
+    pub ttl: u64,
+}
+
+/// 유효 기간이 기록된 캐시 값
+trait CachedValue: Serialize + DeserializeOwned + Send + 'static {
+    type Data: Send + 'static;
+
+    fn wrap(data: Self::Data, cached_at: u64, ttl: u64) -> Self;
+    /// 이 시각(초)까지 최신 값
+    fn fresh_until(&self) -> u64;
+    fn into_data(self) -> Self::Data;
+}
+
+macro_rules! impl_cached_value {
+    ($cached:ty, $data:ty) => {
+        impl CachedValue for $cached {
+            type Data = $data;
+
+            fn wrap(data: $data, cached_at: u64, ttl: u64) -> Self {
+                Self { data, cached_at, ttl }
+            }
+
+            fn fresh_until(&self) -> u64 {
+                self.cached_at + self.ttl
+            }
+
+            fn into_data(self) -> $data {
+                self.data
+            }
+        }
+    };
+}
+
+impl_cached_value!(CachedCandlestickData, CandlestickData);
+impl_cached_value!(CachedMarketStatistics, MarketStatistics);
+impl_cached_value!(CachedAllStatistics, HashMap<String, MarketStatistics>);
+
+/// 메모리 캐시 항목
+struct MemoryEntry {
+    value: Vec<u8>,
+    /// 이전 값으로도 쓸 수 없게 되는 시각 (초, 최신 여부는 값의 `cached_at + ttl`로 판단)
+    expires_at: u64,
+}
+
+/// 진행 중인 원격 조회/갱신 (같은 키의 동시 요청이 공유)
+type RemoteLoad = Shared<BoxFuture<'static, Result<Option<Vec<u8>>, String>>>;
+
+type MemoryCache = Arc<RwLock<HashMap<String, MemoryEntry>>>;
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+}
+
+/// 메모리/원격 저장소 (백그라운드 갱신 태스크와 공유)
+#[derive(Clone)]
+struct CacheTiers {
+    /// 원격 저장소 (Redis, 테스트에서는 Mock)
+    backend: Arc<dyn CacheBackend>,
+    /// 메모리 캐시 (1단계)
+    memory_cache: MemoryCache,
+    cache_stats: Arc<RwLock<CacheStats>>,
+    stale_while_revalidate_seconds: u64,
+}
+
+impl CacheTiers {
+    /// Redis(파이프라인)와 메모리 캐시에 저장 (`ttl`은 최신 값 기간, 보관은 이전 값 기간까지)
+    async fn store(&self, entries: Vec<CacheEntry>) -> Result<(), String> {
+        let count = entries.len() as u64;
+        let stale = self.stale_while_revalidate_seconds;
+        let remote_entries = entries
+            .iter()
+            .map(|(key, value, ttl)| (key.clone(), value.clone(), ttl + stale))
+            .collect();
+        self.backend.set_many(remote_entries).await?;
+
+        let now = now_secs();
+        let mut memory_cache = self.memory_cache.write().await;
+        for (key, value, ttl) in entries {
+            memory_cache.insert(key, MemoryEntry { value, expires_at: now + ttl + stale });
+        }
+        drop(memory_cache);
+
+        self.cache_stats.write().await.sets += count;
+        Ok(())
+    }
+}
+
+/// MDP 캐시 관리자
+pub struct MDPCacheManager {
+    tiers: CacheTiers,
+    /// 설정
+    config: CacheConfig,
+    /// 진행 중인 원격 조회
+    remote_loads: StdMutex<HashMap<String, RemoteLoad>>,
+    /// 진행 중인 원본 갱신
+    refreshes: Arc<StdMutex<HashMap<String, RemoteLoad>>>,
+}
+
+/// 캐시 통계
+#[derive(Debug, Clone)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// 만료 뒤 이전 값으로 응답한 수
+    pub stale_hits: u64,
+    pub sets: u64,
+    pub deletes: u64,
+    /// 원격 저장소 조회 수 (single-flight로 합쳐진 요청은 한 번)
+    pub remote_loads: u64,
+    /// 원본 로더 실행 수 (합쳐진 요청은 한 번)
+    pub refreshes: u64,
+    pub memory_cache_size: usize,
+    pub redis_cache_size: usize,
+}
+
+impl MDPCacheManager {
+    /// 새 캐시 관리자 생성
+    ///
+    /// 테스트 빌드와 `mdp-cache-mock` 기능에서는 메모리 Mock을, 그 외에는 `redis_url`의 Redis를 씁니다.
+    /// Redis 주소가 잘못되면 Mock으로 대체합니다.
+    pub fn new(config: CacheConfig) -> Self {
+        let backend: Arc<dyn CacheBackend> = if cfg!(any(test, feature = "mdp-cache-mock")) {
+            Arc::new(MockCacheBackend::new())
+        } else {
+            match RedisCacheBackend::new(&config.redis_url) {
+                Ok(backend) => Arc::new(backend),
+                Err(e) => {
+                    error!("MDP 캐시 Redis 설정 실패 (메모리 Mock으로 대체): {}", e);
+                    Arc::new(MockCacheBackend::new())
+                }
+            }
+        };
+        Self::with_backend(config, backend)
+    }
+
+    /// 원격 저장소를 지정해 생성
+    pub fn with_backend(config: CacheConfig, backend: Arc<dyn CacheBackend>) -> Self {
+        Self {
+            tiers: CacheTiers {
+                backend,
+                memory_cache: Arc::new(RwLock::new(HashMap::new())),
+                cache_stats: Arc::new(RwLock::new(CacheStats {
+                    hits: 0,
+                    misses: 0,
+                    stale_hits: 0,
+                    sets: 0,
+                    deletes: 0,
+                    remote_loads: 0,
+                    refreshes: 0,
+                    memory_cache_size: 0,
+                    redis_cache_size: 0,
+                })),
+                stale_while_revalidate_seconds: config.stale_while_revalidate_seconds,
+            },
+            config,
+            remote_loads: StdMutex::new(HashMap::new()),
+            refreshes: Arc::new(StdMutex::new(HashMap::new())),
+        }
+    }
+
+    /// 캐시 관리자 시작
+    pub async fn start(&self) {
+        info!("MDP 캐시 관리자 시작");
+        
+        let tiers = self.tiers.clone();
+        let config = self.config.clone();
+        
+        // 메모리 캐시 정리 태스크 (원격 만료는 Redis TTL이 처리)
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_millis(config.update_interval_ms));
+            
+            loop {
+                interval.tick().await;
+                
+                Self::cleanup_expired_cache(&tiers.memory_cache).await;
+                Self::limit_cache_size(&tiers.memory_cache, config.max_cache_size).await;
+
+                match tiers.backend.len().await {
+                    Ok(size) => tiers.cache_stats.write().await.redis_cache_size = size,
+                    Err(e) => error!("Redis 캐시 크기 조회 실패: {}", e),
+                }
+            }
+        });
+    }
+
+    /// 봉차트 데이터 캐시
+    pub async fn cache_candlestick(&self, symbol: &str, timeframe: &str, data: &CandlestickData) -> Result<(), String> {
+        let key = CacheKey::Candlestick(symbol.to_string(), timeframe.to_string());
+        let ttl = self.config.candlestick_ttl_seconds;
+        let cached_data = CachedCandlestickData::wrap(data.clone(), now_secs(), ttl);
+        
+        self.tiers.store(vec![(key.to_string(), encode(&cached_data)?, ttl)]).await?;
+        
+        debug!("봉차트 캐시 저장: {} {}", symbol, timeframe);
+        Ok(())
+    }
+
+    /// 시장 통계 캐시
+    pub async fn cache_statistics(&self, symbol: &str, data: &MarketStatistics) -> Result<(), String> {
+        let key = CacheKey::Statistics(symbol.to_string());
+        let ttl = self.config.statistics_ttl_seconds;
+        let cached_data = CachedMarketStatistics::wrap(data.clone(), now_secs(), ttl);
+        
+        self.tiers.store(vec![(key.to_string(), encode(&cached_data)?, ttl)]).await?;
+        
+        debug!("시장 통계 캐시 저장: {}", symbol);
+        Ok(())
+    }
+
+    /// 전체 시장 통계 캐시 (심볼별 통계도 같은 파이프라인으로 저장)
+    pub async fn cache_all_statistics(&self, data: &HashMap<String, MarketStatistics>) -> Result<(), String> {
+        let cached_at = now_secs();
+        let ttl = self.config.statistics_ttl_seconds;
+
+        let mut entries = Vec::with_capacity(data.len() + 1);
+        for (symbol, statistics) in data {
+            let cached_data = CachedMarketStatistics::wrap(statistics.clone(), cached_at, ttl);
+            entries.push((CacheKey::Statistics(symbol.clone()).to_string(), encode(&cached_data)?, ttl));
+        }
+        let cached_data = CachedAllStatistics::wrap(data.clone(), cached_at, ttl);
+        entries.push((CacheKey::AllStatistics.to_string(), encode(&cached_data)?, ttl));
+
+        self.tiers.store(entries).await?;
+        
+        debug!("전체 시장 통계 캐시 저장: {}개 심볼", data.len());
+        Ok(())
+    }
+
+    /// 봉차트 데이터 조회
+    pub async fn get_candlestick(&self, symbol: &str, timeframe: &str) -> Result<Option<CandlestickData>, String> {
+        let key = CacheKey::Candlestick(symbol.to_string(), timeframe.to_string());
+        self.load::<CachedCandlestickData>(&key.to_string()).await
+    }
+
+    /// 시장 통계 조회
+    pub async fn get_statistics(&self, symbol: &str) -> Result<Option<MarketStatistics>, String> {
+        let key = CacheKey::Statistics(symbol.to_string());
+        self.load::<CachedMarketStatistics>(&key.to_string()).await
+    }
+
+    /// 전체 시장 통계 조회
+    pub async fn get_all_statistics(&self) -> Result<Option<HashMap<String, MarketStatistics>>, String> {
+        self.load::<CachedAllStatistics>(&CacheKey::AllStatistics.to_string()).await
+    }
+
+    /// 봉차트 조회 (없거나 만료되면 `loader`로 갱신, 동시 요청은 로더 한 번을 공유)
+    pub async fn get_candlestick_or_load<F, Fut>(
+        &self,
+        symbol: &str,
+        timeframe: &str,
+        loader: F,
+    ) -> Result<Option<CandlestickData>, String>
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Option<CandlestickData>, String>> + Send + 'static,
+    {
+        let key = CacheKey::Candlestick(symbol.to_string(), timeframe.to_string());
+        self.load_or_refresh::<CachedCandlestickData, _, _>(key.to_string(), self.config.candlestick_ttl_seconds, loader)
+            .await
+    }
+
+    /// 시장 통계 조회 (없거나 만료되면 `loader`로 갱신)
+    pub async fn get_statistics_or_load<F, Fut>(&self, symbol: &str, loader: F) -> Result<Option<MarketStatistics>, String>
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Option<MarketStatistics>, String>> + Send + 'static,
+    {
+        let key = CacheKey::Statistics(symbol.to_string());
+        self.load_or_refresh::<CachedMarketStatistics, _, _>(key.to_string(), self.config.statistics_ttl_seconds, loader)
+            .await
+    }
+
+    /// 전체 시장 통계 조회 (없거나 만료되면 `loader`로 갱신)
+    pub async fn get_all_statistics_or_load<F, Fut>(
+        &self,
+        loader: F,
+    ) -> Result<Option<HashMap<String, MarketStatistics>>, String>
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Option<HashMap<String, MarketStatistics>>, String>> + Send + 'static,
+    {
+        let key = CacheKey::AllStatistics.to_string();
+        self.load_or_refresh::<CachedAllStatistics, _, _>(key, self.config.statistics_ttl_seconds, loader)
+            .await
+    }
+
+    /// 캐시에서 데이터 제거
+    pub async fn invalidate(&self, key: &CacheKey) -> Result<(), String> {
+        let key_str = key.to_string();
+        
+        // Redis에서 제거
+        self.tiers.backend.del(&key_str).await?;
+        
+        // 메모리 캐시에서도 제거
+        self.tiers.memory_cache.write().await.remove(&key_str);
+        
+        // 통계 업데이트
+        self.update_cache_stats(|stats| stats.deletes += 1).await;
+        
+        debug!("캐시 무효화: {}", key_str);
+        Ok(())
+    }
+
+    /// 캐시 통계 조회
+    pub async fn get_cache_stats(&self) -> CacheStats {
+        let stats = self.tiers.cache_stats.read().await;
+        let memory_cache_size = self.tiers.memory_cache.read().await.len();
+        
+        CacheStats {
+            memory_cache_size,
+            ..stats.clone()
+        }
+    }
+
+    /// 최신 값만 조회
+    async fn load<T: CachedValue>(&self, key: &str) -> Result<Option<T::Data>, String> {
+        match self.lookup::<T>(key).await? {
+            Some(cached) if now_secs() < cached.fresh_until() => {
+                self.update_cache_stats(|stats| stats.hits += 1).await;
+                Ok(Some(cached.into_data()))
+            }
+            _ => {
+                self.update_cache_stats(|stats| stats.misses += 1).await;
+                Ok(None)
+            }
+        }
+    }
+
+    /// 최신 값은 그대로, 이전 값은 응답 후 백그라운드 갱신, 그 외에는 갱신 결과를 기다려 응답
+    async fn load_or_refresh<T, F, Fut>(&self, key: String, ttl: u64, loader: F) -> Result<Option<T::Data>, String>
+    where
+        T: CachedValue,
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Option<T::Data>, String>> + Send + 'static,
+    {
+        if let Some(cached) = self.lookup::<T>(&key).await? {
+            let now = now_secs();
+            if now < cached.fresh_until() {
+                self.update_cache_stats(|stats| stats.hits += 1).await;
+                return Ok(Some(cached.into_data()));
+            }
+            if now < cached.fresh_until() + self.config.stale_while_revalidate_seconds {
+                self.update_cache_stats(|stats| stats.stale_hits += 1).await;
+                // 갱신은 내부에서 spawn되므로 핸들은 버립니다
+                drop(self.refresh::<T, _, _>(key, ttl, loader));
+                return Ok(Some(cached.into_data()));
+            }
+        }
+
+        self.update_cache_stats(|stats| stats.misses += 1).await;
+        match self.refresh::<T, _, _>(key, ttl, loader).await? {
+            Some(value) => decode::<T>(&value).map(|cached| Some(cached.into_data())),
+            None => Ok(None),
+        }
+    }
+
+    /// 원본 갱신 시작 (같은 키가 진행 중이면 그 갱신을 공유)
+    ///
+    /// 갱신은 별도 태스크에서 끝까지 실행되므로 기다리지 않아도 캐시에 반영됩니다.
+    fn refresh<T, F, Fut>(&self, key: String, ttl: u64, loader: F) -> RemoteLoad
+    where
+        T: CachedValue,
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Option<T::Data>, String>> + Send + 'static,
+    {
+        let mut refreshes = self.refreshes.lock().unwrap();
+        if let Some(refresh) = refreshes.get(&key) {
+            return refresh.clone();
+        }
+
+        let tiers = self.tiers.clone();
+        let entry_key = key.clone();
+        let refresh = async move {
+            tiers.cache_stats.write().await.refreshes += 1;
+            let Some(data) = loader().await? else {
+                return Ok(None);
+            };
+            let value = encode(&T::wrap(data, now_secs(), ttl))?;
+            tiers.store(vec![(entry_key, value.clone(), ttl)]).await?;
+            Ok(Some(value))
+        }
+        .boxed()
+        .shared();
+        refreshes.insert(key.clone(), refresh.clone());
+        drop(refreshes);
+
+        let running = refresh.clone();
+        let refreshes = self.refreshes.clone();
+        tokio::spawn(async move {
+            if let Err(e) = running.clone().await {
+                error!("MDP 캐시 갱신 실패 ({}): {}", key, e);
+            }
+            let mut refreshes = refreshes.lock().unwrap();
+            if refreshes.get(&key).is_some_and(|current| current.ptr_eq(&running)) {
+                refreshes.remove(&key);
+            }
+        });
+        refresh
+    }
+
+    /// 메모리 → Redis 순서로 조회 (이전 값 기간이 지나지 않은 값, Redis에서 찾으면 메모리에 채움)
+    async fn lookup<T: CachedValue>(&self, key: &str) -> Result<Option<T>, String> {
+        let memory_hit = {
+            let memory_cache = self.tiers.memory_cache.read().await;
+            memory_cache
+                .get(key)
+                .filter(|entry| now_secs() < entry.expires_at)
+                .map(|entry| entry.value.clone())
+        };
+        if let Some(value) = memory_hit {
+            return decode(&value).map(Some);
+        }
+
+        let Some(value) = self.load_remote(key).await? else {
+            return Ok(None);
+        };
+        let cached: T = decode(&value)?;
+        let expires_at = cached.fresh_until() + self.config.stale_while_revalidate_seconds;
+        if now_secs() >= expires_at {
+            return Ok(None);
+        }
+        self.tiers
+            .memory_cache
+            .write()
+            .await
+            .insert(key.to_string(), MemoryEntry { value, expires_at });
+        Ok(Some(cached))
+    }
+
+    /// 원격 조회 (같은 키의 동시 조회는 하나로 합침)
+    async fn load_remote(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+        let (load, leader) = {
+            let mut remote_loads = self.remote_loads.lock().unwrap();
+            match remote_loads.get(key) {
+                Some(load) => (load.clone(), false),
+                None => {
+                    let backend = self.tiers.backend.clone();
+                    let owned_key = key.to_string();
+                    let load = async move { backend.get(&owned_key).await }.boxed().shared();
+                    remote_loads.insert(key.to_string(), load.clone());
+                    (load, true)
+                }
+            }
+        };
+        if leader {
+            self.update_cache_stats(|stats| stats.remote_loads += 1).await;
+        }
+
+        let result = load.clone().await;
+        let mut remote_loads = self.remote_loads.lock().unwrap();
+        if remote_loads.get(key).is_some_and(|current| current.ptr_eq(&load)) {
+            remote_loads.remove(key);
+        }
+        result
+    }
+
+    /// 이전 값 기간까지 지난 메모리 캐시 정리
+    async fn cleanup_expired_cache(memory_cache: &MemoryCache) {
+        let now = now_secs();
+        memory_cache.write().await.retain(|_, entry| now < entry.expires_at);
+    }
+
+    /// 캐시 크기 제한 (만료가 가까운 항목부터 제거)
+    async fn limit_cache_size(memory_cache: &MemoryCache, max_size: usize) {
+        let mut cache = memory_cache.write().await;
+        
+        if cache.len() > max_size {
+            let excess = cache.len() - max_size;
+            let mut by_expiry: Vec<(u64, String)> =
+                cache.iter().map(|(key, entry)| (entry.expires_at, key.clone())).collect();
+            by_expiry.sort();
+            
+            for (_, key) in by_expiry.into_iter().take(excess) {
+                cache.remove(&key);
+            }
+            
+            debug!("캐시 크기 제한: {}개 항목 제거", excess);
+        }
+    }
+
+    /// 캐시 통계 업데이트
+    async fn update_cache_stats<F>(&self, updater: F)
+    where
+        F: FnOnce(&mut CacheStats),
+    {
+        let mut stats = self.tiers.cache_stats.write().await;
+        updater(&mut stats);
+    }
+}
+
+fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, String> {
+    serde_json::to_vec(value).map_err(|e| format!("직렬화 실패: {}", e))
+}
+
+fn decode<T: DeserializeOwned>(value: &[u8]) -> Result<T, String> {
+    serde_json::from_slice(value).map_err(|e| format!("역직렬화 실패: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cache_key_conversion() {
+        let key = CacheKey::Candlestick("BTC-KRW".to_string(), "1m".to_string());
+        assert_eq!(key.to_string(), "candlestick:BTC-KRW:1m");
+        
+        let key = CacheKey::Statistics("BTC-KRW".to_string());
+        assert_eq!(key.to_string(), "statistics:BTC-KRW");
+        
+        let key = CacheKey::AllStatistics;
+        assert_eq!(key.to_string(), "statistics:all");
+    }
+
+    #[tokio::test]
+    async fn test_cache_manager_creation() {
+        let config = CacheConfig::default();
+        let manager = MDPCacheManager::new(config);
+        
+        let stats = manager.get_cache_stats().await;
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.misses, 0);
+        assert_eq!(stats.sets, 0);
+    }
+
+    #[tokio::test]
+    async fn test_candlestick_caching() {
+        let config = CacheConfig::default();
+        let manager = MDPCacheManager::new(config);
+        
+        let candlestick = CandlestickData {
+            symbol: "BTC-KRW".to_string(),
+            timeframe: "1m".to_string(),
+            open: 50000000,
+            high: 51000000,
+            low: 49000000,
+            close: 50500000,
+            volume: 1000,
+            timestamp: 1234567890,
+            trade_count: 10,
+        };
+        
+        // 캐시 저장
+        let result = manager.cache_candlestick("BTC-KRW", "1m", &candlestick).await;
+        assert!(result.is_ok());
+        
+        // 캐시 조회
+        let cached = manager.get_candlestick("BTC-KRW", "1m").await.unwrap();
+        assert!(cached.is_some());
+        
+        let cached_data = cached.unwrap();
+        assert_eq!(cached_data.symbol, "BTC-KRW");
+        assert_eq!(cached_data.timeframe, "1m");
+        assert_eq!(cached_data.open, 50000000);
+        assert_eq!(cached_data.close, 50500000);
+    }
+
+    #[tokio::test]
+    async fn test_statistics_caching() {
+        let config = CacheConfig::default();
+        let manager = MDPCacheManager::new(config);
+        
+        let statistics = MarketStatistics {
+            symbol: "BTC-KRW".to_string(),
+            price_change_24h: 5.5,
+            volume_24h: 1000000,
+            high_24h: 52000000,
+            low_24h: 48000000,
+            last_price: 50500000,
+            bid_price: 50400000,
+            ask_price: 50600000,
+            spread: 200000,
+            timestamp: 1234567890,
+        };
+        
+        // 캐시 저장
+        let result = manager.cache_statistics("BTC-KRW", &statistics).await;
+        assert!(result.is_ok());
+        
+        // 캐시 조회
+        let cached = manager.get_statistics("BTC-KRW").await.unwrap();
+        assert!(cached.is_some());
+        
+        let cached_data = cached.unwrap();
+        assert_eq!(cached_data.symbol, "BTC-KRW");
+        assert_eq!(cached_data.price_change_24h, 5.5);
+        assert_eq!(cached_data.volume_24h, 1000000);
+    }
+
+    #[tokio::test]
+    async fn test_cache_invalidation() {
+        let config = CacheConfig::default();
+        let manager = MDPCacheManager::new(config);
+        
+        let candlestick = CandlestickData {
+            symbol: "BTC-KRW".to_string(),
+            timeframe: "1m".to_string(),
+            open: 50000000,
+            high: 51000000,
+            low: 49000000,
+            close: 50500000,
+            volume: 1000,
+            timestamp: 1234567890,
+            trade_count: 10,
+        };
+        
+        // 캐시 저장
+        manager.cache_candlestick("BTC-KRW", "1m", &candlestick).await.unwrap();
+        
+        // 캐시 조회 (성공)
+        let cached = manager.get_candlestick("BTC-KRW", "1m").await.unwrap();
+        assert!(cached.is_some());
+        
+        // 캐시 무효화
+        let key = CacheKey::Candlestick("BTC-KRW".to_string(), "1m".to_string());
+        manager.invalidate(&key).await.unwrap();
+        
+        // 캐시 조회 (실패)
+        let cached = manager.get_candlestick("BTC-KRW", "1m").await.unwrap();
+        assert!(cached.is_none());
+    }
+
+    /// 느린 원격 저장소 (GET 호출 수 집계)
+    struct SlowBackend {
+        inner: MockCacheBackend,
+        gets: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl CacheBackend for SlowBackend {
+        async fn set_many(&self, entries: Vec<CacheEntry>) -> Result<(), String> {
+            self.inner.set_many(entries).await
+        }
+
+        async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+            self.gets.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.inner.get(key).await
+        }
+
+        async fn del(&self, key: &str) -> Result<(), String> {
+            self.inner.del(key).await
+        }
+
+        async fn len(&self) -> Result<usize, String> {
+            self.inner.len().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_misses_share_one_remote_load() {
+        let backend = Arc::new(SlowBackend {
+            inner: MockCacheBackend::new(),
+            gets: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let statistics = MarketStatistics {
+            symbol: "BTC-KRW".to_string(),
+            price_change_24h: 1.0,
+            volume_24h: 10,
+            high_24h: 0,
+            low_24h: 0,
+            last_price: 0,
+            bid_price: 0,
+            ask_price: 0,
+            spread: 0,
+            timestamp: 0,
+        };
+
+        // 다른 인스턴스가 저장 → 이 인스턴스는 메모리 미스, Redis 히트
+        let writer = MDPCacheManager::with_backend(CacheConfig::default(), backend.clone());
+        writer.cache_statistics("BTC-KRW", &statistics).await.unwrap();
+        let manager = Arc::new(MDPCacheManager::with_backend(CacheConfig::default(), backend.clone()));
+
+        let reads: Vec<_> = (0..8)
+            .map(|_| {
+                let manager = manager.clone();
+                tokio::spawn(async move { manager.get_statistics("BTC-KRW").await })
+            })
+            .collect();
+        for read in reads {
+            assert_eq!(read.await.unwrap().unwrap().unwrap().volume_24h, 10);
+        }
+        assert_eq!(backend.gets.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // 이후 조회는 메모리에서
+        manager.get_statistics("BTC-KRW").await.unwrap();
+        let stats = manager.get_cache_stats().await;
+        assert_eq!(backend.gets.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(stats.remote_loads, 1);
+        assert_eq!(stats.hits, 9);
+    }
+
+    fn candle(close: u64) -> CandlestickData {
+        CandlestickData {
+            symbol: "BTC-KRW".to_string(),
+            timeframe: "1m".to_string(),
+            open: 50000000,
+            high: 51000000,
+            low: 49000000,
+            close,
+            volume: 1000,
+            timestamp: 1234567890,
+            trade_count: 10,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_misses_run_loader_once() {
+        let manager = Arc::new(MDPCacheManager::new(CacheConfig::default()));
+        let loads = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let reads: Vec<_> = (0..8)
+            .map(|_| {
+                let manager = manager.clone();
+                let loads = loads.clone();
+                tokio::spawn(async move {
+                    manager
+                        .get_candlestick_or_load("BTC-KRW", "1m", move || async move {
+                            loads.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                            Ok(Some(candle(50500000)))
+                        })
+                        .await
+                })
+            })
+            .collect();
+        for read in reads {
+            assert_eq!(read.await.unwrap().unwrap().unwrap().close, 50500000);
+        }
+        assert_eq!(loads.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // 갱신 결과는 캐시에 남음
+        assert_eq!(manager.get_candlestick("BTC-KRW", "1m").await.unwrap().unwrap().close, 50500000);
+        assert_eq!(manager.get_cache_stats().await.refreshes, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stale_value_served_while_revalidating() {
+        // TTL 0: 저장 즉시 만료되어 이전 값 기간으로 들어감
+        let config = CacheConfig {
+            candlestick_ttl_seconds: 0,
+            stale_while_revalidate_seconds: 60,
+            ..CacheConfig::default()
+        };
+        let manager = MDPCacheManager::new(config);
+        manager.cache_candlestick("BTC-KRW", "1m", &candle(50000000)).await.unwrap();
+        assert!(manager.get_candlestick("BTC-KRW", "1m").await.unwrap().is_none());
+
+        let loads = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        for _ in 0..3 {
+            let loads = loads.clone();
+            let served = manager
+                .get_candlestick_or_load("BTC-KRW", "1m", move || async move {
+                    loads.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    Ok(Some(candle(51000000)))
+                })
+                .await
+                .unwrap()
+                .unwrap();
+            // 갱신을 기다리지 않고 이전 값으로 응답
+            assert_eq!(served.close, 50000000);
+        }
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(loads.load(std::sync::atomic::Ordering::SeqCst), 1);
+        let key = CacheKey::Candlestick("BTC-KRW".to_string(), "1m".to_string());
+        let refreshed: CachedCandlestickData = manager.lookup(&key.to_string()).await.unwrap().unwrap();
+        assert_eq!(refreshed.data.close, 51000000);
+
+        let stats = manager.get_cache_stats().await;
+        assert_eq!(stats.stale_hits, 3);
+        assert_eq!(stats.refreshes, 1);
+    }
+}
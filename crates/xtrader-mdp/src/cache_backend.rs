@@ -0,0 +1,197 @@
+//! MDP 캐시 원격 저장소
+//!
+//! `MDPCacheManager`의 2단계 캐시(메모리 → 원격) 중 원격 단계입니다. 운영에서는 Redis를 쓰며
+//! 멀티플렉싱 연결 하나를 모든 요청이 공유하고, 여러 키 쓰기는 파이프라인 한 번으로 보내며,
+//! 만료는 Redis `SETEX`에 맡깁니다. 테스트와 `mdp-cache-mock` 기능에서는 프로세스 내 Mock을 씁니다.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use log::warn;
+use redis::aio::MultiplexedConnection;
+use tokio::sync::Mutex;
+
+/// 캐시 항목 (키, 직렬화된 값, TTL 초)
+pub type CacheEntry = (String, Vec<u8>, u64);
+
+/// 원격 캐시 저장소
+#[async_trait]
+pub trait CacheBackend: Send + Sync {
+    /// 여러 항목 저장 (만료는 저장소가 처리)
+    async fn set_many(&self, entries: Vec<CacheEntry>) -> Result<(), String>;
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String>;
+    async fn del(&self, key: &str) -> Result<(), String>;
+    /// 저장된 키 수
+    async fn len(&self) -> Result<usize, String>;
+    /// 저장된 키가 없는지 여부
+    async fn is_empty(&self) -> Result<bool, String> {
+        Ok(self.len().await? == 0)
+    }
+}
+
+/// Redis 저장소
+pub struct RedisCacheBackend {
+    client: redis::Client,
+    /// 공유 멀티플렉싱 연결 (끊기면 다음 요청에서 재연결)
+    connection: Mutex<Option<MultiplexedConnection>>,
+}
+
+impl RedisCacheBackend {
+    /// 연결은 첫 요청 때 맺음
+    pub fn new(redis_url: &str) -> Result<Self, String> {
+        let client = redis::Client::open(redis_url).map_err(|e| format!("Redis 주소 오류: {}", e))?;
+        Ok(Self {
+            client,
+            connection: Mutex::new(None),
+        })
+    }
+
+    async fn connection(&self) -> Result<MultiplexedConnection, String> {
+        let mut connection = self.connection.lock().await;
+        if let Some(connection) = connection.as_ref() {
+            return Ok(connection.clone());
+        }
+        let connected = self
+            .client
+            .get_multiplexed_tokio_connection()
+            .await
+            .map_err(|e| format!("Redis 연결 실패: {}", e))?;
+        *connection = Some(connected.clone());
+        Ok(connected)
+    }
+
+    /// 연결 오류면 공유 연결을 버려 다음 요청에서 재연결
+    async fn command_error(&self, e: redis::RedisError) -> String {
+        if e.is_io_error() || e.is_connection_dropped() || e.is_connection_refusal() {
+            warn!("MDP 캐시 Redis 연결 끊김, 다음 요청에서 재연결: {}", e);
+            *self.connection.lock().await = None;
+        }
+        format!("Redis 명령 실패: {}", e)
+    }
+}
+
+#[async_trait]
+impl CacheBackend for RedisCacheBackend {
+    async fn set_many(&self, entries: Vec<CacheEntry>) -> Result<(), String> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let mut pipe = redis::pipe();
+        for (key, value, ttl_seconds) in entries {
+            pipe.set_ex(key, value, ttl_seconds.max(1)).ignore();
+        }
+
+        let mut connection = self.connection().await?;
+        match pipe.query_async::<_, ()>(&mut connection).await {
+            Ok(()) => Ok(()),
+            Err(e) => Err(self.command_error(e).await),
+        }
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+        let mut connection = self.connection().await?;
+        match redis::cmd("GET").arg(key).query_async::<_, Option<Vec<u8>>>(&mut connection).await {
+            Ok(value) => Ok(value),
+            Err(e) => Err(self.command_error(e).await),
+        }
+    }
+
+    async fn del(&self, key: &str) -> Result<(), String> {
+        let mut connection = self.connection().await?;
+        match redis::cmd("DEL").arg(key).query_async::<_, ()>(&mut connection).await {
+            Ok(()) => Ok(()),
+            Err(e) => Err(self.command_error(e).await),
+        }
+    }
+
+    async fn len(&self) -> Result<usize, String> {
+        let mut connection = self.connection().await?;
+        match redis::cmd("DBSIZE").query_async::<_, usize>(&mut connection).await {
+            Ok(size) => Ok(size),
+            Err(e) => Err(self.command_error(e).await),
+        }
+    }
+}
+
+/// 프로세스 내 Mock 저장소 (테스트, `mdp-cache-mock` 기능)
+#[derive(Default)]
+pub struct MockCacheBackend {
+    /// 키 → (값, 만료 시각 초)
+    data: Mutex<HashMap<String, (Vec<u8>, u64)>>,
+}
+
+impl MockCacheBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+}
+
+#[async_trait]
+impl CacheBackend for MockCacheBackend {
+    async fn set_many(&self, entries: Vec<CacheEntry>) -> Result<(), String> {
+        let now = now_secs();
+        let mut data = self.data.lock().await;
+        for (key, value, ttl_seconds) in entries {
+            data.insert(key, (value, now + ttl_seconds.max(1)));
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+        let mut data = self.data.lock().await;
+        match data.get(key) {
+            Some((value, expiry)) if now_secs() < *expiry => Ok(Some(value.clone())),
+            Some(_) => {
+                data.remove(key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn del(&self, key: &str) -> Result<(), String> {
+        self.data.lock().await.remove(key);
+        Ok(())
+    }
+
+    async fn len(&self) -> Result<usize, String> {
+        let now = now_secs();
+        let mut data = self.data.lock().await;
+        data.retain(|_, (_, expiry)| now < *expiry);
+        Ok(data.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_backend_expires_entries() {
+        let backend = MockCacheBackend::new();
+        backend
+            .set_many(vec![("a".to_string(), b"1".to_vec(), 60), ("b".to_string(), b"2".to_vec(), 60)])
+            .await
+            .unwrap();
+        backend.data.lock().await.get_mut("b").unwrap().1 = 0; // 만료 처리
+
+        assert_eq!(backend.get("a").await.unwrap(), Some(b"1".to_vec()));
+        assert_eq!(backend.get("b").await.unwrap(), None);
+        assert_eq!(backend.len().await.unwrap(), 1);
+        backend.del("a").await.unwrap();
+        assert_eq!(backend.len().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_redis_backend_reports_unreachable_server() {
+        // 사용하지 않는 포트: 연결 실패는 오류로 전달 (패닉/대기 없음)
+        let backend = RedisCacheBackend::new("redis://127.0.0.1:1").unwrap();
+        assert!(backend.get("missing").await.is_err());
+        assert!(RedisCacheBackend::new("not a url").is_err());
+    }
+}
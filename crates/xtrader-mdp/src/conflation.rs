@@ -0,0 +1,316 @@
+//! MDP 시장 데이터 병합(conflation)
+//!
+//! 이 모듈은 느린 구독자의 송신 큐가 밀릴 때 같은 심볼의 호가/통계/봉 업데이트를
+//! 최신 상태 하나로 합쳐, 쌓인 과거 데이터 대신 최신 데이터를 전달합니다.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
+
+use xtrader_api::models::{OrderBookChange, OrderBookChangeType, OrderBookDelta, WebSocketMessage};
+
+/// 병합 키 (같은 키의 미전송 메시지는 최신 상태 하나로 합침)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConflationKey {
+    /// 심볼별 호가창 (Delta/Snapshot/Update)
+    OrderBook(String),
+    /// 심볼별 시장 통계
+    Statistics(String),
+    /// 심볼/간격/시작 시각별 봉 (마감된 봉은 덮어쓰지 않음)
+    Candle(String, String, u64),
+    /// 전 심볼 티커
+    Ticker,
+    /// 심볼별 최우선 호가
+    Bbo(String),
+    /// 심볼별 인덱스 가격
+    Index(String),
+}
+
+impl ConflationKey {
+    /// 메시지의 병합 키 (체결 등 병합 불가 메시지는 None)
+    pub fn of(message: &WebSocketMessage) -> Option<Self> {
+        match message {
+            WebSocketMessage::OrderBookDelta(delta) => Some(Self::OrderBook(delta.symbol.clone())),
+            WebSocketMessage::OrderBookSnapshot(snapshot) => Some(Self::OrderBook(snapshot.symbol.clone())),
+            WebSocketMessage::OrderBookUpdate { symbol, .. } => Some(Self::OrderBook(symbol.clone())),
+            WebSocketMessage::MarketStatistics { symbol, .. } => Some(Self::Statistics(symbol.clone())),
+            WebSocketMessage::CandlestickUpdate { symbol, interval, candle, .. } => {
+                Some(Self::Candle(symbol.clone(), interval.clone(), candle.open_time))
+            }
+            WebSocketMessage::Ticker { .. } => Some(Self::Ticker),
+            WebSocketMessage::Bbo(bbo) => Some(Self::Bbo(bbo.symbol.clone())),
+            WebSocketMessage::IndexPrice(index) => Some(Self::Index(index.symbol.clone())),
+            _ => None,
+        }
+    }
+}
+
+/// 큐 적재 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushOutcome {
+    /// 새 항목으로 적재
+    Queued,
+    /// 미전송 항목에 병합
+    Conflated,
+    /// 용량 초과로 버림 (병합 불가한 시장 데이터만)
+    Dropped,
+}
+
+/// 구독자별 병합 큐
+///
+/// 같은 키의 미전송 메시지가 있으면 그 자리에서 최신 상태로 합치므로
+/// 느린 구독자도 쌓인 과거 데이터 대신 최신 데이터를 받습니다.
+/// 체결처럼 키가 없는 메시지는 합치거나 버리지 않고 순서대로 모두 전달합니다.
+#[derive(Debug)]
+pub struct ConflatingQueue {
+    /// (항목 번호, 최초 적재 시각, 메시지)
+    entries: VecDeque<(u64, Instant, WebSocketMessage)>,
+    /// 키별 미전송 항목 번호
+    pending: HashMap<ConflationKey, u64>,
+    /// 다음 항목 번호
+    next_id: u64,
+    /// 새 시장 데이터 항목을 받을 최대 길이
+    capacity: usize,
+}
+
+impl ConflatingQueue {
+    /// 새 병합 큐 생성
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            pending: HashMap::new(),
+            next_id: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// 메시지 적재
+    pub fn push(&mut self, message: WebSocketMessage) -> PushOutcome {
+        let key = match ConflationKey::of(&message) {
+            Some(key) => key,
+            None => {
+                self.append(message);
+                return PushOutcome::Queued;
+            }
+        };
+
+        if let Some(&id) = self.pending.get(&key) {
+            let front_id = self.entries.front().map(|(id, _, _)| *id).unwrap_or(id);
+            let index = (id - front_id) as usize;
+            let (_, _, pending) = &mut self.entries[index];
+            *pending = merge(std::mem::replace(pending, placeholder()), message);
+            return PushOutcome::Conflated;
+        }
+
+        if self.entries.len() >= self.capacity {
+            return PushOutcome::Dropped;
+        }
+
+        let id = self.append(message);
+        self.pending.insert(key, id);
+        PushOutcome::Queued
+    }
+
+    /// 가장 오래된 메시지 꺼내기 (최초 적재 시각 포함)
+    pub fn pop(&mut self) -> Option<(Instant, WebSocketMessage)> {
+        let (id, queued_at, message) = self.entries.pop_front()?;
+        if let Some(key) = ConflationKey::of(&message) {
+            if self.pending.get(&key) == Some(&id) {
+                self.pending.remove(&key);
+            }
+        }
+        Some((queued_at, message))
+    }
+
+    /// 대기 중인 메시지 수
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 비어 있는지 확인
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn append(&mut self, message: WebSocketMessage) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.push_back((id, Instant::now(), message));
+        id
+    }
+}
+
+/// 병합 중 잠시 자리를 채우는 값
+fn placeholder() -> WebSocketMessage {
+    WebSocketMessage::Error { message: String::new() }
+}
+
+/// 미전송 메시지에 새 메시지를 합침
+fn merge(pending: WebSocketMessage, incoming: WebSocketMessage) -> WebSocketMessage {
+    match (pending, incoming) {
+        // Delta + Delta: 가격 레벨별로 나중 변경이 우선
+        (WebSocketMessage::OrderBookDelta(mut base), WebSocketMessage::OrderBookDelta(delta)) => {
+            merge_changes(&mut base.bid_changes, delta.bid_changes);
+            merge_changes(&mut base.ask_changes, delta.ask_changes);
+            base.timestamp = delta.timestamp;
+            base.sequence = delta.sequence;
+            WebSocketMessage::OrderBookDelta(base)
+        }
+        // 전체 호가 + Delta: 전체 호가에 Delta를 적용
+        (WebSocketMessage::OrderBookSnapshot(mut snapshot), WebSocketMessage::OrderBookDelta(delta)) => {
+            apply_changes(&mut snapshot.bids, &delta.bid_changes, true);
+            apply_changes(&mut snapshot.asks, &delta.ask_changes, false);
+            snapshot.timestamp = delta.timestamp;
+            snapshot.sequence = delta.sequence;
+            WebSocketMessage::OrderBookSnapshot(snapshot)
+        }
+        (
+            WebSocketMessage::OrderBookUpdate { symbol, mut bids, mut asks, .. },
+            WebSocketMessage::OrderBookDelta(OrderBookDelta { bid_changes, ask_changes, timestamp, .. }),
+        ) => {
+            apply_changes(&mut bids, &bid_changes, true);
+            apply_changes(&mut asks, &ask_changes, false);
+            WebSocketMessage::OrderBookUpdate { symbol, bids, asks, timestamp }
+        }
+        // 그 외 (전체 호가, 통계, 봉, 티커, BBO): 최신 상태로 교체
+        (_, incoming) => incoming,
+    }
+}
+
+/// Delta 변경사항 병합 (같은 가격은 나중 변경으로 교체)
+fn merge_changes(base: &mut Vec<OrderBookChange>, changes: Vec<OrderBookChange>) {
+    for change in changes {
+        match base.iter_mut().find(|c| c.price == change.price) {
+            Some(existing) => *existing = change,
+            None => base.push(change),
+        }
+    }
+}
+
+/// 전체 호가 레벨에 Delta 적용 (매수는 내림차순, 매도는 오름차순 유지)
+fn apply_changes(levels: &mut Vec<(u64, u64)>, changes: &[OrderBookChange], descending: bool) {
+    for change in changes {
+        levels.retain(|(price, _)| *price != change.price);
+        if change.change_type != OrderBookChangeType::Remove && change.quantity > 0 {
+            levels.push((change.price, change.quantity));
+        }
+    }
+    if descending {
+        levels.sort_by_key(|level| std::cmp::Reverse(level.0));
+    } else {
+        levels.sort_by_key(|a| a.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xtrader_api::models::{CandleData, OrderBookSnapshot};
+
+    fn change(change_type: OrderBookChangeType, price: u64, quantity: u64) -> OrderBookChange {
+        OrderBookChange { change_type, price, quantity }
+    }
+
+    fn delta(symbol: &str, sequence: u64, bid_changes: Vec<OrderBookChange>) -> WebSocketMessage {
+        WebSocketMessage::OrderBookDelta(OrderBookDelta {
+            symbol: symbol.to_string(),
+            bid_changes,
+            ask_changes: vec![],
+            timestamp: sequence,
+            sequence,
+        })
+    }
+
+    fn candle(open_time: u64, close: u64) -> WebSocketMessage {
+        WebSocketMessage::CandlestickUpdate {
+            symbol: "BTC-KRW".to_string(),
+            interval: "1m".to_string(),
+            candle: CandleData {
+                open_time,
+                close_time: open_time,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                volume: 1,
+                trade_count: 1,
+            },
+            is_closed: false,
+        }
+    }
+
+    #[test]
+    fn test_deltas_coalesce_per_symbol() {
+        let mut queue = ConflatingQueue::new(16);
+
+        assert_eq!(queue.push(delta("BTC-KRW", 1, vec![change(OrderBookChangeType::Add, 100, 5)])), PushOutcome::Queued);
+        assert_eq!(queue.push(delta("ETH-KRW", 2, vec![change(OrderBookChangeType::Add, 10, 1)])), PushOutcome::Queued);
+        assert_eq!(
+            queue.push(delta("BTC-KRW", 3, vec![
+                change(OrderBookChangeType::Update, 100, 2),
+                change(OrderBookChangeType::Add, 99, 7),
+            ])),
+            PushOutcome::Conflated
+        );
+        assert_eq!(queue.len(), 2);
+
+        match queue.pop().unwrap().1 {
+            WebSocketMessage::OrderBookDelta(merged) => {
+                assert_eq!(merged.sequence, 3);
+                let levels: Vec<(u64, u64)> = merged.bid_changes.iter().map(|c| (c.price, c.quantity)).collect();
+                assert_eq!(levels, vec![(100, 2), (99, 7)]);
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+
+        // 전송된 뒤에는 새 항목으로 적재
+        assert_eq!(queue.push(delta("BTC-KRW", 4, vec![])), PushOutcome::Queued);
+    }
+
+    #[test]
+    fn test_delta_applied_to_pending_snapshot() {
+        let mut queue = ConflatingQueue::new(16);
+        queue.push(WebSocketMessage::OrderBookSnapshot(OrderBookSnapshot {
+            symbol: "BTC-KRW".to_string(),
+            bids: vec![(100, 5), (98, 1)],
+            asks: vec![],
+            timestamp: 1,
+            sequence: 1,
+        }));
+        queue.push(delta("BTC-KRW", 2, vec![
+            change(OrderBookChangeType::Remove, 100, 0),
+            change(OrderBookChangeType::Add, 99, 3),
+        ]));
+
+        match queue.pop().unwrap().1 {
+            WebSocketMessage::OrderBookSnapshot(snapshot) => {
+                assert_eq!(snapshot.bids, vec![(99, 3), (98, 1)]);
+                assert_eq!(snapshot.sequence, 2);
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_trades_kept_and_new_candles_not_overwritten() {
+        let mut queue = ConflatingQueue::new(2);
+
+        queue.push(candle(60, 100));
+        assert_eq!(queue.push(candle(60, 101)), PushOutcome::Conflated);
+        assert_eq!(queue.push(candle(120, 102)), PushOutcome::Queued);
+
+        // 용량을 넘으면 새 시장 데이터는 버리지만 키 없는 메시지는 보존
+        assert_eq!(queue.push(delta("BTC-KRW", 1, vec![])), PushOutcome::Dropped);
+        let error = WebSocketMessage::Error { message: "e".to_string() };
+        assert_eq!(queue.push(error), PushOutcome::Queued);
+        assert_eq!(queue.len(), 3);
+
+        let closes: Vec<u64> = std::iter::from_fn(|| queue.pop())
+            .filter_map(|(_, m)| match m {
+                WebSocketMessage::CandlestickUpdate { candle, .. } => Some(candle.close),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(closes, vec![101, 102]);
+    }
+}
@@ -8,13 +8,17 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
-use log::{info, error, warn, debug};
+use log::{info, error, debug};
 use tokio::time::{sleep, interval};
-use crate::mq::kafka_consumer::{KafkaConsumerWorker, KafkaConsumerConfig};
-use crate::mq::kafka_producer::{MarketDataMessage, MarketStatisticsMessage, OrderBookUpdateMessage};
+use xtrader_mq::kafka_consumer::{KafkaConsumerWorker, KafkaConsumerConfig};
+use xtrader_mq::kafka_producer::MarketDataMessage;
+use crate::gap::{GapDetector, GapMetrics, GapRecovery, Ingest};
+
+/// 심볼별 최근 체결 보관 한도
+const MAX_RECENT_TRADES: usize = 1000;
 
 /// 체결 데이터 구조
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TradeData {
     pub symbol: String,
     pub price: u64,
@@ -24,6 +28,19 @@ pub struct TradeData {
     pub trade_id: String,
 }
 
+impl From<&MarketDataMessage> for TradeData {
+    fn from(message: &MarketDataMessage) -> Self {
+        Self {
+            symbol: message.symbol.clone(),
+            price: message.price,
+            quantity: message.quantity,
+            timestamp: message.timestamp,
+            side: message.side.to_lowercase(),
+            trade_id: message.execution_id.clone(),
+        }
+    }
+}
+
 /// 봉차트 데이터 구조
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CandlestickData {
@@ -89,6 +106,7 @@ impl Default for MDPConsumerConfig {
 /// MDP Consumer
 pub struct MDPConsumer {
     /// Kafka Consumer Worker
+    #[allow(dead_code)]
     kafka_consumer: KafkaConsumerWorker,
     /// 설정
     config: MDPConsumerConfig,
@@ -100,6 +118,8 @@ pub struct MDPConsumer {
     recent_trades: Arc<Mutex<HashMap<String, Vec<TradeData>>>>,
     /// 처리 중인 상태
     is_processing: Arc<Mutex<bool>>,
+    /// 체결 메시지 시퀀스 누락 감지/복구
+    gap_detector: GapDetector,
 }
 
 impl MDPConsumer {
@@ -115,13 +135,44 @@ impl MDPConsumer {
         };
 
         Self {
-            kafka_consumer: KafkaConsumerWorker::new(kafka_config),
+            kafka_consumer: KafkaConsumerWorker::from_config(kafka_config),
             config,
             candlestick_data: Arc::new(RwLock::new(HashMap::new())),
             market_statistics: Arc::new(RwLock::new(HashMap::new())),
             recent_trades: Arc::new(Mutex::new(HashMap::new())),
             is_processing: Arc::new(Mutex::new(false)),
+            gap_detector: GapDetector::new(),
+        }
+    }
+
+    /// 메시지 누락 복구 수단 추가 (Producer 재전송 → DB 스냅샷 순으로 등록)
+    pub fn with_gap_recovery(mut self, recovery: Arc<dyn GapRecovery>) -> Self {
+        self.gap_detector = std::mem::take(&mut self.gap_detector).with_recovery(recovery);
+        self
+    }
+
+    /// Kafka 체결 메시지 반영 (시퀀스 누락이면 복구 후 반영)
+    pub async fn ingest_message(&self, message: &MarketDataMessage) {
+        if message.message_type != "execution" {
+            return;
+        }
+
+        let ingest = self.gap_detector.check(message).await;
+        let mut trades_map = self.recent_trades.lock().await;
+        let symbol_trades = trades_map.entry(message.symbol.clone()).or_insert_with(Vec::new);
+        match ingest {
+            Ingest::Skip => return,
+            Ingest::Append(trades) => symbol_trades.extend(trades),
+            Ingest::Replace(trades) => *symbol_trades = trades,
         }
+        if symbol_trades.len() > MAX_RECENT_TRADES {
+            symbol_trades.drain(0..symbol_trades.len() - MAX_RECENT_TRADES);
+        }
+    }
+
+    /// 메시지 누락 지표
+    pub async fn gap_metrics(&self) -> GapMetrics {
+        self.gap_detector.metrics().await
     }
 
     /// MDP Consumer 실행
@@ -132,7 +183,7 @@ impl MDPConsumer {
         let market_statistics = self.market_statistics.clone();
         let recent_trades = self.recent_trades.clone();
         let config = self.config.clone();
-        let is_processing = self.is_processing.clone();
+        let _is_processing = self.is_processing.clone();
 
         // Kafka 메시지 소비 태스크
         let kafka_task = {
@@ -203,10 +254,10 @@ impl MDPConsumer {
 
     /// Kafka 메시지 소비 및 처리
     async fn consume_kafka_messages(
-        candlestick_data: &Arc<RwLock<HashMap<String, HashMap<String, CandlestickData>>>>,
-        market_statistics: &Arc<RwLock<HashMap<String, MarketStatistics>>>,
+        _candlestick_data: &Arc<RwLock<HashMap<String, HashMap<String, CandlestickData>>>>,
+        _market_statistics: &Arc<RwLock<HashMap<String, MarketStatistics>>>,
         recent_trades: &Arc<Mutex<HashMap<String, Vec<TradeData>>>>,
-        config: &MDPConsumerConfig,
+        _config: &MDPConsumerConfig,
     ) -> Result<(), String> {
         // Mock: 실제로는 Kafka에서 메시지를 소비
         let mock_trades = Self::create_mock_trade_data();
@@ -219,8 +270,8 @@ impl MDPConsumer {
             symbol_trades.push(trade.clone());
             
             // 최대 1000개까지만 유지 (메모리 관리)
-            if symbol_trades.len() > 1000 {
-                symbol_trades.drain(0..symbol_trades.len() - 1000);
+            if symbol_trades.len() > MAX_RECENT_TRADES {
+                symbol_trades.drain(0..symbol_trades.len() - MAX_RECENT_TRADES);
             }
         }
         
@@ -452,17 +503,20 @@ impl MDPConsumer {
             statistics_count,
             trades_count,
             is_processing: self.is_processing().await,
+            gaps: self.gap_metrics().await,
         }
     }
 }
 
 /// MDP Consumer 통계
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MDPConsumerStats {
     pub candlestick_count: usize,
     pub statistics_count: usize,
     pub trades_count: usize,
     pub is_processing: bool,
+    /// 체결 메시지 누락 감지/복구 지표
+    pub gaps: GapMetrics,
 }
 
 // fastrand 의존성을 위해 간단한 랜덤 함수 구현
@@ -479,7 +533,7 @@ mod fastrand {
             .as_nanos()
             .hash(&mut hasher);
         let hash = hasher.finish();
-        (hash % 2) == 0
+        hash.is_multiple_of(2)
     }
 
     pub fn u32(range: std::ops::Range<u32>) -> u32 {
@@ -0,0 +1,272 @@
+//! MDP Kafka 메시지 누락 감지 및 복구
+//!
+//! 체결 메시지의 심볼별 시퀀스가 건너뛰면 누락으로 보고, 등록된 복구 수단을 순서대로 시도합니다.
+//! Producer 재전송 버퍼에 누락 구간이 남아 있으면 그 메시지를 받아 채우고, 없으면 DB의 최근 체결
+//! 스냅샷으로 심볼의 체결 목록을 교체합니다. 어느 쪽도 안 되면 누락을 지표에 남기고 그대로 진행하므로
+//! 봉차트/통계가 틀렸을 수 있는 구간이 조용히 묻히지 않습니다.
+//! DB 스냅샷 복구(`ExecutionSnapshotRecovery`)는 `executions` 테이블을 읽으므로 서버의 `mdp::backfill`에 있습니다.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::{error, warn};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::consumer::TradeData;
+use xtrader_mq::kafka_producer::{KafkaProducer, MarketDataMessage};
+
+/// 누락 복구 결과
+pub enum GapFill {
+    /// 누락 구간 메시지 (시퀀스순)
+    Replay(Vec<MarketDataMessage>),
+    /// 심볼의 최근 체결 전체 (시각순, 기존 목록 교체)
+    Snapshot(Vec<TradeData>),
+}
+
+/// 누락 복구 수단
+#[async_trait]
+pub trait GapRecovery: Send + Sync {
+    /// 누락 구간 복구 (이 수단으로 복구할 수 없으면 None)
+    async fn recover(&self, symbol: &str, missing: RangeInclusive<u64>) -> Result<Option<GapFill>, String>;
+}
+
+/// Producer 재전송 버퍼에서 복구
+#[async_trait]
+impl GapRecovery for KafkaProducer {
+    async fn recover(&self, symbol: &str, missing: RangeInclusive<u64>) -> Result<Option<GapFill>, String> {
+        let replayed = self.replay(symbol, *missing.start(), *missing.end()).await;
+        Ok(replayed
+            .filter(|messages| messages.len() as u64 == missing.end() - missing.start() + 1)
+            .map(GapFill::Replay))
+    }
+}
+
+/// 누락 감지 지표
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GapMetrics {
+    /// 감지한 누락 구간 수
+    pub gaps_detected: u64,
+    /// 누락된 메시지 수
+    pub messages_missed: u64,
+    /// Producer 재전송으로 복구한 구간 수
+    pub replay_recoveries: u64,
+    /// DB 스냅샷으로 복구한 구간 수
+    pub snapshot_recoveries: u64,
+    /// 복구하지 못한 구간 수 (해당 심볼 봉차트/통계가 틀렸을 수 있음)
+    pub unrecovered_gaps: u64,
+    /// 이미 반영한 시퀀스라 버린 메시지 수
+    pub duplicates: u64,
+}
+
+/// 메시지 반영 방법
+#[derive(Debug, PartialEq)]
+pub enum Ingest {
+    /// 이미 반영한 메시지 (버림)
+    Skip,
+    /// 체결 목록 끝에 추가
+    Append(Vec<TradeData>),
+    /// 심볼 체결 목록을 교체
+    Replace(Vec<TradeData>),
+}
+
+struct DetectorState {
+    /// 심볼별 다음 기대 시퀀스
+    next_sequence: HashMap<String, u64>,
+    metrics: GapMetrics,
+}
+
+/// 심볼별 시퀀스 누락 감지기
+pub struct GapDetector {
+    state: Mutex<DetectorState>,
+    recoveries: Vec<Arc<dyn GapRecovery>>,
+}
+
+impl Default for GapDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GapDetector {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(DetectorState {
+                next_sequence: HashMap::new(),
+                metrics: GapMetrics::default(),
+            }),
+            recoveries: Vec::new(),
+        }
+    }
+
+    /// 복구 수단 추가 (추가한 순서대로 시도)
+    pub fn with_recovery(mut self, recovery: Arc<dyn GapRecovery>) -> Self {
+        self.recoveries.push(recovery);
+        self
+    }
+
+    pub async fn metrics(&self) -> GapMetrics {
+        self.state.lock().await.metrics.clone()
+    }
+
+    /// 체결 메시지 확인 (누락이면 복구 후 반영할 체결 반환)
+    ///
+    /// 심볼의 첫 메시지는 기준 시퀀스로 삼고, 시퀀스 0(시퀀스 없는 메시지)은 확인하지 않습니다.
+    pub async fn check(&self, message: &MarketDataMessage) -> Ingest {
+        let trade = TradeData::from(message);
+        if message.sequence == 0 {
+            return Ingest::Append(vec![trade]);
+        }
+
+        // 복구 중 같은 심볼의 다음 메시지가 끼어들지 않도록 잠근 채 진행
+        let mut state = self.state.lock().await;
+        let expected = state.next_sequence.get(&message.symbol).copied();
+        if expected.is_some_and(|expected| message.sequence < expected) {
+            state.metrics.duplicates += 1;
+            return Ingest::Skip;
+        }
+        state.next_sequence.insert(message.symbol.clone(), message.sequence + 1);
+
+        let Some(expected) = expected.filter(|&expected| message.sequence > expected) else {
+            return Ingest::Append(vec![trade]);
+        };
+
+        let missing = expected..=message.sequence - 1;
+        state.metrics.gaps_detected += 1;
+        state.metrics.messages_missed += message.sequence - expected;
+        warn!("MDP 메시지 누락 감지: {} 시퀀스 {}~{}", message.symbol, missing.start(), missing.end());
+
+        for recovery in &self.recoveries {
+            match recovery.recover(&message.symbol, missing.clone()).await {
+                Ok(Some(GapFill::Replay(messages))) => {
+                    state.metrics.replay_recoveries += 1;
+                    let mut trades: Vec<TradeData> = messages.iter().map(TradeData::from).collect();
+                    trades.push(trade);
+                    return Ingest::Append(trades);
+                }
+                Ok(Some(GapFill::Snapshot(mut trades))) => {
+                    state.metrics.snapshot_recoveries += 1;
+                    // 스냅샷에 이미 저장된 현재 체결은 다시 넣지 않음
+                    if !trades.iter().any(|t| t.trade_id == trade.trade_id) {
+                        trades.push(trade);
+                    }
+                    return Ingest::Replace(trades);
+                }
+                Ok(None) => continue,
+                Err(e) => error!("MDP 메시지 누락 복구 실패 ({}): {}", message.symbol, e),
+            }
+        }
+
+        state.metrics.unrecovered_gaps += 1;
+        error!(
+            "MDP 메시지 누락 복구 불가: {} 시퀀스 {}~{} (봉차트/통계가 부정확할 수 있음)",
+            message.symbol,
+            missing.start(),
+            missing.end()
+        );
+        Ingest::Append(vec![trade])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(symbol: &str, sequence: u64) -> MarketDataMessage {
+        MarketDataMessage {
+            execution_id: format!("{}-{}", symbol, sequence),
+            symbol: symbol.to_string(),
+            side: "Buy".to_string(),
+            price: 1000 + sequence,
+            quantity: 1,
+            timestamp: 1_700_000_000 + sequence,
+            order_id: "order".to_string(),
+            user_id: "user".to_string(),
+            message_type: "execution".to_string(),
+            sequence,
+            global_sequence: 0,
+        }
+    }
+
+    fn trade_ids(ingest: Ingest) -> Vec<String> {
+        match ingest {
+            Ingest::Append(trades) | Ingest::Replace(trades) => trades.into_iter().map(|t| t.trade_id).collect(),
+            Ingest::Skip => vec![],
+        }
+    }
+
+    /// 주어진 시퀀스만 재전송할 수 있는 Producer 흉내
+    struct PartialReplay(Vec<u64>);
+
+    #[async_trait]
+    impl GapRecovery for PartialReplay {
+        async fn recover(&self, symbol: &str, missing: RangeInclusive<u64>) -> Result<Option<GapFill>, String> {
+            if missing.clone().all(|sequence| self.0.contains(&sequence)) {
+                Ok(Some(GapFill::Replay(missing.map(|sequence| message(symbol, sequence)).collect())))
+            } else {
+                Ok(None)
+            }
+        }
+    }
+
+    /// 고정 스냅샷
+    struct Snapshot;
+
+    #[async_trait]
+    impl GapRecovery for Snapshot {
+        async fn recover(&self, symbol: &str, _missing: RangeInclusive<u64>) -> Result<Option<GapFill>, String> {
+            Ok(Some(GapFill::Snapshot((1..=6).map(|sequence| TradeData::from(&message(symbol, sequence))).collect())))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sequence_checks_without_recovery() {
+        let detector = GapDetector::new();
+
+        assert_eq!(trade_ids(detector.check(&message("BTC-KRW", 5)).await), vec!["BTC-KRW-5"]);
+        assert_eq!(trade_ids(detector.check(&message("BTC-KRW", 6)).await), vec!["BTC-KRW-6"]);
+        assert_eq!(detector.check(&message("BTC-KRW", 6)).await, Ingest::Skip);
+        // 다른 심볼은 따로 셈
+        assert_eq!(trade_ids(detector.check(&message("ETH-KRW", 1)).await), vec!["ETH-KRW-1"]);
+        // 7, 8 누락: 복구 수단이 없으면 현재 메시지만 반영하고 지표에 남김
+        assert_eq!(trade_ids(detector.check(&message("BTC-KRW", 9)).await), vec!["BTC-KRW-9"]);
+        assert_eq!(trade_ids(detector.check(&message("BTC-KRW", 0)).await), vec!["BTC-KRW-0"]);
+
+        let metrics = detector.metrics().await;
+        assert_eq!(
+            metrics,
+            GapMetrics {
+                gaps_detected: 1,
+                messages_missed: 2,
+                unrecovered_gaps: 1,
+                duplicates: 1,
+                ..GapMetrics::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replay_then_snapshot_fallback() {
+        let detector = GapDetector::new()
+            .with_recovery(Arc::new(PartialReplay(vec![2, 3])))
+            .with_recovery(Arc::new(Snapshot));
+
+        detector.check(&message("BTC-KRW", 1)).await;
+        // 2, 3 누락: 재전송으로 채움
+        assert_eq!(
+            trade_ids(detector.check(&message("BTC-KRW", 4)).await),
+            vec!["BTC-KRW-2", "BTC-KRW-3", "BTC-KRW-4"]
+        );
+        // 5 누락: 재전송 불가 → 스냅샷 교체 (스냅샷에 있는 현재 체결은 중복 추가 안 함)
+        match detector.check(&message("BTC-KRW", 6)).await {
+            Ingest::Replace(trades) => assert_eq!(trades.len(), 6),
+            other => panic!("스냅샷 교체 기대: {:?}", other),
+        }
+
+        let metrics = detector.metrics().await;
+        assert_eq!((metrics.gaps_detected, metrics.messages_missed), (2, 3));
+        assert_eq!((metrics.replay_recoveries, metrics.snapshot_recoveries, metrics.unrecovered_gaps), (1, 1, 0));
+    }
+}
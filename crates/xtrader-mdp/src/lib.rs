@@ -0,0 +1,37 @@
+/*
+* filename : mod
+* author : HAMA
+* date: 2025. 5. 13.
+* description: Market Data Publisher 모듈
+*/
+
+//! 시장 데이터 발행(MDP)
+//!
+//! 체결로 봉차트/시장 통계/티커를 만들고, Kafka 시장 데이터 Consumer, 조회 API와 캐시, 호가창
+//! Delta 병합, 녹화/재생을 제공합니다. 체결 보고서는 `xtrader-engine`, WebSocket 프레임은
+//! `xtrader-api`, Kafka는 `xtrader-mq` 타입을 씁니다. `executions` 테이블을 읽는 봉차트 백필과
+//! 체결 스냅샷 복구는 서버 크레이트의 `mdp::backfill`에 있습니다.
+
+pub mod model;
+pub mod publisher;
+pub mod consumer;
+pub mod api;
+pub mod cache;
+pub mod cache_backend;
+pub mod conflation;
+pub mod ticker;
+pub mod recorder;
+pub mod playback;
+pub mod gap;
+
+pub use model::*;
+pub use model::{CandlestickData, MarketStatistics};
+pub use publisher::MarketDataPublisher;
+pub use consumer::*;
+pub use api::*;
+pub use cache::*;
+pub use conflation::*;
+pub use ticker::*;
+pub use recorder::{MarketDataRecorder, RecorderConfig};
+pub use playback::{MarketDataPlayer, PlaybackConfig};
+pub use gap::{GapFill, GapMetrics, GapRecovery};
\ No newline at end of file
@@ -1,12 +1,11 @@
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
 
-/**
+/*
 * filename : model
 * author : HAMA
 * date: 2025. 5. 13.
 * description: Market Data Publisher 모델 정의
-**/
+*/
 
 /// 시장 데이터 이벤트 타입
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,213 @@
+//! MDP 녹화 데이터 재생
+//!
+//! `MarketDataRecorder`가 남긴 녹화 파일을 읽어 원래 간격(배속 조절 가능)대로
+//! WebSocket 브로드캐스트와 Kafka에 다시 발행합니다. 전략 백테스트용입니다.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use flate2::read::MultiGzDecoder;
+use log::{error, info, warn};
+use tokio::sync::{broadcast, mpsc};
+use tokio::time::Instant;
+
+use xtrader_api::models::WebSocketMessage;
+use crate::recorder::RecordedEvent;
+use xtrader_mq::KafkaProducer;
+
+/// 재생 설정
+#[derive(Debug, Clone)]
+pub struct PlaybackConfig {
+    /// 녹화 파일 경로
+    pub path: PathBuf,
+    /// 재생 배속 (1.0 = 실시간, 0 이하 = 대기 없이 최대 속도)
+    pub speed: f64,
+}
+
+/// 재생 결과
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlaybackSummary {
+    /// 발행한 이벤트 수
+    pub events: u64,
+    /// 순번 불연속 (녹화 중 누락) 횟수
+    pub gaps: u64,
+    /// 녹화 구간 길이 (마이크로초)
+    pub recorded_span_us: i64,
+}
+
+/// 녹화 파일 읽기 (녹화 중이던 파일처럼 끝이 잘린 경우 읽은 데까지 반환)
+pub fn read_recording(path: &Path) -> io::Result<impl Iterator<Item = io::Result<RecordedEvent>>> {
+    let reader = BufReader::new(MultiGzDecoder::new(File::open(path)?));
+    Ok(reader.lines().map_while(|line| match line {
+        Ok(line) => Some(serde_json::from_str::<RecordedEvent>(&line).map_err(io::Error::from)),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+            warn!("녹화 파일 끝이 잘려 있음 - 읽은 데까지만 재생");
+            None
+        }
+        Err(e) => Some(Err(e)),
+    }))
+}
+
+/// 녹화 데이터 재생기
+pub struct MarketDataPlayer {
+    config: PlaybackConfig,
+    broadcast_tx: broadcast::Sender<WebSocketMessage>,
+    kafka_producer: Option<Arc<KafkaProducer>>,
+}
+
+impl MarketDataPlayer {
+    pub fn new(config: PlaybackConfig, broadcast_tx: broadcast::Sender<WebSocketMessage>) -> Self {
+        Self {
+            config,
+            broadcast_tx,
+            kafka_producer: None,
+        }
+    }
+
+    /// 체결을 Kafka에도 발행
+    pub fn with_kafka_producer(mut self, kafka_producer: Option<Arc<KafkaProducer>>) -> Self {
+        self.kafka_producer = kafka_producer;
+        self
+    }
+
+    /// 파일 끝까지 재생
+    pub async fn run(self) -> io::Result<PlaybackSummary> {
+        info!("시장 데이터 재생 시작: {} ({}배속)", self.config.path.display(), self.config.speed);
+
+        // 파일 읽기/파싱은 블로킹 스레드에서, 발행 간격 조절은 비동기로 처리
+        let (tx, mut rx) = mpsc::channel::<io::Result<RecordedEvent>>(1024);
+        let path = self.config.path.clone();
+        let reader = tokio::task::spawn_blocking(move || -> io::Result<()> {
+            for event in read_recording(&path)? {
+                if tx.blocking_send(event).is_err() {
+                    break;
+                }
+            }
+            Ok(())
+        });
+
+        let mut summary = PlaybackSummary::default();
+        let mut start: Option<(Instant, i64)> = None;
+        let mut last_seq: Option<u64> = None;
+
+        while let Some(event) = rx.recv().await {
+            let event = event?;
+
+            let (started_at, first_ts) = *start.get_or_insert((Instant::now(), event.ts_us));
+            if self.config.speed > 0.0 {
+                let offset_us = (event.ts_us - first_ts).max(0) as f64 / self.config.speed;
+                tokio::time::sleep_until(started_at + Duration::from_micros(offset_us as u64)).await;
+            }
+
+            if last_seq.is_some_and(|seq| event.seq > seq + 1) {
+                summary.gaps += 1;
+            }
+            last_seq = Some(event.seq);
+            summary.recorded_span_us = event.ts_us - first_ts;
+            summary.events += 1;
+
+            self.publish(event.message).await;
+        }
+
+        reader.await.map_err(io::Error::other)??;
+        info!(
+            "시장 데이터 재생 완료: {}건, 녹화 구간 {:.1}초, 누락 구간 {}개",
+            summary.events,
+            summary.recorded_span_us as f64 / 1_000_000.0,
+            summary.gaps
+        );
+        Ok(summary)
+    }
+
+    /// 라이브와 같은 경로로 발행 (WebSocket 브로드캐스트, 체결은 Kafka)
+    async fn publish(&self, message: WebSocketMessage) {
+        if let (Some(kafka), WebSocketMessage::Execution { execution_report, .. }) = (&self.kafka_producer, &message) {
+            if let Err(e) = kafka.publish_execution(execution_report).await {
+                error!("재생 체결 Kafka 발행 실패: {}", e);
+            }
+        }
+
+        // 구독자가 없으면 실패하므로 무시
+        let _ = self.broadcast_tx.send(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xtrader_api::models::{OrderBookChange, OrderBookChangeType, OrderBookDelta};
+    use crate::recorder::{recording_path, RecordingWriter};
+    use xtrader_engine::model::{ExecType, ExecutionReport, Side};
+    use chrono::{TimeZone, Utc};
+
+    fn trade(id: &str, exec_type: ExecType) -> WebSocketMessage {
+        WebSocketMessage::Execution {
+            execution_report: ExecutionReport {
+                execution_id: id.to_string(),
+                order_id: "o1".to_string(),
+                symbol: "BTC-KRW".to_string(),
+                side: Side::Buy,
+                price: 1000,
+                quantity: 1,
+                remaining_quantity: 0,
+                timestamp: 0,
+                counterparty_id: "o2".to_string(),
+                is_maker: false,
+                exec_type,
+                sequence: 0,
+                tag: None,
+            },
+            order_status: "Filled".to_string(),
+        }
+    }
+
+    fn delta(sequence: u64) -> WebSocketMessage {
+        WebSocketMessage::OrderBookDelta(OrderBookDelta {
+            symbol: "BTC-KRW".to_string(),
+            bid_changes: vec![OrderBookChange { change_type: OrderBookChangeType::Add, price: 999, quantity: 3 }],
+            ask_changes: vec![],
+            timestamp: sequence,
+            sequence,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let dir = std::env::temp_dir().join(format!("xtrader-recording-{}", uuid::Uuid::new_v4()));
+        let base = Utc.with_ymd_and_hms(2025, 5, 13, 9, 0, 0).unwrap();
+
+        let mut writer = RecordingWriter::new(&dir).unwrap();
+        writer.write(&delta(1), base).unwrap();
+        writer.write(&trade("e1", ExecType::Trade), base + chrono::Duration::milliseconds(200)).unwrap();
+        // 취소 보고서는 녹화하지 않음
+        writer.write(&trade("c1", ExecType::Canceled), base + chrono::Duration::milliseconds(300)).unwrap();
+        writer.skip(5);
+        writer.write(&delta(2), base + chrono::Duration::milliseconds(400)).unwrap();
+        writer.finish().unwrap();
+        assert_eq!(writer.recorded(), 3);
+
+        let path = recording_path(&dir, "2025-05-13");
+        let (broadcast_tx, mut broadcast_rx) = broadcast::channel(16);
+        let player = MarketDataPlayer::new(PlaybackConfig { path, speed: 0.0 }, broadcast_tx);
+        let summary = player.run().await.unwrap();
+
+        assert_eq!(summary.events, 3);
+        assert_eq!(summary.gaps, 1);
+        assert_eq!(summary.recorded_span_us, 400_000);
+
+        let mut kinds = Vec::new();
+        while let Ok(message) = broadcast_rx.try_recv() {
+            kinds.push(match message {
+                WebSocketMessage::OrderBookDelta(d) => format!("delta{}", d.sequence),
+                WebSocketMessage::Execution { execution_report, .. } => execution_report.execution_id,
+                other => panic!("unexpected message: {:?}", other),
+            });
+        }
+        assert_eq!(kinds, vec!["delta1", "e1", "delta2"]);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
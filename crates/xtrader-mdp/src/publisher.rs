@@ -0,0 +1,666 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+use xtrader_engine::model::{ExecType, ExecutionReport, OrderBookSnapshot};
+use crate::model::{CandlestickData, MarketStatistics};
+use crate::ticker::TickerAggregator;
+use xtrader_api::models::{CandleData, TickerData, WebSocketMessage};
+use xtrader_engine::clock::{SharedClock, SystemClock};
+
+/// 봉차트 간격
+pub const CANDLE_INTERVALS: [&str; 7] = ["1m", "5m", "15m", "30m", "1h", "4h", "1d"];
+
+/// 봉 간격 길이 (초, 알 수 없는 간격은 1분)
+pub fn candle_interval_secs(interval: &str) -> u64 {
+    match interval {
+        "1m" => 60,
+        "5m" => 300,
+        "15m" => 900,
+        "30m" => 1800,
+        "1h" => 3600,
+        "4h" => 14400,
+        "1d" => 86400,
+        _ => 60,
+    }
+}
+
+/// 심볼/간격별 실시간 봉 전송 상태
+#[derive(Debug)]
+struct CandleStreamState {
+    /// 실시간으로 전송 중인 봉 시작 시각
+    open_time: u64,
+    /// 마감 프레임 전송 여부
+    closed: bool,
+    /// 마지막 진행 중 프레임 전송 시각 (Unix 밀리초)
+    last_pushed: Option<u64>,
+}
+
+impl CandleStreamState {
+    fn new(open_time: u64) -> Self {
+        Self { open_time, closed: false, last_pushed: None }
+    }
+}
+
+/// 심볼 → 타임프레임 → 봉 목록
+type CandlestickStore = HashMap<String, HashMap<String, Vec<CandlestickData>>>;
+
+/// 시장 데이터 발행자
+pub struct MarketDataPublisher {
+    /// 심볼별 체결 내역 저장소
+    executions: Arc<Mutex<HashMap<String, Vec<ExecutionReport>>>>,
+    /// 심볼별 봉차트 데이터 저장소
+    candlesticks: Arc<Mutex<CandlestickStore>>,
+    /// 심볼/간격별 실시간 봉 전송 상태
+    candle_streams: Arc<Mutex<HashMap<(String, String), CandleStreamState>>>,
+    /// 진행 중인 봉 프레임 최소 전송 간격 (0이면 체결마다)
+    candle_throttle: Duration,
+    /// 심볼별 시장 통계 저장소
+    statistics: Arc<Mutex<HashMap<String, MarketStatistics>>>,
+    /// 전 심볼 24시간 롤링 티커
+    tickers: Arc<Mutex<TickerAggregator>>,
+    /// 최대 체결 내역 보관 수
+    max_executions: usize,
+    /// WebSocket 브로드캐스트 채널
+    broadcast_tx: Option<tokio::sync::broadcast::Sender<WebSocketMessage>>,
+    /// 시각 소스 (봉 프레임 전송 간격, 티커/봉 마감 기준 시각)
+    clock: SharedClock,
+}
+
+impl MarketDataPublisher {
+    /// 새 MDP 생성
+    pub fn new(max_executions: usize) -> Self {
+        let mdp = Self {
+            executions: Arc::new(Mutex::new(HashMap::new())),
+            candlesticks: Arc::new(Mutex::new(HashMap::new())),
+            candle_streams: Arc::new(Mutex::new(HashMap::new())),
+            candle_throttle: Duration::ZERO,
+            statistics: Arc::new(Mutex::new(HashMap::new())),
+            tickers: Arc::new(Mutex::new(TickerAggregator::new())),
+            max_executions,
+            broadcast_tx: None,
+            clock: SystemClock::shared(),
+        };
+
+        // 초기 가짜 데이터 로드 시도
+        if let Err(e) = mdp.load_initial_data() {
+            eprintln!("Failed to load initial fake data: {}", e);
+        }
+
+        mdp
+    }
+
+    /// WebSocket 브로드캐스트 채널 설정
+    pub fn set_broadcast_channel(&mut self, broadcast_tx: tokio::sync::broadcast::Sender<WebSocketMessage>) {
+        self.broadcast_tx = Some(broadcast_tx);
+    }
+
+    /// 시각 소스 설정 (시뮬레이션 테스트용, 기본값은 시스템 시각)
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// 시각 소스
+    pub fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+
+    /// 진행 중인 봉 프레임 최소 전송 간격 설정 (마감 프레임은 항상 전송)
+    pub fn set_candle_throttle(&mut self, throttle: Duration) {
+        self.candle_throttle = throttle;
+    }
+
+    /// 체결 전에도 티커에 나타날 심볼 등록
+    pub async fn register_ticker_symbols(&self, symbols: &[String]) {
+        self.tickers.lock().await.register_symbols(symbols);
+    }
+
+    /// 가짜 데이터에서 초기 캔들 데이터 로드
+    fn load_initial_data(&self) -> Result<(), Box<dyn std::error::Error>> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        use crate::model::CandlestickData;
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let base_time = now - (24 * 60 * 60); // 24시간 전부터 시작
+
+        // BTC-KRW, ETH-KRW, AAPL에 대한 가짜 historical 캔들 생성
+        let symbols = ["BTC-KRW", "ETH-KRW", "AAPL"];
+        let base_prices = [100000000u64, 4000000u64, 200000u64];
+
+        tokio::spawn({
+            let candlesticks = self.candlesticks.clone();
+            async move {
+                let mut candlesticks_guard = candlesticks.lock().await;
+
+                for (symbol_idx, symbol) in symbols.iter().enumerate() {
+                    // 저장된 체결로 이미 재구성된 심볼은 건너뜀
+                    if candlesticks_guard.contains_key(*symbol) {
+                        continue;
+                    }
+                    let base_price = base_prices[symbol_idx];
+                    let symbol_candlesticks = candlesticks_guard.entry(symbol.to_string()).or_insert_with(HashMap::new);
+
+                    // 5분 간격 캔들 100개 생성 (약 8시간 분량)
+                    let interval_candles = symbol_candlesticks.entry("5m".to_string()).or_insert_with(Vec::new);
+
+                    for i in 0..100 {
+                        let open_time = base_time + (i * 5 * 60); // 5분 간격
+                        let close_time = open_time + (5 * 60); // 5분 후 종가 시간
+                        let price_variance = (i as f64 * 0.123).sin() * 0.02 + 1.0;
+                        let adjusted_price = (base_price as f64 * price_variance) as u64;
+
+                        let open = adjusted_price;
+                        let close = adjusted_price + (((i as f64 * 0.456).cos() * 0.01) * base_price as f64) as u64;
+                        let high = std::cmp::max(open, close) + (base_price / 1000);
+                        let low = std::cmp::min(open, close) - (base_price / 1500);
+                        let volume = 50 + (i % 200);
+
+                        let candle = CandlestickData {
+                            open_time,
+                            close_time,
+                            open,
+                            high,
+                            low,
+                            close,
+                            volume,
+                            trade_count: 10 + (i % 50),
+                        };
+
+                        interval_candles.push(candle);
+                    }
+
+                    println!("📊 생성된 {} 캔들 데이터: 100개", symbol);
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// 체결 보고서 처리
+    pub async fn process_execution(&self, execution: ExecutionReport) {
+        let symbol = execution.symbol.clone();
+        
+        // 체결 내역 저장
+        {
+            let mut executions = self.executions.lock().await;
+            let symbol_executions = executions.entry(symbol.clone()).or_insert_with(Vec::new);
+            symbol_executions.push(execution.clone());
+            
+            // 최대 개수 초과 시 오래된 것 제거
+            if symbol_executions.len() > self.max_executions {
+                symbol_executions.remove(0);
+            }
+        }
+
+        // 봉차트/시장 통계는 체결만 반영 (취소/만료 보고서의 수량은 해제된 잔량이라 거래량이 아님)
+        if execution.exec_type == ExecType::Trade {
+            self.update_candlesticks(&execution).await;
+            self.stream_candles(&symbol).await;
+            self.update_statistics(&execution).await;
+        }
+
+        // 티커 업데이트 (체결 1건당 테이커 보고서 하나만 반영)
+        if execution.exec_type == ExecType::Trade && !execution.is_maker {
+            self.tickers
+                .lock()
+                .await
+                .record_trade(&execution.symbol, execution.price, execution.quantity, execution.timestamp);
+        }
+
+        // 시장 데이터 브로드캐스트
+        self.broadcast_market_data(&execution).await;
+    }
+
+    /// 봉차트 데이터 업데이트
+    async fn update_candlesticks(&self, execution: &ExecutionReport) {
+        let mut candlesticks = self.candlesticks.lock().await;
+        let symbol_candlesticks = candlesticks.entry(execution.symbol.clone()).or_insert_with(HashMap::new);
+        self.apply_trade_to_candles(symbol_candlesticks, execution.price, execution.quantity, execution.timestamp);
+    }
+
+    /// 저장된 체결로 심볼의 봉차트를 다시 구성 (`trades`: 시각순 (가격, 수량, 체결 시각))
+    ///
+    /// 기존 봉차트(초기 가짜 데이터 포함)는 재구성한 봉차트로 교체됩니다. 체결이 없으면 그대로 둡니다.
+    pub async fn backfill_candles(&self, symbol: &str, trades: &[(u64, u64, u64)]) {
+        if trades.is_empty() {
+            return;
+        }
+
+        let mut rebuilt = HashMap::new();
+        for &(price, quantity, timestamp) in trades {
+            self.apply_trade_to_candles(&mut rebuilt, price, quantity, timestamp);
+        }
+        self.candlesticks.lock().await.insert(symbol.to_string(), rebuilt);
+    }
+
+    /// 체결 한 건을 전 시간 간격 봉차트에 반영
+    fn apply_trade_to_candles(
+        &self,
+        symbol_candlesticks: &mut HashMap<String, Vec<CandlestickData>>,
+        price: u64,
+        quantity: u64,
+        timestamp: u64,
+    ) {
+        // 다양한 시간 간격에 대해 봉차트 업데이트
+        for interval in CANDLE_INTERVALS {
+            let interval_candles = symbol_candlesticks.entry(interval.to_string()).or_default();
+            
+            // 현재 시간에 해당하는 봉 찾기
+            let current_candle_time = self.get_candle_time(timestamp, interval);
+            
+            if let Some(last_candle) = interval_candles.last_mut() {
+                if last_candle.close_time == current_candle_time {
+                    // 기존 봉 업데이트
+                    last_candle.high = last_candle.high.max(price);
+                    last_candle.low = last_candle.low.min(price);
+                    last_candle.close = price;
+                    last_candle.volume += quantity;
+                    last_candle.trade_count += 1;
+                } else {
+                    // 새 봉 생성
+                    let new_candle = CandlestickData {
+                        open_time: current_candle_time,
+                        close_time: current_candle_time,
+                        open: price,
+                        high: price,
+                        low: price,
+                        close: price,
+                        volume: quantity,
+                        trade_count: 1,
+                    };
+                    interval_candles.push(new_candle);
+                    
+                    // 최대 봉 개수 제한
+                    self.limit_candles(interval_candles, interval);
+                }
+            } else {
+                // 첫 번째 봉 생성
+                let new_candle = CandlestickData {
+                    open_time: current_candle_time,
+                    close_time: current_candle_time,
+                    open: price,
+                    high: price,
+                    low: price,
+                    close: price,
+                    volume: quantity,
+                    trade_count: 1,
+                };
+                interval_candles.push(new_candle);
+            }
+        }
+    }
+
+    /// 시장 통계 업데이트
+    async fn update_statistics(&self, execution: &ExecutionReport) {
+        let symbol = execution.symbol.clone();
+        let price = execution.price;
+        let quantity = execution.quantity;
+
+        let mut statistics = self.statistics.lock().await;
+        let stats = statistics.entry(symbol).or_insert_with(|| MarketStatistics {
+            symbol: execution.symbol.clone(),
+            timestamp: execution.timestamp,
+            open_price_24h: None,
+            high_price_24h: None,
+            low_price_24h: None,
+            last_price: Some(price),
+            volume_24h: 0,
+            price_change_24h: None,
+            bid_price: None,
+            ask_price: None,
+        });
+
+        // 최고가/최저가 업데이트
+        if stats.high_price_24h.is_none() || price > stats.high_price_24h.unwrap() {
+            stats.high_price_24h = Some(price);
+        }
+        if stats.low_price_24h.is_none() || price < stats.low_price_24h.unwrap() {
+            stats.low_price_24h = Some(price);
+        }
+
+        // 거래량 업데이트
+        stats.volume_24h += quantity;
+
+        // 마지막 가격 업데이트
+        stats.last_price = Some(price);
+
+        // 24시간 시작가 설정 (첫 거래 시)
+        if stats.open_price_24h.is_none() {
+            stats.open_price_24h = Some(price);
+        }
+
+        // 가격 변화율 계산
+        if let (Some(open), Some(last)) = (stats.open_price_24h, stats.last_price) {
+            if open > 0 {
+                stats.price_change_24h = Some(((last as f64 - open as f64) / open as f64) * 100.0);
+            }
+        }
+
+        stats.timestamp = execution.timestamp;
+    }
+
+    /// 봉차트 시간 계산
+    fn get_candle_time(&self, timestamp: u64, interval: &str) -> u64 {
+        let interval_seconds = candle_interval_secs(interval);
+        (timestamp / interval_seconds) * interval_seconds
+    }
+
+    /// 봉차트 개수 제한
+    fn limit_candles(&self, candles: &mut Vec<CandlestickData>, interval: &str) {
+        let max_candles = match interval {
+            "1m" => 1440,   // 24시간
+            "5m" => 1152,   // 4일
+            "15m" => 960,   // 10일
+            "30m" => 1008,  // 3주
+            "1h" => 720,    // 30일
+            "4h" => 720,    // 120일
+            "1d" => 365,    // 1년
+            _ => 100,
+        };
+
+        if candles.len() > max_candles {
+            let remove_count = candles.len() - max_candles;
+            candles.drain(0..remove_count);
+        }
+    }
+
+    /// 체결 내역 조회
+    pub async fn get_executions(&self, symbol: &str, limit: usize) -> Vec<ExecutionReport> {
+        let executions = self.executions.lock().await;
+        if let Some(symbol_executions) = executions.get(symbol) {
+            let start = symbol_executions.len().saturating_sub(limit);
+            symbol_executions[start..].to_vec()
+        } else {
+            vec![]
+        }
+    }
+
+    /// 봉차트 데이터 조회
+    pub async fn get_candles(&self, symbol: &str, interval: &str, limit: usize) -> Vec<CandlestickData> {
+        let candlesticks = self.candlesticks.lock().await;
+        if let Some(symbol_candlesticks) = candlesticks.get(symbol) {
+            if let Some(interval_candles) = symbol_candlesticks.get(interval) {
+                let start = interval_candles.len().saturating_sub(limit);
+                interval_candles[start..].to_vec()
+            } else {
+                vec![]
+            }
+        } else {
+            vec![]
+        }
+    }
+
+    /// 시장 통계 조회
+    pub async fn get_statistics(&self, symbol: &str) -> Option<MarketStatistics> {
+        let statistics = self.statistics.lock().await;
+        statistics.get(symbol).cloned()
+    }
+
+    /// 전 심볼 티커 조회 (`now`: Unix 타임스탬프, 초)
+    pub async fn get_tickers(&self, now: u64) -> Vec<TickerData> {
+        self.tickers.lock().await.all_tickers(now)
+    }
+
+    /// 심볼 티커 조회
+    pub async fn get_ticker(&self, symbol: &str, now: u64) -> Option<TickerData> {
+        self.tickers.lock().await.ticker(symbol, now)
+    }
+
+    /// 전 심볼 티커 브로드캐스트
+    pub async fn publish_tickers(&self) {
+        if let Some(ref broadcast_tx) = self.broadcast_tx {
+            let now = self.clock.now_millis();
+            let message = WebSocketMessage::Ticker {
+                timestamp: now,
+                tickers: self.get_tickers(now / 1000).await,
+            };
+
+            // 구독자가 없으면 실패하므로 무시
+            let _ = broadcast_tx.send(message);
+        }
+    }
+
+    /// 체결 직후 심볼의 전 간격 진행 중인 봉 전송
+    ///
+    /// 체결로 새 봉이 열렸으면 이전 봉의 마감 프레임을 먼저 보냅니다 (주기 점검에서 이미 보냈으면 생략).
+    /// 진행 중인 봉 프레임은 `candle_throttle` 간격으로 줄이며, 건너뛴 상태는 다음 프레임이나 마감 프레임에 반영됩니다.
+    async fn stream_candles(&self, symbol: &str) {
+        let Some(ref broadcast_tx) = self.broadcast_tx else {
+            return;
+        };
+
+        let candlesticks = self.candlesticks.lock().await;
+        let Some(symbol_candlesticks) = candlesticks.get(symbol) else {
+            return;
+        };
+        let mut streams = self.candle_streams.lock().await;
+        let now = self.clock.now_millis();
+        let throttle = self.candle_throttle.as_millis() as u64;
+
+        for interval in CANDLE_INTERVALS {
+            let Some(current) = symbol_candlesticks.get(interval).and_then(|candles| candles.last()) else {
+                continue;
+            };
+            let stream = streams
+                .entry((symbol.to_string(), interval.to_string()))
+                .or_insert_with(|| CandleStreamState::new(current.open_time));
+
+            if stream.open_time != current.open_time {
+                if !stream.closed {
+                    let previous = symbol_candlesticks[interval].iter().rev().find(|c| c.open_time == stream.open_time);
+                    if let Some(previous) = previous {
+                        // 구독자가 없으면 실패하므로 무시
+                        let _ = broadcast_tx.send(candle_message(symbol, interval, previous, true));
+                    }
+                }
+                *stream = CandleStreamState::new(current.open_time);
+            }
+
+            // 마감 뒤 늦게 도착한 체결은 정정된 마감 프레임으로 전송
+            if !stream.closed {
+                let throttled = stream
+                    .last_pushed
+                    .is_some_and(|pushed| now.saturating_sub(pushed) < throttle);
+                if throttled {
+                    continue;
+                }
+                stream.last_pushed = Some(now);
+            }
+            let _ = broadcast_tx.send(candle_message(symbol, interval, current, stream.closed));
+        }
+    }
+
+    /// 체결 없이 기간이 끝난 봉의 마감 프레임 전송 (`now`: Unix 타임스탬프, 초)
+    pub async fn publish_closed_candles(&self, now: u64) {
+        let Some(ref broadcast_tx) = self.broadcast_tx else {
+            return;
+        };
+
+        let candlesticks = self.candlesticks.lock().await;
+        let mut streams = self.candle_streams.lock().await;
+        for ((symbol, interval), stream) in streams.iter_mut() {
+            if stream.closed || stream.open_time + candle_interval_secs(interval) > now {
+                continue;
+            }
+            let candle = candlesticks
+                .get(symbol)
+                .and_then(|symbol_candlesticks| symbol_candlesticks.get(interval))
+                .and_then(|candles| candles.iter().rev().find(|c| c.open_time == stream.open_time));
+            if let Some(candle) = candle {
+                let _ = broadcast_tx.send(candle_message(symbol, interval, candle, true));
+            }
+            stream.closed = true;
+        }
+    }
+
+    /// 봉 마감 주기 점검 태스크 시작 (`period`마다 시각 소스 기준으로 [`Self::publish_closed_candles`])
+    pub fn spawn_candle_closer(mdp: Arc<Mutex<Self>>, period: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let clock = mdp.lock().await.clock.clone();
+            loop {
+                let now = clock.now_secs();
+                mdp.lock().await.publish_closed_candles(now).await;
+                clock.sleep(period).await;
+            }
+        })
+    }
+
+    /// 주문서 업데이트 (오더북 스냅샷 기반)
+    pub async fn update_orderbook(&self, _snapshot: OrderBookSnapshot) {
+        // 주문서 업데이트는 별도로 처리할 수 있음
+        // 현재는 체결 데이터만 처리
+    }
+
+    /// 시장 데이터 브로드캐스트
+    async fn broadcast_market_data(&self, execution: &ExecutionReport) {
+        if let Some(ref broadcast_tx) = self.broadcast_tx {
+            let symbol = execution.symbol.clone();
+            
+            // 시장 통계 브로드캐스트
+            if let Some(stats) = self.get_statistics(&symbol).await {
+                let message = WebSocketMessage::MarketStatistics {
+                    symbol: stats.symbol.clone(),
+                    timestamp: stats.timestamp,
+                    last_price: stats.last_price,
+                    price_change_24h: stats.price_change_24h,
+                    volume_24h: stats.volume_24h,
+                    high_price_24h: stats.high_price_24h,
+                    low_price_24h: stats.low_price_24h,
+                };
+                
+                if let Err(e) = broadcast_tx.send(message) {
+                    eprintln!("Failed to broadcast market statistics: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// 봉 WebSocket 메시지 생성
+fn candle_message(symbol: &str, interval: &str, candle: &CandlestickData, is_closed: bool) -> WebSocketMessage {
+    WebSocketMessage::CandlestickUpdate {
+        symbol: symbol.to_string(),
+        interval: interval.to_string(),
+        candle: CandleData {
+            open_time: candle.open_time,
+            close_time: candle.close_time,
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+            volume: candle.volume,
+            trade_count: candle.trade_count,
+        },
+        is_closed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xtrader_engine::clock::SimClock;
+
+    const T0: u64 = 1_700_000_040; // 1분 봉 경계
+
+    fn trade(price: u64, timestamp: u64) -> ExecutionReport {
+        ExecutionReport {
+            execution_id: format!("e{}", timestamp),
+            order_id: "taker".to_string(),
+            symbol: "SOL-KRW".to_string(),
+            side: xtrader_engine::model::Side::Buy,
+            price,
+            quantity: 1,
+            remaining_quantity: 0,
+            timestamp,
+            counterparty_id: "maker".to_string(),
+            is_maker: false,
+            exec_type: ExecType::Trade,
+            sequence: 0,
+            tag: None,
+        }
+    }
+
+    /// 수신한 1분 봉 프레임 (종가, 마감 여부)
+    fn minute_frames(rx: &mut tokio::sync::broadcast::Receiver<WebSocketMessage>) -> Vec<(u64, bool)> {
+        std::iter::from_fn(|| rx.try_recv().ok())
+            .filter_map(|message| match message {
+                WebSocketMessage::CandlestickUpdate { interval, candle, is_closed, .. } if interval == "1m" => {
+                    Some((candle.close, is_closed))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_streams_in_progress_and_closed_candles() {
+        let (tx, mut rx) = tokio::sync::broadcast::channel(256);
+        let mut mdp = MarketDataPublisher::new(100);
+        mdp.set_broadcast_channel(tx);
+
+        mdp.process_execution(trade(100, T0)).await;
+        mdp.process_execution(trade(105, T0 + 10)).await;
+        assert_eq!(minute_frames(&mut rx), vec![(100, false), (105, false)]);
+
+        // 새 봉이 열리면 이전 봉 마감 프레임을 먼저 전송
+        mdp.process_execution(trade(110, T0 + 60)).await;
+        assert_eq!(minute_frames(&mut rx), vec![(105, true), (110, false)]);
+
+        // 체결 없이 기간이 끝나면 주기 점검에서 한 번만 마감
+        mdp.publish_closed_candles(T0 + 119).await;
+        assert!(minute_frames(&mut rx).is_empty());
+        mdp.publish_closed_candles(T0 + 120).await;
+        mdp.publish_closed_candles(T0 + 121).await;
+        assert_eq!(minute_frames(&mut rx), vec![(110, true)]);
+
+        // 다음 봉을 여는 체결은 마감 프레임을 다시 보내지 않음
+        mdp.process_execution(trade(120, T0 + 180)).await;
+        assert_eq!(minute_frames(&mut rx), vec![(120, false)]);
+    }
+
+    #[tokio::test]
+    async fn test_throttle_skips_in_progress_frames_only() {
+        let (tx, mut rx) = tokio::sync::broadcast::channel(256);
+        let mut mdp = MarketDataPublisher::new(100);
+        mdp.set_broadcast_channel(tx);
+        mdp.set_candle_throttle(Duration::from_secs(3600));
+
+        mdp.process_execution(trade(100, T0)).await;
+        mdp.process_execution(trade(105, T0 + 10)).await;
+        mdp.process_execution(trade(110, T0 + 60)).await;
+        assert_eq!(minute_frames(&mut rx), vec![(100, false), (105, true), (110, false)]);
+    }
+
+    #[tokio::test]
+    async fn test_candle_rollover_and_throttle_follow_sim_clock() {
+        let clock = SimClock::new(T0 * 1000);
+        let (tx, mut rx) = tokio::sync::broadcast::channel(256);
+        let mut mdp = MarketDataPublisher::new(100);
+        mdp.set_broadcast_channel(tx);
+        mdp.set_clock(clock.shared());
+        mdp.set_candle_throttle(Duration::from_secs(5));
+        let mdp = Arc::new(Mutex::new(mdp));
+        let closer = MarketDataPublisher::spawn_candle_closer(mdp.clone(), Duration::from_secs(1));
+
+        mdp.lock().await.process_execution(trade(100, T0)).await;
+        mdp.lock().await.process_execution(trade(105, T0 + 1)).await;
+        assert_eq!(minute_frames(&mut rx), vec![(100, false)]);
+
+        // 전송 간격이 지나면 진행 중인 봉을 다시 전송
+        clock.advance(Duration::from_secs(5));
+        mdp.lock().await.process_execution(trade(106, T0 + 5)).await;
+        assert_eq!(minute_frames(&mut rx), vec![(106, false)]);
+
+        // 체결 없이 1분이 지나면 주기 점검이 마감 프레임을 보냄
+        clock.advance(Duration::from_secs(54));
+        clock.settle().await;
+        assert!(minute_frames(&mut rx).is_empty());
+        clock.advance(Duration::from_secs(1));
+        clock.settle().await;
+        assert_eq!(minute_frames(&mut rx), vec![(106, true)]);
+
+        closer.abort();
+    }
+}
@@ -0,0 +1,205 @@
+//! MDP 시장 데이터 녹화
+//!
+//! 시퀀싱된 시장 데이터 스트림(체결 + 호가 Delta/Snapshot)을 구독해
+//! 하루(UTC) 단위 gzip 압축 JSON Lines 파일로 저장합니다.
+//! 녹화 파일은 `MarketDataPlayer`로 같은 인터페이스에 다시 발행할 수 있습니다.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use xtrader_api::models::WebSocketMessage;
+use xtrader_engine::model::ExecType;
+
+/// 녹화 파일 확장자
+pub const RECORDING_EXTENSION: &str = "mdr.gz";
+
+/// 녹화 레코드 (파일의 한 줄)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// 녹화 순번 (브로드캐스트에서 메시지를 놓치면 그만큼 건너뜀)
+    pub seq: u64,
+    /// 수신 시각 (Unix 마이크로초)
+    pub ts_us: i64,
+    /// 원본 메시지
+    pub message: WebSocketMessage,
+}
+
+/// 녹화 설정
+#[derive(Debug, Clone)]
+pub struct RecorderConfig {
+    /// 녹화 파일 디렉터리
+    pub directory: PathBuf,
+    /// 디스크 반영 주기
+    pub flush_interval: Duration,
+}
+
+impl RecorderConfig {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            flush_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// 날짜별 녹화 파일 경로 (`{dir}/{YYYY-MM-DD}.mdr.gz`)
+pub fn recording_path(directory: &Path, date: &str) -> PathBuf {
+    directory.join(format!("{}.{}", date, RECORDING_EXTENSION))
+}
+
+/// 녹화 대상인지 확인 (체결과 호가 변경만 녹화, 통계/봉/티커는 재생 시 다시 계산 가능)
+pub fn is_recorded(message: &WebSocketMessage) -> bool {
+    match message {
+        WebSocketMessage::Execution { execution_report, .. } => execution_report.exec_type == ExecType::Trade,
+        WebSocketMessage::OrderBookDelta(_)
+        | WebSocketMessage::OrderBookSnapshot(_)
+        | WebSocketMessage::OrderBookUpdate { .. } => true,
+        _ => false,
+    }
+}
+
+/// 하루 단위 녹화 파일 작성기
+pub struct RecordingWriter {
+    directory: PathBuf,
+    /// 현재 파일 날짜 및 인코더
+    current: Option<(String, BufWriter<GzEncoder<File>>)>,
+    next_seq: u64,
+    recorded: u64,
+}
+
+impl RecordingWriter {
+    /// 새 작성기 생성 (디렉터리가 없으면 생성)
+    pub fn new(directory: impl Into<PathBuf>) -> io::Result<Self> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)?;
+        Ok(Self {
+            directory,
+            current: None,
+            next_seq: 0,
+            recorded: 0,
+        })
+    }
+
+    /// 누적 녹화 수
+    pub fn recorded(&self) -> u64 {
+        self.recorded
+    }
+
+    /// 놓친 메시지 수만큼 순번을 건너뜀
+    pub fn skip(&mut self, count: u64) {
+        self.next_seq += count;
+    }
+
+    /// 메시지 기록 (날짜가 바뀌면 새 파일로 교체)
+    pub fn write(&mut self, message: &WebSocketMessage, at: DateTime<Utc>) -> io::Result<()> {
+        if !is_recorded(message) {
+            return Ok(());
+        }
+        let seq = self.next_seq;
+        self.next_seq += 1;
+
+        let date = at.format("%Y-%m-%d").to_string();
+        if self.current.as_ref().is_none_or(|(d, _)| d != &date) {
+            self.rotate(date)?;
+        }
+
+        let event = RecordedEvent {
+            seq,
+            ts_us: at.timestamp_micros(),
+            message: message.clone(),
+        };
+        if let Some((_, writer)) = self.current.as_mut() {
+            serde_json::to_writer(&mut *writer, &event)?;
+            writer.write_all(b"\n")?;
+            self.recorded += 1;
+        }
+        Ok(())
+    }
+
+    /// 버퍼를 압축 스트림에 반영 (파일이 잘려도 여기까지는 읽을 수 있음)
+    pub fn flush(&mut self) -> io::Result<()> {
+        if let Some((_, writer)) = self.current.as_mut() {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// 현재 파일 마무리
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some((_, writer)) = self.current.take() {
+            let encoder = writer.into_inner().map_err(|e| e.into_error())?;
+            encoder.finish()?.sync_all()?;
+        }
+        Ok(())
+    }
+
+    fn rotate(&mut self, date: String) -> io::Result<()> {
+        self.finish()?;
+
+        // 같은 날 재시작하면 기존 파일 뒤에 새 gzip 멤버로 이어 씀
+        let path = recording_path(&self.directory, &date);
+        let file = fs::OpenOptions::new().create(true).append(true).open(&path)?;
+        info!("시장 데이터 녹화 파일: {}", path.display());
+        self.current = Some((date, BufWriter::new(GzEncoder::new(file, Compression::default()))));
+        Ok(())
+    }
+}
+
+/// 시장 데이터 녹화기
+pub struct MarketDataRecorder {
+    config: RecorderConfig,
+}
+
+impl MarketDataRecorder {
+    pub fn new(config: RecorderConfig) -> Self {
+        Self { config }
+    }
+
+    /// 브로드캐스트 스트림을 구독해 채널이 닫힐 때까지 녹화
+    pub async fn run(self, mut rx: broadcast::Receiver<WebSocketMessage>) {
+        let mut writer = match RecordingWriter::new(&self.config.directory) {
+            Ok(writer) => writer,
+            Err(e) => {
+                error!("녹화 디렉터리 생성 실패 ({}): {}", self.config.directory.display(), e);
+                return;
+            }
+        };
+        let mut flush_interval = tokio::time::interval(self.config.flush_interval);
+
+        loop {
+            tokio::select! {
+                received = rx.recv() => match received {
+                    Ok(message) => {
+                        if let Err(e) = writer.write(&message, Utc::now()) {
+                            error!("시장 데이터 녹화 실패: {}", e);
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("시장 데이터 녹화 지연 - {}개 메시지 누락", skipped);
+                        writer.skip(skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                _ = flush_interval.tick() => {
+                    if let Err(e) = writer.flush() {
+                        error!("녹화 파일 반영 실패: {}", e);
+                    }
+                }
+            }
+        }
+
+        if let Err(e) = writer.finish() {
+            error!("녹화 파일 마무리 실패: {}", e);
+        }
+        info!("시장 데이터 녹화 종료 ({}건)", writer.recorded());
+    }
+}
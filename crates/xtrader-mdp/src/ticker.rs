@@ -0,0 +1,217 @@
+//! MDP 24시간 롤링 티커
+//!
+//! 체결마다 1분 단위 버킷을 갱신하고 24시간이 지난 버킷을 밀어내는 방식으로
+//! 최근가, 24시간 시가/고가/저가/거래량/변동률을 체결 내역을 다시 훑지 않고 계산합니다.
+
+use std::collections::{HashMap, VecDeque};
+
+use xtrader_api::models::TickerData;
+
+/// 롤링 윈도 길이 (초)
+pub const TICKER_WINDOW_SECS: u64 = 24 * 60 * 60;
+
+/// 버킷 길이 (초)
+const BUCKET_SECS: u64 = 60;
+
+/// 1분 단위 집계 버킷
+#[derive(Debug, Clone)]
+struct TickerBucket {
+    start: u64,
+    open: u64,
+    high: u64,
+    low: u64,
+    volume: u64,
+    trade_count: u64,
+}
+
+/// 심볼별 24시간 롤링 통계
+#[derive(Debug, Default)]
+struct RollingTicker {
+    /// 시간순 버킷 (최대 1440개)
+    buckets: VecDeque<TickerBucket>,
+    /// 윈도 내 거래량 합계
+    volume: u64,
+    /// 윈도 내 체결 수 합계
+    trade_count: u64,
+    /// 윈도 내 고가/저가 (버킷이 빠질 때만 다시 계산)
+    high: Option<u64>,
+    low: Option<u64>,
+    /// 마지막 체결가 (윈도가 비어도 유지)
+    last_price: Option<u64>,
+}
+
+impl RollingTicker {
+    fn record(&mut self, price: u64, quantity: u64, timestamp: u64) {
+        let start = timestamp - timestamp % BUCKET_SECS;
+
+        match self.buckets.back_mut() {
+            // 같은 버킷이거나 늦게 도착한 체결은 마지막 버킷에 합산
+            Some(bucket) if bucket.start >= start => {
+                bucket.high = bucket.high.max(price);
+                bucket.low = bucket.low.min(price);
+                bucket.volume += quantity;
+                bucket.trade_count += 1;
+            }
+            _ => self.buckets.push_back(TickerBucket {
+                start,
+                open: price,
+                high: price,
+                low: price,
+                volume: quantity,
+                trade_count: 1,
+            }),
+        }
+
+        self.volume += quantity;
+        self.trade_count += 1;
+        self.high = Some(self.high.map_or(price, |high| high.max(price)));
+        self.low = Some(self.low.map_or(price, |low| low.min(price)));
+        self.last_price = Some(price);
+    }
+
+    /// 윈도를 벗어난 버킷 제거
+    fn evict(&mut self, now: u64) {
+        let cutoff = now.saturating_sub(TICKER_WINDOW_SECS);
+        let mut extremes_evicted = false;
+
+        while let Some(bucket) = self.buckets.front() {
+            if bucket.start + BUCKET_SECS > cutoff {
+                break;
+            }
+            extremes_evicted |= Some(bucket.high) == self.high || Some(bucket.low) == self.low;
+            self.volume -= bucket.volume;
+            self.trade_count -= bucket.trade_count;
+            self.buckets.pop_front();
+        }
+
+        if extremes_evicted {
+            self.high = self.buckets.iter().map(|b| b.high).max();
+            self.low = self.buckets.iter().map(|b| b.low).min();
+        }
+    }
+
+    fn snapshot(&self, symbol: &str) -> TickerData {
+        let open_price_24h = self.buckets.front().map(|b| b.open);
+        let price_change_pct_24h = match (open_price_24h, self.last_price) {
+            (Some(open), Some(last)) if open > 0 => Some((last as f64 - open as f64) / open as f64 * 100.0),
+            _ => None,
+        };
+
+        TickerData {
+            symbol: symbol.to_string(),
+            last_price: self.last_price,
+            open_price_24h,
+            high_price_24h: self.high,
+            low_price_24h: self.low,
+            volume_24h: self.volume,
+            trade_count_24h: self.trade_count,
+            price_change_pct_24h,
+        }
+    }
+}
+
+/// 전 심볼 티커 집계기
+#[derive(Debug, Default)]
+pub struct TickerAggregator {
+    tickers: HashMap<String, RollingTicker>,
+}
+
+impl TickerAggregator {
+    /// 새 집계기 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 체결 없이도 티커에 나타날 심볼 등록
+    pub fn register_symbols(&mut self, symbols: &[String]) {
+        for symbol in symbols {
+            self.tickers.entry(symbol.clone()).or_default();
+        }
+    }
+
+    /// 체결 반영 (`timestamp`: Unix 타임스탬프, 초)
+    pub fn record_trade(&mut self, symbol: &str, price: u64, quantity: u64, timestamp: u64) {
+        self.tickers
+            .entry(symbol.to_string())
+            .or_default()
+            .record(price, quantity, timestamp);
+    }
+
+    /// 심볼 티커 조회
+    pub fn ticker(&mut self, symbol: &str, now: u64) -> Option<TickerData> {
+        let ticker = self.tickers.get_mut(symbol)?;
+        ticker.evict(now);
+        Some(ticker.snapshot(symbol))
+    }
+
+    /// 전 심볼 티커 조회 (심볼순)
+    pub fn all_tickers(&mut self, now: u64) -> Vec<TickerData> {
+        let mut tickers: Vec<TickerData> = self
+            .tickers
+            .iter_mut()
+            .map(|(symbol, ticker)| {
+                ticker.evict(now);
+                ticker.snapshot(symbol)
+            })
+            .collect();
+        tickers.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        tickers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T0: u64 = 1_700_000_040;
+
+    #[test]
+    fn test_rolling_stats() {
+        let mut aggregator = TickerAggregator::new();
+        aggregator.register_symbols(&["ETH-KRW".to_string()]);
+
+        aggregator.record_trade("BTC-KRW", 100, 2, T0);
+        aggregator.record_trade("BTC-KRW", 120, 1, T0 + 30);
+        aggregator.record_trade("BTC-KRW", 90, 3, T0 + 3600);
+        aggregator.record_trade("BTC-KRW", 110, 1, T0 + 7200);
+
+        let tickers = aggregator.all_tickers(T0 + 7200);
+        assert_eq!(tickers.iter().map(|t| t.symbol.as_str()).collect::<Vec<_>>(), vec!["BTC-KRW", "ETH-KRW"]);
+
+        let btc = &tickers[0];
+        assert_eq!(btc.last_price, Some(110));
+        assert_eq!(btc.open_price_24h, Some(100));
+        assert_eq!(btc.high_price_24h, Some(120));
+        assert_eq!(btc.low_price_24h, Some(90));
+        assert_eq!(btc.volume_24h, 7);
+        assert_eq!(btc.trade_count_24h, 4);
+        assert!((btc.price_change_pct_24h.unwrap() - 10.0).abs() < 1e-9);
+
+        let eth = &tickers[1];
+        assert_eq!(eth.last_price, None);
+        assert_eq!(eth.volume_24h, 0);
+    }
+
+    #[test]
+    fn test_window_eviction_recomputes_extremes() {
+        let mut aggregator = TickerAggregator::new();
+        aggregator.record_trade("BTC-KRW", 120, 5, T0);
+        aggregator.record_trade("BTC-KRW", 100, 1, T0 + 3600);
+        aggregator.record_trade("BTC-KRW", 105, 2, T0 + 7200);
+
+        // 첫 버킷이 빠지면 고가/시가/거래량이 남은 버킷 기준으로 바뀜
+        let btc = aggregator.ticker("BTC-KRW", T0 + TICKER_WINDOW_SECS + 60).unwrap();
+        assert_eq!(btc.open_price_24h, Some(100));
+        assert_eq!(btc.high_price_24h, Some(105));
+        assert_eq!(btc.low_price_24h, Some(100));
+        assert_eq!(btc.volume_24h, 3);
+        assert_eq!(btc.trade_count_24h, 2);
+
+        // 모두 빠져도 마지막 체결가는 유지
+        let btc = aggregator.ticker("BTC-KRW", T0 + 2 * TICKER_WINDOW_SECS).unwrap();
+        assert_eq!(btc.last_price, Some(105));
+        assert_eq!(btc.volume_24h, 0);
+        assert_eq!(btc.high_price_24h, None);
+        assert_eq!(btc.price_change_pct_24h, None);
+    }
+}
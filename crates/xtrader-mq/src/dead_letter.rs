@@ -0,0 +1,527 @@
+//! Dead Letter Queue 독성 메시지 격리
+//!
+//! DLQ로 넘어온 메시지를 재시도해도 소용없는 독성 메시지(역직렬화 실패, 필수 필드 누락)와
+//! 일시적 실패로 나눕니다. 독성 메시지는 곧바로, 일시적 실패는 최대 재시도 후에도 실패하면
+//! 격리 저장소(`dlq_quarantine` 테이블)에 보관합니다. 관리자는 원본 본문을 확인한 뒤 메시지를
+//! 하나씩 원래 라우팅 키로 재발행하거나 폐기하며, 처리 내역은 감사 로그(`audit_logs`)에 남습니다.
+
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+use crate::rabbitmq_producer::{RabbitMQProducer, WebSocketNotificationMessage};
+use crate::store::{QuarantineRepository, QuarantinedMessageRecord};
+
+/// 감사 로그 엔티티 타입
+pub const DLQ_AUDIT_ENTITY: &str = "dlq_message";
+pub const DLQ_REQUEUED_EVENT: &str = "DLQ_MESSAGE_REQUEUED";
+pub const DLQ_DISCARDED_EVENT: &str = "DLQ_MESSAGE_DISCARDED";
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
+/// DLQ로 넘어온 메시지 (원본 본문 그대로)
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    /// 원래 라우팅 키
+    pub routing_key: String,
+    /// 원본 본문 (JSON)
+    pub payload: String,
+    /// 원래 Consumer가 남긴 실패 사유 (x-death 헤더 등)
+    pub error: Option<String>,
+}
+
+impl DeadLetter {
+    /// 알림 메시지로 DLQ 메시지 구성
+    pub fn from_message(message: &WebSocketNotificationMessage, error: Option<String>) -> Self {
+        Self {
+            routing_key: message.routing_key.clone(),
+            payload: serde_json::to_string(message).unwrap_or_default(),
+            error,
+        }
+    }
+}
+
+/// 격리 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum QuarantineReason {
+    /// 본문을 알림 메시지로 읽을 수 없음
+    Deserialization,
+    /// 필수 필드 누락/범위 밖 값
+    InvalidMessage,
+    /// 일시적 실패였지만 최대 재시도 후에도 실패
+    RetriesExhausted,
+}
+
+impl QuarantineReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuarantineReason::Deserialization => "deserialization",
+            QuarantineReason::InvalidMessage => "invalid_message",
+            QuarantineReason::RetriesExhausted => "retries_exhausted",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "deserialization" => Some(QuarantineReason::Deserialization),
+            "invalid_message" => Some(QuarantineReason::InvalidMessage),
+            "retries_exhausted" => Some(QuarantineReason::RetriesExhausted),
+            _ => None,
+        }
+    }
+}
+
+/// DLQ 메시지 분류 결과
+#[derive(Debug)]
+pub enum Classification {
+    /// 일시적 실패 (재시도 대상)
+    Transient(WebSocketNotificationMessage),
+    /// 독성 메시지 (재시도 없이 격리)
+    Poison { reason: QuarantineReason, detail: String },
+}
+
+/// DLQ 메시지 분류
+///
+/// 본문을 읽을 수 없거나 필수 필드가 비어 있으면 몇 번을 재시도해도 같은 결과이므로 독성으로 봅니다.
+pub fn classify(letter: &DeadLetter) -> Classification {
+    let message = match serde_json::from_str::<WebSocketNotificationMessage>(&letter.payload) {
+        Ok(message) => message,
+        Err(e) => {
+            return Classification::Poison {
+                reason: QuarantineReason::Deserialization,
+                detail: format!("역직렬화 실패: {}", e),
+            }
+        }
+    };
+
+    let invalid = if message.message_id.trim().is_empty() {
+        Some("message_id가 비어 있습니다".to_string())
+    } else if message.routing_key.trim().is_empty() {
+        Some("routing_key가 비어 있습니다".to_string())
+    } else if message.priority > 9 {
+        Some(format!("priority 범위(0-9) 밖: {}", message.priority))
+    } else {
+        None
+    };
+    match invalid {
+        Some(detail) => Classification::Poison { reason: QuarantineReason::InvalidMessage, detail },
+        None => Classification::Transient(message),
+    }
+}
+
+/// 격리 메시지 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum QuarantineStatus {
+    /// 관리자 처리 대기
+    Quarantined,
+    /// 원래 라우팅 키로 재발행됨
+    Requeued,
+    /// 폐기됨
+    Discarded,
+}
+
+impl QuarantineStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuarantineStatus::Quarantined => "quarantined",
+            QuarantineStatus::Requeued => "requeued",
+            QuarantineStatus::Discarded => "discarded",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "quarantined" => Some(QuarantineStatus::Quarantined),
+            "requeued" => Some(QuarantineStatus::Requeued),
+            "discarded" => Some(QuarantineStatus::Discarded),
+            _ => None,
+        }
+    }
+}
+
+/// 격리된 DLQ 메시지
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct QuarantinedMessage {
+    pub id: String,
+    /// 원본 메시지 ID (역직렬화 실패 시 없음)
+    pub message_id: Option<String>,
+    pub routing_key: String,
+    /// 원본 본문
+    pub payload: String,
+    pub reason: QuarantineReason,
+    /// 분류/재시도 실패 내용
+    pub error: String,
+    /// 격리 전 재시도 횟수
+    pub attempts: u32,
+    pub status: QuarantineStatus,
+    /// 격리 시각 (밀리초)
+    pub quarantined_at: u64,
+    pub resolved_by: Option<String>,
+    pub resolved_at: Option<u64>,
+}
+
+impl QuarantinedMessage {
+    fn from_record(record: QuarantinedMessageRecord) -> Result<Self, QuarantineError> {
+        Ok(Self {
+            reason: QuarantineReason::from_name(&record.reason)
+                .ok_or_else(|| QuarantineError::InvalidRecord(format!("{} reason: {}", record.id, record.reason)))?,
+            status: QuarantineStatus::from_name(&record.status)
+                .ok_or_else(|| QuarantineError::InvalidRecord(format!("{} status: {}", record.id, record.status)))?,
+            id: record.id,
+            message_id: record.message_id,
+            routing_key: record.routing_key,
+            payload: record.payload,
+            error: record.error,
+            attempts: record.attempts as u32,
+            quarantined_at: record.quarantined_at as u64,
+            resolved_by: record.resolved_by,
+            resolved_at: record.resolved_at.map(|t| t as u64),
+        })
+    }
+
+    fn to_record(&self) -> QuarantinedMessageRecord {
+        QuarantinedMessageRecord {
+            id: self.id.clone(),
+            message_id: self.message_id.clone(),
+            routing_key: self.routing_key.clone(),
+            payload: self.payload.clone(),
+            reason: self.reason.as_str().to_string(),
+            error: self.error.clone(),
+            attempts: self.attempts as i64,
+            status: self.status.as_str().to_string(),
+            quarantined_at: self.quarantined_at as i64,
+            resolved_by: self.resolved_by.clone(),
+            resolved_at: self.resolved_at.map(|t| t as i64),
+        }
+    }
+}
+
+/// DLQ 격리 오류
+#[derive(Debug, thiserror::Error)]
+pub enum QuarantineError {
+    #[error("격리 메시지를 찾을 수 없습니다: {0}")]
+    NotFound(String),
+    #[error("이미 처리된 격리 메시지입니다: {0}")]
+    AlreadyResolved(String),
+    #[error("재발행할 수 없는 메시지입니다 ({0}): {1}")]
+    NotRequeueable(String, String),
+    #[error("RabbitMQ Producer가 없어 재발행할 수 없습니다")]
+    ProducerUnavailable,
+    #[error("재발행 실패: {0}")]
+    Publish(String),
+    #[error("격리 메시지 기록 오류: {0}")]
+    InvalidRecord(String),
+    #[error("격리 메시지 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 격리 메시지 재발행/폐기 내역을 남길 감사 로그 (서버의 `audit_logs` 저장소가 구현)
+#[async_trait::async_trait]
+pub trait AuditSink: Send + Sync {
+    async fn log(
+        &self,
+        event_type: &str,
+        entity_type: &str,
+        entity_id: &str,
+        details: Option<&str>,
+    ) -> Result<(), sqlx::Error>;
+}
+
+/// DLQ 격리 저장소
+pub struct QuarantineStore {
+    repository: QuarantineRepository,
+    audit: Arc<dyn AuditSink>,
+    producer: Option<Arc<RabbitMQProducer>>,
+}
+
+impl QuarantineStore {
+    pub fn new(pool: SqlitePool, audit: Arc<dyn AuditSink>) -> Self {
+        Self {
+            repository: QuarantineRepository::new(pool),
+            audit,
+            producer: None,
+        }
+    }
+
+    /// 재발행에 쓸 Producer 설정
+    pub fn with_producer(mut self, producer: Arc<RabbitMQProducer>) -> Self {
+        self.producer = Some(producer);
+        self
+    }
+
+    /// DLQ 메시지 격리
+    pub async fn quarantine(
+        &self,
+        letter: &DeadLetter,
+        reason: QuarantineReason,
+        error: &str,
+        attempts: u32,
+    ) -> Result<QuarantinedMessage, QuarantineError> {
+        let message_id = serde_json::from_str::<serde_json::Value>(&letter.payload)
+            .ok()
+            .and_then(|value| value.get("message_id").and_then(|id| id.as_str()).map(str::to_string));
+        let message = QuarantinedMessage {
+            id: uuid::Uuid::new_v4().to_string(),
+            message_id,
+            routing_key: letter.routing_key.clone(),
+            payload: letter.payload.clone(),
+            reason,
+            error: error.to_string(),
+            attempts,
+            status: QuarantineStatus::Quarantined,
+            quarantined_at: now_millis(),
+            resolved_by: None,
+            resolved_at: None,
+        };
+        self.repository.insert(&message.to_record()).await?;
+
+        warn!(
+            "DLQ 메시지 격리: {} ({}, {}, 재시도 {}회): {}",
+            message.id,
+            message.routing_key,
+            reason.as_str(),
+            attempts,
+            error
+        );
+        Ok(message)
+    }
+
+    /// 격리 메시지 조회
+    pub async fn get(&self, id: &str) -> Result<QuarantinedMessage, QuarantineError> {
+        let record = self
+            .repository
+            .find(id)
+            .await?
+            .ok_or_else(|| QuarantineError::NotFound(id.to_string()))?;
+        QuarantinedMessage::from_record(record)
+    }
+
+    /// 상태별 격리 메시지 목록 (최신순)
+    pub async fn list(&self, status: QuarantineStatus, limit: i64) -> Result<Vec<QuarantinedMessage>, QuarantineError> {
+        self.repository
+            .find_by_status(status.as_str(), limit)
+            .await?
+            .into_iter()
+            .map(QuarantinedMessage::from_record)
+            .collect()
+    }
+
+    /// 격리 메시지를 원래 라우팅 키로 재발행
+    ///
+    /// 본문이 여전히 독성이면 재발행하지 않습니다 (폐기만 가능).
+    pub async fn requeue(&self, id: &str, actor: &str) -> Result<QuarantinedMessage, QuarantineError> {
+        let message = self.pending(id).await?;
+        let letter = DeadLetter { routing_key: message.routing_key.clone(), payload: message.payload.clone(), error: None };
+        let notification = match classify(&letter) {
+            Classification::Transient(notification) => notification,
+            Classification::Poison { reason, detail } => {
+                return Err(QuarantineError::NotRequeueable(reason.as_str().to_string(), detail))
+            }
+        };
+        let producer = self.producer.as_ref().ok_or(QuarantineError::ProducerUnavailable)?;
+        producer
+            .publish_notification(&notification)
+            .await
+            .map_err(|e| QuarantineError::Publish(e.to_string()))?;
+
+        self.resolve(message, QuarantineStatus::Requeued, DLQ_REQUEUED_EVENT, actor).await
+    }
+
+    /// 격리 메시지 폐기
+    pub async fn discard(&self, id: &str, actor: &str) -> Result<QuarantinedMessage, QuarantineError> {
+        let message = self.pending(id).await?;
+        self.resolve(message, QuarantineStatus::Discarded, DLQ_DISCARDED_EVENT, actor).await
+    }
+
+    /// 처리 대기 중인 격리 메시지
+    async fn pending(&self, id: &str) -> Result<QuarantinedMessage, QuarantineError> {
+        let message = self.get(id).await?;
+        if message.status != QuarantineStatus::Quarantined {
+            return Err(QuarantineError::AlreadyResolved(id.to_string()));
+        }
+        Ok(message)
+    }
+
+    async fn resolve(
+        &self,
+        mut message: QuarantinedMessage,
+        status: QuarantineStatus,
+        event: &str,
+        actor: &str,
+    ) -> Result<QuarantinedMessage, QuarantineError> {
+        let now = now_millis();
+        // 동시에 들어온 요청 중 하나만 반영
+        if !self.repository.resolve(&message.id, status.as_str(), actor, now as i64).await? {
+            return Err(QuarantineError::AlreadyResolved(message.id));
+        }
+        message.status = status;
+        message.resolved_by = Some(actor.to_string());
+        message.resolved_at = Some(now);
+
+        let details = serde_json::json!({
+            "actor": actor,
+            "message_id": message.message_id,
+            "routing_key": message.routing_key,
+            "reason": message.reason,
+        })
+        .to_string();
+        self.audit.log(event, DLQ_AUDIT_ENTITY, &message.id, Some(&details)).await?;
+
+        info!("DLQ 격리 메시지 {}: {} (by {})", status.as_str(), message.id, actor);
+        Ok(message)
+    }
+}
+
+/// 감사 로그를 메모리에 모으는 테스트용 저장소 (이벤트, 엔티티 타입, 엔티티 ID)
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct MemoryAuditSink {
+    entries: std::sync::Mutex<Vec<(String, String, String)>>,
+}
+
+#[cfg(test)]
+impl MemoryAuditSink {
+    pub(crate) fn entries(&self) -> Vec<(String, String, String)> {
+        self.entries.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl AuditSink for MemoryAuditSink {
+    async fn log(
+        &self,
+        event_type: &str,
+        entity_type: &str,
+        entity_id: &str,
+        _details: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        self.entries
+            .lock()
+            .unwrap()
+            .push((event_type.to_string(), entity_type.to_string(), entity_id.to_string()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::store::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    fn notification(message_id: &str, priority: u8) -> WebSocketNotificationMessage {
+        WebSocketNotificationMessage {
+            message_id: message_id.to_string(),
+            routing_key: "execution.BTC-KRW".to_string(),
+            message_type: "execution".to_string(),
+            symbol: Some("BTC-KRW".to_string()),
+            user_id: None,
+            data: serde_json::json!({ "price": 50000 }),
+            timestamp: 1_700_000_000_000,
+            priority,
+        }
+    }
+
+    fn garbage() -> DeadLetter {
+        DeadLetter {
+            routing_key: "execution.BTC-KRW".to_string(),
+            payload: "{not json".to_string(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        assert!(matches!(
+            classify(&DeadLetter::from_message(&notification("msg-1", 1), None)),
+            Classification::Transient(message) if message.message_id == "msg-1"
+        ));
+        assert!(matches!(
+            classify(&garbage()),
+            Classification::Poison { reason: QuarantineReason::Deserialization, .. }
+        ));
+        assert!(matches!(
+            classify(&DeadLetter::from_message(&notification("", 1), None)),
+            Classification::Poison { reason: QuarantineReason::InvalidMessage, .. }
+        ));
+        assert!(matches!(
+            classify(&DeadLetter::from_message(&notification("msg-2", 12), None)),
+            Classification::Poison { reason: QuarantineReason::InvalidMessage, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_requeue_and_discard() {
+        let pool = test_pool().await;
+        let producer = Arc::new(RabbitMQProducer::new("amqp://localhost:5672", "websocket_notifications").await.unwrap());
+        let audit = Arc::new(MemoryAuditSink::default());
+        let store = QuarantineStore::new(pool, audit.clone()).with_producer(producer.clone());
+
+        let exhausted = DeadLetter::from_message(&notification("msg-1", 1), Some("consumer timeout".to_string()));
+        let retried = store
+            .quarantine(&exhausted, QuarantineReason::RetriesExhausted, "최대 재시도 횟수 초과: 3", 3)
+            .await
+            .unwrap();
+        let poison = store
+            .quarantine(&garbage(), QuarantineReason::Deserialization, "역직렬화 실패", 0)
+            .await
+            .unwrap();
+        assert_eq!(retried.message_id.as_deref(), Some("msg-1"));
+        assert_eq!(poison.message_id, None);
+        assert_eq!(store.list(QuarantineStatus::Quarantined, 10).await.unwrap().len(), 2);
+
+        // 독성 본문은 재발행 불가, 폐기만 가능
+        assert!(matches!(store.requeue(&poison.id, "ops").await, Err(QuarantineError::NotRequeueable(..))));
+        let discarded = store.discard(&poison.id, "ops").await.unwrap();
+        assert_eq!(discarded.status, QuarantineStatus::Discarded);
+        assert_eq!(discarded.resolved_by.as_deref(), Some("ops"));
+
+        let requeued = store.requeue(&retried.id, "ops").await.unwrap();
+        assert_eq!(requeued.status, QuarantineStatus::Requeued);
+        assert_eq!(producer.get_producer_stats().await.unwrap().messages_sent, 1);
+        assert!(matches!(store.requeue(&retried.id, "ops").await, Err(QuarantineError::AlreadyResolved(_))));
+        assert!(matches!(store.discard("missing", "ops").await, Err(QuarantineError::NotFound(_))));
+
+        let stored = store.get(&retried.id).await.unwrap();
+        assert_eq!((stored.status, stored.attempts), (QuarantineStatus::Requeued, 3));
+        assert!(store.list(QuarantineStatus::Quarantined, 10).await.unwrap().is_empty());
+        assert_eq!(store.list(QuarantineStatus::Discarded, 10).await.unwrap()[0].id, poison.id);
+
+        let entries = audit.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].0, DLQ_REQUEUED_EVENT);
+        assert_eq!((entries[1].1.as_str(), entries[1].2.as_str()), (DLQ_AUDIT_ENTITY, retried.id.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_requeue_without_producer() {
+        let store = QuarantineStore::new(test_pool().await, Arc::new(MemoryAuditSink::default()));
+        let letter = DeadLetter::from_message(&notification("msg-1", 1), None);
+        let message = store.quarantine(&letter, QuarantineReason::RetriesExhausted, "재시도 초과", 3).await.unwrap();
+
+        assert!(matches!(store.requeue(&message.id, "ops").await, Err(QuarantineError::ProducerUnavailable)));
+        // 재발행하지 못했으면 격리 상태 유지
+        assert_eq!(store.get(&message.id).await.unwrap().status, QuarantineStatus::Quarantined);
+    }
+}
@@ -0,0 +1,32 @@
+//! MQ 게이지 발행 대상
+//!
+//! Consumer 지연, Pending 회수, 발행 재시도 카운터를 게이지로 내보낼 곳입니다. 서버의
+//! `MetricsCollector`가 구현하므로 MQ 크레이트는 서버 메트릭 저장소에 의존하지 않습니다.
+
+/// 게이지 값을 받는 메트릭 저장소
+#[async_trait::async_trait]
+pub trait GaugeSink: Send + Sync {
+    async fn set_gauge(&self, name: &str, value: u64);
+}
+
+/// 게이지를 메모리에 모으는 테스트용 저장소
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct MemoryGaugeSink {
+    gauges: std::sync::Mutex<std::collections::HashMap<String, u64>>,
+}
+
+#[cfg(test)]
+impl MemoryGaugeSink {
+    pub(crate) fn get(&self, name: &str) -> Option<u64> {
+        self.gauges.lock().unwrap().get(name).copied()
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl GaugeSink for MemoryGaugeSink {
+    async fn set_gauge(&self, name: &str, value: u64) {
+        self.gauges.lock().unwrap().insert(name.to_string(), value);
+    }
+}
@@ -0,0 +1,148 @@
+//! 일관 해시 링
+//!
+//! 서버마다 가상 노드를 링에 여러 개 올려 키(클라이언트 ID)를 시계 방향으로 가장 가까운 서버에 배정합니다.
+//! 서버가 추가/제거되면 그 서버와 맞닿은 구간의 키만 옮겨지므로 재연결이 최소화됩니다.
+//! 해시는 프로세스/버전이 달라도 같은 배정이 나오도록 FNV-1a(64비트)를 씁니다.
+
+use std::collections::BTreeMap;
+
+/// 서버당 기본 가상 노드 수
+pub const DEFAULT_VIRTUAL_NODES: usize = 160;
+
+/// 일관 해시 링
+#[derive(Debug, Clone)]
+pub struct HashRing {
+    virtual_nodes: usize,
+    ring: BTreeMap<u64, String>,
+}
+
+impl Default for HashRing {
+    fn default() -> Self {
+        Self::new(DEFAULT_VIRTUAL_NODES)
+    }
+}
+
+impl HashRing {
+    pub fn new(virtual_nodes: usize) -> Self {
+        Self {
+            virtual_nodes: virtual_nodes.max(1),
+            ring: BTreeMap::new(),
+        }
+    }
+
+    /// 서버 추가 (이미 있으면 무시)
+    pub fn add(&mut self, server_id: &str) {
+        for replica in 0..self.virtual_nodes {
+            self.ring
+                .entry(hash(format!("{}#{}", server_id, replica).as_bytes()))
+                .or_insert_with(|| server_id.to_string());
+        }
+    }
+
+    /// 서버 제거
+    pub fn remove(&mut self, server_id: &str) {
+        self.ring.retain(|_, server| server != server_id);
+    }
+
+    pub fn contains(&self, server_id: &str) -> bool {
+        self.ring.values().any(|server| server == server_id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+
+    /// 키를 맡을 서버
+    pub fn get(&self, key: &str) -> Option<&str> {
+        let point = hash(key.as_bytes());
+        self.ring
+            .range(point..)
+            .chain(self.ring.range(..point))
+            .next()
+            .map(|(_, server)| server.as_str())
+    }
+
+    /// 키를 맡을 서버 후보 (우선순위순, 중복 없음)
+    ///
+    /// 첫 서버가 받을 수 없을 때 링에서 다음 서버로 넘길 때 씁니다.
+    pub fn candidates(&self, key: &str) -> Vec<&str> {
+        let point = hash(key.as_bytes());
+        let mut servers: Vec<&str> = Vec::new();
+        for (_, server) in self.ring.range(point..).chain(self.ring.range(..point)) {
+            if !servers.contains(&server.as_str()) {
+                servers.push(server);
+            }
+        }
+        servers
+    }
+}
+
+/// FNV-1a 64비트
+fn hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    // 비슷한 키(client-1, client-2)가 링에 고르게 퍼지도록 한 번 더 섞음
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^ (hash >> 33)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn ring(servers: &[&str]) -> HashRing {
+        let mut ring = HashRing::default();
+        for server in servers {
+            ring.add(server);
+        }
+        ring
+    }
+
+    #[test]
+    fn test_keys_spread_across_servers() {
+        let ring = ring(&["ws-1", "ws-2", "ws-3"]);
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for i in 0..3000 {
+            *counts.entry(ring.get(&format!("client-{}", i)).unwrap()).or_default() += 1;
+        }
+
+        assert_eq!(counts.len(), 3);
+        assert!(counts.values().all(|&count| (700..=1300).contains(&count)), "{:?}", counts);
+        assert_eq!(ring.candidates("client-1").len(), 3);
+        assert_eq!(ring.candidates("client-1")[0], ring.get("client-1").unwrap());
+        assert!(HashRing::default().get("client-1").is_none());
+    }
+
+    #[test]
+    fn test_membership_change_moves_only_affected_keys() {
+        let before = ring(&["ws-1", "ws-2", "ws-3"]);
+        let mut joined = before.clone();
+        joined.add("ws-4");
+
+        for i in 0..3000 {
+            let key = format!("client-{}", i);
+            let (old, new) = (before.get(&key).unwrap(), joined.get(&key).unwrap());
+            // 새 서버로 가는 키만 옮겨짐
+            assert!(old == new || new == "ws-4");
+        }
+
+        let mut left = before.clone();
+        left.remove("ws-2");
+        assert!(!left.contains("ws-2"));
+        for i in 0..3000 {
+            let key = format!("client-{}", i);
+            let old = before.get(&key).unwrap();
+            // 빠진 서버의 키만 옮겨지고, 링의 다음 후보로 감
+            if old != "ws-2" {
+                assert_eq!(left.get(&key).unwrap(), old);
+            } else {
+                assert_eq!(left.get(&key).unwrap(), before.candidates(&key)[1]);
+            }
+        }
+    }
+}
@@ -3,14 +3,12 @@
 //! 이 모듈은 Redis, Kafka, RabbitMQ의 연결 상태를 모니터링하고
 //! 장애를 감지하여 자동 복구를 수행하는 기능을 제공합니다.
 
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use log::{info, error, warn, debug};
 use tokio::time::{sleep, interval};
-use crate::mq::{LocalBackupQueue, MQType, BackupMessageBuilder};
+use crate::{LocalBackupQueue, MQType, BackupMessageBuilder};
 
 /// MQ 연결 상태
 #[derive(Debug, Clone, PartialEq)]
@@ -259,6 +257,7 @@ impl MQHealthMonitor {
     }
 
     /// 헬스 상태 업데이트
+    #[allow(clippy::too_many_arguments)]
     async fn update_health_status<F>(
         health_status: &Arc<RwLock<MQHealthStatus>>,
         get_status: F,
@@ -277,7 +276,7 @@ impl MQHealthMonitor {
             .as_millis() as u64;
 
         let mut mq_status = health_status.write().await;
-        let status = get_status(&mut *mq_status);
+        let status = get_status(&mut mq_status);
 
         status.last_check_time = current_time;
         status.total_checks += 1;
@@ -0,0 +1,381 @@
+//! Kafka 호가창 업데이트 배치와 압축
+//!
+//! 호가창 업데이트는 체결보다 훨씬 자주 나오므로 Producer가 심볼별로 모아 한 레코드 배치로 보냅니다.
+//! 배치는 `max_messages`개가 차거나 첫 메시지 후 `linger`가 지나면 닫히고, 메시지 배열을
+//! `wire_format`(기본 rkyv)으로 직렬화한 뒤 `compression`으로 압축해 심볼 파티션에 기록합니다.
+//! Consumer는 배치를 풀어 메시지 단위로 처리합니다.
+//!
+//! 압축 코덱은 Kafka `compression.type` 이름을 따르며, gzip(flate2), snappy(snap), lz4(lz4_flex, LZ4 프레임),
+//! zstd(zstd)를 지원합니다.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::kafka_producer::{KafkaError, OrderBookUpdateMessage};
+use crate::wire_format::{self, WireFormat};
+
+/// 압축 해제 후 배치 최대 크기 (손상되거나 조작된 배치가 메모리를 고갈시키지 않도록)
+pub const MAX_DECOMPRESSED_BYTES: usize = 16 * 1024 * 1024;
+
+/// zstd 압축 레벨 (librdkafka 기본값과 같은 3)
+const ZSTD_LEVEL: i32 = 3;
+
+/// 레코드 배치 압축 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionType {
+    None,
+    Gzip,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+impl CompressionType {
+    /// Kafka `compression.type` 이름 파싱
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "none" => Ok(CompressionType::None),
+            "gzip" => Ok(CompressionType::Gzip),
+            "snappy" => Ok(CompressionType::Snappy),
+            "lz4" => Ok(CompressionType::Lz4),
+            "zstd" => Ok(CompressionType::Zstd),
+            _ => Err(format!("알 수 없는 압축 방식: {} (none, gzip, snappy, lz4, zstd 중 선택)", name)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompressionType::None => "none",
+            CompressionType::Gzip => "gzip",
+            CompressionType::Snappy => "snappy",
+            CompressionType::Lz4 => "lz4",
+            CompressionType::Zstd => "zstd",
+        }
+    }
+
+    pub fn compress(&self, data: &[u8]) -> Result<Vec<u8>, KafkaError> {
+        let error = |e: std::io::Error| KafkaError::SerializationError(format!("{} 압축 실패: {}", self.as_str(), e));
+        match self {
+            CompressionType::None => Ok(data.to_vec()),
+            CompressionType::Gzip => {
+                let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+                encoder.write_all(data).map_err(error)?;
+                encoder.finish().map_err(error)
+            }
+            CompressionType::Snappy => snap::raw::Encoder::new()
+                .compress_vec(data)
+                .map_err(|e| error(e.into())),
+            CompressionType::Lz4 => {
+                let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+                encoder.write_all(data).map_err(error)?;
+                encoder.finish().map_err(|e| error(e.into()))
+            }
+            CompressionType::Zstd => zstd::bulk::compress(data, ZSTD_LEVEL).map_err(error),
+        }
+    }
+
+    /// 압축 해제 (`MAX_DECOMPRESSED_BYTES`를 넘는 배치는 풀기 전에 거부)
+    pub fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, KafkaError> {
+        let error = |e: std::io::Error| KafkaError::SerializationError(format!("{} 압축 해제 실패: {}", self.as_str(), e));
+        let too_large = || KafkaError::SerializationError(format!(
+            "{} 압축 해제 실패: 배치가 {}바이트를 넘습니다",
+            self.as_str(),
+            MAX_DECOMPRESSED_BYTES
+        ));
+        match self {
+            CompressionType::None if data.len() > MAX_DECOMPRESSED_BYTES => Err(too_large()),
+            CompressionType::None => Ok(data.to_vec()),
+            CompressionType::Gzip => {
+                read_limited(flate2::read::GzDecoder::new(data)).map_err(error)?.ok_or_else(too_large)
+            }
+            CompressionType::Lz4 => {
+                read_limited(lz4_flex::frame::FrameDecoder::new(data)).map_err(error)?.ok_or_else(too_large)
+            }
+            CompressionType::Zstd => {
+                let decoder = zstd::stream::read::Decoder::new(data).map_err(error)?;
+                read_limited(decoder).map_err(error)?.ok_or_else(too_large)
+            }
+            CompressionType::Snappy => {
+                // 헤더의 원본 길이만큼 먼저 할당하므로 길이부터 확인
+                let length = snap::raw::decompress_len(data).map_err(|e| error(e.into()))?;
+                if length > MAX_DECOMPRESSED_BYTES {
+                    return Err(too_large());
+                }
+                snap::raw::Decoder::new()
+                    .decompress_vec(data)
+                    .map_err(|e| error(e.into()))
+            }
+        }
+    }
+}
+
+/// 스트림 압축 해제 (`MAX_DECOMPRESSED_BYTES`를 넘으면 그 이상 읽지 않고 None)
+fn read_limited(reader: impl Read) -> std::io::Result<Option<Vec<u8>>> {
+    let mut decoded = Vec::new();
+    reader.take(MAX_DECOMPRESSED_BYTES as u64 + 1).read_to_end(&mut decoded)?;
+    Ok((decoded.len() <= MAX_DECOMPRESSED_BYTES).then_some(decoded))
+}
+
+/// 호가창 업데이트 배치 설정 (Kafka `batch.size`/`linger.ms`/`compression.type`에 해당)
+#[derive(Debug, Clone, PartialEq)]
+pub struct KafkaBatchConfig {
+    /// 배치 하나의 최대 메시지 수
+    pub max_messages: usize,
+    /// 배치의 첫 메시지 후 최대 대기 시간
+    pub linger: Duration,
+    pub compression: CompressionType,
+    /// 배치 직렬화 형식
+    pub wire_format: WireFormat,
+}
+
+impl KafkaBatchConfig {
+    /// 업데이트마다 비압축 레코드 하나 (Kafka 기본값 `linger.ms=0`, `compression.type=none`)
+    pub fn unbatched() -> Self {
+        Self {
+            max_messages: 1,
+            linger: Duration::ZERO,
+            compression: CompressionType::None,
+            wire_format: WireFormat::default(),
+        }
+    }
+}
+
+impl Default for KafkaBatchConfig {
+    fn default() -> Self {
+        Self {
+            max_messages: 500,
+            linger: Duration::from_millis(20),
+            compression: CompressionType::Snappy,
+            wire_format: WireFormat::default(),
+        }
+    }
+}
+
+/// 압축된 호가창 업데이트 배치 (파티션 로그의 레코드 하나)
+#[derive(Debug, Clone)]
+pub struct RecordBatch {
+    pub symbol: String,
+    pub compression: CompressionType,
+    pub wire_format: WireFormat,
+    pub message_count: usize,
+    /// 압축 전 직렬화 크기
+    pub uncompressed_bytes: usize,
+    pub payload: Vec<u8>,
+}
+
+impl RecordBatch {
+    /// 메시지 배열을 직렬화/압축해 배치 생성
+    pub fn encode(
+        symbol: &str,
+        messages: &[OrderBookUpdateMessage],
+        compression: CompressionType,
+        wire_format: WireFormat,
+    ) -> Result<Self, KafkaError> {
+        let serialized = wire_format::encode_slice(messages, wire_format).map_err(KafkaError::SerializationError)?;
+        Ok(Self {
+            symbol: symbol.to_string(),
+            compression,
+            wire_format,
+            message_count: messages.len(),
+            uncompressed_bytes: serialized.len(),
+            payload: compression.compress(&serialized)?,
+        })
+    }
+
+    /// 압축을 풀어 메시지 복원 (Consumer, 직렬화 형식은 페이로드로 판별)
+    pub fn decode(&self) -> Result<Vec<OrderBookUpdateMessage>, KafkaError> {
+        let serialized = self.compression.decompress(&self.payload)?;
+        wire_format::decode(&serialized).map_err(KafkaError::SerializationError)
+    }
+}
+
+struct PendingBatch {
+    opened_at: Instant,
+    messages: Vec<OrderBookUpdateMessage>,
+}
+
+/// 심볼별 호가창 업데이트 누적기
+pub struct BatchAccumulator {
+    config: KafkaBatchConfig,
+    pending: HashMap<String, PendingBatch>,
+}
+
+impl BatchAccumulator {
+    pub fn new(config: KafkaBatchConfig) -> Self {
+        Self {
+            config,
+            pending: HashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &KafkaBatchConfig {
+        &self.config
+    }
+
+    /// 메시지 추가 (배치가 가득 차면 닫힌 배치 반환)
+    pub fn push(&mut self, message: OrderBookUpdateMessage, now: Instant) -> Option<(String, Vec<OrderBookUpdateMessage>)> {
+        let symbol = message.symbol.clone();
+        let batch = self.pending.entry(symbol.clone()).or_insert_with(|| PendingBatch {
+            opened_at: now,
+            messages: Vec::new(),
+        });
+        batch.messages.push(message);
+        if batch.messages.len() < self.config.max_messages.max(1) {
+            return None;
+        }
+        self.pending.remove(&symbol).map(|batch| (symbol, batch.messages))
+    }
+
+    /// `linger`가 지난 배치 닫기
+    pub fn drain_expired(&mut self, now: Instant) -> Vec<(String, Vec<OrderBookUpdateMessage>)> {
+        let expired: Vec<String> = self
+            .pending
+            .iter()
+            .filter(|(_, batch)| now.duration_since(batch.opened_at) >= self.config.linger)
+            .map(|(symbol, _)| symbol.clone())
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|symbol| self.pending.remove(&symbol).map(|batch| (symbol, batch.messages)))
+            .collect()
+    }
+
+    /// 모든 배치 닫기 (종료 시)
+    pub fn drain_all(&mut self) -> Vec<(String, Vec<OrderBookUpdateMessage>)> {
+        self.pending.drain().map(|(symbol, batch)| (symbol, batch.messages)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(symbol: &str, sequence: u64) -> OrderBookUpdateMessage {
+        OrderBookUpdateMessage {
+            symbol: symbol.to_string(),
+            timestamp: 1_700_000_000_000 + sequence,
+            message_type: "orderbook_update".to_string(),
+            bids: vec![(50_000_000 - sequence % 10 * 1000, 1 + sequence % 7)],
+            asks: vec![(50_001_000 + sequence % 10 * 1000, 2 + sequence % 5)],
+            sequence,
+        }
+    }
+
+    #[test]
+    fn test_batches_by_symbol_and_linger() {
+        let mut accumulator = BatchAccumulator::new(KafkaBatchConfig {
+            max_messages: 3,
+            linger: Duration::from_millis(10),
+            compression: CompressionType::None,
+            wire_format: WireFormat::Rkyv,
+        });
+        let start = Instant::now();
+
+        assert!(accumulator.push(update("BTC-KRW", 1), start).is_none());
+        assert!(accumulator.push(update("ETH-KRW", 1), start).is_none());
+        assert!(accumulator.push(update("BTC-KRW", 2), start).is_none());
+        let (symbol, full) = accumulator.push(update("BTC-KRW", 3), start).unwrap();
+        assert_eq!(symbol, "BTC-KRW");
+        assert_eq!(full.iter().map(|m| m.sequence).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        // ETH 배치는 linger가 지나야 닫힘
+        assert!(accumulator.drain_expired(start + Duration::from_millis(5)).is_empty());
+        let expired = accumulator.drain_expired(start + Duration::from_millis(10));
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].0, "ETH-KRW");
+        assert!(accumulator.drain_all().is_empty());
+    }
+
+    #[test]
+    fn test_record_batch_round_trip_for_each_codec() {
+        let messages: Vec<_> = (0..200).map(|i| update("BTC-KRW", i)).collect();
+        for wire_format in [WireFormat::Json, WireFormat::Rkyv] {
+            for compression in [
+                CompressionType::None,
+                CompressionType::Gzip,
+                CompressionType::Snappy,
+                CompressionType::Lz4,
+                CompressionType::Zstd,
+            ] {
+                let batch = RecordBatch::encode("BTC-KRW", &messages, compression, wire_format).unwrap();
+                assert_eq!(batch.message_count, 200);
+                if compression != CompressionType::None {
+                    assert!(batch.payload.len() < batch.uncompressed_bytes / 2, "{:?} {:?}", wire_format, compression);
+                }
+                let decoded = batch.decode().unwrap();
+                assert_eq!(decoded.len(), 200);
+                assert_eq!(decoded[199].sequence, 199);
+                assert_eq!(decoded[5].bids, messages[5].bids);
+            }
+        }
+
+        assert_eq!(CompressionType::parse("Snappy"), Ok(CompressionType::Snappy));
+        assert_eq!(CompressionType::parse("lz4"), Ok(CompressionType::Lz4));
+        assert_eq!(CompressionType::parse("ZSTD"), Ok(CompressionType::Zstd));
+        assert!(CompressionType::parse("brotli").is_err());
+    }
+
+    #[test]
+    fn test_oversized_batch_rejected_before_decompressing() {
+        // 퍼징 회귀: 원본 길이를 4GB로 적은 snappy 헤더 (예전에는 그만큼 할당하다 메모리 부족으로 중단)
+        let forged = [0xff, 0xff, 0xff, 0xff, 0x0f, 0x00];
+        assert!(CompressionType::Snappy.decompress(&forged).is_err());
+
+        let bomb = CompressionType::Gzip.compress(&vec![0u8; MAX_DECOMPRESSED_BYTES + 1]).unwrap();
+        assert!(CompressionType::Gzip.decompress(&bomb).is_err());
+        let fits = CompressionType::Gzip.compress(&vec![0u8; 1024]).unwrap();
+        assert_eq!(CompressionType::Gzip.decompress(&fits).unwrap().len(), 1024);
+
+        for compression in [CompressionType::Lz4, CompressionType::Zstd] {
+            let bomb = compression.compress(&vec![0u8; MAX_DECOMPRESSED_BYTES + 1]).unwrap();
+            assert!(compression.decompress(&bomb).is_err(), "{:?}", compression);
+            assert!(compression.decompress(b"not a compressed batch").is_err(), "{:?}", compression);
+        }
+    }
+
+    /// 배치/압축 전후 처리량 비교
+    ///
+    /// `cargo test --release kafka_batch::tests::bench -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_batching_throughput() {
+        const MESSAGES: u64 = 200_000;
+        let messages: Vec<_> = (0..MESSAGES).map(|i| update("BTC-KRW", i)).collect();
+
+        let run = |name: &str, batch_size: usize, compression: CompressionType, wire_format: WireFormat| {
+            let started = Instant::now();
+            let mut bytes = 0;
+            let mut records = 0;
+            for chunk in messages.chunks(batch_size) {
+                records += 1;
+                let batch = RecordBatch::encode("BTC-KRW", chunk, compression, wire_format).unwrap();
+                bytes += batch.payload.len();
+                assert_eq!(batch.decode().unwrap().len(), chunk.len());
+            }
+            let elapsed = started.elapsed();
+            println!(
+                "{:<22} {:>10.0} msg/s  {:>8.1} bytes/msg  레코드 {:>6}개  ({:?})",
+                name,
+                MESSAGES as f64 / elapsed.as_secs_f64(),
+                bytes as f64 / MESSAGES as f64,
+                records,
+                elapsed
+            );
+        };
+
+        run("메시지별 (배치 없음)", 1, CompressionType::None, WireFormat::Json);
+        run("배치 500, 압축 없음", 500, CompressionType::None, WireFormat::Json);
+        run("배치 500, gzip", 500, CompressionType::Gzip, WireFormat::Json);
+        run("배치 500, snappy", 500, CompressionType::Snappy, WireFormat::Json);
+        run("배치 500, lz4", 500, CompressionType::Lz4, WireFormat::Json);
+        run("배치 500, zstd", 500, CompressionType::Zstd, WireFormat::Json);
+        run("배치 500, rkyv", 500, CompressionType::None, WireFormat::Rkyv);
+        run("배치 500, rkyv+snappy", 500, CompressionType::Snappy, WireFormat::Rkyv);
+        run("배치 500, rkyv+lz4", 500, CompressionType::Lz4, WireFormat::Rkyv);
+        run("배치 500, rkyv+zstd", 500, CompressionType::Zstd, WireFormat::Rkyv);
+    }
+}
@@ -0,0 +1,707 @@
+//! Apache Kafka Consumer 구현 (Mock 버전)
+//!
+//! 이 모듈은 Kafka Topic에서 메시지를 소비하는 기능을 Mock으로 구현합니다.
+//! 실제 Kafka 라이브러리 대신 로깅과 시뮬레이션을 사용합니다.
+//!
+//! Worker는 Mock 브로커(`KafkaProducer`)의 파티션 로그를 커밋된 오프셋부터 읽고,
+//! 메시지를 성공적으로 처리한 뒤에만 다음 오프셋을 수동 커밋합니다 (at-least-once).
+//! 커밋한 오프셋은 DB(`kafka_consumer_offsets`)에 저장되어 재시작하면 그 위치부터 이어 읽고,
+//! 파티션별 지연(로그 끝 오프셋 - 커밋 오프셋)은 `ConsumerLagRegistry`로 모아 메트릭으로 내보냅니다.
+//! 호가창 업데이트 배치 레코드는 압축을 풀어 메시지 단위로 처리하고, 모두 성공해야 오프셋을 넘깁니다.
+
+use serde::Serialize;
+use sqlx::sqlite::SqlitePool;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use log::{debug, info, error, warn};
+use crate::store::{ConsumerOffsetRecord, ConsumerOffsetRepository};
+use crate::kafka_producer::{KafkaProducer, MarketDataMessage, OrderBookUpdateMessage, PartitionRecord};
+use crate::gauge::GaugeSink;
+
+/// Kafka Consumer Worker (Mock 구현)
+pub struct KafkaConsumerWorker {
+    topic_name: String,
+    consumer_group: String,
+    worker_id: String,
+    batch_size: usize,
+    processing_interval_ms: u64,
+    messages_processed: Arc<Mutex<u64>>,
+    /// 메시지를 읽을 브로커와 오프셋 저장소 (없으면 읽을 메시지 없음)
+    source: Option<ConsumerSource>,
+    /// 파티션별 다음에 읽을 오프셋 (= 커밋된 오프셋)
+    offsets: Mutex<HashMap<u32, u64>>,
+}
+
+/// Consumer가 읽을 브로커, 오프셋 저장소, 지연 집계
+#[derive(Clone)]
+pub struct ConsumerSource {
+    broker: Arc<KafkaProducer>,
+    offset_store: Option<SqlitePool>,
+    lags: Option<Arc<ConsumerLagRegistry>>,
+}
+
+impl ConsumerSource {
+    pub fn new(broker: Arc<KafkaProducer>) -> Self {
+        Self {
+            broker,
+            offset_store: None,
+            lags: None,
+        }
+    }
+
+    /// 커밋한 오프셋을 DB에 저장 (재시작 시 이어 읽기)
+    pub fn with_offset_store(mut self, pool: SqlitePool) -> Self {
+        self.offset_store = Some(pool);
+        self
+    }
+
+    /// 파티션별 지연을 집계할 레지스트리
+    pub fn with_lag_registry(mut self, lags: Arc<ConsumerLagRegistry>) -> Self {
+        self.lags = Some(lags);
+        self
+    }
+}
+
+/// 파티션별 Consumer 지연
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartitionLag {
+    pub consumer_group: String,
+    pub topic: String,
+    pub partition: u32,
+    /// 다음에 읽을 오프셋
+    pub committed_offset: u64,
+    /// 파티션 로그 끝 오프셋 (다음에 기록될 오프셋)
+    pub end_offset: u64,
+    /// 아직 처리하지 않은 메시지 수
+    pub lag: u64,
+}
+
+/// Consumer 그룹·토픽·파티션별 지연 집계 (메트릭/대시보드 게시용)
+#[derive(Default)]
+pub struct ConsumerLagRegistry {
+    lags: std::sync::Mutex<BTreeMap<(String, String, u32), PartitionLag>>,
+}
+
+impl ConsumerLagRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, lag: PartitionLag) {
+        let key = (lag.consumer_group.clone(), lag.topic.clone(), lag.partition);
+        self.lags.lock().unwrap().insert(key, lag);
+    }
+
+    /// 그룹·토픽·파티션 순 지연 목록
+    pub fn snapshot(&self) -> Vec<PartitionLag> {
+        self.lags.lock().unwrap().values().cloned().collect()
+    }
+
+    /// `kafka.consumer.lag.{그룹}.{토픽}.{파티션}` 게이지로 게시
+    pub async fn publish(&self, collector: &dyn GaugeSink) {
+        for lag in self.snapshot() {
+            let name = format!("kafka.consumer.lag.{}.{}.{}", lag.consumer_group, lag.topic, lag.partition);
+            collector.set_gauge(&name, lag.lag).await;
+        }
+    }
+}
+
+/// Consumer Worker 설정
+#[derive(Clone)]
+pub struct KafkaConsumerConfig {
+    pub kafka_brokers: Vec<String>,
+    pub topic_name: String,
+    pub consumer_group: String,
+    pub worker_id: String,
+    pub batch_size: usize,
+    pub processing_interval_ms: u64,
+}
+
+impl KafkaConsumerWorker {
+    /// 새 Kafka Consumer Worker 생성 (Mock)
+    pub async fn new(config: KafkaConsumerConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self::from_config(config))
+    }
+
+    /// 설정으로 Consumer Worker 생성 (Mock은 연결하지 않으므로 동기 생성 가능)
+    pub fn from_config(config: KafkaConsumerConfig) -> Self {
+        info!("Kafka Consumer Worker 초기화 완료 (Mock): {} - {}", config.consumer_group, config.worker_id);
+
+        Self {
+            topic_name: config.topic_name,
+            consumer_group: config.consumer_group,
+            worker_id: config.worker_id,
+            batch_size: config.batch_size,
+            processing_interval_ms: config.processing_interval_ms,
+            messages_processed: Arc::new(Mutex::new(0)),
+            source: None,
+            offsets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 메시지를 읽을 브로커 연결
+    pub fn with_source(mut self, source: ConsumerSource) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Consumer Worker 실행 (메인 루프) (Mock)
+    pub async fn run(&self) -> Result<(), String> {
+        info!("Kafka Consumer Worker 시작 (Mock): {}", self.worker_id);
+        self.load_committed_offsets().await;
+        
+        loop {
+            match self.process_batch().await {
+                Ok(processed_count) => {
+                    if processed_count > 0 {
+                        info!("Worker {} 처리 완료 (Mock): {}개 메시지", self.worker_id, processed_count);
+                    }
+                }
+                Err(e) => {
+                    error!("Worker {} 처리 오류 (Mock): {}", self.worker_id, e);
+                    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+                }
+            }
+            
+            // 처리 간격 대기
+            tokio::time::sleep(tokio::time::Duration::from_millis(self.processing_interval_ms)).await;
+        }
+    }
+
+    /// DB에 커밋된 오프셋 로드 (없는 파티션은 보관 중인 가장 오래된 메시지부터)
+    async fn load_committed_offsets(&self) {
+        let Some(pool) = self.source.as_ref().and_then(|source| source.offset_store.clone()) else {
+            return;
+        };
+        match ConsumerOffsetRepository::new(pool).find_by_group(&self.consumer_group, &self.topic_name).await {
+            Ok(records) => {
+                let mut offsets = self.offsets.lock().await;
+                for record in records {
+                    offsets.insert(record.partition as u32, record.committed_offset as u64);
+                }
+                info!("Worker {} 커밋된 오프셋에서 시작: {:?}", self.worker_id, *offsets);
+            }
+            Err(e) => warn!("Worker {} 커밋 오프셋 조회 실패 (처음부터 읽음): {}", self.worker_id, e),
+        }
+    }
+
+    /// 파티션별로 커밋된 오프셋부터 메시지 배치 처리
+    ///
+    /// 처리에 성공한 메시지까지만 커밋하고, 실패한 파티션은 그 메시지부터 다음 배치에서 다시 읽습니다.
+    /// 다른 파티션은 계속 처리하며 첫 오류를 반환합니다.
+    async fn process_batch(&self) -> Result<usize, String> {
+        let Some(source) = &self.source else {
+            return Ok(0);
+        };
+        let end_offsets = source.broker.end_offsets().await;
+        let mut offsets = self.offsets.lock().await;
+        let mut processed_count = 0;
+        let mut first_error = None;
+
+        for (partition, end_offset) in end_offsets.into_iter().enumerate() {
+            let partition = partition as u32;
+            let committed_offset = offsets.get(&partition).copied().unwrap_or(0);
+            let mut next_offset = committed_offset;
+            for (offset, record) in source.broker.fetch(partition, committed_offset, self.batch_size).await {
+                match self.process_record(&record).await {
+                    Ok(count) => processed_count += count,
+                    Err(e) => {
+                        first_error.get_or_insert(format!("파티션 {} 오프셋 {}: {}", partition, offset, e));
+                        break;
+                    }
+                }
+                next_offset = offset + 1;
+            }
+
+            if next_offset != committed_offset {
+                offsets.insert(partition, next_offset);
+                self.commit_offset(source, partition, next_offset).await;
+            }
+            if let Some(lags) = &source.lags {
+                lags.record(PartitionLag {
+                    consumer_group: self.consumer_group.clone(),
+                    topic: self.topic_name.clone(),
+                    partition,
+                    committed_offset: next_offset,
+                    end_offset,
+                    lag: end_offset.saturating_sub(next_offset),
+                });
+            }
+        }
+        *self.messages_processed.lock().await += processed_count as u64;
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(processed_count),
+        }
+    }
+
+    /// 오프셋 커밋 (DB 저장 실패 시 메모리 오프셋으로 계속 진행하고, 재시작하면 마지막 저장 위치부터 다시 처리)
+    async fn commit_offset(&self, source: &ConsumerSource, partition: u32, offset: u64) {
+        let Some(pool) = &source.offset_store else {
+            return;
+        };
+        let record = ConsumerOffsetRecord {
+            consumer_group: self.consumer_group.clone(),
+            topic: self.topic_name.clone(),
+            partition: partition as i64,
+            committed_offset: offset as i64,
+            updated_at: chrono::Utc::now().timestamp_millis(),
+        };
+        if let Err(e) = ConsumerOffsetRepository::new(pool.clone()).commit(&record).await {
+            warn!("Worker {} 오프셋 커밋 실패 (파티션 {}, 오프셋 {}): {}", self.worker_id, partition, offset, e);
+        }
+    }
+
+    /// 레코드 처리 (처리한 메시지 수)
+    async fn process_record(&self, record: &PartitionRecord) -> Result<usize, String> {
+        match record {
+            PartitionRecord::Execution(message) => self.process_message(message).await.map(|_| 1),
+            PartitionRecord::OrderBookBatch(batch) => {
+                let updates = batch.decode().map_err(|e| format!("호가창 배치 압축 해제 실패: {}", e))?;
+                for update in &updates {
+                    self.process_orderbook_update(update).await?;
+                }
+                Ok(updates.len())
+            }
+        }
+    }
+
+    /// 호가창 업데이트 처리
+    async fn process_orderbook_update(&self, update: &OrderBookUpdateMessage) -> Result<(), String> {
+        debug!("호가창 업데이트 처리 (Mock): {} #{} (매수 {}개, 매도 {}개 레벨)",
+               update.symbol, update.sequence, update.bids.len(), update.asks.len());
+        Ok(())
+    }
+
+    /// 개별 메시지 처리
+    async fn process_message(&self, message: &MarketDataMessage) -> Result<(), String> {
+        info!("메시지 처리 (Mock): {} - {} ({} {})", 
+              message.symbol, message.execution_id, message.price, message.quantity);
+        
+        // TODO: 실제 비즈니스 로직 구현
+        // 예: 외부 거래소 가격 동기화, 규제 보고서 생성, 분석 시스템 업데이트
+        
+        Ok(())
+    }
+}
+
+/// MDP Consumer (Market Data Publisher Consumer) (Mock)
+pub struct MDPConsumer {
+    worker: KafkaConsumerWorker,
+}
+
+impl MDPConsumer {
+    /// 새 MDP Consumer 생성 (Mock)
+    pub async fn new(config: KafkaConsumerConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        let worker = KafkaConsumerWorker::new(config).await?;
+        
+        info!("MDP Consumer 초기화 완료 (Mock)");
+        
+        Ok(Self { worker })
+    }
+
+    /// 메시지를 읽을 브로커 연결
+    pub fn with_source(mut self, source: ConsumerSource) -> Self {
+        self.worker = self.worker.with_source(source);
+        self
+    }
+
+    /// MDP Consumer 실행 (Mock)
+    pub async fn run(&self) -> Result<(), String> {
+        info!("MDP Consumer 시작 (Mock)");
+        
+        // MDP 전용 처리 로직
+        self.worker.run().await
+    }
+
+    /// 봉차트 데이터 처리 (Mock)
+    #[allow(dead_code)]
+    async fn process_candlestick_data(&self, message: &MarketDataMessage) -> Result<(), Box<dyn std::error::Error>> {
+        info!("봉차트 데이터 처리 (Mock): {} - {} at {}", 
+              message.symbol, message.price, message.timestamp);
+        
+        // TODO: 봉차트 계산 로직 구현
+        // 1분, 5분, 15분, 1시간, 4시간, 1일 봉차트 업데이트
+        
+        Ok(())
+    }
+
+    /// 시장 통계 계산 (Mock)
+    #[allow(dead_code)]
+    async fn calculate_market_statistics(&self, symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
+        info!("시장 통계 계산 (Mock): {}", symbol);
+        
+        // TODO: 시장 통계 계산 로직 구현
+        // 24시간 거래량, 가격 변동률, 고가/저가 등
+        
+        Ok(())
+    }
+}
+
+/// 외부 거래소 Consumer (Mock)
+pub struct ExternalExchangeConsumer {
+    worker: KafkaConsumerWorker,
+}
+
+impl ExternalExchangeConsumer {
+    /// 새 외부 거래소 Consumer 생성 (Mock)
+    pub async fn new(config: KafkaConsumerConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        let worker = KafkaConsumerWorker::new(config).await?;
+        
+        info!("외부 거래소 Consumer 초기화 완료 (Mock)");
+        
+        Ok(Self { worker })
+    }
+
+    /// 메시지를 읽을 브로커 연결
+    pub fn with_source(mut self, source: ConsumerSource) -> Self {
+        self.worker = self.worker.with_source(source);
+        self
+    }
+
+    /// 외부 거래소 Consumer 실행 (Mock)
+    pub async fn run(&self) -> Result<(), String> {
+        info!("외부 거래소 Consumer 시작 (Mock)");
+        
+        // 외부 거래소 전용 처리 로직
+        self.worker.run().await
+    }
+
+    /// 가격 동기화 처리 (Mock)
+    #[allow(dead_code)]
+    async fn sync_prices_with_external_exchanges(&self, message: &MarketDataMessage) -> Result<(), Box<dyn std::error::Error>> {
+        info!("외부 거래소 가격 동기화 (Mock): {} - {}", message.symbol, message.price);
+        
+        // TODO: 외부 거래소 API 호출하여 가격 비교
+        // Binance, Coinbase, Kraken 등과 가격 동기화
+        
+        Ok(())
+    }
+
+    /// 차익거래 기회 탐지 (Mock)
+    #[allow(dead_code)]
+    async fn detect_arbitrage_opportunities(&self, symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
+        info!("차익거래 기회 탐지 (Mock): {}", symbol);
+        
+        // TODO: 차익거래 기회 탐지 로직 구현
+        // 여러 거래소 간 가격 차이 분석
+        
+        Ok(())
+    }
+}
+
+/// 규제 기관 Consumer (Mock)
+pub struct RegulatoryConsumer {
+    worker: KafkaConsumerWorker,
+}
+
+impl RegulatoryConsumer {
+    /// 새 규제 기관 Consumer 생성 (Mock)
+    pub async fn new(config: KafkaConsumerConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        let worker = KafkaConsumerWorker::new(config).await?;
+        
+        info!("규제 기관 Consumer 초기화 완료 (Mock)");
+        
+        Ok(Self { worker })
+    }
+
+    /// 메시지를 읽을 브로커 연결
+    pub fn with_source(mut self, source: ConsumerSource) -> Self {
+        self.worker = self.worker.with_source(source);
+        self
+    }
+
+    /// 규제 기관 Consumer 실행 (Mock)
+    pub async fn run(&self) -> Result<(), String> {
+        info!("규제 기관 Consumer 시작 (Mock)");
+        
+        // 규제 기관 전용 처리 로직
+        self.worker.run().await
+    }
+
+    /// 대량 거래 감지 (Mock)
+    #[allow(dead_code)]
+    async fn detect_large_trades(&self, message: &MarketDataMessage) -> Result<(), Box<dyn std::error::Error>> {
+        // 대량 거래 임계값 (예: 1억원 이상)
+        let large_trade_threshold = 100_000_000;
+        
+        if message.price * message.quantity >= large_trade_threshold {
+            info!("대량 거래 감지 (Mock): {} - {}원", message.execution_id, message.price * message.quantity);
+            
+            // TODO: 규제 기관 보고서 생성
+            // 금융감독원, 과세청 등에 보고
+        }
+        
+        Ok(())
+    }
+
+    /// 실시간 보고서 생성 (Mock)
+    #[allow(dead_code)]
+    async fn generate_regulatory_report(&self, symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
+        info!("규제 보고서 생성 (Mock): {}", symbol);
+        
+        // TODO: 규제 보고서 생성 로직 구현
+        // 거래 내역, 거래량, 가격 변동 등 정기 보고
+        
+        Ok(())
+    }
+}
+
+/// 분석 시스템 Consumer (Mock)
+pub struct AnalyticsConsumer {
+    worker: KafkaConsumerWorker,
+}
+
+impl AnalyticsConsumer {
+    /// 새 분석 시스템 Consumer 생성 (Mock)
+    pub async fn new(config: KafkaConsumerConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        let worker = KafkaConsumerWorker::new(config).await?;
+        
+        info!("분석 시스템 Consumer 초기화 완료 (Mock)");
+        
+        Ok(Self { worker })
+    }
+
+    /// 메시지를 읽을 브로커 연결
+    pub fn with_source(mut self, source: ConsumerSource) -> Self {
+        self.worker = self.worker.with_source(source);
+        self
+    }
+
+    /// 분석 시스템 Consumer 실행 (Mock)
+    pub async fn run(&self) -> Result<(), String> {
+        info!("분석 시스템 Consumer 시작 (Mock)");
+        
+        // 분석 시스템 전용 처리 로직
+        self.worker.run().await
+    }
+
+    /// 고래 거래 패턴 분석 (Mock)
+    #[allow(dead_code)]
+    async fn analyze_whale_trading_patterns(&self, message: &MarketDataMessage) -> Result<(), Box<dyn std::error::Error>> {
+        // 고래 거래 임계값 (예: 10억원 이상)
+        let whale_trade_threshold = 1_000_000_000;
+        
+        if message.price * message.quantity >= whale_trade_threshold {
+            info!("고래 거래 패턴 분석 (Mock): {} - {}원", message.execution_id, message.price * message.quantity);
+            
+            // TODO: 고래 거래 패턴 분석 로직 구현
+            // 거래 시간대, 가격 패턴, 수량 패턴 등 분석
+        }
+        
+        Ok(())
+    }
+
+    /// 시장 조작 감지 (Mock)
+    #[allow(dead_code)]
+    async fn detect_market_manipulation(&self, symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
+        info!("시장 조작 감지 (Mock): {}", symbol);
+        
+        // TODO: 시장 조작 감지 로직 구현
+        // 가격 조작, 거래량 조작, 스푸핑 등 감지
+        
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_kafka_consumer_config() {
+        let config = KafkaConsumerConfig {
+            kafka_brokers: vec!["localhost:9092".to_string()],
+            topic_name: "test-market-data".to_string(),
+            consumer_group: "test-group".to_string(),
+            worker_id: "test-worker-1".to_string(),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+        };
+        
+        assert_eq!(config.worker_id, "test-worker-1");
+        assert_eq!(config.batch_size, 100);
+        assert_eq!(config.topic_name, "test-market-data");
+    }
+
+    #[tokio::test]
+    async fn test_kafka_consumer_worker_mock() {
+        let config = KafkaConsumerConfig {
+            kafka_brokers: vec!["localhost:9092".to_string()],
+            topic_name: "test-market-data".to_string(),
+            consumer_group: "test-group".to_string(),
+            worker_id: "test-worker-1".to_string(),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+        };
+        
+        let worker = KafkaConsumerWorker::new(config).await.unwrap();
+        assert_eq!(worker.worker_id, "test-worker-1");
+        assert_eq!(worker.topic_name, "test-market-data");
+    }
+
+    #[tokio::test]
+    async fn test_market_data_message_processing() {
+        let message = MarketDataMessage {
+            execution_id: "exec_001".to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: "Buy".to_string(),
+            price: 50000,
+            quantity: 100,
+            timestamp: 1234567890,
+            order_id: "order_001".to_string(),
+            user_id: "user_001".to_string(),
+            message_type: "execution".to_string(),
+            sequence: 1,
+            global_sequence: 0,
+        };
+        
+        assert_eq!(message.symbol, "BTC-KRW");
+        assert_eq!(message.message_type, "execution");
+    }
+
+    #[tokio::test]
+    async fn test_mdp_consumer_mock() {
+        let config = KafkaConsumerConfig {
+            kafka_brokers: vec!["localhost:9092".to_string()],
+            topic_name: "market-data".to_string(),
+            consumer_group: "mdp-group".to_string(),
+            worker_id: "mdp-worker".to_string(),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+        };
+        
+        let _consumer = MDPConsumer::new(config).await.unwrap();
+        // Mock 구현이므로 실제 실행은 하지 않음
+    }
+
+    fn execution(symbol: &str, id: usize) -> xtrader_engine::model::ExecutionReport {
+        use xtrader_engine::model::{ExecType, ExecutionReport, Side};
+        ExecutionReport {
+            execution_id: format!("exec_{}", id),
+            symbol: symbol.to_string(),
+            side: Side::Buy,
+            price: 50000,
+            quantity: 1,
+            remaining_quantity: 0,
+            timestamp: 1234567890,
+            order_id: format!("order_{}", id),
+            counterparty_id: "order_0".to_string(),
+            is_maker: false,
+            exec_type: ExecType::Trade,
+            sequence: id as u64,
+            tag: None,
+        }
+    }
+
+    async fn worker(broker: &Arc<KafkaProducer>, pool: &SqlitePool, lags: &Arc<ConsumerLagRegistry>) -> KafkaConsumerWorker {
+        let config = KafkaConsumerConfig {
+            kafka_brokers: vec!["localhost:9092".to_string()],
+            topic_name: "market-data".to_string(),
+            consumer_group: "mdp-group".to_string(),
+            worker_id: "mdp-worker".to_string(),
+            batch_size: 2,
+            processing_interval_ms: 1000,
+        };
+        let source = ConsumerSource::new(broker.clone())
+            .with_offset_store(pool.clone())
+            .with_lag_registry(lags.clone());
+        let worker = KafkaConsumerWorker::new(config).await.unwrap().with_source(source);
+        worker.load_committed_offsets().await;
+        worker
+    }
+
+    #[tokio::test]
+    async fn test_commits_offsets_and_resumes_after_restart() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::store::create_tables(&pool).await.unwrap();
+        let broker = Arc::new(KafkaProducer::new(&["localhost:9092".to_string()], "market-data").await.unwrap());
+        let lags = Arc::new(ConsumerLagRegistry::new());
+        for i in 0..3 {
+            broker.publish_execution(&execution("BTC-KRW", i)).await.unwrap();
+        }
+        let partition = crate::kafka_producer::partition_for("BTC-KRW", broker.partition_count().await);
+
+        // 배치 크기 2만큼 처리하고 커밋, 나머지 1건은 지연으로 남음
+        let first = worker(&broker, &pool, &lags).await;
+        assert_eq!(first.process_batch().await.unwrap(), 2);
+        let lag = lags.snapshot().into_iter().find(|lag| lag.partition == partition).unwrap();
+        assert_eq!((lag.committed_offset, lag.end_offset, lag.lag), (2, 3, 1));
+        assert!(lags.snapshot().iter().filter(|lag| lag.partition != partition).all(|lag| lag.lag == 0));
+
+        // 재시작한 Worker는 커밋된 오프셋부터 이어 읽음
+        broker.publish_execution(&execution("BTC-KRW", 3)).await.unwrap();
+        let restarted = worker(&broker, &pool, &lags).await;
+        assert_eq!(restarted.process_batch().await.unwrap(), 2);
+        assert_eq!(restarted.process_batch().await.unwrap(), 0);
+        let lag = lags.snapshot().into_iter().find(|lag| lag.partition == partition).unwrap();
+        assert_eq!((lag.committed_offset, lag.lag), (4, 0));
+
+        let committed = ConsumerOffsetRepository::new(pool.clone())
+            .find_by_group("mdp-group", "market-data")
+            .await
+            .unwrap();
+        assert_eq!(committed.len(), 1);
+        assert_eq!((committed[0].partition, committed[0].committed_offset), (partition as i64, 4));
+
+        let collector = crate::gauge::MemoryGaugeSink::default();
+        lags.publish(&collector).await;
+        let gauge = format!("kafka.consumer.lag.mdp-group.market-data.{}", partition);
+        assert_eq!(collector.get(&gauge), Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_consumes_compressed_orderbook_batches() {
+        use crate::kafka_batch::{CompressionType, KafkaBatchConfig};
+
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::store::create_tables(&pool).await.unwrap();
+        let broker = Arc::new(
+            KafkaProducer::new(&["localhost:9092".to_string()], "market-data")
+                .await
+                .unwrap()
+                .with_batching(KafkaBatchConfig {
+                    max_messages: 3,
+                    linger: std::time::Duration::from_secs(60),
+                    compression: CompressionType::Snappy,
+                    ..KafkaBatchConfig::default()
+                }),
+        );
+        let update = |sequence: u64| OrderBookUpdateMessage {
+            symbol: "BTC-KRW".to_string(),
+            timestamp: 1234567890,
+            message_type: "orderbook_update".to_string(),
+            bids: vec![(50000 - sequence, 1)],
+            asks: vec![(50001 + sequence, 0)],
+            sequence,
+        };
+        for sequence in 1..=4 {
+            broker.publish_orderbook_update("BTC-KRW", &update(sequence)).await.unwrap();
+        }
+        // 가득 찬 배치 하나만 기록되고, 네 번째 업데이트는 linger 대기 중
+        assert_eq!(broker.end_offsets().await.iter().sum::<u64>(), 1);
+        assert_eq!(broker.flush_batches().await.unwrap(), 1);
+
+        let stats = broker.get_producer_stats().await.unwrap();
+        assert_eq!((stats.messages_sent, stats.batches_sent), (4, 2));
+        assert!(stats.compressed_bytes > 0 && stats.uncompressed_bytes > 0);
+
+        // 레코드 2개를 풀어 업데이트 4건 처리
+        let lags = Arc::new(ConsumerLagRegistry::new());
+        let consumer = worker(&broker, &pool, &lags).await;
+        assert_eq!(consumer.process_batch().await.unwrap(), 4);
+        let partition = crate::kafka_producer::partition_for("BTC-KRW", broker.partition_count().await);
+        let lag = lags.snapshot().into_iter().find(|lag| lag.partition == partition).unwrap();
+        assert_eq!((lag.committed_offset, lag.lag), (2, 0));
+    }
+}
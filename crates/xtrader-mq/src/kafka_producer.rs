@@ -0,0 +1,588 @@
+//! Apache Kafka Producer 구현 (Mock 버전)
+//!
+//! 이 모듈은 체결 내역을 Kafka Topic에 발행하는 기능을 Mock으로 구현합니다.
+//! 실제 Kafka 라이브러리 대신 로깅과 메모리 저장을 사용합니다.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use log::{debug, info};
+use xtrader_api::models::{BboUpdate, FundingEvent, OrderBookChange, OrderBookChangeType, OrderBookDelta};
+use xtrader_engine::model::ExecutionReport;
+use crate::kafka_batch::{BatchAccumulator, KafkaBatchConfig, RecordBatch};
+use crate::wire_format;
+
+/// 최우선 호가(BBO) 변경 전용 토픽 (전체 호가 업데이트와 분리)
+pub const BBO_TOPIC: &str = "market-data-bbo";
+
+/// 펀딩 정산 결과 토픽
+pub const FUNDING_TOPIC: &str = "funding-events";
+
+/// 심볼별 재전송 버퍼 크기 (이보다 오래된 메시지는 DB 스냅샷으로 복구)
+const REPLAY_BUFFER_PER_SYMBOL: usize = 10_000;
+
+/// 토픽 파티션 수 (메시지 키는 심볼)
+pub const TOPIC_PARTITIONS: u32 = 3;
+
+/// 파티션별 보관 레코드 수 (넘으면 오래된 레코드부터 삭제, 오프셋은 계속 증가)
+const PARTITION_RETENTION: usize = 100_000;
+
+/// Kafka Producer (Mock 구현)
+pub struct KafkaProducer {
+    topic_name: String,
+    messages_sent: Arc<Mutex<u64>>,
+    /// 심볼별 시퀀스와 최근 발행 메시지 (Consumer 누락 재전송용)
+    replay_log: Mutex<ReplayLog>,
+    /// 파티션별 레코드 로그 (Consumer가 오프셋으로 읽음)
+    partitions: Mutex<Vec<PartitionLog>>,
+    /// 심볼별 호가창 업데이트 배치
+    batcher: Mutex<BatchAccumulator>,
+    batch_stats: Mutex<BatchStats>,
+}
+
+/// 파티션 로그 레코드
+#[derive(Debug, Clone)]
+pub enum PartitionRecord {
+    Execution(MarketDataMessage),
+    /// 같은 심볼의 호가창 업데이트 묶음 (압축됨)
+    OrderBookBatch(RecordBatch),
+}
+
+impl PartitionRecord {
+    fn key(&self) -> &str {
+        match self {
+            PartitionRecord::Execution(message) => &message.symbol,
+            PartitionRecord::OrderBookBatch(batch) => &batch.symbol,
+        }
+    }
+}
+
+/// 호가창 배치 발행 누계
+#[derive(Debug, Default, Clone, Copy)]
+struct BatchStats {
+    batches_sent: u64,
+    uncompressed_bytes: u64,
+    compressed_bytes: u64,
+}
+
+/// 파티션 로그 (오프셋은 파티션 안에서 0부터 연속)
+#[derive(Default)]
+struct PartitionLog {
+    /// 보관 중인 가장 오래된 레코드 오프셋
+    start_offset: u64,
+    records: VecDeque<PartitionRecord>,
+}
+
+impl PartitionLog {
+    /// 다음에 기록될 오프셋
+    fn end_offset(&self) -> u64 {
+        self.start_offset + self.records.len() as u64
+    }
+
+    fn append(&mut self, record: PartitionRecord) {
+        self.records.push_back(record);
+        if self.records.len() > PARTITION_RETENTION {
+            self.records.pop_front();
+            self.start_offset += 1;
+        }
+    }
+}
+
+/// 메시지 키의 파티션 (FNV-1a 해시라 재시작해도 같은 파티션)
+pub fn partition_for(key: &str, partitions: u32) -> u32 {
+    let hash = key.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    (hash % partitions.max(1) as u64) as u32
+}
+
+/// 심볼별 발행 시퀀스와 재전송 버퍼
+#[derive(Default)]
+struct ReplayLog {
+    last_sequence: HashMap<String, u64>,
+    recent: HashMap<String, VecDeque<MarketDataMessage>>,
+}
+
+impl ReplayLog {
+    /// 다음 시퀀스 부여 후 버퍼에 보관
+    fn sequence(&mut self, mut message: MarketDataMessage) -> MarketDataMessage {
+        let sequence = self.last_sequence.entry(message.symbol.clone()).or_insert(0);
+        *sequence += 1;
+        message.sequence = *sequence;
+
+        let recent = self.recent.entry(message.symbol.clone()).or_default();
+        recent.push_back(message.clone());
+        if recent.len() > REPLAY_BUFFER_PER_SYMBOL {
+            recent.pop_front();
+        }
+        message
+    }
+}
+
+/// 시장 데이터 메시지 구조
+#[derive(Debug, Serialize, Deserialize, Clone, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+pub struct MarketDataMessage {
+    pub execution_id: String,
+    pub symbol: String,
+    pub side: String,
+    pub price: u64,
+    pub quantity: u64,
+    pub timestamp: u64,
+    pub order_id: String,
+    pub user_id: String,
+    pub message_type: String, // "execution", "orderbook_update", "market_statistics"
+    /// 심볼별 발행 시퀀스 (1부터 연속, 0이면 시퀀스 없는 메시지)
+    #[serde(default)]
+    pub sequence: u64,
+    /// 시퀀서 전역 시퀀스 번호 (주문 응답/WebSocket 체결과 같은 번호, 0이면 없음)
+    #[serde(default)]
+    pub global_sequence: u64,
+}
+
+impl From<&ExecutionReport> for MarketDataMessage {
+    fn from(report: &ExecutionReport) -> Self {
+        Self {
+            execution_id: report.execution_id.clone(),
+            symbol: report.symbol.clone(),
+            side: format!("{:?}", report.side),
+            price: report.price,
+            quantity: report.quantity,
+            timestamp: report.timestamp,
+            order_id: report.order_id.clone(),
+            user_id: "user_placeholder".to_string(), // TODO: ExecutionReport에 user_id 필드 추가 필요
+            message_type: "execution".to_string(),
+            sequence: 0,
+            global_sequence: report.sequence,
+        }
+    }
+}
+
+/// Kafka 오류 타입 (Mock)
+#[derive(Debug)]
+pub enum KafkaError {
+    ConnectionError(String),
+    SerializationError(String),
+    SendError(String),
+}
+
+impl std::fmt::Display for KafkaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KafkaError::ConnectionError(msg) => write!(f, "Connection error: {}", msg),
+            KafkaError::SerializationError(msg) => write!(f, "Serialization error: {}", msg),
+            KafkaError::SendError(msg) => write!(f, "Send error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for KafkaError {}
+
+impl KafkaProducer {
+    /// 새 Kafka Producer 생성 (Mock)
+    pub async fn new(kafka_brokers: &[String], topic_name: &str) -> Result<Self, KafkaError> {
+        info!("Kafka Producer 초기화 완료 (Mock): {} -> {}", kafka_brokers.join(","), topic_name);
+        
+        Ok(Self {
+            topic_name: topic_name.to_string(),
+            messages_sent: Arc::new(Mutex::new(0)),
+            replay_log: Mutex::new(ReplayLog::default()),
+            partitions: Mutex::new((0..TOPIC_PARTITIONS).map(|_| PartitionLog::default()).collect()),
+            batcher: Mutex::new(BatchAccumulator::new(KafkaBatchConfig::unbatched())),
+            batch_stats: Mutex::new(BatchStats::default()),
+        })
+    }
+
+    /// 호가창 업데이트 배치/압축 설정 (기본은 업데이트마다 비압축 레코드 하나)
+    pub fn with_batching(self, config: KafkaBatchConfig) -> Self {
+        info!("Kafka 호가창 배치 설정: {} -> 최대 {}개, linger {:?}, 압축 {}, 형식 {}",
+              self.topic_name, config.max_messages, config.linger, config.compression.as_str(), config.wire_format.as_str());
+        Self {
+            batcher: Mutex::new(BatchAccumulator::new(config)),
+            ..self
+        }
+    }
+
+    /// 배치 설정
+    pub async fn batch_config(&self) -> KafkaBatchConfig {
+        self.batcher.lock().await.config().clone()
+    }
+
+    /// 심볼을 키로 파티션 로그에 기록
+    async fn append(&self, records: impl IntoIterator<Item = PartitionRecord>) {
+        let mut partitions = self.partitions.lock().await;
+        let count = partitions.len() as u32;
+        for record in records {
+            partitions[partition_for(record.key(), count) as usize].append(record);
+        }
+    }
+
+    /// 닫힌 호가창 배치들을 압축해 기록
+    async fn write_batches(&self, batches: Vec<(String, Vec<OrderBookUpdateMessage>)>) -> Result<usize, KafkaError> {
+        if batches.is_empty() {
+            return Ok(0);
+        }
+        let (compression, wire_format) = {
+            let batcher = self.batcher.lock().await;
+            (batcher.config().compression, batcher.config().wire_format)
+        };
+        let mut records = Vec::with_capacity(batches.len());
+        let mut message_count = 0;
+        let mut stats = BatchStats::default();
+        for (symbol, messages) in &batches {
+            let batch = RecordBatch::encode(symbol, messages, compression, wire_format)?;
+            message_count += batch.message_count;
+            stats.batches_sent += 1;
+            stats.uncompressed_bytes += batch.uncompressed_bytes as u64;
+            stats.compressed_bytes += batch.payload.len() as u64;
+            records.push(PartitionRecord::OrderBookBatch(batch));
+        }
+        self.append(records).await;
+
+        let mut total = self.batch_stats.lock().await;
+        total.batches_sent += stats.batches_sent;
+        total.uncompressed_bytes += stats.uncompressed_bytes;
+        total.compressed_bytes += stats.compressed_bytes;
+        *self.messages_sent.lock().await += message_count as u64;
+
+        debug!("호가창 배치 Kafka 발행 (Mock): {} 배치 {}개, 메시지 {}개, {} -> {} bytes ({}, {})",
+               self.topic_name, stats.batches_sent, message_count,
+               stats.uncompressed_bytes, stats.compressed_bytes, wire_format.as_str(), compression.as_str());
+        Ok(message_count)
+    }
+
+    /// `linger`가 지난 호가창 배치 발행 (주기적으로 호출)
+    pub async fn flush_expired_batches(&self) -> Result<usize, KafkaError> {
+        let batches = self.batcher.lock().await.drain_expired(std::time::Instant::now());
+        self.write_batches(batches).await
+    }
+
+    /// 대기 중인 호가창 배치 모두 발행 (종료 시)
+    pub async fn flush_batches(&self) -> Result<usize, KafkaError> {
+        let batches = self.batcher.lock().await.drain_all();
+        self.write_batches(batches).await
+    }
+
+    /// 체결 내역을 Kafka Topic에 발행 (Mock)
+    pub async fn publish_execution(&self, execution: &ExecutionReport) -> Result<(), KafkaError> {
+        let message = self.replay_log.lock().await.sequence(MarketDataMessage::from(execution));
+        let wire_format = self.batcher.lock().await.config().wire_format;
+        let payload = wire_format::encode(&message, wire_format).map_err(KafkaError::SerializationError)?;
+        self.append([PartitionRecord::Execution(message)]).await;
+        
+        // Mock: 메시지 카운터 증가
+        let mut count = self.messages_sent.lock().await;
+        *count += 1;
+        
+        info!("체결 내역 Kafka 발행 완료 (Mock): {} -> {} (메시지 #{}: {} {} bytes)", 
+              execution.execution_id, execution.symbol, *count, wire_format.as_str(), payload.len());
+        
+        Ok(())
+    }
+
+    /// 배치로 체결 내역 발행 (Mock)
+    pub async fn publish_executions_batch(&self, executions: &[ExecutionReport]) -> Result<usize, KafkaError> {
+        if executions.is_empty() {
+            return Ok(0);
+        }
+
+        let mut replay_log = self.replay_log.lock().await;
+        let messages: Vec<MarketDataMessage> = executions
+            .iter()
+            .map(|execution| replay_log.sequence(MarketDataMessage::from(execution)))
+            .collect();
+        drop(replay_log);
+        self.append(messages.into_iter().map(PartitionRecord::Execution)).await;
+        
+        let mut count = self.messages_sent.lock().await;
+        *count += executions.len() as u64;
+        
+        info!("배치 체결 내역 Kafka 발행 완료 (Mock): {}개 (총 메시지: {})", executions.len(), *count);
+        
+        Ok(executions.len())
+    }
+
+    /// 시장 통계 발행 (Mock)
+    pub async fn publish_market_statistics(&self, symbol: &str, stats: &MarketStatisticsMessage) -> Result<(), KafkaError> {
+        let message_json = serde_json::to_string(stats)
+            .map_err(|e| KafkaError::SerializationError(e.to_string()))?;
+        
+        let mut count = self.messages_sent.lock().await;
+        *count += 1;
+        
+        info!("시장 통계 Kafka 발행 완료 (Mock): {} (메시지 #{}: {})", symbol, *count, message_json);
+        
+        Ok(())
+    }
+
+    /// 호가창 업데이트 발행 (Mock)
+    ///
+    /// 심볼 배치에 쌓고, 배치가 가득 차면 바로 압축해 기록합니다.
+    /// 덜 찬 배치는 `flush_expired_batches`가 `linger` 후에 기록합니다.
+    pub async fn publish_orderbook_update(&self, symbol: &str, orderbook: &OrderBookUpdateMessage) -> Result<(), KafkaError> {
+        let mut message = orderbook.clone();
+        message.symbol = symbol.to_string();
+        let full = self.batcher.lock().await.push(message, std::time::Instant::now());
+        if let Some(batch) = full {
+            self.write_batches(vec![batch]).await?;
+        }
+        Ok(())
+    }
+
+    /// 최우선 호가 변경 발행 (Mock)
+    pub async fn publish_bbo(&self, bbo: &BboUpdate) -> Result<(), KafkaError> {
+        let message_json = serde_json::to_string(bbo)
+            .map_err(|e| KafkaError::SerializationError(e.to_string()))?;
+
+        let mut count = self.messages_sent.lock().await;
+        *count += 1;
+
+        debug!("BBO Kafka 발행 완료 (Mock): {} -> {} (메시지 #{}: {})", bbo.symbol, self.topic_name, *count, message_json);
+
+        Ok(())
+    }
+
+    /// 펀딩 정산 결과 발행 (Mock)
+    pub async fn publish_funding(&self, funding: &FundingEvent) -> Result<(), KafkaError> {
+        let message_json = serde_json::to_string(funding)
+            .map_err(|e| KafkaError::SerializationError(e.to_string()))?;
+
+        let mut count = self.messages_sent.lock().await;
+        *count += 1;
+
+        info!("펀딩 정산 Kafka 발행 완료 (Mock): {} -> {} (메시지 #{}: {})", funding.symbol, self.topic_name, *count, message_json);
+
+        Ok(())
+    }
+
+    /// Producer 상태 조회
+    pub async fn get_producer_stats(&self) -> Result<ProducerStats, KafkaError> {
+        let count = self.messages_sent.lock().await;
+        let batch_stats = *self.batch_stats.lock().await;
+        
+        Ok(ProducerStats {
+            topic_name: self.topic_name.clone(),
+            messages_sent: *count,
+            last_send_time: std::time::SystemTime::now(),
+            batches_sent: batch_stats.batches_sent,
+            uncompressed_bytes: batch_stats.uncompressed_bytes,
+            compressed_bytes: batch_stats.compressed_bytes,
+        })
+    }
+
+    /// 심볼의 `[from, to]` 시퀀스 메시지 재전송 (버퍼에서 밀려났으면 None)
+    pub async fn replay(&self, symbol: &str, from: u64, to: u64) -> Option<Vec<MarketDataMessage>> {
+        let replay_log = self.replay_log.lock().await;
+        let recent = replay_log.recent.get(symbol)?;
+        if recent.front().is_none_or(|oldest| oldest.sequence > from) {
+            return None;
+        }
+        Some(
+            recent
+                .iter()
+                .filter(|message| (from..=to).contains(&message.sequence))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// 발행 토픽 이름
+    pub fn topic_name(&self) -> &str {
+        &self.topic_name
+    }
+
+    /// 파티션 수
+    pub async fn partition_count(&self) -> u32 {
+        self.partitions.lock().await.len() as u32
+    }
+
+    /// 파티션별 로그 끝 오프셋 (다음에 기록될 오프셋, Consumer 지연 계산용)
+    pub async fn end_offsets(&self) -> Vec<u64> {
+        self.partitions.lock().await.iter().map(PartitionLog::end_offset).collect()
+    }
+
+    /// 파티션의 `offset`부터 최대 `max`개 레코드와 오프셋
+    ///
+    /// 보관 기간이 지나 삭제된 오프셋이면 남아 있는 가장 오래된 레코드부터 돌려줍니다.
+    pub async fn fetch(&self, partition: u32, offset: u64, max: usize) -> Vec<(u64, PartitionRecord)> {
+        let partitions = self.partitions.lock().await;
+        let Some(log) = partitions.get(partition as usize) else {
+            return Vec::new();
+        };
+        let from = offset.max(log.start_offset);
+        log.records
+            .iter()
+            .skip((from - log.start_offset) as usize)
+            .take(max)
+            .enumerate()
+            .map(|(i, record)| (from + i as u64, record.clone()))
+            .collect()
+    }
+
+    /// 브로커 재연결 (Mock)
+    pub async fn reconnect(&self) -> Result<(), KafkaError> {
+        info!("Kafka Producer 재연결 완료 (Mock): {}", self.topic_name);
+        Ok(())
+    }
+}
+
+/// 시장 통계 메시지
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarketStatisticsMessage {
+    pub symbol: String,
+    pub timestamp: u64,
+    pub message_type: String,
+    pub volume_24h: u64,
+    pub price_change_24h: f64,
+    pub high_24h: u64,
+    pub low_24h: u64,
+    pub trades_count_24h: u64,
+}
+
+/// 호가창 업데이트 메시지
+#[derive(Debug, Serialize, Deserialize, Clone, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+pub struct OrderBookUpdateMessage {
+    pub symbol: String,
+    pub timestamp: u64,
+    pub message_type: String,
+    pub bids: Vec<(u64, u64)>,
+    pub asks: Vec<(u64, u64)>,
+    pub sequence: u64,
+}
+
+impl From<&OrderBookDelta> for OrderBookUpdateMessage {
+    /// 변경된 가격 레벨만 담음 (삭제된 레벨은 수량 0)
+    fn from(delta: &OrderBookDelta) -> Self {
+        let levels = |changes: &[OrderBookChange]| {
+            changes
+                .iter()
+                .map(|change| match change.change_type {
+                    OrderBookChangeType::Remove => (change.price, 0),
+                    _ => (change.price, change.quantity),
+                })
+                .collect()
+        };
+        Self {
+            symbol: delta.symbol.clone(),
+            timestamp: delta.timestamp,
+            message_type: "orderbook_update".to_string(),
+            bids: levels(&delta.bid_changes),
+            asks: levels(&delta.ask_changes),
+            sequence: delta.sequence,
+        }
+    }
+}
+
+/// Producer 통계 정보
+#[derive(Debug, Clone)]
+pub struct ProducerStats {
+    pub topic_name: String,
+    pub messages_sent: u64,
+    pub last_send_time: std::time::SystemTime,
+    /// 발행한 호가창 배치 수
+    pub batches_sent: u64,
+    /// 호가창 배치 압축 전/후 크기 누계
+    pub uncompressed_bytes: u64,
+    pub compressed_bytes: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xtrader_engine::model::{ExecutionReport, ExecType, Side};
+
+    fn create_test_execution() -> ExecutionReport {
+        ExecutionReport {
+            execution_id: "exec_001".to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: Side::Buy,
+            price: 50000,
+            quantity: 100,
+            timestamp: 1234567890,
+            order_id: "order_001".to_string(),
+            remaining_quantity: 0,
+            counterparty_id: "order_002".to_string(),
+            is_maker: true,
+            exec_type: ExecType::Trade,
+            sequence: 7,
+            tag: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_market_data_message_conversion() {
+        let execution = create_test_execution();
+        let message = MarketDataMessage::from(&execution);
+        
+        assert_eq!(message.execution_id, "exec_001");
+        assert_eq!(message.symbol, "BTC-KRW");
+        assert_eq!(message.side, "Buy");
+        assert_eq!(message.price, 50000);
+        assert_eq!(message.quantity, 100);
+        assert_eq!(message.message_type, "execution");
+        assert_eq!(message.global_sequence, 7);
+    }
+
+    #[tokio::test]
+    async fn test_message_serialization() {
+        let execution = create_test_execution();
+        let message = MarketDataMessage::from(&execution);
+        
+        let json = serde_json::to_string(&message).unwrap();
+        let deserialized: MarketDataMessage = serde_json::from_str(&json).unwrap();
+        
+        assert_eq!(message.execution_id, deserialized.execution_id);
+        assert_eq!(message.symbol, deserialized.symbol);
+        assert_eq!(message.price, deserialized.price);
+    }
+
+    #[tokio::test]
+    async fn test_kafka_producer_mock() {
+        let producer = KafkaProducer::new(&["localhost:9092".to_string()], "test-topic").await.unwrap();
+        
+        let execution = create_test_execution();
+        let result = producer.publish_execution(&execution).await;
+        
+        assert!(result.is_ok());
+        
+        let stats = producer.get_producer_stats().await.unwrap();
+        assert_eq!(stats.messages_sent, 1);
+        assert_eq!(stats.topic_name, "test-topic");
+    }
+
+    #[tokio::test]
+    async fn test_per_symbol_sequence_and_replay() {
+        let producer = KafkaProducer::new(&["localhost:9092".to_string()], "test-topic").await.unwrap();
+        let mut execution = create_test_execution();
+        for i in 0..3 {
+            execution.execution_id = format!("exec_{}", i);
+            producer.publish_execution(&execution).await.unwrap();
+        }
+        execution.symbol = "ETH-KRW".to_string();
+        producer.publish_execution(&execution).await.unwrap();
+
+        let replayed = producer.replay("BTC-KRW", 2, 3).await.unwrap();
+        assert_eq!(replayed.iter().map(|m| m.sequence).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(replayed[0].execution_id, "exec_1");
+        assert_eq!(producer.replay("ETH-KRW", 1, 1).await.unwrap()[0].sequence, 1);
+        assert!(producer.replay("XRP-KRW", 1, 1).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_market_statistics_message() {
+        let stats = MarketStatisticsMessage {
+            symbol: "BTC-KRW".to_string(),
+            timestamp: 1234567890,
+            message_type: "market_statistics".to_string(),
+            volume_24h: 1000000,
+            price_change_24h: 5.5,
+            high_24h: 52000,
+            low_24h: 48000,
+            trades_count_24h: 1500,
+        };
+        
+        assert_eq!(stats.symbol, "BTC-KRW");
+        assert_eq!(stats.message_type, "market_statistics");
+        assert_eq!(stats.volume_24h, 1000000);
+    }
+}
\ No newline at end of file
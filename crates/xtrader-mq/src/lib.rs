@@ -0,0 +1,41 @@
+//! Message Queue 통합 모듈
+//!
+//! 이 크레이트는 Redis Streams, Apache Kafka, RabbitMQ를 통합하여
+//! 고성능 메시지 처리를 제공합니다. 메시지 본문은 `xtrader-engine` 체결 보고서와 `xtrader-api` 시세
+//! 프레임이고, 서버 쪽 저장소는 [`AuditSink`](감사 로그)와 [`GaugeSink`](메트릭) 트레이트로만 씁니다.
+
+pub mod redis_streams;
+pub mod redis_consumer;
+pub mod kafka_producer;
+pub mod kafka_consumer;
+pub mod kafka_batch;
+pub mod rabbitmq_producer;
+pub mod rabbitmq_consumer;
+pub mod dead_letter;
+pub mod hash_ring;
+pub mod backup_queue;
+pub mod segment_log;
+pub mod health_monitor;
+pub mod recovery_manager;
+pub mod publish_retry;
+pub mod wire_format;
+pub mod gauge;
+pub mod store;
+
+pub use redis_streams::{RedisStreamsProducer, ExecutionMessage};
+pub use redis_consumer::{RedisConsumerWorker, RedisConsumerManager, ConsumerConfig, PendingClaimConfig, PendingClaimMetrics};
+pub use kafka_producer::{KafkaProducer, BBO_TOPIC, FUNDING_TOPIC, TOPIC_PARTITIONS, partition_for, PartitionRecord, MarketDataMessage, MarketStatisticsMessage, OrderBookUpdateMessage, ProducerStats};
+pub use kafka_batch::{KafkaBatchConfig, CompressionType, RecordBatch, MAX_DECOMPRESSED_BYTES};
+pub use kafka_consumer::{KafkaConsumerWorker, KafkaConsumerConfig, ConsumerSource, ConsumerLagRegistry, PartitionLag, MDPConsumer, ExternalExchangeConsumer, RegulatoryConsumer, AnalyticsConsumer};
+pub use rabbitmq_producer::{RabbitMQProducer, WebSocketNotificationMessage, RabbitMQError, ProducerStats as RabbitMQProducerStats, RoutingPatterns};
+pub use rabbitmq_consumer::{RabbitMQConsumerWorker, RabbitMQConsumerConfig, WebSocketServerConsumer, LoadBalancerConsumer, LoadBalancerConfig, ClientMove, NotificationDelivery, DEFAULT_CONNECTION_QUEUE, DeadLetterQueueConsumer, DeadLetterOutcome, ServerStatus};
+pub use dead_letter::{AuditSink, DeadLetter, QuarantineStore, QuarantinedMessage, QuarantineReason, QuarantineStatus, QuarantineError};
+pub use hash_ring::HashRing;
+pub use backup_queue::{LocalBackupQueue, BackupMessage, MQType, BackupMessageBuilder, BackupQueueStats, BackupQueueConfig};
+pub use segment_log::{SegmentLog, SegmentLogConfig, SegmentLogStats, SegmentEntry, parse_segment};
+pub use health_monitor::{MQHealthMonitor, HealthStatus, MQHealthStatus, ConnectionStatus, HealthCheckConfig};
+pub use wire_format::WireFormat;
+pub use publish_retry::{PublishRetry, PublishRetryConfig, PublishRetryError, PublishOutcome};
+pub use recovery_manager::{RecoveryManager, RecoveryStats, RecoveryStatus, RecoveryConfig, RecoveryFilter, RecoveryJob, RecoveryJobState, RecoveryJobError};
+pub use gauge::GaugeSink;
+pub use store::{ConsumerOffsetRecord, ConsumerOffsetRepository, QuarantineRepository, QuarantinedMessageRecord};
@@ -0,0 +1,322 @@
+//! MQ 발행 재시도 정책
+//!
+//! Redis/Kafka/RabbitMQ 발행을 한 가지 정책으로 감쌉니다. 시도마다 전송 타임아웃(`send_timeout`)을 두고,
+//! 실패하면 지수 백오프에 지터를 섞어 기다린 뒤 `max_attempts`까지 다시 보냅니다. 전체 소요 시간은
+//! `deadline`을 넘지 않으며, 정책을 모두 소진한 뒤에만 메시지를 로컬 백업 큐(`LocalBackupQueue`)에 넣어
+//! 복구 관리자가 MQ 정상화 후 재발행하게 합니다.
+
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{debug, error, warn};
+
+use crate::backup_queue::{BackupMessage, LocalBackupQueue, MQType};
+use crate::gauge::GaugeSink;
+
+/// 발행 재시도 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublishRetryConfig {
+    /// 최대 시도 횟수 (첫 시도 포함)
+    pub max_attempts: u32,
+    /// 첫 재시도 전 대기 시간 (이후 두 배씩 증가)
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// 대기 시간에서 무작위로 줄이는 최대 비율 (0이면 지터 없음, 1이면 0~대기 시간)
+    pub jitter: f64,
+    /// 시도 한 번의 전송 타임아웃
+    pub send_timeout: Duration,
+    /// 재시도를 포함한 전체 발행 제한 시간
+    pub deadline: Duration,
+}
+
+impl Default for PublishRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(1),
+            jitter: 0.5,
+            send_timeout: Duration::from_secs(2),
+            deadline: Duration::from_secs(5),
+        }
+    }
+}
+
+impl PublishRetryConfig {
+    /// `attempt`번째 시도 실패 후 대기 시간 (`random`은 0 이상 1 미만)
+    fn backoff(&self, attempt: u32, random: f64) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff);
+        backoff.mul_f64(1.0 - self.jitter.clamp(0.0, 1.0) * random)
+    }
+}
+
+/// 0 이상 1 미만의 무작위 값 (UUID v4의 무작위 비트 사용)
+fn jitter_fraction() -> f64 {
+    (uuid::Uuid::new_v4().as_u128() >> 75) as f64 / (1u64 << 53) as f64
+}
+
+/// 재시도 정책을 모두 소진한 발행 실패
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("{attempts}회 시도 후 발행 실패: {last_error}")]
+pub struct PublishRetryError {
+    pub attempts: u32,
+    pub last_error: String,
+}
+
+/// 백업 큐 대체 후 발행 결과
+#[derive(Debug, PartialEq)]
+pub enum PublishOutcome<T> {
+    /// MQ 발행 성공 (재시도 포함)
+    Published(T),
+    /// 정책을 소진해 로컬 백업 큐에 보관됨
+    BackedUp(PublishRetryError),
+}
+
+/// MQ별 발행 재시도 카운터
+#[derive(Default)]
+struct RetryCounters {
+    attempts: AtomicU64,
+    retries: AtomicU64,
+    timeouts: AtomicU64,
+    exhausted: AtomicU64,
+    backed_up: AtomicU64,
+    backup_failed: AtomicU64,
+}
+
+/// MQ 발행 재시도기 (Redis/Kafka/RabbitMQ 공용)
+pub struct PublishRetry {
+    config: PublishRetryConfig,
+    backup_queue: Option<Arc<LocalBackupQueue>>,
+    counters: [RetryCounters; 3],
+}
+
+impl PublishRetry {
+    pub fn new(config: PublishRetryConfig) -> Self {
+        Self {
+            config,
+            backup_queue: None,
+            counters: Default::default(),
+        }
+    }
+
+    /// 정책을 소진한 메시지를 보관할 백업 큐
+    pub fn with_backup_queue(mut self, backup_queue: Arc<LocalBackupQueue>) -> Self {
+        self.backup_queue = Some(backup_queue);
+        self
+    }
+
+    pub fn config(&self) -> &PublishRetryConfig {
+        &self.config
+    }
+
+    fn counters(&self, mq_type: &MQType) -> &RetryCounters {
+        match mq_type {
+            MQType::RedisStreams => &self.counters[0],
+            MQType::Kafka => &self.counters[1],
+            MQType::RabbitMQ => &self.counters[2],
+        }
+    }
+
+    /// 재시도 정책으로 발행 (`send`는 시도마다 새 발행 future를 만듦)
+    pub async fn send<T, E, F, Fut>(&self, mq_type: &MQType, mut send: F) -> Result<T, PublishRetryError>
+    where
+        E: Display,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let counters = self.counters(mq_type);
+        let started = Instant::now();
+        let max_attempts = self.config.max_attempts.max(1);
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            counters.attempts.fetch_add(1, Ordering::Relaxed);
+            let remaining = self.config.deadline.saturating_sub(started.elapsed());
+            let last_error = match tokio::time::timeout(self.config.send_timeout.min(remaining), send()).await {
+                Ok(Ok(value)) => {
+                    if attempt > 1 {
+                        debug!("{} 발행 재시도 성공 ({}회째)", mq_type, attempt);
+                    }
+                    return Ok(value);
+                }
+                Ok(Err(e)) => e.to_string(),
+                Err(_) => {
+                    counters.timeouts.fetch_add(1, Ordering::Relaxed);
+                    format!("전송 타임아웃 ({:?})", self.config.send_timeout.min(remaining))
+                }
+            };
+
+            // 다음 시도까지 기다리면 전체 제한 시간을 넘는 경우도 소진으로 봄
+            let backoff = self.config.backoff(attempt, jitter_fraction());
+            if attempt >= max_attempts || started.elapsed() + backoff >= self.config.deadline {
+                counters.exhausted.fetch_add(1, Ordering::Relaxed);
+                return Err(PublishRetryError { attempts: attempt, last_error });
+            }
+            counters.retries.fetch_add(1, Ordering::Relaxed);
+            warn!("{} 발행 실패 ({}회째, {:?} 후 재시도): {}", mq_type, attempt, backoff, last_error);
+            tokio::time::sleep(backoff).await;
+        }
+    }
+
+    /// 재시도 정책으로 발행하고, 소진되면 `backup`이 만든 메시지를 백업 큐에 보관
+    ///
+    /// 백업 큐가 없거나 보관에도 실패하면 오류를 반환합니다.
+    pub async fn send_or_backup<T, E, F, Fut>(
+        &self,
+        mq_type: &MQType,
+        backup: impl FnOnce() -> BackupMessage,
+        send: F,
+    ) -> Result<PublishOutcome<T>, String>
+    where
+        E: Display,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let failure = match self.send(mq_type, send).await {
+            Ok(value) => return Ok(PublishOutcome::Published(value)),
+            Err(failure) => failure,
+        };
+        let counters = self.counters(mq_type);
+        let Some(backup_queue) = &self.backup_queue else {
+            counters.backup_failed.fetch_add(1, Ordering::Relaxed);
+            return Err(format!("{} (백업 큐 없음)", failure));
+        };
+        match backup_queue.backup_message(backup()).await {
+            Ok(()) => {
+                counters.backed_up.fetch_add(1, Ordering::Relaxed);
+                warn!("{} 발행 재시도 소진, 백업 큐에 보관: {}", mq_type, failure);
+                Ok(PublishOutcome::BackedUp(failure))
+            }
+            Err(e) => {
+                counters.backup_failed.fetch_add(1, Ordering::Relaxed);
+                error!("{} 백업 큐 보관 실패: {} ({})", mq_type, e, failure);
+                Err(format!("{} (백업 실패: {})", failure, e))
+            }
+        }
+    }
+
+    /// 게이지 값 발행 (`mq.publish.{redis|kafka|rabbitmq}.*`)
+    pub async fn publish(&self, collector: &dyn GaugeSink) {
+        for (name, counters) in ["redis", "kafka", "rabbitmq"].iter().zip(&self.counters) {
+            let prefix = format!("mq.publish.{}", name);
+            collector.set_gauge(&format!("{}.attempts", prefix), counters.attempts.load(Ordering::Relaxed)).await;
+            collector.set_gauge(&format!("{}.retries", prefix), counters.retries.load(Ordering::Relaxed)).await;
+            collector.set_gauge(&format!("{}.timeouts", prefix), counters.timeouts.load(Ordering::Relaxed)).await;
+            collector.set_gauge(&format!("{}.exhausted", prefix), counters.exhausted.load(Ordering::Relaxed)).await;
+            collector.set_gauge(&format!("{}.backed_up", prefix), counters.backed_up.load(Ordering::Relaxed)).await;
+            collector
+                .set_gauge(&format!("{}.backup_failed", prefix), counters.backup_failed.load(Ordering::Relaxed))
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup_queue::BackupMessageBuilder;
+    use std::sync::atomic::AtomicU32;
+
+    fn config() -> PublishRetryConfig {
+        PublishRetryConfig {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(4),
+            jitter: 0.5,
+            send_timeout: Duration::from_millis(50),
+            deadline: Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_with_jitter_and_cap() {
+        let config = PublishRetryConfig {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(300),
+            ..config()
+        };
+        assert_eq!(config.backoff(1, 0.0), Duration::from_millis(100));
+        assert_eq!(config.backoff(2, 0.0), Duration::from_millis(200));
+        assert_eq!(config.backoff(3, 0.0), Duration::from_millis(300));
+        // 지터 0.5면 최대 절반까지 줄어듦
+        assert_eq!(config.backoff(2, 0.5), Duration::from_millis(150));
+        assert_eq!(PublishRetryConfig { jitter: 0.0, ..config }.backoff(2, 0.9), Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_success_and_times_out_slow_sends() {
+        let retry = PublishRetry::new(config());
+        let calls = AtomicU32::new(0);
+
+        // 첫 시도는 타임아웃, 두 번째는 오류, 세 번째 성공
+        let result = retry
+            .send(&MQType::Kafka, || {
+                let call = calls.fetch_add(1, Ordering::Relaxed);
+                async move {
+                    match call {
+                        0 => {
+                            tokio::time::sleep(Duration::from_millis(200)).await;
+                            Ok(0)
+                        }
+                        1 => Err("broker unavailable"),
+                        _ => Ok(call),
+                    }
+                }
+            })
+            .await;
+        assert_eq!(result, Ok(2));
+
+        let kafka = retry.counters(&MQType::Kafka);
+        assert_eq!(kafka.attempts.load(Ordering::Relaxed), 3);
+        assert_eq!(kafka.retries.load(Ordering::Relaxed), 2);
+        assert_eq!(kafka.timeouts.load(Ordering::Relaxed), 1);
+        assert_eq!(kafka.exhausted.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn test_backs_up_only_after_policy_exhausted() {
+        let directory = std::env::temp_dir().join(format!("xtrader-publish-retry-{}", uuid::Uuid::new_v4()));
+        let backup_queue = Arc::new(LocalBackupQueue::new(directory.to_string_lossy().into_owned(), 100, 1000));
+        let retry = PublishRetry::new(config()).with_backup_queue(backup_queue.clone());
+        let calls = AtomicU32::new(0);
+
+        let outcome = retry
+            .send_or_backup(
+                &MQType::RedisStreams,
+                || BackupMessageBuilder::new(MQType::RedisStreams, "executions".to_string()).build(),
+                || {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    async { Err::<(), _>("connection refused") }
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            outcome,
+            PublishOutcome::BackedUp(PublishRetryError { attempts: 3, last_error: "connection refused".to_string() })
+        );
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+        assert_eq!(backup_queue.recover_messages(&MQType::RedisStreams, 10).await.unwrap().len(), 1);
+        assert_eq!(retry.counters(&MQType::RedisStreams).backed_up.load(Ordering::Relaxed), 1);
+
+        // 백업 큐가 없으면 오류
+        let without_backup = PublishRetry::new(config());
+        let result = without_backup
+            .send_or_backup(
+                &MQType::RabbitMQ,
+                || BackupMessageBuilder::new(MQType::RabbitMQ, "websocket_notifications".to_string()).build(),
+                || async { Err::<(), _>("channel closed") },
+            )
+            .await;
+        assert!(result.is_err());
+
+        let _ = std::fs::remove_dir_all(directory);
+    }
+}
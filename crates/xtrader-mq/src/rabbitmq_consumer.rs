@@ -0,0 +1,1279 @@
+//! RabbitMQ Consumer 구현 (Mock 버전)
+//!
+//! 이 모듈은 RabbitMQ Topic Exchange에서 WebSocket 알림을 소비하여
+//! 다중 WebSocket 서버로 분배하는 기능을 제공합니다.
+//! 각 WebSocket 서버는 받은 알림을 라우팅 키를 구독한 연결에 연결별 순서대로 전달합니다 (`NotificationDelivery`).
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
+use log::{info, error, warn};
+use crate::dead_letter::{classify, Classification, DeadLetter, QuarantineReason, QuarantineStore};
+use crate::hash_ring::{HashRing, DEFAULT_VIRTUAL_NODES};
+use crate::rabbitmq_producer::WebSocketNotificationMessage;
+
+/// RabbitMQ Consumer Worker (Mock 구현)
+pub struct RabbitMQConsumerWorker {
+    #[allow(dead_code)]
+    exchange_name: String,
+    #[allow(dead_code)]
+    queue_name: String,
+    routing_patterns: Vec<String>,
+    worker_id: String,
+    #[allow(dead_code)]
+    batch_size: usize,
+    processing_interval_ms: u64,
+    messages_processed: Arc<Mutex<u64>>,
+    connection_status: Arc<Mutex<bool>>,
+    /// 받은 알림을 전달할 WebSocket 연결 (None이면 로그만 남김)
+    delivery: Option<Arc<NotificationDelivery>>,
+}
+
+/// Consumer Worker 설정
+#[derive(Clone)]
+pub struct RabbitMQConsumerConfig {
+    pub rabbitmq_url: String,
+    pub exchange_name: String,
+    pub queue_name: String,
+    pub routing_patterns: Vec<String>,
+    pub worker_id: String,
+    pub batch_size: usize,
+    pub processing_interval_ms: u64,
+}
+
+impl RabbitMQConsumerWorker {
+    /// 새 RabbitMQ Consumer Worker 생성 (Mock)
+    pub async fn new(config: RabbitMQConsumerConfig) -> Result<Self, String> {
+        info!("RabbitMQ Consumer Worker 초기화 완료 (Mock): {} - {}", config.exchange_name, config.worker_id);
+        
+        Ok(Self {
+            exchange_name: config.exchange_name,
+            queue_name: config.queue_name,
+            routing_patterns: config.routing_patterns,
+            worker_id: config.worker_id,
+            batch_size: config.batch_size,
+            processing_interval_ms: config.processing_interval_ms,
+            messages_processed: Arc::new(Mutex::new(0)),
+            connection_status: Arc::new(Mutex::new(true)),
+            delivery: None,
+        })
+    }
+
+    /// 받은 알림을 WebSocket 연결로 전달
+    pub fn with_delivery(mut self, delivery: Arc<NotificationDelivery>) -> Self {
+        self.delivery = Some(delivery);
+        self
+    }
+
+    /// Consumer Worker 실행 (메인 루프) (Mock)
+    pub async fn run(&self) -> Result<(), String> {
+        info!("RabbitMQ Consumer Worker 시작 (Mock): {}", self.worker_id);
+        
+        loop {
+            match self.process_batch().await {
+                Ok(processed_count) => {
+                    if processed_count > 0 {
+                        info!("Worker {} 처리 완료 (Mock): {}개 메시지", self.worker_id, processed_count);
+                    }
+                }
+                Err(e) => {
+                    error!("Worker {} 처리 오류 (Mock): {}", self.worker_id, e);
+                    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+                }
+            }
+            
+            // 처리 간격 대기
+            tokio::time::sleep(tokio::time::Duration::from_millis(self.processing_interval_ms)).await;
+        }
+    }
+
+    /// 메시지 배치 처리 (Mock)
+    async fn process_batch(&self) -> Result<usize, String> {
+        // Mock: 실제로는 RabbitMQ에서 메시지를 폴링하지만, 여기서는 시뮬레이션
+        let mut count = self.messages_processed.lock().await;
+        let processed_count = (*count % 5) + 1; // 1-5개 사이의 랜덤한 메시지 처리
+        *count += processed_count;
+        
+        // Mock 메시지 생성 및 처리
+        for i in 0..processed_count {
+            let mock_message = self.create_mock_message(i as usize);
+            self.process_message(&mock_message).await?;
+        }
+        
+        Ok(processed_count as usize)
+    }
+
+    /// Mock 메시지 생성
+    fn create_mock_message(&self, index: usize) -> WebSocketNotificationMessage {
+        let symbols = ["BTC-KRW", "ETH-KRW", "XRP-KRW"];
+        let message_types = ["execution", "orderbook_delta", "market_statistics", "candlestick_update"];
+        
+        let symbol = symbols[index % symbols.len()];
+        let message_type = message_types[index % message_types.len()];
+        
+        WebSocketNotificationMessage {
+            message_id: format!("mock_msg_{}", index),
+            routing_key: format!("{}.{}", message_type, symbol),
+            message_type: message_type.to_string(),
+            symbol: Some(symbol.to_string()),
+            user_id: Some(format!("mock_user_{}", index % 10)),
+            data: serde_json::json!({
+                "price": 50000 + (index as u64 * 100),
+                "quantity": 100 + (index as u64 * 10),
+                "timestamp": std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_millis() as u64
+            }),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64,
+            priority: (index % 10) as u8,
+        }
+    }
+
+    /// 개별 메시지 처리
+    async fn process_message(&self, message: &WebSocketNotificationMessage) -> Result<(), String> {
+        info!("WebSocket 메시지 처리 (Mock): {} - {} (우선순위: {})", 
+              message.routing_key, message.message_id, message.priority);
+        
+        // 라우팅 패턴 매칭 확인
+        if self.matches_routing_patterns(&message.routing_key) {
+            self.distribute_to_websocket_servers(message).await?;
+        } else {
+            warn!("라우팅 패턴 불일치: {} (패턴: {:?})", 
+                  message.routing_key, self.routing_patterns);
+        }
+        
+        Ok(())
+    }
+
+    /// 라우팅 패턴 매칭 확인
+    fn matches_routing_patterns(&self, routing_key: &str) -> bool {
+        for pattern in &self.routing_patterns {
+            if matches_pattern(pattern, routing_key) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// WebSocket 서버로 분배
+    async fn distribute_to_websocket_servers(&self, message: &WebSocketNotificationMessage) -> Result<(), String> {
+        if let Some(delivery) = &self.delivery {
+            delivery.deliver(message).await;
+            return Ok(());
+        }
+
+        // Mock: 전달할 연결이 없는 Worker는 로그만 남김
+        let servers = ["ws-server-1", "ws-server-2", "ws-server-3"];
+        
+        for server in &servers {
+            info!("WebSocket 서버로 메시지 전송 (Mock): {} -> {} (메시지: {})", 
+                  server, message.routing_key, message.message_id);
+        }
+        
+        Ok(())
+    }
+
+    /// 연결 상태 확인
+    pub async fn is_connected(&self) -> bool {
+        let status = self.connection_status.lock().await;
+        *status
+    }
+
+    /// 연결 상태 설정
+    pub async fn set_connection_status(&self, connected: bool) {
+        let mut status = self.connection_status.lock().await;
+        *status = connected;
+    }
+
+    /// 처리된 메시지 수 조회
+    pub async fn get_processed_count(&self) -> u64 {
+        let count = self.messages_processed.lock().await;
+        *count
+    }
+}
+
+/// 와일드카드 패턴 매칭 (간단한 구현)
+fn matches_pattern(pattern: &str, routing_key: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+
+    if pattern.contains('*') {
+        let parts: Vec<&str> = pattern.split('*').collect();
+        if parts.len() == 2 {
+            let prefix = parts[0];
+            let suffix = parts[1];
+            return routing_key.starts_with(prefix) && routing_key.ends_with(suffix);
+        }
+    }
+
+    pattern == routing_key
+}
+
+/// 연결별 알림 큐 길이 (가득 차면 그 연결로 가는 새 알림은 버림)
+pub const DEFAULT_CONNECTION_QUEUE: usize = 256;
+
+/// 알림을 구독한 WebSocket 연결
+struct Subscriber {
+    /// 인증된 계정 (사용자 알림은 이 계정으로 연결한 곳에만 전달)
+    client_id: Option<String>,
+    /// 구독할 라우팅 키 패턴
+    patterns: Vec<String>,
+    tx: mpsc::Sender<WebSocketNotificationMessage>,
+}
+
+impl Subscriber {
+    fn accepts(&self, message: &WebSocketNotificationMessage) -> bool {
+        let owner_matches = match &message.user_id {
+            Some(user_id) => self.client_id.as_deref() == Some(user_id.as_str()),
+            None => true,
+        };
+        owner_matches && self.patterns.iter().any(|pattern| matches_pattern(pattern, &message.routing_key))
+    }
+}
+
+/// RabbitMQ 알림을 WebSocket 연결로 전달
+///
+/// 연결마다 큐 하나로 보내므로 한 연결 안에서는 Consumer가 받은 순서대로 도착합니다.
+/// 느린 연결은 다른 연결을 막지 않도록 큐가 가득 차면 그 알림을 버리고, 끊긴 연결은 구독에서 뺍니다.
+pub struct NotificationDelivery {
+    /// 연결 ID → 구독
+    connections: RwLock<HashMap<String, Subscriber>>,
+    queue_capacity: usize,
+    delivered: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl NotificationDelivery {
+    pub fn new(queue_capacity: usize) -> Self {
+        Self {
+            connections: RwLock::new(HashMap::new()),
+            queue_capacity: queue_capacity.max(1),
+            delivered: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// 연결 구독 등록 (같은 연결 ID로 다시 등록하면 이전 구독을 대체)
+    pub async fn connect(
+        &self,
+        connection_id: &str,
+        client_id: Option<&str>,
+        patterns: Vec<String>,
+    ) -> mpsc::Receiver<WebSocketNotificationMessage> {
+        let (tx, rx) = mpsc::channel(self.queue_capacity);
+        let subscriber = Subscriber { client_id: client_id.map(str::to_string), patterns, tx };
+        self.connections.write().await.insert(connection_id.to_string(), subscriber);
+        rx
+    }
+
+    /// 연결 구독 해제
+    pub async fn disconnect(&self, connection_id: &str) -> bool {
+        self.connections.write().await.remove(connection_id).is_some()
+    }
+
+    /// 라우팅 키를 구독한 연결로 알림 전달 (전달한 연결 수 반환)
+    pub async fn deliver(&self, message: &WebSocketNotificationMessage) -> usize {
+        let mut delivered = 0;
+        let mut closed = Vec::new();
+        for (connection_id, subscriber) in self.connections.read().await.iter() {
+            if !subscriber.accepts(message) {
+                continue;
+            }
+            match subscriber.tx.try_send(message.clone()) {
+                Ok(()) => delivered += 1,
+                Err(TrySendError::Full(_)) => {
+                    warn!("WebSocket 연결 알림 큐 가득 참, 알림 버림: {} ({})", connection_id, message.message_id);
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(TrySendError::Closed(_)) => {
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                    closed.push(connection_id.clone());
+                }
+            }
+        }
+        self.delivered.fetch_add(delivered as u64, Ordering::Relaxed);
+
+        if !closed.is_empty() {
+            let mut connections = self.connections.write().await;
+            for connection_id in closed {
+                connections.remove(&connection_id);
+                info!("끊긴 WebSocket 연결 구독 해제: {}", connection_id);
+            }
+        }
+        delivered
+    }
+
+    /// 구독 중인 연결 수
+    pub async fn connection_count(&self) -> usize {
+        self.connections.read().await.len()
+    }
+
+    /// 연결로 전달한 알림 수
+    pub fn delivered(&self) -> u64 {
+        self.delivered.load(Ordering::Relaxed)
+    }
+
+    /// 큐가 가득 찼거나 끊긴 연결이라 버린 알림 수
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// WebSocket 서버 Consumer
+pub struct WebSocketServerConsumer {
+    worker: RabbitMQConsumerWorker,
+    server_id: String,
+    max_connections: u32,
+    current_connections: Arc<Mutex<u32>>,
+    delivery: Arc<NotificationDelivery>,
+}
+
+impl WebSocketServerConsumer {
+    /// 새 WebSocket 서버 Consumer 생성 (Mock)
+    pub async fn new(config: RabbitMQConsumerConfig, server_id: String, max_connections: u32) -> Result<Self, String> {
+        let delivery = Arc::new(NotificationDelivery::new(DEFAULT_CONNECTION_QUEUE));
+        let worker = RabbitMQConsumerWorker::new(config).await?.with_delivery(delivery.clone());
+        
+        info!("WebSocket 서버 Consumer 초기화 완료 (Mock): {}", server_id);
+        
+        Ok(Self {
+            worker,
+            server_id,
+            max_connections,
+            current_connections: Arc::new(Mutex::new(0)),
+            delivery,
+        })
+    }
+
+    /// 이 서버에 연결한 클라이언트로 알림을 전달하는 구독 관리자
+    pub fn delivery(&self) -> Arc<NotificationDelivery> {
+        self.delivery.clone()
+    }
+
+    /// RabbitMQ에서 받은 알림 처리 (서버 큐의 라우팅 패턴에 맞으면 구독한 연결로 전달)
+    pub async fn handle_message(&self, message: &WebSocketNotificationMessage) -> Result<(), String> {
+        self.worker.process_message(message).await
+    }
+
+    /// WebSocket 서버 Consumer 실행 (Mock)
+    pub async fn run(&self) -> Result<(), String> {
+        info!("WebSocket 서버 Consumer 시작 (Mock): {}", self.server_id);
+        
+        // WebSocket 서버 전용 처리 로직
+        self.worker.run().await
+    }
+
+    /// 연결 수 증가
+    pub async fn increment_connections(&self) -> Result<(), String> {
+        let mut connections = self.current_connections.lock().await;
+        if *connections < self.max_connections {
+            *connections += 1;
+            info!("WebSocket 연결 증가 (Mock): {} ({}/{})", 
+                  self.server_id, *connections, self.max_connections);
+            Ok(())
+        } else {
+            Err(format!("최대 연결 수 초과: {} (최대: {})", *connections, self.max_connections))
+        }
+    }
+
+    /// 연결 수 감소
+    pub async fn decrement_connections(&self) {
+        let mut connections = self.current_connections.lock().await;
+        if *connections > 0 {
+            *connections -= 1;
+            info!("WebSocket 연결 감소 (Mock): {} ({}/{})", 
+                  self.server_id, *connections, self.max_connections);
+        }
+    }
+
+    /// 현재 연결 수 조회
+    pub async fn get_current_connections(&self) -> u32 {
+        let connections = self.current_connections.lock().await;
+        *connections
+    }
+
+    /// 서버 상태 조회
+    pub async fn get_server_status(&self) -> ServerStatus {
+        let connections = self.current_connections.lock().await;
+        let processed_count = self.worker.get_processed_count().await;
+        let is_connected = self.worker.is_connected().await;
+        
+        ServerStatus {
+            server_id: self.server_id.clone(),
+            current_connections: *connections,
+            max_connections: self.max_connections,
+            processed_messages: processed_count,
+            delivered_messages: self.delivery.delivered(),
+            dropped_messages: self.delivery.dropped(),
+            is_connected,
+            last_activity: std::time::SystemTime::now(),
+        }
+    }
+}
+
+/// 로드밸런서 설정
+#[derive(Debug, Clone)]
+pub struct LoadBalancerConfig {
+    /// 서버당 해시 링 가상 노드 수
+    pub virtual_nodes: usize,
+    /// 하트비트 간격 (로드밸런서가 직접 실행하는 서버)
+    pub heartbeat_interval: Duration,
+    /// 이 시간 동안 하트비트가 없으면 서버를 링에서 제거
+    pub heartbeat_timeout: Duration,
+}
+
+impl Default for LoadBalancerConfig {
+    fn default() -> Self {
+        Self {
+            virtual_nodes: DEFAULT_VIRTUAL_NODES,
+            heartbeat_interval: Duration::from_secs(5),
+            heartbeat_timeout: Duration::from_secs(15),
+        }
+    }
+}
+
+/// 클라이언트 재배정 (재연결 필요)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientMove {
+    pub client_id: String,
+    /// 이전 서버 (죽은 서버였으면 연결이 이미 끊김)
+    pub from: String,
+    /// 새 서버 (받을 서버가 없으면 None)
+    pub to: Option<String>,
+}
+
+/// 등록된 WebSocket 서버
+struct RegisteredServer {
+    consumer: Arc<WebSocketServerConsumer>,
+    last_heartbeat: Instant,
+}
+
+/// 서버 목록, 해시 링, 클라이언트 배정
+struct BalancerState {
+    servers: BTreeMap<String, RegisteredServer>,
+    ring: HashRing,
+    /// 클라이언트 ID → 서버 ID
+    assignments: HashMap<String, String>,
+}
+
+/// 로드밸런서 Consumer
+///
+/// 클라이언트 연결은 일관 해시 링으로 WebSocket 서버 큐에 배정하고, 하트비트로 서버를 등록/제거합니다.
+/// 서버가 들어오거나 죽으면 링에서 맡는 서버가 바뀐 클라이언트만 옮기고 `subscribe`로 알립니다.
+pub struct LoadBalancerConsumer {
+    config: LoadBalancerConfig,
+    state: Arc<RwLock<BalancerState>>,
+    /// 처음 설정된 서버 (`run`에서 직접 실행하고 대신 하트비트)
+    local_servers: Vec<Arc<WebSocketServerConsumer>>,
+    current_index: Arc<Mutex<usize>>,
+    moves_tx: broadcast::Sender<ClientMove>,
+}
+
+impl LoadBalancerConsumer {
+    /// 새 로드밸런서 Consumer 생성 (Mock)
+    pub async fn new(server_configs: Vec<(RabbitMQConsumerConfig, String, u32)>) -> Result<Self, String> {
+        let mut local_servers = Vec::new();
+        
+        for (config, server_id, max_connections) in server_configs {
+            let worker = Arc::new(WebSocketServerConsumer::new(config, server_id, max_connections).await?);
+            local_servers.push(worker);
+        }
+        
+        info!("로드밸런서 Consumer 초기화 완료 (Mock): {}개 서버", local_servers.len());
+        
+        let config = LoadBalancerConfig::default();
+        let (moves_tx, _) = broadcast::channel(1024);
+        let balancer = Self {
+            state: Arc::new(RwLock::new(BalancerState {
+                servers: BTreeMap::new(),
+                ring: HashRing::new(config.virtual_nodes),
+                assignments: HashMap::new(),
+            })),
+            config,
+            local_servers: Vec::new(),
+            current_index: Arc::new(Mutex::new(0)),
+            moves_tx,
+        };
+        Ok(balancer.with_local_servers(local_servers))
+    }
+
+    /// 설정 변경 (가상 노드 수를 바꾸면 링을 다시 구성)
+    pub fn with_config(mut self, config: LoadBalancerConfig) -> Self {
+        let state = Arc::get_mut(&mut self.state).expect("실행 전 설정").get_mut();
+        state.ring = HashRing::new(config.virtual_nodes);
+        for server_id in state.servers.keys() {
+            state.ring.add(server_id);
+        }
+        self.config = config;
+        self
+    }
+
+    fn with_local_servers(mut self, servers: Vec<Arc<WebSocketServerConsumer>>) -> Self {
+        let state = Arc::get_mut(&mut self.state).expect("실행 전 설정").get_mut();
+        for consumer in &servers {
+            state.ring.add(&consumer.server_id);
+            state.servers.insert(
+                consumer.server_id.clone(),
+                RegisteredServer { consumer: consumer.clone(), last_heartbeat: Instant::now() },
+            );
+        }
+        self.local_servers = servers;
+        self
+    }
+
+    /// 로드밸런서 Consumer 실행 (Mock)
+    ///
+    /// 처음 설정된 서버 Worker를 실행하며 대신 하트비트를 보내고,
+    /// 하트비트가 끊긴 서버를 주기적으로 링에서 제거합니다.
+    pub async fn run(&self) -> Result<(), String> {
+        info!("로드밸런서 Consumer 시작 (Mock)");
+        
+        // 모든 서버 Worker 시작 (이 future가 중단되면 Worker도 함께 중단)
+        let mut workers = tokio::task::JoinSet::new();
+        
+        for worker in &self.local_servers {
+            let worker_clone = worker.clone();
+            let state = self.state.clone();
+            let heartbeat_interval = self.config.heartbeat_interval;
+            workers.spawn(async move {
+                let heartbeat = async {
+                    let mut ticker = tokio::time::interval(heartbeat_interval);
+                    loop {
+                        ticker.tick().await;
+                        Self::record_heartbeat(&state, &worker_clone.server_id).await;
+                    }
+                };
+                // Worker가 멈추면 하트비트도 멈춰 만료 후 링에서 빠짐
+                tokio::select! {
+                    result = worker_clone.run() => {
+                        if let Err(e) = result {
+                            error!("WebSocket 서버 Worker 실행 오류: {}", e);
+                        }
+                    }
+                    _ = heartbeat => {}
+                }
+            });
+        }
+
+        let state = self.state.clone();
+        let moves_tx = self.moves_tx.clone();
+        let config = self.config.clone();
+        workers.spawn(async move {
+            let mut ticker = tokio::time::interval(config.heartbeat_timeout / 3);
+            loop {
+                ticker.tick().await;
+                Self::expire(&state, &moves_tx, config.heartbeat_timeout).await;
+            }
+        });
+        
+        // 모든 Worker가 종료될 때까지 대기
+        while workers.join_next().await.is_some() {}
+        
+        Ok(())
+    }
+
+    /// 클라이언트 재배정 알림 구독
+    pub fn subscribe(&self) -> broadcast::Receiver<ClientMove> {
+        self.moves_tx.subscribe()
+    }
+
+    /// 서버 등록 (하트비트 시작), 새 서버가 맡게 된 클라이언트 재배정
+    pub async fn register_server(&self, consumer: Arc<WebSocketServerConsumer>) -> Vec<ClientMove> {
+        let mut state = self.state.write().await;
+        let server_id = consumer.server_id.clone();
+        state.ring.add(&server_id);
+        state.servers.insert(server_id.clone(), RegisteredServer { consumer, last_heartbeat: Instant::now() });
+        info!("WebSocket 서버 등록: {} ({}개 서버)", server_id, state.servers.len());
+
+        let moves = Self::rebalance(&mut state, None).await;
+        drop(state);
+        self.publish(&moves);
+        moves
+    }
+
+    /// 서버 제거, 그 서버의 클라이언트를 링의 다음 서버로 재배정
+    pub async fn deregister_server(&self, server_id: &str) -> Vec<ClientMove> {
+        let mut state = self.state.write().await;
+        let moves = Self::remove_server(&mut state, server_id).await;
+        drop(state);
+        self.publish(&moves);
+        moves
+    }
+
+    /// 서버 하트비트 (등록되지 않았거나 만료된 서버는 다시 등록해야 함)
+    pub async fn heartbeat(&self, server_id: &str) -> Result<(), String> {
+        if Self::record_heartbeat(&self.state, server_id).await {
+            Ok(())
+        } else {
+            Err(format!("등록되지 않은 WebSocket 서버: {}", server_id))
+        }
+    }
+
+    /// 하트비트가 끊긴 서버 제거
+    pub async fn expire_dead_servers(&self) -> Vec<ClientMove> {
+        Self::expire(&self.state, &self.moves_tx, self.config.heartbeat_timeout).await
+    }
+
+    /// 클라이언트 연결 배정 (이미 배정된 클라이언트는 같은 서버)
+    ///
+    /// 링에서 맡는 서버가 가득 차 있으면 링의 다음 서버에 배정합니다.
+    pub async fn assign_client(&self, client_id: &str) -> Option<Arc<WebSocketServerConsumer>> {
+        let mut state = self.state.write().await;
+        if let Some(server) = state.assignments.get(client_id).and_then(|id| state.servers.get(id)) {
+            return Some(server.consumer.clone());
+        }
+
+        let server = Self::place(&state, client_id).await?;
+        state.assignments.insert(client_id.to_string(), server.server_id.clone());
+        Some(server)
+    }
+
+    /// 클라이언트 연결 해제
+    pub async fn release_client(&self, client_id: &str) {
+        let mut state = self.state.write().await;
+        if let Some(server) = state.assignments.remove(client_id).and_then(|id| state.servers.get(&id)) {
+            server.consumer.decrement_connections().await;
+        }
+    }
+
+    /// 클라이언트가 배정된 서버 ID
+    pub async fn assigned_server(&self, client_id: &str) -> Option<String> {
+        self.state.read().await.assignments.get(client_id).cloned()
+    }
+
+    /// 라운드 로빈으로 서버 선택
+    pub async fn select_server(&self) -> Option<Arc<WebSocketServerConsumer>> {
+        let servers = self.servers().await;
+        if servers.is_empty() {
+            return None;
+        }
+        
+        let mut index = self.current_index.lock().await;
+        let selected = servers[*index % servers.len()].clone();
+        *index = (*index + 1) % servers.len();
+        
+        Some(selected)
+    }
+
+    /// 최소 연결 수를 가진 서버 선택
+    pub async fn select_least_loaded_server(&self) -> Option<Arc<WebSocketServerConsumer>> {
+        let mut min_connections = u32::MAX;
+        let mut selected_server = None;
+        
+        for worker in self.servers().await {
+            let connections = worker.get_current_connections().await;
+            if connections < min_connections {
+                min_connections = connections;
+                selected_server = Some(worker);
+            }
+        }
+        
+        selected_server
+    }
+
+    /// 모든 서버 상태 조회
+    pub async fn get_all_server_status(&self) -> Vec<ServerStatus> {
+        let mut statuses = Vec::new();
+        
+        for worker in self.servers().await {
+            let status = worker.get_server_status().await;
+            statuses.push(status);
+        }
+        
+        statuses
+    }
+
+    /// 등록된 서버 (서버 ID순)
+    async fn servers(&self) -> Vec<Arc<WebSocketServerConsumer>> {
+        self.state.read().await.servers.values().map(|s| s.consumer.clone()).collect()
+    }
+
+    async fn record_heartbeat(state: &Arc<RwLock<BalancerState>>, server_id: &str) -> bool {
+        match state.write().await.servers.get_mut(server_id) {
+            Some(server) => {
+                server.last_heartbeat = Instant::now();
+                true
+            }
+            None => false,
+        }
+    }
+
+    async fn expire(
+        state: &Arc<RwLock<BalancerState>>,
+        moves_tx: &broadcast::Sender<ClientMove>,
+        timeout: Duration,
+    ) -> Vec<ClientMove> {
+        let mut state = state.write().await;
+        let dead: Vec<String> = state
+            .servers
+            .iter()
+            .filter(|(_, server)| server.last_heartbeat.elapsed() > timeout)
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        let mut moves = Vec::new();
+        for server_id in dead {
+            warn!("WebSocket 서버 하트비트 만료: {}", server_id);
+            moves.extend(Self::remove_server(&mut state, &server_id).await);
+        }
+        for client_move in &moves {
+            let _ = moves_tx.send(client_move.clone());
+        }
+        moves
+    }
+
+    async fn remove_server(state: &mut BalancerState, server_id: &str) -> Vec<ClientMove> {
+        if state.servers.remove(server_id).is_none() {
+            return Vec::new();
+        }
+        state.ring.remove(server_id);
+        info!("WebSocket 서버 제거: {} ({}개 서버)", server_id, state.servers.len());
+        Self::rebalance(state, Some(server_id)).await
+    }
+
+    /// 링에서 맡는 서버가 바뀐 클라이언트만 옮김 (`removed`: 방금 빠진 서버)
+    async fn rebalance(state: &mut BalancerState, removed: Option<&str>) -> Vec<ClientMove> {
+        let mut clients: Vec<(String, String)> = state
+            .assignments
+            .iter()
+            .filter(|(client_id, server_id)| {
+                Some(server_id.as_str()) == removed || state.ring.get(client_id) != Some(server_id.as_str())
+            })
+            .map(|(client_id, server_id)| (client_id.clone(), server_id.clone()))
+            .collect();
+        clients.sort();
+
+        let mut moves = Vec::new();
+        for (client_id, from) in clients {
+            let to = Self::place(state, &client_id).await;
+            if to.as_ref().map(|server| &server.server_id) == Some(&from) {
+                // 링에서 맡는 서버가 가득 차 그대로 남음 (place가 늘린 연결 수 되돌림)
+                if let Some(server) = to {
+                    server.decrement_connections().await;
+                }
+                continue;
+            }
+            if let Some(server) = state.servers.get(&from) {
+                server.consumer.decrement_connections().await;
+            }
+
+            match &to {
+                Some(server) => {
+                    state.assignments.insert(client_id.clone(), server.server_id.clone());
+                }
+                None => {
+                    state.assignments.remove(&client_id);
+                }
+            }
+            moves.push(ClientMove {
+                client_id,
+                from,
+                to: to.map(|server| server.server_id.clone()),
+            });
+        }
+
+        if !moves.is_empty() {
+            info!("클라이언트 재배정: {}명", moves.len());
+        }
+        moves
+    }
+
+    /// 링 순서로 연결을 받을 수 있는 서버를 찾아 연결 수 증가
+    async fn place(state: &BalancerState, client_id: &str) -> Option<Arc<WebSocketServerConsumer>> {
+        for server_id in state.ring.candidates(client_id) {
+            let Some(server) = state.servers.get(server_id) else { continue };
+            if server.consumer.increment_connections().await.is_ok() {
+                return Some(server.consumer.clone());
+            }
+        }
+        warn!("클라이언트를 받을 WebSocket 서버 없음: {}", client_id);
+        None
+    }
+
+    fn publish(&self, moves: &[ClientMove]) {
+        for client_move in moves {
+            // 구독자가 없으면 버림
+            let _ = self.moves_tx.send(client_move.clone());
+        }
+    }
+}
+
+/// 서버 상태 정보
+#[derive(Debug, Clone)]
+pub struct ServerStatus {
+    pub server_id: String,
+    pub current_connections: u32,
+    pub max_connections: u32,
+    pub processed_messages: u64,
+    /// 연결로 전달한 알림 수
+    pub delivered_messages: u64,
+    /// 큐가 가득 찼거나 끊긴 연결이라 버린 알림 수
+    pub dropped_messages: u64,
+    pub is_connected: bool,
+    pub last_activity: std::time::SystemTime,
+}
+
+/// 메시지별 재시도 기록 보관 시간 (그동안 DLQ로 다시 오지 않으면 재시도에 성공한 것으로 봄)
+const DLQ_RETRY_STATE_TTL: Duration = Duration::from_secs(3600);
+
+/// Dead Letter Queue 메시지 처리 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeadLetterOutcome {
+    /// 원래 큐로 재시도 (재시도 횟수)
+    Retried(u32),
+    /// 격리 저장소로 이동 (격리 ID)
+    Quarantined(String),
+}
+
+/// Dead Letter Queue Consumer
+///
+/// 독성 메시지는 재시도 없이, 일시적 실패는 메시지별 최대 재시도 후 격리 저장소로 보냅니다.
+pub struct DeadLetterQueueConsumer {
+    worker: RabbitMQConsumerWorker,
+    /// 메시지 ID별 (재시도 횟수, 마지막 재시도 시각)
+    retry_counts: Arc<Mutex<HashMap<String, (u32, Instant)>>>,
+    max_retries: u32,
+    quarantine: Option<Arc<QuarantineStore>>,
+}
+
+impl DeadLetterQueueConsumer {
+    /// 새 Dead Letter Queue Consumer 생성 (Mock)
+    pub async fn new(config: RabbitMQConsumerConfig, max_retries: u32) -> Result<Self, String> {
+        let worker = RabbitMQConsumerWorker::new(config).await?;
+        
+        info!("Dead Letter Queue Consumer 초기화 완료 (Mock)");
+        
+        Ok(Self {
+            worker,
+            retry_counts: Arc::new(Mutex::new(HashMap::new())),
+            max_retries,
+            quarantine: None,
+        })
+    }
+
+    /// 처리할 수 없는 메시지를 보관할 격리 저장소 설정
+    pub fn with_quarantine(mut self, quarantine: Arc<QuarantineStore>) -> Self {
+        self.quarantine = Some(quarantine);
+        self
+    }
+
+    /// Dead Letter Queue Consumer 실행 (Mock)
+    pub async fn run(&self) -> Result<(), String> {
+        info!("Dead Letter Queue Consumer 시작 (Mock)");
+        
+        // Dead Letter Queue 전용 처리 로직
+        self.worker.run().await
+    }
+
+    /// DLQ 메시지 처리
+    ///
+    /// 독성 메시지(역직렬화 실패, 필수 필드 누락)는 곧바로 격리하고,
+    /// 일시적 실패는 재시도하다가 최대 재시도 횟수를 넘기면 격리합니다.
+    pub async fn handle(&self, letter: &DeadLetter) -> Result<DeadLetterOutcome, String> {
+        let message = match classify(letter) {
+            Classification::Transient(message) => message,
+            Classification::Poison { reason, detail } => {
+                return self.quarantine(letter, reason, &detail, 0).await;
+            }
+        };
+
+        match self.retry_failed_message(&message).await {
+            Ok(attempt) => Ok(DeadLetterOutcome::Retried(attempt)),
+            Err(e) => {
+                self.retry_counts.lock().await.remove(&message.message_id);
+                self.quarantine(letter, QuarantineReason::RetriesExhausted, &e, self.max_retries).await
+            }
+        }
+    }
+
+    /// 실패한 메시지 재처리 (메시지별 재시도 횟수 반환)
+    pub async fn retry_failed_message(&self, message: &WebSocketNotificationMessage) -> Result<u32, String> {
+        let mut retry_counts = self.retry_counts.lock().await;
+        let now = Instant::now();
+        retry_counts.retain(|_, (_, last_retry)| now.duration_since(*last_retry) < DLQ_RETRY_STATE_TTL);
+
+        let (retry_count, last_retry) = retry_counts.entry(message.message_id.clone()).or_insert((0, now));
+        if *retry_count < self.max_retries {
+            *retry_count += 1;
+            *last_retry = now;
+            info!("실패한 메시지 재처리 (Mock): {} (재시도: {}/{})", 
+                  message.message_id, *retry_count, self.max_retries);
+            
+            // TODO: 실제 재처리 로직 구현
+            // - 원본 큐로 메시지 재전송
+            // - 지수 백오프 적용
+            
+            Ok(*retry_count)
+        } else {
+            error!("최대 재시도 횟수 초과 (Mock): {}", message.message_id);
+            Err(format!("최대 재시도 횟수 초과: {}", self.max_retries))
+        }
+    }
+
+    async fn quarantine(
+        &self,
+        letter: &DeadLetter,
+        reason: QuarantineReason,
+        detail: &str,
+        attempts: u32,
+    ) -> Result<DeadLetterOutcome, String> {
+        let Some(store) = &self.quarantine else {
+            error!("DLQ 격리 저장소가 없어 메시지를 버립니다: {} ({})", letter.routing_key, detail);
+            return Err(format!("격리 저장소 없음: {}", detail));
+        };
+        let error = match &letter.error {
+            Some(original) => format!("{} (원래 실패 사유: {})", detail, original),
+            None => detail.to_string(),
+        };
+        let message = store
+            .quarantine(letter, reason, &error, attempts)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(DeadLetterOutcome::Quarantined(message.id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rabbitmq_consumer_config() {
+        let config = RabbitMQConsumerConfig {
+            rabbitmq_url: "amqp://localhost:5672".to_string(),
+            exchange_name: "websocket_notifications".to_string(),
+            queue_name: "ws-server-1".to_string(),
+            routing_patterns: vec!["execution.*".to_string(), "orderbook.*".to_string()],
+            worker_id: "ws-worker-1".to_string(),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+        };
+        
+        assert_eq!(config.worker_id, "ws-worker-1");
+        assert_eq!(config.batch_size, 100);
+        assert_eq!(config.routing_patterns.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rabbitmq_consumer_worker_mock() {
+        let config = RabbitMQConsumerConfig {
+            rabbitmq_url: "amqp://localhost:5672".to_string(),
+            exchange_name: "websocket_notifications".to_string(),
+            queue_name: "ws-server-1".to_string(),
+            routing_patterns: vec!["execution.*".to_string()],
+            worker_id: "ws-worker-1".to_string(),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+        };
+        
+        let worker = RabbitMQConsumerWorker::new(config).await.unwrap();
+        assert_eq!(worker.worker_id, "ws-worker-1");
+        assert_eq!(worker.exchange_name, "websocket_notifications");
+    }
+
+    #[tokio::test]
+    async fn test_pattern_matching() {
+        let config = RabbitMQConsumerConfig {
+            rabbitmq_url: "amqp://localhost:5672".to_string(),
+            exchange_name: "websocket_notifications".to_string(),
+            queue_name: "ws-server-1".to_string(),
+            routing_patterns: vec!["execution.*".to_string(), "orderbook.*".to_string()],
+            worker_id: "ws-worker-1".to_string(),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+        };
+        
+        let worker = RabbitMQConsumerWorker::new(config).await.unwrap();
+        
+        // 패턴 매칭 테스트
+        assert!(worker.matches_routing_patterns("execution.BTC-KRW"));
+        assert!(worker.matches_routing_patterns("orderbook.delta.BTC-KRW"));
+        assert!(!worker.matches_routing_patterns("market.stats.BTC-KRW"));
+    }
+
+    #[tokio::test]
+    async fn test_websocket_server_consumer_mock() {
+        let config = RabbitMQConsumerConfig {
+            rabbitmq_url: "amqp://localhost:5672".to_string(),
+            exchange_name: "websocket_notifications".to_string(),
+            queue_name: "ws-server-1".to_string(),
+            routing_patterns: vec!["execution.*".to_string()],
+            worker_id: "ws-worker-1".to_string(),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+        };
+        
+        let consumer = WebSocketServerConsumer::new(config, "ws-server-1".to_string(), 1000).await.unwrap();
+        assert_eq!(consumer.server_id, "ws-server-1");
+        assert_eq!(consumer.max_connections, 1000);
+        
+        // 연결 수 증가 테스트
+        let result = consumer.increment_connections().await;
+        assert!(result.is_ok());
+        
+        let connections = consumer.get_current_connections().await;
+        assert_eq!(connections, 1);
+    }
+
+    #[tokio::test]
+    async fn test_load_balancer_consumer_mock() {
+        let config1 = RabbitMQConsumerConfig {
+            rabbitmq_url: "amqp://localhost:5672".to_string(),
+            exchange_name: "websocket_notifications".to_string(),
+            queue_name: "ws-server-1".to_string(),
+            routing_patterns: vec!["execution.*".to_string()],
+            worker_id: "ws-worker-1".to_string(),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+        };
+        
+        let config2 = RabbitMQConsumerConfig {
+            rabbitmq_url: "amqp://localhost:5672".to_string(),
+            exchange_name: "websocket_notifications".to_string(),
+            queue_name: "ws-server-2".to_string(),
+            routing_patterns: vec!["execution.*".to_string()],
+            worker_id: "ws-worker-2".to_string(),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+        };
+        
+        let server_configs = vec![
+            (config1, "ws-server-1".to_string(), 1000),
+            (config2, "ws-server-2".to_string(), 1000),
+        ];
+        
+        let load_balancer = LoadBalancerConsumer::new(server_configs).await.unwrap();
+        
+        // 서버 선택 테스트
+        let server1 = load_balancer.select_server().await;
+        assert!(server1.is_some());
+        
+        let server2 = load_balancer.select_server().await;
+        assert!(server2.is_some());
+        
+        // 서버 상태 조회 테스트
+        let statuses = load_balancer.get_all_server_status().await;
+        assert_eq!(statuses.len(), 2);
+    }
+
+    async fn ws_server(server_id: &str, max_connections: u32) -> Arc<WebSocketServerConsumer> {
+        let config = RabbitMQConsumerConfig {
+            rabbitmq_url: "amqp://localhost:5672".to_string(),
+            exchange_name: "websocket_notifications".to_string(),
+            queue_name: server_id.to_string(),
+            routing_patterns: vec!["execution.*".to_string()],
+            worker_id: format!("{}-worker", server_id),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+        };
+        Arc::new(WebSocketServerConsumer::new(config, server_id.to_string(), max_connections).await.unwrap())
+    }
+
+    async fn assignments(load_balancer: &LoadBalancerConsumer, clients: &[String]) -> HashMap<String, String> {
+        let mut assigned = HashMap::new();
+        for client in clients {
+            assigned.insert(client.clone(), load_balancer.assigned_server(client).await.unwrap());
+        }
+        assigned
+    }
+
+    #[tokio::test]
+    async fn test_consistent_hash_rebalancing() {
+        let load_balancer = LoadBalancerConsumer::new(vec![]).await.unwrap();
+        let mut moves_rx = load_balancer.subscribe();
+        let (ws1, ws2) = (ws_server("ws-server-1", 1000).await, ws_server("ws-server-2", 1000).await);
+        load_balancer.register_server(ws1.clone()).await;
+        load_balancer.register_server(ws2.clone()).await;
+
+        let clients: Vec<String> = (0..200).map(|i| format!("client-{}", i)).collect();
+        for client in &clients {
+            let first = load_balancer.assign_client(client).await.unwrap();
+            // 같은 클라이언트는 같은 서버
+            assert_eq!(load_balancer.assign_client(client).await.unwrap().server_id, first.server_id);
+        }
+        assert_eq!(ws1.get_current_connections().await + ws2.get_current_connections().await, 200);
+        let before = assignments(&load_balancer, &clients).await;
+
+        // 새 서버는 자기 구간의 클라이언트만 가져감
+        let ws3 = ws_server("ws-server-3", 1000).await;
+        let moves = load_balancer.register_server(ws3.clone()).await;
+        assert!(!moves.is_empty());
+        assert!(moves.iter().all(|m| m.to.as_deref() == Some("ws-server-3")));
+        assert_eq!(ws3.get_current_connections().await as usize, moves.len());
+        assert_eq!(moves_rx.try_recv().unwrap(), moves[0]);
+
+        // 죽은 서버(하트비트 만료)의 클라이언트만 남은 서버로 옮겨짐
+        let after_join = assignments(&load_balancer, &clients).await;
+        let load_balancer = load_balancer.with_config(LoadBalancerConfig {
+            heartbeat_timeout: Duration::from_millis(20),
+            ..LoadBalancerConfig::default()
+        });
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        load_balancer.heartbeat("ws-server-1").await.unwrap();
+        load_balancer.heartbeat("ws-server-3").await.unwrap();
+        let moves = load_balancer.expire_dead_servers().await;
+        assert_eq!(moves.len(), after_join.values().filter(|s| *s == "ws-server-2").count());
+        assert!(moves.iter().all(|m| m.from == "ws-server-2" && m.to.is_some()));
+        assert!(load_balancer.heartbeat("ws-server-2").await.is_err());
+
+        let after_expire = assignments(&load_balancer, &clients).await;
+        for client in &clients {
+            if after_join[client] != "ws-server-3" {
+                assert_eq!(after_join[client], before[client]);
+            }
+            if after_join[client] != "ws-server-2" {
+                assert_eq!(after_expire[client], after_join[client]);
+            }
+        }
+        assert_eq!(ws1.get_current_connections().await + ws3.get_current_connections().await, 200);
+
+        load_balancer.release_client("client-0").await;
+        assert_eq!(ws1.get_current_connections().await + ws3.get_current_connections().await, 199);
+        assert!(load_balancer.assigned_server("client-0").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_full_server_falls_back_along_ring() {
+        let load_balancer = LoadBalancerConsumer::new(vec![]).await.unwrap();
+        let (ws1, ws2) = (ws_server("ws-server-1", 1).await, ws_server("ws-server-2", 1).await);
+        load_balancer.register_server(ws1).await;
+        load_balancer.register_server(ws2).await;
+
+        let first = load_balancer.assign_client("client-a").await.unwrap();
+        let second = load_balancer.assign_client("client-b").await.unwrap();
+        assert_ne!(first.server_id, second.server_id);
+        // 모든 서버가 가득 참
+        assert!(load_balancer.assign_client("client-c").await.is_none());
+
+        // 서버가 빠지고 받을 곳이 없으면 배정 해제
+        let moves = load_balancer.deregister_server(&first.server_id).await;
+        assert_eq!(moves, vec![ClientMove { client_id: "client-a".to_string(), from: first.server_id.clone(), to: None }]);
+        assert!(load_balancer.assigned_server("client-a").await.is_none());
+    }
+
+    fn notification(id: &str, routing_key: &str, user_id: Option<&str>) -> WebSocketNotificationMessage {
+        WebSocketNotificationMessage {
+            message_id: id.to_string(),
+            routing_key: routing_key.to_string(),
+            message_type: "execution".to_string(),
+            symbol: Some("BTC-KRW".to_string()),
+            user_id: user_id.map(str::to_string),
+            data: serde_json::json!({}),
+            timestamp: 0,
+            priority: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notifications_delivered_to_subscribed_connections() {
+        let server = ws_server("ws-server-1", 1000).await;
+        let delivery = server.delivery();
+        let mut trader = delivery.connect("conn-1", Some("trader_1"), vec!["execution.*".to_string()]).await;
+        let mut other = delivery.connect("conn-2", Some("trader_2"), vec!["*".to_string()]).await;
+
+        for i in 0..3 {
+            server.handle_message(&notification(&format!("exec-{}", i), "execution.BTC-KRW", None)).await.unwrap();
+        }
+        // 사용자 알림은 그 계정 연결에만, 서버 큐 패턴 밖의 알림은 전달하지 않음
+        server.handle_message(&notification("private", "execution.BTC-KRW", Some("trader_1"))).await.unwrap();
+        server.handle_message(&notification("ticker", "ticker.BTC-KRW", None)).await.unwrap();
+
+        // 연결마다 받은 순서대로 도착
+        let mut received = Vec::new();
+        while let Ok(message) = trader.try_recv() {
+            received.push(message.message_id);
+        }
+        assert_eq!(received, vec!["exec-0", "exec-1", "exec-2", "private"]);
+        let mut received = Vec::new();
+        while let Ok(message) = other.try_recv() {
+            received.push(message.message_id);
+        }
+        assert_eq!(received, vec!["exec-0", "exec-1", "exec-2"]);
+
+        // 끊긴 연결은 버린 알림으로 세고 구독 해제
+        drop(other);
+        server.handle_message(&notification("exec-3", "execution.BTC-KRW", None)).await.unwrap();
+        assert_eq!(delivery.connection_count().await, 1);
+        let status = server.get_server_status().await;
+        assert_eq!((status.delivered_messages, status.dropped_messages), (8, 1));
+        assert!(delivery.disconnect("conn-1").await);
+    }
+
+    #[tokio::test]
+    async fn test_full_connection_queue_drops_notifications() {
+        let delivery = NotificationDelivery::new(2);
+        let mut slow = delivery.connect("slow", None, vec!["execution.*".to_string()]).await;
+        let _fast = delivery.connect("fast", None, vec!["execution.*".to_string()]).await;
+
+        for i in 0..3 {
+            delivery.deliver(&notification(&format!("exec-{}", i), "execution.BTC-KRW", None)).await;
+        }
+        // 큐가 가득 찬 뒤의 알림만 버리고 순서는 유지
+        assert_eq!(slow.recv().await.unwrap().message_id, "exec-0");
+        assert_eq!(slow.recv().await.unwrap().message_id, "exec-1");
+        assert!(slow.try_recv().is_err());
+        assert_eq!((delivery.delivered(), delivery.dropped()), (4, 2));
+    }
+
+    #[tokio::test]
+    async fn test_dead_letter_quarantine() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::store::create_tables(&pool).await.unwrap();
+        let store = Arc::new(QuarantineStore::new(pool, Arc::new(crate::dead_letter::MemoryAuditSink::default())));
+        let config = RabbitMQConsumerConfig {
+            rabbitmq_url: "amqp://localhost:5672".to_string(),
+            exchange_name: "websocket_notifications".to_string(),
+            queue_name: "websocket_notifications.dlq".to_string(),
+            routing_patterns: vec!["*".to_string()],
+            worker_id: "dlq-worker".to_string(),
+            batch_size: 50,
+            processing_interval_ms: 5000,
+        };
+        let consumer = DeadLetterQueueConsumer::new(config, 2).await.unwrap().with_quarantine(store.clone());
+
+        // 독성 메시지는 재시도 없이 격리
+        let poison = DeadLetter { routing_key: "execution.BTC-KRW".to_string(), payload: "{".to_string(), error: None };
+        let DeadLetterOutcome::Quarantined(poison_id) = consumer.handle(&poison).await.unwrap() else {
+            panic!("독성 메시지는 격리되어야 함");
+        };
+        let quarantined = store.get(&poison_id).await.unwrap();
+        assert_eq!((quarantined.reason, quarantined.attempts), (QuarantineReason::Deserialization, 0));
+
+        // 일시적 실패는 메시지별로 재시도 횟수를 세고, 초과하면 격리
+        let message = |id: &str| WebSocketNotificationMessage {
+            message_id: id.to_string(),
+            routing_key: "execution.BTC-KRW".to_string(),
+            message_type: "execution".to_string(),
+            symbol: Some("BTC-KRW".to_string()),
+            user_id: None,
+            data: serde_json::json!({}),
+            timestamp: 0,
+            priority: 1,
+        };
+        let transient = DeadLetter::from_message(&message("msg-1"), Some("consumer timeout".to_string()));
+        assert_eq!(consumer.handle(&transient).await.unwrap(), DeadLetterOutcome::Retried(1));
+        assert_eq!(consumer.handle(&transient).await.unwrap(), DeadLetterOutcome::Retried(2));
+        // 다른 메시지의 재시도 횟수는 따로 셈
+        let other = DeadLetter::from_message(&message("msg-2"), None);
+        assert_eq!(consumer.handle(&other).await.unwrap(), DeadLetterOutcome::Retried(1));
+
+        let DeadLetterOutcome::Quarantined(id) = consumer.handle(&transient).await.unwrap() else {
+            panic!("재시도 초과 메시지는 격리되어야 함");
+        };
+        let quarantined = store.get(&id).await.unwrap();
+        assert_eq!((quarantined.reason, quarantined.attempts), (QuarantineReason::RetriesExhausted, 2));
+        assert!(quarantined.error.contains("consumer timeout"));
+    }
+}
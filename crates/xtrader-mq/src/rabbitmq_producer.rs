@@ -6,8 +6,8 @@
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use log::{info, error};
-use crate::api::models::WebSocketMessage;
+use log::info;
+use xtrader_api::models::WebSocketMessage;
 
 /// RabbitMQ Producer (Mock 구현)
 pub struct RabbitMQProducer {
@@ -58,6 +58,54 @@ impl From<&WebSocketMessage> for WebSocketNotificationMessage {
                     serde_json::to_value(snapshot).unwrap_or_default(),
                 )
             }
+            WebSocketMessage::Bbo(bbo) => {
+                (
+                    format!("bbo.{}", bbo.symbol),
+                    Some(bbo.symbol.clone()),
+                    None,
+                    serde_json::to_value(bbo).unwrap_or_default(),
+                )
+            }
+            WebSocketMessage::IndexPrice(update) => {
+                (
+                    format!("index.price.{}", update.symbol),
+                    Some(update.symbol.clone()),
+                    None,
+                    serde_json::to_value(update).unwrap_or_default(),
+                )
+            }
+            WebSocketMessage::Funding(event) => {
+                (
+                    format!("funding.{}", event.symbol),
+                    Some(event.symbol.clone()),
+                    None,
+                    serde_json::to_value(event).unwrap_or_default(),
+                )
+            }
+            WebSocketMessage::ContractLifecycle(status) => {
+                (
+                    format!("contract.{}", status.symbol),
+                    Some(status.symbol.clone()),
+                    None,
+                    serde_json::to_value(status).unwrap_or_default(),
+                )
+            }
+            WebSocketMessage::PrivateEvent(event) => {
+                (
+                    format!("private.{}", event.client_id),
+                    Some(event.execution_report.symbol.clone()),
+                    Some(event.client_id.clone()),
+                    serde_json::to_value(event).unwrap_or_default(),
+                )
+            }
+            WebSocketMessage::ThrottleUpdate(update) => {
+                (
+                    format!("private.throttle.{}", update.client_id),
+                    None,
+                    Some(update.client_id.clone()),
+                    serde_json::to_value(update).unwrap_or_default(),
+                )
+            }
             WebSocketMessage::MarketStatistics { symbol, .. } => {
                 (
                     format!("market.stats.{}", symbol),
@@ -66,6 +114,14 @@ impl From<&WebSocketMessage> for WebSocketNotificationMessage {
                     serde_json::to_value(ws_message).unwrap_or_default(),
                 )
             }
+            WebSocketMessage::Ticker { .. } => {
+                (
+                    "market.ticker".to_string(),
+                    None,
+                    None,
+                    serde_json::to_value(ws_message).unwrap_or_default(),
+                )
+            }
             WebSocketMessage::CandlestickUpdate { symbol, .. } => {
                 (
                     format!("candlestick.{}", symbol),
@@ -120,13 +176,21 @@ impl WebSocketNotificationMessage {
     /// WebSocket 메시지 타입 추출
     fn get_message_type(ws_message: &WebSocketMessage) -> String {
         match ws_message {
-            WebSocketMessage::Execution(_) => "execution".to_string(),
+            WebSocketMessage::Execution { .. } => "execution".to_string(),
             WebSocketMessage::OrderBookDelta(_) => "orderbook_delta".to_string(),
             WebSocketMessage::OrderBookSnapshot(_) => "orderbook_snapshot".to_string(),
+            WebSocketMessage::Bbo(_) => "bbo".to_string(),
+            WebSocketMessage::IndexPrice(_) => "index_price".to_string(),
+            WebSocketMessage::Funding(_) => "funding".to_string(),
+            WebSocketMessage::ContractLifecycle(_) => "contract_lifecycle".to_string(),
             WebSocketMessage::OrderBookUpdate { .. } => "orderbook_update".to_string(),
             WebSocketMessage::MarketStatistics { .. } => "market_statistics".to_string(),
+            WebSocketMessage::Ticker { .. } => "ticker".to_string(),
             WebSocketMessage::CandlestickUpdate { .. } => "candlestick_update".to_string(),
             WebSocketMessage::SyncResponse { .. } => "sync_response".to_string(),
+            WebSocketMessage::PrivateEvent(_) => "private_event".to_string(),
+            WebSocketMessage::ThrottleUpdate(_) => "throttle_update".to_string(),
+            WebSocketMessage::ReplayComplete { .. } => "replay_complete".to_string(),
             WebSocketMessage::Error { .. } => "error".to_string(),
         }
     }
@@ -135,9 +199,16 @@ impl WebSocketNotificationMessage {
     fn get_priority_for_message_type(message_type: &str) -> u8 {
         match message_type {
             "execution" => 1,        // 체결: 높은 우선순위
+            "private_event" => 1,    // 계정 이벤트: 높은 우선순위
+            "throttle_update" => 1,  // 주문 속도 제한: 높은 우선순위
             "orderbook_delta" => 2,  // 호가창 변경: 높은 우선순위
             "orderbook_snapshot" => 3, // 호가창 스냅샷: 중간 우선순위
+            "bbo" => 2,              // 최우선 호가: 높은 우선순위
+            "index_price" => 3,      // 지수/공정 가격: 중간 우선순위
+            "funding" => 2,          // 펀딩 정산: 높은 우선순위
+            "contract_lifecycle" => 1, // 만기/정산 상태: 높은 우선순위
             "market_statistics" => 4, // 시장 통계: 중간 우선순위
+            "ticker" => 4,           // 24시간 티커: 중간 우선순위
             "candlestick_update" => 5, // 봉차트: 낮은 우선순위
             "sync_response" => 1,    // 동기화 응답: 높은 우선순위
             "error" => 0,            // 에러: 최고 우선순위
@@ -197,6 +268,11 @@ impl RabbitMQProducer {
         })
     }
 
+    /// 발행 Exchange 이름
+    pub fn exchange_name(&self) -> &str {
+        &self.exchange_name
+    }
+
     /// WebSocket 메시지를 RabbitMQ에 발행 (Mock)
     pub async fn publish_websocket_message(&self, ws_message: &WebSocketMessage) -> Result<String, RabbitMQError> {
         let notification = WebSocketNotificationMessage::from(ws_message);
@@ -213,6 +289,20 @@ impl RabbitMQProducer {
         Ok(notification.message_id)
     }
 
+    /// 알림 메시지를 라우팅 키 그대로 재발행 (Mock, DLQ 격리 메시지 재발행용)
+    pub async fn publish_notification(&self, notification: &WebSocketNotificationMessage) -> Result<String, RabbitMQError> {
+        let message_json = serde_json::to_string(notification)
+            .map_err(|e| RabbitMQError::SerializationError(e.to_string()))?;
+        
+        let mut count = self.messages_sent.lock().await;
+        *count += 1;
+        
+        info!("알림 메시지 RabbitMQ 재발행 완료 (Mock): {} -> {} (메시지 #{}: {})", 
+              notification.message_id, notification.routing_key, *count, message_json);
+        
+        Ok(notification.message_id.clone())
+    }
+
     /// 사용자별 WebSocket 메시지 발행 (Mock)
     pub async fn publish_user_message(&self, ws_message: &WebSocketMessage, user_id: &str) -> Result<String, RabbitMQError> {
         let mut notification = WebSocketNotificationMessage::from(ws_message);
@@ -257,7 +347,7 @@ impl RabbitMQProducer {
         let mut count = self.messages_sent.lock().await;
         let mut published_count = 0;
         
-        for (ws_message, user_id) in messages {
+        for (ws_message, _user_id) in messages {
             let notification = WebSocketNotificationMessage::from(ws_message);
             let message_json = serde_json::to_string(&notification)
                 .map_err(|e| RabbitMQError::SerializationError(e.to_string()))?;
@@ -297,6 +387,12 @@ impl RabbitMQProducer {
         info!("RabbitMQ Dead Letter Queue 설정 완료 (Mock): {}.dlq", self.exchange_name);
         Ok(())
     }
+
+    /// 연결 재생성 후 Exchange 재설정 (Mock)
+    pub async fn reconnect(&self) -> Result<(), RabbitMQError> {
+        info!("RabbitMQ Producer 재연결 완료 (Mock): {}", self.exchange_name);
+        self.setup_exchange().await
+    }
 }
 
 /// Producer 통계 정보
@@ -327,6 +423,14 @@ impl RoutingPatterns {
         format!("orderbook.*.{}", symbol)
     }
     
+    /// 모든 최우선 호가(BBO) 메시지 (전체 호가창 메시지와 분리)
+    pub const BBO_ALL: &'static str = "bbo.*";
+    
+    /// 특정 심볼 최우선 호가 메시지
+    pub fn bbo_symbol(symbol: &str) -> String {
+        format!("bbo.{}", symbol)
+    }
+    
     /// 모든 시장 통계 메시지
     pub const MARKET_STATS_ALL: &'static str = "market.stats.*";
     
@@ -357,8 +461,8 @@ impl RoutingPatterns {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::api::models::WebSocketMessage;
-    use crate::matching_engine::model::{ExecutionReport, Side};
+    use xtrader_api::models::WebSocketMessage;
+    use xtrader_engine::model::{ExecutionReport, ExecType, Side};
 
     fn create_test_execution() -> ExecutionReport {
         ExecutionReport {
@@ -369,15 +473,19 @@ mod tests {
             quantity: 100,
             timestamp: 1234567890,
             order_id: "order_001".to_string(),
-            user_id: "user_001".to_string(),
+            remaining_quantity: 0,
+            counterparty_id: "order_002".to_string(),
             is_maker: true,
+            exec_type: ExecType::Trade,
+            sequence: 0,
+            tag: None,
         }
     }
 
     #[tokio::test]
     async fn test_websocket_notification_message_conversion() {
         let execution = create_test_execution();
-        let ws_message = WebSocketMessage::Execution(execution);
+        let ws_message = WebSocketMessage::Execution { execution_report: execution, order_status: "Filled".to_string() };
         let notification = WebSocketNotificationMessage::from(&ws_message);
         
         assert_eq!(notification.message_type, "execution");
@@ -389,7 +497,7 @@ mod tests {
     #[tokio::test]
     async fn test_user_routing_key() {
         let execution = create_test_execution();
-        let ws_message = WebSocketMessage::Execution(execution);
+        let ws_message = WebSocketMessage::Execution { execution_report: execution, order_status: "Filled".to_string() };
         let notification = WebSocketNotificationMessage::from(&ws_message);
         
         let user_routing = notification.with_user_routing("user_123");
@@ -399,7 +507,7 @@ mod tests {
     #[tokio::test]
     async fn test_symbol_routing_key() {
         let execution = create_test_execution();
-        let ws_message = WebSocketMessage::Execution(execution);
+        let ws_message = WebSocketMessage::Execution { execution_report: execution, order_status: "Filled".to_string() };
         let notification = WebSocketNotificationMessage::from(&ws_message);
         
         let symbol_routing = notification.with_symbol_routing();
@@ -411,7 +519,7 @@ mod tests {
         let producer = RabbitMQProducer::new("amqp://localhost:5672", "websocket_notifications").await.unwrap();
         
         let execution = create_test_execution();
-        let ws_message = WebSocketMessage::Execution(execution);
+        let ws_message = WebSocketMessage::Execution { execution_report: execution, order_status: "Filled".to_string() };
         let result = producer.publish_websocket_message(&ws_message).await;
         
         assert!(result.is_ok());
@@ -425,6 +533,7 @@ mod tests {
     async fn test_routing_patterns() {
         assert_eq!(RoutingPatterns::execution_symbol("BTC-KRW"), "execution.BTC-KRW");
         assert_eq!(RoutingPatterns::orderbook_symbol("BTC-KRW"), "orderbook.*.BTC-KRW");
+        assert_eq!(RoutingPatterns::bbo_symbol("BTC-KRW"), "bbo.BTC-KRW");
         assert_eq!(RoutingPatterns::market_stats_symbol("BTC-KRW"), "market.stats.BTC-KRW");
         assert_eq!(RoutingPatterns::candlestick_symbol("BTC-KRW"), "candlestick.BTC-KRW");
         assert_eq!(RoutingPatterns::user_pattern("user_123"), "user.user_123.*");
@@ -434,7 +543,7 @@ mod tests {
     #[tokio::test]
     async fn test_message_priority() {
         let execution = create_test_execution();
-        let ws_message = WebSocketMessage::Execution(execution);
+        let ws_message = WebSocketMessage::Execution { execution_report: execution, order_status: "Filled".to_string() };
         let notification = WebSocketNotificationMessage::from(&ws_message);
         
         assert_eq!(notification.priority, 1); // 체결은 높은 우선순위
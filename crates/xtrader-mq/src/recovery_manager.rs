@@ -2,15 +2,24 @@
 //!
 //! 이 모듈은 MQ 장애 시 백업된 메시지를 자동으로 재발행하고
 //! 지수 백오프를 통한 재시도 메커니즘을 제공합니다.
+//!
+//! 전체 자동 복구와 별도로, MQ 종류/토픽/심볼/백업 시각 범위로 대상을 좁힌 복구 작업을
+//! 관리자 요청으로 실행하고 진행률과 예상 남은 시간을 조회할 수 있습니다.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use log::{info, error, warn, debug};
 use tokio::time::{sleep, interval};
-use crate::mq::backup_queue::{LocalBackupQueue, BackupMessage, MQType, BackupMessageBuilder};
-use crate::mq::health_monitor::{MQHealthMonitor, MQHealthStatus};
+use crate::backup_queue::{LocalBackupQueue, BackupMessage, MQType};
+use crate::health_monitor::MQHealthMonitor;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// 보관하는 복구 작업 수 (넘으면 끝난 작업부터 삭제)
+const MAX_RECOVERY_JOBS: usize = 100;
 
 /// 재발행 상태
 #[derive(Debug, Clone, PartialEq)]
@@ -55,6 +64,108 @@ impl Default for RecoveryConfig {
     }
 }
 
+/// 부분 복구 대상 (지정하지 않은 조건은 제한 없음)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RecoveryFilter {
+    pub mq_type: Option<MQType>,
+    /// 토픽/스트림 (예: market-data)
+    pub topic_stream: Option<String>,
+    /// 심볼 (라우팅 키 또는 메시지 본문의 `symbol`)
+    pub symbol: Option<String>,
+    /// 최초 백업 시각 하한 (Unix 밀리초, 포함)
+    pub since_ms: Option<u64>,
+    /// 최초 백업 시각 상한 (Unix 밀리초, 미포함)
+    pub until_ms: Option<u64>,
+}
+
+impl RecoveryFilter {
+    pub fn matches(&self, message: &BackupMessage) -> bool {
+        self.mq_type.as_ref().is_none_or(|mq_type| message.mq_type == *mq_type)
+            && self.topic_stream.as_ref().is_none_or(|topic| message.topic_stream == *topic)
+            && self.symbol.as_ref().is_none_or(|symbol| {
+                message.routing_key.as_ref() == Some(symbol)
+                    || message.message_data.get("symbol").and_then(|v| v.as_str()) == Some(symbol.as_str())
+            })
+            && self.since_ms.is_none_or(|since| message.created_at >= since)
+            && self.until_ms.is_none_or(|until| message.created_at < until)
+    }
+}
+
+/// 복구 작업 상태
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryJobState {
+    Running,
+    Completed,
+    /// 관리자 취소 (진행 중이던 배치는 마친 뒤 중단)
+    Cancelled,
+    /// 백업 큐 조회 실패
+    Failed,
+}
+
+/// 복구 작업 진행 상황
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RecoveryJob {
+    pub id: String,
+    pub filter: RecoveryFilter,
+    pub state: RecoveryJobState,
+    /// 시작 시점의 대상 메시지 수
+    pub total: usize,
+    /// 재발행 성공 수
+    pub recovered: usize,
+    /// 재발행 실패 수 (재시도 카운트 증가 후 큐에 남음)
+    pub failed: usize,
+    /// 진행률 (0.0 ~ 1.0)
+    pub progress: f64,
+    /// 초당 처리 메시지 수
+    pub rate_per_sec: f64,
+    /// 예상 남은 시간 (초, 진행 중이고 처리 속도를 알 때만)
+    pub eta_secs: Option<u64>,
+    pub started_at: u64,
+    pub finished_at: Option<u64>,
+    /// 마지막 재발행/조회 오류
+    pub last_error: Option<String>,
+}
+
+impl RecoveryJob {
+    fn processed(&self) -> usize {
+        self.recovered + self.failed
+    }
+
+    /// 진행률과 예상 남은 시간 갱신
+    fn update_progress(&mut self, now: u64) {
+        // 시작 후 다른 경로(자동 복구 등)로 늘어난 처리분은 대상 수에 포함
+        self.total = self.total.max(self.processed());
+        let elapsed_secs = now.saturating_sub(self.started_at) as f64 / 1000.0;
+        self.rate_per_sec = if elapsed_secs > 0.0 { self.processed() as f64 / elapsed_secs } else { 0.0 };
+
+        if self.state != RecoveryJobState::Running {
+            self.eta_secs = None;
+            if self.state == RecoveryJobState::Completed {
+                self.progress = 1.0;
+            }
+            return;
+        }
+        self.progress = if self.total == 0 { 0.0 } else { self.processed() as f64 / self.total as f64 };
+        let remaining = self.total - self.processed();
+        self.eta_secs = (self.rate_per_sec > 0.0).then(|| (remaining as f64 / self.rate_per_sec).ceil() as u64);
+    }
+}
+
+/// 복구 작업 오류
+#[derive(Debug, thiserror::Error)]
+pub enum RecoveryJobError {
+    #[error("복구 작업을 찾을 수 없습니다: {0}")]
+    NotFound(String),
+    #[error("이미 끝난 복구 작업입니다: {0}")]
+    AlreadyFinished(String),
+    #[error("백업 큐 조회 실패: {0}")]
+    BackupQueue(String),
+}
+
 /// MQ 복구 관리자
 pub struct RecoveryManager {
     /// 백업 큐 참조
@@ -69,6 +180,8 @@ pub struct RecoveryManager {
     stats: Arc<RwLock<RecoveryStats>>,
     /// 복구 활성화 상태
     is_recovery_active: Arc<Mutex<bool>>,
+    /// 부분 복구 작업 (시작순)
+    jobs: Arc<RwLock<Vec<RecoveryJob>>>,
 }
 
 impl RecoveryManager {
@@ -96,6 +209,7 @@ impl RecoveryManager {
                 status: RecoveryStatus::Idle,
             })),
             is_recovery_active: Arc::new(Mutex::new(false)),
+            jobs: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -158,7 +272,7 @@ impl RecoveryManager {
         backup_queue: &Arc<LocalBackupQueue>,
         health_monitor: &Arc<MQHealthMonitor>,
         config: &RecoveryConfig,
-        recovery_status: &Arc<RwLock<RecoveryStatus>>,
+        _recovery_status: &Arc<RwLock<RecoveryStatus>>,
         stats: &Arc<RwLock<RecoveryStats>>,
     ) {
         // Redis가 정상인지 확인
@@ -215,7 +329,7 @@ impl RecoveryManager {
         backup_queue: &Arc<LocalBackupQueue>,
         health_monitor: &Arc<MQHealthMonitor>,
         config: &RecoveryConfig,
-        recovery_status: &Arc<RwLock<RecoveryStatus>>,
+        _recovery_status: &Arc<RwLock<RecoveryStatus>>,
         stats: &Arc<RwLock<RecoveryStats>>,
     ) {
         // Kafka가 정상인지 확인
@@ -272,7 +386,7 @@ impl RecoveryManager {
         backup_queue: &Arc<LocalBackupQueue>,
         health_monitor: &Arc<MQHealthMonitor>,
         config: &RecoveryConfig,
-        recovery_status: &Arc<RwLock<RecoveryStatus>>,
+        _recovery_status: &Arc<RwLock<RecoveryStatus>>,
         stats: &Arc<RwLock<RecoveryStats>>,
     ) {
         // RabbitMQ가 정상인지 확인
@@ -424,13 +538,7 @@ impl RecoveryManager {
         let mut recovered_count = 0;
         
         for message in messages {
-            let result = match mq_type {
-                MQType::RedisStreams => Self::republish_redis_message(&message).await,
-                MQType::Kafka => Self::republish_kafka_message(&message).await,
-                MQType::RabbitMQ => Self::republish_rabbitmq_message(&message).await,
-            };
-            
-            match result {
+            match Self::republish_message(&message).await {
                 Ok(_) => {
                     if let Err(e) = self.backup_queue.remove_message(&message.id).await {
                         error!("백업 메시지 제거 실패: {}", e);
@@ -450,6 +558,222 @@ impl RecoveryManager {
         Ok(recovered_count)
     }
 
+    /// 부분 복구 작업 시작
+    ///
+    /// 백업 시각 상한이 없으면 시작 시각까지 백업된 메시지만 대상으로 삼고,
+    /// 메시지마다 한 번씩만 재발행을 시도합니다 (실패한 메시지는 재시도 카운트만 증가).
+    pub async fn start_job(&self, mut filter: RecoveryFilter) -> Result<RecoveryJob, RecoveryJobError> {
+        let started_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        filter.until_ms.get_or_insert(started_at);
+
+        let matcher = filter.clone();
+        let total = self
+            .backup_queue
+            .count_matching(move |m| matcher.matches(m))
+            .await
+            .map_err(RecoveryJobError::BackupQueue)?;
+
+        let job = RecoveryJob {
+            id: uuid::Uuid::new_v4().to_string(),
+            filter: filter.clone(),
+            state: RecoveryJobState::Running,
+            total,
+            recovered: 0,
+            failed: 0,
+            progress: 0.0,
+            rate_per_sec: 0.0,
+            eta_secs: None,
+            started_at,
+            finished_at: None,
+            last_error: None,
+        };
+
+        {
+            let mut jobs = self.jobs.write().await;
+            if jobs.len() >= MAX_RECOVERY_JOBS {
+                if let Some(index) = jobs.iter().position(|j| j.state != RecoveryJobState::Running) {
+                    jobs.remove(index);
+                }
+            }
+            jobs.push(job.clone());
+        }
+        info!("부분 복구 작업 시작: {} (대상 {}개, {:?})", job.id, total, filter);
+
+        tokio::spawn(Self::run_job(
+            self.backup_queue.clone(),
+            self.jobs.clone(),
+            self.stats.clone(),
+            job.id.clone(),
+            filter,
+            self.config.max_batch_size,
+        ));
+
+        Ok(job)
+    }
+
+    /// 부분 복구 작업 조회
+    pub async fn get_job(&self, job_id: &str) -> Result<RecoveryJob, RecoveryJobError> {
+        self.jobs
+            .read()
+            .await
+            .iter()
+            .find(|j| j.id == job_id)
+            .cloned()
+            .ok_or_else(|| RecoveryJobError::NotFound(job_id.to_string()))
+    }
+
+    /// 부분 복구 작업 목록 (최신순)
+    pub async fn list_jobs(&self) -> Vec<RecoveryJob> {
+        self.jobs.read().await.iter().rev().cloned().collect()
+    }
+
+    /// 부분 복구 작업 취소 (진행 중이던 배치는 마친 뒤 중단)
+    pub async fn cancel_job(&self, job_id: &str) -> Result<RecoveryJob, RecoveryJobError> {
+        let mut jobs = self.jobs.write().await;
+        let job = jobs
+            .iter_mut()
+            .find(|j| j.id == job_id)
+            .ok_or_else(|| RecoveryJobError::NotFound(job_id.to_string()))?;
+        if job.state != RecoveryJobState::Running {
+            return Err(RecoveryJobError::AlreadyFinished(job_id.to_string()));
+        }
+
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        job.state = RecoveryJobState::Cancelled;
+        job.finished_at = Some(current_time);
+        job.update_progress(current_time);
+        info!("부분 복구 작업 취소: {}", job_id);
+        Ok(job.clone())
+    }
+
+    /// 부분 복구 작업 실행
+    async fn run_job(
+        backup_queue: Arc<LocalBackupQueue>,
+        jobs: Arc<RwLock<Vec<RecoveryJob>>>,
+        stats: Arc<RwLock<RecoveryStats>>,
+        job_id: String,
+        filter: RecoveryFilter,
+        batch_size: usize,
+    ) {
+        // 이번 작업에서 이미 시도한 메시지 (실패한 메시지를 다시 꺼내지 않음)
+        let mut attempted = HashSet::new();
+
+        let final_state = loop {
+            if Self::job_state(&jobs, &job_id).await != Some(RecoveryJobState::Running) {
+                return;
+            }
+
+            let batch = match backup_queue
+                .recover_matching(|m| filter.matches(m) && !attempted.contains(&m.id), batch_size)
+                .await
+            {
+                Ok(batch) => batch,
+                Err(e) => {
+                    error!("부분 복구 작업 {} 백업 큐 조회 실패: {}", job_id, e);
+                    Self::update_job(&jobs, &job_id, |job| job.last_error = Some(e)).await;
+                    break RecoveryJobState::Failed;
+                }
+            };
+            if batch.is_empty() {
+                break RecoveryJobState::Completed;
+            }
+
+            let batch_len = batch.len();
+            for message in batch {
+                attempted.insert(message.id.clone());
+                let result = Self::republish_message(&message).await;
+                let success = result.is_ok();
+
+                match result {
+                    Ok(_) => {
+                        if let Err(e) = backup_queue.remove_message(&message.id).await {
+                            error!("백업 메시지 제거 실패: {}", e);
+                        }
+                    }
+                    Err(ref e) => {
+                        error!("부분 복구 재발행 실패: {} - {}", message.id, e);
+                        if let Err(e) = backup_queue.increment_retry_count(&message.id).await {
+                            error!("재시도 카운트 증가 실패: {}", e);
+                        }
+                    }
+                }
+
+                Self::update_recovery_stats(&stats, success, batch_len).await;
+                Self::update_job(&jobs, &job_id, |job| match result {
+                    Ok(_) => job.recovered += 1,
+                    Err(e) => {
+                        job.failed += 1;
+                        job.last_error = Some(e);
+                    }
+                })
+                .await;
+            }
+        };
+
+        Self::update_job(&jobs, &job_id, |job| {
+            // 취소된 작업은 상태를 덮어쓰지 않음
+            if job.state == RecoveryJobState::Running {
+                job.state = final_state;
+                job.finished_at = Some(
+                    SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap()
+                        .as_millis() as u64,
+                );
+            }
+        })
+        .await;
+
+        if let Ok(job) = Self::job_snapshot(&jobs, &job_id).await {
+            info!(
+                "부분 복구 작업 종료: {} ({:?}, 성공 {}개, 실패 {}개)",
+                job.id, job.state, job.recovered, job.failed
+            );
+        }
+    }
+
+    async fn job_state(jobs: &Arc<RwLock<Vec<RecoveryJob>>>, job_id: &str) -> Option<RecoveryJobState> {
+        jobs.read().await.iter().find(|j| j.id == job_id).map(|j| j.state)
+    }
+
+    async fn job_snapshot(jobs: &Arc<RwLock<Vec<RecoveryJob>>>, job_id: &str) -> Result<RecoveryJob, RecoveryJobError> {
+        jobs.read()
+            .await
+            .iter()
+            .find(|j| j.id == job_id)
+            .cloned()
+            .ok_or_else(|| RecoveryJobError::NotFound(job_id.to_string()))
+    }
+
+    /// 작업 갱신 후 진행률 재계산
+    async fn update_job(jobs: &Arc<RwLock<Vec<RecoveryJob>>>, job_id: &str, update: impl FnOnce(&mut RecoveryJob)) {
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        let mut jobs = jobs.write().await;
+        if let Some(job) = jobs.iter_mut().find(|j| j.id == job_id) {
+            update(job);
+            job.update_progress(current_time);
+        }
+    }
+
+    /// MQ 종류에 맞게 메시지 재발행
+    async fn republish_message(message: &BackupMessage) -> Result<(), String> {
+        match message.mq_type {
+            MQType::RedisStreams => Self::republish_redis_message(message).await,
+            MQType::Kafka => Self::republish_kafka_message(message).await,
+            MQType::RabbitMQ => Self::republish_rabbitmq_message(message).await,
+        }
+    }
+
     /// 복구 리포트 생성
     pub async fn generate_recovery_report(&self) -> String {
         let stats = self.get_recovery_stats().await;
@@ -500,6 +824,8 @@ mod fastrand {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backup_queue::BackupMessageBuilder;
+    use crate::health_monitor::HealthCheckConfig;
 
     #[tokio::test]
     async fn test_recovery_manager_creation() {
@@ -556,4 +882,106 @@ mod tests {
         
         assert_eq!(delay, config.base_retry_delay_ms); // 첫 번째 재시도
     }
-}
\ No newline at end of file
+
+    fn backup_message(mq_type: MQType, topic: &str, symbol: &str, created_at: u64) -> BackupMessage {
+        let mut message = BackupMessageBuilder::new(mq_type, topic.to_string())
+            .message_data(serde_json::json!({"symbol": symbol}))
+            .build();
+        message.created_at = created_at;
+        message
+    }
+
+    #[test]
+    fn test_recovery_filter_matching() {
+        let message = backup_message(MQType::Kafka, "market-data", "BTC-KRW", 5_000);
+        let mut routed = backup_message(MQType::RabbitMQ, "websocket", "", 5_000);
+        routed.routing_key = Some("ETH-KRW".to_string());
+
+        assert!(RecoveryFilter::default().matches(&message));
+        let filter = RecoveryFilter {
+            mq_type: Some(MQType::Kafka),
+            topic_stream: Some("market-data".to_string()),
+            symbol: Some("BTC-KRW".to_string()),
+            since_ms: Some(5_000),
+            until_ms: Some(5_001),
+        };
+        assert!(filter.matches(&message));
+        assert!(!RecoveryFilter { until_ms: Some(5_000), ..filter.clone() }.matches(&message));
+        assert!(!RecoveryFilter { mq_type: Some(MQType::RabbitMQ), ..filter }.matches(&message));
+        // 심볼은 라우팅 키로도 맞춤
+        assert!(RecoveryFilter { symbol: Some("ETH-KRW".to_string()), ..Default::default() }.matches(&routed));
+    }
+
+    #[tokio::test]
+    async fn test_partial_recovery_job() {
+        let dir = std::env::temp_dir().join(format!("xtrader-recovery-job-{}", uuid::Uuid::new_v4()));
+        let backup_queue = Arc::new(LocalBackupQueue::new(dir.to_string_lossy().into_owned(), 100, 60_000));
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+
+        // 최근 10분 Kafka market-data 3건만 대상
+        for i in 0..3 {
+            backup_queue.backup_message(backup_message(MQType::Kafka, "market-data", "BTC-KRW", now - 1_000 + i)).await.unwrap();
+        }
+        let old = backup_message(MQType::Kafka, "market-data", "BTC-KRW", now - 3_600_000);
+        let other = backup_message(MQType::RabbitMQ, "websocket", "BTC-KRW", now - 1_000);
+        backup_queue.backup_message(old).await.unwrap();
+        backup_queue.backup_message(other).await.unwrap();
+
+        let health_monitor = Arc::new(MQHealthMonitor::new(HealthCheckConfig::default(), backup_queue.clone()));
+        let manager = RecoveryManager::new(backup_queue.clone(), health_monitor, RecoveryConfig::default());
+
+        let job = manager
+            .start_job(RecoveryFilter {
+                mq_type: Some(MQType::Kafka),
+                topic_stream: Some("market-data".to_string()),
+                since_ms: Some(now - 600_000),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!((job.total, job.state), (3, RecoveryJobState::Running));
+
+        let mut finished = job.clone();
+        for _ in 0..200 {
+            finished = manager.get_job(&job.id).await.unwrap();
+            if finished.state != RecoveryJobState::Running {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(finished.state, RecoveryJobState::Completed);
+        assert_eq!(finished.recovered + finished.failed, 3);
+        assert_eq!((finished.progress, finished.eta_secs), (1.0, None));
+
+        // 대상 밖 메시지와 재발행에 실패한 메시지는 큐에 남음
+        assert_eq!(backup_queue.size().await, 2 + finished.failed);
+        assert!(matches!(manager.cancel_job(&job.id).await, Err(RecoveryJobError::AlreadyFinished(_))));
+        assert!(matches!(manager.get_job("missing").await, Err(RecoveryJobError::NotFound(_))));
+        assert_eq!(manager.list_jobs().await.len(), 1);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_job_progress_and_eta() {
+        let mut job = RecoveryJob {
+            id: "job".to_string(),
+            filter: RecoveryFilter::default(),
+            state: RecoveryJobState::Running,
+            total: 10,
+            recovered: 3,
+            failed: 1,
+            progress: 0.0,
+            rate_per_sec: 0.0,
+            eta_secs: None,
+            started_at: 0,
+            finished_at: None,
+            last_error: None,
+        };
+        job.update_progress(2_000);
+        assert_eq!((job.progress, job.rate_per_sec, job.eta_secs), (0.4, 2.0, Some(3)));
+
+        job.state = RecoveryJobState::Cancelled;
+        job.update_progress(3_000);
+        assert_eq!((job.progress, job.eta_secs), (0.4, None));
+    }
+}
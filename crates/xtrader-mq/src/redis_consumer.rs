@@ -0,0 +1,534 @@
+//! Redis Streams Consumer 구현 (간단 버전)
+//!
+//! 이 모듈은 체결 내역을 처리하는 기본 Consumer 기능을 제공합니다.
+//!
+//! Worker는 Consumer Group으로 새 메시지를 읽고 처리에 성공한 메시지만 XACK합니다.
+//! 처리 도중 Worker가 죽으면 메시지가 보류(pending) 목록에 남으므로, 살아 있는 Worker가
+//! 주기적으로 `min_idle` 이상 보류된 메시지를 XAUTOCLAIM으로 가져와 다시 처리합니다.
+//! `max_deliveries`번 넘게 전달되고도 처리되지 않았거나 본문을 읽을 수 없는 독성 메시지는
+//! DLQ 격리 저장소에 보관한 뒤 XACK해 보류 목록에서 뺍니다.
+
+use redis::{RedisResult, AsyncCommands, Value};
+use redis::aio::Connection;
+use redis::streams::{StreamPendingCountReply, StreamReadOptions, StreamReadReply};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use log::{debug, info, error, warn};
+use crate::dead_letter::{DeadLetter, QuarantineReason, QuarantineStore};
+use crate::redis_streams::ExecutionMessage;
+use crate::wire_format;
+use crate::gauge::GaugeSink;
+
+/// 체결 메시지 본문 필드 (Producer XADD와 같음)
+const EXECUTION_FIELD: &str = "execution";
+
+/// Redis Consumer Worker
+pub struct RedisConsumerWorker {
+    #[allow(dead_code)]
+    client: Arc<redis::Client>,
+    connection: Arc<Mutex<Connection>>,
+    stream_name: String,
+    consumer_group: String,
+    consumer_name: String,
+    #[allow(dead_code)]
+    db_pool: SqlitePool,
+    worker_id: String,
+    batch_size: usize,
+    processing_interval_ms: u64,
+    pending_claim: PendingClaimConfig,
+    /// XAUTOCLAIM 스캔 위치 (한 바퀴 돌면 0-0으로 돌아옴)
+    claim_cursor: Mutex<String>,
+    claim_metrics: Arc<PendingClaimMetrics>,
+    dead_letters: Option<Arc<QuarantineStore>>,
+}
+
+/// Consumer Worker 설정
+#[derive(Clone)]
+pub struct ConsumerConfig {
+    pub redis_url: String,
+    pub stream_name: String,
+    pub consumer_group: String,
+    pub worker_id: String,
+    pub batch_size: usize,
+    pub processing_interval_ms: u64,
+    pub pending_claim: PendingClaimConfig,
+}
+
+/// 보류 메시지 회수 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingClaimConfig {
+    /// 이 시간 이상 ACK되지 않은 메시지를 회수
+    pub min_idle: Duration,
+    /// 회수 주기
+    pub interval: Duration,
+    /// 이 횟수를 넘게 전달된 메시지는 독성 메시지로 격리
+    pub max_deliveries: u64,
+    /// 한 번에 회수할 최대 메시지 수
+    pub batch_size: usize,
+}
+
+impl Default for PendingClaimConfig {
+    fn default() -> Self {
+        Self {
+            min_idle: Duration::from_secs(30),
+            interval: Duration::from_secs(5),
+            max_deliveries: 5,
+            batch_size: 100,
+        }
+    }
+}
+
+/// 보류 메시지 회수 메트릭 (전체 Worker 누적)
+#[derive(Default)]
+pub struct PendingClaimMetrics {
+    claimed: AtomicU64,
+    reprocessed: AtomicU64,
+    poisoned: AtomicU64,
+}
+
+impl PendingClaimMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 다른 Worker(또는 재시작 전 자신)에게서 회수한 메시지 수
+    pub fn claimed(&self) -> u64 {
+        self.claimed.load(Ordering::Relaxed)
+    }
+
+    /// 회수 후 처리에 성공한 메시지 수
+    pub fn reprocessed(&self) -> u64 {
+        self.reprocessed.load(Ordering::Relaxed)
+    }
+
+    /// 독성 메시지로 격리한 메시지 수
+    pub fn poisoned(&self) -> u64 {
+        self.poisoned.load(Ordering::Relaxed)
+    }
+
+    /// 게이지 값 발행
+    pub async fn publish(&self, collector: &dyn GaugeSink) {
+        collector.set_gauge("redis.consumer.pending.claimed", self.claimed()).await;
+        collector.set_gauge("redis.consumer.pending.reprocessed", self.reprocessed()).await;
+        collector.set_gauge("redis.consumer.pending.poisoned", self.poisoned()).await;
+    }
+}
+
+/// 읽은 메시지 처리 방법
+#[derive(Debug)]
+enum EntryAction {
+    Process(ExecutionMessage),
+    Quarantine(QuarantineReason, String),
+}
+
+/// 전달 횟수와 본문으로 처리/격리 결정 (본문은 JSON/rkyv 자동 판별)
+fn classify_entry(payload: Option<&[u8]>, deliveries: u64, max_deliveries: u64) -> EntryAction {
+    if deliveries > max_deliveries {
+        return EntryAction::Quarantine(
+            QuarantineReason::RetriesExhausted,
+            format!("{}회 전달 후에도 처리되지 않음", deliveries),
+        );
+    }
+    match payload.map(wire_format::decode::<ExecutionMessage>) {
+        Some(Ok(message)) => EntryAction::Process(message),
+        Some(Err(e)) => EntryAction::Quarantine(QuarantineReason::Deserialization, e),
+        None => EntryAction::Quarantine(
+            QuarantineReason::Deserialization,
+            format!("{} 필드가 없습니다", EXECUTION_FIELD),
+        ),
+    }
+}
+
+fn entry_payload(fields: &HashMap<String, Value>) -> Option<Vec<u8>> {
+    fields.get(EXECUTION_FIELD).and_then(|value| redis::from_redis_value(value).ok())
+}
+
+/// 회수한 메시지 ID와 본문 (`execution` 필드가 없으면 None)
+type ClaimedEntry = (String, Option<Vec<u8>>);
+
+/// XAUTOCLAIM 응답 파싱: (다음 커서, 회수한 메시지)
+///
+/// 보류 중에 스트림에서 삭제된 메시지(Redis 6.2는 nil 항목, 7 이상은 세 번째 요소)는 제외합니다.
+fn parse_autoclaim_reply(reply: &Value) -> RedisResult<(String, Vec<ClaimedEntry>)> {
+    let invalid = || redis::RedisError::from((redis::ErrorKind::TypeError, "XAUTOCLAIM 응답 형식 오류"));
+    let Value::Bulk(parts) = reply else {
+        return Err(invalid());
+    };
+    let cursor: String = redis::from_redis_value(parts.first().ok_or_else(invalid)?)?;
+    let Some(Value::Bulk(entries)) = parts.get(1) else {
+        return Err(invalid());
+    };
+
+    let mut claimed = Vec::new();
+    for entry in entries {
+        let Value::Bulk(entry) = entry else {
+            continue;
+        };
+        let (Some(id), Some(Value::Bulk(fields))) = (entry.first(), entry.get(1)) else {
+            continue;
+        };
+        let id: String = redis::from_redis_value(id)?;
+        let fields: HashMap<String, Value> = fields
+            .chunks(2)
+            .filter_map(|pair| match pair {
+                [name, value] => Some((redis::from_redis_value(name).ok()?, value.clone())),
+                _ => None,
+            })
+            .collect();
+        claimed.push((id, entry_payload(&fields)));
+    }
+    Ok((cursor, claimed))
+}
+
+impl RedisConsumerWorker {
+    /// 새 Consumer Worker 생성
+    pub async fn new(config: ConsumerConfig, db_pool: SqlitePool) -> RedisResult<Self> {
+        let client = redis::Client::open(config.redis_url)?;
+        let connection = client.get_async_connection().await?;
+        
+        info!("Redis Consumer Worker 초기화 완료: {}", config.worker_id);
+        
+        Ok(Self {
+            client: Arc::new(client),
+            connection: Arc::new(Mutex::new(connection)),
+            stream_name: config.stream_name,
+            consumer_group: config.consumer_group,
+            consumer_name: config.worker_id.clone(),
+            db_pool,
+            worker_id: config.worker_id,
+            batch_size: config.batch_size,
+            processing_interval_ms: config.processing_interval_ms,
+            pending_claim: config.pending_claim,
+            claim_cursor: Mutex::new("0-0".to_string()),
+            claim_metrics: Arc::new(PendingClaimMetrics::new()),
+            dead_letters: None,
+        })
+    }
+
+    /// 보류 메시지 회수 메트릭 공유
+    pub fn with_claim_metrics(mut self, metrics: Arc<PendingClaimMetrics>) -> Self {
+        self.claim_metrics = metrics;
+        self
+    }
+
+    /// 독성 메시지를 보관할 격리 저장소
+    pub fn with_dead_letters(mut self, dead_letters: Arc<QuarantineStore>) -> Self {
+        self.dead_letters = Some(dead_letters);
+        self
+    }
+
+    /// Consumer Worker 실행 (메인 루프)
+    pub async fn run(&self) -> RedisResult<()> {
+        info!("Consumer Worker 시작: {}", self.worker_id);
+        self.ensure_group().await?;
+
+        // 시작하자마자 한 번 회수 (재시작 전에 처리하던 메시지 포함)
+        self.claim_and_log().await;
+        let mut last_claim = Instant::now();
+        
+        loop {
+            if last_claim.elapsed() >= self.pending_claim.interval {
+                self.claim_and_log().await;
+                last_claim = Instant::now();
+            }
+
+            match self.process_batch().await {
+                Ok(processed_count) => {
+                    if processed_count > 0 {
+                        info!("Worker {} 처리 완료: {}개 메시지", self.worker_id, processed_count);
+                    }
+                }
+                Err(e) => {
+                    error!("Worker {} 처리 오류: {}", self.worker_id, e);
+                    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+                }
+            }
+            
+            // 처리 간격 대기
+            tokio::time::sleep(tokio::time::Duration::from_millis(self.processing_interval_ms)).await;
+        }
+    }
+
+    /// Consumer Group 생성 (이미 있으면 그대로 사용)
+    async fn ensure_group(&self) -> RedisResult<()> {
+        let mut conn = self.connection.lock().await;
+        let result: RedisResult<()> = conn.xgroup_create_mkstream(&self.stream_name, &self.consumer_group, "0").await;
+        match result {
+            Err(e) if e.code() == Some("BUSYGROUP") => Ok(()),
+            other => other,
+        }
+    }
+
+    /// 새 메시지 배치 처리 (처리에 성공한 메시지만 ACK)
+    async fn process_batch(&self) -> RedisResult<usize> {
+        let mut conn = self.connection.lock().await;
+        let options = StreamReadOptions::default()
+            .group(&self.consumer_group, &self.consumer_name)
+            .count(self.batch_size);
+        let reply: StreamReadReply = conn.xread_options(&[&self.stream_name], &[">"], &options).await?;
+
+        let mut processed_count = 0;
+        for entry in reply.keys.into_iter().flat_map(|key| key.ids) {
+            let payload = entry_payload(&entry.map);
+            if self.handle_entry(&mut conn, &entry.id, payload.as_deref(), 1).await? {
+                processed_count += 1;
+            }
+        }
+        Ok(processed_count)
+    }
+
+    async fn claim_and_log(&self) {
+        match self.claim_stale_pending().await {
+            Ok(0) => {}
+            Ok(claimed) => info!("Worker {} 보류 메시지 회수: {}개", self.worker_id, claimed),
+            Err(e) => error!("Worker {} 보류 메시지 회수 오류: {}", self.worker_id, e),
+        }
+    }
+
+    /// `min_idle` 이상 ACK되지 않은 메시지를 이 Worker로 회수해 다시 처리
+    async fn claim_stale_pending(&self) -> RedisResult<usize> {
+        let mut conn = self.connection.lock().await;
+        let mut cursor = self.claim_cursor.lock().await;
+        let reply: Value = redis::cmd("XAUTOCLAIM")
+            .arg(&self.stream_name)
+            .arg(&self.consumer_group)
+            .arg(&self.consumer_name)
+            .arg(self.pending_claim.min_idle.as_millis() as u64)
+            .arg(cursor.as_str())
+            .arg("COUNT")
+            .arg(self.pending_claim.batch_size)
+            .query_async(&mut *conn)
+            .await?;
+        let (next_cursor, claimed) = parse_autoclaim_reply(&reply)?;
+        *cursor = next_cursor;
+        drop(cursor);
+
+        let (Some((first, _)), Some((last, _))) = (claimed.first(), claimed.last()) else {
+            return Ok(0);
+        };
+        self.claim_metrics.claimed.fetch_add(claimed.len() as u64, Ordering::Relaxed);
+
+        // 회수로 늘어난 전달 횟수 조회
+        let pending: StreamPendingCountReply = conn
+            .xpending_consumer_count(
+                &self.stream_name,
+                &self.consumer_group,
+                first,
+                last,
+                claimed.len(),
+                &self.consumer_name,
+            )
+            .await?;
+        let deliveries: HashMap<String, u64> = pending
+            .ids
+            .into_iter()
+            .map(|pending| (pending.id, pending.times_delivered as u64))
+            .collect();
+
+        for (id, payload) in &claimed {
+            let delivered = deliveries.get(id).copied().unwrap_or(1);
+            if self.handle_entry(&mut conn, id, payload.as_deref(), delivered).await? {
+                self.claim_metrics.reprocessed.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        Ok(claimed.len())
+    }
+
+    /// 메시지 하나 처리 후 ACK (처리했으면 true)
+    ///
+    /// 처리에 실패하면 ACK하지 않고 보류 목록에 남겨 회수 때 다시 처리하며,
+    /// 독성 메시지는 격리 저장소에 보관된 뒤에만 ACK합니다.
+    async fn handle_entry(
+        &self,
+        conn: &mut Connection,
+        id: &str,
+        payload: Option<&[u8]>,
+        deliveries: u64,
+    ) -> RedisResult<bool> {
+        match classify_entry(payload, deliveries, self.pending_claim.max_deliveries) {
+            EntryAction::Process(message) => {
+                if let Err(e) = self.process_message(&message).await {
+                    warn!("Worker {} 메시지 처리 실패 ({}회째, 회수 후 재처리): {} - {}", self.worker_id, deliveries, id, e);
+                    return Ok(false);
+                }
+                let _: i64 = conn.xack(&self.stream_name, &self.consumer_group, &[id]).await?;
+                Ok(true)
+            }
+            EntryAction::Quarantine(reason, error) => {
+                if let Some(dead_letters) = &self.dead_letters {
+                    let letter = DeadLetter {
+                        routing_key: format!("redis:{}", self.stream_name),
+                        payload: wire_format::payload_to_string(payload.unwrap_or_default()),
+                        error: Some(format!("stream id {}", id)),
+                    };
+                    if let Err(e) = dead_letters.quarantine(&letter, reason, &error, deliveries as u32).await {
+                        // 격리에 실패하면 ACK하지 않고 다음 회수 때 다시 시도
+                        warn!("Worker {} 독성 메시지 격리 실패: {} - {}", self.worker_id, id, e);
+                        return Ok(false);
+                    }
+                } else {
+                    error!("Worker {} 독성 메시지 폐기 (격리 저장소 없음): {} - {}", self.worker_id, id, error);
+                }
+                let _: i64 = conn.xack(&self.stream_name, &self.consumer_group, &[id]).await?;
+                self.claim_metrics.poisoned.fetch_add(1, Ordering::Relaxed);
+                Ok(false)
+            }
+        }
+    }
+
+    /// 체결 메시지 처리
+    async fn process_message(&self, message: &ExecutionMessage) -> Result<(), String> {
+        debug!("Worker {} 체결 메시지 처리: {} ({} {} @ {})",
+               self.worker_id, message.execution_id, message.symbol, message.quantity, message.price);
+        Ok(())
+    }
+}
+
+/// Consumer Manager (여러 Worker 관리)
+pub struct RedisConsumerManager {
+    workers: Vec<Arc<RedisConsumerWorker>>,
+    #[allow(dead_code)]
+    db_pool: SqlitePool,
+}
+
+impl RedisConsumerManager {
+    /// 새 Consumer Manager 생성
+    ///
+    /// 모든 Worker가 보류 메시지 회수 메트릭과 독성 메시지 격리 저장소를 공유합니다.
+    pub async fn new(
+        configs: Vec<ConsumerConfig>,
+        db_pool: SqlitePool,
+        dead_letters: Arc<QuarantineStore>,
+        claim_metrics: Arc<PendingClaimMetrics>,
+    ) -> RedisResult<Self> {
+        let mut workers = Vec::new();
+        
+        for config in configs {
+            let worker = RedisConsumerWorker::new(config, db_pool.clone())
+                .await?
+                .with_dead_letters(dead_letters.clone())
+                .with_claim_metrics(claim_metrics.clone());
+            workers.push(Arc::new(worker));
+        }
+        
+        info!("Consumer Manager 초기화 완료: {}개 Worker", workers.len());
+        
+        Ok(Self {
+            workers,
+            db_pool,
+        })
+    }
+
+    /// 모든 Worker 시작
+    ///
+    /// 반환된 future를 중단하면(태스크 abort 등) Worker 태스크도 함께 중단됩니다.
+    pub async fn start_all_workers(&self) -> RedisResult<()> {
+        let mut workers = tokio::task::JoinSet::new();
+        
+        for worker in &self.workers {
+            let worker_clone = worker.clone();
+            workers.spawn(async move {
+                if let Err(e) = worker_clone.run().await {
+                    error!("Worker {} 실행 오류: {}", worker_clone.worker_id, e);
+                }
+            });
+        }
+        
+        info!("모든 Consumer Worker 시작 완료: {}개", self.workers.len());
+        
+        // 모든 Worker가 종료될 때까지 대기
+        while workers.join_next().await.is_some() {}
+        
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_consumer_config() {
+        let config = ConsumerConfig {
+            redis_url: "redis://localhost:6379".to_string(),
+            stream_name: "test-executions".to_string(),
+            consumer_group: "test-group".to_string(),
+            worker_id: "test-worker-1".to_string(),
+            batch_size: 100,
+            processing_interval_ms: 1000,
+            pending_claim: PendingClaimConfig::default(),
+        };
+        
+        assert_eq!(config.worker_id, "test-worker-1");
+        assert_eq!(config.batch_size, 100);
+    }
+
+    fn bulk(values: Vec<Value>) -> Value {
+        Value::Bulk(values)
+    }
+
+    fn data(text: &str) -> Value {
+        Value::Data(text.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_parse_autoclaim_reply_skips_deleted_entries() {
+        let payload = r#"{"execution_id":"exec_1","symbol":"BTC-KRW","side":"Buy","price":100,"quantity":2,"timestamp":1,"order_id":"o1","user_id":"u1"}"#;
+        let reply = bulk(vec![
+            data("1700000000000-5"),
+            bulk(vec![
+                bulk(vec![data("1700000000000-1"), bulk(vec![data("execution"), data(payload)])]),
+                // Redis 6.2: 보류 중 삭제된 메시지
+                bulk(vec![data("1700000000000-2"), Value::Nil]),
+                bulk(vec![data("1700000000000-3"), bulk(vec![data("other"), data("x")])]),
+            ]),
+            // Redis 7: 삭제된 메시지 ID 목록
+            bulk(vec![data("1700000000000-4")]),
+        ]);
+
+        let (cursor, claimed) = parse_autoclaim_reply(&reply).unwrap();
+        assert_eq!(cursor, "1700000000000-5");
+        assert_eq!(claimed.len(), 2);
+        assert_eq!(claimed[0], ("1700000000000-1".to_string(), Some(payload.as_bytes().to_vec())));
+        assert_eq!(claimed[1], ("1700000000000-3".to_string(), None));
+        assert!(parse_autoclaim_reply(&Value::Nil).is_err());
+    }
+
+    #[test]
+    fn test_classify_entry_quarantines_poison_messages() {
+        let payload = r#"{"execution_id":"exec_1","symbol":"BTC-KRW","side":"Buy","price":100,"quantity":2,"timestamp":1,"order_id":"o1","user_id":"u1"}"#;
+
+        assert!(matches!(
+            classify_entry(Some(payload.as_bytes()), 5, 5),
+            EntryAction::Process(message) if message.execution_id == "exec_1"
+        ));
+        assert!(matches!(
+            classify_entry(Some(payload.as_bytes()), 6, 5),
+            EntryAction::Quarantine(QuarantineReason::RetriesExhausted, _)
+        ));
+        assert!(matches!(
+            classify_entry(Some(b"not json"), 1, 5),
+            EntryAction::Quarantine(QuarantineReason::Deserialization, _)
+        ));
+
+        // rkyv 본문도 처리하고, 잘린 본문은 격리
+        let message: ExecutionMessage = serde_json::from_str(payload).unwrap();
+        let binary = wire_format::encode(&message, wire_format::WireFormat::Rkyv).unwrap();
+        assert!(matches!(
+            classify_entry(Some(&binary), 1, 5),
+            EntryAction::Process(message) if message.quantity == 2
+        ));
+        assert!(matches!(
+            classify_entry(Some(&binary[..binary.len() - 8]), 1, 5),
+            EntryAction::Quarantine(QuarantineReason::Deserialization, _)
+        ));
+        assert!(matches!(
+            classify_entry(None, 1, 5),
+            EntryAction::Quarantine(QuarantineReason::Deserialization, _)
+        ));
+    }
+}
\ No newline at end of file
@@ -1,24 +1,27 @@
 //! Redis Streams Producer 및 Consumer 구현 (간단 버전)
 //!
 //! 이 모듈은 체결 내역을 Redis Streams에 발행하는 기본 기능을 제공합니다.
+//! 메시지 본문은 `execution` 필드에 [`WireFormat`] 형식(기본 rkyv)으로 담깁니다.
 
 use redis::{Client, RedisResult, AsyncCommands};
 use redis::aio::Connection;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use log::{info, error};
-use crate::matching_engine::model::ExecutionReport;
+use log::info;
+use xtrader_engine::model::ExecutionReport;
+use crate::wire_format::{self, WireFormat};
 
 /// Redis Streams Producer
 pub struct RedisStreamsProducer {
     client: Arc<Client>,
     connection: Arc<Mutex<Connection>>,
     stream_name: String,
+    wire_format: WireFormat,
 }
 
 /// 체결 내역 메시지 구조
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
 pub struct ExecutionMessage {
     pub execution_id: String,
     pub symbol: String,
@@ -57,14 +60,24 @@ impl RedisStreamsProducer {
             client: Arc::new(client),
             connection: Arc::new(Mutex::new(connection)),
             stream_name: stream_name.to_string(),
+            wire_format: WireFormat::default(),
         })
     }
 
+    /// 메시지 직렬화 형식 (Consumer는 형식을 자동 판별)
+    pub fn with_wire_format(self, wire_format: WireFormat) -> Self {
+        info!("Redis Streams 메시지 형식: {} -> {}", self.stream_name, wire_format.as_str());
+        Self { wire_format, ..self }
+    }
+
+    fn encode(&self, execution: &ExecutionReport) -> RedisResult<Vec<u8>> {
+        wire_format::encode(&ExecutionMessage::from(execution), self.wire_format)
+            .map_err(|e| redis::RedisError::from((redis::ErrorKind::TypeError, "메시지 직렬화 실패", e)))
+    }
+
     /// 체결 내역을 Redis Stream에 발행
     pub async fn publish_execution(&self, execution: &ExecutionReport) -> RedisResult<String> {
-        let message = ExecutionMessage::from(execution);
-        let message_json = serde_json::to_string(&message)
-            .map_err(|e| redis::RedisError::from((redis::ErrorKind::TypeError, "JSON serialization error")))?;
+        let payload = self.encode(execution)?;
         
         let mut conn = self.connection.lock().await;
         
@@ -72,7 +85,7 @@ impl RedisStreamsProducer {
         let message_id: String = conn.xadd(
             &self.stream_name,
             "*", // 자동 생성 ID
-            &[("execution", &message_json)]
+            &[("execution", &payload)]
         ).await?;
         
         info!("체결 내역 발행 완료: {} -> {}", execution.execution_id, message_id);
@@ -85,14 +98,12 @@ impl RedisStreamsProducer {
         let mut message_ids = Vec::new();
         
         for execution in executions {
-            let message = ExecutionMessage::from(execution);
-            let message_json = serde_json::to_string(&message)
-                .map_err(|e| redis::RedisError::from((redis::ErrorKind::TypeError, "JSON serialization error")))?;
+            let payload = self.encode(execution)?;
             
             let message_id: String = conn.xadd(
                 &self.stream_name,
                 "*",
-                &[("execution", &message_json)]
+                &[("execution", &payload)]
             ).await?;
             
             message_ids.push(message_id);
@@ -101,12 +112,26 @@ impl RedisStreamsProducer {
         info!("배치 체결 내역 발행 완료: {}개", executions.len());
         Ok(message_ids)
     }
+
+    /// 발행 스트림 이름
+    pub fn stream_name(&self) -> &str {
+        &self.stream_name
+    }
+
+    /// 연결 재생성 (장애 복구용)
+    pub async fn reconnect(&self) -> RedisResult<()> {
+        let connection = self.client.get_async_connection().await?;
+        *self.connection.lock().await = connection;
+        
+        info!("Redis Streams Producer 재연결 완료: {}", self.stream_name);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::matching_engine::model::{ExecutionReport, Side};
+    use xtrader_engine::model::{ExecutionReport, ExecType, Side};
 
     fn create_test_execution() -> ExecutionReport {
         ExecutionReport {
@@ -117,8 +142,12 @@ mod tests {
             quantity: 100,
             timestamp: 1234567890,
             order_id: "order_001".to_string(),
-            user_id: "user_001".to_string(),
+            remaining_quantity: 0,
+            counterparty_id: "order_002".to_string(),
             is_maker: true,
+            exec_type: ExecType::Trade,
+            sequence: 0,
+            tag: None,
         }
     }
 
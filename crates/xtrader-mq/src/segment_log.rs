@@ -0,0 +1,456 @@
+//! 백업 큐 디스크 세그먼트 로그
+//!
+//! 백업 큐의 모든 변경(메시지 추가/재시도 갱신, 재발행 완료)을 세그먼트 파일에 덧붙여 기록합니다.
+//! 재시작 시 세그먼트를 순서대로 다시 읽어 남은 메시지를 복원합니다.
+//!
+//! 세그먼트 파일(`{번호:020}.seg`)은 4바이트 매직(`XBQ1`) 뒤에 레코드가 이어집니다.
+//! 레코드는 `[길이 u32 LE][CRC32 u32 LE][JSON 본문]`이며, 같은 메시지 ID의 마지막 레코드가 유효합니다.
+//! CRC가 맞지 않거나 본문을 읽을 수 없는 레코드는 `quarantine/`에 원본 바이트를 남기고 건너뛰며,
+//! 길이가 잘못되었거나 잘린 꼬리(쓰는 중 장애)는 그 지점부터 세그먼트 끝까지 격리합니다.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::backup_queue::BackupMessage;
+
+/// 세그먼트 파일 매직 (형식 버전 1)
+const SEGMENT_MAGIC: &[u8; 4] = b"XBQ1";
+/// 레코드 헤더 (길이 + CRC)
+const RECORD_HEADER_LEN: usize = 8;
+/// 레코드 본문 최대 크기 (이보다 크면 길이 필드 손상으로 봄)
+const MAX_RECORD_BYTES: usize = 16 * 1024 * 1024;
+
+/// 세그먼트 로그 설정
+#[derive(Debug, Clone)]
+pub struct SegmentLogConfig {
+    /// 세그먼트 디렉터리
+    pub directory: PathBuf,
+    /// 세그먼트 최대 크기 (넘으면 새 세그먼트)
+    pub segment_max_bytes: u64,
+    /// 메시지 보관 기간 (최초 백업 시각 기준, 지나면 재발행하지 않음)
+    pub retention: Duration,
+    /// 전체 세그먼트 최대 크기 (넘으면 오래된 세그먼트부터 메시지째 삭제)
+    pub max_total_bytes: u64,
+}
+
+impl SegmentLogConfig {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            segment_max_bytes: 8 * 1024 * 1024,
+            retention: Duration::from_secs(24 * 60 * 60),
+            max_total_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+/// 로그 레코드
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LogRecord {
+    /// 메시지 추가 또는 갱신 (재시도 횟수 등)
+    Message(BackupMessage),
+    /// 재발행 완료로 제거
+    Ack { id: String },
+}
+
+/// 세그먼트 해석 결과
+#[derive(Debug)]
+pub enum SegmentEntry {
+    Record(LogRecord),
+    /// 손상 구간 (세그먼트 안 위치와 길이, 사유)
+    Corrupted { offset: usize, len: usize, reason: &'static str },
+}
+
+/// 세그먼트 바이트를 레코드 단위로 해석 (파일 I/O 없음)
+///
+/// 손상 레코드는 구간만 표시하고 다음 레코드로 넘어가며, 헤더/길이가 잘못되면 세그먼트 끝까지를
+/// 한 구간으로 표시하고 멈춥니다.
+pub fn parse_segment(bytes: &[u8]) -> Vec<SegmentEntry> {
+    let mut entries = Vec::new();
+    if !bytes.starts_with(SEGMENT_MAGIC) {
+        entries.push(SegmentEntry::Corrupted { offset: 0, len: bytes.len(), reason: "세그먼트 매직 불일치" });
+        return entries;
+    }
+
+    let mut offset = SEGMENT_MAGIC.len();
+    while offset < bytes.len() {
+        let rest = &bytes[offset..];
+        if rest.len() < RECORD_HEADER_LEN {
+            entries.push(SegmentEntry::Corrupted { offset, len: rest.len(), reason: "잘린 레코드 헤더" });
+            break;
+        }
+        let length = u32::from_le_bytes(rest[0..4].try_into().unwrap()) as usize;
+        let checksum = u32::from_le_bytes(rest[4..8].try_into().unwrap());
+        if length > MAX_RECORD_BYTES || rest.len() < RECORD_HEADER_LEN + length {
+            entries.push(SegmentEntry::Corrupted { offset, len: rest.len(), reason: "레코드 길이 손상 또는 잘린 레코드" });
+            break;
+        }
+
+        let len = RECORD_HEADER_LEN + length;
+        let payload = &rest[RECORD_HEADER_LEN..len];
+        if crc32fast::hash(payload) != checksum {
+            entries.push(SegmentEntry::Corrupted { offset, len, reason: "CRC 불일치" });
+        } else {
+            match serde_json::from_slice::<LogRecord>(payload) {
+                Ok(record) => entries.push(SegmentEntry::Record(record)),
+                Err(_) => entries.push(SegmentEntry::Corrupted { offset, len, reason: "레코드 본문 해석 실패" }),
+            }
+        }
+        offset += len;
+    }
+    entries
+}
+
+/// 세그먼트 로그 통계
+#[derive(Debug, Clone, Default)]
+pub struct SegmentLogStats {
+    pub segments: usize,
+    pub bytes: u64,
+    /// 격리한 손상 레코드 수 (누적)
+    pub corrupted_records: u64,
+    /// 보관 기간이 지나 제외된 메시지 수 (마지막 조회 기준)
+    pub expired_messages: u64,
+    /// 크기 제한으로 삭제된 메시지 수 (누적)
+    pub dropped_messages: u64,
+}
+
+/// 쓰는 중인 세그먼트
+struct ActiveSegment {
+    id: u64,
+    file: File,
+    size: u64,
+}
+
+/// 디스크 세그먼트 로그
+pub struct SegmentLog {
+    config: SegmentLogConfig,
+    /// 디렉터리를 열었는지 (첫 사용 시 생성/확인)
+    opened: bool,
+    active: Option<ActiveSegment>,
+    next_segment: u64,
+    /// 이미 격리한 (세그먼트, 위치)
+    quarantined: HashSet<(u64, usize)>,
+    stats: SegmentLogStats,
+}
+
+impl SegmentLog {
+    /// 디스크 접근은 첫 사용 시
+    pub fn new(config: SegmentLogConfig) -> Self {
+        Self {
+            config,
+            opened: false,
+            active: None,
+            next_segment: 1,
+            quarantined: HashSet::new(),
+            stats: SegmentLogStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> SegmentLogStats {
+        self.stats.clone()
+    }
+
+    /// 레코드 추가 (디스크 동기화 후 반환)
+    pub fn append(&mut self, records: &[LogRecord]) -> Result<(), String> {
+        if records.is_empty() {
+            return Ok(());
+        }
+        self.open().map_err(|e| format!("백업 디렉터리 열기 실패: {}", e))?;
+
+        let mut buffer = Vec::new();
+        for record in records {
+            let payload = serde_json::to_vec(record).map_err(|e| format!("JSON 직렬화 실패: {}", e))?;
+            buffer.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+            buffer.extend_from_slice(&payload);
+        }
+
+        let active = self.active_segment().map_err(|e| format!("세그먼트 생성 실패: {}", e))?;
+        active.file.write_all(&buffer).map_err(|e| format!("세그먼트 쓰기 실패: {}", e))?;
+        active.file.sync_data().map_err(|e| format!("세그먼트 동기화 실패: {}", e))?;
+        active.size += buffer.len() as u64;
+        let full = active.size >= self.config.segment_max_bytes;
+
+        self.stats.bytes += buffer.len() as u64;
+        if full {
+            self.active = None;
+        }
+        Ok(())
+    }
+
+    /// 남은 메시지 전체를 최초 백업 시각순으로 조회 (`now_ms`: Unix 밀리초)
+    ///
+    /// 보관 기간이 지난 메시지는 제외하고, 남은 메시지가 없는 오래된 세그먼트는 지우며,
+    /// 전체 크기가 제한을 넘으면 가장 오래된 세그먼트부터 메시지째 지웁니다.
+    pub fn scan(&mut self, now_ms: u64) -> Result<Vec<BackupMessage>, String> {
+        self.open().map_err(|e| format!("백업 디렉터리 열기 실패: {}", e))?;
+        let segments = self.segment_files().map_err(|e| format!("세그먼트 목록 조회 실패: {}", e))?;
+
+        // 메시지 ID → (마지막 레코드, 그 레코드가 있는 세그먼트)
+        let mut live: HashMap<String, (BackupMessage, u64)> = HashMap::new();
+        for (segment, path, _) in &segments {
+            let bytes = fs::read(path).map_err(|e| format!("세그먼트 읽기 실패: {}", e))?;
+            for record in self.decode_segment(*segment, &bytes) {
+                match record {
+                    LogRecord::Message(message) => {
+                        live.insert(message.id.clone(), (message, *segment));
+                    }
+                    LogRecord::Ack { id } => {
+                        live.remove(&id);
+                    }
+                }
+            }
+        }
+
+        let retention_ms = self.config.retention.as_millis() as u64;
+        let before = live.len();
+        live.retain(|_, (message, _)| message.created_at.saturating_add(retention_ms) > now_ms);
+        self.stats.expired_messages = (before - live.len()) as u64;
+
+        self.compact(&segments, &mut live).map_err(|e| format!("세그먼트 정리 실패: {}", e))?;
+
+        let mut messages: Vec<BackupMessage> = live.into_values().map(|(message, _)| message).collect();
+        messages.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| a.id.cmp(&b.id)));
+        Ok(messages)
+    }
+
+    /// 세그먼트 전체 삭제
+    pub fn clear(&mut self) -> Result<(), String> {
+        self.open().map_err(|e| format!("백업 디렉터리 열기 실패: {}", e))?;
+        self.active = None;
+        for (_, path, _) in self.segment_files().map_err(|e| format!("세그먼트 목록 조회 실패: {}", e))? {
+            fs::remove_file(&path).map_err(|e| format!("세그먼트 삭제 실패: {}", e))?;
+        }
+        self.stats.segments = 0;
+        self.stats.bytes = 0;
+        Ok(())
+    }
+
+    /// 디렉터리 생성, 다음 세그먼트 번호 확인 (재시작 후에는 항상 새 세그먼트에 씀)
+    fn open(&mut self) -> io::Result<()> {
+        if self.opened {
+            return Ok(());
+        }
+        fs::create_dir_all(self.quarantine_dir())?;
+        let segments = self.segment_files()?;
+        self.next_segment = segments.last().map_or(1, |(segment, _, _)| segment + 1);
+        self.stats.segments = segments.len();
+        self.stats.bytes = segments.iter().map(|(_, _, size)| size).sum();
+        self.opened = true;
+        Ok(())
+    }
+
+    fn active_segment(&mut self) -> io::Result<&mut ActiveSegment> {
+        if self.active.is_none() {
+            let id = self.next_segment;
+            let mut file = OpenOptions::new().create_new(true).append(true).open(self.segment_path(id))?;
+            file.write_all(SEGMENT_MAGIC)?;
+            self.next_segment += 1;
+            self.stats.segments += 1;
+            self.stats.bytes += SEGMENT_MAGIC.len() as u64;
+            self.active = Some(ActiveSegment { id, file, size: SEGMENT_MAGIC.len() as u64 });
+        }
+        Ok(self.active.as_mut().expect("활성 세그먼트"))
+    }
+
+    /// 오래된 세그먼트부터 정리 (남은 메시지가 없거나 전체 크기 제한 초과 시, 쓰는 중인 세그먼트 제외)
+    fn compact(&mut self, segments: &[(u64, PathBuf, u64)], live: &mut HashMap<String, (BackupMessage, u64)>) -> io::Result<()> {
+        let active_id = self.active.as_ref().map(|active| active.id);
+        let mut total: u64 = segments.iter().map(|(_, _, size)| size).sum();
+        let mut removed = 0;
+
+        for (segment, path, size) in segments {
+            if Some(*segment) == active_id {
+                break;
+            }
+            let live_ids: Vec<String> = live
+                .iter()
+                .filter(|(_, (_, in_segment))| in_segment == segment)
+                .map(|(id, _)| id.clone())
+                .collect();
+            if !live_ids.is_empty() {
+                if total <= self.config.max_total_bytes {
+                    break;
+                }
+                warn!("백업 큐 크기 제한 초과: 세그먼트 {} 삭제 (메시지 {}개 유실)", segment, live_ids.len());
+                for id in &live_ids {
+                    live.remove(id);
+                }
+                self.stats.dropped_messages += live_ids.len() as u64;
+            }
+
+            fs::remove_file(path)?;
+            total -= size;
+            removed += 1;
+        }
+
+        if removed > 0 {
+            info!("백업 큐 세그먼트 정리: {}개 삭제", removed);
+        }
+        self.stats.segments = segments.len() - removed;
+        self.stats.bytes = total;
+        Ok(())
+    }
+
+    /// 세그먼트 레코드 해석 (손상 레코드는 격리하고 건너뜀)
+    fn decode_segment(&mut self, segment: u64, bytes: &[u8]) -> Vec<LogRecord> {
+        let mut records = Vec::new();
+        for entry in parse_segment(bytes) {
+            match entry {
+                SegmentEntry::Record(record) => records.push(record),
+                SegmentEntry::Corrupted { offset, len, reason } => {
+                    self.quarantine(segment, offset, &bytes[offset..offset + len], reason)
+                }
+            }
+        }
+        records
+    }
+
+    /// 손상 바이트를 격리 디렉터리에 보관 (같은 위치는 한 번만)
+    fn quarantine(&mut self, segment: u64, offset: usize, bytes: &[u8], reason: &str) {
+        if !self.quarantined.insert((segment, offset)) {
+            return;
+        }
+        self.stats.corrupted_records += 1;
+
+        let path = self.quarantine_dir().join(format!("{:020}-{}.bin", segment, offset));
+        match fs::write(&path, bytes) {
+            Ok(()) => warn!("백업 큐 손상 레코드 격리: 세그먼트 {} 위치 {} ({}) -> {}", segment, offset, reason, path.display()),
+            Err(e) => warn!("백업 큐 손상 레코드 격리 실패: 세그먼트 {} 위치 {} ({}): {}", segment, offset, reason, e),
+        }
+    }
+
+    /// 세그먼트 파일 (번호, 경로, 크기) 번호순
+    fn segment_files(&self) -> io::Result<Vec<(u64, PathBuf, u64)>> {
+        let mut segments = Vec::new();
+        for entry in fs::read_dir(&self.config.directory)? {
+            let path = entry?.path();
+            let Some(segment) = Self::segment_id(&path) else { continue };
+            segments.push((segment, path.clone(), fs::metadata(&path)?.len()));
+        }
+        segments.sort_by_key(|(segment, _, _)| *segment);
+        Ok(segments)
+    }
+
+    fn segment_id(path: &Path) -> Option<u64> {
+        if path.extension()? != "seg" {
+            return None;
+        }
+        path.file_stem()?.to_str()?.parse().ok()
+    }
+
+    fn segment_path(&self, segment: u64) -> PathBuf {
+        self.config.directory.join(format!("{:020}.seg", segment))
+    }
+
+    fn quarantine_dir(&self) -> PathBuf {
+        self.config.directory.join("quarantine")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup_queue::{BackupMessageBuilder, MQType};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("xtrader-{}-{}", name, uuid::Uuid::new_v4()))
+    }
+
+    fn message(created_at: u64) -> BackupMessage {
+        let mut message = BackupMessageBuilder::new(MQType::Kafka, "market-data".to_string()).build();
+        message.created_at = created_at;
+        message
+    }
+
+    #[test]
+    fn test_replay_after_restart_in_enqueue_order() {
+        let config = SegmentLogConfig::new(temp_dir("segment-replay"));
+        let (first, second, third) = (message(1_000), message(2_000), message(3_000));
+
+        let mut log = SegmentLog::new(config.clone());
+        // 기록 순서와 무관하게 최초 백업 시각순으로 복원
+        log.append(&[LogRecord::Message(third.clone()), LogRecord::Message(first.clone())]).unwrap();
+        log.append(&[LogRecord::Message(second.clone())]).unwrap();
+        let mut retried = first.clone();
+        retried.retry_count = 2;
+        log.append(&[LogRecord::Message(retried), LogRecord::Ack { id: third.id.clone() }]).unwrap();
+        drop(log);
+
+        let mut reopened = SegmentLog::new(config.clone());
+        let messages = reopened.scan(4_000).unwrap();
+        assert_eq!(messages.iter().map(|m| m.id.clone()).collect::<Vec<_>>(), vec![first.id.clone(), second.id]);
+        assert_eq!(messages[0].retry_count, 2);
+
+        // 재시작 후에는 새 세그먼트에 씀
+        reopened.append(&[LogRecord::Ack { id: first.id }]).unwrap();
+        assert_eq!(reopened.stats().segments, 2);
+        fs::remove_dir_all(config.directory).unwrap();
+    }
+
+    #[test]
+    fn test_corrupted_records_are_quarantined() {
+        let config = SegmentLogConfig::new(temp_dir("segment-corrupt"));
+        let (first, second, third) = (message(1_000), message(2_000), message(3_000));
+
+        let mut log = SegmentLog::new(config.clone());
+        log.append(&[LogRecord::Message(first.clone())]).unwrap();
+        log.append(&[LogRecord::Message(second.clone())]).unwrap();
+        log.append(&[LogRecord::Message(third.clone())]).unwrap();
+        drop(log);
+
+        // 두 번째 레코드 본문 한 바이트 변조 + 마지막 레코드 꼬리 잘림
+        let path = config.directory.join(format!("{:020}.seg", 1));
+        let mut bytes = fs::read(&path).unwrap();
+        let first_len = u32::from_le_bytes(bytes[4..8].try_into().unwrap()) as usize;
+        let second_payload = 4 + RECORD_HEADER_LEN + first_len + RECORD_HEADER_LEN;
+        bytes[second_payload + 3] ^= 0xff;
+        bytes.truncate(bytes.len() - 5);
+        fs::write(&path, bytes).unwrap();
+
+        let mut reopened = SegmentLog::new(config.clone());
+        let messages = reopened.scan(4_000).unwrap();
+        assert_eq!(messages.iter().map(|m| m.id.clone()).collect::<Vec<_>>(), vec![first.id]);
+        assert_eq!(reopened.stats().corrupted_records, 2);
+        assert_eq!(fs::read_dir(config.directory.join("quarantine")).unwrap().count(), 2);
+
+        // 다시 읽어도 같은 손상은 한 번만 셈
+        reopened.scan(4_000).unwrap();
+        assert_eq!(reopened.stats().corrupted_records, 2);
+        fs::remove_dir_all(config.directory).unwrap();
+    }
+
+    #[test]
+    fn test_retention_and_size_limits() {
+        let mut config = SegmentLogConfig::new(temp_dir("segment-limits"));
+        config.segment_max_bytes = 1; // 레코드마다 새 세그먼트
+        config.retention = Duration::from_millis(10_000);
+        let (old, kept, newest) = (message(1_000), message(20_000), message(21_000));
+
+        let mut log = SegmentLog::new(config.clone());
+        for message in [&old, &kept, &newest] {
+            log.append(&[LogRecord::Message(message.clone())]).unwrap();
+        }
+        assert_eq!(log.stats().segments, 3);
+
+        // 보관 기간이 지난 메시지는 제외되고 그 세그먼트는 삭제
+        let messages = log.scan(15_000).unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(log.stats().expired_messages, 1);
+        assert_eq!(log.stats().segments, 2);
+
+        // 크기 제한: 가장 오래된 세그먼트부터 메시지째 삭제
+        log.config.max_total_bytes = log.stats().bytes - 1;
+        let messages = log.scan(15_000).unwrap();
+        assert_eq!(messages.iter().map(|m| m.id.clone()).collect::<Vec<_>>(), vec![newest.id]);
+        assert_eq!(log.stats().dropped_messages, 1);
+        fs::remove_dir_all(config.directory).unwrap();
+    }
+}
@@ -0,0 +1,213 @@
+//! MQ 상태 저장소 (SQLite)
+//!
+//! Kafka Consumer 커밋 오프셋(`kafka_consumer_offsets`)과 DLQ 격리 메시지(`dlq_quarantine`)를 저장합니다.
+//! 두 테이블은 MQ만 쓰므로 스키마도 이 모듈이 만들고, 서버의 `db::create_tables`가 함께 호출합니다.
+
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqlitePool;
+use sqlx::{Error as SqlxError, FromRow};
+
+/// MQ 테이블 생성 (이미 있으면 그대로 둠)
+pub async fn create_tables(pool: &SqlitePool) -> Result<(), SqlxError> {
+    // Kafka Consumer 그룹별 커밋 오프셋 (처리를 마친 다음 오프셋, 재시작 시 여기서 이어 읽음)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS kafka_consumer_offsets (
+            consumer_group TEXT NOT NULL,
+            topic TEXT NOT NULL,
+            partition INTEGER NOT NULL,
+            committed_offset INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (consumer_group, topic, partition)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // DLQ 격리 메시지 (재시도해도 처리할 수 없는 메시지, 관리자 재발행/폐기 대기)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS dlq_quarantine (
+            id TEXT PRIMARY KEY,
+            message_id TEXT,
+            routing_key TEXT NOT NULL,
+            payload TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            error TEXT NOT NULL,
+            attempts INTEGER NOT NULL DEFAULT 0,
+            status TEXT NOT NULL,
+            quarantined_at INTEGER NOT NULL,
+            resolved_by TEXT,
+            resolved_at INTEGER
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_dlq_quarantine_status ON dlq_quarantine(status, quarantined_at)")
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Kafka Consumer 커밋 오프셋 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ConsumerOffsetRecord {
+    pub consumer_group: String,
+    pub topic: String,
+    pub partition: i64,
+    /// 다음에 읽을 오프셋 (마지막으로 처리한 메시지 오프셋 + 1)
+    pub committed_offset: i64,
+    /// 커밋 시각 (밀리초)
+    pub updated_at: i64,
+}
+
+/// DLQ 격리 메시지 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct QuarantinedMessageRecord {
+    pub id: String,
+    /// 원본 메시지 ID (역직렬화 실패 시 없음)
+    pub message_id: Option<String>,
+    pub routing_key: String,
+    /// 원본 본문
+    pub payload: String,
+    /// 격리 사유 (deserialization, invalid_message, retries_exhausted)
+    pub reason: String,
+    pub error: String,
+    pub attempts: i64,
+    /// 상태 (quarantined, requeued, discarded)
+    pub status: String,
+    pub quarantined_at: i64,
+    pub resolved_by: Option<String>,
+    pub resolved_at: Option<i64>,
+}
+
+/// Kafka Consumer 커밋 오프셋 저장소
+pub struct ConsumerOffsetRepository {
+    pool: SqlitePool,
+}
+
+impl ConsumerOffsetRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 파티션 오프셋 커밋 (그룹·토픽·파티션당 한 행, 있으면 갱신)
+    pub async fn commit(&self, record: &ConsumerOffsetRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO kafka_consumer_offsets (consumer_group, topic, partition, committed_offset, updated_at)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(consumer_group, topic, partition) DO UPDATE SET
+                committed_offset = excluded.committed_offset,
+                updated_at = excluded.updated_at"
+        )
+        .bind(&record.consumer_group)
+        .bind(&record.topic)
+        .bind(record.partition)
+        .bind(record.committed_offset)
+        .bind(record.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 그룹이 토픽에 커밋한 파티션별 오프셋
+    pub async fn find_by_group(&self, consumer_group: &str, topic: &str) -> Result<Vec<ConsumerOffsetRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, ConsumerOffsetRecord>(
+            "SELECT consumer_group, topic, partition, committed_offset, updated_at
+             FROM kafka_consumer_offsets
+             WHERE consumer_group = ? AND topic = ?
+             ORDER BY partition"
+        )
+        .bind(consumer_group)
+        .bind(topic)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// DLQ 격리 메시지 Repository
+pub struct QuarantineRepository {
+    pool: SqlitePool,
+}
+
+impl QuarantineRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 격리 메시지 저장
+    pub async fn insert(&self, record: &QuarantinedMessageRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO dlq_quarantine
+             (id, message_id, routing_key, payload, reason, error, attempts, status, quarantined_at, resolved_by, resolved_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&record.id)
+        .bind(&record.message_id)
+        .bind(&record.routing_key)
+        .bind(&record.payload)
+        .bind(&record.reason)
+        .bind(&record.error)
+        .bind(record.attempts)
+        .bind(&record.status)
+        .bind(record.quarantined_at)
+        .bind(&record.resolved_by)
+        .bind(record.resolved_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 격리 상태인 메시지만 처리 결과로 갱신 (이미 처리됐으면 false)
+    pub async fn resolve(&self, id: &str, status: &str, resolved_by: &str, resolved_at: i64) -> Result<bool, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE dlq_quarantine SET status = ?, resolved_by = ?, resolved_at = ?
+             WHERE id = ? AND status = 'quarantined'"
+        )
+        .bind(status)
+        .bind(resolved_by)
+        .bind(resolved_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// ID로 조회
+    pub async fn find(&self, id: &str) -> Result<Option<QuarantinedMessageRecord>, SqlxError> {
+        let record = sqlx::query_as::<_, QuarantinedMessageRecord>(
+            "SELECT id, message_id, routing_key, payload, reason, error, attempts, status, quarantined_at,
+                    resolved_by, resolved_at
+             FROM dlq_quarantine
+             WHERE id = ?"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// 상태별 조회 (최신순)
+    pub async fn find_by_status(&self, status: &str, limit: i64) -> Result<Vec<QuarantinedMessageRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, QuarantinedMessageRecord>(
+            "SELECT id, message_id, routing_key, payload, reason, error, attempts, status, quarantined_at,
+                    resolved_by, resolved_at
+             FROM dlq_quarantine
+             WHERE status = ?
+             ORDER BY quarantined_at DESC
+             LIMIT ?"
+        )
+        .bind(status)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
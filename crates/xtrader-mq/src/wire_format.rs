@@ -0,0 +1,271 @@
+//! 내부 MQ 페이로드 직렬화 형식
+//!
+//! 체결/시장 데이터 메시지는 Redis Streams와 Kafka를 거치며 여러 번 직렬화되므로,
+//! 내부 MQ 페이로드는 rkyv 바이너리로 보내고 JSON은 외부 API(REST/WebSocket) 경계에서만 씁니다.
+//! rkyv 페이로드는 `XRK1` 매직 바이트로 시작하므로 Consumer는 형식 설정 없이 JSON과 함께 읽을 수 있고,
+//! 배포 중 Producer와 Consumer 버전이 섞여도 메시지가 유실되지 않습니다.
+//!
+//! 아카이브는 리틀 엔디언/비정렬 형식이라 MQ에서 받은 버퍼를 복사하지 않고 [`access`]로 바로 읽을 수 있습니다.
+
+use rkyv::api::high::{HighDeserializer, HighSerializer, HighValidator};
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::{self, Fallible};
+use rkyv::ser::allocator::ArenaHandle;
+use rkyv::ser::{Allocator, Writer};
+use rkyv::util::AlignedVec;
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Place};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// rkyv 페이로드 앞의 매직 바이트 (JSON은 `{`/`[`로 시작하므로 겹치지 않음)
+pub const RKYV_MAGIC: &[u8; 4] = b"XRK1";
+
+/// 내부 MQ 페이로드 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WireFormat {
+    Json,
+    #[default]
+    Rkyv,
+}
+
+impl WireFormat {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(WireFormat::Json),
+            "rkyv" | "binary" => Ok(WireFormat::Rkyv),
+            _ => Err(format!("알 수 없는 MQ 페이로드 형식: {} (json, rkyv 중 선택)", name)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WireFormat::Json => "json",
+            WireFormat::Rkyv => "rkyv",
+        }
+    }
+
+    /// 페이로드 형식 판별 (매직 바이트가 없으면 JSON)
+    pub fn detect(payload: &[u8]) -> Self {
+        if payload.starts_with(RKYV_MAGIC) {
+            WireFormat::Rkyv
+        } else {
+            WireFormat::Json
+        }
+    }
+}
+
+/// 메시지 직렬화
+pub fn encode<M>(message: &M, format: WireFormat) -> Result<Vec<u8>, String>
+where
+    M: Serialize + for<'a> rkyv::Serialize<HighSerializer<AlignedVec, ArenaHandle<'a>, rancor::Error>>,
+{
+    match format {
+        WireFormat::Json => serde_json::to_vec(message).map_err(|e| format!("JSON 직렬화 실패: {}", e)),
+        WireFormat::Rkyv => {
+            let archived = rkyv::to_bytes::<rancor::Error>(message).map_err(|e| format!("rkyv 직렬화 실패: {}", e))?;
+            Ok(framed(&archived))
+        }
+    }
+}
+
+/// 메시지 슬라이스를 `Vec<M>`과 같은 형식으로 직렬화 (배치용, 복사 없이)
+pub fn encode_slice<M>(messages: &[M], format: WireFormat) -> Result<Vec<u8>, String>
+where
+    M: Serialize + for<'a> rkyv::Serialize<HighSerializer<AlignedVec, ArenaHandle<'a>, rancor::Error>>,
+{
+    match format {
+        WireFormat::Json => serde_json::to_vec(messages).map_err(|e| format!("JSON 직렬화 실패: {}", e)),
+        WireFormat::Rkyv => {
+            let archived = rkyv::to_bytes::<rancor::Error>(&SliceRef(messages))
+                .map_err(|e| format!("rkyv 직렬화 실패: {}", e))?;
+            Ok(framed(&archived))
+        }
+    }
+}
+
+fn framed(archived: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(RKYV_MAGIC.len() + archived.len());
+    payload.extend_from_slice(RKYV_MAGIC);
+    payload.extend_from_slice(archived);
+    payload
+}
+
+/// rkyv 페이로드를 역직렬화 없이 검증 후 그대로 참조 (JSON 페이로드는 오류)
+pub fn access<M>(payload: &[u8]) -> Result<&M::Archived, String>
+where
+    M: Archive,
+    M::Archived: for<'a> CheckBytes<HighValidator<'a, rancor::Error>>,
+{
+    let body = payload
+        .strip_prefix(RKYV_MAGIC.as_slice())
+        .ok_or_else(|| "rkyv 페이로드가 아닙니다".to_string())?;
+    rkyv::access::<M::Archived, rancor::Error>(body).map_err(|e| format!("rkyv 페이로드 검증 실패: {}", e))
+}
+
+/// 메시지 역직렬화 (형식은 페이로드로 판별)
+pub fn decode<M>(payload: &[u8]) -> Result<M, String>
+where
+    M: DeserializeOwned + Archive,
+    M::Archived: for<'a> CheckBytes<HighValidator<'a, rancor::Error>> + rkyv::Deserialize<M, HighDeserializer<rancor::Error>>,
+{
+    match WireFormat::detect(payload) {
+        WireFormat::Json => serde_json::from_slice(payload).map_err(|e| format!("JSON 역직렬화 실패: {}", e)),
+        WireFormat::Rkyv => rkyv::deserialize::<M, rancor::Error>(access::<M>(payload)?)
+            .map_err(|e| format!("rkyv 역직렬화 실패: {}", e)),
+    }
+}
+
+/// 격리 저장소 등 문자열 필드에 담을 페이로드 표현 (바이너리는 base64)
+pub fn payload_to_string(payload: &[u8]) -> String {
+    match std::str::from_utf8(payload) {
+        Ok(text) if WireFormat::detect(payload) == WireFormat::Json => text.to_string(),
+        _ => format!("base64:{}", data_encoding::BASE64.encode(payload)),
+    }
+}
+
+/// 슬라이스를 `Vec<T>` 아카이브로 직렬화하기 위한 참조 래퍼
+struct SliceRef<'a, T>(&'a [T]);
+
+impl<T: Archive> Archive for SliceRef<'_, T> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self.0, resolver, out);
+    }
+}
+
+impl<T, S> rkyv::Serialize<S> for SliceRef<'_, T>
+where
+    T: rkyv::Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self.0, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kafka_producer::OrderBookUpdateMessage;
+    use crate::redis_streams::ExecutionMessage;
+    use std::time::Instant;
+
+    fn execution(sequence: u64) -> ExecutionMessage {
+        ExecutionMessage {
+            execution_id: format!("exec_{}", sequence),
+            symbol: "BTC-KRW".to_string(),
+            side: "Buy".to_string(),
+            price: 50_000_000 + sequence % 100,
+            quantity: 1 + sequence % 7,
+            timestamp: 1_700_000_000_000 + sequence,
+            order_id: format!("order_{}", sequence),
+            user_id: "user_001".to_string(),
+        }
+    }
+
+    fn update(sequence: u64) -> OrderBookUpdateMessage {
+        OrderBookUpdateMessage {
+            symbol: "BTC-KRW".to_string(),
+            timestamp: 1_700_000_000_000 + sequence,
+            message_type: "orderbook_update".to_string(),
+            bids: vec![(50_000_000 - sequence % 10 * 1000, 1 + sequence % 7)],
+            asks: vec![(50_001_000 + sequence % 10 * 1000, 2 + sequence % 5)],
+            sequence,
+        }
+    }
+
+    #[test]
+    fn test_round_trip_and_detection() {
+        let message = execution(7);
+        for format in [WireFormat::Json, WireFormat::Rkyv] {
+            let payload = encode(&message, format).unwrap();
+            assert_eq!(WireFormat::detect(&payload), format);
+            let decoded: ExecutionMessage = decode(&payload).unwrap();
+            assert_eq!((decoded.execution_id.as_str(), decoded.quantity), ("exec_7", 1));
+        }
+
+        // 복사 없이 필드 읽기 (바이트 경계가 맞지 않는 버퍼도 허용)
+        let payload = encode(&message, WireFormat::Rkyv).unwrap();
+        let mut shifted = vec![0u8];
+        shifted.extend_from_slice(&payload);
+        let archived = access::<ExecutionMessage>(&shifted[1..]).unwrap();
+        assert_eq!(archived.symbol.as_str(), "BTC-KRW");
+        assert_eq!(archived.price.to_native(), 50_000_007);
+        assert!(access::<ExecutionMessage>(&encode(&message, WireFormat::Json).unwrap()).is_err());
+
+        assert_eq!(WireFormat::parse("RKYV"), Ok(WireFormat::Rkyv));
+        assert!(WireFormat::parse("protobuf").is_err());
+    }
+
+    #[test]
+    fn test_slice_matches_vec_archive_and_rejects_corruption() {
+        let updates: Vec<_> = (0..50).map(update).collect();
+        let payload = encode_slice(&updates[..], WireFormat::Rkyv).unwrap();
+        assert_eq!(payload, encode(&updates, WireFormat::Rkyv).unwrap());
+        let decoded: Vec<OrderBookUpdateMessage> = decode(&payload).unwrap();
+        assert_eq!(decoded.len(), 50);
+        assert_eq!(decoded[49].asks, updates[49].asks);
+
+        // 잘린 페이로드는 패닉 없이 오류
+        let truncated = &payload[..payload.len() / 2];
+        assert!(decode::<Vec<OrderBookUpdateMessage>>(truncated).is_err());
+        assert!(payload_to_string(truncated).starts_with("base64:"));
+        assert_eq!(payload_to_string(b"{\"a\":1}"), "{\"a\":1}");
+    }
+
+    /// JSON/rkyv 직렬화 CPU 비용 비교
+    ///
+    /// `cargo test --release wire_format::tests::bench -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_wire_formats() {
+        const MESSAGES: u64 = 200_000;
+        let executions: Vec<_> = (0..MESSAGES).map(execution).collect();
+        let updates: Vec<_> = (0..MESSAGES).map(update).collect();
+
+        let report = |name: &str, format: WireFormat, encode_time: std::time::Duration, decode_time: std::time::Duration, bytes: usize| {
+            println!(
+                "{:<18} {:<5} 직렬화 {:>10.0} msg/s  역직렬화 {:>10.0} msg/s  {:>6.1} bytes/msg",
+                name,
+                format.as_str(),
+                MESSAGES as f64 / encode_time.as_secs_f64(),
+                MESSAGES as f64 / decode_time.as_secs_f64(),
+                bytes as f64 / MESSAGES as f64,
+            );
+        };
+
+        for format in [WireFormat::Json, WireFormat::Rkyv] {
+            let started = Instant::now();
+            let payloads: Vec<_> = executions.iter().map(|m| encode(m, format).unwrap()).collect();
+            let encode_time = started.elapsed();
+            let started = Instant::now();
+            for payload in &payloads {
+                let _: ExecutionMessage = decode(payload).unwrap();
+            }
+            report("체결 (메시지별)", format, encode_time, started.elapsed(), payloads.iter().map(Vec::len).sum());
+
+            let started = Instant::now();
+            let batches: Vec<_> = updates.chunks(500).map(|chunk| encode_slice(chunk, format).unwrap()).collect();
+            let encode_time = started.elapsed();
+            let started = Instant::now();
+            for batch in &batches {
+                let _: Vec<OrderBookUpdateMessage> = decode(batch).unwrap();
+            }
+            report("호가창 (배치 500)", format, encode_time, started.elapsed(), batches.iter().map(Vec::len).sum());
+        }
+
+        // 필드 몇 개만 읽는 Consumer는 역직렬화 없이 접근
+        let payloads: Vec<_> = executions.iter().map(|m| encode(m, WireFormat::Rkyv).unwrap()).collect();
+        let started = Instant::now();
+        let volume: u64 = payloads
+            .iter()
+            .map(|payload| access::<ExecutionMessage>(payload).unwrap().quantity.to_native())
+            .sum();
+        let elapsed = started.elapsed();
+        println!("체결 제로카피 접근   {:>10.0} msg/s  (수량 합계 {})", MESSAGES as f64 / elapsed.as_secs_f64(), volume);
+    }
+}
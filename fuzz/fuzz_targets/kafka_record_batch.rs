@@ -0,0 +1,36 @@
+//! Kafka 호가창 업데이트 배치 압축 해제
+//!
+//! 첫 바이트로 압축 방식을 고르고 나머지를 배치 본문으로 씁니다.
+//! 압축 해제 결과는 `MAX_DECOMPRESSED_BYTES`를 넘지 않아야 하고,
+//! 풀린 본문(JSON 또는 `XRK1` rkyv)은 검증 실패 시 패닉 없이 오류여야 합니다.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use xtrader::mq::{CompressionType, RecordBatch, WireFormat, MAX_DECOMPRESSED_BYTES};
+
+fuzz_target!(|data: &[u8]| {
+    let Some((codec, payload)) = data.split_first() else {
+        return;
+    };
+    let compression = match codec % 5 {
+        0 => CompressionType::None,
+        1 => CompressionType::Gzip,
+        2 => CompressionType::Snappy,
+        3 => CompressionType::Lz4,
+        _ => CompressionType::Zstd,
+    };
+    if let Ok(decoded) = compression.decompress(payload) {
+        assert!(decoded.len() <= MAX_DECOMPRESSED_BYTES);
+    }
+
+    let batch = RecordBatch {
+        symbol: "BTC-KRW".to_string(),
+        compression,
+        wire_format: WireFormat::detect(payload),
+        message_count: 0,
+        uncompressed_bytes: 0,
+        payload: payload.to_vec(),
+    };
+    let _ = batch.decode();
+});
@@ -0,0 +1,21 @@
+//! 주문 요청 JSON 해석과 검증
+//!
+//! `POST /v1/order` 본문을 받는 경로와 같게 `OrderRequest`로 역직렬화한 뒤 검증기를 통과시킵니다.
+//! 어떤 입력이든 오류로 거부되어야 하며 패닉이 나면 안 됩니다.
+
+#![no_main]
+
+use std::collections::HashMap;
+
+use libfuzzer_sys::fuzz_target;
+use xtrader::api::models::OrderRequest;
+use xtrader::api::OrderValidator;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(request) = serde_json::from_slice::<OrderRequest>(data) else {
+        return;
+    };
+    let validator = OrderValidator::new(["BTC-KRW", "ETH-KRW", "AAPL"].map(String::from))
+        .with_max_order_sizes(HashMap::from([("BTC-KRW".to_string(), 10_000)]));
+    let _ = validator.validate(&request, 1_700_000_000);
+});
@@ -0,0 +1,17 @@
+//! 백업 큐 세그먼트 해석
+//!
+//! 재시작 시 디스크에서 읽는 세그먼트 바이트는 쓰는 중 장애나 디스크 손상으로 임의의 값일 수 있습니다.
+//! 손상 구간은 항상 입력 범위 안이어야 격리 시 잘라 낼 수 있습니다.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use xtrader::mq::{parse_segment, SegmentEntry};
+
+fuzz_target!(|data: &[u8]| {
+    for entry in parse_segment(data) {
+        if let SegmentEntry::Corrupted { offset, len, .. } = entry {
+            assert!(offset + len <= data.len());
+        }
+    }
+});
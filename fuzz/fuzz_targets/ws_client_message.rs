@@ -0,0 +1,14 @@
+//! WebSocket 클라이언트 메시지 해석
+//!
+//! `/ws` 연결이 받은 텍스트 프레임을 해석하는 경로입니다.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use xtrader::api::websocket::ClientMessage;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = ClientMessage::parse(text);
+    }
+});
@@ -0,0 +1,55 @@
+#!/bin/bash
+# 부하 테스트 실행 (CI용)
+#
+# 로컬 서버를 띄운 뒤 주문 전송률을 단계적으로 올려 최대 지속 처리량을 측정합니다.
+# LOADTEST_MIN_RATE 미달 시 0이 아닌 코드로 종료합니다.
+# 이미 실행 중인 서버를 사용하려면 XTRADER_SKIP_SERVER=1 로 실행하세요.
+
+set -e
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+API_URL="${XTRADER_API_URL:-http://localhost:7000}"
+
+echo "xTrader 부하 테스트"
+echo ""
+echo "환경 변수:"
+echo "  LOADTEST_START_RATE      시작 전송률, 초당 주문 수 (기본: 50)"
+echo "  LOADTEST_RATE_MULTIPLIER 단계별 전송률 배수 (기본: 1.5)"
+echo "  LOADTEST_MAX_RATE        전송률 상한 (기본: 20000)"
+echo "  LOADTEST_STEP_SECS       단계별 측정 시간(초) (기본: 10)"
+echo "  LOADTEST_TARGET_P99_MS   목표 p99 접수 지연(ms) (기본: 50)"
+echo "  LOADTEST_MAX_ERROR_RATE  허용 오류율 (기본: 0.01)"
+echo "  LOADTEST_MIN_RATE        최소 요구 처리량, 미달 시 실패 (기본: 0)"
+echo "  LOADTEST_MAX_IN_FLIGHT   최대 동시 요청 수 (기본: 256)"
+echo "  SIM_REPORT_PATH          리포트 JSON 저장 경로"
+echo ""
+
+SERVER_PID=""
+cleanup() {
+    if [ -n "$SERVER_PID" ]; then
+        kill "$SERVER_PID" 2>/dev/null || true
+    fi
+}
+trap cleanup EXIT
+
+if [ -z "$XTRADER_SKIP_SERVER" ]; then
+    echo "로컬 서버 빌드 및 실행..."
+    (cd "$SCRIPT_DIR/.." && cargo build --release)
+    (cd "$SCRIPT_DIR/.." && ./target/release/xTrader > /tmp/xtrader_load_test_server.log 2>&1) &
+    SERVER_PID=$!
+fi
+
+# 서버 준비 대기 (최대 60초)
+for _ in $(seq 1 60); do
+    if curl -sf "$API_URL/api/v1/orderbook/BTC-KRW" > /dev/null; then
+        break
+    fi
+    sleep 1
+done
+if ! curl -sf "$API_URL/api/v1/orderbook/BTC-KRW" > /dev/null; then
+    echo "서버 준비 실패: $API_URL"
+    exit 1
+fi
+
+cd "$SCRIPT_DIR"
+SIM_MODE=loadtest cargo run --release
@@ -3,6 +3,16 @@
 echo "xTrader 시뮬레이션 프로그램 시작"
 echo "백엔드 서버가 실행 중인지 확인하세요: http://localhost:7000"
 echo ""
+echo "환경 변수:"
+echo "  XTRADER_API_URL   REST API 주소 (기본: http://localhost:7000)"
+echo "  XTRADER_WS_URL    WebSocket 체결 피드 주소 (기본: ws://localhost:7000/ws)"
+echo "  SIM_DURATION_SECS 실행 시간(초), 종료 시 지연/체결 리포트 출력 (기본: Ctrl+C까지)"
+echo "  SIM_REPORT_PATH   리포트 JSON 저장 경로"
+echo "  SIM_CANCEL_RATIO  지정가 주문 취소 비율 (기본: 0.2)"
+echo "  SIM_REPLACE_RATIO 취소 후 재주문(정정) 비율 (기본: 0.5)"
+echo "  SIM_CANCEL_DELAY_MS 취소 지연 범위 \"최소-최대\" (기본: 200-5000)"
+echo "  SIM_MODE          loadtest: 부하 테스트 모드 (run_load_test.sh 참고)"
+echo ""
 
 # 시뮬레이션 프로그램 실행
 cargo run
\ No newline at end of file
@@ -0,0 +1,238 @@
+//! 부하 테스트 모드
+//!
+//! 주문 전송률을 단계적으로 올리면서 단계마다 접수 지연(p99)과 오류율을 측정하고,
+//! 임계값을 넘는 첫 단계 직전의 전송률을 최대 지속 처리량으로 보고합니다.
+//! 동시 요청 수에 상한을 두므로 서버가 느려지면 실제 전송률이 목표보다 떨어지며,
+//! 이 역시 한계 도달로 판정합니다.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use log::{info, warn};
+use reqwest::Client;
+use serde::Serialize;
+use tokio::sync::{Mutex, Semaphore};
+use tokio::time::{self, MissedTickBehavior};
+
+use crate::stats::LatencyHistogram;
+use crate::{create_random_order, submit_order, OrderTemplate};
+
+/// 목표 대비 실제 전송률이 이 비율 미만이면 포화로 판정
+const MIN_ACHIEVED_RATIO: f64 = 0.9;
+
+/// 부하 테스트 설정 (환경 변수)
+#[derive(Debug, Clone)]
+pub struct LoadTestConfig {
+    /// 시작 전송률, 초당 주문 수 (LOADTEST_START_RATE)
+    pub start_rate: f64,
+    /// 단계별 전송률 배수 (LOADTEST_RATE_MULTIPLIER)
+    pub rate_multiplier: f64,
+    /// 전송률 상한 (LOADTEST_MAX_RATE)
+    pub max_rate: f64,
+    /// 단계별 측정 시간 (LOADTEST_STEP_SECS)
+    pub step_duration: Duration,
+    /// 목표 p99 접수 지연 (LOADTEST_TARGET_P99_MS)
+    pub target_p99: Duration,
+    /// 허용 오류율 (LOADTEST_MAX_ERROR_RATE, 0.0~1.0)
+    pub max_error_rate: f64,
+    /// 최소 요구 처리량, 미달 시 실패 종료 (LOADTEST_MIN_RATE)
+    pub min_sustainable_rate: f64,
+    /// 최대 동시 요청 수 (LOADTEST_MAX_IN_FLIGHT)
+    pub max_in_flight: usize,
+}
+
+impl LoadTestConfig {
+    pub fn from_env() -> Self {
+        fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<T>().ok())
+                .unwrap_or(default)
+        }
+
+        Self {
+            start_rate: env_or("LOADTEST_START_RATE", 50.0_f64).max(1.0),
+            rate_multiplier: env_or("LOADTEST_RATE_MULTIPLIER", 1.5_f64).max(1.01),
+            max_rate: env_or("LOADTEST_MAX_RATE", 20_000.0),
+            step_duration: Duration::from_secs(env_or("LOADTEST_STEP_SECS", 10_u64).max(1)),
+            target_p99: Duration::from_millis(env_or("LOADTEST_TARGET_P99_MS", 50_u64)),
+            max_error_rate: env_or("LOADTEST_MAX_ERROR_RATE", 0.01_f64).clamp(0.0, 1.0),
+            min_sustainable_rate: env_or("LOADTEST_MIN_RATE", 0.0),
+            max_in_flight: env_or("LOADTEST_MAX_IN_FLIGHT", 256_usize).max(1),
+        }
+    }
+}
+
+/// 단계별 측정 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadStepResult {
+    pub target_rate: f64,
+    pub achieved_rate: f64,
+    pub sent: u64,
+    pub errors: u64,
+    pub error_rate: f64,
+    pub p50_us: u64,
+    pub p99_us: u64,
+    /// 임계값 초과 사유 (통과 시 None)
+    pub breach: Option<String>,
+}
+
+/// 부하 테스트 리포트
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadTestReport {
+    pub target_p99_ms: u64,
+    pub max_error_rate: f64,
+    pub min_sustainable_rate: f64,
+    /// 임계값을 넘지 않은 마지막 단계의 실제 전송률
+    pub max_sustainable_rate: f64,
+    pub steps: Vec<LoadStepResult>,
+    pub passed: bool,
+}
+
+impl LoadTestReport {
+    /// 리포트 콘솔 출력
+    pub fn print(&self) {
+        println!();
+        println!("📈 부하 테스트 리포트 (목표 p99 {}ms, 허용 오류율 {:.2}%)",
+                 self.target_p99_ms, self.max_error_rate * 100.0);
+        println!("  {:>10} {:>10} {:>8} {:>8} {:>10} {:>10}  결과",
+                 "목표/s", "실제/s", "전송", "오류", "p50(us)", "p99(us)");
+        for step in &self.steps {
+            println!("  {:>10.1} {:>10.1} {:>8} {:>8} {:>10} {:>10}  {}",
+                     step.target_rate, step.achieved_rate, step.sent, step.errors,
+                     step.p50_us, step.p99_us,
+                     step.breach.as_deref().unwrap_or("통과"));
+        }
+        println!("  최대 지속 처리량: {:.1} 주문/초", self.max_sustainable_rate);
+        if self.min_sustainable_rate > 0.0 {
+            println!("  최소 요구 처리량: {:.1} 주문/초 → {}",
+                     self.min_sustainable_rate, if self.passed { "통과" } else { "실패" });
+        }
+    }
+}
+
+/// 부하 테스트 실행
+pub async fn run(client: Client, api_base_url: &str, config: &LoadTestConfig) -> LoadTestReport {
+    // 매수/매도를 교차시켜 체결 경로까지 부하를 줌
+    let templates = [
+        OrderTemplate::new("BTC-KRW", "Buy", "Limit", (99_900_000, 100_050_000), (1, 5), 0),
+        OrderTemplate::new("BTC-KRW", "Sell", "Limit", (99_950_000, 100_100_000), (1, 5), 0),
+    ];
+
+    println!("부하 테스트 시작: {:.0}/s부터 x{:.2}씩 증가 (단계당 {}초, 최대 {:.0}/s)",
+             config.start_rate, config.rate_multiplier,
+             config.step_duration.as_secs(), config.max_rate);
+
+    let mut steps = Vec::new();
+    let mut max_sustainable_rate = 0.0;
+    let mut rate = config.start_rate;
+
+    while rate <= config.max_rate {
+        let step = run_step(&client, api_base_url, &templates, rate, config).await;
+        info!("부하 단계 완료: 목표 {:.1}/s, 실제 {:.1}/s, p99 {}us, 오류율 {:.2}%",
+              step.target_rate, step.achieved_rate, step.p99_us, step.error_rate * 100.0);
+
+        let breached = step.breach.is_some();
+        if !breached {
+            max_sustainable_rate = step.achieved_rate;
+        } else {
+            warn!("임계값 초과: {:.1}/s - {}", rate, step.breach.as_deref().unwrap_or_default());
+        }
+        steps.push(step);
+        if breached {
+            break;
+        }
+
+        rate *= config.rate_multiplier;
+    }
+
+    LoadTestReport {
+        target_p99_ms: config.target_p99.as_millis() as u64,
+        max_error_rate: config.max_error_rate,
+        min_sustainable_rate: config.min_sustainable_rate,
+        max_sustainable_rate,
+        passed: max_sustainable_rate >= config.min_sustainable_rate,
+        steps,
+    }
+}
+
+/// 한 단계 동안 고정 전송률로 주문을 보내고 결과 측정
+async fn run_step(
+    client: &Client,
+    api_base_url: &str,
+    templates: &[OrderTemplate],
+    rate: f64,
+    config: &LoadTestConfig,
+) -> LoadStepResult {
+    let latency = Arc::new(Mutex::new(LatencyHistogram::default()));
+    let errors = Arc::new(AtomicU64::new(0));
+    let in_flight = Arc::new(Semaphore::new(config.max_in_flight));
+
+    let mut interval = time::interval(Duration::from_secs_f64(1.0 / rate));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let started_at = Instant::now();
+    let mut handles = Vec::new();
+    let mut sent = 0_u64;
+
+    while started_at.elapsed() < config.step_duration {
+        interval.tick().await;
+
+        // 동시 요청 상한 도달 시 응답을 기다림 (실제 전송률 하락)
+        let permit = match in_flight.clone().acquire_owned().await {
+            Ok(permit) => permit,
+            Err(_) => break,
+        };
+
+        let order = create_random_order(&templates[sent as usize % templates.len()]);
+        sent += 1;
+
+        let client = client.clone();
+        let url = api_base_url.to_string();
+        let latency = latency.clone();
+        let errors = errors.clone();
+        handles.push(tokio::spawn(async move {
+            let submitted_at = Instant::now();
+            match submit_order(&client, &url, &order).await {
+                Ok(_) => latency.lock().await.record(submitted_at.elapsed()),
+                Err(_) => {
+                    errors.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            drop(permit);
+        }));
+    }
+    let send_elapsed = started_at.elapsed().as_secs_f64();
+
+    // 진행 중인 요청 완료 대기
+    for handle in handles {
+        let _ = handle.await;
+    }
+
+    let summary = latency.lock().await.summary();
+    let errors = errors.load(Ordering::Relaxed);
+    let achieved_rate = sent as f64 / send_elapsed;
+    let error_rate = if sent > 0 { errors as f64 / sent as f64 } else { 0.0 };
+    let target_p99_us = config.target_p99.as_micros() as u64;
+
+    let breach = if error_rate > config.max_error_rate {
+        Some(format!("오류율 {:.2}% > {:.2}%", error_rate * 100.0, config.max_error_rate * 100.0))
+    } else if summary.p99_us > target_p99_us {
+        Some(format!("p99 {}us > {}us", summary.p99_us, target_p99_us))
+    } else if achieved_rate < rate * MIN_ACHIEVED_RATIO {
+        Some(format!("전송률 포화 {:.1}/s < 목표 {:.1}/s", achieved_rate, rate))
+    } else {
+        None
+    };
+
+    LoadStepResult {
+        target_rate: rate,
+        achieved_rate,
+        sent,
+        errors,
+        error_rate,
+        p50_us: summary.p50_us,
+        p99_us: summary.p99_us,
+        breach,
+    }
+}
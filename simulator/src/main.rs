@@ -1,10 +1,90 @@
-use std::time::Duration;
+mod load_test;
+mod stats;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::time;
 use reqwest::Client;
 use serde_json::json;
 use uuid::Uuid;
 use rand::{Rng, thread_rng};
 use log::{info, warn, error};
+use futures_util::StreamExt;
+
+use load_test::LoadTestConfig;
+use stats::SimulationStats;
+
+/// 시뮬레이터 실행 설정 (환경 변수)
+#[derive(Debug, Clone)]
+struct SimulatorConfig {
+    /// REST API 주소 (XTRADER_API_URL)
+    api_base_url: String,
+    /// WebSocket 체결 피드 주소 (XTRADER_WS_URL)
+    ws_url: String,
+    /// 실행 시간 (SIM_DURATION_SECS, 없으면 Ctrl+C까지)
+    duration: Option<Duration>,
+    /// 리포트 JSON 저장 경로 (SIM_REPORT_PATH)
+    report_path: Option<String>,
+    /// 취소/정정 트래픽 설정
+    cancel_profile: CancelProfile,
+    /// 부하 테스트 설정 (SIM_MODE=loadtest일 때만)
+    load_test: Option<LoadTestConfig>,
+}
+
+/// 취소/정정 트래픽 설정
+#[derive(Debug, Clone, Copy)]
+struct CancelProfile {
+    /// 접수된 지정가 주문 중 취소할 비율 (SIM_CANCEL_RATIO, 0.0~1.0)
+    cancel_ratio: f64,
+    /// 취소 후 새 가격으로 재주문할 비율 (SIM_REPLACE_RATIO, 0.0~1.0)
+    replace_ratio: f64,
+    /// 취소까지의 지연 범위 (SIM_CANCEL_DELAY_MS, "최소-최대")
+    delay_ms: (u64, u64),
+}
+
+impl CancelProfile {
+    fn from_env() -> Self {
+        let ratio = |key: &str, default: f64| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .unwrap_or(default)
+                .clamp(0.0, 1.0)
+        };
+        let delay_ms = std::env::var("SIM_CANCEL_DELAY_MS")
+            .ok()
+            .and_then(|v| {
+                let (min, max) = v.split_once('-')?;
+                Some((min.trim().parse::<u64>().ok()?, max.trim().parse::<u64>().ok()?))
+            })
+            .filter(|(min, max)| min <= max)
+            .unwrap_or((200, 5000));
+
+        Self {
+            cancel_ratio: ratio("SIM_CANCEL_RATIO", 0.2),
+            replace_ratio: ratio("SIM_REPLACE_RATIO", 0.5),
+            delay_ms,
+        }
+    }
+}
+
+impl SimulatorConfig {
+    fn from_env() -> Self {
+        Self {
+            api_base_url: std::env::var("XTRADER_API_URL").unwrap_or_else(|_| "http://localhost:7000".to_string()),
+            ws_url: std::env::var("XTRADER_WS_URL").unwrap_or_else(|_| "ws://localhost:7000/ws".to_string()),
+            duration: std::env::var("SIM_DURATION_SECS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .map(Duration::from_secs),
+            report_path: std::env::var("SIM_REPORT_PATH").ok(),
+            cancel_profile: CancelProfile::from_env(),
+            load_test: std::env::var("SIM_MODE")
+                .is_ok_and(|mode| mode.eq_ignore_ascii_case("loadtest"))
+                .then(LoadTestConfig::from_env),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 struct OrderTemplate {
@@ -36,9 +116,33 @@ async fn main() -> Result<(), String> {
     
     println!("xTrader 시뮬레이션 프로그램 시작");
     
+    // 실행 설정 및 통계 수집기
+    let config = SimulatorConfig::from_env();
+    let stats = Arc::new(SimulationStats::new());
+
     // API 클라이언트 생성
     let client = Client::new();
-    let api_base_url = "http://localhost:7000";
+
+    // 부하 테스트 모드: 최대 지속 처리량 측정 후 종료 (최소 요구치 미달 시 실패)
+    if let Some(load_config) = &config.load_test {
+        let report = load_test::run(client, &config.api_base_url, load_config).await;
+        report.print();
+        if let Some(path) = &config.report_path {
+            let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+            std::fs::write(path, json).map_err(|e| e.to_string())?;
+            println!("리포트 저장: {}", path);
+        }
+        if !report.passed {
+            return Err(format!(
+                "최대 지속 처리량 {:.1}/s가 최소 요구치 {:.1}/s에 미달",
+                report.max_sustainable_rate, load_config.min_sustainable_rate
+            ));
+        }
+        return Ok(());
+    }
+
+    // WebSocket 체결 피드 수신 (첫 체결 지연시간 측정)
+    let ws_handle = tokio::spawn(listen_executions(config.ws_url.clone(), stats.clone()));
     
     // 실전적인 주문 템플릿 생성 (현실적인 가격대와 패턴)
     let order_templates = vec![
@@ -73,21 +177,102 @@ async fn main() -> Result<(), String> {
     
     for template in order_templates {
         let client_clone = client.clone();
+        let api_base_url = config.api_base_url.clone();
+        let stats_clone = stats.clone();
+        let cancel_profile = config.cancel_profile;
         let handle = tokio::spawn(async move {
-            simulate_orders(client_clone, api_base_url, template).await;
+            simulate_orders(client_clone, &api_base_url, template, stats_clone, cancel_profile).await;
         });
         handles.push(handle);
     }
     
-    // 모든 스케줄러가 실행되도록 대기
-    for handle in handles {
-        handle.await.map_err(|e| e.to_string())?;
+    // 실행 시간 경과 또는 Ctrl+C까지 실행
+    match config.duration {
+        Some(duration) => {
+            tokio::select! {
+                _ = time::sleep(duration) => {}
+                _ = tokio::signal::ctrl_c() => {}
+            }
+        }
+        None => {
+            let _ = tokio::signal::ctrl_c().await;
+        }
+    }
+
+    // 스케줄러 종료
+    for handle in &handles {
+        handle.abort();
+    }
+    ws_handle.abort();
+
+    // 리포트 출력 및 저장
+    let report = stats.report().await;
+    report.print();
+    if let Some(path) = &config.report_path {
+        let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())?;
+        println!("리포트 저장: {}", path);
     }
     
     Ok(())
 }
 
-async fn simulate_orders(client: Client, api_base_url: &str, template: OrderTemplate) {
+/// WebSocket 체결 피드를 수신하여 체결 통계 기록
+async fn listen_executions(ws_url: String, stats: Arc<SimulationStats>) {
+    loop {
+        match tokio_tungstenite::connect_async(ws_url.as_str()).await {
+            Ok((ws_stream, _)) => {
+                info!("WebSocket 체결 피드 연결: {}", ws_url);
+                let (_write, mut read) = ws_stream.split();
+                
+                while let Some(message) = read.next().await {
+                    let text = match message {
+                        Ok(tokio_tungstenite::tungstenite::Message::Text(text)) => text,
+                        Ok(_) => continue,
+                        Err(e) => {
+                            warn!("WebSocket 수신 오류: {}", e);
+                            break;
+                        }
+                    };
+                    
+                    let value: serde_json::Value = match serde_json::from_str(&text) {
+                        Ok(value) => value,
+                        Err(_) => continue,
+                    };
+                    if value["type"] != "Execution" {
+                        continue;
+                    }
+                    
+                    let report = &value["execution_report"];
+                    // 취소/만료/거부 보고서는 체결이 아님 (수량은 해제된 잔량)
+                    if report["exec_type"].as_str().unwrap_or("Trade") != "Trade" {
+                        continue;
+                    }
+                    let quantity = report["quantity"].as_u64().unwrap_or(0);
+                    if quantity == 0 {
+                        continue;
+                    }
+                    if let Some(order_id) = report["order_id"].as_str() {
+                        stats.record_fill(order_id, quantity).await;
+                    }
+                }
+            }
+            Err(e) => {
+                warn!("WebSocket 연결 실패: {} - {} (첫 체결 지연 측정 불가, 재시도)", ws_url, e);
+            }
+        }
+        
+        time::sleep(Duration::from_secs(5)).await;
+    }
+}
+
+async fn simulate_orders(
+    client: Client,
+    api_base_url: &str,
+    template: OrderTemplate,
+    stats: Arc<SimulationStats>,
+    cancel_profile: CancelProfile,
+) {
     let mut interval = time::interval(Duration::from_millis(template.frequency_ms));
     
     println!("시뮬레이션 시작: {} {} {} ({}ms 간격)", 
@@ -99,12 +284,37 @@ async fn simulate_orders(client: Client, api_base_url: &str, template: OrderTemp
         // 주문 생성
         let order = create_random_order(&template);
         
-        // API 호출
+        // API 호출 (제출 시각 기록)
+        let submitted_at = Instant::now();
         let order_result = submit_order(&client, api_base_url, &order).await;
         let order_symbol = order["symbol"].as_str().unwrap_or("UNKNOWN").to_string();
+        let quantity = order["quantity"].as_u64().unwrap_or(0);
 
         match order_result {
-            Ok(_response) => {
+            Ok(response) => {
+                // 응답의 주문 ID로 접수 지연 기록
+                let order_id = serde_json::from_str::<serde_json::Value>(&response)
+                    .ok()
+                    .and_then(|v| v["order_id"].as_str().map(|id| id.to_string()));
+                match order_id {
+                    Some(order_id) => {
+                        stats.record_ack(&order_id, submitted_at, quantity).await;
+
+                        // 지정가 주문 일부는 지연 후 취소/정정
+                        if template.order_type == "Limit" && thread_rng().gen_bool(cancel_profile.cancel_ratio) {
+                            tokio::spawn(cancel_or_replace(
+                                client.clone(),
+                                api_base_url.to_string(),
+                                template.clone(),
+                                order_id,
+                                stats.clone(),
+                                cancel_profile,
+                            ));
+                        }
+                    }
+                    None => warn!("주문 응답에 order_id 없음: {}", response),
+                }
+
                 info!("✅ 주문 성공: {} {} {} - 수량: {}, 가격: ₩{}",
                      order["symbol"], order["side"], order["order_type"],
                      order["quantity"], order["price"]);
@@ -112,6 +322,7 @@ async fn simulate_orders(client: Client, api_base_url: &str, template: OrderTemp
             Err(e) => {
                 let error_str = e.to_string();
                 warn!("❌ 주문 실패: {} - {}", order_symbol, error_str);
+                stats.record_reject(quantity).await;
                 // 서버가 다운된 경우 잠시 대기
                 time::sleep(Duration::from_secs(5)).await;
             }
@@ -122,15 +333,80 @@ async fn simulate_orders(client: Client, api_base_url: &str, template: OrderTemp
     }
 }
 
+/// 랜덤 지연 후 미체결 주문 취소, 일정 비율은 새 가격으로 재주문 (정정)
+async fn cancel_or_replace(
+    client: Client,
+    api_base_url: String,
+    template: OrderTemplate,
+    order_id: String,
+    stats: Arc<SimulationStats>,
+    cancel_profile: CancelProfile,
+) {
+    let (delay_ms, replace) = {
+        let mut rng = thread_rng();
+        (
+            rng.gen_range(cancel_profile.delay_ms.0..=cancel_profile.delay_ms.1),
+            rng.gen_bool(cancel_profile.replace_ratio),
+        )
+    };
+    time::sleep(Duration::from_millis(delay_ms)).await;
+
+    // 이미 체결된 주문은 취소하지 않음
+    if !stats.is_open(&order_id).await {
+        return;
+    }
+
+    match cancel_order(&client, &api_base_url, &order_id).await {
+        Ok(accepted) => {
+            stats.record_cancel(&order_id, accepted).await;
+            if !accepted {
+                info!("취소 대상 없음 (이미 체결): {}", order_id);
+                return;
+            }
+            info!("🗑️ 주문 취소: {} ({}ms 후)", order_id, delay_ms);
+        }
+        Err(e) => {
+            warn!("❌ 취소 실패: {} - {}", order_id, e);
+            return;
+        }
+    }
+
+    if !replace {
+        return;
+    }
+
+    // 정정: 같은 템플릿으로 새 가격의 주문 제출
+    let order = create_random_order(&template);
+    let quantity = order["quantity"].as_u64().unwrap_or(0);
+    let submitted_at = Instant::now();
+    match submit_order(&client, &api_base_url, &order).await {
+        Ok(response) => {
+            stats.record_replace().await;
+            if let Some(new_order_id) = serde_json::from_str::<serde_json::Value>(&response)
+                .ok()
+                .and_then(|v| v["order_id"].as_str().map(|id| id.to_string()))
+            {
+                stats.record_ack(&new_order_id, submitted_at, quantity).await;
+                info!("✏️ 주문 정정: {} -> {} (가격: ₩{})", order_id, new_order_id, order["price"]);
+            }
+        }
+        Err(e) => {
+            warn!("❌ 정정 주문 실패: {} - {}", order_id, e);
+            stats.record_reject(quantity).await;
+        }
+    }
+}
+
 fn create_random_order(template: &OrderTemplate) -> serde_json::Value {
     let order_id = Uuid::new_v4().to_string();
     let mut rng = thread_rng();
     let client_id = format!("simulator_{}", rng.gen_range(1..=10));
     
+    // 시장가는 가격 없음
     let price = if template.order_type == "Market" {
-        0 // 시장가는 가격 0
+        None
     } else {
-        rng.gen_range(template.price_range.0..=template.price_range.1)
+        Some(rng.gen_range(template.price_range.0..=template.price_range.1))
     };
     
     let quantity = rng.gen_range(template.quantity_range.0..=template.quantity_range.1);
@@ -163,4 +439,26 @@ async fn submit_order(client: &Client, api_base_url: &str, order: &serde_json::V
         let error_text = response.text().await.map_err(|e| e.to_string())?;
         Err(format!("HTTP {}: {}", status, error_text))
     }
+}
+
+/// 주문 취소 요청 (주문이 없으면 Ok(false))
+async fn cancel_order(client: &Client, api_base_url: &str, order_id: &str) -> Result<bool, String> {
+    let url = format!("{}/v1/order/cancel", api_base_url);
+
+    let response = client
+        .post(&url)
+        .json(&json!({ "order_id": order_id }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if status.is_success() {
+        Ok(true)
+    } else if status == reqwest::StatusCode::NOT_FOUND {
+        Ok(false)
+    } else {
+        let error_text = response.text().await.map_err(|e| e.to_string())?;
+        Err(format!("HTTP {}: {}", status, error_text))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,309 @@
+//! 시뮬레이션 지연시간 및 체결 통계
+//!
+//! 주문별로 제출→접수(ACK), 제출→첫 체결 지연시간을 기록하고
+//! 실행 종료 시 히스토그램(p50/p99)과 체결률 리포트를 출력/저장합니다.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+/// ACK보다 먼저 도착한 체결을 보관하는 시간 (지나면 다른 참여자 주문이나 실패한 제출의 체결로 보고 버림)
+const EARLY_FILL_TTL: Duration = Duration::from_secs(30);
+
+/// 히스토그램 버킷 상한 (마이크로초)
+const BUCKET_BOUNDS_US: [u64; 10] = [100, 250, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 100_000, 1_000_000];
+
+/// 지연시간 히스토그램
+#[derive(Debug, Default)]
+pub struct LatencyHistogram {
+    /// 원본 샘플 (마이크로초)
+    samples: Vec<u64>,
+}
+
+impl LatencyHistogram {
+    /// 샘플 기록
+    pub fn record(&mut self, latency: Duration) {
+        self.samples.push(latency.as_micros() as u64);
+    }
+
+    /// 요약 통계 계산
+    pub fn summary(&self) -> LatencySummary {
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+
+        let percentile = |p: f64| -> u64 {
+            if sorted.is_empty() {
+                return 0;
+            }
+            let rank = ((p / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+            sorted[rank.min(sorted.len() - 1)]
+        };
+
+        let mut buckets: Vec<(String, u64)> = BUCKET_BOUNDS_US
+            .iter()
+            .map(|bound| (format!("<={}us", bound), 0))
+            .collect();
+        buckets.push((format!(">{}us", BUCKET_BOUNDS_US[BUCKET_BOUNDS_US.len() - 1]), 0));
+        for sample in &sorted {
+            let idx = BUCKET_BOUNDS_US
+                .iter()
+                .position(|bound| sample <= bound)
+                .unwrap_or(BUCKET_BOUNDS_US.len());
+            buckets[idx].1 += 1;
+        }
+
+        LatencySummary {
+            count: sorted.len(),
+            min_us: sorted.first().copied().unwrap_or(0),
+            max_us: sorted.last().copied().unwrap_or(0),
+            mean_us: if sorted.is_empty() {
+                0.0
+            } else {
+                sorted.iter().sum::<u64>() as f64 / sorted.len() as f64
+            },
+            p50_us: percentile(50.0),
+            p90_us: percentile(90.0),
+            p99_us: percentile(99.0),
+            buckets,
+        }
+    }
+}
+
+/// 지연시간 요약
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencySummary {
+    pub count: usize,
+    pub min_us: u64,
+    pub max_us: u64,
+    pub mean_us: f64,
+    pub p50_us: u64,
+    pub p90_us: u64,
+    pub p99_us: u64,
+    /// (버킷 라벨, 샘플 수)
+    pub buckets: Vec<(String, u64)>,
+}
+
+/// 주문 추적 정보
+#[derive(Debug)]
+struct OrderTrack {
+    submitted_at: Instant,
+    quantity: u64,
+    filled_quantity: u64,
+    first_fill_recorded: bool,
+    canceled: bool,
+}
+
+/// 체결 통계 리포트
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulationReport {
+    pub duration_secs: f64,
+    pub submitted: u64,
+    pub accepted: u64,
+    pub rejected: u64,
+    pub orders_with_fill: u64,
+    pub fully_filled: u64,
+    pub fill_rate: f64,
+    pub filled_quantity: u64,
+    pub submitted_quantity: u64,
+    pub cancels_sent: u64,
+    pub cancels_accepted: u64,
+    pub cancels_missed: u64,
+    pub replaces: u64,
+    pub ack_latency: LatencySummary,
+    pub first_fill_latency: LatencySummary,
+}
+
+#[derive(Debug, Default)]
+struct StatsInner {
+    submitted: u64,
+    rejected: u64,
+    submitted_quantity: u64,
+    orders: HashMap<String, OrderTrack>,
+    /// ACK 응답보다 먼저 도착한 체결 (주문 ID → (수신 시각, 체결 수량))
+    early_fills: HashMap<String, (Instant, u64)>,
+    /// 마지막으로 오래된 선도착 체결을 정리한 시각
+    early_fills_pruned_at: Option<Instant>,
+    cancels_sent: u64,
+    cancels_accepted: u64,
+    cancels_missed: u64,
+    replaces: u64,
+    ack_latency: LatencyHistogram,
+    first_fill_latency: LatencyHistogram,
+}
+
+impl StatsInner {
+    /// 보관 시간이 지난 선도착 체결 제거 (정리 주기는 보관 시간과 같음)
+    fn prune_early_fills(&mut self, now: Instant) {
+        if self.early_fills_pruned_at.is_some_and(|at| now.saturating_duration_since(at) < EARLY_FILL_TTL) {
+            return;
+        }
+        self.early_fills_pruned_at = Some(now);
+        self.early_fills.retain(|_, (filled_at, _)| now.saturating_duration_since(*filled_at) < EARLY_FILL_TTL);
+    }
+}
+
+/// 시뮬레이션 통계 수집기
+pub struct SimulationStats {
+    started_at: Instant,
+    inner: Mutex<StatsInner>,
+}
+
+impl SimulationStats {
+    /// 새 통계 수집기 생성
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            inner: Mutex::new(StatsInner::default()),
+        }
+    }
+
+    /// 주문 접수(ACK) 기록
+    pub async fn record_ack(&self, order_id: &str, submitted_at: Instant, quantity: u64) {
+        let acked_at = Instant::now();
+        let mut inner = self.inner.lock().await;
+        inner.submitted += 1;
+        inner.submitted_quantity += quantity;
+        inner.ack_latency.record(acked_at - submitted_at);
+
+        let mut track = OrderTrack {
+            submitted_at,
+            quantity,
+            filled_quantity: 0,
+            first_fill_recorded: false,
+            canceled: false,
+        };
+
+        // ACK보다 먼저 도착한 체결 반영
+        if let Some((filled_at, filled_quantity)) = inner.early_fills.remove(order_id) {
+            track.filled_quantity = filled_quantity;
+            track.first_fill_recorded = true;
+            inner.first_fill_latency.record(filled_at.saturating_duration_since(submitted_at));
+        }
+
+        inner.orders.insert(order_id.to_string(), track);
+    }
+
+    /// 주문 거부 기록
+    ///
+    /// 실패한 제출은 주문 ID를 모르므로 보관 시간이 지난 선도착 체결을 함께 정리합니다.
+    pub async fn record_reject(&self, quantity: u64) {
+        let mut inner = self.inner.lock().await;
+        inner.submitted += 1;
+        inner.rejected += 1;
+        inner.submitted_quantity += quantity;
+        inner.prune_early_fills(Instant::now());
+    }
+
+    /// WebSocket 체결 기록
+    ///
+    /// 추적 중이 아닌 주문의 체결은 ACK를 기다리며 `EARLY_FILL_TTL` 동안만 보관합니다.
+    pub async fn record_fill(&self, order_id: &str, quantity: u64) {
+        let filled_at = Instant::now();
+        let mut inner = self.inner.lock().await;
+        if !inner.orders.contains_key(order_id) {
+            inner.prune_early_fills(filled_at);
+        }
+        let StatsInner { orders, early_fills, first_fill_latency, .. } = &mut *inner;
+
+        match orders.get_mut(order_id) {
+            Some(track) => {
+                if !track.first_fill_recorded {
+                    track.first_fill_recorded = true;
+                    first_fill_latency.record(filled_at - track.submitted_at);
+                }
+                track.filled_quantity += quantity;
+            }
+            None => {
+                let entry = early_fills.entry(order_id.to_string()).or_insert((filled_at, 0));
+                entry.1 += quantity;
+            }
+        }
+    }
+
+    /// 아직 미체결 잔량이 있고 취소되지 않은 주문인지 확인
+    pub async fn is_open(&self, order_id: &str) -> bool {
+        let inner = self.inner.lock().await;
+        inner.orders
+            .get(order_id)
+            .is_some_and(|t| !t.canceled && t.filled_quantity < t.quantity)
+    }
+
+    /// 취소 요청 결과 기록
+    ///
+    /// `accepted`가 false면 서버가 주문을 찾지 못한 경우(이미 체결 등)입니다.
+    pub async fn record_cancel(&self, order_id: &str, accepted: bool) {
+        let mut inner = self.inner.lock().await;
+        inner.cancels_sent += 1;
+        if accepted {
+            inner.cancels_accepted += 1;
+            if let Some(track) = inner.orders.get_mut(order_id) {
+                track.canceled = true;
+            }
+        } else {
+            inner.cancels_missed += 1;
+        }
+    }
+
+    /// 정정(취소 후 재주문) 기록
+    pub async fn record_replace(&self) {
+        self.inner.lock().await.replaces += 1;
+    }
+
+    /// 리포트 생성
+    pub async fn report(&self) -> SimulationReport {
+        let inner = self.inner.lock().await;
+        let accepted = inner.orders.len() as u64;
+        let orders_with_fill = inner.orders.values().filter(|t| t.filled_quantity > 0).count() as u64;
+        let fully_filled = inner.orders.values().filter(|t| t.filled_quantity >= t.quantity).count() as u64;
+        let filled_quantity = inner.orders.values().map(|t| t.filled_quantity.min(t.quantity)).sum();
+
+        SimulationReport {
+            duration_secs: self.started_at.elapsed().as_secs_f64(),
+            submitted: inner.submitted,
+            accepted,
+            rejected: inner.rejected,
+            orders_with_fill,
+            fully_filled,
+            fill_rate: if accepted > 0 { orders_with_fill as f64 / accepted as f64 } else { 0.0 },
+            filled_quantity,
+            submitted_quantity: inner.submitted_quantity,
+            cancels_sent: inner.cancels_sent,
+            cancels_accepted: inner.cancels_accepted,
+            cancels_missed: inner.cancels_missed,
+            replaces: inner.replaces,
+            ack_latency: inner.ack_latency.summary(),
+            first_fill_latency: inner.first_fill_latency.summary(),
+        }
+    }
+}
+
+impl SimulationReport {
+    /// 리포트 콘솔 출력
+    pub fn print(&self) {
+        println!();
+        println!("📊 시뮬레이션 리포트 ({:.1}초)", self.duration_secs);
+        println!("  주문: 제출 {}건, 접수 {}건, 거부 {}건", self.submitted, self.accepted, self.rejected);
+        println!("  체결: 체결 주문 {}건, 완전 체결 {}건, 체결률 {:.1}%",
+                 self.orders_with_fill, self.fully_filled, self.fill_rate * 100.0);
+        println!("  수량: 제출 {}, 체결 {}", self.submitted_quantity, self.filled_quantity);
+        println!("  취소: 요청 {}건, 접수 {}건, 대상 없음 {}건, 정정 {}건",
+                 self.cancels_sent, self.cancels_accepted, self.cancels_missed, self.replaces);
+        Self::print_latency("제출→접수", &self.ack_latency);
+        Self::print_latency("제출→첫 체결", &self.first_fill_latency);
+    }
+
+    fn print_latency(label: &str, summary: &LatencySummary) {
+        println!("  {} 지연 ({}건): p50 {}us, p90 {}us, p99 {}us, 최소 {}us, 최대 {}us, 평균 {:.0}us",
+                 label, summary.count, summary.p50_us, summary.p90_us, summary.p99_us,
+                 summary.min_us, summary.max_us, summary.mean_us);
+        if summary.count == 0 {
+            return;
+        }
+        let max_bucket = summary.buckets.iter().map(|(_, c)| *c).max().unwrap_or(1).max(1);
+        for (bucket, count) in &summary.buckets {
+            let bar = "#".repeat(((*count as f64 / max_bucket as f64) * 40.0).round() as usize);
+            println!("    {:>12} | {:<40} {}", bucket, bar, count);
+        }
+    }
+}
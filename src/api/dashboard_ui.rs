@@ -0,0 +1,83 @@
+//! 내장 운영 대시보드 UI
+//!
+//! 빌드 시 `dashboard_ui/` 의 정적 파일을 바이너리에 포함해 `/dashboard` 에서 제공합니다.
+//! 화면은 `/ws/dashboard` (위젯 업데이트)와 `/ws` (체결 스트림)에 연결해 실시간으로 갱신되므로
+//! 별도 Grafana 없이 헬스 상태, 큐, 지연, 메트릭, 주문 흐름을 확인할 수 있습니다.
+
+use axum::{
+    extract::Path,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+
+/// 내장 정적 파일 (파일 이름, Content-Type, 내용)
+const ASSETS: &[(&str, &str, &str)] = &[
+    ("index.html", "text/html; charset=utf-8", include_str!("dashboard_ui/index.html")),
+    ("dashboard.js", "text/javascript; charset=utf-8", include_str!("dashboard_ui/dashboard.js")),
+    ("dashboard.css", "text/css; charset=utf-8", include_str!("dashboard_ui/dashboard.css")),
+];
+
+/// 내장 파일 조회
+fn asset(name: &str) -> Option<(&'static str, &'static str)> {
+    ASSETS
+        .iter()
+        .find(|(file, _, _)| *file == name)
+        .map(|(_, content_type, body)| (*content_type, *body))
+}
+
+fn asset_response(name: &str) -> Response {
+    match asset(name) {
+        // 배포 후 바로 반영되도록 캐시하지 않음
+        Some((content_type, body)) => (
+            [(header::CONTENT_TYPE, content_type), (header::CACHE_CONTROL, "no-cache")],
+            body,
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn dashboard_index() -> Response {
+    asset_response("index.html")
+}
+
+async fn dashboard_asset(Path(file): Path<String>) -> Response {
+    asset_response(&file)
+}
+
+/// 대시보드 UI 라우터
+pub fn dashboard_ui_router<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/dashboard", get(dashboard_index))
+        .route("/dashboard/", get(dashboard_index))
+        .route("/dashboard/:file", get(dashboard_asset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_references_embedded_assets() {
+        let (content_type, index) = asset("index.html").unwrap();
+        assert!(content_type.starts_with("text/html"));
+
+        for (file, _, _) in ASSETS.iter().filter(|(file, _, _)| *file != "index.html") {
+            assert!(index.contains(&format!("/dashboard/{}", file)), "index.html에서 {} 참조 누락", file);
+        }
+        assert!(asset("../Cargo.toml").is_none());
+    }
+
+    #[test]
+    fn test_script_uses_dashboard_channels() {
+        let (_, script) = asset("dashboard.js").unwrap();
+        assert!(script.contains("/ws/dashboard"));
+        assert!(script.contains("'/ws'"));
+        assert!(script.contains("/v1/metrics/history"));
+    }
+}
@@ -0,0 +1,323 @@
+// xTrader 운영 대시보드
+//
+// /ws/dashboard 에서 위젯 스냅샷과 증분 업데이트를 받고,
+// /ws 체결 스트림으로 주문 흐름을 표시합니다.
+// 주간 성능 추이는 /v1/metrics/history 시간별 롤업으로 지난주 같은 요일과 비교합니다.
+(function () {
+  'use strict';
+
+  const TOKEN_KEY = 'xtrader.adminToken';
+  const MAX_EXECUTIONS = 50;
+  const MAX_RECONNECT_DELAY_MS = 10000;
+  const HISTORY_DAYS = 7;
+  const HISTORY_REFRESH_MS = 10 * 60 * 1000;
+  const DAY_MS = 24 * 60 * 60 * 1000;
+
+  const state = {
+    queues: {},
+    throughput: {},
+    metrics: {},
+  };
+
+  const wsBase = (location.protocol === 'https:' ? 'wss://' : 'ws://') + location.host;
+
+  function el(tag, text, className) {
+    const node = document.createElement(tag);
+    if (text !== undefined) node.textContent = text;
+    if (className) node.className = className;
+    return node;
+  }
+
+  function formatNumber(value, digits) {
+    if (typeof value !== 'number' || !isFinite(value)) return '-';
+    return value.toLocaleString('ko-KR', { maximumFractionDigits: digits === undefined ? 2 : digits });
+  }
+
+  function replaceRows(tbody, rows) {
+    tbody.replaceChildren.apply(tbody, rows);
+  }
+
+  // 위젯 렌더링
+
+  function renderHealth(health) {
+    const badge = document.getElementById('overall-status');
+    const status = (health && health.overall_status) || 'Unknown';
+    badge.textContent = status;
+    badge.className = 'badge ' + status;
+
+    const services = (health && health.services) || {};
+    const rows = Object.keys(services).sort().map(function (name) {
+      const svc = services[name];
+      const tr = el('tr');
+      tr.appendChild(el('td', name));
+      const statusCell = el('td');
+      statusCell.appendChild(el('span', svc.status, 'badge ' + svc.status));
+      tr.appendChild(statusCell);
+      tr.appendChild(el('td', formatNumber(svc.response_time_ms, 0), 'num'));
+      tr.appendChild(el('td', svc.message || ''));
+      return tr;
+    });
+    replaceRows(document.querySelector('#services tbody'), rows);
+  }
+
+  function renderQueues() {
+    const rows = Object.keys(state.queues).sort().map(function (name) {
+      const q = state.queues[name];
+      const tr = el('tr');
+      tr.appendChild(el('td', name));
+      tr.appendChild(el('td', q.depth + ' / ' + q.capacity, 'num'));
+
+      const utilization = Math.min(1, q.utilization || 0);
+      const bar = el('div', undefined, utilization >= 0.8 ? 'bar high' : 'bar');
+      const fill = el('div');
+      fill.style.width = (utilization * 100).toFixed(1) + '%';
+      bar.appendChild(fill);
+      const barCell = el('td');
+      barCell.appendChild(bar);
+      tr.appendChild(barCell);
+
+      tr.appendChild(el('td', formatNumber(state.throughput[name], 1), 'num'));
+      return tr;
+    });
+    replaceRows(document.querySelector('#queues tbody'), rows);
+  }
+
+  function renderLatency(latency) {
+    ['p50', 'p95', 'p99'].forEach(function (key) {
+      document.getElementById('latency-' + key).textContent = formatNumber(latency && latency[key]);
+    });
+  }
+
+  function renderMetrics() {
+    const filter = document.getElementById('metric-filter').value.trim().toLowerCase();
+    const rows = Object.keys(state.metrics)
+      .filter(function (name) { return !filter || name.toLowerCase().indexOf(filter) !== -1; })
+      .sort()
+      .map(function (name) {
+        const tr = el('tr');
+        tr.appendChild(el('td', name));
+        tr.appendChild(el('td', formatNumber(state.metrics[name], 0), 'num'));
+        return tr;
+      });
+    replaceRows(document.querySelector('#metrics tbody'), rows);
+  }
+
+  function renderIncidents(incidents) {
+    const items = (incidents || []).slice(0, 20).map(function (incident) {
+      const li = el('li');
+      li.appendChild(el('strong', '[' + incident.priority + '] ' + incident.title));
+      const resolvedAt = incident.resolved_at ? new Date(incident.resolved_at).toLocaleString('ko-KR') : '';
+      li.appendChild(el('div', (incident.resolved_by || '') + ' ' + resolvedAt));
+      return li;
+    });
+    document.getElementById('incidents').replaceChildren.apply(document.getElementById('incidents'), items);
+  }
+
+  function formatChange(current, previous) {
+    if (typeof current !== 'number' || typeof previous !== 'number' || previous === 0) return '-';
+    const pct = (current - previous) / previous * 100;
+    return (pct >= 0 ? '+' : '') + pct.toFixed(1) + '%';
+  }
+
+  // 하루 요약: p99는 가장 나쁜 시간, 주문/초는 수집된 시간 평균, 오류율은 전체 요청 대비
+  function summarizeDay(hours) {
+    let requests = 0;
+    let errors = 0;
+    let orders = 0;
+    let p99 = null;
+    hours.forEach(function (hour) {
+      requests += hour.requests;
+      errors += hour.errors;
+      orders += hour.orders;
+      if (typeof hour.p99_latency_ms === 'number') {
+        p99 = p99 === null ? hour.p99_latency_ms : Math.max(p99, hour.p99_latency_ms);
+      }
+    });
+    return {
+      p99: p99,
+      ordersPerSec: hours.length ? orders / (hours.length * 3600) : null,
+      errorRate: requests ? errors / requests * 100 : null,
+    };
+  }
+
+  function renderHistory(hours) {
+    const now = Date.now();
+    const today = now - (now % DAY_MS);
+    const byDay = {};
+    (hours || []).forEach(function (hour) {
+      const day = hour.hour_start - (hour.hour_start % DAY_MS);
+      (byDay[day] = byDay[day] || []).push(hour);
+    });
+
+    const rows = [];
+    for (let i = 0; i < HISTORY_DAYS; i += 1) {
+      const day = today - i * DAY_MS;
+      const current = summarizeDay(byDay[day] || []);
+      const previous = summarizeDay(byDay[day - HISTORY_DAYS * DAY_MS] || []);
+      const tr = el('tr');
+      tr.appendChild(el('td', new Date(day).toLocaleDateString('ko-KR', { timeZone: 'UTC' })));
+      tr.appendChild(el('td', formatNumber(current.p99, 1), 'num'));
+      tr.appendChild(el('td', formatChange(current.p99, previous.p99), 'num'));
+      tr.appendChild(el('td', formatNumber(current.ordersPerSec, 1), 'num'));
+      tr.appendChild(el('td', formatChange(current.ordersPerSec, previous.ordersPerSec), 'num'));
+      tr.appendChild(el('td', formatNumber(current.errorRate, 3), 'num'));
+      rows.push(tr);
+    }
+    replaceRows(document.querySelector('#history tbody'), rows);
+  }
+
+  function loadHistory(token) {
+    const to = Date.now();
+    const from = to - 2 * HISTORY_DAYS * DAY_MS;
+    fetch('/v1/metrics/history?from=' + from + '&to=' + to, { headers: { 'X-Admin-Token': token } })
+      .then(function (response) {
+        if (!response.ok) throw new Error('HTTP ' + response.status);
+        return response.json();
+      })
+      .then(function (body) { renderHistory(body.hours); })
+      .catch(function (e) { console.warn('성능 추이 조회 실패:', e.message); });
+  }
+
+  function applyWidget(update) {
+    const data = update.data;
+    switch (update.widget_id) {
+      case 'system_health':
+        renderHealth(data);
+        break;
+      case 'queue_depths':
+        state.queues = data || {};
+        renderQueues();
+        break;
+      case 'throughput':
+        state.throughput = data || {};
+        renderQueues();
+        break;
+      case 'latency':
+        renderLatency(data);
+        break;
+      case 'metrics':
+        state.metrics = Object.assign({}, (data && data.counters) || {}, (data && data.gauges) || {});
+        renderMetrics();
+        break;
+      case 'incident_history':
+        renderIncidents(data);
+        break;
+      default:
+        break;
+    }
+  }
+
+  function addExecution(message) {
+    const report = message.execution_report;
+    const tr = el('tr');
+    tr.appendChild(el('td', new Date(report.timestamp).toLocaleTimeString('ko-KR')));
+    tr.appendChild(el('td', report.symbol));
+    tr.appendChild(el('td', report.side, report.side));
+    tr.appendChild(el('td', formatNumber(report.price, 0), 'num'));
+    tr.appendChild(el('td', formatNumber(report.quantity, 0), 'num'));
+    tr.appendChild(el('td', message.order_status));
+
+    const tbody = document.querySelector('#executions tbody');
+    tbody.insertBefore(tr, tbody.firstChild);
+    while (tbody.children.length > MAX_EXECUTIONS) {
+      tbody.removeChild(tbody.lastChild);
+    }
+  }
+
+  // 연결 관리
+
+  function setConnected(connected) {
+    const badge = document.getElementById('connection-status');
+    badge.textContent = connected ? '실시간' : '연결 안 됨';
+    badge.className = 'badge ' + (connected ? 'connected' : 'disconnected');
+  }
+
+  function reconnecting(connect) {
+    let attempt = 0;
+    return {
+      opened: function () { attempt = 0; },
+      schedule: function () {
+        attempt += 1;
+        const delay = Math.min(500 * Math.pow(2, attempt - 1), MAX_RECONNECT_DELAY_MS);
+        setTimeout(connect, delay);
+      },
+    };
+  }
+
+  function connectDashboard(token) {
+    let opened = false;
+    const socket = new WebSocket(wsBase + '/ws/dashboard?token=' + encodeURIComponent(token));
+
+    socket.onopen = function () {
+      opened = true;
+      dashboardRetry.opened();
+      setConnected(true);
+      loadHistory(token);
+    };
+    socket.onmessage = function (event) {
+      const message = JSON.parse(event.data);
+      if (message.type === 'snapshot') {
+        message.widgets.forEach(applyWidget);
+      } else if (message.type === 'update') {
+        applyWidget(message);
+      } else if (message.type === 'error') {
+        console.warn('대시보드 오류:', message.message);
+      }
+    };
+    socket.onclose = function () {
+      setConnected(false);
+      if (!opened) {
+        // 업그레이드 거부 (토큰 오류 또는 관리자 API 비활성화)
+        sessionStorage.removeItem(TOKEN_KEY);
+        showLogin();
+        return;
+      }
+      dashboardRetry.schedule();
+    };
+  }
+
+  function connectExecutions() {
+    const socket = new WebSocket(wsBase + '/ws');
+    socket.onopen = executionRetry.opened;
+    socket.onmessage = function (event) {
+      const message = JSON.parse(event.data);
+      if (message.type === 'Execution') {
+        addExecution(message);
+      }
+    };
+    socket.onclose = executionRetry.schedule;
+  }
+
+  const dashboardRetry = reconnecting(function () {
+    const token = sessionStorage.getItem(TOKEN_KEY);
+    if (token) connectDashboard(token);
+  });
+  const executionRetry = reconnecting(connectExecutions);
+
+  function showLogin() {
+    document.getElementById('login').hidden = false;
+  }
+
+  document.getElementById('login').addEventListener('submit', function (event) {
+    event.preventDefault();
+    const token = document.getElementById('token').value;
+    sessionStorage.setItem(TOKEN_KEY, token);
+    document.getElementById('login').hidden = true;
+    connectDashboard(token);
+  });
+
+  document.getElementById('metric-filter').addEventListener('input', renderMetrics);
+
+  setInterval(function () {
+    const token = sessionStorage.getItem(TOKEN_KEY);
+    if (token) loadHistory(token);
+  }, HISTORY_REFRESH_MS);
+
+  const savedToken = sessionStorage.getItem(TOKEN_KEY);
+  if (savedToken) {
+    connectDashboard(savedToken);
+  } else {
+    showLogin();
+  }
+  connectExecutions();
+})();
@@ -0,0 +1,215 @@
+//! 대시보드 WebSocket
+//!
+//! 연결 시 구독 위젯의 현재 스냅샷을 보내고, 이후에는 데이터가 바뀐 위젯만 증분 전송합니다.
+//! 클라이언트는 `subscribe` 메시지로 받을 위젯을 지정할 수 있습니다 (빈 목록이면 전체).
+//! 브로드캐스트 채널에서 업데이트를 놓치면 스냅샷을 다시 보내 상태를 맞춥니다.
+//! 거래소 전체 운영 지표를 노출하므로 관리자 API와 같은 `X-Admin-Token` 인증과 `ops:manage` 권한을 요구합니다.
+//! 브라우저는 WebSocket 요청에 헤더를 넣을 수 없으므로 `token`, `user` 쿼리 파라미터도 허용합니다.
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Query, State,
+    },
+    http::{HeaderMap, HeaderValue},
+    response::Response,
+};
+use futures::{sink::SinkExt, stream::{SplitSink, StreamExt}};
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Instant;
+use tokio::sync::broadcast;
+
+use crate::api::error::ApiError;
+use crate::api::handlers::authorize_admin;
+use crate::monitoring::{DashboardServer, DashboardUpdate};
+use crate::rbac::Permission;
+use crate::server::ServerState;
+
+/// 서버 → 클라이언트 메시지
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DashboardPush {
+    /// 구독 위젯 전체 상태
+    Snapshot { widgets: Vec<DashboardUpdate> },
+    /// 위젯 증분 업데이트
+    Update(DashboardUpdate),
+    /// 잘못된 요청
+    Error { message: String },
+}
+
+/// 클라이언트 → 서버 메시지
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DashboardClientMessage {
+    /// 구독 위젯 교체 (빈 목록이면 전체 위젯)
+    Subscribe {
+        #[serde(default)]
+        widgets: Vec<String>,
+    },
+}
+
+/// 대시보드 WebSocket 연결 핸들러
+pub async fn dashboard_websocket_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<ServerState>,
+    Query(params): Query<HashMap<String, String>>,
+    mut headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    // 헤더가 우선, 없으면 쿼리 파라미터 토큰/관리자 이름 사용
+    for (header, param) in [("x-admin-token", "token"), ("x-admin-user", "user")] {
+        if !headers.contains_key(header) {
+            if let Some(value) = params.get(param).and_then(|v| HeaderValue::from_str(v).ok()) {
+                headers.insert(header, value);
+            }
+        }
+    }
+    let actor = authorize_admin(&state, &headers)?;
+    state.rbac.authorize(&actor, Permission::OpsManage, "GET /ws/dashboard").await?;
+    Ok(ws.on_upgrade(|socket| dashboard_connection(socket, state)))
+}
+
+/// 메시지 전송 (연결이 끊겼으면 false)
+async fn send_push(sender: &mut SplitSink<WebSocket, Message>, push: &DashboardPush) -> bool {
+    match serde_json::to_string(push) {
+        Ok(json) => sender.send(Message::Text(json)).await.is_ok(),
+        Err(e) => {
+            warn!("대시보드 메시지 직렬화 실패: {}", e);
+            true
+        }
+    }
+}
+
+/// 구독 위젯 스냅샷 전송
+async fn send_snapshot(
+    sender: &mut SplitSink<WebSocket, Message>,
+    dashboard: &DashboardServer,
+    client_id: &str,
+) -> bool {
+    let widgets = dashboard.snapshot_for_client(client_id).await;
+    send_push(sender, &DashboardPush::Snapshot { widgets }).await
+}
+
+/// 대시보드 WebSocket 연결 처리
+async fn dashboard_connection(socket: WebSocket, state: ServerState) {
+    let dashboard = state.dashboard.clone();
+    let config = state.ws_config.clone();
+    let client_id = uuid::Uuid::new_v4().to_string();
+
+    // 스냅샷 이후 업데이트를 놓치지 않도록 먼저 구독
+    let mut updates = dashboard.subscribe();
+    if let Err(e) = dashboard.connect_websocket_client(client_id.clone()).await {
+        warn!("대시보드 클라이언트 등록 실패: {}", e);
+        return;
+    }
+    info!("대시보드 WebSocket 연결 수립: {} (현재 {}개)", client_id, dashboard.get_connected_clients_count().await);
+
+    let (mut sender, mut receiver) = socket.split();
+    let mut ping_interval = tokio::time::interval(config.ping_interval);
+    ping_interval.tick().await;
+    let mut last_seen = Instant::now();
+
+    if send_snapshot(&mut sender, &dashboard, &client_id).await {
+        loop {
+            tokio::select! {
+                update = updates.recv() => {
+                    let delivered = match update {
+                        Ok(update) => {
+                            if !dashboard.is_client_subscribed(&client_id, &update.widget_id).await {
+                                continue;
+                            }
+                            send_push(&mut sender, &DashboardPush::Update(update)).await
+                        }
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!("대시보드 업데이트 {}개 누락 - 스냅샷 재전송: {}", skipped, client_id);
+                            send_snapshot(&mut sender, &dashboard, &client_id).await
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    };
+                    if !delivered {
+                        break;
+                    }
+                }
+                msg = receiver.next() => {
+                    let Some(Ok(msg)) = msg else { break };
+                    last_seen = Instant::now();
+                    match msg {
+                        Message::Text(text) => {
+                            let delivered = match serde_json::from_str::<DashboardClientMessage>(&text) {
+                                Ok(DashboardClientMessage::Subscribe { widgets }) => {
+                                    debug!("대시보드 구독 변경: {} -> {:?}", client_id, widgets);
+                                    match dashboard.set_client_subscription(&client_id, widgets).await {
+                                        Ok(()) => send_snapshot(&mut sender, &dashboard, &client_id).await,
+                                        Err(message) => send_push(&mut sender, &DashboardPush::Error { message }).await,
+                                    }
+                                }
+                                Err(e) => {
+                                    let message = format!("알 수 없는 메시지: {}", e);
+                                    send_push(&mut sender, &DashboardPush::Error { message }).await
+                                }
+                            };
+                            if !delivered {
+                                break;
+                            }
+                        }
+                        Message::Close(_) => {
+                            debug!("클라이언트가 대시보드 WebSocket 연결 종료: {}", client_id);
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                _ = ping_interval.tick() => {
+                    if last_seen.elapsed() > config.pong_timeout {
+                        warn!("대시보드 WebSocket pong 타임아웃 - 연결 종료: {}", client_id);
+                        let _ = sender.send(Message::Close(None)).await;
+                        break;
+                    }
+                    if sender.send(Message::Ping(Vec::new())).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    let _ = dashboard.disconnect_websocket_client(&client_id).await;
+    info!("대시보드 WebSocket 연결 종료: {} (현재 {}개)", client_id, dashboard.get_connected_clients_count().await);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitoring::WidgetType;
+
+    #[test]
+    fn test_client_message_parsing() {
+        let msg: DashboardClientMessage =
+            serde_json::from_str(r#"{"type":"subscribe","widgets":["latency","queue_depths"]}"#).unwrap();
+        let DashboardClientMessage::Subscribe { widgets } = msg;
+        assert_eq!(widgets, vec!["latency".to_string(), "queue_depths".to_string()]);
+
+        // widgets 생략 시 전체 구독
+        let msg: DashboardClientMessage = serde_json::from_str(r#"{"type":"subscribe"}"#).unwrap();
+        let DashboardClientMessage::Subscribe { widgets } = msg;
+        assert!(widgets.is_empty());
+
+        assert!(serde_json::from_str::<DashboardClientMessage>(r#"{"type":"unknown"}"#).is_err());
+    }
+
+    #[test]
+    fn test_update_push_format() {
+        let push = DashboardPush::Update(DashboardUpdate {
+            widget_id: "latency".to_string(),
+            widget_type: WidgetType::MetricGauge,
+            data: serde_json::json!({"p99": 12.5}),
+            timestamp: 1,
+        });
+
+        let json = serde_json::to_value(&push).unwrap();
+        assert_eq!(json["type"], "update");
+        assert_eq!(json["widget_id"], "latency");
+        assert_eq!(json["data"]["p99"], 12.5);
+    }
+}
@@ -0,0 +1,554 @@
+//! REST API 오류 모델
+//!
+//! 모든 핸들러는 `ApiError`를 반환하며, 오류 코드별 HTTP 상태와
+//! `{ "error": "<코드>", "message": "<설명>" }` 형식의 JSON 본문으로 변환됩니다.
+
+use axum::{
+    extract::rejection::{JsonRejection, PathRejection, QueryRejection},
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+};
+use std::fmt;
+
+use crate::api::models::ErrorResponse;
+use crate::auth::AuthError;
+use crate::currency::CurrencyError;
+use crate::db::{BackupError, RebateError};
+use crate::fee::FeeError;
+use crate::funding::FundingError;
+use crate::kill_switch::KillSwitchError;
+use crate::kyc::KycError;
+use crate::rbac::RbacError;
+use crate::risk::RiskError;
+use crate::totp::TotpError;
+use crate::matching_engine::order_ack::OrderRejectReason;
+use crate::monitoring::incident_tracker::IncidentError;
+use crate::monitoring::notification_routing::RoutingRuleError;
+use crate::mq::{QuarantineError, RecoveryJobError};
+use crate::sequencer::{AdmissionError, QueueError, ReplicationError, UsageError};
+
+/// 기계 판독용 오류 코드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// 요청 본문/파라미터 형식 오류
+    InvalidRequest,
+    /// 지원하지 않는 심볼로 주문
+    InvalidSymbol,
+    /// client_id 형식 오류
+    InvalidClientId,
+    /// 수량 오류
+    InvalidQuantity,
+    /// 지정가 주문 가격 누락
+    MissingPrice,
+    /// 가격 오류
+    InvalidPrice,
+    /// 시장가 슬리피지 한도 오류
+    InvalidSlippage,
+    /// 시장가 최대 레벨 수 오류
+    InvalidMaxLevels,
+    /// 만료 시간 오류
+    InvalidExpireTime,
+    /// 주문 태그 형식 오류
+    InvalidTag,
+    /// 봉차트 간격 오류
+    InvalidInterval,
+    /// 잔고 부족
+    InsufficientBalance,
+    /// 관리자 인증 실패
+    Unauthorized,
+    /// 민감한 관리자 작업의 2단계 인증(TOTP) 코드 없음/불일치
+    TotpRequired,
+    /// 다른 계정 또는 권한(scope) 밖의 요청
+    Forbidden,
+    /// 정지된 계정
+    AccountSuspended,
+    /// 킬 스위치로 차단된 계정
+    ClientBlocked,
+    /// KYC 인증 단계별 주문 금액 한도 초과
+    KycLimitExceeded,
+    /// 계정 사전 리스크 한도 초과 (미체결 주문 수, 주문 금액, 순포지션)
+    RiskLimitExceeded,
+    /// 조회 대상 심볼 없음
+    SymbolNotFound,
+    /// 주문 없음
+    OrderNotFound,
+    /// 알림 라우팅 규칙 없음
+    NotificationRuleNotFound,
+    /// 인시던트 없음
+    IncidentNotFound,
+    /// API 키 없음 (이미 폐기됨)
+    ApiKeyNotFound,
+    /// 발동 중이 아닌 킬 스위치
+    KillSwitchNotFound,
+    /// 계정별 리스크 한도 설정 없음
+    RiskLimitNotFound,
+    /// 역할 지정 없음 (기본 역할 적용 중)
+    RoleAssignmentNotFound,
+    /// 이미 해결된 인시던트
+    IncidentResolved,
+    /// 요청 한도 초과
+    RateLimited,
+    /// 거래 중단된 시장
+    MarketHalted,
+    /// 처리 큐 포화
+    QueueFull,
+    /// 처리 큐 포화가 재시도 대기 시간(`max_wait_ms`) 안에 풀리지 않음
+    Timeout,
+    /// 대기(standby) 인스턴스라 주문을 받지 않음
+    NotPrimary,
+    /// 이미 주(primary) 인스턴스 (승격 불가)
+    AlreadyPrimary,
+    /// 보고 통화 환율 없음 (환산할 시세 없음)
+    RateUnavailable,
+    /// 복구 작업 없음
+    RecoveryJobNotFound,
+    /// 이미 끝난 복구 작업 (취소 불가)
+    RecoveryJobFinished,
+    /// DLQ 격리 메시지 없음
+    DeadLetterNotFound,
+    /// 이미 재발행/폐기된 격리 메시지
+    DeadLetterResolved,
+    /// 본문이 독성이라 재발행할 수 없는 격리 메시지
+    DeadLetterNotRequeueable,
+    /// 내부 처리 경로 사용 불가
+    ServiceUnavailable,
+    /// 기타 내부 오류
+    Internal,
+}
+
+impl ErrorCode {
+    /// 응답 본문에 쓰이는 코드 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::InvalidRequest => "INVALID_REQUEST",
+            ErrorCode::InvalidSymbol => "INVALID_SYMBOL",
+            ErrorCode::InvalidClientId => "INVALID_CLIENT_ID",
+            ErrorCode::InvalidQuantity => "INVALID_QUANTITY",
+            ErrorCode::MissingPrice => "MISSING_PRICE",
+            ErrorCode::InvalidPrice => "INVALID_PRICE",
+            ErrorCode::InvalidSlippage => "INVALID_SLIPPAGE",
+            ErrorCode::InvalidMaxLevels => "INVALID_MAX_LEVELS",
+            ErrorCode::InvalidExpireTime => "INVALID_EXPIRE_TIME",
+            ErrorCode::InvalidTag => "INVALID_TAG",
+            ErrorCode::InvalidInterval => "INVALID_INTERVAL",
+            ErrorCode::InsufficientBalance => "INSUFFICIENT_BALANCE",
+            ErrorCode::Unauthorized => "UNAUTHORIZED",
+            ErrorCode::TotpRequired => "TOTP_REQUIRED",
+            ErrorCode::Forbidden => "FORBIDDEN",
+            ErrorCode::AccountSuspended => "ACCOUNT_SUSPENDED",
+            ErrorCode::ClientBlocked => "CLIENT_BLOCKED",
+            ErrorCode::KycLimitExceeded => "KYC_LIMIT_EXCEEDED",
+            ErrorCode::RiskLimitExceeded => "RISK_LIMIT_EXCEEDED",
+            ErrorCode::SymbolNotFound => "SYMBOL_NOT_FOUND",
+            ErrorCode::OrderNotFound => "ORDER_NOT_FOUND",
+            ErrorCode::NotificationRuleNotFound => "NOTIFICATION_RULE_NOT_FOUND",
+            ErrorCode::IncidentNotFound => "INCIDENT_NOT_FOUND",
+            ErrorCode::ApiKeyNotFound => "API_KEY_NOT_FOUND",
+            ErrorCode::KillSwitchNotFound => "KILL_SWITCH_NOT_FOUND",
+            ErrorCode::RiskLimitNotFound => "RISK_LIMIT_NOT_FOUND",
+            ErrorCode::RoleAssignmentNotFound => "ROLE_ASSIGNMENT_NOT_FOUND",
+            ErrorCode::IncidentResolved => "INCIDENT_ALREADY_RESOLVED",
+            ErrorCode::RateLimited => "RATE_LIMITED",
+            ErrorCode::MarketHalted => "MARKET_HALTED",
+            ErrorCode::QueueFull => "QUEUE_FULL",
+            ErrorCode::Timeout => "TIMEOUT",
+            ErrorCode::NotPrimary => "NOT_PRIMARY",
+            ErrorCode::AlreadyPrimary => "ALREADY_PRIMARY",
+            ErrorCode::RateUnavailable => "RATE_UNAVAILABLE",
+            ErrorCode::RecoveryJobNotFound => "RECOVERY_JOB_NOT_FOUND",
+            ErrorCode::RecoveryJobFinished => "RECOVERY_JOB_FINISHED",
+            ErrorCode::DeadLetterNotFound => "DLQ_MESSAGE_NOT_FOUND",
+            ErrorCode::DeadLetterResolved => "DLQ_MESSAGE_ALREADY_RESOLVED",
+            ErrorCode::DeadLetterNotRequeueable => "DLQ_MESSAGE_NOT_REQUEUEABLE",
+            ErrorCode::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+            ErrorCode::Internal => "INTERNAL_ERROR",
+        }
+    }
+
+    /// 오류 코드별 HTTP 상태
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ErrorCode::InvalidRequest
+            | ErrorCode::InvalidSymbol
+            | ErrorCode::InvalidClientId
+            | ErrorCode::InvalidQuantity
+            | ErrorCode::MissingPrice
+            | ErrorCode::InvalidPrice
+            | ErrorCode::InvalidSlippage
+            | ErrorCode::InvalidMaxLevels
+            | ErrorCode::InvalidExpireTime
+            | ErrorCode::InvalidTag
+            | ErrorCode::InvalidInterval => StatusCode::BAD_REQUEST,
+            ErrorCode::InsufficientBalance | ErrorCode::DeadLetterNotRequeueable => StatusCode::UNPROCESSABLE_ENTITY,
+            ErrorCode::Unauthorized | ErrorCode::TotpRequired => StatusCode::UNAUTHORIZED,
+            ErrorCode::Forbidden
+            | ErrorCode::AccountSuspended
+            | ErrorCode::ClientBlocked
+            | ErrorCode::KycLimitExceeded
+            | ErrorCode::RiskLimitExceeded => StatusCode::FORBIDDEN,
+            ErrorCode::SymbolNotFound
+            | ErrorCode::OrderNotFound
+            | ErrorCode::NotificationRuleNotFound
+            | ErrorCode::IncidentNotFound
+            | ErrorCode::ApiKeyNotFound
+            | ErrorCode::KillSwitchNotFound
+            | ErrorCode::RiskLimitNotFound
+            | ErrorCode::RoleAssignmentNotFound
+            | ErrorCode::RecoveryJobNotFound
+            | ErrorCode::DeadLetterNotFound => StatusCode::NOT_FOUND,
+            ErrorCode::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            ErrorCode::MarketHalted
+            | ErrorCode::IncidentResolved
+            | ErrorCode::AlreadyPrimary
+            | ErrorCode::RecoveryJobFinished
+            | ErrorCode::DeadLetterResolved => StatusCode::CONFLICT,
+            ErrorCode::QueueFull
+            | ErrorCode::Timeout
+            | ErrorCode::NotPrimary
+            | ErrorCode::RateUnavailable
+            | ErrorCode::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            ErrorCode::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// API 오류
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl ApiError {
+    /// 새 API 오류 생성
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// HTTP 상태
+    pub fn status(&self) -> StatusCode {
+        self.code.status()
+    }
+
+    /// 조회 대상 심볼 없음
+    pub fn symbol_not_found(symbol: &str) -> Self {
+        Self::new(ErrorCode::SymbolNotFound, format!("심볼 '{}'을 찾을 수 없습니다", symbol))
+    }
+
+    /// 주문 없음
+    pub fn order_not_found(order_id: &str) -> Self {
+        Self::new(ErrorCode::OrderNotFound, format!("주문 '{}'을 찾을 수 없습니다", order_id))
+    }
+
+    /// 대기 인스턴스의 주문 거부
+    pub fn not_primary() -> Self {
+        Self::new(
+            ErrorCode::NotPrimary,
+            "대기(standby) 인스턴스는 주문을 받지 않습니다. 주 인스턴스로 요청하세요",
+        )
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ErrorResponse {
+            error: self.code.as_str().to_string(),
+            message: self.message,
+        };
+        (self.code.status(), Json(body)).into_response()
+    }
+}
+
+impl From<QueueError> for ApiError {
+    fn from(e: QueueError) -> Self {
+        match e {
+            QueueError::Full(_) => Self::new(
+                ErrorCode::QueueFull,
+                format!("처리량이 한도를 초과했습니다. 잠시 후 다시 시도하세요 ({})", e),
+            ),
+            QueueError::Disconnected(_) => Self::new(ErrorCode::ServiceUnavailable, e.to_string()),
+        }
+    }
+}
+
+impl From<AdmissionError> for ApiError {
+    fn from(e: AdmissionError) -> Self {
+        match e {
+            AdmissionError::Queue(e) => e.into(),
+            AdmissionError::Timeout { waited } => Self::new(
+                ErrorCode::Timeout,
+                format!("주문 큐가 {}ms 동안 포화 상태라 주문을 접수하지 못했습니다", waited.as_millis()),
+            ),
+        }
+    }
+}
+
+impl From<OrderRejectReason> for ApiError {
+    fn from(reason: OrderRejectReason) -> Self {
+        match reason {
+            OrderRejectReason::UnknownSymbol => {
+                Self::new(ErrorCode::InvalidSymbol, "매칭 엔진에 주문장이 없는 심볼입니다")
+            }
+            OrderRejectReason::Expired => {
+                Self::new(ErrorCode::InvalidExpireTime, "매칭 엔진에 도착했을 때 이미 만료된 주문입니다")
+            }
+            OrderRejectReason::ClientBlocked => {
+                Self::new(ErrorCode::ClientBlocked, "주문 처리 중 계정이 차단되었습니다")
+            }
+            OrderRejectReason::SymbolHalted => {
+                Self::new(ErrorCode::MarketHalted, "주문 처리 중 심볼 거래가 중단되었습니다")
+            }
+            OrderRejectReason::Throttled => {
+                Self::new(ErrorCode::RateLimited, "취소 비율 과다로 계정 주문 속도가 제한되었습니다")
+            }
+            OrderRejectReason::MarketOrderInAuction => {
+                Self::new(ErrorCode::InvalidRequest, "배치 경매 심볼은 지정가 주문만 받습니다")
+            }
+        }
+    }
+}
+
+impl From<KycError> for ApiError {
+    fn from(e: KycError) -> Self {
+        let code = match e {
+            KycError::Suspended { .. } => ErrorCode::AccountSuspended,
+            KycError::LimitExceeded { .. } => ErrorCode::KycLimitExceeded,
+            KycError::InvalidRecord(_) | KycError::Storage(_) => ErrorCode::Internal,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<AuthError> for ApiError {
+    fn from(e: AuthError) -> Self {
+        let code = match e {
+            AuthError::Missing
+            | AuthError::InvalidApiKey
+            | AuthError::InvalidToken(_)
+            | AuthError::RefreshTokenReused => ErrorCode::Unauthorized,
+            AuthError::Forbidden(_) | AuthError::IpNotAllowed(_) => ErrorCode::Forbidden,
+            AuthError::KeyNotFound(_) => ErrorCode::ApiKeyNotFound,
+            AuthError::NoScopes | AuthError::InvalidIpAllowlist(_) => ErrorCode::InvalidRequest,
+            AuthError::Disabled => ErrorCode::ServiceUnavailable,
+            AuthError::Storage(_) => ErrorCode::Internal,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<TotpError> for ApiError {
+    fn from(e: TotpError) -> Self {
+        let code = match e {
+            TotpError::Missing | TotpError::InvalidCode => ErrorCode::TotpRequired,
+            TotpError::NotEnrolled(_) => ErrorCode::Forbidden,
+            TotpError::NotPending(_) => ErrorCode::InvalidRequest,
+            TotpError::Storage(_) => ErrorCode::Internal,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<KillSwitchError> for ApiError {
+    fn from(e: KillSwitchError) -> Self {
+        let code = match e {
+            KillSwitchError::ClientBlocked { .. } => ErrorCode::ClientBlocked,
+            KillSwitchError::SymbolHalted { .. } => ErrorCode::MarketHalted,
+            KillSwitchError::InvalidRecord(_) | KillSwitchError::Storage(_) => ErrorCode::Internal,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<RbacError> for ApiError {
+    fn from(e: RbacError) -> Self {
+        let code = match e {
+            RbacError::PermissionDenied { .. } => ErrorCode::Forbidden,
+            RbacError::UnknownRole(_)
+            | RbacError::UnknownSubjectKind(_)
+            | RbacError::ImmutableRole => ErrorCode::InvalidRequest,
+            RbacError::AssignmentNotFound(_) => ErrorCode::RoleAssignmentNotFound,
+            RbacError::InvalidRecord(_) | RbacError::Storage(_) => ErrorCode::Internal,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<RebateError> for ApiError {
+    fn from(e: RebateError) -> Self {
+        let code = match e {
+            RebateError::InvalidMonth(_) => ErrorCode::InvalidRequest,
+            RebateError::Storage(_) | RebateError::Csv(_) => ErrorCode::Internal,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<FundingError> for ApiError {
+    fn from(e: FundingError) -> Self {
+        Self::new(ErrorCode::Internal, e.to_string())
+    }
+}
+
+impl From<UsageError> for ApiError {
+    fn from(e: UsageError) -> Self {
+        let code = match e {
+            UsageError::HardCapExceeded { .. } => ErrorCode::RateLimited,
+            UsageError::Storage(_) => ErrorCode::Internal,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<RiskError> for ApiError {
+    fn from(e: RiskError) -> Self {
+        let code = match e {
+            RiskError::OpenOrdersExceeded { .. }
+            | RiskError::OrderNotionalExceeded { .. }
+            | RiskError::PositionExceeded { .. } => ErrorCode::RiskLimitExceeded,
+            RiskError::InvalidRecord(_) | RiskError::Storage(_) => ErrorCode::Internal,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<FeeError> for ApiError {
+    fn from(e: FeeError) -> Self {
+        ApiError::new(ErrorCode::Internal, e.to_string())
+    }
+}
+
+impl From<IncidentError> for ApiError {
+    fn from(e: IncidentError) -> Self {
+        let code = match &e {
+            IncidentError::NotFound(_) => ErrorCode::IncidentNotFound,
+            IncidentError::AlreadyResolved(_) => ErrorCode::IncidentResolved,
+            IncidentError::EmptyNote => ErrorCode::InvalidRequest,
+            IncidentError::InvalidRecord(_) | IncidentError::Storage(_) => ErrorCode::Internal,
+        };
+        ApiError::new(code, e.to_string())
+    }
+}
+
+impl From<RoutingRuleError> for ApiError {
+    fn from(e: RoutingRuleError) -> Self {
+        ApiError::new(ErrorCode::InvalidRequest, e.to_string())
+    }
+}
+
+impl From<ReplicationError> for ApiError {
+    fn from(e: ReplicationError) -> Self {
+        match e {
+            ReplicationError::AlreadyPrimary => ApiError::new(ErrorCode::AlreadyPrimary, e.to_string()),
+        }
+    }
+}
+
+impl From<BackupError> for ApiError {
+    fn from(e: BackupError) -> Self {
+        ApiError::new(ErrorCode::Internal, e.to_string())
+    }
+}
+
+impl From<RecoveryJobError> for ApiError {
+    fn from(e: RecoveryJobError) -> Self {
+        let code = match e {
+            RecoveryJobError::NotFound(_) => ErrorCode::RecoveryJobNotFound,
+            RecoveryJobError::AlreadyFinished(_) => ErrorCode::RecoveryJobFinished,
+            RecoveryJobError::BackupQueue(_) => ErrorCode::Internal,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<QuarantineError> for ApiError {
+    fn from(e: QuarantineError) -> Self {
+        let code = match &e {
+            QuarantineError::NotFound(_) => ErrorCode::DeadLetterNotFound,
+            QuarantineError::AlreadyResolved(_) => ErrorCode::DeadLetterResolved,
+            QuarantineError::NotRequeueable(..) => ErrorCode::DeadLetterNotRequeueable,
+            QuarantineError::ProducerUnavailable | QuarantineError::Publish(_) => ErrorCode::ServiceUnavailable,
+            QuarantineError::InvalidRecord(_) | QuarantineError::Storage(_) => ErrorCode::Internal,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<CurrencyError> for ApiError {
+    fn from(e: CurrencyError) -> Self {
+        let code = match e {
+            CurrencyError::UnknownAsset(_) | CurrencyError::InvalidSymbol(_) => ErrorCode::InvalidSymbol,
+            CurrencyError::RateUnavailable { .. } => ErrorCode::RateUnavailable,
+        };
+        Self::new(code, e.to_string())
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::new(ErrorCode::InvalidRequest, rejection.body_text())
+    }
+}
+
+impl From<PathRejection> for ApiError {
+    fn from(rejection: PathRejection) -> Self {
+        Self::new(ErrorCode::InvalidRequest, rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::new(ErrorCode::InvalidRequest, rejection.body_text())
+    }
+}
+
+/// 핸들러 결과 타입
+pub type ApiResult<T> = Result<Json<T>, ApiError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_mapping() {
+        assert_eq!(ApiError::new(ErrorCode::InvalidSymbol, "").status(), StatusCode::BAD_REQUEST);
+        assert_eq!(ApiError::new(ErrorCode::RateLimited, "").status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(ApiError::order_not_found("o1").status(), StatusCode::NOT_FOUND);
+        assert_eq!(ApiError::from(QueueError::Full("orders".to_string())).code, ErrorCode::QueueFull);
+        let timeout = ApiError::from(AdmissionError::Timeout { waited: std::time::Duration::from_millis(500) });
+        assert_eq!((timeout.code.as_str(), timeout.status()), ("TIMEOUT", StatusCode::SERVICE_UNAVAILABLE));
+        assert_eq!(
+            ApiError::from(KycError::Suspended { client_id: "c1".to_string(), reason: None }).status(),
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(ApiError::from(OrderRejectReason::SymbolHalted).status(), StatusCode::CONFLICT);
+        assert_eq!(ApiError::from(OrderRejectReason::Throttled).status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn test_json_error_body() {
+        let response = ApiError::new(ErrorCode::MarketHalted, "거래 중단").into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["error"], "MARKET_HALTED");
+        assert_eq!(body["message"], "거래 중단");
+    }
+}
@@ -1,57 +1,127 @@
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    response::Json,
+    body::Body,
+    extract::{rejection::JsonRejection, Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Json, Response},
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
-use tokio::sync::Mutex;
 use uuid::Uuid;
 
+use crate::api::error::{ApiError, ApiResult, ErrorCode};
+use crate::auth::{AuthContext, AuthError, AuthService, ClientIp, IssuedApiKey, Scope, TokenPair};
+use crate::totp::{TotpProvisioning, TotpRegistry, TOTP_HEADER};
 use crate::api::models::*;
-use crate::matching_engine::engine::MatchingEngine;
-use crate::matching_engine::model::{Order, OrderType, Side};
+use crate::api::validation::{validate_client_id, validate_tag};
+use crate::db::repository::{ArbitrageOpportunityRepository, AuditLogRepository, BalanceRepository, NotificationRoutingRuleRepository, OrderRepository};
+use crate::currency::{OrderReservation, UserBalance};
+use crate::db::metrics_history::HOUR_MS;
+use crate::db::{BackupManager, BackupMetadata, ClientStatsService, ExportFormat, RebateReport, StatsWindow, TradeExportQuery, TradeExportService};
+use crate::external::local_fillable_quantity;
+use crate::kill_switch::KillSwitchScope;
+use crate::kyc::{KycAccount, KycUpdate};
+use crate::rbac::{RbacError, Role, RoleAssignment, RolePermissions, SubjectKind};
+use crate::risk::{RiskCheck, RiskLimits};
+use crate::mdp::publisher::CANDLE_INTERVALS;
+use crate::matching_engine::order_ack::{OrderAckStatus, OrderRejectReason};
+use crate::monitoring::incident_tracker::{Incident, IncidentStatus};
+use crate::monitoring::readiness::ReadinessReport;
+use crate::monitoring::notification_routing::{
+    RoutingRule, RULE_AUDIT_ENTITY, RULE_DELETED_AUDIT_EVENT, RULE_UPDATED_AUDIT_EVENT,
+};
+use crate::matching_engine::model::{Order, OrderType, Side, MarketProtection};
+use crate::mq::{QuarantineStatus, QuarantinedMessage, RecoveryFilter, RecoveryJob};
+use crate::sequencer::backpressure::QueueError;
+use crate::sequencer::message_usage::{MessageCounts, MessageKind};
+use crate::sequencer::replication::{ReplicationStatus, PROMOTION_AUDIT_ENTITY, PROMOTION_AUDIT_EVENT};
 use crate::server::ServerState;
 
 /// 주문 제출 핸들러
+///
+/// 매칭 엔진이 주문을 처리할 때까지 기다려 확정 상태(접수/체결/취소)와 즉시 체결 수량을 돌려줍니다.
+/// 엔진이 거부하면 사유별 오류 코드로 응답하고, 대기 시간을 넘기면 `PENDING` 으로 응답합니다.
+#[utoipa::path(
+    post,
+    path = "/v1/order",
+    tag = "orders",
+    request_body = OrderRequest,
+    responses(
+        (status = 200, description = "주문 처리 결과", body = OrderResponse),
+        (status = 400, description = "잘못된 주문 또는 매칭 엔진 거부", body = ErrorResponse),
+        (status = 401, description = "인증 실패 (인증 설정 시)", body = ErrorResponse),
+        (status = 403, description = "다른 계정 주문, trade 권한 없음, 정지/차단된 계정, KYC 주문 금액 한도 또는 리스크 한도 초과", body = ErrorResponse),
+        (status = 409, description = "거래 중단된 심볼", body = ErrorResponse),
+        (status = 503, description = "주문 큐 포화 (`max_wait_ms` 대기 초과 시 TIMEOUT), 대기 인스턴스 또는 보고 통화 환율 없음", body = ErrorResponse),
+    )
+)]
 pub async fn submit_order(
     State(state): State<ServerState>,
-    Json(payload): Json<OrderRequest>,
-) -> Result<Json<OrderResponse>, (StatusCode, Json<ErrorResponse>)> {
-    // 입력 검증
-    if payload.quantity == 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "INVALID_QUANTITY".to_string(),
-                message: "수량은 0보다 커야 합니다".to_string(),
-            }),
-        ));
+    auth: Option<AuthContext>,
+    payload: Result<Json<OrderRequest>, JsonRejection>,
+) -> ApiResult<OrderResponse> {
+    if state.replication.is_standby() {
+        return Err(ApiError::not_primary());
     }
+    let Json(payload) = payload?;
+    authorize_client(&state, &auth, &payload.client_id, Scope::Trade)?;
 
-    if payload.order_type == OrderType::Limit && payload.price.is_none() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "MISSING_PRICE".to_string(),
-                message: "지정가 주문에는 가격이 필요합니다".to_string(),
-            }),
-        ));
-    }
+    // 분당 메시지 집계 (하드 상한을 넘으면 RATE_LIMITED)
+    let usage = &state.message_usage;
+    usage.record(&payload.client_id, MessageKind::Order, usage.now_millis())?;
 
-    if payload.order_type == OrderType::Limit && payload.price.unwrap_or(0) == 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "INVALID_PRICE".to_string(),
-                message: "가격은 0보다 커야 합니다".to_string(),
-            }),
-        ));
-    }
+    // 입력 검증
+    state.order_validator.validate(&payload, chrono::Utc::now().timestamp() as u64)?;
+
+    // 킬 스위치 확인 (차단 계정, 거래 중단 심볼)
+    state.kill_switch.check_order(&payload.client_id, &payload.symbol)?;
+
+    // KYC 확인 (정지 계정 거부, 인증 단계별 1회 주문 금액 한도)
+    // 시장가 주문은 반대편 최우선 호가로 금액을 추정하고, 한도는 보고 통화로 환산해 비교
+    let reference_price = match payload.order_type {
+        OrderType::Limit => payload.price.unwrap_or(0),
+        OrderType::Market => {
+            let engine_guard = state.engine.lock().await;
+            engine_guard
+                .get_order_book_snapshot(&payload.symbol, 1)
+                .and_then(|book| match payload.side {
+                    Side::Buy => book.asks.first().map(|(price, _)| *price),
+                    Side::Sell => book.bids.first().map(|(price, _)| *price),
+                })
+                .unwrap_or(0)
+        }
+    };
+    let notional = state
+        .currency
+        .notional_to_reporting(&payload.symbol, reference_price.saturating_mul(payload.quantity))?;
+    state.kyc.check_order(&payload.client_id, notional).await?;
+
+    // 사전 리스크 한도 확인 (미체결 주문 수, 1회 주문 금액, 같은 방향 미체결 수량을 포함한 순포지션)
+    let (open_orders, pending_quantity) = {
+        let engine_guard = state.engine.lock().await;
+        let open_orders = engine_guard.get_open_orders(&payload.client_id);
+        let pending_quantity = open_orders
+            .iter()
+            .filter(|order| order.symbol == payload.symbol && order.side == payload.side)
+            .map(|order| order.remaining_quantity)
+            .sum();
+        (open_orders.len(), pending_quantity)
+    };
+    state
+        .risk
+        .check_order(&RiskCheck {
+            client_id: &payload.client_id,
+            symbol: &payload.symbol,
+            side: payload.side.clone(),
+            quantity: payload.quantity,
+            notional,
+            open_orders,
+            pending_quantity,
+        })
+        .await?;
 
     // 주문 생성
     let order_id = Uuid::new_v4().to_string();
-    let order = Order::new(
+    let mut order = Order::new(
         order_id.clone(),
         payload.symbol.clone(),
         payload.side.clone(),
@@ -61,60 +131,234 @@ pub async fn submit_order(
         payload.client_id.clone(),
     );
 
-    // 주문을 채널로 전송 (빠른 응답을 위해 clone 사용)
-    if let Err(e) = state.order_tx.send(order.clone()) {
-        return Err((
-            StatusCode::SERVICE_UNAVAILABLE,
-            Json(ErrorResponse {
-                error: "ORDER_SEND_FAILED".to_string(),
-                message: format!("주문 전송 실패: {}", e),
+    // 시장가 주문 보호 설정 (요청에 지정된 경우만)
+    if payload.max_slippage_pct.is_some() || payload.max_levels.is_some() {
+        order = order.with_protection(MarketProtection {
+            max_slippage_pct: payload.max_slippage_pct,
+            max_levels: payload.max_levels,
+        });
+    }
+
+    // GTD 만료 시간 설정
+    if let Some(expire_time) = payload.expire_time {
+        order = order.with_expire_time(expire_time);
+    }
+
+    // 사용자 지정 태그 (체결 보고서와 주문 기록에 그대로 실림)
+    if let Some(tag) = payload.tag.clone() {
+        order = order.with_tag(tag);
+    }
+
+    // 모의 주문은 현재 주문장으로 체결만 해 보고 큐에 넣지 않음 (외부 라우팅 제외)
+    if payload.dry_run {
+        let result = {
+            let engine_guard = state.engine.lock().await;
+            engine_guard.simulate_order(&order)
+        }
+        .ok_or_else(|| ApiError::from(OrderRejectReason::UnknownSymbol))?;
+        return Ok(Json(OrderResponse {
+            order_id,
+            status: result.status.as_str().to_string(),
+            message: "모의 체결 결과입니다. 주문은 제출되지 않았습니다".to_string(),
+            sequence: None,
+            filled_quantity: result.filled_quantity,
+            remaining_quantity: result.remaining_quantity,
+            external_fills: Vec::new(),
+            dry_run: Some(DryRunDetails {
+                fills: result.fills,
+                reference_price: result.reference_price,
+                average_price: result.average_price,
+                slippage_bps: result.slippage_bps,
             }),
-        ));
+        }));
+    }
+
+    // 외부 거래소 라우팅 (요청 시, 로컬 호가로 채우지 못하는 수량만)
+    let mut external_fills = Vec::new();
+    if let (true, Some(router)) = (payload.route_external, state.order_router.as_ref()) {
+        let limit_price = match order.order_type {
+            OrderType::Limit => Some(order.price),
+            OrderType::Market => None,
+        };
+        let local_quantity = {
+            let engine_guard = state.engine.lock().await;
+            engine_guard
+                .get_order_book_snapshot(&order.symbol, router.book_depth())
+                .map_or(0, |book| local_fillable_quantity(&book, &order.side, limit_price))
+        };
+
+        let shortfall = order.quantity.saturating_sub(local_quantity);
+        if shortfall > 0 {
+            let routed = router.route(&order, shortfall).await;
+
+            // 외부 체결은 로컬 체결과 같은 형식으로 WebSocket에 전달
+            for mut report in routed.execution_reports(&order) {
+                report.sequence = state.global_sequence.next();
+                let order_status = if report.remaining_quantity == 0 { "Filled" } else { "PartiallyFilled" };
+                let _ = state.execution_tx.send(WebSocketMessage::Execution {
+                    execution_report: report,
+                    order_status: order_status.to_string(),
+                });
+            }
+            // 수수료는 호가 자산 기준, 보고 통화 환산값을 함께 제공
+            let fee_asset = state.currency.registry().quote_asset(&order.symbol)?;
+            for result in &routed.results {
+                external_fills.extend(result.fills.iter().map(|fill| ExternalFill {
+                    exchange: result.exchange.to_string(),
+                    venue_order_id: result.venue_order_id.clone(),
+                    price: fill.price,
+                    quantity: fill.quantity,
+                    fee: fill.fee,
+                    fee_currency: fee_asset.code.clone(),
+                    fee_reporting: state.currency.minor_to_reporting(fill.fee, fee_asset).ok(),
+                }));
+            }
+
+            // 로컬 매칭 엔진에는 외부 체결분을 뺀 수량만 전달
+            order.quantity -= routed.filled_quantity();
+            order.remaining_quantity = order.quantity;
+        }
+    }
+
+    if order.quantity == 0 {
+        return Ok(Json(OrderResponse {
+            order_id,
+            status: "FILLED".to_string(),
+            message: "외부 거래소에서 전량 체결되었습니다".to_string(),
+            sequence: None,
+            filled_quantity: payload.quantity,
+            remaining_quantity: 0,
+            external_fills,
+            dry_run: None,
+        }));
     }
+    let external_quantity = payload.quantity - order.quantity;
+
+    // 엔진이 큐에서 꺼내기 전에 처리 결과 채널 등록
+    let ack_rx = state.order_acks.register(&order_id);
+
+    // 전역 시퀀스 번호를 붙여 큐로 전송 (큐가 가득 차거나 버려지면 503, `max_wait_ms` 지정 시 그동안 재시도)
+    let max_wait = state.order_admission.wait_for(payload.max_wait_ms);
+    let sequence = match state.order_admission.submit(&state.global_sequence, order, &state.order_tx, max_wait).await {
+        Ok(Some(sequence)) => sequence,
+        result => {
+            state.order_acks.forget(&order_id);
+            return Err(match result {
+                Err(e) => e.into(),
+                Ok(_) => QueueError::Full(state.order_tx.gauge().name().to_string()).into(),
+            });
+        }
+    };
+
+    let Some(ack) = state.order_acks.wait(&order_id, ack_rx).await else {
+        return Ok(Json(OrderResponse {
+            order_id,
+            status: "PENDING".to_string(),
+            message: "주문이 접수되었으나 처리 결과를 기다리는 시간이 초과되었습니다. 결과는 WebSocket으로 전달됩니다".to_string(),
+            sequence: Some(sequence),
+            filled_quantity: external_quantity,
+            remaining_quantity: payload.quantity - external_quantity,
+            external_fills,
+            dry_run: None,
+        }));
+    };
 
-    // 즉시 접수 확인 응답 (매칭 결과는 WebSocket으로 전달)
+    // 외부 거래소 체결분이 있으면 로컬에서 체결이 없어도 일부 체결
+    let status = match ack.status {
+        OrderAckStatus::Rejected(reason) => return Err(reason.into()),
+        OrderAckStatus::Accepted if external_quantity > 0 => OrderAckStatus::PartiallyFilled,
+        status => status,
+    };
+    let message = match status {
+        OrderAckStatus::Accepted => "주문이 접수되어 주문장에 등록되었습니다",
+        OrderAckStatus::PartiallyFilled => "주문이 일부 체결되었습니다. 남은 수량은 주문장에 등록되었습니다",
+        OrderAckStatus::Filled => "주문이 전량 체결되었습니다",
+        _ => "체결할 호가가 부족하여 남은 수량이 취소되었습니다",
+    };
     Ok(Json(OrderResponse {
         order_id,
-        status: "ACCEPTED".to_string(),
-        message: "주문이 접수되었습니다. 체결 결과는 WebSocket으로 전달됩니다".to_string(),
+        status: status.as_str().to_string(),
+        message: message.to_string(),
+        sequence: Some(sequence),
+        filled_quantity: external_quantity + ack.filled_quantity,
+        remaining_quantity: ack.remaining_quantity,
+        external_fills,
+        dry_run: None,
     }))
 }
 
 /// 주문 취소 핸들러
+///
+/// 취소는 시퀀서의 취소 레인으로 전달되어 신규 주문보다 우선 처리됩니다.
+#[utoipa::path(
+    post,
+    path = "/v1/order/cancel",
+    tag = "orders",
+    request_body = CancelOrderRequest,
+    responses(
+        (status = 200, description = "취소 요청 접수", body = CancelOrderResponse),
+        (status = 401, description = "인증 실패 (인증 설정 시)", body = ErrorResponse),
+        (status = 403, description = "다른 계정 주문 또는 trade 권한 없음", body = ErrorResponse),
+        (status = 404, description = "주문 없음", body = ErrorResponse),
+        (status = 503, description = "취소 큐 포화 또는 대기 인스턴스", body = ErrorResponse),
+    )
+)]
 pub async fn cancel_order(
     State(state): State<ServerState>,
-    Json(payload): Json<CancelOrderRequest>,
-) -> Result<Json<CancelOrderResponse>, (StatusCode, Json<ErrorResponse>)> {
-    let mut engine_guard = state.engine.lock().await;
-    
-    // 주문 존재 확인
-    if engine_guard.get_order(&payload.order_id).is_none() {
-        return Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: "ORDER_NOT_FOUND".to_string(),
-                message: "주문을 찾을 수 없습니다".to_string(),
-            }),
-        ));
+    auth: Option<AuthContext>,
+    payload: Result<Json<CancelOrderRequest>, JsonRejection>,
+) -> ApiResult<CancelOrderResponse> {
+    if state.replication.is_standby() {
+        return Err(ApiError::not_primary());
     }
+    let Json(payload) = payload?;
 
-    // 취소 주문 생성
-    let cancel_order = Order::new_cancel(payload.order_id.clone());
-    engine_guard.handle_cancel_order(&cancel_order);
+    let owner = {
+        let engine_guard = state.engine.lock().await;
+        
+        // 주문 존재 확인
+        match engine_guard.get_order(&payload.order_id) {
+            Some(order) => order.client_id.clone(),
+            None => return Err(ApiError::order_not_found(&payload.order_id)),
+        }
+    };
+    authorize_client(&state, &auth, &owner, Scope::Trade)?;
+    // 취소는 하드 상한을 넘어도 거부하지 않고 세기만 함
+    let usage = &state.message_usage;
+    usage.record(&owner, MessageKind::Cancel, usage.now_millis())?;
+
+    // 취소 주문 생성 후 취소 레인으로 전송
+    let sequence = state
+        .global_sequence
+        .submit(Order::new_cancel(payload.order_id.clone()), &state.cancel_tx)?;
 
     Ok(Json(CancelOrderResponse {
         order_id: payload.order_id,
-        status: "CANCELLED".to_string(),
-        message: "주문이 취소되었습니다".to_string(),
+        status: "CANCEL_REQUESTED".to_string(),
+        message: "취소 요청이 접수되었습니다. 결과는 WebSocket으로 전달됩니다".to_string(),
+        sequence,
     }))
 }
 
 /// 주문서 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/orderbook/{symbol}",
+    tag = "market-data",
+    params(
+        ("symbol" = String, Path, description = "거래 심볼"),
+        ("depth" = Option<usize>, Query, description = "호가 깊이 (기본 10)"),
+    ),
+    responses(
+        (status = 200, description = "주문서", body = OrderBookResponse),
+        (status = 404, description = "심볼 없음", body = ErrorResponse),
+    )
+)]
 pub async fn get_orderbook(
     State(state): State<ServerState>,
     Path(symbol): Path<String>,
     Query(params): Query<HashMap<String, String>>,
-) -> Result<Json<OrderBookResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> ApiResult<OrderBookResponse> {
     let engine_guard = state.engine.lock().await;
     
     let depth = params
@@ -124,22 +368,28 @@ pub async fn get_orderbook(
 
     match engine_guard.get_order_book_snapshot(&symbol, depth) {
         Some(orderbook) => Ok(Json(OrderBookResponse { orderbook })),
-        None => Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: "SYMBOL_NOT_FOUND".to_string(),
-                message: "지원하지 않는 심볼입니다".to_string(),
-            }),
-        )),
+        None => Err(ApiError::symbol_not_found(&symbol)),
     }
 }
 
 /// 체결 내역 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/executions/{symbol}",
+    tag = "market-data",
+    params(
+        ("symbol" = String, Path, description = "거래 심볼"),
+        ("limit" = Option<usize>, Query, description = "최대 개수 (기본 100)"),
+    ),
+    responses(
+        (status = 200, description = "최근 체결 내역", body = ExecutionResponse),
+    )
+)]
 pub async fn get_executions(
     State(state): State<ServerState>,
     Path(symbol): Path<String>,
     Query(params): Query<HashMap<String, String>>,
-) -> Result<Json<ExecutionResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> ApiResult<ExecutionResponse> {
     let limit = params
         .get("limit")
         .and_then(|l| l.parse::<usize>().ok())
@@ -152,10 +402,19 @@ pub async fn get_executions(
 }
 
 /// 시장 통계 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/statistics/{symbol}",
+    tag = "market-data",
+    params(("symbol" = String, Path, description = "거래 심볼")),
+    responses(
+        (status = 200, description = "24시간 시장 통계", body = MarketStatisticsResponse),
+    )
+)]
 pub async fn get_statistics(
     State(state): State<ServerState>,
     Path(symbol): Path<String>,
-) -> Result<Json<MarketStatisticsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> ApiResult<MarketStatisticsResponse> {
     let mdp_guard = state.mdp.lock().await;
     
     if let Some(stats) = mdp_guard.get_statistics(&symbol).await {
@@ -189,11 +448,32 @@ pub async fn get_statistics(
 }
 
 /// 봉차트 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/api/v1/klines/{symbol}/{interval}",
+    tag = "market-data",
+    params(
+        ("symbol" = String, Path, description = "거래 심볼"),
+        ("interval" = String, Path, description = "봉 간격 (1m, 5m, 15m, 30m, 1h, 4h, 1d)"),
+        ("limit" = Option<usize>, Query, description = "최대 개수 (기본 100)"),
+    ),
+    responses(
+        (status = 200, description = "봉차트", body = CandleResponse),
+        (status = 400, description = "지원하지 않는 간격", body = ErrorResponse),
+    )
+)]
 pub async fn get_candles(
     State(state): State<ServerState>,
     Path((symbol, interval)): Path<(String, String)>,
     Query(params): Query<HashMap<String, String>>,
-) -> Result<Json<CandleResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> ApiResult<CandleResponse> {
+    if !CANDLE_INTERVALS.contains(&interval.as_str()) {
+        return Err(ApiError::new(
+            ErrorCode::InvalidInterval,
+            format!("지원하지 않는 봉 간격입니다: {} (지원: {})", interval, CANDLE_INTERVALS.join(", ")),
+        ));
+    }
+
     let limit = params
         .get("limit")
         .and_then(|l| l.parse::<usize>().ok())
@@ -222,62 +502,2609 @@ pub async fn get_candles(
 }
 
 /// 주문 상태 조회 핸들러 (하이브리드 방식)
+///
+/// 인증 설정 시 본인 계정의 주문만 조회할 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/v1/order/{order_id}",
+    tag = "orders",
+    params(("order_id" = String, Path, description = "주문 ID")),
+    responses(
+        (status = 200, description = "주문 상태", body = OrderStatusResponse),
+        (status = 401, description = "인증 실패 (인증 설정 시)", body = ErrorResponse),
+        (status = 403, description = "다른 계정 주문", body = ErrorResponse),
+        (status = 404, description = "주문 없음", body = ErrorResponse),
+    )
+)]
 pub async fn get_order_status(
     State(state): State<ServerState>,
+    auth: Option<AuthContext>,
     Path(order_id): Path<String>,
-) -> Result<Json<OrderStatusResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> ApiResult<OrderStatusResponse> {
     let engine_guard = state.engine.lock().await;
     
     // 주문 정보 조회
-    if let Some(order) = engine_guard.get_order(&order_id) {
-        // 주문 상태 결정
-        let status = if order.is_filled() {
-            "Filled"
-        } else if order.remaining_quantity < order.quantity {
-            "PartiallyFilled"
-        } else {
-            "Pending"
-        };
-        
-        Ok(Json(OrderStatusResponse {
-            order_id: order.id.clone(),
-            symbol: order.symbol.clone(),
-            side: format!("{:?}", order.side),
-            order_type: format!("{:?}", order.order_type),
-            price: order.price,
-            quantity: order.quantity,
-            remaining_quantity: order.remaining_quantity,
-            status: status.to_string(),
-            created_at: order.created_at,
-            updated_at: order.updated_at,
-        }))
+    let Some(order) = engine_guard.get_order(&order_id) else {
+        return Err(ApiError::order_not_found(&order_id));
+    };
+    authorize_client(&state, &auth, &order.client_id, Scope::Read)?;
+
+    // 주문 상태 결정
+    let status = if order.is_filled() {
+        "Filled"
+    } else if order.remaining_quantity < order.quantity {
+        "PartiallyFilled"
     } else {
-        Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: "ORDER_NOT_FOUND".to_string(),
-                message: "주문을 찾을 수 없습니다".to_string(),
+        "Pending"
+    };
+
+    Ok(Json(OrderStatusResponse {
+        order_id: order.id.clone(),
+        symbol: order.symbol.clone(),
+        side: format!("{:?}", order.side),
+        order_type: format!("{:?}", order.order_type),
+        price: order.price,
+        quantity: order.quantity,
+        remaining_quantity: order.remaining_quantity,
+        status: status.to_string(),
+        // 엔진은 접수 시각만 보관하므로 최근 변경 시각도 접수 시각으로 응답
+        created_at: order.timestamp,
+        updated_at: order.timestamp,
+    }))
+}
+
+/// 체결 내역 내보내기 핸들러 (CSV/Parquet, 청크 전송)
+#[utoipa::path(
+    get,
+    path = "/v1/export/trades",
+    tag = "market-data",
+    params(
+        ("symbol" = String, Query, description = "거래 심볼"),
+        ("from" = Option<i64>, Query, description = "시작 시각 (Unix 초, 포함, 기본 0)"),
+        ("to" = Option<i64>, Query, description = "종료 시각 (Unix 초, 제외, 기본 현재)"),
+        ("format" = Option<String>, Query, description = "csv 또는 parquet (기본 csv)"),
+    ),
+    responses(
+        (status = 200, description = "체결 내역 파일", content_type = "text/csv"),
+        (status = 400, description = "잘못된 기간/형식", body = ErrorResponse),
+        (status = 404, description = "심볼 없음", body = ErrorResponse),
+    )
+)]
+pub async fn export_trades(
+    State(state): State<ServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Response, ApiError> {
+    let symbol = params
+        .get("symbol")
+        .ok_or_else(|| ApiError::new(ErrorCode::InvalidRequest, "symbol 파라미터가 필요합니다"))?;
+    if !state.order_validator.is_supported_symbol(symbol) {
+        return Err(ApiError::symbol_not_found(symbol));
+    }
+
+    let parse_time = |name: &str, default: i64| -> Result<i64, ApiError> {
+        match params.get(name) {
+            Some(value) => value.parse::<i64>().map_err(|_| {
+                ApiError::new(ErrorCode::InvalidRequest, format!("{}는 Unix 타임스탬프(초)여야 합니다: {}", name, value))
             }),
-        ))
+            None => Ok(default),
+        }
+    };
+    let from = parse_time("from", 0)?;
+    let to = parse_time("to", chrono::Utc::now().timestamp() + 1)?;
+    if from >= to {
+        return Err(ApiError::new(ErrorCode::InvalidRequest, "from은 to보다 작아야 합니다"));
+    }
+
+    let format = match params.get("format") {
+        Some(value) => ExportFormat::parse(value).ok_or_else(|| {
+            ApiError::new(ErrorCode::InvalidRequest, format!("지원하지 않는 형식입니다: {} (지원: csv, parquet)", value))
+        })?,
+        None => ExportFormat::Csv,
+    };
+
+    let filename = format!("trades_{}_{}_{}.{}", symbol, from, to, format.extension());
+    let query = TradeExportQuery { symbol: symbol.clone(), from, to };
+    let chunks = TradeExportService::new(state.db_pool.clone()).stream(query, format);
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", filename)),
+        ],
+        Body::from_stream(tokio_stream::wrappers::ReceiverStream::new(chunks)),
+    )
+        .into_response())
+}
+
+/// 차익거래 기회 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/v1/arbitrage/opportunities",
+    tag = "market-data",
+    params(
+        ("symbol" = Option<String>, Query, description = "거래 심볼 (생략 시 전 심볼)"),
+        ("limit" = Option<i64>, Query, description = "최대 개수 (기본 100, 최대 1000)"),
+    ),
+    responses(
+        (status = 200, description = "최근 차익거래 기회 (최신순)", body = ArbitrageOpportunitiesResponse),
+        (status = 500, description = "조회 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_arbitrage_opportunities(
+    State(state): State<ServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<ArbitrageOpportunitiesResponse> {
+    let limit = params
+        .get("limit")
+        .and_then(|l| l.parse::<i64>().ok())
+        .unwrap_or(100)
+        .clamp(1, 1000);
+
+    let records = ArbitrageOpportunityRepository::new(state.db_pool.clone())
+        .find_recent(params.get("symbol").map(String::as_str), limit)
+        .await
+        .map_err(|e| ApiError::new(ErrorCode::Internal, format!("차익거래 기회 조회 실패: {}", e)))?;
+
+    let opportunities = records
+        .into_iter()
+        .map(|r| ArbitrageOpportunityData {
+            id: r.id,
+            symbol: r.symbol,
+            buy_exchange: r.buy_exchange,
+            sell_exchange: r.sell_exchange,
+            buy_price: r.buy_price,
+            sell_price: r.sell_price,
+            spread_percent: r.spread_percent,
+            consecutive_ticks: r.consecutive_ticks as u32,
+            first_seen_at: r.first_seen_at as u64,
+            detected_at: r.detected_at as u64,
+        })
+        .collect();
+
+    Ok(Json(ArbitrageOpportunitiesResponse { opportunities }))
+}
+
+/// 계정별 거래 통계 조회 핸들러 (관리자)
+///
+/// 최근 24시간/7일 메이커·테이커 거래대금, 주문 대비 체결 비율, 취소율을 계정별로 집계해
+/// `window` 구간 거래대금 순으로 돌려줍니다. 수수료 등급 산정과 이상 거래 탐지용입니다.
+#[utoipa::path(
+    get,
+    path = "/v1/stats/clients",
+    tag = "admin",
+    params(
+        ("window" = Option<String>, Query, description = "순위 기준 구간 (24h, 7d, 기본 24h)"),
+        ("limit" = Option<usize>, Query, description = "최대 계정 수 (기본 100, 최대 1000)"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "계정별 거래 통계", body = ClientStatsResponse),
+        (status = 400, description = "잘못된 구간", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 500, description = "집계 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_client_stats(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<ClientStatsResponse> {
+    authorize_admin(&state, &headers)?;
+
+    let window = match params.get("window") {
+        Some(value) => StatsWindow::parse(value)
+            .ok_or_else(|| ApiError::new(ErrorCode::InvalidRequest, format!("지원하지 않는 구간: {} (24h, 7d)", value)))?,
+        None => StatsWindow::Day,
+    };
+    let limit = params
+        .get("limit")
+        .and_then(|l| l.parse::<usize>().ok())
+        .unwrap_or(100)
+        .clamp(1, 1000);
+
+    let now = chrono::Utc::now().timestamp() as u64;
+    let to_reporting = |symbol: &str, notional: u64| state.currency.notional_to_reporting(symbol, notional).ok();
+    let report = ClientStatsService::new(state.db_pool.clone())
+        .leaderboard(now, window, limit, &to_reporting)
+        .await
+        .map_err(|e| ApiError::new(ErrorCode::Internal, format!("계정 거래 통계 집계 실패: {}", e)))?;
+
+    Ok(Json(ClientStatsResponse {
+        generated_at: now,
+        reporting_currency: state.currency.reporting_currency().to_string(),
+        window,
+        clients: report.clients,
+        unpriced_symbols: report.unpriced_symbols,
+    }))
+}
+
+/// 마켓 메이커 월 리베이트 보고서 핸들러 (관리자, JSON/CSV)
+///
+/// 계정·심볼별 최우선 호가 체류 시간과 잔량 × 시간으로 유동성 점수를 매기고 심볼별 월 예산을 나눕니다.
+#[utoipa::path(
+    get,
+    path = "/v1/admin/rebates/{month}",
+    tag = "admin",
+    params(
+        ("month" = String, Path, description = "보고 월 (YYYY-MM, 진행 중인 월은 현재까지)"),
+        ("format" = Option<String>, Query, description = "json 또는 csv (기본 json)"),
+    ),
+    responses(
+        (status = 200, description = "월 리베이트 보고서 (format=csv면 CSV 파일)", body = RebateReport),
+        (status = 400, description = "잘못된 월 또는 형식", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 500, description = "집계 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_rebate_report(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(month): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Response, ApiError> {
+    authorize_admin(&state, &headers)?;
+
+    let csv = match params.get("format").map(String::as_str) {
+        None | Some("json") => false,
+        Some("csv") => true,
+        Some(other) => {
+            return Err(ApiError::new(ErrorCode::InvalidRequest, format!("지원하지 않는 형식입니다: {} (지원: json, csv)", other)))
+        }
+    };
+
+    let report = state.rebates.monthly_report(&month, chrono::Utc::now().timestamp() as u64).await?;
+    if !csv {
+        return Ok(Json(report).into_response());
+    }
+    Ok((
+        [
+            (header::CONTENT_TYPE, ExportFormat::Csv.content_type().to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"rebates_{}.csv\"", report.month)),
+        ],
+        report.to_csv()?,
+    )
+        .into_response())
+}
+
+/// 24시간 티커 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/v1/ticker",
+    tag = "market-data",
+    params(("symbol" = Option<String>, Query, description = "조회할 심볼 (생략 시 전 심볼)")),
+    responses(
+        (status = 200, description = "24시간 롤링 티커", body = TickerResponse),
+        (status = 404, description = "심볼 없음", body = ErrorResponse),
+    )
+)]
+pub async fn get_ticker(
+    State(state): State<ServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<TickerResponse> {
+    let now = chrono::Utc::now();
+    let now_secs = now.timestamp() as u64;
+    let mdp_guard = state.mdp.lock().await;
+
+    let tickers = match params.get("symbol") {
+        Some(symbol) => match mdp_guard.get_ticker(symbol, now_secs).await {
+            Some(ticker) => vec![ticker],
+            None => return Err(ApiError::symbol_not_found(symbol)),
+        },
+        None => mdp_guard.get_tickers(now_secs).await,
+    };
+
+    Ok(Json(TickerResponse {
+        timestamp: now.timestamp_millis() as u64,
+        tickers,
+    }))
+}
+
+/// 인덱스/공정 가격 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/v1/index",
+    tag = "market-data",
+    params(("symbol" = Option<String>, Query, description = "조회할 심볼 (생략 시 전 심볼)")),
+    responses(
+        (status = 200, description = "외부 거래소 시세 기반 인덱스 가격과 공정 가격", body = IndexPriceResponse),
+        (status = 404, description = "인덱스가 없는 심볼", body = ErrorResponse),
+    )
+)]
+pub async fn get_index_prices(
+    State(state): State<ServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<IndexPriceResponse> {
+    let prices = match params.get("symbol") {
+        Some(symbol) => match state.index_prices.get(symbol) {
+            Some(price) => vec![price],
+            None => return Err(ApiError::symbol_not_found(symbol)),
+        },
+        None => state.index_prices.all(),
+    };
+
+    Ok(Json(IndexPriceResponse {
+        timestamp: chrono::Utc::now().timestamp_millis() as u64,
+        prices,
+    }))
+}
+
+/// 펀딩 정산 이력 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/v1/funding/{symbol}",
+    tag = "market-data",
+    params(
+        ("symbol" = String, Path, description = "거래 심볼"),
+        ("limit" = Option<usize>, Query, description = "최대 개수 (기본 100, 최대 1000)"),
+    ),
+    responses(
+        (status = 200, description = "최근 펀딩 정산 (최신순)", body = FundingHistoryResponse),
+        (status = 404, description = "정산 대상이 아니고 이력도 없는 심볼", body = ErrorResponse),
+        (status = 500, description = "조회 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_funding_history(
+    State(state): State<ServerState>,
+    Path(symbol): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<FundingHistoryResponse> {
+    let limit = params
+        .get("limit")
+        .and_then(|l| l.parse::<usize>().ok())
+        .unwrap_or(100)
+        .clamp(1, 1000);
+
+    let enabled = state.funding.is_enabled(&symbol);
+    let settlements = state.funding.history(&symbol, limit).await?;
+    if !enabled && settlements.is_empty() {
+        return Err(ApiError::symbol_not_found(&symbol));
+    }
+
+    Ok(Json(FundingHistoryResponse { symbol, enabled, settlements }))
+}
+
+/// 만기 상품 목록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/v1/futures",
+    tag = "market-data",
+    responses(
+        (status = 200, description = "만기 상품 메타데이터와 상태 (만기순)", body = FuturesContractsResponse),
+    )
+)]
+pub async fn get_futures_contracts(State(state): State<ServerState>) -> ApiResult<FuturesContractsResponse> {
+    Ok(Json(FuturesContractsResponse { contracts: state.futures.statuses() }))
+}
+
+/// 자산 목록 및 보고 통화 환율 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/v1/assets",
+    tag = "market-data",
+    responses(
+        (status = 200, description = "등록 자산과 보고 통화 환율 (최근 체결가 기준)", body = AssetsResponse),
+    )
+)]
+pub async fn get_assets(State(state): State<ServerState>) -> ApiResult<AssetsResponse> {
+    let reporting_currency = state.currency.reporting_currency();
+    let assets = state
+        .currency
+        .registry()
+        .assets()
+        .into_iter()
+        .map(|asset| AssetData {
+            code: asset.code.clone(),
+            kind: asset.kind,
+            decimals: asset.decimals,
+            reporting_rate: state.currency.rate(&asset.code, reporting_currency).ok(),
+        })
+        .collect();
+
+    Ok(Json(AssetsResponse {
+        reporting_currency: reporting_currency.to_string(),
+        assets,
+    }))
+}
+
+/// 포트폴리오 평가 핸들러 (자산별 잔고를 보고 통화로 환산)
+#[utoipa::path(
+    get,
+    path = "/v1/portfolio/{client_id}",
+    tag = "accounts",
+    params(("client_id" = String, Path, description = "계정 ID")),
+    responses(
+        (status = 200, description = "보고 통화 기준 포트폴리오 평가", body = PortfolioResponse),
+        (status = 400, description = "client_id 형식 오류", body = ErrorResponse),
+        (status = 500, description = "잔고 조회 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_portfolio(
+    State(state): State<ServerState>,
+    auth: Option<AuthContext>,
+    Path(client_id): Path<String>,
+) -> ApiResult<PortfolioResponse> {
+    validate_client_id(&client_id)?;
+    authorize_client(&state, &auth, &client_id, Scope::Read)?;
+    let balances = BalanceRepository::new(state.db_pool.clone())
+        .find_by_client(&client_id)
+        .await
+        .map_err(|e| ApiError::new(ErrorCode::Internal, format!("잔고 조회 실패: {}", e)))?;
+
+    let reporting_currency = state.currency.reporting_currency();
+    let mut total_value = 0.0;
+    let mut unpriced_assets = Vec::new();
+    let holdings = balances
+        .into_iter()
+        .map(|balance| {
+            let reporting_rate = state.currency.rate(&balance.asset, reporting_currency).ok();
+            let value = state
+                .currency
+                .registry()
+                .asset(&balance.asset)
+                .zip(reporting_rate)
+                .map(|(asset, rate)| asset.to_major((balance.available + balance.locked) as f64) * rate);
+            match value {
+                Some(value) => total_value += value,
+                None => unpriced_assets.push(balance.asset.clone()),
+            }
+            PortfolioHolding {
+                asset: balance.asset,
+                available: balance.available,
+                locked: balance.locked,
+                reporting_rate,
+                value,
+            }
+        })
+        .collect();
+
+    Ok(Json(PortfolioResponse {
+        client_id,
+        reporting_currency: reporting_currency.to_string(),
+        total_value,
+        holdings,
+        unpriced_assets,
+    }))
+}
+
+/// 사용자 잔고 조회 핸들러
+///
+/// DB 잔고를 자산별로 돌려주고, 주문장에 남은 주문의 예약 금액을 자산별로 나눠 붙입니다.
+/// 잔고 행이 없어도 예약 금액이 있는 자산은 응답에 포함됩니다.
+#[utoipa::path(
+    get,
+    path = "/v1/user/{client_id}/balance",
+    tag = "accounts",
+    params(("client_id" = String, Path, description = "계정 ID")),
+    responses(
+        (status = 200, description = "자산별 잔고와 미체결 주문 예약 금액", body = UserBalanceResponse),
+        (status = 400, description = "client_id 형식 오류", body = ErrorResponse),
+        (status = 401, description = "인증 실패 (인증 설정 시)", body = ErrorResponse),
+        (status = 403, description = "다른 계정 또는 read 권한 없음", body = ErrorResponse),
+        (status = 500, description = "잔고 조회 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_user_balance(
+    State(state): State<ServerState>,
+    auth: Option<AuthContext>,
+    Path(client_id): Path<String>,
+) -> ApiResult<UserBalanceResponse> {
+    validate_client_id(&client_id)?;
+    authorize_client(&state, &auth, &client_id, Scope::Read)?;
+    let records = BalanceRepository::new(state.db_pool.clone())
+        .find_by_client(&client_id)
+        .await
+        .map_err(|e| ApiError::new(ErrorCode::Internal, format!("잔고 조회 실패: {}", e)))?;
+
+    let mut balances: BTreeMap<String, AssetBalanceData> = UserBalance::from_records(records)
+        .assets()
+        .iter()
+        .map(|(asset, balance)| {
+            (
+                asset.clone(),
+                AssetBalanceData {
+                    available: balance.available,
+                    locked: balance.locked,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect();
+
+    let engine_guard = state.engine.lock().await;
+    for order in engine_guard.get_open_orders(&client_id) {
+        let reservation = OrderReservation::for_order(state.currency.registry(), order)?;
+        let entry = balances.entry(reservation.asset).or_default();
+        entry.reserved += reservation.amount;
+        entry.reservations.push(OrderReservationData {
+            order_id: reservation.order_id,
+            symbol: reservation.symbol,
+            side: reservation.side,
+            price: reservation.price,
+            remaining_quantity: reservation.remaining_quantity,
+            amount: reservation.amount,
+        });
+    }
+
+    Ok(Json(UserBalanceResponse { client_id, balances }))
+}
+
+/// 계정 주문 기록 조회 핸들러 (태그/심볼 필터, 전략별 주문 추적)
+#[utoipa::path(
+    get,
+    path = "/v1/user/{client_id}/orders",
+    tag = "accounts",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("tag" = Option<String>, Query, description = "주문 태그 (생략 시 전체)"),
+        ("symbol" = Option<String>, Query, description = "거래 심볼 (생략 시 전 심볼)"),
+        ("limit" = Option<i64>, Query, description = "최대 개수 (기본 100, 최대 1000)"),
+    ),
+    responses(
+        (status = 200, description = "계정 주문 기록 (최신순)", body = UserOrdersResponse),
+        (status = 400, description = "client_id 또는 tag 형식 오류", body = ErrorResponse),
+        (status = 401, description = "인증 실패 (인증 설정 시)", body = ErrorResponse),
+        (status = 403, description = "다른 계정 또는 read 권한 없음", body = ErrorResponse),
+        (status = 500, description = "주문 조회 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_user_orders(
+    State(state): State<ServerState>,
+    auth: Option<AuthContext>,
+    Path(client_id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<UserOrdersResponse> {
+    validate_client_id(&client_id)?;
+    authorize_client(&state, &auth, &client_id, Scope::Read)?;
+    let tag = params.get("tag").map(String::as_str);
+    if let Some(tag) = tag {
+        validate_tag(tag)?;
+    }
+    let limit = params
+        .get("limit")
+        .and_then(|l| l.parse::<i64>().ok())
+        .unwrap_or(100)
+        .clamp(1, 1000);
+
+    let records = OrderRepository::new(state.db_pool.clone())
+        .find_by_client_filtered(&client_id, tag, params.get("symbol").map(String::as_str), limit)
+        .await
+        .map_err(|e| ApiError::new(ErrorCode::Internal, format!("주문 조회 실패: {}", e)))?;
+
+    let orders = records
+        .into_iter()
+        .map(|r| UserOrderData {
+            order_id: r.order_id,
+            symbol: r.symbol,
+            side: r.side,
+            order_type: r.order_type,
+            price: r.price,
+            quantity: r.quantity,
+            filled_quantity: r.filled_quantity,
+            status: r.status,
+            tag: r.tag,
+        })
+        .collect();
+
+    Ok(Json(UserOrdersResponse { client_id, orders }))
+}
+
+/// 분당 메시지 사용량 조회 구간 상한 (분)
+const MAX_USAGE_WINDOW_MINUTES: u64 = 24 * 60;
+
+/// `from`/`to` 쿼리 (밀리초) 파싱, 생략 시 `to`는 현재 시각, `from`은 `to - default_ms`
+fn parse_usage_window(
+    params: &HashMap<String, String>,
+    now_ms: u64,
+    default_ms: u64,
+) -> Result<(u64, u64), ApiError> {
+    let parse = |name: &str| -> Result<Option<u64>, ApiError> {
+        params
+            .get(name)
+            .map(|value| {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| ApiError::new(ErrorCode::InvalidRequest, format!("{}는 밀리초 정수여야 합니다: {}", name, value)))
+            })
+            .transpose()
+    };
+    let to = parse("to")?.unwrap_or(now_ms);
+    let from = parse("from")?.unwrap_or_else(|| to.saturating_sub(default_ms));
+    if from >= to {
+        return Err(ApiError::new(ErrorCode::InvalidRequest, "from은 to보다 작아야 합니다"));
+    }
+    Ok((from, to))
+}
+
+/// 계정 분당 메시지 사용량 조회 핸들러
+///
+/// 주문/취소/조회 메시지를 분 단위로 돌려주며 아직 DB에 저장하지 않은 현재 분도 포함합니다.
+#[utoipa::path(
+    get,
+    path = "/v1/usage/{client_id}",
+    tag = "account",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("from" = Option<u64>, Query, description = "구간 시작 (밀리초, 기본 to - 60분)"),
+        ("to" = Option<u64>, Query, description = "구간 끝 (밀리초, 기본 현재 시각)"),
+    ),
+    responses(
+        (status = 200, description = "분당 메시지 사용량", body = MessageUsageResponse),
+        (status = 400, description = "client_id 형식 오류 또는 잘못된 구간 (최대 24시간)", body = ErrorResponse),
+        (status = 401, description = "인증 실패 (인증 설정 시)", body = ErrorResponse),
+        (status = 403, description = "다른 계정 또는 read 권한 없음", body = ErrorResponse),
+        (status = 500, description = "사용량 조회 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_message_usage(
+    State(state): State<ServerState>,
+    auth: Option<AuthContext>,
+    Path(client_id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<MessageUsageResponse> {
+    validate_client_id(&client_id)?;
+    authorize_client(&state, &auth, &client_id, Scope::Read)?;
+
+    let usage = &state.message_usage;
+    let (from, to) = parse_usage_window(&params, usage.now_millis(), 60 * 60_000)?;
+    if to - from > MAX_USAGE_WINDOW_MINUTES * 60_000 {
+        return Err(ApiError::new(
+            ErrorCode::InvalidRequest,
+            format!("조회 구간은 최대 {}분입니다", MAX_USAGE_WINDOW_MINUTES),
+        ));
+    }
+    let minutes = usage.usage(&client_id, from, to).await?;
+    let totals = minutes.iter().fold(MessageCounts::default(), |mut totals, minute| {
+        totals.orders += minute.orders;
+        totals.cancels += minute.cancels;
+        totals.queries += minute.queries;
+        totals.rejected += minute.rejected;
+        totals
+    });
+
+    Ok(Json(MessageUsageResponse {
+        client_id,
+        soft_cap_per_minute: usage.config().soft_cap_per_minute,
+        hard_cap_per_minute: usage.config().hard_cap_per_minute,
+        from,
+        to,
+        totals,
+        minutes,
+    }))
+}
+
+/// 계정별 메시지 사용량 순위 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/usage",
+    tag = "admin",
+    params(
+        ("from" = Option<u64>, Query, description = "구간 시작 (밀리초, 기본 to - 24시간)"),
+        ("to" = Option<u64>, Query, description = "구간 끝 (밀리초, 기본 현재 시각)"),
+        ("limit" = Option<usize>, Query, description = "최대 계정 수 (기본 100, 최대 1000)"),
+    ),
+    responses(
+        (status = 200, description = "메시지 합계 내림차순 계정별 사용량", body = MessageUsageSummaryResponse),
+        (status = 400, description = "잘못된 구간", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 500, description = "집계 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_message_usage_summary(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<MessageUsageSummaryResponse> {
+    authorize_admin(&state, &headers)?;
+
+    let usage = &state.message_usage;
+    let (from, to) = parse_usage_window(&params, usage.now_millis(), 24 * 60 * 60_000)?;
+    let limit = params
+        .get("limit")
+        .and_then(|l| l.parse::<usize>().ok())
+        .unwrap_or(100)
+        .clamp(1, 1000);
+    let clients = usage.summary(from, to, limit).await?;
+
+    Ok(Json(MessageUsageSummaryResponse { from, to, clients }))
+}
+
+/// 성능 지표 추이 조회 핸들러 (관리자)
+///
+/// 시간별 REST 지연 백분위수, 오류율, 초당 주문 수를 돌려줍니다. 대시보드가 주 단위 추이를 그리는 데 씁니다.
+#[utoipa::path(
+    get,
+    path = "/v1/metrics/history",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("from" = Option<u64>, Query, description = "구간 시작 (밀리초, 기본 to - 7일)"),
+        ("to" = Option<u64>, Query, description = "구간 끝 (밀리초, 기본 현재 시각)"),
+    ),
+    responses(
+        (status = 200, description = "시간별 성능 지표 (시간순)", body = MetricsHistoryResponse),
+        (status = 400, description = "잘못된 구간 (최대 보관 기간)", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 500, description = "조회 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_metrics_history(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<MetricsHistoryResponse> {
+    authorize_admin(&state, &headers)?;
+
+    let history = &state.metrics_history;
+    let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+    let (from, to) = parse_usage_window(&params, now_ms, 7 * 24 * HOUR_MS)?;
+    let retention_days = history.config().retention_days;
+    if to - from > retention_days * 24 * HOUR_MS {
+        return Err(ApiError::new(
+            ErrorCode::InvalidRequest,
+            format!("조회 구간은 최대 {}일입니다", retention_days),
+        ));
+    }
+    let records = history
+        .history(from, to)
+        .await
+        .map_err(|e| ApiError::new(ErrorCode::Internal, format!("성능 지표 조회 실패: {}", e)))?;
+
+    let hours = records
+        .into_iter()
+        .map(|r| MetricsHourPoint {
+            hour_start: r.hour_start as u64,
+            requests: r.requests as u64,
+            errors: r.errors as u64,
+            error_rate_pct: (r.requests > 0).then(|| r.errors as f64 / r.requests as f64 * 100.0),
+            p50_latency_ms: r.p50_latency_ms,
+            p99_latency_ms: r.p99_latency_ms,
+            max_latency_ms: r.max_latency_ms,
+            orders: r.orders as u64,
+            orders_per_sec: r.orders as f64 / (HOUR_MS / 1000) as f64,
+        })
+        .collect();
+
+    Ok(Json(MetricsHistoryResponse { from, to, interval_ms: HOUR_MS, hours }))
+}
+
+/// 호가창 미시구조 지표 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/v1/market/{symbol}/microstructure",
+    tag = "market-data",
+    params(("symbol" = String, Path, description = "거래 심볼")),
+    responses(
+        (status = 200, description = "잔량 불균형, 가중 중간가, 스프레드, 범위별 유동성", body = MicrostructureResponse),
+        (status = 404, description = "심볼 없음", body = ErrorResponse),
+    )
+)]
+pub async fn get_microstructure(
+    State(state): State<ServerState>,
+    Path(symbol): Path<String>,
+) -> ApiResult<MicrostructureResponse> {
+    let engine_guard = state.engine.lock().await;
+
+    match engine_guard.get_microstructure(&symbol) {
+        Some(stats) => Ok(Json(stats)),
+        None => Err(ApiError::symbol_not_found(&symbol)),
+    }
+}
+
+/// 시장 충격 추정 핸들러
+///
+/// 요청 수량의 가상 시장가 주문이 현재 호가를 쓸어갈 때의 평균/최악 체결가와 총 체결 금액을 돌려줍니다.
+#[utoipa::path(
+    get,
+    path = "/v1/market/{symbol}/impact",
+    tag = "market-data",
+    params(
+        ("symbol" = String, Path, description = "거래 심볼"),
+        ("side" = String, Query, description = "주문 방향 (buy, sell)"),
+        ("quantity" = u64, Query, description = "가상 시장가 주문 수량"),
+    ),
+    responses(
+        (status = 200, description = "예상 평균/최악 체결가와 총 체결 금액", body = MarketImpactResponse),
+        (status = 400, description = "side 또는 quantity 오류", body = ErrorResponse),
+        (status = 404, description = "심볼 없음", body = ErrorResponse),
+    )
+)]
+pub async fn get_market_impact(
+    State(state): State<ServerState>,
+    Path(symbol): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<MarketImpactResponse> {
+    let side = match params.get("side").map(|s| s.to_ascii_lowercase()).as_deref() {
+        Some("buy") => Side::Buy,
+        Some("sell") => Side::Sell,
+        _ => return Err(ApiError::new(ErrorCode::InvalidRequest, "side는 buy 또는 sell이어야 합니다")),
+    };
+    let quantity = params
+        .get("quantity")
+        .and_then(|q| q.parse::<u64>().ok())
+        .filter(|&q| q > 0)
+        .ok_or_else(|| ApiError::new(ErrorCode::InvalidQuantity, "quantity는 0보다 큰 정수여야 합니다"))?;
+
+    let engine_guard = state.engine.lock().await;
+    match engine_guard.get_market_impact(&symbol, side, quantity) {
+        Some(impact) => Ok(Json(impact)),
+        None => Err(ApiError::symbol_not_found(&symbol)),
     }
 }
 
 /// 호가창 동기화 핸들러 (하이브리드 방식)
+#[utoipa::path(
+    get,
+    path = "/api/v1/sync/{symbol}",
+    tag = "market-data",
+    params(("symbol" = String, Path, description = "거래 심볼")),
+    responses(
+        (status = 200, description = "호가창 스냅샷", body = OrderBookSnapshot),
+        (status = 404, description = "심볼 없음", body = ErrorResponse),
+    )
+)]
 pub async fn sync_orderbook(
     State(state): State<ServerState>,
     Path(symbol): Path<String>,
-) -> Result<Json<OrderBookSnapshot>, (StatusCode, Json<ErrorResponse>)> {
+) -> ApiResult<OrderBookSnapshot> {
     let mut engine_guard = state.engine.lock().await;
     
     if let Some(snapshot) = engine_guard.handle_sync_request(&symbol) {
         Ok(Json(snapshot))
     } else {
-        Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: "SYMBOL_NOT_FOUND".to_string(),
-                message: format!("심볼 '{}'을 찾을 수 없습니다", symbol),
-            }),
-        ))
+        Err(ApiError::symbol_not_found(&symbol))
     }
-}
\ No newline at end of file
+}
+
+/// 관리자 인증 (`X-Admin-Token`), 감사 로그에 남길 관리자 이름(`X-Admin-User`) 반환
+pub(crate) fn authorize_admin(state: &ServerState, headers: &HeaderMap) -> Result<String, ApiError> {
+    let Some(expected) = state.admin_token.as_deref() else {
+        return Err(ApiError::new(
+            ErrorCode::ServiceUnavailable,
+            "관리자 API가 비활성화되어 있습니다 (XTRADER_ADMIN_TOKEN 미설정)",
+        ));
+    };
+    let token = headers.get("x-admin-token").and_then(|v| v.to_str().ok());
+    if token != Some(expected) {
+        return Err(ApiError::new(ErrorCode::Unauthorized, "관리자 토큰이 올바르지 않습니다"));
+    }
+
+    Ok(headers
+        .get("x-admin-user")
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| !v.is_empty())
+        .unwrap_or("admin")
+        .to_string())
+}
+
+/// 인증 서비스 (미설정이면 503)
+fn auth_service(state: &ServerState) -> Result<&Arc<AuthService>, ApiError> {
+    state.auth.as_ref().ok_or_else(|| AuthError::Disabled.into())
+}
+
+/// 계정 본인 요청인지 확인 (인증 설정 시 API 키 또는 액세스 토큰 필수)
+pub(crate) fn authorize_client(
+    state: &ServerState,
+    auth: &Option<AuthContext>,
+    client_id: &str,
+    scope: Scope,
+) -> Result<(), ApiError> {
+    match (&state.auth, auth) {
+        (None, _) => Ok(()),
+        (Some(_), None) => Err(AuthError::Missing.into()),
+        (Some(_), Some(auth)) => Ok(auth.authorize(client_id, scope)?),
+    }
+}
+
+/// 민감한 관리자 작업 인증 (관리자 토큰, TOTP 활성화 시 `X-Admin-TOTP` 코드 추가 확인)
+pub(crate) async fn authorize_sensitive_admin(
+    state: &ServerState,
+    headers: &HeaderMap,
+    operation: &str,
+) -> Result<String, ApiError> {
+    let actor = authorize_admin(state, headers)?;
+    if let Some(totp) = &state.admin_totp {
+        let code = headers.get(TOTP_HEADER).and_then(|v| v.to_str().ok());
+        totp.verify(&actor, code, operation).await?;
+    }
+    Ok(actor)
+}
+
+fn kyc_account_response(state: &ServerState, account: KycAccount) -> KycAccountResponse {
+    KycAccountResponse {
+        effective_order_limit: state.kyc.order_limit(&account),
+        client_id: account.client_id,
+        level: account.level,
+        status: account.status,
+        max_order_notional: account.max_order_notional,
+        reason: account.reason,
+        updated_by: account.updated_by,
+        updated_at: account.updated_at,
+    }
+}
+
+/// 계정 KYC 상태 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/kyc/{client_id}",
+    tag = "admin",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "KYC 상태 (미등록 계정은 미인증)", body = KycAccountResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_kyc_account(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(client_id): Path<String>,
+) -> ApiResult<KycAccountResponse> {
+    authorize_admin(&state, &headers)?;
+    validate_client_id(&client_id)?;
+
+    let account = state.kyc.account(&client_id).await;
+    Ok(Json(kyc_account_response(&state, account)))
+}
+
+/// 계정 KYC 상태 변경 핸들러 (관리자, 감사 로그 기록)
+#[utoipa::path(
+    put,
+    path = "/v1/admin/kyc/{client_id}",
+    tag = "admin",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "변경자 (감사 로그, 기본 admin)"),
+    ),
+    request_body = KycUpdateRequest,
+    responses(
+        (status = 200, description = "변경된 KYC 상태", body = KycAccountResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn update_kyc_account(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(client_id): Path<String>,
+    payload: Result<Json<KycUpdateRequest>, JsonRejection>,
+) -> ApiResult<KycAccountResponse> {
+    let actor = authorize_admin(&state, &headers)?;
+    validate_client_id(&client_id)?;
+    let Json(payload) = payload?;
+
+    let update = KycUpdate {
+        level: payload.level,
+        status: payload.status,
+        max_order_notional: payload.max_order_notional,
+        reason: payload.reason,
+    };
+    let account = state.kyc.update(&client_id, update, &actor).await?;
+    Ok(Json(kyc_account_response(&state, account)))
+}
+
+/// 계정 수수료 등급 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/fees/{client_id}",
+    tag = "admin",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("limit" = Option<i64>, Query, description = "최대 이력 수 (기본 100, 최대 1000)"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "현재 등급과 등급 변경 이력 (최신순)", body = FeeTierResponse),
+        (status = 400, description = "client_id 형식 오류", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 500, description = "이력 조회 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_fee_tier(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(client_id): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<FeeTierResponse> {
+    authorize_admin(&state, &headers)?;
+    validate_client_id(&client_id)?;
+    let limit = params
+        .get("limit")
+        .and_then(|l| l.parse::<i64>().ok())
+        .unwrap_or(100)
+        .clamp(1, 1000);
+
+    let history = state.fees.history(&client_id, limit).await?;
+    Ok(Json(FeeTierResponse {
+        current: state.fees.client_tier(&client_id),
+        history,
+    }))
+}
+
+/// 계정 KYC 변경 이력 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/kyc/{client_id}/history",
+    tag = "admin",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "KYC 변경 이력 (최신순)", body = KycHistoryResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_kyc_history(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(client_id): Path<String>,
+) -> ApiResult<KycHistoryResponse> {
+    authorize_admin(&state, &headers)?;
+    validate_client_id(&client_id)?;
+
+    let changes = state
+        .kyc
+        .history(&client_id)
+        .await?
+        .into_iter()
+        .map(|change| KycChangeData {
+            from_level: change.before.level,
+            from_status: change.before.status,
+            to_level: change.after.level,
+            to_status: change.after.status,
+            max_order_notional: change.after.max_order_notional,
+            reason: change.after.reason,
+            updated_by: change.after.updated_by,
+            updated_at: change.after.updated_at,
+        })
+        .collect();
+
+    Ok(Json(KycHistoryResponse { client_id, changes }))
+}
+
+/// 발동 중인 킬 스위치 목록 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/kill-switch",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "발동 중인 킬 스위치 (종류, 대상순)", body = KillSwitchListResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_kill_switches(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<KillSwitchListResponse> {
+    authorize_admin(&state, &headers)?;
+
+    Ok(Json(KillSwitchListResponse {
+        kill_switches: state.kill_switch.entries(),
+    }))
+}
+
+/// 계정 차단 핸들러 (관리자, 감사 로그 기록)
+///
+/// 차단 즉시 신규 주문을 거부하고, 미체결 주문은 취소 레인으로 취소를 요청합니다.
+#[utoipa::path(
+    put,
+    path = "/v1/admin/kill-switch/clients/{client_id}",
+    tag = "admin",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "2단계 인증 코드 (TOTP 활성화 시 필수)"),
+        ("X-Admin-User" = Option<String>, Header, description = "발동자 (감사 로그, 기본 admin)"),
+    ),
+    request_body = KillSwitchRequest,
+    responses(
+        (status = 200, description = "발동된 킬 스위치와 취소 요청한 주문", body = KillSwitchResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+        (status = 503, description = "취소 큐 포화 (다시 요청하면 남은 주문 취소)", body = ErrorResponse),
+    )
+)]
+pub async fn block_client(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(client_id): Path<String>,
+    payload: Result<Json<KillSwitchRequest>, JsonRejection>,
+) -> ApiResult<KillSwitchResponse> {
+    let actor = authorize_sensitive_admin(&state, &headers, "client_block").await?;
+    validate_client_id(&client_id)?;
+    let Json(payload) = payload?;
+
+    let kill_switch = state
+        .kill_switch
+        .activate(KillSwitchScope::Client, &client_id, payload.reason, &actor)
+        .await?;
+
+    // 차단 이후 접수된 주문은 엔진에서 거부되므로 현재 미체결 주문만 취소
+    let canceled_order_ids = cancel_open_orders(&state, &client_id).await?;
+
+    Ok(Json(KillSwitchResponse { kill_switch, canceled_order_ids }))
+}
+
+/// 계정의 현재 미체결 주문을 취소 레인으로 취소 요청 (요청한 주문 ID 반환)
+async fn cancel_open_orders(state: &ServerState, client_id: &str) -> Result<Vec<String>, ApiError> {
+    let open_order_ids: Vec<String> = {
+        let engine_guard = state.engine.lock().await;
+        engine_guard.get_open_orders(client_id).into_iter().map(|order| order.id.clone()).collect()
+    };
+    let mut canceled_order_ids = Vec::with_capacity(open_order_ids.len());
+    for order_id in open_order_ids {
+        state
+            .global_sequence
+            .submit(Order::new_cancel(order_id.clone()), &state.cancel_tx)?;
+        canceled_order_ids.push(order_id);
+    }
+    Ok(canceled_order_ids)
+}
+
+/// 계정 미체결 주문 일괄 취소 핸들러 (관리자)
+///
+/// 계정을 차단하지 않고 지금 주문장에 있는 주문만 취소합니다.
+#[utoipa::path(
+    post,
+    path = "/v1/admin/clients/{client_id}/cancel-orders",
+    tag = "admin",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "2단계 인증 코드 (TOTP 활성화 시 필수)"),
+    ),
+    responses(
+        (status = 200, description = "취소 요청한 주문", body = CancelClientOrdersResponse),
+        (status = 400, description = "client_id 형식 오류", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+        (status = 503, description = "취소 큐 포화 (다시 요청하면 남은 주문 취소)", body = ErrorResponse),
+    )
+)]
+pub async fn cancel_client_orders(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(client_id): Path<String>,
+) -> ApiResult<CancelClientOrdersResponse> {
+    authorize_sensitive_admin(&state, &headers, "client_cancel_orders").await?;
+    validate_client_id(&client_id)?;
+
+    let canceled_order_ids = cancel_open_orders(&state, &client_id).await?;
+    Ok(Json(CancelClientOrdersResponse { client_id, canceled_order_ids }))
+}
+
+/// 심볼 목록 조회 핸들러 (관리자, 거래 중단 여부와 주문장 요약)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/symbols",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "심볼별 거래 중단 여부, 미체결 주문 수, 최우선 호가", body = AdminSymbolsResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_admin_symbols(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<AdminSymbolsResponse> {
+    authorize_admin(&state, &headers)?;
+
+    let mut symbols: Vec<AdminSymbolData> = {
+        let engine_guard = state.engine.lock().await;
+        engine_guard
+            .get_order_books()
+            .iter()
+            .map(|(symbol, book)| AdminSymbolData {
+                symbol: symbol.clone(),
+                halted: state.kill_switch.is_symbol_halted(symbol),
+                open_orders: book.order_count(),
+                best_bid: book.get_best_bid().map(|(price, _)| price),
+                best_ask: book.get_best_ask().map(|(price, _)| price),
+            })
+            .collect()
+    };
+    symbols.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+    Ok(Json(AdminSymbolsResponse { symbols }))
+}
+
+/// 매칭 엔진 통계 조회 핸들러 (관리자)
+///
+/// 심볼별 남은 주문 수, 상위 N 레벨 잔량 합, 최근 1분 체결 속도와 평균 체결 수량을 돌려줍니다.
+#[utoipa::path(
+    get,
+    path = "/v1/admin/engine/stats",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("levels" = Option<usize>, Query, description = "잔량 합에 쓸 호가 레벨 수 (기본 5, 최대 100)"),
+    ),
+    responses(
+        (status = 200, description = "심볼별 매칭 통계 (심볼순)", body = EngineStatsResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_engine_stats(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<EngineStatsResponse> {
+    authorize_admin(&state, &headers)?;
+    let levels = params
+        .get("levels")
+        .and_then(|l| l.parse::<usize>().ok())
+        .unwrap_or(5)
+        .clamp(1, 100);
+
+    let engine_guard = state.engine.lock().await;
+    Ok(Json(engine_guard.get_engine_stats(levels)))
+}
+
+/// 계정 차단 해제 핸들러 (관리자, 감사 로그 기록)
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/kill-switch/clients/{client_id}",
+    tag = "admin",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "해제자 (감사 로그, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "해제된 킬 스위치", body = KillSwitchResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "차단되지 않은 계정", body = ErrorResponse),
+    )
+)]
+pub async fn unblock_client(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(client_id): Path<String>,
+) -> ApiResult<KillSwitchResponse> {
+    let actor = authorize_admin(&state, &headers)?;
+    validate_client_id(&client_id)?;
+
+    release_kill_switch(&state, KillSwitchScope::Client, &client_id, &actor).await
+}
+
+/// 심볼 거래 중단 핸들러 (관리자, 감사 로그 기록)
+///
+/// 신규 주문만 거부하고 주문장에 남은 주문과 취소 요청은 그대로 둡니다.
+#[utoipa::path(
+    put,
+    path = "/v1/admin/kill-switch/symbols/{symbol}",
+    tag = "admin",
+    params(
+        ("symbol" = String, Path, description = "거래 심볼"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "2단계 인증 코드 (TOTP 활성화 시 필수)"),
+        ("X-Admin-User" = Option<String>, Header, description = "발동자 (감사 로그, 기본 admin)"),
+    ),
+    request_body = KillSwitchRequest,
+    responses(
+        (status = 200, description = "발동된 킬 스위치", body = KillSwitchResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+        (status = 404, description = "심볼 없음", body = ErrorResponse),
+    )
+)]
+pub async fn halt_symbol(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(symbol): Path<String>,
+    payload: Result<Json<KillSwitchRequest>, JsonRejection>,
+) -> ApiResult<KillSwitchResponse> {
+    let actor = authorize_sensitive_admin(&state, &headers, "symbol_halt").await?;
+    let Json(payload) = payload?;
+    if state.engine.lock().await.get_order_book_snapshot(&symbol, 0).is_none() {
+        return Err(ApiError::symbol_not_found(&symbol));
+    }
+
+    let kill_switch = state
+        .kill_switch
+        .activate(KillSwitchScope::Symbol, &symbol, payload.reason, &actor)
+        .await?;
+    Ok(Json(KillSwitchResponse {
+        kill_switch,
+        canceled_order_ids: Vec::new(),
+    }))
+}
+
+/// 심볼 거래 재개 핸들러 (관리자, 감사 로그 기록)
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/kill-switch/symbols/{symbol}",
+    tag = "admin",
+    params(
+        ("symbol" = String, Path, description = "거래 심볼"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "해제자 (감사 로그, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "해제된 킬 스위치", body = KillSwitchResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "거래 중단되지 않은 심볼", body = ErrorResponse),
+    )
+)]
+pub async fn resume_symbol(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(symbol): Path<String>,
+) -> ApiResult<KillSwitchResponse> {
+    let actor = authorize_admin(&state, &headers)?;
+
+    release_kill_switch(&state, KillSwitchScope::Symbol, &symbol, &actor).await
+}
+
+async fn release_kill_switch(
+    state: &ServerState,
+    scope: KillSwitchScope,
+    target: &str,
+    actor: &str,
+) -> ApiResult<KillSwitchResponse> {
+    match state.kill_switch.release(scope, target, actor).await? {
+        Some(kill_switch) => Ok(Json(KillSwitchResponse {
+            kill_switch,
+            canceled_order_ids: Vec::new(),
+        })),
+        None => Err(ApiError::new(
+            ErrorCode::KillSwitchNotFound,
+            format!("'{}'에 발동 중인 킬 스위치가 없습니다", target),
+        )),
+    }
+}
+
+async fn risk_profile_response(state: &ServerState, client_id: String) -> RiskProfileResponse {
+    let open_orders = state.engine.lock().await.get_open_orders(&client_id).len();
+    RiskProfileResponse {
+        client_limits: state.risk.client_limits(&client_id),
+        effective_limits: state.risk.effective_limits(&client_id),
+        open_orders,
+        positions: state.risk.positions(&client_id),
+        client_id,
+    }
+}
+
+/// 계정 리스크 한도 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/risk/{client_id}",
+    tag = "admin",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "적용 한도, 미체결 주문 수, 심볼별 순포지션", body = RiskProfileResponse),
+        (status = 400, description = "client_id 형식 오류", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_risk_limits(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(client_id): Path<String>,
+) -> ApiResult<RiskProfileResponse> {
+    authorize_admin(&state, &headers)?;
+    validate_client_id(&client_id)?;
+
+    Ok(Json(risk_profile_response(&state, client_id).await))
+}
+
+/// 계정 리스크 한도 설정 핸들러 (관리자, 감사 로그 기록)
+///
+/// 요청에서 생략한 항목은 서버 기본값을 따릅니다. 이미 접수된 주문에는 영향을 주지 않습니다.
+#[utoipa::path(
+    put,
+    path = "/v1/admin/risk/{client_id}",
+    tag = "admin",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "변경자 (감사 로그, 기본 admin)"),
+    ),
+    request_body = RiskLimitsRequest,
+    responses(
+        (status = 200, description = "변경 후 한도와 사용량", body = RiskProfileResponse),
+        (status = 400, description = "잘못된 요청 또는 지원하지 않는 심볼", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn update_risk_limits(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(client_id): Path<String>,
+    payload: Result<Json<RiskLimitsRequest>, JsonRejection>,
+) -> ApiResult<RiskProfileResponse> {
+    let actor = authorize_admin(&state, &headers)?;
+    validate_client_id(&client_id)?;
+    let Json(payload) = payload?;
+
+    {
+        let engine_guard = state.engine.lock().await;
+        if let Some(symbol) = payload.max_net_position.keys().find(|symbol| !engine_guard.get_order_books().contains_key(*symbol)) {
+            return Err(ApiError::new(ErrorCode::InvalidSymbol, format!("지원하지 않는 심볼입니다: {}", symbol)));
+        }
+    }
+
+    let limits = RiskLimits {
+        max_open_orders: payload.max_open_orders,
+        max_order_notional: payload.max_order_notional,
+        max_net_position: payload.max_net_position,
+    };
+    state.risk.update(&client_id, limits, payload.reason, &actor).await?;
+    Ok(Json(risk_profile_response(&state, client_id).await))
+}
+
+/// 계정 리스크 한도 삭제 핸들러 (관리자, 서버 기본값으로 복원)
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/risk/{client_id}",
+    tag = "admin",
+    params(
+        ("client_id" = String, Path, description = "계정 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "변경자 (감사 로그, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "기본값 복원 후 한도와 사용량", body = RiskProfileResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "계정별 한도 설정 없음", body = ErrorResponse),
+    )
+)]
+pub async fn reset_risk_limits(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(client_id): Path<String>,
+) -> ApiResult<RiskProfileResponse> {
+    let actor = authorize_admin(&state, &headers)?;
+    validate_client_id(&client_id)?;
+
+    if state.risk.reset(&client_id, &actor).await?.is_none() {
+        return Err(ApiError::new(
+            ErrorCode::RiskLimitNotFound,
+            format!("'{}'에 설정된 리스크 한도가 없습니다", client_id),
+        ));
+    }
+    Ok(Json(risk_profile_response(&state, client_id).await))
+}
+
+fn audit_error(e: sqlx::Error) -> ApiError {
+    ApiError::new(ErrorCode::Internal, format!("감사 로그 기록 실패: {}", e))
+}
+
+/// 알림 라우팅 규칙 목록 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/notifications/rules",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "알림 라우팅 규칙 목록", body = NotificationRulesResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_notification_rules(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<NotificationRulesResponse> {
+    authorize_admin(&state, &headers)?;
+
+    let rules = state.notifications.routing_rules().await;
+    Ok(Json(NotificationRulesResponse { rules }))
+}
+
+/// 알림 라우팅 규칙 등록/변경 핸들러 (관리자, 감사 로그 기록)
+#[utoipa::path(
+    put,
+    path = "/v1/admin/notifications/rules/{rule_id}",
+    tag = "admin",
+    params(
+        ("rule_id" = String, Path, description = "규칙 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "변경자 (감사 로그, 기본 admin)"),
+    ),
+    request_body = RoutingRule,
+    responses(
+        (status = 200, description = "저장된 규칙 (즉시 적용)", body = RoutingRule),
+        (status = 400, description = "잘못된 규칙", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn put_notification_rule(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(rule_id): Path<String>,
+    payload: Result<Json<RoutingRule>, JsonRejection>,
+) -> ApiResult<RoutingRule> {
+    let actor = authorize_admin(&state, &headers)?;
+    let Json(mut rule) = payload?;
+    rule.id = rule_id;
+    rule.validate()?;
+
+    let updated_at = chrono::Utc::now().timestamp_millis();
+    NotificationRoutingRuleRepository::new(state.db_pool.clone())
+        .upsert(&rule.to_record(&actor, updated_at))
+        .await
+        .map_err(|e| ApiError::new(ErrorCode::Internal, format!("알림 라우팅 규칙 저장 실패: {}", e)))?;
+    let details = serde_json::json!({ "actor": actor, "rule": &rule }).to_string();
+    AuditLogRepository::new(state.db_pool.clone())
+        .log(RULE_UPDATED_AUDIT_EVENT, RULE_AUDIT_ENTITY, &rule.id, Some(&details))
+        .await
+        .map_err(audit_error)?;
+
+    state.notifications.upsert_routing_rule(rule.clone()).await?;
+    Ok(Json(rule))
+}
+
+/// 알림 라우팅 규칙 삭제 핸들러 (관리자, 감사 로그 기록)
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/notifications/rules/{rule_id}",
+    tag = "admin",
+    params(
+        ("rule_id" = String, Path, description = "규칙 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "변경자 (감사 로그, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "삭제된 규칙 목록 (남은 규칙)", body = NotificationRulesResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "규칙 없음", body = ErrorResponse),
+    )
+)]
+pub async fn delete_notification_rule(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(rule_id): Path<String>,
+) -> ApiResult<NotificationRulesResponse> {
+    let actor = authorize_admin(&state, &headers)?;
+
+    let deleted = NotificationRoutingRuleRepository::new(state.db_pool.clone())
+        .delete(&rule_id)
+        .await
+        .map_err(|e| ApiError::new(ErrorCode::Internal, format!("알림 라우팅 규칙 삭제 실패: {}", e)))?;
+    let removed = state.notifications.remove_routing_rule(&rule_id).await;
+    if !deleted && !removed {
+        return Err(ApiError::new(
+            ErrorCode::NotificationRuleNotFound,
+            format!("알림 라우팅 규칙을 찾을 수 없습니다: {}", rule_id),
+        ));
+    }
+
+    let details = serde_json::json!({ "actor": actor }).to_string();
+    AuditLogRepository::new(state.db_pool.clone())
+        .log(RULE_DELETED_AUDIT_EVENT, RULE_AUDIT_ENTITY, &rule_id, Some(&details))
+        .await
+        .map_err(audit_error)?;
+
+    let rules = state.notifications.routing_rules().await;
+    Ok(Json(NotificationRulesResponse { rules }))
+}
+
+/// 확인 대기 중인 에스컬레이션 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/notifications/escalations",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "확인 대기 중인 에스컬레이션 (예정 시각순)", body = PendingEscalationsResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_pending_escalations(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<PendingEscalationsResponse> {
+    authorize_admin(&state, &headers)?;
+
+    let escalations = state.notifications.pending_escalations().await;
+    Ok(Json(PendingEscalationsResponse { escalations }))
+}
+
+/// 인시던트 목록 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/incidents",
+    tag = "admin",
+    params(
+        ("status" = Option<String>, Query, description = "active(기본, 미해결), open, acknowledged, resolved"),
+        ("limit" = Option<i64>, Query, description = "최대 개수 (기본 100, 최대 1000)"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "인시던트 목록 (최신순)", body = IncidentsResponse),
+        (status = 400, description = "잘못된 상태", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_incidents(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<IncidentsResponse> {
+    authorize_admin(&state, &headers)?;
+    let limit = params
+        .get("limit")
+        .and_then(|l| l.parse::<i64>().ok())
+        .unwrap_or(100)
+        .clamp(1, 1000);
+    let statuses = match params.get("status").map(String::as_str).unwrap_or("active") {
+        "active" => vec![IncidentStatus::Open, IncidentStatus::Acknowledged],
+        name => vec![IncidentStatus::from_name(name).ok_or_else(|| {
+            ApiError::new(ErrorCode::InvalidRequest, format!("지원하지 않는 인시던트 상태입니다: {}", name))
+        })?],
+    };
+
+    let incidents = state.incidents.list(&statuses, limit).await?;
+    Ok(Json(IncidentsResponse { incidents }))
+}
+
+/// 인시던트 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/incidents/{incident_id}",
+    tag = "admin",
+    params(
+        ("incident_id" = String, Path, description = "인시던트 ID (알림 ID)"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "인시던트 (메모 포함)", body = Incident),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "인시던트 없음", body = ErrorResponse),
+    )
+)]
+pub async fn get_incident(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(incident_id): Path<String>,
+) -> ApiResult<Incident> {
+    authorize_admin(&state, &headers)?;
+
+    Ok(Json(state.incidents.get(&incident_id).await?))
+}
+
+/// 인시던트 확인 핸들러 (관리자, 에스컬레이션 중단)
+#[utoipa::path(
+    post,
+    path = "/v1/admin/incidents/{incident_id}/ack",
+    tag = "admin",
+    params(
+        ("incident_id" = String, Path, description = "인시던트 ID (알림 ID)"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "확인자 (감사 로그, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "확인된 인시던트", body = Incident),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "인시던트 없음", body = ErrorResponse),
+        (status = 409, description = "이미 해결된 인시던트", body = ErrorResponse),
+    )
+)]
+pub async fn acknowledge_incident(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(incident_id): Path<String>,
+) -> ApiResult<Incident> {
+    let actor = authorize_admin(&state, &headers)?;
+
+    let incident = state.incidents.acknowledge(&incident_id, &actor).await?;
+    state.notifications.acknowledge(&incident_id, &actor).await;
+    Ok(Json(incident))
+}
+
+/// 인시던트 메모 추가 핸들러 (관리자)
+#[utoipa::path(
+    post,
+    path = "/v1/admin/incidents/{incident_id}/notes",
+    tag = "admin",
+    params(
+        ("incident_id" = String, Path, description = "인시던트 ID (알림 ID)"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "작성자 (기본 admin)"),
+    ),
+    request_body = IncidentNoteRequest,
+    responses(
+        (status = 200, description = "메모가 추가된 인시던트", body = Incident),
+        (status = 400, description = "빈 메모", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "인시던트 없음", body = ErrorResponse),
+    )
+)]
+pub async fn add_incident_note(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(incident_id): Path<String>,
+    payload: Result<Json<IncidentNoteRequest>, JsonRejection>,
+) -> ApiResult<Incident> {
+    let actor = authorize_admin(&state, &headers)?;
+    let Json(payload) = payload?;
+
+    Ok(Json(state.incidents.add_note(&incident_id, &actor, &payload.note).await?))
+}
+
+/// 인시던트 해결 핸들러 (관리자, 해결 내용은 메모로 기록)
+#[utoipa::path(
+    post,
+    path = "/v1/admin/incidents/{incident_id}/resolve",
+    tag = "admin",
+    params(
+        ("incident_id" = String, Path, description = "인시던트 ID (알림 ID)"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "해결자 (기본 admin)"),
+    ),
+    request_body = IncidentResolveRequest,
+    responses(
+        (status = 200, description = "해결된 인시던트", body = Incident),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "인시던트 없음", body = ErrorResponse),
+        (status = 409, description = "이미 해결된 인시던트", body = ErrorResponse),
+    )
+)]
+pub async fn resolve_incident(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(incident_id): Path<String>,
+    payload: Result<Json<IncidentResolveRequest>, JsonRejection>,
+) -> ApiResult<Incident> {
+    let actor = authorize_admin(&state, &headers)?;
+    let Json(payload) = payload?;
+
+    let incident = state.incidents.resolve(&incident_id, &actor, payload.resolution.as_deref()).await?;
+    state.notifications.acknowledge(&incident_id, &actor).await;
+    Ok(Json(incident))
+}
+
+/// 엔진 복제 상태 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/replication",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "역할, 적용 순번, 복제 지연", body = ReplicationStatus),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_replication_status(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<ReplicationStatus> {
+    authorize_admin(&state, &headers)?;
+    Ok(Json(state.replication.status()))
+}
+
+/// 대기 인스턴스 승격 핸들러 (관리자, 감사 로그 기록)
+///
+/// 복제를 멈추고 주문을 받기 시작합니다. 기존 주 인스턴스는 먼저 중단되어 있어야 합니다.
+#[utoipa::path(
+    post,
+    path = "/v1/admin/replication/promote",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "2단계 인증 코드 (TOTP 활성화 시 필수)"),
+        ("X-Admin-User" = Option<String>, Header, description = "승격 실행자 (감사 로그, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "승격 후 복제 상태", body = ReplicationStatus),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+        (status = 409, description = "이미 주 인스턴스", body = ErrorResponse),
+    )
+)]
+pub async fn promote_replica(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<ReplicationStatus> {
+    let actor = authorize_sensitive_admin(&state, &headers, "replica_promotion").await?;
+
+    let status = state.replication.promote()?;
+    let details = serde_json::json!({
+        "actor": actor,
+        "primary_addr": status.primary_addr,
+        "applied_seq": status.applied_seq,
+        "lag_entries_at_promotion": status.lag_entries,
+    })
+    .to_string();
+    AuditLogRepository::new(state.db_pool.clone())
+        .log(PROMOTION_AUDIT_EVENT, PROMOTION_AUDIT_ENTITY, "matching_engine", Some(&details))
+        .await
+        .map_err(audit_error)?;
+
+    Ok(Json(status))
+}
+
+/// DB 백업 생성 핸들러 (관리자)
+///
+/// 비동기 커밋 큐를 비운 뒤 SQLite 백업 API로 DB 전체를 복사합니다.
+/// 메타데이터의 저널 순번은 복원 후 복제 저널을 이어 받을 위치입니다.
+#[utoipa::path(
+    post,
+    path = "/v1/admin/db/backups",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "생성된 백업", body = BackupMetadata),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 503, description = "백업 디렉터리 미설정", body = ErrorResponse),
+    )
+)]
+pub async fn create_db_backup(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<BackupMetadata> {
+    authorize_admin(&state, &headers)?;
+    let backups = backup_manager(&state)?;
+    // 큐를 비우기 전에 읽어야 이 순번까지의 주문이 백업에 들어감
+    let journal_seq = state.replication.journal().head_seq();
+    Ok(Json(backups.create(journal_seq).await?))
+}
+
+/// DB 백업 목록 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/db/backups",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "백업 목록 (최신순)", body = DbBackupsResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 503, description = "백업 디렉터리 미설정", body = ErrorResponse),
+    )
+)]
+pub async fn get_db_backups(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<DbBackupsResponse> {
+    authorize_admin(&state, &headers)?;
+    let backups = backup_manager(&state)?;
+    Ok(Json(DbBackupsResponse { dir: backups.dir().display().to_string(), backups: backups.list().await? }))
+}
+
+fn backup_manager(state: &ServerState) -> Result<&Arc<BackupManager>, ApiError> {
+    state.backups.as_ref().ok_or_else(|| {
+        ApiError::new(ErrorCode::ServiceUnavailable, "DB 백업이 비활성화되어 있습니다 (XTRADER_BACKUP_DIR 미설정)")
+    })
+}
+
+/// 부분 복구 작업 시작 핸들러 (관리자)
+///
+/// MQ 종류, 토픽, 심볼, 백업 시각 범위에 맞는 백업 메시지만 재발행합니다.
+#[utoipa::path(
+    post,
+    path = "/v1/admin/recovery/jobs",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "2단계 인증 코드 (TOTP 활성화 시 필수)"),
+    ),
+    request_body = RecoveryJobRequest,
+    responses(
+        (status = 200, description = "시작된 복구 작업 (대상 메시지 수 포함)", body = RecoveryJob),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn start_recovery_job(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    payload: Result<Json<RecoveryJobRequest>, JsonRejection>,
+) -> ApiResult<RecoveryJob> {
+    authorize_sensitive_admin(&state, &headers, "mq_recovery").await?;
+    let Json(payload) = payload?;
+
+    let since_ms = match (payload.since_ms, payload.within_secs) {
+        (Some(_), Some(_)) => {
+            return Err(ApiError::new(
+                ErrorCode::InvalidRequest,
+                "since_ms와 within_secs는 함께 지정할 수 없습니다",
+            ))
+        }
+        (Some(since), None) => Some(since),
+        (None, Some(secs)) => Some((chrono::Utc::now().timestamp_millis() as u64).saturating_sub(secs * 1000)),
+        (None, None) => None,
+    };
+    let filter = RecoveryFilter {
+        mq_type: payload.mq_type,
+        topic_stream: payload.topic_stream,
+        symbol: payload.symbol,
+        since_ms,
+        until_ms: payload.until_ms,
+    };
+
+    Ok(Json(state.recovery.start_job(filter).await?))
+}
+
+/// 부분 복구 작업 목록 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/recovery/jobs",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "복구 작업 목록 (최신순)", body = RecoveryJobsResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_recovery_jobs(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<RecoveryJobsResponse> {
+    authorize_admin(&state, &headers)?;
+    Ok(Json(RecoveryJobsResponse { jobs: state.recovery.list_jobs().await }))
+}
+
+/// 부분 복구 작업 진행 상황 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/recovery/jobs/{job_id}",
+    tag = "admin",
+    params(
+        ("job_id" = String, Path, description = "복구 작업 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "진행률, 처리 속도, 예상 남은 시간", body = RecoveryJob),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "복구 작업 없음", body = ErrorResponse),
+    )
+)]
+pub async fn get_recovery_job(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(job_id): Path<String>,
+) -> ApiResult<RecoveryJob> {
+    authorize_admin(&state, &headers)?;
+    Ok(Json(state.recovery.get_job(&job_id).await?))
+}
+
+/// 부분 복구 작업 취소 핸들러 (관리자, 진행 중이던 배치는 마친 뒤 중단)
+#[utoipa::path(
+    post,
+    path = "/v1/admin/recovery/jobs/{job_id}/cancel",
+    tag = "admin",
+    params(
+        ("job_id" = String, Path, description = "복구 작업 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "취소된 복구 작업", body = RecoveryJob),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "복구 작업 없음", body = ErrorResponse),
+        (status = 409, description = "이미 끝난 복구 작업", body = ErrorResponse),
+    )
+)]
+pub async fn cancel_recovery_job(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(job_id): Path<String>,
+) -> ApiResult<RecoveryJob> {
+    authorize_admin(&state, &headers)?;
+    Ok(Json(state.recovery.cancel_job(&job_id).await?))
+}
+
+/// DLQ 격리 메시지 목록 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/dlq/messages",
+    tag = "admin",
+    params(
+        ("status" = Option<String>, Query, description = "quarantined(기본), requeued, discarded"),
+        ("limit" = Option<i64>, Query, description = "최대 개수 (기본 100, 최대 1000)"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "격리 메시지 목록 (최신순)", body = QuarantinedMessagesResponse),
+        (status = 400, description = "잘못된 상태", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+    )
+)]
+pub async fn get_quarantined_messages(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Query(params): Query<HashMap<String, String>>,
+) -> ApiResult<QuarantinedMessagesResponse> {
+    authorize_admin(&state, &headers)?;
+    let limit = params
+        .get("limit")
+        .and_then(|l| l.parse::<i64>().ok())
+        .unwrap_or(100)
+        .clamp(1, 1000);
+    let name = params.get("status").map(String::as_str).unwrap_or("quarantined");
+    let status = QuarantineStatus::from_name(name).ok_or_else(|| {
+        ApiError::new(ErrorCode::InvalidRequest, format!("지원하지 않는 격리 메시지 상태입니다: {}", name))
+    })?;
+
+    let messages = state.dead_letters.list(status, limit).await?;
+    Ok(Json(QuarantinedMessagesResponse { messages }))
+}
+
+/// DLQ 격리 메시지 조회 핸들러 (관리자, 원본 본문 포함)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/dlq/messages/{quarantine_id}",
+    tag = "admin",
+    params(
+        ("quarantine_id" = String, Path, description = "격리 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "격리 메시지", body = QuarantinedMessage),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "격리 메시지 없음", body = ErrorResponse),
+    )
+)]
+pub async fn get_quarantined_message(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(quarantine_id): Path<String>,
+) -> ApiResult<QuarantinedMessage> {
+    authorize_admin(&state, &headers)?;
+
+    Ok(Json(state.dead_letters.get(&quarantine_id).await?))
+}
+
+/// DLQ 격리 메시지 재발행 핸들러 (관리자, 원래 라우팅 키로 발행)
+#[utoipa::path(
+    post,
+    path = "/v1/admin/dlq/messages/{quarantine_id}/requeue",
+    tag = "admin",
+    params(
+        ("quarantine_id" = String, Path, description = "격리 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "처리자 (감사 로그, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "재발행된 격리 메시지", body = QuarantinedMessage),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "격리 메시지 없음", body = ErrorResponse),
+        (status = 409, description = "이미 처리된 격리 메시지", body = ErrorResponse),
+        (status = 422, description = "본문이 독성이라 재발행 불가 (폐기만 가능)", body = ErrorResponse),
+        (status = 503, description = "RabbitMQ Producer 없음/발행 실패", body = ErrorResponse),
+    )
+)]
+pub async fn requeue_quarantined_message(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(quarantine_id): Path<String>,
+) -> ApiResult<QuarantinedMessage> {
+    let actor = authorize_admin(&state, &headers)?;
+
+    Ok(Json(state.dead_letters.requeue(&quarantine_id, &actor).await?))
+}
+
+/// DLQ 격리 메시지 폐기 핸들러 (관리자)
+#[utoipa::path(
+    post,
+    path = "/v1/admin/dlq/messages/{quarantine_id}/discard",
+    tag = "admin",
+    params(
+        ("quarantine_id" = String, Path, description = "격리 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "처리자 (감사 로그, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "폐기된 격리 메시지", body = QuarantinedMessage),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "격리 메시지 없음", body = ErrorResponse),
+        (status = 409, description = "이미 처리된 격리 메시지", body = ErrorResponse),
+    )
+)]
+pub async fn discard_quarantined_message(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(quarantine_id): Path<String>,
+) -> ApiResult<QuarantinedMessage> {
+    let actor = authorize_admin(&state, &headers)?;
+
+    Ok(Json(state.dead_letters.discard(&quarantine_id, &actor).await?))
+}
+
+/// 로그인 핸들러 (웹 UI)
+///
+/// API 키로 짧은 수명의 액세스 토큰(JWT)과 한 번만 쓸 수 있는 리프레시 토큰을 발급합니다.
+#[utoipa::path(
+    post,
+    path = "/v1/auth/login",
+    tag = "auth",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "발급된 토큰", body = TokenPair),
+        (status = 401, description = "API 키가 올바르지 않음", body = ErrorResponse),
+        (status = 403, description = "API 키의 IP 허용 목록 밖에서 요청", body = ErrorResponse),
+        (status = 503, description = "인증 비활성화", body = ErrorResponse),
+    )
+)]
+pub async fn login(
+    State(state): State<ServerState>,
+    client_ip: ClientIp,
+    payload: Result<Json<LoginRequest>, JsonRejection>,
+) -> ApiResult<TokenPair> {
+    let auth = auth_service(&state)?;
+    let Json(payload) = payload?;
+
+    Ok(Json(auth.login(&payload.api_key, client_ip).await?))
+}
+
+/// 토큰 갱신 핸들러
+///
+/// 리프레시 토큰을 새 토큰으로 교체합니다. 이미 교체된 토큰이 다시 쓰이면 같은 로그인의 토큰을 모두 폐기합니다.
+#[utoipa::path(
+    post,
+    path = "/v1/auth/refresh",
+    tag = "auth",
+    request_body = RefreshTokenRequest,
+    responses(
+        (status = 200, description = "새로 발급된 토큰", body = TokenPair),
+        (status = 401, description = "만료/폐기/재사용된 리프레시 토큰", body = ErrorResponse),
+        (status = 403, description = "API 키의 IP 허용 목록 밖에서 요청", body = ErrorResponse),
+        (status = 503, description = "인증 비활성화", body = ErrorResponse),
+    )
+)]
+pub async fn refresh_token(
+    State(state): State<ServerState>,
+    client_ip: ClientIp,
+    payload: Result<Json<RefreshTokenRequest>, JsonRejection>,
+) -> ApiResult<TokenPair> {
+    let auth = auth_service(&state)?;
+    let Json(payload) = payload?;
+
+    Ok(Json(auth.refresh(&payload.refresh_token, client_ip).await?))
+}
+
+/// 로그아웃 핸들러 (리프레시 토큰 폐기, 액세스 토큰은 만료까지 유효)
+#[utoipa::path(
+    post,
+    path = "/v1/auth/logout",
+    tag = "auth",
+    request_body = RefreshTokenRequest,
+    responses(
+        (status = 204, description = "로그아웃"),
+        (status = 401, description = "알 수 없는 리프레시 토큰", body = ErrorResponse),
+        (status = 503, description = "인증 비활성화", body = ErrorResponse),
+    )
+)]
+pub async fn logout(
+    State(state): State<ServerState>,
+    payload: Result<Json<RefreshTokenRequest>, JsonRejection>,
+) -> Result<StatusCode, ApiError> {
+    let auth = auth_service(&state)?;
+    let Json(payload) = payload?;
+
+    auth.logout(&payload.refresh_token).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 현재 인증 정보 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/v1/auth/me",
+    tag = "auth",
+    params(
+        ("Authorization" = Option<String>, Header, description = "Bearer 액세스 토큰"),
+        ("X-API-Key" = Option<String>, Header, description = "API 키"),
+    ),
+    responses(
+        (status = 200, description = "인증된 계정과 권한", body = AuthContext),
+        (status = 401, description = "인증 필요", body = ErrorResponse),
+    )
+)]
+pub async fn get_auth_context(auth: AuthContext) -> Json<AuthContext> {
+    Json(auth)
+}
+
+/// API 키 발급 핸들러 (관리자, 감사 로그 기록)
+///
+/// 비밀 키는 이 응답에서만 확인할 수 있습니다 (DB에는 해시만 저장).
+#[utoipa::path(
+    post,
+    path = "/v1/admin/api-keys",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "2단계 인증 코드 (TOTP 활성화 시 필수)"),
+        ("X-Admin-User" = Option<String>, Header, description = "발급자 (감사 로그, 기본 admin)"),
+    ),
+    request_body = IssueApiKeyRequest,
+    responses(
+        (status = 200, description = "발급된 API 키", body = IssuedApiKey),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+        (status = 503, description = "인증 비활성화", body = ErrorResponse),
+    )
+)]
+pub async fn issue_api_key(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    payload: Result<Json<IssueApiKeyRequest>, JsonRejection>,
+) -> ApiResult<IssuedApiKey> {
+    let actor = authorize_sensitive_admin(&state, &headers, "api_key_issue").await?;
+    let auth = auth_service(&state)?;
+    let Json(payload) = payload?;
+    validate_client_id(&payload.client_id)?;
+
+    Ok(Json(auth.issue_api_key(&payload.client_id, payload.scopes, &payload.ip_allowlist, &actor).await?))
+}
+
+/// API 키 폐기 핸들러 (관리자, 감사 로그 기록)
+///
+/// 그 키로 로그인한 리프레시 토큰도 함께 폐기합니다.
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/api-keys/{key_id}",
+    tag = "admin",
+    params(
+        ("key_id" = String, Path, description = "API 키 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "폐기자 (감사 로그, 기본 admin)"),
+    ),
+    responses(
+        (status = 204, description = "폐기됨"),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "API 키 없음", body = ErrorResponse),
+        (status = 503, description = "인증 비활성화", body = ErrorResponse),
+    )
+)]
+pub async fn revoke_api_key(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(key_id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let actor = authorize_admin(&state, &headers)?;
+    let auth = auth_service(&state)?;
+
+    auth.revoke_api_key(&key_id, &actor).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// API 키 IP 허용 목록 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/api-keys/{key_id}/ip-allowlist",
+    tag = "admin",
+    params(
+        ("key_id" = String, Path, description = "API 키 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+    ),
+    responses(
+        (status = 200, description = "허용 IP/CIDR (비어 있으면 모든 IP 허용)", body = IpAllowlistResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 404, description = "API 키 없음", body = ErrorResponse),
+        (status = 503, description = "인증 비활성화", body = ErrorResponse),
+    )
+)]
+pub async fn get_api_key_ip_allowlist(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(key_id): Path<String>,
+) -> ApiResult<IpAllowlistResponse> {
+    authorize_admin(&state, &headers)?;
+    let auth = auth_service(&state)?;
+
+    let entries = auth.ip_allowlist(&key_id).await?;
+    Ok(Json(IpAllowlistResponse { key_id, entries }))
+}
+
+/// API 키 IP 허용 목록 변경 핸들러 (관리자, 감사 로그 기록)
+///
+/// 목록 전체를 교체하며, 빈 목록은 모든 IP를 허용합니다. 이미 발급된 액세스 토큰에도 바로 적용됩니다.
+#[utoipa::path(
+    put,
+    path = "/v1/admin/api-keys/{key_id}/ip-allowlist",
+    tag = "admin",
+    params(
+        ("key_id" = String, Path, description = "API 키 ID"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "2단계 인증 코드 (TOTP 활성화 시 필수)"),
+        ("X-Admin-User" = Option<String>, Header, description = "변경자 (감사 로그, 기본 admin)"),
+    ),
+    request_body = IpAllowlistRequest,
+    responses(
+        (status = 200, description = "변경된 허용 목록", body = IpAllowlistResponse),
+        (status = 400, description = "잘못된 IP/CIDR", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+        (status = 404, description = "API 키 없음", body = ErrorResponse),
+        (status = 503, description = "인증 비활성화", body = ErrorResponse),
+    )
+)]
+pub async fn update_api_key_ip_allowlist(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(key_id): Path<String>,
+    payload: Result<Json<IpAllowlistRequest>, JsonRejection>,
+) -> ApiResult<IpAllowlistResponse> {
+    let actor = authorize_sensitive_admin(&state, &headers, "api_key_ip_allowlist").await?;
+    let auth = auth_service(&state)?;
+    let Json(payload) = payload?;
+
+    let entries = auth.set_ip_allowlist(&key_id, &payload.entries, &actor).await?;
+    Ok(Json(IpAllowlistResponse { key_id, entries }))
+}
+
+fn parse_role(name: &str) -> Result<Role, ApiError> {
+    Role::from_name(name).ok_or_else(|| RbacError::UnknownRole(name.to_string()).into())
+}
+
+fn parse_subject_kind(name: &str) -> Result<SubjectKind, ApiError> {
+    SubjectKind::from_name(name).ok_or_else(|| RbacError::UnknownSubjectKind(name.to_string()).into())
+}
+
+/// 역할별 권한 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/rbac/roles",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "요청한 관리자 (권한 확인, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "역할별 권한", body = RolesResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 403, description = "access:manage 권한 없음", body = ErrorResponse),
+    )
+)]
+pub async fn get_roles(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<RolesResponse> {
+    authorize_admin(&state, &headers)?;
+
+    Ok(Json(RolesResponse { roles: state.rbac.roles() }))
+}
+
+/// 역할 권한 교체 핸들러 (관리자, 감사 로그 기록)
+///
+/// 권한 목록 전체를 교체하며 바로 다음 요청부터 적용됩니다. admin 역할은 바꿀 수 없습니다.
+#[utoipa::path(
+    put,
+    path = "/v1/admin/rbac/roles/{role}",
+    tag = "admin",
+    params(
+        ("role" = String, Path, description = "역할 (trader, market-maker, ops, compliance)"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "2단계 인증 코드 (TOTP 활성화 시 필수)"),
+        ("X-Admin-User" = Option<String>, Header, description = "변경자 (권한 확인, 감사 로그, 기본 admin)"),
+    ),
+    request_body = RolePermissionsRequest,
+    responses(
+        (status = 200, description = "변경된 역할 권한", body = RolePermissions),
+        (status = 400, description = "알 수 없는 역할 또는 admin 역할 변경", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+        (status = 403, description = "access:manage 권한 없음", body = ErrorResponse),
+    )
+)]
+pub async fn update_role_permissions(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(role): Path<String>,
+    payload: Result<Json<RolePermissionsRequest>, JsonRejection>,
+) -> ApiResult<RolePermissions> {
+    let actor = authorize_sensitive_admin(&state, &headers, "role_permissions").await?;
+    let role = parse_role(&role)?;
+    let Json(payload) = payload?;
+
+    let permissions = payload.permissions.into_iter().collect();
+    Ok(Json(state.rbac.set_role_permissions(role, permissions, &actor).await?))
+}
+
+/// 역할 지정 목록 조회 핸들러 (관리자)
+#[utoipa::path(
+    get,
+    path = "/v1/admin/rbac/assignments",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = Option<String>, Header, description = "요청한 관리자 (권한 확인, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "역할 지정과 기본 역할", body = RoleAssignmentsResponse),
+        (status = 401, description = "관리자 인증 실패", body = ErrorResponse),
+        (status = 403, description = "access:manage 권한 없음", body = ErrorResponse),
+    )
+)]
+pub async fn get_role_assignments(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<RoleAssignmentsResponse> {
+    authorize_admin(&state, &headers)?;
+
+    let config = state.rbac.config();
+    Ok(Json(RoleAssignmentsResponse {
+        default_client_role: config.default_client_role,
+        default_admin_role: config.default_admin_role,
+        assignments: state.rbac.assignments(),
+    }))
+}
+
+/// 역할 지정 핸들러 (관리자, 감사 로그 기록)
+///
+/// `client`는 API 계정(`client_id`), `admin`은 `X-Admin-User` 관리자 이름에 역할을 지정합니다.
+#[utoipa::path(
+    put,
+    path = "/v1/admin/rbac/assignments/{subject_kind}/{subject}",
+    tag = "admin",
+    params(
+        ("subject_kind" = String, Path, description = "대상 종류 (client, admin)"),
+        ("subject" = String, Path, description = "계정 ID 또는 관리자 이름"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "2단계 인증 코드 (TOTP 활성화 시 필수)"),
+        ("X-Admin-User" = Option<String>, Header, description = "지정자 (권한 확인, 감사 로그, 기본 admin)"),
+    ),
+    request_body = RoleAssignmentRequest,
+    responses(
+        (status = 200, description = "지정된 역할", body = RoleAssignment),
+        (status = 400, description = "잘못된 대상 종류, 계정 ID 또는 역할", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+        (status = 403, description = "access:manage 권한 없음", body = ErrorResponse),
+    )
+)]
+pub async fn assign_role(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path((subject_kind, subject)): Path<(String, String)>,
+    payload: Result<Json<RoleAssignmentRequest>, JsonRejection>,
+) -> ApiResult<RoleAssignment> {
+    let actor = authorize_sensitive_admin(&state, &headers, "role_assignment").await?;
+    let kind = parse_subject_kind(&subject_kind)?;
+    if kind == SubjectKind::Client {
+        validate_client_id(&subject)?;
+    }
+    let Json(payload) = payload?;
+
+    Ok(Json(state.rbac.assign(kind, &subject, payload.role, &actor).await?))
+}
+
+/// 역할 지정 해제 핸들러 (관리자, 감사 로그 기록, 이후 기본 역할 적용)
+#[utoipa::path(
+    delete,
+    path = "/v1/admin/rbac/assignments/{subject_kind}/{subject}",
+    tag = "admin",
+    params(
+        ("subject_kind" = String, Path, description = "대상 종류 (client, admin)"),
+        ("subject" = String, Path, description = "계정 ID 또는 관리자 이름"),
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "2단계 인증 코드 (TOTP 활성화 시 필수)"),
+        ("X-Admin-User" = Option<String>, Header, description = "해제자 (권한 확인, 감사 로그, 기본 admin)"),
+    ),
+    responses(
+        (status = 200, description = "해제된 역할 지정", body = RoleAssignment),
+        (status = 400, description = "잘못된 대상 종류", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+        (status = 403, description = "access:manage 권한 없음", body = ErrorResponse),
+        (status = 404, description = "역할 지정 없음", body = ErrorResponse),
+    )
+)]
+pub async fn unassign_role(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path((subject_kind, subject)): Path<(String, String)>,
+) -> ApiResult<RoleAssignment> {
+    let actor = authorize_sensitive_admin(&state, &headers, "role_assignment").await?;
+    let kind = parse_subject_kind(&subject_kind)?;
+
+    Ok(Json(state.rbac.unassign(kind, &subject, &actor).await?))
+}
+
+/// TOTP 비밀 키 발급 핸들러 (관리자 본인, 감사 로그 기록)
+///
+/// `X-Admin-User` 관리자의 비밀 키를 발급합니다. 이미 등록한 관리자는 `X-Admin-TOTP`에 현재 코드가 있어야 재발급됩니다.
+/// 인증 앱에 등록한 뒤 `/v1/admin/totp/confirm`으로 첫 코드를 확인해야 민감한 작업에 쓸 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/v1/admin/totp/provision",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = String, Header, description = "비밀 키를 발급할 관리자"),
+        ("X-Admin-TOTP" = Option<String>, Header, description = "현재 코드 (재발급 시 필수)"),
+    ),
+    responses(
+        (status = 200, description = "발급된 비밀 키와 otpauth URI", body = TotpProvisioning),
+        (status = 401, description = "관리자 인증 또는 2단계 인증 실패", body = ErrorResponse),
+        (status = 503, description = "2단계 인증 비활성화", body = ErrorResponse),
+    )
+)]
+pub async fn provision_totp(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> ApiResult<TotpProvisioning> {
+    let actor = authorize_admin(&state, &headers)?;
+    let totp = totp_registry(&state)?;
+    let code = headers.get(TOTP_HEADER).and_then(|v| v.to_str().ok());
+
+    Ok(Json(totp.provision(&actor, code).await?))
+}
+
+/// TOTP 등록 확인 핸들러 (관리자 본인, 인증 앱의 첫 코드)
+#[utoipa::path(
+    post,
+    path = "/v1/admin/totp/confirm",
+    tag = "admin",
+    params(
+        ("X-Admin-Token" = String, Header, description = "관리자 토큰"),
+        ("X-Admin-User" = String, Header, description = "등록을 확인할 관리자"),
+    ),
+    request_body = TotpConfirmRequest,
+    responses(
+        (status = 204, description = "등록 완료"),
+        (status = 400, description = "등록 대기 중인 비밀 키 없음", body = ErrorResponse),
+        (status = 401, description = "관리자 인증 실패 또는 코드 불일치", body = ErrorResponse),
+        (status = 503, description = "2단계 인증 비활성화", body = ErrorResponse),
+    )
+)]
+pub async fn confirm_totp(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    payload: Result<Json<TotpConfirmRequest>, JsonRejection>,
+) -> Result<StatusCode, ApiError> {
+    let actor = authorize_admin(&state, &headers)?;
+    let totp = totp_registry(&state)?;
+    let Json(payload) = payload?;
+
+    totp.confirm(&actor, &payload.code).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn totp_registry(state: &ServerState) -> Result<&Arc<TotpRegistry>, ApiError> {
+    state.admin_totp.as_ref().ok_or_else(|| {
+        ApiError::new(ErrorCode::ServiceUnavailable, "2단계 인증이 비활성화되어 있습니다 (XTRADER_ADMIN_TOTP 미설정)")
+    })
+}
+
+/// 생존 상태(liveness) 핸들러
+///
+/// 요청을 처리할 수 있으면 항상 200을 반환합니다. 의존성 상태는 `/readyz` 에서 확인합니다.
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    tag = "health",
+    responses(
+        (status = 200, description = "프로세스 응답 가능", body = LivenessResponse),
+    )
+)]
+pub async fn liveness() -> Json<LivenessResponse> {
+    Json(LivenessResponse {
+        status: "ok".to_string(),
+        timestamp: chrono::Utc::now().timestamp_millis() as u64,
+    })
+}
+
+/// 준비 상태(readiness) 핸들러
+///
+/// DB, 매칭 엔진, 시퀀서 큐, 주문장 점검을 모두 통과하면 200, 하나라도 실패하면 503을 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    tag = "health",
+    responses(
+        (status = 200, description = "트래픽 처리 가능", body = ReadinessReport),
+        (status = 503, description = "준비 안 됨 (실패한 항목 포함)", body = ReadinessReport),
+    )
+)]
+pub async fn readiness(State(state): State<ServerState>) -> Response {
+    let report = state.readiness.check().await;
+    let status = if report.ready { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (status, Json(report)).into_response()
+}
@@ -1,7 +1,17 @@
+pub mod dashboard_ui;
+pub mod dashboard_websocket;
+pub mod error;
 pub mod handlers;
 pub mod models;
+pub mod openapi;
 pub mod routes;
+pub mod tls;
+pub mod validation;
+pub mod websocket;
 
+pub use error::{ApiError, ApiResult, ErrorCode};
 pub use handlers::*;
 pub use models::*;
-pub use routes::*;
\ No newline at end of file
+pub use routes::*;
+pub use validation::OrderValidator;
+pub use websocket::{WebSocketConfig, WebSocketMetrics};
\ No newline at end of file
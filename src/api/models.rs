@@ -1,8 +1,30 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
-use crate::matching_engine::model::{Order, OrderType, Side, ExecutionReport, OrderBookSnapshot as EngineOrderBookSnapshot};
+use utoipa::ToSchema;
+use crate::matching_engine::model::{OrderType, Side, ExecutionReport, OrderBookSnapshot as EngineOrderBookSnapshot};
+use crate::matching_engine::dry_run::SimulatedFill;
+use crate::auth::Scope;
+use crate::currency::AssetKind;
+use crate::db::{BackupMetadata, ClientTradingStats, StatsWindow};
+use crate::fee::ClientFeeTier;
+use crate::kill_switch::KillSwitchEntry;
+use crate::kyc::{KycLevel, KycStatus};
+use crate::rbac::{Permission, Role, RoleAssignment, RolePermissions};
+use crate::risk::{ClientRiskLimits, RiskLimits};
+use crate::sequencer::{ClientMessageUsage, MessageCounts, MinuteUsage};
+use crate::monitoring::incident_tracker::Incident;
+use crate::monitoring::notification_routing::{PendingEscalation, RoutingRule};
+use crate::mq::{MQType, QuarantinedMessage, RecoveryJob};
+
+// WebSocket 스트림/시세 프레임은 MQ와 시장 데이터 크레이트도 쓰도록 xtrader-api 크레이트로 분리 (기존 경로 유지)
+pub use xtrader_api::models::{
+    BboUpdate, CandleData, FundingEvent, FuturesContractStatus, IndexPriceUpdate, OrderBookChange, OrderBookChangeType,
+    OrderBookDelta, OrderBookSnapshot, PrivateEvent, ThrottleUpdate, TickerData, WebSocketMessage,
+};
 
 /// 주문 제출 요청
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct OrderRequest {
     pub symbol: String,
     pub side: Side,
@@ -10,32 +32,100 @@ pub struct OrderRequest {
     pub price: Option<u64>,
     pub quantity: u64,
     pub client_id: String,
+    /// 시장가 주문 최대 슬리피지 (퍼센트, 생략 시 심볼 기본값)
+    #[serde(default)]
+    pub max_slippage_pct: Option<f64>,
+    /// 시장가 주문 최대 체결 레벨 수 (생략 시 심볼 기본값)
+    #[serde(default)]
+    pub max_levels: Option<usize>,
+    /// 주문 만료 시간 (Unix 타임스탬프, 지정 시 GTD 주문)
+    #[serde(default)]
+    pub expire_time: Option<u64>,
+    /// 로컬 호가로 채우지 못하는 수량을 외부 거래소로 라우팅
+    #[serde(default)]
+    pub route_external: bool,
+    /// 모의 주문 (검증 후 현재 주문장으로 체결만 해 보고 제출하지 않음)
+    #[serde(default)]
+    pub dry_run: bool,
+    /// 주문 큐가 가득 찼을 때 서버에서 다시 넣어 볼 최대 시간 (밀리초, 서버 상한으로 제한, 생략 시 바로 `QUEUE_FULL`)
+    #[serde(default)]
+    pub max_wait_ms: Option<u64>,
+    /// 사용자 지정 태그 (전략 ID 등, 최대 64자의 영숫자와 `_ - . :`, 체결 보고서와 주문 조회에 그대로 실림)
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+/// 외부 거래소 체결 (스마트 주문 라우팅)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ExternalFill {
+    /// 거래소
+    pub exchange: String,
+    /// 외부 거래소 주문 ID
+    pub venue_order_id: String,
+    pub price: u64,
+    pub quantity: u64,
+    /// 수수료 (호가 자산 최소 단위)
+    pub fee: f64,
+    /// 수수료 자산 (심볼의 호가 자산)
+    pub fee_currency: String,
+    /// 보고 통화 환산 수수료 (보고 통화 최소 단위, 환율이 없으면 null)
+    pub fee_reporting: Option<f64>,
 }
 
 /// 주문 제출 응답
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct OrderResponse {
     pub order_id: String,
+    /// 매칭 엔진 처리 결과 (ACCEPTED, PARTIALLY_FILLED, FILLED, CANCELED, 대기 시간 초과 시 PENDING)
     pub status: String,
     pub message: String,
+    /// 전역 시퀀스 번호 (시퀀서 큐에 들어간 경우만, 체결 보고서 번호와 같은 순서)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<u64>,
+    /// 즉시 체결된 수량 (외부 거래소 체결 포함)
+    pub filled_quantity: u64,
+    /// 주문장에 남은 수량 (전량 체결/취소되면 0)
+    pub remaining_quantity: u64,
+    /// 외부 거래소 체결 (라우팅한 경우만)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub external_fills: Vec<ExternalFill>,
+    /// 모의 체결 내역 (`dry_run` 요청만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<DryRunDetails>,
+}
+
+/// 모의 주문 체결 내역
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DryRunDetails {
+    /// 가격별 예상 체결 (체결 순서)
+    pub fills: Vec<SimulatedFill>,
+    /// 매칭 시작 시 반대편 최우선 호가 (호가가 없으면 null)
+    pub reference_price: Option<u64>,
+    /// 체결 수량 가중 평균 가격 (체결이 없으면 null)
+    pub average_price: Option<f64>,
+    /// 최우선 호가 대비 평균 체결가의 불리한 방향 차이 (bp)
+    pub slippage_bps: Option<f64>,
 }
 
 /// 주문 취소 요청
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CancelOrderRequest {
     pub order_id: String,
 }
 
 /// 주문 취소 응답
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CancelOrderResponse {
     pub order_id: String,
     pub status: String,
     pub message: String,
+    /// 전역 시퀀스 번호 (취소 레인에 들어간 경우만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<u64>,
 }
 
 /// 주문 상태 조회 응답 (하이브리드 방식)
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct OrderStatusResponse {
     pub order_id: String,
     pub symbol: String,
@@ -50,19 +140,19 @@ pub struct OrderStatusResponse {
 }
 
 /// 체결 내역 조회 응답
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ExecutionResponse {
     pub executions: Vec<ExecutionReport>,
 }
 
 /// 주문서 조회 응답
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct OrderBookResponse {
     pub orderbook: EngineOrderBookSnapshot,
 }
 
 /// 시장 통계 응답
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct MarketStatisticsResponse {
     pub symbol: String,
     pub timestamp: u64,
@@ -77,127 +167,643 @@ pub struct MarketStatisticsResponse {
 }
 
 /// 봉차트 데이터 응답
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CandleResponse {
     pub symbol: String,
     pub interval: String,
     pub candles: Vec<CandleData>,
 }
 
-/// 봉차트 데이터
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct CandleData {
-    pub open_time: u64,
-    pub close_time: u64,
-    pub open: u64,
-    pub high: u64,
-    pub low: u64,
-    pub close: u64,
-    pub volume: u64,
-    pub trade_count: u64,
-}
-
-/// 호가창 변경 타입
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub enum OrderBookChangeType {
-    /// 추가
-    Add,
-    /// 업데이트
-    Update,
-    /// 제거
-    Remove,
-}
-
-/// 호가창 변경 사항
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct OrderBookChange {
-    /// 변경 타입
-    pub change_type: OrderBookChangeType,
-    /// 가격
+/// 생존 상태(liveness) 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LivenessResponse {
+    /// 항상 "ok" (응답했다는 것 자체가 프로세스가 살아 있다는 뜻)
+    pub status: String,
+    /// 응답 시각 (밀리초)
+    pub timestamp: u64,
+}
+
+/// 티커 조회 응답
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TickerResponse {
+    /// 조회 시각 (밀리초)
+    pub timestamp: u64,
+    pub tickers: Vec<TickerData>,
+}
+
+/// 차익거래 기회 (알림 규칙이 확정한 기회)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ArbitrageOpportunityData {
+    pub id: String,
+    pub symbol: String,
+    /// 매수 거래소
+    pub buy_exchange: String,
+    /// 매도 거래소
+    pub sell_exchange: String,
+    pub buy_price: f64,
+    pub sell_price: f64,
+    /// 스프레드 (%)
+    pub spread_percent: f64,
+    /// 알림 시점까지 연속 관측된 틱 수
+    pub consecutive_ticks: u32,
+    /// 연속 구간 시작 시각 (밀리초)
+    pub first_seen_at: u64,
+    /// 알림 발생 시각 (밀리초)
+    pub detected_at: u64,
+}
+
+/// 차익거래 기회 조회 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ArbitrageOpportunitiesResponse {
+    pub opportunities: Vec<ArbitrageOpportunityData>,
+}
+
+/// 자산 정보
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssetData {
+    pub code: String,
+    pub kind: AssetKind,
+    /// 가격/잔고 최소 단위 소수 자릿수
+    pub decimals: u32,
+    /// 1 단위의 보고 통화 가격 (환율이 없으면 null)
+    pub reporting_rate: Option<f64>,
+}
+
+/// 자산 목록 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssetsResponse {
+    pub reporting_currency: String,
+    pub assets: Vec<AssetData>,
+}
+
+/// 자산별 보유 잔고 평가
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PortfolioHolding {
+    pub asset: String,
+    /// 주문 가능 잔고 (자산 최소 단위)
+    pub available: i64,
+    /// 주문에 묶인 잔고 (자산 최소 단위)
+    pub locked: i64,
+    /// 1 단위의 보고 통화 가격 (환율이 없으면 null)
+    pub reporting_rate: Option<f64>,
+    /// 보고 통화 평가액 (환율이 없으면 null)
+    pub value: Option<f64>,
+}
+
+/// 포트폴리오 평가 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PortfolioResponse {
+    pub client_id: String,
+    pub reporting_currency: String,
+    /// 평가 가능한 자산의 보고 통화 평가액 합계
+    pub total_value: f64,
+    pub holdings: Vec<PortfolioHolding>,
+    /// 환율이 없어 합계에서 빠진 자산
+    pub unpriced_assets: Vec<String>,
+}
+
+/// 미체결 주문 하나의 예약 금액
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrderReservationData {
+    pub order_id: String,
+    pub symbol: String,
+    pub side: Side,
     pub price: u64,
-    /// 수량
-    pub quantity: u64,
+    pub remaining_quantity: u64,
+    /// 예약 금액 (자산 최소 단위)
+    pub amount: u64,
+}
+
+/// 자산 하나의 잔고
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct AssetBalanceData {
+    /// 주문 가능 잔고 (자산 최소 단위)
+    pub available: i64,
+    /// 주문에 묶인 잔고 (자산 최소 단위)
+    pub locked: i64,
+    /// 미체결 주문 예약 금액 합계 (자산 최소 단위)
+    pub reserved: u64,
+    /// 미체결 주문별 예약 금액
+    pub reservations: Vec<OrderReservationData>,
+}
+
+/// 사용자 잔고 응답 (자산 코드 → 잔고)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserBalanceResponse {
+    pub client_id: String,
+    pub balances: BTreeMap<String, AssetBalanceData>,
 }
 
-/// 호가창 Delta 업데이트
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct OrderBookDelta {
-    /// 심볼
+/// 계정 주문 기록 한 건
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserOrderData {
+    pub order_id: String,
     pub symbol: String,
-    /// 매수 변경사항
-    pub bid_changes: Vec<OrderBookChange>,
-    /// 매도 변경사항
-    pub ask_changes: Vec<OrderBookChange>,
-    /// 타임스탬프
+    pub side: String,
+    pub order_type: String,
+    /// 지정가 (시장가 주문은 null)
+    pub price: Option<i64>,
+    pub quantity: i64,
+    pub filled_quantity: i64,
+    pub status: String,
+    /// 사용자 지정 태그 (없으면 null)
+    pub tag: Option<String>,
+}
+
+/// 계정 주문 기록 응답 (최신순)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserOrdersResponse {
+    pub client_id: String,
+    pub orders: Vec<UserOrderData>,
+}
+
+/// 계정별 거래 통계 응답 (관리자)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClientStatsResponse {
+    /// 집계 기준 시각 (초)
+    pub generated_at: u64,
+    /// 거래대금 단위 통화
+    pub reporting_currency: String,
+    /// 순위 기준 구간
+    pub window: StatsWindow,
+    /// `window` 구간 메이커+테이커 거래대금 내림차순
+    pub clients: Vec<ClientTradingStats>,
+    /// 보고 통화로 환산하지 못해 거래대금에서 뺀 심볼
+    pub unpriced_symbols: Vec<String>,
+}
+
+/// 계정 분당 메시지 사용량 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MessageUsageResponse {
+    pub client_id: String,
+    /// 분당 메시지 소프트 상한 (없으면 null)
+    pub soft_cap_per_minute: Option<u64>,
+    /// 분당 메시지 하드 상한 (없으면 null)
+    pub hard_cap_per_minute: Option<u64>,
+    /// 조회 구간 시작 (밀리초, 포함)
+    pub from: u64,
+    /// 조회 구간 끝 (밀리초, 제외)
+    pub to: u64,
+    /// 구간 합계
+    pub totals: MessageCounts,
+    /// 메시지가 있었던 분만 시간 순
+    pub minutes: Vec<MinuteUsage>,
+}
+
+/// 한 시간의 성능 지표
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MetricsHourPoint {
+    /// 시간 시작 (Unix 밀리초, UTC 정시)
+    pub hour_start: u64,
+    /// REST 요청 수
+    pub requests: u64,
+    /// 5xx 응답 수
+    pub errors: u64,
+    /// 오류율 (퍼센트, 요청이 없으면 null)
+    pub error_rate_pct: Option<f64>,
+    /// REST 요청 지연 p50 (밀리초, 요청이 없으면 null)
+    pub p50_latency_ms: Option<f64>,
+    /// REST 요청 지연 p99 (밀리초, 요청이 없으면 null)
+    pub p99_latency_ms: Option<f64>,
+    /// REST 요청 지연 최대 (밀리초, 요청이 없으면 null)
+    pub max_latency_ms: Option<f64>,
+    /// 주문 큐 적재 수
+    pub orders: u64,
+    /// 초당 주문 수 (시간 평균)
+    pub orders_per_sec: f64,
+}
+
+/// 성능 지표 추이 응답 (관리자)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MetricsHistoryResponse {
+    /// 조회 구간 시작 (밀리초, 포함)
+    pub from: u64,
+    /// 조회 구간 끝 (밀리초, 제외)
+    pub to: u64,
+    /// 집계 단위 (밀리초, 1시간)
+    pub interval_ms: u64,
+    /// 끝난 시간만 시간 순 (수집되지 않은 시간은 빠짐)
+    pub hours: Vec<MetricsHourPoint>,
+}
+
+/// 계정별 메시지 사용량 순위 응답 (관리자)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MessageUsageSummaryResponse {
+    /// 조회 구간 시작 (밀리초, 포함)
+    pub from: u64,
+    /// 조회 구간 끝 (밀리초, 제외)
+    pub to: u64,
+    /// 메시지 합계 내림차순
+    pub clients: Vec<ClientMessageUsage>,
+}
+
+/// 계정 KYC 상태 변경 요청 (관리자)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct KycUpdateRequest {
+    pub level: KycLevel,
+    pub status: KycStatus,
+    /// 계정별 1회 주문 금액 한도 (생략 시 인증 단계 기본값)
+    #[serde(default)]
+    pub max_order_notional: Option<u64>,
+    /// 변경 사유 (감사 로그에 기록)
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// 계정 KYC 상태
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KycAccountResponse {
+    pub client_id: String,
+    pub level: KycLevel,
+    pub status: KycStatus,
+    /// 계정별 1회 주문 금액 한도
+    pub max_order_notional: Option<u64>,
+    /// 실제 적용되는 1회 주문 금액 한도 (None이면 무제한)
+    pub effective_order_limit: Option<u64>,
+    pub reason: Option<String>,
+    pub updated_by: String,
+    /// 마지막 변경 시각 (밀리초, 미등록 계정은 0)
+    pub updated_at: u64,
+}
+
+/// KYC 변경 이력 항목
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KycChangeData {
+    pub from_level: KycLevel,
+    pub from_status: KycStatus,
+    pub to_level: KycLevel,
+    pub to_status: KycStatus,
+    pub max_order_notional: Option<u64>,
+    pub reason: Option<String>,
+    pub updated_by: String,
+    /// 변경 시각 (밀리초)
+    pub updated_at: u64,
+}
+
+/// KYC 변경 이력 응답 (최신순)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KycHistoryResponse {
+    pub client_id: String,
+    pub changes: Vec<KycChangeData>,
+}
+
+/// 계정 수수료 등급 응답 (관리자)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FeeTierResponse {
+    /// 현재 적용 중인 등급 (이력이 없으면 기본 등급)
+    pub current: ClientFeeTier,
+    /// 등급 변경 이력 (최신순)
+    pub history: Vec<ClientFeeTier>,
+}
+
+/// 로그인 요청 (웹 UI)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct LoginRequest {
+    /// 관리자가 발급한 API 키
+    pub api_key: String,
+}
+
+/// 토큰 갱신/로그아웃 요청
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RefreshTokenRequest {
+    pub refresh_token: String,
+}
+
+/// API 키 발급 요청 (관리자)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct IssueApiKeyRequest {
+    pub client_id: String,
+    /// 부여할 권한 (`read`, `trade`)
+    pub scopes: Vec<Scope>,
+    /// 허용 IP/CIDR (`203.0.113.7`, `10.0.0.0/8`, 비우면 모든 IP 허용)
+    #[serde(default)]
+    pub ip_allowlist: Vec<String>,
+}
+
+/// API 키 IP 허용 목록 변경 요청 (관리자)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct IpAllowlistRequest {
+    /// 허용 IP/CIDR (비우면 모든 IP 허용)
+    pub entries: Vec<String>,
+}
+
+/// API 키 IP 허용 목록 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IpAllowlistResponse {
+    pub key_id: String,
+    /// 허용 IP/CIDR (비어 있으면 모든 IP 허용)
+    pub entries: Vec<String>,
+}
+
+/// TOTP 등록 확인 요청 (관리자)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TotpConfirmRequest {
+    /// 인증 앱에 표시된 6자리 코드
+    pub code: String,
+}
+
+/// 킬 스위치 발동 요청 (관리자)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct KillSwitchRequest {
+    /// 발동 사유 (감사 로그, 알림에 기록)
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// 킬 스위치 발동/해제 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KillSwitchResponse {
+    pub kill_switch: KillSwitchEntry,
+    /// 계정 차단 시 취소 요청한 미체결 주문 ID
+    pub canceled_order_ids: Vec<String>,
+}
+
+/// 심볼 운영 상태 (관리자)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminSymbolData {
+    pub symbol: String,
+    /// 킬 스위치로 거래 중단 중인지
+    pub halted: bool,
+    /// 주문장에 남은 주문 수
+    pub open_orders: usize,
+    pub best_bid: Option<u64>,
+    pub best_ask: Option<u64>,
+}
+
+/// 심볼 목록 (관리자, 심볼순)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminSymbolsResponse {
+    pub symbols: Vec<AdminSymbolData>,
+}
+
+/// 심볼별 매칭 통계 (관리자)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct SymbolMatchStats {
+    pub symbol: String,
+    /// 주문장에 남은 매수 주문 수
+    pub bid_orders: usize,
+    /// 주문장에 남은 매도 주문 수
+    pub ask_orders: usize,
+    /// 상위 N 매수 레벨 잔량 합
+    pub bid_open_interest: u64,
+    /// 상위 N 매도 레벨 잔량 합
+    pub ask_open_interest: u64,
+    /// 최근 1분 체결 건수
+    pub trades_last_minute: u64,
+    /// 최근 1분 체결 수량
+    pub volume_last_minute: u64,
+    /// 초당 체결 건수 (최근 1분 평균)
+    pub trades_per_sec: f64,
+    /// 최근 1분 평균 체결 수량 (체결이 없으면 null)
+    pub avg_trade_size: Option<f64>,
+    /// 엔진 시작 이후 체결 건수
+    pub total_trades: u64,
+    /// 엔진 시작 이후 체결 수량
+    pub total_volume: u64,
+}
+
+/// 매칭 엔진 통계 응답 (관리자, 심볼순)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct EngineStatsResponse {
+    /// 집계 시각 (Unix 초, 엔진 시각)
     pub timestamp: u64,
-    /// 시퀀스 번호 (동기화용)
-    pub sequence: u64,
+    /// 잔량 합에 사용한 호가 레벨 수 (N)
+    pub depth_levels: usize,
+    /// 체결 속도/평균 수량 집계 창 (초)
+    pub window_secs: u64,
+    pub symbols: Vec<SymbolMatchStats>,
+}
+
+/// 계정 미체결 주문 일괄 취소 응답 (관리자)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CancelClientOrdersResponse {
+    pub client_id: String,
+    /// 취소 요청한 미체결 주문 ID
+    pub canceled_order_ids: Vec<String>,
+}
+
+/// 발동 중인 킬 스위치 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KillSwitchListResponse {
+    pub kill_switches: Vec<KillSwitchEntry>,
+}
+
+/// 역할별 권한 목록 (관리자)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RolesResponse {
+    pub roles: Vec<RolePermissions>,
+}
+
+/// 역할 권한 교체 요청 (관리자, 빈 목록이면 모든 권한 회수)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RolePermissionsRequest {
+    pub permissions: Vec<Permission>,
+}
+
+/// 역할 지정 목록 (관리자)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoleAssignmentsResponse {
+    /// 역할을 지정하지 않은 계정의 역할 (None이면 거부)
+    pub default_client_role: Option<Role>,
+    /// 역할을 지정하지 않은 관리자의 역할 (None이면 거부)
+    pub default_admin_role: Option<Role>,
+    pub assignments: Vec<RoleAssignment>,
+}
+
+/// 역할 지정 요청 (관리자)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RoleAssignmentRequest {
+    pub role: Role,
+}
+
+/// 계정별 리스크 한도 설정 요청 (관리자, 생략한 항목은 서버 기본값)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RiskLimitsRequest {
+    #[serde(default)]
+    pub max_open_orders: Option<u64>,
+    /// 1회 주문 금액 한도 (보고 통화 최소 단위)
+    #[serde(default)]
+    pub max_order_notional: Option<u64>,
+    /// 심볼별 최대 순포지션 (절댓값, 기준 자산 수량)
+    #[serde(default)]
+    pub max_net_position: BTreeMap<String, u64>,
+    /// 변경 사유 (감사 로그에 기록)
+    #[serde(default)]
+    pub reason: Option<String>,
 }
 
-/// 호가창 Snapshot 업데이트
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct OrderBookSnapshot {
-    /// 심볼
+/// 계정 리스크 한도와 현재 사용량 (관리자)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RiskProfileResponse {
+    pub client_id: String,
+    /// 계정별 한도 설정 (없으면 서버 기본값만 적용)
+    pub client_limits: Option<ClientRiskLimits>,
+    /// 실제 적용되는 한도
+    pub effective_limits: RiskLimits,
+    /// 현재 미체결 주문 수
+    pub open_orders: usize,
+    /// 심볼별 순포지션 (매수 체결 - 매도 체결, 0 제외)
+    pub positions: BTreeMap<String, i64>,
+}
+
+/// 알림 라우팅 규칙 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationRulesResponse {
+    pub rules: Vec<RoutingRule>,
+}
+
+/// 확인을 기다리는 에스컬레이션 목록 (예정 시각순)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PendingEscalationsResponse {
+    pub escalations: Vec<PendingEscalation>,
+}
+
+/// 인시던트 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IncidentsResponse {
+    pub incidents: Vec<Incident>,
+}
+
+/// 인시던트 메모 추가 요청
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct IncidentNoteRequest {
+    pub note: String,
+}
+
+/// 인시던트 해결 요청
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct IncidentResolveRequest {
+    /// 해결 내용 (메모로 기록)
+    #[serde(default)]
+    pub resolution: Option<String>,
+}
+
+/// 부분 복구 작업 시작 요청 (지정하지 않은 조건은 제한 없음)
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct RecoveryJobRequest {
+    #[serde(default)]
+    pub mq_type: Option<MQType>,
+    /// 토픽/스트림 (예: market-data)
+    #[serde(default)]
+    pub topic_stream: Option<String>,
+    /// 심볼 (라우팅 키 또는 메시지 본문의 symbol)
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// 최초 백업 시각 하한 (Unix 밀리초)
+    #[serde(default)]
+    pub since_ms: Option<u64>,
+    /// 최초 백업 시각 상한 (Unix 밀리초, 기본 요청 시각)
+    #[serde(default)]
+    pub until_ms: Option<u64>,
+    /// 최근 N초 동안 백업된 메시지만 (`since_ms` 대신)
+    #[serde(default)]
+    pub within_secs: Option<u64>,
+}
+
+/// 부분 복구 작업 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RecoveryJobsResponse {
+    pub jobs: Vec<RecoveryJob>,
+}
+
+/// DB 백업 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DbBackupsResponse {
+    /// 백업 디렉터리
+    pub dir: String,
+    /// 최근 것부터
+    pub backups: Vec<BackupMetadata>,
+}
+
+/// DLQ 격리 메시지 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QuarantinedMessagesResponse {
+    pub messages: Vec<QuarantinedMessage>,
+}
+
+/// 호가 기준 가격에서 일정 범위(bps) 안의 유동성
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct LiquidityBand {
+    /// 중간가 기준 범위 (bps)
+    pub within_bps: u32,
+    /// 범위 안의 매수 잔량 합계
+    pub bid_quantity: u64,
+    /// 범위 안의 매도 잔량 합계
+    pub ask_quantity: u64,
+}
+
+/// 호가창 미시구조 지표 응답
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MicrostructureResponse {
     pub symbol: String,
-    /// 매수 호가
-    pub bids: Vec<(u64, u64)>,
-    /// 매도 호가
-    pub asks: Vec<(u64, u64)>,
-    /// 타임스탬프
+    /// 계산 시각 (밀리초)
+    pub timestamp: u64,
+    pub best_bid: Option<u64>,
+    pub best_ask: Option<u64>,
+    /// 상위 N 레벨 잔량 불균형 ((매수 - 매도) / (매수 + 매도), -1 ~ 1)
+    pub imbalance: Option<f64>,
+    /// 최우선 호가 잔량 가중 중간가
+    pub weighted_mid_price: Option<f64>,
+    /// 스프레드 (bps, 중간가 기준)
+    pub spread_bps: Option<f64>,
+    /// 계산에 사용한 호가 레벨 수 (N)
+    pub depth_levels: usize,
+    /// 중간가 기준 범위별 유동성
+    pub liquidity: Vec<LiquidityBand>,
+}
+
+/// 시장 충격 추정 응답 (가상 시장가 주문)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MarketImpactResponse {
+    pub symbol: String,
+    /// 계산 시각 (밀리초)
+    pub timestamp: u64,
+    pub side: Side,
+    /// 요청 수량
+    pub quantity: u64,
+    /// 현재 호가로 채울 수 있는 수량 (호가가 부족하면 요청 수량보다 작음)
+    pub fillable_quantity: u64,
+    /// 반대편 최우선 호가
+    pub best_price: Option<u64>,
+    /// 예상 평균 체결가 (체결 수량 가중)
+    pub average_price: Option<f64>,
+    /// 마지막으로 닿는 가격 레벨
+    pub worst_price: Option<u64>,
+    /// 총 체결 금액 (가격 × 수량 합계, 호가 자산 최소 단위)
+    pub total_cost: u64,
+    /// 최우선 호가 대비 평균 체결가의 불리한 방향 차이 (bp)
+    pub slippage_bps: Option<f64>,
+    /// 쓸어가는 가격 레벨 수
+    pub levels_consumed: usize,
+}
+
+/// 인덱스 가격 조회 응답
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct IndexPriceResponse {
+    /// 조회 시각 (밀리초)
     pub timestamp: u64,
-    /// 시퀀스 번호 (동기화용)
-    pub sequence: u64,
-}
-
-/// WebSocket 메시지 타입
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(tag = "type")]
-pub enum WebSocketMessage {
-    /// 체결 결과 (하이브리드 방식 - 상태 정보 포함)
-    Execution {
-        execution_report: ExecutionReport,
-        order_status: String, // "Filled", "PartiallyFilled", "Pending"
-    },
-    /// 호가창 Delta 업데이트 (변경된 부분만)
-    OrderBookDelta(OrderBookDelta),
-    /// 호가창 Snapshot 업데이트 (전체 호가창)
-    OrderBookSnapshot(OrderBookSnapshot),
-    /// 호가창 업데이트 (기존 호환성 유지)
-    OrderBookUpdate {
-        symbol: String,
-        bids: Vec<(u64, u64)>,
-        asks: Vec<(u64, u64)>,
-        timestamp: u64,
-    },
-    /// 시장 통계 업데이트
-    MarketStatistics {
-        symbol: String,
-        timestamp: u64,
-        last_price: Option<u64>,
-        price_change_24h: Option<f64>,
-        volume_24h: u64,
-        high_price_24h: Option<u64>,
-        low_price_24h: Option<u64>,
-    },
-    /// 봉차트 업데이트
-    CandlestickUpdate {
-        symbol: String,
-        interval: String,
-        candle: CandleData,
-    },
-    /// 동기화 요청 응답
-    SyncResponse {
-        symbol: String,
-        snapshot: OrderBookSnapshot,
-    },
-    /// 에러 메시지
-    Error {
-        message: String,
-    },
+    pub prices: Vec<IndexPriceUpdate>,
+}
+
+/// 펀딩 정산 이력 조회 응답
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FundingHistoryResponse {
+    pub symbol: String,
+    /// 펀딩 정산 대상 심볼인지
+    pub enabled: bool,
+    /// 최신순 정산 결과
+    pub settlements: Vec<FundingEvent>,
+}
+
+/// 만기 상품 목록 응답
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FuturesContractsResponse {
+    /// 만기순
+    pub contracts: Vec<FuturesContractStatus>,
 }
 
 /// API 오류 응답
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
     pub error: String,
     pub message: String,
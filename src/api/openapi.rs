@@ -0,0 +1,354 @@
+//! OpenAPI 스펙 및 Swagger UI
+//!
+//! 핸들러의 `#[utoipa::path]` 선언과 모델의 `ToSchema` 구현에서 OpenAPI 3 스펙을 생성하고
+//! `/docs`에 Swagger UI를, `/api-docs/openapi.json`에 스펙 JSON을 제공합니다.
+
+use axum::Router;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::api::handlers;
+use crate::api::models::*;
+use crate::auth::{AuthContext, IssuedApiKey, Scope, TokenPair};
+use crate::totp::TotpProvisioning;
+use crate::currency::AssetKind;
+use crate::db::{BackupMetadata, ClientTradingStats, ClientWindowStats, RebateReport, RebateReportRow, StatsWindow};
+use crate::fee::ClientFeeTier;
+use crate::futures::{ContractState, SettlementMethod};
+use crate::kill_switch::{KillSwitchEntry, KillSwitchScope};
+use crate::risk::{ClientRiskLimits, RiskLimits};
+use crate::kyc::{KycLevel, KycStatus};
+use crate::rbac::{Permission, Role, RoleAssignment, RolePermissions, SubjectKind};
+use crate::matching_engine::dry_run::SimulatedFill;
+use crate::monitoring::incident_tracker::{Incident, IncidentNote, IncidentStatus};
+use crate::monitoring::notification_routing::{EscalationPolicy, PendingEscalation, QuietHours, RoutingRule};
+use crate::monitoring::notification_system::{NotificationChannel, NotificationPriority, NotificationType};
+use crate::monitoring::readiness::{ReadinessCheck, ReadinessReport};
+use crate::mq::{MQType, QuarantineReason, QuarantineStatus, QuarantinedMessage, RecoveryFilter, RecoveryJob, RecoveryJobState};
+use crate::sequencer::message_usage::{ClientMessageUsage, MessageCounts, MinuteUsage};
+use crate::sequencer::replication::{ReplicationRole, ReplicationStatus};
+use crate::matching_engine::model::{ExecType, ExecutionReport, OrderBookSnapshot as EngineOrderBookSnapshot, OrderType, Side};
+
+/// xTrader REST API 스펙
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "xTrader API", description = "xTrader 주문 및 시장 데이터 REST API"),
+    paths(
+        handlers::submit_order,
+        handlers::cancel_order,
+        handlers::get_order_status,
+        handlers::get_orderbook,
+        handlers::get_executions,
+        handlers::get_statistics,
+        handlers::get_candles,
+        handlers::get_ticker,
+        handlers::get_index_prices,
+        handlers::get_funding_history,
+        handlers::get_futures_contracts,
+        handlers::get_assets,
+        handlers::get_portfolio,
+        handlers::get_user_balance,
+        handlers::get_user_orders,
+        handlers::get_message_usage,
+        handlers::export_trades,
+        handlers::get_microstructure,
+        handlers::get_market_impact,
+        handlers::sync_orderbook,
+        handlers::get_arbitrage_opportunities,
+        handlers::get_client_stats,
+        handlers::get_message_usage_summary,
+        handlers::get_rebate_report,
+        handlers::get_kyc_account,
+        handlers::update_kyc_account,
+        handlers::get_kyc_history,
+        handlers::get_fee_tier,
+        handlers::get_kill_switches,
+        handlers::block_client,
+        handlers::unblock_client,
+        handlers::halt_symbol,
+        handlers::resume_symbol,
+        handlers::get_admin_symbols,
+        handlers::get_engine_stats,
+        handlers::get_metrics_history,
+        handlers::cancel_client_orders,
+        handlers::get_risk_limits,
+        handlers::update_risk_limits,
+        handlers::reset_risk_limits,
+        handlers::get_notification_rules,
+        handlers::put_notification_rule,
+        handlers::delete_notification_rule,
+        handlers::get_pending_escalations,
+        handlers::get_incidents,
+        handlers::get_incident,
+        handlers::acknowledge_incident,
+        handlers::add_incident_note,
+        handlers::resolve_incident,
+        handlers::get_replication_status,
+        handlers::promote_replica,
+        handlers::create_db_backup,
+        handlers::get_db_backups,
+        handlers::start_recovery_job,
+        handlers::get_recovery_jobs,
+        handlers::get_recovery_job,
+        handlers::cancel_recovery_job,
+        handlers::get_quarantined_messages,
+        handlers::get_quarantined_message,
+        handlers::requeue_quarantined_message,
+        handlers::discard_quarantined_message,
+        handlers::login,
+        handlers::refresh_token,
+        handlers::logout,
+        handlers::get_auth_context,
+        handlers::issue_api_key,
+        handlers::revoke_api_key,
+        handlers::get_api_key_ip_allowlist,
+        handlers::update_api_key_ip_allowlist,
+        handlers::get_roles,
+        handlers::update_role_permissions,
+        handlers::get_role_assignments,
+        handlers::assign_role,
+        handlers::unassign_role,
+        handlers::provision_totp,
+        handlers::confirm_totp,
+        handlers::liveness,
+        handlers::readiness,
+    ),
+    components(schemas(
+        OrderRequest,
+        OrderResponse,
+        ExternalFill,
+        DryRunDetails,
+        SimulatedFill,
+        CancelOrderRequest,
+        CancelOrderResponse,
+        OrderStatusResponse,
+        ExecutionResponse,
+        OrderBookResponse,
+        MarketStatisticsResponse,
+        CandleResponse,
+        CandleData,
+        TickerResponse,
+        TickerData,
+        IndexPriceResponse,
+        IndexPriceUpdate,
+        FundingHistoryResponse,
+        FundingEvent,
+        FuturesContractsResponse,
+        FuturesContractStatus,
+        SettlementMethod,
+        ContractState,
+        AssetsResponse,
+        AssetData,
+        AssetKind,
+        PortfolioResponse,
+        PortfolioHolding,
+        UserBalanceResponse,
+        AssetBalanceData,
+        UserOrdersResponse,
+        UserOrderData,
+        OrderReservationData,
+        MicrostructureResponse,
+        MarketImpactResponse,
+        LiquidityBand,
+        ArbitrageOpportunityData,
+        ArbitrageOpportunitiesResponse,
+        ClientStatsResponse,
+        ClientTradingStats,
+        ClientWindowStats,
+        StatsWindow,
+        MessageUsageResponse,
+        MessageUsageSummaryResponse,
+        MessageCounts,
+        MinuteUsage,
+        ClientMessageUsage,
+        RebateReport,
+        RebateReportRow,
+        KycUpdateRequest,
+        KycAccountResponse,
+        KycChangeData,
+        KycHistoryResponse,
+        FeeTierResponse,
+        ClientFeeTier,
+        KillSwitchRequest,
+        KillSwitchResponse,
+        KillSwitchListResponse,
+        AdminSymbolsResponse,
+        AdminSymbolData,
+        EngineStatsResponse,
+        MetricsHistoryResponse,
+        MetricsHourPoint,
+        SymbolMatchStats,
+        CancelClientOrdersResponse,
+        KillSwitchEntry,
+        KillSwitchScope,
+        RiskLimitsRequest,
+        RiskProfileResponse,
+        RiskLimits,
+        ClientRiskLimits,
+        KycLevel,
+        KycStatus,
+        NotificationRulesResponse,
+        PendingEscalationsResponse,
+        IncidentsResponse,
+        IncidentNoteRequest,
+        IncidentResolveRequest,
+        Incident,
+        IncidentNote,
+        IncidentStatus,
+        RoutingRule,
+        QuietHours,
+        EscalationPolicy,
+        PendingEscalation,
+        ReplicationStatus,
+        ReplicationRole,
+        RecoveryJobRequest,
+        DbBackupsResponse,
+        BackupMetadata,
+        RecoveryJobsResponse,
+        RecoveryJob,
+        RecoveryJobState,
+        RecoveryFilter,
+        MQType,
+        QuarantinedMessagesResponse,
+        QuarantinedMessage,
+        QuarantineReason,
+        QuarantineStatus,
+        NotificationChannel,
+        NotificationPriority,
+        NotificationType,
+        OrderBookSnapshot,
+        LoginRequest,
+        RefreshTokenRequest,
+        IssueApiKeyRequest,
+        IpAllowlistRequest,
+        IpAllowlistResponse,
+        RolesResponse,
+        RolePermissionsRequest,
+        RoleAssignmentsResponse,
+        RoleAssignmentRequest,
+        RolePermissions,
+        RoleAssignment,
+        Role,
+        Permission,
+        SubjectKind,
+        TokenPair,
+        IssuedApiKey,
+        AuthContext,
+        Scope,
+        TotpConfirmRequest,
+        TotpProvisioning,
+        LivenessResponse,
+        ReadinessReport,
+        ReadinessCheck,
+        ErrorResponse,
+        ExecutionReport,
+        EngineOrderBookSnapshot,
+        ExecType,
+        OrderType,
+        Side,
+    )),
+    tags(
+        (name = "orders", description = "주문 제출/취소/조회"),
+        (name = "market-data", description = "호가, 체결, 통계, 봉차트"),
+        (name = "accounts", description = "계좌 잔고 평가"),
+        (name = "admin", description = "계정 KYC 관리 (X-Admin-Token 필요)"),
+        (name = "auth", description = "웹 UI 로그인과 토큰 갱신"),
+        (name = "health", description = "오케스트레이터용 생존/준비 상태"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Swagger UI 라우터 (`/docs`, `/api-docs/openapi.json`)
+pub fn docs_router<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    SwaggerUi::new("/docs")
+        .url("/api-docs/openapi.json", ApiDoc::openapi())
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spec_covers_all_routes() {
+        let spec = ApiDoc::openapi();
+        let paths: Vec<&String> = spec.paths.paths.keys().collect();
+
+        for path in [
+            "/v1/order",
+            "/v1/order/cancel",
+            "/v1/order/{order_id}",
+            "/api/v1/orderbook/{symbol}",
+            "/api/v1/executions/{symbol}",
+            "/api/v1/statistics/{symbol}",
+            "/api/v1/klines/{symbol}/{interval}",
+            "/v1/market/{symbol}/microstructure",
+            "/v1/market/{symbol}/impact",
+            "/v1/ticker",
+            "/v1/index",
+            "/v1/funding/{symbol}",
+            "/v1/futures",
+            "/v1/assets",
+            "/v1/portfolio/{client_id}",
+            "/v1/user/{client_id}/balance",
+            "/v1/user/{client_id}/orders",
+            "/v1/usage/{client_id}",
+            "/v1/export/trades",
+            "/api/v1/sync/{symbol}",
+            "/v1/arbitrage/opportunities",
+            "/v1/stats/clients",
+            "/v1/admin/usage",
+            "/v1/admin/rebates/{month}",
+            "/v1/admin/kyc/{client_id}",
+            "/v1/admin/kyc/{client_id}/history",
+            "/v1/admin/fees/{client_id}",
+            "/v1/admin/api-keys",
+            "/v1/admin/api-keys/{key_id}",
+            "/v1/admin/api-keys/{key_id}/ip-allowlist",
+            "/v1/admin/rbac/roles",
+            "/v1/admin/rbac/roles/{role}",
+            "/v1/admin/rbac/assignments",
+            "/v1/admin/rbac/assignments/{subject_kind}/{subject}",
+            "/v1/admin/totp/provision",
+            "/v1/admin/totp/confirm",
+            "/v1/auth/login",
+            "/v1/auth/refresh",
+            "/v1/auth/logout",
+            "/v1/auth/me",
+            "/v1/admin/kill-switch",
+            "/v1/admin/kill-switch/clients/{client_id}",
+            "/v1/admin/kill-switch/symbols/{symbol}",
+            "/v1/admin/risk/{client_id}",
+            "/v1/admin/notifications/rules",
+            "/v1/admin/notifications/rules/{rule_id}",
+            "/v1/admin/notifications/escalations",
+            "/v1/admin/incidents",
+            "/v1/admin/incidents/{incident_id}",
+            "/v1/admin/incidents/{incident_id}/ack",
+            "/v1/admin/incidents/{incident_id}/notes",
+            "/v1/admin/incidents/{incident_id}/resolve",
+            "/v1/admin/replication",
+            "/v1/admin/replication/promote",
+            "/v1/metrics/history",
+            "/v1/admin/db/backups",
+            "/v1/admin/recovery/jobs",
+            "/v1/admin/recovery/jobs/{job_id}",
+            "/v1/admin/recovery/jobs/{job_id}/cancel",
+            "/v1/admin/dlq/messages",
+            "/v1/admin/dlq/messages/{quarantine_id}",
+            "/v1/admin/dlq/messages/{quarantine_id}/requeue",
+            "/v1/admin/dlq/messages/{quarantine_id}/discard",
+            "/healthz",
+            "/readyz",
+        ] {
+            assert!(paths.iter().any(|p| p.as_str() == path), "스펙에 경로 없음: {}", path);
+        }
+
+        let json = spec.to_json().unwrap();
+        assert!(json.contains("\"OrderRequest\""));
+        assert!(json.contains("\"ErrorResponse\""));
+    }
+}
@@ -1,25 +1,274 @@
 use axum::{
-    routing::{get, post},
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    routing::{delete, get, post, put},
     Router,
 };
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
 
+use crate::api::error::ApiError;
 use crate::api::handlers::*;
+use crate::api::openapi::docs_router;
+use crate::api::dashboard_ui::dashboard_ui_router;
+use crate::api::dashboard_websocket::dashboard_websocket_handler;
+use crate::api::websocket::{bbo_websocket_handler, candle_websocket_handler, index_websocket_handler, private_websocket_handler, websocket_handler};
+use crate::auth::{AuthContext, AuthError};
+use crate::performance::MetricsCollector;
+use crate::rbac::{Permission, SubjectKind};
+use crate::sequencer::{MessageKind, MessageUsage};
 use crate::server::ServerState;
 
+/// REST 요청 처리 시간 타이머 이름 (대시보드 지연 백분위수)
+pub const REQUEST_LATENCY_TIMER: &str = "api.request.latency_ms";
+
 /// API 라우터 생성
-pub fn create_api_router() -> Router<ServerState> {
-    Router::new()
-        // 주문 관련 API
+///
+/// 주문/계좌 API와 관리자 API는 경로 묶음마다 필요한 RBAC 권한을 `require_permission`으로 확인합니다.
+/// 관리자 TOTP 등록은 본인 자격 증명이므로 권한을 확인하지 않습니다.
+pub fn create_api_router(state: &ServerState) -> Router<ServerState> {
+    let require = |permission: Permission| {
+        axum::middleware::from_fn_with_state((state.clone(), permission), require_permission)
+    };
+
+    // 주문 제출/취소, 본인 주문 상태 조회 (orders:write)
+    let orders = Router::new()
         .route("/v1/order", post(submit_order))
         .route("/v1/order/cancel", post(cancel_order))
         .route("/v1/order/:order_id", get(get_order_status))
-        
+        .route_layer(require(Permission::OrdersWrite));
+
+    // 계좌 API, 개인 체결 WebSocket (account:read)
+    let account = Router::new()
+        .route("/v1/portfolio/:client_id", get(get_portfolio))
+        .route("/v1/user/:client_id/balance", get(get_user_balance))
+        .route("/v1/user/:client_id/orders", get(get_user_orders))
+        .route("/v1/usage/:client_id", get(get_message_usage))
+        .route("/ws/private/:client_id", get(private_websocket_handler))
+        .route_layer(require(Permission::AccountRead));
+
+    // 킬 스위치, 계정 리스크 한도, 계정 주문 일괄 취소 (risk:manage)
+    let risk = Router::new()
+        .route("/v1/admin/kill-switch", get(get_kill_switches))
+        .route("/v1/admin/kill-switch/clients/:client_id", put(block_client).delete(unblock_client))
+        .route("/v1/admin/kill-switch/symbols/:symbol", put(halt_symbol).delete(resume_symbol))
+        .route("/v1/admin/clients/:client_id/cancel-orders", post(cancel_client_orders))
+        .route("/v1/admin/risk/:client_id", get(get_risk_limits).put(update_risk_limits).delete(reset_risk_limits))
+        .route_layer(require(Permission::RiskManage));
+
+    // 계정별 거래 통계, KYC, 수수료 등급 (compliance:manage)
+    let compliance = Router::new()
+        .route("/v1/stats/clients", get(get_client_stats))
+        .route("/v1/admin/usage", get(get_message_usage_summary))
+        .route("/v1/admin/rebates/:month", get(get_rebate_report))
+        .route("/v1/admin/kyc/:client_id", get(get_kyc_account).put(update_kyc_account))
+        .route("/v1/admin/kyc/:client_id/history", get(get_kyc_history))
+        .route("/v1/admin/fees/:client_id", get(get_fee_tier))
+        .route_layer(require(Permission::ComplianceManage));
+
+    // 심볼/엔진 상태, 알림, 인시던트, 복제, 백업, 복구, DLQ (ops:manage)
+    let ops = Router::new()
+        .route("/v1/admin/symbols", get(get_admin_symbols))
+        .route("/v1/admin/engine/stats", get(get_engine_stats))
+        .route("/v1/metrics/history", get(get_metrics_history))
+        .route("/v1/admin/notifications/rules", get(get_notification_rules))
+        .route(
+            "/v1/admin/notifications/rules/:rule_id",
+            put(put_notification_rule).delete(delete_notification_rule),
+        )
+        .route("/v1/admin/notifications/escalations", get(get_pending_escalations))
+        .route("/v1/admin/incidents", get(get_incidents))
+        .route("/v1/admin/incidents/:incident_id", get(get_incident))
+        .route("/v1/admin/incidents/:incident_id/ack", post(acknowledge_incident))
+        .route("/v1/admin/incidents/:incident_id/notes", post(add_incident_note))
+        .route("/v1/admin/incidents/:incident_id/resolve", post(resolve_incident))
+        .route("/v1/admin/replication", get(get_replication_status))
+        .route("/v1/admin/replication/promote", post(promote_replica))
+        .route("/v1/admin/db/backups", get(get_db_backups).post(create_db_backup))
+        .route("/v1/admin/recovery/jobs", get(get_recovery_jobs).post(start_recovery_job))
+        .route("/v1/admin/recovery/jobs/:job_id", get(get_recovery_job))
+        .route("/v1/admin/recovery/jobs/:job_id/cancel", post(cancel_recovery_job))
+        .route("/v1/admin/dlq/messages", get(get_quarantined_messages))
+        .route("/v1/admin/dlq/messages/:quarantine_id", get(get_quarantined_message))
+        .route("/v1/admin/dlq/messages/:quarantine_id/requeue", post(requeue_quarantined_message))
+        .route("/v1/admin/dlq/messages/:quarantine_id/discard", post(discard_quarantined_message))
+        .route_layer(require(Permission::OpsManage));
+
+    // API 키, 역할과 권한 (access:manage)
+    let access = Router::new()
+        .route("/v1/admin/api-keys", post(issue_api_key))
+        .route("/v1/admin/api-keys/:key_id", delete(revoke_api_key))
+        .route(
+            "/v1/admin/api-keys/:key_id/ip-allowlist",
+            get(get_api_key_ip_allowlist).put(update_api_key_ip_allowlist),
+        )
+        .route("/v1/admin/rbac/roles", get(get_roles))
+        .route("/v1/admin/rbac/roles/:role", put(update_role_permissions))
+        .route("/v1/admin/rbac/assignments", get(get_role_assignments))
+        .route("/v1/admin/rbac/assignments/:subject_kind/:subject", put(assign_role).delete(unassign_role))
+        .route_layer(require(Permission::AccessManage));
+
+    Router::new()
         // 시장 데이터 API
         .route("/api/v1/orderbook/:symbol", get(get_orderbook))
         .route("/api/v1/executions/:symbol", get(get_executions))
         .route("/api/v1/statistics/:symbol", get(get_statistics))
         .route("/api/v1/klines/:symbol/:interval", get(get_candles))
+        .route("/v1/market/:symbol/microstructure", get(get_microstructure))
+        .route("/v1/market/:symbol/impact", get(get_market_impact))
+        .route("/v1/ticker", get(get_ticker))
+        .route("/v1/index", get(get_index_prices))
+        .route("/v1/funding/:symbol", get(get_funding_history))
+        .route("/v1/futures", get(get_futures_contracts))
+        .route("/v1/assets", get(get_assets))
+        
+        // 웹 UI 로그인 (API 키 → 액세스/리프레시 토큰)
+        .route("/v1/auth/login", post(login))
+        .route("/v1/auth/refresh", post(refresh_token))
+        .route("/v1/auth/logout", post(logout))
+        .route("/v1/auth/me", get(get_auth_context))
+        
+        // 과거 시장 데이터 내보내기 API
+        .route("/v1/export/trades", get(export_trades))
+        
+        // 외부 거래소 차익거래 기회 API
+        .route("/v1/arbitrage/opportunities", get(get_arbitrage_opportunities))
+        
+        // 관리자 본인 TOTP 등록 (X-Admin-Token 필요)
+        .route("/v1/admin/totp/provision", post(provision_totp))
+        .route("/v1/admin/totp/confirm", post(confirm_totp))
+        
+        // 권한별 주문/계좌/관리자 API
+        .merge(orders)
+        .merge(account)
+        .merge(risk)
+        .merge(compliance)
+        .merge(ops)
+        .merge(access)
         
         // 하이브리드 호가창 동기화 API
         .route("/api/v1/sync/:symbol", get(sync_orderbook))
-}
\ No newline at end of file
+        
+        // 오케스트레이터용 생존/준비 상태
+        .route("/healthz", get(liveness))
+        .route("/readyz", get(readiness))
+
+        // 실시간 체결/시장 데이터 WebSocket
+        .route("/ws", get(websocket_handler))
+
+        // 최우선 호가(BBO) 변경 전용 WebSocket
+        .route("/ws/bbo", get(bbo_websocket_handler))
+
+        // 인덱스/공정 가격 WebSocket
+        .route("/ws/index", get(index_websocket_handler))
+
+        // 심볼/간격별 실시간 봉 WebSocket
+        .route("/ws/candles", get(candle_websocket_handler))
+
+        // 관리자 대시보드 실시간 위젯 업데이트 WebSocket (핸들러에서 관리자 토큰과 ops:manage 확인)
+        .route("/ws/dashboard", get(dashboard_websocket_handler))
+        
+        // OpenAPI 스펙 및 Swagger UI
+        .merge(docs_router())
+
+        // 내장 운영 대시보드 UI
+        .merge(dashboard_ui_router())
+}
+
+/// 경로 묶음별 RBAC 권한 확인 미들웨어
+///
+/// 계정 권한은 인증 미들웨어가 넣은 `AuthContext` 계정으로, 관리자 권한은 관리자 토큰을 확인한 뒤
+/// `X-Admin-User` 관리자로 확인합니다. 인증이 꺼져 있으면 계정을 알 수 없으므로 기본 계정 역할로 판정하고,
+/// 기본 역할이 없으면 거부합니다. 자격 증명이 없거나 관리자 토큰이 틀린 요청도 권한 거부로 감사 로그에 남깁니다.
+pub async fn require_permission(
+    State((state, permission)): State<(ServerState, Permission)>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let target = format!("{} {}", request.method(), request.uri().path());
+    let subject = match permission.subject_kind() {
+        SubjectKind::Client => match (&state.auth, request.extensions().get::<AuthContext>()) {
+            (None, _) => {
+                return match state.rbac.authorize_anonymous(permission, &target).await {
+                    Ok(_) => next.run(request).await,
+                    Err(e) => ApiError::from(e).into_response(),
+                };
+            }
+            (Some(_), None) => {
+                let error = ApiError::from(AuthError::Missing);
+                state.rbac.record_unauthenticated(None, permission, &target, &error.message).await;
+                return error.into_response();
+            }
+            (Some(_), Some(auth)) => auth.client_id.clone(),
+        },
+        SubjectKind::Admin => match authorize_admin(&state, request.headers()) {
+            Ok(actor) => actor,
+            Err(e) => {
+                let claimed = request.headers().get("x-admin-user").and_then(|v| v.to_str().ok()).filter(|v| !v.is_empty());
+                state.rbac.record_unauthenticated(claimed, permission, &target, &e.message).await;
+                return e.into_response();
+            }
+        },
+    };
+
+    match state.rbac.authorize(&subject, permission, &target).await {
+        Ok(_) => next.run(request).await,
+        Err(e) => ApiError::from(e).into_response(),
+    }
+}
+
+/// 인증된 계정의 조회(GET) 요청을 분당 메시지 사용량에 세는 미들웨어
+///
+/// 인증 미들웨어 안쪽에 두어 `AuthContext`가 있는 요청만 셉니다. WebSocket, 대시보드,
+/// 헬스체크, API 문서는 세지 않으며 하드 상한을 넘으면 `RATE_LIMITED`로 거부합니다.
+pub async fn count_client_queries(
+    State(usage): State<Arc<MessageUsage>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path();
+    let counted = request.method() == axum::http::Method::GET
+        && !(path.starts_with("/ws")
+            || path.starts_with("/dashboard")
+            || path.starts_with("/docs")
+            || path.starts_with("/api-docs")
+            || path == "/healthz"
+            || path == "/readyz");
+    if let Some(auth) = request.extensions().get::<AuthContext>().filter(|_| counted) {
+        if let Err(e) = usage.record(&auth.client_id, MessageKind::Query, usage.now_millis()) {
+            return ApiError::from(e).into_response();
+        }
+    }
+    next.run(request).await
+}
+
+/// REST 요청 처리 시간 기록 미들웨어
+///
+/// WebSocket 경로는 연결 수립만 측정되고 대시보드 UI는 정적 파일이므로 제외합니다.
+/// 오케스트레이터가 주기적으로 호출하는 `/healthz`, `/readyz` 도 지연 통계를 왜곡하지 않도록 제외합니다.
+pub async fn track_request_latency(
+    State(metrics): State<Arc<MetricsCollector>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+    if route.starts_with("/ws") || route.starts_with("/dashboard") || route == "/healthz" || route == "/readyz" {
+        return next.run(request).await;
+    }
+
+    let started = Instant::now();
+    let response = next.run(request).await;
+    let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+    let mut tags = HashMap::new();
+    tags.insert("route".to_string(), route);
+    tags.insert("status".to_string(), response.status().as_u16().to_string());
+    metrics.record_timer(REQUEST_LATENCY_TIMER, elapsed_ms, tags).await;
+    response
+}
@@ -0,0 +1,273 @@
+//! REST/WebSocket 서버 TLS 종단 (rustls)
+//!
+//! 인증서와 개인키(PEM)를 설정하면 외부 프록시 없이 REST API와 WebSocket을 HTTPS/WSS로 제공합니다 (`tls` 기능 필요).
+//! 관리자 클라이언트 CA를 함께 설정하면 핸드셰이크에서 클라이언트 인증서를 선택적으로 검증하고,
+//! 관리자 엔드포인트(`/v1/admin/*`, `/ws/dashboard`)는 그 CA로 검증된 인증서를 제시한 연결만 허용합니다 (mTLS).
+//! 일반 API는 클라이언트 인증서 없이 그대로 사용할 수 있으며, 관리자 토큰 인증도 계속 적용됩니다.
+
+use std::path::PathBuf;
+
+use axum::{extract::Request, middleware::Next, response::{IntoResponse, Response}};
+
+use crate::api::error::{ApiError, ErrorCode};
+
+/// TLS 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlsConfig {
+    /// 서버 인증서 체인 (PEM, 리프 인증서부터)
+    pub cert_path: PathBuf,
+    /// 서버 개인키 (PEM, PKCS#8/PKCS#1/SEC1)
+    pub key_path: PathBuf,
+    /// 관리자 클라이언트 인증서를 검증할 CA (PEM, None이면 mTLS 없음)
+    pub admin_client_ca: Option<PathBuf>,
+}
+
+impl TlsConfig {
+    pub fn new(cert_path: impl Into<PathBuf>, key_path: impl Into<PathBuf>) -> Self {
+        Self {
+            cert_path: cert_path.into(),
+            key_path: key_path.into(),
+            admin_client_ca: None,
+        }
+    }
+
+    pub fn with_admin_client_ca(mut self, path: impl Into<PathBuf>) -> Self {
+        self.admin_client_ca = Some(path.into());
+        self
+    }
+}
+
+/// TLS 설정 오류
+#[derive(Debug, thiserror::Error)]
+pub enum TlsError {
+    #[error("{path} 읽기 실패: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("{0}에 인증서가 없습니다")]
+    NoCertificate(PathBuf),
+    #[error("{0}에 개인키가 없습니다")]
+    NoPrivateKey(PathBuf),
+    #[error("TLS 설정 오류: {0}")]
+    Config(String),
+    #[error("TLS 서버 실행 실패: {0}")]
+    Serve(std::io::Error),
+    #[error("TLS는 `tls` 기능으로 빌드해야 사용할 수 있습니다")]
+    Unavailable,
+}
+
+/// TLS 연결 정보 (TLS로 받은 모든 요청의 확장에 추가)
+#[derive(Debug, Clone, Default)]
+pub struct TlsPeer {
+    /// 관리자 CA로 검증된 클라이언트 리프 인증서 (DER, 제시하지 않았으면 None)
+    pub client_certificate: Option<Vec<u8>>,
+}
+
+/// mTLS를 요구하는 관리자 엔드포인트
+pub fn is_admin_path(path: &str) -> bool {
+    path.starts_with("/v1/admin/") || path == "/ws/dashboard"
+}
+
+/// 관리자 엔드포인트 mTLS 확인 미들웨어 (`admin_client_ca` 설정 시에만 라우터에 추가)
+pub async fn require_admin_client_certificate(request: Request, next: Next) -> Response {
+    let verified = request
+        .extensions()
+        .get::<TlsPeer>()
+        .is_some_and(|peer| peer.client_certificate.is_some());
+    if is_admin_path(request.uri().path()) && !verified {
+        return ApiError::new(ErrorCode::Unauthorized, "관리자 API는 검증된 클라이언트 인증서가 필요합니다 (mTLS)")
+            .into_response();
+    }
+    next.run(request).await
+}
+
+#[cfg(feature = "tls")]
+mod rustls_server {
+    use std::fs::File;
+    use std::future::Future;
+    use std::io::BufReader;
+    use std::net::SocketAddr;
+    use std::path::Path;
+    use std::pin::Pin;
+    use std::sync::Arc;
+
+    use axum::Router;
+    use axum_server::accept::Accept;
+    use axum_server::tls_rustls::{RustlsAcceptor, RustlsConfig};
+    use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+    use rustls::server::WebPkiClientVerifier;
+    use tokio::io::{AsyncRead, AsyncWrite};
+    use tokio_rustls::server::TlsStream;
+    use tower::Layer;
+
+    use super::{TlsConfig, TlsError, TlsPeer};
+
+    fn open(path: &Path) -> Result<BufReader<File>, TlsError> {
+        File::open(path)
+            .map(BufReader::new)
+            .map_err(|source| TlsError::Io { path: path.to_path_buf(), source })
+    }
+
+    fn load_certificates(path: &Path) -> Result<Vec<CertificateDer<'static>>, TlsError> {
+        let certificates = rustls_pemfile::certs(&mut open(path)?)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|source| TlsError::Io { path: path.to_path_buf(), source })?;
+        if certificates.is_empty() {
+            return Err(TlsError::NoCertificate(path.to_path_buf()));
+        }
+        Ok(certificates)
+    }
+
+    fn load_private_key(path: &Path) -> Result<PrivateKeyDer<'static>, TlsError> {
+        rustls_pemfile::private_key(&mut open(path)?)
+            .map_err(|source| TlsError::Io { path: path.to_path_buf(), source })?
+            .ok_or_else(|| TlsError::NoPrivateKey(path.to_path_buf()))
+    }
+
+    /// rustls 서버 설정 생성 (관리자 CA가 있으면 클라이언트 인증서를 선택적으로 검증)
+    pub fn load_server_config(config: &TlsConfig) -> Result<Arc<rustls::ServerConfig>, TlsError> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let builder = rustls::ServerConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .map_err(|e| TlsError::Config(e.to_string()))?;
+
+        let builder = match &config.admin_client_ca {
+            Some(ca_path) => {
+                let mut roots = rustls::RootCertStore::empty();
+                for certificate in load_certificates(ca_path)? {
+                    roots.add(certificate).map_err(|e| TlsError::Config(format!("{}: {}", ca_path.display(), e)))?;
+                }
+                let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                    .allow_unauthenticated()
+                    .build()
+                    .map_err(|e| TlsError::Config(e.to_string()))?;
+                builder.with_client_cert_verifier(verifier)
+            }
+            None => builder.with_no_client_auth(),
+        };
+
+        let mut server_config = builder
+            .with_single_cert(load_certificates(&config.cert_path)?, load_private_key(&config.key_path)?)
+            .map_err(|e| TlsError::Config(e.to_string()))?;
+        server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+        Ok(Arc::new(server_config))
+    }
+
+    /// rustls 핸드셰이크 후 클라이언트 인증서를 요청 확장(`TlsPeer`)으로 전달하는 acceptor
+    #[derive(Clone)]
+    pub struct TlsPeerAcceptor {
+        inner: RustlsAcceptor,
+    }
+
+    impl<I, S> Accept<I, S> for TlsPeerAcceptor
+    where
+        I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+        S: Send + 'static,
+    {
+        type Stream = TlsStream<I>;
+        type Service = <axum::Extension<TlsPeer> as Layer<S>>::Service;
+        type Future = Pin<Box<dyn Future<Output = std::io::Result<(Self::Stream, Self::Service)>> + Send>>;
+
+        fn accept(&self, stream: I, service: S) -> Self::Future {
+            let handshake = self.inner.accept(stream, service);
+            Box::pin(async move {
+                let (stream, service) = handshake.await?;
+                let peer = TlsPeer {
+                    client_certificate: stream
+                        .get_ref()
+                        .1
+                        .peer_certificates()
+                        .and_then(|chain| chain.first())
+                        .map(|certificate| certificate.to_vec()),
+                };
+                Ok((stream, axum::Extension(peer).layer(service)))
+            })
+        }
+    }
+
+    /// HTTPS/WSS로 라우터 제공
+    pub async fn serve(addr: SocketAddr, router: Router, config: &TlsConfig) -> Result<(), TlsError> {
+        let acceptor = TlsPeerAcceptor {
+            inner: RustlsAcceptor::new(RustlsConfig::from_config(load_server_config(config)?)),
+        };
+        axum_server::bind(addr)
+            .acceptor(acceptor)
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
+            .await
+            .map_err(TlsError::Serve)
+    }
+}
+
+#[cfg(feature = "tls")]
+pub use rustls_server::{load_server_config, serve, TlsPeerAcceptor};
+
+/// 시작 시 인증서/개인키/CA 확인 (서비스를 띄우기 전에 설정 오류를 드러냄)
+pub fn validate(config: &TlsConfig) -> Result<(), TlsError> {
+    #[cfg(feature = "tls")]
+    return load_server_config(config).map(|_| ());
+    #[cfg(not(feature = "tls"))]
+    {
+        let _ = config;
+        Err(TlsError::Unavailable)
+    }
+}
+
+/// HTTPS/WSS로 라우터 제공 (`tls` 기능 없이 빌드됨)
+#[cfg(not(feature = "tls"))]
+pub async fn serve(_addr: std::net::SocketAddr, _router: axum::Router, _config: &TlsConfig) -> Result<(), TlsError> {
+    Err(TlsError::Unavailable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::StatusCode, middleware, routing::get, Router};
+    use tower::Service;
+
+    fn router() -> Router {
+        Router::new()
+            .route("/v1/admin/kill-switch", get(|| async { "ok" }))
+            .route("/v1/ticker", get(|| async { "ok" }))
+            .layer(middleware::from_fn(require_admin_client_certificate))
+    }
+
+    async fn status(path: &str, peer: Option<TlsPeer>) -> StatusCode {
+        let mut request = Request::builder().uri(path).body(Body::empty()).unwrap();
+        if let Some(peer) = peer {
+            request.extensions_mut().insert(peer);
+        }
+        router().call(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_admin_paths_require_client_certificate() {
+        let verified = TlsPeer { client_certificate: Some(vec![0x30]) };
+
+        assert_eq!(status("/v1/admin/kill-switch", None).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status("/v1/admin/kill-switch", Some(TlsPeer::default())).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status("/v1/admin/kill-switch", Some(verified)).await, StatusCode::OK);
+        // 일반 API는 인증서 없이 허용
+        assert_eq!(status("/v1/ticker", Some(TlsPeer::default())).await, StatusCode::OK);
+
+        assert!(is_admin_path("/ws/dashboard"));
+        assert!(!is_admin_path("/dashboard"));
+    }
+
+    #[cfg(feature = "tls")]
+    #[test]
+    fn test_load_server_config_reports_missing_files() {
+        let dir = std::env::temp_dir().join(format!("xtrader_tls_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let empty = dir.join("empty.pem");
+        std::fs::write(&empty, "").unwrap();
+
+        let missing = TlsConfig::new(dir.join("missing.pem"), &empty);
+        assert!(matches!(load_server_config(&missing), Err(TlsError::Io { .. })));
+        let no_certificate = TlsConfig::new(&empty, &empty);
+        assert!(matches!(load_server_config(&no_certificate), Err(TlsError::NoCertificate(_))));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,229 @@
+//! 주문 요청 검증
+//!
+//! 주문 핸들러에서 시퀀서로 보내기 전에 요청을 검증합니다.
+//! 잘못된 주문은 엔진에서 조용히 버려지는 대신 구조화된 오류(`ApiError`)로 거부됩니다.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::api::error::{ApiError, ErrorCode};
+use crate::api::models::OrderRequest;
+use crate::matching_engine::model::OrderType;
+
+/// client_id 최대 길이
+const MAX_CLIENT_ID_LEN: usize = 64;
+
+/// 주문 태그 최대 길이
+const MAX_TAG_LEN: usize = 64;
+
+/// 주문 요청 검증기
+#[derive(Debug, Clone)]
+pub struct OrderValidator {
+    /// 거래 가능한 심볼
+    symbols: HashSet<String>,
+    /// 심볼별 1회 최대 주문 수량
+    max_order_size: HashMap<String, u64>,
+}
+
+impl OrderValidator {
+    /// 새 검증기 생성
+    pub fn new(symbols: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            symbols: symbols.into_iter().collect(),
+            max_order_size: HashMap::new(),
+        }
+    }
+
+    /// 심볼별 최대 주문 수량 설정
+    pub fn with_max_order_sizes(mut self, max_order_size: HashMap<String, u64>) -> Self {
+        self.max_order_size = max_order_size;
+        self
+    }
+
+    /// 거래 가능한 심볼인지 확인
+    pub fn is_supported_symbol(&self, symbol: &str) -> bool {
+        self.symbols.contains(symbol)
+    }
+
+    /// 심볼의 최대 주문 수량 (제한 없으면 None)
+    pub fn max_order_size(&self, symbol: &str) -> Option<u64> {
+        self.max_order_size.get(symbol).copied()
+    }
+
+    /// 주문 요청 검증 (`now`: 현재 Unix 타임스탬프, 초)
+    pub fn validate(&self, request: &OrderRequest, now: u64) -> Result<(), ApiError> {
+        if !self.is_supported_symbol(&request.symbol) {
+            return Err(ApiError::new(
+                ErrorCode::InvalidSymbol,
+                format!("지원하지 않는 심볼입니다: {}", request.symbol),
+            ));
+        }
+
+        validate_client_id(&request.client_id)?;
+
+        if request.quantity == 0 {
+            return Err(ApiError::new(ErrorCode::InvalidQuantity, "수량은 0보다 커야 합니다"));
+        }
+
+        if let Some(max) = self.max_order_size(&request.symbol) {
+            if request.quantity > max {
+                return Err(ApiError::new(
+                    ErrorCode::InvalidQuantity,
+                    format!("{} 최대 주문 수량({})을 초과했습니다", request.symbol, max),
+                ));
+            }
+        }
+
+        match request.order_type {
+            OrderType::Limit => match request.price {
+                None => {
+                    return Err(ApiError::new(ErrorCode::MissingPrice, "지정가 주문에는 가격이 필요합니다"));
+                }
+                Some(0) => {
+                    return Err(ApiError::new(ErrorCode::InvalidPrice, "가격은 0보다 커야 합니다"));
+                }
+                Some(_) => {}
+            },
+            OrderType::Market => {
+                if request.price.is_some() {
+                    return Err(ApiError::new(ErrorCode::InvalidPrice, "시장가 주문에는 가격을 지정할 수 없습니다"));
+                }
+            }
+        }
+
+        if let Some(pct) = request.max_slippage_pct {
+            if pct.is_nan() || pct <= 0.0 {
+                return Err(ApiError::new(ErrorCode::InvalidSlippage, "슬리피지 한도는 0보다 커야 합니다"));
+            }
+        }
+
+        if request.max_levels == Some(0) {
+            return Err(ApiError::new(ErrorCode::InvalidMaxLevels, "최대 레벨 수는 0보다 커야 합니다"));
+        }
+
+        if let Some(expire_time) = request.expire_time {
+            if expire_time <= now {
+                return Err(ApiError::new(ErrorCode::InvalidExpireTime, "만료 시간은 현재 시각 이후여야 합니다"));
+            }
+        }
+
+        if let Some(tag) = &request.tag {
+            validate_tag(tag)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// client_id 형식 검증 (1~64자, 영문/숫자/`_`/`-`)
+pub fn validate_client_id(client_id: &str) -> Result<(), ApiError> {
+    let valid = !client_id.is_empty()
+        && client_id.len() <= MAX_CLIENT_ID_LEN
+        && client_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ApiError::new(
+            ErrorCode::InvalidClientId,
+            format!("client_id는 1~{}자의 영문, 숫자, '_', '-'만 사용할 수 있습니다", MAX_CLIENT_ID_LEN),
+        ))
+    }
+}
+
+/// 주문 태그 형식 검증 (1~64자, 영문/숫자/`_`/`-`/`.`/`:`)
+pub fn validate_tag(tag: &str) -> Result<(), ApiError> {
+    let valid = !tag.is_empty()
+        && tag.len() <= MAX_TAG_LEN
+        && tag.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ApiError::new(
+            ErrorCode::InvalidTag,
+            format!("tag는 1~{}자의 영문, 숫자, '_', '-', '.', ':'만 사용할 수 있습니다", MAX_TAG_LEN),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching_engine::model::Side;
+
+    fn validator() -> OrderValidator {
+        OrderValidator::new(vec!["BTC-KRW".to_string()])
+            .with_max_order_sizes(HashMap::from([("BTC-KRW".to_string(), 100)]))
+    }
+
+    fn request(order_type: OrderType, price: Option<u64>, quantity: u64) -> OrderRequest {
+        OrderRequest {
+            symbol: "BTC-KRW".to_string(),
+            side: Side::Buy,
+            order_type,
+            price,
+            quantity,
+            client_id: "test_user_001".to_string(),
+            max_slippage_pct: None,
+            max_levels: None,
+            expire_time: None,
+            route_external: false,
+            dry_run: false,
+            max_wait_ms: None,
+            tag: None,
+        }
+    }
+
+    fn code(result: Result<(), ApiError>) -> Option<ErrorCode> {
+        result.err().map(|e| e.code)
+    }
+
+    #[test]
+    fn test_valid_orders() {
+        let v = validator();
+        assert!(v.validate(&request(OrderType::Limit, Some(1000), 10), 0).is_ok());
+        assert!(v.validate(&request(OrderType::Market, None, 100), 0).is_ok());
+    }
+
+    #[test]
+    fn test_price_rules_by_order_type() {
+        let v = validator();
+        assert_eq!(code(v.validate(&request(OrderType::Limit, None, 1), 0)), Some(ErrorCode::MissingPrice));
+        assert_eq!(code(v.validate(&request(OrderType::Limit, Some(0), 1), 0)), Some(ErrorCode::InvalidPrice));
+        assert_eq!(code(v.validate(&request(OrderType::Market, Some(1000), 1), 0)), Some(ErrorCode::InvalidPrice));
+    }
+
+    #[test]
+    fn test_symbol_quantity_and_client_id() {
+        let v = validator();
+
+        let mut unknown = request(OrderType::Limit, Some(1000), 1);
+        unknown.symbol = "DOGE-KRW".to_string();
+        assert_eq!(code(v.validate(&unknown, 0)), Some(ErrorCode::InvalidSymbol));
+
+        assert_eq!(code(v.validate(&request(OrderType::Limit, Some(1000), 0), 0)), Some(ErrorCode::InvalidQuantity));
+        assert_eq!(code(v.validate(&request(OrderType::Limit, Some(1000), 101), 0)), Some(ErrorCode::InvalidQuantity));
+
+        let mut bad_client = request(OrderType::Limit, Some(1000), 1);
+        bad_client.client_id = "user 1;".to_string();
+        assert_eq!(code(v.validate(&bad_client, 0)), Some(ErrorCode::InvalidClientId));
+        bad_client.client_id = String::new();
+        assert_eq!(code(v.validate(&bad_client, 0)), Some(ErrorCode::InvalidClientId));
+    }
+
+    #[test]
+    fn test_tag_format() {
+        let v = validator();
+
+        let mut tagged = request(OrderType::Limit, Some(1000), 1);
+        tagged.tag = Some("grid-v2:btc.1".to_string());
+        assert!(v.validate(&tagged, 0).is_ok());
+
+        tagged.tag = Some(String::new());
+        assert_eq!(code(v.validate(&tagged, 0)), Some(ErrorCode::InvalidTag));
+        tagged.tag = Some("전략 A".to_string());
+        assert_eq!(code(v.validate(&tagged, 0)), Some(ErrorCode::InvalidTag));
+        tagged.tag = Some("a".repeat(65));
+        assert_eq!(code(v.validate(&tagged, 0)), Some(ErrorCode::InvalidTag));
+    }
+}
@@ -1,86 +1,789 @@
+//! WebSocket 연결 관리
+//!
+//! 연결마다 다음을 관리합니다.
+//! - 주기적 ping 전송, 일정 시간 수신이 없으면 연결 종료
+//! - 연결별 송신 큐: 시장 데이터는 밀리면 심볼별 최신 상태로 병합하고 그래도 용량을 넘으면 버림,
+//!   주문 이벤트는 절대 버리지 않음
+//! - 연결 수, 버린 메시지 수, 송신 지연 메트릭
+//!
+//! `/ws` 는 BBO와 인덱스 가격을 뺀 전체 스트림, `/ws/bbo` 는 최우선 호가 변경만 전달하는 가벼운 채널입니다.
+//! `/ws/index` 는 외부 거래소 시세로 계산한 심볼별 인덱스/공정 가격만 계산 주기마다 전달합니다.
+//! `/ws/candles?symbol=&interval=` 은 한 심볼/간격의 진행 중인 봉과 마감 프레임만 전달합니다.
+//! `/ws/private/{client_id}` 는 계정 주문/체결 이벤트 채널이며, `last_sequence` 를 주면 그 뒤에
+//! 놓친 이벤트를 먼저 다시 보낸 뒤 실시간 이벤트로 이어갑니다.
+
 use axum::{
     extract::{
         ws::{Message, WebSocket, WebSocketUpgrade},
-        State,
+        Path, Query, State,
     },
-    response::Response,
+    response::{IntoResponse, Response},
 };
 use futures::{sink::SinkExt, stream::StreamExt};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::Mutex;
-use tokio::sync::broadcast;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, Notify};
+use log::{debug, info, warn};
+use serde::Deserialize;
 use serde_json::Value;
 
-use crate::api::models::{WebSocketMessage, OrderBookSnapshot};
-use crate::matching_engine::model::ExecutionReport;
+use crate::api::error::{ApiError, ErrorCode};
+use crate::api::models::{CandleData, WebSocketMessage};
+use crate::api::handlers::authorize_client;
+use crate::api::validation::validate_client_id;
+use crate::auth::{AuthContext, Scope};
+use crate::db::repository::PrivateEventRepository;
+use crate::mdp::publisher::{candle_interval_secs, CANDLE_INTERVALS};
+use crate::mdp::{ConflatingQueue, PushOutcome};
+use crate::performance::MetricsCollector;
+use crate::sequencer::private_events::replay_after;
 use crate::server::ServerState;
 
+/// WebSocket 연결 설정
+#[derive(Debug, Clone)]
+pub struct WebSocketConfig {
+    /// ping 전송 주기
+    pub ping_interval: Duration,
+    /// 이 시간 동안 아무것도 수신하지 못하면 연결 종료
+    pub pong_timeout: Duration,
+    /// 연결별 시장 데이터 송신 큐 용량
+    pub market_data_queue_capacity: usize,
+}
+
+impl Default for WebSocketConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval: Duration::from_secs(15),
+            pong_timeout: Duration::from_secs(45),
+            market_data_queue_capacity: 1024,
+        }
+    }
+}
+
+/// WebSocket 연결 메트릭
+#[derive(Debug, Default)]
+pub struct WebSocketMetrics {
+    /// 현재 연결 수
+    active_connections: AtomicUsize,
+    /// 누적 연결 수
+    total_connections: AtomicU64,
+    /// ping 응답 없음으로 끊은 연결 수
+    timed_out: AtomicU64,
+    /// 느린 연결에서 버린 시장 데이터 수
+    dropped_market_data: AtomicU64,
+    /// 느린 연결에서 병합된 시장 데이터 수
+    conflated_market_data: AtomicU64,
+    /// 브로드캐스트 채널에서 놓친 메시지 수
+    broadcast_lagged: AtomicU64,
+    /// 발행 주기 내 최대 송신 지연 (큐 대기 시간, 마이크로초)
+    max_send_lag_us: AtomicU64,
+}
+
+impl WebSocketMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 현재 연결 수
+    pub fn active_connections(&self) -> usize {
+        self.active_connections.load(Ordering::Relaxed)
+    }
+
+    /// 누적 연결 수
+    pub fn total_connections(&self) -> u64 {
+        self.total_connections.load(Ordering::Relaxed)
+    }
+
+    /// 타임아웃으로 끊은 연결 수
+    pub fn timed_out(&self) -> u64 {
+        self.timed_out.load(Ordering::Relaxed)
+    }
+
+    /// 버린 시장 데이터 수
+    pub fn dropped_market_data(&self) -> u64 {
+        self.dropped_market_data.load(Ordering::Relaxed)
+    }
+
+    /// 병합된 시장 데이터 수
+    pub fn conflated_market_data(&self) -> u64 {
+        self.conflated_market_data.load(Ordering::Relaxed)
+    }
+
+    /// 브로드캐스트 채널에서 놓친 메시지 수
+    pub fn broadcast_lagged(&self) -> u64 {
+        self.broadcast_lagged.load(Ordering::Relaxed)
+    }
+
+    fn connection_opened(&self) {
+        self.active_connections.fetch_add(1, Ordering::Relaxed);
+        self.total_connections.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn connection_closed(&self) {
+        self.active_connections.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn record_send_lag(&self, lag: Duration) {
+        self.max_send_lag_us.fetch_max(lag.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// 메트릭 수집기로 게이지 발행 (최대 송신 지연은 발행 후 초기화)
+    pub async fn publish(&self, collector: &MetricsCollector) {
+        collector.set_gauge("websocket.connections.active", self.active_connections() as u64).await;
+        collector.set_gauge("websocket.connections.total", self.total_connections()).await;
+        collector.set_gauge("websocket.connections.timed_out", self.timed_out()).await;
+        collector.set_gauge("websocket.market_data.dropped", self.dropped_market_data()).await;
+        collector.set_gauge("websocket.market_data.conflated", self.conflated_market_data()).await;
+        collector.set_gauge("websocket.broadcast.lagged", self.broadcast_lagged()).await;
+        collector.set_gauge("websocket.send_lag.max_us", self.max_send_lag_us.swap(0, Ordering::Relaxed)).await;
+    }
+}
+
+/// 클라이언트가 보내는 WebSocket 메시지
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientMessage {
+    /// 호가창 동기화 요청
+    SyncRequest { symbol: Option<String> },
+    /// 지원하지 않는 메시지 타입
+    Unknown(String),
+}
+
+impl ClientMessage {
+    /// 텍스트 프레임 해석 (JSON 객체가 아니거나 `type` 문자열이 없으면 None)
+    pub fn parse(text: &str) -> Option<Self> {
+        let json = serde_json::from_str::<Value>(text).ok()?;
+        let msg_type = json.get("type")?.as_str()?;
+        Some(match msg_type {
+            "sync_request" => ClientMessage::SyncRequest {
+                symbol: json.get("symbol").and_then(Value::as_str).map(str::to_string),
+            },
+            other => ClientMessage::Unknown(other.to_string()),
+        })
+    }
+}
+
+/// WebSocket 채널 (연결이 받을 메시지 종류)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebSocketChannel {
+    /// 체결, 호가창, 통계 등 전체 스트림 (BBO, 인덱스 가격, 계정 이벤트, 1분 외 봉 제외)
+    Full,
+    /// 최우선 호가 변경만
+    Bbo,
+    /// 인덱스/공정 가격만
+    Index,
+    /// 한 심볼/간격의 봉 업데이트만
+    Candles { symbol: String, interval: String },
+    /// 계정 이벤트만 (재전송한 시퀀스 이후)
+    Private { client_id: String, after_sequence: u64 },
+}
+
+impl WebSocketChannel {
+    /// 이 채널로 전달할 메시지인지 확인
+    pub fn accepts(&self, message: &WebSocketMessage) -> bool {
+        match (self, message) {
+            (WebSocketChannel::Private { client_id, after_sequence }, WebSocketMessage::PrivateEvent(event)) => {
+                &event.client_id == client_id && event.sequence > *after_sequence
+            }
+            (WebSocketChannel::Private { client_id, .. }, WebSocketMessage::ThrottleUpdate(update)) => {
+                &update.client_id == client_id
+            }
+            (WebSocketChannel::Private { .. }, _)
+            | (_, WebSocketMessage::PrivateEvent(_) | WebSocketMessage::ThrottleUpdate(_)) => false,
+            (
+                WebSocketChannel::Candles { symbol, interval },
+                WebSocketMessage::CandlestickUpdate { symbol: candle_symbol, interval: candle_interval, .. },
+            ) => candle_symbol == symbol && candle_interval == interval,
+            (WebSocketChannel::Candles { .. }, _) => false,
+            // 전체 스트림은 기존과 같이 1분 봉만
+            (WebSocketChannel::Full, WebSocketMessage::CandlestickUpdate { interval, .. }) => interval == "1m",
+            (WebSocketChannel::Full, message) => {
+                !matches!(message, WebSocketMessage::Bbo(_) | WebSocketMessage::IndexPrice(_))
+            }
+            (WebSocketChannel::Bbo, message) => matches!(message, WebSocketMessage::Bbo(_)),
+            (WebSocketChannel::Index, message) => matches!(message, WebSocketMessage::IndexPrice(_)),
+        }
+    }
+}
+
+/// 버려도 되는 시장 데이터인지 확인 (주문 이벤트/응답, 펀딩 정산, 만기 상품 상태는 버리지 않음)
+fn is_market_data(message: &WebSocketMessage) -> bool {
+    !matches!(
+        message,
+        WebSocketMessage::Execution { .. }
+            | WebSocketMessage::Funding(_)
+            | WebSocketMessage::ContractLifecycle(_)
+            | WebSocketMessage::PrivateEvent(_)
+            | WebSocketMessage::ThrottleUpdate(_)
+            | WebSocketMessage::ReplayComplete { .. }
+            | WebSocketMessage::SyncResponse { .. }
+            | WebSocketMessage::Error { .. }
+    )
+}
+
+/// 송신 큐 항목 (적재 시각 포함)
+type QueuedMessage = (Instant, WebSocketMessage);
+
+/// 연결별 송신 큐
+///
+/// 주문 이벤트는 무제한 큐, 시장 데이터는 병합 큐에 넣습니다.
+struct ConnectionSendQueue {
+    private_tx: mpsc::UnboundedSender<QueuedMessage>,
+    market: Arc<std::sync::Mutex<ConflatingQueue>>,
+    market_notify: Arc<Notify>,
+    metrics: Arc<WebSocketMetrics>,
+}
+
+/// 시장 데이터 병합 큐 수신측
+struct MarketDataReceiver {
+    market: Arc<std::sync::Mutex<ConflatingQueue>>,
+    market_notify: Arc<Notify>,
+}
+
+impl ConnectionSendQueue {
+    fn new(
+        capacity: usize,
+        metrics: Arc<WebSocketMetrics>,
+    ) -> (Self, mpsc::UnboundedReceiver<QueuedMessage>, MarketDataReceiver) {
+        let (private_tx, private_rx) = mpsc::unbounded_channel();
+        let market = Arc::new(std::sync::Mutex::new(ConflatingQueue::new(capacity)));
+        let market_notify = Arc::new(Notify::new());
+        let receiver = MarketDataReceiver {
+            market: market.clone(),
+            market_notify: market_notify.clone(),
+        };
+        (Self { private_tx, market, market_notify, metrics }, private_rx, receiver)
+    }
+
+    /// 메시지 적재 (연결이 닫혔으면 false)
+    fn push(&self, message: WebSocketMessage) -> bool {
+        if !is_market_data(&message) {
+            return self.private_tx.send((Instant::now(), message)).is_ok();
+        }
+        if self.private_tx.is_closed() {
+            return false;
+        }
+
+        let outcome = match self.market.lock() {
+            Ok(mut market) => market.push(message),
+            Err(_) => return false,
+        };
+        match outcome {
+            PushOutcome::Queued => self.market_notify.notify_one(),
+            PushOutcome::Conflated => {
+                self.metrics.conflated_market_data.fetch_add(1, Ordering::Relaxed);
+            }
+            PushOutcome::Dropped => {
+                self.metrics.dropped_market_data.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        true
+    }
+}
+
+impl MarketDataReceiver {
+    /// 다음 시장 데이터 대기
+    async fn recv(&self) -> QueuedMessage {
+        loop {
+            if let Some(entry) = self.try_recv() {
+                return entry;
+            }
+            self.market_notify.notified().await;
+        }
+    }
+
+    fn try_recv(&self) -> Option<QueuedMessage> {
+        self.market.lock().ok()?.pop()
+    }
+}
+
 /// WebSocket 연결 핸들러
 pub async fn websocket_handler(
     ws: WebSocketUpgrade,
     State(state): State<ServerState>,
 ) -> Response {
-    ws.on_upgrade(|socket| websocket_connection(socket, state))
+    ws.on_upgrade(|socket| websocket_connection(socket, state, WebSocketChannel::Full))
+}
+
+/// BBO 전용 WebSocket 연결 핸들러
+pub async fn bbo_websocket_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<ServerState>,
+) -> Response {
+    ws.on_upgrade(|socket| websocket_connection(socket, state, WebSocketChannel::Bbo))
+}
+
+/// 인덱스 가격 전용 WebSocket 연결 핸들러
+pub async fn index_websocket_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<ServerState>,
+) -> Response {
+    ws.on_upgrade(|socket| websocket_connection(socket, state, WebSocketChannel::Index))
+}
+
+/// 봉 채널 구독 파라미터
+#[derive(Debug, Deserialize)]
+pub struct CandleChannelQuery {
+    pub symbol: String,
+    /// 봉 간격 (기본 1m)
+    pub interval: Option<String>,
+}
+
+/// 봉 WebSocket 연결 핸들러
+pub async fn candle_websocket_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<ServerState>,
+    Query(query): Query<CandleChannelQuery>,
+) -> Response {
+    let interval = query.interval.unwrap_or_else(|| "1m".to_string());
+    if !CANDLE_INTERVALS.contains(&interval.as_str()) {
+        return ApiError::new(
+            ErrorCode::InvalidInterval,
+            format!("지원하지 않는 봉 간격입니다: {} (지원: {})", interval, CANDLE_INTERVALS.join(", ")),
+        )
+        .into_response();
+    }
+    ws.on_upgrade(move |socket| candle_websocket_connection(socket, state, query.symbol, interval))
+}
+
+/// 계정 이벤트 채널 재연결 파라미터
+#[derive(Debug, Deserialize)]
+pub struct PrivateResumeQuery {
+    /// 마지막으로 받은 이벤트 시퀀스 (없으면 재전송 없이 실시간 이벤트만)
+    pub last_sequence: Option<u64>,
+}
+
+/// 계정 이벤트 WebSocket 연결 핸들러
+pub async fn private_websocket_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<ServerState>,
+    Path(client_id): Path<String>,
+    Query(resume): Query<PrivateResumeQuery>,
+    auth: Option<AuthContext>,
+) -> Response {
+    if let Err(e) = validate_client_id(&client_id) {
+        return e.into_response();
+    }
+    // 브라우저는 WebSocket 헤더를 못 붙이므로 `access_token` 쿼리 파라미터로도 인증
+    if let Err(e) = authorize_client(&state, &auth, &client_id, Scope::Read) {
+        return e.into_response();
+    }
+    ws.on_upgrade(move |socket| private_websocket_connection(socket, state, client_id, resume.last_sequence))
 }
 
 /// WebSocket 연결 처리
 async fn websocket_connection(
     socket: WebSocket,
     state: ServerState,
+    channel: WebSocketChannel,
 ) {
+    let rx = state.execution_tx.subscribe();
+    serve_connection(socket, rx, channel, Vec::new(), state.ws_config.clone(), state.ws_metrics.clone()).await;
+}
+
+/// 봉 연결 처리
+///
+/// 차트를 바로 그릴 수 있도록 최근 봉을 먼저 보내고 실시간 봉 업데이트로 이어갑니다.
+async fn candle_websocket_connection(
+    socket: WebSocket,
+    state: ServerState,
+    symbol: String,
+    interval: String,
+) {
+    let rx = state.execution_tx.subscribe();
+    let latest = state.mdp.lock().await.get_candles(&symbol, &interval, 1).await;
+    let now = chrono::Utc::now().timestamp() as u64;
+    let initial = latest
+        .into_iter()
+        .map(|candle| WebSocketMessage::CandlestickUpdate {
+            symbol: symbol.clone(),
+            interval: interval.clone(),
+            is_closed: candle.open_time + candle_interval_secs(&interval) <= now,
+            candle: CandleData {
+                open_time: candle.open_time,
+                close_time: candle.close_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: candle.trade_count,
+            },
+        })
+        .collect();
+    let channel = WebSocketChannel::Candles { symbol, interval };
+    serve_connection(socket, rx, channel, initial, state.ws_config.clone(), state.ws_metrics.clone()).await;
+}
+
+/// 계정 이벤트 연결 처리
+///
+/// 재전송 구간을 구하기 전에 구독하므로 그 사이 발생한 이벤트도 놓치지 않고,
+/// 이미 재전송한 시퀀스는 실시간 스트림에서 걸러냅니다.
+async fn private_websocket_connection(
+    socket: WebSocket,
+    state: ServerState,
+    client_id: String,
+    last_sequence: Option<u64>,
+) {
+    let rx = state.execution_tx.subscribe();
+    let current = state.private_events.last_sequence(&client_id);
+    // 서버보다 앞선 번호(DB 초기화 등)는 현재 번호로 맞춤
+    let after_sequence = last_sequence.unwrap_or(current).min(current);
+
+    let replay = replay_after(
+        &state.private_events,
+        &PrivateEventRepository::new(state.db_pool.clone()),
+        &client_id,
+        after_sequence,
+    )
+    .await;
+    let replayed = replay.events.len();
+    let last_delivered = replay.events.last().map_or(after_sequence, |event| event.sequence);
+    if !replay.complete {
+        warn!("계정 이벤트 재전송 불완전: {} ({} 이후 {}개)", client_id, after_sequence, replayed);
+    }
+
+    let mut initial: Vec<WebSocketMessage> = replay.events.into_iter().map(WebSocketMessage::PrivateEvent).collect();
+    initial.push(WebSocketMessage::ReplayComplete {
+        client_id: client_id.clone(),
+        replayed,
+        last_sequence: last_delivered,
+        complete: replay.complete,
+    });
+    // 주문 속도 제한 중이면 현재 상태도 알림
+    if let Some(update) = state.order_throttle.as_ref().and_then(|throttle| throttle.status(&client_id, throttle.now_millis())) {
+        initial.push(WebSocketMessage::ThrottleUpdate(update));
+    }
+    let channel = WebSocketChannel::Private { client_id, after_sequence: last_delivered };
+    serve_connection(socket, rx, channel, initial, state.ws_config.clone(), state.ws_metrics.clone()).await;
+}
+
+/// 브로드캐스트 채널을 WebSocket 연결 하나로 전달 (송신 큐, ping, 메트릭 포함)
+///
+/// `initial` 은 실시간 메시지보다 먼저 보냅니다 (재전송 이벤트 등).
+/// 게이트웨이도 여러 인스턴스의 시장 데이터를 합친 채널을 같은 방식으로 전달합니다.
+pub(crate) async fn serve_connection(
+    socket: WebSocket,
+    mut rx: broadcast::Receiver<WebSocketMessage>,
+    channel: WebSocketChannel,
+    initial: Vec<WebSocketMessage>,
+    config: WebSocketConfig,
+    metrics: Arc<WebSocketMetrics>,
+) {
+    metrics.connection_opened();
+    info!("WebSocket 연결 수립 (현재 {}개)", metrics.active_connections());
+
     let (mut sender, mut receiver) = socket.split();
-    let mut rx = state.execution_tx.subscribe();
+    let (queue, mut private_rx, market_rx) =
+        ConnectionSendQueue::new(config.market_data_queue_capacity, metrics.clone());
+    for message in initial {
+        queue.push(message);
+    }
+
+    // 마지막 수신 시각 (연결 시작 기준 밀리초)
+    let connected_at = Instant::now();
+    let last_seen_ms = Arc::new(AtomicU64::new(0));
+
+    // 브로드캐스트 수신 → 연결별 송신 큐 적재
+    let fanout_metrics = metrics.clone();
+    let mut fanout_task = tokio::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(ws_message) => {
+                    if !channel.accepts(&ws_message) {
+                        continue;
+                    }
+                    if !queue.push(ws_message) {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("WebSocket 브로드캐스트 수신 지연 - {}개 메시지 누락", skipped);
+                    fanout_metrics.broadcast_lagged.fetch_add(skipped, Ordering::Relaxed);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
 
     // 클라이언트로부터 메시지 수신 처리
-    let send_task = tokio::spawn(async move {
-        while let Some(msg) = receiver.next().await {
-            if let Ok(msg) = msg {
-                match msg {
-                    Message::Text(text) => {
-                        // 클라이언트로부터 받은 텍스트 메시지 처리
-                        if let Ok(json) = serde_json::from_str::<Value>(&text) {
-                            if let Some(msg_type) = json.get("type").and_then(|v| v.as_str()) {
-                                match msg_type {
-                                    "sync_request" => {
-                                        // 동기화 요청 처리
-                                        if let Some(symbol) = json.get("symbol").and_then(|v| v.as_str()) {
-                                            println!("Sync request for symbol: {}", symbol);
-                                            // TODO: 매칭 엔진에서 동기화 응답 생성
-                                        }
-                                    }
-                                    _ => {
-                                        println!("Unknown message type: {}", msg_type);
-                                    }
-                                }
-                            }
-                        } else {
-                            println!("Received message: {}", text);
+    let last_seen_recv = last_seen_ms.clone();
+    let mut recv_task = tokio::spawn(async move {
+        while let Some(Ok(msg)) = receiver.next().await {
+            last_seen_recv.store(connected_at.elapsed().as_millis() as u64, Ordering::Relaxed);
+            match msg {
+                // 클라이언트로부터 받은 텍스트 메시지 처리
+                Message::Text(text) => match ClientMessage::parse(&text) {
+                    Some(ClientMessage::SyncRequest { symbol }) => {
+                        if let Some(symbol) = symbol {
+                            debug!("WebSocket 동기화 요청: {}", symbol);
+                            // TODO: 매칭 엔진에서 동기화 응답 생성
                         }
                     }
-                    Message::Close(_) => {
-                        println!("WebSocket connection closed");
-                        break;
+                    Some(ClientMessage::Unknown(msg_type)) => {
+                        debug!("알 수 없는 WebSocket 메시지 타입: {}", msg_type);
                     }
-                    _ => {}
+                    None => debug!("WebSocket 메시지 수신: {}", text),
+                },
+                Message::Close(_) => {
+                    debug!("클라이언트가 WebSocket 연결 종료");
+                    break;
                 }
+                _ => {}
             }
         }
     });
 
-    // WebSocket 메시지 브로드캐스트 수신 및 클라이언트로 전송
-    let recv_task = tokio::spawn(async move {
-        while let Ok(ws_message) = rx.recv().await {
-            let json_message = serde_json::to_string(&ws_message).unwrap();
-            
+    // 송신 큐 → 클라이언트 전송 및 주기적 ping
+    let send_metrics = metrics.clone();
+    let mut send_task = tokio::spawn(async move {
+        let mut ping_interval = tokio::time::interval(config.ping_interval);
+        ping_interval.tick().await;
+
+        loop {
+            let entry = tokio::select! {
+                biased;
+                Some(entry) = private_rx.recv() => entry,
+                entry = market_rx.recv() => entry,
+                _ = ping_interval.tick() => {
+                    let idle = connected_at.elapsed()
+                        .saturating_sub(Duration::from_millis(last_seen_ms.load(Ordering::Relaxed)));
+                    if idle > config.pong_timeout {
+                        warn!("WebSocket pong 타임아웃 ({}초 무응답) - 연결 종료", idle.as_secs());
+                        send_metrics.timed_out.fetch_add(1, Ordering::Relaxed);
+                        let _ = sender.send(Message::Close(None)).await;
+                        break;
+                    }
+                    if sender.send(Message::Ping(Vec::new())).await.is_err() {
+                        break;
+                    }
+                    continue;
+                }
+                else => break,
+            };
+
+            let (queued_at, ws_message) = entry;
+            send_metrics.record_send_lag(queued_at.elapsed());
+            let json_message = match serde_json::to_string(&ws_message) {
+                Ok(json) => json,
+                Err(e) => {
+                    warn!("WebSocket 메시지 직렬화 실패: {}", e);
+                    continue;
+                }
+            };
             if sender.send(Message::Text(json_message)).await.is_err() {
                 break;
             }
         }
     });
 
-    // 두 태스크 중 하나라도 완료되면 연결 종료
+    // 세 태스크 중 하나라도 완료되면 연결 종료
     tokio::select! {
-        _ = send_task => {},
-        _ = recv_task => {},
+        _ = &mut fanout_task => {},
+        _ = &mut recv_task => {},
+        _ = &mut send_task => {},
+    }
+    fanout_task.abort();
+    recv_task.abort();
+    send_task.abort();
+
+    metrics.connection_closed();
+    info!("WebSocket 연결 종료 (현재 {}개)", metrics.active_connections());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::{BboUpdate, IndexPriceUpdate, PrivateEvent, ThrottleUpdate};
+    use crate::matching_engine::model::{ExecutionReport, ExecType, Side};
+
+    fn execution() -> WebSocketMessage {
+        WebSocketMessage::Execution {
+            execution_report: ExecutionReport {
+                execution_id: "exec1".to_string(),
+                order_id: "order1".to_string(),
+                symbol: "BTC-KRW".to_string(),
+                side: Side::Buy,
+                price: 1000,
+                quantity: 1,
+                remaining_quantity: 0,
+                timestamp: 0,
+                counterparty_id: "order2".to_string(),
+                is_maker: false,
+                exec_type: ExecType::Trade,
+                sequence: 0,
+                tag: None,
+            },
+            order_status: "Filled".to_string(),
+        }
+    }
+
+    fn book_update(symbol: &str) -> WebSocketMessage {
+        WebSocketMessage::OrderBookUpdate {
+            symbol: symbol.to_string(),
+            bids: vec![(1000, 1)],
+            asks: vec![],
+            timestamp: 0,
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_slow_consumer_conflates_and_drops_market_data_only() {
+        let metrics = Arc::new(WebSocketMetrics::new());
+        let (queue, mut private_rx, market_rx) = ConnectionSendQueue::new(2, metrics.clone());
+
+        // 같은 심볼 호가는 병합, 용량을 넘는 새 심볼은 버림
+        for symbol in ["BTC-KRW", "BTC-KRW", "ETH-KRW", "AAPL", "BTC-KRW"] {
+            assert!(queue.push(book_update(symbol)));
+        }
+        for _ in 0..5 {
+            assert!(queue.push(execution()));
+        }
+
+        // 체결 이벤트는 모두 보존
+        assert_eq!(metrics.conflated_market_data(), 2);
+        assert_eq!(metrics.dropped_market_data(), 1);
+        assert_eq!(std::iter::from_fn(|| market_rx.try_recv()).count(), 2);
+        assert_eq!(std::iter::from_fn(|| private_rx.try_recv().ok()).count(), 5);
+    }
+
+    #[test]
+    fn test_bbo_channel_receives_only_bbo() {
+        let bbo = WebSocketMessage::Bbo(BboUpdate {
+            symbol: "BTC-KRW".to_string(),
+            bid_price: Some(1000),
+            bid_quantity: 1,
+            ask_price: None,
+            ask_quantity: 0,
+            timestamp: 0,
+            sequence: 1,
+        });
+
+        assert!(WebSocketChannel::Bbo.accepts(&bbo));
+        assert!(!WebSocketChannel::Bbo.accepts(&book_update("BTC-KRW")));
+        assert!(!WebSocketChannel::Bbo.accepts(&execution()));
+        assert!(!WebSocketChannel::Full.accepts(&bbo));
+        assert!(WebSocketChannel::Full.accepts(&book_update("BTC-KRW")));
+    }
+
+    #[test]
+    fn test_index_channel_receives_only_index_prices() {
+        let index = WebSocketMessage::IndexPrice(IndexPriceUpdate {
+            symbol: "BTC-KRW".to_string(),
+            index_price: 1000.0,
+            fair_price: 1000.0,
+            internal_mid: None,
+            sources: vec!["Binance".to_string(), "Upbit".to_string()],
+            outliers: Vec::new(),
+            stale_sources: Vec::new(),
+            stale: false,
+            index_updated_at: 0,
+            timestamp: 0,
+        });
+
+        assert!(WebSocketChannel::Index.accepts(&index));
+        assert!(!WebSocketChannel::Index.accepts(&book_update("BTC-KRW")));
+        assert!(!WebSocketChannel::Full.accepts(&index));
+        assert!(!WebSocketChannel::Bbo.accepts(&index));
+        assert!(is_market_data(&index));
+    }
+
+    #[test]
+    fn test_candle_channel_filters_symbol_and_interval() {
+        let candle = |symbol: &str, interval: &str, is_closed: bool| WebSocketMessage::CandlestickUpdate {
+            symbol: symbol.to_string(),
+            interval: interval.to_string(),
+            candle: CandleData {
+                open_time: 60,
+                close_time: 60,
+                open: 1000,
+                high: 1000,
+                low: 1000,
+                close: 1000,
+                volume: 1,
+                trade_count: 1,
+            },
+            is_closed,
+        };
+        let channel = WebSocketChannel::Candles { symbol: "BTC-KRW".to_string(), interval: "5m".to_string() };
+
+        assert!(channel.accepts(&candle("BTC-KRW", "5m", false)));
+        assert!(channel.accepts(&candle("BTC-KRW", "5m", true)));
+        assert!(!channel.accepts(&candle("BTC-KRW", "1m", false)));
+        assert!(!channel.accepts(&candle("ETH-KRW", "5m", false)));
+        assert!(!channel.accepts(&book_update("BTC-KRW")));
+        assert!(!channel.accepts(&execution()));
+        // 전체 스트림은 1분 봉만
+        assert!(WebSocketChannel::Full.accepts(&candle("BTC-KRW", "1m", false)));
+        assert!(!WebSocketChannel::Full.accepts(&candle("BTC-KRW", "5m", false)));
+    }
+
+    #[test]
+    fn test_private_channel_receives_own_events_after_replay() {
+        let event = |client_id: &str, sequence: u64| {
+            let WebSocketMessage::Execution { execution_report, order_status } = execution() else { unreachable!() };
+            WebSocketMessage::PrivateEvent(PrivateEvent {
+                client_id: client_id.to_string(),
+                sequence,
+                execution_report,
+                order_status,
+            })
+        };
+        let channel = WebSocketChannel::Private { client_id: "alice".to_string(), after_sequence: 5 };
+
+        // 이미 재전송한 시퀀스와 다른 계정 이벤트는 거름
+        assert!(channel.accepts(&event("alice", 6)));
+        assert!(!channel.accepts(&event("alice", 5)));
+        assert!(!channel.accepts(&event("bob", 6)));
+        assert!(!channel.accepts(&execution()));
+        assert!(!channel.accepts(&book_update("BTC-KRW")));
+        // 공개 채널로는 계정 이벤트가 나가지 않음
+        assert!(!WebSocketChannel::Full.accepts(&event("alice", 6)));
+
+        // 주문 속도 제한 알림은 해당 계정 비공개 채널로만
+        let throttle = |client_id: &str| {
+            WebSocketMessage::ThrottleUpdate(ThrottleUpdate {
+                client_id: client_id.to_string(),
+                throttled: true,
+                max_orders_per_sec: 5,
+                orders: 100,
+                cancels: 99,
+                cancel_ratio: 0.99,
+                started_at: 1_000,
+                until: 301_000,
+                rejected_orders: 0,
+                timestamp: 1_000,
+            })
+        };
+        assert!(channel.accepts(&throttle("alice")));
+        assert!(!channel.accepts(&throttle("bob")));
+        assert!(!WebSocketChannel::Full.accepts(&throttle("alice")));
+        assert!(!is_market_data(&throttle("alice")));
+    }
+
+    #[test]
+    fn test_client_message_parse() {
+        assert_eq!(
+            ClientMessage::parse(r#"{"type":"sync_request","symbol":"BTC-KRW"}"#),
+            Some(ClientMessage::SyncRequest { symbol: Some("BTC-KRW".to_string()) })
+        );
+        assert_eq!(ClientMessage::parse(r#"{"type":"sync_request","symbol":1}"#), Some(ClientMessage::SyncRequest { symbol: None }));
+        assert_eq!(ClientMessage::parse(r#"{"type":"ping"}"#), Some(ClientMessage::Unknown("ping".to_string())));
+        // 퍼징 회귀 코퍼스와 같은 잘못된 입력 (배열, 문자열이 아닌 type, 깊은 중첩)
+        let nested = "[".repeat(10_000);
+        for text in ["[1,2]", r#"{"type":7}"#, "\"sync_request\"", nested.as_str(), ""] {
+            assert_eq!(ClientMessage::parse(text), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_push_fails_after_connection_closed() {
+        let metrics = Arc::new(WebSocketMetrics::new());
+        let (queue, private_rx, market_rx) = ConnectionSendQueue::new(2, metrics);
+        drop(private_rx);
+        drop(market_rx);
+
+        assert!(!queue.push(execution()));
+        assert!(!queue.push(book_update("BTC-KRW")));
+    }
+}
@@ -0,0 +1,760 @@
+//! API 키 인증과 세션 토큰(JWT) 로그인
+//!
+//! 관리자가 계정(`client_id`)별로 권한을 지정해 API 키를 발급하면, 프로그램 클라이언트는 `X-API-Key` 헤더로
+//! 바로 인증하고 웹 UI는 API 키로 로그인해 짧은 수명의 액세스 토큰(JWT, HS256)과 리프레시 토큰을 받습니다.
+//! 리프레시 토큰은 한 번만 쓸 수 있고 갱신할 때마다 새 토큰으로 교체(회전)되며, 이미 교체된 토큰이 다시
+//! 쓰이면 탈취로 보고 같은 로그인에서 나온 토큰 묶음(family)을 모두 폐기합니다.
+//!
+//! API 키와 리프레시 토큰은 SHA-256 해시만 DB에 저장합니다. 인증 미들웨어는 검증한 계정과 권한을
+//! `AuthContext`로 요청 확장에 넣고, 핸들러는 이를 추출해 주문 계정과 권한을 확인합니다.
+//!
+//! API 키마다 IP 허용 목록(IP/CIDR)을 둘 수 있습니다. 목록이 있는 키는 직접 인증, 로그인, 토큰 갱신,
+//! 그 키로 발급된 액세스 토큰 모두 목록 안의 IP에서만 받고, 거부한 시도는 감사 로그에 남깁니다.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use axum::extract::{ConnectInfo, FromRequestParts, Request, State};
+use axum::http::request::Parts;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use ipnet::IpNet;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use utoipa::ToSchema;
+
+use crate::api::error::ApiError;
+use crate::db::models::{ApiKeyRecord, RefreshTokenRecord};
+use crate::db::repository::{ApiKeyRepository, AuditLogRepository, RefreshTokenRepository};
+
+/// 감사 로그 이벤트 타입
+pub const API_KEY_ISSUED_EVENT: &str = "API_KEY_ISSUED";
+pub const API_KEY_REVOKED_EVENT: &str = "API_KEY_REVOKED";
+pub const REFRESH_TOKEN_REUSED_EVENT: &str = "REFRESH_TOKEN_REUSED";
+pub const API_KEY_IP_ALLOWLIST_UPDATED_EVENT: &str = "API_KEY_IP_ALLOWLIST_UPDATED";
+pub const API_KEY_IP_REJECTED_EVENT: &str = "API_KEY_IP_REJECTED";
+/// 감사 로그 엔티티 타입
+pub const API_KEY_AUDIT_ENTITY: &str = "api_key";
+
+/// 액세스 토큰 발급자 (`iss`)
+const TOKEN_ISSUER: &str = "xtrader";
+
+/// 권한
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Scope {
+    /// 잔고, 포트폴리오, 개인 체결 스트림 조회
+    Read,
+    /// 주문 제출/취소
+    Trade,
+}
+
+impl Scope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::Read => "read",
+            Scope::Trade => "trade",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "read" => Some(Scope::Read),
+            "trade" => Some(Scope::Trade),
+            _ => None,
+        }
+    }
+}
+
+fn join_scopes(scopes: &[Scope]) -> String {
+    scopes.iter().map(Scope::as_str).collect::<Vec<_>>().join(",")
+}
+
+fn parse_scopes(scopes: &str) -> Vec<Scope> {
+    scopes.split([',', ' ']).filter_map(Scope::from_name).collect()
+}
+
+/// IP 허용 목록 항목 파싱 (`10.0.0.0/8`, 단일 IP는 /32, /128로 취급)
+pub fn parse_ip_allowlist<S: AsRef<str>>(entries: &[S]) -> Result<Vec<IpNet>, AuthError> {
+    entries
+        .iter()
+        .map(|entry| {
+            let entry = entry.as_ref().trim();
+            entry
+                .parse::<IpNet>()
+                .map(|net| net.trunc())
+                .or_else(|_| entry.parse::<IpAddr>().map(IpNet::from))
+                .map_err(|_| AuthError::InvalidIpAllowlist(entry.to_string()))
+        })
+        .collect()
+}
+
+fn join_ip_allowlist(allowlist: &[IpNet]) -> Option<String> {
+    (!allowlist.is_empty()).then(|| allowlist.iter().map(IpNet::to_string).collect::<Vec<_>>().join(","))
+}
+
+/// 인증 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthConfig {
+    /// 액세스 토큰 서명 키 (HS256)
+    pub jwt_secret: String,
+    /// 액세스 토큰 수명
+    pub access_token_ttl: Duration,
+    /// 리프레시 토큰 수명 (회전해도 늘어나지 않고 새 토큰마다 다시 계산)
+    pub refresh_token_ttl: Duration,
+    /// 클라이언트 IP를 `X-Forwarded-For` 마지막 항목에서 읽음 (앞단 프록시가 덮어쓰는 경우에만 사용)
+    pub trust_forwarded_for: bool,
+}
+
+impl AuthConfig {
+    pub fn new(jwt_secret: impl Into<String>) -> Self {
+        Self {
+            jwt_secret: jwt_secret.into(),
+            access_token_ttl: Duration::from_secs(15 * 60),
+            refresh_token_ttl: Duration::from_secs(7 * 24 * 3600),
+            trust_forwarded_for: false,
+        }
+    }
+}
+
+/// 인증된 요청의 계정과 권한 (인증 미들웨어가 요청 확장에 추가)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct AuthContext {
+    pub client_id: String,
+    pub scopes: Vec<Scope>,
+    /// 인증에 쓴 API 키 (로그인 토큰이면 로그인에 쓴 키)
+    pub key_id: String,
+}
+
+impl AuthContext {
+    /// 계정 본인이고 권한이 있는지 확인
+    pub fn authorize(&self, client_id: &str, scope: Scope) -> Result<(), AuthError> {
+        if self.client_id != client_id {
+            return Err(AuthError::Forbidden(format!("{} 계정에 대한 권한이 없습니다", client_id)));
+        }
+        if !self.scopes.contains(&scope) {
+            return Err(AuthError::Forbidden(format!("{} 권한이 필요합니다", scope.as_str())));
+        }
+        Ok(())
+    }
+}
+
+/// 요청한 클라이언트 IP (인증 미들웨어가 요청 확장에 추가, 알 수 없으면 None)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+/// 액세스 토큰 클레임
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    sub: String,
+    /// 공백 구분 권한 (OAuth `scope` 형식)
+    scope: String,
+    /// 로그인에 쓴 API 키 (폐기, IP 허용 목록 확인)
+    key_id: String,
+    iss: String,
+    iat: i64,
+    exp: i64,
+}
+
+/// 로그인/갱신 응답 토큰
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TokenPair {
+    pub access_token: String,
+    /// 항상 `Bearer`
+    pub token_type: String,
+    /// 액세스 토큰 수명 (초)
+    pub expires_in: u64,
+    pub refresh_token: String,
+    /// 리프레시 토큰 만료 시각 (초)
+    pub refresh_expires_at: i64,
+}
+
+/// 발급한 API 키 (비밀 키는 발급 응답에서만 보여줌)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct IssuedApiKey {
+    pub key_id: String,
+    pub client_id: String,
+    pub scopes: Vec<Scope>,
+    pub api_key: String,
+    pub created_at: i64,
+    /// 허용 IP/CIDR (비어 있으면 모든 IP 허용)
+    pub ip_allowlist: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct ActiveApiKey {
+    context: AuthContext,
+    ip_allowlist: Vec<IpNet>,
+}
+
+/// 사용 중인 API 키 (키 ID별, 키 해시 → 키 ID)
+#[derive(Default)]
+struct ApiKeyCache {
+    keys: HashMap<String, ActiveApiKey>,
+    by_hash: HashMap<String, String>,
+}
+
+impl ApiKeyCache {
+    fn insert(&mut self, key_hash: String, key: ActiveApiKey) {
+        self.by_hash.insert(key_hash, key.context.key_id.clone());
+        self.keys.insert(key.context.key_id.clone(), key);
+    }
+
+    fn by_secret(&self, api_key: &str) -> Option<&ActiveApiKey> {
+        self.by_hash.get(&hash_secret(api_key)).and_then(|key_id| self.keys.get(key_id))
+    }
+}
+
+/// 인증 오류
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("인증이 비활성화되어 있습니다 (XTRADER_JWT_SECRET 미설정)")]
+    Disabled,
+    #[error("인증이 필요합니다")]
+    Missing,
+    #[error("API 키가 올바르지 않습니다")]
+    InvalidApiKey,
+    #[error("토큰이 올바르지 않습니다: {0}")]
+    InvalidToken(String),
+    #[error("이미 사용된 리프레시 토큰입니다 (같은 로그인의 토큰을 모두 폐기했습니다)")]
+    RefreshTokenReused,
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("API 키를 찾을 수 없습니다: {0}")]
+    KeyNotFound(String),
+    #[error("권한을 하나 이상 지정해야 합니다")]
+    NoScopes,
+    #[error("IP 허용 목록 항목이 올바르지 않습니다: {0}")]
+    InvalidIpAllowlist(String),
+    #[error("허용되지 않은 IP입니다: {0}")]
+    IpNotAllowed(String),
+    #[error("인증 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+fn now_secs() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+/// 비밀 값 SHA-256 (hex)
+fn hash_secret(secret: &str) -> String {
+    Sha256::digest(secret.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// 추측할 수 없는 토큰 (UUID v4 두 개, 244비트 무작위)
+fn random_token(prefix: &str) -> String {
+    format!("{}_{}{}", prefix, uuid::Uuid::new_v4().simple(), uuid::Uuid::new_v4().simple())
+}
+
+/// API 키, 액세스 토큰, 리프레시 토큰 발급과 검증
+pub struct AuthService {
+    config: AuthConfig,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    api_keys: RwLock<ApiKeyCache>,
+    keys: ApiKeyRepository,
+    refresh_tokens: RefreshTokenRepository,
+    audit: AuditLogRepository,
+}
+
+impl AuthService {
+    /// DB에 저장된 API 키를 읽어 생성
+    pub async fn load(config: AuthConfig, pool: SqlitePool) -> Result<Self, AuthError> {
+        let keys = ApiKeyRepository::new(pool.clone());
+        let mut api_keys = ApiKeyCache::default();
+        for record in keys.find_active().await? {
+            let entries: Vec<&str> = record.ip_allowlist.as_deref().map(|list| list.split(',').collect()).unwrap_or_default();
+            let ip_allowlist = parse_ip_allowlist(&entries)?;
+            let context = AuthContext {
+                client_id: record.client_id,
+                scopes: parse_scopes(&record.scopes),
+                key_id: record.key_id,
+            };
+            api_keys.insert(record.key_hash, ActiveApiKey { context, ip_allowlist });
+        }
+        info!("API 키 {}개 로드", api_keys.keys.len());
+
+        Ok(Self {
+            encoding_key: EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+            decoding_key: DecodingKey::from_secret(config.jwt_secret.as_bytes()),
+            config,
+            api_keys: RwLock::new(api_keys),
+            keys,
+            refresh_tokens: RefreshTokenRepository::new(pool.clone()),
+            audit: AuditLogRepository::new(pool),
+        })
+    }
+
+    /// API 키 발급 (IP 허용 목록이 비어 있으면 모든 IP 허용, 감사 로그 기록)
+    pub async fn issue_api_key(
+        &self,
+        client_id: &str,
+        scopes: Vec<Scope>,
+        ip_allowlist: &[String],
+        actor: &str,
+    ) -> Result<IssuedApiKey, AuthError> {
+        if scopes.is_empty() {
+            return Err(AuthError::NoScopes);
+        }
+        let ip_allowlist = parse_ip_allowlist(ip_allowlist)?;
+        let api_key = random_token("xtk");
+        let record = ApiKeyRecord {
+            key_id: uuid::Uuid::new_v4().to_string(),
+            key_hash: hash_secret(&api_key),
+            client_id: client_id.to_string(),
+            scopes: join_scopes(&scopes),
+            created_by: actor.to_string(),
+            created_at: now_secs(),
+            revoked_at: None,
+            ip_allowlist: join_ip_allowlist(&ip_allowlist),
+        };
+        self.keys.insert(&record).await?;
+        let details = serde_json::json!({
+            "key_id": record.key_id,
+            "scopes": record.scopes,
+            "ip_allowlist": record.ip_allowlist,
+            "by": actor,
+        })
+        .to_string();
+        self.audit.log(API_KEY_ISSUED_EVENT, API_KEY_AUDIT_ENTITY, client_id, Some(&details)).await?;
+        info!("API 키 발급: {} ({}) [{}] (by {})", client_id, record.key_id, record.scopes, actor);
+
+        let context = AuthContext {
+            client_id: client_id.to_string(),
+            scopes: scopes.clone(),
+            key_id: record.key_id.clone(),
+        };
+        let allowed = ip_allowlist.iter().map(IpNet::to_string).collect();
+        self.api_keys.write().await.insert(record.key_hash, ActiveApiKey { context, ip_allowlist });
+        Ok(IssuedApiKey {
+            key_id: record.key_id,
+            client_id: client_id.to_string(),
+            scopes,
+            api_key,
+            created_at: record.created_at,
+            ip_allowlist: allowed,
+        })
+    }
+
+    /// API 키 IP 허용 목록 조회
+    pub async fn ip_allowlist(&self, key_id: &str) -> Result<Vec<String>, AuthError> {
+        self.api_keys
+            .read()
+            .await
+            .keys
+            .get(key_id)
+            .map(|key| key.ip_allowlist.iter().map(IpNet::to_string).collect())
+            .ok_or_else(|| AuthError::KeyNotFound(key_id.to_string()))
+    }
+
+    /// API 키 IP 허용 목록 교체 (비우면 모든 IP 허용, 감사 로그 기록)
+    pub async fn set_ip_allowlist(&self, key_id: &str, entries: &[String], actor: &str) -> Result<Vec<String>, AuthError> {
+        let ip_allowlist = parse_ip_allowlist(entries)?;
+        let joined = join_ip_allowlist(&ip_allowlist);
+        if !self.keys.update_ip_allowlist(key_id, joined.as_deref()).await? {
+            return Err(AuthError::KeyNotFound(key_id.to_string()));
+        }
+
+        let mut api_keys = self.api_keys.write().await;
+        let key = api_keys.keys.get_mut(key_id).ok_or_else(|| AuthError::KeyNotFound(key_id.to_string()))?;
+        let previous = join_ip_allowlist(&key.ip_allowlist);
+        key.ip_allowlist = ip_allowlist.clone();
+        let client_id = key.context.client_id.clone();
+        drop(api_keys);
+
+        let details = serde_json::json!({ "key_id": key_id, "before": previous, "after": joined, "by": actor }).to_string();
+        self.audit.log(API_KEY_IP_ALLOWLIST_UPDATED_EVENT, API_KEY_AUDIT_ENTITY, &client_id, Some(&details)).await?;
+        info!("API 키 IP 허용 목록 변경: {} ({}) [{}] (by {})", client_id, key_id, joined.as_deref().unwrap_or("모든 IP"), actor);
+        Ok(ip_allowlist.iter().map(IpNet::to_string).collect())
+    }
+
+    /// IP 허용 목록 확인 (목록이 있는 키는 IP를 알 수 없으면 거부, 거부는 감사 로그 기록)
+    async fn check_ip(&self, key: &ActiveApiKey, client_ip: ClientIp, via: &str) -> Result<(), AuthError> {
+        if key.ip_allowlist.is_empty() {
+            return Ok(());
+        }
+        if let ClientIp(Some(ip)) = client_ip {
+            if key.ip_allowlist.iter().any(|net| net.contains(&ip)) {
+                return Ok(());
+            }
+        }
+
+        let ip = client_ip.0.map(|ip| ip.to_string()).unwrap_or_else(|| "unknown".to_string());
+        warn!("허용되지 않은 IP의 API 키 사용 거부: {} ({}) from {} via {}", key.context.client_id, key.context.key_id, ip, via);
+        let details = serde_json::json!({ "key_id": key.context.key_id, "ip": ip, "via": via }).to_string();
+        self.audit.log(API_KEY_IP_REJECTED_EVENT, API_KEY_AUDIT_ENTITY, &key.context.client_id, Some(&details)).await?;
+        Err(AuthError::IpNotAllowed(ip))
+    }
+
+    /// API 키 폐기 (그 키로 로그인한 리프레시 토큰도 폐기, 발급된 액세스 토큰도 바로 거부)
+    pub async fn revoke_api_key(&self, key_id: &str, actor: &str) -> Result<(), AuthError> {
+        let now = now_secs();
+        if !self.keys.revoke(key_id, now).await? {
+            return Err(AuthError::KeyNotFound(key_id.to_string()));
+        }
+        let revoked_tokens = self.refresh_tokens.revoke_by_key(key_id, now).await?;
+
+        let mut api_keys = self.api_keys.write().await;
+        let client_id = api_keys.keys.remove(key_id).map(|key| key.context.client_id).unwrap_or_default();
+        api_keys.by_hash.retain(|_, id| id != key_id);
+        drop(api_keys);
+
+        let details = serde_json::json!({ "key_id": key_id, "revoked_refresh_tokens": revoked_tokens, "by": actor }).to_string();
+        self.audit.log(API_KEY_REVOKED_EVENT, API_KEY_AUDIT_ENTITY, &client_id, Some(&details)).await?;
+        info!("API 키 폐기: {} ({}) 리프레시 토큰 {}개 폐기 (by {})", client_id, key_id, revoked_tokens, actor);
+        Ok(())
+    }
+
+    /// `X-API-Key` 인증
+    pub async fn authenticate_api_key(&self, api_key: &str, client_ip: ClientIp) -> Result<AuthContext, AuthError> {
+        let key = self.api_keys.read().await.by_secret(api_key).cloned().ok_or(AuthError::InvalidApiKey)?;
+        self.check_ip(&key, client_ip, "api_key").await?;
+        Ok(key.context)
+    }
+
+    /// 액세스 토큰 검증 (로그인에 쓴 API 키가 폐기됐거나 IP 허용 목록 밖이면 거부)
+    pub async fn verify_access_token(&self, token: &str, client_ip: ClientIp) -> Result<AuthContext, AuthError> {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_issuer(&[TOKEN_ISSUER]);
+        validation.leeway = 5;
+        let claims = jsonwebtoken::decode::<Claims>(token, &self.decoding_key, &validation)
+            .map_err(|e| AuthError::InvalidToken(e.to_string()))?
+            .claims;
+        let key = self
+            .api_keys
+            .read()
+            .await
+            .keys
+            .get(&claims.key_id)
+            .cloned()
+            .ok_or_else(|| AuthError::InvalidToken("API 키가 폐기되었습니다".to_string()))?;
+        self.check_ip(&key, client_ip, "access_token").await?;
+        Ok(AuthContext { client_id: claims.sub, scopes: parse_scopes(&claims.scope), key_id: claims.key_id })
+    }
+
+    /// API 키로 로그인 (새 토큰 묶음 시작)
+    pub async fn login(&self, api_key: &str, client_ip: ClientIp) -> Result<TokenPair, AuthError> {
+        let key = self.api_keys.read().await.by_secret(api_key).cloned().ok_or(AuthError::InvalidApiKey)?;
+        self.check_ip(&key, client_ip, "login").await?;
+        let family_id = uuid::Uuid::new_v4().to_string();
+        info!("로그인: {} (API 키 {})", key.context.client_id, key.context.key_id);
+        self.issue_tokens(&key.context, &family_id).await
+    }
+
+    /// 리프레시 토큰 회전 (이미 회전된 토큰이면 묶음 전체 폐기)
+    pub async fn refresh(&self, refresh_token: &str, client_ip: ClientIp) -> Result<TokenPair, AuthError> {
+        let now = now_secs();
+        let invalid = || AuthError::InvalidToken("알 수 없는 리프레시 토큰".to_string());
+        let record = self.refresh_tokens.find_by_hash(&hash_secret(refresh_token)).await?.ok_or_else(invalid)?;
+        if record.revoked_at.is_some() {
+            return Err(AuthError::InvalidToken("폐기된 리프레시 토큰".to_string()));
+        }
+        if record.expires_at <= now {
+            return Err(AuthError::InvalidToken("만료된 리프레시 토큰".to_string()));
+        }
+        if record.rotated_at.is_some() || !self.refresh_tokens.mark_rotated(&record.token_hash, now).await? {
+            return Err(self.reject_reuse(&record, now).await);
+        }
+
+        // 로그인에 쓴 API 키가 폐기됐으면 갱신 불가 (키 폐기 시 토큰도 폐기하지만 권한 변경에 대비해 다시 확인)
+        let key = self
+            .api_keys
+            .read()
+            .await
+            .keys
+            .get(&record.key_id)
+            .cloned()
+            .ok_or_else(|| AuthError::InvalidToken("API 키가 폐기되었습니다".to_string()))?;
+        self.check_ip(&key, client_ip, "refresh").await?;
+        self.issue_tokens(&key.context, &record.family_id).await
+    }
+
+    async fn reject_reuse(&self, record: &RefreshTokenRecord, now: i64) -> AuthError {
+        warn!("리프레시 토큰 재사용 탐지: {} (묶음 {}) - 묶음 전체 폐기", record.client_id, record.family_id);
+        let revoked = match self.refresh_tokens.revoke_family(&record.family_id, now).await {
+            Ok(revoked) => revoked,
+            Err(e) => return AuthError::Storage(e),
+        };
+        let details = serde_json::json!({ "family_id": record.family_id, "key_id": record.key_id, "revoked": revoked }).to_string();
+        if let Err(e) = self.audit.log(REFRESH_TOKEN_REUSED_EVENT, API_KEY_AUDIT_ENTITY, &record.client_id, Some(&details)).await {
+            return AuthError::Storage(e);
+        }
+        AuthError::RefreshTokenReused
+    }
+
+    /// 로그아웃 (리프레시 토큰 묶음 폐기)
+    pub async fn logout(&self, refresh_token: &str) -> Result<(), AuthError> {
+        let record = self
+            .refresh_tokens
+            .find_by_hash(&hash_secret(refresh_token))
+            .await?
+            .ok_or_else(|| AuthError::InvalidToken("알 수 없는 리프레시 토큰".to_string()))?;
+        self.refresh_tokens.revoke_family(&record.family_id, now_secs()).await?;
+        info!("로그아웃: {} (묶음 {})", record.client_id, record.family_id);
+        Ok(())
+    }
+
+    async fn issue_tokens(&self, context: &AuthContext, family_id: &str) -> Result<TokenPair, AuthError> {
+        let now = now_secs();
+        let claims = Claims {
+            sub: context.client_id.clone(),
+            scope: context.scopes.iter().map(Scope::as_str).collect::<Vec<_>>().join(" "),
+            key_id: context.key_id.clone(),
+            iss: TOKEN_ISSUER.to_string(),
+            iat: now,
+            exp: now + self.config.access_token_ttl.as_secs() as i64,
+        };
+        let access_token = jsonwebtoken::encode(&Header::new(Algorithm::HS256), &claims, &self.encoding_key)
+            .map_err(|e| AuthError::InvalidToken(e.to_string()))?;
+
+        let refresh_token = random_token("xtr");
+        let record = RefreshTokenRecord {
+            token_hash: hash_secret(&refresh_token),
+            family_id: family_id.to_string(),
+            key_id: context.key_id.clone(),
+            client_id: context.client_id.clone(),
+            scopes: join_scopes(&context.scopes),
+            created_at: now,
+            expires_at: now + self.config.refresh_token_ttl.as_secs() as i64,
+            rotated_at: None,
+            revoked_at: None,
+        };
+        self.refresh_tokens.insert(&record).await?;
+
+        Ok(TokenPair {
+            access_token,
+            token_type: "Bearer".to_string(),
+            expires_in: self.config.access_token_ttl.as_secs(),
+            refresh_token,
+            refresh_expires_at: record.expires_at,
+        })
+    }
+}
+
+/// 로그인/갱신/로그아웃 엔드포인트 (만료된 액세스 토큰을 단 채로 갱신할 수 있도록 인증 미들웨어 제외)
+pub fn is_token_endpoint(path: &str) -> bool {
+    matches!(path, "/v1/auth/login" | "/v1/auth/refresh" | "/v1/auth/logout")
+}
+
+/// 요청한 클라이언트 IP (연결 주소, 설정 시 `X-Forwarded-For` 마지막 항목)
+fn client_ip(request: &Request, trust_forwarded_for: bool) -> ClientIp {
+    let forwarded = trust_forwarded_for
+        .then(|| request.headers().get("x-forwarded-for").and_then(|v| v.to_str().ok()))
+        .flatten()
+        .and_then(|value| value.rsplit(',').next())
+        .and_then(|last| last.trim().parse::<IpAddr>().ok());
+    let peer = request.extensions().get::<ConnectInfo<SocketAddr>>().map(|info| info.0.ip());
+    ClientIp(forwarded.or(peer))
+}
+
+/// 인증 미들웨어 (인증 설정 시에만 라우터에 추가)
+///
+/// `Authorization: Bearer <액세스 토큰>`, `X-API-Key`, 또는 (헤더를 못 쓰는 브라우저 WebSocket용)
+/// `access_token` 쿼리 파라미터를 확인해 `AuthContext`를 요청 확장에 넣습니다.
+/// 자격 증명이 없는 요청은 그대로 통과하고, 있는데 올바르지 않으면 401로, IP 허용 목록 밖이면 403으로 거부합니다.
+pub async fn authenticate(State(auth): State<Arc<AuthService>>, mut request: Request, next: Next) -> Response {
+    let ip = client_ip(&request, auth.config.trust_forwarded_for);
+    request.extensions_mut().insert(ip);
+    if is_token_endpoint(request.uri().path()) {
+        return next.run(request).await;
+    }
+    let headers = request.headers();
+    let bearer = headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::to_string);
+    let api_key = headers.get("x-api-key").and_then(|v| v.to_str().ok()).map(str::to_string);
+    let query_token = request.uri().query().and_then(|query| {
+        query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("access_token="))
+            .map(str::to_string)
+    });
+
+    let context = match (bearer.or(query_token), api_key) {
+        (Some(token), _) => auth.verify_access_token(&token, ip).await,
+        (None, Some(api_key)) => auth.authenticate_api_key(&api_key, ip).await,
+        (None, None) => return next.run(request).await,
+    };
+    match context {
+        Ok(context) => {
+            request.extensions_mut().insert(context);
+            next.run(request).await
+        }
+        Err(e) => ApiError::from(e).into_response(),
+    }
+}
+
+#[axum::async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AuthContext {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts.extensions.get::<AuthContext>().cloned().ok_or_else(|| AuthError::Missing.into())
+    }
+}
+
+#[axum::async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ClientIp {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts.extensions.get::<ClientIp>().copied().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OFFICE: ClientIp = ClientIp(Some(IpAddr::V4(std::net::Ipv4Addr::new(10, 1, 2, 3))));
+    const ELSEWHERE: ClientIp = ClientIp(Some(IpAddr::V4(std::net::Ipv4Addr::new(203, 0, 113, 7))));
+
+    async fn service() -> AuthService {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        AuthService::load(AuthConfig::new("test-secret"), pool).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_login_issues_verifiable_access_token() {
+        let auth = service().await;
+        let issued = auth.issue_api_key("trader_1", vec![Scope::Read, Scope::Trade], &[], "admin").await.unwrap();
+
+        // API 키 직접 인증과 로그인 토큰 모두 같은 계정/권한
+        let context = auth.authenticate_api_key(&issued.api_key, ClientIp(None)).await.unwrap();
+        assert_eq!(
+            context,
+            AuthContext {
+                client_id: "trader_1".to_string(),
+                scopes: vec![Scope::Read, Scope::Trade],
+                key_id: issued.key_id.clone(),
+            }
+        );
+        let tokens = auth.login(&issued.api_key, ClientIp(None)).await.unwrap();
+        assert_eq!(auth.verify_access_token(&tokens.access_token, ClientIp(None)).await.unwrap(), context);
+        assert!(context.authorize("trader_1", Scope::Trade).is_ok());
+        assert!(matches!(context.authorize("trader_2", Scope::Read), Err(AuthError::Forbidden(_))));
+
+        assert!(matches!(auth.login("xtk_wrong", ClientIp(None)).await, Err(AuthError::InvalidApiKey)));
+        assert!(matches!(auth.verify_access_token("not.a.jwt", ClientIp(None)).await, Err(AuthError::InvalidToken(_))));
+
+        // 다른 키로 서명했거나 만료된 토큰 거부
+        let claims = Claims {
+            sub: "trader_1".to_string(),
+            scope: "trade".to_string(),
+            key_id: issued.key_id.clone(),
+            iss: TOKEN_ISSUER.to_string(),
+            iat: 0,
+            exp: now_secs() + 60,
+        };
+        let forged = jsonwebtoken::encode(&Header::default(), &claims, &EncodingKey::from_secret(b"other")).unwrap();
+        assert!(auth.verify_access_token(&forged, ClientIp(None)).await.is_err());
+        let expired = Claims { exp: now_secs() - 60, ..claims };
+        let expired = jsonwebtoken::encode(&Header::default(), &expired, &EncodingKey::from_secret(b"test-secret")).unwrap();
+        assert!(auth.verify_access_token(&expired, ClientIp(None)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_rotation_detects_reuse() {
+        let auth = service().await;
+        let issued = auth.issue_api_key("trader_1", vec![Scope::Read], &[], "admin").await.unwrap();
+        let first = auth.login(&issued.api_key, ClientIp(None)).await.unwrap();
+
+        let second = auth.refresh(&first.refresh_token, ClientIp(None)).await.unwrap();
+        assert_ne!(second.refresh_token, first.refresh_token);
+
+        // 교체된 토큰 재사용 → 묶음 전체 폐기, 최신 토큰도 사용 불가
+        assert!(matches!(auth.refresh(&first.refresh_token, ClientIp(None)).await, Err(AuthError::RefreshTokenReused)));
+        assert!(matches!(auth.refresh(&second.refresh_token, ClientIp(None)).await, Err(AuthError::InvalidToken(_))));
+
+        // 로그아웃과 API 키 폐기도 리프레시 토큰 폐기
+        let third = auth.login(&issued.api_key, ClientIp(None)).await.unwrap();
+        auth.logout(&third.refresh_token).await.unwrap();
+        assert!(auth.refresh(&third.refresh_token, ClientIp(None)).await.is_err());
+
+        let fourth = auth.login(&issued.api_key, ClientIp(None)).await.unwrap();
+        auth.revoke_api_key(&issued.key_id, "admin").await.unwrap();
+        assert!(auth.refresh(&fourth.refresh_token, ClientIp(None)).await.is_err());
+        assert!(auth.verify_access_token(&fourth.access_token, ClientIp(None)).await.is_err());
+        assert!(matches!(auth.authenticate_api_key(&issued.api_key, ClientIp(None)).await, Err(AuthError::InvalidApiKey)));
+        assert!(matches!(auth.revoke_api_key(&issued.key_id, "admin").await, Err(AuthError::KeyNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_ip_allowlist_restricts_key_and_tokens() {
+        let auth = service().await;
+        assert!(matches!(
+            auth.issue_api_key("trader_1", vec![Scope::Trade], &["10.0.0.0/33".to_string()], "admin").await,
+            Err(AuthError::InvalidIpAllowlist(_))
+        ));
+        let allowlist = vec!["10.1.0.0/16".to_string(), "192.0.2.1".to_string()];
+        let issued = auth.issue_api_key("trader_1", vec![Scope::Trade], &allowlist, "admin").await.unwrap();
+        assert_eq!(issued.ip_allowlist, vec!["10.1.0.0/16", "192.0.2.1/32"]);
+
+        assert!(auth.authenticate_api_key(&issued.api_key, OFFICE).await.is_ok());
+        assert!(matches!(auth.authenticate_api_key(&issued.api_key, ELSEWHERE).await, Err(AuthError::IpNotAllowed(_))));
+        // IP를 알 수 없으면 허용 목록이 있는 키는 거부
+        assert!(matches!(auth.authenticate_api_key(&issued.api_key, ClientIp(None)).await, Err(AuthError::IpNotAllowed(_))));
+        assert!(matches!(auth.login(&issued.api_key, ELSEWHERE).await, Err(AuthError::IpNotAllowed(_))));
+
+        // 허용된 IP에서 받은 토큰도 다른 IP에서는 거부
+        let tokens = auth.login(&issued.api_key, OFFICE).await.unwrap();
+        assert!(auth.verify_access_token(&tokens.access_token, OFFICE).await.is_ok());
+        assert!(auth.verify_access_token(&tokens.access_token, ELSEWHERE).await.is_err());
+        assert!(matches!(auth.refresh(&tokens.refresh_token, ELSEWHERE).await, Err(AuthError::IpNotAllowed(_))));
+
+        // 목록을 비우면 모든 IP 허용
+        assert!(auth.set_ip_allowlist(&issued.key_id, &[], "admin").await.unwrap().is_empty());
+        assert!(auth.authenticate_api_key(&issued.api_key, ELSEWHERE).await.is_ok());
+        assert!(matches!(auth.set_ip_allowlist("missing", &[], "admin").await, Err(AuthError::KeyNotFound(_))));
+
+        let audit = auth.audit.find_by_entity("trader_1").await.unwrap();
+        let rejected = audit.iter().filter(|log| log.event_type == API_KEY_IP_REJECTED_EVENT).count();
+        assert_eq!(rejected, 5);
+        assert!(audit.iter().any(|log| log.event_type == API_KEY_IP_ALLOWLIST_UPDATED_EVENT));
+    }
+
+    #[tokio::test]
+    async fn test_middleware_injects_auth_context() {
+        use axum::{body::Body, http::StatusCode, middleware, routing::{get, post}, Router};
+        use tower::Service;
+
+        let auth = Arc::new(service().await);
+        let issued = auth.issue_api_key("trader_1", vec![Scope::Read], &[], "admin").await.unwrap();
+        let tokens = auth.login(&issued.api_key, ClientIp(None)).await.unwrap();
+        let mut router = Router::new()
+            .route("/v1/auth/me", get(|auth: AuthContext| async move { auth.client_id }))
+            .route("/v1/auth/refresh", post(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(auth.clone(), authenticate));
+
+        let mut status = |uri: &str, method: &str, header: Option<(&str, String)>| {
+            let mut request = Request::builder().method(method).uri(uri);
+            if let Some((name, value)) = header {
+                request = request.header(name, value);
+            }
+            router.call(request.body(Body::empty()).unwrap())
+        };
+        let bearer = format!("Bearer {}", tokens.access_token);
+        assert_eq!(status("/v1/auth/me", "GET", Some(("authorization", bearer))).await.unwrap().status(), StatusCode::OK);
+        assert_eq!(status("/v1/auth/me", "GET", Some(("x-api-key", issued.api_key))).await.unwrap().status(), StatusCode::OK);
+        let query = format!("/v1/auth/me?access_token={}", tokens.access_token);
+        assert_eq!(status(&query, "GET", None).await.unwrap().status(), StatusCode::OK);
+        assert_eq!(status("/v1/auth/me", "GET", None).await.unwrap().status(), StatusCode::UNAUTHORIZED);
+        let invalid = ("authorization", "Bearer expired".to_string());
+        assert_eq!(status("/v1/auth/me", "GET", Some(invalid.clone())).await.unwrap().status(), StatusCode::UNAUTHORIZED);
+        // 갱신은 만료된 액세스 토큰을 단 채로 호출해도 통과
+        assert_eq!(status("/v1/auth/refresh", "POST", Some(invalid)).await.unwrap().status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,239 @@
+//! 백테스트 입력 주문 흐름
+//!
+//! 과거 주문을 그대로 쓰거나, MDP 녹화 파일의 호가/체결 메시지를
+//! 매칭 엔진에 넣을 수 있는 이벤트로 변환합니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::path::Path;
+
+use crate::api::models::{OrderBookChange, OrderBookChangeType, WebSocketMessage};
+use crate::matching_engine::model::{ExecType, Order, Side};
+use crate::mdp::playback::read_recording;
+
+/// 매칭 엔진에 투입할 이벤트
+#[derive(Debug, Clone)]
+pub enum FlowEvent {
+    /// 과거 주문 (신규/취소) - 그대로 매칭 엔진에 투입
+    Order(Order),
+    /// 호가 레벨 잔량 설정 - 합성 유동성 주문을 넣거나 취소해 맞춤
+    SetLevel {
+        symbol: String,
+        side: Side,
+        price: u64,
+        quantity: u64,
+    },
+    /// 녹화 체결 - 체결가 지정가 주문을 넣고 남은 수량은 즉시 취소 (IOC)
+    Trade {
+        symbol: String,
+        taker_side: Side,
+        price: u64,
+        quantity: u64,
+    },
+}
+
+impl FlowEvent {
+    /// 이벤트 대상 심볼
+    pub fn symbol(&self) -> &str {
+        match self {
+            FlowEvent::Order(order) => &order.symbol,
+            FlowEvent::SetLevel { symbol, .. } | FlowEvent::Trade { symbol, .. } => symbol,
+        }
+    }
+}
+
+/// 시각이 붙은 이벤트
+#[derive(Debug, Clone)]
+pub struct TimedFlowEvent {
+    /// 이벤트 시각 (Unix 마이크로초)
+    pub ts_us: i64,
+    pub event: FlowEvent,
+}
+
+/// 심볼별 녹화 호가 상태 (가격 → 잔량)
+#[derive(Debug, Default)]
+struct RecordedBook {
+    bids: BTreeMap<u64, u64>,
+    asks: BTreeMap<u64, u64>,
+}
+
+/// 녹화 메시지 → 주문 흐름 변환기
+///
+/// 스냅샷에 없는 레벨을 지우려면 이전 호가 상태가 필요하므로 심볼별로 유지합니다.
+#[derive(Debug, Default)]
+pub struct RecordingFlowConverter {
+    books: HashMap<String, RecordedBook>,
+}
+
+impl RecordingFlowConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 메시지 하나를 이벤트로 변환 (백테스트와 무관한 메시지는 빈 목록)
+    pub fn convert(&mut self, message: &WebSocketMessage) -> Vec<FlowEvent> {
+        match message {
+            // 체결은 메이커/테이커 보고서가 모두 녹화되므로 테이커 쪽만 사용
+            WebSocketMessage::Execution { execution_report: report, .. } => {
+                if report.exec_type != ExecType::Trade || report.is_maker {
+                    return Vec::new();
+                }
+                vec![FlowEvent::Trade {
+                    symbol: report.symbol.clone(),
+                    taker_side: report.side.clone(),
+                    price: report.price,
+                    quantity: report.quantity,
+                }]
+            }
+            WebSocketMessage::OrderBookDelta(delta) => {
+                let book = self.books.entry(delta.symbol.clone()).or_default();
+                let mut events = Vec::new();
+                apply_changes(&delta.symbol, Side::Buy, &mut book.bids, &delta.bid_changes, &mut events);
+                apply_changes(&delta.symbol, Side::Sell, &mut book.asks, &delta.ask_changes, &mut events);
+                events
+            }
+            WebSocketMessage::OrderBookSnapshot(snapshot) => self.replace_book(&snapshot.symbol, &snapshot.bids, &snapshot.asks),
+            WebSocketMessage::OrderBookUpdate { symbol, bids, asks, .. } => self.replace_book(symbol, bids, asks),
+            _ => Vec::new(),
+        }
+    }
+
+    /// 전체 호가로 교체 (사라진 레벨은 잔량 0으로 설정)
+    fn replace_book(&mut self, symbol: &str, bids: &[(u64, u64)], asks: &[(u64, u64)]) -> Vec<FlowEvent> {
+        let book = self.books.entry(symbol.to_string()).or_default();
+        let mut events = Vec::new();
+        replace_side(symbol, Side::Buy, &mut book.bids, bids, &mut events);
+        replace_side(symbol, Side::Sell, &mut book.asks, asks, &mut events);
+        events
+    }
+}
+
+fn set_level(symbol: &str, side: &Side, levels: &mut BTreeMap<u64, u64>, price: u64, quantity: u64, events: &mut Vec<FlowEvent>) {
+    let previous = if quantity == 0 { levels.remove(&price) } else { levels.insert(price, quantity) };
+    if previous.unwrap_or(0) != quantity {
+        events.push(FlowEvent::SetLevel {
+            symbol: symbol.to_string(),
+            side: side.clone(),
+            price,
+            quantity,
+        });
+    }
+}
+
+fn apply_changes(symbol: &str, side: Side, levels: &mut BTreeMap<u64, u64>, changes: &[OrderBookChange], events: &mut Vec<FlowEvent>) {
+    for change in changes {
+        let quantity = match change.change_type {
+            OrderBookChangeType::Remove => 0,
+            OrderBookChangeType::Add | OrderBookChangeType::Update => change.quantity,
+        };
+        set_level(symbol, &side, levels, change.price, quantity, events);
+    }
+}
+
+fn replace_side(symbol: &str, side: Side, levels: &mut BTreeMap<u64, u64>, snapshot: &[(u64, u64)], events: &mut Vec<FlowEvent>) {
+    let stale: Vec<u64> = levels
+        .keys()
+        .filter(|price| !snapshot.iter().any(|(p, _)| p == *price))
+        .copied()
+        .collect();
+    for price in stale {
+        set_level(symbol, &side, levels, price, 0, events);
+    }
+    for &(price, quantity) in snapshot {
+        set_level(symbol, &side, levels, price, quantity, events);
+    }
+}
+
+/// 녹화 파일 전체를 주문 흐름으로 변환
+pub fn flow_from_recording(path: &Path) -> io::Result<Vec<TimedFlowEvent>> {
+    let mut converter = RecordingFlowConverter::new();
+    let mut flow = Vec::new();
+    for event in read_recording(path)? {
+        let event = event?;
+        flow.extend(
+            converter
+                .convert(&event.message)
+                .into_iter()
+                .map(|flow_event| TimedFlowEvent { ts_us: event.ts_us, event: flow_event }),
+        );
+    }
+    Ok(flow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::{OrderBookDelta, OrderBookSnapshot};
+    use crate::matching_engine::model::ExecutionReport;
+
+    fn level(event: &FlowEvent) -> (bool, u64, u64) {
+        match event {
+            FlowEvent::SetLevel { side, price, quantity, .. } => (*side == Side::Buy, *price, *quantity),
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_convert_recorded_messages() {
+        let mut converter = RecordingFlowConverter::new();
+
+        let snapshot = WebSocketMessage::OrderBookSnapshot(OrderBookSnapshot {
+            symbol: "BTC-KRW".to_string(),
+            bids: vec![(100, 5), (99, 2)],
+            asks: vec![(101, 4)],
+            timestamp: 0,
+            sequence: 1,
+        });
+        let events: Vec<_> = converter.convert(&snapshot).iter().map(level).collect();
+        assert_eq!(events, vec![(true, 100, 5), (true, 99, 2), (false, 101, 4)]);
+
+        // 변화 없는 레벨은 이벤트를 만들지 않음
+        let delta = WebSocketMessage::OrderBookDelta(OrderBookDelta {
+            symbol: "BTC-KRW".to_string(),
+            bid_changes: vec![
+                OrderBookChange { change_type: OrderBookChangeType::Update, price: 100, quantity: 5 },
+                OrderBookChange { change_type: OrderBookChangeType::Remove, price: 99, quantity: 0 },
+            ],
+            ask_changes: vec![OrderBookChange { change_type: OrderBookChangeType::Add, price: 102, quantity: 1 }],
+            timestamp: 0,
+            sequence: 2,
+        });
+        let events: Vec<_> = converter.convert(&delta).iter().map(level).collect();
+        assert_eq!(events, vec![(true, 99, 0), (false, 102, 1)]);
+
+        // 스냅샷에서 사라진 레벨은 0으로 설정
+        let update = WebSocketMessage::OrderBookUpdate {
+            symbol: "BTC-KRW".to_string(),
+            bids: vec![(100, 5)],
+            asks: vec![(101, 4)],
+            timestamp: 0,
+        };
+        let events: Vec<_> = converter.convert(&update).iter().map(level).collect();
+        assert_eq!(events, vec![(false, 102, 0)]);
+
+        let mut report = ExecutionReport {
+            execution_id: "e1".to_string(),
+            order_id: "o1".to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: Side::Sell,
+            price: 100,
+            quantity: 3,
+            remaining_quantity: 0,
+            timestamp: 0,
+            counterparty_id: "o2".to_string(),
+            is_maker: false,
+            exec_type: ExecType::Trade,
+            sequence: 0,
+            tag: None,
+        };
+        let taker = WebSocketMessage::Execution { execution_report: report.clone(), order_status: "Filled".to_string() };
+        match converter.convert(&taker).as_slice() {
+            [FlowEvent::Trade { taker_side: Side::Sell, price: 100, quantity: 3, .. }] => {}
+            other => panic!("unexpected events: {:?}", other),
+        }
+
+        report.is_maker = true;
+        let maker = WebSocketMessage::Execution { execution_report: report, order_status: "Filled".to_string() };
+        assert!(converter.convert(&maker).is_empty());
+    }
+}
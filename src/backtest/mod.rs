@@ -0,0 +1,15 @@
+//! 백테스트
+//!
+//! 과거 주문 흐름이나 MDP 녹화 데이터를 운영과 같은 `MatchingEngine`에
+//! 오프라인으로 투입하고, `Strategy` 구현체의 주문을 함께 매칭해
+//! 체결 내역과 손익 보고서를 만듭니다.
+
+pub mod flow;
+pub mod report;
+pub mod runner;
+pub mod strategy;
+
+pub use flow::{flow_from_recording, FlowEvent, RecordingFlowConverter, TimedFlowEvent};
+pub use report::{BacktestReport, Fill, Portfolio, SymbolPnl};
+pub use runner::{BacktestConfig, Backtester};
+pub use strategy::{MarketTick, OrderAction, Strategy, StrategyContext};
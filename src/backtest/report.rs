@@ -0,0 +1,205 @@
+//! 백테스트 손익 집계 및 보고서
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+
+use crate::matching_engine::model::Side;
+
+/// 전략 주문 체결
+#[derive(Debug, Clone, Serialize)]
+pub struct Fill {
+    pub order_id: String,
+    pub symbol: String,
+    pub side: Side,
+    pub price: u64,
+    pub quantity: u64,
+    /// 주문의 남은 수량
+    pub remaining_quantity: u64,
+    /// 메이커 체결 여부
+    pub is_maker: bool,
+    /// 수수료
+    pub fee: f64,
+    /// 체결 시각 (Unix 마이크로초)
+    pub ts_us: i64,
+}
+
+/// 심볼별 포지션 (평균 단가 방식)
+#[derive(Debug, Clone, Default)]
+struct Position {
+    quantity: i64,
+    avg_price: f64,
+    realized_pnl: f64,
+    traded_quantity: u64,
+}
+
+impl Position {
+    fn apply(&mut self, side: &Side, price: u64, quantity: u64) {
+        let signed = match side {
+            Side::Buy => quantity as i64,
+            Side::Sell => -(quantity as i64),
+        };
+        let price = price as f64;
+
+        if self.quantity == 0 || self.quantity.signum() == signed.signum() {
+            // 같은 방향 추가: 평균 단가 갱신
+            let held = self.quantity.unsigned_abs() as f64;
+            self.avg_price = (self.avg_price * held + price * quantity as f64) / (held + quantity as f64);
+        } else {
+            // 반대 방향: 청산분만큼 실현 손익, 방향이 뒤집히면 체결가가 새 단가
+            let closing = self.quantity.unsigned_abs().min(quantity);
+            self.realized_pnl += closing as f64 * (price - self.avg_price) * self.quantity.signum() as f64;
+            if quantity > closing {
+                self.avg_price = price;
+            }
+        }
+
+        self.quantity += signed;
+        if self.quantity == 0 {
+            self.avg_price = 0.0;
+        }
+        self.traded_quantity += quantity;
+    }
+}
+
+/// 전략 계좌 (현금 + 포지션)
+#[derive(Debug, Clone)]
+pub struct Portfolio {
+    initial_cash: f64,
+    cash: f64,
+    fees: f64,
+    positions: HashMap<String, Position>,
+}
+
+impl Portfolio {
+    pub fn new(initial_cash: f64) -> Self {
+        Self {
+            initial_cash,
+            cash: initial_cash,
+            fees: 0.0,
+            positions: HashMap::new(),
+        }
+    }
+
+    pub fn cash(&self) -> f64 {
+        self.cash
+    }
+
+    /// 심볼 보유 수량 (매도 포지션은 음수)
+    pub fn position(&self, symbol: &str) -> i64 {
+        self.positions.get(symbol).map_or(0, |p| p.quantity)
+    }
+
+    /// 체결 반영
+    pub fn apply_fill(&mut self, fill: &Fill) {
+        let notional = fill.price as f64 * fill.quantity as f64;
+        match fill.side {
+            Side::Buy => self.cash -= notional,
+            Side::Sell => self.cash += notional,
+        }
+        self.cash -= fill.fee;
+        self.fees += fill.fee;
+        self.positions
+            .entry(fill.symbol.clone())
+            .or_default()
+            .apply(&fill.side, fill.price, fill.quantity);
+    }
+
+    /// 평가 자산 (시세가 없는 심볼은 평균 단가로 평가)
+    pub fn equity(&self, marks: &HashMap<String, u64>) -> f64 {
+        self.cash
+            + self
+                .positions
+                .iter()
+                .map(|(symbol, p)| {
+                    let mark = marks.get(symbol).map_or(p.avg_price, |&m| m as f64);
+                    p.quantity as f64 * mark
+                })
+                .sum::<f64>()
+    }
+}
+
+/// 심볼별 손익
+#[derive(Debug, Clone, Serialize)]
+pub struct SymbolPnl {
+    pub symbol: String,
+    /// 최종 보유 수량
+    pub position: i64,
+    /// 평균 단가
+    pub avg_price: f64,
+    /// 평가 가격 (마지막 체결가)
+    pub mark_price: Option<u64>,
+    pub realized_pnl: f64,
+    pub unrealized_pnl: f64,
+    /// 누적 체결 수량
+    pub traded_quantity: u64,
+}
+
+/// 백테스트 결과 보고서
+#[derive(Debug, Clone, Serialize)]
+pub struct BacktestReport {
+    /// 처리한 주문 흐름 이벤트 수
+    pub events: u64,
+    /// 전략 주문 수
+    pub orders_submitted: u64,
+    pub initial_cash: f64,
+    pub final_cash: f64,
+    pub final_equity: f64,
+    /// 총 손익 (수수료 차감 후)
+    pub total_pnl: f64,
+    pub realized_pnl: f64,
+    pub unrealized_pnl: f64,
+    pub fees: f64,
+    /// 최대 낙폭 (평가 자산 고점 대비)
+    pub max_drawdown: f64,
+    pub symbols: Vec<SymbolPnl>,
+    pub fills: Vec<Fill>,
+}
+
+impl BacktestReport {
+    /// 최종 계좌 상태로 보고서 작성
+    pub(crate) fn build(
+        portfolio: &Portfolio,
+        marks: &HashMap<String, u64>,
+        events: u64,
+        orders_submitted: u64,
+        max_drawdown: f64,
+        fills: Vec<Fill>,
+    ) -> Self {
+        let symbols: Vec<SymbolPnl> = portfolio
+            .positions
+            .iter()
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .map(|(symbol, p)| {
+                let mark_price = marks.get(symbol).copied();
+                let unrealized_pnl = mark_price.map_or(0.0, |m| (m as f64 - p.avg_price) * p.quantity as f64);
+                SymbolPnl {
+                    symbol: symbol.clone(),
+                    position: p.quantity,
+                    avg_price: p.avg_price,
+                    mark_price,
+                    realized_pnl: p.realized_pnl,
+                    unrealized_pnl,
+                    traded_quantity: p.traded_quantity,
+                }
+            })
+            .collect();
+
+        let final_equity = portfolio.equity(marks);
+        Self {
+            events,
+            orders_submitted,
+            initial_cash: portfolio.initial_cash,
+            final_cash: portfolio.cash,
+            final_equity,
+            total_pnl: final_equity - portfolio.initial_cash,
+            realized_pnl: symbols.iter().map(|s| s.realized_pnl).sum(),
+            unrealized_pnl: symbols.iter().map(|s| s.unrealized_pnl).sum(),
+            fees: portfolio.fees,
+            max_drawdown,
+            symbols,
+            fills,
+        }
+    }
+}
@@ -0,0 +1,472 @@
+//! 백테스트 실행기
+//!
+//! 운영과 같은 `MatchingEngine`을 시뮬레이션 시각으로 구동합니다.
+//! 주문 흐름 이벤트를 하나 반영할 때마다 전략에 `on_tick`을, 전략 주문이
+//! 체결되면 `on_fill`을 호출하고, 전략이 낸 주문은 다음 이벤트 전에 바로 매칭합니다.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use log::{info, warn};
+
+use crate::backtest::flow::{FlowEvent, TimedFlowEvent};
+use crate::backtest::report::{BacktestReport, Fill, Portfolio};
+use crate::backtest::strategy::{MarketTick, OrderAction, Strategy, StrategyContext};
+use crate::matching_engine::model::{ExecType, ExecutionReport, Order, OrderType, Side};
+use crate::matching_engine::MatchingEngine;
+use crate::sequencer::backpressure::{bounded_queue, BoundedReceiver, OverflowPolicy};
+
+/// 재생 주문의 고객 ID
+const FLOW_CLIENT_ID: &str = "backtest-flow";
+
+/// 체결 보고서 큐 용량 (주문 하나가 만드는 보고서를 모두 담을 수 있어야 함)
+const EXEC_QUEUE_CAPACITY: usize = 1 << 16;
+
+/// 백테스트 설정
+#[derive(Debug, Clone)]
+pub struct BacktestConfig {
+    pub symbols: Vec<String>,
+    pub initial_cash: f64,
+    /// 메이커 수수료 (bp)
+    pub maker_fee_bps: f64,
+    /// 테이커 수수료 (bp)
+    pub taker_fee_bps: f64,
+}
+
+impl BacktestConfig {
+    pub fn new(symbols: Vec<String>, initial_cash: f64) -> Self {
+        Self {
+            symbols,
+            initial_cash,
+            maker_fee_bps: 0.0,
+            taker_fee_bps: 0.0,
+        }
+    }
+
+    /// 수수료 설정
+    pub fn with_fees(mut self, maker_fee_bps: f64, taker_fee_bps: f64) -> Self {
+        self.maker_fee_bps = maker_fee_bps;
+        self.taker_fee_bps = taker_fee_bps;
+        self
+    }
+}
+
+/// 합성 유동성 주문이 놓인 호가 레벨
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct LevelKey {
+    symbol: String,
+    is_bid: bool,
+    price: u64,
+}
+
+/// 백테스트 실행기
+pub struct Backtester<S: Strategy> {
+    config: BacktestConfig,
+    strategy: S,
+    engine: MatchingEngine,
+    exec_rx: BoundedReceiver<ExecutionReport>,
+    portfolio: Portfolio,
+    /// 전략 미체결 주문
+    open_orders: HashSet<String>,
+    /// 레벨별 합성 유동성 주문 (주문 ID, 남은 수량) - 들어온 순서
+    synthetic: HashMap<LevelKey, Vec<(String, u64)>>,
+    synthetic_index: HashMap<String, LevelKey>,
+    /// 심볼별 마지막 체결가
+    last_prices: HashMap<String, u64>,
+    pending: VecDeque<OrderAction>,
+    fills: Vec<Fill>,
+    next_order_id: u64,
+    next_flow_id: u64,
+    now_us: i64,
+    events: u64,
+    orders_submitted: u64,
+    peak_equity: f64,
+    max_drawdown: f64,
+}
+
+impl<S: Strategy> Backtester<S> {
+    pub fn new(config: BacktestConfig, strategy: S) -> Self {
+        let (exec_tx, exec_rx) = bounded_queue("backtest_executions", EXEC_QUEUE_CAPACITY, OverflowPolicy::Block);
+        let engine = MatchingEngine::new(config.symbols.clone(), exec_tx, None);
+        let portfolio = Portfolio::new(config.initial_cash);
+        let peak_equity = config.initial_cash;
+
+        Self {
+            config,
+            strategy,
+            engine,
+            exec_rx,
+            portfolio,
+            open_orders: HashSet::new(),
+            synthetic: HashMap::new(),
+            synthetic_index: HashMap::new(),
+            last_prices: HashMap::new(),
+            pending: VecDeque::new(),
+            fills: Vec::new(),
+            next_order_id: 0,
+            next_flow_id: 0,
+            now_us: 0,
+            events: 0,
+            orders_submitted: 0,
+            peak_equity,
+            max_drawdown: 0.0,
+        }
+    }
+
+    /// 전략 참조 (실행 후 전략 내부 상태 확인용)
+    pub fn strategy(&self) -> &S {
+        &self.strategy
+    }
+
+    /// 주문 흐름 전체를 재생하고 보고서 반환
+    pub fn run(mut self, flow: impl IntoIterator<Item = TimedFlowEvent>) -> BacktestReport {
+        info!("백테스트 시작: {:?}", self.config.symbols);
+
+        for TimedFlowEvent { ts_us, event } in flow {
+            self.advance_clock(ts_us);
+
+            let symbol = event.symbol().to_string();
+            self.apply_event(event);
+            self.events += 1;
+            self.settle();
+
+            self.tick(&symbol);
+            self.settle();
+            self.sample_equity();
+        }
+
+        let report = BacktestReport::build(
+            &self.portfolio,
+            &self.last_prices,
+            self.events,
+            self.orders_submitted,
+            self.max_drawdown,
+            self.fills,
+        );
+        info!(
+            "백테스트 완료: 이벤트 {}건, 전략 주문 {}건, 체결 {}건, 손익 {:.2}",
+            report.events,
+            report.orders_submitted,
+            report.fills.len(),
+            report.total_pnl
+        );
+        report
+    }
+
+    /// 시뮬레이션 시각 진행 및 만료 주문 정리
+    fn advance_clock(&mut self, ts_us: i64) {
+        if ts_us < self.now_us {
+            warn!("백테스트 이벤트 시각 역행: {} < {}", ts_us, self.now_us);
+        }
+        self.now_us = self.now_us.max(ts_us);
+
+        let now_secs = (self.now_us / 1_000_000).max(0) as u64;
+        self.engine.set_simulated_time(Some(now_secs));
+        if self.engine.expire_orders(now_secs) > 0 {
+            self.settle();
+        }
+    }
+
+    /// 주문 흐름 이벤트를 엔진에 반영
+    fn apply_event(&mut self, event: FlowEvent) {
+        match event {
+            FlowEvent::Order(order) => self.engine.submit(order),
+            FlowEvent::SetLevel { symbol, side, price, quantity } => self.set_level(symbol, side, price, quantity),
+            FlowEvent::Trade { symbol, taker_side, price, quantity } => {
+                let order = self.flow_order(&symbol, taker_side, price, quantity);
+                let order_id = order.id.clone();
+                self.engine.submit(order);
+                if self.engine.get_order(&order_id).is_some() {
+                    let cancel = self.flow_cancel(&symbol, &order_id);
+                    self.engine.submit(cancel);
+                }
+            }
+        }
+    }
+
+    /// 레벨의 합성 유동성을 목표 잔량에 맞춤
+    fn set_level(&mut self, symbol: String, side: Side, price: u64, quantity: u64) {
+        let key = LevelKey { symbol: symbol.clone(), is_bid: side == Side::Buy, price };
+        let current: u64 = self.synthetic.get(&key).map_or(0, |orders| orders.iter().map(|(_, q)| q).sum());
+
+        if quantity > current {
+            self.add_synthetic(key, side, quantity - current);
+        } else if quantity < current {
+            // 나중에 들어온 주문부터 취소하고, 초과분만큼만 줄어들도록 나머지는 다시 넣음
+            let mut excess = current - quantity;
+            while excess > 0 {
+                let Some((order_id, remaining)) = self.synthetic.get_mut(&key).and_then(|orders| orders.pop()) else {
+                    break;
+                };
+                self.synthetic_index.remove(&order_id);
+                let cancel = self.flow_cancel(&symbol, &order_id);
+                self.engine.submit(cancel);
+                if remaining > excess {
+                    self.add_synthetic(key.clone(), side.clone(), remaining - excess);
+                }
+                excess = excess.saturating_sub(remaining);
+            }
+        }
+    }
+
+    fn add_synthetic(&mut self, key: LevelKey, side: Side, quantity: u64) {
+        let order = self.flow_order(&key.symbol, side, key.price, quantity);
+        let order_id = order.id.clone();
+
+        // 전략 주문과 교차해 바로 체결되면 체결 보고서 처리 시 잔량이 줄어듦
+        self.synthetic_index.insert(order_id.clone(), key.clone());
+        self.synthetic.entry(key).or_default().push((order_id, quantity));
+        self.engine.submit(order);
+    }
+
+    fn flow_order(&mut self, symbol: &str, side: Side, price: u64, quantity: u64) -> Order {
+        self.next_flow_id += 1;
+        let mut order = Order::new(
+            format!("flow-{}", self.next_flow_id),
+            symbol.to_string(),
+            side,
+            OrderType::Limit,
+            price,
+            quantity,
+            FLOW_CLIENT_ID.to_string(),
+        );
+        order.timestamp = (self.now_us / 1_000_000).max(0) as u64;
+        order
+    }
+
+    fn flow_cancel(&mut self, symbol: &str, target_order_id: &str) -> Order {
+        let mut cancel = self.flow_order(symbol, Side::Buy, 0, 0);
+        cancel.is_cancel = true;
+        cancel.target_order_id = Some(target_order_id.to_string());
+        cancel
+    }
+
+    /// 전략에 시세 전달
+    fn tick(&mut self, symbol: &str) {
+        let Some(snapshot) = self.engine.get_order_book_snapshot(symbol, 1) else {
+            return;
+        };
+        let tick = MarketTick {
+            ts_us: self.now_us,
+            symbol: symbol.to_string(),
+            best_bid: snapshot.bids.first().copied(),
+            best_ask: snapshot.asks.first().copied(),
+            last_price: self.last_prices.get(symbol).copied(),
+        };
+
+        let mut ctx = StrategyContext::new(self.now_us, &self.portfolio, &self.open_orders, &mut self.next_order_id);
+        self.strategy.on_tick(&tick, &mut ctx);
+        self.pending.extend(ctx.into_actions());
+    }
+
+    /// 쌓인 체결 보고서와 전략 주문을 더 이상 없을 때까지 처리
+    fn settle(&mut self) {
+        loop {
+            let fills = self.drain_reports();
+            self.notify_fills(&fills);
+
+            if self.pending.is_empty() {
+                break;
+            }
+            while let Some(action) = self.pending.pop_front() {
+                self.execute(action);
+            }
+        }
+    }
+
+    fn execute(&mut self, action: OrderAction) {
+        match action {
+            OrderAction::Submit(order) => {
+                let order_id = order.id.clone();
+                let is_market = order.order_type == OrderType::Market;
+                self.orders_submitted += 1;
+                self.open_orders.insert(order_id.clone());
+                self.engine.submit(order);
+
+                // 시장가 주문은 주문장에 남지 않으므로 체결분을 반영한 뒤 미체결 목록에서 제거
+                if is_market {
+                    let fills = self.drain_reports();
+                    self.open_orders.remove(&order_id);
+                    self.notify_fills(&fills);
+                }
+            }
+            OrderAction::Cancel { order_id } => {
+                if self.open_orders.contains(&order_id) {
+                    let symbol = self.engine.get_order(&order_id).map(|o| o.symbol.clone());
+                    if let Some(symbol) = symbol {
+                        let cancel = self.flow_cancel(&symbol, &order_id);
+                        self.engine.submit(cancel);
+                    }
+                }
+            }
+        }
+    }
+
+    /// 전략에 체결 통지 (새로 낸 주문은 대기열에 추가)
+    fn notify_fills(&mut self, fills: &[Fill]) {
+        for fill in fills {
+            let mut ctx = StrategyContext::new(self.now_us, &self.portfolio, &self.open_orders, &mut self.next_order_id);
+            self.strategy.on_fill(fill, &mut ctx);
+            self.pending.extend(ctx.into_actions());
+        }
+    }
+
+    /// 체결 보고서 처리 - 합성 유동성 잔량, 마지막 체결가, 전략 체결 반영
+    fn drain_reports(&mut self) -> Vec<Fill> {
+        let mut fills = Vec::new();
+        while let Ok(report) = self.exec_rx.try_recv() {
+            if report.exec_type == ExecType::Trade && !report.is_maker {
+                self.last_prices.insert(report.symbol.clone(), report.price);
+            }
+
+            if let Some(key) = self.synthetic_index.get(&report.order_id).cloned() {
+                self.update_synthetic(&key, &report);
+                continue;
+            }
+
+            if !self.open_orders.contains(&report.order_id) {
+                continue;
+            }
+            if report.exec_type != ExecType::Trade || report.remaining_quantity == 0 {
+                self.open_orders.remove(&report.order_id);
+            }
+            if report.exec_type == ExecType::Trade {
+                let fee_bps = if report.is_maker { self.config.maker_fee_bps } else { self.config.taker_fee_bps };
+                let fill = Fill {
+                    order_id: report.order_id.clone(),
+                    symbol: report.symbol.clone(),
+                    side: report.side.clone(),
+                    price: report.price,
+                    quantity: report.quantity,
+                    remaining_quantity: report.remaining_quantity,
+                    is_maker: report.is_maker,
+                    fee: report.price as f64 * report.quantity as f64 * fee_bps / 10_000.0,
+                    ts_us: self.now_us,
+                };
+                self.portfolio.apply_fill(&fill);
+                self.fills.push(fill.clone());
+                fills.push(fill);
+            }
+        }
+        fills
+    }
+
+    fn update_synthetic(&mut self, key: &LevelKey, report: &ExecutionReport) {
+        let closed = report.exec_type != ExecType::Trade || report.remaining_quantity == 0;
+        if let Some(orders) = self.synthetic.get_mut(key) {
+            if closed {
+                orders.retain(|(id, _)| id != &report.order_id);
+            } else if let Some(entry) = orders.iter_mut().find(|(id, _)| id == &report.order_id) {
+                entry.1 = report.remaining_quantity;
+            }
+            if orders.is_empty() {
+                self.synthetic.remove(key);
+            }
+        }
+        if closed {
+            self.synthetic_index.remove(&report.order_id);
+        }
+    }
+
+    /// 평가 자산 고점 대비 낙폭 갱신
+    fn sample_equity(&mut self) {
+        let equity = self.portfolio.equity(&self.last_prices);
+        self.peak_equity = self.peak_equity.max(equity);
+        self.max_drawdown = self.max_drawdown.max(self.peak_equity - equity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SYMBOL: &str = "BTC-KRW";
+
+    /// 첫 시세에 최우선 매수호가 아래로 매수 주문을 내고, 체결되면 한 호가 위에 되파는 전략
+    #[derive(Default)]
+    struct BuyThenSell {
+        bid_id: Option<String>,
+        fills: Vec<(Side, u64, u64)>,
+    }
+
+    impl Strategy for BuyThenSell {
+        fn on_tick(&mut self, tick: &MarketTick, ctx: &mut StrategyContext<'_>) {
+            if self.bid_id.is_none() {
+                if let Some((bid, _)) = tick.best_bid {
+                    self.bid_id = Some(ctx.limit_order(&tick.symbol, Side::Buy, bid, 2));
+                }
+            }
+        }
+
+        fn on_fill(&mut self, fill: &Fill, ctx: &mut StrategyContext<'_>) {
+            self.fills.push((fill.side.clone(), fill.price, fill.quantity));
+            if fill.side == Side::Buy {
+                ctx.limit_order(&fill.symbol, Side::Sell, fill.price + 5, fill.quantity);
+            }
+        }
+    }
+
+    fn order(id: &str, side: Side, order_type: OrderType, price: u64, quantity: u64) -> Order {
+        Order::new(id.to_string(), SYMBOL.to_string(), side, order_type, price, quantity, "hist".to_string())
+    }
+
+    fn at(ts_us: i64, event: FlowEvent) -> TimedFlowEvent {
+        TimedFlowEvent { ts_us, event }
+    }
+
+    #[test]
+    fn test_strategy_trades_against_historical_orders() {
+        let flow = vec![
+            at(1_000_000, FlowEvent::Order(order("h1", Side::Buy, OrderType::Limit, 100, 1))),
+            at(2_000_000, FlowEvent::Order(order("h2", Side::Sell, OrderType::Limit, 110, 5))),
+            // 시간 우선: h1 다음에 전략 매수 주문이 체결됨
+            at(3_000_000, FlowEvent::Order(order("h3", Side::Sell, OrderType::Market, 0, 3))),
+            // 전략의 되팔기 주문(105)이 최우선 매도호가
+            at(4_000_000, FlowEvent::Order(order("h4", Side::Buy, OrderType::Market, 0, 2))),
+        ];
+
+        let config = BacktestConfig::new(vec![SYMBOL.to_string()], 10_000.0).with_fees(0.0, 10.0);
+        let backtester = Backtester::new(config, BuyThenSell::default());
+        let report = backtester.run(flow);
+
+        assert_eq!(report.events, 4);
+        assert_eq!(report.orders_submitted, 2);
+        assert_eq!(report.fills.len(), 2);
+        assert_eq!((report.fills[0].price, report.fills[0].quantity, report.fills[0].is_maker), (100, 2, true));
+        assert_eq!((report.fills[1].price, report.fills[1].quantity, report.fills[1].is_maker), (105, 2, true));
+
+        let btc = &report.symbols[0];
+        assert_eq!(btc.position, 0);
+        assert!((btc.realized_pnl - 10.0).abs() < 1e-9);
+        assert!((report.total_pnl - 10.0).abs() < 1e-9);
+        assert!((report.final_cash - 10_010.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_recorded_trades_consume_synthetic_liquidity_first() {
+        let level = |side: Side, price: u64, quantity: u64| FlowEvent::SetLevel {
+            symbol: SYMBOL.to_string(),
+            side,
+            price,
+            quantity,
+        };
+        let flow = vec![
+            at(1_000_000, level(Side::Buy, 100, 3)),
+            at(1_000_000, level(Side::Sell, 102, 4)),
+            // 전략 매수 주문은 합성 유동성 3개 뒤에 대기
+            at(2_000_000, FlowEvent::Trade { symbol: SYMBOL.to_string(), taker_side: Side::Sell, price: 100, quantity: 4 }),
+            // 체결 후 녹화 호가는 100 레벨이 비었다고 알려줌 - 이미 반영되어 추가 취소 없음
+            at(2_000_000, level(Side::Buy, 100, 0)),
+            at(3_000_000, level(Side::Sell, 102, 1)),
+        ];
+
+        let config = BacktestConfig::new(vec![SYMBOL.to_string()], 1_000.0);
+        let backtester = Backtester::new(config, BuyThenSell::default());
+        let report = backtester.run(flow);
+
+        assert_eq!(report.fills.len(), 1);
+        assert_eq!((report.fills[0].price, report.fills[0].quantity), (100, 1));
+        assert_eq!(report.symbols[0].position, 1);
+        assert_eq!(report.symbols[0].mark_price, Some(100));
+        assert!((report.final_cash - 900.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,119 @@
+//! 백테스트 전략 인터페이스
+//!
+//! 전략은 시세 변화(`on_tick`)와 자기 주문 체결(`on_fill`) 콜백에서
+//! `StrategyContext`로 주문을 내거나 취소합니다.
+
+use std::collections::HashSet;
+
+use crate::backtest::report::{Fill, Portfolio};
+use crate::matching_engine::model::{Order, OrderType, Side};
+
+/// 전략 주문의 고객 ID
+pub const STRATEGY_CLIENT_ID: &str = "backtest-strategy";
+
+/// 시세 이벤트 (주문 흐름 이벤트를 반영한 직후의 호가 상태)
+#[derive(Debug, Clone)]
+pub struct MarketTick {
+    /// 이벤트 시각 (Unix 마이크로초)
+    pub ts_us: i64,
+    pub symbol: String,
+    pub best_bid: Option<(u64, u64)>,
+    pub best_ask: Option<(u64, u64)>,
+    /// 마지막 체결가
+    pub last_price: Option<u64>,
+}
+
+impl MarketTick {
+    /// 중간가
+    pub fn mid_price(&self) -> Option<f64> {
+        match (self.best_bid, self.best_ask) {
+            (Some((bid, _)), Some((ask, _))) => Some((bid + ask) as f64 / 2.0),
+            _ => None,
+        }
+    }
+}
+
+/// 전략이 요청한 주문 동작
+#[derive(Debug, Clone)]
+pub enum OrderAction {
+    Submit(Order),
+    Cancel { order_id: String },
+}
+
+/// 콜백 중 전략이 사용하는 컨텍스트
+pub struct StrategyContext<'a> {
+    ts_us: i64,
+    portfolio: &'a Portfolio,
+    open_orders: &'a HashSet<String>,
+    next_order_id: &'a mut u64,
+    actions: Vec<OrderAction>,
+}
+
+impl<'a> StrategyContext<'a> {
+    pub(crate) fn new(ts_us: i64, portfolio: &'a Portfolio, open_orders: &'a HashSet<String>, next_order_id: &'a mut u64) -> Self {
+        Self {
+            ts_us,
+            portfolio,
+            open_orders,
+            next_order_id,
+            actions: Vec::new(),
+        }
+    }
+
+    /// 현재 시각 (Unix 마이크로초)
+    pub fn now_us(&self) -> i64 {
+        self.ts_us
+    }
+
+    /// 심볼 보유 수량 (매도 포지션은 음수)
+    pub fn position(&self, symbol: &str) -> i64 {
+        self.portfolio.position(symbol)
+    }
+
+    /// 현금 잔고
+    pub fn cash(&self) -> f64 {
+        self.portfolio.cash()
+    }
+
+    /// 미체결 주문 여부
+    pub fn is_open(&self, order_id: &str) -> bool {
+        self.open_orders.contains(order_id)
+    }
+
+    /// 지정가 주문 (주문 ID 반환)
+    pub fn limit_order(&mut self, symbol: &str, side: Side, price: u64, quantity: u64) -> String {
+        self.submit(symbol, side, OrderType::Limit, price, quantity)
+    }
+
+    /// 시장가 주문 (주문 ID 반환, 남은 수량은 주문장에 남지 않음)
+    pub fn market_order(&mut self, symbol: &str, side: Side, quantity: u64) -> String {
+        self.submit(symbol, side, OrderType::Market, 0, quantity)
+    }
+
+    /// 주문 취소
+    pub fn cancel(&mut self, order_id: &str) {
+        self.actions.push(OrderAction::Cancel { order_id: order_id.to_string() });
+    }
+
+    pub(crate) fn into_actions(self) -> Vec<OrderAction> {
+        self.actions
+    }
+
+    fn submit(&mut self, symbol: &str, side: Side, order_type: OrderType, price: u64, quantity: u64) -> String {
+        *self.next_order_id += 1;
+        let id = format!("bt-{}", self.next_order_id);
+        let mut order = Order::new(id.clone(), symbol.to_string(), side, order_type, price, quantity, STRATEGY_CLIENT_ID.to_string());
+        order.timestamp = (self.ts_us / 1_000_000).max(0) as u64;
+        self.actions.push(OrderAction::Submit(order));
+        id
+    }
+}
+
+/// 백테스트 전략
+pub trait Strategy {
+    /// 주문 흐름 이벤트마다 호출
+    fn on_tick(&mut self, tick: &MarketTick, ctx: &mut StrategyContext<'_>);
+
+    /// 전략 주문이 체결될 때마다 호출 (부분 체결 포함)
+    fn on_fill(&mut self, fill: &Fill, ctx: &mut StrategyContext<'_>);
+}
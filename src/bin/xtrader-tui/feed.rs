@@ -0,0 +1,360 @@
+//! 서버 피드
+//!
+//! - `/ws`: 호가창 스냅샷/델타와 체결 (연결이 끊기면 재연결)
+//! - `/ws/dashboard`: `queue_depths`, `throughput` 위젯 (관리자 토큰이 있을 때만)
+//! - REST: 심볼 목록(`/v1/ticker`)과 호가창 초기 적재(`/api/v1/orderbook/{symbol}`)
+//!
+//! 서버 메시지는 필요한 필드만 읽어 `FeedEvent`로 바꿔 화면 루프에 보냅니다.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::state::{FeedEvent, LevelChange, Link, LinkStatus, QueueStat, Side, Trade};
+
+/// 재연결 대기 시간 상한
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(10);
+
+/// `/ws` 메시지 중 모니터가 쓰는 것
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum MarketMessage {
+    Execution { execution_report: ExecutionReport },
+    OrderBookSnapshot(BookSnapshot),
+    OrderBookDelta(BookDelta),
+    Error { message: String },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExecutionReport {
+    symbol: String,
+    side: String,
+    price: u64,
+    quantity: u64,
+    timestamp: u64,
+    is_maker: bool,
+    #[serde(default)]
+    exec_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BookSnapshot {
+    symbol: String,
+    bids: Vec<(u64, u64)>,
+    asks: Vec<(u64, u64)>,
+    #[serde(default)]
+    sequence: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BookDelta {
+    symbol: String,
+    bid_changes: Vec<BookChange>,
+    ask_changes: Vec<BookChange>,
+    #[serde(default)]
+    sequence: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct BookChange {
+    change_type: String,
+    price: u64,
+    quantity: u64,
+}
+
+impl From<BookChange> for LevelChange {
+    fn from(change: BookChange) -> Self {
+        LevelChange { price: change.price, quantity: change.quantity, removed: change.change_type == "Remove" }
+    }
+}
+
+/// `/ws/dashboard` 메시지
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum DashboardMessage {
+    Snapshot { widgets: Vec<Widget> },
+    Update(Widget),
+    Error { message: String },
+}
+
+#[derive(Debug, Deserialize)]
+struct Widget {
+    widget_id: String,
+    #[serde(default)]
+    data: Value,
+}
+
+/// `/ws` 텍스트 프레임 해석
+///
+/// 한 체결은 메이커/테이커 보고서 두 건으로 오므로 테이커 쪽 체결만 테이프에 올립니다.
+pub fn parse_market(text: &str) -> Option<FeedEvent> {
+    match serde_json::from_str::<MarketMessage>(text).ok()? {
+        MarketMessage::Execution { execution_report: report } => {
+            let is_trade = report.exec_type.as_deref().is_none_or(|t| t == "Trade");
+            if report.is_maker || !is_trade {
+                return None;
+            }
+            Some(FeedEvent::Trade(Trade {
+                symbol: report.symbol,
+                side: if report.side == "Buy" { Side::Buy } else { Side::Sell },
+                price: report.price,
+                quantity: report.quantity,
+                timestamp: report.timestamp,
+            }))
+        }
+        MarketMessage::OrderBookSnapshot(snapshot) => Some(FeedEvent::BookSnapshot {
+            symbol: snapshot.symbol,
+            bids: snapshot.bids,
+            asks: snapshot.asks,
+            sequence: snapshot.sequence,
+        }),
+        MarketMessage::OrderBookDelta(delta) => Some(FeedEvent::BookDelta {
+            symbol: delta.symbol,
+            bid_changes: delta.bid_changes.into_iter().map(Into::into).collect(),
+            ask_changes: delta.ask_changes.into_iter().map(Into::into).collect(),
+            sequence: delta.sequence,
+        }),
+        MarketMessage::Error { message } => Some(FeedEvent::Notice(message)),
+        MarketMessage::Other => None,
+    }
+}
+
+/// `/ws/dashboard` 텍스트 프레임 해석 (큐 위젯만)
+pub fn parse_dashboard(text: &str) -> Vec<FeedEvent> {
+    let widgets = match serde_json::from_str::<DashboardMessage>(text) {
+        Ok(DashboardMessage::Snapshot { widgets }) => widgets,
+        Ok(DashboardMessage::Update(widget)) => vec![widget],
+        Ok(DashboardMessage::Error { message }) => return vec![FeedEvent::Notice(message)],
+        Err(_) => return Vec::new(),
+    };
+
+    widgets
+        .into_iter()
+        .filter_map(|widget| {
+            let data = widget.data.as_object()?;
+            match widget.widget_id.as_str() {
+                "queue_depths" => Some(FeedEvent::Queues(
+                    data.iter()
+                        .map(|(name, queue)| {
+                            let field = |key: &str| queue.get(key).and_then(Value::as_u64).unwrap_or(0);
+                            QueueStat {
+                                name: name.clone(),
+                                depth: field("depth"),
+                                capacity: field("capacity"),
+                                high_watermark: field("high_watermark"),
+                                rate: None,
+                            }
+                        })
+                        .collect(),
+                )),
+                "throughput" => Some(FeedEvent::Throughput(
+                    data.iter().filter_map(|(name, rate)| Some((name.clone(), rate.as_f64()?))).collect::<HashMap<_, _>>(),
+                )),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// 피드 작업 생성기
+#[derive(Clone)]
+pub struct Feed {
+    http: reqwest::Client,
+    api_base: String,
+    ws_base: String,
+    admin_token: Option<String>,
+    depth: usize,
+    tx: mpsc::Sender<FeedEvent>,
+}
+
+impl Feed {
+    pub fn new(api_url: &str, admin_token: Option<String>, depth: usize, tx: mpsc::Sender<FeedEvent>) -> Self {
+        let api_base = api_url.trim_end_matches('/').to_string();
+        let ws_base = api_base
+            .strip_prefix("https://")
+            .map(|rest| format!("wss://{}", rest))
+            .or_else(|| api_base.strip_prefix("http://").map(|rest| format!("ws://{}", rest)))
+            .unwrap_or_else(|| api_base.clone());
+        let http = reqwest::Client::builder().timeout(Duration::from_secs(5)).build().unwrap_or_default();
+        Self { http, api_base, ws_base, admin_token, depth, tx }
+    }
+
+    /// 시장 데이터와 (토큰이 있으면) 대시보드 스트림 시작, 심볼 목록 조회
+    pub fn start(&self) {
+        let feed = self.clone();
+        tokio::spawn(async move { feed.load_symbols().await });
+
+        let feed = self.clone();
+        tokio::spawn(async move { feed.stream(Link::Market, "/ws", |text| parse_market(text).into_iter().collect()).await });
+
+        if self.admin_token.is_some() {
+            let feed = self.clone();
+            tokio::spawn(async move { feed.stream(Link::Dashboard, "/ws/dashboard", parse_dashboard).await });
+        } else {
+            let status = LinkStatus::Disabled("관리자 토큰 없음 (--token 또는 XTRADER_ADMIN_TOKEN)".to_string());
+            let _ = self.tx.try_send(FeedEvent::Status(Link::Dashboard, status));
+        }
+    }
+
+    /// REST로 호가창 적재
+    pub fn load_book(&self, symbol: String) {
+        let feed = self.clone();
+        tokio::spawn(async move {
+            let url = format!("{}/api/v1/orderbook/{}?depth={}", feed.api_base, symbol, feed.depth);
+            let event = match feed.get_json(&url).await {
+                Ok(body) => match serde_json::from_value::<BookSnapshot>(body["orderbook"].clone()) {
+                    Ok(book) => FeedEvent::BookSnapshot { symbol: book.symbol, bids: book.bids, asks: book.asks, sequence: None },
+                    Err(e) => FeedEvent::Notice(format!("{} 호가창 응답 해석 실패: {}", symbol, e)),
+                },
+                Err(e) => FeedEvent::Notice(format!("{} 호가창 조회 실패: {}", symbol, e)),
+            };
+            let _ = feed.tx.send(event).await;
+        });
+    }
+
+    async fn load_symbols(&self) {
+        let url = format!("{}/v1/ticker", self.api_base);
+        let event = match self.get_json(&url).await {
+            Ok(body) => FeedEvent::Symbols(
+                body["tickers"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|ticker| ticker["symbol"].as_str().map(str::to_string))
+                    .collect(),
+            ),
+            Err(e) => FeedEvent::Notice(format!("심볼 목록 조회 실패: {}", e)),
+        };
+        let _ = self.tx.send(event).await;
+    }
+
+    async fn get_json(&self, url: &str) -> Result<Value, String> {
+        let response = self.http.get(url).send().await.map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            return Err(response.status().to_string());
+        }
+        response.json::<Value>().await.map_err(|e| e.to_string())
+    }
+
+    /// WebSocket 연결 유지 (끊기면 1초부터 두 배씩, 최대 10초 기다렸다가 재연결)
+    async fn stream(&self, link: Link, path: &str, parse: fn(&str) -> Vec<FeedEvent>) {
+        let mut delay = Duration::from_secs(1);
+        loop {
+            let _ = self.tx.send(FeedEvent::Status(link, LinkStatus::Connecting)).await;
+            let reason = match self.run_stream(link, path, parse).await {
+                Ok(()) => "서버가 연결을 닫음".to_string(),
+                Err(e) => e,
+            };
+            if self.tx.is_closed() {
+                return;
+            }
+            let _ = self.tx.send(FeedEvent::Status(link, LinkStatus::Disconnected(reason))).await;
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+        }
+    }
+
+    async fn run_stream(&self, link: Link, path: &str, parse: fn(&str) -> Vec<FeedEvent>) -> Result<(), String> {
+        let url = format!("{}{}", self.ws_base, path);
+        let mut request = url.as_str().into_client_request().map_err(|e| e.to_string())?;
+        if let (Link::Dashboard, Some(token)) = (link, &self.admin_token) {
+            let token = HeaderValue::from_str(token).map_err(|e| e.to_string())?;
+            request.headers_mut().insert("X-Admin-Token", token);
+        }
+
+        let (mut socket, _) = tokio_tungstenite::connect_async(request).await.map_err(|e| format!("{}: {}", url, e))?;
+        if link == Link::Dashboard {
+            let subscribe = r#"{"type":"subscribe","widgets":["queue_depths","throughput"]}"#;
+            socket.send(Message::Text(subscribe.to_string())).await.map_err(|e| e.to_string())?;
+        }
+        let _ = self.tx.send(FeedEvent::Status(link, LinkStatus::Connected)).await;
+
+        while let Some(message) = socket.next().await {
+            match message.map_err(|e| e.to_string())? {
+                Message::Text(text) => {
+                    for event in parse(&text) {
+                        if self.tx.send(event).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+                }
+                Message::Close(_) => break,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_market_messages() {
+        let execution = |is_maker: bool| {
+            format!(
+                r#"{{"type":"Execution","order_status":"Filled","execution_report":{{"execution_id":"e1","order_id":"o1",
+                "symbol":"BTC-KRW","side":"Sell","price":100,"quantity":2,"remaining_quantity":0,"timestamp":1700000000,
+                "counterparty_id":"o2","is_maker":{},"exec_type":"Trade"}}}}"#,
+                is_maker
+            )
+        };
+        assert_eq!(parse_market(&execution(true)), None);
+        assert_eq!(
+            parse_market(&execution(false)),
+            Some(FeedEvent::Trade(Trade {
+                symbol: "BTC-KRW".to_string(),
+                side: Side::Sell,
+                price: 100,
+                quantity: 2,
+                timestamp: 1_700_000_000,
+            }))
+        );
+
+        let delta = r#"{"type":"OrderBookDelta","symbol":"BTC-KRW","timestamp":1,"sequence":7,
+            "bid_changes":[{"change_type":"Remove","price":99,"quantity":0}],
+            "ask_changes":[{"change_type":"Update","price":101,"quantity":5}]}"#;
+        assert_eq!(
+            parse_market(delta),
+            Some(FeedEvent::BookDelta {
+                symbol: "BTC-KRW".to_string(),
+                bid_changes: vec![LevelChange { price: 99, quantity: 0, removed: true }],
+                ask_changes: vec![LevelChange { price: 101, quantity: 5, removed: false }],
+                sequence: 7,
+            })
+        );
+        assert_eq!(parse_market(r#"{"type":"Ticker","timestamp":1,"tickers":[]}"#), None);
+    }
+
+    #[test]
+    fn test_parse_dashboard_queue_widgets() {
+        let snapshot = r#"{"type":"snapshot","widgets":[
+            {"widget_id":"queue_depths","widget_type":"MetricGauge","timestamp":1,
+             "data":{"engine":{"depth":5,"capacity":100,"high_watermark":9,"utilization":0.05}}},
+            {"widget_id":"latency","widget_type":"MetricGauge","timestamp":1,"data":{"p99":3.0}}]}"#;
+        let events = parse_dashboard(snapshot);
+        assert_eq!(
+            events,
+            [FeedEvent::Queues(vec![QueueStat {
+                name: "engine".to_string(),
+                depth: 5,
+                capacity: 100,
+                high_watermark: 9,
+                rate: None,
+            }])]
+        );
+
+        let update = r#"{"type":"update","widget_id":"throughput","widget_type":"MetricGauge","timestamp":2,"data":{"engine":42.5}}"#;
+        assert_eq!(parse_dashboard(update), [FeedEvent::Throughput(HashMap::from([("engine".to_string(), 42.5)]))]);
+    }
+}
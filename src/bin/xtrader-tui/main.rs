@@ -0,0 +1,139 @@
+//! xtrader-tui - 터미널 실시간 모니터
+//!
+//! 브라우저 없이 서버에 WebSocket으로 붙어 심볼별 호가창, 체결 테이프,
+//! 엔진/시퀀서 큐 지표를 보여줍니다. 키보드로 심볼을 전환합니다.
+//!
+//! ```bash
+//! cargo run --features tui --bin xtrader-tui -- --url http://localhost:7000 --symbols BTC-KRW,ETH-KRW
+//! ```
+
+mod feed;
+mod state;
+mod ui;
+
+use std::collections::HashSet;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use crossterm::event::{Event, EventStream, KeyEventKind};
+use futures_util::StreamExt;
+use tokio::sync::mpsc;
+
+use feed::Feed;
+use state::{KeyAction, MonitorState};
+
+const USAGE: &str = "\
+사용법: xtrader-tui [--url 주소] [--token 관리자토큰] [--symbols 심볼,...] [--depth N]
+
+  --url       서버 주소 (기본 $XTRADER_API_URL 또는 http://localhost:7000)
+  --token     관리자 토큰, 큐 지표(/ws/dashboard)에 필요 (기본 $XTRADER_ADMIN_TOKEN)
+  --symbols   처음 보여줄 심볼 (없으면 서버 티커와 수신한 시장 데이터에서 수집)
+  --depth     REST로 적재할 호가 단계 수 (기본 20)
+
+키: ←/→ 또는 Tab 심볼 전환, 1-9 심볼 선택, r 호가창 다시 적재, q 종료
+";
+
+/// 명령행 옵션
+struct Options {
+    api_url: String,
+    admin_token: Option<String>,
+    symbols: Vec<String>,
+    depth: usize,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        api_url: std::env::var("XTRADER_API_URL").unwrap_or_else(|_| "http://localhost:7000".to_string()),
+        admin_token: std::env::var("XTRADER_ADMIN_TOKEN").ok().filter(|token| !token.is_empty()),
+        symbols: Vec::new(),
+        depth: 20,
+    };
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} 옵션에 값이 필요합니다", arg));
+        match arg.as_str() {
+            "--url" => options.api_url = value()?,
+            "--token" => options.admin_token = Some(value()?),
+            "--symbols" => {
+                options.symbols = value()?.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect();
+            }
+            "--depth" => {
+                let depth = value()?;
+                options.depth = depth.parse().map_err(|_| format!("--depth 값은 정수여야 합니다: {}", depth))?;
+            }
+            "-h" | "--help" => return Err(String::new()),
+            other => return Err(format!("알 수 없는 인자: {}", other)),
+        }
+    }
+    Ok(options)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) if e.is_empty() => {
+            print!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, options).await;
+    ratatui::restore();
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("오류: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(terminal: &mut ratatui::DefaultTerminal, options: Options) -> std::io::Result<()> {
+    let (tx, mut rx) = mpsc::channel(4096);
+    let feed = Feed::new(&options.api_url, options.admin_token, options.depth, tx);
+    let mut state = MonitorState::new(options.symbols);
+    let mut requested: HashSet<String> = HashSet::new();
+    feed.start();
+
+    let mut keys = EventStream::new();
+    let mut redraw = tokio::time::interval(Duration::from_millis(250));
+
+    loop {
+        // 선택 심볼의 호가창이 아직 없으면 한 번 REST로 적재
+        if let Some(symbol) = state.selected_symbol() {
+            if state.ladder(symbol).is_none() && requested.insert(symbol.to_string()) {
+                feed.load_book(symbol.to_string());
+            }
+        }
+        terminal.draw(|frame| ui::draw(frame, &state))?;
+
+        tokio::select! {
+            Some(event) = rx.recv() => {
+                state.apply(event);
+                // 몰려온 이벤트는 한 번에 적용하고 다시 그림
+                while let Ok(event) = rx.try_recv() {
+                    state.apply(event);
+                }
+            }
+            event = keys.next() => match event {
+                Some(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => match state.handle_key(key) {
+                    KeyAction::Quit => return Ok(()),
+                    KeyAction::Reload(symbol) => feed.load_book(symbol),
+                    KeyAction::Selected(_) | KeyAction::None => {}
+                },
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(e),
+                None => return Ok(()),
+            },
+            _ = redraw.tick() => {}
+        }
+    }
+}
@@ -0,0 +1,421 @@
+//! 모니터 화면 상태
+//!
+//! 피드 이벤트(호가창 스냅샷/델타, 체결, 큐 지표)를 적용하고 키 입력으로 심볼을 선택합니다.
+//! 그리기는 `ui.rs`가 이 상태만 보고 합니다.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// 심볼별로 보관하는 최근 체결 수
+pub const TRADE_TAPE_LEN: usize = 200;
+
+/// 매수/매도 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+/// 호가 단계 변경 (수량 0 또는 `removed`면 단계 삭제)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelChange {
+    pub price: u64,
+    pub quantity: u64,
+    pub removed: bool,
+}
+
+/// 체결 (테이커 쪽 보고서 기준)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trade {
+    pub symbol: String,
+    /// 테이커 방향 (매수면 상승 체결)
+    pub side: Side,
+    pub price: u64,
+    pub quantity: u64,
+    /// 체결 시각 (Unix 초)
+    pub timestamp: u64,
+}
+
+/// 큐 깊이 지표 (`queue_depths` 위젯)
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueStat {
+    pub name: String,
+    pub depth: u64,
+    pub capacity: u64,
+    pub high_watermark: u64,
+    /// 직전 샘플 대비 처리량 (건/초, `throughput` 위젯)
+    pub rate: Option<f64>,
+}
+
+impl QueueStat {
+    /// 사용률 (0.0 ~ 1.0)
+    pub fn utilization(&self) -> f64 {
+        if self.capacity == 0 {
+            0.0
+        } else {
+            (self.depth as f64 / self.capacity as f64).min(1.0)
+        }
+    }
+}
+
+/// 피드 연결 상태
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkStatus {
+    Connecting,
+    Connected,
+    Disconnected(String),
+    /// 사용하지 않음 (예: 관리자 토큰이 없어 대시보드 스트림 생략)
+    Disabled(String),
+}
+
+/// 피드 채널
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Link {
+    /// `/ws` 시장 데이터
+    Market,
+    /// `/ws/dashboard` 엔진/시퀀서 지표
+    Dashboard,
+}
+
+/// 피드 작업이 화면 루프로 보내는 이벤트
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedEvent {
+    /// 심볼 목록 (REST 티커)
+    Symbols(Vec<String>),
+    /// 전체 호가창 (REST 초기 적재는 `sequence` 없음)
+    BookSnapshot {
+        symbol: String,
+        bids: Vec<(u64, u64)>,
+        asks: Vec<(u64, u64)>,
+        sequence: Option<u64>,
+    },
+    /// 호가창 변경분
+    BookDelta {
+        symbol: String,
+        bid_changes: Vec<LevelChange>,
+        ask_changes: Vec<LevelChange>,
+        sequence: u64,
+    },
+    Trade(Trade),
+    /// 큐 깊이 (처리량은 유지)
+    Queues(Vec<QueueStat>),
+    /// 큐별 처리량
+    Throughput(HashMap<String, f64>),
+    Status(Link, LinkStatus),
+    /// 서버가 보낸 오류나 요청 실패
+    Notice(String),
+}
+
+/// 한 심볼의 호가창
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ladder {
+    pub bids: BTreeMap<u64, u64>,
+    pub asks: BTreeMap<u64, u64>,
+    /// 마지막으로 적용한 시퀀스 (REST 적재 직후 0)
+    pub sequence: u64,
+}
+
+impl Ladder {
+    fn apply(levels: &mut BTreeMap<u64, u64>, changes: &[LevelChange]) {
+        for change in changes {
+            if change.removed || change.quantity == 0 {
+                levels.remove(&change.price);
+            } else {
+                levels.insert(change.price, change.quantity);
+            }
+        }
+    }
+
+    pub fn best_bid(&self) -> Option<u64> {
+        self.bids.keys().next_back().copied()
+    }
+
+    pub fn best_ask(&self) -> Option<u64> {
+        self.asks.keys().next().copied()
+    }
+
+    pub fn spread(&self) -> Option<u64> {
+        Some(self.best_ask()?.saturating_sub(self.best_bid()?))
+    }
+}
+
+/// 키 입력 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyAction {
+    None,
+    Quit,
+    /// 선택 심볼이 바뀜 (호가창이 없으면 REST로 적재)
+    Selected(String),
+    /// 선택 심볼 호가창 다시 적재
+    Reload(String),
+}
+
+/// 화면 상태
+#[derive(Debug, Default)]
+pub struct MonitorState {
+    symbols: Vec<String>,
+    selected: usize,
+    books: HashMap<String, Ladder>,
+    trades: HashMap<String, VecDeque<Trade>>,
+    queues: Vec<QueueStat>,
+    market_link: Option<LinkStatus>,
+    dashboard_link: Option<LinkStatus>,
+    notice: Option<String>,
+}
+
+impl MonitorState {
+    pub fn new(symbols: Vec<String>) -> Self {
+        let mut state = Self::default();
+        state.add_symbols(symbols);
+        state
+    }
+
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_symbol(&self) -> Option<&str> {
+        self.symbols.get(self.selected).map(String::as_str)
+    }
+
+    pub fn ladder(&self, symbol: &str) -> Option<&Ladder> {
+        self.books.get(symbol)
+    }
+
+    /// 최근 체결 (최신 순)
+    pub fn trades(&self, symbol: &str) -> impl Iterator<Item = &Trade> {
+        self.trades.get(symbol).into_iter().flat_map(|tape| tape.iter().rev())
+    }
+
+    pub fn queues(&self) -> &[QueueStat] {
+        &self.queues
+    }
+
+    pub fn link(&self, link: Link) -> Option<&LinkStatus> {
+        match link {
+            Link::Market => self.market_link.as_ref(),
+            Link::Dashboard => self.dashboard_link.as_ref(),
+        }
+    }
+
+    pub fn notice(&self) -> Option<&str> {
+        self.notice.as_deref()
+    }
+
+    /// 심볼 추가 (정렬 유지, 선택 심볼은 그대로)
+    fn add_symbols(&mut self, symbols: impl IntoIterator<Item = String>) {
+        let selected = self.selected_symbol().map(str::to_string);
+        let before = self.symbols.len();
+        for symbol in symbols {
+            if !self.symbols.contains(&symbol) {
+                self.symbols.push(symbol);
+            }
+        }
+        if self.symbols.len() != before {
+            self.symbols.sort();
+            if let Some(selected) = selected {
+                self.selected = self.symbols.iter().position(|s| *s == selected).unwrap_or(0);
+            }
+        }
+    }
+
+    /// 피드 이벤트 적용
+    pub fn apply(&mut self, event: FeedEvent) {
+        match event {
+            FeedEvent::Symbols(symbols) => self.add_symbols(symbols),
+            FeedEvent::BookSnapshot { symbol, bids, asks, sequence } => {
+                self.add_symbols([symbol.clone()]);
+                let ladder = self.books.entry(symbol).or_default();
+                // 이미 더 최신 델타를 적용했으면 오래된 스냅샷 무시
+                if sequence.is_some_and(|sequence| sequence < ladder.sequence) {
+                    return;
+                }
+                ladder.bids = bids.into_iter().filter(|(_, quantity)| *quantity > 0).collect();
+                ladder.asks = asks.into_iter().filter(|(_, quantity)| *quantity > 0).collect();
+                ladder.sequence = sequence.unwrap_or(0);
+            }
+            FeedEvent::BookDelta { symbol, bid_changes, ask_changes, sequence } => {
+                // 스냅샷을 받기 전 델타는 적용할 기준이 없으므로 버림
+                let Some(ladder) = self.books.get_mut(&symbol) else {
+                    self.add_symbols([symbol]);
+                    return;
+                };
+                if sequence != 0 && sequence <= ladder.sequence {
+                    return;
+                }
+                Ladder::apply(&mut ladder.bids, &bid_changes);
+                Ladder::apply(&mut ladder.asks, &ask_changes);
+                ladder.sequence = sequence;
+            }
+            FeedEvent::Trade(trade) => {
+                self.add_symbols([trade.symbol.clone()]);
+                let tape = self.trades.entry(trade.symbol.clone()).or_default();
+                tape.push_back(trade);
+                if tape.len() > TRADE_TAPE_LEN {
+                    tape.pop_front();
+                }
+            }
+            FeedEvent::Queues(mut queues) => {
+                for queue in &mut queues {
+                    queue.rate = self.queues.iter().find(|q| q.name == queue.name).and_then(|q| q.rate);
+                }
+                queues.sort_by(|a, b| a.name.cmp(&b.name));
+                self.queues = queues;
+            }
+            FeedEvent::Throughput(rates) => {
+                for queue in &mut self.queues {
+                    if let Some(rate) = rates.get(&queue.name) {
+                        queue.rate = Some(*rate);
+                    }
+                }
+            }
+            FeedEvent::Status(link, status) => match link {
+                Link::Market => self.market_link = Some(status),
+                Link::Dashboard => self.dashboard_link = Some(status),
+            },
+            FeedEvent::Notice(notice) => self.notice = Some(notice),
+        }
+    }
+
+    /// 키 입력 처리
+    ///
+    /// `←`/`→`, `Tab`/`Shift+Tab`: 이전/다음 심볼, `1`~`9`: 해당 순번 심볼,
+    /// `r`: 호가창 다시 적재, `q`/`Esc`/`Ctrl+C`: 종료
+    pub fn handle_key(&mut self, key: KeyEvent) -> KeyAction {
+        let count = self.symbols.len();
+        let target = match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return KeyAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return KeyAction::Quit,
+            KeyCode::Char('r') => {
+                return self.selected_symbol().map(|s| KeyAction::Reload(s.to_string())).unwrap_or(KeyAction::None);
+            }
+            _ if count == 0 => return KeyAction::None,
+            KeyCode::Right | KeyCode::Tab => (self.selected + 1) % count,
+            KeyCode::Left | KeyCode::BackTab => (self.selected + count - 1) % count,
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                if index >= count {
+                    return KeyAction::None;
+                }
+                index
+            }
+            _ => return KeyAction::None,
+        };
+        if target == self.selected {
+            return KeyAction::None;
+        }
+        self.selected = target;
+        self.notice = None;
+        KeyAction::Selected(self.symbols[target].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(price: u64, quantity: u64) -> LevelChange {
+        LevelChange { price, quantity, removed: false }
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_snapshot_then_deltas_in_sequence() {
+        let mut state = MonitorState::new(vec![]);
+        // 스냅샷 전 델타는 버림
+        state.apply(FeedEvent::BookDelta {
+            symbol: "BTC-KRW".to_string(),
+            bid_changes: vec![change(100, 1)],
+            ask_changes: vec![],
+            sequence: 1,
+        });
+        assert!(state.ladder("BTC-KRW").is_none());
+        assert_eq!(state.symbols(), ["BTC-KRW".to_string()]);
+
+        state.apply(FeedEvent::BookSnapshot {
+            symbol: "BTC-KRW".to_string(),
+            bids: vec![(99, 3), (98, 1)],
+            asks: vec![(101, 2)],
+            sequence: Some(5),
+        });
+        state.apply(FeedEvent::BookDelta {
+            symbol: "BTC-KRW".to_string(),
+            bid_changes: vec![change(99, 0), change(100, 4)],
+            ask_changes: vec![LevelChange { price: 101, quantity: 2, removed: true }, change(102, 7)],
+            sequence: 6,
+        });
+        // 이미 적용한 시퀀스는 무시
+        state.apply(FeedEvent::BookDelta {
+            symbol: "BTC-KRW".to_string(),
+            bid_changes: vec![change(100, 9)],
+            ask_changes: vec![],
+            sequence: 6,
+        });
+
+        let ladder = state.ladder("BTC-KRW").unwrap();
+        assert_eq!(ladder.bids.iter().map(|(p, q)| (*p, *q)).collect::<Vec<_>>(), [(98, 1), (100, 4)]);
+        assert_eq!((ladder.best_bid(), ladder.best_ask(), ladder.spread()), (Some(100), Some(102), Some(2)));
+        assert_eq!(ladder.sequence, 6);
+    }
+
+    #[test]
+    fn test_trade_tape_and_queue_metrics() {
+        let mut state = MonitorState::new(vec!["ETH-KRW".to_string()]);
+        for i in 0..(TRADE_TAPE_LEN as u64 + 5) {
+            state.apply(FeedEvent::Trade(Trade {
+                symbol: "ETH-KRW".to_string(),
+                side: Side::Buy,
+                price: 3000 + i,
+                quantity: 1,
+                timestamp: i,
+            }));
+        }
+        assert_eq!(state.trades("ETH-KRW").count(), TRADE_TAPE_LEN);
+        assert_eq!(state.trades("ETH-KRW").next().unwrap().price, 3000 + TRADE_TAPE_LEN as u64 + 4);
+
+        let queue = |name: &str, depth| QueueStat {
+            name: name.to_string(),
+            depth,
+            capacity: 100,
+            high_watermark: depth,
+            rate: None,
+        };
+        state.apply(FeedEvent::Queues(vec![queue("sequencer", 10), queue("engine", 50)]));
+        state.apply(FeedEvent::Throughput(HashMap::from([("engine".to_string(), 120.0)])));
+        // 깊이만 갱신돼도 처리량은 유지
+        state.apply(FeedEvent::Queues(vec![queue("engine", 80), queue("sequencer", 0)]));
+        let queues = state.queues();
+        assert_eq!((queues[0].name.as_str(), queues[0].rate, queues[0].utilization()), ("engine", Some(120.0), 0.8));
+        assert_eq!(queues[1].rate, None);
+    }
+
+    #[test]
+    fn test_keyboard_navigation_keeps_selection_when_symbols_arrive() {
+        let mut state = MonitorState::new(vec!["ETH-KRW".to_string(), "BTC-KRW".to_string()]);
+        assert_eq!(state.selected_symbol(), Some("BTC-KRW"));
+        assert_eq!(state.handle_key(key(KeyCode::Right)), KeyAction::Selected("ETH-KRW".to_string()));
+
+        // 앞쪽에 정렬되는 심볼이 추가돼도 선택 유지
+        state.apply(FeedEvent::Symbols(vec!["ADA-KRW".to_string()]));
+        assert_eq!(state.selected_symbol(), Some("ETH-KRW"));
+
+        assert_eq!(state.handle_key(key(KeyCode::Right)), KeyAction::Selected("ADA-KRW".to_string()));
+        assert_eq!(state.handle_key(key(KeyCode::Left)), KeyAction::Selected("ETH-KRW".to_string()));
+        assert_eq!(state.handle_key(key(KeyCode::Char('2'))), KeyAction::Selected("BTC-KRW".to_string()));
+        assert_eq!(state.handle_key(key(KeyCode::Char('2'))), KeyAction::None);
+        assert_eq!(state.handle_key(key(KeyCode::Char('9'))), KeyAction::None);
+        assert_eq!(state.handle_key(key(KeyCode::Char('r'))), KeyAction::Reload("BTC-KRW".to_string()));
+        assert_eq!(
+            state.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            KeyAction::Quit
+        );
+    }
+}
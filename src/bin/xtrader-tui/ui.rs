@@ -0,0 +1,196 @@
+//! 화면 그리기
+//!
+//! ```text
+//! ┌ 심볼 탭 ─────────────────────────────────────────┐
+//! │ 호가창 (매도 위, 매수 아래)    │ 체결 테이프        │
+//! ├──────────────────────────────────────────────────┤
+//! │ 엔진/시퀀서 큐 (깊이, 사용률, 처리량)              │
+//! └ 연결 상태 / 키 안내 ─────────────────────────────┘
+//! ```
+
+use chrono::{Local, TimeZone};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, Tabs};
+use ratatui::Frame;
+
+use crate::state::{Ladder, Link, LinkStatus, MonitorState, QueueStat, Side};
+
+/// 수량 막대 최대 폭
+const BAR_WIDTH: usize = 20;
+
+pub fn draw(frame: &mut Frame, state: &MonitorState) {
+    let queue_rows = state.queues().len().max(1) as u16 + 3;
+    let [tabs_area, main_area, queue_area, status_area] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(8),
+        Constraint::Length(queue_rows),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [book_area, tape_area] = Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)]).areas(main_area);
+
+    draw_tabs(frame, tabs_area, state);
+    let symbol = state.selected_symbol();
+    draw_ladder(frame, book_area, symbol, symbol.and_then(|s| state.ladder(s)));
+    draw_tape(frame, tape_area, state);
+    draw_queues(frame, queue_area, state);
+    draw_status(frame, status_area, state);
+}
+
+fn draw_tabs(frame: &mut Frame, area: Rect, state: &MonitorState) {
+    let block = Block::bordered().title(" xTrader 모니터 ");
+    if state.symbols().is_empty() {
+        frame.render_widget(Paragraph::new("심볼 대기 중...").block(block), area);
+        return;
+    }
+    let titles = state.symbols().iter().enumerate().map(|(i, symbol)| {
+        if i < 9 {
+            format!("{} {}", i + 1, symbol)
+        } else {
+            symbol.clone()
+        }
+    });
+    let tabs = Tabs::new(titles)
+        .block(block)
+        .select(state.selected_index())
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED));
+    frame.render_widget(tabs, area);
+}
+
+fn draw_ladder(frame: &mut Frame, area: Rect, symbol: Option<&str>, ladder: Option<&Ladder>) {
+    let title = match ladder.and_then(Ladder::spread) {
+        Some(spread) => format!(" 호가창 {} (스프레드 {}) ", symbol.unwrap_or_default(), spread),
+        None => format!(" 호가창 {} ", symbol.unwrap_or_default()),
+    };
+    let block = Block::bordered().title(title);
+    let Some(ladder) = ladder else {
+        frame.render_widget(Paragraph::new("호가창 적재 중...").block(block), area);
+        return;
+    };
+
+    // 테두리, 머리글, 가운데 구분선을 뺀 나머지를 매도/매수가 반씩 사용
+    let levels = (area.height.saturating_sub(4) / 2) as usize;
+    let asks: Vec<(u64, u64)> = ladder.asks.iter().take(levels).map(|(p, q)| (*p, *q)).collect();
+    let bids: Vec<(u64, u64)> = ladder.bids.iter().rev().take(levels).map(|(p, q)| (*p, *q)).collect();
+    let max_quantity = asks.iter().chain(&bids).map(|(_, q)| *q).max().unwrap_or(1).max(1);
+
+    let row = |(price, quantity): (u64, u64), color: Color| {
+        let bar = "█".repeat(((quantity as f64 / max_quantity as f64) * BAR_WIDTH as f64).ceil() as usize);
+        Row::new([
+            Cell::from(price.to_string()).fg(color),
+            Cell::from(quantity.to_string()),
+            Cell::from(bar).fg(color),
+        ])
+    };
+    // 매도 호가가 적어도 구분선이 가운데 오도록 위를 빈 줄로 채움
+    let mut rows: Vec<Row> = vec![Row::new([""; 3]); levels.saturating_sub(asks.len())];
+    rows.extend(asks.iter().rev().map(|level| row(*level, Color::Red)));
+    rows.push(Row::new([Cell::from("─".repeat(12)), Cell::from("─".repeat(10)), Cell::from("")]).dark_gray());
+    rows.extend(bids.iter().map(|level| row(*level, Color::Green)));
+
+    let table = Table::new(rows, [Constraint::Length(14), Constraint::Length(12), Constraint::Min(BAR_WIDTH as u16)])
+        .header(Row::new(["가격", "수량", ""]).bold())
+        .block(block);
+    frame.render_widget(table, area);
+}
+
+fn draw_tape(frame: &mut Frame, area: Rect, state: &MonitorState) {
+    let visible = area.height.saturating_sub(3) as usize;
+    let rows = state
+        .selected_symbol()
+        .into_iter()
+        .flat_map(|symbol| state.trades(symbol))
+        .take(visible)
+        .map(|trade| {
+            let (label, color) = match trade.side {
+                Side::Buy => ("매수", Color::Green),
+                Side::Sell => ("매도", Color::Red),
+            };
+            Row::new([
+                Cell::from(format_time(trade.timestamp)).dark_gray(),
+                Cell::from(trade.price.to_string()).fg(color),
+                Cell::from(trade.quantity.to_string()),
+                Cell::from(label).fg(color),
+            ])
+        });
+    let widths = [Constraint::Length(10), Constraint::Length(14), Constraint::Length(12), Constraint::Length(4)];
+    let table = Table::new(rows, widths)
+        .header(Row::new(["시각", "가격", "수량", ""]).bold())
+        .block(Block::bordered().title(" 체결 "));
+    frame.render_widget(table, area);
+}
+
+fn draw_queues(frame: &mut Frame, area: Rect, state: &MonitorState) {
+    let block = Block::bordered().title(" 엔진/시퀀서 큐 ");
+    if state.queues().is_empty() {
+        let message = match state.link(Link::Dashboard) {
+            Some(LinkStatus::Disabled(reason)) => reason.clone(),
+            _ => "지표 대기 중...".to_string(),
+        };
+        frame.render_widget(Paragraph::new(message).dark_gray().block(block), area);
+        return;
+    }
+
+    let rows = state.queues().iter().map(|queue: &QueueStat| {
+        let utilization = queue.utilization();
+        let color = match utilization {
+            u if u >= 0.8 => Color::Red,
+            u if u >= 0.5 => Color::Yellow,
+            _ => Color::Green,
+        };
+        let filled = (utilization * BAR_WIDTH as f64).round() as usize;
+        Row::new([
+            Cell::from(queue.name.clone()),
+            Cell::from(format!("{}/{}", queue.depth, queue.capacity)),
+            Cell::from(Line::from(vec![
+                Span::styled("█".repeat(filled), Style::default().fg(color)),
+                Span::styled("░".repeat(BAR_WIDTH - filled), Style::default().fg(Color::DarkGray)),
+                Span::raw(format!(" {:>3.0}%", utilization * 100.0)),
+            ])),
+            Cell::from(queue.high_watermark.to_string()),
+            Cell::from(queue.rate.map(|rate| format!("{:.1}", rate)).unwrap_or_else(|| "-".to_string())),
+        ])
+    });
+    let widths = [
+        Constraint::Min(16),
+        Constraint::Length(14),
+        Constraint::Length(BAR_WIDTH as u16 + 5),
+        Constraint::Length(10),
+        Constraint::Length(12),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(["큐", "깊이", "사용률", "최고 깊이", "처리량(건/초)"]).bold())
+        .block(block);
+    frame.render_widget(table, area);
+}
+
+fn draw_status(frame: &mut Frame, area: Rect, state: &MonitorState) {
+    let link = |name: &str, status: Option<&LinkStatus>| {
+        let (text, color) = match status {
+            Some(LinkStatus::Connected) => ("연결됨".to_string(), Color::Green),
+            Some(LinkStatus::Connecting) | None => ("연결 중".to_string(), Color::Yellow),
+            Some(LinkStatus::Disconnected(reason)) => (format!("끊김: {}", reason), Color::Red),
+            Some(LinkStatus::Disabled(_)) => ("사용 안 함".to_string(), Color::DarkGray),
+        };
+        vec![Span::raw(format!("{} ", name)), Span::styled(text, Style::default().fg(color)), Span::raw("  ")]
+    };
+
+    let mut spans = link("시장", state.link(Link::Market));
+    spans.extend(link("지표", state.link(Link::Dashboard)));
+    match state.notice() {
+        Some(notice) => spans.push(Span::styled(notice.to_string(), Style::default().fg(Color::Yellow))),
+        None => spans.push(Span::styled("←/→ 심볼  1-9 이동  r 새로고침  q 종료", Style::default().fg(Color::DarkGray))),
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// 체결 시각 (Unix 초 → 현지 시각)
+fn format_time(timestamp: u64) -> String {
+    Local
+        .timestamp_opt(timestamp as i64, 0)
+        .single()
+        .map(|time| time.format("%H:%M:%S").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
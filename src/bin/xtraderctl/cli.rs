@@ -0,0 +1,206 @@
+//! 명령행 인자 해석
+//!
+//! 서버(`main.rs`)와 시뮬레이터처럼 외부 인자 파서 없이 직접 해석합니다.
+
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+사용법: xtraderctl [--config 경로] [--json] <명령> [옵션]
+
+명령:
+  symbols                              심볼 목록 (거래 중단 여부, 미체결 주문 수, 최우선 호가)
+  halt <심볼> [--reason 사유]           심볼 거래 중단 (TOTP 활성화 시 --totp 필요)
+  resume <심볼>                        심볼 거래 재개
+  orderbook <심볼> [--depth N]          호가창 조회 (기본 10단계)
+  cancel-orders <계정>                  계정 미체결 주문 일괄 취소 (계정은 차단하지 않음)
+  recover [--mq 종류] [--topic 토픽] [--symbol 심볼] [--within 초]
+                                       MQ 백업 메시지 부분 복구 시작 (종류: kafka, rabbitmq, redis)
+  recovery-jobs [작업ID]                복구 작업 목록 또는 단일 작업 진행 상황
+  backup                               DB 온라인 백업 생성 (서버의 XTRADER_BACKUP_DIR에 저장)
+  backups                              DB 백업 목록 (저널 순번, 크기, SHA-256)
+  health                               생존/준비 상태 (/healthz, /readyz)
+  tail [심볼]                          실시간 체결 출력 (Ctrl+C로 종료)
+
+공통 옵션:
+  --config 경로   설정 파일 (기본 $XTRADERCTL_CONFIG 또는 ~/.config/xtrader/xtraderctl.toml)
+  --json          응답 JSON을 그대로 출력
+  --totp 코드     관리자 2단계 인증 코드 (halt, cancel-orders, recover)
+";
+
+/// 실행할 명령
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Symbols,
+    Halt { symbol: String, reason: Option<String> },
+    Resume { symbol: String },
+    OrderBook { symbol: String, depth: usize },
+    CancelOrders { client_id: String },
+    Recover {
+        mq_type: Option<String>,
+        topic: Option<String>,
+        symbol: Option<String>,
+        within_secs: Option<u64>,
+    },
+    RecoveryJobs { job_id: Option<String> },
+    Backup,
+    Backups,
+    Health,
+    Tail { symbol: Option<String> },
+    Help,
+}
+
+/// 해석된 명령행
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invocation {
+    pub command: Command,
+    pub config_path: Option<PathBuf>,
+    pub json: bool,
+    pub totp: Option<String>,
+}
+
+/// 인자 목록 해석 (프로그램 이름 제외)
+pub fn parse<I, S>(args: I) -> Result<Invocation, String>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut config_path = None;
+    let mut json = false;
+    let mut totp = None;
+    let mut options: Vec<(String, String)> = Vec::new();
+    let mut positional: Vec<String> = Vec::new();
+
+    let mut args = args.into_iter().map(Into::into);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => positional.insert(0, "help".to_string()),
+            "--json" => json = true,
+            _ if arg.starts_with("--") => {
+                let name = arg.trim_start_matches("--").to_string();
+                let value = args.next().ok_or_else(|| format!("--{} 옵션에 값이 필요합니다", name))?;
+                match name.as_str() {
+                    "config" => config_path = Some(PathBuf::from(value)),
+                    "totp" => totp = Some(value),
+                    _ => options.push((name, value)),
+                }
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let name = positional.next().unwrap_or_else(|| "help".to_string());
+    let mut arg = |what: &str| positional.next().ok_or_else(|| format!("{}: {}이(가) 필요합니다", name, what));
+
+    let command = match name.as_str() {
+        "help" => Command::Help,
+        "symbols" => Command::Symbols,
+        "halt" => Command::Halt { symbol: arg("심볼")?, reason: take(&mut options, "reason") },
+        "resume" => Command::Resume { symbol: arg("심볼")? },
+        "orderbook" => Command::OrderBook {
+            symbol: arg("심볼")?,
+            depth: take_number(&mut options, "depth")?.unwrap_or(10) as usize,
+        },
+        "cancel-orders" => Command::CancelOrders { client_id: arg("계정")? },
+        "recover" => Command::Recover {
+            mq_type: take(&mut options, "mq").map(|mq| mq_type_name(&mq)).transpose()?,
+            topic: take(&mut options, "topic"),
+            symbol: take(&mut options, "symbol"),
+            within_secs: take_number(&mut options, "within")?,
+        },
+        "recovery-jobs" => Command::RecoveryJobs { job_id: arg("작업ID").ok() },
+        "backup" => Command::Backup,
+        "backups" => Command::Backups,
+        "health" => Command::Health,
+        "tail" => Command::Tail { symbol: arg("심볼").ok() },
+        other => return Err(format!("알 수 없는 명령: {}", other)),
+    };
+
+    if let Some((name, _)) = options.first() {
+        return Err(format!("{} 명령에 쓸 수 없는 옵션: --{}", command_name(&command), name));
+    }
+
+    Ok(Invocation { command, config_path, json, totp })
+}
+
+fn take(options: &mut Vec<(String, String)>, name: &str) -> Option<String> {
+    let index = options.iter().position(|(key, _)| key == name)?;
+    Some(options.remove(index).1)
+}
+
+fn take_number(options: &mut Vec<(String, String)>, name: &str) -> Result<Option<u64>, String> {
+    take(options, name)
+        .map(|value| value.parse::<u64>().map_err(|_| format!("--{} 값은 0 이상의 정수여야 합니다: {}", name, value)))
+        .transpose()
+}
+
+/// 복구 API의 MQ 종류 이름 (`MQType` 직렬화 값)
+fn mq_type_name(mq: &str) -> Result<String, String> {
+    match mq.to_ascii_lowercase().as_str() {
+        "kafka" => Ok("Kafka".to_string()),
+        "rabbitmq" => Ok("RabbitMQ".to_string()),
+        "redis" | "redis-streams" => Ok("RedisStreams".to_string()),
+        _ => Err(format!("--mq 값은 kafka, rabbitmq, redis 중 하나여야 합니다: {}", mq)),
+    }
+}
+
+fn command_name(command: &Command) -> &'static str {
+    match command {
+        Command::Symbols => "symbols",
+        Command::Halt { .. } => "halt",
+        Command::Resume { .. } => "resume",
+        Command::OrderBook { .. } => "orderbook",
+        Command::CancelOrders { .. } => "cancel-orders",
+        Command::Recover { .. } => "recover",
+        Command::RecoveryJobs { .. } => "recovery-jobs",
+        Command::Backup => "backup",
+        Command::Backups => "backups",
+        Command::Health => "health",
+        Command::Tail { .. } => "tail",
+        Command::Help => "help",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands_and_global_options() {
+        let invocation = parse(["--json", "halt", "BTC-KRW", "--reason", "점검", "--totp", "123456"]).unwrap();
+        assert_eq!(
+            invocation.command,
+            Command::Halt { symbol: "BTC-KRW".to_string(), reason: Some("점검".to_string()) }
+        );
+        assert!(invocation.json);
+        assert_eq!(invocation.totp.as_deref(), Some("123456"));
+
+        let invocation = parse(["--config", "/etc/xtraderctl.toml", "orderbook", "ETH-KRW", "--depth", "5"]).unwrap();
+        assert_eq!(invocation.command, Command::OrderBook { symbol: "ETH-KRW".to_string(), depth: 5 });
+        assert_eq!(invocation.config_path, Some(PathBuf::from("/etc/xtraderctl.toml")));
+
+        assert_eq!(
+            parse(["recover", "--mq", "redis", "--within", "600"]).unwrap().command,
+            Command::Recover {
+                mq_type: Some("RedisStreams".to_string()),
+                topic: None,
+                symbol: None,
+                within_secs: Some(600),
+            }
+        );
+        assert_eq!(parse(["tail"]).unwrap().command, Command::Tail { symbol: None });
+        assert_eq!(parse(["backups", "--json"]).unwrap().command, Command::Backups);
+        assert_eq!(parse(Vec::<String>::new()).unwrap().command, Command::Help);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_input() {
+        assert!(parse(["halt"]).is_err());
+        assert!(parse(["frobnicate"]).is_err());
+        assert!(parse(["orderbook", "BTC-KRW", "--depth", "many"]).is_err());
+        assert!(parse(["recover", "--mq", "sqs"]).is_err());
+        // 명령에 맞지 않는 옵션
+        assert!(parse(["symbols", "--reason", "x"]).is_err());
+        assert!(parse(["halt", "BTC-KRW", "--reason"]).is_err());
+    }
+}
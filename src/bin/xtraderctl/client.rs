@@ -0,0 +1,92 @@
+//! 관리자 REST/WebSocket 클라이언트
+
+use futures_util::StreamExt;
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::config::CtlConfig;
+use crate::CtlError;
+
+/// xTrader 서버 클라이언트 (관리자 헤더를 요청마다 붙임)
+pub struct AdminClient {
+    http: reqwest::Client,
+    config: CtlConfig,
+    totp: Option<String>,
+}
+
+impl AdminClient {
+    pub fn new(config: CtlConfig, totp: Option<String>) -> Result<Self, CtlError> {
+        let http = reqwest::Client::builder().timeout(config.timeout()).build()?;
+        Ok(Self { http, config, totp })
+    }
+
+    /// 요청을 보내고 상태 코드와 JSON 본문 반환 (오류 응답도 그대로 반환)
+    pub async fn request_raw(&self, method: Method, path: &str, body: Option<Value>) -> Result<(StatusCode, Value), CtlError> {
+        let mut request = self.http.request(method, format!("{}{}", self.config.api_base(), path));
+        if let Some(token) = &self.config.admin_token {
+            request = request.header("X-Admin-Token", token);
+        }
+        if let Some(user) = &self.config.admin_user {
+            request = request.header("X-Admin-User", user);
+        }
+        if let Some(code) = &self.totp {
+            request = request.header("X-Admin-TOTP", code);
+        }
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+        let body = serde_json::from_str(&text).unwrap_or(Value::String(text));
+        Ok((status, body))
+    }
+
+    /// 요청을 보내고 성공 응답 본문 반환 (4xx/5xx는 `CtlError::Api`)
+    pub async fn request(&self, method: Method, path: &str, body: Option<Value>) -> Result<Value, CtlError> {
+        let (status, body) = self.request_raw(method, path, body).await?;
+        if status.is_success() {
+            return Ok(body);
+        }
+        let field = |name: &str| body.get(name).and_then(Value::as_str).map(str::to_string);
+        Err(CtlError::Api {
+            status: status.as_u16(),
+            code: field("error").unwrap_or_else(|| status.canonical_reason().unwrap_or("HTTP_ERROR").to_string()),
+            message: field("message").unwrap_or_else(|| body.to_string()),
+        })
+    }
+
+    pub async fn get(&self, path: &str) -> Result<Value, CtlError> {
+        self.request(Method::GET, path, None).await
+    }
+
+    /// 체결 WebSocket을 구독해 체결 보고서마다 `on_execution` 호출 (연결이 끊기면 반환)
+    pub async fn tail_executions(&self, symbol: Option<&str>, mut on_execution: impl FnMut(&Value)) -> Result<(), CtlError> {
+        let url = self.config.ws_endpoint();
+        let (mut stream, _) = tokio_tungstenite::connect_async(url.as_str())
+            .await
+            .map_err(|e| CtlError::WebSocket(format!("{}: {}", url, e)))?;
+
+        while let Some(message) = stream.next().await {
+            let text = match message.map_err(|e| CtlError::WebSocket(e.to_string()))? {
+                Message::Text(text) => text,
+                Message::Close(_) => break,
+                _ => continue,
+            };
+            let Ok(message) = serde_json::from_str::<Value>(&text) else {
+                continue;
+            };
+            if message.get("type").and_then(Value::as_str) != Some("Execution") {
+                continue;
+            }
+            let report = &message["execution_report"];
+            if symbol.is_some_and(|symbol| report.get("symbol").and_then(Value::as_str) != Some(symbol)) {
+                continue;
+            }
+            on_execution(report);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+//! 접속 설정 파일
+//!
+//! ```toml
+//! api_url = "https://xtrader.internal:7000"
+//! admin_token = "..."
+//! admin_user = "ops-kim"      # 감사 로그에 남는 관리자 이름 (기본 admin)
+//! # ws_url = "wss://xtrader.internal:7000/ws"   # 생략하면 api_url에서 유도
+//! # timeout_secs = 10
+//! ```
+//!
+//! 관리자 토큰은 파일에 두는 것이 기본이며 `XTRADER_ADMIN_TOKEN`, 서버 주소는 `XTRADER_API_URL`
+//! 환경 변수로 덮어쓸 수 있습니다 (서버와 같은 변수 이름).
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::CtlError;
+
+/// 설정 파일 경로 환경 변수
+pub const CONFIG_ENV: &str = "XTRADERCTL_CONFIG";
+
+/// 접속 설정
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CtlConfig {
+    #[serde(default = "default_api_url")]
+    pub api_url: String,
+    #[serde(default)]
+    pub ws_url: Option<String>,
+    #[serde(default)]
+    pub admin_token: Option<String>,
+    #[serde(default)]
+    pub admin_user: Option<String>,
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_api_url() -> String {
+    "http://localhost:7000".to_string()
+}
+
+fn default_timeout_secs() -> u64 {
+    10
+}
+
+impl Default for CtlConfig {
+    fn default() -> Self {
+        Self {
+            api_url: default_api_url(),
+            ws_url: None,
+            admin_token: None,
+            admin_user: None,
+            timeout_secs: default_timeout_secs(),
+        }
+    }
+}
+
+impl CtlConfig {
+    /// 설정 파일을 읽고 환경 변수로 덮어씀
+    ///
+    /// 경로를 지정하지 않았고 기본 경로에 파일이 없으면 기본값을 씁니다.
+    pub fn load(path: Option<&Path>) -> Result<Self, CtlError> {
+        let explicit = path.map(Path::to_path_buf).or_else(|| std::env::var_os(CONFIG_ENV).map(PathBuf::from));
+        let mut config = match (&explicit, default_path()) {
+            (Some(path), _) => Self::read(path)?,
+            (None, Some(path)) if path.exists() => Self::read(&path)?,
+            _ => Self::default(),
+        };
+
+        if let Ok(api_url) = std::env::var("XTRADER_API_URL") {
+            config.api_url = api_url;
+        }
+        if let Ok(token) = std::env::var("XTRADER_ADMIN_TOKEN") {
+            config.admin_token = Some(token);
+        }
+        Ok(config)
+    }
+
+    fn read(path: &Path) -> Result<Self, CtlError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| CtlError::Config(format!("{}: {}", path.display(), e)))?;
+        Self::parse(&text).map_err(|e| CtlError::Config(format!("{}: {}", path.display(), e)))
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let config: Self = toml::from_str(text).map_err(|e| e.to_string())?;
+        if !(config.api_url.starts_with("http://") || config.api_url.starts_with("https://")) {
+            return Err(format!("api_url은 http:// 또는 https://로 시작해야 합니다: {}", config.api_url));
+        }
+        Ok(config)
+    }
+
+    /// REST API 주소 (끝의 `/` 제외)
+    pub fn api_base(&self) -> &str {
+        self.api_url.trim_end_matches('/')
+    }
+
+    /// 체결 WebSocket 주소 (`ws_url`이 없으면 `api_url`의 스킴만 바꿔 `/ws`)
+    pub fn ws_endpoint(&self) -> String {
+        match &self.ws_url {
+            Some(ws_url) => ws_url.clone(),
+            None => {
+                let base = self.api_base();
+                let base = base
+                    .strip_prefix("https://")
+                    .map(|rest| format!("wss://{}", rest))
+                    .or_else(|| base.strip_prefix("http://").map(|rest| format!("ws://{}", rest)))
+                    .unwrap_or_else(|| base.to_string());
+                format!("{}/ws", base)
+            }
+        }
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs.max(1))
+    }
+}
+
+/// 기본 설정 파일 경로 (`~/.config/xtrader/xtraderctl.toml`)
+fn default_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME")?;
+    Some(PathBuf::from(home).join(".config").join("xtrader").join("xtraderctl.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config_and_derive_ws_url() {
+        let config = CtlConfig::parse(
+            r#"
+            api_url = "https://xtrader.internal:7000/"
+            admin_token = "secret"
+            admin_user = "ops-kim"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.api_base(), "https://xtrader.internal:7000");
+        assert_eq!(config.ws_endpoint(), "wss://xtrader.internal:7000/ws");
+        assert_eq!((config.admin_token.as_deref(), config.timeout_secs), (Some("secret"), 10));
+
+        assert_eq!(CtlConfig::default().ws_endpoint(), "ws://localhost:7000/ws");
+        assert!(CtlConfig::parse("api_url = \"xtrader:7000\"").is_err());
+        // 오타 난 키는 조용히 무시하지 않음
+        assert!(CtlConfig::parse("admin_tokn = \"secret\"").is_err());
+    }
+}
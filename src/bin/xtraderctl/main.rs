@@ -0,0 +1,123 @@
+//! xtraderctl - xTrader 운영 CLI
+//!
+//! 관리자 REST API(`/v1/admin/*`)와 체결 WebSocket으로 자주 쓰는 운영 작업(심볼 목록/거래 중단,
+//! 호가창 조회, 계정 주문 일괄 취소, MQ 부분 복구, DB 백업, 상태 점검, 체결 실시간 출력)을 수행합니다.
+//! 서버 주소와 관리자 토큰은 설정 파일(`config.rs`)에서 읽습니다.
+
+mod cli;
+mod client;
+mod config;
+mod output;
+
+use std::process::ExitCode;
+
+use reqwest::Method;
+use serde_json::{json, Value};
+
+use cli::{Command, Invocation, USAGE};
+use client::AdminClient;
+use config::CtlConfig;
+
+/// CLI 오류
+#[derive(Debug, thiserror::Error)]
+pub enum CtlError {
+    #[error("설정 오류: {0}")]
+    Config(String),
+    #[error("요청 실패: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("{code} ({status}): {message}")]
+    Api { status: u16, code: String, message: String },
+    #[error("WebSocket 오류: {0}")]
+    WebSocket(String),
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let invocation = match cli::parse(std::env::args().skip(1)) {
+        Ok(invocation) => invocation,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    if invocation.command == Command::Help {
+        print!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    match run(invocation).await {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("오류: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(invocation: Invocation) -> Result<ExitCode, CtlError> {
+    let config = CtlConfig::load(invocation.config_path.as_deref())?;
+    let client = AdminClient::new(config, invocation.totp)?;
+    let print = |value: &Value, human: fn(&Value) -> String| {
+        if invocation.json {
+            println!("{}", serde_json::to_string_pretty(value).unwrap_or_default());
+        } else {
+            print!("{}", human(value));
+        }
+    };
+
+    match invocation.command {
+        Command::Symbols => print(&client.get("/v1/admin/symbols").await?, output::symbols),
+        Command::Halt { symbol, reason } => {
+            let body = json!({ "reason": reason });
+            let response = client.request(Method::PUT, &format!("/v1/admin/kill-switch/symbols/{}", symbol), Some(body)).await?;
+            print(&response, output::kill_switch);
+        }
+        Command::Resume { symbol } => {
+            let response = client.request(Method::DELETE, &format!("/v1/admin/kill-switch/symbols/{}", symbol), None).await?;
+            print(&response, output::kill_switch);
+        }
+        Command::OrderBook { symbol, depth } => {
+            let response = client.get(&format!("/api/v1/orderbook/{}?depth={}", symbol, depth)).await?;
+            print(&response["orderbook"], output::order_book);
+        }
+        Command::CancelOrders { client_id } => {
+            let path = format!("/v1/admin/clients/{}/cancel-orders", client_id);
+            print(&client.request(Method::POST, &path, None).await?, output::canceled_orders);
+        }
+        Command::Recover { mq_type, topic, symbol, within_secs } => {
+            let body = json!({ "mq_type": mq_type, "topic_stream": topic, "symbol": symbol, "within_secs": within_secs });
+            print(&client.request(Method::POST, "/v1/admin/recovery/jobs", Some(body)).await?, output::recovery_job);
+        }
+        Command::RecoveryJobs { job_id: Some(job_id) } => {
+            print(&client.get(&format!("/v1/admin/recovery/jobs/{}", job_id)).await?, output::recovery_job);
+        }
+        Command::RecoveryJobs { job_id: None } => {
+            print(&client.get("/v1/admin/recovery/jobs").await?, output::recovery_jobs);
+        }
+        Command::Backup => print(&client.request(Method::POST, "/v1/admin/db/backups", None).await?, output::backup),
+        Command::Backups => print(&client.get("/v1/admin/db/backups").await?, output::backups),
+        Command::Health => {
+            // 준비되지 않았으면 /readyz가 503과 함께 점검 결과를 주므로 본문을 그대로 출력
+            let (_, liveness) = client.request_raw(Method::GET, "/healthz", None).await?;
+            let (status, readiness) = client.request_raw(Method::GET, "/readyz", None).await?;
+            print(&json!({ "liveness": liveness, "readiness": readiness }), output::health);
+            if !status.is_success() {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Tail { symbol } => {
+            let json_output = invocation.json;
+            client
+                .tail_executions(symbol.as_deref(), |report| {
+                    if json_output {
+                        println!("{}", report);
+                    } else {
+                        print!("{}", output::execution(report));
+                    }
+                })
+                .await?;
+        }
+        Command::Help => print!("{}", USAGE),
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,168 @@
+//! 사람이 읽는 출력 형식 (`--json`이 아닐 때)
+
+use std::fmt::Write;
+
+use serde_json::Value;
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn items<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value.get(key).and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default()
+}
+
+/// `symbols`
+pub fn symbols(response: &Value) -> String {
+    let mut out = format!("{:<12} {:<8} {:>8} {:>14} {:>14}\n", "SYMBOL", "STATUS", "ORDERS", "BEST_BID", "BEST_ASK");
+    for symbol in items(response, "symbols") {
+        let status = if symbol["halted"].as_bool() == Some(true) { "HALTED" } else { "TRADING" };
+        let _ = writeln!(
+            out,
+            "{:<12} {:<8} {:>8} {:>14} {:>14}",
+            text(&symbol["symbol"]),
+            status,
+            text(&symbol["open_orders"]),
+            text(&symbol["best_bid"]),
+            text(&symbol["best_ask"])
+        );
+    }
+    out
+}
+
+/// `halt`, `resume`
+pub fn kill_switch(response: &Value) -> String {
+    let entry = &response["kill_switch"];
+    format!(
+        "{} {} (사유: {}, 발동자: {})\n",
+        text(&entry["scope"]),
+        text(&entry["target"]),
+        text(&entry["reason"]),
+        text(&entry["activated_by"])
+    )
+}
+
+/// `orderbook` (매도 호가는 높은 가격부터, 매수 호가는 높은 가격부터)
+pub fn order_book(snapshot: &Value) -> String {
+    let level = |level: &Value| (text(&level[0]), text(&level[1]));
+    let mut out = format!("{}\n{:>14} {:>14}\n", text(&snapshot["symbol"]), "PRICE", "QUANTITY");
+    for (price, quantity) in items(snapshot, "asks").iter().rev().map(level) {
+        let _ = writeln!(out, "{:>14} {:>14}  ask", price, quantity);
+    }
+    out.push_str(&format!("{:-^30}\n", ""));
+    for (price, quantity) in items(snapshot, "bids").iter().map(level) {
+        let _ = writeln!(out, "{:>14} {:>14}  bid", price, quantity);
+    }
+    out
+}
+
+/// `cancel-orders`
+pub fn canceled_orders(response: &Value) -> String {
+    let ids = items(response, "canceled_order_ids");
+    let mut out = format!("{}: 미체결 주문 {}건 취소 요청\n", text(&response["client_id"]), ids.len());
+    for id in ids {
+        let _ = writeln!(out, "  {}", text(id));
+    }
+    out
+}
+
+/// `recover`, `recovery-jobs <작업ID>`
+pub fn recovery_job(job: &Value) -> String {
+    format!(
+        "{} {} {:.0}% (재발행 {}, 실패 {} / 대상 {}, 남은 시간 {}초){}\n",
+        text(&job["id"]),
+        text(&job["state"]),
+        job["progress"].as_f64().unwrap_or(0.0) * 100.0,
+        text(&job["recovered"]),
+        text(&job["failed"]),
+        text(&job["total"]),
+        text(&job["eta_secs"]),
+        job["last_error"].as_str().map(|e| format!(" - {}", e)).unwrap_or_default()
+    )
+}
+
+/// `recovery-jobs`
+pub fn recovery_jobs(response: &Value) -> String {
+    let jobs = items(response, "jobs");
+    if jobs.is_empty() {
+        return "복구 작업 없음\n".to_string();
+    }
+    jobs.iter().map(recovery_job).collect()
+}
+
+/// `backup`
+pub fn backup(metadata: &Value) -> String {
+    format!(
+        "{} (저널 순번 {}, {} bytes, sha256 {})\n",
+        text(&metadata["file"]),
+        text(&metadata["journal_seq"]),
+        text(&metadata["size_bytes"]),
+        text(&metadata["sha256"])
+    )
+}
+
+/// `backups`
+pub fn backups(response: &Value) -> String {
+    let backups = items(response, "backups");
+    if backups.is_empty() {
+        return format!("백업 없음 ({})\n", text(&response["dir"]));
+    }
+    let mut out = format!("{}\n", text(&response["dir"]));
+    for metadata in backups {
+        out.push_str(&format!("  {}", backup(metadata)));
+    }
+    out
+}
+
+/// `health`
+pub fn health(response: &Value) -> String {
+    let readiness = &response["readiness"];
+    let mut out = format!(
+        "liveness: {}\nreadiness: {}\n",
+        text(&response["liveness"]["status"]),
+        if readiness["ready"].as_bool() == Some(true) { "ready" } else { "NOT READY" }
+    );
+    for check in items(readiness, "checks") {
+        let mark = if check["ok"].as_bool() == Some(true) { "ok  " } else { "FAIL" };
+        let _ = writeln!(out, "  [{}] {:<16} {}", mark, text(&check["name"]), text(&check["detail"]));
+    }
+    out
+}
+
+/// `tail` 체결 한 줄
+pub fn execution(report: &Value) -> String {
+    format!(
+        "{} {:<10} {:<8} {:<4} {:>12} x {:<10} {} ({}, 잔량 {})\n",
+        text(&report["timestamp"]),
+        text(&report["symbol"]),
+        text(&report["exec_type"]),
+        text(&report["side"]),
+        text(&report["price"]),
+        text(&report["quantity"]),
+        text(&report["order_id"]),
+        if report["is_maker"].as_bool() == Some(true) { "maker" } else { "taker" },
+        text(&report["remaining_quantity"])
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_order_book_and_symbols_layout() {
+        let snapshot = json!({ "symbol": "BTC-KRW", "bids": [[99, 3], [98, 1]], "asks": [[101, 2], [102, 5]] });
+        let lines: Vec<String> = order_book(&snapshot).lines().map(|line| line.split_whitespace().collect::<Vec<_>>().join(" ")).collect();
+        assert_eq!(lines[2..4], ["102 5 ask".to_string(), "101 2 ask".to_string()]);
+        assert_eq!(lines[5..], ["99 3 bid".to_string(), "98 1 bid".to_string()]);
+
+        let response = json!({ "symbols": [{ "symbol": "ETH-KRW", "halted": true, "open_orders": 4, "best_bid": null, "best_ask": 3000 }] });
+        let row: Vec<String> = symbols(&response).lines().nth(1).unwrap().split_whitespace().map(str::to_string).collect();
+        assert_eq!(row, ["ETH-KRW", "HALTED", "4", "-", "3000"]);
+    }
+}
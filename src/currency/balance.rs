@@ -0,0 +1,124 @@
+//! 자산별 잔고와 미체결 주문 예약 금액
+//!
+//! 계정 잔고는 자산 코드별 주문 가능 금액(`available`)과 묶인 금액(`locked`)이며,
+//! DB `balances` 테이블의 (client_id, asset) 행 하나가 자산 하나에 대응합니다.
+//! 주문장에 남은 지정가 주문은 매수면 호가 자산 `가격 × 남은 수량`, 매도면 기초 자산
+//! `남은 수량`만큼을 예약합니다.
+
+use std::collections::BTreeMap;
+
+use crate::currency::registry::AssetRegistry;
+use crate::currency::CurrencyError;
+use crate::db::models::BalanceRecord;
+use crate::matching_engine::model::{Order, Side};
+
+/// 자산 하나의 잔고 (자산 최소 단위)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AssetBalance {
+    /// 주문 가능 잔고
+    pub available: i64,
+    /// 주문에 묶인 잔고
+    pub locked: i64,
+}
+
+/// 사용자 잔고 정보 (자산 코드 → 잔고)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserBalance {
+    assets: BTreeMap<String, AssetBalance>,
+}
+
+impl UserBalance {
+    /// DB 잔고 행으로 구성
+    pub fn from_records(records: Vec<BalanceRecord>) -> Self {
+        let assets = records
+            .into_iter()
+            .map(|record| (record.asset, AssetBalance { available: record.available, locked: record.locked }))
+            .collect();
+        Self { assets }
+    }
+
+    /// 자산별 잔고 (자산 코드순)
+    pub fn assets(&self) -> &BTreeMap<String, AssetBalance> {
+        &self.assets
+    }
+
+    /// 자산 잔고 (보유하지 않은 자산은 0)
+    pub fn get(&self, asset: &str) -> AssetBalance {
+        self.assets.get(asset).copied().unwrap_or_default()
+    }
+}
+
+/// 미체결 주문 하나가 예약한 금액
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderReservation {
+    pub order_id: String,
+    pub symbol: String,
+    pub side: Side,
+    pub price: u64,
+    pub remaining_quantity: u64,
+    /// 예약 자산 (매수는 호가 자산, 매도는 기초 자산)
+    pub asset: String,
+    /// 예약 금액 (예약 자산 최소 단위)
+    pub amount: u64,
+}
+
+impl OrderReservation {
+    /// 주문장에 남은 주문의 예약 금액
+    pub fn for_order(registry: &AssetRegistry, order: &Order) -> Result<Self, CurrencyError> {
+        let pair = registry.pair(&order.symbol)?;
+        let (asset, amount) = match order.side {
+            Side::Buy => (pair.quote, order.price.saturating_mul(order.remaining_quantity)),
+            Side::Sell => (pair.base, order.remaining_quantity),
+        };
+        Ok(Self {
+            order_id: order.id.clone(),
+            symbol: order.symbol.clone(),
+            side: order.side.clone(),
+            price: order.price,
+            remaining_quantity: order.remaining_quantity,
+            asset,
+            amount,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching_engine::model::OrderType;
+
+    fn record(asset: &str, available: i64, locked: i64) -> BalanceRecord {
+        BalanceRecord {
+            client_id: "trader_01".to_string(),
+            asset: asset.to_string(),
+            available,
+            locked,
+        }
+    }
+
+    #[test]
+    fn test_balance_is_keyed_by_asset() {
+        let balance = UserBalance::from_records(vec![record("KRW", 1_000_000, 50_000), record("BTC", 150_000_000, 0)]);
+
+        let codes: Vec<&String> = balance.assets().keys().collect();
+        assert_eq!(codes, vec!["BTC", "KRW"]);
+        assert_eq!(balance.get("KRW"), AssetBalance { available: 1_000_000, locked: 50_000 });
+        assert_eq!(balance.get("ETH"), AssetBalance::default());
+    }
+
+    #[test]
+    fn test_reservation_uses_quote_for_buys_and_base_for_sells() {
+        let registry = AssetRegistry::default();
+        let mut buy = Order::new("b1".to_string(), "ETH-BTC".to_string(), Side::Buy, OrderType::Limit, 5_000_000, 3, "trader_01".to_string());
+        buy.remaining_quantity = 2;
+        let sell = Order::new("s1".to_string(), "BTC-KRW".to_string(), Side::Sell, OrderType::Limit, 98_000_000, 7, "trader_01".to_string());
+
+        let buy = OrderReservation::for_order(&registry, &buy).unwrap();
+        assert_eq!((buy.asset.as_str(), buy.amount), ("BTC", 10_000_000));
+        let sell = OrderReservation::for_order(&registry, &sell).unwrap();
+        assert_eq!((sell.asset.as_str(), sell.amount), ("BTC", 7));
+
+        let unknown = Order::new("u1".to_string(), "DOGE-KRW".to_string(), Side::Sell, OrderType::Limit, 100, 1, "trader_01".to_string());
+        assert!(OrderReservation::for_order(&registry, &unknown).is_err());
+    }
+}
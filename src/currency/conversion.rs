@@ -0,0 +1,223 @@
+//! 통화 환산 서비스
+//!
+//! MDP 티커의 최근 체결가로 자산 간 환율을 구합니다. 직접 시장(`BTC-USDT`)이나 역방향 시장이
+//! 없으면 중개 자산 하나를 거쳐(USDT → BTC → KRW) 환산하고, 거래소에 시장이 없는 법정화폐
+//! 쌍(USD/KRW)은 설정의 고정 환율을 씁니다. 환율은 항상 기본 단위 기준(1 BTC = 98,000,000 KRW)입니다.
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::RwLock;
+
+use crate::api::models::TickerData;
+use crate::currency::registry::{Asset, AssetRegistry, CurrencyError};
+
+/// 환산 경로 최대 단계 (중개 자산 1개)
+const MAX_HOPS: usize = 2;
+
+/// 고정 환율 (1 `from` = `rate` `to`, 역방향도 사용)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixedRate {
+    pub from: String,
+    pub to: String,
+    pub rate: f64,
+}
+
+impl FixedRate {
+    /// `USD/KRW=1350,EUR/KRW=1450` 형식 파싱
+    pub fn parse_list(spec: &str) -> Result<Vec<Self>, String> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let parsed = entry.split_once('=').and_then(|(pair, rate)| {
+                    let (from, to) = pair.trim().split_once('/')?;
+                    let rate = rate.trim().parse::<f64>().ok().filter(|r| r.is_finite() && *r > 0.0)?;
+                    Some(Self { from: from.trim().to_string(), to: to.trim().to_string(), rate })
+                });
+                parsed.ok_or_else(|| format!("고정 환율 형식 오류: {} (예: USD/KRW=1350)", entry))
+            })
+            .collect()
+    }
+}
+
+/// 통화 설정
+#[derive(Debug, Clone, Default)]
+pub struct CurrencyConfig {
+    pub registry: AssetRegistry,
+    /// 거래소에 시장이 없는 통화 쌍의 고정 환율
+    pub fixed_rates: Vec<FixedRate>,
+}
+
+/// 통화 환산 서비스
+pub struct CurrencyConverter {
+    registry: AssetRegistry,
+    fixed_rates: Vec<FixedRate>,
+    /// 심볼별 최근 체결가 (호가 자산 최소 단위)
+    last_prices: RwLock<BTreeMap<String, u64>>,
+}
+
+impl CurrencyConverter {
+    pub fn new(config: CurrencyConfig) -> Self {
+        Self {
+            registry: config.registry,
+            fixed_rates: config.fixed_rates,
+            last_prices: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn registry(&self) -> &AssetRegistry {
+        &self.registry
+    }
+
+    pub fn reporting_currency(&self) -> &str {
+        self.registry.reporting_currency()
+    }
+
+    /// 심볼 최근 체결가 갱신
+    pub fn update_price(&self, symbol: &str, price: u64) {
+        if price > 0 {
+            self.last_prices.write().unwrap().insert(symbol.to_string(), price);
+        }
+    }
+
+    /// MDP 티커로 최근 체결가 갱신
+    pub fn update_from_tickers(&self, tickers: &[TickerData]) {
+        let mut last_prices = self.last_prices.write().unwrap();
+        for ticker in tickers {
+            if let Some(price) = ticker.last_price.filter(|&price| price > 0) {
+                last_prices.insert(ticker.symbol.clone(), price);
+            }
+        }
+    }
+
+    /// 자산별 환율 간선 (시장 체결가 → 고정 환율 순)
+    fn rate_edges(&self) -> HashMap<String, Vec<(String, f64)>> {
+        let mut edges: HashMap<String, Vec<(String, f64)>> = HashMap::new();
+        let mut add = |from: &str, to: &str, rate: f64| {
+            edges.entry(from.to_string()).or_default().push((to.to_string(), rate));
+            edges.entry(to.to_string()).or_default().push((from.to_string(), 1.0 / rate));
+        };
+
+        for (symbol, &price) in self.last_prices.read().unwrap().iter() {
+            let Ok(pair) = self.registry.pair(symbol) else { continue };
+            let quote = self.registry.asset(&pair.quote).expect("등록된 호가 자산");
+            add(&pair.base, &pair.quote, quote.to_major(price as f64));
+        }
+        for fixed in &self.fixed_rates {
+            add(&fixed.from, &fixed.to, fixed.rate);
+        }
+        edges
+    }
+
+    /// 1 `from` 의 `to` 가격 (기본 단위 기준)
+    pub fn rate(&self, from: &str, to: &str) -> Result<f64, CurrencyError> {
+        for code in [from, to] {
+            if self.registry.asset(code).is_none() {
+                return Err(CurrencyError::UnknownAsset(code.to_string()));
+            }
+        }
+        if from == to {
+            return Ok(1.0);
+        }
+
+        // 단계 수가 가장 적은 경로 (같으면 시장 체결가 우선)
+        let edges = self.rate_edges();
+        let mut visited = HashSet::from([from.to_string()]);
+        let mut queue = VecDeque::from([(from.to_string(), 1.0, 0)]);
+        while let Some((asset, rate, hops)) = queue.pop_front() {
+            if hops == MAX_HOPS {
+                continue;
+            }
+            for (next, edge_rate) in edges.get(&asset).into_iter().flatten() {
+                let next_rate = rate * edge_rate;
+                if next == to {
+                    return Ok(next_rate);
+                }
+                if visited.insert(next.clone()) {
+                    queue.push_back((next.clone(), next_rate, hops + 1));
+                }
+            }
+        }
+        Err(CurrencyError::RateUnavailable { from: from.to_string(), to: to.to_string() })
+    }
+
+    /// 기본 단위 금액 환산
+    pub fn convert(&self, amount: f64, from: &str, to: &str) -> Result<f64, CurrencyError> {
+        if amount == 0.0 {
+            return Ok(0.0);
+        }
+        Ok(amount * self.rate(from, to)?)
+    }
+
+    /// 최소 단위 금액을 보고 통화 최소 단위로 환산
+    pub fn minor_to_reporting(&self, amount: f64, asset: &Asset) -> Result<f64, CurrencyError> {
+        let reporting = self.registry.asset(self.reporting_currency()).expect("등록된 보고 통화");
+        let major = self.convert(asset.to_major(amount), &asset.code, &reporting.code)?;
+        Ok(reporting.to_minor(major))
+    }
+
+    /// 심볼 주문 금액(가격 × 수량, 호가 자산 최소 단위)을 보고 통화 최소 단위로 환산
+    pub fn notional_to_reporting(&self, symbol: &str, notional: u64) -> Result<u64, CurrencyError> {
+        let quote = self.registry.quote_asset(symbol)?;
+        Ok(self.minor_to_reporting(notional as f64, quote)?.round() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn converter(fixed_rates: Vec<FixedRate>) -> CurrencyConverter {
+        CurrencyConverter::new(CurrencyConfig { registry: AssetRegistry::default(), fixed_rates })
+    }
+
+    #[test]
+    fn test_direct_inverse_and_cross_rates() {
+        let converter = converter(vec![]);
+        converter.update_price("BTC-KRW", 100_000_000);
+        converter.update_price("BTC-USDT", 7_000_000); // 70,000.00 USDT
+        converter.update_price("ETH-BTC", 5_000_000); // 0.05 BTC
+
+        assert_eq!(converter.rate("BTC", "KRW").unwrap(), 100_000_000.0);
+        assert_eq!(converter.rate("BTC", "USDT").unwrap(), 70_000.0);
+        assert_eq!(converter.rate("ETH", "BTC").unwrap(), 0.05);
+        // ETH → BTC → KRW
+        assert_eq!(converter.rate("ETH", "KRW").unwrap(), 5_000_000.0);
+        // USDT → BTC → KRW
+        let usdt_krw = converter.rate("USDT", "KRW").unwrap();
+        assert!((usdt_krw - 100_000_000.0 / 70_000.0).abs() < 1e-6);
+
+        // 1,000.00 USDT (최소 단위 100,000) → 약 1,428,571원
+        assert_eq!(converter.notional_to_reporting("BTC-USDT", 100_000).unwrap(), 1_428_571);
+        assert_eq!(converter.notional_to_reporting("BTC-KRW", 5_000).unwrap(), 5_000);
+        assert_eq!(converter.notional_to_reporting("BTC-USDT", 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_fixed_rates_and_unavailable() {
+        let without_rates = converter(vec![]);
+        assert_eq!(
+            without_rates.rate("USD", "KRW"),
+            Err(CurrencyError::RateUnavailable { from: "USD".to_string(), to: "KRW".to_string() })
+        );
+        assert!(matches!(without_rates.rate("EUR", "KRW"), Err(CurrencyError::UnknownAsset(_))));
+
+        let converter = converter(FixedRate::parse_list("USD/KRW=1350").unwrap());
+        assert_eq!(converter.rate("USD", "KRW").unwrap(), 1350.0);
+        assert_eq!(converter.rate("KRW", "USD").unwrap(), 1.0 / 1350.0);
+        // 10.00 USD → 13,500원
+        assert_eq!(converter.minor_to_reporting(1_000.0, converter.registry().asset("USD").unwrap()).unwrap(), 13_500.0);
+    }
+
+    #[test]
+    fn test_parse_fixed_rates() {
+        assert_eq!(
+            FixedRate::parse_list(" USD/KRW=1350 , EUR/USD=1.08").unwrap(),
+            vec![
+                FixedRate { from: "USD".to_string(), to: "KRW".to_string(), rate: 1350.0 },
+                FixedRate { from: "EUR".to_string(), to: "USD".to_string(), rate: 1.08 },
+            ]
+        );
+        assert!(FixedRate::parse_list("USD-KRW=1350").is_err());
+        assert!(FixedRate::parse_list("USD/KRW=0").is_err());
+    }
+}
@@ -0,0 +1,14 @@
+//! 다중 통화 지원
+//!
+//! 거래 심볼의 기초/호가 자산을 정의하는 자산 레지스트리와, MDP 최근 체결가로
+//! 자산 간 금액을 보고 통화(기본 KRW)로 환산하는 환산 서비스를 제공합니다.
+//! KYC 주문 금액 한도, 규제 보고 임계값, 포트폴리오 평가, 수수료 환산이 이 서비스를 씁니다.
+//! 계정 잔고는 자산별로 보관하고, 미체결 주문의 예약 금액은 심볼의 기초/호가 자산으로 나눕니다.
+
+pub mod registry;
+pub mod conversion;
+pub mod balance;
+
+pub use registry::{AssetKind, CurrencyError};
+pub use conversion::{CurrencyConfig, CurrencyConverter, FixedRate};
+pub use balance::{AssetBalance, OrderReservation, UserBalance};
@@ -0,0 +1,210 @@
+//! 자산(통화) 레지스트리
+//!
+//! 심볼은 `BASE-QUOTE` 형식(`BTC-KRW`, `ETH-BTC`, `BTC-USDT`)이며, 호가 자산이 붙지 않은
+//! 심볼(`AAPL`)은 따로 지정하지 않으면 보고 통화로 호가된 것으로 봅니다.
+//! 가격과 잔고는 호가/보유 자산의 최소 단위 정수이고, 최소 단위의 소수 자릿수는 자산마다 다릅니다
+//! (KRW 0, USD/USDT 2, BTC/ETH 8).
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 자산 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetKind {
+    /// 법정화폐
+    Fiat,
+    /// 스테이블코인
+    Stablecoin,
+    /// 암호화폐
+    Crypto,
+    /// 주식
+    Equity,
+}
+
+/// 자산
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Asset {
+    /// 자산 코드 (예: `KRW`, `USDT`, `BTC`)
+    pub code: String,
+    pub kind: AssetKind,
+    /// 최소 단위 소수 자릿수 (가격/잔고 정수 1 = 10^-decimals 단위)
+    pub decimals: u32,
+}
+
+impl Asset {
+    pub fn new(code: &str, kind: AssetKind, decimals: u32) -> Self {
+        Self {
+            code: code.to_string(),
+            kind,
+            decimals,
+        }
+    }
+
+    /// 최소 단위 금액 → 기본 단위 금액 (예: 12345 USD 센트 → 123.45)
+    pub fn to_major(&self, minor: f64) -> f64 {
+        minor / 10f64.powi(self.decimals as i32)
+    }
+
+    /// 기본 단위 금액 → 최소 단위 금액
+    pub fn to_minor(&self, major: f64) -> f64 {
+        major * 10f64.powi(self.decimals as i32)
+    }
+}
+
+/// 통화 오류
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CurrencyError {
+    #[error("등록되지 않은 자산입니다: {0}")]
+    UnknownAsset(String),
+    #[error("심볼 형식 오류: {0}")]
+    InvalidSymbol(String),
+    #[error("{from} → {to} 환율을 구할 수 없습니다 (시세 없음)")]
+    RateUnavailable { from: String, to: String },
+}
+
+/// 심볼의 기초/호가 자산
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolPair {
+    pub base: String,
+    pub quote: String,
+}
+
+/// 자산 레지스트리
+#[derive(Debug, Clone)]
+pub struct AssetRegistry {
+    /// 보고 통화 (KYC 한도, 규제 임계값, 포트폴리오 평가 기준)
+    reporting_currency: String,
+    assets: HashMap<String, Asset>,
+    /// 호가 자산이 붙지 않은 심볼의 호가 자산 (예: `AAPL` → `USD`)
+    symbol_quotes: HashMap<String, String>,
+    /// 다른 심볼의 자산 쌍을 따르는 심볼 (예: 만기 상품 `BTC-KRW-2612` → `BTC-KRW`)
+    symbol_aliases: HashMap<String, String>,
+}
+
+impl Default for AssetRegistry {
+    fn default() -> Self {
+        Self::new(
+            "KRW",
+            vec![
+                Asset::new("KRW", AssetKind::Fiat, 0),
+                Asset::new("USD", AssetKind::Fiat, 2),
+                Asset::new("USDT", AssetKind::Stablecoin, 2),
+                Asset::new("BTC", AssetKind::Crypto, 8),
+                Asset::new("ETH", AssetKind::Crypto, 8),
+                Asset::new("AAPL", AssetKind::Equity, 0),
+            ],
+        )
+        .expect("기본 자산 레지스트리")
+    }
+}
+
+impl AssetRegistry {
+    /// 새 레지스트리 (보고 통화도 등록된 자산이어야 함)
+    pub fn new(reporting_currency: &str, assets: Vec<Asset>) -> Result<Self, CurrencyError> {
+        let assets: HashMap<String, Asset> = assets.into_iter().map(|asset| (asset.code.clone(), asset)).collect();
+        if !assets.contains_key(reporting_currency) {
+            return Err(CurrencyError::UnknownAsset(reporting_currency.to_string()));
+        }
+        Ok(Self {
+            reporting_currency: reporting_currency.to_string(),
+            assets,
+            symbol_quotes: HashMap::new(),
+            symbol_aliases: HashMap::new(),
+        })
+    }
+
+    /// 호가 자산이 붙지 않은 심볼의 호가 자산 지정
+    pub fn with_symbol_quote(mut self, symbol: &str, quote: &str) -> Self {
+        self.symbol_quotes.insert(symbol.to_string(), quote.to_string());
+        self
+    }
+
+    /// `symbol`의 자산 쌍을 `underlying` 심볼과 같게 지정
+    pub fn with_symbol_alias(mut self, symbol: &str, underlying: &str) -> Self {
+        self.symbol_aliases.insert(symbol.to_string(), underlying.to_string());
+        self
+    }
+
+    pub fn reporting_currency(&self) -> &str {
+        &self.reporting_currency
+    }
+
+    pub fn asset(&self, code: &str) -> Option<&Asset> {
+        self.assets.get(code)
+    }
+
+    /// 등록 자산 (코드순)
+    pub fn assets(&self) -> Vec<&Asset> {
+        let mut assets: Vec<&Asset> = self.assets.values().collect();
+        assets.sort_by(|a, b| a.code.cmp(&b.code));
+        assets
+    }
+
+    /// 심볼의 기초/호가 자산 (둘 다 등록된 자산이어야 함)
+    pub fn pair(&self, symbol: &str) -> Result<SymbolPair, CurrencyError> {
+        if let Some(underlying) = self.symbol_aliases.get(symbol) {
+            return self.pair(underlying);
+        }
+        let (base, quote) = match symbol.split_once('-') {
+            Some((base, quote)) if !base.is_empty() && !quote.is_empty() && !quote.contains('-') => {
+                (base.to_string(), quote.to_string())
+            }
+            Some(_) => return Err(CurrencyError::InvalidSymbol(symbol.to_string())),
+            None => (
+                symbol.to_string(),
+                self.symbol_quotes.get(symbol).cloned().unwrap_or_else(|| self.reporting_currency.clone()),
+            ),
+        };
+
+        for code in [&base, &quote] {
+            if !self.assets.contains_key(code) {
+                return Err(CurrencyError::UnknownAsset(code.clone()));
+            }
+        }
+        if base == quote {
+            return Err(CurrencyError::InvalidSymbol(symbol.to_string()));
+        }
+        Ok(SymbolPair { base, quote })
+    }
+
+    /// 심볼의 호가 자산
+    pub fn quote_asset(&self, symbol: &str) -> Result<&Asset, CurrencyError> {
+        let pair = self.pair(symbol)?;
+        Ok(&self.assets[&pair.quote])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol_pairs() {
+        let registry = AssetRegistry::default().with_symbol_quote("AAPL", "USD");
+
+        assert_eq!(
+            registry.pair("ETH-BTC").unwrap(),
+            SymbolPair { base: "ETH".to_string(), quote: "BTC".to_string() }
+        );
+        assert_eq!(registry.quote_asset("BTC-USDT").unwrap().decimals, 2);
+        assert_eq!(registry.pair("AAPL").unwrap().quote, "USD");
+        assert_eq!(AssetRegistry::default().pair("AAPL").unwrap().quote, "KRW");
+        let futures = AssetRegistry::default().with_symbol_alias("BTC-KRW-2612", "BTC-KRW");
+        assert_eq!(futures.pair("BTC-KRW-2612").unwrap(), futures.pair("BTC-KRW").unwrap());
+
+        assert_eq!(registry.pair("DOGE-KRW"), Err(CurrencyError::UnknownAsset("DOGE".to_string())));
+        assert!(matches!(registry.pair("BTC-"), Err(CurrencyError::InvalidSymbol(_))));
+        assert!(matches!(registry.pair("BTC-BTC"), Err(CurrencyError::InvalidSymbol(_))));
+        assert!(AssetRegistry::new("EUR", vec![Asset::new("KRW", AssetKind::Fiat, 0)]).is_err());
+    }
+
+    #[test]
+    fn test_minor_units() {
+        let usdt = Asset::new("USDT", AssetKind::Stablecoin, 2);
+        assert_eq!(usdt.to_major(12_345.0), 123.45);
+        assert_eq!(usdt.to_minor(1.5), 150.0);
+    }
+}
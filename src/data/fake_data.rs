@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use crate::matching_engine::model::{Order, OrderType, Side};
+use crate::matching_engine::model::{OrderType, Side};
 
 /// 가짜 시장 데이터
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,400 @@
+//! 데이터셋 생성기
+//!
+//! 테스트 환경이 손으로 쓴 `fake_dataset.json` 대신 원하는 시작 상태를 만들 수 있도록
+//! 시드 기반으로 재현 가능한 데이터셋(시장 정보, 초기 호가창, 과거 봉)을 생성합니다.
+//! 생성기는 `DatasetGenerator` 트레이트로 추가하며, 같은 시드는 항상 같은 데이터셋을 만듭니다.
+
+use std::collections::BTreeMap;
+
+use crate::data::schema::{BookSpec, CandleSpec, DatasetFile, HistorySpec, MarketDataSpec, UserSpec};
+
+/// 시드 기반 난수 생성기 (SplitMix64, 외부 크레이트 버전과 무관하게 같은 시드는 같은 결과)
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `[low, high]` 범위의 정수
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        if high <= low {
+            return low;
+        }
+        low + self.next_u64() % (high - low + 1)
+    }
+
+    /// `[-1, 1)` 범위의 실수
+    pub fn signed_unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+    }
+}
+
+/// 호가 깊이 프로필
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiquidityProfile {
+    /// 얕은 호가 (레벨 적음, 호가 사이 간격 넓음, 잔량 적음)
+    Thin,
+    Normal,
+    /// 두꺼운 호가 (레벨 많음, 촘촘함, 잔량 많음)
+    Thick,
+}
+
+impl LiquidityProfile {
+    /// 이름으로 조회 (`thin`, `normal`, `thick`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "thin" => Some(Self::Thin),
+            "normal" => Some(Self::Normal),
+            "thick" => Some(Self::Thick),
+            _ => None,
+        }
+    }
+
+    /// 한쪽 호가 레벨 수
+    fn levels(&self) -> usize {
+        match self {
+            Self::Thin => 5,
+            Self::Normal => 10,
+            Self::Thick => 25,
+        }
+    }
+
+    /// 인접 레벨 사이 최대 틱 수
+    fn max_gap_ticks(&self) -> u64 {
+        match self {
+            Self::Thin => 5,
+            Self::Normal => 2,
+            Self::Thick => 1,
+        }
+    }
+
+    /// 최우선 매수/매도 사이 틱 수
+    fn spread_ticks(&self) -> u64 {
+        match self {
+            Self::Thin => 8,
+            Self::Normal => 4,
+            Self::Thick => 2,
+        }
+    }
+
+    /// 레벨 잔량 배수 (수량 단위 기준)
+    fn quantity_scale(&self) -> u64 {
+        match self {
+            Self::Thin => 1,
+            Self::Normal => 5,
+            Self::Thick => 20,
+        }
+    }
+}
+
+/// 생성할 심볼 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolTemplate {
+    pub symbol: String,
+    pub base_price: u64,
+    pub tick_size: u64,
+    pub lot_size: u64,
+    /// 가격 변동성 (0.01 = 1%)
+    pub volatility: f64,
+}
+
+impl SymbolTemplate {
+    pub fn new(symbol: &str, base_price: u64, tick_size: u64, lot_size: u64, volatility: f64) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            base_price,
+            tick_size: tick_size.max(1),
+            lot_size: lot_size.max(1),
+            volatility,
+        }
+    }
+
+    /// 기본 심볼 (`fake_dataset.json`과 같은 가격대)
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::new("BTC-KRW", 100_000_000, 1_000, 1, 0.025),
+            Self::new("ETH-KRW", 4_000_000, 1_000, 1, 0.035),
+            Self::new("AAPL", 200_000, 100, 100, 0.015),
+        ]
+    }
+
+    /// 호가 단위로 내림
+    fn snap(&self, price: u64) -> u64 {
+        (price / self.tick_size * self.tick_size).max(self.tick_size)
+    }
+
+    /// 현재가를 담는 시장 정보 (가격 범위는 변동성 기준 ±2σ, 관측 범위 포함)
+    fn market_data(&self, current_price: u64, observed: (u64, u64)) -> MarketDataSpec {
+        let band = (self.base_price as f64 * self.volatility * 2.0) as u64;
+        let low = self.base_price.saturating_sub(band).min(observed.0).min(current_price);
+        let high = (self.base_price + band).max(observed.1).max(current_price);
+        MarketDataSpec {
+            symbol: self.symbol.clone(),
+            base_price: self.base_price,
+            volatility: self.volatility,
+            volume_range: (self.lot_size, self.lot_size * 100),
+            price_range: (low, high),
+            current_price: Some(current_price),
+            tick_size: Some(self.tick_size),
+            lot_size: Some(self.lot_size),
+            ..MarketDataSpec::default()
+        }
+    }
+
+    /// 중간가 주변 호가창 생성
+    fn book(&self, rng: &mut SeededRng, mid: u64, liquidity: LiquidityProfile) -> BookSpec {
+        let tick = self.tick_size;
+        let spread = liquidity.spread_ticks();
+        let best_bid = self.snap(mid).saturating_sub(spread / 2 * tick).max(tick);
+        let best_ask = best_bid + spread * tick;
+
+        let mut book = BookSpec::default();
+        let (mut bid, mut ask) = (best_bid, best_ask);
+        for level in 0..liquidity.levels() {
+            // 깊은 레벨일수록 잔량이 늘어남
+            let depth_factor = 1 + level as u64 / 3;
+            let mut quantity = || rng.range(1, 10) * liquidity.quantity_scale() * depth_factor * self.lot_size;
+            if bid > 0 {
+                book.bids.push((bid, quantity()));
+            }
+            book.asks.push((ask, quantity()));
+
+            let gap = rng.range(1, liquidity.max_gap_ticks()) * tick;
+            bid = bid.saturating_sub(gap);
+            ask += rng.range(1, liquidity.max_gap_ticks()) * tick;
+        }
+        book
+    }
+}
+
+/// 데이터셋 생성기
+pub trait DatasetGenerator {
+    /// 생성기 이름 (로그/설정용)
+    fn name(&self) -> &'static str;
+
+    /// 데이터셋 생성 (같은 난수 상태면 같은 결과)
+    fn generate(&self, rng: &mut SeededRng) -> DatasetFile;
+}
+
+/// 기준가 주변에 무작위 호가창을 만드는 생성기
+#[derive(Debug, Clone)]
+pub struct RandomBookGenerator {
+    pub symbols: Vec<SymbolTemplate>,
+    pub liquidity: LiquidityProfile,
+}
+
+impl RandomBookGenerator {
+    pub fn new(symbols: Vec<SymbolTemplate>, liquidity: LiquidityProfile) -> Self {
+        Self { symbols, liquidity }
+    }
+}
+
+impl DatasetGenerator for RandomBookGenerator {
+    fn name(&self) -> &'static str {
+        "random"
+    }
+
+    fn generate(&self, rng: &mut SeededRng) -> DatasetFile {
+        let mut dataset = empty_dataset();
+        for template in &self.symbols {
+            // 중간가는 기준가에서 변동성의 절반 이내로 흔듦
+            let offset = template.base_price as f64 * template.volatility * 0.5 * rng.signed_unit();
+            let mid = template.snap((template.base_price as f64 + offset).max(1.0) as u64);
+            let book = template.book(rng, mid, self.liquidity);
+            let observed = book_range(&book, mid);
+            dataset.market_data.insert(template.symbol.clone(), template.market_data(mid, observed));
+            dataset.initial_orderbook.insert(template.symbol.clone(), book);
+        }
+        dataset
+    }
+}
+
+/// 일정한 방향으로 움직인 과거 봉과 그 끝 가격 주변 호가창을 만드는 생성기
+#[derive(Debug, Clone)]
+pub struct TrendingMarketGenerator {
+    pub symbols: Vec<SymbolTemplate>,
+    pub liquidity: LiquidityProfile,
+    /// 1분 봉당 평균 가격 변화 (bps, 음수면 하락장)
+    pub drift_bps: i64,
+    /// 생성할 1분 봉 수
+    pub candles: usize,
+    /// 첫 봉 시작 시각 (Unix 초)
+    pub start_time: u64,
+}
+
+impl TrendingMarketGenerator {
+    pub fn new(symbols: Vec<SymbolTemplate>, liquidity: LiquidityProfile, drift_bps: i64) -> Self {
+        Self {
+            symbols,
+            liquidity,
+            drift_bps,
+            candles: 60,
+            start_time: 1_694_707_200,
+        }
+    }
+}
+
+impl DatasetGenerator for TrendingMarketGenerator {
+    fn name(&self) -> &'static str {
+        "trending"
+    }
+
+    fn generate(&self, rng: &mut SeededRng) -> DatasetFile {
+        let mut dataset = empty_dataset();
+        for template in &self.symbols {
+            // 봉당 잡음은 일 변동성을 1분 단위로 나눈 정도
+            let noise = template.volatility / (24.0 * 60.0f64).sqrt();
+            let mut price = template.base_price;
+            let mut candles = Vec::with_capacity(self.candles);
+            for i in 0..self.candles {
+                let open = price;
+                let change = self.drift_bps as f64 / 10_000.0 + noise * rng.signed_unit();
+                let close = template.snap((open as f64 * (1.0 + change)).max(1.0) as u64);
+                let wick = (open.max(close) as f64 * noise * 0.5 * (rng.signed_unit() + 1.0)) as u64;
+                let high = open.max(close) + wick / template.tick_size * template.tick_size;
+                let low = template.snap(open.min(close).saturating_sub(wick)).min(open.min(close));
+                candles.push(CandleSpec {
+                    timestamp: self.start_time + i as u64 * 60,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume: rng.range(1, 100) * template.lot_size,
+                });
+                price = close;
+            }
+
+            let low = candles.iter().map(|c| c.low).min().unwrap_or(price);
+            let high = candles.iter().map(|c| c.high).max().unwrap_or(price);
+            let book = template.book(rng, price, self.liquidity);
+            let (book_low, book_high) = book_range(&book, price);
+            dataset
+                .market_data
+                .insert(template.symbol.clone(), template.market_data(price, (low.min(book_low), high.max(book_high))));
+            dataset.initial_orderbook.insert(template.symbol.clone(), book);
+            dataset.historical_data.insert(template.symbol.clone(), HistorySpec { candles_1m: candles });
+        }
+        dataset
+    }
+}
+
+/// 이름으로 생성기 선택 (`random`, `trending`)
+pub fn generator_by_name(
+    name: &str,
+    symbols: Vec<SymbolTemplate>,
+    liquidity: LiquidityProfile,
+    drift_bps: i64,
+) -> Option<Box<dyn DatasetGenerator>> {
+    match name {
+        "random" => Some(Box::new(RandomBookGenerator::new(symbols, liquidity))),
+        "trending" => Some(Box::new(TrendingMarketGenerator::new(symbols, liquidity, drift_bps))),
+        _ => None,
+    }
+}
+
+/// 시드로 데이터셋 생성
+pub fn generate_dataset(generator: &dyn DatasetGenerator, seed: u64) -> DatasetFile {
+    generator.generate(&mut SeededRng::new(seed))
+}
+
+/// 생성 데이터셋 공통 부분 (초기 호가를 공급할 시장 조성 사용자)
+fn empty_dataset() -> DatasetFile {
+    DatasetFile {
+        market_data: BTreeMap::new(),
+        initial_orderbook: BTreeMap::new(),
+        fake_users: vec![UserSpec {
+            id: "market_maker_001".to_string(),
+            name: "시장조성자".to_string(),
+            user_type: "institutional".to_string(),
+            trading_style: "market_making".to_string(),
+            balance: 10_000_000_000,
+        }],
+        historical_data: BTreeMap::new(),
+        trading_patterns: None,
+    }
+}
+
+/// 호가창 가격 범위 (호가가 없으면 중간가)
+fn book_range(book: &BookSpec, mid: u64) -> (u64, u64) {
+    let low = book.bids.last().map_or(mid, |&(price, _)| price);
+    let high = book.asks.last().map_or(mid, |&(price, _)| price);
+    (low.min(mid), high.max(mid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_generators() -> Vec<Box<dyn DatasetGenerator>> {
+        let mut generators = Vec::new();
+        for liquidity in [LiquidityProfile::Thin, LiquidityProfile::Normal, LiquidityProfile::Thick] {
+            for name in ["random", "trending"] {
+                generators.push(generator_by_name(name, SymbolTemplate::defaults(), liquidity, -15).unwrap());
+            }
+        }
+        generators
+    }
+
+    #[test]
+    fn test_generated_datasets_pass_validation() {
+        for generator in all_generators() {
+            for seed in 0..20 {
+                let dataset = generate_dataset(generator.as_ref(), seed);
+                if let Err(e) = dataset.validate() {
+                    panic!("{} 생성기 시드 {}: {}", generator.name(), seed, e);
+                }
+                // JSON 왕복 후에도 같은 데이터셋
+                let json = serde_json::to_string(&dataset).unwrap();
+                assert_eq!(DatasetFile::parse(&json).unwrap(), dataset);
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_dataset() {
+        let generator = RandomBookGenerator::new(SymbolTemplate::defaults(), LiquidityProfile::Normal);
+        assert_eq!(generate_dataset(&generator, 7), generate_dataset(&generator, 7));
+        assert_ne!(generate_dataset(&generator, 7), generate_dataset(&generator, 8));
+    }
+
+    #[test]
+    fn test_liquidity_profiles_change_depth() {
+        let depth = |liquidity| {
+            let dataset = generate_dataset(&RandomBookGenerator::new(SymbolTemplate::defaults(), liquidity), 1);
+            let book = &dataset.initial_orderbook["BTC-KRW"];
+            (book.bids.len(), book.bids.iter().map(|&(_, qty)| qty).sum::<u64>())
+        };
+        let (thin_levels, thin_qty) = depth(LiquidityProfile::Thin);
+        let (thick_levels, thick_qty) = depth(LiquidityProfile::Thick);
+        assert!(thin_levels < thick_levels);
+        assert!(thin_qty < thick_qty);
+    }
+
+    #[test]
+    fn test_trending_market_moves_in_drift_direction() {
+        let up = TrendingMarketGenerator::new(SymbolTemplate::defaults(), LiquidityProfile::Normal, 20);
+        let down = TrendingMarketGenerator { drift_bps: -20, ..up.clone() };
+        for (generator, rising) in [(up, true), (down, false)] {
+            let dataset = generate_dataset(&generator, 3);
+            let candles = &dataset.historical_data["ETH-KRW"].candles_1m;
+            assert_eq!(candles.len(), 60);
+            let (first, last) = (candles[0].open, candles[59].close);
+            assert_eq!(last > first, rising);
+            // 호가창은 마지막 종가 주변
+            let book = &dataset.initial_orderbook["ETH-KRW"];
+            assert!(book.bids[0].0 <= last && last <= book.asks[0].0);
+        }
+    }
+}
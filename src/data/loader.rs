@@ -3,7 +3,7 @@ use std::fs;
 use log::{info, warn, error};
 use serde_json;
 use crate::data::FakeDataset;
-use crate::matching_engine::model::{Order, Side, OrderType};
+use crate::data::DatasetFile;
 
 /// 데이터 로더
 pub struct DataLoader;
@@ -20,37 +20,27 @@ impl DataLoader {
         }
     }
 
-    /// JSON 파일에서 데이터셋 로드
+    /// JSON 파일에서 데이터셋 로드 (스키마 검증 후 변환)
     fn load_from_json(data_path: &str) -> Result<FakeDataset, Box<dyn std::error::Error>> {
-        let content = fs::read_to_string(data_path)?;
-        let json_data: serde_json::Value = serde_json::from_str(&content)?;
+        let dataset = DatasetFile::load(Path::new(data_path))?;
 
-        info!("✅ JSON 데이터 파싱 완료");
-        info!("📊 지원 심볼: {:?}",
-            json_data["market_data"].as_object()
-                .map(|obj| obj.keys().collect::<Vec<_>>())
-                .unwrap_or_default());
+        info!("✅ JSON 데이터 파싱 및 검증 완료");
+        info!("📊 지원 심볼: {:?}", dataset.market_data.keys().collect::<Vec<_>>());
 
         // 기존 FakeDataset과 호환되도록 변환
-        let mut market_data = std::collections::HashMap::new();
-
-        if let Some(market_obj) = json_data["market_data"].as_object() {
-            for (symbol, data) in market_obj {
-                market_data.insert(symbol.clone(), crate::data::FakeMarketData {
-                    symbol: data["symbol"].as_str().unwrap_or(symbol).to_string(),
-                    base_price: data["base_price"].as_u64().unwrap_or(100000000),
-                    volatility: data["volatility"].as_f64().unwrap_or(0.02),
-                    volume_range: (
-                        data["volume_range"][0].as_u64().unwrap_or(1),
-                        data["volume_range"][1].as_u64().unwrap_or(100)
-                    ),
-                    price_range: (
-                        data["price_range"][0].as_u64().unwrap_or(95000000),
-                        data["price_range"][1].as_u64().unwrap_or(105000000)
-                    ),
-                });
-            }
-        }
+        let market_data = dataset
+            .market_data
+            .into_iter()
+            .map(|(symbol, data)| {
+                (symbol, crate::data::FakeMarketData {
+                    symbol: data.symbol,
+                    base_price: data.base_price,
+                    volatility: data.volatility,
+                    volume_range: data.volume_range,
+                    price_range: data.price_range,
+                })
+            })
+            .collect();
 
         let dataset = FakeDataset {
             market_data,
@@ -60,59 +50,6 @@ impl DataLoader {
         Ok(dataset)
     }
 
-    /// 초기 주문서 생성 (실전적인 깊이 제공)
-    pub fn create_initial_orders(json_data: &serde_json::Value) -> Vec<Order> {
-        let mut orders = Vec::new();
-        let mut order_id = 1;
-
-        if let Some(orderbook_obj) = json_data["initial_orderbook"].as_object() {
-            for (symbol, book_data) in orderbook_obj {
-                info!("📋 {}의 초기 주문서 생성 중...", symbol);
-
-                // 매수 주문 생성 (bids)
-                if let Some(bids) = book_data["bids"].as_array() {
-                    for bid in bids {
-                        let price = bid[0].as_u64().unwrap_or(0);
-                        let quantity = bid[1].as_u64().unwrap_or(0);
-
-                        orders.push(Order::new(
-                            format!("init_bid_{}", order_id),
-                            symbol.clone(),
-                            Side::Buy,
-                            OrderType::Limit,
-                            price,
-                            quantity,
-                            "market_maker".to_string(),
-                        ));
-                        order_id += 1;
-                    }
-                }
-
-                // 매도 주문 생성 (asks)
-                if let Some(asks) = book_data["asks"].as_array() {
-                    for ask in asks {
-                        let price = ask[0].as_u64().unwrap_or(0);
-                        let quantity = ask[1].as_u64().unwrap_or(0);
-
-                        orders.push(Order::new(
-                            format!("init_ask_{}", order_id),
-                            symbol.clone(),
-                            Side::Sell,
-                            OrderType::Limit,
-                            price,
-                            quantity,
-                            "market_maker".to_string(),
-                        ));
-                        order_id += 1;
-                    }
-                }
-            }
-        }
-
-        info!("✅ 초기 주문 {}개 생성 완료", orders.len());
-        orders
-    }
-    
     /// 기본 데이터셋 생성 및 저장
     fn create_default_dataset(data_path: &str) -> Result<FakeDataset, Box<dyn std::error::Error>> {
         let dataset = FakeDataset::default();
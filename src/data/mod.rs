@@ -1,5 +1,11 @@
 pub mod fake_data;
+pub mod generator;
 pub mod loader;
+pub mod schema;
+pub mod seed;
 
 pub use fake_data::*;
-pub use loader::*;
\ No newline at end of file
+pub use generator::*;
+pub use loader::*;
+pub use schema::*;
+pub use seed::*;
\ No newline at end of file
@@ -0,0 +1,342 @@
+//! 데이터셋 파일 스키마 및 검증
+//!
+//! `fake_dataset.json`과 데이터셋 생성기 출력의 형식을 serde 구조체로 정의합니다.
+//! 형식 오류(오타 난 필드, 잘못된 타입)는 줄/열 위치와 함께, 값 오류(역전된 호가, 틱 단위가 아닌 가격 등)는
+//! `initial_orderbook.BTC-KRW.bids[3]` 같은 경로와 함께 한 번에 모두 보고합니다.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::data::seed::SEED_CLIENT_ID;
+use crate::matching_engine::model::{Order, OrderType, Side};
+
+/// 데이터셋 파일 전체
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DatasetFile {
+    /// 심볼별 시장 정보
+    pub market_data: BTreeMap<String, MarketDataSpec>,
+    /// 심볼별 초기 호가창
+    #[serde(default)]
+    pub initial_orderbook: BTreeMap<String, BookSpec>,
+    /// 가짜 사용자
+    #[serde(default)]
+    pub fake_users: Vec<UserSpec>,
+    /// 심볼별 과거 봉
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub historical_data: BTreeMap<String, HistorySpec>,
+    /// 사용자 유형별 거래 패턴 (검증 없이 보존)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trading_patterns: Option<Value>,
+}
+
+/// 심볼 시장 정보
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MarketDataSpec {
+    pub symbol: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub base_price: u64,
+    /// 가격 변동성 (0.01 = 1%)
+    pub volatility: f64,
+    /// (최소, 최대) 주문 수량
+    pub volume_range: (u64, u64),
+    /// (최소, 최대) 가격
+    pub price_range: (u64, u64),
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_price: Option<u64>,
+    /// 호가 단위 (가격은 이 값의 배수)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tick_size: Option<u64>,
+    /// 수량 단위 (수량은 이 값의 배수)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lot_size: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_volume: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub market_cap: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub circulating_supply: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trading_hours: Option<String>,
+}
+
+/// 초기 호가창 (가격, 수량)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BookSpec {
+    /// 매수 호가 (가격 내림차순)
+    pub bids: Vec<(u64, u64)>,
+    /// 매도 호가 (가격 오름차순)
+    pub asks: Vec<(u64, u64)>,
+}
+
+/// 가짜 사용자
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserSpec {
+    pub id: String,
+    pub name: String,
+    /// retail, institutional
+    #[serde(rename = "type")]
+    pub user_type: String,
+    pub trading_style: String,
+    pub balance: u64,
+}
+
+/// 심볼 과거 봉
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HistorySpec {
+    #[serde(rename = "1m_candles", default)]
+    pub candles_1m: Vec<CandleSpec>,
+}
+
+/// 봉 하나
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CandleSpec {
+    pub timestamp: u64,
+    pub open: u64,
+    pub high: u64,
+    pub low: u64,
+    pub close: u64,
+    pub volume: u64,
+}
+
+/// 검증 위반 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// 위반 위치 (예: `initial_orderbook.BTC-KRW.bids[3]`)
+    pub path: String,
+    pub message: String,
+}
+
+/// 데이터셋 로드/검증 오류
+#[derive(Debug)]
+pub enum DatasetError {
+    /// 파일 읽기/쓰기 실패
+    Io { path: PathBuf, source: std::io::Error },
+    /// JSON 형식 또는 필드 타입 오류
+    Parse { line: usize, column: usize, message: String },
+    /// 값 검증 실패 (모든 위반 항목)
+    Invalid(Vec<SchemaViolation>),
+}
+
+impl fmt::Display for DatasetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatasetError::Io { path, source } => write!(f, "데이터셋 파일 {} 접근 실패: {}", path.display(), source),
+            DatasetError::Parse { line, column, message } => {
+                write!(f, "데이터셋 형식 오류 ({}행 {}열): {}", line, column, message)
+            }
+            DatasetError::Invalid(violations) => {
+                write!(f, "데이터셋 검증 실패 ({}건)", violations.len())?;
+                for violation in violations {
+                    write!(f, "\n  - {}: {}", violation.path, violation.message)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DatasetError {}
+
+/// 위반 항목 수집기
+#[derive(Default)]
+struct Violations(Vec<SchemaViolation>);
+
+impl Violations {
+    fn check(&mut self, ok: bool, path: impl Into<String>, message: impl Into<String>) {
+        if !ok {
+            self.0.push(SchemaViolation { path: path.into(), message: message.into() });
+        }
+    }
+}
+
+impl DatasetFile {
+    /// JSON 문자열 파싱 후 검증
+    pub fn parse(json: &str) -> Result<Self, DatasetError> {
+        let dataset: DatasetFile = serde_json::from_str(json).map_err(|e| DatasetError::Parse {
+            line: e.line(),
+            column: e.column(),
+            message: e.to_string(),
+        })?;
+        dataset.validate()?;
+        Ok(dataset)
+    }
+
+    /// 파일 로드 후 검증
+    pub fn load(path: &Path) -> Result<Self, DatasetError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|source| DatasetError::Io { path: path.to_path_buf(), source })?;
+        Self::parse(&content)
+    }
+
+    /// JSON 파일로 저장 (저장 전 검증)
+    pub fn save(&self, path: &Path) -> Result<(), DatasetError> {
+        self.validate()?;
+        let json = serde_json::to_string_pretty(self).map_err(|e| DatasetError::Parse {
+            line: 0,
+            column: 0,
+            message: e.to_string(),
+        })?;
+        std::fs::write(path, json).map_err(|source| DatasetError::Io { path: path.to_path_buf(), source })
+    }
+
+    /// 값 검증 (위반 항목을 모두 모아 반환)
+    pub fn validate(&self) -> Result<(), DatasetError> {
+        let mut v = Violations::default();
+
+        for (key, market) in &self.market_data {
+            let path = format!("market_data.{}", key);
+            v.check(market.symbol == *key, format!("{}.symbol", path), format!("키와 다른 심볼 `{}`", market.symbol));
+            v.check(market.base_price > 0, format!("{}.base_price", path), "0보다 커야 함");
+            v.check(
+                market.volatility.is_finite() && market.volatility >= 0.0,
+                format!("{}.volatility", path),
+                format!("0 이상의 유한한 값이어야 함 ({})", market.volatility),
+            );
+            v.check(
+                market.volume_range.0 <= market.volume_range.1,
+                format!("{}.volume_range", path),
+                format!("최소 {}가 최대 {}보다 큼", market.volume_range.0, market.volume_range.1),
+            );
+            let (low, high) = market.price_range;
+            v.check(low <= high, format!("{}.price_range", path), format!("최소 {}가 최대 {}보다 큼", low, high));
+            v.check(
+                (low..=high).contains(&market.base_price),
+                format!("{}.base_price", path),
+                format!("price_range [{}, {}] 밖의 값 {}", low, high, market.base_price),
+            );
+            v.check(market.tick_size != Some(0), format!("{}.tick_size", path), "0보다 커야 함");
+            v.check(market.lot_size != Some(0), format!("{}.lot_size", path), "0보다 커야 함");
+        }
+
+        for (symbol, book) in &self.initial_orderbook {
+            let path = format!("initial_orderbook.{}", symbol);
+            let market = self.market_data.get(symbol);
+            v.check(market.is_some(), &path, "market_data에 없는 심볼");
+            let tick = market.and_then(|m| m.tick_size).filter(|&t| t > 0).unwrap_or(1);
+            let lot = market.and_then(|m| m.lot_size).filter(|&l| l > 0).unwrap_or(1);
+
+            for (side, levels, descending) in [("bids", &book.bids, true), ("asks", &book.asks, false)] {
+                for (i, &(price, quantity)) in levels.iter().enumerate() {
+                    let level = format!("{}.{}[{}]", path, side, i);
+                    v.check(price > 0, &level, "가격은 0보다 커야 함");
+                    v.check(price % tick == 0, &level, format!("가격 {}가 호가 단위 {}의 배수가 아님", price, tick));
+                    v.check(quantity > 0, &level, "수량은 0보다 커야 함");
+                    v.check(quantity % lot == 0, &level, format!("수량 {}가 수량 단위 {}의 배수가 아님", quantity, lot));
+                    if i > 0 {
+                        let prev = levels[i - 1].0;
+                        let ordered = if descending { prev > price } else { prev < price };
+                        let order = if descending { "내림차순" } else { "오름차순" };
+                        v.check(ordered, &level, format!("가격 {}가 {} 정렬이 아님 (이전 {})", price, order, prev));
+                    }
+                }
+            }
+
+            if let (Some(&(bid, _)), Some(&(ask, _))) = (book.bids.first(), book.asks.first()) {
+                v.check(bid < ask, &path, format!("최우선 매수 {}가 최우선 매도 {} 이상 (교차 호가)", bid, ask));
+            }
+        }
+
+        let mut user_ids = HashSet::new();
+        for (i, user) in self.fake_users.iter().enumerate() {
+            v.check(user_ids.insert(user.id.as_str()), format!("fake_users[{}].id", i), format!("중복 ID `{}`", user.id));
+        }
+
+        for (symbol, history) in &self.historical_data {
+            for (i, candle) in history.candles_1m.iter().enumerate() {
+                let path = format!("historical_data.{}.1m_candles[{}]", symbol, i);
+                let body_low = candle.open.min(candle.close);
+                let body_high = candle.open.max(candle.close);
+                v.check(
+                    candle.low <= body_low && body_high <= candle.high,
+                    path,
+                    format!("low {} ≤ open/close ≤ high {} 이어야 함", candle.low, candle.high),
+                );
+            }
+        }
+
+        if v.0.is_empty() {
+            Ok(())
+        } else {
+            Err(DatasetError::Invalid(v.0))
+        }
+    }
+
+    /// 초기 호가창을 주문으로 변환 (심볼 이름순, 고객 ID는 `SEED_CLIENT_ID`)
+    pub fn initial_orders(&self) -> Vec<Order> {
+        let mut orders = Vec::new();
+        let mut order_id = 1;
+
+        for (symbol, book) in &self.initial_orderbook {
+            for (side, prefix, levels) in [(Side::Buy, "init_bid", &book.bids), (Side::Sell, "init_ask", &book.asks)] {
+                for &(price, quantity) in levels {
+                    orders.push(Order::new(
+                        format!("{}_{}", prefix, order_id),
+                        symbol.clone(),
+                        side.clone(),
+                        OrderType::Limit,
+                        price,
+                        quantity,
+                        SEED_CLIENT_ID.to_string(),
+                    ));
+                    order_id += 1;
+                }
+            }
+        }
+
+        orders
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUNDLED_DATASET: &str = include_str!("../../data/fake_dataset.json");
+
+    #[test]
+    fn test_bundled_dataset_is_valid() {
+        let dataset = DatasetFile::parse(BUNDLED_DATASET).unwrap();
+        assert_eq!(dataset.market_data.len(), 3);
+        assert_eq!(dataset.initial_orders().len(), 60);
+        assert!(dataset.trading_patterns.is_some());
+        assert_eq!(dataset.market_data["AAPL"].name.as_deref(), Some("Apple Inc."));
+    }
+
+    #[test]
+    fn test_parse_error_reports_position() {
+        // 오타 난 필드는 위치와 함께 거부
+        let typo = BUNDLED_DATASET.replacen("\"tick_size\"", "\"tick_szie\"", 1);
+        let expected_line = typo.lines().position(|line| line.contains("tick_szie")).unwrap() + 1;
+        let err = DatasetFile::parse(&typo).unwrap_err();
+        let DatasetError::Parse { line, ref message, .. } = err else { panic!("형식 오류가 아님: {}", err) };
+        assert_eq!(line, expected_line);
+        assert!(message.contains("tick_szie"));
+    }
+
+    #[test]
+    fn test_validation_collects_all_violations() {
+        let mut dataset = DatasetFile::parse(BUNDLED_DATASET).unwrap();
+        let book = dataset.initial_orderbook.get_mut("BTC-KRW").unwrap();
+        book.bids[1].0 = book.bids[0].0 + 1_000; // 정렬 위반
+        book.asks[0].0 = 99_940_500; // 교차 호가 + 호가 단위 위반
+        dataset.initial_orderbook.insert("DOGE-KRW".to_string(), BookSpec::default());
+
+        let DatasetError::Invalid(violations) = dataset.validate().unwrap_err() else { panic!("검증 오류가 아님") };
+        let paths: Vec<&str> = violations.iter().map(|v| v.path.as_str()).collect();
+        assert!(paths.contains(&"initial_orderbook.BTC-KRW.bids[1]"));
+        assert!(paths.contains(&"initial_orderbook.BTC-KRW.asks[0]"));
+        assert!(paths.contains(&"initial_orderbook.BTC-KRW"));
+        assert!(paths.contains(&"initial_orderbook.DOGE-KRW"));
+    }
+}
@@ -0,0 +1,132 @@
+//! 시작 시 초기 호가 공급 (seed liquidity)
+//!
+//! 개발 환경이 빈 호가창 대신 실제와 비슷한 호가창으로 시작하도록, API를 열기 전에
+//! `fake_dataset.json`의 초기 주문서를 일반 주문과 같은 경로(전역 시퀀스 → 시퀀서 → 매칭 엔진)로 넣습니다.
+//! 초기 주문은 고객 ID `SEED_CLIENT_ID`로 구분합니다.
+
+use std::path::Path;
+
+use log::warn;
+
+use crate::data::DatasetFile;
+use crate::matching_engine::model::Order;
+use crate::matching_engine::order_ack::{OrderAckRegistry, OrderAckStatus};
+use crate::sequencer::backpressure::BoundedSender;
+use crate::sequencer::GlobalSequence;
+
+/// 초기 호가 주문의 고객 ID
+pub const SEED_CLIENT_ID: &str = "seed_liquidity";
+
+/// 초기 호가 공급 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeedSummary {
+    /// 매칭 엔진이 받아들인 주문 수 (체결 포함)
+    pub accepted: usize,
+    /// 거부되거나 큐에 넣지 못한 주문 수
+    pub rejected: usize,
+    /// 처리 결과를 받지 못한 주문 수
+    pub timed_out: usize,
+}
+
+/// 데이터셋 파일에서 초기 주문서 로드 (파일이 없거나 스키마 검증에 실패하면 빈 목록)
+pub fn load_seed_orders(dataset_path: &Path) -> Vec<Order> {
+    match DatasetFile::load(dataset_path) {
+        Ok(dataset) => dataset.initial_orders(),
+        Err(e) => {
+            warn!("초기 호가 데이터셋 로드 실패 ({}): {}", dataset_path.display(), e);
+            Vec::new()
+        }
+    }
+}
+
+/// 초기 주문을 시퀀서에 하나씩 넣고 매칭 엔진 처리 결과를 기다림
+///
+/// 주문을 하나씩 처리하므로 주문 큐 용량과 무관하게 모두 넣을 수 있고,
+/// 함수가 돌아오면 처리된 주문은 이미 호가창에 있습니다.
+pub async fn seed_order_book(
+    orders: Vec<Order>,
+    order_tx: &BoundedSender<Order>,
+    global_sequence: &GlobalSequence,
+    order_acks: &OrderAckRegistry,
+) -> SeedSummary {
+    let mut summary = SeedSummary::default();
+
+    for order in orders {
+        let order_id = order.id.clone();
+        let ack_rx = order_acks.register(&order_id);
+        match global_sequence.submit(order, order_tx) {
+            Ok(Some(_)) => {}
+            Ok(None) | Err(_) => {
+                order_acks.forget(&order_id);
+                warn!("초기 호가 주문을 큐에 넣지 못함: {}", order_id);
+                summary.rejected += 1;
+                continue;
+            }
+        }
+
+        match order_acks.wait(&order_id, ack_rx).await.map(|ack| ack.status) {
+            Some(OrderAckStatus::Rejected(reason)) => {
+                warn!("초기 호가 주문 거부: {} ({:?})", order_id, reason);
+                summary.rejected += 1;
+            }
+            Some(_) => summary.accepted += 1,
+            None => {
+                warn!("초기 호가 주문 처리 결과 시간 초과: {}", order_id);
+                summary.timed_out += 1;
+            }
+        }
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching_engine::model::{OrderType, Side};
+    use crate::matching_engine::order_ack::{OrderAck, OrderRejectReason};
+    use crate::sequencer::backpressure::{bounded_queue, OverflowPolicy};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn seed_order(id: &str, symbol: &str) -> Order {
+        Order::new(id.to_string(), symbol.to_string(), Side::Buy, OrderType::Limit, 1000, 1, SEED_CLIENT_ID.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_seed_waits_for_engine_result_per_order() {
+        // 용량 1짜리 큐로도 모든 주문을 넣을 수 있어야 함
+        let (order_tx, order_rx) = bounded_queue::<Order>("orders", 1, OverflowPolicy::DropNewest);
+        let global_sequence = GlobalSequence::new();
+        let order_acks = Arc::new(OrderAckRegistry::new(Duration::from_secs(1)));
+
+        // 매칭 엔진 대신 알려진 심볼만 접수
+        let engine_acks = order_acks.clone();
+        let engine = std::thread::spawn(move || {
+            let mut sequences = Vec::new();
+            while let Ok(order) = order_rx.recv() {
+                sequences.push(order.sequence);
+                let status = if order.symbol == "BTC-KRW" {
+                    OrderAckStatus::Accepted
+                } else {
+                    OrderAckStatus::Rejected(OrderRejectReason::UnknownSymbol)
+                };
+                engine_acks.complete(OrderAck {
+                    order_id: order.id,
+                    status,
+                    filled_quantity: 0,
+                    remaining_quantity: order.quantity,
+                });
+            }
+            sequences
+        });
+
+        let orders = vec![seed_order("s1", "BTC-KRW"), seed_order("s2", "DOGE-KRW"), seed_order("s3", "BTC-KRW")];
+        let summary = seed_order_book(orders, &order_tx, &global_sequence, &order_acks).await;
+        drop(order_tx);
+
+        assert_eq!(summary, SeedSummary { accepted: 2, rejected: 1, timed_out: 0 });
+        assert_eq!(engine.join().unwrap(), vec![1, 2, 3]);
+        assert_eq!(order_acks.pending_count(), 0);
+    }
+}
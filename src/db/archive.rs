@@ -0,0 +1,499 @@
+//! 콜드 스토리지 보관
+//!
+//! 마감된 날짜(UTC)의 체결, 1분봉, 감사 로그를 날짜·데이터셋별 Parquet(snappy) 파일로 만들어
+//! S3 호환 저장소에 올립니다. 올린 뒤 HEAD로 크기와 SHA-256을 다시 확인하고
+//! `archive_manifest`에 기록하며, 설정하면 보관을 마친 날짜의 로컬 행을 정리합니다.
+//! 정리할 때 체결은 보존 정리와 같이 일별 집계와 순포지션 이월분을 남깁니다.
+//!
+//! 객체 키: `{prefix}/{dataset}/date=YYYY-MM-DD/{dataset}-YYYY-MM-DD.parquet`
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+use chrono::{TimeZone, Utc};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use sqlx::sqlite::SqlitePool;
+
+use super::export::{trade_batch, trade_schema};
+use super::models::{ArchiveManifestRecord, AuditLogRecord, MinuteCandleRecord};
+use super::repository::{ArchiveRepository, RetentionRepository};
+use crate::external::object_storage::{sha256_hex, ObjectMeta, ObjectStorageError, ObjectStore, S3Config};
+use crate::performance::metrics_collector::MetricsCollector;
+
+/// 하루 (초)
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+/// 한 번에 읽어 행 그룹 하나로 쓰는 행 수
+const PAGE_ROWS: i64 = 50_000;
+
+const PARQUET_CONTENT_TYPE: &str = "application/vnd.apache.parquet";
+
+/// 보관 데이터셋
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveDataset {
+    Executions,
+    /// 체결에서 계산한 심볼별 1분봉
+    Candles,
+    AuditLogs,
+}
+
+impl ArchiveDataset {
+    pub const ALL: [ArchiveDataset; 3] = [ArchiveDataset::Executions, ArchiveDataset::Candles, ArchiveDataset::AuditLogs];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ArchiveDataset::Executions => "executions",
+            ArchiveDataset::Candles => "candles_1m",
+            ArchiveDataset::AuditLogs => "audit_logs",
+        }
+    }
+}
+
+/// 보관 설정
+#[derive(Debug, Clone)]
+pub struct ArchiveConfig {
+    pub storage: S3Config,
+    /// 객체 키 앞부분 (빈 값이면 버킷 최상위)
+    pub prefix: String,
+    /// 보관 주기
+    pub interval: Duration,
+    /// 마감 후 이 일수가 지난 날짜부터 보관 (늦게 저장되는 행을 기다림)
+    pub delay_days: u64,
+    /// 한 번에 보관하는 최대 일수
+    pub max_days_per_run: usize,
+    /// 보관을 마친 날짜의 로컬 체결/감사 로그 정리
+    pub prune: bool,
+}
+
+impl ArchiveConfig {
+    pub fn new(storage: S3Config) -> Self {
+        Self {
+            storage,
+            prefix: "xtrader".to_string(),
+            interval: Duration::from_secs(60 * 60),
+            delay_days: 1,
+            max_days_per_run: 7,
+            prune: false,
+        }
+    }
+}
+
+/// 보관 오류
+#[derive(Debug, thiserror::Error)]
+pub enum ArchiveError {
+    #[error("DB 조회 오류: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("Arrow 변환 오류: {0}")]
+    Arrow(#[from] ArrowError),
+    #[error("Parquet 인코딩 오류: {0}")]
+    Parquet(#[from] ParquetError),
+    #[error("저장소 오류: {0}")]
+    Storage(#[from] ObjectStorageError),
+    #[error("업로드 검증 실패 ({key}): {reason}")]
+    Integrity { key: String, reason: String },
+}
+
+/// 한 번 보관한 결과
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArchiveReport {
+    /// 모든 데이터셋 보관을 마친 날짜 수
+    pub days_archived: usize,
+    pub objects_uploaded: u64,
+    pub bytes_uploaded: u64,
+    pub rows_archived: u64,
+    /// 로컬 행을 정리한 날짜 수
+    pub days_pruned: usize,
+    pub executions_pruned: u64,
+    pub audit_logs_pruned: u64,
+}
+
+impl ArchiveReport {
+    /// 지표 기록
+    pub async fn publish(&self, metrics: &MetricsCollector) {
+        metrics.increment_counter("archive.runs", 1).await;
+        metrics.increment_counter("archive.objects.uploaded", self.objects_uploaded).await;
+        metrics.increment_counter("archive.bytes.uploaded", self.bytes_uploaded).await;
+        metrics.increment_counter("archive.rows.archived", self.rows_archived).await;
+        metrics.increment_counter("archive.rows.pruned", self.executions_pruned + self.audit_logs_pruned).await;
+    }
+}
+
+/// 콜드 스토리지 보관 작업
+pub struct Archiver {
+    repository: ArchiveRepository,
+    retention: RetentionRepository,
+    store: Arc<dyn ObjectStore>,
+    config: ArchiveConfig,
+}
+
+impl Archiver {
+    pub fn new(pool: SqlitePool, store: Arc<dyn ObjectStore>, config: ArchiveConfig) -> Self {
+        Self {
+            repository: ArchiveRepository::new(pool.clone()),
+            retention: RetentionRepository::new(pool),
+            store,
+            config,
+        }
+    }
+
+    pub fn config(&self) -> &ArchiveConfig {
+        &self.config
+    }
+
+    /// `now_secs`(Unix 초) 기준으로 한 번 보관
+    ///
+    /// 날짜 중간에 실패하면 그때까지 올린 데이터셋은 기록해 두고 다음 실행에서 나머지를 이어 갑니다.
+    pub async fn run(&self, now_secs: u64) -> Result<ArchiveReport, ArchiveError> {
+        let cutoff = (now_secs - now_secs % DAY_SECS).saturating_sub(self.config.delay_days * DAY_SECS) as i64;
+        let datasets = ArchiveDataset::ALL.len() as i64;
+        let limit = self.config.max_days_per_run as i64;
+        let mut report = ArchiveReport::default();
+
+        for day in self.repository.local_days_before(cutoff, datasets, false, limit).await? {
+            let done = self.repository.manifest(day).await?;
+            for dataset in ArchiveDataset::ALL {
+                if done.iter().any(|record| record.dataset == dataset.name()) {
+                    continue;
+                }
+                let record = self.archive_dataset(day, dataset, now_secs, &mut report).await?;
+                self.repository.record(&record).await?;
+            }
+            report.days_archived += 1;
+        }
+
+        if self.config.prune {
+            for day in self.repository.local_days_before(cutoff, datasets, true, limit).await? {
+                report.executions_pruned += self.retention.roll_up_execution_day(day).await?;
+                report.audit_logs_pruned += self.repository.delete_audit_logs_for_day(day).await?;
+                report.days_pruned += 1;
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// 데이터셋 하나를 인코딩해 올리고 검증
+    async fn archive_dataset(
+        &self,
+        day: i64,
+        dataset: ArchiveDataset,
+        now_secs: u64,
+        report: &mut ArchiveReport,
+    ) -> Result<ArchiveManifestRecord, ArchiveError> {
+        let (body, rows) = match dataset {
+            ArchiveDataset::Executions => self.encode_executions(day).await?,
+            ArchiveDataset::Candles => encode_candles(&self.repository.minute_candles(day).await?)?,
+            ArchiveDataset::AuditLogs => self.encode_audit_logs(day).await?,
+        };
+        let mut record = ArchiveManifestRecord {
+            day,
+            dataset: dataset.name().to_string(),
+            object_key: None,
+            row_count: rows as i64,
+            size_bytes: 0,
+            sha256: None,
+            archived_at: now_secs as i64,
+        };
+        if rows == 0 {
+            return Ok(record);
+        }
+
+        let key = self.object_key(day, dataset);
+        let sha256 = sha256_hex(&body);
+        let size = body.len() as u64;
+        // 이전 실행에서 올리고 기록하기 전에 멈췄으면 같은 객체가 이미 있음
+        let existing = self.store.head(&key).await?;
+        if existing.as_ref().map(|meta| (meta.size, meta.sha256.as_deref())) != Some((size, Some(sha256.as_str()))) {
+            self.store.put(&key, body, &sha256, PARQUET_CONTENT_TYPE).await?;
+            verify_upload(self.store.head(&key).await?, &key, size, &sha256)?;
+            report.objects_uploaded += 1;
+            report.bytes_uploaded += size;
+        }
+        report.rows_archived += rows;
+
+        record.object_key = Some(key);
+        record.size_bytes = size as i64;
+        record.sha256 = Some(sha256);
+        Ok(record)
+    }
+
+    async fn encode_executions(&self, day: i64) -> Result<(Vec<u8>, u64), ArchiveError> {
+        let mut writer = parquet_writer(trade_schema())?;
+        let mut rows = 0;
+        let mut after: Option<(i64, String)> = None;
+        loop {
+            let page = self
+                .repository
+                .executions_page(day, after.as_ref().map(|(time, id)| (*time, id.as_str())), PAGE_ROWS)
+                .await?;
+            let Some(last) = page.last() else { break };
+            after = Some((last.transaction_time, last.exec_id.clone()));
+            writer.write(&trade_batch(&page)?)?;
+            rows += page.len() as u64;
+            if (page.len() as i64) < PAGE_ROWS {
+                break;
+            }
+        }
+        Ok((writer.into_inner()?, rows))
+    }
+
+    async fn encode_audit_logs(&self, day: i64) -> Result<(Vec<u8>, u64), ArchiveError> {
+        let mut writer = parquet_writer(audit_log_schema())?;
+        let mut rows = 0;
+        let mut after_id = 0;
+        loop {
+            let page = self.repository.audit_logs_page(day, after_id, PAGE_ROWS).await?;
+            let Some(last) = page.last() else { break };
+            after_id = last.id;
+            writer.write(&audit_log_batch(&page)?)?;
+            rows += page.len() as u64;
+            if (page.len() as i64) < PAGE_ROWS {
+                break;
+            }
+        }
+        Ok((writer.into_inner()?, rows))
+    }
+
+    fn object_key(&self, day: i64, dataset: ArchiveDataset) -> String {
+        let date = Utc.timestamp_opt(day, 0).single().map(|time| time.format("%Y-%m-%d").to_string()).unwrap_or_else(|| day.to_string());
+        let file = format!("{}/date={}/{}-{}.parquet", dataset.name(), date, dataset.name(), date);
+        match self.config.prefix.trim_matches('/') {
+            "" => file,
+            prefix => format!("{}/{}", prefix, file),
+        }
+    }
+}
+
+/// 올린 객체의 크기와 SHA-256 확인
+fn verify_upload(meta: Option<ObjectMeta>, key: &str, size: u64, sha256: &str) -> Result<(), ArchiveError> {
+    let integrity = |reason: String| ArchiveError::Integrity { key: key.to_string(), reason };
+    let meta = meta.ok_or_else(|| integrity("업로드한 객체가 없습니다".to_string()))?;
+    if meta.size != size {
+        return Err(integrity(format!("크기 불일치: {} != {}", meta.size, size)));
+    }
+    match meta.sha256.as_deref() {
+        Some(actual) if actual == sha256 => Ok(()),
+        Some(actual) => Err(integrity(format!("SHA-256 불일치: {}", actual))),
+        None => Err(integrity("SHA-256 메타데이터가 없습니다".to_string())),
+    }
+}
+
+fn parquet_writer(schema: SchemaRef) -> Result<ArrowWriter<Vec<u8>>, ParquetError> {
+    let props = WriterProperties::builder().set_compression(Compression::SNAPPY).build();
+    ArrowWriter::try_new(Vec::new(), schema, Some(props))
+}
+
+fn encode_candles(candles: &[MinuteCandleRecord]) -> Result<(Vec<u8>, u64), ArchiveError> {
+    let mut writer = parquet_writer(candle_schema())?;
+    if !candles.is_empty() {
+        writer.write(&candle_batch(candles)?)?;
+    }
+    Ok((writer.into_inner()?, candles.len() as u64))
+}
+
+fn candle_schema() -> SchemaRef {
+    let mut fields = vec![Field::new("symbol", DataType::Utf8, false)];
+    fields.extend(
+        ["open_time", "open", "high", "low", "close", "volume", "trade_count"]
+            .into_iter()
+            .map(|name| Field::new(name, DataType::Int64, false)),
+    );
+    Arc::new(Schema::new(fields))
+}
+
+fn candle_batch(rows: &[MinuteCandleRecord]) -> Result<RecordBatch, ArrowError> {
+    let ints = |f: fn(&MinuteCandleRecord) -> i64| -> ArrayRef { Arc::new(Int64Array::from_iter_values(rows.iter().map(f))) };
+    RecordBatch::try_new(
+        candle_schema(),
+        vec![
+            Arc::new(StringArray::from_iter_values(rows.iter().map(|r| &r.symbol))),
+            ints(|r| r.open_time),
+            ints(|r| r.open_price),
+            ints(|r| r.high_price),
+            ints(|r| r.low_price),
+            ints(|r| r.close_price),
+            ints(|r| r.volume),
+            ints(|r| r.trade_count),
+        ],
+    )
+}
+
+fn audit_log_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("event_type", DataType::Utf8, false),
+        Field::new("entity_type", DataType::Utf8, false),
+        Field::new("entity_id", DataType::Utf8, false),
+        Field::new("details", DataType::Utf8, true),
+        Field::new("timestamp", DataType::Utf8, false),
+    ]))
+}
+
+fn audit_log_batch(rows: &[AuditLogRecord]) -> Result<RecordBatch, ArrowError> {
+    let strings = |f: fn(&AuditLogRecord) -> &str| -> ArrayRef { Arc::new(StringArray::from_iter_values(rows.iter().map(f))) };
+    RecordBatch::try_new(
+        audit_log_schema(),
+        vec![
+            Arc::new(Int64Array::from_iter_values(rows.iter().map(|r| r.id))),
+            strings(|r| r.event_type.as_str()),
+            strings(|r| r.entity_type.as_str()),
+            strings(|r| r.entity_id.as_str()),
+            Arc::new(rows.iter().map(|r| r.details.as_deref()).collect::<StringArray>()),
+            strings(|r| r.timestamp.as_str()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use axum::body::Bytes;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use crate::db::models::{ExecutionRecord, OrderRecord};
+    use crate::db::repository::{AuditLogRepository, ExecutionRepository, OrderRepository, RiskLimitRepository};
+
+    /// 2023-11-14 00:00:00 UTC
+    const TODAY: u64 = 1_699_920_000;
+
+    /// 메모리 저장소 (`corrupt`면 HEAD에 다른 SHA-256을 돌려줌)
+    #[derive(Default)]
+    struct MemoryStore {
+        objects: Mutex<HashMap<String, (Vec<u8>, String)>>,
+        corrupt: bool,
+    }
+
+    #[async_trait]
+    impl ObjectStore for MemoryStore {
+        async fn put(&self, key: &str, body: Vec<u8>, sha256: &str, _content_type: &str) -> Result<(), ObjectStorageError> {
+            self.objects.lock().unwrap().insert(key.to_string(), (body, sha256.to_string()));
+            Ok(())
+        }
+
+        async fn head(&self, key: &str) -> Result<Option<ObjectMeta>, ObjectStorageError> {
+            Ok(self.objects.lock().unwrap().get(key).map(|(body, sha256)| ObjectMeta {
+                size: body.len() as u64,
+                sha256: Some(if self.corrupt { "0".repeat(64) } else { sha256.clone() }),
+            }))
+        }
+    }
+
+    async fn seeded_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        super::super::create_tables(&pool).await.unwrap();
+
+        let orders = OrderRepository::new(pool.clone());
+        for (order_id, client_id) in [("a1", "alice"), ("b1", "bob")] {
+            orders
+                .save(&OrderRecord {
+                    order_id: order_id.to_string(),
+                    client_id: client_id.to_string(),
+                    symbol: "BTC-KRW".to_string(),
+                    side: "Buy".to_string(),
+                    order_type: "Limit".to_string(),
+                    price: Some(100),
+                    quantity: 10,
+                    filled_quantity: 0,
+                    status: "Filled".to_string(),
+                    tag: None,
+                })
+                .await
+                .unwrap();
+        }
+        // 사흘 전 체결 3건(1분봉 2개), 오늘 체결 1건 (아직 마감 전)
+        let executions = ExecutionRepository::new(pool.clone());
+        let old_day = TODAY - 3 * DAY_SECS;
+        for (exec_id, price, time) in [("e1", 100, old_day + 5), ("e2", 105, old_day + 30), ("e3", 99, old_day + 65), ("e4", 101, TODAY + 10)] {
+            executions
+                .save(&ExecutionRecord {
+                    exec_id: exec_id.to_string(),
+                    taker_order_id: "a1".to_string(),
+                    maker_order_id: "b1".to_string(),
+                    symbol: "BTC-KRW".to_string(),
+                    side: "Buy".to_string(),
+                    price,
+                    quantity: 2,
+                    taker_fee: 0,
+                    maker_fee: 0,
+                    transaction_time: time as i64,
+                })
+                .await
+                .unwrap();
+        }
+        let audit = AuditLogRepository::new(pool.clone());
+        audit.log("ORDER_CREATED", "ORDER", "a1", Some("{}")).await.unwrap();
+        sqlx::query("UPDATE audit_logs SET timestamp = datetime(?, 'unixepoch')")
+            .bind((old_day + 3600) as i64)
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    fn row_count(body: &[u8]) -> usize {
+        ParquetRecordBatchReaderBuilder::try_new(Bytes::copy_from_slice(body))
+            .unwrap()
+            .build()
+            .unwrap()
+            .map(|batch| batch.unwrap().num_rows())
+            .sum()
+    }
+
+    #[tokio::test]
+    async fn test_archives_closed_days_and_prunes_after_upload() {
+        let pool = seeded_pool().await;
+        let store = Arc::new(MemoryStore::default());
+        let mut config = ArchiveConfig::new(S3Config::new("http://localhost:9000", "archive", "a", "s"));
+        config.prune = true;
+        let archiver = Archiver::new(pool.clone(), store.clone(), config);
+        let positions_before = RiskLimitRepository::new(pool.clone()).net_positions().await.unwrap();
+
+        let report = archiver.run(TODAY + 600).await.unwrap();
+        assert_eq!((report.days_archived, report.objects_uploaded, report.rows_archived), (1, 3, 3 + 2 + 1));
+        assert_eq!((report.days_pruned, report.executions_pruned, report.audit_logs_pruned), (1, 3, 1));
+
+        {
+            let objects = store.objects.lock().unwrap();
+            let (body, _) = &objects["xtrader/executions/date=2023-11-11/executions-2023-11-11.parquet"];
+            assert_eq!(row_count(body), 3);
+            let (body, _) = &objects["xtrader/candles_1m/date=2023-11-11/candles_1m-2023-11-11.parquet"];
+            assert_eq!(row_count(body), 2);
+            assert!(objects.contains_key("xtrader/audit_logs/date=2023-11-11/audit_logs-2023-11-11.parquet"));
+        }
+        let manifest = archiver.repository.manifest((TODAY - 3 * DAY_SECS) as i64).await.unwrap();
+        assert_eq!(manifest.len(), 3);
+
+        // 오늘 체결은 남고, 정리한 체결분은 순포지션 이월로 유지
+        let positions_after = RiskLimitRepository::new(pool.clone()).net_positions().await.unwrap();
+        let totals = |records: &[crate::db::models::NetPositionRecord]| records.iter().map(|p| p.net_quantity.abs()).sum::<i64>();
+        assert_eq!(totals(&positions_before), totals(&positions_after));
+        assert_eq!(archiver.run(TODAY + 600).await.unwrap(), ArchiveReport::default());
+    }
+
+    #[tokio::test]
+    async fn test_failed_verification_keeps_local_rows() {
+        let pool = seeded_pool().await;
+        let store = Arc::new(MemoryStore { corrupt: true, ..Default::default() });
+        let mut config = ArchiveConfig::new(S3Config::new("http://localhost:9000", "archive", "a", "s"));
+        config.prune = true;
+        let archiver = Archiver::new(pool.clone(), store, config);
+
+        assert!(matches!(archiver.run(TODAY + 600).await, Err(ArchiveError::Integrity { .. })));
+        assert!(archiver.repository.manifest((TODAY - 3 * DAY_SECS) as i64).await.unwrap().is_empty());
+        assert_eq!(ExecutionRepository::new(pool).find_all().await.unwrap().len(), 4);
+    }
+}
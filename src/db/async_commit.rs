@@ -4,6 +4,8 @@
 //! - 메모리 우선: 체결은 즉시 메모리에서 완료
 //! - 비동기 저장: DB 저장은 백그라운드에서 배치 처리
 //! - 배치 최적화: 여러 체결을 하나의 트랜잭션으로 묶어 처리
+//! - 주문 상태: 신규 주문과 상태 전이도 같은 큐에서 순서대로 기록
+//! - 계정별 이벤트: WebSocket 재연결 재전송용 주문/체결 이벤트
 
 use std::sync::Arc;
 use std::collections::VecDeque;
@@ -12,8 +14,35 @@ use tokio::time::{Duration, interval};
 use sqlx::sqlite::SqlitePool;
 use log::{debug, error, info, warn};
 
-use crate::db::models::ExecutionRecord;
-use crate::db::repository::ExecutionRepository;
+use crate::db::models::{ExecutionRecord, OrderRecord, PrivateEventRecord};
+
+/// 커밋 대기 작업 (큐 순서대로 기록)
+#[derive(Debug, Clone)]
+pub enum CommitTask {
+    /// 체결 내역
+    Execution(ExecutionRecord),
+    /// 신규 주문
+    NewOrder(OrderRecord),
+    /// 주문 상태 전이
+    OrderStatus {
+        order_id: String,
+        filled_quantity: i64,
+        status: String,
+    },
+    /// 계정별 주문/체결 이벤트
+    PrivateEvent(PrivateEventRecord),
+}
+
+impl CommitTask {
+    fn key(&self) -> &str {
+        match self {
+            CommitTask::Execution(execution) => &execution.exec_id,
+            CommitTask::NewOrder(order) => &order.order_id,
+            CommitTask::OrderStatus { order_id, .. } => order_id,
+            CommitTask::PrivateEvent(event) => &event.client_id,
+        }
+    }
+}
 
 /// 비동기 커밋 매니저
 ///
@@ -21,7 +50,7 @@ use crate::db::repository::ExecutionRepository;
 /// 배치 커밋을 통해 DB 트랜잭션 오버헤드를 줄입니다.
 pub struct AsyncCommitManager {
     /// 커밋 대기 큐
-    commit_queue: Arc<Mutex<VecDeque<ExecutionRecord>>>,
+    commit_queue: Arc<Mutex<VecDeque<CommitTask>>>,
     /// 데이터베이스 풀
     db_pool: SqlitePool,
     /// 배치 크기 (한 번에 커밋할 최대 개수)
@@ -59,9 +88,33 @@ impl AsyncCommitManager {
 
     /// 체결 내역을 큐에 추가 (비차단)
     pub async fn enqueue(&self, execution: ExecutionRecord) {
+        self.enqueue_task(CommitTask::Execution(execution)).await;
+    }
+
+    /// 신규 주문을 큐에 추가 (비차단)
+    pub async fn enqueue_order(&self, order: OrderRecord) {
+        self.enqueue_task(CommitTask::NewOrder(order)).await;
+    }
+
+    /// 주문 상태 전이를 큐에 추가 (비차단)
+    pub async fn enqueue_order_status(&self, order_id: &str, filled_quantity: i64, status: &str) {
+        self.enqueue_task(CommitTask::OrderStatus {
+            order_id: order_id.to_string(),
+            filled_quantity,
+            status: status.to_string(),
+        })
+        .await;
+    }
+
+    /// 계정별 이벤트를 큐에 추가 (비차단)
+    pub async fn enqueue_private_event(&self, event: PrivateEventRecord) {
+        self.enqueue_task(CommitTask::PrivateEvent(event)).await;
+    }
+
+    async fn enqueue_task(&self, task: CommitTask) {
         let mut queue = self.commit_queue.lock().await;
-        queue.push_back(execution);
-        debug!("체결 내역 큐에 추가 (큐 크기: {})", queue.len());
+        queue.push_back(task);
+        debug!("커밋 작업 큐에 추가 (큐 크기: {})", queue.len());
     }
 
     /// 배치 커밋 루프 실행 (백그라운드 태스크)
@@ -93,42 +146,77 @@ impl AsyncCommitManager {
     }
 
     /// 배치 커밋 실행
-    async fn commit_batch(&self, batch: Vec<ExecutionRecord>) -> Result<(), sqlx::Error> {
+    async fn commit_batch(&self, batch: Vec<CommitTask>) -> Result<(), sqlx::Error> {
         let batch_size = batch.len();
         debug!("배치 커밋 시작: {} 건", batch_size);
 
-        let exec_repo = ExecutionRepository::new(self.db_pool.clone());
-
-        // 단일 트랜잭션으로 모든 체결 저장
+        // 단일 트랜잭션으로 모든 작업 저장
         let mut tx = self.db_pool.begin().await?;
 
         let mut success_count = 0;
         let mut fail_count = 0;
 
-        for execution in batch {
-            // INSERT OR REPLACE 사용으로 중복 처리
-            let result = sqlx::query(
-                "INSERT OR REPLACE INTO executions
-                 (exec_id, taker_order_id, maker_order_id, symbol, side, price, quantity, taker_fee, maker_fee, transaction_time)
-                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
-            )
-            .bind(&execution.exec_id)
-            .bind(&execution.taker_order_id)
-            .bind(&execution.maker_order_id)
-            .bind(&execution.symbol)
-            .bind(&execution.side)
-            .bind(execution.price)
-            .bind(execution.quantity)
-            .bind(execution.taker_fee)
-            .bind(execution.maker_fee)
-            .bind(execution.transaction_time)
-            .execute(&mut *tx)
-            .await;
+        for task in batch {
+            let result = match &task {
+                // INSERT OR REPLACE 사용으로 중복 처리
+                CommitTask::Execution(execution) => sqlx::query(
+                    "INSERT OR REPLACE INTO executions
+                     (exec_id, taker_order_id, maker_order_id, symbol, side, price, quantity, taker_fee, maker_fee, transaction_time)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+                )
+                .bind(&execution.exec_id)
+                .bind(&execution.taker_order_id)
+                .bind(&execution.maker_order_id)
+                .bind(&execution.symbol)
+                .bind(&execution.side)
+                .bind(execution.price)
+                .bind(execution.quantity)
+                .bind(execution.taker_fee)
+                .bind(execution.maker_fee)
+                .bind(execution.transaction_time)
+                .execute(&mut *tx)
+                .await,
+                CommitTask::NewOrder(order) => sqlx::query(
+                    "INSERT OR REPLACE INTO orders
+                     (order_id, client_id, symbol, side, order_type, price, quantity, filled_quantity, status, tag)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+                )
+                .bind(&order.order_id)
+                .bind(&order.client_id)
+                .bind(&order.symbol)
+                .bind(&order.side)
+                .bind(&order.order_type)
+                .bind(order.price)
+                .bind(order.quantity)
+                .bind(order.filled_quantity)
+                .bind(&order.status)
+                .bind(&order.tag)
+                .execute(&mut *tx)
+                .await,
+                CommitTask::OrderStatus { order_id, filled_quantity, status } => sqlx::query(
+                    "UPDATE orders
+                     SET filled_quantity = ?, status = ?, updated_at = CURRENT_TIMESTAMP
+                     WHERE order_id = ?"
+                )
+                .bind(filled_quantity)
+                .bind(status)
+                .bind(order_id)
+                .execute(&mut *tx)
+                .await,
+                CommitTask::PrivateEvent(event) => sqlx::query(
+                    "INSERT OR REPLACE INTO private_events (client_id, sequence, payload) VALUES (?, ?, ?)"
+                )
+                .bind(&event.client_id)
+                .bind(event.sequence)
+                .bind(&event.payload)
+                .execute(&mut *tx)
+                .await,
+            };
 
             match result {
                 Ok(_) => success_count += 1,
                 Err(e) => {
-                    warn!("체결 내역 저장 실패 - {}: {}", execution.exec_id, e);
+                    warn!("커밋 작업 저장 실패 - {}: {}", task.key(), e);
                     fail_count += 1;
                 }
             }
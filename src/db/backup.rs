@@ -0,0 +1,352 @@
+//! SQLite 온라인 백업과 복원
+//!
+//! 서버를 멈추지 않고 SQLite 백업 API(`sqlite3_backup_*`)로 DB 전체를 파일 하나에 복사합니다.
+//! 복사 직전에 비동기 커밋 큐를 비워 그때까지 처리된 주문/체결이 스냅샷에 들어가게 하고,
+//! 복제 저널 순번과 파일 SHA-256을 같은 이름의 `.json` 메타데이터로 남깁니다.
+//! 복원은 서버 시작 전에 백업 파일을 빈 DB로 되돌려 쓰는 방식이며, 복원한 인스턴스는
+//! 기록된 순번 다음부터 저널을 다시 받아 적용합니다 (docs/api.md "DB 백업과 복원").
+
+use std::ffi::{c_int, CStr, CString};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use libsqlite3_sys as ffi;
+use log::info;
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqliteConnection, SqlitePool};
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+
+use super::async_commit::AsyncCommitManager;
+use super::models::OrderRecord;
+use super::repository::OrderRepository;
+use crate::external::object_storage::sha256_hex;
+use crate::matching_engine::model::{Order, OrderType, Side};
+
+/// 다른 연결이 쓰는 중이라 복사하지 못했을 때 다시 시도하는 횟수
+const COPY_ATTEMPTS: u32 = 50;
+const COPY_RETRY_DELAY: Duration = Duration::from_millis(20);
+
+/// 백업 메타데이터 (백업 파일 옆 `.json`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BackupMetadata {
+    /// 백업 파일 이름 (메타데이터와 같은 디렉터리)
+    pub file: String,
+    /// 생성 시각 (Unix 밀리초)
+    pub created_at: i64,
+    /// 복사 직전 복제 저널 순번 (복원 후 이 다음 순번부터 재생)
+    pub journal_seq: u64,
+    pub size_bytes: u64,
+    /// 백업 파일 SHA-256 (16진수)
+    pub sha256: String,
+}
+
+/// 백업/복원 오류
+#[derive(Debug, thiserror::Error)]
+pub enum BackupError {
+    #[error("DB 오류: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("SQLite 백업 실패 (코드 {code}): {message}")]
+    Sqlite { code: i32, message: String },
+    #[error("파일 오류: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("메타데이터 오류: {0}")]
+    Metadata(#[from] serde_json::Error),
+    #[error("백업 파일 손상 ({file}): SHA-256 {actual}, 기록값 {expected}")]
+    Checksum { file: String, expected: String, actual: String },
+}
+
+/// DB 백업 관리자
+pub struct BackupManager {
+    pool: SqlitePool,
+    commit_mgr: Arc<AsyncCommitManager>,
+    dir: PathBuf,
+    /// 동시에 한 백업만 진행
+    running: Mutex<()>,
+}
+
+impl BackupManager {
+    pub fn new(pool: SqlitePool, commit_mgr: Arc<AsyncCommitManager>, dir: impl Into<PathBuf>) -> Self {
+        Self { pool, commit_mgr, dir: dir.into(), running: Mutex::new(()) }
+    }
+
+    /// 백업 디렉터리
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 백업 생성
+    ///
+    /// `journal_seq`는 커밋 큐를 비우기 전에 읽은 복제 저널 순번이어야 합니다.
+    /// 그래야 그 순번 이후 주문만 복원 뒤 다시 재생할 대상이 됩니다.
+    pub async fn create(&self, journal_seq: u64) -> Result<BackupMetadata, BackupError> {
+        let _running = self.running.lock().await;
+        tokio::fs::create_dir_all(&self.dir).await?;
+        self.commit_mgr.flush().await?;
+
+        let created_at = Utc::now();
+        let file = format!("xtrader-{}-{}.db", created_at.format("%Y%m%dT%H%M%S%3fZ"), journal_seq);
+        let path = self.dir.join(&file);
+        // 복사가 끝난 파일만 백업 이름을 갖도록 임시 이름으로 쓴 뒤 바꿈
+        let partial = path.with_extension("db.partial");
+        if tokio::fs::try_exists(&partial).await? {
+            tokio::fs::remove_file(&partial).await?;
+        }
+        {
+            let mut conn = self.pool.acquire().await?;
+            copy_database(&mut conn, &partial, CopyDirection::ToFile).await?;
+        }
+        let body = tokio::fs::read(&partial).await?;
+        tokio::fs::rename(&partial, &path).await?;
+
+        let metadata = BackupMetadata {
+            file,
+            created_at: created_at.timestamp_millis(),
+            journal_seq,
+            size_bytes: body.len() as u64,
+            sha256: sha256_hex(&body),
+        };
+        tokio::fs::write(metadata_path(&path), serde_json::to_vec_pretty(&metadata)?).await?;
+        info!("DB 백업 완료: {} ({} bytes, 저널 순번 {})", path.display(), metadata.size_bytes, journal_seq);
+        Ok(metadata)
+    }
+
+    /// 백업 목록 (최근 것부터, 디렉터리가 없으면 빈 목록)
+    pub async fn list(&self) -> Result<Vec<BackupMetadata>, BackupError> {
+        let mut entries = match tokio::fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut backups = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                backups.push(serde_json::from_slice::<BackupMetadata>(&tokio::fs::read(&path).await?)?);
+            }
+        }
+        backups.sort_by_key(|backup| std::cmp::Reverse(backup.created_at));
+        Ok(backups)
+    }
+}
+
+/// 백업 파일의 메타데이터 경로 (`xtrader-...db` → `xtrader-...json`)
+pub fn metadata_path(backup: &Path) -> PathBuf {
+    backup.with_extension("json")
+}
+
+/// 백업 파일을 `pool` DB로 복원
+///
+/// 메타데이터의 SHA-256으로 파일을 확인한 뒤 DB 전체를 백업 내용으로 바꾸고,
+/// 백업 이후 추가된 테이블을 만듭니다. 다른 연결이 DB를 쓰지 않는 서버 시작 전에 호출합니다.
+pub async fn restore(pool: &SqlitePool, backup: &Path) -> Result<BackupMetadata, BackupError> {
+    let metadata: BackupMetadata = serde_json::from_slice(&tokio::fs::read(metadata_path(backup)).await?)?;
+    let actual = sha256_hex(&tokio::fs::read(backup).await?);
+    if actual != metadata.sha256 {
+        return Err(BackupError::Checksum { file: backup.display().to_string(), expected: metadata.sha256, actual });
+    }
+
+    {
+        let mut conn = pool.acquire().await?;
+        copy_database(&mut conn, backup, CopyDirection::FromFile).await?;
+    }
+    super::create_tables(pool).await?;
+    info!("DB 복원 완료: {} (저널 순번 {})", backup.display(), metadata.journal_seq);
+    Ok(metadata)
+}
+
+/// 복원한 DB에서 주문장에 남아 있던 지정가 주문 (접수 순서)
+///
+/// 만료 시각과 시장가 보호 설정은 주문 테이블에 없으므로 복원되지 않습니다.
+pub async fn resting_orders(pool: &SqlitePool) -> Result<Vec<Order>, BackupError> {
+    let records = OrderRepository::new(pool.clone()).find_resting().await?;
+    Ok(records.iter().filter_map(resting_order).collect())
+}
+
+fn resting_order(record: &OrderRecord) -> Option<Order> {
+    let side = match record.side.as_str() {
+        "Buy" => Side::Buy,
+        "Sell" => Side::Sell,
+        _ => return None,
+    };
+    let price = record.price.filter(|_| record.order_type == "Limit")?;
+    let mut order = Order::new(
+        record.order_id.clone(),
+        record.symbol.clone(),
+        side,
+        OrderType::Limit,
+        price as u64,
+        record.quantity as u64,
+        record.client_id.clone(),
+    );
+    order.remaining_quantity = (record.quantity - record.filled_quantity).max(0) as u64;
+    order.tag = record.tag.clone();
+    (order.remaining_quantity > 0).then_some(order)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CopyDirection {
+    /// 풀 DB → 파일 (백업)
+    ToFile,
+    /// 파일 → 풀 DB (복원)
+    FromFile,
+}
+
+/// 풀 연결의 DB와 파일 사이 전체 복사
+///
+/// 한 단계에 모든 페이지를 복사하므로 복사 대상에는 원본의 한 시점 상태가 그대로 들어갑니다.
+/// 다른 연결이 쓰는 중이면 잠시 뒤 다시 시도합니다.
+async fn copy_database(conn: &mut SqliteConnection, file: &Path, direction: CopyDirection) -> Result<(), BackupError> {
+    let path = CString::new(file.to_string_lossy().as_bytes())
+        .map_err(|_| BackupError::Sqlite { code: ffi::SQLITE_MISUSE, message: format!("잘못된 파일 경로: {}", file.display()) })?;
+    for _ in 0..COPY_ATTEMPTS {
+        let copied = {
+            let mut handle = conn.lock_handle().await?;
+            let db = handle.as_raw_handle().as_ptr();
+            let file_db = FileDatabase::open(&path, direction)?;
+            // SAFETY: 두 연결 모두 이 블록이 끝날 때까지 다른 곳에서 쓰이지 않음 (풀 연결은 잠금 보유)
+            unsafe {
+                match direction {
+                    CopyDirection::ToFile => copy_all(file_db.0, db)?,
+                    CopyDirection::FromFile => copy_all(db, file_db.0)?,
+                }
+            }
+        };
+        if copied {
+            return Ok(());
+        }
+        tokio::time::sleep(COPY_RETRY_DELAY).await;
+    }
+    Err(BackupError::Sqlite { code: ffi::SQLITE_BUSY, message: "DB가 계속 잠겨 있어 복사하지 못했습니다".to_string() })
+}
+
+/// 백업 파일 연결 (블록을 벗어나면 닫음)
+struct FileDatabase(*mut ffi::sqlite3);
+
+impl FileDatabase {
+    fn open(path: &CStr, direction: CopyDirection) -> Result<Self, BackupError> {
+        let flags = match direction {
+            CopyDirection::ToFile => ffi::SQLITE_OPEN_READWRITE | ffi::SQLITE_OPEN_CREATE,
+            CopyDirection::FromFile => ffi::SQLITE_OPEN_READONLY,
+        };
+        let mut db = std::ptr::null_mut();
+        // SAFETY: 실패해도 핸들이 만들어질 수 있으므로 항상 FileDatabase로 감싸 닫음
+        let code = unsafe { ffi::sqlite3_open_v2(path.as_ptr(), &mut db, flags, std::ptr::null()) };
+        let file_db = FileDatabase(db);
+        if code != ffi::SQLITE_OK {
+            return Err(sqlite_error(code, db));
+        }
+        Ok(file_db)
+    }
+}
+
+impl Drop for FileDatabase {
+    fn drop(&mut self) {
+        // SAFETY: sqlite3_open_v2가 준 핸들이며 백업 객체는 이미 정리됨
+        unsafe {
+            ffi::sqlite3_close(self.0);
+        }
+    }
+}
+
+/// `src` 전체를 `dst`로 복사, 잠겨 있어 복사하지 못했으면 false
+unsafe fn copy_all(dst: *mut ffi::sqlite3, src: *mut ffi::sqlite3) -> Result<bool, BackupError> {
+    let main = c"main";
+    let backup = ffi::sqlite3_backup_init(dst, main.as_ptr(), src, main.as_ptr());
+    if backup.is_null() {
+        return Err(sqlite_error(ffi::sqlite3_errcode(dst), dst));
+    }
+    let step = ffi::sqlite3_backup_step(backup, -1);
+    let finish = ffi::sqlite3_backup_finish(backup);
+    match step {
+        ffi::SQLITE_DONE if finish == ffi::SQLITE_OK => Ok(true),
+        ffi::SQLITE_BUSY | ffi::SQLITE_LOCKED => Ok(false),
+        ffi::SQLITE_DONE => Err(sqlite_error(finish, dst)),
+        code => Err(sqlite_error(code, dst)),
+    }
+}
+
+fn sqlite_error(code: c_int, db: *mut ffi::sqlite3) -> BackupError {
+    // SAFETY: 반환 문자열은 SQLite가 소유하며 바로 복사함
+    let message = unsafe {
+        let message = if db.is_null() { ffi::sqlite3_errstr(code) } else { ffi::sqlite3_errmsg(db) };
+        CStr::from_ptr(message).to_string_lossy().into_owned()
+    };
+    BackupError::Sqlite { code, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn memory_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        super::super::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    fn order(order_id: &str, side: &str, filled_quantity: i64, status: &str) -> OrderRecord {
+        OrderRecord {
+            order_id: order_id.to_string(),
+            client_id: "alice".to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: side.to_string(),
+            order_type: "Limit".to_string(),
+            price: Some(100),
+            quantity: 10,
+            filled_quantity,
+            status: status.to_string(),
+            tag: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backup_restores_into_empty_database() {
+        let dir = std::env::temp_dir().join(format!("xtrader-backup-{}", uuid::Uuid::new_v4()));
+        let pool = memory_pool().await;
+        let orders = OrderRepository::new(pool.clone());
+        orders.save(&order("o1", "Buy", 4, "PartiallyFilled")).await.unwrap();
+        orders.save(&order("o2", "Sell", 10, "Filled")).await.unwrap();
+
+        let manager = BackupManager::new(pool.clone(), Arc::new(AsyncCommitManager::new(pool.clone())), &dir);
+        let metadata = manager.create(42).await.unwrap();
+        assert_eq!(metadata.journal_seq, 42);
+        assert_eq!(manager.list().await.unwrap(), vec![metadata.clone()]);
+
+        // 백업 이후 변경은 복원본에 없음
+        orders.save(&order("o3", "Buy", 0, "New")).await.unwrap();
+
+        let restored = memory_pool().await;
+        assert_eq!(restore(&restored, &dir.join(&metadata.file)).await.unwrap(), metadata);
+        let resting = resting_orders(&restored).await.unwrap();
+        assert_eq!(resting.len(), 1);
+        assert_eq!((resting[0].id.as_str(), resting[0].remaining_quantity), ("o1", 6));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_corrupted_backup() {
+        let dir = std::env::temp_dir().join(format!("xtrader-backup-{}", uuid::Uuid::new_v4()));
+        let pool = memory_pool().await;
+        let manager = BackupManager::new(pool.clone(), Arc::new(AsyncCommitManager::new(pool.clone())), &dir);
+        let metadata = manager.create(0).await.unwrap();
+
+        let path = dir.join(&metadata.file);
+        let mut body = std::fs::read(&path).unwrap();
+        body[100] ^= 0xff;
+        std::fs::write(&path, body).unwrap();
+
+        let result = restore(&memory_pool().await, &path).await;
+        assert!(matches!(result, Err(BackupError::Checksum { .. })));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
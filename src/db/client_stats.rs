@@ -0,0 +1,314 @@
+//! 계정별 거래 통계
+//!
+//! executions, orders 테이블에서 계정(`client_id`)별 메이커/테이커 거래대금, 주문 대비 체결 비율,
+//! 취소율을 최근 24시간/7일 구간으로 집계합니다. 수수료 등급 산정과 이상 거래 탐지에 씁니다.
+//! 심볼마다 호가 자산이 다르므로 거래대금은 보고 통화 최소 단위로 환산해 합칩니다.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqlitePool;
+use sqlx::Error as SqlxError;
+use utoipa::ToSchema;
+
+use super::models::{ClientOrderCountRecord, ClientVolumeRecord};
+use super::repository::ClientStatsRepository;
+
+/// 집계 구간
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum StatsWindow {
+    #[serde(rename = "24h")]
+    Day,
+    #[serde(rename = "7d")]
+    Week,
+}
+
+impl StatsWindow {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "24h" => Some(StatsWindow::Day),
+            "7d" => Some(StatsWindow::Week),
+            _ => None,
+        }
+    }
+
+    /// 구간 길이 (초)
+    pub fn secs(&self) -> u64 {
+        match self {
+            StatsWindow::Day => 24 * 60 * 60,
+            StatsWindow::Week => 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+/// 한 구간의 계정 거래 통계
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+pub struct ClientWindowStats {
+    /// 메이커 거래대금 (보고 통화 최소 단위)
+    pub maker_volume: u64,
+    /// 테이커 거래대금 (보고 통화 최소 단위)
+    pub taker_volume: u64,
+    pub maker_trades: u64,
+    pub taker_trades: u64,
+    /// 구간 내 접수 주문 수
+    pub orders: u64,
+    /// 구간 내 접수 후 취소된 주문 수
+    pub canceled_orders: u64,
+    /// 주문 수 ÷ 체결 수 (체결이 없으면 null)
+    pub order_to_trade_ratio: Option<f64>,
+    /// 취소 주문 수 ÷ 주문 수 (주문이 없으면 null)
+    pub cancel_rate: Option<f64>,
+}
+
+impl ClientWindowStats {
+    pub fn total_volume(&self) -> u64 {
+        self.maker_volume + self.taker_volume
+    }
+
+    fn finish(&mut self) {
+        let trades = self.maker_trades + self.taker_trades;
+        self.order_to_trade_ratio = (trades > 0).then(|| self.orders as f64 / trades as f64);
+        self.cancel_rate = (self.orders > 0).then(|| self.canceled_orders as f64 / self.orders as f64);
+    }
+}
+
+/// 계정 거래 통계 (24시간, 7일)
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ClientTradingStats {
+    pub client_id: String,
+    pub last_24h: ClientWindowStats,
+    pub last_7d: ClientWindowStats,
+}
+
+impl ClientTradingStats {
+    pub fn window(&self, window: StatsWindow) -> &ClientWindowStats {
+        match window {
+            StatsWindow::Day => &self.last_24h,
+            StatsWindow::Week => &self.last_7d,
+        }
+    }
+}
+
+/// 구간 집계 결과
+#[derive(Debug, Default)]
+struct WindowAggregate {
+    clients: BTreeMap<String, ClientWindowStats>,
+    /// 보고 통화로 환산하지 못해 거래대금에서 뺀 심볼
+    unpriced_symbols: BTreeSet<String>,
+}
+
+/// DB 집계 행을 계정별 통계로 합침 (`to_reporting`: 심볼, 호가 자산 금액 → 보고 통화 금액)
+fn aggregate(
+    volumes: Vec<ClientVolumeRecord>,
+    order_counts: Vec<ClientOrderCountRecord>,
+    to_reporting: &(dyn Fn(&str, u64) -> Option<u64> + Sync),
+) -> WindowAggregate {
+    let mut result = WindowAggregate::default();
+
+    for record in volumes {
+        let stats = result.clients.entry(record.client_id).or_default();
+        stats.maker_trades += record.maker_trades.max(0) as u64;
+        stats.taker_trades += record.taker_trades.max(0) as u64;
+        let maker = to_reporting(&record.symbol, record.maker_notional.max(0) as u64);
+        let taker = to_reporting(&record.symbol, record.taker_notional.max(0) as u64);
+        match maker.zip(taker) {
+            Some((maker, taker)) => {
+                stats.maker_volume += maker;
+                stats.taker_volume += taker;
+            }
+            None => {
+                result.unpriced_symbols.insert(record.symbol);
+            }
+        }
+    }
+    for record in order_counts {
+        let stats = result.clients.entry(record.client_id).or_default();
+        stats.orders += record.orders.max(0) as u64;
+        stats.canceled_orders += record.canceled_orders.max(0) as u64;
+    }
+    result.clients.values_mut().for_each(ClientWindowStats::finish);
+
+    result
+}
+
+/// 계정별 거래 통계 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientStatsReport {
+    /// 순위 구간의 거래대금 합계 내림차순
+    pub clients: Vec<ClientTradingStats>,
+    /// 보고 통화로 환산하지 못해 거래대금에서 뺀 심볼
+    pub unpriced_symbols: Vec<String>,
+}
+
+/// 계정별 거래 통계 서비스
+pub struct ClientStatsService {
+    repository: ClientStatsRepository,
+}
+
+impl ClientStatsService {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            repository: ClientStatsRepository::new(pool),
+        }
+    }
+
+    /// `now`(초) 기준 24시간/7일 통계, `rank_by` 구간 거래대금 상위 `limit` 계정
+    pub async fn leaderboard(
+        &self,
+        now: u64,
+        rank_by: StatsWindow,
+        limit: usize,
+        to_reporting: &(dyn Fn(&str, u64) -> Option<u64> + Sync),
+    ) -> Result<ClientStatsReport, SqlxError> {
+        let mut windows = Vec::new();
+        for window in [StatsWindow::Day, StatsWindow::Week] {
+            let since = now.saturating_sub(window.secs()) as i64;
+            let volumes = self.repository.volumes_since(since).await?;
+            let order_counts = self.repository.order_counts_since(since).await?;
+            windows.push(aggregate(volumes, order_counts, to_reporting));
+        }
+        let week = windows.pop().unwrap_or_default();
+        let mut day = windows.pop().unwrap_or_default();
+
+        // 7일 구간에 활동한 계정이 24시간 구간 계정을 모두 포함
+        let mut clients: Vec<ClientTradingStats> = week
+            .clients
+            .into_iter()
+            .map(|(client_id, last_7d)| ClientTradingStats {
+                last_24h: day.clients.remove(&client_id).unwrap_or_default(),
+                client_id,
+                last_7d,
+            })
+            .collect();
+        clients.sort_by(|a, b| {
+            b.window(rank_by)
+                .total_volume()
+                .cmp(&a.window(rank_by).total_volume())
+                .then_with(|| a.client_id.cmp(&b.client_id))
+        });
+        clients.truncate(limit);
+
+        let unpriced_symbols = week.unpriced_symbols.union(&day.unpriced_symbols).cloned().collect();
+        Ok(ClientStatsReport { clients, unpriced_symbols })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::{ExecutionRecord, OrderRecord};
+    use crate::db::repository::{ExecutionRepository, OrderRepository};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn order(order_id: &str, client_id: &str, status: &str) -> OrderRecord {
+        OrderRecord {
+            order_id: order_id.to_string(),
+            client_id: client_id.to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: "Buy".to_string(),
+            order_type: "Limit".to_string(),
+            price: Some(100),
+            quantity: 10,
+            filled_quantity: 0,
+            status: status.to_string(),
+            tag: None,
+        }
+    }
+
+    fn execution(exec_id: &str, taker: &str, maker: &str, symbol: &str, quantity: i64, age_secs: u64) -> ExecutionRecord {
+        ExecutionRecord {
+            exec_id: exec_id.to_string(),
+            taker_order_id: taker.to_string(),
+            maker_order_id: maker.to_string(),
+            symbol: symbol.to_string(),
+            side: "Buy".to_string(),
+            price: 100,
+            quantity,
+            taker_fee: 0,
+            maker_fee: 0,
+            transaction_time: (NOW - age_secs) as i64,
+        }
+    }
+
+    #[test]
+    fn test_ratios_and_unpriced_symbols() {
+        let volume = |client_id: &str, symbol: &str, maker_notional, taker_notional, maker_trades, taker_trades| ClientVolumeRecord {
+            client_id: client_id.to_string(),
+            symbol: symbol.to_string(),
+            maker_notional,
+            taker_notional,
+            maker_trades,
+            taker_trades,
+        };
+        let counts = |client_id: &str, orders, canceled_orders| ClientOrderCountRecord {
+            client_id: client_id.to_string(),
+            orders,
+            canceled_orders,
+        };
+        let to_reporting = |symbol: &str, amount: u64| (symbol != "ETH-USD").then_some(amount * 2);
+
+        let result = aggregate(
+            vec![volume("alice", "BTC-KRW", 100, 50, 2, 1), volume("alice", "ETH-USD", 999, 0, 1, 0)],
+            vec![counts("alice", 8, 2), counts("quiet", 4, 4)],
+            &to_reporting,
+        );
+
+        let alice = &result.clients["alice"];
+        assert_eq!((alice.maker_volume, alice.taker_volume), (200, 100));
+        assert_eq!((alice.maker_trades, alice.taker_trades), (3, 1));
+        assert_eq!(alice.order_to_trade_ratio, Some(2.0));
+        assert_eq!(alice.cancel_rate, Some(0.25));
+        // 체결 없이 주문만 낸 계정은 비율 없음
+        assert_eq!(result.clients["quiet"].order_to_trade_ratio, None);
+        assert_eq!(result.clients["quiet"].cancel_rate, Some(1.0));
+        assert_eq!(result.unpriced_symbols.into_iter().collect::<Vec<_>>(), vec!["ETH-USD"]);
+    }
+
+    #[tokio::test]
+    async fn test_leaderboard_splits_maker_and_taker_by_window() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        super::super::create_tables(&pool).await.unwrap();
+
+        let orders = OrderRepository::new(pool.clone());
+        for record in [
+            order("a1", "alice", "Filled"),
+            order("a2", "alice", "Canceled"),
+            order("b1", "bob", "Filled"),
+            order("b2", "bob", "Filled"),
+        ] {
+            orders.save(&record).await.unwrap();
+        }
+        let executions = ExecutionRepository::new(pool.clone());
+        // 최근: bob 테이커, alice 메이커 / 3일 전: alice 테이커, bob 메이커 (수량 0인 취소 보고서는 제외)
+        for record in [
+            execution("e1", "b1", "a1", "BTC-KRW", 3, 60),
+            execution("e2", "a1", "b2", "BTC-KRW", 10, 3 * 24 * 60 * 60),
+            execution("e3", "a2", "system", "BTC-KRW", 0, 60),
+        ] {
+            executions.save(&record).await.unwrap();
+        }
+
+        let service = ClientStatsService::new(pool);
+        let identity = |_: &str, amount: u64| Some(amount);
+        let report = service.leaderboard(NOW, StatsWindow::Week, 10, &identity).await.unwrap();
+
+        let ids: Vec<&str> = report.clients.iter().map(|c| c.client_id.as_str()).collect();
+        assert_eq!(ids, vec!["alice", "bob"]);
+        let alice = &report.clients[0];
+        assert_eq!((alice.last_24h.maker_volume, alice.last_24h.taker_volume), (300, 0));
+        assert_eq!((alice.last_7d.maker_volume, alice.last_7d.taker_volume), (300, 1000));
+        assert_eq!(alice.last_7d.cancel_rate, Some(0.5));
+        assert_eq!(report.clients[1].last_24h.taker_volume, 300);
+
+        // 24시간 기준 순위는 bob과 alice가 같은 거래대금이면 ID순
+        let report = service.leaderboard(NOW, StatsWindow::Day, 1, &identity).await.unwrap();
+        assert_eq!(report.clients.len(), 1);
+        assert_eq!(report.clients[0].client_id, "alice");
+    }
+}
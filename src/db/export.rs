@@ -0,0 +1,362 @@
+//! 체결 내역 내보내기
+//!
+//! DB의 체결 내역을 페이지 단위로 읽어 CSV 또는 Parquet으로 인코딩하고,
+//! 인코딩된 청크를 채널로 넘겨 HTTP 청크 전송으로 스트리밍합니다.
+//! 전체 결과를 메모리에 모으지 않으므로 긴 기간도 일정한 메모리로 내보낼 수 있습니다.
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+use log::error;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use sqlx::sqlite::SqlitePool;
+use tokio::sync::mpsc;
+
+use super::models::ExecutionRecord;
+use super::repository::ExecutionRepository;
+
+/// 페이지(청크)당 기본 행 수
+pub const DEFAULT_EXPORT_CHUNK_ROWS: usize = 10_000;
+
+/// 인코딩된 청크 채널 용량 (느린 클라이언트면 DB 조회도 대기)
+const EXPORT_CHANNEL_CAPACITY: usize = 4;
+
+/// CSV 헤더 (Parquet 컬럼 순서와 동일)
+const TRADE_COLUMNS: [&str; 10] = [
+    "exec_id",
+    "taker_order_id",
+    "maker_order_id",
+    "symbol",
+    "side",
+    "price",
+    "quantity",
+    "taker_fee",
+    "maker_fee",
+    "transaction_time",
+];
+
+/// 내보내기 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Parquet,
+}
+
+impl ExportFormat {
+    /// 쿼리 파라미터 값 파싱 (`csv`, `parquet`)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "parquet" => Some(Self::Parquet),
+            _ => None,
+        }
+    }
+
+    /// 응답 Content-Type
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+
+    /// 파일 확장자
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+/// 체결 내역 내보내기 조건 (`[from, to)`, Unix 타임스탬프 초)
+#[derive(Debug, Clone)]
+pub struct TradeExportQuery {
+    pub symbol: String,
+    pub from: i64,
+    pub to: i64,
+}
+
+/// 내보내기 오류
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("DB 조회 오류: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("CSV 인코딩 오류: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("Arrow 변환 오류: {0}")]
+    Arrow(#[from] ArrowError),
+    #[error("Parquet 인코딩 오류: {0}")]
+    Parquet(#[from] ParquetError),
+}
+
+/// 체결 내역 내보내기 서비스
+#[derive(Debug, Clone)]
+pub struct TradeExportService {
+    pool: SqlitePool,
+    chunk_rows: usize,
+}
+
+impl TradeExportService {
+    /// 새 서비스 생성
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            chunk_rows: DEFAULT_EXPORT_CHUNK_ROWS,
+        }
+    }
+
+    /// 페이지당 행 수 설정
+    pub fn with_chunk_rows(mut self, chunk_rows: usize) -> Self {
+        self.chunk_rows = chunk_rows.max(1);
+        self
+    }
+
+    /// 내보내기 시작 - 인코딩된 청크를 순서대로 받는 채널 반환
+    ///
+    /// 중간에 오류가 나면 오류 하나를 보내고 종료합니다.
+    /// 수신측이 닫히면 (클라이언트 연결 종료) 조회를 멈춥니다.
+    pub fn stream(&self, query: TradeExportQuery, format: ExportFormat) -> mpsc::Receiver<Result<Vec<u8>, ExportError>> {
+        let (tx, rx) = mpsc::channel(EXPORT_CHANNEL_CAPACITY);
+        let repository = ExecutionRepository::new(self.pool.clone());
+        let chunk_rows = self.chunk_rows;
+
+        tokio::spawn(async move {
+            if let Err(e) = export_trades(&repository, &query, format, chunk_rows, &tx).await {
+                error!("체결 내역 내보내기 실패 ({}): {}", query.symbol, e);
+                let _ = tx.send(Err(e)).await;
+            }
+        });
+
+        rx
+    }
+}
+
+/// 페이지 조회 → 인코딩 → 전송 반복
+async fn export_trades(
+    repository: &ExecutionRepository,
+    query: &TradeExportQuery,
+    format: ExportFormat,
+    chunk_rows: usize,
+    tx: &mpsc::Sender<Result<Vec<u8>, ExportError>>,
+) -> Result<(), ExportError> {
+    let mut encoder = TradeEncoder::new(format)?;
+    let mut cursor: Option<(i64, String)> = None;
+
+    loop {
+        let after = cursor.as_ref().map(|(time, id)| (*time, id.as_str()));
+        let rows = repository
+            .find_range_page(&query.symbol, query.from, query.to, after, chunk_rows as i64)
+            .await?;
+
+        if let Some(last) = rows.last() {
+            cursor = Some((last.transaction_time, last.exec_id.clone()));
+            let chunk = encoder.encode(&rows)?;
+            if !chunk.is_empty() && tx.send(Ok(chunk)).await.is_err() {
+                return Ok(());
+            }
+        }
+
+        if rows.len() < chunk_rows {
+            break;
+        }
+    }
+
+    let tail = encoder.finish()?;
+    if !tail.is_empty() {
+        let _ = tx.send(Ok(tail)).await;
+    }
+    Ok(())
+}
+
+/// 형식별 청크 인코더
+enum TradeEncoder {
+    /// CSV (헤더는 첫 청크에 포함)
+    Csv { header_written: bool },
+    /// Parquet (청크마다 행 그룹 하나, 마지막에 푸터)
+    Parquet(Box<ArrowWriter<Vec<u8>>>),
+}
+
+impl TradeEncoder {
+    fn new(format: ExportFormat) -> Result<Self, ExportError> {
+        match format {
+            ExportFormat::Csv => Ok(Self::Csv { header_written: false }),
+            ExportFormat::Parquet => {
+                let props = WriterProperties::builder()
+                    .set_compression(Compression::SNAPPY)
+                    .build();
+                Ok(Self::Parquet(Box::new(ArrowWriter::try_new(Vec::new(), trade_schema(), Some(props))?)))
+            }
+        }
+    }
+
+    /// 한 페이지를 인코딩해 지금 보낼 수 있는 바이트 반환
+    fn encode(&mut self, rows: &[ExecutionRecord]) -> Result<Vec<u8>, ExportError> {
+        match self {
+            Self::Csv { header_written } => {
+                let mut writer = csv::WriterBuilder::new().has_headers(false).from_writer(Vec::new());
+                if !*header_written {
+                    writer.write_record(TRADE_COLUMNS)?;
+                    *header_written = true;
+                }
+                for row in rows {
+                    writer.serialize(row)?;
+                }
+                writer.into_inner().map_err(|e| ExportError::Csv(e.into_error().into()))
+            }
+            Self::Parquet(writer) => {
+                writer.write(&trade_batch(rows)?)?;
+                writer.flush()?;
+                Ok(std::mem::take(writer.inner_mut()))
+            }
+        }
+    }
+
+    /// 남은 바이트 반환 (CSV 헤더만 있는 빈 결과, Parquet 푸터)
+    fn finish(self) -> Result<Vec<u8>, ExportError> {
+        match self {
+            Self::Csv { header_written: true } => Ok(Vec::new()),
+            Self::Csv { header_written: false } => Ok(format!("{}\n", TRADE_COLUMNS.join(",")).into_bytes()),
+            Self::Parquet(writer) => Ok(writer.into_inner()?),
+        }
+    }
+}
+
+/// 체결 내역 Arrow 스키마
+pub(super) fn trade_schema() -> SchemaRef {
+    let fields: Vec<Field> = TRADE_COLUMNS
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let data_type = if i < 5 { DataType::Utf8 } else { DataType::Int64 };
+            Field::new(*name, data_type, false)
+        })
+        .collect();
+    Arc::new(Schema::new(fields))
+}
+
+/// 체결 내역 → Arrow RecordBatch
+pub(super) fn trade_batch(rows: &[ExecutionRecord]) -> Result<RecordBatch, ArrowError> {
+    fn strings(rows: &[ExecutionRecord], f: impl Fn(&ExecutionRecord) -> &str) -> ArrayRef {
+        Arc::new(StringArray::from_iter_values(rows.iter().map(f)))
+    }
+    fn ints(rows: &[ExecutionRecord], f: impl Fn(&ExecutionRecord) -> i64) -> ArrayRef {
+        Arc::new(Int64Array::from_iter_values(rows.iter().map(f)))
+    }
+
+    RecordBatch::try_new(
+        trade_schema(),
+        vec![
+            strings(rows, |r| &r.exec_id),
+            strings(rows, |r| &r.taker_order_id),
+            strings(rows, |r| &r.maker_order_id),
+            strings(rows, |r| &r.symbol),
+            strings(rows, |r| &r.side),
+            ints(rows, |r| r.price),
+            ints(rows, |r| r.quantity),
+            ints(rows, |r| r.taker_fee),
+            ints(rows, |r| r.maker_fee),
+            ints(rows, |r| r.transaction_time),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Bytes;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn seeded_service() -> TradeExportService {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        super::super::create_tables(&pool).await.unwrap();
+
+        let repository = ExecutionRepository::new(pool.clone());
+        for i in 0..25i64 {
+            let symbol = if i % 5 == 0 { "ETH-KRW" } else { "BTC-KRW" };
+            repository
+                .save(&ExecutionRecord {
+                    exec_id: format!("e{:02}", i),
+                    taker_order_id: format!("t{}", i),
+                    maker_order_id: format!("m{}", i),
+                    symbol: symbol.to_string(),
+                    side: "Buy".to_string(),
+                    price: 1000 + i,
+                    quantity: 1,
+                    taker_fee: 0,
+                    maker_fee: 0,
+                    // 같은 시각의 체결이 페이지 경계에 걸치도록 2건씩 같은 시각
+                    transaction_time: 100 + i / 2,
+                })
+                .await
+                .unwrap();
+        }
+
+        TradeExportService::new(pool).with_chunk_rows(3)
+    }
+
+    async fn collect(mut rx: mpsc::Receiver<Result<Vec<u8>, ExportError>>) -> (usize, Vec<u8>) {
+        let mut chunks = 0;
+        let mut bytes = Vec::new();
+        while let Some(chunk) = rx.recv().await {
+            chunks += 1;
+            bytes.extend(chunk.unwrap());
+        }
+        (chunks, bytes)
+    }
+
+    fn query(from: i64, to: i64) -> TradeExportQuery {
+        TradeExportQuery { symbol: "BTC-KRW".to_string(), from, to }
+    }
+
+    #[tokio::test]
+    async fn test_csv_export_pages_through_range() {
+        let service = seeded_service().await;
+
+        // 시각 101~110 (i = 2..=21) 중 BTC-KRW 16건
+        let (chunks, bytes) = collect(service.stream(query(101, 111), ExportFormat::Csv)).await;
+        let text = String::from_utf8(bytes).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert!(chunks > 1);
+        assert_eq!(lines[0], TRADE_COLUMNS.join(","));
+        assert_eq!(lines.len(), 1 + 16);
+        assert!(lines[1].starts_with("e02,"));
+        assert!(lines.last().unwrap().starts_with("e21,"));
+        assert!(lines[1..].iter().all(|line| line.contains(",BTC-KRW,")));
+    }
+
+    #[tokio::test]
+    async fn test_parquet_export_roundtrip() {
+        let service = seeded_service().await;
+
+        let (_, bytes) = collect(service.stream(query(0, i64::MAX), ExportFormat::Parquet)).await;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(bytes)).unwrap().build().unwrap();
+        let batches: Vec<RecordBatch> = reader.map(|b| b.unwrap()).collect();
+
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 20);
+        let prices = batches[0].column(5).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(prices.value(0), 1001);
+    }
+
+    #[tokio::test]
+    async fn test_empty_range() {
+        let service = seeded_service().await;
+
+        let (_, csv) = collect(service.stream(query(500, 600), ExportFormat::Csv)).await;
+        assert_eq!(String::from_utf8(csv).unwrap(), format!("{}\n", TRADE_COLUMNS.join(",")));
+
+        let (_, parquet) = collect(service.stream(query(500, 600), ExportFormat::Parquet)).await;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(parquet)).unwrap().build().unwrap();
+        assert_eq!(reader.count(), 0);
+    }
+}
@@ -0,0 +1,241 @@
+//! 성능 지표 장기 추이 (시간별 롤업)
+//!
+//! `MetricsCollector`는 최근 샘플만 메모리에 보관하므로 실시간 값만 볼 수 있습니다. 수집 작업이 주기적으로
+//! REST 요청 지연 타이머 샘플과 주문 큐 누적 적재 수를 가져가 UTC 정시 단위로 모으고, 끝난 시간만 `metrics_hourly`에 저장합니다.
+//! 대시보드는 `GET /v1/metrics/history`로 시간별 p99 지연, 초당 주문 수, 오류율을 받아 주 단위 성능 추이를 그립니다.
+//! 진행 중인 시간은 메모리에만 있으므로 재시작하면 그 시간은 재시작 이후 값만 남습니다.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use sqlx::sqlite::SqlitePool;
+use tokio::sync::Mutex;
+
+use super::models::MetricsHourlyRecord;
+use super::repository::MetricsHistoryRepository;
+use crate::performance::{MetricsCollector, TimerMetric};
+
+/// 한 시간 (밀리초)
+pub const HOUR_MS: u64 = 60 * 60 * 1000;
+
+/// 한 시간에 보관하는 지연 샘플 상한 (넘으면 백분위수는 앞쪽 샘플로 계산, 요청 수는 모두 셈)
+const MAX_LATENCY_SAMPLES_PER_HOUR: usize = 500_000;
+
+/// 성능 지표 추이 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsHistoryConfig {
+    /// 수집기에서 샘플을 가져가는 주기 (수집기 타이머 보관 개수 안에서 충분히 짧아야 함)
+    pub collect_interval: Duration,
+    /// 시간별 롤업 보관 기간 (일)
+    pub retention_days: u64,
+}
+
+impl Default for MetricsHistoryConfig {
+    fn default() -> Self {
+        Self {
+            collect_interval: Duration::from_secs(60),
+            retention_days: 90,
+        }
+    }
+}
+
+impl MetricsHistoryConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.collect_interval.is_zero() {
+            return Err("성능 지표 수집 주기는 0보다 커야 합니다".to_string());
+        }
+        if self.retention_days == 0 {
+            return Err("성능 지표 보관 기간은 1일 이상이어야 합니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 진행 중인 한 시간의 누적값
+#[derive(Debug, Default)]
+struct HourBucket {
+    requests: u64,
+    errors: u64,
+    latencies: Vec<f64>,
+    orders: u64,
+}
+
+/// 시간별 롤업 누적기 (샘플 시각 기준으로 시간을 나눔)
+#[derive(Debug, Default)]
+pub struct MetricsRollup {
+    hours: BTreeMap<u64, HourBucket>,
+    last_orders_total: Option<u64>,
+}
+
+impl MetricsRollup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// REST 요청 지연 샘플 누적 (`status` 태그가 5xx면 오류)
+    pub fn record_requests(&mut self, samples: &[TimerMetric]) {
+        for sample in samples {
+            let bucket = self.hours.entry(sample.timestamp / HOUR_MS * HOUR_MS).or_default();
+            bucket.requests += 1;
+            let is_error = sample
+                .tags
+                .get("status")
+                .and_then(|status| status.parse::<u16>().ok())
+                .is_some_and(|status| status >= 500);
+            if is_error {
+                bucket.errors += 1;
+            }
+            if bucket.latencies.len() < MAX_LATENCY_SAMPLES_PER_HOUR {
+                bucket.latencies.push(sample.duration_ms);
+            }
+        }
+    }
+
+    /// 주문 큐 누적 적재 수 관측 (직전 관측 이후 증가분을 `now_ms`가 속한 시간에 더함)
+    pub fn record_orders(&mut self, now_ms: u64, orders_total: u64) {
+        let previous = self.last_orders_total.replace(orders_total);
+        let Some(previous) = previous else {
+            return;
+        };
+        self.hours.entry(now_ms / HOUR_MS * HOUR_MS).or_default().orders += orders_total.saturating_sub(previous);
+    }
+
+    /// `now_ms` 이전에 끝난 시간의 롤업을 가져가고 비움 (시간순)
+    pub fn take_completed(&mut self, now_ms: u64) -> Vec<MetricsHourlyRecord> {
+        let current_hour = now_ms / HOUR_MS * HOUR_MS;
+        let pending = self.hours.split_off(&current_hour);
+        std::mem::replace(&mut self.hours, pending)
+            .into_iter()
+            .map(|(hour_start, mut bucket)| {
+                bucket.latencies.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+                MetricsHourlyRecord {
+                    hour_start: hour_start as i64,
+                    requests: bucket.requests as i64,
+                    errors: bucket.errors as i64,
+                    p50_latency_ms: percentile(&bucket.latencies, 50.0),
+                    p99_latency_ms: percentile(&bucket.latencies, 99.0),
+                    max_latency_ms: bucket.latencies.last().copied(),
+                    orders: bucket.orders.min(i64::MAX as u64) as i64,
+                }
+            })
+            .collect()
+    }
+}
+
+/// 정렬된 샘플의 백분위수 (nearest-rank, 샘플이 없으면 None)
+fn percentile(sorted: &[f64], p: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.saturating_sub(1).min(sorted.len() - 1)])
+}
+
+/// 수집 상태 (다음 수집 구간 시작과 누적기)
+struct CollectState {
+    collected_until_ms: Option<u64>,
+    rollup: MetricsRollup,
+}
+
+/// 성능 지표 추이 (수집, 시간별 저장, 조회)
+pub struct MetricsHistoryService {
+    repository: MetricsHistoryRepository,
+    collector: Arc<MetricsCollector>,
+    /// 지연 샘플을 가져올 타이머 이름
+    timer: String,
+    config: MetricsHistoryConfig,
+    state: Mutex<CollectState>,
+}
+
+impl MetricsHistoryService {
+    pub fn new(pool: SqlitePool, collector: Arc<MetricsCollector>, timer: &str, config: MetricsHistoryConfig) -> Self {
+        Self {
+            repository: MetricsHistoryRepository::new(pool),
+            collector,
+            timer: timer.to_string(),
+            config,
+            state: Mutex::new(CollectState {
+                collected_until_ms: None,
+                rollup: MetricsRollup::new(),
+            }),
+        }
+    }
+
+    pub fn config(&self) -> &MetricsHistoryConfig {
+        &self.config
+    }
+
+    /// 직전 수집 이후 샘플을 누적하고 끝난 시간을 저장 (저장한 시간 수)
+    ///
+    /// 첫 수집은 수집기에 남아 있는 샘플 중 현재 시간의 것부터 가져갑니다.
+    pub async fn collect(&self, now_ms: u64, orders_total: u64) -> Result<usize, sqlx::Error> {
+        let mut state = self.state.lock().await;
+        let from_ms = state.collected_until_ms.unwrap_or(now_ms / HOUR_MS * HOUR_MS);
+        let samples = self.collector.get_timer_samples(&self.timer, from_ms, now_ms).await;
+        state.collected_until_ms = Some(now_ms.max(from_ms));
+        state.rollup.record_requests(&samples);
+        state.rollup.record_orders(now_ms, orders_total);
+        let completed = state.rollup.take_completed(now_ms);
+        drop(state);
+
+        if completed.is_empty() {
+            return Ok(0);
+        }
+        self.repository.save(&completed).await?;
+        let retention_ms = self.config.retention_days.saturating_mul(24 * HOUR_MS);
+        self.repository.delete_before(now_ms.saturating_sub(retention_ms) as i64).await?;
+        Ok(completed.len())
+    }
+
+    /// 구간 [from_ms, to_ms) 의 시간별 롤업 (시간순)
+    pub async fn history(&self, from_ms: u64, to_ms: u64) -> Result<Vec<MetricsHourlyRecord>, sqlx::Error> {
+        self.repository.find_between(from_ms as i64, to_ms as i64).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn sample(timestamp: u64, duration_ms: f64, status: &str) -> TimerMetric {
+        TimerMetric {
+            name: "api.request.latency_ms".to_string(),
+            duration_ms,
+            timestamp,
+            tags: HashMap::from([("status".to_string(), status.to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_rollup_splits_samples_by_hour_and_keeps_current_hour() {
+        let mut rollup = MetricsRollup::new();
+        let mut samples: Vec<TimerMetric> = (1..=100).map(|i| sample(HOUR_MS + i, i as f64, "200")).collect();
+        samples.push(sample(HOUR_MS + 200, 500.0, "503"));
+        samples.push(sample(2 * HOUR_MS + 1, 3.0, "200"));
+        rollup.record_requests(&samples);
+        rollup.record_orders(HOUR_MS + 10, 1_000);
+        rollup.record_orders(HOUR_MS + 20, 4_600);
+        rollup.record_orders(2 * HOUR_MS + 5, 4_700);
+
+        // 진행 중인 두 번째 시간은 남겨 둠
+        let completed = rollup.take_completed(2 * HOUR_MS + 10);
+        assert_eq!(
+            completed,
+            vec![MetricsHourlyRecord {
+                hour_start: HOUR_MS as i64,
+                requests: 101,
+                errors: 1,
+                p50_latency_ms: Some(51.0),
+                p99_latency_ms: Some(100.0),
+                max_latency_ms: Some(500.0),
+                orders: 3_600,
+            }]
+        );
+
+        let next = rollup.take_completed(3 * HOUR_MS);
+        assert_eq!((next[0].hour_start, next[0].requests, next[0].orders), (2 * HOUR_MS as i64, 1, 100));
+        assert!(rollup.take_completed(4 * HOUR_MS).is_empty());
+    }
+}
@@ -1,11 +1,25 @@
 pub mod models;
 pub mod repository;
 pub mod async_commit;
+pub mod export;
+pub mod client_stats;
+pub mod rebate;
+pub mod metrics_history;
+pub mod retention;
+pub mod archive;
+pub mod backup;
 
 use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
 use sqlx::Error as SqlxError;
 
-pub use async_commit::{AsyncCommitManager, CommitStats};
+pub use async_commit::{AsyncCommitManager, CommitStats, CommitTask};
+pub use export::{ExportError, ExportFormat, TradeExportQuery, TradeExportService};
+pub use client_stats::{ClientStatsReport, ClientStatsService, ClientTradingStats, ClientWindowStats, StatsWindow};
+pub use rebate::{RebateConfig, RebateError, RebateReport, RebateReportRow, RebateService};
+pub use metrics_history::{MetricsHistoryConfig, MetricsHistoryService, MetricsRollup};
+pub use retention::{RetentionConfig, RetentionJob, RetentionReport};
+pub use archive::{ArchiveConfig, ArchiveDataset, ArchiveError, ArchiveReport, Archiver};
+pub use backup::{BackupError, BackupManager, BackupMetadata};
 
 /// SQLite 데이터베이스 초기화 및 연결
 pub async fn init_database(database_url: &str) -> Result<SqlitePool, SqlxError> {
@@ -25,8 +39,84 @@ pub async fn init_database(database_url: &str) -> Result<SqlitePool, SqlxError>
     Ok(pool)
 }
 
+/// 잔고 테이블 정의
+const BALANCES_TABLE_DDL: &str = "CREATE TABLE IF NOT EXISTS balances (
+            client_id TEXT NOT NULL,
+            asset TEXT NOT NULL,
+            available INTEGER NOT NULL,
+            locked INTEGER DEFAULT 0,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (client_id, asset)
+        )";
+
+/// 예전 잔고 테이블(client_id 단일 키)을 (client_id, asset) 복합 키로 변환
+///
+/// 예전 테이블은 계정당 한 행만 담을 수 있었으므로 기존 행은 그대로 옮깁니다.
+async fn migrate_balances_table(pool: &SqlitePool) -> Result<(), SqlxError> {
+    let asset_in_key: Option<(i64,)> =
+        sqlx::query_as("SELECT pk FROM pragma_table_info('balances') WHERE name = 'asset'")
+            .fetch_optional(pool)
+            .await?;
+    if matches!(asset_in_key, Some((pk,)) if pk > 0) {
+        return Ok(());
+    }
+
+    println!("🔧 잔고 테이블을 (client_id, asset) 복합 키로 변환 중...");
+    let mut tx = pool.begin().await?;
+    sqlx::query("ALTER TABLE balances RENAME TO balances_legacy")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(BALANCES_TABLE_DDL)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        "INSERT INTO balances (client_id, asset, available, locked, updated_at)
+         SELECT client_id, asset, available, locked, updated_at FROM balances_legacy"
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query("DROP TABLE balances_legacy")
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await
+}
+
+/// IP 허용 목록 이전에 만든 API 키 테이블에 `ip_allowlist` 열 추가 (기존 키는 모든 IP 허용)
+async fn migrate_api_keys_ip_allowlist(pool: &SqlitePool) -> Result<(), SqlxError> {
+    let column: Option<(String,)> =
+        sqlx::query_as("SELECT name FROM pragma_table_info('api_keys') WHERE name = 'ip_allowlist'")
+            .fetch_optional(pool)
+            .await?;
+    if column.is_some() {
+        return Ok(());
+    }
+
+    println!("🔧 API 키 테이블에 IP 허용 목록 열 추가 중...");
+    sqlx::query("ALTER TABLE api_keys ADD COLUMN ip_allowlist TEXT")
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// 주문 태그 이전에 만든 주문 테이블에 `tag` 열 추가 (기존 주문은 태그 없음)
+async fn migrate_orders_tag(pool: &SqlitePool) -> Result<(), SqlxError> {
+    let column: Option<(String,)> =
+        sqlx::query_as("SELECT name FROM pragma_table_info('orders') WHERE name = 'tag'")
+            .fetch_optional(pool)
+            .await?;
+    if column.is_some() {
+        return Ok(());
+    }
+
+    println!("🔧 주문 테이블에 태그 열 추가 중...");
+    sqlx::query("ALTER TABLE orders ADD COLUMN tag TEXT")
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
 /// 필요한 테이블 생성
-async fn create_tables(pool: &SqlitePool) -> Result<(), SqlxError> {
+pub(crate) async fn create_tables(pool: &SqlitePool) -> Result<(), SqlxError> {
     // 체결 내역 테이블
     sqlx::query(
         "CREATE TABLE IF NOT EXISTS executions (
@@ -58,21 +148,256 @@ async fn create_tables(pool: &SqlitePool) -> Result<(), SqlxError> {
             quantity INTEGER NOT NULL,
             filled_quantity INTEGER DEFAULT 0,
             status TEXT NOT NULL,
+            tag TEXT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )"
     )
     .execute(pool)
     .await?;
+    migrate_orders_tag(pool).await?;
+
+    // 잔고 테이블 (계정·자산별 한 행)
+    sqlx::query(BALANCES_TABLE_DDL)
+        .execute(pool)
+        .await?;
+    migrate_balances_table(pool).await?;
+
+    // 계정별 주문/체결 이벤트 (WebSocket 재연결 재전송)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS private_events (
+            client_id TEXT NOT NULL,
+            sequence INTEGER NOT NULL,
+            payload TEXT NOT NULL,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (client_id, sequence)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 계정별 수수료 등급 이력 (재산정으로 등급이 바뀔 때마다 한 행)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS fee_tier_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            client_id TEXT NOT NULL,
+            tier TEXT NOT NULL,
+            volume_30d INTEGER NOT NULL,
+            maker_fee_bps INTEGER NOT NULL,
+            taker_fee_bps INTEGER NOT NULL,
+            effective_at INTEGER NOT NULL
+        )"
+    )
+    .execute(pool)
+    .await?;
 
-    // 잔고 테이블
+    // 킬 스위치 (차단 계정, 거래 중단 심볼; 해제하면 행 삭제, 이력은 audit_logs)
     sqlx::query(
-        "CREATE TABLE IF NOT EXISTS balances (
+        "CREATE TABLE IF NOT EXISTS kill_switches (
+            scope TEXT NOT NULL,
+            target TEXT NOT NULL,
+            reason TEXT,
+            activated_by TEXT NOT NULL,
+            activated_at INTEGER NOT NULL,
+            PRIMARY KEY (scope, target)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 계정별 사전 리스크 한도 (설정하지 않은 항목은 서버 기본값, 이력은 audit_logs)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS risk_limits (
             client_id TEXT PRIMARY KEY,
+            max_open_orders INTEGER,
+            max_order_notional INTEGER,
+            max_net_position TEXT NOT NULL,
+            updated_by TEXT NOT NULL,
+            updated_at INTEGER NOT NULL
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // API 키 (비밀 키는 SHA-256 해시만 저장, 폐기해도 행은 남김)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS api_keys (
+            key_id TEXT PRIMARY KEY,
+            key_hash TEXT NOT NULL UNIQUE,
+            client_id TEXT NOT NULL,
+            scopes TEXT NOT NULL,
+            created_by TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            revoked_at INTEGER,
+            ip_allowlist TEXT
+        )"
+    )
+    .execute(pool)
+    .await?;
+    migrate_api_keys_ip_allowlist(pool).await?;
+
+    // 리프레시 토큰 (해시만 저장, 같은 로그인에서 회전된 토큰은 family_id 공유)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS refresh_tokens (
+            token_hash TEXT PRIMARY KEY,
+            family_id TEXT NOT NULL,
+            key_id TEXT NOT NULL,
+            client_id TEXT NOT NULL,
+            scopes TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            expires_at INTEGER NOT NULL,
+            rotated_at INTEGER,
+            revoked_at INTEGER
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 관리자 TOTP 비밀 키 (민감한 관리자 작업 2단계 확인, last_used_step은 코드 재사용 방지)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS admin_totp (
+            admin_user TEXT PRIMARY KEY,
+            secret TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            confirmed_at INTEGER,
+            last_used_step INTEGER
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 역할별 권한 (RBAC, 처음 시작할 때 기본 권한으로 채움, 변경 이력은 audit_logs)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS role_permissions (
+            role TEXT PRIMARY KEY,
+            permissions TEXT NOT NULL,
+            updated_by TEXT NOT NULL,
+            updated_at INTEGER NOT NULL
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 계정/관리자 역할 지정 (지정하지 않은 대상은 서버 기본 역할)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS role_assignments (
+            subject_kind TEXT NOT NULL,
+            subject TEXT NOT NULL,
+            role TEXT NOT NULL,
+            assigned_by TEXT NOT NULL,
+            assigned_at INTEGER NOT NULL,
+            PRIMARY KEY (subject_kind, subject)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 계정별 분당 메시지 수 (주문, 취소, 조회, 하드 상한 거부; 보존 기간이 지나면 삭제)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS client_message_usage (
+            client_id TEXT NOT NULL,
+            minute INTEGER NOT NULL,
+            orders INTEGER NOT NULL,
+            cancels INTEGER NOT NULL,
+            queries INTEGER NOT NULL,
+            rejected INTEGER NOT NULL,
+            PRIMARY KEY (client_id, minute)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 계정·심볼별 일별 최우선 호가 유동성 공급 (마켓 메이커 리베이트 보고서)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS maker_liquidity_daily (
+            day INTEGER NOT NULL,
+            symbol TEXT NOT NULL,
+            client_id TEXT NOT NULL,
+            bid_time_ms INTEGER NOT NULL,
+            ask_time_ms INTEGER NOT NULL,
+            bid_size_ms INTEGER NOT NULL,
+            ask_size_ms INTEGER NOT NULL,
+            PRIMARY KEY (day, symbol, client_id)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 심볼별 펀딩 정산과 계정별 펀딩 원장
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS funding_settlements (
+            symbol TEXT NOT NULL,
+            funding_time INTEGER NOT NULL,
+            funding_rate REAL NOT NULL,
+            index_price REAL NOT NULL,
+            last_price INTEGER NOT NULL,
+            mark_price REAL NOT NULL,
+            long_positions INTEGER NOT NULL,
+            short_positions INTEGER NOT NULL,
+            total_paid INTEGER NOT NULL,
+            total_received INTEGER NOT NULL,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (symbol, funding_time)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS funding_payments (
+            symbol TEXT NOT NULL,
+            funding_time INTEGER NOT NULL,
+            client_id TEXT NOT NULL,
             asset TEXT NOT NULL,
-            available INTEGER NOT NULL,
-            locked INTEGER DEFAULT 0,
-            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            position INTEGER NOT NULL,
+            amount INTEGER NOT NULL,
+            PRIMARY KEY (symbol, funding_time, client_id)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 만기 상품 최종 정산과 계정별 정산 원장
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS futures_settlements (
+            symbol TEXT PRIMARY KEY,
+            underlying TEXT NOT NULL,
+            expiry INTEGER NOT NULL,
+            settlement_price INTEGER NOT NULL,
+            index_price REAL NOT NULL,
+            positions INTEGER NOT NULL,
+            total_paid INTEGER NOT NULL,
+            total_received INTEGER NOT NULL,
+            settled_at INTEGER NOT NULL
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS futures_settlement_payments (
+            symbol TEXT NOT NULL,
+            client_id TEXT NOT NULL,
+            asset TEXT NOT NULL,
+            position INTEGER NOT NULL,
+            net_cost INTEGER NOT NULL,
+            amount INTEGER NOT NULL,
+            PRIMARY KEY (symbol, client_id)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 시간별 성능 지표 롤업 (REST 지연 백분위수, 오류 수, 주문 수, 대시보드 장기 추이)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS metrics_hourly (
+            hour_start INTEGER PRIMARY KEY,
+            requests INTEGER NOT NULL,
+            errors INTEGER NOT NULL,
+            p50_latency_ms REAL,
+            p99_latency_ms REAL,
+            max_latency_ms REAL,
+            orders INTEGER NOT NULL
         )"
     )
     .execute(pool)
@@ -92,6 +417,146 @@ async fn create_tables(pool: &SqlitePool) -> Result<(), SqlxError> {
     .execute(pool)
     .await?;
 
+    // 차익거래 기회 테이블 (알림 규칙이 확정한 기회)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS arbitrage_opportunities (
+            id TEXT PRIMARY KEY,
+            symbol TEXT NOT NULL,
+            buy_exchange TEXT NOT NULL,
+            sell_exchange TEXT NOT NULL,
+            buy_price REAL NOT NULL,
+            sell_price REAL NOT NULL,
+            spread_percent REAL NOT NULL,
+            consecutive_ticks INTEGER NOT NULL,
+            first_seen_at INTEGER NOT NULL,
+            detected_at INTEGER NOT NULL,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // AML 규칙 집합 테이블 (버전별 JSON 정의)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS aml_rule_sets (
+            version INTEGER PRIMARY KEY,
+            definition TEXT NOT NULL,
+            author TEXT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 계정 KYC 상태 테이블 (변경 이력은 audit_logs)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS kyc_accounts (
+            client_id TEXT PRIMARY KEY,
+            level TEXT NOT NULL,
+            status TEXT NOT NULL,
+            max_order_notional INTEGER,
+            reason TEXT,
+            updated_by TEXT NOT NULL,
+            updated_at INTEGER NOT NULL
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 알림 라우팅 규칙 테이블 (규칙 정의는 JSON)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS notification_routing_rules (
+            id TEXT PRIMARY KEY,
+            definition TEXT NOT NULL,
+            updated_by TEXT NOT NULL,
+            updated_at INTEGER NOT NULL
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 알림 인시던트 테이블 (확인/해결 추적)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS incidents (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            content TEXT NOT NULL,
+            priority TEXT NOT NULL,
+            notification_type TEXT NOT NULL,
+            source TEXT,
+            status TEXT NOT NULL,
+            escalation_count INTEGER NOT NULL DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            acknowledged_by TEXT,
+            acknowledged_at INTEGER,
+            resolved_by TEXT,
+            resolved_at INTEGER
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 인시던트 메모 (해결 내용 등)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS incident_notes (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            incident_id TEXT NOT NULL,
+            author TEXT NOT NULL,
+            note TEXT NOT NULL,
+            created_at INTEGER NOT NULL
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 보존 기간이 지난 체결의 심볼 일별 집계
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS execution_daily_aggregates (
+            day INTEGER NOT NULL,
+            symbol TEXT NOT NULL,
+            trade_count INTEGER NOT NULL,
+            volume INTEGER NOT NULL,
+            notional INTEGER NOT NULL,
+            open_price INTEGER NOT NULL,
+            high_price INTEGER NOT NULL,
+            low_price INTEGER NOT NULL,
+            close_price INTEGER NOT NULL,
+            taker_fees INTEGER NOT NULL,
+            maker_fees INTEGER NOT NULL,
+            PRIMARY KEY (day, symbol)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 삭제한 체결의 계정·심볼별 순포지션 (재시작 시 남은 체결과 합산)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS net_position_carryover (
+            client_id TEXT NOT NULL,
+            symbol TEXT NOT NULL,
+            net_quantity INTEGER NOT NULL,
+            PRIMARY KEY (client_id, symbol)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // 콜드 스토리지로 옮긴 날짜·데이터셋 (올린 객체 키, 행 수, 크기, SHA-256)
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS archive_manifest (
+            day INTEGER NOT NULL,
+            dataset TEXT NOT NULL,
+            object_key TEXT,
+            row_count INTEGER NOT NULL,
+            size_bytes INTEGER NOT NULL,
+            sha256 TEXT,
+            archived_at INTEGER NOT NULL,
+            PRIMARY KEY (day, dataset)
+        )"
+    )
+    .execute(pool)
+    .await?;
+
     // 인덱스 생성
     sqlx::query("CREATE INDEX IF NOT EXISTS idx_executions_symbol ON executions(symbol)")
         .execute(pool)
@@ -101,6 +566,31 @@ async fn create_tables(pool: &SqlitePool) -> Result<(), SqlxError> {
         .execute(pool)
         .await?;
 
+    // 기간별 내보내기 (심볼, 시각, 체결 ID 순 페이지 조회)
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_executions_symbol_time ON executions(symbol, transaction_time, exec_id)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_incidents_status ON incidents(status, created_at)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_incident_notes_incident ON incident_notes(incident_id)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_refresh_tokens_family ON refresh_tokens(family_id)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_refresh_tokens_key ON refresh_tokens(key_id)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_client_message_usage_minute ON client_message_usage(minute)")
+        .execute(pool)
+        .await?;
+
     sqlx::query("CREATE INDEX IF NOT EXISTS idx_orders_client ON orders(client_id)")
         .execute(pool)
         .await?;
@@ -109,7 +599,126 @@ async fn create_tables(pool: &SqlitePool) -> Result<(), SqlxError> {
         .execute(pool)
         .await?;
 
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_orders_client_tag ON orders(client_id, tag)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_fee_tier_history_client ON fee_tier_history(client_id, id)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_orders_created ON orders(created_at)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_executions_maker ON executions(maker_order_id)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_arbitrage_symbol_time ON arbitrage_opportunities(symbol, detected_at)")
+        .execute(pool)
+        .await?;
+
+    // Kafka Consumer 커밋 오프셋, DLQ 격리 메시지 (xtrader-mq가 스키마를 가짐)
+    xtrader_mq::store::create_tables(pool).await?;
+
     println!("📋 테이블 생성 완료");
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_legacy_balances_table_is_migrated_to_composite_key() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE balances (
+                client_id TEXT PRIMARY KEY,
+                asset TEXT NOT NULL,
+                available INTEGER NOT NULL,
+                locked INTEGER DEFAULT 0,
+                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            )"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO balances (client_id, asset, available, locked) VALUES ('trader_01', 'KRW', 1000, 10)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        create_tables(&pool).await.unwrap();
+        // 두 번째 실행은 변환하지 않음
+        create_tables(&pool).await.unwrap();
+
+        // 같은 계정에 다른 자산 행을 넣을 수 있어야 함
+        sqlx::query("INSERT INTO balances (client_id, asset, available, locked) VALUES ('trader_01', 'BTC', 5, 0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let rows: Vec<(String, i64, i64)> =
+            sqlx::query_as("SELECT asset, available, locked FROM balances WHERE client_id = 'trader_01' ORDER BY asset")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(rows, vec![("BTC".to_string(), 5, 0), ("KRW".to_string(), 1000, 10)]);
+    }
+
+    #[tokio::test]
+    async fn test_orders_table_gains_tag_column() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE orders (
+                order_id TEXT PRIMARY KEY,
+                client_id TEXT NOT NULL,
+                symbol TEXT NOT NULL,
+                side TEXT NOT NULL,
+                order_type TEXT NOT NULL,
+                price INTEGER,
+                quantity INTEGER NOT NULL,
+                filled_quantity INTEGER DEFAULT 0,
+                status TEXT NOT NULL,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            )"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO orders (order_id, client_id, symbol, side, order_type, price, quantity, status)
+             VALUES ('o1', 'trader_01', 'BTC-KRW', 'Buy', 'Limit', 100, 1, 'New')"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        create_tables(&pool).await.unwrap();
+        // 두 번째 실행은 열을 다시 추가하지 않음
+        create_tables(&pool).await.unwrap();
+
+        let orders = repository::OrderRepository::new(pool.clone());
+        let mut tagged = orders.find_by_client("trader_01").await.unwrap().remove(0);
+        assert_eq!(tagged.tag, None);
+        tagged.order_id = "o2".to_string();
+        tagged.tag = Some("grid-v2".to_string());
+        orders.save(&tagged).await.unwrap();
+
+        let found = orders.find_by_client_filtered("trader_01", Some("grid-v2"), None, 100).await.unwrap();
+        assert_eq!(found.iter().map(|o| o.order_id.as_str()).collect::<Vec<_>>(), vec!["o2"]);
+        assert_eq!(orders.find_by_client_filtered("trader_01", None, Some("BTC-KRW"), 100).await.unwrap().len(), 2);
+        assert!(orders.find_by_client_filtered("trader_01", None, Some("ETH-KRW"), 100).await.unwrap().is_empty());
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+// MQ 전용 테이블 모델은 xtrader-mq 크레이트에 있음 (기존 경로 유지)
+pub use xtrader_mq::store::{ConsumerOffsetRecord, QuarantinedMessageRecord};
+
 /// 체결 내역 DB 모델
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct ExecutionRecord {
@@ -28,6 +31,8 @@ pub struct OrderRecord {
     pub quantity: i64,
     pub filled_quantity: i64,
     pub status: String,
+    /// 사용자 지정 태그 (전략 ID 등)
+    pub tag: Option<String>,
 }
 
 /// 잔고 DB 모델
@@ -48,3 +53,433 @@ pub struct AuditLog {
     pub entity_id: String,
     pub details: Option<String>,
 }
+
+/// 차익거래 기회 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ArbitrageOpportunityRecord {
+    pub id: String,
+    pub symbol: String,
+    pub buy_exchange: String,
+    pub sell_exchange: String,
+    pub buy_price: f64,
+    pub sell_price: f64,
+    pub spread_percent: f64,
+    pub consecutive_ticks: i64,
+    /// 연속 구간 시작 시각 (Unix 밀리초)
+    pub first_seen_at: i64,
+    /// 알림 발생 시각 (Unix 밀리초)
+    pub detected_at: i64,
+}
+
+/// AML 규칙 집합 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AmlRuleSetRecord {
+    pub version: i64,
+    /// 규칙 집합 JSON
+    pub definition: String,
+    /// 등록자
+    pub author: Option<String>,
+}
+
+/// 계정 KYC 상태 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct KycAccountRecord {
+    pub client_id: String,
+    /// 인증 단계 (unverified, basic, full)
+    pub level: String,
+    /// 계정 상태 (active, suspended)
+    pub status: String,
+    /// 계정별 1회 주문 금액 한도 (NULL이면 단계 기본값)
+    pub max_order_notional: Option<i64>,
+    pub reason: Option<String>,
+    pub updated_by: String,
+    pub updated_at: i64,
+}
+
+/// 알림 라우팅 규칙 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct NotificationRoutingRuleRecord {
+    pub id: String,
+    /// 규칙 정의 (JSON)
+    pub definition: String,
+    pub updated_by: String,
+    pub updated_at: i64,
+}
+
+/// 알림 인시던트 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct IncidentRecord {
+    /// 알림 ID
+    pub id: String,
+    pub title: String,
+    pub content: String,
+    pub priority: String,
+    pub notification_type: String,
+    /// 발신 서비스
+    pub source: Option<String>,
+    /// 상태 (open, acknowledged, resolved)
+    pub status: String,
+    pub escalation_count: i64,
+    pub created_at: i64,
+    pub acknowledged_by: Option<String>,
+    pub acknowledged_at: Option<i64>,
+    pub resolved_by: Option<String>,
+    pub resolved_at: Option<i64>,
+}
+
+/// 인시던트 메모 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct IncidentNoteRecord {
+    pub id: Option<i64>,
+    pub incident_id: String,
+    pub author: String,
+    pub note: String,
+    pub created_at: i64,
+}
+
+/// 계정별 주문/체결 이벤트 DB 모델 (WebSocket 재연결 재전송용)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PrivateEventRecord {
+    pub client_id: String,
+    pub sequence: i64,
+    /// `PrivateEvent` JSON
+    pub payload: String,
+}
+
+/// 계정·심볼별 메이커/테이커 체결 집계
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ClientVolumeRecord {
+    pub client_id: String,
+    pub symbol: String,
+    /// 메이커 체결 금액 합계 (가격 × 수량, 호가 자산 최소 단위)
+    pub maker_notional: i64,
+    /// 테이커 체결 금액 합계
+    pub taker_notional: i64,
+    pub maker_trades: i64,
+    pub taker_trades: i64,
+}
+
+/// 계정별 주문 수 집계
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ClientOrderCountRecord {
+    pub client_id: String,
+    pub orders: i64,
+    pub canceled_orders: i64,
+}
+
+/// 계정별 수수료 등급 이력 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct FeeTierHistoryRecord {
+    pub id: Option<i64>,
+    pub client_id: String,
+    pub tier: String,
+    /// 산정 기준 30일 거래대금 (보고 통화 최소 단위)
+    pub volume_30d: i64,
+    pub maker_fee_bps: i64,
+    pub taker_fee_bps: i64,
+    /// 적용 시작 시각 (초)
+    pub effective_at: i64,
+}
+
+/// 킬 스위치 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct KillSwitchRecord {
+    /// 대상 종류 (client, symbol)
+    pub scope: String,
+    /// 계정 ID 또는 심볼
+    pub target: String,
+    pub reason: Option<String>,
+    pub activated_by: String,
+    /// 발동 시각 (밀리초)
+    pub activated_at: i64,
+}
+
+/// 계정별 사전 리스크 한도 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RiskLimitRecord {
+    pub client_id: String,
+    pub max_open_orders: Option<i64>,
+    /// 1회 주문 금액 한도 (보고 통화 최소 단위)
+    pub max_order_notional: Option<i64>,
+    /// 심볼별 순포지션 한도 (JSON 객체, 심볼 → 수량)
+    pub max_net_position: String,
+    pub updated_by: String,
+    /// 변경 시각 (밀리초)
+    pub updated_at: i64,
+}
+
+/// 계정·심볼별 체결 순포지션 집계
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct NetPositionRecord {
+    pub client_id: String,
+    pub symbol: String,
+    /// 매수 체결 수량 - 매도 체결 수량
+    pub net_quantity: i64,
+}
+
+/// API 키 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ApiKeyRecord {
+    pub key_id: String,
+    /// 비밀 키 SHA-256 (hex)
+    pub key_hash: String,
+    pub client_id: String,
+    /// 권한 (쉼표 구분, 예: read,trade)
+    pub scopes: String,
+    pub created_by: String,
+    /// 발급 시각 (초)
+    pub created_at: i64,
+    /// 폐기 시각 (초, NULL이면 사용 중)
+    pub revoked_at: Option<i64>,
+    /// 허용 IP/CIDR (쉼표 구분, NULL이면 모든 IP 허용)
+    pub ip_allowlist: Option<String>,
+}
+
+/// 리프레시 토큰 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RefreshTokenRecord {
+    /// 토큰 SHA-256 (hex)
+    pub token_hash: String,
+    /// 로그인 한 번에서 회전된 토큰 묶음
+    pub family_id: String,
+    /// 로그인에 사용한 API 키
+    pub key_id: String,
+    pub client_id: String,
+    pub scopes: String,
+    /// 시각은 모두 초
+    pub created_at: i64,
+    pub expires_at: i64,
+    /// 새 토큰으로 교체된 시각 (다시 쓰이면 탈취로 보고 묶음 전체 폐기)
+    pub rotated_at: Option<i64>,
+    pub revoked_at: Option<i64>,
+}
+
+/// 관리자 TOTP DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AdminTotpRecord {
+    pub admin_user: String,
+    /// 비밀 키 (base32)
+    pub secret: String,
+    /// 발급 시각 (초)
+    pub created_at: i64,
+    /// 첫 코드 확인 시각 (초, NULL이면 등록 미완료)
+    pub confirmed_at: Option<i64>,
+    /// 마지막으로 받은 코드의 시간 단계 (같은 코드 재사용 거부)
+    pub last_used_step: Option<i64>,
+}
+
+/// 역할별 권한 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RolePermissionsRecord {
+    pub role: String,
+    /// 권한 (쉼표 구분, 예: orders:write,account:read)
+    pub permissions: String,
+    pub updated_by: String,
+    /// 변경 시각 (밀리초)
+    pub updated_at: i64,
+}
+
+/// 역할 지정 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RoleAssignmentRecord {
+    /// 대상 종류 (client, admin)
+    pub subject_kind: String,
+    /// 계정 ID 또는 관리자 이름
+    pub subject: String,
+    pub role: String,
+    pub assigned_by: String,
+    /// 지정 시각 (밀리초)
+    pub assigned_at: i64,
+}
+
+/// 계정별 분당 메시지 수 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct MessageUsageRecord {
+    pub client_id: String,
+    /// 분 시작 시각 (밀리초)
+    pub minute: i64,
+    pub orders: i64,
+    pub cancels: i64,
+    pub queries: i64,
+    /// 하드 상한으로 거부한 메시지 수 (위 세 항목에도 포함)
+    pub rejected: i64,
+}
+
+/// 계정별 기간 메시지 수 합계
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MessageUsageSummaryRecord {
+    pub client_id: String,
+    pub orders: i64,
+    pub cancels: i64,
+    pub queries: i64,
+    pub rejected: i64,
+    /// 분당 최대 메시지 수 (주문 + 취소 + 조회)
+    pub peak_per_minute: i64,
+}
+
+/// 계정·심볼별 일별 최우선 호가 유동성 공급 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct MakerLiquidityRecord {
+    /// UTC 자정 (초)
+    pub day: i64,
+    pub symbol: String,
+    pub client_id: String,
+    /// 최우선 매수 호가 체류 시간 (밀리초)
+    pub bid_time_ms: i64,
+    /// 최우선 매도 호가 체류 시간 (밀리초)
+    pub ask_time_ms: i64,
+    /// 최우선 매수 호가 잔량 × 체류 시간 (수량·밀리초)
+    pub bid_size_ms: i64,
+    /// 최우선 매도 호가 잔량 × 체류 시간 (수량·밀리초)
+    pub ask_size_ms: i64,
+}
+
+/// 심볼별 펀딩 정산 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq)]
+pub struct FundingSettlementRecord {
+    pub symbol: String,
+    /// 정산 시각 (Unix 초)
+    pub funding_time: i64,
+    pub funding_rate: f64,
+    pub index_price: f64,
+    pub last_price: i64,
+    pub mark_price: f64,
+    /// 정산한 롱/숏 포지션 계정 수
+    pub long_positions: i64,
+    pub short_positions: i64,
+    /// 계정들이 낸 펀딩 합계 / 받은 펀딩 합계 (호가 자산 최소 단위)
+    pub total_paid: i64,
+    pub total_received: i64,
+}
+
+/// 계정별 펀딩 지급/수령 원장 DB 모델 (`amount`가 양수면 수령, 음수면 지급)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct FundingPaymentRecord {
+    pub symbol: String,
+    pub funding_time: i64,
+    pub client_id: String,
+    pub asset: String,
+    /// 정산 시점 순포지션
+    pub position: i64,
+    pub amount: i64,
+}
+
+/// 만기 상품 계정별 포지션과 체결 대금 (체결 기록으로 계산)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct FuturesPositionRecord {
+    pub client_id: String,
+    /// 매수 체결 수량 - 매도 체결 수량
+    pub net_quantity: i64,
+    /// 매수 체결 대금 - 매도 체결 대금 (호가 자산 최소 단위)
+    pub net_cost: i64,
+}
+
+/// 만기 상품 최종 정산 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq)]
+pub struct FuturesSettlementRecord {
+    pub symbol: String,
+    pub underlying: String,
+    /// 만기 시각 (Unix 초)
+    pub expiry: i64,
+    /// 정산 가격 (인덱스 가격을 호가 자산 최소 단위로 반올림)
+    pub settlement_price: i64,
+    pub index_price: f64,
+    /// 정산한 포지션 계정 수
+    pub positions: i64,
+    /// 계정들이 낸 정산금 합계 / 받은 정산금 합계 (호가 자산 최소 단위)
+    pub total_paid: i64,
+    pub total_received: i64,
+    /// 정산 시각 (Unix 초)
+    pub settled_at: i64,
+}
+
+/// 만기 상품 계정별 정산 원장 DB 모델 (`amount`가 양수면 수령, 음수면 지급)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct FuturesSettlementPaymentRecord {
+    pub symbol: String,
+    pub client_id: String,
+    pub asset: String,
+    /// 만기 시점 순포지션
+    pub position: i64,
+    /// 순포지션의 체결 대금 (매수 - 매도)
+    pub net_cost: i64,
+    pub amount: i64,
+}
+
+/// 체결 일별 집계 DB 모델 (보존 기간이 지난 체결을 접은 것)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct ExecutionDailyAggregateRecord {
+    /// UTC 자정 (초)
+    pub day: i64,
+    pub symbol: String,
+    pub trade_count: i64,
+    /// 체결 수량 합
+    pub volume: i64,
+    /// 체결 가격 × 수량 합
+    pub notional: i64,
+    pub open_price: i64,
+    pub high_price: i64,
+    pub low_price: i64,
+    pub close_price: i64,
+    pub taker_fees: i64,
+    pub maker_fees: i64,
+}
+
+/// 1분봉 (체결에서 계산, 콜드 스토리지 보관용)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct MinuteCandleRecord {
+    pub symbol: String,
+    /// 봉 시작 시각 (초)
+    pub open_time: i64,
+    pub open_price: i64,
+    pub high_price: i64,
+    pub low_price: i64,
+    pub close_price: i64,
+    pub volume: i64,
+    pub trade_count: i64,
+}
+
+/// 감사 로그 행 (기록 시각 포함, 콜드 스토리지 보관용)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AuditLogRecord {
+    pub id: i64,
+    pub event_type: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub details: Option<String>,
+    /// `YYYY-MM-DD HH:MM:SS` (UTC)
+    pub timestamp: String,
+}
+
+/// 콜드 스토리지 보관 기록 (날짜·데이터셋별 한 행)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct ArchiveManifestRecord {
+    /// UTC 자정 (초)
+    pub day: i64,
+    pub dataset: String,
+    /// 객체 키 (행이 없어 올리지 않았으면 None)
+    pub object_key: Option<String>,
+    pub row_count: i64,
+    pub size_bytes: i64,
+    pub sha256: Option<String>,
+    pub archived_at: i64,
+}
+
+/// 시간별 성능 지표 롤업 DB 모델
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq)]
+pub struct MetricsHourlyRecord {
+    /// 시간 시작 (Unix 밀리초, UTC 정시)
+    pub hour_start: i64,
+    /// REST 요청 수
+    pub requests: i64,
+    /// 5xx 응답 수
+    pub errors: i64,
+    /// REST 요청 지연 p50 (밀리초, 요청이 없으면 None)
+    pub p50_latency_ms: Option<f64>,
+    /// REST 요청 지연 p99 (밀리초)
+    pub p99_latency_ms: Option<f64>,
+    /// REST 요청 지연 최대 (밀리초)
+    pub max_latency_ms: Option<f64>,
+    /// 주문 큐 적재 수
+    pub orders: i64,
+}
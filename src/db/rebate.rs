@@ -0,0 +1,377 @@
+//! 마켓 메이커 유동성 리베이트 보고서
+//!
+//! 매칭 엔진 호가창 추적기가 누적한 계정·심볼별 최우선 호가 체류 시간과 잔량 × 시간을 보고 작업이 주기적으로 가져가
+//! `maker_liquidity_daily`에 일별로 더합니다. 월 보고서는 한 달치를 합쳐 계정별 유동성 점수(최우선 호가 잔량 × 시간)와
+//! 심볼 안 점수 비중을 계산하고, 최우선 호가 체류율이 기준 이상인 계정에게 심볼별 월 리베이트 예산을 점수 비율로 나눕니다.
+//! 사업팀이 마켓 메이커 프로그램 정산에 쓰도록 CSV로 내보낼 수 있습니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use sqlx::sqlite::SqlitePool;
+use utoipa::ToSchema;
+
+use super::models::MakerLiquidityRecord;
+use super::repository::MakerLiquidityRepository;
+use crate::matching_engine::MakerLiquiditySample;
+
+/// 하루 (초)
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+/// CSV 헤더 (`RebateReportRow` 필드 순서)
+pub const REBATE_COLUMNS: [&str; 11] = [
+    "symbol",
+    "client_id",
+    "bid_time_ms",
+    "ask_time_ms",
+    "presence",
+    "avg_bid_size",
+    "avg_ask_size",
+    "liquidity_score",
+    "score_share",
+    "eligible",
+    "rebate",
+];
+
+/// 리베이트 프로그램 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct RebateConfig {
+    /// 심볼별 월 리베이트 예산 (심볼 호가 자산 최소 단위, 없는 심볼은 리베이트 0)
+    pub pools: BTreeMap<String, u64>,
+    /// 리베이트 대상 최소 최우선 호가 체류율 (매수/매도 체류 시간 평균 ÷ 보고 기간, 0~1)
+    pub min_presence: f64,
+    /// 엔진에서 누적값을 가져가 저장하는 주기
+    pub collect_interval: Duration,
+}
+
+impl Default for RebateConfig {
+    fn default() -> Self {
+        Self {
+            pools: BTreeMap::new(),
+            min_presence: 0.0,
+            collect_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RebateConfig {
+    /// `BTC-KRW:1000000,ETH-KRW:500000` (심볼:월 예산) 형식 파싱
+    pub fn parse_pools(spec: &str) -> Result<BTreeMap<String, u64>, String> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                entry
+                    .split_once(':')
+                    .and_then(|(symbol, pool)| Some((symbol.trim(), pool.trim().parse::<u64>().ok()?)))
+                    .filter(|(symbol, _)| !symbol.is_empty())
+                    .map(|(symbol, pool)| (symbol.to_string(), pool))
+                    .ok_or_else(|| format!("리베이트 예산 형식 오류: {} (예: BTC-KRW:1000000)", entry))
+            })
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.min_presence) {
+            return Err(format!("리베이트 최소 체류율은 0~1 사이여야 합니다: {}", self.min_presence));
+        }
+        if self.collect_interval.is_zero() {
+            return Err("유동성 수집 주기는 0보다 커야 합니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 리베이트 보고서 오류
+#[derive(Debug, thiserror::Error)]
+pub enum RebateError {
+    #[error("잘못된 보고 월입니다: {0} (YYYY-MM, 현재 월까지)")]
+    InvalidMonth(String),
+    #[error("유동성 집계 저장소 오류: {0}")]
+    Storage(#[from] sqlx::Error),
+    #[error("CSV 인코딩 오류: {0}")]
+    Csv(#[from] csv::Error),
+}
+
+/// 계정·심볼별 월 유동성 공급과 리베이트
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct RebateReportRow {
+    pub symbol: String,
+    pub client_id: String,
+    /// 최우선 매수 호가 체류 시간 (밀리초)
+    pub bid_time_ms: u64,
+    /// 최우선 매도 호가 체류 시간 (밀리초)
+    pub ask_time_ms: u64,
+    /// 최우선 호가 체류율 (매수/매도 체류 시간 평균 ÷ 보고 기간)
+    pub presence: f64,
+    /// 최우선 매수 호가 체류 중 평균 잔량 (체류하지 않았으면 null)
+    pub avg_bid_size: Option<f64>,
+    /// 최우선 매도 호가 체류 중 평균 잔량 (체류하지 않았으면 null)
+    pub avg_ask_size: Option<f64>,
+    /// 유동성 점수 (최우선 호가 잔량 × 체류 시간 합, 수량·밀리초)
+    pub liquidity_score: u64,
+    /// 심볼 전체 점수 중 비중 (0~1)
+    pub score_share: f64,
+    /// 리베이트 대상 여부 (체류율 기준 충족)
+    pub eligible: bool,
+    /// 리베이트 (심볼 호가 자산 최소 단위)
+    pub rebate: u64,
+}
+
+/// 월 리베이트 보고서
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct RebateReport {
+    /// 보고 월 (YYYY-MM)
+    pub month: String,
+    /// 보고 기간 시작 (Unix 초, 월 1일 UTC 자정)
+    pub period_start: u64,
+    /// 보고 기간 끝 (Unix 초, 제외, 진행 중인 월은 현재 시각)
+    pub period_end: u64,
+    /// 리베이트 대상 최소 체류율
+    pub min_presence: f64,
+    /// 심볼·리베이트 많은 순
+    pub rows: Vec<RebateReportRow>,
+}
+
+impl RebateReport {
+    /// CSV 인코딩 (헤더 포함)
+    pub fn to_csv(&self) -> Result<Vec<u8>, RebateError> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(REBATE_COLUMNS)?;
+        for row in &self.rows {
+            writer.write_record([
+                row.symbol.clone(),
+                row.client_id.clone(),
+                row.bid_time_ms.to_string(),
+                row.ask_time_ms.to_string(),
+                format!("{:.6}", row.presence),
+                row.avg_bid_size.map(|size| format!("{:.4}", size)).unwrap_or_default(),
+                row.avg_ask_size.map(|size| format!("{:.4}", size)).unwrap_or_default(),
+                row.liquidity_score.to_string(),
+                format!("{:.6}", row.score_share),
+                row.eligible.to_string(),
+                row.rebate.to_string(),
+            ])?;
+        }
+        writer.into_inner().map_err(|e| RebateError::Csv(e.into_error().into()))
+    }
+}
+
+/// 리베이트 보고 (유동성 저장, 월 보고서 계산)
+pub struct RebateService {
+    repository: MakerLiquidityRepository,
+    config: RebateConfig,
+}
+
+impl RebateService {
+    pub fn new(pool: SqlitePool, config: RebateConfig) -> Self {
+        Self {
+            repository: MakerLiquidityRepository::new(pool),
+            config,
+        }
+    }
+
+    pub fn config(&self) -> &RebateConfig {
+        &self.config
+    }
+
+    /// 엔진에서 가져온 누적값을 `now_secs`가 속한 날(UTC)에 더함
+    pub async fn record(&self, samples: &[MakerLiquiditySample], now_secs: u64) -> Result<(), RebateError> {
+        if samples.is_empty() {
+            return Ok(());
+        }
+        let day = (now_secs / DAY_SECS * DAY_SECS) as i64;
+        let records: Vec<MakerLiquidityRecord> = samples
+            .iter()
+            .map(|sample| MakerLiquidityRecord {
+                day,
+                symbol: sample.symbol.clone(),
+                client_id: sample.client_id.clone(),
+                bid_time_ms: sample.bid_time_ms as i64,
+                ask_time_ms: sample.ask_time_ms as i64,
+                bid_size_ms: sample.bid_size_ms.min(i64::MAX as u64) as i64,
+                ask_size_ms: sample.ask_size_ms.min(i64::MAX as u64) as i64,
+            })
+            .collect();
+        self.repository.add(&records).await?;
+        Ok(())
+    }
+
+    /// 월 리베이트 보고서 (`month`: YYYY-MM, 진행 중인 월은 `now_secs`까지)
+    pub async fn monthly_report(&self, month: &str, now_secs: u64) -> Result<RebateReport, RebateError> {
+        let (period_start, month_end) = month_bounds(month).ok_or_else(|| RebateError::InvalidMonth(month.to_string()))?;
+        if period_start >= now_secs {
+            return Err(RebateError::InvalidMonth(month.to_string()));
+        }
+        let period_end = month_end.min(now_secs);
+        let records = self.repository.sum_between(period_start as i64, month_end as i64).await?;
+
+        Ok(RebateReport {
+            month: month.to_string(),
+            period_start,
+            period_end,
+            min_presence: self.config.min_presence,
+            rows: build_rows(&records, (period_end - period_start) * 1000, &self.config),
+        })
+    }
+}
+
+/// `YYYY-MM`의 [월 1일, 다음 달 1일) UTC 자정 (Unix 초)
+fn month_bounds(month: &str) -> Option<(u64, u64)> {
+    if month.len() != 7 {
+        return None;
+    }
+    let first = NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d").ok()?;
+    let next = if first.month() == 12 {
+        NaiveDate::from_ymd_opt(first.year() + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(first.year(), first.month() + 1, 1)?
+    };
+    let secs = |date: NaiveDate| u64::try_from(date.and_hms_opt(0, 0, 0)?.and_utc().timestamp()).ok();
+    Some((secs(first)?, secs(next)?))
+}
+
+/// 계정·심볼별 합계로 보고서 행 계산 (예산은 체류율 기준을 넘은 계정끼리 점수 비율로 나눔)
+fn build_rows(records: &[MakerLiquidityRecord], period_ms: u64, config: &RebateConfig) -> Vec<RebateReportRow> {
+    let score = |record: &MakerLiquidityRecord| (record.bid_size_ms as u64).saturating_add(record.ask_size_ms as u64);
+    let presence = |record: &MakerLiquidityRecord| {
+        if period_ms == 0 {
+            return 0.0;
+        }
+        (record.bid_time_ms + record.ask_time_ms) as f64 / 2.0 / period_ms as f64
+    };
+
+    // 심볼별 전체 점수, 리베이트 대상 점수
+    let mut symbol_scores: HashMap<&str, (u128, u128)> = HashMap::new();
+    for record in records {
+        let totals = symbol_scores.entry(record.symbol.as_str()).or_default();
+        totals.0 += score(record) as u128;
+        if presence(record) >= config.min_presence {
+            totals.1 += score(record) as u128;
+        }
+    }
+
+    let mut rows: Vec<RebateReportRow> = records
+        .iter()
+        .map(|record| {
+            let liquidity_score = score(record);
+            let presence = presence(record);
+            let eligible = presence >= config.min_presence && liquidity_score > 0;
+            let (symbol_total, eligible_total) = symbol_scores[record.symbol.as_str()];
+            let pool = config.pools.get(&record.symbol).copied().unwrap_or(0);
+            let rebate = if eligible && eligible_total > 0 {
+                (pool as u128 * liquidity_score as u128 / eligible_total) as u64
+            } else {
+                0
+            };
+            let average = |size_ms: i64, time_ms: i64| (time_ms > 0).then(|| size_ms as f64 / time_ms as f64);
+            RebateReportRow {
+                symbol: record.symbol.clone(),
+                client_id: record.client_id.clone(),
+                bid_time_ms: record.bid_time_ms as u64,
+                ask_time_ms: record.ask_time_ms as u64,
+                presence,
+                avg_bid_size: average(record.bid_size_ms, record.bid_time_ms),
+                avg_ask_size: average(record.ask_size_ms, record.ask_time_ms),
+                liquidity_score,
+                score_share: if symbol_total > 0 { liquidity_score as f64 / symbol_total as f64 } else { 0.0 },
+                eligible,
+                rebate,
+            }
+        })
+        .collect();
+    rows.sort_by(|a, b| {
+        a.symbol
+            .cmp(&b.symbol)
+            .then(b.rebate.cmp(&a.rebate))
+            .then(b.liquidity_score.cmp(&a.liquidity_score))
+            .then(a.client_id.cmp(&b.client_id))
+    });
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::create_tables;
+
+    fn sample(client_id: &str, bid_time_ms: u64, ask_time_ms: u64, bid_size_ms: u64, ask_size_ms: u64) -> MakerLiquiditySample {
+        MakerLiquiditySample {
+            symbol: "BTC-KRW".to_string(),
+            client_id: client_id.to_string(),
+            bid_time_ms,
+            ask_time_ms,
+            bid_size_ms,
+            ask_size_ms,
+        }
+    }
+
+    #[test]
+    fn test_month_bounds_and_pool_parsing() {
+        assert_eq!(month_bounds("2024-12"), Some((1_733_011_200, 1_735_689_600)));
+        assert_eq!(month_bounds("2024-13"), None);
+        assert_eq!(month_bounds("2024-1"), None);
+
+        let pools = RebateConfig::parse_pools("BTC-KRW:1000, ETH-KRW:500").unwrap();
+        assert_eq!(pools.get("ETH-KRW"), Some(&500));
+        assert!(RebateConfig::parse_pools("BTC-KRW").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_monthly_report_splits_pool_among_eligible_makers() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        create_tables(&pool).await.unwrap();
+        let config = RebateConfig {
+            pools: BTreeMap::from([("BTC-KRW".to_string(), 9_000)]),
+            min_presence: 0.1,
+            ..Default::default()
+        };
+        let service = RebateService::new(pool, config);
+
+        // 2024-12 보고 기간을 12월 1일 00:00~01:00로 두고 (진행 중인 월), 두 날에 나눠 저장
+        let month_start = 1_733_011_200;
+        let hour_ms = 3_600_000;
+        service.record(&[sample("mm_a", hour_ms / 2, hour_ms / 2, 20 * hour_ms, 0)], month_start).await.unwrap();
+        service
+            .record(
+                &[sample("mm_a", 0, 0, 10 * hour_ms, 0), sample("mm_b", hour_ms / 2, 0, 15 * hour_ms, 0)],
+                month_start + 60,
+            )
+            .await
+            .unwrap();
+        // 체류율 미달 계정 (점수는 비중에 포함, 리베이트는 0)
+        service.record(&[sample("mm_c", 60_000, 0, 45 * hour_ms, 0)], month_start + 60).await.unwrap();
+        // 다음 달 데이터는 제외
+        service.record(&[sample("mm_a", hour_ms, hour_ms, hour_ms, 0)], 1_735_689_600).await.unwrap();
+
+        let report = service.monthly_report("2024-12", month_start + 3600).await.unwrap();
+        assert_eq!((report.period_start, report.period_end), (month_start, month_start + 3600));
+        let rows: Vec<(&str, u64, bool, u64)> = report
+            .rows
+            .iter()
+            .map(|row| (row.client_id.as_str(), row.liquidity_score, row.eligible, row.rebate))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("mm_a", 30 * hour_ms, true, 6_000),
+                ("mm_b", 15 * hour_ms, true, 3_000),
+                ("mm_c", 45 * hour_ms, false, 0),
+            ]
+        );
+        assert_eq!(report.rows[0].presence, 0.5);
+        assert_eq!(report.rows[0].avg_bid_size, Some(60.0));
+        assert_eq!(report.rows[2].score_share, 0.5);
+
+        let csv = String::from_utf8(report.to_csv().unwrap()).unwrap();
+        assert_eq!(csv.lines().next().unwrap(), REBATE_COLUMNS.join(","));
+        assert_eq!(csv.lines().count(), 4);
+
+        assert!(matches!(
+            service.monthly_report("2025-01", month_start + 3600).await,
+            Err(RebateError::InvalidMonth(_))
+        ));
+    }
+}
@@ -1,7 +1,10 @@
-use super::models::{ExecutionRecord, OrderRecord, BalanceRecord, AuditLog};
+use super::models::{ExecutionRecord, OrderRecord, BalanceRecord, AuditLog, ArbitrageOpportunityRecord, AmlRuleSetRecord, KycAccountRecord, NotificationRoutingRuleRecord, IncidentRecord, IncidentNoteRecord, PrivateEventRecord, ClientVolumeRecord, ClientOrderCountRecord, FeeTierHistoryRecord, KillSwitchRecord, RiskLimitRecord, NetPositionRecord, ApiKeyRecord, RefreshTokenRecord, AdminTotpRecord, RolePermissionsRecord, RoleAssignmentRecord, MessageUsageRecord, MessageUsageSummaryRecord, MakerLiquidityRecord, FundingSettlementRecord, FundingPaymentRecord, FuturesPositionRecord, FuturesSettlementRecord, FuturesSettlementPaymentRecord, ExecutionDailyAggregateRecord, MinuteCandleRecord, AuditLogRecord, ArchiveManifestRecord, MetricsHourlyRecord};
 use sqlx::sqlite::SqlitePool;
 use sqlx::Error as SqlxError;
 
+// MQ 전용 테이블 저장소는 xtrader-mq 크레이트에 있음 (기존 경로 유지)
+pub use xtrader_mq::store::{ConsumerOffsetRepository, QuarantineRepository};
+
 /// 체결 내역 저장소
 pub struct ExecutionRepository {
     pool: SqlitePool,
@@ -52,6 +55,40 @@ impl ExecutionRepository {
         Ok(executions)
     }
 
+    /// 기간별 체결 내역 페이지 조회 (내보내기용)
+    ///
+    /// `[from, to)` 구간을 (체결 시각, 체결 ID) 순으로 읽으며,
+    /// `after`에 이전 페이지의 마지막 키를 넘기면 그다음부터 조회합니다.
+    pub async fn find_range_page(
+        &self,
+        symbol: &str,
+        from: i64,
+        to: i64,
+        after: Option<(i64, &str)>,
+        limit: i64,
+    ) -> Result<Vec<ExecutionRecord>, SqlxError> {
+        let (after_time, after_id) = after.unwrap_or((i64::MIN, ""));
+        let executions = sqlx::query_as::<_, ExecutionRecord>(
+            "SELECT exec_id, taker_order_id, maker_order_id, symbol, side, price, quantity, taker_fee, maker_fee, transaction_time
+             FROM executions
+             WHERE symbol = ? AND transaction_time >= ? AND transaction_time < ?
+               AND (transaction_time > ? OR (transaction_time = ? AND exec_id > ?))
+             ORDER BY transaction_time ASC, exec_id ASC
+             LIMIT ?"
+        )
+        .bind(symbol)
+        .bind(from)
+        .bind(to)
+        .bind(after_time)
+        .bind(after_time)
+        .bind(after_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(executions)
+    }
+
     /// 모든 체결 내역 조회 (복구용)
     pub async fn find_all(&self) -> Result<Vec<ExecutionRecord>, SqlxError> {
         let executions = sqlx::query_as::<_, ExecutionRecord>(
@@ -80,8 +117,8 @@ impl OrderRepository {
     pub async fn save(&self, order: &OrderRecord) -> Result<(), SqlxError> {
         sqlx::query(
             "INSERT INTO orders
-             (order_id, client_id, symbol, side, order_type, price, quantity, filled_quantity, status)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+             (order_id, client_id, symbol, side, order_type, price, quantity, filled_quantity, status, tag)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
         )
         .bind(&order.order_id)
         .bind(&order.client_id)
@@ -92,6 +129,7 @@ impl OrderRepository {
         .bind(order.quantity)
         .bind(order.filled_quantity)
         .bind(&order.status)
+        .bind(&order.tag)
         .execute(&self.pool)
         .await?;
 
@@ -122,7 +160,7 @@ impl OrderRepository {
     /// 클라이언트별 주문 조회
     pub async fn find_by_client(&self, client_id: &str) -> Result<Vec<OrderRecord>, SqlxError> {
         let orders = sqlx::query_as::<_, OrderRecord>(
-            "SELECT order_id, client_id, symbol, side, order_type, price, quantity, filled_quantity, status
+            "SELECT order_id, client_id, symbol, side, order_type, price, quantity, filled_quantity, status, tag
              FROM orders
              WHERE client_id = ?
              ORDER BY created_at DESC"
@@ -133,6 +171,49 @@ impl OrderRepository {
 
         Ok(orders)
     }
+
+    /// 클라이언트 주문을 태그/심볼로 걸러 최신순 조회
+    pub async fn find_by_client_filtered(
+        &self,
+        client_id: &str,
+        tag: Option<&str>,
+        symbol: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<OrderRecord>, SqlxError> {
+        let orders = sqlx::query_as::<_, OrderRecord>(
+            "SELECT order_id, client_id, symbol, side, order_type, price, quantity, filled_quantity, status, tag
+             FROM orders
+             WHERE client_id = ?
+               AND (? IS NULL OR tag = ?)
+               AND (? IS NULL OR symbol = ?)
+             ORDER BY created_at DESC, rowid DESC
+             LIMIT ?"
+        )
+        .bind(client_id)
+        .bind(tag)
+        .bind(tag)
+        .bind(symbol)
+        .bind(symbol)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(orders)
+    }
+
+    /// 주문장에 남아 있는 주문 (New, PartiallyFilled), 접수 순서
+    pub async fn find_resting(&self) -> Result<Vec<OrderRecord>, SqlxError> {
+        let orders = sqlx::query_as::<_, OrderRecord>(
+            "SELECT order_id, client_id, symbol, side, order_type, price, quantity, filled_quantity, status, tag
+             FROM orders
+             WHERE status IN ('New', 'PartiallyFilled')
+             ORDER BY created_at, rowid"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(orders)
+    }
 }
 
 /// 잔고 저장소
@@ -150,7 +231,7 @@ impl BalanceRepository {
         sqlx::query(
             "INSERT INTO balances (client_id, asset, available, locked)
              VALUES (?, ?, ?, ?)
-             ON CONFLICT(client_id) DO UPDATE SET
+             ON CONFLICT(client_id, asset) DO UPDATE SET
                 available = excluded.available,
                 locked = excluded.locked,
                 updated_at = CURRENT_TIMESTAMP"
@@ -165,12 +246,13 @@ impl BalanceRepository {
         Ok(())
     }
 
-    /// 잔고 조회
+    /// 잔고 조회 (자산 코드순)
     pub async fn find_by_client(&self, client_id: &str) -> Result<Vec<BalanceRecord>, SqlxError> {
         let balances = sqlx::query_as::<_, BalanceRecord>(
             "SELECT client_id, asset, available, locked
              FROM balances
-             WHERE client_id = ?"
+             WHERE client_id = ?
+             ORDER BY asset"
         )
         .bind(client_id)
         .fetch_all(&self.pool)
@@ -227,3 +309,1671 @@ impl AuditLogRepository {
         Ok(logs)
     }
 }
+
+/// DLQ 격리 메시지 처리 내역을 감사 로그에 기록
+#[async_trait::async_trait]
+impl xtrader_mq::AuditSink for AuditLogRepository {
+    async fn log(
+        &self,
+        event_type: &str,
+        entity_type: &str,
+        entity_id: &str,
+        details: Option<&str>,
+    ) -> Result<(), SqlxError> {
+        AuditLogRepository::log(self, event_type, entity_type, entity_id, details).await
+    }
+}
+
+/// 차익거래 기회 저장소
+pub struct ArbitrageOpportunityRepository {
+    pool: SqlitePool,
+}
+
+impl ArbitrageOpportunityRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 차익거래 기회 저장
+    pub async fn save(&self, record: &ArbitrageOpportunityRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO arbitrage_opportunities
+             (id, symbol, buy_exchange, sell_exchange, buy_price, sell_price, spread_percent, consecutive_ticks, first_seen_at, detected_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&record.id)
+        .bind(&record.symbol)
+        .bind(&record.buy_exchange)
+        .bind(&record.sell_exchange)
+        .bind(record.buy_price)
+        .bind(record.sell_price)
+        .bind(record.spread_percent)
+        .bind(record.consecutive_ticks)
+        .bind(record.first_seen_at)
+        .bind(record.detected_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 최근 차익거래 기회 조회 (심볼 생략 시 전 심볼)
+    pub async fn find_recent(&self, symbol: Option<&str>, limit: i64) -> Result<Vec<ArbitrageOpportunityRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, ArbitrageOpportunityRecord>(
+            "SELECT id, symbol, buy_exchange, sell_exchange, buy_price, sell_price, spread_percent, consecutive_ticks, first_seen_at, detected_at
+             FROM arbitrage_opportunities
+             WHERE ? IS NULL OR symbol = ?
+             ORDER BY detected_at DESC
+             LIMIT ?"
+        )
+        .bind(symbol)
+        .bind(symbol)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// AML 규칙 집합 저장소
+pub struct AmlRuleSetRepository {
+    pool: SqlitePool,
+}
+
+impl AmlRuleSetRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 새 버전 저장 (이미 있는 버전은 덮어쓰지 않고 오류)
+    pub async fn save(&self, version: i64, definition: &str, author: Option<&str>) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO aml_rule_sets (version, definition, author)
+             VALUES (?, ?, ?)"
+        )
+        .bind(version)
+        .bind(definition)
+        .bind(author)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 최신 버전 조회
+    pub async fn find_latest(&self) -> Result<Option<AmlRuleSetRecord>, SqlxError> {
+        let record = sqlx::query_as::<_, AmlRuleSetRecord>(
+            "SELECT version, definition, author
+             FROM aml_rule_sets
+             ORDER BY version DESC
+             LIMIT 1"
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// 전체 버전 이력 조회 (최신순)
+    pub async fn find_all(&self) -> Result<Vec<AmlRuleSetRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, AmlRuleSetRecord>(
+            "SELECT version, definition, author
+             FROM aml_rule_sets
+             ORDER BY version DESC"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 계정 KYC 상태 저장소
+pub struct KycAccountRepository {
+    pool: SqlitePool,
+}
+
+impl KycAccountRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// KYC 상태 저장 (계정당 한 행, 있으면 갱신)
+    pub async fn upsert(&self, record: &KycAccountRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO kyc_accounts (client_id, level, status, max_order_notional, reason, updated_by, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(client_id) DO UPDATE SET
+                level = excluded.level,
+                status = excluded.status,
+                max_order_notional = excluded.max_order_notional,
+                reason = excluded.reason,
+                updated_by = excluded.updated_by,
+                updated_at = excluded.updated_at"
+        )
+        .bind(&record.client_id)
+        .bind(&record.level)
+        .bind(&record.status)
+        .bind(record.max_order_notional)
+        .bind(&record.reason)
+        .bind(&record.updated_by)
+        .bind(record.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 전체 계정 조회
+    pub async fn find_all(&self) -> Result<Vec<KycAccountRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, KycAccountRecord>(
+            "SELECT client_id, level, status, max_order_notional, reason, updated_by, updated_at
+             FROM kyc_accounts"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 알림 라우팅 규칙 저장소
+pub struct NotificationRoutingRuleRepository {
+    pool: SqlitePool,
+}
+
+impl NotificationRoutingRuleRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 규칙 저장 (있으면 갱신)
+    pub async fn upsert(&self, record: &NotificationRoutingRuleRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO notification_routing_rules (id, definition, updated_by, updated_at)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET
+                definition = excluded.definition,
+                updated_by = excluded.updated_by,
+                updated_at = excluded.updated_at"
+        )
+        .bind(&record.id)
+        .bind(&record.definition)
+        .bind(&record.updated_by)
+        .bind(record.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 규칙 삭제 (삭제 여부 반환)
+    pub async fn delete(&self, id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM notification_routing_rules WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 전체 규칙 조회 (ID 순)
+    pub async fn find_all(&self) -> Result<Vec<NotificationRoutingRuleRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, NotificationRoutingRuleRecord>(
+            "SELECT id, definition, updated_by, updated_at
+             FROM notification_routing_rules
+             ORDER BY id"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 알림 인시던트 저장소
+pub struct IncidentRepository {
+    pool: SqlitePool,
+}
+
+impl IncidentRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 인시던트 저장 (이미 있으면 무시)
+    pub async fn insert(&self, record: &IncidentRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT OR IGNORE INTO incidents
+             (id, title, content, priority, notification_type, source, status, escalation_count, created_at,
+              acknowledged_by, acknowledged_at, resolved_by, resolved_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&record.id)
+        .bind(&record.title)
+        .bind(&record.content)
+        .bind(&record.priority)
+        .bind(&record.notification_type)
+        .bind(&record.source)
+        .bind(&record.status)
+        .bind(record.escalation_count)
+        .bind(record.created_at)
+        .bind(&record.acknowledged_by)
+        .bind(record.acknowledged_at)
+        .bind(&record.resolved_by)
+        .bind(record.resolved_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 상태 및 확인/해결 정보 갱신
+    pub async fn update(&self, record: &IncidentRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "UPDATE incidents SET
+                status = ?, escalation_count = ?, acknowledged_by = ?, acknowledged_at = ?,
+                resolved_by = ?, resolved_at = ?
+             WHERE id = ?"
+        )
+        .bind(&record.status)
+        .bind(record.escalation_count)
+        .bind(&record.acknowledged_by)
+        .bind(record.acknowledged_at)
+        .bind(&record.resolved_by)
+        .bind(record.resolved_at)
+        .bind(&record.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// ID로 조회
+    pub async fn find(&self, id: &str) -> Result<Option<IncidentRecord>, SqlxError> {
+        let record = sqlx::query_as::<_, IncidentRecord>(
+            "SELECT id, title, content, priority, notification_type, source, status, escalation_count, created_at,
+                    acknowledged_by, acknowledged_at, resolved_by, resolved_at
+             FROM incidents
+             WHERE id = ?"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// 상태별 조회 (최신순, 해결된 인시던트는 해결 시각순)
+    pub async fn find_by_status(&self, statuses: &[&str], limit: i64) -> Result<Vec<IncidentRecord>, SqlxError> {
+        let placeholders = vec!["?"; statuses.len()].join(", ");
+        let sql = format!(
+            "SELECT id, title, content, priority, notification_type, source, status, escalation_count, created_at,
+                    acknowledged_by, acknowledged_at, resolved_by, resolved_at
+             FROM incidents
+             WHERE status IN ({})
+             ORDER BY COALESCE(resolved_at, created_at) DESC
+             LIMIT ?",
+            placeholders
+        );
+        let mut query = sqlx::query_as::<_, IncidentRecord>(&sql);
+        for status in statuses {
+            query = query.bind(*status);
+        }
+        let records = query.bind(limit).fetch_all(&self.pool).await?;
+
+        Ok(records)
+    }
+
+    /// 메모 추가
+    pub async fn add_note(&self, note: &IncidentNoteRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO incident_notes (incident_id, author, note, created_at)
+             VALUES (?, ?, ?, ?)"
+        )
+        .bind(&note.incident_id)
+        .bind(&note.author)
+        .bind(&note.note)
+        .bind(note.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 인시던트 메모 조회 (작성순)
+    pub async fn find_notes(&self, incident_id: &str) -> Result<Vec<IncidentNoteRecord>, SqlxError> {
+        let notes = sqlx::query_as::<_, IncidentNoteRecord>(
+            "SELECT id, incident_id, author, note, created_at
+             FROM incident_notes
+             WHERE incident_id = ?
+             ORDER BY id"
+        )
+        .bind(incident_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(notes)
+    }
+}
+
+/// 계정별 주문/체결 이벤트 Repository
+pub struct PrivateEventRepository {
+    pool: SqlitePool,
+}
+
+impl PrivateEventRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 시퀀스 이후 이벤트 조회 (시퀀스순)
+    pub async fn find_after(&self, client_id: &str, after_sequence: i64, limit: i64) -> Result<Vec<PrivateEventRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, PrivateEventRecord>(
+            "SELECT client_id, sequence, payload
+             FROM private_events
+             WHERE client_id = ? AND sequence > ?
+             ORDER BY sequence
+             LIMIT ?"
+        )
+        .bind(client_id)
+        .bind(after_sequence)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 계정별 마지막 시퀀스 (재시작 후 시퀀스 이어가기)
+    pub async fn last_sequences(&self) -> Result<Vec<(String, i64)>, SqlxError> {
+        let rows = sqlx::query_as::<_, (String, i64)>(
+            "SELECT client_id, MAX(sequence) FROM private_events GROUP BY client_id"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows)
+    }
+}
+
+/// 계정별 거래 통계 Repository
+pub struct ClientStatsRepository {
+    pool: SqlitePool,
+}
+
+impl ClientStatsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 체결 시각(초)이 `since` 이후인 체결의 계정·심볼별 메이커/테이커 집계
+    pub async fn volumes_since(&self, since: i64) -> Result<Vec<ClientVolumeRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, ClientVolumeRecord>(
+            "SELECT o.client_id, e.symbol,
+                    SUM(CASE WHEN o.order_id = e.maker_order_id THEN e.price * e.quantity ELSE 0 END) AS maker_notional,
+                    SUM(CASE WHEN o.order_id = e.taker_order_id THEN e.price * e.quantity ELSE 0 END) AS taker_notional,
+                    SUM(CASE WHEN o.order_id = e.maker_order_id THEN 1 ELSE 0 END) AS maker_trades,
+                    SUM(CASE WHEN o.order_id = e.taker_order_id THEN 1 ELSE 0 END) AS taker_trades
+             FROM executions e
+             JOIN orders o ON o.order_id IN (e.maker_order_id, e.taker_order_id)
+             WHERE e.transaction_time >= ? AND e.quantity > 0
+             GROUP BY o.client_id, e.symbol"
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 접수 시각(초)이 `since` 이후인 주문의 계정별 주문 수와 취소 수
+    pub async fn order_counts_since(&self, since: i64) -> Result<Vec<ClientOrderCountRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, ClientOrderCountRecord>(
+            "SELECT client_id,
+                    COUNT(*) AS orders,
+                    SUM(CASE WHEN status = 'Canceled' THEN 1 ELSE 0 END) AS canceled_orders
+             FROM orders
+             WHERE created_at >= datetime(?, 'unixepoch')
+             GROUP BY client_id"
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 계정별 수수료 등급 이력 Repository
+pub struct FeeTierHistoryRepository {
+    pool: SqlitePool,
+}
+
+impl FeeTierHistoryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 등급 변경 기록
+    pub async fn save(&self, record: &FeeTierHistoryRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO fee_tier_history (client_id, tier, volume_30d, maker_fee_bps, taker_fee_bps, effective_at)
+             VALUES (?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&record.client_id)
+        .bind(&record.tier)
+        .bind(record.volume_30d)
+        .bind(record.maker_fee_bps)
+        .bind(record.taker_fee_bps)
+        .bind(record.effective_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 계정별 현재 등급 (가장 최근 이력)
+    pub async fn find_current(&self) -> Result<Vec<FeeTierHistoryRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, FeeTierHistoryRecord>(
+            "SELECT h.id, h.client_id, h.tier, h.volume_30d, h.maker_fee_bps, h.taker_fee_bps, h.effective_at
+             FROM fee_tier_history h
+             WHERE h.id = (SELECT MAX(id) FROM fee_tier_history WHERE client_id = h.client_id)"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 계정 등급 이력 (최신순)
+    pub async fn find_by_client(&self, client_id: &str, limit: i64) -> Result<Vec<FeeTierHistoryRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, FeeTierHistoryRecord>(
+            "SELECT id, client_id, tier, volume_30d, maker_fee_bps, taker_fee_bps, effective_at
+             FROM fee_tier_history
+             WHERE client_id = ?
+             ORDER BY id DESC
+             LIMIT ?"
+        )
+        .bind(client_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 킬 스위치 저장소
+pub struct KillSwitchRepository {
+    pool: SqlitePool,
+}
+
+impl KillSwitchRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 킬 스위치 저장 (대상당 한 행, 있으면 갱신)
+    pub async fn upsert(&self, record: &KillSwitchRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO kill_switches (scope, target, reason, activated_by, activated_at)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(scope, target) DO UPDATE SET
+                reason = excluded.reason,
+                activated_by = excluded.activated_by,
+                activated_at = excluded.activated_at"
+        )
+        .bind(&record.scope)
+        .bind(&record.target)
+        .bind(&record.reason)
+        .bind(&record.activated_by)
+        .bind(record.activated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 킬 스위치 해제 (삭제된 행이 있으면 true)
+    pub async fn delete(&self, scope: &str, target: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM kill_switches WHERE scope = ? AND target = ?")
+            .bind(scope)
+            .bind(target)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 발동 중인 킬 스위치 전체 조회
+    pub async fn find_all(&self) -> Result<Vec<KillSwitchRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, KillSwitchRecord>(
+            "SELECT scope, target, reason, activated_by, activated_at FROM kill_switches"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 계정별 사전 리스크 한도 저장소
+pub struct RiskLimitRepository {
+    pool: SqlitePool,
+}
+
+impl RiskLimitRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 계정 한도 저장 (계정당 한 행, 있으면 갱신)
+    pub async fn upsert(&self, record: &RiskLimitRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO risk_limits (client_id, max_open_orders, max_order_notional, max_net_position, updated_by, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?)
+             ON CONFLICT(client_id) DO UPDATE SET
+                max_open_orders = excluded.max_open_orders,
+                max_order_notional = excluded.max_order_notional,
+                max_net_position = excluded.max_net_position,
+                updated_by = excluded.updated_by,
+                updated_at = excluded.updated_at"
+        )
+        .bind(&record.client_id)
+        .bind(record.max_open_orders)
+        .bind(record.max_order_notional)
+        .bind(&record.max_net_position)
+        .bind(&record.updated_by)
+        .bind(record.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 계정 한도 삭제 (삭제된 행이 있으면 true)
+    pub async fn delete(&self, client_id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM risk_limits WHERE client_id = ?")
+            .bind(client_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 계정 한도 전체 조회
+    pub async fn find_all(&self) -> Result<Vec<RiskLimitRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, RiskLimitRecord>(
+            "SELECT client_id, max_open_orders, max_order_notional, max_net_position, updated_by, updated_at
+             FROM risk_limits"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 저장된 체결로 계산한 계정·심볼별 순포지션
+    ///
+    /// 체결 행의 `side`는 테이커 방향이므로 메이커 쪽은 반대 방향으로 셉니다.
+    /// 보존 기간이 지나 삭제한 체결분은 `net_position_carryover`에 이월돼 있으므로 더합니다.
+    pub async fn net_positions(&self) -> Result<Vec<NetPositionRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, NetPositionRecord>(
+            "SELECT client_id, symbol, SUM(net_quantity) AS net_quantity
+             FROM (
+                 SELECT o.client_id, e.symbol,
+                        CASE WHEN (o.order_id = e.taker_order_id) = (e.side = 'Buy')
+                             THEN e.quantity ELSE -e.quantity END AS net_quantity
+                 FROM executions e
+                 JOIN orders o ON o.order_id IN (e.maker_order_id, e.taker_order_id)
+                 WHERE e.quantity > 0
+                 UNION ALL
+                 SELECT client_id, symbol, net_quantity FROM net_position_carryover
+             )
+             GROUP BY client_id, symbol"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 데이터 보존 저장소 (오래된 체결 일별 집계, 감사 로그 정리, DB 파일 크기)
+pub struct RetentionRepository {
+    pool: SqlitePool,
+}
+
+impl RetentionRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 체결 시각(초)이 `before` 이전인 체결이 있는 날짜 (UTC 자정, 오래된 순, 최대 `limit`일)
+    ///
+    /// `archived_only`면 콜드 스토리지에 체결을 보관한 날짜만 반환합니다.
+    pub async fn execution_days_before(&self, before: i64, archived_only: bool, limit: i64) -> Result<Vec<i64>, SqlxError> {
+        let days: Vec<(i64,)> = sqlx::query_as(
+            "SELECT DISTINCT transaction_time - transaction_time % 86400 AS day
+             FROM executions
+             WHERE transaction_time < ?1
+               AND (NOT ?2 OR transaction_time - transaction_time % 86400 IN
+                    (SELECT day FROM archive_manifest WHERE dataset = 'executions'))
+             ORDER BY day
+             LIMIT ?3"
+        )
+        .bind(before)
+        .bind(archived_only)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(days.into_iter().map(|(day,)| day).collect())
+    }
+
+    /// 하루치 체결을 일별 집계와 순포지션 이월분에 더하고 원본 삭제 (한 트랜잭션, 삭제한 행 수 반환)
+    ///
+    /// 같은 날짜를 다시 접으면(늦게 저장된 체결) 기존 집계에 합칩니다.
+    pub async fn roll_up_execution_day(&self, day: i64) -> Result<u64, SqlxError> {
+        let mut tx = self.pool.begin().await?;
+
+        // SQLite upsert는 INSERT ... SELECT에 WHERE가 있어야 ON CONFLICT를 구분함
+        sqlx::query(
+            "INSERT INTO execution_daily_aggregates
+                 (day, symbol, trade_count, volume, notional, open_price, high_price, low_price, close_price, taker_fees, maker_fees)
+             SELECT ?1, symbol, COUNT(*), SUM(quantity), SUM(price * quantity),
+                    MAX(CASE WHEN first_rank = 1 THEN price END), MAX(price), MIN(price),
+                    MAX(CASE WHEN last_rank = 1 THEN price END), SUM(taker_fee), SUM(maker_fee)
+             FROM (
+                 SELECT symbol, price, quantity, taker_fee, maker_fee,
+                        ROW_NUMBER() OVER (PARTITION BY symbol ORDER BY transaction_time, exec_id) AS first_rank,
+                        ROW_NUMBER() OVER (PARTITION BY symbol ORDER BY transaction_time DESC, exec_id DESC) AS last_rank
+                 FROM executions
+                 WHERE transaction_time >= ?1 AND transaction_time < ?1 + 86400 AND quantity > 0
+             )
+             WHERE true
+             GROUP BY symbol
+             ON CONFLICT(day, symbol) DO UPDATE SET
+                 trade_count = trade_count + excluded.trade_count,
+                 volume = volume + excluded.volume,
+                 notional = notional + excluded.notional,
+                 high_price = MAX(high_price, excluded.high_price),
+                 low_price = MIN(low_price, excluded.low_price),
+                 close_price = excluded.close_price,
+                 taker_fees = taker_fees + excluded.taker_fees,
+                 maker_fees = maker_fees + excluded.maker_fees"
+        )
+        .bind(day)
+        .execute(&mut *tx)
+        .await?;
+
+        // 재시작 시 순포지션 재계산(`RiskLimitRepository::net_positions`)에 쓰도록 이월
+        sqlx::query(
+            "INSERT INTO net_position_carryover (client_id, symbol, net_quantity)
+             SELECT o.client_id, e.symbol,
+                    SUM(CASE WHEN (o.order_id = e.taker_order_id) = (e.side = 'Buy')
+                             THEN e.quantity ELSE -e.quantity END)
+             FROM executions e
+             JOIN orders o ON o.order_id IN (e.maker_order_id, e.taker_order_id)
+             WHERE e.transaction_time >= ?1 AND e.transaction_time < ?1 + 86400 AND e.quantity > 0
+             GROUP BY o.client_id, e.symbol
+             ON CONFLICT(client_id, symbol) DO UPDATE SET net_quantity = net_quantity + excluded.net_quantity"
+        )
+        .bind(day)
+        .execute(&mut *tx)
+        .await?;
+
+        let deleted = sqlx::query("DELETE FROM executions WHERE transaction_time >= ?1 AND transaction_time < ?1 + 86400")
+            .bind(day)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+        tx.commit().await?;
+        Ok(deleted)
+    }
+
+    /// 기록 시각(초)이 `before` 이전인 감사 로그를 오래된 순으로 최대 `limit`건 삭제
+    ///
+    /// `archived_only`면 콜드 스토리지에 감사 로그를 보관한 날짜의 행만 지웁니다.
+    pub async fn delete_audit_logs_before(&self, before: i64, archived_only: bool, limit: i64) -> Result<u64, SqlxError> {
+        let result = sqlx::query(
+            "DELETE FROM audit_logs WHERE id IN (
+                 SELECT id FROM audit_logs
+                 WHERE timestamp < datetime(?1, 'unixepoch')
+                   AND (NOT ?2 OR CAST(strftime('%s', timestamp) AS INTEGER) / 86400 * 86400 IN
+                        (SELECT day FROM archive_manifest WHERE dataset = 'audit_logs'))
+                 ORDER BY id LIMIT ?3
+             )"
+        )
+        .bind(before)
+        .bind(archived_only)
+        .bind(limit)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// DB 파일 크기와 그중 빈 페이지 크기 (바이트)
+    pub async fn storage_bytes(&self) -> Result<(i64, i64), SqlxError> {
+        sqlx::query_as(
+            "SELECT p.page_count * s.page_size, f.freelist_count * s.page_size
+             FROM pragma_page_count() p, pragma_freelist_count() f, pragma_page_size() s"
+        )
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    /// 빈 페이지를 돌려주도록 DB 파일 재작성 (실행 중에는 쓰기가 막힘)
+    pub async fn vacuum(&self) -> Result<(), SqlxError> {
+        sqlx::query("VACUUM").execute(&self.pool).await?;
+        Ok(())
+    }
+
+    /// 심볼 일별 집계 (날짜 범위 `[from_day, to_day]`, 날짜순)
+    pub async fn daily_aggregates(&self, symbol: &str, from_day: i64, to_day: i64) -> Result<Vec<ExecutionDailyAggregateRecord>, SqlxError> {
+        sqlx::query_as::<_, ExecutionDailyAggregateRecord>(
+            "SELECT day, symbol, trade_count, volume, notional, open_price, high_price, low_price, close_price, taker_fees, maker_fees
+             FROM execution_daily_aggregates
+             WHERE symbol = ? AND day >= ? AND day <= ?
+             ORDER BY day"
+        )
+        .bind(symbol)
+        .bind(from_day)
+        .bind(to_day)
+        .fetch_all(&self.pool)
+        .await
+    }
+}
+
+/// 콜드 스토리지 보관 저장소 (날짜별 원본 조회, 보관 기록)
+pub struct ArchiveRepository {
+    pool: SqlitePool,
+}
+
+impl ArchiveRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// `before`(UTC 자정, 초) 이전에 체결이나 감사 로그가 남아 있는 날짜 (오래된 순, 최대 `limit`일)
+    ///
+    /// `archived`가 true면 모든 데이터셋(`datasets`개)을 이미 보관한 날짜만, false면 아직 덜 보관한 날짜만 반환합니다.
+    pub async fn local_days_before(&self, before: i64, datasets: i64, archived: bool, limit: i64) -> Result<Vec<i64>, SqlxError> {
+        let days: Vec<(i64,)> = sqlx::query_as(
+            "SELECT day FROM (
+                 SELECT DISTINCT transaction_time - transaction_time % 86400 AS day FROM executions WHERE transaction_time < ?1
+                 UNION
+                 SELECT DISTINCT CAST(strftime('%s', timestamp) AS INTEGER) / 86400 * 86400 AS day
+                 FROM audit_logs WHERE timestamp < datetime(?1, 'unixepoch')
+             ) d
+             WHERE ((SELECT COUNT(*) FROM archive_manifest m WHERE m.day = d.day) >= ?2) = ?3
+             ORDER BY day
+             LIMIT ?4"
+        )
+        .bind(before)
+        .bind(datasets)
+        .bind(archived)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(days.into_iter().map(|(day,)| day).collect())
+    }
+
+    /// 하루치 체결 페이지 ((체결 시각, 체결 ID) 순, `after` 다음부터)
+    pub async fn executions_page(&self, day: i64, after: Option<(i64, &str)>, limit: i64) -> Result<Vec<ExecutionRecord>, SqlxError> {
+        let (after_time, after_id) = after.unwrap_or((i64::MIN, ""));
+        sqlx::query_as::<_, ExecutionRecord>(
+            "SELECT exec_id, taker_order_id, maker_order_id, symbol, side, price, quantity, taker_fee, maker_fee, transaction_time
+             FROM executions
+             WHERE transaction_time >= ?1 AND transaction_time < ?1 + 86400
+               AND (transaction_time > ?2 OR (transaction_time = ?2 AND exec_id > ?3))
+             ORDER BY transaction_time ASC, exec_id ASC
+             LIMIT ?4"
+        )
+        .bind(day)
+        .bind(after_time)
+        .bind(after_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// 하루치 체결로 계산한 심볼별 1분봉 (심볼, 시각 순)
+    pub async fn minute_candles(&self, day: i64) -> Result<Vec<MinuteCandleRecord>, SqlxError> {
+        sqlx::query_as::<_, MinuteCandleRecord>(
+            "SELECT symbol, open_time,
+                    MAX(CASE WHEN first_rank = 1 THEN price END) AS open_price,
+                    MAX(price) AS high_price, MIN(price) AS low_price,
+                    MAX(CASE WHEN last_rank = 1 THEN price END) AS close_price,
+                    SUM(quantity) AS volume, COUNT(*) AS trade_count
+             FROM (
+                 SELECT symbol, price, quantity, transaction_time - transaction_time % 60 AS open_time,
+                        ROW_NUMBER() OVER (PARTITION BY symbol, transaction_time - transaction_time % 60 ORDER BY transaction_time, exec_id) AS first_rank,
+                        ROW_NUMBER() OVER (PARTITION BY symbol, transaction_time - transaction_time % 60 ORDER BY transaction_time DESC, exec_id DESC) AS last_rank
+                 FROM executions
+                 WHERE transaction_time >= ?1 AND transaction_time < ?1 + 86400 AND quantity > 0
+             )
+             GROUP BY symbol, open_time
+             ORDER BY symbol, open_time"
+        )
+        .bind(day)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// 하루치 감사 로그 페이지 (ID 순, `after_id` 다음부터)
+    pub async fn audit_logs_page(&self, day: i64, after_id: i64, limit: i64) -> Result<Vec<AuditLogRecord>, SqlxError> {
+        sqlx::query_as::<_, AuditLogRecord>(
+            "SELECT id, event_type, entity_type, entity_id, details, timestamp
+             FROM audit_logs
+             WHERE timestamp >= datetime(?1, 'unixepoch') AND timestamp < datetime(?1 + 86400, 'unixepoch') AND id > ?2
+             ORDER BY id
+             LIMIT ?3"
+        )
+        .bind(day)
+        .bind(after_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// 하루치 감사 로그 삭제 (보관 후 정리)
+    pub async fn delete_audit_logs_for_day(&self, day: i64) -> Result<u64, SqlxError> {
+        let result = sqlx::query("DELETE FROM audit_logs WHERE timestamp >= datetime(?1, 'unixepoch') AND timestamp < datetime(?1 + 86400, 'unixepoch')")
+            .bind(day)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// 보관 기록 저장
+    pub async fn record(&self, record: &ArchiveManifestRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO archive_manifest (day, dataset, object_key, row_count, size_bytes, sha256, archived_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(record.day)
+        .bind(&record.dataset)
+        .bind(&record.object_key)
+        .bind(record.row_count)
+        .bind(record.size_bytes)
+        .bind(&record.sha256)
+        .bind(record.archived_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 날짜별 보관 기록
+    pub async fn manifest(&self, day: i64) -> Result<Vec<ArchiveManifestRecord>, SqlxError> {
+        sqlx::query_as::<_, ArchiveManifestRecord>(
+            "SELECT day, dataset, object_key, row_count, size_bytes, sha256, archived_at
+             FROM archive_manifest WHERE day = ? ORDER BY dataset"
+        )
+        .bind(day)
+        .fetch_all(&self.pool)
+        .await
+    }
+}
+
+/// API 키 저장소
+pub struct ApiKeyRepository {
+    pool: SqlitePool,
+}
+
+impl ApiKeyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// API 키 저장
+    pub async fn insert(&self, record: &ApiKeyRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO api_keys (key_id, key_hash, client_id, scopes, created_by, created_at, revoked_at, ip_allowlist)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&record.key_id)
+        .bind(&record.key_hash)
+        .bind(&record.client_id)
+        .bind(&record.scopes)
+        .bind(&record.created_by)
+        .bind(record.created_at)
+        .bind(record.revoked_at)
+        .bind(&record.ip_allowlist)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 폐기되지 않은 API 키
+    pub async fn find_active(&self) -> Result<Vec<ApiKeyRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, ApiKeyRecord>(
+            "SELECT key_id, key_hash, client_id, scopes, created_by, created_at, revoked_at, ip_allowlist
+             FROM api_keys
+             WHERE revoked_at IS NULL"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 사용 중인 API 키의 IP 허용 목록 변경 (폐기됐거나 없으면 false)
+    pub async fn update_ip_allowlist(&self, key_id: &str, ip_allowlist: Option<&str>) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE api_keys SET ip_allowlist = ? WHERE key_id = ? AND revoked_at IS NULL")
+            .bind(ip_allowlist)
+            .bind(key_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// API 키 폐기 (이미 폐기됐거나 없으면 false)
+    pub async fn revoke(&self, key_id: &str, revoked_at: i64) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE api_keys SET revoked_at = ? WHERE key_id = ? AND revoked_at IS NULL")
+            .bind(revoked_at)
+            .bind(key_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// 리프레시 토큰 저장소
+pub struct RefreshTokenRepository {
+    pool: SqlitePool,
+}
+
+impl RefreshTokenRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 리프레시 토큰 저장
+    pub async fn insert(&self, record: &RefreshTokenRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO refresh_tokens
+             (token_hash, family_id, key_id, client_id, scopes, created_at, expires_at, rotated_at, revoked_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&record.token_hash)
+        .bind(&record.family_id)
+        .bind(&record.key_id)
+        .bind(&record.client_id)
+        .bind(&record.scopes)
+        .bind(record.created_at)
+        .bind(record.expires_at)
+        .bind(record.rotated_at)
+        .bind(record.revoked_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 토큰 해시로 조회
+    pub async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshTokenRecord>, SqlxError> {
+        let record = sqlx::query_as::<_, RefreshTokenRecord>(
+            "SELECT token_hash, family_id, key_id, client_id, scopes, created_at, expires_at, rotated_at, revoked_at
+             FROM refresh_tokens
+             WHERE token_hash = ?"
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// 회전 표시 (이미 회전됐거나 폐기됐으면 false, 동시 갱신 요청 중 하나만 성공)
+    pub async fn mark_rotated(&self, token_hash: &str, rotated_at: i64) -> Result<bool, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE refresh_tokens SET rotated_at = ?
+             WHERE token_hash = ? AND rotated_at IS NULL AND revoked_at IS NULL"
+        )
+        .bind(rotated_at)
+        .bind(token_hash)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 토큰 묶음 전체 폐기 (로그아웃, 재사용 탐지)
+    pub async fn revoke_family(&self, family_id: &str, revoked_at: i64) -> Result<u64, SqlxError> {
+        let result = sqlx::query("UPDATE refresh_tokens SET revoked_at = ? WHERE family_id = ? AND revoked_at IS NULL")
+            .bind(revoked_at)
+            .bind(family_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// API 키로 발급된 토큰 모두 폐기 (API 키 폐기 시)
+    pub async fn revoke_by_key(&self, key_id: &str, revoked_at: i64) -> Result<u64, SqlxError> {
+        let result = sqlx::query("UPDATE refresh_tokens SET revoked_at = ? WHERE key_id = ? AND revoked_at IS NULL")
+            .bind(revoked_at)
+            .bind(key_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// 관리자 TOTP 저장소
+pub struct AdminTotpRepository {
+    pool: SqlitePool,
+}
+
+impl AdminTotpRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 비밀 키 저장 (재발급이면 확인 상태 초기화)
+    pub async fn upsert(&self, record: &AdminTotpRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO admin_totp (admin_user, secret, created_at, confirmed_at, last_used_step)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(admin_user) DO UPDATE SET
+                secret = excluded.secret,
+                created_at = excluded.created_at,
+                confirmed_at = excluded.confirmed_at,
+                last_used_step = excluded.last_used_step"
+        )
+        .bind(&record.admin_user)
+        .bind(&record.secret)
+        .bind(record.created_at)
+        .bind(record.confirmed_at)
+        .bind(record.last_used_step)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 모든 관리자 TOTP
+    pub async fn find_all(&self) -> Result<Vec<AdminTotpRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, AdminTotpRecord>(
+            "SELECT admin_user, secret, created_at, confirmed_at, last_used_step FROM admin_totp"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 코드 사용 기록 (등록 확인 포함)
+    pub async fn record_use(&self, admin_user: &str, step: i64, confirmed_at: i64) -> Result<(), SqlxError> {
+        sqlx::query(
+            "UPDATE admin_totp SET last_used_step = ?, confirmed_at = COALESCE(confirmed_at, ?)
+             WHERE admin_user = ?"
+        )
+        .bind(step)
+        .bind(confirmed_at)
+        .bind(admin_user)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// 역할별 권한과 역할 지정 저장소 (RBAC)
+pub struct RbacRepository {
+    pool: SqlitePool,
+}
+
+impl RbacRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 역할 권한이 없을 때만 저장 (기본 권한 채우기, 저장했으면 true)
+    pub async fn insert_role_if_missing(&self, record: &RolePermissionsRecord) -> Result<bool, SqlxError> {
+        let result = sqlx::query(
+            "INSERT OR IGNORE INTO role_permissions (role, permissions, updated_by, updated_at)
+             VALUES (?, ?, ?, ?)"
+        )
+        .bind(&record.role)
+        .bind(&record.permissions)
+        .bind(&record.updated_by)
+        .bind(record.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 역할 권한 저장 (역할당 한 행, 있으면 갱신)
+    pub async fn upsert_role(&self, record: &RolePermissionsRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO role_permissions (role, permissions, updated_by, updated_at)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(role) DO UPDATE SET
+                permissions = excluded.permissions,
+                updated_by = excluded.updated_by,
+                updated_at = excluded.updated_at"
+        )
+        .bind(&record.role)
+        .bind(&record.permissions)
+        .bind(&record.updated_by)
+        .bind(record.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 모든 역할 권한
+    pub async fn find_roles(&self) -> Result<Vec<RolePermissionsRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, RolePermissionsRecord>(
+            "SELECT role, permissions, updated_by, updated_at FROM role_permissions"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 역할 지정 저장 (대상당 한 행, 있으면 갱신)
+    pub async fn upsert_assignment(&self, record: &RoleAssignmentRecord) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO role_assignments (subject_kind, subject, role, assigned_by, assigned_at)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(subject_kind, subject) DO UPDATE SET
+                role = excluded.role,
+                assigned_by = excluded.assigned_by,
+                assigned_at = excluded.assigned_at"
+        )
+        .bind(&record.subject_kind)
+        .bind(&record.subject)
+        .bind(&record.role)
+        .bind(&record.assigned_by)
+        .bind(record.assigned_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 역할 지정 해제 (삭제된 행이 있으면 true)
+    pub async fn delete_assignment(&self, subject_kind: &str, subject: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM role_assignments WHERE subject_kind = ? AND subject = ?")
+            .bind(subject_kind)
+            .bind(subject)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 모든 역할 지정
+    pub async fn find_assignments(&self) -> Result<Vec<RoleAssignmentRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, RoleAssignmentRecord>(
+            "SELECT subject_kind, subject, role, assigned_by, assigned_at FROM role_assignments"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 계정별 분당 메시지 수 저장소
+pub struct MessageUsageRepository {
+    pool: SqlitePool,
+}
+
+impl MessageUsageRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 분당 메시지 수 더하기 (계정·분당 한 행, 있으면 누적)
+    pub async fn add(&self, records: &[MessageUsageRecord]) -> Result<(), SqlxError> {
+        let mut tx = self.pool.begin().await?;
+        for record in records {
+            sqlx::query(
+                "INSERT INTO client_message_usage (client_id, minute, orders, cancels, queries, rejected)
+                 VALUES (?, ?, ?, ?, ?, ?)
+                 ON CONFLICT(client_id, minute) DO UPDATE SET
+                    orders = orders + excluded.orders,
+                    cancels = cancels + excluded.cancels,
+                    queries = queries + excluded.queries,
+                    rejected = rejected + excluded.rejected"
+            )
+            .bind(&record.client_id)
+            .bind(record.minute)
+            .bind(record.orders)
+            .bind(record.cancels)
+            .bind(record.queries)
+            .bind(record.rejected)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// 계정의 기간 [from, to) 분당 메시지 수 (분 순)
+    pub async fn find_by_client(&self, client_id: &str, from: i64, to: i64) -> Result<Vec<MessageUsageRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, MessageUsageRecord>(
+            "SELECT client_id, minute, orders, cancels, queries, rejected
+             FROM client_message_usage
+             WHERE client_id = ? AND minute >= ? AND minute < ?
+             ORDER BY minute"
+        )
+        .bind(client_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 기간 [from, to) 계정별 메시지 수 합계 (많은 순)
+    pub async fn summarize(&self, from: i64, to: i64, limit: i64) -> Result<Vec<MessageUsageSummaryRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, MessageUsageSummaryRecord>(
+            "SELECT client_id,
+                    SUM(orders) AS orders,
+                    SUM(cancels) AS cancels,
+                    SUM(queries) AS queries,
+                    SUM(rejected) AS rejected,
+                    MAX(orders + cancels + queries) AS peak_per_minute
+             FROM client_message_usage
+             WHERE minute >= ? AND minute < ?
+             GROUP BY client_id
+             ORDER BY SUM(orders + cancels + queries) DESC, client_id
+             LIMIT ?"
+        )
+        .bind(from)
+        .bind(to)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 기준 시각 이전 분 삭제 (삭제한 행 수)
+    pub async fn delete_before(&self, minute: i64) -> Result<u64, SqlxError> {
+        let result = sqlx::query("DELETE FROM client_message_usage WHERE minute < ?")
+            .bind(minute)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// 일별 최우선 호가 유동성 공급 저장소
+pub struct MakerLiquidityRepository {
+    pool: SqlitePool,
+}
+
+impl MakerLiquidityRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 일별 유동성 공급 더하기 (날짜·심볼·계정당 한 행, 있으면 누적)
+    pub async fn add(&self, records: &[MakerLiquidityRecord]) -> Result<(), SqlxError> {
+        let mut tx = self.pool.begin().await?;
+        for record in records {
+            sqlx::query(
+                "INSERT INTO maker_liquidity_daily (day, symbol, client_id, bid_time_ms, ask_time_ms, bid_size_ms, ask_size_ms)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)
+                 ON CONFLICT(day, symbol, client_id) DO UPDATE SET
+                    bid_time_ms = bid_time_ms + excluded.bid_time_ms,
+                    ask_time_ms = ask_time_ms + excluded.ask_time_ms,
+                    bid_size_ms = bid_size_ms + excluded.bid_size_ms,
+                    ask_size_ms = ask_size_ms + excluded.ask_size_ms"
+            )
+            .bind(record.day)
+            .bind(&record.symbol)
+            .bind(&record.client_id)
+            .bind(record.bid_time_ms)
+            .bind(record.ask_time_ms)
+            .bind(record.bid_size_ms)
+            .bind(record.ask_size_ms)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// 기간 [from_day, to_day) 심볼·계정별 합계 (`day`는 구간 시작, 심볼·계정 순)
+    pub async fn sum_between(&self, from_day: i64, to_day: i64) -> Result<Vec<MakerLiquidityRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, MakerLiquidityRecord>(
+            "SELECT ? AS day, symbol, client_id,
+                    SUM(bid_time_ms) AS bid_time_ms,
+                    SUM(ask_time_ms) AS ask_time_ms,
+                    SUM(bid_size_ms) AS bid_size_ms,
+                    SUM(ask_size_ms) AS ask_size_ms
+             FROM maker_liquidity_daily
+             WHERE day >= ? AND day < ?
+             GROUP BY symbol, client_id
+             ORDER BY symbol, client_id"
+        )
+        .bind(from_day)
+        .bind(from_day)
+        .bind(to_day)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 펀딩 정산 저장소 (정산 기록, 계정별 원장, 잔고 반영)
+pub struct FundingRepository {
+    pool: SqlitePool,
+}
+
+impl FundingRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 정산 기록과 계정별 원장을 저장하고 호가 자산 잔고에 더함 (한 트랜잭션)
+    ///
+    /// 같은 심볼·정산 시각이 이미 있으면 아무것도 바꾸지 않고 false를 반환합니다.
+    pub async fn settle(
+        &self,
+        settlement: &FundingSettlementRecord,
+        payments: &[FundingPaymentRecord],
+    ) -> Result<bool, SqlxError> {
+        let mut tx = self.pool.begin().await?;
+        let inserted = sqlx::query(
+            "INSERT OR IGNORE INTO funding_settlements
+                (symbol, funding_time, funding_rate, index_price, last_price, mark_price,
+                 long_positions, short_positions, total_paid, total_received)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&settlement.symbol)
+        .bind(settlement.funding_time)
+        .bind(settlement.funding_rate)
+        .bind(settlement.index_price)
+        .bind(settlement.last_price)
+        .bind(settlement.mark_price)
+        .bind(settlement.long_positions)
+        .bind(settlement.short_positions)
+        .bind(settlement.total_paid)
+        .bind(settlement.total_received)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if inserted == 0 {
+            tx.rollback().await?;
+            return Ok(false);
+        }
+
+        for payment in payments {
+            sqlx::query(
+                "INSERT INTO funding_payments (symbol, funding_time, client_id, asset, position, amount)
+                 VALUES (?, ?, ?, ?, ?, ?)"
+            )
+            .bind(&payment.symbol)
+            .bind(payment.funding_time)
+            .bind(&payment.client_id)
+            .bind(&payment.asset)
+            .bind(payment.position)
+            .bind(payment.amount)
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                "INSERT INTO balances (client_id, asset, available, locked)
+                 VALUES (?, ?, ?, 0)
+                 ON CONFLICT(client_id, asset) DO UPDATE SET
+                    available = available + excluded.available,
+                    updated_at = CURRENT_TIMESTAMP"
+            )
+            .bind(&payment.client_id)
+            .bind(&payment.asset)
+            .bind(payment.amount)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// 심볼의 최근 정산 (최신순)
+    pub async fn find_settlements(&self, symbol: &str, limit: i64) -> Result<Vec<FundingSettlementRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, FundingSettlementRecord>(
+            "SELECT symbol, funding_time, funding_rate, index_price, last_price, mark_price,
+                    long_positions, short_positions, total_paid, total_received
+             FROM funding_settlements
+             WHERE symbol = ?
+             ORDER BY funding_time DESC
+             LIMIT ?"
+        )
+        .bind(symbol)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 정산 한 건의 계정별 원장 (계정순)
+    pub async fn find_payments(&self, symbol: &str, funding_time: i64) -> Result<Vec<FundingPaymentRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, FundingPaymentRecord>(
+            "SELECT symbol, funding_time, client_id, asset, position, amount
+             FROM funding_payments
+             WHERE symbol = ? AND funding_time = ?
+             ORDER BY client_id"
+        )
+        .bind(symbol)
+        .bind(funding_time)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 만기 상품 정산 저장소 (체결 기준 포지션, 최종 정산, 계정별 원장, 잔고 반영)
+pub struct FuturesRepository {
+    pool: SqlitePool,
+}
+
+impl FuturesRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 심볼의 계정별 순포지션과 체결 대금 (체결 기록 기준, 계정순)
+    ///
+    /// 보존 기간이 지나 삭제한 체결은 대금을 알 수 없으므로 이월 포지션은 더하지 않습니다.
+    pub async fn positions(&self, symbol: &str) -> Result<Vec<FuturesPositionRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, FuturesPositionRecord>(
+            "SELECT o.client_id,
+                    SUM(CASE WHEN (o.order_id = e.taker_order_id) = (e.side = 'Buy')
+                             THEN e.quantity ELSE -e.quantity END) AS net_quantity,
+                    SUM(CASE WHEN (o.order_id = e.taker_order_id) = (e.side = 'Buy')
+                             THEN e.price * e.quantity ELSE -e.price * e.quantity END) AS net_cost
+             FROM executions e
+             JOIN orders o ON o.order_id IN (e.maker_order_id, e.taker_order_id)
+             WHERE e.symbol = ? AND e.quantity > 0
+             GROUP BY o.client_id
+             ORDER BY o.client_id"
+        )
+        .bind(symbol)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 최종 정산과 계정별 원장을 저장하고 호가 자산 잔고에 더함 (한 트랜잭션)
+    ///
+    /// 심볼이 이미 정산됐으면 아무것도 바꾸지 않고 false를 반환합니다.
+    pub async fn settle(
+        &self,
+        settlement: &FuturesSettlementRecord,
+        payments: &[FuturesSettlementPaymentRecord],
+    ) -> Result<bool, SqlxError> {
+        let mut tx = self.pool.begin().await?;
+        let inserted = sqlx::query(
+            "INSERT OR IGNORE INTO futures_settlements
+                (symbol, underlying, expiry, settlement_price, index_price, positions, total_paid, total_received, settled_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&settlement.symbol)
+        .bind(&settlement.underlying)
+        .bind(settlement.expiry)
+        .bind(settlement.settlement_price)
+        .bind(settlement.index_price)
+        .bind(settlement.positions)
+        .bind(settlement.total_paid)
+        .bind(settlement.total_received)
+        .bind(settlement.settled_at)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if inserted == 0 {
+            tx.rollback().await?;
+            return Ok(false);
+        }
+
+        for payment in payments {
+            sqlx::query(
+                "INSERT INTO futures_settlement_payments (symbol, client_id, asset, position, net_cost, amount)
+                 VALUES (?, ?, ?, ?, ?, ?)"
+            )
+            .bind(&payment.symbol)
+            .bind(&payment.client_id)
+            .bind(&payment.asset)
+            .bind(payment.position)
+            .bind(payment.net_cost)
+            .bind(payment.amount)
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                "INSERT INTO balances (client_id, asset, available, locked)
+                 VALUES (?, ?, ?, 0)
+                 ON CONFLICT(client_id, asset) DO UPDATE SET
+                    available = available + excluded.available,
+                    updated_at = CURRENT_TIMESTAMP"
+            )
+            .bind(&payment.client_id)
+            .bind(&payment.asset)
+            .bind(payment.amount)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// 정산 완료된 만기 상품 (심볼순)
+    pub async fn find_settlements(&self) -> Result<Vec<FuturesSettlementRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, FuturesSettlementRecord>(
+            "SELECT symbol, underlying, expiry, settlement_price, index_price, positions, total_paid, total_received, settled_at
+             FROM futures_settlements
+             ORDER BY symbol"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 만기 상품의 계정별 정산 원장 (계정순)
+    pub async fn find_payments(&self, symbol: &str) -> Result<Vec<FuturesSettlementPaymentRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, FuturesSettlementPaymentRecord>(
+            "SELECT symbol, client_id, asset, position, net_cost, amount
+             FROM futures_settlement_payments
+             WHERE symbol = ?
+             ORDER BY client_id"
+        )
+        .bind(symbol)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
+
+/// 시간별 성능 지표 롤업 저장소
+pub struct MetricsHistoryRepository {
+    pool: SqlitePool,
+}
+
+impl MetricsHistoryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 시간별 롤업 저장 (같은 시간이 있으면 교체)
+    pub async fn save(&self, records: &[MetricsHourlyRecord]) -> Result<(), SqlxError> {
+        let mut tx = self.pool.begin().await?;
+        for record in records {
+            sqlx::query(
+                "INSERT OR REPLACE INTO metrics_hourly
+                 (hour_start, requests, errors, p50_latency_ms, p99_latency_ms, max_latency_ms, orders)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)"
+            )
+            .bind(record.hour_start)
+            .bind(record.requests)
+            .bind(record.errors)
+            .bind(record.p50_latency_ms)
+            .bind(record.p99_latency_ms)
+            .bind(record.max_latency_ms)
+            .bind(record.orders)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// 구간 [from, to) 시간별 롤업 (밀리초, 시간순)
+    pub async fn find_between(&self, from: i64, to: i64) -> Result<Vec<MetricsHourlyRecord>, SqlxError> {
+        let records = sqlx::query_as::<_, MetricsHourlyRecord>(
+            "SELECT hour_start, requests, errors, p50_latency_ms, p99_latency_ms, max_latency_ms, orders
+             FROM metrics_hourly
+             WHERE hour_start >= ? AND hour_start < ?
+             ORDER BY hour_start"
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// 보관 기간이 지난 롤업 삭제 (`before` 밀리초 이전 시간)
+    pub async fn delete_before(&self, before: i64) -> Result<u64, SqlxError> {
+        let result = sqlx::query("DELETE FROM metrics_hourly WHERE hour_start < ?")
+            .bind(before)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,314 @@
+//! 데이터 보존 (체결/감사 로그 정리)
+//!
+//! executions, audit_logs 테이블은 계속 늘어나므로 주기적으로 정리합니다.
+//! 보존 기간이 지난 체결은 날짜(UTC)·심볼별 집계(`execution_daily_aggregates`)로 접고
+//! 계정별 순포지션은 `net_position_carryover`에 이월한 뒤 원본을 지웁니다.
+//! 감사 로그는 보존 기간이 지나면 그대로 지웁니다. 지운 뒤 빈 페이지 비율이 기준을 넘으면
+//! VACUUM으로 파일을 줄이고, 줄어든 크기를 지표로 남깁니다.
+
+use std::time::Duration;
+
+use sqlx::sqlite::SqlitePool;
+use sqlx::Error as SqlxError;
+
+use super::repository::RetentionRepository;
+use crate::performance::metrics_collector::MetricsCollector;
+
+/// 하루 (초)
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+/// 수수료 등급이 최근 30일 체결량을 보므로 체결은 그보다 짧게 보존할 수 없음
+pub const MIN_EXECUTION_RETENTION_DAYS: u64 = 31;
+
+/// 감사 로그 한 번에 지우는 건수 (쓰기 잠금을 오래 잡지 않도록)
+const AUDIT_DELETE_BATCH: i64 = 10_000;
+
+/// 보존 정책
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetentionConfig {
+    /// 원본 체결 보존 일수 (None이면 체결은 정리하지 않음)
+    pub execution_days: Option<u64>,
+    /// 감사 로그 보존 일수 (None이면 감사 로그는 정리하지 않음)
+    pub audit_log_days: Option<u64>,
+    /// 정리 주기
+    pub interval: Duration,
+    /// 정리 후 빈 페이지 비율이 이 값 이상이면 VACUUM (None이면 VACUUM 안 함)
+    pub vacuum_min_free_ratio: Option<f64>,
+    /// 한 번에 접는 최대 일수 (처음 켰을 때 밀린 기간을 나눠 처리)
+    pub max_days_per_run: usize,
+    /// 콜드 스토리지에 보관한 날짜만 정리 (보관 작업과 함께 쓸 때)
+    pub archived_only: bool,
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            execution_days: Some(90),
+            audit_log_days: Some(5 * 365),
+            interval: Duration::from_secs(DAY_SECS),
+            vacuum_min_free_ratio: Some(0.2),
+            max_days_per_run: 30,
+            archived_only: false,
+        }
+    }
+}
+
+impl RetentionConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(days) = self.execution_days.filter(|days| *days < MIN_EXECUTION_RETENTION_DAYS) {
+            return Err(format!(
+                "체결 보존 기간은 {}일 이상이어야 합니다 (수수료 등급 산정): {}",
+                MIN_EXECUTION_RETENTION_DAYS, days
+            ));
+        }
+        if self.audit_log_days == Some(0) {
+            return Err("감사 로그 보존 기간은 1일 이상이어야 합니다".to_string());
+        }
+        if self.interval.is_zero() {
+            return Err("보존 정리 주기는 0보다 커야 합니다".to_string());
+        }
+        if let Some(ratio) = self.vacuum_min_free_ratio.filter(|ratio| !(0.0..=1.0).contains(ratio)) {
+            return Err(format!("VACUUM 빈 페이지 비율은 0~1 사이여야 합니다: {}", ratio));
+        }
+        if self.max_days_per_run == 0 {
+            return Err("한 번에 접는 일수는 1 이상이어야 합니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 한 번 정리한 결과
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RetentionReport {
+    /// 일별 집계로 접은 날짜 수
+    pub rolled_up_days: usize,
+    /// 접고 지운 체결 행 수
+    pub executions_deleted: u64,
+    /// 지운 감사 로그 수
+    pub audit_logs_deleted: u64,
+    /// 정리 전 DB 파일 크기 (바이트)
+    pub size_before: u64,
+    /// 정리 후 DB 파일 크기 (바이트)
+    pub size_after: u64,
+    /// 정리 후 빈 페이지 크기 (바이트)
+    pub free_after: u64,
+    pub vacuumed: bool,
+}
+
+impl RetentionReport {
+    /// 줄어든 DB 파일 크기 (바이트)
+    pub fn reclaimed_bytes(&self) -> u64 {
+        self.size_before.saturating_sub(self.size_after)
+    }
+
+    /// 지표 기록
+    pub async fn publish(&self, metrics: &MetricsCollector) {
+        metrics.increment_counter("retention.runs", 1).await;
+        metrics.increment_counter("retention.executions.rolled_up", self.executions_deleted).await;
+        metrics.increment_counter("retention.audit_logs.deleted", self.audit_logs_deleted).await;
+        metrics.increment_counter("retention.reclaimed_bytes", self.reclaimed_bytes()).await;
+        metrics.set_gauge("retention.db.size_bytes", self.size_after).await;
+        metrics.set_gauge("retention.db.free_bytes", self.free_after).await;
+    }
+}
+
+/// 보존 정리 작업
+pub struct RetentionJob {
+    repository: RetentionRepository,
+    config: RetentionConfig,
+}
+
+impl RetentionJob {
+    pub fn new(pool: SqlitePool, config: RetentionConfig) -> Self {
+        Self { repository: RetentionRepository::new(pool), config }
+    }
+
+    pub fn config(&self) -> &RetentionConfig {
+        &self.config
+    }
+
+    /// `now_secs`(Unix 초) 기준으로 한 번 정리
+    pub async fn run(&self, now_secs: u64) -> Result<RetentionReport, SqlxError> {
+        let (size_before, _) = self.repository.storage_bytes().await?;
+        let mut report = RetentionReport { size_before: size_before as u64, ..Default::default() };
+
+        if let Some(days) = self.config.execution_days {
+            // 날짜 단위로 접으므로 기준 시각을 UTC 자정으로 내림
+            let cutoff = day_start(now_secs.saturating_sub(days * DAY_SECS));
+            let pending = self
+                .repository
+                .execution_days_before(cutoff as i64, self.config.archived_only, self.config.max_days_per_run as i64)
+                .await?;
+            for day in pending {
+                report.executions_deleted += self.repository.roll_up_execution_day(day).await?;
+                report.rolled_up_days += 1;
+            }
+        }
+
+        if let Some(days) = self.config.audit_log_days {
+            let cutoff = now_secs.saturating_sub(days * DAY_SECS) as i64;
+            loop {
+                let deleted = self
+                    .repository
+                    .delete_audit_logs_before(cutoff, self.config.archived_only, AUDIT_DELETE_BATCH)
+                    .await?;
+                report.audit_logs_deleted += deleted;
+                if deleted < AUDIT_DELETE_BATCH as u64 {
+                    break;
+                }
+            }
+        }
+
+        let (size, free) = self.repository.storage_bytes().await?;
+        let should_vacuum = self
+            .config
+            .vacuum_min_free_ratio
+            .is_some_and(|ratio| free > 0 && free as f64 >= size as f64 * ratio);
+        if should_vacuum {
+            self.repository.vacuum().await?;
+            let (size, free) = self.repository.storage_bytes().await?;
+            report.size_after = size as u64;
+            report.free_after = free as u64;
+            report.vacuumed = true;
+        } else {
+            report.size_after = size as u64;
+            report.free_after = free as u64;
+        }
+
+        Ok(report)
+    }
+}
+
+/// 시각이 속한 날의 UTC 자정 (초)
+fn day_start(secs: u64) -> u64 {
+    secs - secs % DAY_SECS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::{ExecutionRecord, NetPositionRecord, OrderRecord};
+    use crate::db::repository::{AuditLogRepository, ExecutionRepository, OrderRepository, RiskLimitRepository};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    /// 2023-11-14 00:00:00 UTC
+    const TODAY: u64 = 1_699_920_000;
+
+    fn order(order_id: &str, client_id: &str) -> OrderRecord {
+        OrderRecord {
+            order_id: order_id.to_string(),
+            client_id: client_id.to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: "Buy".to_string(),
+            order_type: "Limit".to_string(),
+            price: Some(100),
+            quantity: 10,
+            filled_quantity: 0,
+            status: "Filled".to_string(),
+            tag: None,
+        }
+    }
+
+    fn execution(exec_id: &str, taker: &str, maker: &str, price: i64, quantity: i64, transaction_time: u64) -> ExecutionRecord {
+        ExecutionRecord {
+            exec_id: exec_id.to_string(),
+            taker_order_id: taker.to_string(),
+            maker_order_id: maker.to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: "Buy".to_string(),
+            price,
+            quantity,
+            taker_fee: 2,
+            maker_fee: 1,
+            transaction_time: transaction_time as i64,
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_retention_shorter_than_fee_window() {
+        assert!(RetentionConfig::default().validate().is_ok());
+        let short = RetentionConfig { execution_days: Some(7), ..Default::default() };
+        assert!(short.validate().is_err());
+        let disabled = RetentionConfig { execution_days: None, vacuum_min_free_ratio: None, ..Default::default() };
+        assert!(disabled.validate().is_ok());
+        assert!(RetentionConfig { vacuum_min_free_ratio: Some(1.5), ..Default::default() }.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rolls_up_old_executions_and_keeps_net_positions() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        super::super::create_tables(&pool).await.unwrap();
+
+        let orders = OrderRepository::new(pool.clone());
+        for record in [order("a1", "alice"), order("b1", "bob")] {
+            orders.save(&record).await.unwrap();
+        }
+        // 100일 전 체결 3건(같은 날), 어제 체결 1건: alice 테이커 매수, bob 메이커
+        let old_day = TODAY - 100 * DAY_SECS;
+        let executions = ExecutionRepository::new(pool.clone());
+        for record in [
+            execution("e1", "a1", "b1", 100, 2, old_day + 60),
+            execution("e2", "a1", "b1", 120, 3, old_day + 120),
+            execution("e3", "a1", "b1", 90, 5, old_day + 180),
+            execution("e4", "a1", "b1", 110, 4, TODAY - DAY_SECS),
+        ] {
+            executions.save(&record).await.unwrap();
+        }
+        let risk = RiskLimitRepository::new(pool.clone());
+        let before = risk.net_positions().await.unwrap();
+
+        let job = RetentionJob::new(pool.clone(), RetentionConfig { audit_log_days: None, ..Default::default() });
+        let report = job.run(TODAY + 3600).await.unwrap();
+        assert_eq!((report.rolled_up_days, report.executions_deleted), (1, 3));
+
+        let aggregates = job.repository.daily_aggregates("BTC-KRW", 0, TODAY as i64).await.unwrap();
+        assert_eq!(aggregates.len(), 1);
+        let day = &aggregates[0];
+        assert_eq!((day.day, day.trade_count, day.volume, day.notional), (old_day as i64, 3, 10, 200 + 360 + 450));
+        assert_eq!((day.open_price, day.high_price, day.low_price, day.close_price), (100, 120, 90, 90));
+        assert_eq!((day.taker_fees, day.maker_fees), (6, 3));
+
+        // 원본을 지워도 재시작 때 계산하는 순포지션은 그대로
+        let positions = |records: Vec<NetPositionRecord>| {
+            let mut positions: Vec<(String, i64)> = records.into_iter().map(|p| (p.client_id, p.net_quantity)).collect();
+            positions.sort();
+            positions
+        };
+        let after = positions(risk.net_positions().await.unwrap());
+        assert_eq!(positions(before), after);
+        assert_eq!(after, vec![("alice".to_string(), 14), ("bob".to_string(), -14)]);
+
+        // 다시 돌려도 더 접을 것이 없음
+        let again = job.run(TODAY + 3600).await.unwrap();
+        assert_eq!((again.rolled_up_days, again.executions_deleted), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn test_deletes_expired_audit_logs() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        super::super::create_tables(&pool).await.unwrap();
+
+        let audit = AuditLogRepository::new(pool.clone());
+        audit.log("ORDER_CREATED", "ORDER", "o1", None).await.unwrap();
+        audit.log("ORDER_CREATED", "ORDER", "o2", None).await.unwrap();
+        sqlx::query("UPDATE audit_logs SET timestamp = datetime(?, 'unixepoch') WHERE entity_id = 'o1'")
+            .bind((TODAY - 400 * DAY_SECS) as i64)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let config = RetentionConfig { execution_days: None, audit_log_days: Some(365), ..Default::default() };
+        let report = RetentionJob::new(pool.clone(), config).run(TODAY).await.unwrap();
+        assert_eq!(report.audit_logs_deleted, 1);
+        assert!(audit.find_by_entity("o1").await.unwrap().is_empty());
+        assert_eq!(audit.find_by_entity("o2").await.unwrap().len(), 1);
+    }
+}
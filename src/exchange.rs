@@ -0,0 +1,383 @@
+//! 임베디드 거래소 (HTTP 없이 프로세스 안에서 매칭 엔진 실행)
+//!
+//! 백테스터, 봇, 테스트 같은 다른 Rust 프로그램이 REST/WebSocket 서버, DB, MQ 없이 매칭 엔진과
+//! 시퀀서를 띄우고 채널로 직접 주문을 넣고 체결 보고서를 받습니다.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use xtrader::exchange::{ExchangeBuilder, Order, OrderType, Side};
+//!
+//! let (exchange, executions) = ExchangeBuilder::new(["BTC-KRW"]).start().unwrap();
+//! exchange.submit(Order::new("s1".into(), "BTC-KRW".into(), Side::Sell, OrderType::Limit, 1_000, 5, "maker".into())).unwrap();
+//! exchange.submit(Order::new("b1".into(), "BTC-KRW".into(), Side::Buy, OrderType::Limit, 1_000, 5, "taker".into())).unwrap();
+//! while let Some(report) = executions.recv_timeout(Duration::from_millis(100)) {
+//!     println!("{} {:?} {}@{} (seq {})", report.order_id, report.exec_type, report.quantity, report.price, report.sequence);
+//! }
+//! exchange.shutdown();
+//! ```
+//!
+//! 안정성: 이 모듈의 공개 항목(`ExchangeBuilder`, `Exchange`, `ExecutionStream`, `ExchangeError`)과
+//! 재내보내는 모델 타입은 semver를 따릅니다. 호환되지 않는 변경은 메이저 버전에서만 하며,
+//! `ExchangeError`는 `#[non_exhaustive]`라 변형이 추가될 수 있습니다. 내부 큐/엔진 타입은 노출하지 않습니다.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+
+use crate::matching_engine::engine::MatchingEngine;
+use crate::sequencer::backpressure::{bounded_queue, BoundedReceiver, BoundedSender, OverflowPolicy};
+use crate::sequencer::global_sequence::GlobalSequence;
+use crate::util::clock::{SharedClock, SystemClock};
+
+pub use crate::matching_engine::model::{ExecType, ExecutionReport, MarketProtection, Order, OrderBookSnapshot, OrderType, Side};
+pub use crate::matching_engine::order_ack::{OrderAck, OrderAckStatus, OrderRejectReason};
+use crate::matching_engine::order_ack::OrderAckRegistry;
+
+/// 기본 주문 큐 용량
+pub const DEFAULT_ORDER_QUEUE_CAPACITY: usize = 10_000;
+/// 기본 체결 보고서 큐 용량
+pub const DEFAULT_EXECUTION_QUEUE_CAPACITY: usize = 100_000;
+/// 기본 주문 처리 결과 대기 시간 (`submit_and_wait`)
+pub const DEFAULT_ACK_TIMEOUT: Duration = Duration::from_secs(2);
+/// 체결 시퀀서가 종료 요청을 확인하는 주기
+const SEQUENCER_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// 임베디드 거래소 오류
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ExchangeError {
+    /// 심볼 없이 시작
+    #[error("심볼이 하나 이상 필요합니다")]
+    NoSymbols,
+    /// 주문장이 없는 심볼
+    #[error("지원하지 않는 심볼: {0}")]
+    UnknownSymbol(String),
+    /// 거래소가 종료되어 주문을 받을 수 없음
+    #[error("거래소가 종료되었습니다")]
+    Closed,
+    /// 주문 처리 결과를 기한 안에 받지 못함 (주문은 큐에 들어가 처리될 수 있음)
+    #[error("주문 처리 결과를 {0:?} 안에 받지 못했습니다")]
+    AckTimeout(Duration),
+}
+
+/// 임베디드 거래소 설정
+#[derive(Debug, Clone)]
+pub struct ExchangeBuilder {
+    symbols: Vec<String>,
+    market_protection: Option<MarketProtection>,
+    batch_auctions: HashMap<String, Duration>,
+    max_order_age_secs: Option<u64>,
+    order_queue_capacity: usize,
+    execution_queue_capacity: usize,
+    ack_timeout: Duration,
+    clock: SharedClock,
+}
+
+impl ExchangeBuilder {
+    /// 거래할 심볼로 설정 시작
+    pub fn new<I, S>(symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            symbols: symbols.into_iter().map(Into::into).collect(),
+            market_protection: None,
+            batch_auctions: HashMap::new(),
+            max_order_age_secs: None,
+            order_queue_capacity: DEFAULT_ORDER_QUEUE_CAPACITY,
+            execution_queue_capacity: DEFAULT_EXECUTION_QUEUE_CAPACITY,
+            ack_timeout: DEFAULT_ACK_TIMEOUT,
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// 전 심볼 공통 시장가 주문 보호 기본값
+    pub fn with_market_protection(mut self, protection: MarketProtection) -> Self {
+        self.market_protection = Some(protection);
+        self
+    }
+
+    /// 심볼을 연속 매칭 대신 `interval` 주기 배치 경매로 매칭
+    pub fn with_batch_auction(mut self, symbol: &str, interval: Duration) -> Self {
+        self.batch_auctions.insert(symbol.to_string(), interval);
+        self
+    }
+
+    /// 만료 시간 없는 주문의 최대 대기 시간 (초)
+    pub fn with_max_order_age(mut self, max_order_age_secs: u64) -> Self {
+        self.max_order_age_secs = Some(max_order_age_secs);
+        self
+    }
+
+    /// 주문 큐와 체결 보고서 큐 용량 (가득 차면 넣는 쪽이 자리가 날 때까지 대기)
+    pub fn with_queue_capacity(mut self, orders: usize, executions: usize) -> Self {
+        self.order_queue_capacity = orders.max(1);
+        self.execution_queue_capacity = executions.max(1);
+        self
+    }
+
+    /// `submit_and_wait` 결과 대기 시간
+    pub fn with_ack_timeout(mut self, timeout: Duration) -> Self {
+        self.ack_timeout = timeout;
+        self
+    }
+
+    /// 시각 소스 (GTD 만료, 배치 경매 주기, 체결 보고서 시각, 기본값은 시스템 시각)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 매칭 엔진과 체결 시퀀서를 전용 스레드로 시작
+    ///
+    /// 체결 보고서는 반환된 [`ExecutionStream`]으로만 나오며, 읽지 않으면 체결 큐가 가득 찬 뒤
+    /// 매칭 엔진이 대기합니다.
+    pub fn start(self) -> Result<(Exchange, ExecutionStream), ExchangeError> {
+        if self.symbols.is_empty() {
+            return Err(ExchangeError::NoSymbols);
+        }
+
+        let (order_tx, order_rx) = bounded_queue("embedded_orders", self.order_queue_capacity, OverflowPolicy::Block);
+        let (exec_tx, exec_rx) = bounded_queue("embedded_engine_executions", self.execution_queue_capacity, OverflowPolicy::Block);
+        let (stream_tx, stream_rx) = bounded_queue("embedded_executions", self.execution_queue_capacity, OverflowPolicy::Block);
+
+        let order_acks = Arc::new(OrderAckRegistry::new(self.ack_timeout));
+        let mut engine = MatchingEngine::new(self.symbols.clone(), exec_tx, None);
+        engine.set_clock(self.clock);
+        engine.set_order_acks(order_acks.clone());
+        engine.set_max_order_age(self.max_order_age_secs);
+        if let Some(protection) = &self.market_protection {
+            for symbol in &self.symbols {
+                engine.set_market_protection(symbol, protection.clone());
+            }
+        }
+        for (symbol, interval) in &self.batch_auctions {
+            engine.set_batch_auction(symbol, Some(*interval));
+        }
+        let engine = Arc::new(Mutex::new(engine));
+
+        let engine_thread = {
+            let engine = engine.clone();
+            std::thread::Builder::new()
+                .name("xtrader-engine".to_string())
+                .spawn(move || MatchingEngine::run_shared(engine, order_rx))
+                .expect("매칭 엔진 스레드 생성 실패")
+        };
+
+        let sequence = Arc::new(GlobalSequence::new());
+        let stopping = Arc::new(AtomicBool::new(false));
+        let sequencer_thread = {
+            let sequence = sequence.clone();
+            let stopping = stopping.clone();
+            std::thread::Builder::new()
+                .name("xtrader-exec-sequencer".to_string())
+                .spawn(move || run_execution_sequencer(exec_rx, stream_tx, &sequence, &stopping))
+                .expect("체결 시퀀서 스레드 생성 실패")
+        };
+
+        let exchange = Exchange {
+            symbols: self.symbols.into_iter().collect(),
+            order_tx: Some(order_tx),
+            engine,
+            sequence,
+            order_acks,
+            ack_timeout: self.ack_timeout,
+            stopping,
+            engine_thread: Some(engine_thread),
+            sequencer_thread: Some(sequencer_thread),
+        };
+        Ok((exchange, ExecutionStream { rx: stream_rx }))
+    }
+}
+
+/// 엔진 체결 보고서에 전역 시퀀스 번호를 붙여 전달 (엔진이 끝나고 큐가 비면 종료)
+fn run_execution_sequencer(
+    exec_rx: BoundedReceiver<ExecutionReport>,
+    stream_tx: BoundedSender<ExecutionReport>,
+    sequence: &GlobalSequence,
+    stopping: &AtomicBool,
+) {
+    loop {
+        match exec_rx.recv_timeout(SEQUENCER_POLL_INTERVAL) {
+            Ok(mut report) => {
+                report.sequence = sequence.next();
+                // 수신측을 버렸으면 번호만 매기고 버림
+                let _ = stream_tx.send(report);
+            }
+            Err(RecvTimeoutError::Timeout) if stopping.load(Ordering::SeqCst) => break,
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+/// 실행 중인 임베디드 거래소
+///
+/// 주문과 체결 보고서는 하나의 전역 시퀀스 번호를 공유합니다. 버리거나 [`Exchange::shutdown`]을
+/// 호출하면 이미 받은 주문까지 처리한 뒤 스레드를 정리하고 [`ExecutionStream`]이 끝납니다.
+pub struct Exchange {
+    symbols: HashSet<String>,
+    order_tx: Option<BoundedSender<Order>>,
+    engine: Arc<Mutex<MatchingEngine>>,
+    sequence: Arc<GlobalSequence>,
+    order_acks: Arc<OrderAckRegistry>,
+    ack_timeout: Duration,
+    stopping: Arc<AtomicBool>,
+    engine_thread: Option<JoinHandle<()>>,
+    sequencer_thread: Option<JoinHandle<()>>,
+}
+
+impl Exchange {
+    /// 주문 제출 (전역 시퀀스 번호 반환, 큐가 가득 차면 자리가 날 때까지 대기)
+    pub fn submit(&self, order: Order) -> Result<u64, ExchangeError> {
+        if !order.is_cancel && !self.symbols.contains(&order.symbol) {
+            return Err(ExchangeError::UnknownSymbol(order.symbol));
+        }
+        let order_tx = self.order_tx.as_ref().ok_or(ExchangeError::Closed)?;
+        match self.sequence.submit(order, order_tx) {
+            Ok(Some(sequence)) => Ok(sequence),
+            Ok(None) | Err(_) => Err(ExchangeError::Closed),
+        }
+    }
+
+    /// 주문 취소 요청 (결과는 Canceled 체결 보고서로 나옴)
+    pub fn cancel(&self, order_id: &str) -> Result<u64, ExchangeError> {
+        self.submit(Order::new_cancel(order_id.to_string()))
+    }
+
+    /// 주문 제출 후 매칭 엔진 처리 결과 대기 (접수, 즉시 체결 수량, 거부 사유)
+    pub async fn submit_and_wait(&self, order: Order) -> Result<OrderAck, ExchangeError> {
+        let order_id = order.id.clone();
+        let rx = self.order_acks.register(&order_id);
+        if let Err(e) = self.submit(order) {
+            self.order_acks.forget(&order_id);
+            return Err(e);
+        }
+        self.order_acks
+            .wait(&order_id, rx)
+            .await
+            .ok_or(ExchangeError::AckTimeout(self.ack_timeout))
+    }
+
+    /// 호가창 스냅샷 (`depth`: 방향별 가격 레벨 수)
+    pub async fn order_book(&self, symbol: &str, depth: usize) -> Option<OrderBookSnapshot> {
+        self.engine.lock().await.get_order_book_snapshot(symbol, depth)
+    }
+
+    /// 호가창 스냅샷 (비동기 런타임 밖에서 호출)
+    pub fn blocking_order_book(&self, symbol: &str, depth: usize) -> Option<OrderBookSnapshot> {
+        self.engine.blocking_lock().get_order_book_snapshot(symbol, depth)
+    }
+
+    /// 마지막으로 발급한 전역 시퀀스 번호
+    pub fn last_sequence(&self) -> u64 {
+        self.sequence.last()
+    }
+
+    /// 받은 주문을 모두 처리한 뒤 종료
+    pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        // 주문 큐를 닫으면 엔진이 남은 주문을 처리하고 루프를 빠져나감
+        self.order_tx.take();
+        if let Some(engine_thread) = self.engine_thread.take() {
+            let _ = engine_thread.join();
+        }
+        self.stopping.store(true, Ordering::SeqCst);
+        if let Some(sequencer_thread) = self.sequencer_thread.take() {
+            let _ = sequencer_thread.join();
+        }
+    }
+}
+
+impl Drop for Exchange {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// 시퀀스 번호가 붙은 체결 보고서 수신측 (거래소가 종료되고 남은 보고서를 다 읽으면 끝)
+pub struct ExecutionStream {
+    rx: BoundedReceiver<ExecutionReport>,
+}
+
+impl ExecutionStream {
+    /// 다음 체결 보고서 대기 (종료되면 None)
+    pub fn recv(&self) -> Option<ExecutionReport> {
+        self.rx.recv().ok()
+    }
+
+    /// 다음 체결 보고서를 `timeout`까지 대기
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<ExecutionReport> {
+        self.rx.recv_timeout(timeout).ok()
+    }
+
+    /// 이미 도착한 체결 보고서만 확인
+    pub fn try_recv(&self) -> Option<ExecutionReport> {
+        self.rx.try_recv().ok()
+    }
+}
+
+impl Iterator for ExecutionStream {
+    type Item = ExecutionReport;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.recv()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WAIT: Duration = Duration::from_secs(2);
+
+    fn limit(id: &str, side: Side, price: u64, quantity: u64) -> Order {
+        Order::new(id.to_string(), "BTC-KRW".to_string(), side, OrderType::Limit, price, quantity, "bot".to_string())
+    }
+
+    #[test]
+    fn test_submit_and_receive_sequenced_executions() {
+        let (exchange, executions) = ExchangeBuilder::new(["BTC-KRW"]).start().unwrap();
+
+        let maker_seq = exchange.submit(limit("s1", Side::Sell, 1_000, 5)).unwrap();
+        let taker_seq = exchange.submit(limit("b1", Side::Buy, 1_000, 3)).unwrap();
+        assert!(taker_seq > maker_seq);
+
+        let fills: Vec<ExecutionReport> = (0..2).map(|_| executions.recv_timeout(WAIT).expect("체결 보고서")).collect();
+        assert!(fills.iter().all(|report| report.exec_type == ExecType::Trade && report.quantity == 3));
+        assert!(fills[0].sequence > taker_seq && fills[1].sequence > fills[0].sequence);
+        assert_eq!(exchange.blocking_order_book("BTC-KRW", 5).unwrap().asks, vec![(1_000, 2)]);
+
+        exchange.cancel("s1").unwrap();
+        let canceled = executions.recv_timeout(WAIT).expect("취소 보고서");
+        assert_eq!((canceled.order_id.as_str(), canceled.exec_type), ("s1", ExecType::Canceled));
+
+        // 종료하면 남은 보고서를 다 읽은 뒤 스트림이 끝남
+        exchange.shutdown();
+        assert_eq!(executions.count(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_submit_and_wait_reports_engine_result() {
+        let (exchange, _executions) = ExchangeBuilder::new(["BTC-KRW"]).start().unwrap();
+
+        let ack = exchange.submit_and_wait(limit("s1", Side::Sell, 1_000, 5)).await.unwrap();
+        assert_eq!((ack.status, ack.remaining_quantity), (OrderAckStatus::Accepted, 5));
+        let ack = exchange.submit_and_wait(limit("b1", Side::Buy, 1_000, 5)).await.unwrap();
+        assert_eq!((ack.status, ack.filled_quantity), (OrderAckStatus::Filled, 5));
+        assert!(exchange.order_book("BTC-KRW", 5).await.unwrap().asks.is_empty());
+
+        let unknown = Order::new("x".to_string(), "DOGE-KRW".to_string(), Side::Buy, OrderType::Limit, 1, 1, "bot".to_string());
+        assert_eq!(exchange.submit(unknown), Err(ExchangeError::UnknownSymbol("DOGE-KRW".to_string())));
+        assert_eq!(ExchangeBuilder::new(Vec::<String>::new()).start().err(), Some(ExchangeError::NoSymbols));
+    }
+}
@@ -0,0 +1,582 @@
+//! AML 규칙 엔진
+//!
+//! 의심 거래 탐지 규칙(금액 기준, 구조화/스머핑 패턴, 거래 속도, 고위험 국가)을
+//! 코드가 아닌 설정(JSON)이나 DB에 정의하고 거래마다 평가합니다. 규칙 집합에는
+//! 버전이 있어 컴플라이언스 팀이 새 버전을 올리면 재배포 없이 반영되며,
+//! 탐지 결과에는 어떤 버전의 어떤 규칙에 걸렸는지가 남습니다.
+
+use std::collections::{HashSet, VecDeque};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::external::regulatory_reporting::TransactionData;
+
+/// 최근 거래 보관 한도 (윈도 규칙 평가용)
+const MAX_RECENT_TRANSACTIONS: usize = 100_000;
+
+/// AML 규칙 오류
+#[derive(Debug, thiserror::Error)]
+pub enum AmlRuleError {
+    #[error("규칙 집합 파싱 실패: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("규칙 파일 읽기 실패: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("규칙 {rule_id} 설정 오류: {reason}")]
+    InvalidRule { rule_id: String, reason: String },
+    #[error("규칙 ID 중복: {0}")]
+    DuplicateRuleId(String),
+    #[error("규칙 집합 설정 오류: {0}")]
+    InvalidRuleSet(String),
+    #[error("규칙 버전 {proposed}은(는) 현재 버전 {current}보다 커야 합니다")]
+    StaleVersion { current: u32, proposed: u32 },
+    #[error("규칙 저장소 오류: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 규칙 조건
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AmlCondition {
+    /// 단일 거래 금액이 기준 이상
+    LargeTransaction { min_total_value: f64 },
+    /// 고위험 국가 사용자
+    HighRiskCountry { countries: Vec<String> },
+    /// 대량 수량을 비정상적으로 낮은 가격에 거래
+    UnusualPattern { min_amount: f64, max_price: f64 },
+    /// 구조화: 같은 사용자가 보고 기준 바로 아래 금액으로 나눠 거래
+    Structuring {
+        /// 회피 대상 보고 기준 금액
+        reporting_threshold: f64,
+        /// 기준 아래 몇 % 이내를 "바로 아래"로 볼지
+        margin_percent: f64,
+        window_ms: u64,
+        /// 윈도 내 해당 거래 수 (현재 거래 포함)
+        min_count: usize,
+    },
+    /// 스머핑: 여러 계정이 같은 IP나 기기에서 나눠 거래
+    Smurfing {
+        window_ms: u64,
+        /// 윈도 내 서로 다른 계정 수 (현재 거래 포함)
+        min_accounts: usize,
+        /// 윈도 내 합계 금액
+        min_total_value: f64,
+    },
+    /// 거래 속도: 같은 사용자의 윈도 내 거래 수 또는 금액 초과
+    Velocity {
+        window_ms: u64,
+        #[serde(default)]
+        max_count: Option<usize>,
+        #[serde(default)]
+        max_total_value: Option<f64>,
+    },
+}
+
+impl AmlCondition {
+    /// 윈도 길이 (윈도가 없는 조건은 0)
+    fn window_ms(&self) -> u64 {
+        match self {
+            AmlCondition::Structuring { window_ms, .. }
+            | AmlCondition::Smurfing { window_ms, .. }
+            | AmlCondition::Velocity { window_ms, .. } => *window_ms,
+            _ => 0,
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let positive = |name: &str, value: f64| {
+            if value.is_finite() && value > 0.0 {
+                Ok(())
+            } else {
+                Err(format!("{}는 0보다 커야 합니다", name))
+            }
+        };
+
+        match self {
+            AmlCondition::LargeTransaction { min_total_value } => positive("min_total_value", *min_total_value),
+            AmlCondition::HighRiskCountry { countries } => {
+                if countries.is_empty() {
+                    Err("countries가 비어 있습니다".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+            AmlCondition::UnusualPattern { min_amount, max_price } => {
+                positive("min_amount", *min_amount)?;
+                positive("max_price", *max_price)
+            }
+            AmlCondition::Structuring { reporting_threshold, margin_percent, min_count, .. } => {
+                positive("reporting_threshold", *reporting_threshold)?;
+                if !(*margin_percent > 0.0 && *margin_percent < 100.0) {
+                    return Err("margin_percent는 0과 100 사이여야 합니다".to_string());
+                }
+                if *min_count < 2 {
+                    return Err("min_count는 2 이상이어야 합니다".to_string());
+                }
+                Ok(())
+            }
+            AmlCondition::Smurfing { min_accounts, min_total_value, .. } => {
+                positive("min_total_value", *min_total_value)?;
+                if *min_accounts < 2 {
+                    return Err("min_accounts는 2 이상이어야 합니다".to_string());
+                }
+                Ok(())
+            }
+            AmlCondition::Velocity { max_count, max_total_value, .. } => {
+                if max_count.is_none() && max_total_value.is_none() {
+                    return Err("max_count나 max_total_value 중 하나는 필요합니다".to_string());
+                }
+                if let Some(value) = max_total_value {
+                    positive("max_total_value", *value)?;
+                }
+                Ok(())
+            }
+        }?;
+
+        if matches!(
+            self,
+            AmlCondition::Structuring { .. } | AmlCondition::Smurfing { .. } | AmlCondition::Velocity { .. }
+        ) && self.window_ms() == 0
+        {
+            return Err("window_ms는 0보다 커야 합니다".to_string());
+        }
+        Ok(())
+    }
+
+    /// 조건 평가 (충족 시 근거 설명 반환)
+    ///
+    /// `recent`에는 현재 거래 이전의 거래가 시각순으로 들어 있습니다.
+    fn check(&self, tx: &TransactionData, recent: &VecDeque<TransactionData>) -> Option<String> {
+        let in_window = |window_ms: u64| {
+            let since = tx.timestamp.saturating_sub(window_ms);
+            recent.iter().filter(move |t| t.timestamp >= since && t.timestamp <= tx.timestamp)
+        };
+
+        match self {
+            AmlCondition::LargeTransaction { min_total_value } => (tx.total_value >= *min_total_value)
+                .then(|| format!("거래 금액 {:.0} ≥ {:.0}", tx.total_value, min_total_value)),
+            AmlCondition::HighRiskCountry { countries } => countries
+                .iter()
+                .any(|c| c == &tx.user_country)
+                .then(|| format!("고위험 국가 {}", tx.user_country)),
+            AmlCondition::UnusualPattern { min_amount, max_price } => (tx.amount > *min_amount
+                && tx.price < *max_price)
+                .then(|| format!("수량 {} > {}, 가격 {} < {}", tx.amount, min_amount, tx.price, max_price)),
+            AmlCondition::Structuring { reporting_threshold, margin_percent, window_ms, min_count } => {
+                let floor = reporting_threshold * (1.0 - margin_percent / 100.0);
+                let near = |t: &TransactionData| t.total_value >= floor && t.total_value < *reporting_threshold;
+                if !near(tx) {
+                    return None;
+                }
+                let related: Vec<&TransactionData> =
+                    in_window(*window_ms).filter(|t| t.user_id == tx.user_id && near(t)).collect();
+                let count = related.len() + 1;
+                let total = related.iter().map(|t| t.total_value).sum::<f64>() + tx.total_value;
+                (count >= *min_count).then(|| {
+                    format!(
+                        "{}ms 내 보고 기준({:.0}) 직하 거래 {}건, 합계 {:.0}",
+                        window_ms, reporting_threshold, count, total
+                    )
+                })
+            }
+            AmlCondition::Smurfing { window_ms, min_accounts, min_total_value } => {
+                let related: Vec<&TransactionData> = in_window(*window_ms)
+                    .filter(|t| t.ip_address == tx.ip_address || t.device_fingerprint == tx.device_fingerprint)
+                    .collect();
+                let accounts: HashSet<&str> = related
+                    .iter()
+                    .map(|t| t.user_id.as_str())
+                    .chain(std::iter::once(tx.user_id.as_str()))
+                    .collect();
+                let total = related.iter().map(|t| t.total_value).sum::<f64>() + tx.total_value;
+                (accounts.len() >= *min_accounts && total >= *min_total_value).then(|| {
+                    format!(
+                        "{}ms 내 같은 IP/기기({}, {})에서 계정 {}개, 합계 {:.0}",
+                        window_ms,
+                        tx.ip_address,
+                        tx.device_fingerprint,
+                        accounts.len(),
+                        total
+                    )
+                })
+            }
+            AmlCondition::Velocity { window_ms, max_count, max_total_value } => {
+                let related: Vec<&TransactionData> =
+                    in_window(*window_ms).filter(|t| t.user_id == tx.user_id).collect();
+                let count = related.len() + 1;
+                let total = related.iter().map(|t| t.total_value).sum::<f64>() + tx.total_value;
+                let count_exceeded = max_count.is_some_and(|max| count > max);
+                let value_exceeded = max_total_value.is_some_and(|max| total > max);
+                (count_exceeded || value_exceeded)
+                    .then(|| format!("{}ms 내 거래 {}건, 합계 {:.0}", window_ms, count, total))
+            }
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// AML 규칙
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AmlRule {
+    /// 규칙 ID (규칙 집합 안에서 고유)
+    pub id: String,
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 충족 시 더할 위험도
+    pub risk_score: f64,
+    #[serde(flatten)]
+    pub condition: AmlCondition,
+}
+
+/// 버전이 있는 AML 규칙 집합
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AmlRuleSet {
+    pub version: u32,
+    /// 의심 거래로 판단할 위험도 합계 기준
+    pub alert_threshold: f64,
+    /// 심각도를 "high"로 올릴 위험도 합계 기준
+    pub high_severity_threshold: f64,
+    pub rules: Vec<AmlRule>,
+}
+
+impl Default for AmlRuleSet {
+    fn default() -> Self {
+        Self::builtin(10_000_000.0)
+    }
+}
+
+impl AmlRuleSet {
+    /// 기본 규칙 집합 (버전 1)
+    ///
+    /// 규칙 엔진 도입 전 하드코딩돼 있던 휴리스틱(대규모 거래, 비정상 패턴,
+    /// 고위험 국가)을 같은 가중치로 옮긴 것입니다.
+    pub fn builtin(large_transaction_threshold: f64) -> Self {
+        Self {
+            version: 1,
+            alert_threshold: 0.5,
+            high_severity_threshold: 0.8,
+            rules: vec![
+                AmlRule {
+                    id: "large-transaction".to_string(),
+                    name: "Large transaction amount".to_string(),
+                    enabled: true,
+                    risk_score: 0.3,
+                    condition: AmlCondition::LargeTransaction { min_total_value: large_transaction_threshold },
+                },
+                AmlRule {
+                    id: "unusual-pattern".to_string(),
+                    name: "Unusual trading pattern".to_string(),
+                    enabled: true,
+                    risk_score: 0.2,
+                    condition: AmlCondition::UnusualPattern { min_amount: 1000.0, max_price: 100.0 },
+                },
+                AmlRule {
+                    id: "high-risk-country".to_string(),
+                    name: "High-risk country".to_string(),
+                    enabled: true,
+                    risk_score: 0.4,
+                    condition: AmlCondition::HighRiskCountry {
+                        countries: vec!["XX".to_string(), "YY".to_string(), "ZZ".to_string()],
+                    },
+                },
+            ],
+        }
+    }
+
+    /// JSON에서 규칙 집합 읽기 (검증 포함)
+    pub fn from_json(json: &str) -> Result<Self, AmlRuleError> {
+        let rules: Self = serde_json::from_str(json)?;
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    /// JSON 파일에서 규칙 집합 읽기
+    pub fn from_file(path: &Path) -> Result<Self, AmlRuleError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// 규칙 집합 검증
+    pub fn validate(&self) -> Result<(), AmlRuleError> {
+        if !(self.alert_threshold.is_finite() && self.alert_threshold > 0.0) {
+            return Err(AmlRuleError::InvalidRuleSet("alert_threshold는 0보다 커야 합니다".to_string()));
+        }
+        if self.high_severity_threshold < self.alert_threshold {
+            return Err(AmlRuleError::InvalidRuleSet(
+                "high_severity_threshold는 alert_threshold 이상이어야 합니다".to_string(),
+            ));
+        }
+
+        let mut ids = HashSet::new();
+        for rule in &self.rules {
+            if rule.id.is_empty() {
+                return Err(AmlRuleError::InvalidRule { rule_id: rule.name.clone(), reason: "id가 비어 있습니다".to_string() });
+            }
+            if !ids.insert(rule.id.as_str()) {
+                return Err(AmlRuleError::DuplicateRuleId(rule.id.clone()));
+            }
+            if !(rule.risk_score.is_finite() && rule.risk_score >= 0.0) {
+                return Err(AmlRuleError::InvalidRule {
+                    rule_id: rule.id.clone(),
+                    reason: "risk_score는 0 이상이어야 합니다".to_string(),
+                });
+            }
+            rule.condition
+                .validate()
+                .map_err(|reason| AmlRuleError::InvalidRule { rule_id: rule.id.clone(), reason })?;
+        }
+        Ok(())
+    }
+
+    /// 활성 규칙 중 가장 긴 윈도
+    fn max_window_ms(&self) -> u64 {
+        self.rules
+            .iter()
+            .filter(|r| r.enabled)
+            .map(|r| r.condition.window_ms())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// 규칙 충족 내역
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmlRuleMatch {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub risk_score: f64,
+    pub detail: String,
+}
+
+/// 거래 한 건의 평가 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmlEvaluation {
+    /// 평가에 쓴 규칙 집합 버전
+    pub rule_set_version: u32,
+    /// 충족한 규칙 위험도 합계
+    pub risk_score: f64,
+    pub matches: Vec<AmlRuleMatch>,
+    /// 의심 거래 여부 (`alert_threshold` 이상)
+    pub suspicious: bool,
+    /// "medium" 또는 "high"
+    pub severity: String,
+}
+
+/// AML 규칙 엔진
+///
+/// 현재 규칙 집합과 윈도 규칙(구조화, 스머핑, 속도) 평가를 위한 최근 거래를
+/// 보관합니다. 규칙을 교체해도 최근 거래는 유지됩니다.
+#[derive(Debug)]
+pub struct AmlRuleEngine {
+    rules: AmlRuleSet,
+    recent: VecDeque<TransactionData>,
+}
+
+impl AmlRuleEngine {
+    pub fn new(rules: AmlRuleSet) -> Self {
+        Self {
+            rules,
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// 현재 규칙 집합
+    pub fn rules(&self) -> &AmlRuleSet {
+        &self.rules
+    }
+
+    /// 새 버전 규칙 집합으로 교체 (버전은 현재보다 커야 함)
+    pub fn replace_rules(&mut self, rules: AmlRuleSet) -> Result<(), AmlRuleError> {
+        rules.validate()?;
+        if rules.version <= self.rules.version {
+            return Err(AmlRuleError::StaleVersion { current: self.rules.version, proposed: rules.version });
+        }
+        self.rules = rules;
+        Ok(())
+    }
+
+    /// 거래 평가 후 최근 거래에 추가
+    pub fn evaluate(&mut self, tx: &TransactionData) -> AmlEvaluation {
+        // 가장 긴 윈도보다 오래된 거래 정리
+        let since = tx.timestamp.saturating_sub(self.rules.max_window_ms());
+        while self.recent.front().is_some_and(|t| t.timestamp < since) {
+            self.recent.pop_front();
+        }
+
+        let matches: Vec<AmlRuleMatch> = self
+            .rules
+            .rules
+            .iter()
+            .filter(|rule| rule.enabled)
+            .filter_map(|rule| {
+                rule.condition.check(tx, &self.recent).map(|detail| AmlRuleMatch {
+                    rule_id: rule.id.clone(),
+                    rule_name: rule.name.clone(),
+                    risk_score: rule.risk_score,
+                    detail,
+                })
+            })
+            .collect();
+
+        if self.rules.max_window_ms() > 0 {
+            self.recent.push_back(tx.clone());
+            if self.recent.len() > MAX_RECENT_TRANSACTIONS {
+                self.recent.pop_front();
+            }
+        }
+
+        let risk_score = matches.iter().map(|m| m.risk_score).sum::<f64>();
+        AmlEvaluation {
+            rule_set_version: self.rules.version,
+            risk_score,
+            suspicious: risk_score >= self.rules.alert_threshold,
+            severity: if risk_score >= self.rules.high_severity_threshold { "high" } else { "medium" }.to_string(),
+            matches,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tx(id: u32, user: &str, total_value: f64, timestamp: u64) -> TransactionData {
+        TransactionData {
+            transaction_id: format!("tx_{}", id),
+            user_id: user.to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: "buy".to_string(),
+            amount: 0.1,
+            price: total_value * 10.0,
+            total_value,
+            timestamp,
+            user_country: "KR".to_string(),
+            user_tax_id: None,
+            ip_address: format!("10.0.0.{}", id),
+            device_fingerprint: format!("device_{}", id),
+        }
+    }
+
+    fn rule(id: &str, risk_score: f64, condition: AmlCondition) -> AmlRule {
+        AmlRule { id: id.to_string(), name: id.to_string(), enabled: true, risk_score, condition }
+    }
+
+    fn rule_set(version: u32, rules: Vec<AmlRule>) -> AmlRuleSet {
+        AmlRuleSet { version, alert_threshold: 0.5, high_severity_threshold: 0.8, rules }
+    }
+
+    #[test]
+    fn test_structuring_detected_within_window() {
+        let mut engine = AmlRuleEngine::new(rule_set(
+            1,
+            vec![rule(
+                "structuring",
+                0.6,
+                AmlCondition::Structuring {
+                    reporting_threshold: 10_000_000.0,
+                    margin_percent: 10.0,
+                    window_ms: 60_000,
+                    min_count: 3,
+                },
+            )],
+        ));
+
+        // 기준 직하 거래 2건, 기준 훨씬 아래 거래는 세지 않음
+        assert!(!engine.evaluate(&tx(1, "alice", 9_500_000.0, 0)).suspicious);
+        assert!(!engine.evaluate(&tx(2, "alice", 1_000_000.0, 10_000)).suspicious);
+        assert!(!engine.evaluate(&tx(3, "alice", 9_900_000.0, 20_000)).suspicious);
+
+        // 다른 사용자의 거래는 별개
+        assert!(!engine.evaluate(&tx(4, "bob", 9_900_000.0, 25_000)).suspicious);
+
+        let evaluation = engine.evaluate(&tx(5, "alice", 9_200_000.0, 30_000));
+        assert!(evaluation.suspicious);
+        assert_eq!(evaluation.matches[0].rule_id, "structuring");
+        assert_eq!(evaluation.severity, "medium");
+
+        // 윈도가 지나면 다시 세기 시작
+        assert!(!engine.evaluate(&tx(6, "alice", 9_200_000.0, 200_000)).suspicious);
+    }
+
+    #[test]
+    fn test_smurfing_and_velocity() {
+        let mut engine = AmlRuleEngine::new(rule_set(
+            1,
+            vec![
+                rule(
+                    "smurfing",
+                    0.5,
+                    AmlCondition::Smurfing { window_ms: 60_000, min_accounts: 3, min_total_value: 3_000_000.0 },
+                ),
+                rule(
+                    "velocity",
+                    0.4,
+                    AmlCondition::Velocity { window_ms: 60_000, max_count: Some(2), max_total_value: None },
+                ),
+            ],
+        ));
+
+        // 서로 다른 계정이 같은 기기에서 거래
+        let shared = |id: u32, user: &str, ts: u64| {
+            let mut t = tx(id, user, 1_200_000.0, ts);
+            t.device_fingerprint = "shared_device".to_string();
+            t
+        };
+        assert!(engine.evaluate(&shared(1, "a", 0)).matches.is_empty());
+        assert!(engine.evaluate(&shared(2, "b", 1_000)).matches.is_empty());
+        let evaluation = engine.evaluate(&shared(3, "c", 2_000));
+        assert_eq!(evaluation.matches.len(), 1);
+        assert_eq!(evaluation.matches[0].rule_id, "smurfing");
+
+        // 같은 사용자 3번째 거래는 속도 규칙 위반 (스머핑과 합산되면 high)
+        engine.evaluate(&shared(4, "c", 3_000));
+        let evaluation = engine.evaluate(&shared(5, "c", 4_000));
+        let ids: Vec<&str> = evaluation.matches.iter().map(|m| m.rule_id.as_str()).collect();
+        assert_eq!(ids, ["smurfing", "velocity"]);
+        assert!((evaluation.risk_score - 0.9).abs() < 1e-9);
+        assert_eq!(evaluation.severity, "high");
+    }
+
+    #[test]
+    fn test_rule_set_json_and_versioning() {
+        let json = r#"{
+            "version": 2,
+            "alert_threshold": 0.5,
+            "high_severity_threshold": 0.8,
+            "rules": [
+                { "id": "large", "name": "Large", "risk_score": 0.5,
+                  "type": "large_transaction", "min_total_value": 5000000 },
+                { "id": "velocity", "name": "Velocity", "risk_score": 0.3, "enabled": false,
+                  "type": "velocity", "window_ms": 60000, "max_total_value": 20000000 }
+            ]
+        }"#;
+        let rules = AmlRuleSet::from_json(json).unwrap();
+        assert_eq!(rules.version, 2);
+        assert!(rules.rules[0].enabled);
+        assert!(!rules.rules[1].enabled);
+        assert_eq!(rules.rules[0].condition, AmlCondition::LargeTransaction { min_total_value: 5_000_000.0 });
+
+        let mut engine = AmlRuleEngine::new(AmlRuleSet::default());
+        assert!(!engine.evaluate(&tx(1, "alice", 6_000_000.0, 0)).suspicious);
+
+        engine.replace_rules(rules.clone()).unwrap();
+        let evaluation = engine.evaluate(&tx(2, "alice", 6_000_000.0, 1_000));
+        assert!(evaluation.suspicious);
+        assert_eq!(evaluation.rule_set_version, 2);
+
+        // 같은 버전이나 낮은 버전은 거부
+        assert!(matches!(
+            engine.replace_rules(rules),
+            Err(AmlRuleError::StaleVersion { current: 2, proposed: 2 })
+        ));
+
+        // 잘못된 규칙은 파싱 단계에서 거부
+        let invalid = json.replace("\"window_ms\": 60000", "\"window_ms\": 0");
+        assert!(matches!(AmlRuleSet::from_json(&invalid), Err(AmlRuleError::InvalidRule { .. })));
+        let duplicate = json.replace("\"id\": \"velocity\"", "\"id\": \"large\"");
+        assert!(matches!(AmlRuleSet::from_json(&duplicate), Err(AmlRuleError::DuplicateRuleId(_))));
+    }
+}
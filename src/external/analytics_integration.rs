@@ -8,11 +8,11 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
-use log::{info, error, warn, debug};
+use log::{info, warn};
 use tokio::time::{sleep, interval};
 
 /// 분석 시스템 타입
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AnalyticsSystem {
     MachineLearning,    // 머신러닝 시스템
     RiskManagement,     // 리스크 관리 시스템
@@ -23,7 +23,7 @@ pub enum AnalyticsSystem {
 }
 
 /// 분석 요청 타입
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AnalysisRequestType {
     PricePrediction,        // 가격 예측
     RiskAssessment,         // 리스크 평가
@@ -62,7 +62,7 @@ pub struct AnalysisResult {
 }
 
 /// 분석 상태
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AnalysisStatus {
     Pending,    // 대기 중
     Processing, // 처리 중
@@ -179,7 +179,7 @@ impl AnalyticsIntegrationManager {
                     }
                     
                     // 우선순위 기준으로 정렬
-                    queue.sort_by(|a, b| b.priority.cmp(&a.priority));
+                    queue.sort_by_key(|item| std::cmp::Reverse(item.priority));
                     queue.pop()
                 };
 
@@ -194,7 +194,7 @@ impl AnalyticsIntegrationManager {
                     }
 
                     // 분석 요청 처리
-                    let request_queue_clone = request_queue.clone();
+                    let _request_queue_clone = request_queue.clone();
                     let results_clone = results.clone();
                     let cache_clone = cache.clone();
                     let active_requests_clone = active_requests.clone();
@@ -247,7 +247,7 @@ impl AnalyticsIntegrationManager {
             queue.push(request.clone());
         }
 
-        info!("분석 요청 제출: {} ({})", request.request_id, request.request_type);
+        info!("분석 요청 제출: {} ({:?})", request.request_id, request.request_type);
         Ok(request.request_id)
     }
 
@@ -260,39 +260,45 @@ impl AnalyticsIntegrationManager {
     /// 분석 요청 처리
     async fn process_analysis_request(
         request: &AnalysisRequest,
-        config: &AnalyticsIntegrationConfig,
+        _config: &AnalyticsIntegrationConfig,
     ) -> AnalysisResult {
-        let start_time = SystemTime::now();
+        let _start_time = SystemTime::now();
         
         // Mock: 실제로는 해당 분석 시스템에 요청 전송
         let processing_time = match request.system {
             AnalyticsSystem::MachineLearning => {
-                sleep(Duration::from_millis(2000 + fastrand::u32(0..3000))).await; // 2-5초
-                2000 + fastrand::u32(0..3000)
+                let delay_ms = 2000 + fastrand::u64(0..3000); // 2-5초
+                sleep(Duration::from_millis(delay_ms)).await;
+                delay_ms
             }
             AnalyticsSystem::RiskManagement => {
-                sleep(Duration::from_millis(1000 + fastrand::u32(0..2000))).await; // 1-3초
-                1000 + fastrand::u32(0..2000)
+                let delay_ms = 1000 + fastrand::u64(0..2000); // 1-3초
+                sleep(Duration::from_millis(delay_ms)).await;
+                delay_ms
             }
             AnalyticsSystem::MarketAnalysis => {
-                sleep(Duration::from_millis(1500 + fastrand::u32(0..2500))).await; // 1.5-4초
-                1500 + fastrand::u32(0..2500)
+                let delay_ms = 1500 + fastrand::u64(0..2500); // 1.5-4초
+                sleep(Duration::from_millis(delay_ms)).await;
+                delay_ms
             }
             AnalyticsSystem::FraudDetection => {
-                sleep(Duration::from_millis(500 + fastrand::u32(0..1000))).await; // 0.5-1.5초
-                500 + fastrand::u32(0..1000)
+                let delay_ms = 500 + fastrand::u64(0..1000); // 0.5-1.5초
+                sleep(Duration::from_millis(delay_ms)).await;
+                delay_ms
             }
             AnalyticsSystem::SentimentAnalysis => {
-                sleep(Duration::from_millis(3000 + fastrand::u32(0..4000))).await; // 3-7초
-                3000 + fastrand::u32(0..4000)
+                let delay_ms = 3000 + fastrand::u64(0..4000); // 3-7초
+                sleep(Duration::from_millis(delay_ms)).await;
+                delay_ms
             }
             AnalyticsSystem::PortfolioOptimization => {
-                sleep(Duration::from_millis(5000 + fastrand::u32(0..5000))).await; // 5-10초
-                5000 + fastrand::u32(0..5000)
+                let delay_ms = 5000 + fastrand::u64(0..5000); // 5-10초
+                sleep(Duration::from_millis(delay_ms)).await;
+                delay_ms
             }
         };
 
-        let processing_time_ms = processing_time as u64;
+        let processing_time_ms = processing_time;
 
         // 타임아웃 확인
         if processing_time_ms > request.timeout_ms {
@@ -314,8 +320,8 @@ impl AnalyticsIntegrationManager {
 
         // 95% 성공률로 시뮬레이션
         if fastrand::f32() < 0.95 {
-            let result = Self::generate_mock_result(&request);
-            let confidence = 0.7 + (fastrand::f32() * 0.3); // 0.7-1.0
+            let result = Self::generate_mock_result(request);
+            let confidence = 0.7 + (fastrand::f64() * 0.3); // 0.7-1.0
 
             AnalysisResult {
                 request_id: request.request_id.clone(),
@@ -440,7 +446,7 @@ impl AnalyticsIntegrationManager {
 
     /// 캐시 키 생성
     fn generate_cache_key(request: &AnalysisRequest) -> String {
-        format!("{}_{}_{}", request.system, request.request_type, request.symbol.as_deref().unwrap_or(""))
+        format!("{:?}_{:?}_{}", request.system, request.request_type, request.symbol.as_deref().unwrap_or(""))
     }
 
     /// 분석 통계 조회
@@ -520,7 +526,11 @@ mod fastrand {
         (hash % 1000) as f32 / 1000.0
     }
 
-    pub fn u32(range: std::ops::Range<u32>) -> u32 {
+    pub fn f64() -> f64 {
+        f32() as f64
+    }
+
+    pub fn u64(range: std::ops::Range<u64>) -> u64 {
         let mut hasher = DefaultHasher::new();
         SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -528,7 +538,7 @@ mod fastrand {
             .as_nanos()
             .hash(&mut hasher);
         let hash = hasher.finish();
-        range.start + ((hash % (range.end - range.start) as u64) as u32)
+        range.start + hash % (range.end - range.start)
     }
 }
 
@@ -0,0 +1,344 @@
+//! 차익거래 기회 알림
+//!
+//! 가격 동기화 결과를 받아 거래소 간 스프레드가 기준 이상인 상태가 N틱 연속
+//! 이어지면 기회로 확정하고, 알림 시스템으로 구조화된 알림을 보낸 뒤
+//! `arbitrage_opportunities` 테이블에 기록합니다.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqlitePool;
+use tokio::sync::{broadcast, Mutex};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::db::models::ArbitrageOpportunityRecord;
+use crate::db::repository::ArbitrageOpportunityRepository;
+use crate::external::exchange_sync::{ExchangeType, PriceSyncResult};
+use crate::monitoring::notification_routing::SOURCE_METADATA_KEY;
+use crate::monitoring::notification_system::{
+    NotificationChannel, NotificationPriority, NotificationSystem, NotificationType,
+};
+
+/// 차익거래 알림 규칙 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArbitrageAlertConfig {
+    /// 최소 스프레드 (%)
+    pub min_spread_percent: f64,
+    /// 연속 틱 수 (이만큼 이어져야 알림)
+    pub consecutive_ticks: u32,
+    /// 알림 채널
+    pub channel: NotificationChannel,
+    /// 알림 우선순위
+    pub priority: NotificationPriority,
+}
+
+impl Default for ArbitrageAlertConfig {
+    fn default() -> Self {
+        Self {
+            min_spread_percent: 0.5,
+            consecutive_ticks: 3,
+            channel: NotificationChannel::Log,
+            priority: NotificationPriority::High,
+        }
+    }
+}
+
+/// 확정된 차익거래 기회
+#[derive(Debug, Clone, Serialize)]
+pub struct ArbitrageAlert {
+    pub id: String,
+    pub symbol: String,
+    pub buy_exchange: ExchangeType,
+    pub sell_exchange: ExchangeType,
+    pub buy_price: f64,
+    pub sell_price: f64,
+    pub spread_percent: f64,
+    pub consecutive_ticks: u32,
+    /// 연속 구간 시작 시각 (Unix 밀리초)
+    pub first_seen_at: u64,
+    /// 알림 발생 시각 (Unix 밀리초)
+    pub detected_at: u64,
+}
+
+impl ArbitrageAlert {
+    /// DB 레코드로 변환
+    pub fn to_record(&self) -> ArbitrageOpportunityRecord {
+        ArbitrageOpportunityRecord {
+            id: self.id.clone(),
+            symbol: self.symbol.clone(),
+            buy_exchange: self.buy_exchange.to_string(),
+            sell_exchange: self.sell_exchange.to_string(),
+            buy_price: self.buy_price,
+            sell_price: self.sell_price,
+            spread_percent: self.spread_percent,
+            consecutive_ticks: self.consecutive_ticks as i64,
+            first_seen_at: self.first_seen_at as i64,
+            detected_at: self.detected_at as i64,
+        }
+    }
+
+    /// 알림 메타데이터
+    fn metadata(&self) -> HashMap<String, String> {
+        HashMap::from([
+            (SOURCE_METADATA_KEY.to_string(), "arbitrage_alert".to_string()),
+            ("opportunity_id".to_string(), self.id.clone()),
+            ("symbol".to_string(), self.symbol.clone()),
+            ("buy_exchange".to_string(), self.buy_exchange.to_string()),
+            ("sell_exchange".to_string(), self.sell_exchange.to_string()),
+            ("buy_price".to_string(), self.buy_price.to_string()),
+            ("sell_price".to_string(), self.sell_price.to_string()),
+            ("spread_percent".to_string(), format!("{:.4}", self.spread_percent)),
+            ("consecutive_ticks".to_string(), self.consecutive_ticks.to_string()),
+            ("first_seen_at".to_string(), self.first_seen_at.to_string()),
+        ])
+    }
+}
+
+/// 연속 구간 키 (심볼, 매수 거래소, 매도 거래소)
+type StreakKey = (String, ExchangeType, ExchangeType);
+
+/// 연속 구간 상태
+#[derive(Debug)]
+struct Streak {
+    ticks: u32,
+    first_seen_at: u64,
+}
+
+/// 연속 틱 기반 차익거래 규칙
+///
+/// 같은 (심볼, 매수, 매도) 조합이 기준 스프레드 이상으로 `consecutive_ticks`번
+/// 연속 관측되면 한 번만 알림을 냅니다. 한 틱이라도 기준 아래로 내려가면
+/// 구간이 끊기고, 다시 N틱이 쌓여야 다음 알림이 나갑니다.
+#[derive(Debug)]
+pub struct ArbitrageRuleEngine {
+    config: ArbitrageAlertConfig,
+    streaks: HashMap<StreakKey, Streak>,
+}
+
+impl ArbitrageRuleEngine {
+    pub fn new(config: ArbitrageAlertConfig) -> Self {
+        Self {
+            config,
+            streaks: HashMap::new(),
+        }
+    }
+
+    /// 심볼 한 틱의 동기화 결과 평가
+    pub fn evaluate(&mut self, result: &PriceSyncResult) -> Vec<ArbitrageAlert> {
+        let mut alerts = Vec::new();
+        let mut seen = Vec::new();
+
+        for opportunity in &result.arbitrage_opportunities {
+            if opportunity.profit_percent < self.config.min_spread_percent {
+                continue;
+            }
+
+            let key = (
+                result.symbol.clone(),
+                opportunity.buy_exchange.clone(),
+                opportunity.sell_exchange.clone(),
+            );
+            let streak = self.streaks.entry(key.clone()).or_insert(Streak {
+                ticks: 0,
+                first_seen_at: opportunity.timestamp,
+            });
+            streak.ticks += 1;
+            seen.push(key);
+
+            if streak.ticks == self.config.consecutive_ticks.max(1) {
+                alerts.push(ArbitrageAlert {
+                    id: Uuid::new_v4().to_string(),
+                    symbol: result.symbol.clone(),
+                    buy_exchange: opportunity.buy_exchange.clone(),
+                    sell_exchange: opportunity.sell_exchange.clone(),
+                    buy_price: opportunity.buy_price,
+                    sell_price: opportunity.sell_price,
+                    spread_percent: opportunity.profit_percent,
+                    consecutive_ticks: streak.ticks,
+                    first_seen_at: streak.first_seen_at,
+                    detected_at: opportunity.timestamp,
+                });
+            }
+        }
+
+        // 이번 틱에 기준을 넘지 못한 조합은 구간 초기화
+        self.streaks
+            .retain(|key, _| key.0 != result.symbol || seen.contains(key));
+
+        alerts
+    }
+}
+
+/// 차익거래 알림 서비스 (규칙 평가 → 알림 발송 → DB 기록)
+pub struct ArbitrageAlertService {
+    config: ArbitrageAlertConfig,
+    rules: Mutex<ArbitrageRuleEngine>,
+    notifications: Arc<NotificationSystem>,
+    repository: ArbitrageOpportunityRepository,
+}
+
+impl ArbitrageAlertService {
+    pub fn new(config: ArbitrageAlertConfig, notifications: Arc<NotificationSystem>, pool: SqlitePool) -> Self {
+        Self {
+            rules: Mutex::new(ArbitrageRuleEngine::new(config.clone())),
+            config,
+            notifications,
+            repository: ArbitrageOpportunityRepository::new(pool),
+        }
+    }
+
+    /// 동기화 결과 처리 후 확정된 기회 반환
+    pub async fn handle(&self, result: &PriceSyncResult) -> Vec<ArbitrageAlert> {
+        let alerts = self.rules.lock().await.evaluate(result);
+
+        for alert in &alerts {
+            info!(
+                "차익거래 기회 확정: {} {} → {} 스프레드 {:.3}% ({}틱 연속)",
+                alert.symbol, alert.buy_exchange, alert.sell_exchange, alert.spread_percent, alert.consecutive_ticks
+            );
+
+            if let Err(e) = self.repository.save(&alert.to_record()).await {
+                error!("차익거래 기회 저장 실패 ({}): {}", alert.id, e);
+            }
+
+            let title = format!("차익거래 기회: {}", alert.symbol);
+            let content = format!(
+                "{}에서 {:.2}에 매수, {}에서 {:.2}에 매도 시 스프레드 {:.3}% ({}틱 연속)",
+                alert.buy_exchange,
+                alert.buy_price,
+                alert.sell_exchange,
+                alert.sell_price,
+                alert.spread_percent,
+                alert.consecutive_ticks
+            );
+            if let Err(e) = self
+                .notifications
+                .send_notification_with_metadata(
+                    title,
+                    content,
+                    self.config.channel.clone(),
+                    self.config.priority.clone(),
+                    NotificationType::ArbitrageAlert,
+                    alert.metadata(),
+                )
+                .await
+            {
+                warn!("차익거래 알림 발송 실패 ({}): {}", alert.id, e);
+            }
+        }
+
+        alerts
+    }
+
+    /// 가격 동기화 결과 구독 후 백그라운드 처리
+    pub fn spawn(self: Arc<Self>, mut results: broadcast::Receiver<PriceSyncResult>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match results.recv().await {
+                    Ok(result) => {
+                        self.handle(&result).await;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("차익거래 알림: 동기화 결과 {}건 누락", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::exchange_sync::ArbitrageOpportunity;
+    use crate::monitoring::notification_system::NotificationConfig;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    fn tick(symbol: &str, spread_percent: Option<f64>, timestamp: u64) -> PriceSyncResult {
+        let buy_price = 100.0;
+        let arbitrage_opportunities = spread_percent
+            .map(|spread| ArbitrageOpportunity {
+                symbol: symbol.to_string(),
+                buy_exchange: ExchangeType::Binance,
+                sell_exchange: ExchangeType::Upbit,
+                buy_price,
+                sell_price: buy_price * (1.0 + spread / 100.0),
+                profit_percent: spread,
+                estimated_profit: buy_price * spread / 100.0,
+                timestamp,
+            })
+            .into_iter()
+            .collect();
+
+        PriceSyncResult {
+            symbol: symbol.to_string(),
+            internal_price: buy_price,
+            external_prices: HashMap::new(),
+            price_deviations: HashMap::new(),
+            arbitrage_opportunities,
+            sync_timestamp: timestamp,
+        }
+    }
+
+    #[test]
+    fn test_alert_after_consecutive_ticks() {
+        let mut rules = ArbitrageRuleEngine::new(ArbitrageAlertConfig::default());
+
+        // 기준 미달 스프레드는 구간에 포함되지 않음
+        assert!(rules.evaluate(&tick("BTC-KRW", Some(0.3), 1)).is_empty());
+        assert!(rules.evaluate(&tick("BTC-KRW", Some(0.6), 2)).is_empty());
+        assert!(rules.evaluate(&tick("BTC-KRW", Some(0.7), 3)).is_empty());
+
+        // 다른 심볼 틱은 구간을 끊지 않음
+        assert!(rules.evaluate(&tick("ETH-KRW", None, 4)).is_empty());
+
+        let alerts = rules.evaluate(&tick("BTC-KRW", Some(0.8), 5));
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].consecutive_ticks, 3);
+        assert_eq!(alerts[0].first_seen_at, 2);
+        assert_eq!(alerts[0].detected_at, 5);
+
+        // 같은 구간에서는 다시 알리지 않음
+        assert!(rules.evaluate(&tick("BTC-KRW", Some(0.9), 6)).is_empty());
+
+        // 구간이 끊기면 다시 N틱이 필요
+        assert!(rules.evaluate(&tick("BTC-KRW", None, 7)).is_empty());
+        assert!(rules.evaluate(&tick("BTC-KRW", Some(0.6), 8)).is_empty());
+        assert!(rules.evaluate(&tick("BTC-KRW", Some(0.6), 9)).is_empty());
+        assert_eq!(rules.evaluate(&tick("BTC-KRW", Some(0.6), 10)).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_service_records_opportunity() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        let config = ArbitrageAlertConfig {
+            consecutive_ticks: 2,
+            ..ArbitrageAlertConfig::default()
+        };
+        let notifications = Arc::new(NotificationSystem::new(NotificationConfig::default()));
+        let service = ArbitrageAlertService::new(config, notifications, pool.clone());
+
+        assert!(service.handle(&tick("BTC-KRW", Some(1.0), 1_000)).await.is_empty());
+        let alerts = service.handle(&tick("BTC-KRW", Some(1.2), 2_000)).await;
+        assert_eq!(alerts.len(), 1);
+
+        let repository = ArbitrageOpportunityRepository::new(pool);
+        let records = repository.find_recent(Some("BTC-KRW"), 10).await.unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].id, alerts[0].id);
+        assert_eq!(records[0].buy_exchange, ExchangeType::Binance.to_string());
+        assert_eq!(records[0].consecutive_ticks, 2);
+        assert_eq!(records[0].first_seen_at, 1_000);
+        assert!(repository.find_recent(Some("ETH-KRW"), 10).await.unwrap().is_empty());
+        assert_eq!(repository.find_recent(None, 10).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,228 @@
+//! 외부 거래소 주문 어댑터
+//!
+//! 스마트 주문 라우터가 외부 거래소 호가를 조회하고 주문을 전달할 때 쓰는
+//! 공통 인터페이스(`ExchangeAdapter`)와, 가격 동기화 데이터로 호가를 흉내 내는
+//! Mock 커넥터(Binance, Upbit)를 제공합니다.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::external::exchange_sync::{ExchangeType, ExternalPriceSyncManager};
+use crate::matching_engine::model::Side;
+
+/// 외부 거래소 호가 (내부 가격/수량 단위)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueOrderBook {
+    pub exchange: ExchangeType,
+    pub symbol: String,
+    /// 매수 호가 [(가격, 수량)] - 내림차순
+    pub bids: Vec<(u64, u64)>,
+    /// 매도 호가 [(가격, 수량)] - 오름차순
+    pub asks: Vec<(u64, u64)>,
+    pub timestamp: u64,
+}
+
+/// 외부 거래소 주문 요청 (IOC - 즉시 체결되지 않은 수량은 취소)
+#[derive(Debug, Clone)]
+pub struct VenueOrderRequest {
+    /// 내부 주문 ID (외부 거래소 client order id로 전달)
+    pub client_order_id: String,
+    pub symbol: String,
+    pub side: Side,
+    /// 체결 한도 가격 (매수는 이하, 매도는 이상에서만 체결)
+    pub limit_price: u64,
+    pub quantity: u64,
+}
+
+/// 외부 거래소 체결
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueFill {
+    pub price: u64,
+    pub quantity: u64,
+    /// 수수료 (호가 통화)
+    pub fee: f64,
+}
+
+/// 외부 거래소 주문 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueOrderResult {
+    pub exchange: ExchangeType,
+    /// 외부 거래소 주문 ID
+    pub venue_order_id: String,
+    pub requested_quantity: u64,
+    pub fills: Vec<VenueFill>,
+}
+
+impl VenueOrderResult {
+    /// 체결 수량 합계
+    pub fn filled_quantity(&self) -> u64 {
+        self.fills.iter().map(|f| f.quantity).sum()
+    }
+}
+
+/// 외부 거래소 어댑터 오류
+#[derive(Debug, thiserror::Error)]
+pub enum ExchangeAdapterError {
+    #[error("{exchange} 시세 없음: {symbol}")]
+    NoMarketData { exchange: ExchangeType, symbol: String },
+    #[error("{0} 연결 오류: {1}")]
+    Connection(ExchangeType, String),
+    #[error("{0} 주문 거부: {1}")]
+    Rejected(ExchangeType, String),
+}
+
+/// 외부 거래소 어댑터 공통 인터페이스
+#[async_trait]
+pub trait ExchangeAdapter: Send + Sync {
+    /// 거래소
+    fn exchange(&self) -> ExchangeType;
+
+    /// 테이커 수수료 (bp)
+    fn taker_fee_bps(&self) -> f64;
+
+    /// 내부 심볼(`BTC-KRW`)을 거래소 심볼로 변환
+    fn venue_symbol(&self, symbol: &str) -> String;
+
+    /// 호가 조회
+    async fn order_book(&self, symbol: &str, depth: usize) -> Result<VenueOrderBook, ExchangeAdapterError>;
+
+    /// IOC 주문 전송
+    async fn place_order(&self, request: &VenueOrderRequest) -> Result<VenueOrderResult, ExchangeAdapterError>;
+}
+
+/// Mock 거래소 어댑터
+///
+/// 실제 주문은 내지 않고, `ExternalPriceSyncManager`가 받은 최신 매수/매도 호가를
+/// 기준으로 레벨마다 일정 수량이 있는 호가를 만들어 그 안에서 체결합니다.
+pub struct MockExchangeAdapter {
+    exchange: ExchangeType,
+    price_source: Arc<ExternalPriceSyncManager>,
+    taker_fee_bps: f64,
+    /// 레벨별 수량
+    level_quantity: u64,
+    /// 레벨 간격 (bp)
+    level_step_bps: f64,
+}
+
+impl MockExchangeAdapter {
+    pub fn new(exchange: ExchangeType, price_source: Arc<ExternalPriceSyncManager>, taker_fee_bps: f64) -> Self {
+        Self {
+            exchange,
+            price_source,
+            taker_fee_bps,
+            level_quantity: 10,
+            level_step_bps: 5.0,
+        }
+    }
+
+    /// Binance Mock (테이커 수수료 0.1%)
+    pub fn binance(price_source: Arc<ExternalPriceSyncManager>) -> Self {
+        Self::new(ExchangeType::Binance, price_source, 10.0)
+    }
+
+    /// Upbit Mock (테이커 수수료 0.05%)
+    pub fn upbit(price_source: Arc<ExternalPriceSyncManager>) -> Self {
+        Self::new(ExchangeType::Upbit, price_source, 5.0)
+    }
+
+    /// 레벨별 수량 설정
+    pub fn with_level_quantity(mut self, level_quantity: u64) -> Self {
+        self.level_quantity = level_quantity;
+        self
+    }
+
+    async fn build_book(&self, symbol: &str, depth: usize) -> Result<VenueOrderBook, ExchangeAdapterError> {
+        let no_data = || ExchangeAdapterError::NoMarketData {
+            exchange: self.exchange.clone(),
+            symbol: symbol.to_string(),
+        };
+        let price = self.price_source.get_external_price(symbol, &self.exchange).await.ok_or_else(no_data)?;
+        let best_bid = price.bid_price.unwrap_or(price.price);
+        let best_ask = price.ask_price.unwrap_or(price.price);
+        if best_bid <= 0.0 || best_ask <= 0.0 {
+            return Err(no_data());
+        }
+
+        let step = |level: usize| self.level_step_bps * level as f64 / 10_000.0;
+        let bids = (0..depth)
+            .map(|level| ((best_bid * (1.0 - step(level))).floor() as u64, self.level_quantity))
+            .collect();
+        let asks = (0..depth)
+            .map(|level| ((best_ask * (1.0 + step(level))).ceil() as u64, self.level_quantity))
+            .collect();
+
+        Ok(VenueOrderBook {
+            exchange: self.exchange.clone(),
+            symbol: symbol.to_string(),
+            bids,
+            asks,
+            timestamp: price.timestamp,
+        })
+    }
+}
+
+#[async_trait]
+impl ExchangeAdapter for MockExchangeAdapter {
+    fn exchange(&self) -> ExchangeType {
+        self.exchange.clone()
+    }
+
+    fn taker_fee_bps(&self) -> f64 {
+        self.taker_fee_bps
+    }
+
+    fn venue_symbol(&self, symbol: &str) -> String {
+        let (base, quote) = symbol.split_once('-').unwrap_or((symbol, ""));
+        match self.exchange {
+            ExchangeType::Upbit | ExchangeType::Bithumb => format!("{}-{}", quote, base),
+            _ => format!("{}{}", base, quote),
+        }
+    }
+
+    async fn order_book(&self, symbol: &str, depth: usize) -> Result<VenueOrderBook, ExchangeAdapterError> {
+        self.build_book(symbol, depth).await
+    }
+
+    async fn place_order(&self, request: &VenueOrderRequest) -> Result<VenueOrderResult, ExchangeAdapterError> {
+        if request.quantity == 0 {
+            return Err(ExchangeAdapterError::Rejected(self.exchange.clone(), "주문 수량이 0입니다".to_string()));
+        }
+
+        // 요청 수량을 모두 담을 만큼 레벨 생성
+        let depth = request.quantity.div_ceil(self.level_quantity.max(1)) as usize;
+        let book = self.build_book(&request.symbol, depth).await?;
+        let levels = match request.side {
+            Side::Buy => book.asks,
+            Side::Sell => book.bids,
+        };
+
+        let mut fills = Vec::new();
+        let mut remaining = request.quantity;
+        for (price, quantity) in levels {
+            let within_limit = match request.side {
+                Side::Buy => price <= request.limit_price,
+                Side::Sell => price >= request.limit_price,
+            };
+            if remaining == 0 || !within_limit {
+                break;
+            }
+            let fill_quantity = remaining.min(quantity);
+            fills.push(VenueFill {
+                price,
+                quantity: fill_quantity,
+                fee: price as f64 * fill_quantity as f64 * self.taker_fee_bps / 10_000.0,
+            });
+            remaining -= fill_quantity;
+        }
+
+        Ok(VenueOrderResult {
+            exchange: self.exchange.clone(),
+            venue_order_id: format!("{}-{}", self.venue_symbol(&request.symbol), Uuid::new_v4()),
+            requested_quantity: request.quantity,
+            fills,
+        })
+    }
+}
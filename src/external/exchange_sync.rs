@@ -6,7 +6,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{broadcast, Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use log::{info, error, warn, debug};
 use tokio::time::{sleep, interval};
@@ -55,6 +55,10 @@ pub struct PriceSyncConfig {
     pub price_tolerance_percent: f64,
     pub max_price_deviation_percent: f64,
     pub enable_arbitrage_detection: bool,
+    /// 실시간 시세 커넥터로 가격을 받는 거래소 (나머지는 Mock 시세)
+    pub live_exchanges: Vec<ExchangeType>,
+    /// 실시간 시세 최대 허용 지연 (밀리초, 넘으면 동기화에서 제외)
+    pub max_price_age_ms: u64,
 }
 
 impl Default for PriceSyncConfig {
@@ -76,6 +80,8 @@ impl Default for PriceSyncConfig {
             price_tolerance_percent: 0.1, // 0.1% 허용 오차
             max_price_deviation_percent: 5.0, // 최대 5% 편차
             enable_arbitrage_detection: true,
+            live_exchanges: Vec::new(),
+            max_price_age_ms: 10_000,
         }
     }
 }
@@ -116,6 +122,8 @@ pub struct ExternalPriceSyncManager {
     sync_results: Arc<RwLock<Vec<PriceSyncResult>>>,
     /// 동기화 활성화 상태
     is_syncing: Arc<Mutex<bool>>,
+    /// 동기화 결과 구독 채널 (차익거래 알림 등)
+    result_tx: broadcast::Sender<PriceSyncResult>,
 }
 
 impl ExternalPriceSyncManager {
@@ -127,9 +135,15 @@ impl ExternalPriceSyncManager {
             internal_prices: Arc::new(RwLock::new(HashMap::new())),
             sync_results: Arc::new(RwLock::new(Vec::new())),
             is_syncing: Arc::new(Mutex::new(false)),
+            result_tx: broadcast::channel(1024).0,
         }
     }
 
+    /// 동기화 결과 구독 (심볼별 동기화가 끝날 때마다 결과 수신)
+    pub fn subscribe_results(&self) -> broadcast::Receiver<PriceSyncResult> {
+        self.result_tx.subscribe()
+    }
+
     /// 가격 동기화 시작
     pub async fn start_sync(&self) {
         let mut is_syncing = self.is_syncing.lock().await;
@@ -147,6 +161,7 @@ impl ExternalPriceSyncManager {
         let sync_results = self.sync_results.clone();
         let config = self.config.clone();
         let is_syncing = self.is_syncing.clone();
+        let result_tx = self.result_tx.clone();
 
         tokio::spawn(async move {
             let mut interval = interval(Duration::from_millis(config.sync_interval_ms));
@@ -169,6 +184,7 @@ impl ExternalPriceSyncManager {
                         &external_prices,
                         &internal_prices,
                         &sync_results,
+                        &result_tx,
                         &config,
                     ).await {
                         error!("심볼 {} 가격 동기화 실패: {}", symbol, e);
@@ -193,6 +209,7 @@ impl ExternalPriceSyncManager {
         external_prices: &Arc<RwLock<HashMap<String, HashMap<ExchangeType, ExternalPriceData>>>>,
         internal_prices: &Arc<RwLock<HashMap<String, f64>>>,
         sync_results: &Arc<RwLock<Vec<PriceSyncResult>>>,
+        result_tx: &broadcast::Sender<PriceSyncResult>,
         config: &PriceSyncConfig,
     ) -> Result<(), String> {
         // 내부 가격 조회 (Mock)
@@ -210,7 +227,7 @@ impl ExternalPriceSyncManager {
         let mut arbitrage_opportunities = Vec::new();
 
         for exchange in &config.exchanges {
-            match Self::fetch_external_price(symbol, exchange).await {
+            match Self::collect_external_price(symbol, exchange, external_prices, config).await {
                 Ok(price_data) => {
                     external_price_map.insert(exchange.clone(), price_data.price);
                     
@@ -249,6 +266,9 @@ impl ExternalPriceSyncManager {
                 .as_millis() as u64,
         };
 
+        // 구독자가 없으면 전송 실패는 무시
+        let _ = result_tx.send(sync_result.clone());
+
         {
             let mut results = sync_results.write().await;
             results.push(sync_result);
@@ -263,6 +283,44 @@ impl ExternalPriceSyncManager {
         Ok(())
     }
 
+    /// 거래소 가격 수집
+    ///
+    /// 실시간 커넥터가 연결된 거래소는 커넥터가 반영한 최신 시세를 쓰고,
+    /// 오래된 시세는 버립니다. 나머지 거래소는 Mock 시세를 만들어 저장합니다.
+    async fn collect_external_price(
+        symbol: &str,
+        exchange: &ExchangeType,
+        external_prices: &Arc<RwLock<HashMap<String, HashMap<ExchangeType, ExternalPriceData>>>>,
+        config: &PriceSyncConfig,
+    ) -> Result<ExternalPriceData, String> {
+        if config.live_exchanges.contains(exchange) {
+            let prices = external_prices.read().await;
+            let price_data = prices
+                .get(symbol)
+                .and_then(|by_exchange| by_exchange.get(exchange))
+                .ok_or_else(|| "실시간 시세 미수신".to_string())?;
+
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64;
+            let age_ms = now.saturating_sub(price_data.timestamp);
+            if age_ms > config.max_price_age_ms {
+                return Err(format!("실시간 시세 지연 ({}ms)", age_ms));
+            }
+            return Ok(price_data.clone());
+        }
+
+        let price_data = Self::fetch_external_price(symbol, exchange).await?;
+        external_prices
+            .write()
+            .await
+            .entry(symbol.to_string())
+            .or_default()
+            .insert(exchange.clone(), price_data.clone());
+        Ok(price_data)
+    }
+
     /// 내부 가격 조회 (Mock)
     async fn get_internal_price(symbol: &str) -> Result<f64, String> {
         // Mock: 실제로는 내부 시스템에서 가격 조회
@@ -348,8 +406,12 @@ impl ExternalPriceSyncManager {
         
         for i in 0..exchanges.len() {
             for j in i+1..exchanges.len() {
-                let buy_exchange = exchanges[i];
-                let sell_exchange = exchanges[j];
+                // 해시맵 순서와 관계없이 싼 쪽에서 사서 비싼 쪽에 파는 방향으로 비교
+                let (buy_exchange, sell_exchange) = if external_prices[exchanges[i]] <= external_prices[exchanges[j]] {
+                    (exchanges[i], exchanges[j])
+                } else {
+                    (exchanges[j], exchanges[i])
+                };
                 
                 let buy_price = external_prices[buy_exchange];
                 let sell_price = external_prices[sell_exchange];
@@ -386,6 +448,30 @@ impl ExternalPriceSyncManager {
         opportunities
     }
 
+    /// 거래소별 최신 외부 가격 조회
+    pub async fn get_external_price(&self, symbol: &str, exchange: &ExchangeType) -> Option<ExternalPriceData> {
+        let prices = self.external_prices.read().await;
+        prices.get(symbol).and_then(|by_exchange| by_exchange.get(exchange)).cloned()
+    }
+
+    /// 심볼의 거래소별 최신 외부 가격 전체 조회
+    pub async fn get_symbol_prices(&self, symbol: &str) -> Vec<ExternalPriceData> {
+        let prices = self.external_prices.read().await;
+        prices
+            .get(symbol)
+            .map(|by_exchange| by_exchange.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// 외부 가격 직접 반영 (동기화 루프 밖에서 받은 시세)
+    pub async fn update_external_price(&self, price_data: ExternalPriceData) {
+        let mut prices = self.external_prices.write().await;
+        prices
+            .entry(price_data.symbol.clone())
+            .or_default()
+            .insert(price_data.exchange.clone(), price_data);
+    }
+
     /// 동기화 결과 조회
     pub async fn get_sync_results(&self, limit: Option<usize>) -> Vec<PriceSyncResult> {
         let results = self.sync_results.read().await;
@@ -427,7 +513,7 @@ impl ExternalPriceSyncManager {
 
     /// 동기화 통계 조회
     pub async fn get_sync_stats(&self) -> PriceSyncStats {
-        let external_prices = self.external_prices.read().await;
+        let _external_prices = self.external_prices.read().await;
         let internal_prices = self.internal_prices.read().await;
         let sync_results = self.sync_results.read().await;
         
@@ -513,7 +599,7 @@ mod tests {
     async fn test_internal_price_fetch() {
         let price = ExternalPriceSyncManager::get_internal_price("BTC-KRW").await.unwrap();
         assert!(price > 0.0);
-        assert!(price >= 49500000.0 && price <= 50500000.0); // ±1% 범위
+        assert!((49500000.0..=50500000.0).contains(&price)); // ±1% 범위
     }
 
     #[tokio::test]
@@ -546,6 +632,48 @@ mod tests {
         assert!(opportunities[0].profit_percent > 0.0);
     }
 
+    #[tokio::test]
+    async fn test_live_exchange_uses_fresh_feed_prices_only() {
+        let config = PriceSyncConfig {
+            exchanges: vec![ExchangeType::Upbit],
+            live_exchanges: vec![ExchangeType::Upbit],
+            ..PriceSyncConfig::default()
+        };
+        let manager = ExternalPriceSyncManager::new(config.clone());
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let feed_price = |timestamp| ExternalPriceData {
+            exchange: ExchangeType::Upbit,
+            symbol: "BTC-KRW".to_string(),
+            price: 98_765_000.0,
+            volume: 1.5,
+            timestamp,
+            bid_price: Some(98_760_000.0),
+            ask_price: Some(98_770_000.0),
+            spread: Some(10_000.0),
+        };
+
+        // 시세 미수신 시 Mock 가격으로 대체하지 않음
+        let missing = ExternalPriceSyncManager::collect_external_price(
+            "BTC-KRW", &ExchangeType::Upbit, &manager.external_prices, &config,
+        ).await;
+        assert!(missing.is_err());
+
+        manager.update_external_price(feed_price(now)).await;
+        let fresh = ExternalPriceSyncManager::collect_external_price(
+            "BTC-KRW", &ExchangeType::Upbit, &manager.external_prices, &config,
+        ).await.unwrap();
+        assert_eq!(fresh.price, 98_765_000.0);
+
+        manager.update_external_price(feed_price(now - config.max_price_age_ms - 1_000)).await;
+        let stale = ExternalPriceSyncManager::collect_external_price(
+            "BTC-KRW", &ExchangeType::Upbit, &manager.external_prices, &config,
+        ).await;
+        assert!(stale.is_err());
+    }
+
     #[tokio::test]
     async fn test_price_sync_config() {
         let config = PriceSyncConfig {
@@ -555,6 +683,8 @@ mod tests {
             price_tolerance_percent: 0.5,
             max_price_deviation_percent: 10.0,
             enable_arbitrage_detection: false,
+            live_exchanges: Vec::new(),
+            max_price_age_ms: 10_000,
         };
         
         assert_eq!(config.sync_interval_ms, 2000);
@@ -0,0 +1,506 @@
+//! 외부 거래소 시세 기반 인덱스 가격과 공정 가격
+//!
+//! 가격 동기화 관리자가 모은 거래소별 최신 시세로 심볼별 인덱스 가격을 주기적으로 계산합니다.
+//! - `max_age_ms`보다 오래된 시세는 제외
+//! - 남은 시세의 중앙값에서 `outlier_percent` 넘게 벗어난 거래소를 이상치로 제외한 뒤 다시 중앙값
+//! - 유효한 거래소가 `min_sources` 미만이면 직전 인덱스를 유지하고 `stale`로 표시
+//!
+//! 공정 가격은 내부 호가 중간값을 인덱스 ± `fair_price_band_percent` 안으로 제한한 값이며,
+//! 내부 호가가 한쪽이라도 비어 있으면 인덱스를 그대로 씁니다. 증거금 엔진이 생기면 이 값을 마크 가격으로 씁니다.
+//! 계산 결과는 `/ws/index` 채널로 발행합니다. 서킷 브레이커를 설정하면 내부 호가 중간값이 인덱스에서
+//! 기준 이상 벌어진 상태가 N틱 이어질 때 킬 스위치로 해당 심볼 거래를 중단합니다 (재개는 관리자가 직접).
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use log::{error, warn};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use crate::api::models::{BboUpdate, IndexPriceUpdate, WebSocketMessage};
+use crate::external::exchange_sync::{ExternalPriceData, ExternalPriceSyncManager};
+use crate::kill_switch::{KillSwitch, KillSwitchScope};
+use crate::util::clock::{SharedClock, SystemClock};
+
+/// 서킷 브레이커가 킬 스위치를 발동할 때 기록하는 발동자
+pub const INDEX_CIRCUIT_BREAKER_ACTOR: &str = "index_circuit_breaker";
+
+/// 인덱스 괴리 서킷 브레이커 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexCircuitBreakerConfig {
+    /// 내부 호가 중간값과 인덱스의 최대 허용 괴리 (%)
+    pub max_deviation_percent: f64,
+    /// 연속 틱 수 (이만큼 이어져야 거래 중단)
+    pub consecutive_ticks: u32,
+}
+
+impl Default for IndexCircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            max_deviation_percent: 10.0,
+            consecutive_ticks: 5,
+        }
+    }
+}
+
+/// 인덱스 가격 계산 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexPriceConfig {
+    /// 계산/발행 주기
+    pub interval: Duration,
+    /// 거래소 시세 최대 허용 지연 (밀리초, 넘으면 제외)
+    pub max_age_ms: u64,
+    /// 중앙값에서 이만큼(%) 넘게 벗어난 거래소는 이상치로 제외
+    pub outlier_percent: f64,
+    /// 인덱스를 새로 계산하는 데 필요한 최소 거래소 수
+    pub min_sources: usize,
+    /// 공정 가격이 인덱스에서 벗어날 수 있는 범위 (%)
+    pub fair_price_band_percent: f64,
+    /// 인덱스 괴리 서킷 브레이커 (None이면 사용 안 함)
+    pub circuit_breaker: Option<IndexCircuitBreakerConfig>,
+}
+
+impl Default for IndexPriceConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            max_age_ms: 10_000,
+            outlier_percent: 2.0,
+            min_sources: 2,
+            fair_price_band_percent: 0.5,
+            circuit_breaker: None,
+        }
+    }
+}
+
+impl IndexPriceConfig {
+    /// 설정 검증
+    pub fn validate(&self) -> Result<(), String> {
+        if self.interval.is_zero() {
+            return Err("인덱스 계산 주기는 0보다 커야 합니다".to_string());
+        }
+        if self.min_sources == 0 {
+            return Err("인덱스 최소 거래소 수는 1 이상이어야 합니다".to_string());
+        }
+        if self.outlier_percent.is_nan() || self.outlier_percent <= 0.0 {
+            return Err(format!("인덱스 이상치 기준은 0보다 커야 합니다: {}", self.outlier_percent));
+        }
+        if self.fair_price_band_percent.is_nan() || self.fair_price_band_percent < 0.0 {
+            return Err(format!("공정 가격 허용 범위는 0 이상이어야 합니다: {}", self.fair_price_band_percent));
+        }
+        if let Some(breaker) = &self.circuit_breaker {
+            if breaker.max_deviation_percent.is_nan() || breaker.max_deviation_percent <= 0.0 {
+                return Err(format!("서킷 브레이커 괴리 기준은 0보다 커야 합니다: {}", breaker.max_deviation_percent));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 한 틱의 인덱스 계산 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexComputation {
+    /// 인덱스 가격 (유효 거래소가 부족하면 None)
+    pub price: Option<f64>,
+    pub sources: Vec<String>,
+    pub outliers: Vec<String>,
+    pub stale_sources: Vec<String>,
+}
+
+/// 중앙값 (빈 목록이면 None)
+fn median(prices: &mut [f64]) -> Option<f64> {
+    if prices.is_empty() {
+        return None;
+    }
+    prices.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = prices.len() / 2;
+    Some(if prices.len().is_multiple_of(2) { (prices[mid - 1] + prices[mid]) / 2.0 } else { prices[mid] })
+}
+
+/// 거래소별 시세로 인덱스 가격 계산
+pub fn compute_index(quotes: &[ExternalPriceData], now_ms: u64, config: &IndexPriceConfig) -> IndexComputation {
+    let mut fresh = Vec::new();
+    let mut stale_sources = Vec::new();
+    for quote in quotes {
+        let valid = quote.price.is_finite() && quote.price > 0.0;
+        if valid && now_ms.saturating_sub(quote.timestamp) <= config.max_age_ms {
+            fresh.push((quote.exchange.to_string(), quote.price));
+        } else {
+            stale_sources.push(quote.exchange.to_string());
+        }
+    }
+    fresh.sort_by(|a, b| a.0.cmp(&b.0));
+    stale_sources.sort();
+
+    let center = median(&mut fresh.iter().map(|(_, price)| *price).collect::<Vec<_>>());
+    let (kept, outliers): (Vec<_>, Vec<_>) = fresh.into_iter().partition(|(_, price)| {
+        center.is_some_and(|center| ((price - center) / center).abs() * 100.0 <= config.outlier_percent)
+    });
+
+    let price = if kept.len() >= config.min_sources {
+        median(&mut kept.iter().map(|(_, price)| *price).collect::<Vec<_>>())
+    } else {
+        None
+    };
+    IndexComputation {
+        price,
+        sources: kept.into_iter().map(|(exchange, _)| exchange).collect(),
+        outliers: outliers.into_iter().map(|(exchange, _)| exchange).collect(),
+        stale_sources,
+    }
+}
+
+/// 공정 가격 (내부 호가 중간값을 인덱스 ± 허용 범위로 제한, 호가가 없으면 인덱스)
+pub fn fair_price(index_price: f64, internal_mid: Option<f64>, band_percent: f64) -> f64 {
+    match internal_mid {
+        Some(mid) => {
+            let band = index_price * band_percent / 100.0;
+            mid.clamp(index_price - band, index_price + band)
+        }
+        None => index_price,
+    }
+}
+
+/// 인덱스 괴리 연속 틱 판정
+///
+/// 같은 심볼의 괴리가 기준을 넘는 틱이 `consecutive_ticks`번 이어지면 한 번만 발동합니다.
+/// 한 틱이라도 기준 안으로 들어오거나 판단할 수 없으면(내부 호가 없음, 인덱스 stale) 구간이 끊깁니다.
+#[derive(Debug)]
+pub struct IndexCircuitBreaker {
+    config: IndexCircuitBreakerConfig,
+    streaks: HashMap<String, u32>,
+}
+
+impl IndexCircuitBreaker {
+    pub fn new(config: IndexCircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            streaks: HashMap::new(),
+        }
+    }
+
+    /// 한 틱 평가 (발동해야 하면 괴리율(%) 반환)
+    pub fn evaluate(&mut self, update: &IndexPriceUpdate) -> Option<f64> {
+        let deviation = update
+            .internal_mid
+            .filter(|_| !update.stale)
+            .map(|mid| (mid - update.index_price).abs() / update.index_price * 100.0)
+            .filter(|deviation| *deviation > self.config.max_deviation_percent);
+        let Some(deviation) = deviation else {
+            self.streaks.remove(&update.symbol);
+            return None;
+        };
+
+        let ticks = self.streaks.entry(update.symbol.clone()).or_insert(0);
+        *ticks += 1;
+        (*ticks == self.config.consecutive_ticks.max(1)).then_some(deviation)
+    }
+}
+
+/// 인덱스/공정 가격 서비스
+pub struct IndexPriceService {
+    config: IndexPriceConfig,
+    symbols: Vec<String>,
+    sources: Arc<ExternalPriceSyncManager>,
+    /// 심볼별 최신 인덱스 (한 번도 계산하지 못한 심볼은 없음)
+    prices: RwLock<HashMap<String, IndexPriceUpdate>>,
+    /// 심볼별 내부 호가 중간값
+    internal_mids: RwLock<HashMap<String, f64>>,
+    breaker: Option<Mutex<IndexCircuitBreaker>>,
+    kill_switch: Option<Arc<KillSwitch>>,
+    publisher: Option<broadcast::Sender<WebSocketMessage>>,
+    clock: SharedClock,
+}
+
+impl IndexPriceService {
+    pub fn new(config: IndexPriceConfig, symbols: Vec<String>, sources: Arc<ExternalPriceSyncManager>) -> Self {
+        Self {
+            breaker: config.circuit_breaker.clone().map(|breaker| Mutex::new(IndexCircuitBreaker::new(breaker))),
+            config,
+            symbols,
+            sources,
+            prices: RwLock::new(HashMap::new()),
+            internal_mids: RwLock::new(HashMap::new()),
+            kill_switch: None,
+            publisher: None,
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// 서킷 브레이커가 거래를 중단할 킬 스위치
+    pub fn with_kill_switch(mut self, kill_switch: Arc<KillSwitch>) -> Self {
+        self.kill_switch = Some(kill_switch);
+        self
+    }
+
+    /// 계산 결과를 발행할 WebSocket 채널
+    pub fn with_publisher(mut self, publisher: broadcast::Sender<WebSocketMessage>) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    /// 계산 주기의 시각 소스
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn config(&self) -> &IndexPriceConfig {
+        &self.config
+    }
+
+    /// 최신 인덱스 가격
+    pub fn index_price(&self, symbol: &str) -> Option<f64> {
+        self.get(symbol).map(|update| update.index_price)
+    }
+
+    /// 최신 공정 가격 (마크 가격)
+    pub fn fair_price(&self, symbol: &str) -> Option<f64> {
+        self.get(symbol).map(|update| update.fair_price)
+    }
+
+    /// 심볼의 최신 인덱스
+    pub fn get(&self, symbol: &str) -> Option<IndexPriceUpdate> {
+        self.prices.read().unwrap().get(symbol).cloned()
+    }
+
+    /// 전 심볼 최신 인덱스 (심볼 순)
+    pub fn all(&self) -> Vec<IndexPriceUpdate> {
+        let mut prices: Vec<_> = self.prices.read().unwrap().values().cloned().collect();
+        prices.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        prices
+    }
+
+    /// 내부 최우선 호가 반영
+    pub fn record_bbo(&self, bbo: &BboUpdate) {
+        let mut mids = self.internal_mids.write().unwrap();
+        match (bbo.bid_price, bbo.ask_price) {
+            (Some(bid), Some(ask)) => {
+                mids.insert(bbo.symbol.clone(), (bid as f64 + ask as f64) / 2.0);
+            }
+            _ => {
+                mids.remove(&bbo.symbol);
+            }
+        }
+    }
+
+    /// 전 심볼 인덱스 계산 후 발행, 서킷 브레이커 평가
+    pub async fn tick(&self, now_ms: u64) -> Vec<IndexPriceUpdate> {
+        let mut updates = Vec::new();
+        for symbol in &self.symbols {
+            let quotes = self.sources.get_symbol_prices(symbol).await;
+            if let Some(update) = self.update_symbol(symbol, &quotes, now_ms) {
+                updates.push(update);
+            }
+        }
+
+        for update in &updates {
+            if let Some(publisher) = &self.publisher {
+                let _ = publisher.send(WebSocketMessage::IndexPrice(update.clone()));
+            }
+            let tripped = self.breaker.as_ref().and_then(|breaker| breaker.lock().unwrap().evaluate(update));
+            if let Some(deviation) = tripped {
+                self.trip(update, deviation).await;
+            }
+        }
+        updates
+    }
+
+    /// 한 심볼 인덱스 갱신 (한 번도 계산하지 못했으면 None)
+    fn update_symbol(&self, symbol: &str, quotes: &[ExternalPriceData], now_ms: u64) -> Option<IndexPriceUpdate> {
+        let computation = compute_index(quotes, now_ms, &self.config);
+        let mut prices = self.prices.write().unwrap();
+        let previous = prices.get(symbol);
+        let (index_price, index_updated_at) = match (computation.price, previous) {
+            (Some(price), _) => (price, now_ms),
+            (None, Some(previous)) => (previous.index_price, previous.index_updated_at),
+            (None, None) => return None,
+        };
+        if computation.price.is_none() && previous.is_some_and(|previous| !previous.stale) {
+            warn!(
+                "{} 인덱스 유효 거래소 부족 ({}개), 직전 인덱스 {} 유지",
+                symbol,
+                computation.sources.len(),
+                index_price
+            );
+        }
+
+        let internal_mid = self.internal_mids.read().unwrap().get(symbol).copied();
+        let update = IndexPriceUpdate {
+            symbol: symbol.to_string(),
+            index_price,
+            fair_price: fair_price(index_price, internal_mid, self.config.fair_price_band_percent),
+            internal_mid,
+            sources: computation.sources,
+            outliers: computation.outliers,
+            stale_sources: computation.stale_sources,
+            stale: computation.price.is_none(),
+            index_updated_at,
+            timestamp: now_ms,
+        };
+        prices.insert(symbol.to_string(), update.clone());
+        Some(update)
+    }
+
+    /// 서킷 브레이커 발동 (이미 거래 중단된 심볼은 그대로 둠)
+    async fn trip(&self, update: &IndexPriceUpdate, deviation: f64) {
+        let Some(kill_switch) = &self.kill_switch else {
+            return;
+        };
+        if kill_switch.is_symbol_halted(&update.symbol) {
+            return;
+        }
+        let reason = format!(
+            "인덱스 괴리 {:.2}% (내부 {:.2}, 인덱스 {:.2})",
+            deviation,
+            update.internal_mid.unwrap_or_default(),
+            update.index_price
+        );
+        if let Err(e) = kill_switch
+            .activate(KillSwitchScope::Symbol, &update.symbol, Some(reason), INDEX_CIRCUIT_BREAKER_ACTOR)
+            .await
+        {
+            error!("{} 인덱스 서킷 브레이커 발동 실패: {}", update.symbol, e);
+        }
+    }
+
+    /// 주기적 계산과 내부 최우선 호가 구독을 백그라운드로 실행
+    pub fn spawn(self: Arc<Self>, mut market_data: broadcast::Receiver<WebSocketMessage>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut next_tick = self.clock.sleep(self.config.interval);
+            loop {
+                tokio::select! {
+                    _ = &mut next_tick => {
+                        self.tick(self.clock.now_millis()).await;
+                        next_tick = self.clock.sleep(self.config.interval);
+                    }
+                    received = market_data.recv() => match received {
+                        Ok(WebSocketMessage::Bbo(bbo)) => self.record_bbo(&bbo),
+                        Ok(_) => {}
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!("인덱스 가격: 시장 데이터 {}건 누락", skipped);
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::exchange_sync::{ExchangeType, PriceSyncConfig};
+
+    fn quote(exchange: ExchangeType, price: f64, timestamp: u64) -> ExternalPriceData {
+        ExternalPriceData {
+            exchange,
+            symbol: "BTC-KRW".to_string(),
+            price,
+            volume: 1.0,
+            timestamp,
+            bid_price: None,
+            ask_price: None,
+            spread: None,
+        }
+    }
+
+    #[test]
+    fn test_compute_index_rejects_stale_and_outliers() {
+        let config = IndexPriceConfig::default();
+        let quotes = vec![
+            quote(ExchangeType::Binance, 100.0, 10_000),
+            quote(ExchangeType::Coinbase, 101.0, 10_000),
+            quote(ExchangeType::Kraken, 102.0, 10_000),
+            // 중앙값 101에서 2% 넘게 벗어남
+            quote(ExchangeType::Upbit, 110.0, 10_000),
+            // 10초 넘게 지난 시세
+            quote(ExchangeType::Bithumb, 50.0, 1),
+        ];
+
+        let computation = compute_index(&quotes, 12_000, &config);
+        assert_eq!(computation.price, Some(101.0));
+        assert_eq!(computation.sources, vec!["Binance", "Coinbase", "Kraken"]);
+        assert_eq!(computation.outliers, vec!["Upbit"]);
+        assert_eq!(computation.stale_sources, vec!["Bithumb"]);
+
+        // 유효 거래소가 최소 수에 못 미치면 계산하지 않음
+        let computation = compute_index(&quotes[..1], 12_000, &config);
+        assert_eq!(computation.price, None);
+        assert_eq!(computation.sources, vec!["Binance"]);
+    }
+
+    #[test]
+    fn test_fair_price_clamped_to_index_band() {
+        assert_eq!(fair_price(1000.0, None, 0.5), 1000.0);
+        assert_eq!(fair_price(1000.0, Some(1003.0), 0.5), 1003.0);
+        assert_eq!(fair_price(1000.0, Some(1100.0), 0.5), 1005.0);
+        assert_eq!(fair_price(1000.0, Some(900.0), 0.5), 995.0);
+    }
+
+    #[tokio::test]
+    async fn test_service_keeps_previous_index_when_sources_go_stale() {
+        let sources = Arc::new(ExternalPriceSyncManager::new(PriceSyncConfig::default()));
+        sources.update_external_price(quote(ExchangeType::Binance, 100.0, 1_000)).await;
+        sources.update_external_price(quote(ExchangeType::Upbit, 102.0, 1_000)).await;
+        let (tx, mut rx) = broadcast::channel(16);
+        let service = IndexPriceService::new(IndexPriceConfig::default(), vec!["BTC-KRW".to_string()], sources)
+            .with_publisher(tx);
+        service.record_bbo(&BboUpdate {
+            symbol: "BTC-KRW".to_string(),
+            bid_price: Some(104),
+            bid_quantity: 1,
+            ask_price: Some(106),
+            ask_quantity: 1,
+            timestamp: 1_000,
+            sequence: 1,
+        });
+
+        let updates = service.tick(2_000).await;
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].index_price, 101.0);
+        assert_eq!(updates[0].internal_mid, Some(105.0));
+        // 인덱스 101의 ±0.5%로 제한
+        assert_eq!(service.fair_price("BTC-KRW"), Some(101.505));
+        assert!(matches!(rx.try_recv(), Ok(WebSocketMessage::IndexPrice(update)) if update == updates[0]));
+
+        // 시세가 모두 오래되면 직전 인덱스 유지
+        let updates = service.tick(20_000).await;
+        assert!(updates[0].stale);
+        assert_eq!(updates[0].index_price, 101.0);
+        assert_eq!(updates[0].index_updated_at, 2_000);
+        assert_eq!(updates[0].stale_sources, vec!["Binance", "Upbit"]);
+        assert_eq!(service.index_price("ETH-KRW"), None);
+    }
+
+    #[test]
+    fn test_circuit_breaker_trips_after_consecutive_ticks() {
+        let mut breaker = IndexCircuitBreaker::new(IndexCircuitBreakerConfig {
+            max_deviation_percent: 5.0,
+            consecutive_ticks: 2,
+        });
+        let update = |internal_mid: Option<f64>, stale: bool| IndexPriceUpdate {
+            symbol: "BTC-KRW".to_string(),
+            index_price: 100.0,
+            fair_price: 100.0,
+            internal_mid,
+            sources: Vec::new(),
+            outliers: Vec::new(),
+            stale_sources: Vec::new(),
+            stale,
+            index_updated_at: 0,
+            timestamp: 0,
+        };
+
+        assert_eq!(breaker.evaluate(&update(Some(110.0), false)), None);
+        // stale 인덱스는 판단하지 않고 구간을 끊음
+        assert_eq!(breaker.evaluate(&update(Some(110.0), true)), None);
+        assert_eq!(breaker.evaluate(&update(Some(110.0), false)), None);
+        assert_eq!(breaker.evaluate(&update(Some(90.0), false)), Some(10.0));
+        // 같은 구간에서는 다시 발동하지 않음
+        assert_eq!(breaker.evaluate(&update(Some(90.0), false)), None);
+        assert_eq!(breaker.evaluate(&update(Some(104.0), false)), None);
+    }
+}
@@ -0,0 +1,107 @@
+//! 실거래소 공개 시세 커넥터
+//!
+//! 거래소 공개 WebSocket 시세를 구독해 내부 가격 모델(`ExternalPriceData`)로 정규화하고
+//! `ExternalPriceSyncManager`에 반영합니다. 연결이 끊기면 일정 시간 뒤 다시 연결해 재구독합니다.
+//! 커넥터별로 기능 플래그(`upbit-feed` 등) 뒤에 있습니다.
+
+#[cfg(feature = "upbit-feed")]
+pub mod upbit;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use log::{error, info, warn};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use crate::external::exchange_sync::{ExchangeType, ExternalPriceData, ExternalPriceSyncManager};
+
+/// 재연결 대기 시간
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// 연결 유지용 ping 주기 (거래소가 유휴 연결을 끊지 않도록)
+const PING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 거래소 시세 커넥터
+pub trait LivePriceFeed: Send + 'static {
+    /// 거래소
+    fn exchange(&self) -> ExchangeType;
+
+    /// WebSocket 주소
+    fn url(&self) -> &str;
+
+    /// 구독 요청 메시지 (내부 심볼 목록)
+    fn subscribe_message(&self, symbols: &[String]) -> String;
+
+    /// 수신 메시지를 내부 가격 모델로 변환 (가격이 아직 없으면 빈 목록)
+    fn parse(&mut self, payload: &[u8]) -> Vec<ExternalPriceData>;
+}
+
+/// 기능 플래그로 활성화된 커넥터의 거래소 목록
+pub fn enabled_exchanges() -> Vec<ExchangeType> {
+    #[allow(unused_mut)]
+    let mut exchanges = Vec::new();
+    #[cfg(feature = "upbit-feed")]
+    exchanges.push(ExchangeType::Upbit);
+    exchanges
+}
+
+/// 활성화된 커넥터를 모두 백그라운드로 시작
+#[allow(unused_variables)]
+pub fn spawn_feeds(manager: Arc<ExternalPriceSyncManager>, symbols: Vec<String>) {
+    #[cfg(feature = "upbit-feed")]
+    tokio::spawn(run_feed(upbit::UpbitFeed::new(), manager.clone(), symbols.clone()));
+}
+
+/// 커넥터 실행 (연결이 끊기면 재연결)
+pub async fn run_feed<F: LivePriceFeed>(mut feed: F, manager: Arc<ExternalPriceSyncManager>, symbols: Vec<String>) {
+    let exchange = feed.exchange();
+    loop {
+        match connect_async(feed.url()).await {
+            Ok((stream, _)) => {
+                info!("{} 실시간 시세 연결: {}", exchange, feed.url());
+                let (mut write, mut read) = stream.split();
+
+                if let Err(e) = write.send(Message::Text(feed.subscribe_message(&symbols))).await {
+                    error!("{} 시세 구독 요청 실패: {}", exchange, e);
+                } else {
+                    let mut ping = tokio::time::interval(PING_INTERVAL);
+                    loop {
+                        tokio::select! {
+                            received = read.next() => match received {
+                                Some(Ok(Message::Text(text))) => apply(&mut feed, &manager, text.as_bytes()).await,
+                                Some(Ok(Message::Binary(bytes))) => apply(&mut feed, &manager, &bytes).await,
+                                Some(Ok(Message::Close(frame))) => {
+                                    warn!("{} 시세 연결 종료: {:?}", exchange, frame);
+                                    break;
+                                }
+                                Some(Ok(_)) => {}
+                                Some(Err(e)) => {
+                                    warn!("{} 시세 수신 오류: {}", exchange, e);
+                                    break;
+                                }
+                                None => break,
+                            },
+                            _ = ping.tick() => {
+                                if let Err(e) = write.send(Message::Ping(Vec::new())).await {
+                                    warn!("{} ping 전송 실패: {}", exchange, e);
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            Err(e) => error!("{} 실시간 시세 연결 실패: {}", exchange, e),
+        }
+
+        warn!("{} 실시간 시세 {}초 후 재연결", exchange, RECONNECT_DELAY.as_secs());
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+async fn apply<F: LivePriceFeed>(feed: &mut F, manager: &ExternalPriceSyncManager, payload: &[u8]) {
+    for price_data in feed.parse(payload) {
+        manager.update_external_price(price_data).await;
+    }
+}
@@ -0,0 +1,173 @@
+//! Upbit 공개 시세 커넥터
+//!
+//! `ticker`(현재가, 24시간 거래량)와 `orderbook`(최우선 호가)을 함께 구독해
+//! 마켓별로 합친 뒤 내부 가격 모델로 변환합니다. Upbit 마켓 코드는 `KRW-BTC`,
+//! 내부 심볼은 `BTC-KRW`입니다.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::LivePriceFeed;
+use crate::external::exchange_sync::{ExchangeType, ExternalPriceData};
+
+/// Upbit 공개 WebSocket 주소
+pub const UPBIT_WEBSOCKET_URL: &str = "wss://api.upbit.com/websocket/v1";
+
+/// Upbit 시세 메시지 (필요한 필드만)
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum UpbitMessage {
+    Ticker {
+        code: String,
+        trade_price: f64,
+        acc_trade_volume_24h: f64,
+        timestamp: u64,
+    },
+    Orderbook {
+        code: String,
+        timestamp: u64,
+        orderbook_units: Vec<UpbitOrderbookUnit>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct UpbitOrderbookUnit {
+    ask_price: f64,
+    bid_price: f64,
+}
+
+/// 마켓별 최신 시세
+#[derive(Debug, Default)]
+struct MarketState {
+    trade_price: Option<f64>,
+    volume_24h: f64,
+    bid_price: Option<f64>,
+    ask_price: Option<f64>,
+    timestamp: u64,
+}
+
+/// Upbit 시세 커넥터
+#[derive(Debug, Default)]
+pub struct UpbitFeed {
+    markets: HashMap<String, MarketState>,
+}
+
+impl UpbitFeed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// 내부 심볼 → Upbit 마켓 코드 (`BTC-KRW` → `KRW-BTC`)
+pub fn to_market_code(symbol: &str) -> Option<String> {
+    let (base, quote) = symbol.split_once('-')?;
+    Some(format!("{}-{}", quote, base))
+}
+
+/// Upbit 마켓 코드 → 내부 심볼 (`KRW-BTC` → `BTC-KRW`)
+pub fn to_symbol(code: &str) -> Option<String> {
+    let (quote, base) = code.split_once('-')?;
+    Some(format!("{}-{}", base, quote))
+}
+
+impl LivePriceFeed for UpbitFeed {
+    fn exchange(&self) -> ExchangeType {
+        ExchangeType::Upbit
+    }
+
+    fn url(&self) -> &str {
+        UPBIT_WEBSOCKET_URL
+    }
+
+    fn subscribe_message(&self, symbols: &[String]) -> String {
+        // 주식 등 Upbit에 없는 형식의 심볼은 제외
+        let codes: Vec<String> = symbols.iter().filter_map(|s| to_market_code(s)).collect();
+        serde_json::json!([
+            { "ticket": format!("xtrader-{}", Uuid::new_v4()) },
+            { "type": "ticker", "codes": codes },
+            { "type": "orderbook", "codes": codes },
+        ])
+        .to_string()
+    }
+
+    fn parse(&mut self, payload: &[u8]) -> Vec<ExternalPriceData> {
+        // 구독 응답, 상태 메시지 등 시세가 아닌 메시지는 무시
+        let Ok(message) = serde_json::from_slice::<UpbitMessage>(payload) else {
+            return Vec::new();
+        };
+
+        let code = match message {
+            UpbitMessage::Ticker { code, trade_price, acc_trade_volume_24h, timestamp } => {
+                let state = self.markets.entry(code.clone()).or_default();
+                state.trade_price = Some(trade_price);
+                state.volume_24h = acc_trade_volume_24h;
+                state.timestamp = state.timestamp.max(timestamp);
+                code
+            }
+            UpbitMessage::Orderbook { code, timestamp, orderbook_units } => {
+                let state = self.markets.entry(code.clone()).or_default();
+                if let Some(best) = orderbook_units.first() {
+                    state.bid_price = Some(best.bid_price);
+                    state.ask_price = Some(best.ask_price);
+                }
+                state.timestamp = state.timestamp.max(timestamp);
+                code
+            }
+        };
+
+        let (Some(symbol), Some(state)) = (to_symbol(&code), self.markets.get(&code)) else {
+            return Vec::new();
+        };
+        let Some(price) = state.trade_price else {
+            return Vec::new();
+        };
+
+        vec![ExternalPriceData {
+            exchange: ExchangeType::Upbit,
+            symbol,
+            price,
+            volume: state.volume_24h,
+            timestamp: state.timestamp,
+            bid_price: state.bid_price,
+            ask_price: state.ask_price,
+            spread: state.bid_price.zip(state.ask_price).map(|(bid, ask)| ask - bid),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ticker_and_orderbook() {
+        let mut feed = UpbitFeed::new();
+
+        let subscribe = feed.subscribe_message(&["BTC-KRW".to_string(), "AAPL".to_string()]);
+        assert!(subscribe.contains(r#""codes":["KRW-BTC"]"#));
+
+        // 호가만 먼저 오면 현재가가 없어 아직 반영하지 않음
+        let orderbook = br#"{"type":"orderbook","code":"KRW-BTC","timestamp":1747126800100,"total_ask_size":1.2,"total_bid_size":3.4,
+            "orderbook_units":[{"ask_price":98770000.0,"bid_price":98760000.0,"ask_size":0.1,"bid_size":0.2}],"stream_type":"REALTIME"}"#;
+        assert!(feed.parse(orderbook).is_empty());
+
+        let ticker = br#"{"type":"ticker","code":"KRW-BTC","opening_price":98000000.0,"trade_price":98765000.0,
+            "acc_trade_volume_24h":1234.5,"timestamp":1747126800200,"stream_type":"REALTIME"}"#;
+        let prices = feed.parse(ticker);
+        assert_eq!(prices.len(), 1);
+
+        let btc = &prices[0];
+        assert_eq!(btc.exchange, ExchangeType::Upbit);
+        assert_eq!(btc.symbol, "BTC-KRW");
+        assert_eq!(btc.price, 98_765_000.0);
+        assert_eq!(btc.volume, 1234.5);
+        assert_eq!(btc.timestamp, 1_747_126_800_200);
+        assert_eq!(btc.bid_price, Some(98_760_000.0));
+        assert_eq!(btc.ask_price, Some(98_770_000.0));
+        assert_eq!(btc.spread, Some(10_000.0));
+
+        assert!(feed.parse(br#"{"status":"UP"}"#).is_empty());
+    }
+}
@@ -4,9 +4,27 @@
 //! 연동을 제공합니다.
 
 pub mod exchange_sync;
+pub mod exchange_adapter;
+pub mod order_router;
+pub mod arbitrage_alert;
+pub mod index_price;
+#[cfg(feature = "live-feed")]
+pub mod live_feed;
 pub mod regulatory_reporting;
+pub mod aml_rules;
+pub mod report_formats;
+pub mod report_delivery;
 pub mod analytics_integration;
+pub mod object_storage;
 
 pub use exchange_sync::*;
+pub use exchange_adapter::*;
+pub use order_router::*;
+pub use arbitrage_alert::*;
+pub use index_price::*;
 pub use regulatory_reporting::*;
-pub use analytics_integration::*;
\ No newline at end of file
+pub use aml_rules::*;
+pub use report_formats::*;
+pub use report_delivery::*;
+pub use analytics_integration::*;
+pub use object_storage::{ObjectMeta, ObjectStorageError, ObjectStore, S3Client, S3Config};
\ No newline at end of file
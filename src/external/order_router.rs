@@ -0,0 +1,364 @@
+//! 스마트 주문 라우터 (SOR)
+//!
+//! 로컬 주문장 유동성으로 채우지 못하는 수량을 외부 거래소 호가에 가격순(수수료 포함)으로
+//! 나눠 IOC 주문으로 전달하고, 거래소별 체결을 원 주문의 체결 보고서로 합쳐 돌려줍니다.
+
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::future::join_all;
+use log::{info, warn};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::external::exchange_adapter::{ExchangeAdapter, VenueOrderRequest, VenueOrderResult};
+use crate::external::exchange_sync::ExchangeType;
+use crate::matching_engine::model::{ExecType, ExecutionReport, Order, OrderBookSnapshot, OrderType, Side};
+
+/// 라우터 설정
+#[derive(Debug, Clone)]
+pub struct RouterConfig {
+    /// 로컬/외부 호가 조회 깊이
+    pub book_depth: usize,
+    /// 거래소별 호가 조회/주문 응답 대기 시간
+    pub venue_timeout: Duration,
+}
+
+impl Default for RouterConfig {
+    fn default() -> Self {
+        Self {
+            book_depth: 20,
+            venue_timeout: Duration::from_secs(2),
+        }
+    }
+}
+
+/// 거래소별 배분
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VenueAllocation {
+    pub exchange: ExchangeType,
+    pub quantity: u64,
+    /// 배분한 호가 중 가장 불리한 가격 (IOC 한도 가격)
+    pub limit_price: u64,
+}
+
+/// 거래소 주문 실패
+#[derive(Debug, Clone, Serialize)]
+pub struct VenueFailure {
+    pub exchange: ExchangeType,
+    pub reason: String,
+}
+
+/// 라우팅 결과
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RoutingResult {
+    pub allocations: Vec<VenueAllocation>,
+    pub results: Vec<VenueOrderResult>,
+    pub failures: Vec<VenueFailure>,
+}
+
+impl RoutingResult {
+    /// 외부 체결 수량 합계
+    pub fn filled_quantity(&self) -> u64 {
+        self.results.iter().map(|r| r.filled_quantity()).sum()
+    }
+
+    /// 외부 체결을 원 주문의 체결 보고서로 변환
+    ///
+    /// 주문 ID는 원 주문 그대로, 상대방 ID는 `{거래소}:{외부 주문 ID}`입니다.
+    /// 남은 수량은 원 주문 수량에서 외부 체결분을 차례로 뺀 값입니다.
+    pub fn execution_reports(&self, order: &Order) -> Vec<ExecutionReport> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let mut remaining = order.quantity;
+        let mut reports = Vec::new();
+
+        for result in &self.results {
+            for fill in &result.fills {
+                remaining = remaining.saturating_sub(fill.quantity);
+                reports.push(ExecutionReport {
+                    execution_id: Uuid::new_v4().to_string(),
+                    order_id: order.id.clone(),
+                    symbol: order.symbol.clone(),
+                    side: order.side.clone(),
+                    price: fill.price,
+                    quantity: fill.quantity,
+                    remaining_quantity: remaining,
+                    timestamp,
+                    counterparty_id: format!("{}:{}", result.exchange, result.venue_order_id),
+                    is_maker: false,
+                    exec_type: ExecType::Trade,
+                    sequence: 0,
+                    tag: order.tag.clone(),
+                });
+            }
+        }
+        reports
+    }
+}
+
+/// 로컬 주문장에서 즉시 체결 가능한 수량 (지정가는 한도 가격 이내만)
+pub fn local_fillable_quantity(book: &OrderBookSnapshot, side: &Side, limit_price: Option<u64>) -> u64 {
+    let levels = match side {
+        Side::Buy => &book.asks,
+        Side::Sell => &book.bids,
+    };
+    levels
+        .iter()
+        .take_while(|(price, _)| match (side, limit_price) {
+            (_, None) => true,
+            (Side::Buy, Some(limit)) => *price <= limit,
+            (Side::Sell, Some(limit)) => *price >= limit,
+        })
+        .map(|(_, quantity)| quantity)
+        .sum()
+}
+
+/// 스마트 주문 라우터
+pub struct SmartOrderRouter {
+    config: RouterConfig,
+    adapters: Vec<Arc<dyn ExchangeAdapter>>,
+}
+
+impl SmartOrderRouter {
+    pub fn new(config: RouterConfig) -> Self {
+        Self {
+            config,
+            adapters: Vec::new(),
+        }
+    }
+
+    /// 외부 거래소 어댑터 추가
+    pub fn with_adapter(mut self, adapter: Arc<dyn ExchangeAdapter>) -> Self {
+        self.adapters.push(adapter);
+        self
+    }
+
+    /// 로컬 호가 조회 깊이
+    pub fn book_depth(&self) -> usize {
+        self.config.book_depth
+    }
+
+    /// 연결된 거래소 목록
+    pub fn exchanges(&self) -> Vec<ExchangeType> {
+        self.adapters.iter().map(|a| a.exchange()).collect()
+    }
+
+    /// 부족 수량을 외부 거래소로 라우팅
+    ///
+    /// 모든 거래소 호가를 동시에 조회해 수수료를 반영한 실효 가격순으로 배분하고,
+    /// 거래소별 IOC 주문을 동시에 전송합니다. 응답하지 않거나 실패한 거래소는 건너뜁니다.
+    pub async fn route(&self, order: &Order, quantity: u64) -> RoutingResult {
+        let mut result = RoutingResult::default();
+        if quantity == 0 || self.adapters.is_empty() {
+            return result;
+        }
+
+        let limit_price = match order.order_type {
+            OrderType::Limit => Some(order.price),
+            OrderType::Market => None,
+        };
+
+        // 1. 거래소 호가 동시 조회
+        let books = join_all(self.adapters.iter().map(|adapter| async move {
+            tokio::time::timeout(self.config.venue_timeout, adapter.order_book(&order.symbol, self.config.book_depth)).await
+        }))
+        .await;
+
+        // 2. 실효 가격순 배분
+        let mut levels = Vec::new();
+        for (index, (adapter, book)) in self.adapters.iter().zip(books).enumerate() {
+            let book = match book {
+                Ok(Ok(book)) => book,
+                Ok(Err(e)) => {
+                    result.failures.push(VenueFailure { exchange: adapter.exchange(), reason: e.to_string() });
+                    continue;
+                }
+                Err(_) => {
+                    result.failures.push(VenueFailure { exchange: adapter.exchange(), reason: "호가 조회 시간 초과".to_string() });
+                    continue;
+                }
+            };
+            let fee = adapter.taker_fee_bps() / 10_000.0;
+            // 매수는 수수료만큼 비싸지고 매도는 수수료만큼 싸짐
+            let (side_levels, fee_sign) = match order.side {
+                Side::Buy => (book.asks, 1.0),
+                Side::Sell => (book.bids, -1.0),
+            };
+            for (price, level_quantity) in side_levels {
+                let within_limit = match (&order.side, limit_price) {
+                    (_, None) => true,
+                    (Side::Buy, Some(limit)) => price <= limit,
+                    (Side::Sell, Some(limit)) => price >= limit,
+                };
+                if within_limit && level_quantity > 0 {
+                    levels.push((price as f64 * (1.0 + fee_sign * fee), price, level_quantity, index));
+                }
+            }
+        }
+        result.allocations = allocate(&mut levels, &order.side, quantity)
+            .into_iter()
+            .map(|(index, quantity, limit_price)| VenueAllocation {
+                exchange: self.adapters[index].exchange(),
+                quantity,
+                limit_price,
+            })
+            .collect();
+
+        // 3. 거래소별 IOC 주문 동시 전송
+        let placements = join_all(result.allocations.iter().map(|allocation| {
+            let adapter = self.adapters.iter().find(|a| a.exchange() == allocation.exchange).cloned();
+            let request = VenueOrderRequest {
+                client_order_id: order.id.clone(),
+                symbol: order.symbol.clone(),
+                side: order.side.clone(),
+                limit_price: allocation.limit_price,
+                quantity: allocation.quantity,
+            };
+            async move {
+                let adapter = adapter.expect("배분된 거래소 어댑터");
+                tokio::time::timeout(self.config.venue_timeout, adapter.place_order(&request)).await
+            }
+        }))
+        .await;
+
+        for (allocation, placement) in result.allocations.iter().zip(placements) {
+            match placement {
+                Ok(Ok(venue_result)) => result.results.push(venue_result),
+                Ok(Err(e)) => result.failures.push(VenueFailure { exchange: allocation.exchange.clone(), reason: e.to_string() }),
+                Err(_) => result.failures.push(VenueFailure {
+                    exchange: allocation.exchange.clone(),
+                    reason: "주문 응답 시간 초과".to_string(),
+                }),
+            }
+        }
+
+        for failure in &result.failures {
+            warn!("외부 라우팅 실패 ({}): {}", failure.exchange, failure.reason);
+        }
+        info!(
+            "외부 라우팅 완료: 주문 {}, 요청 {}, 체결 {}, 거래소 {}곳",
+            order.id,
+            quantity,
+            result.filled_quantity(),
+            result.results.len()
+        );
+        result
+    }
+}
+
+/// 실효 가격이 좋은 레벨부터 수량 배분 → [(어댑터 인덱스, 수량, 한도 가격)]
+fn allocate(levels: &mut [(f64, u64, u64, usize)], side: &Side, quantity: u64) -> Vec<(usize, u64, u64)> {
+    match side {
+        Side::Buy => levels.sort_by(|a, b| a.0.total_cmp(&b.0)),
+        Side::Sell => levels.sort_by(|a, b| b.0.total_cmp(&a.0)),
+    }
+
+    let mut allocations: Vec<(usize, u64, u64)> = Vec::new();
+    let mut remaining = quantity;
+    for &(_, price, level_quantity, index) in levels.iter() {
+        if remaining == 0 {
+            break;
+        }
+        let take = remaining.min(level_quantity);
+        remaining -= take;
+        match allocations.iter_mut().find(|(i, _, _)| *i == index) {
+            Some((_, allocated, limit)) => {
+                *allocated += take;
+                *limit = match side {
+                    Side::Buy => (*limit).max(price),
+                    Side::Sell => (*limit).min(price),
+                };
+            }
+            None => allocations.push((index, take, price)),
+        }
+    }
+    allocations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::exchange_adapter::MockExchangeAdapter;
+    use crate::external::exchange_sync::{ExternalPriceData, ExternalPriceSyncManager, PriceSyncConfig};
+
+    async fn price_source(quotes: &[(ExchangeType, f64, f64)]) -> Arc<ExternalPriceSyncManager> {
+        let manager = Arc::new(ExternalPriceSyncManager::new(PriceSyncConfig::default()));
+        for (exchange, bid, ask) in quotes {
+            manager
+                .update_external_price(ExternalPriceData {
+                    exchange: exchange.clone(),
+                    symbol: "BTC-KRW".to_string(),
+                    price: (bid + ask) / 2.0,
+                    volume: 0.0,
+                    timestamp: 0,
+                    bid_price: Some(*bid),
+                    ask_price: Some(*ask),
+                    spread: Some(ask - bid),
+                })
+                .await;
+        }
+        manager
+    }
+
+    fn order(side: Side, order_type: OrderType, price: u64, quantity: u64) -> Order {
+        Order::new("o1".to_string(), "BTC-KRW".to_string(), side, order_type, price, quantity, "c1".to_string())
+    }
+
+    #[test]
+    fn test_local_fillable_quantity() {
+        let book = OrderBookSnapshot {
+            symbol: "BTC-KRW".to_string(),
+            bids: vec![(99, 5), (98, 5)],
+            asks: vec![(101, 3), (102, 4), (110, 10)],
+        };
+        assert_eq!(local_fillable_quantity(&book, &Side::Buy, None), 17);
+        assert_eq!(local_fillable_quantity(&book, &Side::Buy, Some(102)), 7);
+        assert_eq!(local_fillable_quantity(&book, &Side::Sell, Some(99)), 5);
+    }
+
+    #[tokio::test]
+    async fn test_route_prefers_cheapest_venue_after_fees() {
+        // Binance 매도호가가 더 낮지만 수수료(0.1%)를 더하면 Upbit(0.05%)보다 비쌈
+        let source = price_source(&[
+            (ExchangeType::Binance, 99_900.0, 100_000.0),
+            (ExchangeType::Upbit, 99_950.0, 100_040.0),
+        ])
+        .await;
+        let router = SmartOrderRouter::new(RouterConfig::default())
+            .with_adapter(Arc::new(MockExchangeAdapter::binance(source.clone())))
+            .with_adapter(Arc::new(MockExchangeAdapter::upbit(source)));
+
+        let parent = order(Side::Buy, OrderType::Market, 0, 15);
+        let result = router.route(&parent, 12).await;
+
+        assert!(result.failures.is_empty());
+        assert_eq!(result.allocations[0].exchange, ExchangeType::Upbit);
+        assert_eq!(result.allocations[0].quantity, 10);
+        assert_eq!(result.filled_quantity(), 12);
+
+        let reports = result.execution_reports(&parent);
+        assert_eq!(reports.iter().map(|r| r.quantity).sum::<u64>(), 12);
+        assert_eq!(reports.last().unwrap().remaining_quantity, 3);
+        assert!(reports[0].counterparty_id.starts_with("Upbit:KRW-BTC-"));
+    }
+
+    #[tokio::test]
+    async fn test_route_respects_limit_and_skips_missing_venues() {
+        let source = price_source(&[(ExchangeType::Upbit, 99_950.0, 100_040.0)]).await;
+        let router = SmartOrderRouter::new(RouterConfig::default())
+            .with_adapter(Arc::new(MockExchangeAdapter::binance(source.clone())))
+            .with_adapter(Arc::new(MockExchangeAdapter::upbit(source)));
+
+        // 한도 가격 안에는 Upbit 첫 레벨(10개)만 있음, Binance는 시세 없음
+        let parent = order(Side::Buy, OrderType::Limit, 100_060, 30);
+        let result = router.route(&parent, 30).await;
+
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].exchange, ExchangeType::Binance);
+        assert_eq!(result.allocations, vec![VenueAllocation { exchange: ExchangeType::Upbit, quantity: 10, limit_price: 100_040 }]);
+        assert_eq!(result.filled_quantity(), 10);
+    }
+}
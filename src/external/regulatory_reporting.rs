@@ -8,11 +8,16 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
-use log::{info, error, warn, debug};
+use log::{info, error, warn};
 use tokio::time::{sleep, interval};
 
+use crate::currency::CurrencyConverter;
+use crate::db::repository::AmlRuleSetRepository;
+use crate::external::aml_rules::{AmlRuleEngine, AmlRuleError, AmlRuleSet};
+use crate::external::report_delivery::ReportDeliveryService;
+
 /// 규제 기관 타입
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RegulatoryAgency {
     FSC,        // 금융감독원 (Financial Supervisory Service)
     FATCA,      // Foreign Account Tax Compliance Act
@@ -23,7 +28,7 @@ pub enum RegulatoryAgency {
 }
 
 /// 보고서 타입
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ReportType {
     TransactionReport,      // 거래 보고서
     SuspiciousActivity,     // 의심스러운 활동 보고서
@@ -74,6 +79,12 @@ pub struct SuspiciousActivityData {
     pub evidence: Vec<String>,
     pub timestamp: u64,
     pub severity: String, // "low", "medium", "high", "critical"
+    /// 탐지에 쓴 AML 규칙 집합 버전
+    #[serde(default)]
+    pub rule_set_version: Option<u32>,
+    /// 충족한 AML 규칙 ID
+    #[serde(default)]
+    pub matched_rules: Vec<String>,
 }
 
 /// 규제 보고서 구조
@@ -93,7 +104,7 @@ pub struct RegulatoryReport {
 }
 
 /// 보고서 상태
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ReportStatus {
     Pending,        // 대기 중
     Generated,      // 생성 완료
@@ -107,11 +118,14 @@ pub enum ReportStatus {
 pub struct RegulatoryReportingConfig {
     pub agencies: Vec<RegulatoryAgency>,
     pub report_intervals: HashMap<ReportType, u64>, // 밀리초
+    /// 대규모 거래 보고 기준 금액 (보고 통화)
     pub large_transaction_threshold: f64,
     pub suspicious_activity_threshold: f64,
     pub enable_automatic_submission: bool,
     pub retry_attempts: u32,
     pub retry_interval_ms: u64,
+    /// 의심 거래 탐지 규칙 (초기 규칙, 이후 DB의 새 버전으로 교체 가능)
+    pub aml_rules: AmlRuleSet,
 }
 
 impl Default for RegulatoryReportingConfig {
@@ -137,6 +151,7 @@ impl Default for RegulatoryReportingConfig {
             enable_automatic_submission: true,
             retry_attempts: 3,
             retry_interval_ms: 300000, // 5분
+            aml_rules: AmlRuleSet::default(),
         }
     }
 }
@@ -153,17 +168,56 @@ pub struct RegulatoryReportingManager {
     reports: Arc<RwLock<Vec<RegulatoryReport>>>,
     /// 보고 활성화 상태
     is_reporting: Arc<Mutex<bool>>,
+    /// AML 규칙 엔진
+    aml_engine: Arc<Mutex<AmlRuleEngine>>,
+    /// 규제 기관 전송 (없으면 모의 제출)
+    delivery: Option<Arc<ReportDeliveryService>>,
+    /// 보고 통화 환산 (없으면 모든 거래 금액을 보고 통화로 간주)
+    converter: Option<Arc<CurrencyConverter>>,
 }
 
 impl RegulatoryReportingManager {
     /// 새 규제 보고 관리자 생성
     pub fn new(config: RegulatoryReportingConfig) -> Self {
         Self {
+            aml_engine: Arc::new(Mutex::new(AmlRuleEngine::new(config.aml_rules.clone()))),
             config,
             transactions: Arc::new(RwLock::new(Vec::new())),
             suspicious_activities: Arc::new(RwLock::new(Vec::new())),
             reports: Arc::new(RwLock::new(Vec::new())),
             is_reporting: Arc::new(Mutex::new(false)),
+            delivery: None,
+            converter: None,
+        }
+    }
+
+    /// 보고서 전송 서비스 연결 (XML/CSV 변환 후 SFTP/HTTPS 등으로 제출)
+    pub fn with_delivery(mut self, delivery: Arc<ReportDeliveryService>) -> Self {
+        self.delivery = Some(delivery);
+        self
+    }
+
+    /// 보고 통화 환산 연결 (임계값과 AML 규칙 금액은 보고 통화 기준)
+    pub fn with_converter(mut self, converter: Arc<CurrencyConverter>) -> Self {
+        self.converter = Some(converter);
+        self
+    }
+
+    /// 거래 금액을 보고 통화로 환산한 값 (환율이 없으면 원래 금액)
+    fn reporting_value(&self, transaction: &TransactionData) -> f64 {
+        let Some(converter) = &self.converter else {
+            return transaction.total_value;
+        };
+        let converted = converter
+            .registry()
+            .pair(&transaction.symbol)
+            .and_then(|pair| converter.convert(transaction.total_value, &pair.quote, converter.reporting_currency()));
+        match converted {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("거래 {} 보고 통화 환산 실패 (원래 금액 사용): {}", transaction.transaction_id, e);
+                transaction.total_value
+            }
         }
     }
 
@@ -184,6 +238,7 @@ impl RegulatoryReportingManager {
         let reports = self.reports.clone();
         let config = self.config.clone();
         let is_reporting = self.is_reporting.clone();
+        let delivery = self.delivery.clone();
 
         tokio::spawn(async move {
             let mut interval = interval(Duration::from_millis(60000)); // 1분마다 체크
@@ -212,10 +267,29 @@ impl RegulatoryReportingManager {
                         &suspicious_activities,
                         &reports,
                         &config,
+                        delivery.as_deref(),
                     ).await {
                         error!("{} 보고서 생성/제출 실패: {}", report_type, e);
                     }
                 }
+
+                // 규제 기관 접수 확인 반영
+                if let Some(delivery) = &delivery {
+                    match delivery.poll_acknowledgments().await {
+                        Ok(records) => {
+                            for record in records {
+                                Self::record_submission(
+                                    &reports,
+                                    &record.report_id,
+                                    record.status.report_status(),
+                                    0,
+                                    record.last_error,
+                                ).await;
+                            }
+                        }
+                        Err(e) => warn!("보고서 접수 확인 조회 실패: {}", e),
+                    }
+                }
             }
 
             info!("규제 기관 보고 시스템 종료");
@@ -231,13 +305,19 @@ impl RegulatoryReportingManager {
 
     /// 거래 데이터 추가
     pub async fn add_transaction(&self, transaction: TransactionData) -> Result<(), String> {
+        // 임계값과 AML 규칙은 보고 통화 금액으로 판단
+        let normalized = TransactionData {
+            total_value: self.reporting_value(&transaction),
+            ..transaction.clone()
+        };
+
         // 대규모 거래 확인
-        if transaction.total_value >= self.config.large_transaction_threshold {
+        if normalized.total_value >= self.config.large_transaction_threshold {
             self.generate_large_transaction_report(&transaction).await?;
         }
 
         // 의심스러운 활동 확인
-        if let Some(suspicious_activity) = self.detect_suspicious_activity(&transaction).await {
+        if let Some(suspicious_activity) = self.detect_suspicious_activity(&normalized).await {
             self.add_suspicious_activity(suspicious_activity).await?;
         }
 
@@ -248,7 +328,8 @@ impl RegulatoryReportingManager {
             
             // 최대 10000개 거래만 유지
             if transactions.len() > 10000 {
-                transactions.drain(0..transactions.len() - 10000);
+                let excess = transactions.len() - 10000;
+                transactions.drain(0..excess);
             }
         }
 
@@ -269,7 +350,8 @@ impl RegulatoryReportingManager {
             
             // 최대 1000개 활동만 유지
             if activities.len() > 1000 {
-                activities.drain(0..activities.len() - 1000);
+                let excess = activities.len() - 1000;
+                activities.drain(0..excess);
             }
         }
 
@@ -283,6 +365,7 @@ impl RegulatoryReportingManager {
         suspicious_activities: &Arc<RwLock<Vec<SuspiciousActivityData>>>,
         reports: &Arc<RwLock<Vec<RegulatoryReport>>>,
         config: &RegulatoryReportingConfig,
+        delivery: Option<&ReportDeliveryService>,
     ) -> Result<(), String> {
         // 보고서 생성
         let report = Self::generate_report(
@@ -306,7 +389,7 @@ impl RegulatoryReportingManager {
 
         // 자동 제출 활성화된 경우 제출
         if config.enable_automatic_submission {
-            Self::submit_report(&report, config).await?;
+            Self::submit_report(&report, reports, delivery).await?;
         }
 
         Ok(())
@@ -317,7 +400,7 @@ impl RegulatoryReportingManager {
         report_type: &ReportType,
         transactions: &Arc<RwLock<Vec<TransactionData>>>,
         suspicious_activities: &Arc<RwLock<Vec<SuspiciousActivityData>>>,
-        config: &RegulatoryReportingConfig,
+        _config: &RegulatoryReportingConfig,
     ) -> Result<RegulatoryReport, String> {
         let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -403,7 +486,7 @@ impl RegulatoryReportingManager {
 
         // 즉시 제출
         if self.config.enable_automatic_submission {
-            Self::submit_report(&report, &self.config).await?;
+            Self::submit_report(&report, &self.reports, self.delivery.as_deref()).await?;
         }
 
         info!("대규모 거래 보고서 생성: {}", transaction.transaction_id);
@@ -436,67 +519,136 @@ impl RegulatoryReportingManager {
 
         // 즉시 제출
         if self.config.enable_automatic_submission {
-            Self::submit_report(&report, &self.config).await?;
+            Self::submit_report(&report, &self.reports, self.delivery.as_deref()).await?;
         }
 
         info!("의심스러운 활동 보고서 생성: {}", activity.activity_id);
         Ok(())
     }
 
-    /// 의심스러운 활동 탐지
+    /// 의심스러운 활동 탐지 (AML 규칙 엔진 평가)
     async fn detect_suspicious_activity(&self, transaction: &TransactionData) -> Option<SuspiciousActivityData> {
-        let mut risk_score = 0.0;
-        let mut evidence = Vec::new();
-
-        // 대규모 거래 체크
-        if transaction.total_value >= self.config.large_transaction_threshold {
-            risk_score += 0.3;
-            evidence.push("Large transaction amount".to_string());
+        let evaluation = self.aml_engine.lock().await.evaluate(transaction);
+        if !evaluation.suspicious {
+            return None;
         }
 
-        // 비정상적인 거래 패턴 체크 (Mock)
-        if transaction.amount > 1000.0 && transaction.price < 100.0 {
-            risk_score += 0.2;
-            evidence.push("Unusual trading pattern".to_string());
-        }
+        let activity_type = match evaluation.matches.as_slice() {
+            [single] => single.rule_name.clone(),
+            _ => "Suspicious Transaction".to_string(),
+        };
+        Some(SuspiciousActivityData {
+            activity_id: uuid::Uuid::new_v4().to_string(),
+            user_id: transaction.user_id.clone(),
+            activity_type,
+            risk_score: evaluation.risk_score,
+            description: format!(
+                "Suspicious transaction detected: {} (AML rules v{})",
+                transaction.transaction_id, evaluation.rule_set_version
+            ),
+            evidence: evaluation
+                .matches
+                .iter()
+                .map(|m| format!("{}: {}", m.rule_name, m.detail))
+                .collect(),
+            timestamp: transaction.timestamp,
+            severity: evaluation.severity,
+            rule_set_version: Some(evaluation.rule_set_version),
+            matched_rules: evaluation.matches.into_iter().map(|m| m.rule_id).collect(),
+        })
+    }
 
-        // 고위험 국가 체크 (Mock)
-        let high_risk_countries = ["XX", "YY", "ZZ"];
-        if high_risk_countries.contains(&transaction.user_country.as_str()) {
-            risk_score += 0.4;
-            evidence.push("High-risk country".to_string());
-        }
+    /// 현재 AML 규칙 집합
+    pub async fn aml_rules(&self) -> AmlRuleSet {
+        self.aml_engine.lock().await.rules().clone()
+    }
 
-        // 위험도가 임계값 이상인 경우
-        if risk_score >= 0.5 {
-            Some(SuspiciousActivityData {
-                activity_id: uuid::Uuid::new_v4().to_string(),
-                user_id: transaction.user_id.clone(),
-                activity_type: "Suspicious Transaction".to_string(),
-                risk_score,
-                description: format!("Suspicious transaction detected: {}", transaction.transaction_id),
-                evidence,
-                timestamp: transaction.timestamp,
-                severity: if risk_score >= 0.8 { "high" } else { "medium" }.to_string(),
-            })
-        } else {
-            None
+    /// AML 규칙 교체 (버전은 현재보다 커야 함)
+    pub async fn update_aml_rules(&self, rules: AmlRuleSet) -> Result<(), AmlRuleError> {
+        let (version, count) = (rules.version, rules.rules.len());
+        self.aml_engine.lock().await.replace_rules(rules)?;
+        info!("AML 규칙 v{} 적용 ({}개 규칙)", version, count);
+        Ok(())
+    }
+
+    /// DB의 AML 규칙과 동기화
+    ///
+    /// DB에 더 높은 버전이 있으면 적용하고, DB가 비었거나 현재 규칙이 더 새로우면
+    /// (설정 파일로 새 버전을 배포한 경우) 현재 규칙을 DB에 기록합니다.
+    pub async fn sync_aml_rules(&self, repository: &AmlRuleSetRepository) -> Result<(), AmlRuleError> {
+        let current = self.aml_rules().await;
+        match repository.find_latest().await? {
+            Some(record) if record.version > current.version as i64 => {
+                let rules = AmlRuleSet::from_json(&record.definition)?;
+                self.update_aml_rules(rules).await
+            }
+            Some(record) if record.version == current.version as i64 => Ok(()),
+            _ => {
+                repository
+                    .save(current.version as i64, &serde_json::to_string(&current)?, Some("config"))
+                    .await?;
+                info!("AML 규칙 v{}을(를) DB에 기록", current.version);
+                Ok(())
+            }
         }
     }
 
     /// 보고서 제출
-    async fn submit_report(report: &RegulatoryReport, config: &RegulatoryReportingConfig) -> Result<(), String> {
-        // Mock: 실제로는 규제 기관 API에 제출
-        sleep(Duration::from_millis(100 + fastrand::u32(0..200) as u64)).await;
-
-        // 90% 성공률로 시뮬레이션
-        if fastrand::f32() < 0.9 {
-            info!("보고서 제출 성공: {} ({})", report.report_id, report.report_type);
-            Ok(())
-        } else {
-            let error_msg = "Submission failed due to network error".to_string();
-            error!("보고서 제출 실패: {} - {}", report.report_id, error_msg);
-            Err(error_msg)
+    ///
+    /// 전송 서비스가 있으면 규제 기관 형식으로 변환해 전송(재시도 포함)하고,
+    /// 없으면 모의 제출합니다. 결과는 저장된 보고서 상태에 반영합니다.
+    async fn submit_report(
+        report: &RegulatoryReport,
+        reports: &Arc<RwLock<Vec<RegulatoryReport>>>,
+        delivery: Option<&ReportDeliveryService>,
+    ) -> Result<(), String> {
+        let (status, attempts, error) = match delivery {
+            Some(delivery) => {
+                let record = delivery.deliver(report).await;
+                (record.status.report_status(), record.attempts, record.last_error)
+            }
+            None => {
+                // Mock: 규제 기관 API 제출 시뮬레이션 (항상 성공, 실패/재시도는 전송 서비스가 다룸)
+                sleep(Duration::from_millis(100 + fastrand::u32(0..200) as u64)).await;
+                (ReportStatus::Submitted, 1, None)
+            }
+        };
+
+        Self::record_submission(reports, &report.report_id, status.clone(), attempts, error.clone()).await;
+
+        match error {
+            Some(error_msg) if status == ReportStatus::Failed => {
+                error!("보고서 제출 실패: {} - {}", report.report_id, error_msg);
+                Err(error_msg)
+            }
+            _ => {
+                info!("보고서 제출 성공: {} ({})", report.report_id, report.report_type);
+                Ok(())
+            }
+        }
+    }
+
+    /// 저장된 보고서에 제출 결과 반영
+    async fn record_submission(
+        reports: &Arc<RwLock<Vec<RegulatoryReport>>>,
+        report_id: &str,
+        status: ReportStatus,
+        attempts: u32,
+        error: Option<String>,
+    ) {
+        let mut reports = reports.write().await;
+        if let Some(stored) = reports.iter_mut().rev().find(|r| r.report_id == report_id) {
+            stored.status = status;
+            if attempts > 0 {
+                stored.submission_attempts += attempts;
+                stored.last_submission_attempt = Some(
+                    SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap()
+                        .as_millis() as u64,
+                );
+            }
+            stored.submission_error = error;
         }
     }
 
@@ -560,17 +712,6 @@ mod fastrand {
     use std::hash::{Hash, Hasher};
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    pub fn f32() -> f32 {
-        let mut hasher = DefaultHasher::new();
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos()
-            .hash(&mut hasher);
-        let hash = hasher.finish();
-        (hash % 1000) as f32 / 1000.0
-    }
-
     pub fn u32(range: std::ops::Range<u32>) -> u32 {
         let mut hasher = DefaultHasher::new();
         SystemTime::now()
@@ -661,6 +802,45 @@ mod tests {
         assert!(!large_transaction_reports.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_large_transaction_threshold_in_reporting_currency() {
+        let converter = Arc::new(CurrencyConverter::new(Default::default()));
+        converter.update_price("BTC-KRW", 140_000_000);
+        converter.update_price("BTC-USDT", 10_000_000); // 100,000.00 USDT → 1 USDT = 1,400원
+        let config = RegulatoryReportingConfig {
+            large_transaction_threshold: 10_000_000.0, // 1천만원
+            ..Default::default()
+        };
+        let manager = RegulatoryReportingManager::new(config).with_converter(converter);
+
+        for (id, total_value) in [("usdt_small", 5_000.0), ("usdt_large", 8_000.0)] {
+            let transaction = TransactionData {
+                transaction_id: id.to_string(),
+                user_id: "user_1".to_string(),
+                symbol: "BTC-USDT".to_string(),
+                side: "buy".to_string(),
+                amount: 0.05,
+                price: 100_000.0,
+                total_value, // USDT (700만원 / 1,120만원)
+                timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64,
+                user_country: "KR".to_string(),
+                user_tax_id: None,
+                ip_address: "192.168.1.1".to_string(),
+                device_fingerprint: "device_123".to_string(),
+            };
+            manager.add_transaction(transaction).await.unwrap();
+        }
+
+        let large: Vec<_> = manager
+            .get_reports(None)
+            .await
+            .into_iter()
+            .filter(|r| r.report_type == ReportType::LargeTransaction)
+            .map(|r| r.report_id)
+            .collect();
+        assert_eq!(large, vec!["large_transaction_usdt_large".to_string()]);
+    }
+
     #[tokio::test]
     async fn test_suspicious_activity_detection() {
         let config = RegulatoryReportingConfig::default();
@@ -713,6 +893,16 @@ mod tests {
             manager.add_transaction(transaction).await.unwrap();
         }
         
+        // 주기 보고 루프의 한 번 실행 (거래 보고서는 즉시 생성되지 않음)
+        RegulatoryReportingManager::generate_and_submit_report(
+            &ReportType::TransactionReport,
+            &manager.transactions,
+            &manager.suspicious_activities,
+            &manager.reports,
+            &manager.config,
+            None,
+        ).await.unwrap();
+
         let reports = manager.get_reports(None).await;
         assert!(!reports.is_empty());
         
@@ -721,4 +911,101 @@ mod tests {
             .collect();
         assert!(!transaction_reports.is_empty());
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_aml_rules_synced_from_db() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        let repository = AmlRuleSetRepository::new(pool);
+
+        let manager = RegulatoryReportingManager::new(RegulatoryReportingConfig::default());
+
+        // DB가 비어 있으면 현재 규칙(v1)을 기록
+        manager.sync_aml_rules(&repository).await.unwrap();
+        assert_eq!(repository.find_latest().await.unwrap().unwrap().version, 1);
+
+        // 컴플라이언스가 v2 등록: 500만원 이상 거래만으로 의심 거래
+        let mut rules = AmlRuleSet::builtin(5_000_000.0);
+        rules.version = 2;
+        rules.rules[0].risk_score = 0.5;
+        repository
+            .save(2, &serde_json::to_string(&rules).unwrap(), Some("compliance"))
+            .await
+            .unwrap();
+        manager.sync_aml_rules(&repository).await.unwrap();
+        assert_eq!(manager.aml_rules().await.version, 2);
+
+        let transaction = TransactionData {
+            transaction_id: "rule_v2_tx".to_string(),
+            user_id: "user_1".to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: "buy".to_string(),
+            amount: 0.1,
+            price: 60000000.0,
+            total_value: 6000000.0,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64,
+            user_country: "KR".to_string(),
+            user_tax_id: None,
+            ip_address: "192.168.1.1".to_string(),
+            device_fingerprint: "device_123".to_string(),
+        };
+        manager.add_transaction(transaction).await.unwrap();
+
+        let activities = manager.suspicious_activities.read().await;
+        assert_eq!(activities.len(), 1);
+        assert_eq!(activities[0].rule_set_version, Some(2));
+        assert_eq!(activities[0].matched_rules, ["large-transaction"]);
+
+        // 낮은 버전으로 되돌릴 수 없음
+        assert!(manager.update_aml_rules(AmlRuleSet::default()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_large_transaction_delivered_as_xml() {
+        use crate::external::report_delivery::{DeliveryEndpoint, ReportDeliveryConfig};
+        use crate::external::report_formats::ReportFormat;
+
+        let dir = std::env::temp_dir().join(format!("xtrader-regulatory-{}", uuid::Uuid::new_v4()));
+        let delivery = ReportDeliveryService::new(ReportDeliveryConfig::new(
+            DeliveryEndpoint::Directory { path: dir.clone() },
+            ReportFormat::Xml,
+        ))
+        .unwrap();
+        let manager = RegulatoryReportingManager::new(RegulatoryReportingConfig::default())
+            .with_delivery(Arc::new(delivery));
+
+        let transaction = TransactionData {
+            transaction_id: "delivered_tx".to_string(),
+            user_id: "user_1".to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: "buy".to_string(),
+            amount: 0.3,
+            price: 50000000.0,
+            total_value: 15000000.0,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64,
+            user_country: "KR".to_string(),
+            user_tax_id: None,
+            ip_address: "192.168.1.1".to_string(),
+            device_fingerprint: "device_123".to_string(),
+        };
+        manager.add_transaction(transaction).await.unwrap();
+
+        let reports = manager.get_reports(None).await;
+        let report = reports
+            .iter()
+            .find(|r| r.report_id == "large_transaction_delivered_tx")
+            .unwrap();
+        assert_eq!(report.status, ReportStatus::Submitted);
+        assert_eq!(report.submission_attempts, 1);
+        assert!(report.last_submission_attempt.is_some());
+
+        let xml = std::fs::read_to_string(dir.join("large_transaction_delivered_tx.xml")).unwrap();
+        assert!(xml.contains("<TxId>delivered_tx</TxId>"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,823 @@
+//! 규제 보고서 전송
+//!
+//! `report_formats`로 변환한 보고서 파일을 규제 기관 엔드포인트(HTTPS, SFTP,
+//! 로컬 전송 디렉터리)로 보내고, 일시적인 실패는 지수 백오프로 재시도하며,
+//! 규제 기관의 접수 확인(ack)을 보고서별로 추적합니다.
+//!
+//! 접수 확인은 두 경로로 들어옵니다.
+//! - HTTPS 응답 본문의 `status`가 `ACCEPTED`/`REJECTED`이면 즉시 반영
+//! - 접수 확인 디렉터리(SFTP, 로컬)나 HTTPS ack URL을 주기적으로 조회
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::external::regulatory_reporting::{RegulatoryReport, ReportStatus};
+use crate::external::report_formats::{render_report, RenderedReport, ReportFormat, ReportFormatError, ReportingEntity};
+
+/// 접수 확인 파일 확장자 (접수)
+const ACK_EXTENSION: &str = "ack";
+/// 접수 확인 파일 확장자 (거부)
+const NACK_EXTENSION: &str = "nack";
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
+fn default_sftp_port() -> u16 {
+    22
+}
+
+/// 전송 엔드포인트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum DeliveryEndpoint {
+    /// HTTPS POST (본문은 보고서 파일)
+    Https {
+        url: String,
+        #[serde(default)]
+        auth_token: Option<String>,
+        /// 접수 확인 조회 URL (GET, 없으면 응답 본문의 상태만 사용)
+        #[serde(default)]
+        ack_url: Option<String>,
+    },
+    /// SFTP 업로드 (`sftp-delivery` 기능 필요)
+    Sftp {
+        host: String,
+        #[serde(default = "default_sftp_port")]
+        port: u16,
+        username: String,
+        #[serde(default)]
+        password: Option<String>,
+        #[serde(default)]
+        private_key_path: Option<PathBuf>,
+        upload_dir: String,
+        /// 규제 기관이 `{report_id}.ack`/`.nack` 파일을 올리는 디렉터리
+        #[serde(default)]
+        ack_dir: Option<String>,
+    },
+    /// 로컬 디렉터리 (전송 에이전트 연동, 개발/테스트용). 접수 확인은 `ack/` 하위 디렉터리
+    Directory { path: PathBuf },
+}
+
+/// 재시도 정책
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// 최대 시도 횟수 (첫 시도 포함)
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `attempt`번째 시도 실패 후 대기 시간
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff)
+    }
+}
+
+/// 보고서 전송 설정
+#[derive(Debug, Clone)]
+pub struct ReportDeliveryConfig {
+    pub endpoint: DeliveryEndpoint,
+    pub format: ReportFormat,
+    pub entity: ReportingEntity,
+    pub retry: RetryPolicy,
+    /// 전송 1회 제한 시간
+    pub timeout: Duration,
+}
+
+impl ReportDeliveryConfig {
+    pub fn new(endpoint: DeliveryEndpoint, format: ReportFormat) -> Self {
+        Self {
+            endpoint,
+            format,
+            entity: ReportingEntity::default(),
+            retry: RetryPolicy::default(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// 규제 기관 접수 확인
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Acknowledgment {
+    pub report_id: String,
+    pub accepted: bool,
+    /// 규제 기관 접수 번호
+    #[serde(default)]
+    pub reference: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// 전송 1회 결과
+#[derive(Debug, Clone, Default)]
+pub struct TransportReceipt {
+    /// 엔드포인트가 돌려준 접수 번호나 업로드 경로
+    pub reference: Option<String>,
+    /// 응답에 접수 확인이 포함된 경우
+    pub acknowledgment: Option<Acknowledgment>,
+}
+
+/// 전송 오류
+#[derive(Debug, thiserror::Error)]
+pub enum DeliveryError {
+    #[error(transparent)]
+    Format(#[from] ReportFormatError),
+    /// 일시적 오류 (재시도 대상)
+    #[error("전송 실패: {0}")]
+    Transport(String),
+    /// 수신 측 거부 (재시도하지 않음)
+    #[error("수신 거부: {0}")]
+    Rejected(String),
+    #[error("{0} 전송은 이 빌드에 포함되지 않았습니다 (기능 플래그 {1} 필요)")]
+    Unavailable(&'static str, &'static str),
+}
+
+impl DeliveryError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, DeliveryError::Transport(_))
+    }
+}
+
+impl From<std::io::Error> for DeliveryError {
+    fn from(e: std::io::Error) -> Self {
+        DeliveryError::Transport(e.to_string())
+    }
+}
+
+/// 보고서 전송 방식 공통 인터페이스
+#[async_trait]
+pub trait ReportTransport: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// 보고서 파일 전송
+    async fn upload(&self, report: &RenderedReport) -> Result<TransportReceipt, DeliveryError>;
+
+    /// 새로 도착한 접수 확인 조회 (조회한 확인은 다시 돌려주지 않음)
+    async fn fetch_acknowledgments(&self) -> Result<Vec<Acknowledgment>, DeliveryError> {
+        Ok(Vec::new())
+    }
+}
+
+/// 접수 확인 파일 이름 해석 (`{report_id}.ack` / `{report_id}.nack`)
+fn parse_ack_file(file_name: &str, content: &str) -> Option<Acknowledgment> {
+    let (report_id, extension) = file_name.rsplit_once('.')?;
+    let accepted = match extension {
+        ACK_EXTENSION => true,
+        NACK_EXTENSION => false,
+        _ => return None,
+    };
+    let message = content.trim();
+    Some(Acknowledgment {
+        report_id: report_id.to_string(),
+        accepted,
+        reference: None,
+        message: (!message.is_empty()).then(|| message.to_string()),
+    })
+}
+
+/// HTTPS 전송
+pub struct HttpsTransport {
+    client: reqwest::Client,
+    url: String,
+    auth_token: Option<String>,
+    ack_url: Option<String>,
+}
+
+/// HTTPS 응답 본문 (선택)
+#[derive(Debug, Default, Deserialize)]
+struct HttpsSubmissionResponse {
+    #[serde(default)]
+    receipt_id: Option<String>,
+    /// RECEIVED, ACCEPTED, REJECTED
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+/// HTTPS 접수 확인 조회 응답 항목
+#[derive(Debug, Deserialize)]
+struct HttpsAckEntry {
+    report_id: String,
+    status: String,
+    #[serde(default)]
+    receipt_id: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+impl HttpsTransport {
+    pub fn new(url: String, auth_token: Option<String>, ack_url: Option<String>, timeout: Duration) -> Result<Self, DeliveryError> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| DeliveryError::Transport(e.to_string()))?;
+        Ok(Self { client, url, auth_token, ack_url })
+    }
+
+    fn authorize(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.auth_token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+}
+
+#[async_trait]
+impl ReportTransport for HttpsTransport {
+    fn name(&self) -> &'static str {
+        "https"
+    }
+
+    async fn upload(&self, report: &RenderedReport) -> Result<TransportReceipt, DeliveryError> {
+        // 재시도 시 중복 접수를 막도록 보고서 ID를 멱등 키로 사용
+        let request = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, report.content_type())
+            .header("X-Report-Id", &report.report_id)
+            .header("Idempotency-Key", &report.report_id)
+            .body(report.body.clone());
+        let response = self
+            .authorize(request)
+            .send()
+            .await
+            .map_err(|e| DeliveryError::Transport(e.to_string()))?;
+
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        if status.is_client_error()
+            && status != reqwest::StatusCode::REQUEST_TIMEOUT
+            && status != reqwest::StatusCode::TOO_MANY_REQUESTS
+        {
+            return Err(DeliveryError::Rejected(format!("HTTP {}: {}", status, text)));
+        }
+        if !status.is_success() {
+            return Err(DeliveryError::Transport(format!("HTTP {}: {}", status, text)));
+        }
+
+        let body: HttpsSubmissionResponse = serde_json::from_str(&text).unwrap_or_default();
+        let acknowledgment = match body.status.as_deref() {
+            Some("ACCEPTED") | Some("REJECTED") => Some(Acknowledgment {
+                report_id: report.report_id.clone(),
+                accepted: body.status.as_deref() == Some("ACCEPTED"),
+                reference: body.receipt_id.clone(),
+                message: body.message.clone(),
+            }),
+            _ => None,
+        };
+        Ok(TransportReceipt { reference: body.receipt_id, acknowledgment })
+    }
+
+    async fn fetch_acknowledgments(&self) -> Result<Vec<Acknowledgment>, DeliveryError> {
+        let Some(ack_url) = &self.ack_url else {
+            return Ok(Vec::new());
+        };
+        let response = self
+            .authorize(self.client.get(ack_url))
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| DeliveryError::Transport(e.to_string()))?;
+        let entries: Vec<HttpsAckEntry> = response.json().await.map_err(|e| DeliveryError::Transport(e.to_string()))?;
+
+        Ok(entries
+            .into_iter()
+            .filter(|e| matches!(e.status.as_str(), "ACCEPTED" | "REJECTED"))
+            .map(|e| Acknowledgment {
+                accepted: e.status == "ACCEPTED",
+                report_id: e.report_id,
+                reference: e.receipt_id,
+                message: e.message,
+            })
+            .collect())
+    }
+}
+
+/// 로컬 디렉터리 전송
+///
+/// 임시 파일에 쓴 뒤 이름을 바꿔, 디렉터리를 감시하는 전송 에이전트가
+/// 쓰다 만 파일을 가져가지 않게 합니다.
+pub struct DirectoryTransport {
+    path: PathBuf,
+}
+
+impl DirectoryTransport {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    fn ack_dir(&self) -> PathBuf {
+        self.path.join("ack")
+    }
+}
+
+#[async_trait]
+impl ReportTransport for DirectoryTransport {
+    fn name(&self) -> &'static str {
+        "directory"
+    }
+
+    async fn upload(&self, report: &RenderedReport) -> Result<TransportReceipt, DeliveryError> {
+        tokio::fs::create_dir_all(&self.path).await?;
+        let target = self.path.join(&report.file_name);
+        let partial = self.path.join(format!(".{}.part", report.file_name));
+        tokio::fs::write(&partial, &report.body).await?;
+        tokio::fs::rename(&partial, &target).await?;
+        Ok(TransportReceipt {
+            reference: Some(target.display().to_string()),
+            acknowledgment: None,
+        })
+    }
+
+    async fn fetch_acknowledgments(&self) -> Result<Vec<Acknowledgment>, DeliveryError> {
+        read_ack_dir(&self.ack_dir()).await
+    }
+}
+
+/// 접수 확인 디렉터리에서 ack/nack 파일을 읽고 지움
+async fn read_ack_dir(dir: &Path) -> Result<Vec<Acknowledgment>, DeliveryError> {
+    let mut entries = match tokio::fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut acknowledgments = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let content = tokio::fs::read_to_string(entry.path()).await.unwrap_or_default();
+        if let Some(ack) = parse_ack_file(&file_name, &content) {
+            tokio::fs::remove_file(entry.path()).await?;
+            acknowledgments.push(ack);
+        }
+    }
+    Ok(acknowledgments)
+}
+
+/// SFTP 전송 (libssh2, 블로킹 호출은 별도 스레드에서 실행)
+#[cfg(feature = "sftp-delivery")]
+pub struct SftpTransport {
+    host: String,
+    port: u16,
+    username: String,
+    password: Option<String>,
+    private_key_path: Option<PathBuf>,
+    upload_dir: String,
+    ack_dir: Option<String>,
+    timeout: Duration,
+}
+
+#[cfg(feature = "sftp-delivery")]
+impl SftpTransport {
+    fn connect(&self) -> Result<ssh2::Sftp, DeliveryError> {
+        let transport_error = |e: ssh2::Error| DeliveryError::Transport(format!("SFTP {}: {}", self.host, e));
+
+        let tcp = std::net::TcpStream::connect((self.host.as_str(), self.port))?;
+        tcp.set_read_timeout(Some(self.timeout))?;
+        tcp.set_write_timeout(Some(self.timeout))?;
+
+        let mut session = ssh2::Session::new().map_err(transport_error)?;
+        session.set_tcp_stream(tcp);
+        session.handshake().map_err(transport_error)?;
+        match (&self.private_key_path, &self.password) {
+            (Some(key), passphrase) => session
+                .userauth_pubkey_file(&self.username, None, key, passphrase.as_deref())
+                .map_err(transport_error)?,
+            (None, Some(password)) => session.userauth_password(&self.username, password).map_err(transport_error)?,
+            (None, None) => return Err(DeliveryError::Rejected("SFTP 인증 정보가 없습니다".to_string())),
+        }
+        session.sftp().map_err(transport_error)
+    }
+
+    fn upload_blocking(&self, report: &RenderedReport) -> Result<TransportReceipt, DeliveryError> {
+        use std::io::Write;
+
+        let sftp = self.connect()?;
+        let dir = Path::new(&self.upload_dir);
+        let target = dir.join(&report.file_name);
+        let partial = dir.join(format!(".{}.part", report.file_name));
+        let sftp_error = |e: ssh2::Error| DeliveryError::Transport(format!("SFTP 업로드 실패: {}", e));
+
+        let mut file = sftp.create(&partial).map_err(sftp_error)?;
+        file.write_all(&report.body)?;
+        drop(file);
+        sftp.rename(&partial, &target, Some(ssh2::RenameFlags::OVERWRITE | ssh2::RenameFlags::ATOMIC))
+            .map_err(sftp_error)?;
+
+        Ok(TransportReceipt {
+            reference: Some(format!("sftp://{}{}", self.host, target.display())),
+            acknowledgment: None,
+        })
+    }
+
+    fn fetch_blocking(&self) -> Result<Vec<Acknowledgment>, DeliveryError> {
+        use std::io::Read;
+
+        let Some(ack_dir) = &self.ack_dir else {
+            return Ok(Vec::new());
+        };
+        let sftp = self.connect()?;
+        let sftp_error = |e: ssh2::Error| DeliveryError::Transport(format!("SFTP 접수 확인 조회 실패: {}", e));
+
+        let mut acknowledgments = Vec::new();
+        for (path, _) in sftp.readdir(Path::new(ack_dir)).map_err(sftp_error)? {
+            let Some(file_name) = path.file_name().map(|n| n.to_string_lossy().to_string()) else {
+                continue;
+            };
+            let mut content = String::new();
+            sftp.open(&path).map_err(sftp_error)?.read_to_string(&mut content)?;
+            if let Some(ack) = parse_ack_file(&file_name, &content) {
+                sftp.unlink(&path).map_err(sftp_error)?;
+                acknowledgments.push(ack);
+            }
+        }
+        Ok(acknowledgments)
+    }
+}
+
+#[cfg(feature = "sftp-delivery")]
+#[async_trait]
+impl ReportTransport for SftpTransport {
+    fn name(&self) -> &'static str {
+        "sftp"
+    }
+
+    async fn upload(&self, report: &RenderedReport) -> Result<TransportReceipt, DeliveryError> {
+        let this = self.clone_config();
+        let report = report.clone();
+        tokio::task::spawn_blocking(move || this.upload_blocking(&report))
+            .await
+            .map_err(|e| DeliveryError::Transport(e.to_string()))?
+    }
+
+    async fn fetch_acknowledgments(&self) -> Result<Vec<Acknowledgment>, DeliveryError> {
+        let this = self.clone_config();
+        tokio::task::spawn_blocking(move || this.fetch_blocking())
+            .await
+            .map_err(|e| DeliveryError::Transport(e.to_string()))?
+    }
+}
+
+#[cfg(feature = "sftp-delivery")]
+impl SftpTransport {
+    /// 블로킹 스레드로 옮길 설정 사본
+    fn clone_config(&self) -> Self {
+        Self {
+            host: self.host.clone(),
+            port: self.port,
+            username: self.username.clone(),
+            password: self.password.clone(),
+            private_key_path: self.private_key_path.clone(),
+            upload_dir: self.upload_dir.clone(),
+            ack_dir: self.ack_dir.clone(),
+            timeout: self.timeout,
+        }
+    }
+}
+
+/// 엔드포인트 설정으로 전송 방식 생성
+pub fn create_transport(endpoint: &DeliveryEndpoint, timeout: Duration) -> Result<Arc<dyn ReportTransport>, DeliveryError> {
+    match endpoint {
+        DeliveryEndpoint::Https { url, auth_token, ack_url } => Ok(Arc::new(HttpsTransport::new(
+            url.clone(),
+            auth_token.clone(),
+            ack_url.clone(),
+            timeout,
+        )?)),
+        DeliveryEndpoint::Directory { path } => Ok(Arc::new(DirectoryTransport::new(path.clone()))),
+        #[cfg(feature = "sftp-delivery")]
+        DeliveryEndpoint::Sftp { host, port, username, password, private_key_path, upload_dir, ack_dir } => {
+            Ok(Arc::new(SftpTransport {
+                host: host.clone(),
+                port: *port,
+                username: username.clone(),
+                password: password.clone(),
+                private_key_path: private_key_path.clone(),
+                upload_dir: upload_dir.clone(),
+                ack_dir: ack_dir.clone(),
+                timeout,
+            }))
+        }
+        #[cfg(not(feature = "sftp-delivery"))]
+        DeliveryEndpoint::Sftp { .. } => Err(DeliveryError::Unavailable("SFTP", "sftp-delivery")),
+    }
+}
+
+/// 보고서 전송 상태
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeliveryStatus {
+    /// 전송 완료, 접수 확인 대기
+    Delivered,
+    /// 규제 기관 접수 확인
+    Acknowledged,
+    /// 규제 기관 거부 (형식 오류 등)
+    Rejected,
+    /// 재시도 후에도 전송 실패 또는 변환/검증 실패
+    Failed,
+}
+
+impl DeliveryStatus {
+    /// 보고서 상태로 변환
+    pub fn report_status(&self) -> ReportStatus {
+        match self {
+            DeliveryStatus::Delivered => ReportStatus::Submitted,
+            DeliveryStatus::Acknowledged => ReportStatus::Acknowledged,
+            DeliveryStatus::Rejected | DeliveryStatus::Failed => ReportStatus::Failed,
+        }
+    }
+}
+
+/// 보고서별 전송 기록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryRecord {
+    pub report_id: String,
+    pub file_name: Option<String>,
+    pub format: ReportFormat,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub last_attempt_at: Option<u64>,
+    pub last_error: Option<String>,
+    /// 전송 측 참조 (업로드 경로, 접수 번호)
+    pub reference: Option<String>,
+    pub acknowledged_at: Option<u64>,
+}
+
+/// 보고서 전송 서비스 (변환 → 전송/재시도 → 접수 확인 추적)
+pub struct ReportDeliveryService {
+    config: ReportDeliveryConfig,
+    transport: Arc<dyn ReportTransport>,
+    records: RwLock<HashMap<String, DeliveryRecord>>,
+}
+
+impl ReportDeliveryService {
+    /// 설정된 엔드포인트로 전송 서비스 생성
+    pub fn new(config: ReportDeliveryConfig) -> Result<Self, DeliveryError> {
+        let transport = create_transport(&config.endpoint, config.timeout)?;
+        Ok(Self::with_transport(config, transport))
+    }
+
+    /// 전송 방식을 직접 지정
+    pub fn with_transport(config: ReportDeliveryConfig, transport: Arc<dyn ReportTransport>) -> Self {
+        Self {
+            config,
+            transport,
+            records: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 보고서 변환 후 전송 (일시적 오류는 재시도)
+    pub async fn deliver(&self, report: &RegulatoryReport) -> DeliveryRecord {
+        let mut record = DeliveryRecord {
+            report_id: report.report_id.clone(),
+            file_name: None,
+            format: self.config.format,
+            status: DeliveryStatus::Failed,
+            attempts: 0,
+            last_attempt_at: None,
+            last_error: None,
+            reference: None,
+            acknowledged_at: None,
+        };
+
+        match render_report(report, self.config.format, &self.config.entity) {
+            Ok(rendered) => {
+                record.file_name = Some(rendered.file_name.clone());
+                self.upload_with_retry(&rendered, &mut record).await;
+            }
+            Err(e) => {
+                warn!("보고서 변환 실패: {} - {}", report.report_id, e);
+                record.last_error = Some(e.to_string());
+            }
+        }
+
+        self.records.write().await.insert(record.report_id.clone(), record.clone());
+        record
+    }
+
+    async fn upload_with_retry(&self, rendered: &RenderedReport, record: &mut DeliveryRecord) {
+        let max_attempts = self.config.retry.max_attempts.max(1);
+        loop {
+            record.attempts += 1;
+            record.last_attempt_at = Some(now_millis());
+
+            match self.transport.upload(rendered).await {
+                Ok(receipt) => {
+                    record.reference = receipt.reference;
+                    record.last_error = None;
+                    record.status = DeliveryStatus::Delivered;
+                    if let Some(ack) = receipt.acknowledgment {
+                        Self::apply_acknowledgment(record, &ack);
+                    }
+                    info!(
+                        "보고서 전송 성공: {} ({}, {}회 시도)",
+                        record.report_id,
+                        self.transport.name(),
+                        record.attempts
+                    );
+                    return;
+                }
+                Err(e) => {
+                    record.last_error = Some(e.to_string());
+                    let retryable = e.is_retryable();
+                    record.status = if matches!(e, DeliveryError::Rejected(_)) {
+                        DeliveryStatus::Rejected
+                    } else {
+                        DeliveryStatus::Failed
+                    };
+                    if !retryable || record.attempts >= max_attempts {
+                        warn!("보고서 전송 실패: {} ({}회 시도) - {}", record.report_id, record.attempts, e);
+                        return;
+                    }
+                    let backoff = self.config.retry.backoff(record.attempts);
+                    warn!("보고서 전송 재시도 예정: {} ({:?} 후) - {}", record.report_id, backoff, e);
+                    tokio::time::sleep(backoff).await;
+                }
+            }
+        }
+    }
+
+    fn apply_acknowledgment(record: &mut DeliveryRecord, ack: &Acknowledgment) {
+        record.status = if ack.accepted { DeliveryStatus::Acknowledged } else { DeliveryStatus::Rejected };
+        record.acknowledged_at = Some(now_millis());
+        if ack.reference.is_some() {
+            record.reference = ack.reference.clone();
+        }
+        if !ack.accepted {
+            record.last_error = ack.message.clone().or_else(|| Some("규제 기관 거부".to_string()));
+        }
+    }
+
+    /// 새 접수 확인을 조회해 기록에 반영하고, 바뀐 기록 반환
+    pub async fn poll_acknowledgments(&self) -> Result<Vec<DeliveryRecord>, DeliveryError> {
+        let acknowledgments = self.transport.fetch_acknowledgments().await?;
+        let mut records = self.records.write().await;
+        let mut updated = Vec::new();
+        for ack in acknowledgments {
+            match records.get_mut(&ack.report_id) {
+                Some(record) => {
+                    Self::apply_acknowledgment(record, &ack);
+                    info!("보고서 접수 확인: {} ({:?})", record.report_id, record.status);
+                    updated.push(record.clone());
+                }
+                None => warn!("알 수 없는 보고서의 접수 확인: {}", ack.report_id),
+            }
+        }
+        Ok(updated)
+    }
+
+    /// 보고서 전송 기록
+    pub async fn record(&self, report_id: &str) -> Option<DeliveryRecord> {
+        self.records.read().await.get(report_id).cloned()
+    }
+
+    /// 접수 확인을 기다리는 보고서
+    pub async fn pending_acknowledgments(&self) -> Vec<DeliveryRecord> {
+        self.records
+            .read()
+            .await
+            .values()
+            .filter(|r| r.status == DeliveryStatus::Delivered)
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::regulatory_reporting::{RegulatoryAgency, ReportType, TransactionData};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn large_transaction_report() -> RegulatoryReport {
+        let transaction = TransactionData {
+            transaction_id: "tx_1".to_string(),
+            user_id: "user_1".to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: "buy".to_string(),
+            amount: 0.3,
+            price: 50_000_000.0,
+            total_value: 15_000_000.0,
+            timestamp: 1_700_000_000_000,
+            user_country: "KR".to_string(),
+            user_tax_id: None,
+            ip_address: "10.0.0.1".to_string(),
+            device_fingerprint: "device_1".to_string(),
+        };
+        RegulatoryReport {
+            report_id: "large_transaction_tx_1".to_string(),
+            agency: RegulatoryAgency::FSC,
+            report_type: ReportType::LargeTransaction,
+            period_start: transaction.timestamp,
+            period_end: transaction.timestamp,
+            generated_at: transaction.timestamp,
+            data: serde_json::to_value(&transaction).unwrap(),
+            status: ReportStatus::Generated,
+            submission_attempts: 0,
+            last_submission_attempt: None,
+            submission_error: None,
+        }
+    }
+
+    /// 처음 `failures`번은 일시적 오류를 내는 전송
+    struct FlakyTransport {
+        failures: u32,
+        calls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl ReportTransport for FlakyTransport {
+        fn name(&self) -> &'static str {
+            "flaky"
+        }
+
+        async fn upload(&self, report: &RenderedReport) -> Result<TransportReceipt, DeliveryError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if call <= self.failures {
+                return Err(DeliveryError::Transport("connection reset".to_string()));
+            }
+            Ok(TransportReceipt { reference: Some(format!("rcpt-{}", report.report_id)), acknowledgment: None })
+        }
+    }
+
+    fn config(format: ReportFormat) -> ReportDeliveryConfig {
+        let mut config = ReportDeliveryConfig::new(DeliveryEndpoint::Directory { path: PathBuf::new() }, format);
+        config.retry = RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+        };
+        config
+    }
+
+    #[tokio::test]
+    async fn test_retry_until_delivered_or_exhausted() {
+        let service = ReportDeliveryService::with_transport(
+            config(ReportFormat::Xml),
+            Arc::new(FlakyTransport { failures: 2, calls: AtomicU32::new(0) }),
+        );
+        let record = service.deliver(&large_transaction_report()).await;
+        assert_eq!(record.status, DeliveryStatus::Delivered);
+        assert_eq!(record.attempts, 3);
+        assert_eq!(record.reference.as_deref(), Some("rcpt-large_transaction_tx_1"));
+        assert_eq!(service.pending_acknowledgments().await.len(), 1);
+
+        let service = ReportDeliveryService::with_transport(
+            config(ReportFormat::Xml),
+            Arc::new(FlakyTransport { failures: 5, calls: AtomicU32::new(0) }),
+        );
+        let record = service.deliver(&large_transaction_report()).await;
+        assert_eq!(record.status, DeliveryStatus::Failed);
+        assert_eq!(record.attempts, 3);
+        assert_eq!(record.status.report_status(), ReportStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_directory_delivery_and_acknowledgment() {
+        let dir = std::env::temp_dir().join(format!("xtrader-report-delivery-{}", uuid::Uuid::new_v4()));
+        let service = ReportDeliveryService::with_transport(
+            config(ReportFormat::Csv),
+            Arc::new(DirectoryTransport::new(dir.clone())),
+        );
+
+        let record = service.deliver(&large_transaction_report()).await;
+        assert_eq!(record.status, DeliveryStatus::Delivered);
+        assert_eq!(record.attempts, 1);
+        let uploaded = std::fs::read_to_string(dir.join("large_transaction_tx_1.csv")).unwrap();
+        assert!(uploaded.starts_with("transaction_id,"));
+
+        // 규제 기관이 접수 확인 파일을 올림
+        std::fs::create_dir_all(dir.join("ack")).unwrap();
+        std::fs::write(dir.join("ack").join("large_transaction_tx_1.ack"), "FIU-2023-000123\n").unwrap();
+        std::fs::write(dir.join("ack").join("unknown_report.nack"), "").unwrap();
+
+        let updated = service.poll_acknowledgments().await.unwrap();
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].status, DeliveryStatus::Acknowledged);
+        assert!(service.pending_acknowledgments().await.is_empty());
+        assert!(service.poll_acknowledgments().await.unwrap().is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
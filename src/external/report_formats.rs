@@ -0,0 +1,633 @@
+//! 규제 보고서 파일 형식
+//!
+//! `RegulatoryReport`를 규제 기관이 요구하는 파일로 변환합니다.
+//!
+//! - 거래 보고서(`TransactionReport`, `LargeTransaction`): ISO 20022
+//!   `auth.016.001.03`(FinancialInstrumentReportingTransactionReport) XML 또는 CSV
+//! - 의심 거래 보고서(`SuspiciousActivity`): goAML STR XML 또는 CSV
+//!
+//! XSD 검증기를 두는 대신, 렌더링 전에 스키마가 요구하는 필수 값과 형식 제약
+//! (LEI, MIC, 통화 코드, 텍스트 길이, 금액 자릿수 등)을 검사해 위반 사항을 모두 모아
+//! 돌려줍니다. 검증에 실패한 보고서는 전송하지 않습니다.
+
+use std::fmt::Write as _;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::external::regulatory_reporting::{
+    RegulatoryReport, ReportType, SuspiciousActivityData, TransactionData,
+};
+
+/// ISO 20022 거래 보고서 네임스페이스
+pub const AUTH_016_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:auth.016.001.03";
+
+/// 보고서 파일 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    Json,
+    Xml,
+    Csv,
+}
+
+impl ReportFormat {
+    /// 형식 이름(`json`, `xml`, `csv`)으로 찾기
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "json" => Some(ReportFormat::Json),
+            "xml" => Some(ReportFormat::Xml),
+            "csv" => Some(ReportFormat::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Json => "json",
+            ReportFormat::Xml => "xml",
+            ReportFormat::Csv => "csv",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ReportFormat::Json => "application/json",
+            ReportFormat::Xml => "application/xml",
+            ReportFormat::Csv => "text/csv",
+        }
+    }
+}
+
+/// 보고 주체 정보 (보고서 헤더에 들어가는 값)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportingEntity {
+    /// 거래 보고 주체 LEI (20자리)
+    pub lei: String,
+    /// 거래소 MIC (4자리)
+    pub venue_mic: String,
+    /// 금융정보분석원(FIU) 보고 기관 ID (goAML `rentity_id`, 숫자)
+    pub fiu_entity_id: String,
+    /// 심볼에서 통화를 알 수 없을 때 쓰는 통화
+    pub default_currency: String,
+}
+
+impl Default for ReportingEntity {
+    fn default() -> Self {
+        Self {
+            lei: "5493001KJTIIGC8Y1R12".to_string(),
+            venue_mic: "XXTR".to_string(),
+            fiu_entity_id: "1001".to_string(),
+            default_currency: "KRW".to_string(),
+        }
+    }
+}
+
+/// 변환된 보고서 파일
+#[derive(Debug, Clone)]
+pub struct RenderedReport {
+    pub report_id: String,
+    pub report_type: ReportType,
+    pub format: ReportFormat,
+    pub file_name: String,
+    pub body: Vec<u8>,
+}
+
+impl RenderedReport {
+    pub fn content_type(&self) -> &'static str {
+        self.format.content_type()
+    }
+}
+
+/// 보고서 변환 오류
+#[derive(Debug, thiserror::Error)]
+pub enum ReportFormatError {
+    #[error("{report_type} 보고서는 {format:?} 형식을 지원하지 않습니다")]
+    Unsupported { report_type: ReportType, format: ReportFormat },
+    #[error("보고서 데이터 해석 실패: {0}")]
+    InvalidData(#[from] serde_json::Error),
+    #[error("스키마 검증 실패: {}", .0.join("; "))]
+    Validation(Vec<String>),
+    #[error("CSV 작성 실패: {0}")]
+    Csv(#[from] csv::Error),
+}
+
+/// 보고서 데이터에서 거래 목록 추출
+fn report_transactions(report: &RegulatoryReport) -> Result<Vec<TransactionData>, ReportFormatError> {
+    match report.data.get("transactions") {
+        Some(transactions) => Ok(serde_json::from_value(transactions.clone())?),
+        // 대규모 거래 보고서는 거래 한 건이 그대로 들어 있음
+        None => Ok(vec![serde_json::from_value(report.data.clone())?]),
+    }
+}
+
+/// 보고서 데이터에서 의심 활동 목록 추출
+fn report_activities(report: &RegulatoryReport) -> Result<Vec<SuspiciousActivityData>, ReportFormatError> {
+    match report.data.get("activities") {
+        Some(activities) => Ok(serde_json::from_value(activities.clone())?),
+        // 즉시 보고서는 활동 한 건이 그대로 들어 있음
+        None => Ok(vec![serde_json::from_value(report.data.clone())?]),
+    }
+}
+
+/// 보고서를 지정 형식으로 변환 (검증 포함)
+pub fn render_report(
+    report: &RegulatoryReport,
+    format: ReportFormat,
+    entity: &ReportingEntity,
+) -> Result<RenderedReport, ReportFormatError> {
+    let body = match (&report.report_type, format) {
+        (_, ReportFormat::Json) => serde_json::to_vec_pretty(report)?,
+        (ReportType::TransactionReport | ReportType::LargeTransaction, ReportFormat::Xml) => {
+            let transactions = report_transactions(report)?;
+            validate_transactions(&transactions, entity)?;
+            transactions_to_xml(&transactions, entity).into_bytes()
+        }
+        (ReportType::TransactionReport | ReportType::LargeTransaction, ReportFormat::Csv) => {
+            let transactions = report_transactions(report)?;
+            validate_transactions(&transactions, entity)?;
+            transactions_to_csv(&transactions, entity)?
+        }
+        (ReportType::SuspiciousActivity, ReportFormat::Xml) => {
+            let activities = report_activities(report)?;
+            validate_activities(report, &activities, entity)?;
+            activities_to_goaml(report, &activities, entity).into_bytes()
+        }
+        (ReportType::SuspiciousActivity, ReportFormat::Csv) => {
+            let activities = report_activities(report)?;
+            validate_activities(report, &activities, entity)?;
+            activities_to_csv(&activities)?
+        }
+        (report_type, format) => {
+            return Err(ReportFormatError::Unsupported { report_type: report_type.clone(), format });
+        }
+    };
+
+    Ok(RenderedReport {
+        report_id: report.report_id.clone(),
+        report_type: report.report_type.clone(),
+        format,
+        file_name: format!("{}.{}", report.report_id, format.extension()),
+        body,
+    })
+}
+
+/// 밀리초 타임스탬프 → ISO 8601 (UTC)
+fn iso_datetime(timestamp_ms: u64) -> String {
+    DateTime::<Utc>::from_timestamp_millis(timestamp_ms as i64)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// 심볼의 호가 통화 (`BTC-KRW` → `KRW`)
+fn quote_currency<'a>(symbol: &'a str, entity: &'a ReportingEntity) -> &'a str {
+    symbol.split_once('-').map_or(entity.default_currency.as_str(), |(_, quote)| quote)
+}
+
+/// 소수 표기 (불필요한 0 제거)
+fn decimal(value: f64) -> String {
+    let text = format!("{:.8}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+fn is_upper_alnum(text: &str, len: usize) -> bool {
+    text.len() == len && text.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// ISO 20022 DecimalNumber 제약 (전체 18자리, 소수 17자리 이하)
+fn check_decimal(errors: &mut Vec<String>, field: &str, value: f64) {
+    if !value.is_finite() || value <= 0.0 {
+        errors.push(format!("{}: 0보다 큰 값이어야 합니다 ({})", field, value));
+        return;
+    }
+    let digits = decimal(value).chars().filter(char::is_ascii_digit).count();
+    if digits > 18 {
+        errors.push(format!("{}: 전체 자릿수 18 초과 ({})", field, value));
+    }
+}
+
+fn check_text(errors: &mut Vec<String>, field: &str, value: &str, max_len: usize) {
+    let len = value.chars().count();
+    if len == 0 || len > max_len {
+        errors.push(format!("{}: 길이는 1~{}자여야 합니다 (현재 {}자)", field, max_len, len));
+    }
+}
+
+/// auth.016 스키마 제약 검사
+fn validate_transactions(transactions: &[TransactionData], entity: &ReportingEntity) -> Result<(), ReportFormatError> {
+    let mut errors = Vec::new();
+
+    // LEI: [A-Z0-9]{18}[0-9]{2}
+    if !(is_upper_alnum(&entity.lei, 20) && entity.lei[18..].chars().all(|c| c.is_ascii_digit())) {
+        errors.push(format!("LEI 형식 오류: {}", entity.lei));
+    }
+    if !is_upper_alnum(&entity.venue_mic, 4) {
+        errors.push(format!("MIC 형식 오류: {}", entity.venue_mic));
+    }
+
+    for tx in transactions {
+        let prefix = format!("Tx[{}]", tx.transaction_id);
+        check_text(&mut errors, &format!("{} TxId", prefix), &tx.transaction_id, 52);
+        check_text(&mut errors, &format!("{} 계정 ID", prefix), &tx.user_id, 35);
+        check_decimal(&mut errors, &format!("{} Qty", prefix), tx.amount);
+        check_decimal(&mut errors, &format!("{} Pric", prefix), tx.price);
+        if !matches!(tx.side.as_str(), "buy" | "sell") {
+            errors.push(format!("{} 매매 구분 오류: {}", prefix, tx.side));
+        }
+        let currency = quote_currency(&tx.symbol, entity);
+        if !(currency.len() == 3 && currency.chars().all(|c| c.is_ascii_uppercase())) {
+            errors.push(format!("{} 통화 코드 오류: {}", prefix, currency));
+        }
+        if !(tx.user_country.len() == 2 && tx.user_country.chars().all(|c| c.is_ascii_uppercase())) {
+            errors.push(format!("{} 국가 코드 오류: {}", prefix, tx.user_country));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ReportFormatError::Validation(errors))
+    }
+}
+
+/// goAML STR 스키마 제약 검사
+fn validate_activities(
+    report: &RegulatoryReport,
+    activities: &[SuspiciousActivityData],
+    entity: &ReportingEntity,
+) -> Result<(), ReportFormatError> {
+    let mut errors = Vec::new();
+
+    if entity.fiu_entity_id.is_empty() || !entity.fiu_entity_id.chars().all(|c| c.is_ascii_digit()) {
+        errors.push(format!("rentity_id는 숫자여야 합니다: {}", entity.fiu_entity_id));
+    }
+    check_text(&mut errors, "entity_reference", &report.report_id, 255);
+    if activities.is_empty() {
+        errors.push("보고할 의심 활동이 없습니다".to_string());
+    }
+    for activity in activities {
+        let prefix = format!("활동[{}]", activity.activity_id);
+        check_text(&mut errors, &format!("{} account", prefix), &activity.user_id, 50);
+        check_text(&mut errors, &format!("{} reason", prefix), &activity.description, 4000);
+        if !(0.0..=1.0).contains(&activity.risk_score) {
+            errors.push(format!("{} 위험도는 0~1이어야 합니다: {}", prefix, activity.risk_score));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ReportFormatError::Validation(errors))
+    }
+}
+
+/// 들여쓰기 XML 작성기
+struct XmlWriter {
+    buf: String,
+    stack: Vec<&'static str>,
+}
+
+impl XmlWriter {
+    fn new() -> Self {
+        Self {
+            buf: String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"),
+            stack: Vec::new(),
+        }
+    }
+
+    fn indent(&mut self) {
+        for _ in 0..self.stack.len() {
+            self.buf.push_str("  ");
+        }
+    }
+
+    fn open_with(&mut self, tag: &'static str, attrs: &[(&str, &str)]) {
+        self.indent();
+        let _ = write!(self.buf, "<{}", tag);
+        for (name, value) in attrs {
+            let _ = write!(self.buf, " {}=\"{}\"", name, escape_xml(value));
+        }
+        self.buf.push_str(">\n");
+        self.stack.push(tag);
+    }
+
+    fn open(&mut self, tag: &'static str) {
+        self.open_with(tag, &[]);
+    }
+
+    fn close(&mut self) {
+        let tag = self.stack.pop().expect("닫을 XML 요소 없음");
+        self.indent();
+        let _ = writeln!(self.buf, "</{}>", tag);
+    }
+
+    fn leaf_with(&mut self, tag: &str, attrs: &[(&str, &str)], text: &str) {
+        self.indent();
+        let _ = write!(self.buf, "<{}", tag);
+        for (name, value) in attrs {
+            let _ = write!(self.buf, " {}=\"{}\"", name, escape_xml(value));
+        }
+        let _ = writeln!(self.buf, ">{}</{}>", escape_xml(text), tag);
+    }
+
+    fn leaf(&mut self, tag: &str, text: &str) {
+        self.leaf_with(tag, &[], text);
+    }
+
+    fn finish(mut self) -> String {
+        while !self.stack.is_empty() {
+            self.close();
+        }
+        self.buf
+    }
+}
+
+/// XML 텍스트/속성 이스케이프
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // XML 1.0에서 허용되지 않는 제어 문자는 제거
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// ISO 20022 auth.016 거래 보고서
+fn transactions_to_xml(transactions: &[TransactionData], entity: &ReportingEntity) -> String {
+    let mut xml = XmlWriter::new();
+    xml.open_with("Document", &[("xmlns", AUTH_016_NAMESPACE)]);
+    xml.open("FinInstrmRptgTxRpt");
+
+    for tx in transactions {
+        xml.open("Tx");
+        xml.open("New");
+        xml.leaf("TxId", &tx.transaction_id);
+        xml.open("ExctgPty");
+        xml.leaf("LEI", &entity.lei);
+        xml.close();
+        xml.leaf("InvstmtPtyInd", "true");
+        xml.leaf("SubmitgPty", &entity.lei);
+
+        // 고객 계정은 매수/매도 쪽에만 기재
+        xml.open(if tx.side == "buy" { "Buyr" } else { "Sellr" });
+        xml.open("AcctOwnr");
+        xml.open("Id");
+        xml.open("Prsn");
+        xml.open("Othr");
+        xml.leaf("Id", &tx.user_id);
+        xml.close();
+        xml.close();
+        xml.close();
+        xml.leaf("CtryOfBrnch", &tx.user_country);
+        xml.close();
+        xml.close();
+
+        xml.open("Tx");
+        xml.leaf("TradDt", &iso_datetime(tx.timestamp));
+        xml.leaf("TradgCpcty", "AOTC");
+        xml.open("Qty");
+        xml.leaf("Unit", &decimal(tx.amount));
+        xml.close();
+        xml.open("Pric");
+        xml.open("Pric");
+        xml.open("MntryVal");
+        xml.leaf_with("Amt", &[("Ccy", quote_currency(&tx.symbol, entity))], &decimal(tx.price));
+        xml.close();
+        xml.close();
+        xml.close();
+        xml.leaf("NetAmt", &decimal(tx.total_value));
+        xml.leaf("TradVn", &entity.venue_mic);
+        xml.close();
+
+        xml.open("FinInstrm");
+        xml.open("Othr");
+        xml.open("FinInstrmGnlAttrbts");
+        xml.leaf("FullNm", &tx.symbol);
+        xml.leaf("NtnlCcy", quote_currency(&tx.symbol, entity));
+        xml.close();
+        xml.close();
+        xml.close();
+
+        xml.close();
+        xml.close();
+    }
+
+    xml.finish()
+}
+
+/// goAML 의심 거래 보고서(STR)
+fn activities_to_goaml(
+    report: &RegulatoryReport,
+    activities: &[SuspiciousActivityData],
+    entity: &ReportingEntity,
+) -> String {
+    let mut xml = XmlWriter::new();
+    xml.open("report");
+    xml.leaf("rentity_id", &entity.fiu_entity_id);
+    xml.leaf("submission_code", "E");
+    xml.leaf("report_code", "STR");
+    xml.leaf("entity_reference", &report.report_id);
+    xml.leaf("submission_date", &iso_datetime(report.generated_at));
+    xml.leaf("currency_code_local", &entity.default_currency);
+    xml.leaf(
+        "reason",
+        &format!("{}건의 의심 거래 활동 (자동 탐지)", activities.len()),
+    );
+    xml.leaf("action", "내부 모니터링 후 보고");
+
+    xml.open("activity");
+    xml.open("report_parties");
+    for activity in activities {
+        xml.open("report_party");
+        xml.open("account");
+        xml.leaf("institution_name", "xTrader");
+        xml.leaf("account", &activity.user_id);
+        xml.close();
+        // goAML 중요도는 0~10
+        xml.leaf("significance", &((activity.risk_score * 10.0).round() as u8).min(10).to_string());
+        xml.leaf("reason", &activity.description);
+        let mut comments = format!("type={}; severity={}", activity.activity_type, activity.severity);
+        if let Some(version) = activity.rule_set_version {
+            let _ = write!(comments, "; aml_rules=v{} [{}]", version, activity.matched_rules.join(","));
+        }
+        if !activity.evidence.is_empty() {
+            let _ = write!(comments, "; evidence={}", activity.evidence.join(" | "));
+        }
+        xml.leaf("comments", &comments);
+        xml.close();
+    }
+    xml.finish()
+}
+
+fn transactions_to_csv(transactions: &[TransactionData], entity: &ReportingEntity) -> Result<Vec<u8>, ReportFormatError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record([
+        "transaction_id", "trade_time", "user_id", "symbol", "side", "quantity", "price", "total_value", "currency",
+        "user_country", "venue_mic",
+    ])?;
+    for tx in transactions {
+        writer.write_record([
+            tx.transaction_id.as_str(),
+            &iso_datetime(tx.timestamp),
+            &tx.user_id,
+            &tx.symbol,
+            &tx.side,
+            &decimal(tx.amount),
+            &decimal(tx.price),
+            &decimal(tx.total_value),
+            quote_currency(&tx.symbol, entity),
+            &tx.user_country,
+            &entity.venue_mic,
+        ])?;
+    }
+    writer.into_inner().map_err(|e| ReportFormatError::Csv(e.into_error().into()))
+}
+
+fn activities_to_csv(activities: &[SuspiciousActivityData]) -> Result<Vec<u8>, ReportFormatError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record([
+        "activity_id", "detected_at", "user_id", "activity_type", "risk_score", "severity", "rule_set_version",
+        "matched_rules", "evidence", "description",
+    ])?;
+    for activity in activities {
+        writer.write_record([
+            activity.activity_id.as_str(),
+            &iso_datetime(activity.timestamp),
+            &activity.user_id,
+            &activity.activity_type,
+            &format!("{:.4}", activity.risk_score),
+            &activity.severity,
+            &activity.rule_set_version.map(|v| v.to_string()).unwrap_or_default(),
+            &activity.matched_rules.join(";"),
+            &activity.evidence.join(" | "),
+            &activity.description,
+        ])?;
+    }
+    writer.into_inner().map_err(|e| ReportFormatError::Csv(e.into_error().into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::regulatory_reporting::{RegulatoryAgency, ReportStatus};
+
+    fn transaction(id: &str, side: &str) -> TransactionData {
+        TransactionData {
+            transaction_id: id.to_string(),
+            user_id: "user_1".to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: side.to_string(),
+            amount: 0.25,
+            price: 50_000_000.0,
+            total_value: 12_500_000.0,
+            timestamp: 1_700_000_000_000,
+            user_country: "KR".to_string(),
+            user_tax_id: None,
+            ip_address: "10.0.0.1".to_string(),
+            device_fingerprint: "device_1".to_string(),
+        }
+    }
+
+    fn report(report_type: ReportType, data: serde_json::Value) -> RegulatoryReport {
+        RegulatoryReport {
+            report_id: format!("{}_1", report_type),
+            agency: RegulatoryAgency::FSC,
+            report_type,
+            period_start: 1_699_913_600_000,
+            period_end: 1_700_000_000_000,
+            generated_at: 1_700_000_000_000,
+            data,
+            status: ReportStatus::Generated,
+            submission_attempts: 0,
+            last_submission_attempt: None,
+            submission_error: None,
+        }
+    }
+
+    #[test]
+    fn test_transaction_report_xml_and_csv() {
+        let data = serde_json::json!({
+            "total_transactions": 2,
+            "transactions": [transaction("tx_1", "buy"), transaction("tx<2>", "sell")],
+        });
+        let report = report(ReportType::TransactionReport, data);
+        let entity = ReportingEntity::default();
+
+        let xml = render_report(&report, ReportFormat::Xml, &entity).unwrap();
+        assert_eq!(xml.file_name, "TransactionReport_1.xml");
+        let body = String::from_utf8(xml.body).unwrap();
+        assert!(body.contains(&format!("<Document xmlns=\"{}\">", AUTH_016_NAMESPACE)));
+        assert!(body.contains("<TxId>tx&lt;2&gt;</TxId>"));
+        assert!(body.contains("<Buyr>") && body.contains("<Sellr>"));
+        assert!(body.contains("<Amt Ccy=\"KRW\">50000000</Amt>"));
+        assert!(body.contains("<Unit>0.25</Unit>"));
+        assert!(body.contains("<TradDt>2023-11-14T22:13:20Z</TradDt>"));
+        assert!(body.trim_end().ends_with("</Document>"));
+
+        let csv = render_report(&report, ReportFormat::Csv, &entity).unwrap();
+        let body = String::from_utf8(csv.body).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("tx_1,2023-11-14T22:13:20Z,user_1,BTC-KRW,buy,0.25,50000000,12500000,KRW,KR,XXTR"));
+    }
+
+    #[test]
+    fn test_schema_validation_collects_errors() {
+        let mut bad = transaction("", "hold");
+        bad.user_country = "Korea".to_string();
+        let report = report(ReportType::LargeTransaction, serde_json::to_value(&bad).unwrap());
+        let entity = ReportingEntity { lei: "SHORT".to_string(), ..ReportingEntity::default() };
+
+        let Err(ReportFormatError::Validation(errors)) = render_report(&report, ReportFormat::Xml, &entity) else {
+            panic!("검증 실패가 나야 합니다");
+        };
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("LEI")));
+        assert!(errors.iter().any(|e| e.contains("TxId")));
+        assert!(errors.iter().any(|e| e.contains("매매 구분")));
+        assert!(errors.iter().any(|e| e.contains("국가 코드")));
+
+        let unsupported = RegulatoryReport { report_type: ReportType::AuditReport, ..report };
+        assert!(matches!(
+            render_report(&unsupported, ReportFormat::Xml, &ReportingEntity::default()),
+            Err(ReportFormatError::Unsupported { .. })
+        ));
+    }
+
+    #[test]
+    fn test_suspicious_activity_goaml() {
+        let activity = SuspiciousActivityData {
+            activity_id: "act_1".to_string(),
+            user_id: "user_9".to_string(),
+            activity_type: "Structuring".to_string(),
+            risk_score: 0.86,
+            description: "Suspicious transaction detected: tx_9 (AML rules v3)".to_string(),
+            evidence: vec!["Structuring: 3건".to_string()],
+            timestamp: 1_700_000_000_000,
+            severity: "high".to_string(),
+            rule_set_version: Some(3),
+            matched_rules: vec!["structuring".to_string()],
+        };
+        let report = report(ReportType::SuspiciousActivity, serde_json::to_value(&activity).unwrap());
+
+        let xml = render_report(&report, ReportFormat::Xml, &ReportingEntity::default()).unwrap();
+        let body = String::from_utf8(xml.body).unwrap();
+        assert!(body.contains("<report_code>STR</report_code>"));
+        assert!(body.contains("<rentity_id>1001</rentity_id>"));
+        assert!(body.contains("<account>user_9</account>"));
+        assert!(body.contains("<significance>9</significance>"));
+        assert!(body.contains("aml_rules=v3 [structuring]"));
+
+        let csv = render_report(&report, ReportFormat::Csv, &ReportingEntity::default()).unwrap();
+        let body = String::from_utf8(csv.body).unwrap();
+        assert!(body.lines().nth(1).unwrap().starts_with("act_1,2023-11-14T22:13:20Z,user_9,Structuring,0.8600,high,3,structuring,"));
+    }
+}
@@ -0,0 +1,444 @@
+//! 수수료 등급 산정과 체결 수수료 계산
+//!
+//! 계정(`client_id`)별 최근 30일 거래대금(메이커 + 테이커, 보고 통화 최소 단위)으로 수수료 등급을 정하고,
+//! 시퀀서가 체결을 기록할 때 양쪽 계정 등급의 요율(bp)로 메이커/테이커 수수료를 계산합니다.
+//! 등급은 하루 한 번 재산정하며, 바뀐 등급만 DB(`fee_tier_history`)에 남기고 재시작 시 마지막 등급에서 이어갑니다.
+//! 등급 이력이 없는 계정은 가장 낮은 등급입니다.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::RwLock;
+use std::time::Duration;
+
+use log::{info, warn};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use utoipa::ToSchema;
+
+use crate::db::models::{ClientVolumeRecord, FeeTierHistoryRecord};
+use crate::db::repository::{ClientStatsRepository, FeeTierHistoryRepository};
+
+/// 수수료 등급 (요율 단위 bp = 0.01%, 메이커 요율이 음수면 리베이트)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeTier {
+    pub name: String,
+    /// 등급 최소 30일 거래대금 (보고 통화 최소 단위)
+    pub min_volume: u64,
+    pub maker_fee_bps: i64,
+    pub taker_fee_bps: i64,
+}
+
+impl FeeTier {
+    pub fn new(name: &str, min_volume: u64, maker_fee_bps: i64, taker_fee_bps: i64) -> Self {
+        Self {
+            name: name.to_string(),
+            min_volume,
+            maker_fee_bps,
+            taker_fee_bps,
+        }
+    }
+
+    /// `VIP0:0:10:15,VIP1:1000000000:8:12` (이름:최소 거래대금:메이커 bp:테이커 bp) 형식 파싱
+    pub fn parse_list(spec: &str) -> Result<Vec<Self>, String> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                Self::parse(entry).ok_or_else(|| format!("수수료 등급 형식 오류: {} (예: VIP1:1000000000:8:12)", entry))
+            })
+            .collect()
+    }
+
+    fn parse(entry: &str) -> Option<Self> {
+        let parts: Vec<&str> = entry.split(':').map(str::trim).collect();
+        let [name, min_volume, maker, taker] = parts.as_slice() else {
+            return None;
+        };
+        if name.is_empty() {
+            return None;
+        }
+        let taker_fee_bps = taker.parse::<i64>().ok().filter(|bps| *bps >= 0)?;
+        Some(Self::new(name, min_volume.parse().ok()?, maker.parse().ok()?, taker_fee_bps))
+    }
+}
+
+/// 수수료 설정
+#[derive(Debug, Clone)]
+pub struct FeeConfig {
+    pub tiers: Vec<FeeTier>,
+    /// 등급 산정 거래대금 집계 기간
+    pub volume_window: Duration,
+    /// 등급 재산정 주기
+    pub recalculation_interval: Duration,
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self {
+            tiers: vec![
+                FeeTier::new("VIP0", 0, 10, 15),
+                FeeTier::new("VIP1", 1_000_000_000, 8, 12),   // 10억원
+                FeeTier::new("VIP2", 10_000_000_000, 5, 10),  // 100억원
+                FeeTier::new("VIP3", 100_000_000_000, 0, 8),  // 1000억원
+            ],
+            volume_window: Duration::from_secs(30 * 24 * 60 * 60),
+            recalculation_interval: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+/// 수수료 오류
+#[derive(Debug, thiserror::Error)]
+pub enum FeeError {
+    #[error("수수료 등급 설정 오류: {0}")]
+    InvalidSchedule(String),
+    #[error("수수료 등급 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 최소 거래대금순 수수료 등급표
+#[derive(Debug, Clone)]
+pub struct FeeSchedule {
+    tiers: Vec<FeeTier>,
+}
+
+impl FeeSchedule {
+    /// 등급 이름이 겹치지 않고 최소 거래대금 0인 기본 등급이 있어야 함
+    pub fn new(mut tiers: Vec<FeeTier>) -> Result<Self, FeeError> {
+        tiers.sort_by_key(|tier| tier.min_volume);
+        if tiers.first().map(|tier| tier.min_volume) != Some(0) {
+            return Err(FeeError::InvalidSchedule("최소 거래대금 0인 기본 등급이 필요합니다".to_string()));
+        }
+        let mut names = BTreeSet::new();
+        for tier in &tiers {
+            if !names.insert(tier.name.as_str()) {
+                return Err(FeeError::InvalidSchedule(format!("중복된 등급 이름: {}", tier.name)));
+            }
+        }
+        Ok(Self { tiers })
+    }
+
+    /// 기본 (가장 낮은) 등급
+    pub fn base(&self) -> &FeeTier {
+        &self.tiers[0]
+    }
+
+    /// 30일 거래대금에 해당하는 등급
+    pub fn tier_for_volume(&self, volume: u64) -> &FeeTier {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.min_volume <= volume)
+            .unwrap_or_else(|| self.base())
+    }
+
+    pub fn tier(&self, name: &str) -> Option<&FeeTier> {
+        self.tiers.iter().find(|tier| tier.name == name)
+    }
+}
+
+/// 계정에 적용 중인 수수료 등급
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ClientFeeTier {
+    pub client_id: String,
+    pub tier: String,
+    /// 마지막 산정 시 30일 거래대금 (보고 통화 최소 단위)
+    pub volume_30d: u64,
+    pub maker_fee_bps: i64,
+    pub taker_fee_bps: i64,
+    /// 적용 시작 시각 (초, 기본 등급은 0)
+    pub effective_at: u64,
+}
+
+impl ClientFeeTier {
+    fn assign(client_id: &str, tier: &FeeTier, volume_30d: u64, effective_at: u64) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            tier: tier.name.clone(),
+            volume_30d,
+            maker_fee_bps: tier.maker_fee_bps,
+            taker_fee_bps: tier.taker_fee_bps,
+            effective_at,
+        }
+    }
+
+    fn to_record(&self) -> FeeTierHistoryRecord {
+        FeeTierHistoryRecord {
+            id: None,
+            client_id: self.client_id.clone(),
+            tier: self.tier.clone(),
+            volume_30d: self.volume_30d as i64,
+            maker_fee_bps: self.maker_fee_bps,
+            taker_fee_bps: self.taker_fee_bps,
+            effective_at: self.effective_at as i64,
+        }
+    }
+
+    fn from_record(record: FeeTierHistoryRecord) -> Self {
+        Self {
+            client_id: record.client_id,
+            tier: record.tier,
+            volume_30d: record.volume_30d.max(0) as u64,
+            maker_fee_bps: record.maker_fee_bps,
+            taker_fee_bps: record.taker_fee_bps,
+            effective_at: record.effective_at.max(0) as u64,
+        }
+    }
+}
+
+/// 체결 한 건의 수수료 (호가 자산 최소 단위, 음수면 리베이트)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutionFees {
+    pub maker_fee: i64,
+    pub taker_fee: i64,
+}
+
+/// 체결 금액 × 요율(bp), 0 방향으로 버림
+fn fee_amount(notional: u64, bps: i64) -> i64 {
+    (notional as i128 * bps as i128 / 10_000) as i64
+}
+
+/// 계정별 보고 통화 환산 30일 거래대금 (환산할 수 없는 심볼은 빼고 심볼 목록으로 돌려줌)
+fn client_volumes(
+    records: Vec<ClientVolumeRecord>,
+    to_reporting: &(dyn Fn(&str, u64) -> Option<u64> + Sync),
+) -> (HashMap<String, u64>, BTreeSet<String>) {
+    let mut volumes: HashMap<String, u64> = HashMap::new();
+    let mut unpriced = BTreeSet::new();
+    for record in records {
+        let notional = record.maker_notional.max(0) as u64 + record.taker_notional.max(0) as u64;
+        match to_reporting(&record.symbol, notional) {
+            Some(volume) => *volumes.entry(record.client_id).or_default() += volume,
+            None => {
+                unpriced.insert(record.symbol);
+            }
+        }
+    }
+    (volumes, unpriced)
+}
+
+/// 계정별 수수료 등급 (메모리 + DB 이력)
+pub struct FeeEngine {
+    schedule: FeeSchedule,
+    volume_window: Duration,
+    clients: RwLock<HashMap<String, ClientFeeTier>>,
+    repository: FeeTierHistoryRepository,
+    stats: ClientStatsRepository,
+}
+
+impl FeeEngine {
+    /// DB에 기록된 계정별 마지막 등급을 읽어 생성 (설정에서 사라진 등급은 다음 재산정까지 기본 등급)
+    pub async fn load(config: FeeConfig, pool: SqlitePool) -> Result<Self, FeeError> {
+        let schedule = FeeSchedule::new(config.tiers)?;
+        let repository = FeeTierHistoryRepository::new(pool.clone());
+
+        let mut clients = HashMap::new();
+        for record in repository.find_current().await? {
+            let current = ClientFeeTier::from_record(record);
+            match schedule.tier(&current.tier) {
+                // 요율은 현재 설정을 따름
+                Some(tier) => {
+                    let assigned = ClientFeeTier::assign(&current.client_id, tier, current.volume_30d, current.effective_at);
+                    clients.insert(current.client_id, assigned);
+                }
+                None => warn!("알 수 없는 수수료 등급 '{}' ({}), 기본 등급 적용", current.tier, current.client_id),
+            }
+        }
+        info!("수수료 등급 계정 {}개 로드", clients.len());
+
+        Ok(Self {
+            schedule,
+            volume_window: config.volume_window,
+            clients: RwLock::new(clients),
+            repository,
+            stats: ClientStatsRepository::new(pool),
+        })
+    }
+
+    /// 계정 수수료 등급 (이력이 없으면 기본 등급)
+    pub fn client_tier(&self, client_id: &str) -> ClientFeeTier {
+        self.clients
+            .read()
+            .unwrap()
+            .get(client_id)
+            .cloned()
+            .unwrap_or_else(|| ClientFeeTier::assign(client_id, self.schedule.base(), 0, 0))
+    }
+
+    /// 체결 수수료 (계정을 모르는 쪽은 기본 등급 요율)
+    pub fn execution_fees(&self, maker_client: Option<&str>, taker_client: Option<&str>, notional: u64) -> ExecutionFees {
+        let clients = self.clients.read().unwrap();
+        let base = self.schedule.base();
+        let maker_bps = maker_client
+            .and_then(|client_id| clients.get(client_id))
+            .map_or(base.maker_fee_bps, |tier| tier.maker_fee_bps);
+        let taker_bps = taker_client
+            .and_then(|client_id| clients.get(client_id))
+            .map_or(base.taker_fee_bps, |tier| tier.taker_fee_bps);
+        ExecutionFees {
+            maker_fee: fee_amount(notional, maker_bps),
+            taker_fee: fee_amount(notional, taker_bps),
+        }
+    }
+
+    /// `now`(초) 기준 30일 거래대금으로 전 계정 등급 재산정, 바뀐 등급을 이력에 남기고 반환
+    pub async fn recalculate(
+        &self,
+        now: u64,
+        to_reporting: &(dyn Fn(&str, u64) -> Option<u64> + Sync),
+    ) -> Result<Vec<ClientFeeTier>, FeeError> {
+        let since = now.saturating_sub(self.volume_window.as_secs()) as i64;
+        let (volumes, unpriced) = client_volumes(self.stats.volumes_since(since).await?, to_reporting);
+        if !unpriced.is_empty() {
+            warn!("수수료 등급 산정에서 환산할 수 없는 심볼 제외: {:?}", unpriced);
+        }
+
+        // 거래가 없던 계정도 기존 등급이 있으면 다시 산정 (기본 등급으로 내려감)
+        let mut client_ids: BTreeSet<String> = volumes.keys().cloned().collect();
+        client_ids.extend(self.clients.read().unwrap().keys().cloned());
+
+        let mut changes = Vec::new();
+        for client_id in client_ids {
+            let volume = volumes.get(&client_id).copied().unwrap_or(0);
+            let tier = self.schedule.tier_for_volume(volume);
+            let current = self.client_tier(&client_id);
+            if current.tier == tier.name {
+                if let Some(entry) = self.clients.write().unwrap().get_mut(&client_id) {
+                    entry.volume_30d = volume;
+                }
+                continue;
+            }
+
+            let assigned = ClientFeeTier::assign(&client_id, tier, volume, now);
+            self.repository.save(&assigned.to_record()).await?;
+            info!("수수료 등급 변경: {} {} → {} (30일 거래대금 {})", client_id, current.tier, assigned.tier, volume);
+            self.clients.write().unwrap().insert(client_id, assigned.clone());
+            changes.push(assigned);
+        }
+        Ok(changes)
+    }
+
+    /// 계정 등급 이력 (최신순)
+    pub async fn history(&self, client_id: &str, limit: i64) -> Result<Vec<ClientFeeTier>, FeeError> {
+        Ok(self
+            .repository
+            .find_by_client(client_id, limit)
+            .await?
+            .into_iter()
+            .map(ClientFeeTier::from_record)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::{ExecutionRecord, OrderRecord};
+    use crate::db::repository::{ExecutionRepository, OrderRepository};
+
+    const DAY: u64 = 24 * 60 * 60;
+    const NOW: u64 = 1_700_000_000;
+
+    fn config() -> FeeConfig {
+        FeeConfig {
+            tiers: vec![
+                FeeTier::new("VIP1", 1_000, 5, 10),
+                FeeTier::new("VIP0", 0, 10, 20),
+                FeeTier::new("VIP2", 10_000, -1, 5),
+            ],
+            ..FeeConfig::default()
+        }
+    }
+
+    async fn test_pool() -> SqlitePool {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    async fn trade(pool: &SqlitePool, exec_id: &str, taker: &str, maker: &str, notional: i64, age_secs: u64) {
+        let orders = OrderRepository::new(pool.clone());
+        for (order_id, client_id) in [(taker, taker.trim_end_matches(char::is_numeric)), (maker, maker.trim_end_matches(char::is_numeric))] {
+            let record = OrderRecord {
+                order_id: order_id.to_string(),
+                client_id: client_id.to_string(),
+                symbol: "BTC-KRW".to_string(),
+                side: "Buy".to_string(),
+                order_type: "Limit".to_string(),
+                price: Some(1),
+                quantity: notional,
+                filled_quantity: notional,
+                status: "Filled".to_string(),
+                tag: None,
+            };
+            orders.save(&record).await.unwrap();
+        }
+        let execution = ExecutionRecord {
+            exec_id: exec_id.to_string(),
+            taker_order_id: taker.to_string(),
+            maker_order_id: maker.to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: "Buy".to_string(),
+            price: 1,
+            quantity: notional,
+            taker_fee: 0,
+            maker_fee: 0,
+            transaction_time: (NOW - age_secs) as i64,
+        };
+        ExecutionRepository::new(pool.clone()).save(&execution).await.unwrap();
+    }
+
+    #[test]
+    fn test_schedule_and_fee_rounding() {
+        let schedule = FeeSchedule::new(config().tiers).unwrap();
+        assert_eq!(schedule.base().name, "VIP0");
+        assert_eq!(schedule.tier_for_volume(999).name, "VIP0");
+        assert_eq!(schedule.tier_for_volume(1_000).name, "VIP1");
+        assert_eq!(schedule.tier_for_volume(u64::MAX).name, "VIP2");
+
+        assert!(FeeSchedule::new(vec![FeeTier::new("VIP1", 1, 1, 1)]).is_err());
+        assert!(FeeSchedule::new(vec![FeeTier::new("A", 0, 1, 1), FeeTier::new("A", 5, 1, 1)]).is_err());
+
+        assert_eq!(fee_amount(12_345, 10), 12);
+        assert_eq!(fee_amount(12_345, -1), -1);
+        assert_eq!(
+            FeeTier::parse_list("VIP0:0:10:20, VIP1:1000:-2:8").unwrap(),
+            vec![FeeTier::new("VIP0", 0, 10, 20), FeeTier::new("VIP1", 1_000, -2, 8)]
+        );
+        assert!(FeeTier::parse_list("VIP0:0:10").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_recalculation_uses_rolling_volume_and_persists_changes() {
+        let pool = test_pool().await;
+        // alice: 30일 안 테이커 600 + 메이커 500, bob: 메이커 600, carol: 테이커 500 + 40일 전 20,000 (집계 제외)
+        trade(&pool, "e1", "alice1", "bob1", 600, DAY).await;
+        trade(&pool, "e2", "carol2", "alice2", 500, 10 * DAY).await;
+        trade(&pool, "e3", "bob3", "carol1", 20_000, 40 * DAY).await;
+
+        let engine = FeeEngine::load(config(), pool.clone()).await.unwrap();
+        let identity = |_: &str, amount: u64| Some(amount);
+        let changes = engine.recalculate(NOW, &identity).await.unwrap();
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!((changes[0].client_id.as_str(), changes[0].tier.as_str(), changes[0].volume_30d), ("alice", "VIP1", 1_100));
+        assert_eq!(engine.client_tier("bob").tier, "VIP0");
+        assert_eq!(
+            engine.execution_fees(Some("alice"), Some("bob"), 10_000),
+            ExecutionFees { maker_fee: 5, taker_fee: 20 }
+        );
+        // 같은 등급이면 이력을 다시 남기지 않음
+        assert!(engine.recalculate(NOW, &identity).await.unwrap().is_empty());
+
+        // 재시작 후 마지막 등급에서 이어가고, 거래가 빠지면 기본 등급으로 내려감
+        let engine = FeeEngine::load(config(), pool).await.unwrap();
+        assert_eq!(engine.client_tier("alice").tier, "VIP1");
+        let changes = engine.recalculate(NOW + 40 * DAY, &identity).await.unwrap();
+        assert_eq!(changes.iter().map(|c| c.tier.as_str()).collect::<Vec<_>>(), vec!["VIP0"]);
+        let history = engine.history("alice", 10).await.unwrap();
+        assert_eq!(history.iter().map(|c| c.tier.as_str()).collect::<Vec<_>>(), vec!["VIP0", "VIP1"]);
+    }
+}
@@ -0,0 +1,321 @@
+//! 무기한 상품용 주기적 펀딩 정산
+//!
+//! 정산 시각(Unix epoch 기준 `interval` 배수, 기본 8시간)마다 심볼별 펀딩 비율을
+//! `(최근 체결가 - 인덱스) ÷ 인덱스 + 이자율`로 계산하고 `±max_rate`로 제한합니다.
+//! 계정별 지급액은 `순포지션 × 공정 가격 × 펀딩 비율`이며, 비율이 양수면 롱이 숏에게, 음수면 숏이 롱에게 냅니다.
+//! 지급액은 호가 자산 최소 단위로 내림하므로 내는 쪽은 올림, 받는 쪽은 버림이 되어 끝수는 거래소에 남습니다.
+//!
+//! 정산 기록, 계정별 원장(`funding_payments`), 잔고(`balances`) 반영은 한 트랜잭션이고, 같은 심볼·정산 시각은
+//! 한 번만 정산합니다. 잔고가 부족해도 차감하므로 잔고가 음수가 될 수 있습니다 (증거금 엔진이 생기면 청산 기준).
+//! 정산 결과는 WebSocket `/ws` (`Funding` 메시지)와 Kafka `funding-events` 토픽으로 발행합니다.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::{info, warn};
+use sqlx::SqlitePool;
+use tokio::sync::broadcast;
+
+use crate::api::models::{FundingEvent, WebSocketMessage};
+use crate::db::models::{FundingPaymentRecord, FundingSettlementRecord};
+use crate::db::repository::FundingRepository;
+use crate::mq::KafkaProducer;
+
+/// 펀딩 정산 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct FundingConfig {
+    /// 정산 대상 심볼 (비어 있으면 정산하지 않음)
+    pub symbols: Vec<String>,
+    /// 정산 주기 (Unix epoch 기준 배수 시각에 정산)
+    pub interval: Duration,
+    /// 정산 주기당 이자율 (예: 0.0001 = 0.01%)
+    pub interest_rate: f64,
+    /// 펀딩 비율 절댓값 상한 (예: 0.0075 = 0.75%)
+    pub max_rate: f64,
+}
+
+impl Default for FundingConfig {
+    fn default() -> Self {
+        Self {
+            symbols: Vec::new(),
+            interval: Duration::from_secs(8 * 3600),
+            interest_rate: 0.0001,
+            max_rate: 0.0075,
+        }
+    }
+}
+
+impl FundingConfig {
+    /// 설정 검증
+    pub fn validate(&self) -> Result<(), String> {
+        if self.interval.as_secs() == 0 {
+            return Err("펀딩 정산 주기는 1초 이상이어야 합니다".to_string());
+        }
+        if self.max_rate.is_nan() || self.max_rate <= 0.0 {
+            return Err(format!("펀딩 비율 상한은 0보다 커야 합니다: {}", self.max_rate));
+        }
+        if !self.interest_rate.is_finite() {
+            return Err(format!("펀딩 이자율 형식 오류: {}", self.interest_rate));
+        }
+        Ok(())
+    }
+}
+
+/// 펀딩 정산 오류
+#[derive(Debug, thiserror::Error)]
+pub enum FundingError {
+    #[error("{symbol} 펀딩 정산 가격 오류: {reason}")]
+    InvalidPrice { symbol: String, reason: String },
+    #[error("펀딩 정산 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 심볼 한 번의 정산 입력
+#[derive(Debug, Clone)]
+pub struct FundingInputs {
+    pub symbol: String,
+    /// 정산 시각 (Unix 초)
+    pub funding_time: u64,
+    pub last_price: u64,
+    pub index_price: f64,
+    /// 지급액 계산 가격 (인덱스로 제한한 공정 가격)
+    pub mark_price: f64,
+    /// 지급/수령 자산 (심볼 호가 자산)
+    pub asset: String,
+    /// 계정별 순포지션
+    pub positions: BTreeMap<String, i64>,
+}
+
+/// 펀딩 비율 계산 (소수 8자리 반올림)
+pub fn funding_rate(last_price: u64, index_price: f64, config: &FundingConfig) -> f64 {
+    let premium = (last_price as f64 - index_price) / index_price;
+    let rate = (premium + config.interest_rate).clamp(-config.max_rate, config.max_rate);
+    (rate * 1e8).round() / 1e8
+}
+
+/// 계정별 지급액 (양수면 수령, 음수면 지급, 0은 제외)
+pub fn funding_payments(positions: &BTreeMap<String, i64>, mark_price: f64, rate: f64) -> Vec<(String, i64, i64)> {
+    positions
+        .iter()
+        .filter(|(_, position)| **position != 0)
+        .map(|(client_id, position)| {
+            // 부동소수점 오차로 정수 경계를 넘지 않도록 소수 6자리에서 반올림한 뒤 내림
+            let amount = ((-(*position as f64) * mark_price * rate * 1e6).round() / 1e6).floor() as i64;
+            (client_id.clone(), *position, amount)
+        })
+        .filter(|(_, _, amount)| *amount != 0)
+        .collect()
+}
+
+/// 펀딩 정산 서비스
+pub struct FundingService {
+    config: FundingConfig,
+    repository: FundingRepository,
+    publisher: Option<broadcast::Sender<WebSocketMessage>>,
+    kafka: Option<Arc<KafkaProducer>>,
+}
+
+impl FundingService {
+    pub fn new(config: FundingConfig, pool: SqlitePool) -> Self {
+        Self {
+            config,
+            repository: FundingRepository::new(pool),
+            publisher: None,
+            kafka: None,
+        }
+    }
+
+    /// 정산 결과를 발행할 WebSocket 채널
+    pub fn with_publisher(mut self, publisher: broadcast::Sender<WebSocketMessage>) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    /// 정산 결과를 발행할 Kafka Producer
+    pub fn with_kafka_producer(mut self, producer: Option<Arc<KafkaProducer>>) -> Self {
+        self.kafka = producer;
+        self
+    }
+
+    pub fn config(&self) -> &FundingConfig {
+        &self.config
+    }
+
+    /// 정산 대상 심볼인지
+    pub fn is_enabled(&self, symbol: &str) -> bool {
+        self.config.symbols.iter().any(|s| s == symbol)
+    }
+
+    /// `now_secs` 이후 첫 정산 시각 (Unix 초)
+    pub fn next_funding_time(&self, now_secs: u64) -> u64 {
+        let interval = self.config.interval.as_secs().max(1);
+        (now_secs / interval + 1) * interval
+    }
+
+    /// 심볼 한 번 정산 (이미 정산한 시각이면 None)
+    pub async fn settle(&self, inputs: FundingInputs) -> Result<Option<FundingEvent>, FundingError> {
+        if !(inputs.index_price.is_finite() && inputs.index_price > 0.0) {
+            return Err(FundingError::InvalidPrice {
+                symbol: inputs.symbol,
+                reason: format!("인덱스 가격 {}", inputs.index_price),
+            });
+        }
+        if !(inputs.mark_price.is_finite() && inputs.mark_price > 0.0) {
+            return Err(FundingError::InvalidPrice {
+                symbol: inputs.symbol,
+                reason: format!("공정 가격 {}", inputs.mark_price),
+            });
+        }
+
+        let rate = funding_rate(inputs.last_price, inputs.index_price, &self.config);
+        let payments: Vec<FundingPaymentRecord> = funding_payments(&inputs.positions, inputs.mark_price, rate)
+            .into_iter()
+            .map(|(client_id, position, amount)| FundingPaymentRecord {
+                symbol: inputs.symbol.clone(),
+                funding_time: inputs.funding_time as i64,
+                client_id,
+                asset: inputs.asset.clone(),
+                position,
+                amount,
+            })
+            .collect();
+        let settlement = FundingSettlementRecord {
+            symbol: inputs.symbol.clone(),
+            funding_time: inputs.funding_time as i64,
+            funding_rate: rate,
+            index_price: inputs.index_price,
+            last_price: inputs.last_price as i64,
+            mark_price: inputs.mark_price,
+            long_positions: inputs.positions.values().filter(|position| **position > 0).count() as i64,
+            short_positions: inputs.positions.values().filter(|position| **position < 0).count() as i64,
+            total_paid: payments.iter().filter(|p| p.amount < 0).map(|p| -p.amount).sum(),
+            total_received: payments.iter().filter(|p| p.amount > 0).map(|p| p.amount).sum(),
+        };
+
+        if !self.repository.settle(&settlement, &payments).await? {
+            warn!("{} 펀딩 정산 건너뜀: {} 시각은 이미 정산됨", inputs.symbol, inputs.funding_time);
+            return Ok(None);
+        }
+        let event = self.to_event(settlement);
+        info!(
+            "{} 펀딩 정산: 비율 {:.6}, 계정 {}개, 지급 {} / 수령 {} {}",
+            event.symbol,
+            event.funding_rate,
+            payments.len(),
+            event.total_paid,
+            event.total_received,
+            inputs.asset
+        );
+
+        if let Some(publisher) = &self.publisher {
+            let _ = publisher.send(WebSocketMessage::Funding(event.clone()));
+        }
+        if let Some(kafka) = &self.kafka {
+            if let Err(e) = kafka.publish_funding(&event).await {
+                warn!("{} 펀딩 정산 Kafka 발행 실패: {}", event.symbol, e);
+            }
+        }
+        Ok(Some(event))
+    }
+
+    /// 심볼의 최근 정산 (최신순)
+    pub async fn history(&self, symbol: &str, limit: usize) -> Result<Vec<FundingEvent>, FundingError> {
+        let records = self.repository.find_settlements(symbol, limit as i64).await?;
+        Ok(records.into_iter().map(|record| self.to_event(record)).collect())
+    }
+
+    fn to_event(&self, record: FundingSettlementRecord) -> FundingEvent {
+        let funding_time = record.funding_time as u64;
+        FundingEvent {
+            symbol: record.symbol,
+            funding_time,
+            funding_rate: record.funding_rate,
+            index_price: record.index_price,
+            last_price: record.last_price as u64,
+            mark_price: record.mark_price,
+            long_positions: record.long_positions as u64,
+            short_positions: record.short_positions as u64,
+            total_paid: record.total_paid as u64,
+            total_received: record.total_received as u64,
+            next_funding_time: funding_time + self.config.interval.as_secs(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::repository::BalanceRepository;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[test]
+    fn test_funding_rate_is_premium_plus_interest_clamped() {
+        let config = FundingConfig::default();
+        // 프리미엄 0.2% + 이자 0.01%
+        assert_eq!(funding_rate(1002, 1000.0, &config), 0.0021);
+        assert_eq!(funding_rate(1000, 1000.0, &config), 0.0001);
+        assert_eq!(funding_rate(1100, 1000.0, &config), 0.0075);
+        assert_eq!(funding_rate(900, 1000.0, &config), -0.0075);
+    }
+
+    #[test]
+    fn test_payers_round_up_and_receivers_round_down() {
+        let positions = BTreeMap::from([
+            ("long".to_string(), 3),
+            ("short".to_string(), -3),
+            ("flat".to_string(), 0),
+        ]);
+        // 3 × 1000.5 × 0.001 = 3.0015
+        let payments = funding_payments(&positions, 1000.5, 0.001);
+        assert_eq!(payments, vec![("long".to_string(), 3, -4), ("short".to_string(), -3, 3)]);
+
+        // 비율이 음수면 숏이 냄
+        let payments = funding_payments(&positions, 1000.0, -0.002);
+        assert_eq!(payments, vec![("long".to_string(), 3, 6), ("short".to_string(), -3, -6)]);
+    }
+
+    #[tokio::test]
+    async fn test_settlement_updates_balances_once() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        let config = FundingConfig {
+            symbols: vec!["BTC-PERP".to_string()],
+            ..FundingConfig::default()
+        };
+        let (tx, mut rx) = broadcast::channel(16);
+        let service = FundingService::new(config, pool.clone()).with_publisher(tx);
+        assert_eq!(service.next_funding_time(28_800), 57_600);
+        assert_eq!(service.next_funding_time(28_799), 28_800);
+
+        let inputs = FundingInputs {
+            symbol: "BTC-PERP".to_string(),
+            funding_time: 28_800,
+            last_price: 10_020,
+            index_price: 10_000.0,
+            mark_price: 10_000.0,
+            asset: "KRW".to_string(),
+            positions: BTreeMap::from([("alice".to_string(), 5), ("bob".to_string(), -5)]),
+        };
+        let event = service.settle(inputs.clone()).await.unwrap().unwrap();
+        // 비율 0.21% → 5 × 10,000 × 0.0021 = 105
+        assert_eq!(event.total_paid, 105);
+        assert_eq!(event.total_received, 105);
+        assert_eq!(event.next_funding_time, 57_600);
+        assert!(matches!(rx.try_recv(), Ok(WebSocketMessage::Funding(published)) if published == event));
+
+        // 같은 정산 시각은 다시 정산하지 않음
+        assert!(service.settle(inputs).await.unwrap().is_none());
+
+        let balances = BalanceRepository::new(pool.clone());
+        assert_eq!(balances.find_by_client("alice").await.unwrap()[0].available, -105);
+        assert_eq!(balances.find_by_client("bob").await.unwrap()[0].available, 105);
+        assert_eq!(service.history("BTC-PERP", 10).await.unwrap(), vec![event]);
+        let payments = FundingRepository::new(pool).find_payments("BTC-PERP", 28_800).await.unwrap();
+        assert_eq!(payments.iter().map(|p| p.amount).collect::<Vec<_>>(), vec![-105, 105]);
+    }
+}
@@ -0,0 +1,527 @@
+//! 만기 선물 상품 (만기 거래 중단, 인덱스 현금 정산, 상태 알림)
+//!
+//! 만기 상품은 기존 매칭 엔진의 일반 심볼로 거래하고, 심볼별 설정으로 만기 시각, 기초 인덱스 심볼,
+//! 정산 방식을 붙입니다. 자산 쌍은 기초 심볼을 따릅니다 (예: `BTC-KRW-2612` → BTC/KRW).
+//! 만기 `expiry_notice` 전에 만기 임박을 알리고, 만기 시각에 킬 스위치로 신규 주문을 막은 뒤
+//! 기초 심볼 인덱스 가격(유효 거래소 부족으로 직전 값을 유지 중이면 기다림)으로 현금 정산합니다.
+//!
+//! 계정별 정산금은 `순포지션 × 정산 가격 - 체결 대금`(체결 대금 = 매수 대금 - 매도 대금)이라
+//! 만기 전에 포지션을 정리한 계정의 실현 손익도 함께 정산되고, 모든 계정 정산금의 합은 0입니다.
+//! 포지션과 체결 대금은 체결 기록으로 계산하므로 체결 보존 기간은 상품 거래 기간보다 길어야 합니다.
+//! 정산 기록, 계정별 원장(`futures_settlement_payments`), 잔고(`balances`) 반영은 한 트랜잭션이고
+//! 심볼당 한 번만 정산합니다. 상태 변경은 WebSocket `/ws` (`ContractLifecycle` 메시지)로 발행합니다.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use log::{error, info, warn};
+use sqlx::SqlitePool;
+use tokio::sync::broadcast;
+
+use crate::api::models::{FuturesContractStatus, WebSocketMessage};
+use crate::currency::registry::AssetRegistry;
+use crate::currency::CurrencyError;
+use crate::db::models::{FuturesPositionRecord, FuturesSettlementPaymentRecord, FuturesSettlementRecord};
+use crate::db::repository::FuturesRepository;
+use crate::kill_switch::{KillSwitch, KillSwitchScope};
+
+// 만기 상품 상태는 WebSocket `ContractLifecycle` 메시지와 함께 xtrader-api 크레이트에 있음 (기존 경로 유지)
+pub use xtrader_api::models::{ContractState, SettlementMethod};
+
+/// 만기 거래 중단 킬 스위치 발동자
+pub const FUTURES_EXPIRY_ACTOR: &str = "futures_expiry";
+
+/// 만기 상품 메타데이터
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuturesContract {
+    /// 거래 심볼 (매칭 엔진 심볼에 포함돼 있어야 함)
+    pub symbol: String,
+    /// 기초 인덱스 심볼 (자산 쌍과 정산 가격 기준)
+    pub underlying: String,
+    /// 만기 시각 (Unix 초)
+    pub expiry: u64,
+    pub settlement_method: SettlementMethod,
+}
+
+impl FuturesContract {
+    /// `심볼:기초 심볼:만기 Unix 초[:정산 방식]` 목록 (쉼표 구분, 정산 방식 기본값 cash)
+    pub fn parse_list(spec: &str) -> Result<Vec<Self>, String> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                Self::parse(entry)
+                    .ok_or_else(|| format!("만기 상품 형식 오류: {} (예: BTC-KRW-2612:BTC-KRW:1798243200:cash)", entry))
+            })
+            .collect()
+    }
+
+    fn parse(entry: &str) -> Option<Self> {
+        let parts: Vec<&str> = entry.split(':').map(str::trim).collect();
+        let (symbol, underlying, expiry, method) = match parts.as_slice() {
+            [symbol, underlying, expiry] => (symbol, underlying, expiry, SettlementMethod::Cash),
+            [symbol, underlying, expiry, method] => (symbol, underlying, expiry, SettlementMethod::from_name(method)?),
+            _ => return None,
+        };
+        if symbol.is_empty() || underlying.is_empty() {
+            return None;
+        }
+        Some(Self {
+            symbol: symbol.to_string(),
+            underlying: underlying.to_string(),
+            expiry: expiry.parse().ok()?,
+            settlement_method: method,
+        })
+    }
+}
+
+/// 만기 상품 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuturesConfig {
+    /// 만기 상품 (비어 있으면 비활성화)
+    pub contracts: Vec<FuturesContract>,
+    /// 만기 임박 알림 시점 (만기 전)
+    pub expiry_notice: Duration,
+    /// 만기/정산 확인 주기
+    pub check_interval: Duration,
+}
+
+impl Default for FuturesConfig {
+    fn default() -> Self {
+        Self {
+            contracts: Vec::new(),
+            expiry_notice: Duration::from_secs(3600),
+            check_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl FuturesConfig {
+    /// 설정 검증
+    pub fn validate(&self) -> Result<(), String> {
+        if self.check_interval.is_zero() {
+            return Err("만기 확인 주기는 0보다 커야 합니다".to_string());
+        }
+        for (i, contract) in self.contracts.iter().enumerate() {
+            if contract.symbol == contract.underlying {
+                return Err(format!("만기 상품 {}의 기초 심볼이 자기 자신입니다", contract.symbol));
+            }
+            if contract.settlement_method == SettlementMethod::Physical {
+                return Err(format!("만기 상품 {}: 인도 정산은 아직 지원하지 않습니다", contract.symbol));
+            }
+            if self.contracts[..i].iter().any(|other| other.symbol == contract.symbol) {
+                return Err(format!("만기 상품 심볼 중복: {}", contract.symbol));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn contract(&self, symbol: &str) -> Option<&FuturesContract> {
+        self.contracts.iter().find(|contract| contract.symbol == symbol)
+    }
+}
+
+/// 만기 상품 오류
+#[derive(Debug, thiserror::Error)]
+pub enum FuturesError {
+    #[error("{symbol} 만기 정산 가격 오류: {reason}")]
+    InvalidPrice { symbol: String, reason: String },
+    #[error("만기 상품 자산 오류: {0}")]
+    Currency(#[from] CurrencyError),
+    #[error("만기 정산 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 계정별 정산금 (양수면 수령, 음수면 지급, 0은 제외): (계정, 순포지션, 체결 대금, 정산금)
+pub fn settlement_payments(positions: &[FuturesPositionRecord], settlement_price: i64) -> Vec<(String, i64, i64, i64)> {
+    positions
+        .iter()
+        .map(|p| {
+            let amount = p.net_quantity * settlement_price - p.net_cost;
+            (p.client_id.clone(), p.net_quantity, p.net_cost, amount)
+        })
+        .filter(|(_, _, _, amount)| *amount != 0)
+        .collect()
+}
+
+/// 만기 상품 상태 관리와 정산
+pub struct FuturesService {
+    config: FuturesConfig,
+    repository: FuturesRepository,
+    /// 심볼별 정산 자산 (기초 심볼 호가 자산)
+    assets: HashMap<String, String>,
+    /// 심볼별 현재 상태 (틱 처리 중 `.await` 전에 잠금을 놓으므로 `std::sync::RwLock`)
+    statuses: RwLock<HashMap<String, FuturesContractStatus>>,
+    kill_switch: Option<Arc<KillSwitch>>,
+    publisher: Option<broadcast::Sender<WebSocketMessage>>,
+}
+
+impl FuturesService {
+    /// 정산 완료 기록을 읽어 생성 (`now_ms`는 초기 상태 시각)
+    pub async fn load(
+        config: FuturesConfig,
+        registry: &AssetRegistry,
+        pool: SqlitePool,
+        now_ms: u64,
+    ) -> Result<Self, FuturesError> {
+        let repository = FuturesRepository::new(pool);
+        let settlements: HashMap<String, FuturesSettlementRecord> = repository
+            .find_settlements()
+            .await?
+            .into_iter()
+            .map(|record| (record.symbol.clone(), record))
+            .collect();
+
+        let mut assets = HashMap::new();
+        let mut statuses = HashMap::new();
+        for contract in &config.contracts {
+            assets.insert(contract.symbol.clone(), registry.quote_asset(&contract.underlying)?.code.clone());
+            let mut status = FuturesContractStatus {
+                symbol: contract.symbol.clone(),
+                underlying: contract.underlying.clone(),
+                expiry: contract.expiry,
+                settlement_method: contract.settlement_method,
+                state: ContractState::Active,
+                settlement_price: None,
+                settled_positions: None,
+                settled_at: None,
+                timestamp: now_ms,
+            };
+            if let Some(settlement) = settlements.get(&contract.symbol) {
+                apply_settlement(&mut status, settlement);
+            }
+            statuses.insert(contract.symbol.clone(), status);
+        }
+        info!(
+            "만기 상품 {}개 로드 (정산 완료 {}개)",
+            statuses.len(),
+            statuses.values().filter(|s| s.state == ContractState::Settled).count()
+        );
+
+        Ok(Self {
+            config,
+            repository,
+            assets,
+            statuses: RwLock::new(statuses),
+            kill_switch: None,
+            publisher: None,
+        })
+    }
+
+    /// 만기 시각에 거래를 중단할 킬 스위치
+    pub fn with_kill_switch(mut self, kill_switch: Arc<KillSwitch>) -> Self {
+        self.kill_switch = Some(kill_switch);
+        self
+    }
+
+    /// 상태 변경을 발행할 WebSocket 채널
+    pub fn with_publisher(mut self, publisher: broadcast::Sender<WebSocketMessage>) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    pub fn config(&self) -> &FuturesConfig {
+        &self.config
+    }
+
+    pub fn status(&self, symbol: &str) -> Option<FuturesContractStatus> {
+        self.statuses.read().unwrap().get(symbol).cloned()
+    }
+
+    /// 만기 상품 상태 (만기, 심볼순)
+    pub fn statuses(&self) -> Vec<FuturesContractStatus> {
+        let mut statuses: Vec<FuturesContractStatus> = self.statuses.read().unwrap().values().cloned().collect();
+        statuses.sort_by(|a, b| (a.expiry, &a.symbol).cmp(&(b.expiry, &b.symbol)));
+        statuses
+    }
+
+    /// 만기 임박/중단/정산 확인 (`index_price`: 기초 심볼의 유효 인덱스 가격)
+    pub async fn tick(&self, now_ms: u64, index_price: impl Fn(&str) -> Option<f64>) {
+        let now_secs = now_ms / 1000;
+        for contract in &self.config.contracts {
+            let Some(state) = self.status(&contract.symbol).map(|status| status.state) else {
+                continue;
+            };
+            if state == ContractState::Settled {
+                continue;
+            }
+
+            if now_secs < contract.expiry {
+                if state == ContractState::Active && now_secs + self.config.expiry_notice.as_secs() >= contract.expiry {
+                    info!("{} 만기 임박: {}초 남음", contract.symbol, contract.expiry - now_secs);
+                    self.transition(&contract.symbol, ContractState::Expiring, now_ms);
+                }
+                continue;
+            }
+
+            if state != ContractState::Halted {
+                self.halt(contract).await;
+                self.transition(&contract.symbol, ContractState::Halted, now_ms);
+            }
+            let Some(index) = index_price(&contract.underlying) else {
+                continue;
+            };
+            if let Err(e) = self.settle(contract, index, now_ms).await {
+                error!("{} 만기 정산 실패: {}", contract.symbol, e);
+            }
+        }
+    }
+
+    /// 만기 거래 중단 (이미 중단된 심볼은 그대로 둠)
+    async fn halt(&self, contract: &FuturesContract) {
+        warn!("{} 만기 도래: 거래 중단, {} 인덱스로 정산 대기", contract.symbol, contract.underlying);
+        let Some(kill_switch) = &self.kill_switch else {
+            return;
+        };
+        if kill_switch.is_symbol_halted(&contract.symbol) {
+            return;
+        }
+        let reason = format!("만기 도래 ({})", contract.expiry);
+        if let Err(e) = kill_switch
+            .activate(KillSwitchScope::Symbol, &contract.symbol, Some(reason), FUTURES_EXPIRY_ACTOR)
+            .await
+        {
+            error!("{} 만기 거래 중단 실패: {}", contract.symbol, e);
+        }
+    }
+
+    /// 인덱스 가격으로 현금 정산 (이미 정산된 심볼이면 None)
+    async fn settle(
+        &self,
+        contract: &FuturesContract,
+        index_price: f64,
+        now_ms: u64,
+    ) -> Result<Option<FuturesContractStatus>, FuturesError> {
+        if !(index_price.is_finite() && index_price >= 0.5) {
+            return Err(FuturesError::InvalidPrice {
+                symbol: contract.symbol.clone(),
+                reason: format!("인덱스 가격 {}", index_price),
+            });
+        }
+        let settlement_price = index_price.round() as i64;
+        let asset = self.assets[&contract.symbol].clone();
+        let positions = self.repository.positions(&contract.symbol).await?;
+        let payments: Vec<FuturesSettlementPaymentRecord> = settlement_payments(&positions, settlement_price)
+            .into_iter()
+            .map(|(client_id, position, net_cost, amount)| FuturesSettlementPaymentRecord {
+                symbol: contract.symbol.clone(),
+                client_id,
+                asset: asset.clone(),
+                position,
+                net_cost,
+                amount,
+            })
+            .collect();
+        let settlement = FuturesSettlementRecord {
+            symbol: contract.symbol.clone(),
+            underlying: contract.underlying.clone(),
+            expiry: contract.expiry as i64,
+            settlement_price,
+            index_price,
+            positions: positions.iter().filter(|p| p.net_quantity != 0).count() as i64,
+            total_paid: payments.iter().filter(|p| p.amount < 0).map(|p| -p.amount).sum(),
+            total_received: payments.iter().filter(|p| p.amount > 0).map(|p| p.amount).sum(),
+            settled_at: (now_ms / 1000) as i64,
+        };
+
+        if !self.repository.settle(&settlement, &payments).await? {
+            warn!("{} 만기 정산 건너뜀: 이미 정산됨", contract.symbol);
+            return Ok(None);
+        }
+        info!(
+            "{} 만기 정산: 가격 {}, 계정 {}개, 지급 {} / 수령 {} {}",
+            contract.symbol,
+            settlement_price,
+            payments.len(),
+            settlement.total_paid,
+            settlement.total_received,
+            asset
+        );
+
+        let status = {
+            let mut statuses = self.statuses.write().unwrap();
+            let Some(status) = statuses.get_mut(&contract.symbol) else {
+                return Ok(None);
+            };
+            apply_settlement(status, &settlement);
+            status.timestamp = now_ms;
+            status.clone()
+        };
+        self.publish(&status);
+        Ok(Some(status))
+    }
+
+    fn transition(&self, symbol: &str, state: ContractState, now_ms: u64) {
+        let status = {
+            let mut statuses = self.statuses.write().unwrap();
+            let Some(status) = statuses.get_mut(symbol) else {
+                return;
+            };
+            status.state = state;
+            status.timestamp = now_ms;
+            status.clone()
+        };
+        self.publish(&status);
+    }
+
+    fn publish(&self, status: &FuturesContractStatus) {
+        if let Some(publisher) = &self.publisher {
+            let _ = publisher.send(WebSocketMessage::ContractLifecycle(status.clone()));
+        }
+    }
+}
+
+fn apply_settlement(status: &mut FuturesContractStatus, settlement: &FuturesSettlementRecord) {
+    status.state = ContractState::Settled;
+    status.settlement_price = Some(settlement.settlement_price as u64);
+    status.settled_positions = Some(settlement.positions as u64);
+    status.settled_at = Some(settlement.settled_at as u64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::{ExecutionRecord, OrderRecord};
+    use crate::db::repository::{BalanceRepository, ExecutionRepository, OrderRepository};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    /// 2026-12-25 00:00:00 UTC
+    const EXPIRY: u64 = 1_798_156_800;
+
+    fn contract() -> FuturesContract {
+        FuturesContract {
+            symbol: "BTC-KRW-2612".to_string(),
+            underlying: "BTC-KRW".to_string(),
+            expiry: EXPIRY,
+            settlement_method: SettlementMethod::Cash,
+        }
+    }
+
+    #[test]
+    fn test_parse_and_validate_contracts() {
+        let contracts = FuturesContract::parse_list("BTC-KRW-2612:BTC-KRW:1798156800, ETH-KRW-2612:ETH-KRW:1798156800:cash").unwrap();
+        assert_eq!(contracts[0], contract());
+        assert_eq!(contracts[1].underlying, "ETH-KRW");
+        assert!(FuturesContract::parse_list("BTC-KRW-2612:BTC-KRW").is_err());
+        assert!(FuturesContract::parse_list("BTC-KRW-2612:BTC-KRW:1798156800:swap").is_err());
+
+        let config = |contracts| FuturesConfig { contracts, ..FuturesConfig::default() };
+        assert!(config(contracts.clone()).validate().is_ok());
+        assert!(config(vec![contract(), contract()]).validate().is_err());
+        let physical = FuturesContract { settlement_method: SettlementMethod::Physical, ..contract() };
+        assert!(config(vec![physical]).validate().is_err());
+    }
+
+    #[test]
+    fn test_settlement_includes_realized_pnl_and_sums_to_zero() {
+        let position = |client_id: &str, net_quantity, net_cost| FuturesPositionRecord {
+            client_id: client_id.to_string(),
+            net_quantity,
+            net_cost,
+        };
+        // alice는 100에 2개 매수, bob은 90에 1개 매도, carol은 110에 1개 매도, dave는 정리 완료
+        let positions = vec![
+            position("alice", 2, 200),
+            position("bob", -1, -90),
+            position("carol", -1, -110),
+            position("dave", 0, 0),
+        ];
+        let payments = settlement_payments(&positions, 120);
+        assert_eq!(
+            payments,
+            vec![
+                ("alice".to_string(), 2, 200, 40),
+                ("bob".to_string(), -1, -90, -30),
+                ("carol".to_string(), -1, -110, -10),
+            ]
+        );
+        assert_eq!(payments.iter().map(|(_, _, _, amount)| amount).sum::<i64>(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_lifecycle_halts_and_settles_once_at_expiry() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        let orders = OrderRepository::new(pool.clone());
+        for (order_id, client_id, side) in [("b1", "alice", "Buy"), ("s1", "bob", "Sell")] {
+            orders
+                .save(&OrderRecord {
+                    order_id: order_id.to_string(),
+                    client_id: client_id.to_string(),
+                    symbol: "BTC-KRW-2612".to_string(),
+                    side: side.to_string(),
+                    order_type: "Limit".to_string(),
+                    price: Some(100),
+                    quantity: 3,
+                    filled_quantity: 3,
+                    status: "Filled".to_string(),
+                    tag: None,
+                })
+                .await
+                .unwrap();
+        }
+        ExecutionRepository::new(pool.clone())
+            .save(&ExecutionRecord {
+                exec_id: "e1".to_string(),
+                taker_order_id: "b1".to_string(),
+                maker_order_id: "s1".to_string(),
+                symbol: "BTC-KRW-2612".to_string(),
+                side: "Buy".to_string(),
+                price: 100,
+                quantity: 3,
+                taker_fee: 0,
+                maker_fee: 0,
+                transaction_time: 1_700_000_000,
+            })
+            .await
+            .unwrap();
+
+        let config = FuturesConfig { contracts: vec![contract()], ..FuturesConfig::default() };
+        let registry = AssetRegistry::default();
+        let kill_switch = Arc::new(KillSwitch::load(pool.clone()).await.unwrap());
+        let (tx, mut rx) = broadcast::channel(16);
+        let service = FuturesService::load(config.clone(), &registry, pool.clone(), 0)
+            .await
+            .unwrap()
+            .with_kill_switch(kill_switch.clone())
+            .with_publisher(tx);
+        let state = |rx: &mut broadcast::Receiver<WebSocketMessage>| match rx.try_recv() {
+            Ok(WebSocketMessage::ContractLifecycle(status)) => Some(status.state),
+            _ => None,
+        };
+
+        // 만기 한 시간 전까지는 그대로, 이후 만기 임박
+        service.tick((EXPIRY - 3_601) * 1000, |_| Some(110.0)).await;
+        assert_eq!(state(&mut rx), None);
+        service.tick((EXPIRY - 3_600) * 1000, |_| Some(110.0)).await;
+        assert_eq!(state(&mut rx), Some(ContractState::Expiring));
+
+        // 만기에 인덱스가 없으면 거래만 중단하고 정산 대기
+        service.tick(EXPIRY * 1000, |_| None).await;
+        assert_eq!(state(&mut rx), Some(ContractState::Halted));
+        assert!(kill_switch.is_symbol_halted("BTC-KRW-2612"));
+        service.tick(EXPIRY * 1000 + 1_000, |_| None).await;
+        assert_eq!(state(&mut rx), None);
+
+        service.tick(EXPIRY * 1000 + 2_000, |_| Some(109.6)).await;
+        assert_eq!(state(&mut rx), Some(ContractState::Settled));
+        let status = service.status("BTC-KRW-2612").unwrap();
+        assert_eq!((status.settlement_price, status.settled_positions), (Some(110), Some(2)));
+        service.tick(EXPIRY * 1000 + 3_000, |_| Some(200.0)).await;
+        assert_eq!(state(&mut rx), None);
+
+        // 3 × (110 - 100) = 30
+        let balances = BalanceRepository::new(pool.clone());
+        assert_eq!(balances.find_by_client("alice").await.unwrap()[0].available, 30);
+        assert_eq!(balances.find_by_client("bob").await.unwrap()[0].available, -30);
+
+        // 다시 시작해도 정산 완료 상태
+        let reloaded = FuturesService::load(config, &registry, pool, 0).await.unwrap();
+        assert_eq!(reloaded.status("BTC-KRW-2612").unwrap().state, ContractState::Settled);
+    }
+}
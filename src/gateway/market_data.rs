@@ -0,0 +1,180 @@
+//! 인스턴스 시장 데이터 스트림 통합
+//!
+//! 각 인스턴스의 `/ws` 에 연결해 받은 메시지를 하나의 브로드캐스트 채널로 합칩니다.
+//! 인스턴스가 담당하지 않는 심볼의 메시지는 버리고(설정 오류로 인한 중복 호가 방지),
+//! 인스턴스별로 나뉘어 오는 티커는 전 심볼 티커 하나로 합쳐 다시 발행합니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use futures_util::{SinkExt, StreamExt};
+use log::{debug, info, warn};
+use tokio::sync::broadcast;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use crate::api::models::{TickerData, WebSocketMessage};
+use crate::gateway::routing::{GatewayInstance, SymbolRoutingTable};
+
+/// 메시지의 심볼 (심볼이 없는 메시지는 None)
+fn message_symbol(message: &WebSocketMessage) -> Option<&str> {
+    match message {
+        WebSocketMessage::Execution { execution_report, .. } => Some(&execution_report.symbol),
+        WebSocketMessage::PrivateEvent(event) => Some(&event.execution_report.symbol),
+        WebSocketMessage::OrderBookDelta(delta) => Some(&delta.symbol),
+        WebSocketMessage::OrderBookSnapshot(snapshot) => Some(&snapshot.symbol),
+        WebSocketMessage::Bbo(bbo) => Some(&bbo.symbol),
+        WebSocketMessage::IndexPrice(index) => Some(&index.symbol),
+        WebSocketMessage::Funding(funding) => Some(&funding.symbol),
+        WebSocketMessage::ContractLifecycle(contract) => Some(&contract.symbol),
+        WebSocketMessage::OrderBookUpdate { symbol, .. }
+        | WebSocketMessage::MarketStatistics { symbol, .. }
+        | WebSocketMessage::CandlestickUpdate { symbol, .. }
+        | WebSocketMessage::SyncResponse { symbol, .. } => Some(symbol),
+        WebSocketMessage::Ticker { .. }
+        | WebSocketMessage::ThrottleUpdate(_)
+        | WebSocketMessage::ReplayComplete { .. }
+        | WebSocketMessage::Error { .. } => None,
+    }
+}
+
+/// 시장 데이터 통합기
+pub struct MarketDataAggregator {
+    routing: Arc<SymbolRoutingTable>,
+    tx: broadcast::Sender<WebSocketMessage>,
+    /// 심볼별 최신 티커
+    tickers: Mutex<BTreeMap<String, TickerData>>,
+    /// 인스턴스별 연결 상태
+    connected: Mutex<HashMap<String, bool>>,
+}
+
+impl MarketDataAggregator {
+    pub fn new(routing: Arc<SymbolRoutingTable>, capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity);
+        Self {
+            routing,
+            tx,
+            tickers: Mutex::new(BTreeMap::new()),
+            connected: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 통합 스트림 구독
+    pub fn subscribe(&self) -> broadcast::Receiver<WebSocketMessage> {
+        self.tx.subscribe()
+    }
+
+    /// 인스턴스 시장 데이터 연결 여부
+    pub fn is_connected(&self, instance: &str) -> bool {
+        self.connected.lock().unwrap().get(instance).copied().unwrap_or(false)
+    }
+
+    /// 인스턴스에서 받은 메시지 발행
+    pub fn publish(&self, instance: &GatewayInstance, message: WebSocketMessage) {
+        let owned = |symbol: &str| self.routing.owner(symbol).is_some_and(|owner| owner.name == instance.name);
+
+        let message = match message {
+            WebSocketMessage::Ticker { timestamp, tickers } => {
+                let mut merged = self.tickers.lock().unwrap();
+                for ticker in tickers.into_iter().filter(|ticker| owned(&ticker.symbol)) {
+                    merged.insert(ticker.symbol.clone(), ticker);
+                }
+                WebSocketMessage::Ticker { timestamp, tickers: merged.values().cloned().collect() }
+            }
+            message => {
+                if let Some(symbol) = message_symbol(&message) {
+                    if !owned(symbol) {
+                        debug!("게이트웨이: {}이(가) 담당하지 않는 심볼 {} 메시지 무시", instance.name, symbol);
+                        return;
+                    }
+                }
+                message
+            }
+        };
+        let _ = self.tx.send(message);
+    }
+
+    /// 인스턴스마다 WebSocket 연결 태스크 시작 (끊기면 재연결)
+    pub fn spawn_upstreams(self: &Arc<Self>, reconnect_delay: Duration) {
+        for instance in self.routing.instances().to_vec() {
+            let aggregator = self.clone();
+            tokio::spawn(async move {
+                loop {
+                    if let Err(e) = aggregator.follow(&instance).await {
+                        warn!("게이트웨이: {} 시장 데이터 연결 실패 - {}", instance.name, e);
+                    }
+                    aggregator.connected.lock().unwrap().insert(instance.name.clone(), false);
+                    tokio::time::sleep(reconnect_delay).await;
+                }
+            });
+        }
+    }
+
+    async fn follow(&self, instance: &GatewayInstance) -> Result<(), String> {
+        let url = instance.ws_url();
+        let (mut socket, _) = connect_async(url.as_str()).await.map_err(|e| e.to_string())?;
+        self.connected.lock().unwrap().insert(instance.name.clone(), true);
+        info!("게이트웨이: {} 시장 데이터 연결 ({})", instance.name, url);
+
+        while let Some(frame) = socket.next().await {
+            match frame.map_err(|e| e.to_string())? {
+                Message::Text(text) => match serde_json::from_str::<WebSocketMessage>(&text) {
+                    Ok(message) => self.publish(instance, message),
+                    Err(e) => debug!("게이트웨이: {} 메시지 해석 실패 - {}", instance.name, e),
+                },
+                Message::Ping(payload) => socket.send(Message::Pong(payload)).await.map_err(|e| e.to_string())?,
+                Message::Close(_) => break,
+                _ => {}
+            }
+        }
+        info!("게이트웨이: {} 시장 데이터 연결 종료", instance.name);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instance(name: &str, symbols: &[&str]) -> GatewayInstance {
+        GatewayInstance {
+            name: name.to_string(),
+            base_url: format!("http://{}:7000", name),
+            symbols: symbols.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn ticker(symbol: &str, last_price: u64) -> TickerData {
+        TickerData {
+            symbol: symbol.to_string(),
+            last_price: Some(last_price),
+            open_price_24h: None,
+            high_price_24h: None,
+            low_price_24h: None,
+            volume_24h: 0,
+            trade_count_24h: 0,
+            price_change_pct_24h: None,
+        }
+    }
+
+    #[test]
+    fn test_merges_tickers_and_drops_unowned_symbols() {
+        let a = instance("a", &["BTC-KRW"]);
+        let b = instance("b", &["AAPL"]);
+        let routing = Arc::new(SymbolRoutingTable::new(vec![a.clone(), b.clone()], 10).unwrap());
+        let aggregator = MarketDataAggregator::new(routing, 16);
+        let mut rx = aggregator.subscribe();
+
+        aggregator.publish(&a, WebSocketMessage::Ticker { timestamp: 1, tickers: vec![ticker("BTC-KRW", 100), ticker("AAPL", 1)] });
+        aggregator.publish(&b, WebSocketMessage::Ticker { timestamp: 2, tickers: vec![ticker("AAPL", 200)] });
+        aggregator.publish(
+            &b,
+            WebSocketMessage::OrderBookUpdate { symbol: "BTC-KRW".to_string(), bids: vec![], asks: vec![], timestamp: 3 },
+        );
+
+        let Ok(WebSocketMessage::Ticker { tickers, .. }) = rx.try_recv() else { panic!("티커 없음") };
+        assert_eq!(tickers, vec![ticker("BTC-KRW", 100)]);
+        let Ok(WebSocketMessage::Ticker { tickers, .. }) = rx.try_recv() else { panic!("티커 없음") };
+        assert_eq!(tickers, vec![ticker("AAPL", 200), ticker("BTC-KRW", 100)]);
+        assert!(rx.try_recv().is_err());
+    }
+}
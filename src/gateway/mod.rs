@@ -0,0 +1,13 @@
+//! 심볼 기반 멀티 인스턴스 게이트웨이
+//!
+//! 여러 xTrader 인스턴스가 심볼을 나눠 맡을 때, 클라이언트 앞에서 단일 API를 제공하는
+//! 라우팅 계층입니다. 주문은 심볼 담당 인스턴스로 HTTP 전달하고, 각 인스턴스의 시장 데이터
+//! WebSocket 스트림은 하나로 합쳐 제공합니다.
+
+pub mod routing;
+pub mod market_data;
+pub mod proxy;
+
+pub use routing::{GatewayConfig, GatewayInstance, RoutingError, SymbolRoutingTable};
+pub use market_data::MarketDataAggregator;
+pub use proxy::{create_gateway_router, run_gateway, GatewayState};
@@ -0,0 +1,456 @@
+//! 게이트웨이 REST/WebSocket API
+//!
+//! 클라이언트에는 단일 인스턴스와 같은 주문/시장 데이터 API를 제공합니다.
+//! - 주문, 심볼 경로 조회: 심볼 담당 인스턴스로 HTTP 전달 (응답은 그대로 반환)
+//! - 취소, 주문 상태 조회: 게이트웨이가 기억하는 담당 인스턴스로, 모르면 모든 인스턴스에 조회
+//! - 전 심볼 티커: 모든 인스턴스 결과를 합쳐 반환
+//! - `/ws`: 모든 인스턴스 시장 데이터를 합친 스트림
+//! - `/readyz`: 모든 인스턴스가 준비되고 시장 데이터가 연결돼 있어야 준비됨
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use axum::{
+    body::{Body, Bytes},
+    extract::{ws::WebSocketUpgrade, Path, Query, State},
+    http::{header, StatusCode, Uri},
+    response::{IntoResponse, Json, Response},
+    routing::{get, post},
+    Router,
+};
+use log::{info, warn};
+use reqwest::Method;
+use serde_json::Value;
+use tower_http::cors::CorsLayer;
+use tower_http::trace::TraceLayer;
+
+use crate::api::error::{ApiError, ErrorCode};
+use crate::api::handlers::liveness;
+use crate::api::models::TickerResponse;
+use crate::api::websocket::{serve_connection, WebSocketChannel};
+use crate::api::{WebSocketConfig, WebSocketMetrics};
+use crate::gateway::market_data::MarketDataAggregator;
+use crate::gateway::routing::{GatewayConfig, GatewayInstance, SymbolRoutingTable};
+use crate::monitoring::readiness::{ReadinessCheck, ReadinessReport};
+
+/// 인스턴스 응답 (상태 코드와 본문을 그대로 클라이언트에 전달)
+struct UpstreamResponse {
+    status: u16,
+    content_type: Option<String>,
+    body: Bytes,
+}
+
+impl UpstreamResponse {
+    /// 본문 JSON의 문자열 필드
+    fn json_field(&self, field: &str) -> Option<String> {
+        let value: Value = serde_json::from_slice(&self.body).ok()?;
+        value.get(field)?.as_str().map(str::to_string)
+    }
+}
+
+impl IntoResponse for UpstreamResponse {
+    fn into_response(self) -> Response {
+        let mut builder = Response::builder().status(StatusCode::from_u16(self.status).unwrap_or(StatusCode::BAD_GATEWAY));
+        if let Some(content_type) = self.content_type {
+            builder = builder.header(header::CONTENT_TYPE, content_type);
+        }
+        builder.body(Body::from(self.body)).unwrap_or_else(|_| StatusCode::BAD_GATEWAY.into_response())
+    }
+}
+
+/// 게이트웨이 상태
+#[derive(Clone)]
+pub struct GatewayState {
+    routing: Arc<SymbolRoutingTable>,
+    client: reqwest::Client,
+    market_data: Arc<MarketDataAggregator>,
+    ws_config: WebSocketConfig,
+    ws_metrics: Arc<WebSocketMetrics>,
+}
+
+impl GatewayState {
+    pub fn new(config: &GatewayConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        let routing = Arc::new(SymbolRoutingTable::new(config.instances.clone(), config.order_cache_size)?);
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.request_timeout_ms))
+            .build()?;
+        Ok(Self {
+            market_data: Arc::new(MarketDataAggregator::new(routing.clone(), 10_000)),
+            routing,
+            client,
+            ws_config: WebSocketConfig::default(),
+            ws_metrics: Arc::new(WebSocketMetrics::new()),
+        })
+    }
+
+    /// 인스턴스로 요청 전달
+    async fn forward(
+        &self,
+        instance: &GatewayInstance,
+        method: Method,
+        path_and_query: &str,
+        body: Option<Bytes>,
+    ) -> Result<UpstreamResponse, ApiError> {
+        let url = format!("{}{}", instance.base_url.trim_end_matches('/'), path_and_query);
+        let mut request = self.client.request(method, &url);
+        if let Some(body) = body {
+            request = request.header(reqwest::header::CONTENT_TYPE, "application/json").body(body);
+        }
+
+        let unavailable = |e: reqwest::Error| {
+            warn!("게이트웨이: {} 요청 실패 ({}) - {}", instance.name, url, e);
+            ApiError::new(ErrorCode::ServiceUnavailable, format!("인스턴스 {}에 연결할 수 없습니다", instance.name))
+        };
+        let response = request.send().await.map_err(unavailable)?;
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = response.bytes().await.map_err(unavailable)?;
+        Ok(UpstreamResponse { status, content_type, body })
+    }
+
+    /// 주문 ID로 요청 전달
+    ///
+    /// 게이트웨이를 거친 주문은 담당 인스턴스로 바로 보내고, 기억하지 못하는 주문(게이트웨이 재시작 등)은
+    /// 모든 인스턴스에 차례로 보내 404가 아닌 첫 응답을 사용합니다.
+    async fn forward_by_order(
+        &self,
+        order_id: &str,
+        method: Method,
+        path_and_query: &str,
+        body: Option<Bytes>,
+    ) -> Result<UpstreamResponse, ApiError> {
+        if let Some(instance) = self.routing.order_owner(order_id) {
+            return self.forward(instance, method, path_and_query, body).await;
+        }
+
+        let mut unreachable = None;
+        for instance in self.routing.instances() {
+            match self.forward(instance, method.clone(), path_and_query, body.clone()).await {
+                Ok(response) if response.status == StatusCode::NOT_FOUND.as_u16() => {}
+                Ok(response) => {
+                    if response.status == StatusCode::OK.as_u16() {
+                        self.routing.remember_order(order_id, instance);
+                    }
+                    return Ok(response);
+                }
+                Err(e) => unreachable = Some(e),
+            }
+        }
+        // 응답하지 않은 인스턴스에 주문이 있을 수 있으므로 404 대신 연결 오류 반환
+        Err(unreachable.unwrap_or_else(|| ApiError::order_not_found(order_id)))
+    }
+}
+
+/// 게이트웨이 라우터 생성
+pub fn create_gateway_router() -> Router<GatewayState> {
+    Router::new()
+        .route("/v1/order", post(route_order))
+        .route("/v1/order/cancel", post(route_cancel))
+        .route("/v1/order/:order_id", get(route_order_status))
+        .route("/api/v1/orderbook/:symbol", get(route_by_symbol))
+        .route("/api/v1/executions/:symbol", get(route_by_symbol))
+        .route("/api/v1/statistics/:symbol", get(route_by_symbol))
+        .route("/api/v1/klines/:symbol/:interval", get(route_by_symbol))
+        .route("/v1/market/:symbol/microstructure", get(route_by_symbol))
+        .route("/v1/market/:symbol/impact", get(route_by_symbol))
+        .route("/api/v1/sync/:symbol", get(route_by_symbol))
+        .route("/v1/ticker", get(aggregate_tickers))
+        .route("/healthz", get(liveness))
+        .route("/readyz", get(gateway_readiness))
+        .route("/ws", get(gateway_websocket))
+}
+
+/// 요청 본문 JSON의 문자열 필드
+fn body_field(body: &Bytes, field: &str) -> Result<String, ApiError> {
+    serde_json::from_slice::<Value>(body)
+        .ok()
+        .and_then(|value| value.get(field)?.as_str().map(str::to_string))
+        .ok_or_else(|| ApiError::new(ErrorCode::InvalidRequest, format!("{} 필드가 필요합니다", field)))
+}
+
+/// 주문 제출: 심볼 담당 인스턴스로 전달
+async fn route_order(State(state): State<GatewayState>, body: Bytes) -> Result<Response, ApiError> {
+    let symbol = body_field(&body, "symbol")?;
+    let instance = state
+        .routing
+        .owner(&symbol)
+        .ok_or_else(|| ApiError::new(ErrorCode::InvalidSymbol, format!("지원하지 않는 심볼입니다: {}", symbol)))?;
+
+    let response = state.forward(instance, Method::POST, "/v1/order", Some(body)).await?;
+    if response.status == StatusCode::OK.as_u16() {
+        if let Some(order_id) = response.json_field("order_id") {
+            state.routing.remember_order(&order_id, instance);
+        }
+    }
+    Ok(response.into_response())
+}
+
+/// 주문 취소: 주문 담당 인스턴스로 전달
+async fn route_cancel(State(state): State<GatewayState>, body: Bytes) -> Result<Response, ApiError> {
+    let order_id = body_field(&body, "order_id")?;
+    let response = state.forward_by_order(&order_id, Method::POST, "/v1/order/cancel", Some(body)).await?;
+    Ok(response.into_response())
+}
+
+/// 주문 상태 조회: 주문 담당 인스턴스로 전달
+async fn route_order_status(
+    State(state): State<GatewayState>,
+    Path(order_id): Path<String>,
+    uri: Uri,
+) -> Result<Response, ApiError> {
+    let path = uri.path_and_query().map_or(uri.path(), |pq| pq.as_str());
+    let response = state.forward_by_order(&order_id, Method::GET, path, None).await?;
+    Ok(response.into_response())
+}
+
+/// 심볼 경로 조회: 심볼 담당 인스턴스로 전달 (쿼리 포함)
+async fn route_by_symbol(
+    State(state): State<GatewayState>,
+    Path(params): Path<HashMap<String, String>>,
+    uri: Uri,
+) -> Result<Response, ApiError> {
+    let symbol = params.get("symbol").map(String::as_str).unwrap_or_default();
+    let instance = state.routing.owner(symbol).ok_or_else(|| ApiError::symbol_not_found(symbol))?;
+    let path = uri.path_and_query().map_or(uri.path(), |pq| pq.as_str());
+    Ok(state.forward(instance, Method::GET, path, None).await?.into_response())
+}
+
+/// 티커 조회: 심볼 지정 시 담당 인스턴스로 전달, 아니면 모든 인스턴스 결과 병합
+async fn aggregate_tickers(
+    State(state): State<GatewayState>,
+    Query(params): Query<HashMap<String, String>>,
+    uri: Uri,
+) -> Result<Response, ApiError> {
+    if let Some(symbol) = params.get("symbol") {
+        let instance = state.routing.owner(symbol).ok_or_else(|| ApiError::symbol_not_found(symbol))?;
+        let path = uri.path_and_query().map_or(uri.path(), |pq| pq.as_str());
+        return Ok(state.forward(instance, Method::GET, path, None).await?.into_response());
+    }
+
+    let instances = state.routing.instances();
+    let responses = futures::future::join_all(
+        instances.iter().map(|instance| state.forward(instance, Method::GET, "/v1/ticker", None)),
+    )
+    .await;
+
+    let mut tickers = Vec::new();
+    for (instance, response) in instances.iter().zip(responses) {
+        let parsed = response.ok().and_then(|response| serde_json::from_slice::<TickerResponse>(&response.body).ok());
+        match parsed {
+            // 인스턴스가 담당하지 않는 심볼의 빈 티커는 제외
+            Some(response) => tickers.extend(
+                response
+                    .tickers
+                    .into_iter()
+                    .filter(|ticker| state.routing.owner(&ticker.symbol).is_some_and(|owner| owner.name == instance.name)),
+            ),
+            None => warn!("게이트웨이: {} 티커 조회 실패, 해당 심볼 제외", instance.name),
+        }
+    }
+    tickers.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+
+    Ok(Json(TickerResponse {
+        timestamp: chrono::Utc::now().timestamp_millis() as u64,
+        tickers,
+    })
+    .into_response())
+}
+
+/// 준비 상태: 모든 인스턴스 `/readyz` 통과, 시장 데이터 연결
+async fn gateway_readiness(State(state): State<GatewayState>) -> Response {
+    let instances = state.routing.instances();
+    let responses = futures::future::join_all(
+        instances.iter().map(|instance| state.forward(instance, Method::GET, "/readyz", None)),
+    )
+    .await;
+
+    let checks: Vec<ReadinessCheck> = instances
+        .iter()
+        .zip(responses)
+        .map(|(instance, response)| {
+            let market_data = state.market_data.is_connected(&instance.name);
+            let (instance_ready, detail) = match response {
+                Ok(response) => (
+                    response.status == StatusCode::OK.as_u16(),
+                    format!("/readyz {}", response.status),
+                ),
+                Err(e) => (false, e.message),
+            };
+            ReadinessCheck {
+                name: instance.name.clone(),
+                ok: instance_ready && market_data,
+                detail: format!("{}, 시장 데이터 {}", detail, if market_data { "연결됨" } else { "끊김" }),
+            }
+        })
+        .collect();
+
+    let report = ReadinessReport {
+        ready: checks.iter().all(|check| check.ok),
+        checks,
+        timestamp: chrono::Utc::now().timestamp_millis() as u64,
+    };
+    let status = if report.ready { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (status, Json(report)).into_response()
+}
+
+/// 통합 시장 데이터 WebSocket
+async fn gateway_websocket(ws: WebSocketUpgrade, State(state): State<GatewayState>) -> Response {
+    ws.on_upgrade(move |socket| {
+        serve_connection(socket, state.market_data.subscribe(), WebSocketChannel::Full, Vec::new(), state.ws_config.clone(), state.ws_metrics.clone())
+    })
+}
+
+/// 게이트웨이 실행
+pub async fn run_gateway(config: GatewayConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let state = GatewayState::new(&config)?;
+    for instance in state.routing.instances() {
+        println!("🔀 {} ({}): {}", instance.name, instance.base_url, instance.symbols.join(", "));
+    }
+    state
+        .market_data
+        .spawn_upstreams(Duration::from_millis(config.reconnect_delay_ms));
+
+    let router = create_gateway_router()
+        .layer(CorsLayer::permissive())
+        .layer(TraceLayer::new_for_http())
+        .with_state(state);
+    let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.listen_port)).await?;
+    info!("게이트웨이 시작: 포트 {}", config.listen_port);
+    println!("게이트웨이 REST API: http://localhost:{}", config.listen_port);
+    println!("게이트웨이 WebSocket: ws://localhost:{}/ws", config.listen_port);
+
+    axum::serve(listener, router).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::Json as JsonBody;
+
+    /// 주문 접수/취소/티커만 흉내 내는 인스턴스
+    async fn spawn_instance(name: &'static str, symbols: &'static [&'static str]) -> GatewayInstance {
+        let router = Router::new()
+            .route(
+                "/v1/order",
+                post(move |JsonBody(body): JsonBody<Value>| async move {
+                    Json(serde_json::json!({ "order_id": format!("{}-{}", name, body["symbol"].as_str().unwrap()) }))
+                }),
+            )
+            .route(
+                "/v1/order/cancel",
+                post(move |JsonBody(body): JsonBody<Value>| async move {
+                    let order_id = body["order_id"].as_str().unwrap().to_string();
+                    if order_id.starts_with(name) {
+                        (StatusCode::OK, Json(serde_json::json!({ "order_id": order_id, "instance": name })))
+                    } else {
+                        (StatusCode::NOT_FOUND, Json(serde_json::json!({ "error": "ORDER_NOT_FOUND" })))
+                    }
+                }),
+            )
+            .route(
+                "/v1/ticker",
+                get(move || async move {
+                    // 모든 기본 심볼 티커를 내지만 게이트웨이는 담당 심볼만 사용
+                    let tickers: Vec<Value> = ["AAPL", "BTC-KRW"]
+                        .iter()
+                        .map(|symbol| serde_json::json!({
+                            "symbol": symbol,
+                            "last_price": if symbols.contains(symbol) { Some(1) } else { None },
+                            "open_price_24h": null, "high_price_24h": null, "low_price_24h": null,
+                            "volume_24h": 0, "trade_count_24h": 0, "price_change_pct_24h": null,
+                        }))
+                        .collect();
+                    Json(serde_json::json!({ "timestamp": 0, "tickers": tickers }))
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        GatewayInstance {
+            name: name.to_string(),
+            base_url: format!("http://{}", addr),
+            symbols: symbols.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    async fn spawn_gateway(instances: Vec<GatewayInstance>) -> String {
+        let config = GatewayConfig {
+            listen_port: 0,
+            instances,
+            request_timeout_ms: 1000,
+            order_cache_size: 100,
+            reconnect_delay_ms: 1000,
+        };
+        let router = create_gateway_router().with_state(GatewayState::new(&config).unwrap());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_routes_orders_and_cancels_by_owner() {
+        let a = spawn_instance("a", &["BTC-KRW"]).await;
+        let b = spawn_instance("b", &["AAPL"]).await;
+        let gateway = spawn_gateway(vec![a, b]).await;
+        let client = reqwest::Client::new();
+
+        let order: Value = client
+            .post(format!("{}/v1/order", gateway))
+            .json(&serde_json::json!({ "symbol": "AAPL" }))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(order["order_id"], "b-AAPL");
+
+        let unknown = client
+            .post(format!("{}/v1/order", gateway))
+            .json(&serde_json::json!({ "symbol": "DOGE-KRW" }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(unknown.status().as_u16(), 400);
+
+        // 기억한 주문은 담당 인스턴스로, 모르는 주문은 모든 인스턴스 조회
+        for order_id in ["b-AAPL", "a-unknown"] {
+            let cancel: Value = client
+                .post(format!("{}/v1/order/cancel", gateway))
+                .json(&serde_json::json!({ "order_id": order_id }))
+                .send()
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap();
+            assert_eq!(cancel["instance"], &order_id[..1]);
+        }
+        let missing = client
+            .post(format!("{}/v1/order/cancel", gateway))
+            .json(&serde_json::json!({ "order_id": "c-1" }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(missing.status().as_u16(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_ticker_merges_owned_symbols() {
+        let a = spawn_instance("a", &["BTC-KRW"]).await;
+        let b = spawn_instance("b", &["AAPL"]).await;
+        let gateway = spawn_gateway(vec![a, b]).await;
+
+        let response: TickerResponse = reqwest::get(format!("{}/v1/ticker", gateway)).await.unwrap().json().await.unwrap();
+        let symbols: Vec<(String, Option<u64>)> =
+            response.tickers.into_iter().map(|ticker| (ticker.symbol, ticker.last_price)).collect();
+        assert_eq!(symbols, vec![("AAPL".to_string(), Some(1)), ("BTC-KRW".to_string(), Some(1))]);
+    }
+}
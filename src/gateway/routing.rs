@@ -0,0 +1,234 @@
+//! 심볼 → 인스턴스 라우팅 테이블
+//!
+//! 심볼마다 담당 인스턴스가 정확히 하나여야 하며, 주문 ID로만 요청하는 취소/조회를 위해
+//! 게이트웨이를 거친 주문의 담당 인스턴스를 최근 N건까지 기억합니다.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::path::Path;
+use std::sync::Mutex;
+use serde::Deserialize;
+
+/// 게이트웨이 뒤의 xTrader 인스턴스
+#[derive(Debug, Clone, Deserialize)]
+pub struct GatewayInstance {
+    /// 인스턴스 이름 (로그, 상태 조회용)
+    pub name: String,
+    /// REST API 주소 (예: `http://10.0.0.11:7000`)
+    pub base_url: String,
+    /// 담당 심볼
+    pub symbols: Vec<String>,
+}
+
+impl GatewayInstance {
+    /// 시장 데이터 WebSocket 주소 (`http` → `ws`, `https` → `wss`)
+    pub fn ws_url(&self) -> String {
+        let base = self.base_url.trim_end_matches('/');
+        let base = if let Some(rest) = base.strip_prefix("https://") {
+            format!("wss://{}", rest)
+        } else if let Some(rest) = base.strip_prefix("http://") {
+            format!("ws://{}", rest)
+        } else {
+            format!("ws://{}", base)
+        };
+        format!("{}/ws", base)
+    }
+}
+
+fn default_listen_port() -> u16 {
+    8000
+}
+
+fn default_request_timeout_ms() -> u64 {
+    3000
+}
+
+fn default_order_cache_size() -> usize {
+    100_000
+}
+
+fn default_reconnect_delay_ms() -> u64 {
+    1000
+}
+
+/// 게이트웨이 설정 (JSON 파일)
+#[derive(Debug, Clone, Deserialize)]
+pub struct GatewayConfig {
+    /// 클라이언트용 REST/WebSocket 포트
+    #[serde(default = "default_listen_port")]
+    pub listen_port: u16,
+    pub instances: Vec<GatewayInstance>,
+    /// 인스턴스 요청 제한 시간
+    #[serde(default = "default_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+    /// 담당 인스턴스를 기억하는 최근 주문 수
+    #[serde(default = "default_order_cache_size")]
+    pub order_cache_size: usize,
+    /// 시장 데이터 WebSocket 재연결 대기 시간
+    #[serde(default = "default_reconnect_delay_ms")]
+    pub reconnect_delay_ms: u64,
+}
+
+impl GatewayConfig {
+    /// JSON 파일에서 로드
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+/// 라우팅 설정 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoutingError {
+    /// 인스턴스가 없음
+    NoInstances,
+    /// 한 심볼을 두 인스턴스가 담당
+    DuplicateSymbol { symbol: String, first: String, second: String },
+}
+
+impl fmt::Display for RoutingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoutingError::NoInstances => write!(f, "게이트웨이 인스턴스가 설정되지 않았습니다"),
+            RoutingError::DuplicateSymbol { symbol, first, second } => {
+                write!(f, "심볼 {}을(를) 두 인스턴스가 담당합니다: {}, {}", symbol, first, second)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RoutingError {}
+
+/// 최근 주문의 담당 인스턴스 (오래된 것부터 제거)
+struct OrderOwners {
+    capacity: usize,
+    owners: HashMap<String, usize>,
+    order: VecDeque<String>,
+}
+
+/// 심볼 라우팅 테이블
+pub struct SymbolRoutingTable {
+    instances: Vec<GatewayInstance>,
+    by_symbol: HashMap<String, usize>,
+    order_owners: Mutex<OrderOwners>,
+}
+
+impl SymbolRoutingTable {
+    pub fn new(instances: Vec<GatewayInstance>, order_cache_size: usize) -> Result<Self, RoutingError> {
+        if instances.is_empty() {
+            return Err(RoutingError::NoInstances);
+        }
+
+        let mut by_symbol: HashMap<String, usize> = HashMap::new();
+        for (index, instance) in instances.iter().enumerate() {
+            for symbol in &instance.symbols {
+                if let Some(&first) = by_symbol.get(symbol) {
+                    return Err(RoutingError::DuplicateSymbol {
+                        symbol: symbol.clone(),
+                        first: instances[first].name.clone(),
+                        second: instance.name.clone(),
+                    });
+                }
+                by_symbol.insert(symbol.clone(), index);
+            }
+        }
+
+        Ok(Self {
+            instances,
+            by_symbol,
+            order_owners: Mutex::new(OrderOwners {
+                capacity: order_cache_size.max(1),
+                owners: HashMap::new(),
+                order: VecDeque::new(),
+            }),
+        })
+    }
+
+    pub fn instances(&self) -> &[GatewayInstance] {
+        &self.instances
+    }
+
+    /// 심볼 담당 인스턴스
+    pub fn owner(&self, symbol: &str) -> Option<&GatewayInstance> {
+        self.by_symbol.get(symbol).map(|&index| &self.instances[index])
+    }
+
+    /// 전체 심볼 (정렬)
+    pub fn symbols(&self) -> Vec<String> {
+        let mut symbols: Vec<String> = self.by_symbol.keys().cloned().collect();
+        symbols.sort();
+        symbols
+    }
+
+    /// 게이트웨이를 거친 주문의 담당 인스턴스 기록
+    pub fn remember_order(&self, order_id: &str, instance: &GatewayInstance) {
+        let Some(index) = self.instances.iter().position(|candidate| candidate.name == instance.name) else {
+            return;
+        };
+        let mut owners = self.order_owners.lock().unwrap();
+        if owners.owners.insert(order_id.to_string(), index).is_none() {
+            owners.order.push_back(order_id.to_string());
+        }
+        while owners.order.len() > owners.capacity {
+            if let Some(oldest) = owners.order.pop_front() {
+                owners.owners.remove(&oldest);
+            }
+        }
+    }
+
+    /// 주문 담당 인스턴스 (기억하지 못하면 None)
+    pub fn order_owner(&self, order_id: &str) -> Option<&GatewayInstance> {
+        let index = *self.order_owners.lock().unwrap().owners.get(order_id)?;
+        Some(&self.instances[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instance(name: &str, symbols: &[&str]) -> GatewayInstance {
+        GatewayInstance {
+            name: name.to_string(),
+            base_url: format!("http://{}:7000", name),
+            symbols: symbols.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_routes_by_symbol_and_rejects_duplicates() {
+        let table = SymbolRoutingTable::new(
+            vec![instance("a", &["BTC-KRW", "ETH-KRW"]), instance("b", &["AAPL"])],
+            10,
+        )
+        .unwrap();
+        assert_eq!(table.owner("ETH-KRW").unwrap().name, "a");
+        assert_eq!(table.owner("AAPL").unwrap().name, "b");
+        assert!(table.owner("DOGE-KRW").is_none());
+        assert_eq!(table.symbols(), ["AAPL", "BTC-KRW", "ETH-KRW"]);
+
+        let duplicate = SymbolRoutingTable::new(vec![instance("a", &["BTC-KRW"]), instance("b", &["BTC-KRW"])], 10);
+        assert!(matches!(duplicate, Err(RoutingError::DuplicateSymbol { .. })));
+        assert!(matches!(SymbolRoutingTable::new(vec![], 10), Err(RoutingError::NoInstances)));
+    }
+
+    #[test]
+    fn test_order_owner_cache_evicts_oldest() {
+        let table = SymbolRoutingTable::new(vec![instance("a", &["BTC-KRW"]), instance("b", &["AAPL"])], 2).unwrap();
+        let b = table.owner("AAPL").unwrap().clone();
+        for order_id in ["o1", "o2", "o3"] {
+            table.remember_order(order_id, &b);
+        }
+
+        assert!(table.order_owner("o1").is_none());
+        assert_eq!(table.order_owner("o3").unwrap().name, "b");
+    }
+
+    #[test]
+    fn test_ws_url() {
+        assert_eq!(instance("a", &[]).ws_url(), "ws://a:7000/ws");
+        let mut secure = instance("a", &[]);
+        secure.base_url = "https://xtrader.example.com/".to_string();
+        assert_eq!(secure.ws_url(), "wss://xtrader.example.com/ws");
+    }
+}
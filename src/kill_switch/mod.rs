@@ -0,0 +1,314 @@
+//! 관리자 킬 스위치 (계정 차단, 심볼 거래 중단)
+//!
+//! 차단된 계정(`client_id`)의 신규 주문과 거래 중단된 심볼의 신규 주문을
+//! REST 검증 단계와 매칭 엔진 양쪽에서 거부합니다. 상태는 메모리에서 바로 바뀌므로
+//! 이미 시퀀서 큐에 들어간 주문도 엔진에 도착하는 즉시 거부됩니다. 취소 주문은 막지 않습니다.
+//! 발동/해제는 DB에 저장하고 감사 로그(`audit_logs`)와 보안 알림을 남깁니다.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use utoipa::ToSchema;
+
+use crate::db::models::KillSwitchRecord;
+use crate::db::repository::{AuditLogRepository, KillSwitchRepository};
+use crate::monitoring::notification_routing::SOURCE_METADATA_KEY;
+use crate::monitoring::{NotificationChannel, NotificationPriority, NotificationSystem, NotificationType};
+
+/// 감사 로그 이벤트 타입 (발동)
+pub const KILL_SWITCH_ACTIVATED_EVENT: &str = "KILL_SWITCH_ACTIVATED";
+/// 감사 로그 이벤트 타입 (해제)
+pub const KILL_SWITCH_RELEASED_EVENT: &str = "KILL_SWITCH_RELEASED";
+
+/// 킬 스위치 대상 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KillSwitchScope {
+    /// 계정 차단 (신규 주문 거부, 미체결 주문 취소)
+    Client,
+    /// 심볼 거래 중단 (신규 주문 거부)
+    Symbol,
+}
+
+impl KillSwitchScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KillSwitchScope::Client => "client",
+            KillSwitchScope::Symbol => "symbol",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "client" => Some(KillSwitchScope::Client),
+            "symbol" => Some(KillSwitchScope::Symbol),
+            _ => None,
+        }
+    }
+
+    /// 감사 로그 엔티티 타입
+    fn audit_entity(&self) -> &'static str {
+        match self {
+            KillSwitchScope::Client => "kill_switch_client",
+            KillSwitchScope::Symbol => "kill_switch_symbol",
+        }
+    }
+}
+
+/// 발동 중인 킬 스위치
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct KillSwitchEntry {
+    pub scope: KillSwitchScope,
+    /// 계정 ID 또는 심볼
+    pub target: String,
+    pub reason: Option<String>,
+    pub activated_by: String,
+    /// 발동 시각 (밀리초)
+    pub activated_at: u64,
+}
+
+impl KillSwitchEntry {
+    fn to_record(&self) -> KillSwitchRecord {
+        KillSwitchRecord {
+            scope: self.scope.as_str().to_string(),
+            target: self.target.clone(),
+            reason: self.reason.clone(),
+            activated_by: self.activated_by.clone(),
+            activated_at: self.activated_at as i64,
+        }
+    }
+
+    fn from_record(record: KillSwitchRecord) -> Result<Self, KillSwitchError> {
+        let scope = KillSwitchScope::from_name(&record.scope)
+            .ok_or_else(|| KillSwitchError::InvalidRecord(format!("{}: 알 수 없는 대상 종류 '{}'", record.target, record.scope)))?;
+        Ok(Self {
+            scope,
+            target: record.target,
+            reason: record.reason,
+            activated_by: record.activated_by,
+            activated_at: record.activated_at as u64,
+        })
+    }
+}
+
+/// 킬 스위치 오류
+#[derive(Debug, thiserror::Error)]
+pub enum KillSwitchError {
+    #[error("차단된 계정입니다: {client_id}{}", reason.as_ref().map(|r| format!(" ({})", r)).unwrap_or_default())]
+    ClientBlocked { client_id: String, reason: Option<String> },
+    #[error("거래가 중단된 심볼입니다: {symbol}{}", reason.as_ref().map(|r| format!(" ({})", r)).unwrap_or_default())]
+    SymbolHalted { symbol: String, reason: Option<String> },
+    #[error("킬 스위치 기록 오류: {0}")]
+    InvalidRecord(String),
+    #[error("킬 스위치 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 킬 스위치 상태 (메모리 + DB)
+///
+/// 매칭 엔진이 주문마다 동기로 확인하므로 `std::sync::RwLock`을 씁니다.
+pub struct KillSwitch {
+    entries: RwLock<HashMap<(KillSwitchScope, String), KillSwitchEntry>>,
+    repository: KillSwitchRepository,
+    audit: AuditLogRepository,
+    notifications: Option<Arc<NotificationSystem>>,
+}
+
+impl KillSwitch {
+    /// DB에 저장된 킬 스위치를 읽어 생성
+    pub async fn load(pool: SqlitePool) -> Result<Self, KillSwitchError> {
+        let repository = KillSwitchRepository::new(pool.clone());
+        let entries = repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|record| KillSwitchEntry::from_record(record).map(|e| ((e.scope, e.target.clone()), e)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        if !entries.is_empty() {
+            warn!("발동 중인 킬 스위치 {}개 로드", entries.len());
+        }
+
+        Ok(Self {
+            entries: RwLock::new(entries),
+            repository,
+            audit: AuditLogRepository::new(pool),
+            notifications: None,
+        })
+    }
+
+    /// 발동/해제 알림 발송
+    pub fn with_notifications(mut self, notifications: Arc<NotificationSystem>) -> Self {
+        self.notifications = Some(notifications);
+        self
+    }
+
+    fn entry(&self, scope: KillSwitchScope, target: &str) -> Option<KillSwitchEntry> {
+        self.entries.read().unwrap().get(&(scope, target.to_string())).cloned()
+    }
+
+    pub fn is_client_blocked(&self, client_id: &str) -> bool {
+        self.entry(KillSwitchScope::Client, client_id).is_some()
+    }
+
+    pub fn is_symbol_halted(&self, symbol: &str) -> bool {
+        self.entry(KillSwitchScope::Symbol, symbol).is_some()
+    }
+
+    /// 신규 주문 허용 여부 확인 (심볼 중단을 먼저 확인)
+    pub fn check_order(&self, client_id: &str, symbol: &str) -> Result<(), KillSwitchError> {
+        if let Some(entry) = self.entry(KillSwitchScope::Symbol, symbol) {
+            return Err(KillSwitchError::SymbolHalted { symbol: entry.target, reason: entry.reason });
+        }
+        if let Some(entry) = self.entry(KillSwitchScope::Client, client_id) {
+            return Err(KillSwitchError::ClientBlocked { client_id: entry.target, reason: entry.reason });
+        }
+        Ok(())
+    }
+
+    /// 발동 중인 킬 스위치 (종류, 대상순)
+    pub fn entries(&self) -> Vec<KillSwitchEntry> {
+        let mut entries: Vec<KillSwitchEntry> = self.entries.read().unwrap().values().cloned().collect();
+        entries.sort_by(|a, b| (a.scope, &a.target).cmp(&(b.scope, &b.target)));
+        entries
+    }
+
+    /// 킬 스위치 발동 (이미 발동 중이면 사유와 발동자 갱신)
+    pub async fn activate(
+        &self,
+        scope: KillSwitchScope,
+        target: &str,
+        reason: Option<String>,
+        actor: &str,
+    ) -> Result<KillSwitchEntry, KillSwitchError> {
+        let entry = KillSwitchEntry {
+            scope,
+            target: target.to_string(),
+            reason,
+            activated_by: actor.to_string(),
+            activated_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64,
+        };
+
+        self.repository.upsert(&entry.to_record()).await?;
+        self.entries.write().unwrap().insert((scope, target.to_string()), entry.clone());
+        warn!("킬 스위치 발동: {} {} (by {})", scope.as_str(), target, actor);
+
+        let details = serde_json::to_string(&entry).map_err(|e| KillSwitchError::InvalidRecord(e.to_string()))?;
+        self.audit.log(KILL_SWITCH_ACTIVATED_EVENT, scope.audit_entity(), target, Some(&details)).await?;
+
+        let title = match scope {
+            KillSwitchScope::Client => format!("계정 차단: {}", target),
+            KillSwitchScope::Symbol => format!("거래 중단: {}", target),
+        };
+        let content = format!("{} (by {})", entry.reason.as_deref().unwrap_or("사유 없음"), actor);
+        self.notify(title, content, NotificationPriority::Critical, &entry).await;
+        Ok(entry)
+    }
+
+    /// 킬 스위치 해제 (발동 중이 아니면 None)
+    pub async fn release(
+        &self,
+        scope: KillSwitchScope,
+        target: &str,
+        actor: &str,
+    ) -> Result<Option<KillSwitchEntry>, KillSwitchError> {
+        let Some(entry) = self.entry(scope, target) else {
+            return Ok(None);
+        };
+
+        self.repository.delete(scope.as_str(), target).await?;
+        self.entries.write().unwrap().remove(&(scope, target.to_string()));
+        info!("킬 스위치 해제: {} {} (by {})", scope.as_str(), target, actor);
+
+        let details = serde_json::json!({ "released": entry, "released_by": actor }).to_string();
+        self.audit.log(KILL_SWITCH_RELEASED_EVENT, scope.audit_entity(), target, Some(&details)).await?;
+
+        let title = match scope {
+            KillSwitchScope::Client => format!("계정 차단 해제: {}", target),
+            KillSwitchScope::Symbol => format!("거래 재개: {}", target),
+        };
+        self.notify(title, format!("by {}", actor), NotificationPriority::High, &entry).await;
+        Ok(Some(entry))
+    }
+
+    async fn notify(&self, title: String, content: String, priority: NotificationPriority, entry: &KillSwitchEntry) {
+        let Some(notifications) = &self.notifications else {
+            return;
+        };
+        let metadata = HashMap::from([
+            (SOURCE_METADATA_KEY.to_string(), "kill_switch".to_string()),
+            ("scope".to_string(), entry.scope.as_str().to_string()),
+            ("target".to_string(), entry.target.clone()),
+        ]);
+        if let Err(e) = notifications
+            .send_notification_with_metadata(
+                title,
+                content,
+                NotificationChannel::Log,
+                priority,
+                NotificationType::SecurityAlert,
+                metadata,
+            )
+            .await
+        {
+            error!("킬 스위치 알림 발송 실패: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_activate_release_and_reload() {
+        let pool = test_pool().await;
+        let kill_switch = KillSwitch::load(pool.clone()).await.unwrap();
+        assert!(kill_switch.check_order("alice", "BTC-KRW").is_ok());
+
+        kill_switch
+            .activate(KillSwitchScope::Client, "alice", Some("계정 탈취 의심".to_string()), "security")
+            .await
+            .unwrap();
+        kill_switch.activate(KillSwitchScope::Symbol, "ETH-KRW", None, "ops").await.unwrap();
+
+        assert!(matches!(kill_switch.check_order("alice", "BTC-KRW"), Err(KillSwitchError::ClientBlocked { .. })));
+        // 심볼 중단은 모든 계정에 적용
+        assert!(matches!(kill_switch.check_order("bob", "ETH-KRW"), Err(KillSwitchError::SymbolHalted { .. })));
+        assert!(kill_switch.check_order("bob", "BTC-KRW").is_ok());
+
+        // 재시작 후에도 유지
+        let reloaded = KillSwitch::load(pool.clone()).await.unwrap();
+        assert_eq!(reloaded.entries(), kill_switch.entries());
+        assert_eq!(reloaded.entries()[0].scope, KillSwitchScope::Client);
+
+        let released = kill_switch.release(KillSwitchScope::Client, "alice", "security").await.unwrap();
+        assert_eq!(released.map(|e| e.activated_by), Some("security".to_string()));
+        assert!(kill_switch.release(KillSwitchScope::Client, "alice", "security").await.unwrap().is_none());
+        assert!(!kill_switch.is_client_blocked("alice"));
+        assert!(KillSwitch::load(pool.clone()).await.unwrap().is_symbol_halted("ETH-KRW"));
+
+        let mut events: Vec<String> = AuditLogRepository::new(pool)
+            .find_by_entity("alice")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|log| log.event_type)
+            .collect();
+        events.sort();
+        assert_eq!(events, vec![KILL_SWITCH_ACTIVATED_EVENT, KILL_SWITCH_RELEASED_EVENT]);
+    }
+}
@@ -0,0 +1,364 @@
+//! KYC(고객 확인) 상태 관리와 주문 제한
+//!
+//! 계정(`client_id`)별 인증 단계와 주문 한도를 보관하고, 주문 접수 시
+//! 정지된 계정은 거부하고 인증 단계에 따라 1회 주문 금액을 제한합니다.
+//! 등록되지 않은 계정은 미인증으로 취급합니다. 상태 변경은 DB에 저장하고
+//! 변경 전후 값을 감사 로그(`audit_logs`)에 남깁니다.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::info;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tokio::sync::RwLock;
+use utoipa::ToSchema;
+
+use crate::db::models::KycAccountRecord;
+use crate::db::repository::{AuditLogRepository, KycAccountRepository};
+
+/// 감사 로그 이벤트 타입
+pub const KYC_AUDIT_EVENT: &str = "KYC_UPDATED";
+/// 감사 로그 엔티티 타입
+pub const KYC_AUDIT_ENTITY: &str = "kyc_account";
+
+/// 인증 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KycLevel {
+    /// 미인증 (가입만 완료)
+    Unverified,
+    /// 기본 인증 (본인 확인)
+    Basic,
+    /// 완전 인증 (본인 확인 + 자금 출처 등 추가 심사)
+    Full,
+}
+
+impl KycLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KycLevel::Unverified => "unverified",
+            KycLevel::Basic => "basic",
+            KycLevel::Full => "full",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "unverified" => Some(KycLevel::Unverified),
+            "basic" => Some(KycLevel::Basic),
+            "full" => Some(KycLevel::Full),
+            _ => None,
+        }
+    }
+}
+
+/// 계정 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KycStatus {
+    Active,
+    /// 정지 (모든 주문 거부)
+    Suspended,
+}
+
+impl KycStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KycStatus::Active => "active",
+            KycStatus::Suspended => "suspended",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "active" => Some(KycStatus::Active),
+            "suspended" => Some(KycStatus::Suspended),
+            _ => None,
+        }
+    }
+}
+
+/// 인증 단계별 1회 주문 금액(가격 × 수량) 한도
+#[derive(Debug, Clone)]
+pub struct KycConfig {
+    pub unverified_max_order_notional: u64,
+    pub basic_max_order_notional: u64,
+    /// None이면 무제한
+    pub full_max_order_notional: Option<u64>,
+}
+
+impl Default for KycConfig {
+    fn default() -> Self {
+        Self {
+            unverified_max_order_notional: 1_000_000,  // 100만원
+            basic_max_order_notional: 100_000_000,     // 1억원
+            full_max_order_notional: None,
+        }
+    }
+}
+
+impl KycConfig {
+    /// 인증 단계 기본 한도
+    pub fn level_limit(&self, level: KycLevel) -> Option<u64> {
+        match level {
+            KycLevel::Unverified => Some(self.unverified_max_order_notional),
+            KycLevel::Basic => Some(self.basic_max_order_notional),
+            KycLevel::Full => self.full_max_order_notional,
+        }
+    }
+}
+
+/// 계정 KYC 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KycAccount {
+    pub client_id: String,
+    pub level: KycLevel,
+    pub status: KycStatus,
+    /// 계정별 1회 주문 금액 한도 (설정 시 단계 기본값 대신 사용)
+    pub max_order_notional: Option<u64>,
+    /// 마지막 변경 사유
+    pub reason: Option<String>,
+    pub updated_by: String,
+    /// 마지막 변경 시각 (밀리초)
+    pub updated_at: u64,
+}
+
+impl KycAccount {
+    /// 등록되지 않은 계정의 기본 상태
+    pub fn unverified(client_id: &str) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            level: KycLevel::Unverified,
+            status: KycStatus::Active,
+            max_order_notional: None,
+            reason: None,
+            updated_by: "system".to_string(),
+            updated_at: 0,
+        }
+    }
+
+    fn to_record(&self) -> KycAccountRecord {
+        KycAccountRecord {
+            client_id: self.client_id.clone(),
+            level: self.level.as_str().to_string(),
+            status: self.status.as_str().to_string(),
+            max_order_notional: self.max_order_notional.map(|n| n as i64),
+            reason: self.reason.clone(),
+            updated_by: self.updated_by.clone(),
+            updated_at: self.updated_at as i64,
+        }
+    }
+
+    fn from_record(record: KycAccountRecord) -> Result<Self, KycError> {
+        let level = KycLevel::from_name(&record.level)
+            .ok_or_else(|| KycError::InvalidRecord(format!("{}: 알 수 없는 인증 단계 '{}'", record.client_id, record.level)))?;
+        let status = KycStatus::from_name(&record.status)
+            .ok_or_else(|| KycError::InvalidRecord(format!("{}: 알 수 없는 상태 '{}'", record.client_id, record.status)))?;
+        Ok(Self {
+            client_id: record.client_id,
+            level,
+            status,
+            max_order_notional: record.max_order_notional.map(|n| n as u64),
+            reason: record.reason,
+            updated_by: record.updated_by,
+            updated_at: record.updated_at as u64,
+        })
+    }
+}
+
+/// KYC 상태 변경 요청
+#[derive(Debug, Clone)]
+pub struct KycUpdate {
+    pub level: KycLevel,
+    pub status: KycStatus,
+    pub max_order_notional: Option<u64>,
+    pub reason: Option<String>,
+}
+
+/// 감사 로그에 남는 변경 내역
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KycChange {
+    pub before: KycAccount,
+    pub after: KycAccount,
+}
+
+/// KYC 오류
+#[derive(Debug, thiserror::Error)]
+pub enum KycError {
+    #[error("정지된 계정입니다: {client_id}{}", reason.as_ref().map(|r| format!(" ({})", r)).unwrap_or_default())]
+    Suspended { client_id: String, reason: Option<String> },
+    #[error("{level:?} 계정의 1회 주문 금액 한도 초과: {notional} > {limit}")]
+    LimitExceeded { level: KycLevel, notional: u64, limit: u64 },
+    #[error("KYC 기록 오류: {0}")]
+    InvalidRecord(String),
+    #[error("KYC 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 계정별 KYC 상태 (메모리 캐시 + DB)
+pub struct KycRegistry {
+    config: KycConfig,
+    accounts: RwLock<HashMap<String, KycAccount>>,
+    repository: KycAccountRepository,
+    audit: AuditLogRepository,
+}
+
+impl KycRegistry {
+    /// DB에 저장된 KYC 상태를 읽어 생성
+    pub async fn load(config: KycConfig, pool: SqlitePool) -> Result<Self, KycError> {
+        let repository = KycAccountRepository::new(pool.clone());
+        let accounts = repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|record| KycAccount::from_record(record).map(|a| (a.client_id.clone(), a)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        info!("KYC 계정 {}개 로드", accounts.len());
+
+        Ok(Self {
+            config,
+            accounts: RwLock::new(accounts),
+            repository,
+            audit: AuditLogRepository::new(pool),
+        })
+    }
+
+    /// 계정 KYC 상태 (없으면 미인증)
+    pub async fn account(&self, client_id: &str) -> KycAccount {
+        self.accounts
+            .read()
+            .await
+            .get(client_id)
+            .cloned()
+            .unwrap_or_else(|| KycAccount::unverified(client_id))
+    }
+
+    /// 계정에 적용되는 1회 주문 금액 한도 (None이면 무제한)
+    pub fn order_limit(&self, account: &KycAccount) -> Option<u64> {
+        account.max_order_notional.or_else(|| self.config.level_limit(account.level))
+    }
+
+    /// 주문 허용 여부 확인
+    pub async fn check_order(&self, client_id: &str, notional: u64) -> Result<(), KycError> {
+        let account = self.account(client_id).await;
+        if account.status == KycStatus::Suspended {
+            return Err(KycError::Suspended {
+                client_id: account.client_id,
+                reason: account.reason,
+            });
+        }
+        match self.order_limit(&account) {
+            Some(limit) if notional > limit => Err(KycError::LimitExceeded {
+                level: account.level,
+                notional,
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// KYC 상태 변경 (DB 저장 후 감사 로그 기록)
+    pub async fn update(&self, client_id: &str, update: KycUpdate, actor: &str) -> Result<KycAccount, KycError> {
+        let mut accounts = self.accounts.write().await;
+        let before = accounts
+            .get(client_id)
+            .cloned()
+            .unwrap_or_else(|| KycAccount::unverified(client_id));
+        let after = KycAccount {
+            client_id: client_id.to_string(),
+            level: update.level,
+            status: update.status,
+            max_order_notional: update.max_order_notional,
+            reason: update.reason,
+            updated_by: actor.to_string(),
+            updated_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64,
+        };
+
+        self.repository.upsert(&after.to_record()).await?;
+        let change = KycChange { before, after: after.clone() };
+        let details = serde_json::to_string(&change).map_err(|e| KycError::InvalidRecord(e.to_string()))?;
+        self.audit.log(KYC_AUDIT_EVENT, KYC_AUDIT_ENTITY, client_id, Some(&details)).await?;
+
+        info!(
+            "KYC 변경: {} {:?}/{:?} → {:?}/{:?} (by {})",
+            client_id, change.before.level, change.before.status, after.level, after.status, actor
+        );
+        accounts.insert(client_id.to_string(), after.clone());
+        Ok(after)
+    }
+
+    /// 계정 KYC 변경 이력 (최신순)
+    pub async fn history(&self, client_id: &str) -> Result<Vec<KycChange>, KycError> {
+        self.audit
+            .find_by_entity(client_id)
+            .await?
+            .into_iter()
+            .filter(|log| log.entity_type == KYC_AUDIT_ENTITY)
+            .filter_map(|log| log.details)
+            .map(|details| serde_json::from_str(&details).map_err(|e| KycError::InvalidRecord(e.to_string())))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_order_gating_by_level_and_status() {
+        let registry = KycRegistry::load(KycConfig::default(), test_pool().await).await.unwrap();
+
+        // 미등록 계정은 미인증 한도 적용
+        assert!(registry.check_order("new_user", 1_000_000).await.is_ok());
+        assert!(matches!(
+            registry.check_order("new_user", 1_000_001).await,
+            Err(KycError::LimitExceeded { level: KycLevel::Unverified, limit: 1_000_000, .. })
+        ));
+
+        let verified = KycUpdate { level: KycLevel::Full, status: KycStatus::Active, max_order_notional: None, reason: None };
+        registry.update("new_user", verified, "admin").await.unwrap();
+        assert!(registry.check_order("new_user", u64::MAX).await.is_ok());
+
+        let suspended = KycUpdate {
+            level: KycLevel::Full,
+            status: KycStatus::Suspended,
+            max_order_notional: None,
+            reason: Some("자금세탁 의심".to_string()),
+        };
+        registry.update("new_user", suspended, "compliance").await.unwrap();
+        assert!(matches!(registry.check_order("new_user", 1).await, Err(KycError::Suspended { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_updates_persisted_and_audited() {
+        let pool = test_pool().await;
+        let registry = KycRegistry::load(KycConfig::default(), pool.clone()).await.unwrap();
+
+        let basic = KycUpdate { level: KycLevel::Basic, status: KycStatus::Active, max_order_notional: Some(5_000_000), reason: None };
+        registry.update("user_1", basic, "admin").await.unwrap();
+        let full = KycUpdate { level: KycLevel::Full, status: KycStatus::Active, max_order_notional: None, reason: Some("심사 완료".to_string()) };
+        registry.update("user_1", full, "reviewer").await.unwrap();
+
+        // 재시작 후에도 상태 유지
+        let reloaded = KycRegistry::load(KycConfig::default(), pool).await.unwrap();
+        let account = reloaded.account("user_1").await;
+        assert_eq!(account.level, KycLevel::Full);
+        assert_eq!(account.updated_by, "reviewer");
+
+        let history = reloaded.history("user_1").await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert!(history.iter().any(|c| c.before.level == KycLevel::Unverified && c.after.max_order_notional == Some(5_000_000)));
+        assert!(history.iter().any(|c| c.before.level == KycLevel::Basic && c.after.level == KycLevel::Full));
+    }
+}
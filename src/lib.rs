@@ -0,0 +1,33 @@
+//! xTrader 라이브러리
+//!
+//! 서버 바이너리(`main.rs`)와 퍼징 대상(`fuzz/`)이 같은 모듈을 공유하도록 라이브러리로 제공합니다.
+//! 다른 Rust 프로그램에서 거래소를 프로세스 안에서 실행하려면 [`exchange::ExchangeBuilder`]를 사용합니다.
+
+pub mod api;
+pub mod auth;
+pub mod backtest;
+pub mod currency;
+pub mod data;
+pub mod db;
+pub mod exchange;
+pub mod matching_engine;
+pub mod mdp;
+// MQ는 xtrader-mq 크레이트로 분리 (기존 `xtrader::mq` 경로 유지)
+pub use xtrader_mq as mq;
+pub mod external;
+pub mod fee;
+pub mod funding;
+pub mod futures;
+pub mod gateway;
+pub mod kill_switch;
+pub mod kyc;
+pub mod risk;
+pub mod performance;
+pub mod rbac;
+#[cfg(feature = "pyo3")]
+pub mod python;
+pub mod monitoring;
+pub mod sequencer;
+pub mod totp;
+pub mod server;
+pub mod util;
@@ -1,19 +1,7 @@
-mod api;
-mod data;
-mod db;
-mod matching_engine;
-mod mdp;
-mod mq;
-mod external;
-mod performance;
-mod monitoring;
-mod sequencer;
-mod server;
-mod util;
+use xtrader::{api, auth, currency, data, db, external, fee, futures, gateway, matching_engine, mdp, monitoring, mq, performance, rbac, sequencer, server, totp};
 
 use server::{start_server, ServerConfig};
 use data::DataLoader;
-use serde_json;
 
 
 
@@ -22,6 +10,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 로깅 초기화
     env_logger::init();
 
+    // 게이트웨이 모드: 심볼을 나눠 맡은 인스턴스들 앞에서 단일 API 제공 (환경 변수, JSON 설정 파일)
+    if let Ok(path) = std::env::var("XTRADER_GATEWAY_CONFIG") {
+        println!("xTrader 게이트웨이 시작");
+        let gateway_config = gateway::GatewayConfig::from_file(std::path::Path::new(&path))?;
+        gateway::run_gateway(gateway_config).await?;
+        return Ok(());
+    }
+
+    // 데이터셋 생성 모드: 시드 기반 데이터셋을 파일로 쓰고 종료 (환경 변수)
+    if let Ok(path) = std::env::var("XTRADER_GENERATE_DATASET") {
+        let name = std::env::var("XTRADER_DATASET_GENERATOR").unwrap_or_else(|_| "random".to_string());
+        let liquidity_name = std::env::var("XTRADER_DATASET_LIQUIDITY").unwrap_or_else(|_| "normal".to_string());
+        let liquidity = data::LiquidityProfile::from_name(&liquidity_name)
+            .ok_or_else(|| format!("알 수 없는 호가 깊이 프로필: {} (thin, normal, thick)", liquidity_name))?;
+        let seed = std::env::var("XTRADER_DATASET_SEED").ok().and_then(|v| v.parse::<u64>().ok()).unwrap_or(1);
+        let drift_bps = std::env::var("XTRADER_DATASET_TREND_BPS").ok().and_then(|v| v.parse::<i64>().ok()).unwrap_or(10);
+
+        let generator = data::generator_by_name(&name, data::SymbolTemplate::defaults(), liquidity, drift_bps)
+            .ok_or_else(|| format!("알 수 없는 데이터셋 생성기: {} (random, trending)", name))?;
+        let dataset = data::generate_dataset(generator.as_ref(), seed);
+        dataset.save(std::path::Path::new(&path))?;
+        println!("✅ 데이터셋 생성 완료: {} ({} 생성기, {:?}, 시드 {})", path, generator.name(), liquidity, seed);
+        return Ok(());
+    }
+
     println!("xTrader 거래소 시스템 시작");
 
     // SQLite 데이터베이스 초기화 (메모리 모드)
@@ -34,19 +47,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Ok(dataset) => {
             println!("✅ 데이터셋 로드 성공: {} 심볼 지원", dataset.market_data.len());
 
-            // 초기 주문서 데이터 로드 및 주문 생성
-            if let Ok(json_content) = std::fs::read_to_string("data/fake_dataset.json") {
-                if let Ok(json_data) = serde_json::from_str::<serde_json::Value>(&json_content) {
-                    let initial_orders = DataLoader::create_initial_orders(&json_data);
-                    println!("📋 초기 주문서 생성: {} 개 주문", initial_orders.len());
-
-                    // TODO: 초기 주문을 매칭 엔진에 추가하는 로직
-                    // for order in initial_orders {
-                    //     // 매칭 엔진에 주문 추가 (서버 시작 후)
-                    // }
-                }
-            }
-
             // 가짜 사용자 정보 로드
             let _fake_users = DataLoader::load_fake_users("data/fake_dataset.json");
         },
@@ -56,7 +56,415 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // 서버 설정
-    let config = ServerConfig::default();
+    let mut config = ServerConfig::default();
+
+    // 담당 심볼, REST 포트 (게이트웨이 뒤에서 심볼을 나눠 맡을 때, 환경 변수)
+    if let Ok(symbols) = std::env::var("XTRADER_SYMBOLS") {
+        config.symbols = symbols.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+    }
+    if let Some(port) = std::env::var("XTRADER_REST_PORT").ok().and_then(|v| v.parse::<u16>().ok()) {
+        config.rest_port = port;
+    }
+    if let Some(port) = std::env::var("XTRADER_MDP_API_PORT").ok().and_then(|v| v.parse::<u16>().ok()) {
+        config.mdp_api_port = port;
+    }
+
+    // Redis Streams 주소 (빈 값이면 Redis 체결 스트림 비활성화)
+    if let Ok(url) = std::env::var("XTRADER_REDIS_URL") {
+        config.redis_url = Some(url).filter(|url| !url.is_empty());
+    }
+
+    // RabbitMQ 주소 (빈 값이면 WebSocket 알림을 직접 브로드캐스트)
+    if let Ok(url) = std::env::var("XTRADER_RABBITMQ_URL") {
+        config.rabbitmq_url = Some(url).filter(|url| !url.is_empty());
+    }
+
+    // 배치 경매 심볼 (`심볼:경매 주기 밀리초`, 나머지 심볼은 연속 매칭)
+    if let Ok(auctions) = std::env::var("XTRADER_BATCH_AUCTIONS") {
+        config.batch_auctions = matching_engine::auction::parse_batch_auctions(&auctions)?;
+    }
+
+    // 고정 환율 (거래소에 시장이 없는 통화 쌍, 예: `USD/KRW=1350`)
+    if let Ok(rates) = std::env::var("XTRADER_FX_RATES") {
+        config.currency.fixed_rates = currency::FixedRate::parse_list(&rates)?;
+    }
+
+    // 시작 시 봉차트 백필 기간 (시간, 0이면 백필 안 함)
+    if let Some(hours) = std::env::var("XTRADER_CANDLE_BACKFILL_HOURS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.candle_backfill.lookback = std::time::Duration::from_secs(hours * 3600);
+    }
+
+    // 진행 중인 봉 WebSocket 프레임 최소 전송 간격 (밀리초, 0이면 체결마다)
+    if let Some(ms) = std::env::var("XTRADER_CANDLE_THROTTLE_MS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.candle_throttle = std::time::Duration::from_millis(ms);
+    }
+
+    // 주문 큐 포화 시 서버 재시도 최대 대기 시간 (밀리초, 0이면 max_wait_ms를 무시하고 바로 거부)
+    if let Some(ms) = std::env::var("XTRADER_ORDER_MAX_WAIT_MS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.order_admission.max_wait = std::time::Duration::from_millis(ms);
+    }
+    if let Some(count) = std::env::var("XTRADER_ORDER_MAX_WAITING").ok().and_then(|v| v.parse::<usize>().ok()) {
+        config.order_admission.max_waiting = count;
+    }
+
+    // MQ 백업 큐 세그먼트 디렉터리
+    if let Ok(dir) = std::env::var("XTRADER_BACKUP_QUEUE_DIR") {
+        config.backup_queue.directory = dir.into();
+    }
+
+    // MQ 발행 재시도 정책 (소진되면 백업 큐로)
+    if let Some(attempts) = std::env::var("XTRADER_MQ_PUBLISH_MAX_ATTEMPTS").ok().and_then(|v| v.parse::<u32>().ok()).filter(|a| *a > 0) {
+        config.publish_retry.max_attempts = attempts;
+    }
+    if let Some(ms) = std::env::var("XTRADER_MQ_PUBLISH_BACKOFF_MS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.publish_retry.initial_backoff = std::time::Duration::from_millis(ms);
+    }
+    if let Some(ms) = std::env::var("XTRADER_MQ_PUBLISH_MAX_BACKOFF_MS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.publish_retry.max_backoff = std::time::Duration::from_millis(ms);
+    }
+    if let Some(ms) = std::env::var("XTRADER_MQ_PUBLISH_TIMEOUT_MS").ok().and_then(|v| v.parse::<u64>().ok()).filter(|ms| *ms > 0) {
+        config.publish_retry.send_timeout = std::time::Duration::from_millis(ms);
+    }
+    if let Some(ms) = std::env::var("XTRADER_MQ_PUBLISH_DEADLINE_MS").ok().and_then(|v| v.parse::<u64>().ok()).filter(|ms| *ms > 0) {
+        config.publish_retry.deadline = std::time::Duration::from_millis(ms);
+    }
+
+    // Kafka 호가창 업데이트 배치/압축 (none, gzip, snappy)
+    if let Ok(compression) = std::env::var("XTRADER_KAFKA_COMPRESSION") {
+        config.kafka_batch.compression = mq::CompressionType::parse(&compression)?;
+    }
+    if let Some(ms) = std::env::var("XTRADER_KAFKA_LINGER_MS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.kafka_batch.linger = std::time::Duration::from_millis(ms);
+    }
+    if let Some(max) = std::env::var("XTRADER_KAFKA_BATCH_MAX_MESSAGES").ok().and_then(|v| v.parse::<usize>().ok()).filter(|m| *m > 0) {
+        config.kafka_batch.max_messages = max;
+    }
+
+    // WebSocket 호가창 키프레임 간격 (그 사이에는 바뀐 레벨만 발행)
+    if let Some(ms) = std::env::var("XTRADER_ORDERBOOK_KEYFRAME_MS").ok().and_then(|v| v.parse::<u64>().ok()).filter(|ms| *ms > 0) {
+        config.orderbook_keyframe_interval = std::time::Duration::from_millis(ms);
+    }
+
+    // 주문장 가격 레벨 용량 정리 (주기 0이면 끔, 최우선 호가 근처 레벨 수는 건드리지 않음)
+    if let Some(secs) = std::env::var("XTRADER_BOOK_COMPACT_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.book_lifecycle = (secs > 0).then(|| matching_engine::BookLifecycleConfig {
+            interval: std::time::Duration::from_secs(secs),
+            ..config.book_lifecycle.unwrap_or_default()
+        });
+    }
+    if let Some(levels) = std::env::var("XTRADER_BOOK_NEAR_TOUCH_LEVELS").ok().and_then(|v| v.parse::<usize>().ok()) {
+        if let Some(lifecycle) = &mut config.book_lifecycle {
+            lifecycle.policy.near_touch_levels = levels;
+        }
+    }
+
+    // 시퀀서/매칭 엔진 수신 대기 전략 (스핀 → 양보 → 대기, 둘 다 0이면 블로킹 수신)
+    if let Some(us) = std::env::var("XTRADER_WAIT_SPIN_US").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.queue_config.wait_strategy.spin = std::time::Duration::from_micros(us);
+    }
+    if let Some(us) = std::env::var("XTRADER_WAIT_YIELD_US").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.queue_config.wait_strategy.yield_for = std::time::Duration::from_micros(us);
+    }
+
+    // 내부 MQ 페이로드 형식 (rkyv, json; Consumer는 두 형식 모두 읽음)
+    if let Ok(format) = std::env::var("XTRADER_MQ_WIRE_FORMAT") {
+        let format = mq::WireFormat::parse(&format)?;
+        config.mq_wire_format = format;
+        config.kafka_batch.wire_format = format;
+    }
+
+    // 시장 데이터 녹화/재생 (환경 변수)
+    if let Ok(path) = std::env::var("XTRADER_PLAYBACK_FILE") {
+        let speed = std::env::var("XTRADER_PLAYBACK_SPEED")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .unwrap_or(1.0);
+        config.playback = Some(mdp::PlaybackConfig { path: path.into(), speed });
+    } else if let Ok(dir) = std::env::var("XTRADER_RECORD_DIR") {
+        config.recording = Some(mdp::RecorderConfig::new(dir));
+    }
+
+    // 시작 시 초기 호가 공급 (환경 변수, 기본 데이터셋은 data/fake_dataset.json)
+    if std::env::var("XTRADER_SEED_LIQUIDITY").is_ok_and(|v| v == "1") {
+        let path = std::env::var("XTRADER_SEED_DATASET").unwrap_or_else(|_| "data/fake_dataset.json".to_string());
+        config.seed_dataset = Some(path.into());
+    }
+
+    // 외부 거래소 주문 라우팅 (환경 변수)
+    if std::env::var("XTRADER_ORDER_ROUTING").is_ok_and(|v| v == "1") {
+        config.order_routing = Some(external::RouterConfig::default());
+    }
+
+    // 차익거래 알림 규칙 (환경 변수)
+    if let Some(spread) = std::env::var("XTRADER_ARBITRAGE_MIN_SPREAD").ok().and_then(|v| v.parse::<f64>().ok()) {
+        config.arbitrage_alert.min_spread_percent = spread;
+    }
+    if let Some(ticks) = std::env::var("XTRADER_ARBITRAGE_TICKS").ok().and_then(|v| v.parse::<u32>().ok()) {
+        config.arbitrage_alert.consecutive_ticks = ticks;
+    }
+
+    // 인덱스 가격 (최소 거래소 수, 이상치 기준 %, 공정 가격 허용 범위 %, 괴리 서킷 브레이커 `괴리 %:연속 틱`)
+    if let Some(sources) = std::env::var("XTRADER_INDEX_MIN_SOURCES").ok().and_then(|v| v.parse::<usize>().ok()) {
+        config.index_price.min_sources = sources;
+    }
+    if let Some(percent) = std::env::var("XTRADER_INDEX_OUTLIER_PCT").ok().and_then(|v| v.parse::<f64>().ok()) {
+        config.index_price.outlier_percent = percent;
+    }
+    if let Some(percent) = std::env::var("XTRADER_INDEX_FAIR_BAND_PCT").ok().and_then(|v| v.parse::<f64>().ok()) {
+        config.index_price.fair_price_band_percent = percent;
+    }
+    if let Ok(breaker) = std::env::var("XTRADER_INDEX_CIRCUIT_BREAKER") {
+        let (deviation, ticks) = breaker
+            .split_once(':')
+            .and_then(|(deviation, ticks)| Some((deviation.trim().parse::<f64>().ok()?, ticks.trim().parse::<u32>().ok()?)))
+            .ok_or_else(|| format!("XTRADER_INDEX_CIRCUIT_BREAKER 형식 오류 (괴리 %:연속 틱): {}", breaker))?;
+        config.index_price.circuit_breaker = Some(external::IndexCircuitBreakerConfig {
+            max_deviation_percent: deviation,
+            consecutive_ticks: ticks,
+        });
+    }
+    config.index_price.validate()?;
+
+    // AML 규칙 파일 (환경 변수)
+    if let Ok(path) = std::env::var("XTRADER_AML_RULES_FILE") {
+        config.aml_rules = Some(external::AmlRuleSet::from_file(std::path::Path::new(&path))?);
+    }
+
+    // 규제 보고서 전송 엔드포인트 (환경 변수, JSON)
+    if let Ok(endpoint) = std::env::var("XTRADER_REPORT_ENDPOINT") {
+        let endpoint: external::DeliveryEndpoint = serde_json::from_str(&endpoint)?;
+        let format = std::env::var("XTRADER_REPORT_FORMAT")
+            .ok()
+            .and_then(|v| external::ReportFormat::from_name(&v))
+            .unwrap_or(external::ReportFormat::Xml);
+        config.report_delivery = Some(external::ReportDeliveryConfig::new(endpoint, format));
+    }
+
+    // REST/WebSocket TLS 종단 (인증서/개인키 PEM, 관리자 엔드포인트 mTLS용 클라이언트 CA)
+    match (std::env::var("XTRADER_TLS_CERT"), std::env::var("XTRADER_TLS_KEY")) {
+        (Ok(cert), Ok(key)) => {
+            let mut tls = api::tls::TlsConfig::new(cert, key);
+            if let Ok(ca) = std::env::var("XTRADER_TLS_ADMIN_CLIENT_CA") {
+                tls = tls.with_admin_client_ca(ca);
+            }
+            config.tls = Some(tls);
+        }
+        (Err(_), Err(_)) => {}
+        _ => return Err("XTRADER_TLS_CERT와 XTRADER_TLS_KEY는 함께 설정해야 합니다".into()),
+    }
+
+    // 체결/감사 로그 보존 정리 (XTRADER_RETENTION=1로 활성화, 일수 0이면 해당 테이블은 정리 안 함)
+    if std::env::var("XTRADER_RETENTION").is_ok_and(|v| v == "1") {
+        let mut retention = db::RetentionConfig::default();
+        if let Some(days) = std::env::var("XTRADER_RETENTION_EXECUTION_DAYS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            retention.execution_days = Some(days).filter(|d| *d > 0);
+        }
+        if let Some(days) = std::env::var("XTRADER_RETENTION_AUDIT_DAYS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            retention.audit_log_days = Some(days).filter(|d| *d > 0);
+        }
+        if let Some(hours) = std::env::var("XTRADER_RETENTION_INTERVAL_HOURS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            retention.interval = std::time::Duration::from_secs(hours * 3600);
+        }
+        if let Some(ratio) = std::env::var("XTRADER_RETENTION_VACUUM_FREE_RATIO").ok().and_then(|v| v.parse::<f64>().ok()) {
+            retention.vacuum_min_free_ratio = Some(ratio).filter(|r| *r > 0.0);
+        }
+        retention.validate()?;
+        config.retention = Some(retention);
+    }
+
+    // 콜드 스토리지 보관 (S3 호환 저장소, 엔드포인트를 설정해야 활성화)
+    if let Ok(endpoint) = std::env::var("XTRADER_ARCHIVE_ENDPOINT") {
+        let required = |name: &str| std::env::var(name).map_err(|_| format!("XTRADER_ARCHIVE_ENDPOINT를 쓰려면 {}도 설정해야 합니다", name));
+        let mut storage = external::S3Config::new(
+            endpoint,
+            required("XTRADER_ARCHIVE_BUCKET")?,
+            required("XTRADER_ARCHIVE_ACCESS_KEY")?,
+            required("XTRADER_ARCHIVE_SECRET_KEY")?,
+        );
+        if let Ok(region) = std::env::var("XTRADER_ARCHIVE_REGION") {
+            storage.region = region;
+        }
+        let mut archive = db::ArchiveConfig::new(storage);
+        if let Ok(prefix) = std::env::var("XTRADER_ARCHIVE_PREFIX") {
+            archive.prefix = prefix;
+        }
+        if let Some(hours) = std::env::var("XTRADER_ARCHIVE_INTERVAL_HOURS").ok().and_then(|v| v.parse::<u64>().ok()).filter(|h| *h > 0) {
+            archive.interval = std::time::Duration::from_secs(hours * 3600);
+        }
+        archive.prune = std::env::var("XTRADER_ARCHIVE_PRUNE").is_ok_and(|v| v == "1");
+        // 보존 정리가 보관 전에 원본을 지우지 않도록
+        if let Some(retention) = config.retention.as_mut() {
+            retention.archived_only = true;
+        }
+        config.archive = Some(archive);
+    }
+
+    // DB 온라인 백업 디렉터리, 시작 전에 복원할 백업 파일 (환경 변수)
+    config.backup_dir = std::env::var("XTRADER_BACKUP_DIR").ok().filter(|dir| !dir.is_empty()).map(std::path::PathBuf::from);
+    if let Ok(path) = std::env::var("XTRADER_DB_RESTORE") {
+        let restored = db::backup::restore(&db_pool, std::path::Path::new(&path)).await?;
+        println!("✅ DB 백업 복원 완료: {} (저널 순번 {})", path, restored.journal_seq);
+        config.restored_backup = Some(restored);
+    }
+
+    // 관리자 API 토큰, 미인증 계정 주문 금액 한도 (환경 변수)
+    config.admin_token = std::env::var("XTRADER_ADMIN_TOKEN").ok().filter(|t| !t.is_empty());
+
+    // API 키/세션 토큰 인증 (서명 키를 설정해야 활성화, 토큰 수명은 초 단위)
+    if let Some(secret) = std::env::var("XTRADER_JWT_SECRET").ok().filter(|s| !s.is_empty()) {
+        let mut auth = auth::AuthConfig::new(secret);
+        if let Some(secs) = std::env::var("XTRADER_JWT_ACCESS_TTL_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            auth.access_token_ttl = std::time::Duration::from_secs(secs);
+        }
+        if let Some(secs) = std::env::var("XTRADER_REFRESH_TOKEN_TTL_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            auth.refresh_token_ttl = std::time::Duration::from_secs(secs);
+        }
+        // 프록시 뒤에서 실행할 때만 설정 (IP 허용 목록이 X-Forwarded-For 마지막 항목 사용)
+        auth.trust_forwarded_for = std::env::var("XTRADER_TRUST_X_FORWARDED_FOR").is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
+        config.auth = Some(auth);
+    }
+
+    // 민감한 관리자 작업 2단계 인증 (TOTP)
+    if std::env::var("XTRADER_ADMIN_TOTP").is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true")) {
+        let mut totp = totp::TotpConfig::default();
+        if let Ok(issuer) = std::env::var("XTRADER_ADMIN_TOTP_ISSUER") {
+            totp.issuer = issuer;
+        }
+        if let Some(window) = std::env::var("XTRADER_ADMIN_TOTP_WINDOW").ok().and_then(|v| v.parse::<u64>().ok()) {
+            totp.window = window;
+        }
+        config.admin_totp = Some(totp);
+    }
+
+    // RBAC 기본 역할 (역할을 지정하지 않은 계정/관리자, none이면 지정된 대상만 허용)
+    let default_role = |name: String| -> Result<Option<rbac::Role>, String> {
+        if name == "none" {
+            return Ok(None);
+        }
+        rbac::Role::from_name(&name).map(Some).ok_or_else(|| format!("알 수 없는 역할: {}", name))
+    };
+    if let Ok(role) = std::env::var("XTRADER_RBAC_DEFAULT_CLIENT_ROLE") {
+        config.rbac.default_client_role = default_role(role)?;
+    }
+    if let Ok(role) = std::env::var("XTRADER_RBAC_DEFAULT_ADMIN_ROLE") {
+        config.rbac.default_admin_role = default_role(role)?;
+    }
+    if let Some(limit) = std::env::var("XTRADER_KYC_UNVERIFIED_LIMIT").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.kyc.unverified_max_order_notional = limit;
+    }
+
+    // 계정 공통 사전 리스크 한도 기본값 (환경 변수, 계정별 한도는 관리자 API로 설정)
+    if let Some(limit) = std::env::var("XTRADER_RISK_MAX_OPEN_ORDERS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.risk_limits.max_open_orders = Some(limit);
+    }
+    if let Some(limit) = std::env::var("XTRADER_RISK_MAX_ORDER_NOTIONAL").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.risk_limits.max_order_notional = Some(limit);
+    }
+
+    // 취소 비율 과다 계정 주문 속도 제한 (XTRADER_THROTTLE_CANCEL_RATIO를 설정하면 활성화)
+    if let Some(ratio) = std::env::var("XTRADER_THROTTLE_CANCEL_RATIO").ok().and_then(|v| v.parse::<f64>().ok()) {
+        let mut throttle = sequencer::ThrottleConfig { max_cancel_ratio: ratio, ..Default::default() };
+        if let Some(secs) = std::env::var("XTRADER_THROTTLE_WINDOW_SECS").ok().and_then(|v| v.parse::<u64>().ok()).filter(|s| *s > 0) {
+            throttle.window = std::time::Duration::from_secs(secs);
+        }
+        if let Some(count) = std::env::var("XTRADER_THROTTLE_MIN_ORDERS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            throttle.min_orders = count;
+        }
+        if let Some(rate) = std::env::var("XTRADER_THROTTLE_MAX_ORDERS_PER_SEC").ok().and_then(|v| v.parse::<u32>().ok()) {
+            throttle.max_orders_per_sec = rate;
+        }
+        if let Some(secs) = std::env::var("XTRADER_THROTTLE_DURATION_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            throttle.duration = std::time::Duration::from_secs(secs);
+        }
+        config.order_throttle = Some(throttle);
+    }
+
+    // 계정별 분당 메시지(주문/취소/조회) 소프트/하드 상한과 집계 보존 기간 (환경 변수)
+    if let Some(cap) = std::env::var("XTRADER_USAGE_SOFT_CAP_PER_MIN").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.message_usage.soft_cap_per_minute = Some(cap);
+    }
+    if let Some(cap) = std::env::var("XTRADER_USAGE_HARD_CAP_PER_MIN").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.message_usage.hard_cap_per_minute = Some(cap);
+    }
+    if let Some(days) = std::env::var("XTRADER_USAGE_RETENTION_DAYS").ok().and_then(|v| v.parse::<u64>().ok()).filter(|d| *d > 0) {
+        config.message_usage.retention = std::time::Duration::from_secs(days * 24 * 3600);
+    }
+
+    // 마켓 메이커 리베이트 (`심볼:월 예산` 목록, 최소 최우선 호가 체류율 0~1)
+    if let Ok(pools) = std::env::var("XTRADER_REBATE_POOLS") {
+        config.rebate.pools = db::RebateConfig::parse_pools(&pools)?;
+    }
+    if let Some(presence) = std::env::var("XTRADER_REBATE_MIN_PRESENCE").ok().and_then(|v| v.parse::<f64>().ok()) {
+        config.rebate.min_presence = presence;
+    }
+    config.rebate.validate()?;
+
+    // 외부 메트릭 싱크 (JSON 배열, StatsD/OTLP/Prometheus remote-write 동시 사용 가능), 전송 주기 (환경 변수)
+    if let Ok(sinks) = std::env::var("XTRADER_METRIC_SINKS") {
+        config.metrics.sinks = performance::MetricSinkConfig::parse_list(&sinks)?;
+    }
+    if let Some(secs) = std::env::var("XTRADER_METRIC_SINK_FLUSH_SECS").ok().and_then(|v| v.parse::<u64>().ok()).filter(|s| *s > 0) {
+        config.metrics.sink_flush_interval_ms = secs * 1000;
+    }
+
+    // 성능 지표 시간별 롤업 보관 기간 (일)
+    if let Some(days) = std::env::var("XTRADER_METRICS_HISTORY_RETENTION_DAYS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.metrics_history.retention_days = days;
+    }
+    config.metrics_history.validate()?;
+
+    // 펀딩 정산 대상 심볼(쉼표 구분), 정산 주기, 주기당 이자율, 비율 상한 (환경 변수)
+    if let Ok(symbols) = std::env::var("XTRADER_FUNDING_SYMBOLS") {
+        config.funding.symbols = symbols.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+    }
+    if let Some(secs) = std::env::var("XTRADER_FUNDING_INTERVAL_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.funding.interval = std::time::Duration::from_secs(secs);
+    }
+    if let Some(rate) = std::env::var("XTRADER_FUNDING_INTEREST_RATE").ok().and_then(|v| v.parse::<f64>().ok()) {
+        config.funding.interest_rate = rate;
+    }
+    if let Some(rate) = std::env::var("XTRADER_FUNDING_MAX_RATE").ok().and_then(|v| v.parse::<f64>().ok()) {
+        config.funding.max_rate = rate;
+    }
+    config.funding.validate()?;
+
+    // 만기 상품 (`심볼:기초 심볼:만기 Unix 초[:cash]` 목록, 거래 심볼에 없으면 추가), 만기 임박 알림 시점 (환경 변수)
+    if let Ok(contracts) = std::env::var("XTRADER_FUTURES") {
+        config.futures.contracts = futures::FuturesContract::parse_list(&contracts)?;
+        for contract in &config.futures.contracts {
+            if !config.symbols.contains(&contract.symbol) {
+                config.symbols.push(contract.symbol.clone());
+            }
+        }
+    }
+    if let Some(secs) = std::env::var("XTRADER_FUTURES_EXPIRY_NOTICE_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        config.futures.expiry_notice = std::time::Duration::from_secs(secs);
+    }
+    config.futures.validate()?;
+
+    // 수수료 등급표 (`이름:최소 30일 거래대금:메이커 bp:테이커 bp`), 등급 재산정 주기 (환경 변수)
+    if let Ok(tiers) = std::env::var("XTRADER_FEE_TIERS") {
+        config.fee.tiers = fee::FeeTier::parse_list(&tiers)?;
+    }
+    if let Some(hours) = std::env::var("XTRADER_FEE_RECALC_HOURS").ok().and_then(|v| v.parse::<u64>().ok()).filter(|h| *h > 0) {
+        config.fee.recalculation_interval = std::time::Duration::from_secs(hours * 3600);
+    }
+
+    // 알림 채널: Slack 수신 웹훅, SMTP 이메일 (환경 변수)
+    if let Some(slack) = monitoring::SlackConfig::from_env() {
+        config.notification.enabled_channels.push(monitoring::NotificationChannel::Slack);
+        config.notification.slack = Some(slack);
+    }
+    if let Some(email) = monitoring::EmailConfig::from_env()? {
+        config.notification.enabled_channels.push(monitoring::NotificationChannel::Email);
+        config.notification.email = Some(email);
+    }
+
+    // 엔진 상태 복제: 저널 스트림 포트, 대기 인스턴스로 따라갈 주 인스턴스 주소 (환경 변수)
+    if let Some(port) = std::env::var("XTRADER_REPLICATION_PORT").ok().and_then(|v| v.parse::<u16>().ok()) {
+        config.replication.listen_port = Some(port);
+    }
+    config.replication.standby_of = std::env::var("XTRADER_STANDBY_OF").ok().filter(|addr| !addr.is_empty());
 
     // 서버 시작 (DB 풀 전달)
     start_server(config, db_pool).await?;
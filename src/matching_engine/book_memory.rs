@@ -0,0 +1,67 @@
+//! 주문장 메모리 사용량 보고
+//!
+//! 심볼별 가격 레벨 수, 주문 수, 추정 바이트를 MetricsCollector로 노출합니다.
+//! 가격 레벨 정리 정책(`LevelLifecyclePolicy`)을 켜면 매칭 엔진이 주기마다 최우선 호가에서
+//! 먼 레벨의 남는 용량을 돌려주고, 정리한 레벨 수와 바이트도 함께 보고합니다.
+
+use std::time::Duration;
+
+use crate::matching_engine::order_book::{BookMemory, LevelLifecyclePolicy};
+use crate::performance::MetricsCollector;
+
+/// 주문장 정리 설정
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BookLifecycleConfig {
+  /// 가격 레벨 정리 정책
+  pub policy: LevelLifecyclePolicy,
+  /// 정리 주기
+  pub interval: Duration,
+}
+
+impl Default for BookLifecycleConfig {
+  fn default() -> Self {
+    Self { policy: LevelLifecyclePolicy::default(), interval: Duration::from_secs(60) }
+  }
+}
+
+/// 심볼별 주문장 메모리 사용량
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolBookMemory {
+  pub symbol: String,
+  pub memory: BookMemory,
+}
+
+/// 매칭 엔진 주문장 메모리 보고
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BookMemoryReport {
+  /// 심볼별 사용량 (심볼순)
+  pub symbols: Vec<SymbolBookMemory>,
+  /// 엔진 주문 저장소 추정 바이트 (주문장과 별도로 보관하는 주문 사본)
+  pub order_store_bytes: usize,
+  /// 엔진 시작 이후 용량을 정리한 가격 레벨 수
+  pub compacted_levels: u64,
+  /// 엔진 시작 이후 돌려준 추정 바이트
+  pub reclaimed_bytes: u64,
+}
+
+impl BookMemoryReport {
+  /// 전체 추정 바이트 (주문장 + 주문 저장소)
+  pub fn total_bytes(&self) -> usize {
+    self.symbols.iter().map(|symbol| symbol.memory.bytes).sum::<usize>() + self.order_store_bytes
+  }
+
+  /// MetricsCollector로 사용량 발행
+  pub async fn publish(&self, collector: &MetricsCollector) {
+    for symbol in &self.symbols {
+      let prefix = format!("orderbook.{}", symbol.symbol);
+      collector.set_gauge(&format!("{}.levels.bid", prefix), symbol.memory.bid_levels as u64).await;
+      collector.set_gauge(&format!("{}.levels.ask", prefix), symbol.memory.ask_levels as u64).await;
+      collector.set_gauge(&format!("{}.orders", prefix), symbol.memory.orders as u64).await;
+      collector.set_gauge(&format!("{}.memory_bytes", prefix), symbol.memory.bytes as u64).await;
+    }
+    collector.set_gauge("orderbook.order_store_bytes", self.order_store_bytes as u64).await;
+    collector.set_gauge("orderbook.memory_bytes", self.total_bytes() as u64).await;
+    collector.set_gauge("orderbook.compacted_levels", self.compacted_levels).await;
+    collector.set_gauge("orderbook.reclaimed_bytes", self.reclaimed_bytes).await;
+  }
+}
@@ -1,16 +1,56 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use uuid::Uuid;
-use std::time::{SystemTime, UNIX_EPOCH};
-use log::{debug, error, info, warn, trace};
-use std::cmp::Reverse;
-use std::sync::{Arc, mpsc::{Receiver, Sender}};
+use std::time::Duration;
+use log::{debug, error, info, warn};
+use std::sync::{Arc, mpsc::{Receiver, RecvTimeoutError}};
 use crate::matching_engine::model::{
-  Order, OrderType, Side, ExecutionReport, OrderBookSnapshot
+  Order, OrderType, Side, ExecutionReport, ExecType, OrderBookSnapshot, MarketProtection
 };
+use crate::matching_engine::auction;
+use crate::matching_engine::dry_run::{self, DryRunResult};
+use crate::matching_engine::matching::{self, Fill};
+use crate::matching_engine::order_ack::{OrderAck, OrderAckRegistry, OrderAckStatus, OrderRejectReason};
 use crate::matching_engine::order_book::OrderBook;
-use crate::matching_engine::orderbook_tracker::OrderBookTracker;
-use crate::api::models::{WebSocketMessage, OrderBookDelta, OrderBookSnapshot as ApiOrderBookSnapshot};
-use crate::mq::RabbitMQProducer;
+use crate::matching_engine::orderbook_tracker::{OrderBookTracker, OrderBookUpdateKind};
+use crate::matching_engine::match_stats::{self, MatchStats};
+use crate::matching_engine::maker_liquidity::MakerLiquiditySample;
+use crate::matching_engine::book_memory::{BookLifecycleConfig, BookMemoryReport, SymbolBookMemory};
+use crate::api::models::{BboUpdate, WebSocketMessage, OrderBookSnapshot as ApiOrderBookSnapshot, MarketImpactResponse, MicrostructureResponse, EngineStatsResponse, SymbolMatchStats};
+use crate::kill_switch::{KillSwitch, KillSwitchError};
+use crate::mq::{KafkaProducer, RabbitMQProducer};
+use crate::sequencer::backpressure::{BoundedReceiver, BoundedSender};
+use crate::sequencer::replication::ReplicationState;
+use crate::sequencer::throttle::OrderThrottle;
+use crate::sequencer::wait_strategy::WaitStrategy;
+use crate::util::clock::{SharedClock, SystemClock};
+
+/// 프로브 채널이 있을 때 주문 대기 최대 시간 (유휴 상태에서도 프로브에 빨리 응답)
+const PROBE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// 주문 저장소 항목 크기 (키, 주문, 제어 바이트)
+const ORDER_STORE_ENTRY_BYTES: usize = std::mem::size_of::<String>() + std::mem::size_of::<Order>() + 1;
+
+/// 엔진 헬스 프로브 응답
+#[derive(Debug, Clone)]
+pub struct EngineHeartbeat {
+  /// 보관 중인 주문 수
+  pub open_orders: usize,
+  /// 주문장 수
+  pub symbols: usize,
+}
+
+/// 엔진 헬스 프로브 요청 (응답을 받을 채널)
+pub type EngineProbeRequest = tokio::sync::oneshot::Sender<EngineHeartbeat>;
+
+/// 배치 경매 심볼 상태
+struct BatchAuction {
+  /// 경매 주기
+  interval: Duration,
+  /// 직전 경매 시각 (Unix 밀리초)
+  last_run: u64,
+  /// 직전 경매가 (다음 경매 체결가 산정 기준)
+  last_price: Option<u64>,
+}
 
 /// 매칭 엔진 구현
 pub struct MatchingEngine {
@@ -18,19 +58,59 @@ pub struct MatchingEngine {
   order_books: HashMap<String, OrderBook>,
   /// 주문 ID → 주문 객체 저장소
   order_store: HashMap<String, Order>,
-  /// 체결 보고서 송신 채널
-  exec_tx: Sender<ExecutionReport>,
+  /// 체결 보고서 송신 큐 (가득 차면 대기, 체결 보고서는 버리지 않음)
+  exec_tx: BoundedSender<ExecutionReport>,
   /// WebSocket 브로드캐스트 채널
   broadcast_tx: Option<tokio::sync::broadcast::Sender<WebSocketMessage>>,
   /// 호가창 변경 추적기
   orderbook_tracker: OrderBookTracker,
   /// RabbitMQ Producer (WebSocket 알림 발행)
   rabbitmq_producer: Option<Arc<RabbitMQProducer>>,
+  /// 심볼별 시장가 주문 보호 기본값
+  market_protection: HashMap<String, MarketProtection>,
+  /// 만료 인덱스 (만료 시각, 주문 ID) - 시간순 정렬
+  expiry_index: BTreeSet<(u64, String)>,
+  /// 만료 시간이 없는 주문의 최대 대기 시간 (초, None이면 무기한)
+  max_order_age_secs: Option<u64>,
+  /// 만료 검사 주기
+  expiry_interval: Duration,
+  /// 직전 만료 검사 시각 (Unix 밀리초)
+  last_sweep: u64,
+  /// 시각 소스 (만료 판정, 만료 검사/배치 경매 주기)
+  clock: SharedClock,
+  /// 시뮬레이션 시각 (백테스트용, None이면 시각 소스)
+  simulated_time: Option<u64>,
+  /// 헬스 프로브 요청 수신 채널 (주문 처리 루프에서 응답)
+  probe_rx: Option<Receiver<EngineProbeRequest>>,
+  /// 복제 상태 (대기 인스턴스는 호가창을 MQ로 발행하지 않음)
+  replication: Option<Arc<ReplicationState>>,
+  /// 주문 처리 결과를 기다리는 REST 요청
+  order_acks: Option<Arc<OrderAckRegistry>>,
+  /// BBO 전용 Kafka Producer (최우선 호가 변경만 발행)
+  bbo_producer: Option<Arc<KafkaProducer>>,
+  /// 관리자 킬 스위치 (차단 계정, 거래 중단 심볼의 신규 주문 거부)
+  kill_switch: Option<Arc<KillSwitch>>,
+  /// 취소 비율 과다 계정 주문 속도 제한 (시퀀서가 판정, 초당 한도를 넘는 신규 주문 거부)
+  order_throttle: Option<Arc<OrderThrottle>>,
+  /// 배치 경매 심볼 (주기마다 단일가로 청산, 그 사이 지정가 주문은 매칭 없이 주문장에 쌓음)
+  batch_auctions: HashMap<String, BatchAuction>,
+  /// 심볼별 체결 통계 (관리자 엔진 통계 API)
+  match_stats: MatchStats,
+  /// 주문 수신 대기 전략 (기본값은 블로킹 수신)
+  wait_strategy: WaitStrategy,
+  /// 주문장 정리 설정 (None이면 정리하지 않음)
+  book_lifecycle: Option<BookLifecycleConfig>,
+  /// 직전 주문장 정리 시각 (Unix 밀리초)
+  last_compaction: u64,
+  /// 용량을 정리한 가격 레벨 수 (누적)
+  compacted_levels: u64,
+  /// 정리로 돌려준 추정 바이트 (누적)
+  reclaimed_bytes: u64,
 }
 
 impl MatchingEngine {
   /// 새 매칭 엔진 생성
-  pub fn new(symbols: Vec<String>, exec_tx: Sender<ExecutionReport>, rabbitmq_producer: Option<Arc<RabbitMQProducer>>) -> Self {
+  pub fn new(symbols: Vec<String>, exec_tx: BoundedSender<ExecutionReport>, rabbitmq_producer: Option<Arc<RabbitMQProducer>>) -> Self {
     let mut order_books = HashMap::new();
     
     // 지원하는 모든 심볼에 대해 주문장 생성
@@ -40,6 +120,7 @@ impl MatchingEngine {
     
     // 호가창 변경 추적기 생성 (Delta 임계값: 1, Snapshot 간격: 30초)
     let orderbook_tracker = OrderBookTracker::new(1, 30000);
+    let clock = SystemClock::shared();
     
     MatchingEngine {
       order_books,
@@ -48,7 +129,159 @@ impl MatchingEngine {
       broadcast_tx: None,
       orderbook_tracker,
       rabbitmq_producer,
+      market_protection: HashMap::new(),
+      expiry_index: BTreeSet::new(),
+      max_order_age_secs: None,
+      expiry_interval: Duration::from_secs(1),
+      last_sweep: clock.now_millis(),
+      clock,
+      simulated_time: None,
+      probe_rx: None,
+      replication: None,
+      order_acks: None,
+      bbo_producer: None,
+      kill_switch: None,
+      order_throttle: None,
+      batch_auctions: HashMap::new(),
+      match_stats: MatchStats::new(),
+      wait_strategy: WaitStrategy::blocking("engine"),
+      book_lifecycle: None,
+      last_compaction: 0,
+      compacted_levels: 0,
+      reclaimed_bytes: 0,
+    }
+  }
+
+  /// 장기 미체결 주문 최대 대기 시간 설정 (초)
+  pub fn set_max_order_age(&mut self, max_order_age_secs: Option<u64>) {
+    self.max_order_age_secs = max_order_age_secs;
+  }
+
+  /// 만료 검사 주기 설정
+  pub fn set_expiry_interval(&mut self, interval: Duration) {
+    self.expiry_interval = interval;
+  }
+
+  /// 주문장 정리 설정 (None이면 정리하지 않음)
+  pub fn set_book_lifecycle(&mut self, book_lifecycle: Option<BookLifecycleConfig>) {
+    self.last_compaction = self.clock.now_millis();
+    self.book_lifecycle = book_lifecycle;
+  }
+
+  /// 시각 소스 설정 (시뮬레이션 테스트용, 기본값은 시스템 시각)
+  ///
+  /// 만료 판정, 체결 보고서 시각, 만료 검사와 배치 경매 주기가 이 시각을 따릅니다.
+  pub fn set_clock(&mut self, clock: SharedClock) {
+    self.last_sweep = clock.now_millis();
+    for auction in self.batch_auctions.values_mut() {
+      auction.last_run = clock.now_millis();
+    }
+    self.clock = clock;
+  }
+
+  /// 주문 수신 대기 전략 설정 (스핀 → 양보 → 대기, 통계는 전략 복제본과 공유)
+  pub fn set_wait_strategy(&mut self, wait_strategy: WaitStrategy) {
+    self.wait_strategy = wait_strategy;
+  }
+
+  /// 헬스 프로브 채널 설정
+  ///
+  /// 엔진 루프는 매칭 엔진 뮤텍스를 계속 잡고 있으므로, 헬스체크는 이 채널로
+  /// 요청을 보내 주문 처리 루프가 실제로 돌고 있는지 확인합니다.
+  pub fn set_probe_channel(&mut self, probe_rx: Receiver<EngineProbeRequest>) {
+    self.probe_rx = Some(probe_rx);
+  }
+
+  /// 대기 중인 헬스 프로브에 응답
+  fn answer_probes(&mut self) {
+    let Some(probe_rx) = &self.probe_rx else {
+      return;
+    };
+    while let Ok(reply) = probe_rx.try_recv() {
+      let _ = reply.send(EngineHeartbeat {
+        open_orders: self.order_store.len(),
+        symbols: self.order_books.len(),
+      });
+    }
+  }
+
+  /// 주문 대기 시간 (프로브 채널이 있으면 짧게, 배치 경매 주기보다 길지 않게)
+  fn order_wait(&self) -> Duration {
+    let wait = self.batch_auctions.values().map(|auction| auction.interval).fold(self.expiry_interval, Duration::min);
+    if self.probe_rx.is_some() {
+      wait.min(PROBE_POLL_INTERVAL)
+    } else {
+      wait
+    }
+  }
+
+  /// 복제 상태 설정
+  pub fn set_replication_state(&mut self, replication: Arc<ReplicationState>) {
+    self.replication = Some(replication);
+  }
+
+  /// 주문 처리 결과 응답 채널 설정
+  pub fn set_order_acks(&mut self, order_acks: Arc<OrderAckRegistry>) {
+    self.order_acks = Some(order_acks);
+  }
+
+  /// 킬 스위치 설정
+  pub fn set_kill_switch(&mut self, kill_switch: Arc<KillSwitch>) {
+    self.kill_switch = Some(kill_switch);
+  }
+
+  /// 심볼 매칭 방식 설정 (주기를 주면 배치 경매, None이면 연속 매칭)
+  ///
+  /// 연속 매칭으로 돌아갈 때는 쌓여 있던 교차 호가를 경매로 한 번 청산합니다.
+  pub fn set_batch_auction(&mut self, symbol: &str, interval: Option<Duration>) {
+    match interval {
+      Some(interval) => {
+        let last_price = self.batch_auctions.get(symbol).and_then(|auction| auction.last_price);
+        self.batch_auctions.insert(symbol.to_string(), BatchAuction { interval, last_run: self.clock.now_millis(), last_price });
+      }
+      None => {
+        if self.batch_auctions.contains_key(symbol) {
+          self.run_batch_auction(symbol);
+          self.batch_auctions.remove(symbol);
+        }
+      }
+    }
+  }
+
+  /// 배치 경매 심볼 여부
+  pub fn is_batch_auction(&self, symbol: &str) -> bool {
+    self.batch_auctions.contains_key(symbol)
+  }
+
+  /// 계정별 주문 속도 제한 설정
+  pub fn set_order_throttle(&mut self, order_throttle: Arc<OrderThrottle>) {
+    self.order_throttle = Some(order_throttle);
+  }
+
+  /// BBO 전용 Kafka Producer 설정
+  pub fn set_bbo_producer(&mut self, bbo_producer: Arc<KafkaProducer>) {
+    self.bbo_producer = Some(bbo_producer);
+  }
+
+  /// 대기 인스턴스 여부 (주 인스턴스가 이미 발행하므로 MQ로 발행하지 않음)
+  fn is_standby(&self) -> bool {
+    self.replication.as_ref().is_some_and(|replication| replication.is_standby())
+  }
+
+  /// 호가창 발행용 RabbitMQ Producer (대기 인스턴스는 주 인스턴스가 이미 발행하므로 None)
+  fn mq_producer(&self) -> Option<&Arc<RabbitMQProducer>> {
+    if self.is_standby() {
+      return None;
     }
+    self.rabbitmq_producer.as_ref()
+  }
+
+  /// BBO 발행용 Kafka Producer (대기 인스턴스는 None)
+  fn bbo_producer(&self) -> Option<&Arc<KafkaProducer>> {
+    if self.is_standby() {
+      return None;
+    }
+    self.bbo_producer.as_ref()
   }
 
   /// WebSocket 브로드캐스트 채널 설정
@@ -56,68 +289,323 @@ impl MatchingEngine {
     self.broadcast_tx = Some(broadcast_tx);
   }
 
+  /// 심볼별 시장가 주문 보호 기본값 설정
+  pub fn set_market_protection(&mut self, symbol: &str, protection: MarketProtection) {
+    self.market_protection.insert(symbol.to_string(), protection);
+  }
+
+  /// 심볼별 시장가 주문 보호 기본값 조회
+  pub fn get_market_protection(&self, symbol: &str) -> Option<&MarketProtection> {
+    self.market_protection.get(symbol)
+  }
+
+  /// 주문에 적용할 시장가 보호 설정
+  ///
+  /// 주문이 지정하지 않은 필드는 심볼 기본값을 쓰고, 지정한 필드도 심볼 기본값보다 느슨해질 수 없습니다.
+  fn effective_protection(&self, order: &Order) -> MarketProtection {
+    let own = order.protection.clone().unwrap_or_default();
+    match self.market_protection.get(&order.symbol) {
+      Some(limit) => own.clamp_to(limit),
+      None => own,
+    }
+  }
+
   /// 클라이언트 동기화 요청 처리
+  ///
+  /// 이미 발행한 심볼은 마지막 발행 기준 호가를 현재 시퀀스로 돌려주므로 이후 Delta를 그대로 적용할 수 있습니다.
   pub fn handle_sync_request(&mut self, symbol: &str) -> Option<ApiOrderBookSnapshot> {
-    if let Some(snapshot) = self.get_order_book_snapshot(symbol, 10) {
-      Some(self.orderbook_tracker.create_snapshot(symbol, &snapshot))
-    } else {
-      None
+    if let Some(snapshot) = self.orderbook_tracker.published_snapshot(symbol) {
+      return Some(snapshot);
     }
+    let snapshot = self.get_order_book_snapshot(symbol, 10)?;
+    Some(self.orderbook_tracker.create_snapshot(symbol, &snapshot))
+  }
+
+  /// 호가창 키프레임(전체 호가) 간격 설정
+  pub fn set_orderbook_keyframe_interval(&mut self, interval: Duration) {
+    self.orderbook_tracker.set_snapshot_interval(interval.as_millis() as u64);
   }
 
+  /// 시뮬레이션 시각 설정 (Unix 타임스탬프, 초)
+  ///
+  /// 백테스트에서 과거 주문 흐름을 재생할 때 만료 판정과 체결 보고서 시각을
+  /// 재생 중인 시각으로 맞춥니다. None이면 시각 소스(기본값은 시스템 시각)를 사용합니다.
+  pub fn set_simulated_time(&mut self, now: Option<u64>) {
+    self.simulated_time = now;
+  }
+  
   /// 특정 심볼의 현재 시퀀스 번호 조회
   pub fn get_sequence_number(&self, symbol: &str) -> u64 {
     self.orderbook_tracker.get_sequence(symbol)
   }
   
   /// 매칭 엔진 실행 (주문 처리 루프)
-  pub fn run(&mut self, order_rx: Receiver<Order>) {
+  pub fn run(&mut self, order_rx: BoundedReceiver<Order>) {
     info!("매칭 엔진 시작");
     
-    // 주문 수신 및 처리
-    while let Ok(order) = order_rx.recv() {
-      if order.is_cancel {
-        debug!("취소 주문 수신: {}", order.id);
-        self.handle_cancel_order(&order);
-      } else {
-        debug!("새 주문 수신: {} ({}, {}, 가격: {}, 수량: {})", 
-                      order.id, 
-                      order.symbol,
-                      if let Side::Buy = order.side { "매수" } else { "매도" },
-                      order.price,
-                      order.quantity);
-        self.process_order(order);
+    // 주문 수신 및 처리 (만료 검사 주기마다 깨어남)
+    loop {
+      match self.wait_strategy.recv_timeout(&order_rx, self.order_wait()) {
+        Ok(order) => {
+          if order.is_cancel {
+            debug!("취소 주문 수신: {}", order.id);
+            self.handle_cancel_order(&order);
+          } else {
+            debug!("새 주문 수신: {} ({}, {}, 가격: {}, 수량: {})", 
+                          order.id, 
+                          order.symbol,
+                          if let Side::Buy = order.side { "매수" } else { "매도" },
+                          order.price,
+                          order.quantity);
+            self.process_order(order);
+          }
+        }
+        Err(RecvTimeoutError::Timeout) => {}
+        Err(RecvTimeoutError::Disconnected) => break,
       }
+      
+      self.run_timers();
     }
     
     info!("매칭 엔진 종료");
   }
 
   /// 시퀀서를 통한 매칭 엔진 실행 (순서 보장)
-  pub fn run_sequenced(&mut self, order_rx: Receiver<Order>) {
+  pub fn run_sequenced(&mut self, order_rx: BoundedReceiver<Order>) {
     info!("매칭 엔진 시작 (시퀀서 모드)");
     
-    // 주문 수신 및 처리 (FIFO 순서 보장)
-    while let Ok(order) = order_rx.recv() {
-      if order.is_cancel {
-        debug!("[시퀀서] 취소 주문 수신: {}", order.id);
-        self.handle_cancel_order(&order);
-      } else {
-        debug!("[시퀀서] 새 주문 수신: {} ({}, {}, 가격: {}, 수량: {})", 
-                      order.id, 
-                      order.symbol,
-                      if let Side::Buy = order.side { "매수" } else { "매도" },
-                      order.price,
-                      order.quantity);
-        self.process_order(order);
+    // 주문 수신 및 처리 (FIFO 순서 보장, 만료 검사 주기마다 깨어남)
+    loop {
+      match self.wait_strategy.recv_timeout(&order_rx, self.order_wait()) {
+        Ok(order) => {
+          if order.is_cancel {
+            debug!("[시퀀서] 취소 주문 수신: {}", order.id);
+            self.handle_cancel_order(&order);
+          } else {
+            debug!("[시퀀서] 새 주문 수신: {} ({}, {}, 가격: {}, 수량: {})", 
+                          order.id, 
+                          order.symbol,
+                          if let Side::Buy = order.side { "매수" } else { "매도" },
+                          order.price,
+                          order.quantity);
+            self.process_order(order);
+          }
+        }
+        Err(RecvTimeoutError::Timeout) => {}
+        Err(RecvTimeoutError::Disconnected) => break,
       }
+      
+      self.run_timers();
     }
     
     info!("매칭 엔진 종료 (시퀀서 모드)");
   }
+
+  /// 공유 매칭 엔진 실행 (API 핸들러와 같은 엔진을 쓸 때)
+  ///
+  /// 주문을 기다리는 동안에는 잠금을 풀어 두므로 API가 호가창/미체결 주문을 조회할 수 있습니다.
+  /// 블로킹 루프이므로 `spawn_blocking`이나 별도 스레드에서 실행해야 합니다.
+  pub fn run_shared(engine: Arc<tokio::sync::Mutex<Self>>, order_rx: BoundedReceiver<Order>) {
+    info!("매칭 엔진 시작 (공유 모드)");
+
+    let wait_strategy = engine.blocking_lock().wait_strategy.clone();
+    loop {
+      let wait = engine.blocking_lock().order_wait();
+      let received = match wait_strategy.recv_timeout(&order_rx, wait) {
+        Ok(order) => Some(order),
+        Err(RecvTimeoutError::Timeout) => None,
+        Err(RecvTimeoutError::Disconnected) => break,
+      };
+
+      let mut engine = engine.blocking_lock();
+      if let Some(order) = received {
+        engine.submit(order);
+      }
+      engine.run_timers();
+    }
+
+    info!("매칭 엔진 종료 (공유 모드)");
+  }
+
+  /// 주문 한 건 처리 (취소 주문이면 취소, 아니면 매칭)
+  ///
+  /// 주문 큐 없이 엔진을 직접 구동할 때(백테스트 등) 사용하는 진입점입니다.
+  pub fn submit(&mut self, order: Order) {
+    if order.is_cancel {
+      self.handle_cancel_order(&order);
+    } else {
+      self.process_order(order);
+    }
+  }
+
+  /// 백업에서 복원한 미체결 지정가 주문을 매칭 없이 주문장에 다시 올림 (엔진 시작 전)
+  ///
+  /// 접수 순서대로 넘겨야 같은 가격의 시간 우선순위가 유지됩니다.
+  /// 담당하지 않는 심볼과 이미 있는 주문은 건너뛰며, 올린 주문 수를 반환합니다.
+  pub fn restore_resting_orders(&mut self, orders: Vec<Order>) -> usize {
+    let mut restored = 0;
+    for order in orders {
+      if order.order_type != OrderType::Limit || self.order_store.contains_key(&order.id) {
+        continue;
+      }
+      let Some(order_book) = self.order_books.get_mut(&order.symbol) else {
+        warn!("복원 주문 건너뜀 (담당하지 않는 심볼): {} {}", order.id, order.symbol);
+        continue;
+      };
+      order_book.add_order(order.clone());
+      if let Some(expire_at) = self.effective_expiry(&order) {
+        self.expiry_index.insert((expire_at, order.id.clone()));
+      }
+      self.order_store.insert(order.id.clone(), order);
+      restored += 1;
+    }
+    info!("미체결 주문 {}건 주문장 복원", restored);
+    restored
+  }
+
+  /// 만료된 주문 정리
+  ///
+  /// 만료 인덱스에서 `now` 이전에 만료된 주문을 꺼내 주문장에서 제거하고
+  /// Expired 체결 보고서를 발행합니다. 이미 체결/취소된 주문은 건너뜁니다.
+  /// 반환값은 실제로 만료 처리된 주문 수입니다.
+  pub fn expire_orders(&mut self, now: u64) -> usize {
+    let mut expired = 0;
+    let mut touched_symbols = Vec::new();
+    
+    while let Some((expire_at, order_id)) = self.expiry_index.iter().next().cloned() {
+      if expire_at > now {
+        break;
+      }
+      self.expiry_index.remove(&(expire_at, order_id.clone()));
+      
+      // 이미 체결되었거나 취소된 주문은 무시
+      let symbol = match self.order_store.get(&order_id) {
+        Some(order) => order.symbol.clone(),
+        None => continue,
+      };
+      
+      let removed = self.order_books.get_mut(&symbol)
+        .and_then(|order_book| order_book.cancel_order(&order_id));
+      self.order_store.remove(&order_id);
+      
+      if let Some(expired_order) = removed {
+        let expire_report = ExecutionReport {
+          execution_id: Uuid::new_v4().to_string(),
+          order_id: order_id.clone(),
+          symbol: expired_order.symbol.clone(),
+          side: expired_order.side.clone(),
+          price: expired_order.price,
+          quantity: expired_order.remaining_quantity,
+          remaining_quantity: 0,
+          timestamp: now,
+          counterparty_id: "system".to_string(),
+          is_maker: false,
+          exec_type: ExecType::Expired,
+          sequence: 0,
+          tag: expired_order.tag.clone(),
+        };
+        
+        if let Err(e) = self.exec_tx.send(expire_report) {
+          error!("만료 보고서 전송 실패: {}", e);
+        } else {
+          debug!("주문 만료: {} (만료 시각: {})", order_id, expire_at);
+        }
+        
+        expired += 1;
+        if !touched_symbols.contains(&symbol) {
+          touched_symbols.push(symbol);
+        }
+      }
+    }
+    
+    // 만료 처리 후 호가창 업데이트 브로드캐스트
+    for symbol in touched_symbols {
+      self.broadcast_orderbook_update(&symbol);
+    }
+    
+    if expired > 0 {
+      info!("만료 주문 정리 완료: {}건", expired);
+    }
+    expired
+  }
+
+  /// 주문의 실효 만료 시각 계산 (GTD 만료 시간 또는 최대 대기 시간)
+  fn effective_expiry(&self, order: &Order) -> Option<u64> {
+    order.expire_time
+      .or_else(|| self.max_order_age_secs.map(|age| order.timestamp.saturating_add(age)))
+  }
+
+  /// 엔진 기준 현재 시각 (시뮬레이션 시각이 있으면 그 값, 초)
+  fn clock(&self) -> u64 {
+    self.simulated_time.unwrap_or_else(|| self.clock.now_secs())
+  }
+
+  /// 주기 작업 실행 (만료 검사 주기가 지났으면 만료 정리, 예정된 배치 경매, 헬스 프로브 응답)
+  ///
+  /// 주문 처리 루프가 주문을 받을 때마다, 또는 대기 시간이 끝날 때마다 호출합니다.
+  pub fn run_timers(&mut self) {
+    let now = self.clock.now_millis();
+    if now.saturating_sub(self.last_sweep) >= self.expiry_interval.as_millis() as u64 {
+      self.expire_orders(self.clock());
+      self.last_sweep = now;
+    }
+    self.run_due_batch_auctions();
+    self.run_due_compaction(now);
+    self.answer_probes();
+  }
+
+  /// 정리 주기가 지났으면 주문장과 주문 저장소의 남는 용량 정리
+  fn run_due_compaction(&mut self, now: u64) {
+    let Some(lifecycle) = self.book_lifecycle else {
+      return;
+    };
+    if now.saturating_sub(self.last_compaction) < lifecycle.interval.as_millis() as u64 {
+      return;
+    }
+    self.last_compaction = now;
+
+    let mut levels = 0;
+    let mut reclaimed = 0;
+    for order_book in self.order_books.values_mut() {
+      let result = order_book.compact_levels(&lifecycle.policy);
+      levels += result.levels;
+      reclaimed += result.reclaimed_bytes;
+    }
+    let capacity = self.order_store.capacity();
+    let slack = capacity.saturating_sub(self.order_store.len());
+    if slack >= lifecycle.policy.min_slack && slack > self.order_store.len().saturating_mul(lifecycle.policy.slack_ratio) {
+      self.order_store.shrink_to_fit();
+      reclaimed += capacity.saturating_sub(self.order_store.capacity()) * ORDER_STORE_ENTRY_BYTES;
+    }
+
+    if reclaimed > 0 {
+      self.compacted_levels += levels as u64;
+      self.reclaimed_bytes += reclaimed as u64;
+      info!("주문장 용량 정리: 가격 레벨 {}개, 약 {}KB 반환", levels, reclaimed / 1024);
+    }
+  }
+
+  /// 주문장 메모리 사용량 (심볼순, 바이트는 추정치)
+  pub fn book_memory(&self) -> BookMemoryReport {
+    let mut symbols: Vec<SymbolBookMemory> = self
+      .order_books
+      .iter()
+      .map(|(symbol, order_book)| SymbolBookMemory { symbol: symbol.clone(), memory: order_book.memory_usage() })
+      .collect();
+    symbols.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+
+    let id_bytes: usize = self.order_store.keys().map(|id| id.capacity()).sum();
+    BookMemoryReport {
+      symbols,
+      order_store_bytes: self.order_store.capacity() * ORDER_STORE_ENTRY_BYTES + id_bytes,
+      compacted_levels: self.compacted_levels,
+      reclaimed_bytes: self.reclaimed_bytes,
+    }
+  }
   
   /// 취소 주문 처리
   pub fn handle_cancel_order(&mut self, cancel_order: &Order) {
+    let now = self.clock();
     if let Some(target_order_id) = &cancel_order.target_order_id {
       // 취소할 원본 주문 찾기
       if let Some(order) = self.order_store.get(target_order_id) {
@@ -133,14 +621,14 @@ impl MatchingEngine {
               symbol: cancelled_order.symbol.clone(),
               side: cancelled_order.side.clone(),
               price: cancelled_order.price,
-              quantity: 0,
+              quantity: cancelled_order.remaining_quantity,
               remaining_quantity: 0,
-              timestamp: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
+              timestamp: now,
               counterparty_id: "system".to_string(),
               is_maker: false,
+              exec_type: ExecType::Canceled,
+              sequence: 0,
+              tag: cancelled_order.tag.clone(),
             };
             
             // 체결 보고서 전송
@@ -169,33 +657,193 @@ impl MatchingEngine {
     }
   }
   
-  /// 주문 처리
-  fn process_order(&mut self, mut order: Order) {
+  /// 주문 처리 (결과를 기다리는 요청이 있으면 응답)
+  fn process_order(&mut self, order: Order) {
+    let ack = self.execute_order(order);
+    if let Some(order_acks) = &self.order_acks {
+      order_acks.complete(ack);
+    }
+  }
+  
+  /// 주문 매칭 후 처리 결과 반환
+  fn execute_order(&mut self, mut order: Order) -> OrderAck {
     let symbol = order.symbol.clone();
+    let rejected = |order: &Order, reason| OrderAck {
+      order_id: order.id.clone(),
+      status: OrderAckStatus::Rejected(reason),
+      filled_quantity: 0,
+      remaining_quantity: 0,
+    };
     
     // 지원 심볼 확인
     if !self.order_books.contains_key(&symbol) {
       error!("지원하지 않는 심볼: {}", symbol);
-      return;
+      let reject_report = ExecutionReport {
+        execution_id: Uuid::new_v4().to_string(),
+        order_id: order.id.clone(),
+        symbol: order.symbol.clone(),
+        side: order.side.clone(),
+        price: order.price,
+        quantity: 0,
+        remaining_quantity: 0,
+        timestamp: self.clock(),
+        counterparty_id: "system".to_string(),
+        is_maker: false,
+        exec_type: ExecType::Rejected,
+        sequence: 0,
+        tag: order.tag.clone(),
+      };
+      if let Err(e) = self.exec_tx.send(reject_report) {
+        error!("거부 보고서 전송 실패: {}", e);
+      }
+      return rejected(&order, OrderRejectReason::UnknownSymbol);
+    }
+    
+    // 킬 스위치 확인 (REST 검증 이후 큐에 있던 주문도 여기서 거부)
+    if let Some(Err(e)) = self.kill_switch.as_ref().map(|k| k.check_order(&order.client_id, &symbol)) {
+      warn!("킬 스위치로 주문 거부: {} ({})", order.id, e);
+      let reason = match e {
+        KillSwitchError::SymbolHalted { .. } => OrderRejectReason::SymbolHalted,
+        _ => OrderRejectReason::ClientBlocked,
+      };
+      let reject_report = ExecutionReport {
+        execution_id: Uuid::new_v4().to_string(),
+        order_id: order.id.clone(),
+        symbol: order.symbol.clone(),
+        side: order.side.clone(),
+        price: order.price,
+        quantity: 0,
+        remaining_quantity: 0,
+        timestamp: self.clock(),
+        counterparty_id: "system".to_string(),
+        is_maker: false,
+        exec_type: ExecType::Rejected,
+        sequence: 0,
+        tag: order.tag.clone(),
+      };
+      if let Err(e) = self.exec_tx.send(reject_report) {
+        error!("거부 보고서 전송 실패: {}", e);
+      }
+      return rejected(&order, reason);
+    }
+
+    // 주문 속도 제한 중인 계정은 초당 한도까지만 받음
+    if self.order_throttle.as_ref().is_some_and(|t| !t.admit(&order.client_id, t.now_millis())) {
+      warn!("주문 속도 제한으로 주문 거부: {} ({})", order.id, order.client_id);
+      let reject_report = ExecutionReport {
+        execution_id: Uuid::new_v4().to_string(),
+        order_id: order.id.clone(),
+        symbol: order.symbol.clone(),
+        side: order.side.clone(),
+        price: order.price,
+        quantity: 0,
+        remaining_quantity: 0,
+        timestamp: self.clock(),
+        counterparty_id: "system".to_string(),
+        is_maker: false,
+        exec_type: ExecType::Rejected,
+        sequence: 0,
+        tag: order.tag.clone(),
+      };
+      if let Err(e) = self.exec_tx.send(reject_report) {
+        error!("거부 보고서 전송 실패: {}", e);
+      }
+      return rejected(&order, OrderRejectReason::Throttled);
+    }
+    
+    // 이미 만료된 주문은 매칭하지 않음
+    if order.is_expired(self.clock()) {
+      warn!("만료 시간이 지난 주문 거부: {}", order.id);
+      let expire_report = ExecutionReport {
+        execution_id: Uuid::new_v4().to_string(),
+        order_id: order.id.clone(),
+        symbol: order.symbol.clone(),
+        side: order.side.clone(),
+        price: order.price,
+        quantity: order.remaining_quantity,
+        remaining_quantity: 0,
+        timestamp: self.clock(),
+        counterparty_id: "system".to_string(),
+        is_maker: false,
+        exec_type: ExecType::Expired,
+        sequence: 0,
+        tag: order.tag.clone(),
+      };
+      if let Err(e) = self.exec_tx.send(expire_report) {
+        error!("만료 보고서 전송 실패: {}", e);
+      }
+      return rejected(&order, OrderRejectReason::Expired);
+    }
+
+    // 배치 경매 심볼은 경매가 산정에 참여할 지정가 주문만 받음
+    if order.order_type == OrderType::Market && self.batch_auctions.contains_key(&symbol) {
+      warn!("배치 경매 심볼의 시장가 주문 거부: {}", order.id);
+      let reject_report = ExecutionReport {
+        execution_id: Uuid::new_v4().to_string(),
+        order_id: order.id.clone(),
+        symbol: order.symbol.clone(),
+        side: order.side.clone(),
+        price: order.price,
+        quantity: 0,
+        remaining_quantity: 0,
+        timestamp: self.clock(),
+        counterparty_id: "system".to_string(),
+        is_maker: false,
+        exec_type: ExecType::Rejected,
+        sequence: 0,
+        tag: order.tag.clone(),
+      };
+      if let Err(e) = self.exec_tx.send(reject_report) {
+        error!("거부 보고서 전송 실패: {}", e);
+      }
+      return rejected(&order, OrderRejectReason::MarketOrderInAuction);
     }
     
     // 주문 저장
     self.order_store.insert(order.id.clone(), order.clone());
     
     // 주문 타입에 따라 처리
+    let mut ack = OrderAck {
+      order_id: order.id.clone(),
+      status: OrderAckStatus::Accepted,
+      filled_quantity: 0,
+      remaining_quantity: 0,
+    };
     match order.order_type {
       OrderType::Market => {
         debug!("시장가 주문 처리: {}", order.id);
         self.match_market_order(&mut order, symbol.clone());
+        
+        // 시장가 주문은 주문장에 남지 않음 (남은 수량은 취소)
+        ack.filled_quantity = order.quantity - order.remaining_quantity;
+        ack.status = if order.is_filled() { OrderAckStatus::Filled } else { OrderAckStatus::Canceled };
       },
       OrderType::Limit => {
         debug!("지정가 주문 처리: {} (가격: {})", order.id, order.price);
-        self.match_limit_order(&mut order, symbol.clone());
+        // 배치 경매 심볼은 다음 경매까지 매칭하지 않고 주문장에 쌓음
+        if !self.batch_auctions.contains_key(&symbol) {
+          self.match_limit_order(&mut order, symbol.clone());
+        }
+        
+        ack.filled_quantity = order.quantity - order.remaining_quantity;
+        ack.remaining_quantity = order.remaining_quantity;
+        ack.status = if order.is_filled() {
+          OrderAckStatus::Filled
+        } else if ack.filled_quantity > 0 {
+          OrderAckStatus::PartiallyFilled
+        } else {
+          OrderAckStatus::Accepted
+        };
         
         // 완전히 체결되지 않은 경우 주문장에 추가
         if !order.is_filled() {
           debug!("미체결 지정가 주문 주문장 추가: {}, 남은 수량: {}", 
                           order.id, order.remaining_quantity);
+          if let Some(expire_at) = self.effective_expiry(&order) {
+            self.expiry_index.insert((expire_at, order.id.clone()));
+          }
+          // 저장소에는 접수 시점 사본이 있으므로 체결 후 남은 수량으로 갱신
+          self.order_store.insert(order.id.clone(), order.clone());
           let order_book = self.order_books.get_mut(&symbol).unwrap();
           order_book.add_order(order);
         } else {
@@ -208,274 +856,170 @@ impl MatchingEngine {
     
     // 주문 처리 후 호가창 업데이트 브로드캐스트
     self.broadcast_orderbook_update(&symbol);
+    ack
   }
   
   /// 시장가 주문 매칭
+  ///
+  /// 주문 보호 설정과 심볼 기본값을 필드별로 합쳐 적용합니다 (`effective_protection`).
+  /// 슬리피지 한도나 최대 레벨 수에 도달하면 남은 수량은 취소됩니다.
   fn match_market_order(&mut self, order: &mut Order, symbol: String) {
-    // order_book을 self에서 미리 꺼냄
-    let order_book_ptr = self.order_books.get_mut(&symbol).unwrap() as *mut OrderBook;
-    
-    match order.side {
-      Side::Buy => {
-        // 매수 주문(테이커)은 매도 주문(메이커)과 매칭
-        while !order.is_filled() {
-          let order_book = unsafe { &mut *order_book_ptr };
-          
-          // 최저 매도가 확인
-          if let Some((price, _price_level)) = order_book.get_best_ask() {
-            // 최대 매칭 가능 수량 계산
-            let match_qty = order.remaining_quantity;
-            
-            // 매칭 시도
-            self.match_at_price_level(order, order_book, price, match_qty);
-            
-            // 매칭 후에도 매도 호가가 남아있는지 확인
-            if order_book.asks.is_empty() || order_book.get_best_ask().is_none() {
-              debug!("더 이상 매칭할 매도 주문 없음, 시장가 매수 주문 미체결: {}", order.id);
-              break;
-            }
-          } else {
-            debug!("매칭할 매도 주문 없음, 시장가 매수 주문 미체결: {}", order.id);
-            break;
-          }
-        }
-      }
-      Side::Sell => {
-        // 매도 주문(테이커)은 매수 주문(메이커)과 매칭
-        while !order.is_filled() {
-          let order_book = unsafe { &mut *order_book_ptr };
-          
-          // 최고 매수가 확인
-          if let Some((price, _price_level)) = order_book.get_best_bid() {
-            // 최대 매칭 가능 수량 계산
-            let match_qty = order.remaining_quantity;
-            
-            // 매칭 시도
-            self.match_at_price_level(order, order_book, price, match_qty);
-            
-            // 매칭 후에도 매수 호가가 남아있는지 확인
-            if order_book.bids.is_empty() || order_book.get_best_bid().is_none() {
-              debug!("더 이상 매칭할 매수 주문 없음, 시장가 매도 주문 미체결: {}", order.id);
-              break;
-            }
-          } else {
-            debug!("매칭할 매수 주문 없음, 시장가 매도 주문 미체결: {}", order.id);
-            break;
-          }
-        }
-      }
-    }
+    let protection = self.effective_protection(order);
+    
+    let order_book = self.order_books.get_mut(&symbol).unwrap();
+    let outcome = matching::match_order(order_book, order, &protection);
+    self.apply_fills(order, &outcome.fills);
     
     debug!("시장가 주문 처리 완료: {}, 체결량: {}/{}", 
         order.id, order.quantity - order.remaining_quantity, order.quantity);
     
+    // 남은 수량 취소 (시장가 주문은 주문장에 남지 않음)
+    if !order.is_filled() {
+      let cancel_report = ExecutionReport {
+        execution_id: Uuid::new_v4().to_string(),
+        order_id: order.id.clone(),
+        symbol: order.symbol.clone(),
+        side: order.side.clone(),
+        price: order.price,
+        quantity: order.remaining_quantity,
+        remaining_quantity: 0,
+        timestamp: self.clock(),
+        counterparty_id: "system".to_string(),
+        is_maker: false,
+        exec_type: ExecType::Canceled,
+        sequence: 0,
+        tag: order.tag.clone(),
+      };
+      
+      if let Err(e) = self.exec_tx.send(cancel_report) {
+        error!("시장가 잔량 취소 보고서 전송 실패: {}", e);
+      } else {
+        info!("시장가 주문 잔량 취소 ({}): {}, 취소 수량: {}",
+              if outcome.protection_triggered { "보호 한도" } else { "호가 부족" }, order.id, order.remaining_quantity);
+      }
+    }
+    
     // 처리 완료한 주문은 저장소에서 제거
     self.order_store.remove(&order.id);
   }
   
-  /// 지정가 주문 매칭
+  /// 지정가 주문 매칭 (지정가를 넘지 않는 호가까지)
   fn match_limit_order(&mut self, order: &mut Order, symbol: String) {
-    // order_book을 self에서 미리 꺼냄
-    let order_book_ptr = self.order_books.get_mut(&symbol).unwrap() as *mut OrderBook;
-    
-    match order.side {
-      Side::Buy => {
-        // 매수 주문(테이커)은 매도 주문(메이커)과 매칭
-        let mut continue_matching = true;
-        
-        while !order.is_filled() && continue_matching {
-          let order_book = unsafe { &mut *order_book_ptr };
-          
-          if let Some((ask_price, _price_level)) = order_book.get_best_ask() {
-            // 가격 확인: 매수가 >= 매도가 (체결 가능)
-            if order.price >= ask_price {
-              // 최대 매칭 가능 수량 계산
-              let match_qty = order.remaining_quantity;
-              
-              // 매칭 시도
-              self.match_at_price_level(order, order_book, ask_price, match_qty);
-              
-              // 매칭 후에도 매도 호가가 남아있는지 확인
-              if order_book.asks.is_empty() || order_book.get_best_ask().is_none() {
-                debug!("더 이상 매칭할 매도 주문 없음");
-                continue_matching = false;
-              }
-            } else {
-              // 가격이 맞지 않으면 매칭 종료
-              debug!("매칭 불가: 매수가({}) < 매도가({})", order.price, ask_price);
-              continue_matching = false;
-            }
-          } else {
-            // 매도 호가가 없으면 매칭 종료
-            debug!("매칭할 매도 주문 없음");
-            continue_matching = false;
-          }
-        }
-      }
-      Side::Sell => {
-        // 매도 주문(테이커)은 매수 주문(메이커)과 매칭
-        let mut continue_matching = true;
-        
-        while !order.is_filled() && continue_matching {
-          let order_book = unsafe { &mut *order_book_ptr };
-          
-          if let Some((bid_price, _price_level)) = order_book.get_best_bid() {
-            // 가격 확인: 매도가 <= 매수가 (체결 가능)
-            if order.price <= bid_price {
-              // 최대 매칭 가능 수량 계산
-              let match_qty = order.remaining_quantity;
-              
-              // 매칭 시도
-              self.match_at_price_level(order, order_book, bid_price, match_qty);
-              
-              // 매칭 후에도 매수 호가가 남아있는지 확인
-              if order_book.bids.is_empty() || order_book.get_best_bid().is_none() {
-                debug!("더 이상 매칭할 매수 주문 없음");
-                continue_matching = false;
-              }
-            } else {
-              // 가격이 맞지 않으면 매칭 종료
-              debug!("매칭 불가: 매도가({}) > 매수가({})", order.price, bid_price);
-              continue_matching = false;
-            }
-          } else {
-            // 매수 호가가 없으면 매칭 종료
-            debug!("매칭할 매수 주문 없음");
-            continue_matching = false;
-          }
-        }
+    let order_book = self.order_books.get_mut(&symbol).unwrap();
+    let outcome = matching::match_order(order_book, order, &MarketProtection::default());
+    self.apply_fills(order, &outcome.fills);
+  }
+  
+  /// 체결 내역 반영 - 메이커 주문 저장소 갱신과 taker/maker 체결 보고서 전송
+  fn apply_fills(&mut self, taker: &Order, fills: &[Fill]) {
+    for fill in fills {
+      // 완전 체결된 메이커는 저장소에서 제거, 부분 체결은 남은 수량으로 갱신
+      if fill.maker.is_filled() {
+        self.order_store.remove(&fill.maker.id);
+      } else {
+        self.order_store.insert(fill.maker.id.clone(), fill.maker.clone());
       }
+      self.send_trade_reports(taker, fill.taker_remaining, &fill.maker, fill.price, fill.quantity);
     }
   }
-  
-  /// 특정 가격 레벨에서 주문 매칭
+  /// 체결 한 건의 taker/maker 체결 보고서 전송 (같은 체결 ID)
   ///
-  /// 지정된 가격과 수량으로 주문을 매칭합니다.
-  /// 이 메서드는 실제 체결 처리와 체결 보고서 생성을 담당합니다.
-  ///
-  /// # 매개변수
-  /// - `order`: 매칭할 주문 (테이커)
-  /// - `order_book`: 주문장
-  /// - `price`: 체결 가격
-  /// - `match_qty`: 체결 수량
-  fn match_at_price_level(
-    &mut self,
-    order: &mut Order,
-    order_book: &mut OrderBook,
-    price: u64,
-    match_qty: u64
-  ) {
-    // 매칭할 수량이 없으면 종료
-    if match_qty == 0 {
-      return;
+  /// 체결 통계도 여기서 기록하므로 연속 매칭과 배치 경매 모두 집계됩니다.
+  fn send_trade_reports(&mut self, taker: &Order, taker_remaining: u64, maker: &Order, price: u64, quantity: u64) {
+    let now = self.clock();
+    self.match_stats.record_trade(&taker.symbol, quantity, now);
+    let exec_id = Uuid::new_v4().to_string();
+    let taker_exec = ExecutionReport {
+      execution_id: exec_id.clone(),
+      order_id: taker.id.clone(),
+      symbol: taker.symbol.clone(),
+      side: taker.side.clone(),
+      price,
+      quantity,
+      remaining_quantity: taker_remaining,
+      timestamp: now,
+      counterparty_id: maker.id.clone(),
+      is_maker: false,
+      exec_type: ExecType::Trade,
+      sequence: 0,
+      tag: taker.tag.clone(),
+    };
+    
+    if let Err(e) = self.exec_tx.send(taker_exec) {
+      error!("테이커 체결 보고서 전송 실패: {}", e);
     }
     
-    // 반대편 가격 레벨 가져오기
-    let opposite_side = match order.side {
-      Side::Buy => Side::Sell,
-      Side::Sell => Side::Buy,
+    let maker_exec = ExecutionReport {
+      execution_id: exec_id,
+      order_id: maker.id.clone(),
+      symbol: maker.symbol.clone(),
+      side: maker.side.clone(),
+      price,
+      quantity,
+      remaining_quantity: maker.remaining_quantity,
+      timestamp: now,
+      counterparty_id: taker.id.clone(),
+      is_maker: true,
+      exec_type: ExecType::Trade,
+      sequence: 0,
+      tag: maker.tag.clone(),
     };
     
-    // 반대 가격 레벨에서 주문 매칭
-    let result = match opposite_side {
-      Side::Buy => {
-        // 매수 가격 레벨에서 매칭
-        if let Some(price_level) = order_book.bids.get_mut(&Reverse(price)) {
-          price_level.match_partial(match_qty)
-        } else {
-          None
-        }
-      },
-      Side::Sell => {
-        // 매도 가격 레벨에서 매칭
-        if let Some(price_level) = order_book.asks.get_mut(&price) {
-          price_level.match_partial(match_qty)
-        } else {
-          None
-        }
-      }
+    if let Err(e) = self.exec_tx.send(maker_exec) {
+      error!("메이커 체결 보고서 전송 실패: {}", e);
+    }
+  }
+
+  /// 주기가 된 배치 경매 실행
+  fn run_due_batch_auctions(&mut self) {
+    let now = self.clock.now_millis();
+    let due: Vec<String> = self
+      .batch_auctions
+      .iter()
+      .filter(|(_, auction)| now.saturating_sub(auction.last_run) >= auction.interval.as_millis() as u64)
+      .map(|(symbol, _)| symbol.clone())
+      .collect();
+    for symbol in due {
+      self.run_batch_auction(&symbol);
+    }
+  }
+
+  /// 심볼 경매 즉시 실행 (교차한 호가를 단일가로 청산, 체결 수량 반환)
+  ///
+  /// 경매에는 공격 주문이 없으므로 체결마다 나중에 들어온 주문을 테이커로 기록합니다.
+  pub fn run_batch_auction(&mut self, symbol: &str) -> u64 {
+    let now = self.clock.now_millis();
+    let reference_price = self.batch_auctions.get_mut(symbol).and_then(|auction| {
+      auction.last_run = now;
+      auction.last_price
+    });
+    let Some(order_book) = self.order_books.get_mut(symbol) else {
+      return 0;
     };
-    
-    // 매칭 결과 처리
-    if let Some((cloned_maker_id, cloned_maker, actual_match_qty)) = result {
-      // 빈 가격 레벨 제거 확인
-      match opposite_side {
-        Side::Buy => {
-          if let Some(price_level) = order_book.bids.get(&Reverse(price)) {
-            if price_level.is_empty() {
-              order_book.bids.remove(&Reverse(price));
-              debug!("빈 가격 레벨 제거 (매수): {}", price);
-            }
-          }
-        },
-        Side::Sell => {
-          if let Some(price_level) = order_book.asks.get(&price) {
-            if price_level.is_empty() {
-              order_book.asks.remove(&price);
-              debug!("빈 가격 레벨 제거 (매도): {}", price);
-            }
-          }
+    let Some(cross) = auction::find_clearing_price(order_book, reference_price) else {
+      return 0;
+    };
+    let fills = auction::uncross(order_book, &cross);
+
+    for fill in &fills {
+      for order in [&fill.buy, &fill.sell] {
+        if order.is_filled() {
+          self.order_store.remove(&order.id);
+        } else {
+          self.order_store.insert(order.id.clone(), order.clone());
         }
       }
-      
-      // 테이커 주문 수량 업데이트
-      order.fill(actual_match_qty);
-      
-      // 메이커 주문 저장소 업데이트 (완전 체결된 경우 제거)
-      if cloned_maker.is_filled() {
-        order_book.orders.remove(&cloned_maker_id);
-        self.order_store.remove(&cloned_maker_id);
-      } else {
-        // 부분 체결된 경우 저장소 업데이트
-        self.order_store.insert(cloned_maker_id.clone(), cloned_maker.clone());
-      }
-      
-      // 현재 시간 가져오기
-      let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
-      
-      // taker 체결 보고서 생성 
-      let exec_id = Uuid::new_v4().to_string();
-      let taker_exec = ExecutionReport {
-        execution_id: exec_id.clone(),
-        order_id: order.id.clone(),
-        symbol: order.symbol.clone(),
-        side: order.side.clone(),
-        price,
-        quantity: actual_match_qty,
-        remaining_quantity: order.remaining_quantity,
-        timestamp: now,
-        counterparty_id: cloned_maker.id.clone(),
-        is_maker: false,
-      };
-      
-      if let Err(e) = self.exec_tx.send(taker_exec) {
-        error!("테이커 체결 보고서 전송 실패: {}", e);
-      }
-      
-      // maker 체결 보고서 생성
-      let maker_exec = ExecutionReport {
-        execution_id: exec_id,
-        order_id: cloned_maker.id.clone(),
-        symbol: cloned_maker.symbol.clone(),
-        side: cloned_maker.side.clone(),
-        price,
-        quantity: actual_match_qty,
-        remaining_quantity: cloned_maker.remaining_quantity,
-        timestamp: now,
-        counterparty_id: order.id.clone(),
-        is_maker: true,
-      };
-      
-      if let Err(e) = self.exec_tx.send(maker_exec) {
-        error!("메이커 체결 보고서 전송 실패: {}", e);
-      }
-      
-      debug!("주문 체결: {} <-> {}, 가격: {}, 수량: {}", 
-                  order.id, cloned_maker.id, price, actual_match_qty);
+      let buy_is_taker = (fill.buy.sequence, fill.buy.timestamp) >= (fill.sell.sequence, fill.sell.timestamp);
+      let (taker, maker) = if buy_is_taker { (&fill.buy, &fill.sell) } else { (&fill.sell, &fill.buy) };
+      self.send_trade_reports(taker, taker.remaining_quantity, maker, fill.price, fill.quantity);
+    }
+    if let Some(auction) = self.batch_auctions.get_mut(symbol) {
+      auction.last_price = Some(cross.price);
     }
+
+    info!("배치 경매 체결: {} (가격: {}, 수량: {}, 잔량 불균형: {})", symbol, cross.price, cross.volume, cross.imbalance);
+    self.broadcast_orderbook_update(symbol);
+    cross.volume
   }
   
   /// 주문장 조회
@@ -488,91 +1032,162 @@ impl MatchingEngine {
     self.order_store.get(order_id)
   }
   
+  /// 고객의 미체결 주문 (주문 ID순)
+  pub fn get_open_orders(&self, client_id: &str) -> Vec<&Order> {
+    let mut orders: Vec<&Order> = self.order_store.values().filter(|order| order.client_id == client_id).collect();
+    orders.sort_by(|a, b| a.id.cmp(&b.id));
+    orders
+  }
+  
+  /// 주문장을 바꾸지 않고 주문을 모의 체결 (주문장이 없는 심볼이면 None)
+  pub fn simulate_order(&self, order: &Order) -> Option<DryRunResult> {
+    let order_book = self.order_books.get(&order.symbol)?;
+    let protection = self.effective_protection(order);
+    Some(dry_run::simulate(order_book, order, &protection))
+  }
+  
   /// 주문장 스냅샷 조회
   pub fn get_order_book_snapshot(&self, symbol: &str, depth: usize) -> Option<OrderBookSnapshot> {
     self.order_books.get(symbol).map(|ob| ob.get_order_book_snapshot(depth))
   }
   
+  /// 심볼 미시구조 지표 조회 (아직 업데이트가 없으면 현재 호가창으로 계산)
+  pub fn get_microstructure(&self, symbol: &str) -> Option<MicrostructureResponse> {
+    if let Some(stats) = self.orderbook_tracker.get_microstructure(symbol) {
+      return Some(stats.clone());
+    }
+    let snapshot = self.get_order_book_snapshot(symbol, 10)?;
+    Some(self.orderbook_tracker.compute_microstructure(symbol, &snapshot))
+  }
+
+  /// 지금까지 누적한 계정별 최우선 호가 유동성을 가져감 (리베이트 보고 작업이 주기적으로 호출)
+  pub fn take_maker_liquidity(&mut self) -> Vec<MakerLiquiditySample> {
+    let now = self.clock.now_millis();
+    self.orderbook_tracker.take_maker_liquidity(now)
+  }
+
+  /// 가상 시장가 주문의 시장 충격 추정 (주문장 전체 깊이 사용)
+  pub fn get_market_impact(&self, symbol: &str, side: Side, quantity: u64) -> Option<MarketImpactResponse> {
+    let order_book = self.order_books.get(symbol)?;
+    let depth = order_book.bids.len().max(order_book.asks.len());
+    let snapshot = order_book.get_order_book_snapshot(depth);
+    Some(self.orderbook_tracker.compute_market_impact(symbol, &snapshot, side, quantity))
+  }
+  
   /// 심볼별 주문 통계 출력
   pub fn print_stats(&self) {
-    for (symbol, order_book) in &self.order_books {
-      info!("심볼: {}, 매수 주문 수: {}, 매도 주문 수: {}, 총 주문 수: {}", 
-                 symbol, 
-                 order_book.bid_count(), 
-                 order_book.ask_count(),
-                 order_book.order_count());
+    for stats in self.get_engine_stats(5).symbols {
+      info!("심볼: {}, 매수 주문 수: {}, 매도 주문 수: {}, 총 주문 수: {}, 초당 체결: {:.2}, 평균 체결 수량: {:?}",
+                 stats.symbol,
+                 stats.bid_orders,
+                 stats.ask_orders,
+                 stats.bid_orders + stats.ask_orders,
+                 stats.trades_per_sec,
+                 stats.avg_trade_size);
     }
   }
 
+  /// 심볼별 매칭 통계 (잔량은 상위 `depth_levels` 레벨 합, 체결 속도/평균 수량은 최근 1분)
+  pub fn get_engine_stats(&self, depth_levels: usize) -> EngineStatsResponse {
+    let now = self.clock();
+    let mut symbols: Vec<SymbolMatchStats> = self
+      .order_books
+      .iter()
+      .map(|(symbol, order_book)| {
+        let snapshot = order_book.get_order_book_snapshot(depth_levels);
+        let window = self.match_stats.window(symbol, now);
+        SymbolMatchStats {
+          symbol: symbol.clone(),
+          bid_orders: order_book.bid_count(),
+          ask_orders: order_book.ask_count(),
+          bid_open_interest: snapshot.bids.iter().map(|(_, quantity)| quantity).sum(),
+          ask_open_interest: snapshot.asks.iter().map(|(_, quantity)| quantity).sum(),
+          trades_last_minute: window.trades,
+          volume_last_minute: window.volume,
+          trades_per_sec: window.trades_per_sec(),
+          avg_trade_size: window.avg_trade_size(),
+          total_trades: window.total_trades,
+          total_volume: window.total_volume,
+        }
+      })
+      .collect();
+    symbols.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+
+    EngineStatsResponse { timestamp: now, depth_levels, window_secs: match_stats::WINDOW_SECS, symbols }
+  }
+
   /// 호가창 업데이트 브로드캐스트 (하이브리드 방식)
-  fn broadcast_orderbook_update(&mut self, symbol: &str) {
+  /// BBO 변경 발행 (WebSocket, RabbitMQ, Kafka BBO 토픽)
+  fn broadcast_bbo(&self, bbo: BboUpdate) {
+    if let Some(kafka_prod) = self.bbo_producer() {
+      let kafka_prod_clone = kafka_prod.clone();
+      let bbo = bbo.clone();
+      tokio::spawn(async move {
+        if let Err(e) = kafka_prod_clone.publish_bbo(&bbo).await {
+          error!("BBO Kafka 발행 실패: {}", e);
+        }
+      });
+    }
+
+    let message = WebSocketMessage::Bbo(bbo);
     if let Some(ref broadcast_tx) = self.broadcast_tx {
-      if let Some(snapshot) = self.get_order_book_snapshot(symbol, 10) {
-        // Delta 업데이트 시도
-        if let Some(delta) = self.orderbook_tracker.analyze_changes(symbol, &snapshot) {
-          let message = WebSocketMessage::OrderBookDelta(delta);
-          if let Err(e) = broadcast_tx.send(message.clone()) {
-            warn!("호가창 Delta 업데이트 브로드캐스트 실패: {}", e);
-          }
-          
-          // 🚀 RabbitMQ에 WebSocket 메시지 발행
-          if let Some(ref rabbitmq_prod) = self.rabbitmq_producer {
-            let rabbitmq_prod_clone = rabbitmq_prod.clone();
-            tokio::spawn(async move {
-              if let Err(e) = rabbitmq_prod_clone.publish_websocket_message(&message).await {
-                error!("RabbitMQ WebSocket 메시지 발행 실패: {}", e);
-              }
-            });
-          }
-          
-          return;
+      if let Err(e) = broadcast_tx.send(message.clone()) {
+        warn!("BBO 업데이트 브로드캐스트 실패: {}", e);
+      }
+    }
+
+    // 🚀 RabbitMQ에 WebSocket 메시지 발행
+    if let Some(rabbitmq_prod) = self.mq_producer() {
+      let rabbitmq_prod_clone = rabbitmq_prod.clone();
+      tokio::spawn(async move {
+        if let Err(e) = rabbitmq_prod_clone.publish_websocket_message(&message).await {
+          error!("RabbitMQ WebSocket 메시지 발행 실패: {}", e);
         }
-        
-        // Snapshot 전송이 필요한지 확인
-        if self.orderbook_tracker.should_send_snapshot(symbol) {
-          let api_snapshot = self.orderbook_tracker.create_snapshot(symbol, &snapshot);
-          let message = WebSocketMessage::OrderBookSnapshot(api_snapshot);
-          if let Err(e) = broadcast_tx.send(message.clone()) {
-            warn!("호가창 Snapshot 업데이트 브로드캐스트 실패: {}", e);
-          }
-          
-          // 🚀 RabbitMQ에 WebSocket 메시지 발행
-          if let Some(ref rabbitmq_prod) = self.rabbitmq_producer {
-            let rabbitmq_prod_clone = rabbitmq_prod.clone();
-            tokio::spawn(async move {
-              if let Err(e) = rabbitmq_prod_clone.publish_websocket_message(&message).await {
-                error!("RabbitMQ WebSocket 메시지 발행 실패: {}", e);
-              }
-            });
+      });
+    }
+  }
+
+  fn broadcast_orderbook_update(&mut self, symbol: &str) {
+    let snapshot = match self.get_order_book_snapshot(symbol, 10) {
+      Some(snapshot) => snapshot,
+      None => return,
+    };
+
+    // 미시구조 지표 캐시 갱신 (브로드캐스트 채널 유무와 무관)
+    self.orderbook_tracker.update_microstructure(symbol, &snapshot);
+
+    // 최우선 호가가 바뀌었을 때만 BBO 이벤트 발행 (전체 호가 업데이트와 별도 채널)
+    if let Some(bbo) = self.orderbook_tracker.detect_bbo_change(symbol, &snapshot) {
+      self.broadcast_bbo(bbo);
+    }
+
+    // 최우선 호가 계정별 잔량 (마켓 메이커 유동성 공급 집계)
+    if let Some(order_book) = self.order_books.get(symbol) {
+      let bid = order_book.get_best_bid().map(|(_, level)| level.resting_by_client()).unwrap_or_default();
+      let ask = order_book.get_best_ask().map(|(_, level)| level.resting_by_client()).unwrap_or_default();
+      self.orderbook_tracker.record_best_makers(symbol, self.clock.now_millis(), bid, ask);
+    }
+
+    if let Some(ref broadcast_tx) = self.broadcast_tx {
+      // 첫 발행/키프레임 주기에는 전체 호가, 그 밖에는 바뀐 레벨만
+      let now = self.clock.now_millis();
+      let message = match self.orderbook_tracker.next_update(symbol, &snapshot, now) {
+        Some(OrderBookUpdateKind::Snapshot(api_snapshot)) => WebSocketMessage::OrderBookSnapshot(api_snapshot),
+        Some(OrderBookUpdateKind::Delta(delta)) => WebSocketMessage::OrderBookDelta(delta),
+        None => return,
+      };
+      if let Err(e) = broadcast_tx.send(message.clone()) {
+        warn!("호가창 업데이트 브로드캐스트 실패: {}", e);
+      }
+      
+      // 🚀 RabbitMQ에 WebSocket 메시지 발행
+      if let Some(rabbitmq_prod) = self.mq_producer() {
+        let rabbitmq_prod_clone = rabbitmq_prod.clone();
+        tokio::spawn(async move {
+          if let Err(e) = rabbitmq_prod_clone.publish_websocket_message(&message).await {
+            error!("RabbitMQ WebSocket 메시지 발행 실패: {}", e);
           }
-          
-          return;
-        }
-        
-        // 기존 방식으로 폴백 (호환성 유지)
-        let message = WebSocketMessage::OrderBookUpdate {
-          symbol: symbol.to_string(),
-          bids: snapshot.bids,
-          asks: snapshot.asks,
-          timestamp: SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64,
-        };
-        
-        if let Err(e) = broadcast_tx.send(message.clone()) {
-          warn!("호가창 업데이트 브로드캐스트 실패: {}", e);
-        }
-        
-        // 🚀 RabbitMQ에 WebSocket 메시지 발행
-        if let Some(ref rabbitmq_prod) = self.rabbitmq_producer {
-          let rabbitmq_prod_clone = rabbitmq_prod.clone();
-          tokio::spawn(async move {
-            if let Err(e) = rabbitmq_prod_clone.publish_websocket_message(&message).await {
-              error!("RabbitMQ WebSocket 메시지 발행 실패: {}", e);
-            }
-          });
-        }
+        });
       }
     }
   }
@@ -582,7 +1197,9 @@ impl MatchingEngine {
 mod tests {
   use super::*;
   use crate::matching_engine::model::{Order, Side, OrderType};
-  use std::sync::mpsc;
+  use crate::sequencer::backpressure::{bounded_queue, OverflowPolicy};
+  use crate::util::clock::{Clock, SimClock};
+  use crate::matching_engine::order_book::LevelLifecyclePolicy;
   
   // 테스트용 주문 생성 헬퍼 함수
   fn create_test_order(id: &str, side: Side, order_type: OrderType, price: u64, quantity: u64) -> Order {
@@ -598,6 +1215,10 @@ mod tests {
       timestamp: 0,
       is_cancel: false,
       target_order_id: None,
+      protection: None,
+      expire_time: None,
+      sequence: 0,
+      tag: None,
     }
   }
   
@@ -615,17 +1236,21 @@ mod tests {
       timestamp: 0,
       is_cancel: true,
       target_order_id: Some(target_id.to_string()),
+      protection: None,
+      expire_time: None,
+      sequence: 0,
+      tag: None,
     }
   }
   
   #[test]
   fn test_matching_engine_initialization() {
     // 채널 생성
-    let (exec_tx, _exec_rx) = mpsc::channel();
+    let (exec_tx, _exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
     
     // 매칭 엔진 생성
     let symbols = vec!["BTC-KRW".to_string(), "ETH-KRW".to_string()];
-    let engine = MatchingEngine::new(symbols, exec_tx);
+    let engine = MatchingEngine::new(symbols, exec_tx, None);
     
     // 검증
     assert_eq!(engine.order_books.len(), 2);
@@ -637,11 +1262,11 @@ mod tests {
   #[test]
   fn test_limit_order_no_match() {
     // 채널 생성
-    let (exec_tx, exec_rx) = mpsc::channel();
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
     
     // 매칭 엔진 생성
     let symbols = vec!["BTC-KRW".to_string()];
-    let mut engine = MatchingEngine::new(symbols, exec_tx);
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
     
     // 매수 주문 추가 (매칭되지 않음)
     let buy_order = create_test_order("order1", Side::Buy, OrderType::Limit, 10000, 100);
@@ -658,11 +1283,11 @@ mod tests {
   #[test]
   fn test_limit_order_full_match() {
     // 채널 생성
-    let (exec_tx, exec_rx) = mpsc::channel();
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
     
     // 매칭 엔진 생성
     let symbols = vec!["BTC-KRW".to_string()];
-    let mut engine = MatchingEngine::new(symbols, exec_tx);
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
     
     // 매도 주문 추가 (메이커)
     let sell_order = create_test_order("order1", Side::Sell, OrderType::Limit, 10000, 100);
@@ -682,7 +1307,7 @@ mod tests {
     assert_eq!(taker_report.price, 10000);
     assert_eq!(taker_report.quantity, 100);
     assert_eq!(taker_report.remaining_quantity, 0);
-    assert_eq!(taker_report.is_maker, false);
+    assert!(!taker_report.is_maker);
     assert_eq!(taker_report.counterparty_id, "order1");
     
     // 메이커 체결 보고서 검증
@@ -691,7 +1316,7 @@ mod tests {
     assert_eq!(maker_report.price, 10000);
     assert_eq!(maker_report.quantity, 100);
     assert_eq!(maker_report.remaining_quantity, 0);
-    assert_eq!(maker_report.is_maker, true);
+    assert!(maker_report.is_maker);
     assert_eq!(maker_report.counterparty_id, "order2");
     
     // 더 이상 체결 보고서가 없는지 확인
@@ -701,11 +1326,11 @@ mod tests {
   #[test]
   fn test_limit_order_partial_match() {
     // 채널 생성
-    let (exec_tx, exec_rx) = mpsc::channel();
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
     
     // 매칭 엔진 생성
     let symbols = vec!["BTC-KRW".to_string()];
-    let mut engine = MatchingEngine::new(symbols, exec_tx);
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
     
     // 매도 주문 추가 (메이커)
     let sell_order = create_test_order("order1", Side::Sell, OrderType::Limit, 10000, 100);
@@ -736,11 +1361,11 @@ mod tests {
   #[test]
   fn test_market_order_matching() {
     // 채널 생성
-    let (exec_tx, exec_rx) = mpsc::channel();
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
     
     // 매칭 엔진 생성
     let symbols = vec!["BTC-KRW".to_string()];
-    let mut engine = MatchingEngine::new(symbols, exec_tx);
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
     
     // 여러 가격의 매도 주문 추가 (메이커)
     let sell_order1 = create_test_order("order1", Side::Sell, OrderType::Limit, 10000, 50);
@@ -788,11 +1413,11 @@ mod tests {
   #[test]
   fn test_cancel_order() {
     // 채널 생성
-    let (exec_tx, exec_rx) = mpsc::channel();
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
     
     // 매칭 엔진 생성
     let symbols = vec!["BTC-KRW".to_string()];
-    let mut engine = MatchingEngine::new(symbols, exec_tx);
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
     
     // 매도 주문 추가
     let sell_order = create_test_order("order1", Side::Sell, OrderType::Limit, 10000, 100);
@@ -808,7 +1433,8 @@ mod tests {
     // 취소 보고서 검증
     assert_eq!(cancel_report.order_id, "order1");
     assert_eq!(cancel_report.price, 10000);
-    assert_eq!(cancel_report.quantity, 0);
+    // 취소로 해제된 수량
+    assert_eq!(cancel_report.quantity, 100);
     assert_eq!(cancel_report.remaining_quantity, 0);
     assert_eq!(cancel_report.counterparty_id, "system");
     
@@ -819,11 +1445,11 @@ mod tests {
   #[test]
   fn test_multiple_orders_at_same_price_level() {
     // 채널 생성
-    let (exec_tx, exec_rx) = mpsc::channel();
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
     
     // 매칭 엔진 생성
     let symbols = vec!["BTC-KRW".to_string()];
-    let mut engine = MatchingEngine::new(symbols, exec_tx);
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
     
     // 동일 가격 매도 주문 여러개 추가 (메이커)
     let sell_order1 = create_test_order("order1", Side::Sell, OrderType::Limit, 10000, 40);
@@ -865,11 +1491,11 @@ mod tests {
   #[test]
   fn test_order_book_snapshot() {
     // 채널 생성
-    let (exec_tx, _) = mpsc::channel();
+    let (exec_tx, _) = bounded_queue("executions", 1024, OverflowPolicy::Block);
     
     // 매칭 엔진 생성
     let symbols = vec!["BTC-KRW".to_string()];
-    let mut engine = MatchingEngine::new(symbols, exec_tx);
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
     
     // 주문장에 직접 주문 추가
     let order_book = engine.order_books.get_mut("BTC-KRW").unwrap();
@@ -912,11 +1538,11 @@ mod tests {
   #[test]
   fn test_sequential_matching() {
     // 채널 생성
-    let (exec_tx, exec_rx) = mpsc::channel();
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
     
     // 매칭 엔진 생성
     let symbols = vec!["BTC-KRW".to_string()];
-    let mut engine = MatchingEngine::new(symbols, exec_tx);
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
     
     // 매도 주문 3개 추가 (10주, 50주, 40주)
     let sell_order1 = create_test_order("sell1", Side::Sell, OrderType::Limit, 10000, 10);
@@ -975,11 +1601,11 @@ mod tests {
   #[test]
   fn test_price_time_priority() {
     // 채널 생성
-    let (exec_tx, exec_rx) = mpsc::channel();
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
     
     // 매칭 엔진 생성
     let symbols = vec!["BTC-KRW".to_string()];
-    let mut engine = MatchingEngine::new(symbols, exec_tx);
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
     
     // 다양한 가격의 매도 주문 추가
     let sell_order1 = create_test_order("sell1", Side::Sell, OrderType::Limit, 10100, 30);
@@ -1019,4 +1645,414 @@ mod tests {
     assert_eq!(maker_report2.quantity, 10);
     assert_eq!(maker_report2.remaining_quantity, 20); // 30 - 10 = 20
   }
+  
+  #[test]
+  fn test_market_order_slippage_protection() {
+    // 채널 생성
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+    
+    // 매칭 엔진 생성 (심볼 기본 슬리피지 1%)
+    let symbols = vec!["BTC-KRW".to_string()];
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
+    engine.set_market_protection("BTC-KRW", MarketProtection {
+      max_slippage_pct: Some(1.0),
+      max_levels: None,
+    });
+    
+    // 매도 호가: 10000, 10050 (0.5%), 10200 (2%)
+    engine.process_order(create_test_order("sell1", Side::Sell, OrderType::Limit, 10000, 10));
+    engine.process_order(create_test_order("sell2", Side::Sell, OrderType::Limit, 10050, 10));
+    engine.process_order(create_test_order("sell3", Side::Sell, OrderType::Limit, 10200, 10));
+    
+    // 시장가 매수 30주 - 10200 레벨은 슬리피지 한도 초과
+    engine.process_order(create_test_order("market1", Side::Buy, OrderType::Market, 0, 30));
+    
+    let reports: Vec<ExecutionReport> = exec_rx.try_iter().collect();
+    let taker_fills: Vec<&ExecutionReport> = reports.iter()
+      .filter(|r| r.order_id == "market1" && r.exec_type == ExecType::Trade)
+      .collect();
+    assert_eq!(taker_fills.len(), 2);
+    assert_eq!(taker_fills[1].price, 10050);
+    
+    // 남은 10주는 취소 보고서로 처리
+    let cancel = reports.last().unwrap();
+    assert_eq!(cancel.order_id, "market1");
+    assert_eq!(cancel.exec_type, ExecType::Canceled);
+    assert_eq!((cancel.quantity, cancel.remaining_quantity), (10, 0));
+    assert_eq!(cancel.counterparty_id, "system");
+    
+    // 10200 레벨은 그대로 남아 있음
+    let snapshot = engine.get_order_book_snapshot("BTC-KRW", 5).unwrap();
+    assert_eq!(snapshot.asks, vec![(10200, 10)]);
+  }
+  
+  #[test]
+  fn test_market_order_max_levels_override() {
+    // 채널 생성
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+    
+    // 매칭 엔진 생성
+    let symbols = vec!["BTC-KRW".to_string()];
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
+    
+    // 매수 호가 3개 레벨
+    engine.process_order(create_test_order("buy1", Side::Buy, OrderType::Limit, 10000, 10));
+    engine.process_order(create_test_order("buy2", Side::Buy, OrderType::Limit, 9990, 10));
+    engine.process_order(create_test_order("buy3", Side::Buy, OrderType::Limit, 9980, 10));
+    
+    // 주문 단위 설정: 최대 1개 레벨
+    let market_sell = create_test_order("market1", Side::Sell, OrderType::Market, 0, 25)
+      .with_protection(MarketProtection { max_slippage_pct: None, max_levels: Some(1) });
+    engine.process_order(market_sell);
+    
+    let reports: Vec<ExecutionReport> = exec_rx.try_iter().collect();
+    let filled: u64 = reports.iter()
+      .filter(|r| r.order_id == "market1" && r.exec_type == ExecType::Trade)
+      .map(|r| r.quantity)
+      .sum();
+    assert_eq!(filled, 10);
+    
+    // 레벨 한도로 남은 15주는 취소 보고서로 해제
+    let cancel = reports.last().unwrap();
+    assert_eq!(cancel.order_id, "market1");
+    assert_eq!(cancel.exec_type, ExecType::Canceled);
+    assert_eq!((cancel.quantity, cancel.remaining_quantity), (15, 0));
+    
+    let snapshot = engine.get_order_book_snapshot("BTC-KRW", 5).unwrap();
+    assert_eq!(snapshot.bids, vec![(9990, 10), (9980, 10)]);
+  }
+
+  #[test]
+  fn test_market_order_protection_cannot_loosen_symbol_default() {
+    // 채널 생성
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+
+    // 매칭 엔진 생성 (심볼 기본 슬리피지 1%)
+    let symbols = vec!["BTC-KRW".to_string()];
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
+    engine.set_market_protection("BTC-KRW", MarketProtection {
+      max_slippage_pct: Some(1.0),
+      max_levels: None,
+    });
+
+    // 매도 호가: 10000 (2건), 10050 (0.5%), 10200 (2%)
+    engine.process_order(create_test_order("sell1", Side::Sell, OrderType::Limit, 10000, 5));
+    engine.process_order(create_test_order("sell2", Side::Sell, OrderType::Limit, 10000, 5));
+    engine.process_order(create_test_order("sell3", Side::Sell, OrderType::Limit, 10050, 10));
+    engine.process_order(create_test_order("sell4", Side::Sell, OrderType::Limit, 10200, 10));
+
+    // 주문은 레벨 수만 지정하고 슬리피지를 5%로 느슨하게 요청 - 심볼 기본 1%가 유지됨
+    let market_buy = create_test_order("market1", Side::Buy, OrderType::Market, 0, 30)
+      .with_protection(MarketProtection { max_slippage_pct: Some(5.0), max_levels: Some(3) });
+    engine.process_order(market_buy);
+
+    let reports: Vec<ExecutionReport> = exec_rx.try_iter().collect();
+    let filled: u64 = reports.iter()
+      .filter(|r| r.order_id == "market1" && r.counterparty_id != "system")
+      .map(|r| r.quantity)
+      .sum();
+    assert_eq!(filled, 20);
+
+    let snapshot = engine.get_order_book_snapshot("BTC-KRW", 5).unwrap();
+    assert_eq!(snapshot.asks, vec![(10200, 10)]);
+  }
+  
+  #[test]
+  fn test_expire_orders_sweep() {
+    // 채널 생성
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+    
+    // 매칭 엔진 생성
+    let symbols = vec!["BTC-KRW".to_string()];
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
+    
+    let now = SystemClock.now_secs();
+    
+    // GTD 주문 2개 (만료 시각이 다름) + 만료 없는 주문 1개
+    engine.process_order(create_test_order("gtd1", Side::Buy, OrderType::Limit, 9000, 10).with_expire_time(now + 10));
+    engine.process_order(create_test_order("gtd2", Side::Buy, OrderType::Limit, 9100, 10).with_expire_time(now + 100));
+    engine.process_order(create_test_order("gtc1", Side::Sell, OrderType::Limit, 11000, 10));
+    
+    // 첫 번째 만료 시각 이후 정리
+    assert_eq!(engine.expire_orders(now + 10), 1);
+    
+    let report = exec_rx.try_recv().unwrap();
+    assert_eq!(report.order_id, "gtd1");
+    assert_eq!(report.exec_type, ExecType::Expired);
+    // 만료로 해제된 수량
+    assert_eq!((report.quantity, report.remaining_quantity), (10, 0));
+    assert!(exec_rx.try_recv().is_err());
+    assert!(engine.get_order("gtd1").is_none());
+    
+    let snapshot = engine.get_order_book_snapshot("BTC-KRW", 5).unwrap();
+    assert_eq!(snapshot.bids, vec![(9100, 10)]);
+    assert_eq!(snapshot.asks, vec![(11000, 10)]);
+  }
+  
+  #[test]
+  fn test_expire_orders_skips_filled_and_applies_max_age() {
+    // 채널 생성
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+    
+    // 매칭 엔진 생성 (최대 대기 시간 60초)
+    let symbols = vec!["BTC-KRW".to_string()];
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
+    engine.set_max_order_age(Some(60));
+    
+    let now = SystemClock.now_secs();
+    let mut stale = create_test_order("stale1", Side::Sell, OrderType::Limit, 10000, 10);
+    stale.timestamp = now;
+    let mut filled = create_test_order("filled1", Side::Sell, OrderType::Limit, 10100, 10);
+    filled.timestamp = now;
+    engine.process_order(stale);
+    engine.process_order(filled);
+    
+    // filled1은 만료 전에 완전 체결
+    engine.process_order(create_test_order("buy1", Side::Buy, OrderType::Limit, 10100, 20));
+    let _fills: Vec<ExecutionReport> = exec_rx.try_iter().collect();
+    
+    // stale1도 이미 체결되었으므로 만료 대상 없음
+    assert_eq!(engine.expire_orders(now + 60), 0);
+    assert!(exec_rx.try_recv().is_err());
+    
+    // 새 주문은 최대 대기 시간 후 만료
+    let mut resting = create_test_order("stale2", Side::Sell, OrderType::Limit, 10500, 10);
+    resting.timestamp = now;
+    engine.process_order(resting);
+    assert_eq!(engine.expire_orders(now + 59), 0);
+    assert_eq!(engine.expire_orders(now + 60), 1);
+    assert_eq!(exec_rx.try_recv().unwrap().exec_type, ExecType::Expired);
+  }
+  
+  #[test]
+  fn test_gtd_expiry_follows_sim_clock() {
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+    let symbols = vec!["BTC-KRW".to_string()];
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
+    let clock = SimClock::new(1_700_000_000_000);
+    engine.set_clock(clock.shared());
+    let now = clock.now_secs();
+
+    engine.process_order(create_test_order("gtd1", Side::Buy, OrderType::Limit, 9000, 10).with_expire_time(now + 10));
+    assert!(exec_rx.try_recv().is_err());
+
+    // 만료 시각 전에는 검사 주기가 지나도 남아 있음
+    clock.advance(Duration::from_secs(9));
+    engine.run_timers();
+    assert!(exec_rx.try_recv().is_err());
+    assert!(engine.get_order("gtd1").is_some());
+
+    clock.advance(Duration::from_secs(1));
+    engine.run_timers();
+    let report = exec_rx.try_recv().unwrap();
+    assert_eq!((report.order_id.as_str(), report.exec_type), ("gtd1", ExecType::Expired));
+    assert_eq!(report.timestamp, now + 10);
+
+    // 이미 지난 만료 시각으로 들어온 주문은 바로 만료
+    engine.process_order(create_test_order("gtd2", Side::Buy, OrderType::Limit, 9000, 10).with_expire_time(now + 10));
+    let report = exec_rx.try_recv().unwrap();
+    assert_eq!((report.exec_type, report.quantity), (ExecType::Expired, 10));
+    assert!(engine.get_order("gtd2").is_none());
+  }
+  
+  #[test]
+  fn test_engine_stats_track_resting_orders_and_recent_trades() {
+    let (exec_tx, _exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+    let mut engine = MatchingEngine::new(vec!["BTC-KRW".to_string(), "ETH-KRW".to_string()], exec_tx, None);
+    let clock = SimClock::new(1_700_000_000_000);
+    engine.set_clock(clock.shared());
+
+    engine.process_order(create_test_order("s1", Side::Sell, OrderType::Limit, 10100, 50));
+    engine.process_order(create_test_order("s2", Side::Sell, OrderType::Limit, 10200, 70));
+    engine.process_order(create_test_order("b1", Side::Buy, OrderType::Limit, 9900, 30));
+    engine.process_order(create_test_order("t1", Side::Buy, OrderType::Limit, 10100, 20));
+    clock.advance(Duration::from_secs(10));
+    engine.process_order(create_test_order("t2", Side::Buy, OrderType::Market, 0, 40));
+
+    let stats = engine.get_engine_stats(1);
+    assert_eq!((stats.depth_levels, stats.window_secs), (1, 60));
+    assert_eq!(stats.symbols.iter().map(|s| s.symbol.as_str()).collect::<Vec<_>>(), ["BTC-KRW", "ETH-KRW"]);
+    let btc = &stats.symbols[0];
+    assert_eq!((btc.bid_orders, btc.ask_orders), (1, 1));
+    // 상위 1레벨: 매수 9900 x 30, 매도 10200 x (70 - 10)
+    assert_eq!((btc.bid_open_interest, btc.ask_open_interest), (30, 60));
+    // 체결: 20, 30 (10100 소진), 10 (10200)
+    assert_eq!((btc.trades_last_minute, btc.volume_last_minute), (3, 60));
+    assert_eq!(btc.avg_trade_size, Some(20.0));
+    assert_eq!(btc.trades_per_sec, 3.0 / 60.0);
+    assert_eq!(stats.symbols[1].avg_trade_size, None);
+
+    // 1분이 지나면 최근 창은 비고 누적값만 남음
+    clock.advance(Duration::from_secs(60));
+    let btc = &engine.get_engine_stats(5).symbols[0];
+    assert_eq!((btc.trades_last_minute, btc.total_trades, btc.total_volume), (0, 3, 60));
+  }
+
+  #[test]
+  fn test_orderbook_broadcast_sends_changed_levels_between_keyframes() {
+    let (exec_tx, _exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+    let mut engine = MatchingEngine::new(vec!["BTC-KRW".to_string()], exec_tx, None);
+    let (broadcast_tx, mut broadcast_rx) = tokio::sync::broadcast::channel(64);
+    engine.set_broadcast_channel(broadcast_tx);
+    let clock = SimClock::new(1_700_000_000_000);
+    engine.set_clock(clock.shared());
+    engine.set_orderbook_keyframe_interval(Duration::from_secs(1));
+
+    let mut book_messages = || -> Vec<WebSocketMessage> {
+      std::iter::from_fn(|| broadcast_rx.try_recv().ok())
+        .filter(|message| {
+          matches!(message, WebSocketMessage::OrderBookDelta(_) | WebSocketMessage::OrderBookSnapshot(_) | WebSocketMessage::OrderBookUpdate { .. })
+        })
+        .collect()
+    };
+
+    // 첫 발행은 키프레임
+    engine.process_order(create_test_order("s1", Side::Sell, OrderType::Limit, 10100, 50));
+    let messages = book_messages();
+    assert!(matches!(&messages[..], [WebSocketMessage::OrderBookSnapshot(snapshot)] if snapshot.asks == vec![(10100, 50)]));
+
+    // 이후에는 바뀐 레벨만
+    engine.process_order(create_test_order("s2", Side::Sell, OrderType::Limit, 10200, 70));
+    engine.handle_cancel_order(&create_cancel_order("c1", "s1"));
+    let messages = book_messages();
+    let [WebSocketMessage::OrderBookDelta(added), WebSocketMessage::OrderBookDelta(removed)] = &messages[..] else {
+      panic!("Delta 두 건이어야 함: {:?}", messages);
+    };
+    assert!(added.bid_changes.is_empty());
+    assert_eq!((added.ask_changes.len(), added.ask_changes[0].price, added.ask_changes[0].quantity), (1, 10200, 70));
+    assert_eq!((removed.ask_changes.len(), removed.ask_changes[0].price), (1, 10100));
+    assert_eq!(removed.ask_changes[0].change_type, crate::api::models::OrderBookChangeType::Remove);
+    assert_eq!(removed.sequence, added.sequence + 1);
+
+    // 재동기화 응답은 마지막 발행 기준, 시퀀스 유지
+    let sync = engine.handle_sync_request("BTC-KRW").unwrap();
+    assert_eq!((sync.asks, sync.sequence), (vec![(10200, 70)], removed.sequence));
+
+    // 키프레임 간격이 지나면 다시 전체 호가
+    clock.advance(Duration::from_secs(1));
+    engine.process_order(create_test_order("b1", Side::Buy, OrderType::Limit, 9900, 30));
+    let messages = book_messages();
+    assert!(matches!(
+      &messages[..],
+      [WebSocketMessage::OrderBookSnapshot(snapshot)] if snapshot.bids == vec![(9900, 30)] && snapshot.sequence == removed.sequence + 1
+    ));
+  }
+
+  #[test]
+  fn test_book_lifecycle_reclaims_far_level_capacity() {
+    let (exec_tx, _exec_rx) = bounded_queue("executions", 4096, OverflowPolicy::Block);
+    let mut engine = MatchingEngine::new(vec!["BTC-KRW".to_string()], exec_tx, None);
+    let clock = SimClock::new(1_700_000_000_000);
+    engine.set_clock(clock.shared());
+    engine.set_book_lifecycle(Some(BookLifecycleConfig {
+      policy: LevelLifecyclePolicy { near_touch_levels: 1, min_slack: 16, slack_ratio: 4 },
+      interval: Duration::from_secs(60),
+    }));
+
+    // 최우선 호가에서 먼 레벨에 몰렸던 주문이 한 건만 남음
+    engine.process_order(create_test_order("touch", Side::Buy, OrderType::Limit, 10000, 1));
+    for i in 0..300 {
+      engine.process_order(create_test_order(&format!("far{}", i), Side::Buy, OrderType::Limit, 9000, 1));
+    }
+    for i in 1..300 {
+      engine.handle_cancel_order(&create_cancel_order(&format!("c{}", i), &format!("far{}", i)));
+    }
+    let before = engine.book_memory();
+    assert_eq!(before.symbols[0].memory.bid_levels, 2);
+    assert_eq!(before.symbols[0].memory.orders, 2);
+
+    // 정리 주기 전에는 그대로
+    engine.run_timers();
+    assert_eq!(engine.book_memory(), before);
+
+    clock.advance(Duration::from_secs(61));
+    engine.run_timers();
+    let after = engine.book_memory();
+    assert_eq!(after.compacted_levels, 1);
+    assert!(after.reclaimed_bytes > 0);
+    assert!(after.total_bytes() < before.total_bytes());
+    assert_eq!(after.symbols[0].memory.orders, 2);
+  }
+
+  #[tokio::test]
+  async fn test_order_acks_report_authoritative_status() {
+    let (exec_tx, _exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+    let symbols = vec!["BTC-KRW".to_string()];
+    let mut engine = MatchingEngine::new(symbols, exec_tx, None);
+    let order_acks = Arc::new(OrderAckRegistry::default());
+    engine.set_order_acks(order_acks.clone());
+    
+    let mut submit = |order: Order| {
+      let rx = order_acks.register(&order.id);
+      engine.process_order(order);
+      rx
+    };
+    let resting = submit(create_test_order("sell1", Side::Sell, OrderType::Limit, 10000, 100));
+    let partial = submit(create_test_order("buy1", Side::Buy, OrderType::Limit, 10000, 150));
+    let market = submit(create_test_order("sell2", Side::Sell, OrderType::Market, 0, 80));
+    let mut unknown = create_test_order("buy2", Side::Buy, OrderType::Limit, 10000, 10);
+    unknown.symbol = "XRP-KRW".to_string();
+    let unknown = submit(unknown);
+    
+    let resting = resting.await.unwrap();
+    assert_eq!((resting.status, resting.filled_quantity, resting.remaining_quantity), (OrderAckStatus::Accepted, 0, 100));
+    let partial = partial.await.unwrap();
+    assert_eq!((partial.status, partial.filled_quantity, partial.remaining_quantity), (OrderAckStatus::PartiallyFilled, 100, 50));
+    // 매수 잔량 50만 체결되고 나머지는 취소
+    let market = market.await.unwrap();
+    assert_eq!((market.status, market.filled_quantity, market.remaining_quantity), (OrderAckStatus::Canceled, 50, 0));
+    assert_eq!(unknown.await.unwrap().status, OrderAckStatus::Rejected(OrderRejectReason::UnknownSymbol));
+    assert_eq!(order_acks.pending_count(), 0);
+  }
+
+  #[test]
+  fn test_restored_resting_orders_keep_time_priority() {
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+    let mut engine = MatchingEngine::new(vec!["BTC-KRW".to_string()], exec_tx, None);
+
+    let mut partially_filled = create_test_order("s1", Side::Sell, OrderType::Limit, 10000, 100);
+    partially_filled.remaining_quantity = 40;
+    let mut other_symbol = create_test_order("x1", Side::Sell, OrderType::Limit, 10000, 10);
+    other_symbol.symbol = "XRP-KRW".to_string();
+    let restored = engine.restore_resting_orders(vec![
+      partially_filled,
+      create_test_order("s2", Side::Sell, OrderType::Limit, 10000, 50),
+      other_symbol,
+    ]);
+    assert_eq!(restored, 2);
+    // 복원 중에는 체결하지 않음
+    assert!(exec_rx.try_recv().is_err());
+
+    // 먼저 접수된 s1 잔량 40부터 체결
+    engine.process_order(create_test_order("b1", Side::Buy, OrderType::Limit, 10000, 60));
+    let makers: Vec<(String, u64)> = std::iter::from_fn(|| exec_rx.try_recv().ok())
+      .filter(|report| report.is_maker)
+      .map(|report| (report.order_id, report.quantity))
+      .collect();
+    assert_eq!(makers, vec![("s1".to_string(), 40), ("s2".to_string(), 20)]);
+    assert_eq!(engine.get_order("s2").map(|order| order.remaining_quantity), Some(30));
+  }
+
+  #[test]
+  fn test_reports_carry_each_order_tag() {
+    let (exec_tx, exec_rx) = bounded_queue("executions", 1024, OverflowPolicy::Block);
+    let mut engine = MatchingEngine::new(vec!["BTC-KRW".to_string()], exec_tx, None);
+
+    engine.process_order(create_test_order("s1", Side::Sell, OrderType::Limit, 10000, 100).with_tag("mm:quote".to_string()));
+    engine.process_order(create_test_order("b1", Side::Buy, OrderType::Limit, 10000, 40).with_tag("grid-v2".to_string()));
+    engine.handle_cancel_order(&create_cancel_order("c1", "s1"));
+
+    // 테이커/메이커/취소 보고서 모두 보고서 대상 주문의 태그
+    let tags: Vec<(String, Option<String>)> = std::iter::from_fn(|| exec_rx.try_recv().ok())
+      .map(|report| (report.order_id, report.tag))
+      .collect();
+    assert_eq!(
+      tags,
+      vec![
+        ("b1".to_string(), Some("grid-v2".to_string())),
+        ("s1".to_string(), Some("mm:quote".to_string())),
+        ("s1".to_string(), Some("mm:quote".to_string())),
+      ]
+    );
+  }
 }
\ No newline at end of file
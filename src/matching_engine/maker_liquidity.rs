@@ -0,0 +1,133 @@
+//! 최우선 호가 유동성 공급 집계 (마켓 메이커 리베이트 기준 데이터)
+//!
+//! 호가창이 바뀔 때마다 최우선 매수/매도 레벨에 주문을 올려 둔 계정과 계정별 잔량을 받아,
+//! 직전 상태가 유지된 시간만큼 계정·심볼별로 최우선 호가 체류 시간(밀리초)과 잔량 × 시간(수량·밀리초)을 더합니다.
+//! 보고 작업이 주기적으로 누적값을 가져가 일별로 저장합니다. 엔진 스레드에서만 갱신하므로 잠금이 없습니다.
+
+use std::collections::HashMap;
+
+/// 계정·심볼별 최우선 호가 유동성 누적값
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MakerLiquiditySample {
+  pub symbol: String,
+  pub client_id: String,
+  /// 최우선 매수 호가 체류 시간 (밀리초)
+  pub bid_time_ms: u64,
+  /// 최우선 매도 호가 체류 시간 (밀리초)
+  pub ask_time_ms: u64,
+  /// 최우선 매수 호가 잔량 × 체류 시간 (수량·밀리초)
+  pub bid_size_ms: u64,
+  /// 최우선 매도 호가 잔량 × 체류 시간 (수량·밀리초)
+  pub ask_size_ms: u64,
+}
+
+/// 한 심볼의 최우선 호가 계정별 잔량
+#[derive(Debug, Clone, Default)]
+struct BestMakers {
+  /// 이 상태가 시작된 시각 (Unix 밀리초)
+  since_ms: u64,
+  bid: Vec<(String, u64)>,
+  ask: Vec<(String, u64)>,
+}
+
+/// 최우선 호가 유동성 공급 집계기
+#[derive(Debug, Default)]
+pub struct MakerLiquidity {
+  /// 심볼별 현재 최우선 호가 계정
+  best: HashMap<String, BestMakers>,
+  /// (심볼, 계정)별 누적값
+  totals: HashMap<(String, String), MakerLiquiditySample>,
+}
+
+impl MakerLiquidity {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// 심볼의 최우선 호가 계정별 잔량 갱신 (직전 상태 구간을 누적)
+  pub fn update(&mut self, symbol: &str, now_ms: u64, bid: Vec<(String, u64)>, ask: Vec<(String, u64)>) {
+    if let Some(best) = self.best.get(symbol) {
+      Self::accrue(&mut self.totals, symbol, best, now_ms);
+    }
+    self.best.insert(symbol.to_string(), BestMakers { since_ms: now_ms, bid, ask });
+  }
+
+  /// `now_ms`까지 누적한 값을 가져가고 비움 (현재 최우선 호가 상태는 유지)
+  pub fn take(&mut self, now_ms: u64) -> Vec<MakerLiquiditySample> {
+    for (symbol, best) in self.best.iter_mut() {
+      Self::accrue(&mut self.totals, symbol, best, now_ms);
+      best.since_ms = best.since_ms.max(now_ms);
+    }
+    let mut samples: Vec<MakerLiquiditySample> = self.totals.drain().map(|(_, sample)| sample).collect();
+    samples.sort_by(|a, b| (&a.symbol, &a.client_id).cmp(&(&b.symbol, &b.client_id)));
+    samples
+  }
+
+  fn accrue(totals: &mut HashMap<(String, String), MakerLiquiditySample>, symbol: &str, best: &BestMakers, now_ms: u64) {
+    let elapsed = now_ms.saturating_sub(best.since_ms);
+    if elapsed == 0 {
+      return;
+    }
+    for (is_bid, makers) in [(true, &best.bid), (false, &best.ask)] {
+      for (client_id, quantity) in makers {
+        let sample = totals.entry((symbol.to_string(), client_id.clone())).or_insert_with(|| MakerLiquiditySample {
+          symbol: symbol.to_string(),
+          client_id: client_id.clone(),
+          ..Default::default()
+        });
+        let size_ms = quantity.saturating_mul(elapsed);
+        if is_bid {
+          sample.bid_time_ms += elapsed;
+          sample.bid_size_ms = sample.bid_size_ms.saturating_add(size_ms);
+        } else {
+          sample.ask_time_ms += elapsed;
+          sample.ask_size_ms = sample.ask_size_ms.saturating_add(size_ms);
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn makers(entries: &[(&str, u64)]) -> Vec<(String, u64)> {
+    entries.iter().map(|(client_id, quantity)| (client_id.to_string(), *quantity)).collect()
+  }
+
+  #[test]
+  fn test_accrues_time_and_size_at_best_until_taken() {
+    let mut liquidity = MakerLiquidity::new();
+    liquidity.update("BTC-KRW", 1_000, makers(&[("mm_a", 10)]), makers(&[("mm_b", 5)]));
+    // 1초 뒤 mm_a가 매도 최우선에도 합류
+    liquidity.update("BTC-KRW", 2_000, makers(&[("mm_a", 10)]), makers(&[("mm_b", 5), ("mm_a", 3)]));
+
+    let samples = liquidity.take(4_000);
+    assert_eq!(
+      samples,
+      vec![
+        MakerLiquiditySample {
+          symbol: "BTC-KRW".to_string(),
+          client_id: "mm_a".to_string(),
+          bid_time_ms: 3_000,
+          ask_time_ms: 2_000,
+          bid_size_ms: 30_000,
+          ask_size_ms: 6_000,
+        },
+        MakerLiquiditySample {
+          symbol: "BTC-KRW".to_string(),
+          client_id: "mm_b".to_string(),
+          bid_time_ms: 0,
+          ask_time_ms: 3_000,
+          bid_size_ms: 0,
+          ask_size_ms: 15_000,
+        },
+      ]
+    );
+
+    // 가져간 뒤에는 그 시각부터 다시 셈
+    let samples = liquidity.take(4_500);
+    assert_eq!(samples.iter().map(|sample| sample.bid_time_ms).collect::<Vec<_>>(), vec![500, 0]);
+  }
+}
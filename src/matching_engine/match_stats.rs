@@ -0,0 +1,135 @@
+//! 심볼별 체결 통계
+//!
+//! 매칭 엔진이 체결할 때마다 갱신하는 카운터입니다. 최근 1분은 1초 단위 버킷 60개로 나눠
+//! 체결 속도(건/초)와 평균 체결 수량을 계산하고, 엔진 시작 이후 누적값도 함께 둡니다.
+//! 엔진 스레드에서만 갱신하므로 잠금이 없습니다.
+
+use std::collections::HashMap;
+
+/// 최근 통계 창 (초)
+pub const WINDOW_SECS: u64 = 60;
+
+/// 1초 버킷
+#[derive(Debug, Clone, Copy, Default)]
+struct Bucket {
+  /// 버킷 시각 (Unix 초)
+  second: u64,
+  trades: u64,
+  volume: u64,
+}
+
+/// 한 심볼의 체결 카운터
+#[derive(Debug, Clone)]
+struct SymbolCounters {
+  buckets: [Bucket; WINDOW_SECS as usize],
+  total_trades: u64,
+  total_volume: u64,
+}
+
+impl Default for SymbolCounters {
+  fn default() -> Self {
+    Self { buckets: [Bucket::default(); WINDOW_SECS as usize], total_trades: 0, total_volume: 0 }
+  }
+}
+
+/// 최근 창 집계
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WindowStats {
+  /// 최근 1분 체결 건수
+  pub trades: u64,
+  /// 최근 1분 체결 수량
+  pub volume: u64,
+  /// 엔진 시작 이후 체결 건수
+  pub total_trades: u64,
+  /// 엔진 시작 이후 체결 수량
+  pub total_volume: u64,
+}
+
+impl WindowStats {
+  /// 초당 체결 건수 (최근 1분 평균)
+  pub fn trades_per_sec(&self) -> f64 {
+    self.trades as f64 / WINDOW_SECS as f64
+  }
+
+  /// 평균 체결 수량 (최근 1분 체결이 없으면 None)
+  pub fn avg_trade_size(&self) -> Option<f64> {
+    (self.trades > 0).then(|| self.volume as f64 / self.trades as f64)
+  }
+}
+
+/// 심볼별 체결 통계
+#[derive(Debug, Clone, Default)]
+pub struct MatchStats {
+  symbols: HashMap<String, SymbolCounters>,
+}
+
+impl MatchStats {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// 체결 한 건 기록 (`now_secs`: 체결 시각, Unix 초)
+  pub fn record_trade(&mut self, symbol: &str, quantity: u64, now_secs: u64) {
+    let counters = match self.symbols.get_mut(symbol) {
+      Some(counters) => counters,
+      None => self.symbols.entry(symbol.to_string()).or_default(),
+    };
+    let bucket = &mut counters.buckets[(now_secs % WINDOW_SECS) as usize];
+    if bucket.second != now_secs {
+      *bucket = Bucket { second: now_secs, trades: 0, volume: 0 };
+    }
+    bucket.trades += 1;
+    bucket.volume += quantity;
+    counters.total_trades += 1;
+    counters.total_volume += quantity;
+  }
+
+  /// `now_secs` 기준 최근 1분 집계 (체결이 없던 심볼은 0)
+  pub fn window(&self, symbol: &str, now_secs: u64) -> WindowStats {
+    let Some(counters) = self.symbols.get(symbol) else {
+      return WindowStats::default();
+    };
+    let oldest = now_secs.saturating_sub(WINDOW_SECS - 1);
+    counters
+      .buckets
+      .iter()
+      .filter(|bucket| bucket.trades > 0 && (oldest..=now_secs).contains(&bucket.second))
+      .fold(
+        WindowStats { total_trades: counters.total_trades, total_volume: counters.total_volume, ..Default::default() },
+        |mut stats, bucket| {
+          stats.trades += bucket.trades;
+          stats.volume += bucket.volume;
+          stats
+        },
+      )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_window_drops_trades_older_than_a_minute() {
+    let mut stats = MatchStats::new();
+    stats.record_trade("BTC-KRW", 10, 1_000);
+    stats.record_trade("BTC-KRW", 20, 1_000);
+    stats.record_trade("BTC-KRW", 30, 1_030);
+
+    let window = stats.window("BTC-KRW", 1_030);
+    assert_eq!((window.trades, window.volume), (3, 60));
+    assert_eq!(window.avg_trade_size(), Some(20.0));
+    assert_eq!(window.trades_per_sec(), 3.0 / 60.0);
+
+    // 1,000초 버킷은 창 밖, 같은 칸(1,060 % 60)을 다시 쓰면 이전 값은 지워짐
+    stats.record_trade("BTC-KRW", 5, 1_060);
+    let window = stats.window("BTC-KRW", 1_060);
+    assert_eq!((window.trades, window.volume), (2, 35));
+    assert_eq!((window.total_trades, window.total_volume), (4, 65));
+
+    // 체결이 끊기면 창은 비지만 누적값은 남음
+    let idle = stats.window("BTC-KRW", 1_200);
+    assert_eq!((idle.trades, idle.avg_trade_size(), idle.total_trades), (0, None, 4));
+    assert_eq!(stats.window("ETH-KRW", 1_200), WindowStats::default());
+  }
+}
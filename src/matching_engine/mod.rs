@@ -1,9 +1,16 @@
-pub mod model;
-pub mod order_book;
+// 주문장/모델은 MQ 의존성 없이 쓰도록 xtrader-engine 크레이트로 분리 (기존 경로 유지)
+pub use xtrader_engine::{auction, dry_run, matching, model, order_ack, order_book};
 pub mod engine;
 pub mod ultra_fast_engine;
 pub mod orderbook_tracker;
+pub mod match_stats;
+pub mod maker_liquidity;
+pub mod book_memory;
 
 pub use engine::MatchingEngine;
 pub use ultra_fast_engine::UltraFastMatchingEngine;
-pub use orderbook_tracker::OrderBookTracker;
\ No newline at end of file
+pub use orderbook_tracker::OrderBookTracker;
+pub use match_stats::MatchStats;
+pub use maker_liquidity::{MakerLiquidity, MakerLiquiditySample};
+pub use book_memory::{BookLifecycleConfig, BookMemoryReport, SymbolBookMemory};
+pub use order_ack::{OrderAck, OrderAckRegistry, OrderAckStatus, OrderRejectReason};
@@ -1,24 +1,55 @@
 //! 호가창 변경 추적 및 Delta 생성 모듈
 //!
 //! 이 모듈은 호가창의 변경사항을 추적하고 Delta 업데이트를 생성하는 역할을 담당합니다.
+//! 호가창이 바뀔 때마다 상위 레벨 전체 대신 바뀐 레벨(가격, 새 잔량 또는 삭제)만 보내고,
+//! 심볼의 첫 발행과 키프레임 주기마다 전체 호가(Snapshot)를 보내 누락된 Delta를 복구합니다.
+//! 최우선 호가(BBO)만 필요한 구독자(티커, 리스크)를 위해 BBO 변경 이벤트도 따로 생성합니다.
+//! 최우선 호가에 주문을 올려 둔 계정별 체류 시간과 잔량도 누적해 마켓 메이커 리베이트 보고서에 씁니다.
 
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
-use crate::api::models::{OrderBookChange, OrderBookChangeType, OrderBookDelta, OrderBookSnapshot};
-use crate::matching_engine::model::OrderBookSnapshot as EngineOrderBookSnapshot;
+use crate::api::models::{
+    BboUpdate, LiquidityBand, MarketImpactResponse, MicrostructureResponse, OrderBookChange, OrderBookChangeType, OrderBookDelta,
+    OrderBookSnapshot,
+};
+use crate::matching_engine::maker_liquidity::{MakerLiquidity, MakerLiquiditySample};
+use crate::matching_engine::model::{OrderBookSnapshot as EngineOrderBookSnapshot, Side};
+
+/// 기본 유동성 집계 범위 (bps)
+pub const DEFAULT_LIQUIDITY_BANDS_BPS: [u32; 3] = [10, 50, 100];
+
+/// 심볼별 마지막으로 발행한 호가 (매수, 매도)
+type PublishedLevels = (Vec<(u64, u64)>, Vec<(u64, u64)>);
+
+/// 호가창 발행 메시지
+#[derive(Debug, Clone)]
+pub enum OrderBookUpdateKind {
+    /// 키프레임 (전체 호가)
+    Snapshot(OrderBookSnapshot),
+    /// 바뀐 레벨만
+    Delta(OrderBookDelta),
+}
 
 /// 호가창 변경 추적기
 pub struct OrderBookTracker {
-    /// 심볼별 이전 호가창 상태
-    previous_snapshots: HashMap<String, Vec<(u64, u64)>>,
+    /// 심볼별 이전 호가창 상태 (Delta 기준)
+    previous_snapshots: HashMap<String, PublishedLevels>,
     /// 심볼별 시퀀스 번호
     sequence_numbers: HashMap<String, u64>,
     /// Delta 업데이트 임계값 (변경사항이 이 값 이상일 때만 Delta 전송)
     delta_threshold: usize,
-    /// Snapshot 전송 간격 (밀리초)
+    /// Snapshot(키프레임) 전송 간격 (밀리초)
     snapshot_interval_ms: u64,
     /// 마지막 Snapshot 전송 시간
     last_snapshot_time: HashMap<String, u64>,
+    /// 유동성 집계 범위 (bps)
+    liquidity_bands_bps: Vec<u32>,
+    /// 심볼별 최신 미시구조 지표
+    microstructure: HashMap<String, MicrostructureResponse>,
+    /// 심볼별 마지막으로 발행한 BBO
+    last_bbo: HashMap<String, BboUpdate>,
+    /// 최우선 호가 계정별 유동성 공급 누적
+    maker_liquidity: MakerLiquidity,
 }
 
 impl OrderBookTracker {
@@ -30,10 +61,183 @@ impl OrderBookTracker {
             delta_threshold,
             snapshot_interval_ms,
             last_snapshot_time: HashMap::new(),
+            liquidity_bands_bps: DEFAULT_LIQUIDITY_BANDS_BPS.to_vec(),
+            microstructure: HashMap::new(),
+            last_bbo: HashMap::new(),
+            maker_liquidity: MakerLiquidity::new(),
         }
     }
 
+    /// 키프레임 간격 설정 (밀리초)
+    pub fn set_snapshot_interval(&mut self, snapshot_interval_ms: u64) {
+        self.snapshot_interval_ms = snapshot_interval_ms;
+    }
+
+    /// 유동성 집계 범위 설정 (bps)
+    pub fn with_liquidity_bands(mut self, bands_bps: Vec<u32>) -> Self {
+        self.liquidity_bands_bps = bands_bps;
+        self
+    }
+
+    /// 호가창 업데이트 시 미시구조 지표 갱신
+    pub fn update_microstructure(&mut self, symbol: &str, current_snapshot: &EngineOrderBookSnapshot) {
+        let stats = self.compute_microstructure(symbol, current_snapshot);
+        self.microstructure.insert(symbol.to_string(), stats);
+    }
+
+    /// 캐시된 미시구조 지표 조회
+    pub fn get_microstructure(&self, symbol: &str) -> Option<&MicrostructureResponse> {
+        self.microstructure.get(symbol)
+    }
+
+    /// 호가창에서 미시구조 지표 계산 (호가창의 모든 레벨을 상위 N 레벨로 사용)
+    pub fn compute_microstructure(
+        &self,
+        symbol: &str,
+        current_snapshot: &EngineOrderBookSnapshot,
+    ) -> MicrostructureResponse {
+        let bids = &current_snapshot.bids;
+        let asks = &current_snapshot.asks;
+        let best_bid = bids.first().copied();
+        let best_ask = asks.first().copied();
+
+        // 상위 N 레벨 잔량 불균형
+        let bid_total: u64 = bids.iter().map(|&(_, qty)| qty).sum();
+        let ask_total: u64 = asks.iter().map(|&(_, qty)| qty).sum();
+        let imbalance = if bid_total + ask_total > 0 {
+            Some((bid_total as f64 - ask_total as f64) / (bid_total + ask_total) as f64)
+        } else {
+            None
+        };
+
+        let mut weighted_mid_price = None;
+        let mut spread_bps = None;
+        let mut liquidity = Vec::with_capacity(self.liquidity_bands_bps.len());
+
+        if let (Some((bid_price, bid_qty)), Some((ask_price, ask_qty))) = (best_bid, best_ask) {
+            let mid = (bid_price + ask_price) as f64 / 2.0;
+
+            // 반대편 잔량으로 가중 (매수 잔량이 많을수록 매도 호가 쪽으로 이동)
+            weighted_mid_price = if bid_qty + ask_qty > 0 {
+                Some((bid_price as f64 * ask_qty as f64 + ask_price as f64 * bid_qty as f64) / (bid_qty + ask_qty) as f64)
+            } else {
+                Some(mid)
+            };
+            spread_bps = Some((ask_price as f64 - bid_price as f64) / mid * 10_000.0);
+
+            for &within_bps in &self.liquidity_bands_bps {
+                let range = mid * within_bps as f64 / 10_000.0;
+                liquidity.push(LiquidityBand {
+                    within_bps,
+                    bid_quantity: bids.iter().filter(|&&(price, _)| price as f64 >= mid - range).map(|&(_, qty)| qty).sum(),
+                    ask_quantity: asks.iter().filter(|&&(price, _)| price as f64 <= mid + range).map(|&(_, qty)| qty).sum(),
+                });
+            }
+        } else {
+            // 한쪽 호가가 비어 있으면 중간가가 없으므로 범위별 유동성은 0
+            liquidity.extend(self.liquidity_bands_bps.iter().map(|&within_bps| LiquidityBand {
+                within_bps,
+                bid_quantity: 0,
+                ask_quantity: 0,
+            }));
+        }
+
+        MicrostructureResponse {
+            symbol: symbol.to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64,
+            best_bid: best_bid.map(|(price, _)| price),
+            best_ask: best_ask.map(|(price, _)| price),
+            imbalance,
+            weighted_mid_price,
+            spread_bps,
+            depth_levels: bids.len().max(asks.len()),
+            liquidity,
+        }
+    }
+
+    /// 가상 시장가 주문의 시장 충격 추정
+    ///
+    /// 반대편 호가를 최우선부터 쓸어가며 평균/최악 체결가와 총 체결 금액을 계산합니다.
+    /// 보호 한도는 적용하지 않으며, 호가가 부족하면 채울 수 있는 수량까지만 계산합니다.
+    pub fn compute_market_impact(
+        &self,
+        symbol: &str,
+        current_snapshot: &EngineOrderBookSnapshot,
+        side: Side,
+        quantity: u64,
+    ) -> MarketImpactResponse {
+        let levels = match side {
+            Side::Buy => &current_snapshot.asks,
+            Side::Sell => &current_snapshot.bids,
+        };
+        let best_price = levels.first().map(|&(price, _)| price);
+
+        let mut remaining = quantity;
+        let mut total_cost: u64 = 0;
+        let mut worst_price = None;
+        let mut levels_consumed = 0;
+        for &(price, level_qty) in levels {
+            if remaining == 0 {
+                break;
+            }
+            let fill = remaining.min(level_qty);
+            remaining -= fill;
+            total_cost = total_cost.saturating_add(price.saturating_mul(fill));
+            worst_price = Some(price);
+            levels_consumed += 1;
+        }
+
+        let fillable_quantity = quantity - remaining;
+        let average_price = (fillable_quantity > 0).then(|| total_cost as f64 / fillable_quantity as f64);
+        let slippage_bps = best_price.zip(average_price).map(|(best, average)| {
+            let difference = match side {
+                Side::Buy => average - best as f64,
+                Side::Sell => best as f64 - average,
+            };
+            difference / best as f64 * 10_000.0
+        });
+
+        MarketImpactResponse {
+            symbol: symbol.to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64,
+            side,
+            quantity,
+            fillable_quantity,
+            best_price,
+            average_price,
+            worst_price,
+            total_cost,
+            slippage_bps,
+            levels_consumed,
+        }
+    }
+
+    /// 호가창 변경 시 발행할 메시지 결정
+    ///
+    /// 심볼의 첫 발행이거나 키프레임 간격이 지났으면 전체 호가, 그 밖에는 바뀐 레벨만 반환합니다.
+    /// 바뀐 레벨이 Delta 임계값보다 적으면 None이며, 그 변경은 다음 Delta에 함께 실립니다.
+    pub fn next_update(
+        &mut self,
+        symbol: &str,
+        current_snapshot: &EngineOrderBookSnapshot,
+        now_ms: u64,
+    ) -> Option<OrderBookUpdateKind> {
+        if !self.previous_snapshots.contains_key(symbol) || self.keyframe_due(symbol, now_ms) {
+            self.last_snapshot_time.insert(symbol.to_string(), now_ms);
+            return Some(OrderBookUpdateKind::Snapshot(self.create_snapshot(symbol, current_snapshot)));
+        }
+        self.analyze_changes(symbol, current_snapshot).map(OrderBookUpdateKind::Delta)
+    }
+
     /// 호가창 변경사항 분석 및 Delta 생성
+    ///
+    /// 이전 상태가 없으면 현재 상태를 기준으로 저장만 하고 None을 반환합니다.
     pub fn analyze_changes(
         &mut self,
         symbol: &str,
@@ -42,16 +246,19 @@ impl OrderBookTracker {
         let current_bids = &current_snapshot.bids;
         let current_asks = &current_snapshot.asks;
         
-        // 이전 상태 가져오기
-        let previous_bids = self.previous_snapshots.get(symbol).cloned().unwrap_or_default();
-        let previous_asks = self.previous_snapshots.get(symbol).cloned().unwrap_or_default();
+        // 이전 상태 가져오기 (없으면 기준만 저장)
+        let Some((previous_bids, previous_asks)) = self.previous_snapshots.get(symbol) else {
+            self.previous_snapshots.insert(symbol.to_string(), (current_bids.clone(), current_asks.clone()));
+            return None;
+        };
         
         // 변경사항 분석
-        let bid_changes = self.analyze_price_level_changes(&previous_bids, current_bids);
-        let ask_changes = self.analyze_price_level_changes(&previous_asks, current_asks);
+        let bid_changes = Self::analyze_price_level_changes(previous_bids, current_bids);
+        let ask_changes = Self::analyze_price_level_changes(previous_asks, current_asks);
         
-        // 변경사항이 임계값 미만이면 Delta 전송하지 않음
-        if bid_changes.len() + ask_changes.len() < self.delta_threshold {
+        // 변경사항이 임계값 미만이면 Delta 전송하지 않음 (기준을 유지하므로 다음 Delta에 포함)
+        let changed = bid_changes.len() + ask_changes.len();
+        if changed == 0 || changed < self.delta_threshold {
             return None;
         }
         
@@ -60,8 +267,7 @@ impl OrderBookTracker {
         *sequence += 1;
         
         // 현재 상태 저장
-        self.previous_snapshots.insert(symbol.to_string(), current_bids.clone());
-        self.previous_snapshots.insert(format!("{}_asks", symbol), current_asks.clone());
+        self.previous_snapshots.insert(symbol.to_string(), (current_bids.clone(), current_asks.clone()));
         
         // Delta 생성
         Some(OrderBookDelta {
@@ -83,9 +289,7 @@ impl OrderBookTracker {
             .unwrap()
             .as_millis() as u64;
         
-        let last_time = self.last_snapshot_time.get(symbol).copied().unwrap_or(0);
-        
-        if now - last_time >= self.snapshot_interval_ms {
+        if self.keyframe_due(symbol, now) {
             self.last_snapshot_time.insert(symbol.to_string(), now);
             true
         } else {
@@ -93,6 +297,12 @@ impl OrderBookTracker {
         }
     }
 
+    /// 키프레임 간격이 지났는지 확인
+    fn keyframe_due(&self, symbol: &str, now_ms: u64) -> bool {
+        let last_time = self.last_snapshot_time.get(symbol).copied().unwrap_or(0);
+        now_ms.saturating_sub(last_time) >= self.snapshot_interval_ms
+    }
+
     /// Snapshot 생성
     pub fn create_snapshot(
         &mut self,
@@ -104,8 +314,7 @@ impl OrderBookTracker {
         *sequence += 1;
         
         // 현재 상태 저장
-        self.previous_snapshots.insert(symbol.to_string(), current_snapshot.bids.clone());
-        self.previous_snapshots.insert(format!("{}_asks", symbol), current_snapshot.asks.clone());
+        self.previous_snapshots.insert(symbol.to_string(), (current_snapshot.bids.clone(), current_snapshot.asks.clone()));
         
         OrderBookSnapshot {
             symbol: symbol.to_string(),
@@ -119,9 +328,25 @@ impl OrderBookTracker {
         }
     }
 
+    /// 마지막으로 발행한 호가 (시퀀스 번호를 올리지 않으므로 다른 구독자의 Delta 순서에 영향 없음)
+    ///
+    /// 재동기화하는 구독자는 이 Snapshot 이후 시퀀스의 Delta부터 적용하면 됩니다.
+    pub fn published_snapshot(&self, symbol: &str) -> Option<OrderBookSnapshot> {
+        let (bids, asks) = self.previous_snapshots.get(symbol)?;
+        Some(OrderBookSnapshot {
+            symbol: symbol.to_string(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64,
+            sequence: self.get_sequence(symbol),
+        })
+    }
+
     /// 가격 레벨 변경사항 분석
     fn analyze_price_level_changes(
-        &self,
         previous: &[(u64, u64)],
         current: &[(u64, u64)],
     ) -> Vec<OrderBookChange> {
@@ -166,6 +391,53 @@ impl OrderBookTracker {
         changes
     }
 
+    /// 최우선 호가 변경 감지
+    ///
+    /// 최우선 매수/매도 호가의 가격이나 잔량이 마지막으로 발행한 BBO와 다를 때만 새 BBO를 반환합니다.
+    /// 심볼의 첫 호출은 항상 BBO를 반환하므로 구독자는 첫 이벤트로 현재 상태를 받습니다.
+    pub fn detect_bbo_change(&mut self, symbol: &str, current_snapshot: &EngineOrderBookSnapshot) -> Option<BboUpdate> {
+        let bid = current_snapshot.bids.first().copied();
+        let ask = current_snapshot.asks.first().copied();
+
+        let last = self.last_bbo.get(symbol);
+        if let Some(last) = last {
+            let unchanged = last.bid_price == bid.map(|(price, _)| price)
+                && last.bid_quantity == bid.map_or(0, |(_, qty)| qty)
+                && last.ask_price == ask.map(|(price, _)| price)
+                && last.ask_quantity == ask.map_or(0, |(_, qty)| qty);
+            if unchanged {
+                return None;
+            }
+        }
+
+        let bbo = BboUpdate {
+            symbol: symbol.to_string(),
+            bid_price: bid.map(|(price, _)| price),
+            bid_quantity: bid.map_or(0, |(_, qty)| qty),
+            ask_price: ask.map(|(price, _)| price),
+            ask_quantity: ask.map_or(0, |(_, qty)| qty),
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64,
+            sequence: last.map_or(0, |last| last.sequence) + 1,
+        };
+        self.last_bbo.insert(symbol.to_string(), bbo.clone());
+        Some(bbo)
+    }
+
+    /// 심볼별 마지막으로 발행한 BBO 조회
+    pub fn get_bbo(&self, symbol: &str) -> Option<&BboUpdate> {
+        self.last_bbo.get(symbol)
+    }
+
+    /// 최우선 호가 계정별 잔량 기록 (직전 상태가 유지된 구간을 누적)
+    pub fn record_best_makers(&mut self, symbol: &str, now_ms: u64, bid: Vec<(String, u64)>, ask: Vec<(String, u64)>) {
+        self.maker_liquidity.update(symbol, now_ms, bid, ask);
+    }
+
+    /// `now_ms`까지 누적한 계정별 최우선 호가 유동성을 가져감
+    pub fn take_maker_liquidity(&mut self, now_ms: u64) -> Vec<MakerLiquiditySample> {
+        self.maker_liquidity.take(now_ms)
+    }
+
     /// 심볼별 시퀀스 번호 조회
     pub fn get_sequence(&self, symbol: &str) -> u64 {
         self.sequence_numbers.get(symbol).copied().unwrap_or(0)
@@ -229,6 +501,66 @@ mod tests {
         assert!(delta.ask_changes.iter().any(|c| c.price == 50300 && c.change_type == OrderBookChangeType::Add));
     }
 
+    #[test]
+    fn test_next_update_sends_keyframes_then_changed_levels() {
+        let mut tracker = OrderBookTracker::new(1, 1000);
+        let initial = create_test_snapshot(vec![(50000, 100), (49900, 200)], vec![(50100, 150), (50200, 300)]);
+
+        // 첫 발행은 전체 호가
+        let Some(OrderBookUpdateKind::Snapshot(keyframe)) = tracker.next_update("BTC-KRW", &initial, 10_000) else {
+            panic!("첫 발행은 키프레임이어야 함");
+        };
+        assert_eq!((keyframe.bids.len(), keyframe.asks.len(), keyframe.sequence), (2, 2, 1));
+
+        // 매도 쪽만 바뀌면 매도 변경만 (매수 호가와 섞이지 않음)
+        let changed = create_test_snapshot(vec![(50000, 100), (49900, 200)], vec![(50100, 120), (50200, 300)]);
+        let Some(OrderBookUpdateKind::Delta(delta)) = tracker.next_update("BTC-KRW", &changed, 10_100) else {
+            panic!("변경은 Delta여야 함");
+        };
+        assert!(delta.bid_changes.is_empty());
+        assert_eq!(delta.ask_changes.len(), 1);
+        assert_eq!((delta.ask_changes[0].price, delta.ask_changes[0].quantity), (50100, 120));
+        assert_eq!(delta.sequence, 2);
+
+        // 상위 레벨이 그대로면 보내지 않음
+        assert!(tracker.next_update("BTC-KRW", &changed, 10_200).is_none());
+
+        // 레벨 삭제
+        let removed = create_test_snapshot(vec![(50000, 100)], vec![(50100, 120), (50200, 300)]);
+        let Some(OrderBookUpdateKind::Delta(delta)) = tracker.next_update("BTC-KRW", &removed, 10_300) else {
+            panic!("삭제는 Delta여야 함");
+        };
+        assert_eq!(delta.bid_changes.len(), 1);
+        assert_eq!(delta.bid_changes[0].change_type, OrderBookChangeType::Remove);
+        assert_eq!((delta.bid_changes[0].price, delta.bid_changes[0].quantity), (49900, 0));
+
+        // 키프레임 간격이 지나면 다시 전체 호가
+        let Some(OrderBookUpdateKind::Snapshot(keyframe)) = tracker.next_update("BTC-KRW", &initial, 11_000) else {
+            panic!("키프레임 간격이 지나면 전체 호가여야 함");
+        };
+        assert_eq!((keyframe.bids, keyframe.sequence), (vec![(50000, 100), (49900, 200)], 4));
+    }
+
+    #[test]
+    fn test_published_snapshot_keeps_sequence() {
+        let mut tracker = OrderBookTracker::new(1, 60_000);
+        assert!(tracker.published_snapshot("BTC-KRW").is_none());
+
+        tracker.next_update("BTC-KRW", &create_test_snapshot(vec![(50000, 100)], vec![(50100, 150)]), 0);
+        tracker.next_update("BTC-KRW", &create_test_snapshot(vec![(50000, 80)], vec![(50100, 150)]), 1);
+
+        // 재동기화 응답은 시퀀스를 올리지 않음 (다른 구독자의 다음 Delta는 3)
+        let sync = tracker.published_snapshot("BTC-KRW").unwrap();
+        assert_eq!((sync.bids, sync.sequence), (vec![(50000, 80)], 2));
+        assert_eq!(tracker.get_sequence("BTC-KRW"), 2);
+        let Some(OrderBookUpdateKind::Delta(delta)) =
+            tracker.next_update("BTC-KRW", &create_test_snapshot(vec![(50000, 70)], vec![(50100, 150)]), 2)
+        else {
+            panic!("변경은 Delta여야 함");
+        };
+        assert_eq!(delta.sequence, 3);
+    }
+
     #[test]
     fn test_snapshot_generation() {
         let mut tracker = OrderBookTracker::new(1, 1000);
@@ -246,6 +578,99 @@ mod tests {
         assert_eq!(orderbook_snapshot.sequence, 1);
     }
 
+    #[test]
+    fn test_microstructure_stats() {
+        let mut tracker = OrderBookTracker::new(1, 1000).with_liquidity_bands(vec![10, 100]);
+        assert!(tracker.get_microstructure("BTC-KRW").is_none());
+
+        // 중간가 10000, 10bps = ±10, 100bps = ±100
+        let snapshot = create_test_snapshot(
+            vec![(9995, 300), (9950, 200), (9800, 500)],
+            vec![(10005, 100), (10080, 100)],
+        );
+        tracker.update_microstructure("BTC-KRW", &snapshot);
+
+        let stats = tracker.get_microstructure("BTC-KRW").unwrap();
+        assert_eq!(stats.best_bid, Some(9995));
+        assert_eq!(stats.best_ask, Some(10005));
+        assert_eq!(stats.depth_levels, 3);
+        assert!((stats.imbalance.unwrap() - (1000.0 - 200.0) / 1200.0).abs() < 1e-9);
+        assert!((stats.spread_bps.unwrap() - 10.0).abs() < 1e-9);
+        // (9995 * 100 + 10005 * 300) / 400
+        assert!((stats.weighted_mid_price.unwrap() - 10002.5).abs() < 1e-9);
+        assert_eq!(stats.liquidity, vec![
+            LiquidityBand { within_bps: 10, bid_quantity: 300, ask_quantity: 100 },
+            LiquidityBand { within_bps: 100, bid_quantity: 500, ask_quantity: 200 },
+        ]);
+
+        // 한쪽 호가가 비면 중간가 기반 지표 없음
+        tracker.update_microstructure("BTC-KRW", &create_test_snapshot(vec![(9995, 300)], vec![]));
+        let stats = tracker.get_microstructure("BTC-KRW").unwrap();
+        assert_eq!(stats.imbalance, Some(1.0));
+        assert!(stats.spread_bps.is_none());
+        assert!(stats.weighted_mid_price.is_none());
+        assert!(stats.liquidity.iter().all(|band| band.bid_quantity == 0 && band.ask_quantity == 0));
+    }
+
+    #[test]
+    fn test_market_impact_walks_opposite_side() {
+        let tracker = OrderBookTracker::new(1, 1000);
+        let snapshot = create_test_snapshot(
+            vec![(9990, 50), (9980, 100)],
+            vec![(10000, 100), (10010, 100), (10050, 100)],
+        );
+
+        // 매수 150: 10000 × 100 + 10010 × 50
+        let impact = tracker.compute_market_impact("BTC-KRW", &snapshot, Side::Buy, 150);
+        assert_eq!(impact.fillable_quantity, 150);
+        assert_eq!(impact.total_cost, 1_500_500);
+        assert_eq!((impact.best_price, impact.worst_price, impact.levels_consumed), (Some(10000), Some(10010), 2));
+        assert!((impact.average_price.unwrap() - 1_500_500.0 / 150.0).abs() < 1e-9);
+        assert!((impact.slippage_bps.unwrap() - (1_500_500.0 / 150.0 - 10000.0) / 10000.0 * 10_000.0).abs() < 1e-9);
+
+        // 매도는 매수 호가를 쓸어가고, 호가가 부족하면 채울 수 있는 수량까지만
+        let impact = tracker.compute_market_impact("BTC-KRW", &snapshot, Side::Sell, 500);
+        assert_eq!((impact.fillable_quantity, impact.total_cost), (150, 9990 * 50 + 9980 * 100));
+        assert_eq!(impact.worst_price, Some(9980));
+        assert!(impact.slippage_bps.unwrap() > 0.0);
+
+        // 반대편 호가가 없으면 가격 지표 없음
+        let impact = tracker.compute_market_impact("BTC-KRW", &create_test_snapshot(vec![], vec![]), Side::Buy, 10);
+        assert_eq!((impact.fillable_quantity, impact.best_price, impact.average_price), (0, None, None));
+    }
+
+    #[test]
+    fn test_bbo_emitted_only_when_top_of_book_changes() {
+        let mut tracker = OrderBookTracker::new(1, 1000);
+        assert!(tracker.get_bbo("BTC-KRW").is_none());
+
+        // 첫 호출은 현재 BBO 발행
+        let bbo = tracker
+            .detect_bbo_change("BTC-KRW", &create_test_snapshot(vec![(50000, 100), (49900, 200)], vec![(50100, 150)]))
+            .unwrap();
+        assert_eq!((bbo.bid_price, bbo.bid_quantity, bbo.ask_price, bbo.ask_quantity), (Some(50000), 100, Some(50100), 150));
+        assert_eq!(bbo.sequence, 1);
+
+        // 2호가 이하만 바뀌면 발행하지 않음
+        let deeper_change = create_test_snapshot(vec![(50000, 100), (49800, 500)], vec![(50100, 150), (50200, 10)]);
+        assert!(tracker.detect_bbo_change("BTC-KRW", &deeper_change).is_none());
+
+        // 최우선 잔량 변경
+        let bbo = tracker
+            .detect_bbo_change("BTC-KRW", &create_test_snapshot(vec![(50000, 40)], vec![(50100, 150)]))
+            .unwrap();
+        assert_eq!(bbo.bid_quantity, 40);
+        assert_eq!(bbo.sequence, 2);
+
+        // 매도 호가 소진
+        let bbo = tracker
+            .detect_bbo_change("BTC-KRW", &create_test_snapshot(vec![(50000, 40)], vec![]))
+            .unwrap();
+        assert_eq!((bbo.ask_price, bbo.ask_quantity), (None, 0));
+        assert_eq!(tracker.get_bbo("BTC-KRW"), Some(&bbo));
+        assert!(tracker.detect_bbo_change("BTC-KRW", &create_test_snapshot(vec![(50000, 40)], vec![])).is_none());
+    }
+
     #[test]
     fn test_snapshot_interval() {
         let mut tracker = OrderBookTracker::new(1, 1000);
@@ -2,11 +2,11 @@ use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH, Instant};
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::Mutex;
 use uuid::Uuid;
-use log::{debug, error, info, warn, trace};
+use log::{debug, info, warn};
 
-use crate::matching_engine::model::{Order, OrderType, Side, ExecutionReport, OrderBookSnapshot};
+use crate::matching_engine::model::{Order, OrderType, Side, ExecutionReport, ExecType, OrderBookSnapshot};
 use crate::matching_engine::order_book::OrderBook;
 
 /// 초고성능 매칭 엔진
@@ -58,6 +58,12 @@ pub struct PerformanceMetrics {
     pub start_time: Instant,
 }
 
+impl Default for PerformanceMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PerformanceMetrics {
     pub fn new() -> Self {
         Self {
@@ -153,8 +159,8 @@ impl UltraFastMatchingEngine {
     /// 메모리 전용 매칭 (DB 접근 없음)
     async fn match_in_memory_only(&mut self, order: Order) -> Result<ExecutionReport, ExecutionError> {
         // 잔고 검증 (메모리 캐시에서만)
-        let balance = self.get_balance_memory_only(&order.user_id);
-        if balance < order.total_amount() {
+        let balance = self.get_balance_memory_only(&order.client_id);
+        if balance < order.price.saturating_mul(order.quantity) {
             return Err(ExecutionError::InsufficientBalance);
         }
         
@@ -225,8 +231,10 @@ impl UltraFastMatchingEngine {
                 
                 // 완전히 체결되지 않은 경우 주문장에 추가
                 if !order.is_filled() {
+                    let report = self.create_execution_report(&order);
                     let order_book = self.order_books.get_mut(&symbol).unwrap();
                     order_book.add_order(order);
+                    return Ok(report);
                 } else {
                     // 완전히 체결된 주문은 저장소에서 제거
                     self.order_store.remove(&order.id);
@@ -421,21 +429,29 @@ impl UltraFastMatchingEngine {
                 .as_secs(),
             counterparty_id: "system".to_string(), // 실제로는 매칭된 주문 ID
             is_maker: false,
+            exec_type: ExecType::Trade,
+            sequence: 0,
+            tag: order.tag.clone(),
         }
     }
     
     /// 즉시 클라이언트 응답 전송
     async fn send_immediate_response(&self, execution: &ExecutionReport) {
+        if let Err(e) = self.exec_tx.try_send(execution.clone()) {
+            warn!("체결 보고서 전송 실패: {}", e);
+        }
+
         if let Some(ref broadcast_tx) = self.broadcast_tx {
-            let message = crate::api::models::WebSocketMessage::ExecutionUpdate {
-                execution_id: execution.execution_id.clone(),
-                order_id: execution.order_id.clone(),
-                symbol: execution.symbol.clone(),
-                side: execution.side.clone(),
-                price: execution.price,
-                quantity: execution.quantity,
-                remaining_quantity: execution.remaining_quantity,
-                timestamp: execution.timestamp,
+            let order_status = if execution.remaining_quantity == 0 {
+                "Filled"
+            } else if execution.remaining_quantity < execution.quantity {
+                "PartiallyFilled"
+            } else {
+                "Pending"
+            };
+            let message = crate::api::models::WebSocketMessage::Execution {
+                execution_report: execution.clone(),
+                order_status: order_status.to_string(),
             };
             
             if let Err(e) = broadcast_tx.send(message) {
@@ -463,7 +479,8 @@ impl UltraFastMatchingEngine {
     /// 영속화 큐 가져오기 (배치 처리용)
     pub async fn drain_persistence_queue(&self, batch_size: usize) -> Vec<PersistenceTask> {
         let mut queue = self.persistence_queue.lock().await;
-        queue.drain(..batch_size.min(queue.len())).collect()
+        let count = batch_size.min(queue.len());
+        queue.drain(..count).collect()
     }
     
     /// 성능 메트릭 조회
@@ -533,10 +550,13 @@ mod tests {
             quantity,
             remaining_quantity: quantity,
             client_id: "user1".to_string(),
-            user_id: "user1".to_string(),
             timestamp: 0,
             is_cancel: false,
             target_order_id: None,
+            protection: None,
+            expire_time: None,
+            sequence: 0,
+            tag: None,
         }
     }
     
@@ -548,14 +568,15 @@ mod tests {
         
         // 매도 주문 추가
         let sell_order = create_test_order("order1", Side::Sell, OrderType::Limit, 10000, 100);
-        engine.process_order(sell_order).await.unwrap();
+        engine.ultra_fast_execution(sell_order).await.unwrap();
         
         // 매수 주문 추가 (매칭)
         let buy_order = create_test_order("order2", Side::Buy, OrderType::Limit, 10000, 100);
-        let execution = engine.process_order(buy_order).await.unwrap();
+        let execution = engine.ultra_fast_execution(buy_order).await.unwrap();
         
         assert_eq!(execution.price, 10000);
         assert_eq!(execution.quantity, 100);
+        assert_eq!(exec_rx.recv().await.unwrap().order_id, "order1");
         
         // 성능 메트릭 확인
         let metrics = engine.get_metrics();
@@ -570,11 +591,11 @@ mod tests {
         let engine = UltraFastMatchingEngine::new(symbols, exec_tx);
         
         // 잔고 조회 (메모리에서만)
-        let balance = engine.get_balance("user1");
+        let balance = engine.get_balance_memory_only("user1");
         assert_eq!(balance, 50_000_000_000);
         
         // 존재하지 않는 사용자
-        let balance_unknown = engine.get_balance("unknown_user");
+        let balance_unknown = engine.get_balance_memory_only("unknown_user");
         assert_eq!(balance_unknown, 0);
     }
 }
\ No newline at end of file
@@ -0,0 +1,177 @@
+//! 봉차트 백필과 체결 스냅샷 복구
+//!
+//! 재시작 직후 MDP 봉차트가 비어 차트에 공백이 생기지 않도록, 서비스 시작 전에
+//! `executions` 테이블의 최근 체결(설정한 기간)을 다시 읽어 봉차트를 재구성합니다.
+//! 체결은 심볼별로 (체결 시각, 체결 ID) 순 페이지 단위로 읽습니다.
+//! Kafka 메시지 누락을 재전송으로 채우지 못하면 같은 테이블의 최근 체결 스냅샷으로 복구합니다.
+
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use log::info;
+use sqlx::Error as SqlxError;
+
+use crate::db::repository::ExecutionRepository;
+use crate::mdp::consumer::TradeData;
+use crate::mdp::gap::{GapFill, GapRecovery};
+use crate::mdp::MarketDataPublisher;
+
+/// 한 번에 읽는 체결 수
+const PAGE_ROWS: i64 = 5_000;
+
+/// 봉차트 백필 설정
+#[derive(Debug, Clone)]
+pub struct CandleBackfillConfig {
+    /// 재구성할 기간 (0이면 백필 안 함)
+    pub lookback: Duration,
+}
+
+impl Default for CandleBackfillConfig {
+    fn default() -> Self {
+        Self {
+            // 1분봉 보관 기간과 같은 24시간
+            lookback: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+/// 심볼별 최근 체결로 봉차트 재구성 (`now`: Unix 타임스탬프, 초), 반영한 체결 수 반환
+pub async fn backfill_candles(
+    mdp: &MarketDataPublisher,
+    repository: &ExecutionRepository,
+    symbols: &[String],
+    config: &CandleBackfillConfig,
+    now: u64,
+) -> Result<usize, SqlxError> {
+    if config.lookback.is_zero() {
+        return Ok(0);
+    }
+
+    let from = now.saturating_sub(config.lookback.as_secs()) as i64;
+    let to = now as i64 + 1;
+    let mut total = 0;
+
+    for symbol in symbols {
+        let mut trades = Vec::new();
+        let mut after: Option<(i64, String)> = None;
+        loop {
+            let page = repository
+                .find_range_page(symbol, from, to, after.as_ref().map(|(time, id)| (*time, id.as_str())), PAGE_ROWS)
+                .await?;
+            let Some(last) = page.last() else { break };
+            after = Some((last.transaction_time, last.exec_id.clone()));
+            let full_page = page.len() as i64 == PAGE_ROWS;
+
+            trades.extend(
+                page.iter()
+                    .map(|record| (record.price as u64, record.quantity as u64, record.transaction_time as u64)),
+            );
+            if !full_page {
+                break;
+            }
+        }
+
+        if !trades.is_empty() {
+            info!("봉차트 백필: {} 체결 {}건", symbol, trades.len());
+        }
+        total += trades.len();
+        mdp.backfill_candles(symbol, &trades).await;
+    }
+
+    Ok(total)
+}
+
+/// DB 스냅샷으로 읽는 심볼별 최근 체결 수 (Consumer 보관 한도와 같음)
+const SNAPSHOT_TRADES: i64 = 1000;
+
+/// DB 최근 체결 스냅샷으로 복구
+pub struct ExecutionSnapshotRecovery {
+    repository: ExecutionRepository,
+}
+
+impl ExecutionSnapshotRecovery {
+    pub fn new(repository: ExecutionRepository) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl GapRecovery for ExecutionSnapshotRecovery {
+    async fn recover(&self, symbol: &str, _missing: RangeInclusive<u64>) -> Result<Option<GapFill>, String> {
+        let mut records = self
+            .repository
+            .find_by_symbol(symbol, SNAPSHOT_TRADES)
+            .await
+            .map_err(|e| format!("체결 스냅샷 조회 실패: {}", e))?;
+        records.reverse();
+
+        let trades = records
+            .into_iter()
+            .map(|record| TradeData {
+                symbol: record.symbol,
+                price: record.price as u64,
+                quantity: record.quantity as u64,
+                timestamp: record.transaction_time as u64,
+                side: record.side.to_lowercase(),
+                trade_id: record.exec_id,
+            })
+            .collect();
+        Ok(Some(GapFill::Snapshot(trades)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::ExecutionRecord;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[tokio::test]
+    async fn test_backfill_rebuilds_recent_candles() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+
+        let now = 1_700_000_000u64 / 60 * 60 + 59;
+        let repository = ExecutionRepository::new(pool);
+        // 조회 기간 밖 1건 + 직전 분 2건 + 현재 분 1건
+        let trades = [(now - 7_200, 900), (now - 90, 1_000), (now - 70, 1_200), (now - 10, 1_100)];
+        for (i, (time, price)) in trades.iter().enumerate() {
+            repository
+                .save(&ExecutionRecord {
+                    exec_id: format!("e{}", i),
+                    taker_order_id: format!("t{}", i),
+                    maker_order_id: format!("m{}", i),
+                    symbol: "BTC-KRW".to_string(),
+                    side: "Buy".to_string(),
+                    price: *price,
+                    quantity: 2,
+                    taker_fee: 0,
+                    maker_fee: 0,
+                    transaction_time: *time as i64,
+                })
+                .await
+                .unwrap();
+        }
+
+        let mdp = MarketDataPublisher::new(100);
+        let config = CandleBackfillConfig { lookback: Duration::from_secs(3_600) };
+        let symbols = vec!["BTC-KRW".to_string(), "ETH-KRW".to_string()];
+        let count = backfill_candles(&mdp, &repository, &symbols, &config, now).await.unwrap();
+        assert_eq!(count, 3);
+
+        let candles = mdp.get_candles("BTC-KRW", "1m", 10).await;
+        assert_eq!(candles.len(), 2);
+        assert_eq!((candles[0].open, candles[0].high, candles[0].close), (1_000, 1_200, 1_200));
+        assert_eq!((candles[0].volume, candles[0].trade_count), (4, 2));
+        assert_eq!(candles[1].close, 1_100);
+        assert_eq!(mdp.get_candles("BTC-KRW", "1h", 10).await.iter().map(|c| c.trade_count).sum::<u64>(), 3);
+
+        // 체결이 없는 심볼은 건드리지 않음 (초기 데이터 유지)
+        assert!(mdp.get_candles("ETH-KRW", "1m", 10).await.is_empty());
+    }
+}
@@ -1,18 +1,7 @@
-/**
-* filename : mod
-* author : HAMA
-* date: 2025. 5. 13.
-* description: Market Data Publisher 모듈
-**/
+// 시장 데이터 발행/조회/캐시/녹화는 xtrader-mdp 크레이트로 분리 (기존 `xtrader::mdp` 경로 유지)
+pub use xtrader_mdp::*;
 
-pub mod model;
-pub mod publisher;
-pub mod consumer;
-pub mod api;
-pub mod cache;
+// executions 테이블을 읽는 봉차트 백필, 누락 스냅샷 복구
+pub mod backfill;
 
-pub use model::*;
-pub use publisher::MarketDataPublisher;
-pub use consumer::*;
-pub use api::*;
-pub use cache::*;
\ No newline at end of file
+pub use backfill::{CandleBackfillConfig, ExecutionSnapshotRecovery};
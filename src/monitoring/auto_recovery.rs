@@ -0,0 +1,506 @@
+//! 비정상 서비스 자동 복구
+//!
+//! 헬스체크에서 연속 실패가 `trigger_after_failures` 회 이상인 서비스에 등록된 복구 작업
+//! (MQ Producer 재연결, Consumer 태스크 재시작, DB 풀 재연결, 백업 큐 재발행)을 실행합니다.
+//! 작업마다 쿨다운을 두어 같은 작업이 반복 실행되지 않게 하고,
+//! 자동 실행한 작업은 성공/실패와 관계없이 모두 감사 로그(`audit_logs`)에 남깁니다.
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use log::{debug, info, warn};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use crate::db::repository::AuditLogRepository;
+use crate::mq::{MQType, RecoveryManager};
+
+use super::system_health::{ServiceHealth, ServiceType};
+
+/// 감사 로그 엔티티 타입
+pub const RECOVERY_AUDIT_ENTITY: &str = "auto_recovery";
+pub const RECOVERY_SUCCEEDED_EVENT: &str = "AUTO_RECOVERY_SUCCEEDED";
+pub const RECOVERY_FAILED_EVENT: &str = "AUTO_RECOVERY_FAILED";
+
+/// 보관할 최근 복구 기록 수
+const HISTORY_SIZE: usize = 100;
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
+/// 자동 복구 설정
+#[derive(Debug, Clone)]
+pub struct AutoRecoveryConfig {
+    /// 복구를 시작할 연속 실패 횟수
+    pub trigger_after_failures: u32,
+    /// 같은 작업을 다시 실행하기까지 대기 시간
+    pub cooldown_ms: u64,
+    /// 작업 1회 제한 시간
+    pub action_timeout_ms: u64,
+}
+
+impl Default for AutoRecoveryConfig {
+    fn default() -> Self {
+        Self {
+            trigger_after_failures: 3,
+            cooldown_ms: 300_000,     // 5분
+            action_timeout_ms: 10_000, // 10초
+        }
+    }
+}
+
+/// 복구 작업
+#[async_trait]
+pub trait RecoveryAction: Send + Sync {
+    /// 작업 이름 (감사 로그와 쿨다운 키로 사용)
+    fn name(&self) -> &str;
+
+    /// 작업 실행 (성공 시 결과 메시지)
+    async fn execute(&self) -> Result<String, String>;
+}
+
+type ActionFn = dyn Fn() -> BoxFuture<'static, Result<String, String>> + Send + Sync;
+
+/// 클로저로 정의하는 복구 작업 (예: MQ Producer 재연결)
+pub struct FnRecoveryAction {
+    name: String,
+    action: Box<ActionFn>,
+}
+
+impl FnRecoveryAction {
+    pub fn new<F, Fut>(name: impl Into<String>, action: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, String>> + Send + 'static,
+    {
+        Self {
+            name: name.into(),
+            action: Box::new(move || Box::pin(action())),
+        }
+    }
+}
+
+#[async_trait]
+impl RecoveryAction for FnRecoveryAction {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn execute(&self) -> Result<String, String> {
+        (self.action)().await
+    }
+}
+
+type TaskFactory = dyn Fn() -> BoxFuture<'static, ()> + Send + Sync;
+
+/// 재시작 가능한 백그라운드 태스크 (Consumer 등)
+///
+/// 생성 시 태스크를 시작하며, 복구 작업으로 실행되면 기존 태스크를 중단하고
+/// 팩토리로 새 태스크를 띄웁니다. 팩토리는 재시작마다 연결을 새로 만들어야 합니다.
+pub struct SupervisedTask {
+    name: String,
+    factory: Box<TaskFactory>,
+    handle: std::sync::Mutex<Option<JoinHandle<()>>>,
+    restarts: AtomicU64,
+}
+
+impl SupervisedTask {
+    /// 태스크 시작
+    pub fn spawn<F, Fut>(name: impl Into<String>, factory: F) -> Arc<Self>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let task = Arc::new(Self {
+            name: format!("restart_{}", name.into()),
+            factory: Box::new(move || Box::pin(factory())),
+            handle: std::sync::Mutex::new(None),
+            restarts: AtomicU64::new(0),
+        });
+        task.start();
+        task
+    }
+
+    fn start(&self) {
+        let handle = tokio::spawn((self.factory)());
+        if let Some(previous) = self.handle.lock().unwrap().replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// 기존 태스크를 중단하고 다시 시작 (누적 재시작 횟수 반환)
+    pub fn restart(&self) -> u64 {
+        self.start();
+        self.restarts.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// 태스크 실행 중 여부
+    pub fn is_running(&self) -> bool {
+        self.handle
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|handle| !handle.is_finished())
+            .unwrap_or(false)
+    }
+}
+
+impl Drop for SupervisedTask {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+}
+
+#[async_trait]
+impl RecoveryAction for SupervisedTask {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn execute(&self) -> Result<String, String> {
+        let was_running = self.is_running();
+        let restarts = self.restart();
+        Ok(format!(
+            "태스크 재시작 ({}회째, 이전 상태: {})",
+            restarts,
+            if was_running { "실행 중" } else { "종료됨" }
+        ))
+    }
+}
+
+/// DB 풀 재연결
+///
+/// 풀은 여러 컴포넌트가 복제해 공유하므로 교체하지 않고, 유휴 연결을 닫아
+/// 새 연결을 맺게 한 뒤 `SELECT 1` 로 확인합니다. 공유 캐시 인메모리 DB(`sqlite::memory:`)가
+/// 사라지지 않도록 새 연결이 확인될 때까지 기존 연결 하나를 잡아 둡니다.
+pub struct ReopenDbPoolAction {
+    pool: SqlitePool,
+}
+
+impl ReopenDbPoolAction {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RecoveryAction for ReopenDbPoolAction {
+    fn name(&self) -> &str {
+        "reopen_db_pool"
+    }
+
+    async fn execute(&self) -> Result<String, String> {
+        // 단일 연결 풀은 잡아 둔 연결 외에 새 연결을 맺을 수 없으므로 확인만 함
+        if self.pool.options().get_max_connections() <= 1 {
+            sqlx::query_scalar::<_, i64>("SELECT 1")
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| format!("SELECT 1 실패: {}", e))?;
+            return Ok("단일 연결 풀 확인 (재연결 생략)".to_string());
+        }
+
+        let keeper = self.pool.acquire().await.map_err(|e| format!("DB 연결 실패: {}", e))?;
+        let mut closed = 0;
+        while let Some(connection) = self.pool.try_acquire() {
+            if let Err(e) = connection.close().await {
+                debug!("유휴 DB 연결 종료 실패: {}", e);
+            }
+            closed += 1;
+        }
+
+        sqlx::query_scalar::<_, i64>("SELECT 1")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| format!("유휴 연결 {}개 종료 후 재연결 실패: {}", closed, e))?;
+
+        // 새 연결이 풀에 남았으므로 잡아 둔 연결도 교체
+        if let Err(e) = keeper.close().await {
+            debug!("DB 연결 종료 실패: {}", e);
+        }
+        Ok(format!("연결 {}개 재연결 확인", closed + 1))
+    }
+}
+
+/// 백업 큐 재발행
+///
+/// MQ 장애 동안 로컬 백업 큐에 쌓인 메시지를 즉시 재발행합니다.
+pub struct FlushBackupQueueAction {
+    name: String,
+    recovery_manager: Arc<RecoveryManager>,
+    mq_type: MQType,
+}
+
+impl FlushBackupQueueAction {
+    pub fn new(recovery_manager: Arc<RecoveryManager>, mq_type: MQType) -> Self {
+        Self {
+            name: format!("flush_backup_queue_{:?}", mq_type).to_lowercase(),
+            recovery_manager,
+            mq_type,
+        }
+    }
+}
+
+#[async_trait]
+impl RecoveryAction for FlushBackupQueueAction {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn execute(&self) -> Result<String, String> {
+        let recovered = self.recovery_manager.trigger_manual_recovery(&self.mq_type).await?;
+        Ok(format!("백업 메시지 {}건 재발행", recovered))
+    }
+}
+
+/// 자동 복구 실행 기록
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoveryRecord {
+    pub service: String,
+    pub action: String,
+    pub success: bool,
+    pub message: String,
+    pub consecutive_failures: u32,
+    pub executed_at: u64,
+    pub duration_ms: u64,
+}
+
+/// 자동 복구 관리자 (서비스별 복구 작업 등록소)
+pub struct AutoRecoveryManager {
+    config: AutoRecoveryConfig,
+    actions: HashMap<ServiceType, Vec<Arc<dyn RecoveryAction>>>,
+    last_run: Mutex<HashMap<(ServiceType, String), Instant>>,
+    history: Mutex<VecDeque<RecoveryRecord>>,
+    audit: Option<AuditLogRepository>,
+}
+
+impl AutoRecoveryManager {
+    pub fn new(config: AutoRecoveryConfig) -> Self {
+        Self {
+            config,
+            actions: HashMap::new(),
+            last_run: Mutex::new(HashMap::new()),
+            history: Mutex::new(VecDeque::new()),
+            audit: None,
+        }
+    }
+
+    /// 서비스 복구 작업 등록 (등록 순서대로 실행)
+    pub fn with_action(mut self, service_type: ServiceType, action: Arc<dyn RecoveryAction>) -> Self {
+        self.actions.entry(service_type).or_default().push(action);
+        self
+    }
+
+    /// 감사 로그 기록 설정
+    pub fn with_audit_log(mut self, pool: SqlitePool) -> Self {
+        self.audit = Some(AuditLogRepository::new(pool));
+        self
+    }
+
+    /// 등록된 작업 수
+    pub fn action_count(&self) -> usize {
+        self.actions.values().map(Vec::len).sum()
+    }
+
+    /// 헬스체크 결과에 따라 복구 작업 실행
+    ///
+    /// 연속 실패가 임계치 미만이거나 쿨다운 중인 작업은 건너뜁니다.
+    pub async fn recover(&self, health: &ServiceHealth) -> Vec<RecoveryRecord> {
+        if health.consecutive_failures < self.config.trigger_after_failures {
+            return Vec::new();
+        }
+        let Some(actions) = self.actions.get(&health.service_type) else {
+            return Vec::new();
+        };
+
+        let service = format!("{:?}", health.service_type);
+        let cooldown = Duration::from_millis(self.config.cooldown_ms);
+        let mut records = Vec::new();
+
+        for action in actions {
+            {
+                let mut last_run = self.last_run.lock().await;
+                let key = (health.service_type.clone(), action.name().to_string());
+                if let Some(last) = last_run.get(&key) {
+                    if last.elapsed() < cooldown {
+                        debug!("{} 복구 작업 {} 쿨다운 중", service, action.name());
+                        continue;
+                    }
+                }
+                last_run.insert(key, Instant::now());
+            }
+
+            let record = self.run_action(&service, action.as_ref(), health.consecutive_failures).await;
+            self.audit_log(&record).await;
+
+            let mut history = self.history.lock().await;
+            history.push_front(record.clone());
+            history.truncate(HISTORY_SIZE);
+            records.push(record);
+        }
+
+        records
+    }
+
+    async fn run_action(&self, service: &str, action: &dyn RecoveryAction, consecutive_failures: u32) -> RecoveryRecord {
+        let timeout = Duration::from_millis(self.config.action_timeout_ms);
+        let started = Instant::now();
+        let result = match tokio::time::timeout(timeout, action.execute()).await {
+            Ok(result) => result,
+            Err(_) => Err(format!("{}ms 내 완료되지 않음", timeout.as_millis())),
+        };
+
+        match &result {
+            Ok(message) => info!("자동 복구 성공: {} - {} ({})", service, action.name(), message),
+            Err(e) => warn!("자동 복구 실패: {} - {} ({})", service, action.name(), e),
+        }
+
+        RecoveryRecord {
+            service: service.to_string(),
+            action: action.name().to_string(),
+            success: result.is_ok(),
+            message: result.unwrap_or_else(|e| e),
+            consecutive_failures,
+            executed_at: now_millis(),
+            duration_ms: started.elapsed().as_millis() as u64,
+        }
+    }
+
+    async fn audit_log(&self, record: &RecoveryRecord) {
+        let Some(audit) = &self.audit else {
+            return;
+        };
+        let event = if record.success { RECOVERY_SUCCEEDED_EVENT } else { RECOVERY_FAILED_EVENT };
+        let details = serde_json::to_string(record).unwrap_or_default();
+        if let Err(e) = audit.log(event, RECOVERY_AUDIT_ENTITY, &record.service, Some(&details)).await {
+            warn!("자동 복구 감사 로그 기록 실패: {}", e);
+        }
+    }
+
+    /// 최근 복구 기록 (최신순)
+    pub async fn get_history(&self) -> Vec<RecoveryRecord> {
+        self.history.lock().await.iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitoring::system_health::HealthStatus;
+    use std::sync::atomic::AtomicUsize;
+
+    fn health(service_type: ServiceType, consecutive_failures: u32) -> ServiceHealth {
+        ServiceHealth {
+            service_type,
+            status: HealthStatus::Critical,
+            message: "[unreachable] Connection refused".to_string(),
+            response_time_ms: 1,
+            last_check: 0,
+            consecutive_failures,
+            uptime_percent: 0.0,
+            error_rate: 100.0,
+        }
+    }
+
+    fn counting_action(name: &str, calls: Arc<AtomicUsize>, result: Result<String, String>) -> Arc<dyn RecoveryAction> {
+        Arc::new(FnRecoveryAction::new(name, move || {
+            let calls = calls.clone();
+            let result = result.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                result
+            }
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_recovery_triggers_after_failures_with_cooldown() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let manager = AutoRecoveryManager::new(AutoRecoveryConfig::default())
+            .with_action(ServiceType::Redis, counting_action("reconnect_redis", calls.clone(), Ok("ok".to_string())));
+
+        assert!(manager.recover(&health(ServiceType::Redis, 2)).await.is_empty());
+        assert!(manager.recover(&health(ServiceType::Kafka, 5)).await.is_empty());
+
+        let records = manager.recover(&health(ServiceType::Redis, 3)).await;
+        assert_eq!(records.len(), 1);
+        assert!(records[0].success);
+
+        // 쿨다운 중에는 다시 실행하지 않음
+        assert!(manager.recover(&health(ServiceType::Redis, 4)).await.is_empty());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_action_is_audited() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let manager = AutoRecoveryManager::new(AutoRecoveryConfig { cooldown_ms: 0, ..AutoRecoveryConfig::default() })
+            .with_action(ServiceType::Kafka, counting_action("reconnect_kafka", calls.clone(), Err("refused".to_string())))
+            .with_audit_log(pool.clone());
+
+        manager.recover(&health(ServiceType::Kafka, 3)).await;
+        manager.recover(&health(ServiceType::Kafka, 4)).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let history = manager.get_history().await;
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].consecutive_failures, 4);
+        assert!(!history[0].success);
+
+        let logs = AuditLogRepository::new(pool).find_by_entity("Kafka").await.unwrap();
+        assert_eq!(logs.len(), 2);
+        assert!(logs.iter().all(|log| log.event_type == RECOVERY_FAILED_EVENT));
+    }
+
+    #[tokio::test]
+    async fn test_reopen_db_pool_keeps_in_memory_data() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(3)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        AuditLogRepository::new(pool.clone()).log("TEST", "test", "db", None).await.unwrap();
+
+        let message = ReopenDbPoolAction::new(pool.clone()).execute().await.unwrap();
+        assert!(message.contains("재연결"));
+        assert_eq!(AuditLogRepository::new(pool).find_by_entity("db").await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_supervised_task_restart_replaces_task() {
+        let starts = Arc::new(AtomicUsize::new(0));
+        let counter = starts.clone();
+        let task = SupervisedTask::spawn("consumer", move || {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                std::future::pending::<()>().await;
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(task.is_running());
+
+        let message = task.execute().await.unwrap();
+        assert!(message.contains("1회째"));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(starts.load(Ordering::SeqCst), 2);
+        assert!(task.is_running());
+        assert_eq!(task.name(), "restart_consumer");
+    }
+}
@@ -6,11 +6,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{broadcast, Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
-use log::{info, error, warn, debug};
-use tokio::time::{sleep, interval};
-use std::sync::atomic::{AtomicUsize, AtomicU64, Ordering};
+use log::{info, warn, debug};
+use tokio::time::interval;
 
 /// 대시보드 위젯 타입
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -21,6 +20,7 @@ pub enum WidgetType {
     AlertList,         // 알림 목록
     MetricGauge,       // 메트릭 게이지
     LogViewer,         // 로그 뷰어
+    IncidentHistory,   // 해결된 인시던트 이력
     Custom,            // 사용자 정의
 }
 
@@ -49,6 +49,37 @@ pub struct DashboardLayout {
     pub is_default: bool,
 }
 
+/// 위젯 증분 업데이트 (데이터가 바뀐 위젯만 전송)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DashboardUpdate {
+    pub widget_id: String,
+    pub widget_type: WidgetType,
+    pub data: serde_json::Value,
+    pub timestamp: u64,
+}
+
+impl DashboardUpdate {
+    fn from_widget(widget: &DashboardWidget) -> Self {
+        Self {
+            widget_id: widget.id.clone(),
+            widget_type: widget.widget_type.clone(),
+            data: widget.data.clone(),
+            timestamp: widget.last_updated,
+        }
+    }
+}
+
+/// 큐 깊이/처리량 샘플
+#[derive(Debug, Clone)]
+pub struct QueueSample {
+    pub name: String,
+    pub depth: usize,
+    pub capacity: usize,
+    pub high_watermark: usize,
+    /// 누적 적재 메시지 수
+    pub enqueued: u64,
+}
+
 /// 대시보드 설정
 #[derive(Debug, Clone)]
 pub struct DashboardConfig {
@@ -59,6 +90,8 @@ pub struct DashboardConfig {
     pub websocket_port: u16,
     pub enable_export: bool,
     pub export_formats: Vec<String>,
+    /// 업데이트 브로드캐스트 채널 버퍼 크기
+    pub update_buffer_size: usize,
 }
 
 impl Default for DashboardConfig {
@@ -71,6 +104,7 @@ impl Default for DashboardConfig {
             websocket_port: 8080,
             enable_export: true,
             export_formats: vec!["json".to_string(), "csv".to_string()],
+            update_buffer_size: 1024,
         }
     }
 }
@@ -82,6 +116,7 @@ pub struct DashboardServer {
     current_layout: Arc<RwLock<String>>,
     is_running: Arc<Mutex<bool>>,
     websocket_clients: Arc<RwLock<HashMap<String, WebSocketClient>>>,
+    updates: broadcast::Sender<DashboardUpdate>,
 }
 
 /// WebSocket 클라이언트
@@ -90,15 +125,35 @@ pub struct WebSocketClient {
     pub id: String,
     pub connected_at: u64,
     pub last_activity: u64,
+    /// 구독 위젯 목록 (비어 있으면 전체 위젯)
     pub subscribed_widgets: Vec<String>,
 }
 
+impl WebSocketClient {
+    /// 위젯 구독 여부
+    pub fn is_subscribed(&self, widget_id: &str) -> bool {
+        self.subscribed_widgets.is_empty() || self.subscribed_widgets.iter().any(|w| w == widget_id)
+    }
+}
+
+/// (샘플 시각, 큐별 누적 적재 수)
+type QueueTotals = (u64, HashMap<String, u64>);
+
 /// 대시보드 데이터 제공자
 pub struct DashboardDataProvider {
     system_health_data: Arc<RwLock<Option<serde_json::Value>>>,
     performance_data: Arc<RwLock<Vec<serde_json::Value>>>,
     alert_data: Arc<RwLock<Vec<serde_json::Value>>>,
     metric_data: Arc<RwLock<HashMap<String, serde_json::Value>>>,
+    incident_history: Arc<RwLock<Vec<serde_json::Value>>>,
+    /// 직전 큐 샘플 (시각, 큐별 누적 적재 수)
+    last_queue_sample: Arc<RwLock<Option<QueueTotals>>>,
+}
+
+impl Default for DashboardDataProvider {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DashboardDataProvider {
@@ -108,6 +163,8 @@ impl DashboardDataProvider {
             performance_data: Arc::new(RwLock::new(Vec::new())),
             alert_data: Arc::new(RwLock::new(Vec::new())),
             metric_data: Arc::new(RwLock::new(HashMap::new())),
+            incident_history: Arc::new(RwLock::new(Vec::new())),
+            last_queue_sample: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -124,7 +181,8 @@ impl DashboardDataProvider {
         
         // 최대 1000개 데이터만 유지
         if data.len() > 1000 {
-            data.drain(0..data.len() - 1000);
+            let excess = data.len() - 1000;
+            data.drain(0..excess);
         }
     }
 
@@ -135,7 +193,8 @@ impl DashboardDataProvider {
         
         // 최대 500개 알림만 유지
         if data.len() > 500 {
-            data.drain(0..data.len() - 500);
+            let excess = data.len() - 500;
+            data.drain(0..excess);
         }
     }
 
@@ -145,6 +204,57 @@ impl DashboardDataProvider {
         data.insert(metric_name, metric_data);
     }
 
+    /// 큐 깊이 및 처리량 업데이트
+    ///
+    /// 처리량(건/초)은 직전 샘플 대비 누적 적재 수 증가분으로 계산하므로
+    /// 첫 샘플에서는 큐 깊이만 갱신됩니다.
+    pub async fn update_queue_samples(&self, samples: &[QueueSample], sampled_at_ms: u64) {
+        let depths: serde_json::Map<String, serde_json::Value> = samples
+            .iter()
+            .map(|q| {
+                let utilization = if q.capacity == 0 { 0.0 } else { q.depth as f64 / q.capacity as f64 };
+                (q.name.clone(), serde_json::json!({
+                    "depth": q.depth,
+                    "capacity": q.capacity,
+                    "high_watermark": q.high_watermark,
+                    "utilization": utilization,
+                }))
+            })
+            .collect();
+        self.update_metric_data("queue_depths".to_string(), serde_json::Value::Object(depths)).await;
+
+        let counts: HashMap<String, u64> = samples.iter().map(|q| (q.name.clone(), q.enqueued)).collect();
+        let previous = self.last_queue_sample.write().await.replace((sampled_at_ms, counts.clone()));
+
+        if let Some((prev_at, prev_counts)) = previous {
+            let elapsed_secs = sampled_at_ms.saturating_sub(prev_at) as f64 / 1000.0;
+            if elapsed_secs <= 0.0 {
+                return;
+            }
+
+            let rates: serde_json::Map<String, serde_json::Value> = counts
+                .iter()
+                .map(|(name, count)| {
+                    let delta = count.saturating_sub(prev_counts.get(name).copied().unwrap_or(0));
+                    (name.clone(), serde_json::json!(delta as f64 / elapsed_secs))
+                })
+                .collect();
+            self.update_metric_data("throughput".to_string(), serde_json::Value::Object(rates)).await;
+        }
+    }
+
+    /// 해결된 인시던트 추가
+    pub async fn record_resolved_incident(&self, incident: serde_json::Value) {
+        let mut data = self.incident_history.write().await;
+        data.push(incident);
+
+        // 최근 100건만 유지
+        if data.len() > 100 {
+            let excess = data.len() - 100;
+            data.drain(0..excess);
+        }
+    }
+
     /// 위젯 데이터 조회
     pub async fn get_widget_data(&self, widget_type: &WidgetType, widget_id: &str) -> serde_json::Value {
         match widget_type {
@@ -152,6 +262,12 @@ impl DashboardDataProvider {
                 let data = self.system_health_data.read().await;
                 data.clone().unwrap_or(serde_json::Value::Null)
             }
+            WidgetType::ServiceStatus => {
+                let data = self.system_health_data.read().await;
+                data.as_ref()
+                    .and_then(|health| health.get("services").cloned())
+                    .unwrap_or(serde_json::Value::Null)
+            }
             WidgetType::PerformanceChart => {
                 let data = self.performance_data.read().await;
                 serde_json::to_value(data.clone()).unwrap_or(serde_json::Value::Null)
@@ -160,6 +276,11 @@ impl DashboardDataProvider {
                 let data = self.alert_data.read().await;
                 serde_json::to_value(data.clone()).unwrap_or(serde_json::Value::Null)
             }
+            WidgetType::IncidentHistory => {
+                // 최근 해결 순
+                let data = self.incident_history.read().await;
+                serde_json::Value::Array(data.iter().rev().cloned().collect())
+            }
             WidgetType::MetricGauge => {
                 let data = self.metric_data.read().await;
                 if let Some(metric_data) = data.get(widget_id) {
@@ -176,12 +297,14 @@ impl DashboardDataProvider {
 impl DashboardServer {
     /// 새 대시보드 서버 생성
     pub fn new(config: DashboardConfig) -> Self {
+        let (updates, _) = broadcast::channel(config.update_buffer_size.max(1));
         let mut server = Self {
             config,
             layouts: Arc::new(RwLock::new(HashMap::new())),
             current_layout: Arc::new(RwLock::new("default".to_string())),
             is_running: Arc::new(Mutex::new(false)),
             websocket_clients: Arc::new(RwLock::new(HashMap::new())),
+            updates,
         };
 
         // 기본 레이아웃 생성
@@ -195,6 +318,7 @@ impl DashboardServer {
             id: "default".to_string(),
             name: "기본 대시보드".to_string(),
             description: "시스템 모니터링 기본 대시보드".to_string(),
+            // 목록 위젯은 빈 배열로 시작 (첫 갱신에서 변경 없는 위젯을 보내지 않도록)
             widgets: vec![
                 DashboardWidget {
                     id: "system_health".to_string(),
@@ -223,7 +347,7 @@ impl DashboardServer {
                     position: (0, 2),
                     size: (8, 3),
                     config: HashMap::new(),
-                    data: serde_json::Value::Null,
+                    data: serde_json::json!([]),
                     last_updated: 0,
                 },
                 DashboardWidget {
@@ -233,6 +357,56 @@ impl DashboardServer {
                     position: (8, 0),
                     size: (4, 5),
                     config: HashMap::new(),
+                    data: serde_json::json!([]),
+                    last_updated: 0,
+                },
+                DashboardWidget {
+                    id: "incident_history".to_string(),
+                    title: "인시던트 이력".to_string(),
+                    widget_type: WidgetType::IncidentHistory,
+                    position: (0, 5),
+                    size: (12, 3),
+                    config: HashMap::new(),
+                    data: serde_json::json!([]),
+                    last_updated: 0,
+                },
+                DashboardWidget {
+                    id: "queue_depths".to_string(),
+                    title: "큐 깊이".to_string(),
+                    widget_type: WidgetType::MetricGauge,
+                    position: (0, 8),
+                    size: (4, 2),
+                    config: HashMap::new(),
+                    data: serde_json::Value::Null,
+                    last_updated: 0,
+                },
+                DashboardWidget {
+                    id: "throughput".to_string(),
+                    title: "처리량 (건/초)".to_string(),
+                    widget_type: WidgetType::MetricGauge,
+                    position: (4, 8),
+                    size: (4, 2),
+                    config: HashMap::new(),
+                    data: serde_json::Value::Null,
+                    last_updated: 0,
+                },
+                DashboardWidget {
+                    id: "latency".to_string(),
+                    title: "API 지연 백분위수 (ms)".to_string(),
+                    widget_type: WidgetType::MetricGauge,
+                    position: (8, 8),
+                    size: (4, 2),
+                    config: HashMap::new(),
+                    data: serde_json::Value::Null,
+                    last_updated: 0,
+                },
+                DashboardWidget {
+                    id: "metrics".to_string(),
+                    title: "메트릭 수집기".to_string(),
+                    widget_type: WidgetType::MetricGauge,
+                    position: (0, 10),
+                    size: (12, 3),
+                    config: HashMap::new(),
                     data: serde_json::Value::Null,
                     last_updated: 0,
                 },
@@ -266,7 +440,7 @@ impl DashboardServer {
 
         let layouts = self.layouts.clone();
         let current_layout = self.current_layout.clone();
-        let websocket_clients = self.websocket_clients.clone();
+        let updates = self.updates.clone();
         let config = self.config.clone();
         let is_running = self.is_running.clone();
 
@@ -285,11 +459,9 @@ impl DashboardServer {
                     }
                 }
 
-                // 위젯 데이터 업데이트
-                Self::update_widget_data(&layouts, &current_layout, &data_provider).await;
-
-                // WebSocket 클라이언트에게 업데이트 전송
-                Self::broadcast_updates(&websocket_clients, &layouts, &current_layout).await;
+                // 위젯 데이터 업데이트 후 변경분만 브로드캐스트
+                let changed = Self::update_widget_data(&layouts, &current_layout, &data_provider).await;
+                Self::broadcast_updates(&updates, changed);
             }
 
             info!("대시보드 서버 종료");
@@ -304,51 +476,105 @@ impl DashboardServer {
     }
 
     /// 위젯 데이터 업데이트
+    ///
+    /// 데이터가 이전 값과 달라진 위젯만 갱신하고 해당 업데이트 목록을 반환합니다.
     async fn update_widget_data(
         layouts: &Arc<RwLock<HashMap<String, DashboardLayout>>>,
         current_layout: &Arc<RwLock<String>>,
         data_provider: &Arc<DashboardDataProvider>,
-    ) {
-        let layouts_guard = layouts.read().await;
-        let current_layout_name = current_layout.read().await;
-        
-        if let Some(layout) = layouts_guard.get(&*current_layout_name) {
-            let mut updated_layout = layout.clone();
-            let current_time = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_millis() as u64;
+    ) -> Vec<DashboardUpdate> {
+        let current_layout_name = current_layout.read().await.clone();
+        let widgets = match layouts.read().await.get(&current_layout_name) {
+            Some(layout) => layout.widgets.clone(),
+            None => return Vec::new(),
+        };
+
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        let mut changed = HashMap::new();
+        for widget in &widgets {
+            let data = data_provider.get_widget_data(&widget.widget_type, &widget.id).await;
+            if data != widget.data {
+                changed.insert(widget.id.clone(), data);
+            }
+        }
+
+        if changed.is_empty() {
+            return Vec::new();
+        }
 
-            for widget in &mut updated_layout.widgets {
-                widget.data = data_provider.get_widget_data(&widget.widget_type, &widget.id).await;
+        // 조회 중 레이아웃이 바뀌었을 수 있으므로 현재 위젯 기준으로 반영
+        let mut layouts_guard = layouts.write().await;
+        let Some(layout) = layouts_guard.get_mut(&current_layout_name) else {
+            return Vec::new();
+        };
+
+        let mut updates = Vec::with_capacity(changed.len());
+        for widget in &mut layout.widgets {
+            if let Some(data) = changed.remove(&widget.id) {
+                widget.data = data;
                 widget.last_updated = current_time;
+                updates.push(DashboardUpdate::from_widget(widget));
             }
+        }
+        updates
+    }
 
-            // 레이아웃 업데이트
-            drop(layouts_guard);
-            let mut layouts_guard = layouts.write().await;
-            layouts_guard.insert(current_layout_name.clone(), updated_layout);
+    /// WebSocket 구독자에게 업데이트 브로드캐스트
+    fn broadcast_updates(updates: &broadcast::Sender<DashboardUpdate>, changed: Vec<DashboardUpdate>) {
+        for update in changed {
+            debug!("대시보드 위젯 업데이트: {}", update.widget_id);
+            // 구독자가 없으면 전송 실패하지만 무시
+            let _ = updates.send(update);
         }
     }
 
-    /// WebSocket 클라이언트에게 업데이트 브로드캐스트
-    async fn broadcast_updates(
-        websocket_clients: &Arc<RwLock<HashMap<String, WebSocketClient>>>,
-        layouts: &Arc<RwLock<HashMap<String, DashboardLayout>>>,
-        current_layout: &Arc<RwLock<String>>,
-    ) {
-        let clients = websocket_clients.read().await;
-        let layouts_guard = layouts.read().await;
-        let current_layout_name = current_layout.read().await;
+    /// 위젯 업데이트 스트림 구독
+    pub fn subscribe(&self) -> broadcast::Receiver<DashboardUpdate> {
+        self.updates.subscribe()
+    }
 
-        if let Some(layout) = layouts_guard.get(&*current_layout_name) {
-            let update_data = serde_json::to_value(layout).unwrap_or(serde_json::Value::Null);
+    /// 클라이언트 구독 위젯 변경 (빈 목록이면 전체 위젯)
+    pub async fn set_client_subscription(&self, client_id: &str, widgets: Vec<String>) -> Result<(), String> {
+        let mut clients = self.websocket_clients.write().await;
+        let client = clients
+            .get_mut(client_id)
+            .ok_or_else(|| format!("클라이언트를 찾을 수 없습니다: {}", client_id))?;
+
+        client.subscribed_widgets = widgets;
+        client.last_activity = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        Ok(())
+    }
 
-            for client in clients.values() {
-                // Mock: 실제로는 WebSocket을 통한 데이터 전송
-                debug!("WebSocket 업데이트 전송: {} -> {}", client.id, update_data);
-            }
-        }
+    /// 클라이언트가 위젯을 구독 중인지 확인
+    pub async fn is_client_subscribed(&self, client_id: &str, widget_id: &str) -> bool {
+        let clients = self.websocket_clients.read().await;
+        clients.get(client_id).map(|c| c.is_subscribed(widget_id)).unwrap_or(false)
+    }
+
+    /// 클라이언트 구독 위젯의 현재 스냅샷
+    pub async fn snapshot_for_client(&self, client_id: &str) -> Vec<DashboardUpdate> {
+        let client = match self.websocket_clients.read().await.get(client_id) {
+            Some(client) => client.clone(),
+            None => return Vec::new(),
+        };
+
+        self.get_current_layout()
+            .await
+            .map(|layout| {
+                layout.widgets
+                    .iter()
+                    .filter(|w| client.is_subscribed(&w.id))
+                    .map(DashboardUpdate::from_widget)
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
     /// 레이아웃 조회
@@ -389,8 +615,8 @@ impl DashboardServer {
             return Err("최대 레이아웃 수를 초과했습니다".to_string());
         }
 
-        layouts.insert(layout.id.clone(), layout);
         info!("새 레이아웃 생성: {}", layout.id);
+        layouts.insert(layout.id.clone(), layout);
         Ok(())
     }
 
@@ -430,13 +656,13 @@ impl DashboardServer {
                 return Err(format!("위젯 ID가 중복됩니다: {}", widget.id));
             }
 
+            info!("위젯 추가: {} -> {}", widget.id, layout_id);
             layout.widgets.push(widget);
             layout.updated_at = SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .unwrap()
                 .as_millis() as u64;
 
-            info!("위젯 추가: {} -> {}", widget.id, layout_id);
             Ok(())
         } else {
             Err(format!("레이아웃을 찾을 수 없습니다: {}", layout_id))
@@ -560,7 +786,7 @@ mod tests {
         
         let stats = server.get_dashboard_stats().await;
         assert_eq!(stats.total_layouts, 1); // 기본 레이아웃
-        assert_eq!(stats.total_widgets, 4); // 기본 위젯들
+        assert_eq!(stats.total_widgets, 9); // 기본 위젯들
     }
 
     #[tokio::test]
@@ -642,6 +868,68 @@ mod tests {
         assert_eq!(count, 0);
     }
 
+    #[tokio::test]
+    async fn test_incremental_updates_only_for_changed_widgets() {
+        let server = DashboardServer::new(DashboardConfig::default());
+        let provider = Arc::new(DashboardDataProvider::new());
+        let mut rx = server.subscribe();
+
+        provider.update_metric_data("latency".to_string(), serde_json::json!({"p50": 1.0})).await;
+        let changed = DashboardServer::update_widget_data(&server.layouts, &server.current_layout, &provider).await;
+        DashboardServer::broadcast_updates(&server.updates, changed);
+
+        let update = rx.try_recv().unwrap();
+        assert_eq!(update.widget_id, "latency");
+        assert_eq!(update.data, serde_json::json!({"p50": 1.0}));
+        assert!(rx.try_recv().is_err());
+
+        // 값이 같으면 다시 전송하지 않음
+        let changed = DashboardServer::update_widget_data(&server.layouts, &server.current_layout, &provider).await;
+        assert!(changed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_client_subscription_filter() {
+        let server = DashboardServer::new(DashboardConfig::default());
+        server.connect_websocket_client("client1".to_string()).await.unwrap();
+
+        // 구독 지정 전에는 전체 위젯
+        assert!(server.is_client_subscribed("client1", "alert_list").await);
+        assert_eq!(server.snapshot_for_client("client1").await.len(), 9);
+
+        server.set_client_subscription("client1", vec!["queue_depths".to_string()]).await.unwrap();
+        assert!(server.is_client_subscribed("client1", "queue_depths").await);
+        assert!(!server.is_client_subscribed("client1", "alert_list").await);
+
+        let snapshot = server.snapshot_for_client("client1").await;
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].widget_id, "queue_depths");
+
+        assert!(server.set_client_subscription("unknown", Vec::new()).await.is_err());
+        assert!(!server.is_client_subscribed("unknown", "queue_depths").await);
+    }
+
+    #[tokio::test]
+    async fn test_queue_samples_throughput() {
+        let provider = DashboardDataProvider::new();
+        let sample = |depth, enqueued| QueueSample {
+            name: "orders".to_string(),
+            depth,
+            capacity: 100,
+            high_watermark: 10,
+            enqueued,
+        };
+
+        provider.update_queue_samples(&[sample(5, 100)], 1_000).await;
+        let depths = provider.get_widget_data(&WidgetType::MetricGauge, "queue_depths").await;
+        assert_eq!(depths["orders"]["depth"], 5);
+        assert_eq!(provider.get_widget_data(&WidgetType::MetricGauge, "throughput").await, serde_json::Value::Null);
+
+        provider.update_queue_samples(&[sample(2, 400)], 3_000).await;
+        let throughput = provider.get_widget_data(&WidgetType::MetricGauge, "throughput").await;
+        assert_eq!(throughput["orders"], 150.0);
+    }
+
     #[tokio::test]
     async fn test_data_provider() {
         let provider = DashboardDataProvider::new();
@@ -0,0 +1,455 @@
+//! 알림 인시던트 추적
+//!
+//! 확인이 필요한 알림(기본 High 이상, 또는 에스컬레이션 규칙에 걸린 알림)을 인시던트로 기록하고
+//! 확인(ack), 메모, 해결 상태를 관리합니다. 인시던트 ID는 알림 ID와 같습니다.
+//! 변경은 DB(`incidents`, `incident_notes`)에 저장하고 감사 로그(`audit_logs`)에 남기며,
+//! 해결된 인시던트는 대시보드의 인시던트 이력 위젯에 추가합니다.
+
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::info;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use utoipa::ToSchema;
+
+use crate::db::models::{IncidentNoteRecord, IncidentRecord};
+use crate::db::repository::{AuditLogRepository, IncidentRepository};
+
+use super::dashboard::DashboardDataProvider;
+use super::notification_routing::SOURCE_METADATA_KEY;
+use super::notification_system::{NotificationMessage, NotificationPriority, NotificationType};
+
+/// 감사 로그 엔티티 타입
+pub const INCIDENT_AUDIT_ENTITY: &str = "incident";
+pub const INCIDENT_ACKNOWLEDGED_EVENT: &str = "INCIDENT_ACKNOWLEDGED";
+pub const INCIDENT_NOTE_EVENT: &str = "INCIDENT_NOTE_ADDED";
+pub const INCIDENT_RESOLVED_EVENT: &str = "INCIDENT_RESOLVED";
+
+/// 시작 시 대시보드에 채울 해결 이력 수
+const DASHBOARD_HISTORY_SIZE: i64 = 100;
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
+/// DB에 저장된 열거형 이름 파싱 (serde 이름 그대로 저장)
+fn parse_name<T: DeserializeOwned>(id: &str, field: &str, value: &str) -> Result<T, IncidentError> {
+    serde_json::from_value(serde_json::Value::String(value.to_string()))
+        .map_err(|_| IncidentError::InvalidRecord(format!("{} {}: {}", id, field, value)))
+}
+
+/// 인시던트 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentStatus {
+    /// 확인 대기 (에스컬레이션 진행)
+    Open,
+    /// 담당자 확인
+    Acknowledged,
+    /// 해결
+    Resolved,
+}
+
+impl IncidentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IncidentStatus::Open => "open",
+            IncidentStatus::Acknowledged => "acknowledged",
+            IncidentStatus::Resolved => "resolved",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "open" => Some(IncidentStatus::Open),
+            "acknowledged" => Some(IncidentStatus::Acknowledged),
+            "resolved" => Some(IncidentStatus::Resolved),
+            _ => None,
+        }
+    }
+}
+
+/// 인시던트 메모
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IncidentNote {
+    pub author: String,
+    pub note: String,
+    /// 작성 시각 (밀리초)
+    pub created_at: u64,
+}
+
+/// 알림 인시던트
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Incident {
+    /// 알림 ID
+    pub id: String,
+    pub title: String,
+    pub content: String,
+    pub priority: NotificationPriority,
+    pub notification_type: NotificationType,
+    /// 발신 서비스 (알림 메타데이터 `source`)
+    pub source: Option<String>,
+    pub status: IncidentStatus,
+    /// 에스컬레이션 횟수
+    pub escalation_count: u32,
+    /// 발생 시각 (밀리초)
+    pub created_at: u64,
+    pub acknowledged_by: Option<String>,
+    pub acknowledged_at: Option<u64>,
+    pub resolved_by: Option<String>,
+    pub resolved_at: Option<u64>,
+    /// 메모 (작성순, 해결 내용 포함)
+    pub notes: Vec<IncidentNote>,
+}
+
+impl Incident {
+    fn from_message(message: &NotificationMessage) -> Self {
+        Self {
+            id: message.id.clone(),
+            title: message.title.clone(),
+            content: message.content.clone(),
+            priority: message.priority.clone(),
+            notification_type: message.notification_type.clone(),
+            source: message.metadata.get(SOURCE_METADATA_KEY).cloned(),
+            status: IncidentStatus::Open,
+            escalation_count: 0,
+            created_at: message.timestamp,
+            acknowledged_by: None,
+            acknowledged_at: None,
+            resolved_by: None,
+            resolved_at: None,
+            notes: Vec::new(),
+        }
+    }
+
+    fn from_record(record: IncidentRecord, notes: Vec<IncidentNoteRecord>) -> Result<Self, IncidentError> {
+        Ok(Self {
+            priority: parse_name(&record.id, "priority", &record.priority)?,
+            notification_type: parse_name(&record.id, "notification_type", &record.notification_type)?,
+            status: IncidentStatus::from_name(&record.status)
+                .ok_or_else(|| IncidentError::InvalidRecord(format!("{} status: {}", record.id, record.status)))?,
+            id: record.id,
+            title: record.title,
+            content: record.content,
+            source: record.source,
+            escalation_count: record.escalation_count as u32,
+            created_at: record.created_at as u64,
+            acknowledged_by: record.acknowledged_by,
+            acknowledged_at: record.acknowledged_at.map(|t| t as u64),
+            resolved_by: record.resolved_by,
+            resolved_at: record.resolved_at.map(|t| t as u64),
+            notes: notes
+                .into_iter()
+                .map(|note| IncidentNote { author: note.author, note: note.note, created_at: note.created_at as u64 })
+                .collect(),
+        })
+    }
+
+    fn to_record(&self) -> IncidentRecord {
+        let name = |value: serde_json::Value| value.as_str().unwrap_or_default().to_string();
+        IncidentRecord {
+            id: self.id.clone(),
+            title: self.title.clone(),
+            content: self.content.clone(),
+            priority: name(serde_json::to_value(&self.priority).unwrap_or_default()),
+            notification_type: name(serde_json::to_value(&self.notification_type).unwrap_or_default()),
+            source: self.source.clone(),
+            status: self.status.as_str().to_string(),
+            escalation_count: self.escalation_count as i64,
+            created_at: self.created_at as i64,
+            acknowledged_by: self.acknowledged_by.clone(),
+            acknowledged_at: self.acknowledged_at.map(|t| t as i64),
+            resolved_by: self.resolved_by.clone(),
+            resolved_at: self.resolved_at.map(|t| t as i64),
+        }
+    }
+}
+
+/// 인시던트 오류
+#[derive(Debug, thiserror::Error)]
+pub enum IncidentError {
+    #[error("인시던트를 찾을 수 없습니다: {0}")]
+    NotFound(String),
+    #[error("이미 해결된 인시던트입니다: {0}")]
+    AlreadyResolved(String),
+    #[error("인시던트 메모가 비어 있습니다")]
+    EmptyNote,
+    #[error("인시던트 기록 오류: {0}")]
+    InvalidRecord(String),
+    #[error("인시던트 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 알림 인시던트 추적기
+pub struct IncidentTracker {
+    repository: IncidentRepository,
+    audit: AuditLogRepository,
+    /// 이 우선순위 이상의 알림을 인시던트로 기록
+    min_priority: NotificationPriority,
+    dashboard: Option<Arc<DashboardDataProvider>>,
+}
+
+impl IncidentTracker {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            repository: IncidentRepository::new(pool.clone()),
+            audit: AuditLogRepository::new(pool),
+            min_priority: NotificationPriority::High,
+            dashboard: None,
+        }
+    }
+
+    /// 인시던트로 기록할 최소 우선순위 설정
+    pub fn with_min_priority(mut self, min_priority: NotificationPriority) -> Self {
+        self.min_priority = min_priority;
+        self
+    }
+
+    /// 해결된 인시던트를 대시보드 인시던트 이력 위젯에 추가
+    pub fn with_dashboard(mut self, dashboard: Arc<DashboardDataProvider>) -> Self {
+        self.dashboard = Some(dashboard);
+        self
+    }
+
+    /// 인시던트로 기록할 알림인지 여부 (에스컬레이션 대상 알림은 항상 기록)
+    pub fn should_track(&self, message: &NotificationMessage, escalates: bool) -> bool {
+        escalates || message.priority >= self.min_priority
+    }
+
+    /// 알림으로 인시던트 생성
+    pub async fn open(&self, message: &NotificationMessage) -> Result<Incident, IncidentError> {
+        let incident = Incident::from_message(message);
+        self.repository.insert(&incident.to_record()).await?;
+        info!("인시던트 생성: {} [{}] {}", incident.id, incident.priority, incident.title);
+        Ok(incident)
+    }
+
+    /// 인시던트 조회 (메모 포함)
+    pub async fn get(&self, id: &str) -> Result<Incident, IncidentError> {
+        let record = self
+            .repository
+            .find(id)
+            .await?
+            .ok_or_else(|| IncidentError::NotFound(id.to_string()))?;
+        let notes = self.repository.find_notes(id).await?;
+        Incident::from_record(record, notes)
+    }
+
+    /// 미해결 인시던트 (확인 대기 + 확인됨, 최신순)
+    pub async fn active(&self, limit: i64) -> Result<Vec<Incident>, IncidentError> {
+        self.list(&[IncidentStatus::Open, IncidentStatus::Acknowledged], limit).await
+    }
+
+    /// 상태별 인시던트 목록 (최신순)
+    pub async fn list(&self, statuses: &[IncidentStatus], limit: i64) -> Result<Vec<Incident>, IncidentError> {
+        let names: Vec<&str> = statuses.iter().map(IncidentStatus::as_str).collect();
+        let records = self.repository.find_by_status(&names, limit).await?;
+        let mut incidents = Vec::with_capacity(records.len());
+        for record in records {
+            let notes = self.repository.find_notes(&record.id).await?;
+            incidents.push(Incident::from_record(record, notes)?);
+        }
+        Ok(incidents)
+    }
+
+    /// 에스컬레이션 횟수 기록
+    pub async fn record_escalation(&self, id: &str) -> Result<Incident, IncidentError> {
+        let mut incident = self.get(id).await?;
+        incident.escalation_count += 1;
+        self.repository.update(&incident.to_record()).await?;
+        Ok(incident)
+    }
+
+    /// 인시던트 확인 (이미 확인된 인시던트는 그대로 반환)
+    pub async fn acknowledge(&self, id: &str, actor: &str) -> Result<Incident, IncidentError> {
+        let mut incident = self.get(id).await?;
+        match incident.status {
+            IncidentStatus::Resolved => return Err(IncidentError::AlreadyResolved(id.to_string())),
+            IncidentStatus::Acknowledged => return Ok(incident),
+            IncidentStatus::Open => {}
+        }
+
+        incident.status = IncidentStatus::Acknowledged;
+        incident.acknowledged_by = Some(actor.to_string());
+        incident.acknowledged_at = Some(now_millis());
+        self.repository.update(&incident.to_record()).await?;
+        self.audit_log(INCIDENT_ACKNOWLEDGED_EVENT, id, actor, None).await?;
+
+        info!("인시던트 확인: {} (by {})", id, actor);
+        Ok(incident)
+    }
+
+    /// 메모 추가
+    pub async fn add_note(&self, id: &str, actor: &str, note: &str) -> Result<Incident, IncidentError> {
+        let note = note.trim();
+        if note.is_empty() {
+            return Err(IncidentError::EmptyNote);
+        }
+        let mut incident = self.get(id).await?;
+
+        let created_at = now_millis();
+        self.repository
+            .add_note(&IncidentNoteRecord {
+                id: None,
+                incident_id: id.to_string(),
+                author: actor.to_string(),
+                note: note.to_string(),
+                created_at: created_at as i64,
+            })
+            .await?;
+        self.audit_log(INCIDENT_NOTE_EVENT, id, actor, Some(note)).await?;
+
+        incident.notes.push(IncidentNote { author: actor.to_string(), note: note.to_string(), created_at });
+        Ok(incident)
+    }
+
+    /// 인시던트 해결 (확인되지 않은 인시던트는 해결자가 확인한 것으로 기록)
+    pub async fn resolve(&self, id: &str, actor: &str, note: Option<&str>) -> Result<Incident, IncidentError> {
+        let mut incident = self.get(id).await?;
+        if incident.status == IncidentStatus::Resolved {
+            return Err(IncidentError::AlreadyResolved(id.to_string()));
+        }
+        if let Some(note) = note.map(str::trim).filter(|note| !note.is_empty()) {
+            incident = self.add_note(id, actor, note).await?;
+        }
+
+        let now = now_millis();
+        if incident.acknowledged_at.is_none() {
+            incident.acknowledged_by = Some(actor.to_string());
+            incident.acknowledged_at = Some(now);
+        }
+        incident.status = IncidentStatus::Resolved;
+        incident.resolved_by = Some(actor.to_string());
+        incident.resolved_at = Some(now);
+        self.repository.update(&incident.to_record()).await?;
+        self.audit_log(INCIDENT_RESOLVED_EVENT, id, actor, note).await?;
+
+        if let Some(dashboard) = &self.dashboard {
+            dashboard.record_resolved_incident(Self::history_entry(&incident)).await;
+        }
+
+        info!("인시던트 해결: {} (by {})", id, actor);
+        Ok(incident)
+    }
+
+    /// 최근 해결 이력을 대시보드에 채움 (서버 시작 시)
+    pub async fn load_dashboard_history(&self) -> Result<(), IncidentError> {
+        let Some(dashboard) = &self.dashboard else {
+            return Ok(());
+        };
+        let mut resolved = self.list(&[IncidentStatus::Resolved], DASHBOARD_HISTORY_SIZE).await?;
+        resolved.reverse(); // 오래된 것부터 추가
+        for incident in &resolved {
+            dashboard.record_resolved_incident(Self::history_entry(incident)).await;
+        }
+        Ok(())
+    }
+
+    /// 대시보드 인시던트 이력 항목
+    fn history_entry(incident: &Incident) -> serde_json::Value {
+        serde_json::json!({
+            "id": incident.id,
+            "title": incident.title,
+            "priority": incident.priority,
+            "source": incident.source,
+            "escalation_count": incident.escalation_count,
+            "created_at": incident.created_at,
+            "acknowledged_at": incident.acknowledged_at,
+            "resolved_at": incident.resolved_at,
+            "resolved_by": incident.resolved_by,
+            "time_to_resolve_ms": incident.resolved_at.map(|t| t.saturating_sub(incident.created_at)),
+            "resolution": incident.notes.last().map(|note| note.note.clone()),
+        })
+    }
+
+    async fn audit_log(&self, event: &str, id: &str, actor: &str, note: Option<&str>) -> Result<(), IncidentError> {
+        let details = serde_json::json!({ "actor": actor, "note": note }).to_string();
+        self.audit.log(event, INCIDENT_AUDIT_ENTITY, id, Some(&details)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitoring::dashboard::WidgetType;
+    use crate::monitoring::notification_system::NotificationChannel;
+    use std::collections::HashMap;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    fn message(id: &str, priority: NotificationPriority) -> NotificationMessage {
+        NotificationMessage {
+            id: id.to_string(),
+            title: "매칭 엔진 지연".to_string(),
+            content: "p99 500ms 초과".to_string(),
+            channel: NotificationChannel::Slack,
+            priority,
+            notification_type: NotificationType::PerformanceAlert,
+            timestamp: 1_000,
+            retry_count: 0,
+            max_retries: 3,
+            metadata: HashMap::from([(SOURCE_METADATA_KEY.to_string(), "matching_engine".to_string())]),
+            next_attempt_at: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_incident_lifecycle() {
+        let tracker = IncidentTracker::new(test_pool().await);
+        assert!(!tracker.should_track(&message("n0", NotificationPriority::Normal), false));
+        assert!(tracker.should_track(&message("n0", NotificationPriority::Normal), true));
+
+        tracker.open(&message("n1", NotificationPriority::Critical)).await.unwrap();
+        tracker.open(&message("n2", NotificationPriority::High)).await.unwrap();
+        tracker.record_escalation("n1").await.unwrap();
+        assert_eq!(tracker.active(50).await.unwrap().len(), 2);
+
+        let incident = tracker.acknowledge("n1", "oncall").await.unwrap();
+        assert_eq!(incident.status, IncidentStatus::Acknowledged);
+        assert_eq!(incident.escalation_count, 1);
+        tracker.add_note("n1", "oncall", "엔진 재시작 중").await.unwrap();
+        assert!(matches!(tracker.add_note("n1", "oncall", "  ").await, Err(IncidentError::EmptyNote)));
+
+        let incident = tracker.resolve("n1", "oncall", Some("GC 설정 변경으로 해결")).await.unwrap();
+        assert_eq!(incident.status, IncidentStatus::Resolved);
+        assert_eq!(incident.notes.len(), 2);
+        assert!(matches!(tracker.acknowledge("n1", "oncall").await, Err(IncidentError::AlreadyResolved(_))));
+        assert!(matches!(tracker.get("missing").await, Err(IncidentError::NotFound(_))));
+
+        let active = tracker.active(50).await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, "n2");
+        assert_eq!(active[0].source.as_deref(), Some("matching_engine"));
+    }
+
+    #[tokio::test]
+    async fn test_resolved_incidents_feed_dashboard() {
+        let pool = test_pool().await;
+        let dashboard = Arc::new(DashboardDataProvider::new());
+        let tracker = IncidentTracker::new(pool.clone()).with_dashboard(dashboard.clone());
+
+        tracker.open(&message("n1", NotificationPriority::Critical)).await.unwrap();
+        tracker.resolve("n1", "oncall", Some("복구 완료")).await.unwrap();
+
+        let history = dashboard.get_widget_data(&WidgetType::IncidentHistory, "incident_history").await;
+        assert_eq!(history[0]["id"], "n1");
+        assert_eq!(history[0]["resolution"], "복구 완료");
+
+        // 재시작 시 DB의 해결 이력으로 위젯 복원
+        let restarted = Arc::new(DashboardDataProvider::new());
+        IncidentTracker::new(pool).with_dashboard(restarted.clone()).load_dashboard_history().await.unwrap();
+        let history = restarted.get_widget_data(&WidgetType::IncidentHistory, "incident_history").await;
+        assert_eq!(history.as_array().unwrap().len(), 1);
+    }
+}
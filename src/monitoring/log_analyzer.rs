@@ -2,6 +2,7 @@
 //!
 //! 이 모듈은 구조화된 로그를 분석하고 검색하여
 //! 시스템 문제를 조기 감지하고 분석합니다.
+//! 로그가 추가될 때마다 이상 탐지(`log_anomaly`)를 수행하고 알림 시스템으로 보고합니다.
 
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
@@ -9,8 +10,14 @@ use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use log::{info, error, warn, debug};
-use tokio::time::{sleep, interval};
-use std::sync::atomic::{AtomicUsize, AtomicU64, Ordering};
+use tokio::time::interval;
+
+use crate::monitoring::log_anomaly::{LogAnomaly, LogAnomalyConfig, LogAnomalyDetector, LogAnomalyKind};
+use crate::monitoring::notification_routing::SOURCE_METADATA_KEY;
+use crate::monitoring::notification_system::{NotificationChannel, NotificationSystem, NotificationType};
+
+/// 보관할 최근 이상 탐지 결과 수
+const MAX_RECENT_ANOMALIES: usize = 100;
 
 /// 로그 레벨
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -39,7 +46,7 @@ pub struct LogEntry {
 }
 
 /// 로그 검색 쿼리
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LogQuery {
     pub text: Option<String>,
     pub level: Option<LogLevel>,
@@ -98,6 +105,10 @@ pub struct LogAnalyzerConfig {
     pub max_search_results: usize,
     pub enable_indexing: bool,
     pub index_update_interval_ms: u64,
+    /// 이상 탐지 설정
+    pub anomaly: LogAnomalyConfig,
+    /// 이상 탐지 알림 채널
+    pub anomaly_channel: NotificationChannel,
 }
 
 impl Default for LogAnalyzerConfig {
@@ -111,6 +122,8 @@ impl Default for LogAnalyzerConfig {
             max_search_results: 1000,
             enable_indexing: true,
             index_update_interval_ms: 10000, // 10초
+            anomaly: LogAnomalyConfig::default(),
+            anomaly_channel: NotificationChannel::Log,
         }
     }
 }
@@ -123,6 +136,9 @@ pub struct LogAnalyzer {
     patterns: Arc<RwLock<HashMap<String, LogPattern>>>,
     indexes: Arc<RwLock<LogIndexes>>,
     is_running: Arc<Mutex<bool>>,
+    anomaly_detector: Arc<Mutex<LogAnomalyDetector>>,
+    anomalies: Arc<RwLock<VecDeque<LogAnomaly>>>,
+    notifications: Option<Arc<NotificationSystem>>,
 }
 
 /// 로그 인덱스
@@ -151,6 +167,9 @@ impl LogAnalyzer {
     /// 새 로그 분석기 생성
     pub fn new(config: LogAnalyzerConfig) -> Self {
         Self {
+            anomaly_detector: Arc::new(Mutex::new(LogAnomalyDetector::new(config.anomaly.clone()))),
+            anomalies: Arc::new(RwLock::new(VecDeque::new())),
+            notifications: None,
             config,
             log_entries: Arc::new(RwLock::new(VecDeque::new())),
             stats: Arc::new(RwLock::new(LogStats {
@@ -170,6 +189,12 @@ impl LogAnalyzer {
         }
     }
 
+    /// 이상 탐지 결과를 알림 시스템으로 보고
+    pub fn with_notifications(mut self, notifications: Arc<NotificationSystem>) -> Self {
+        self.notifications = Some(notifications);
+        self
+    }
+
     /// 로그 분석기 시작
     pub async fn start(&self) {
         let mut is_running = self.is_running.lock().await;
@@ -237,21 +262,76 @@ impl LogAnalyzer {
 
     /// 로그 엔트리 추가
     pub async fn add_log_entry(&self, entry: LogEntry) {
-        let mut entries = self.log_entries.write().await;
-        
-        // 최대 엔트리 수 제한
-        if entries.len() >= self.config.max_entries {
-            entries.pop_front();
+        let anomalies = self.anomaly_detector.lock().await.observe(&entry);
+
+        {
+            let mut entries = self.log_entries.write().await;
+
+            // 최대 엔트리 수 제한
+            if entries.len() >= self.config.max_entries {
+                entries.pop_front();
+            }
+
+            entries.push_back(entry);
+        }
+
+        for anomaly in anomalies {
+            self.report_anomaly(anomaly).await;
         }
-        
-        entries.push_back(entry);
+    }
+
+    /// 이상 탐지 결과 보관 및 알림 발송
+    async fn report_anomaly(&self, anomaly: LogAnomaly) {
+        warn!("로그 이상 탐지 [{}] {}: {}", anomaly.kind.as_str(), anomaly.module, anomaly.detail);
+
+        {
+            let mut anomalies = self.anomalies.write().await;
+            if anomalies.len() >= MAX_RECENT_ANOMALIES {
+                anomalies.pop_front();
+            }
+            anomalies.push_back(anomaly.clone());
+        }
+
+        let Some(notifications) = &self.notifications else {
+            return;
+        };
+
+        let notification_type = match anomaly.kind {
+            LogAnomalyKind::SlowLatency => NotificationType::PerformanceAlert,
+            LogAnomalyKind::ErrorSpike | LogAnomalyKind::NewErrorSignature => NotificationType::ErrorAlert,
+        };
+        let metadata = HashMap::from([
+            (SOURCE_METADATA_KEY.to_string(), "log_analyzer".to_string()),
+            ("anomaly_kind".to_string(), anomaly.kind.as_str().to_string()),
+            ("module".to_string(), anomaly.module.clone()),
+        ]);
+
+        if let Err(e) = notifications
+            .send_notification_with_metadata(
+                anomaly.title(),
+                anomaly.content(),
+                self.config.anomaly_channel.clone(),
+                anomaly.priority(),
+                notification_type,
+                metadata,
+            )
+            .await
+        {
+            error!("로그 이상 알림 발송 실패: {}", e);
+        }
+    }
+
+    /// 최근 탐지된 이상 조회 (최신순)
+    pub async fn get_anomalies(&self) -> Vec<LogAnomaly> {
+        let anomalies = self.anomalies.read().await;
+        anomalies.iter().rev().cloned().collect()
     }
 
     /// 로그 검색
     pub async fn search_logs(&self, query: LogQuery) -> LogSearchResult {
         let start_time = SystemTime::now();
         let entries = self.log_entries.read().await;
-        let indexes = self.indexes.read().await;
+        let _indexes = self.indexes.read().await;
 
         let mut results = Vec::new();
         let mut total_count = 0;
@@ -528,8 +608,8 @@ impl LogAnalyzer {
             return Err(format!("패턴이 이미 존재합니다: {}", pattern.id));
         }
 
-        patterns.insert(pattern.id.clone(), pattern);
         info!("새 패턴 추가: {}", pattern.id);
+        patterns.insert(pattern.id.clone(), pattern);
         Ok(())
     }
 
@@ -679,6 +759,39 @@ mod tests {
         assert_eq!(result.entries[0].level, LogLevel::Error);
     }
 
+    #[tokio::test]
+    async fn test_error_spike_recorded_as_anomaly() {
+        let config = LogAnalyzerConfig {
+            anomaly: LogAnomalyConfig {
+                spike_min_errors: 3,
+                ..LogAnomalyConfig::default()
+            },
+            ..LogAnalyzerConfig::default()
+        };
+        let analyzer = LogAnalyzer::new(config);
+
+        for i in 0..3u64 {
+            analyzer.add_log_entry(LogEntry {
+                id: format!("entry{}", i),
+                timestamp: 1_000 + i,
+                level: LogLevel::Error,
+                message: format!("주문 {} 저장 실패", i),
+                module: "db".to_string(),
+                thread_id: "thread_1".to_string(),
+                file: "db.rs".to_string(),
+                line: 1,
+                metadata: HashMap::new(),
+                tags: Vec::new(),
+            }).await;
+        }
+
+        let anomalies = analyzer.get_anomalies().await;
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].kind, LogAnomalyKind::ErrorSpike);
+        assert_eq!(anomalies[0].module, "db");
+        assert_eq!(analyzer.get_entry_count().await, 3);
+    }
+
     #[tokio::test]
     async fn test_pattern_operations() {
         let config = LogAnalyzerConfig::default();
@@ -703,7 +816,7 @@ mod tests {
         
         // 패턴 목록 조회
         let patterns = analyzer.get_patterns().await;
-        assert!(patterns.len() > 0);
+        assert!(!patterns.is_empty());
         
         analyzer.stop().await;
     }
@@ -0,0 +1,471 @@
+//! 로그 이상 탐지
+//!
+//! 로그 타임스탬프 기준 슬라이딩 윈도우로 다음 이상을 찾습니다.
+//! - 모듈별 에러 급증: 현재 윈도우 에러 수가 최소 건수 이상이면서 직전 윈도우 평균의 N배 이상
+//! - 처음 보는 에러 시그니처: 숫자/ID를 정규화한 에러 메시지가 학습 기간 이후 처음 등장
+//! - 지연: "latency", "took", "지연" 등의 키워드와 함께 기록된 시간이 임계값을 넘는 로그가 윈도우 내 반복
+//!
+//! 같은 이상은 쿨다운 동안 한 번만 보고합니다.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::monitoring::log_analyzer::{LogEntry, LogLevel};
+use crate::monitoring::notification_system::NotificationPriority;
+
+/// 지연 시간 추출 대상 키워드 (소문자)
+pub const LATENCY_KEYWORDS: &[&str] = &[
+    "latency", "took", "elapsed", "duration", "response time", "지연", "소요", "응답 시간", "응답시간",
+];
+
+/// 로그 이상 탐지 설정
+#[derive(Debug, Clone)]
+pub struct LogAnomalyConfig {
+    /// 슬라이딩 윈도우 크기
+    pub window_ms: u64,
+    /// 에러 급증 기준선으로 쓰는 직전 윈도우 수
+    pub baseline_windows: u32,
+    /// 에러 급증으로 보는 윈도우 내 최소 에러 수
+    pub spike_min_errors: usize,
+    /// 기준선 대비 배수
+    pub spike_ratio: f64,
+    /// 시작 후 이 기간 동안은 에러 시그니처를 학습만 함
+    pub signature_warmup_ms: u64,
+    /// 기억할 최대 시그니처 수 (초과 시 새 시그니처 탐지 중단)
+    pub max_signatures: usize,
+    /// 느린 요청으로 보는 지연 시간
+    pub latency_threshold_ms: f64,
+    /// 윈도우 내 느린 로그가 이 수 이상이면 보고
+    pub latency_min_hits: usize,
+    /// 같은 이상 재보고 간격
+    pub alert_cooldown_ms: u64,
+}
+
+impl Default for LogAnomalyConfig {
+    fn default() -> Self {
+        Self {
+            window_ms: 60_000, // 1분
+            baseline_windows: 10,
+            spike_min_errors: 10,
+            spike_ratio: 3.0,
+            signature_warmup_ms: 300_000, // 5분
+            max_signatures: 10_000,
+            latency_threshold_ms: 1000.0,
+            latency_min_hits: 5,
+            alert_cooldown_ms: 300_000, // 5분
+        }
+    }
+}
+
+/// 이상 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogAnomalyKind {
+    ErrorSpike,
+    NewErrorSignature,
+    SlowLatency,
+}
+
+impl LogAnomalyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogAnomalyKind::ErrorSpike => "error_spike",
+            LogAnomalyKind::NewErrorSignature => "new_error_signature",
+            LogAnomalyKind::SlowLatency => "slow_latency",
+        }
+    }
+}
+
+/// 탐지된 이상
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogAnomaly {
+    pub kind: LogAnomalyKind,
+    pub module: String,
+    /// 에러 시그니처 또는 대표 로그 메시지
+    pub detail: String,
+    /// 관측값 (에러 수, 느린 로그 수 등)
+    pub observed: f64,
+    /// 적용된 임계값
+    pub threshold: f64,
+    pub detected_at: u64,
+}
+
+impl LogAnomaly {
+    /// 알림 우선순위
+    pub fn priority(&self) -> NotificationPriority {
+        match self.kind {
+            LogAnomalyKind::ErrorSpike | LogAnomalyKind::SlowLatency => NotificationPriority::High,
+            LogAnomalyKind::NewErrorSignature => NotificationPriority::Normal,
+        }
+    }
+
+    /// 알림 제목
+    pub fn title(&self) -> String {
+        match self.kind {
+            LogAnomalyKind::ErrorSpike => format!("에러 급증: {}", self.module),
+            LogAnomalyKind::NewErrorSignature => format!("새로운 에러 유형: {}", self.module),
+            LogAnomalyKind::SlowLatency => format!("지연 증가: {}", self.module),
+        }
+    }
+
+    /// 알림 본문
+    pub fn content(&self) -> String {
+        match self.kind {
+            LogAnomalyKind::ErrorSpike => format!(
+                "최근 윈도우 에러 {}건 (임계값 {:.1}건)\n최근 에러: {}",
+                self.observed, self.threshold, self.detail
+            ),
+            LogAnomalyKind::NewErrorSignature => format!("처음 보는 에러 시그니처: {}", self.detail),
+            LogAnomalyKind::SlowLatency => format!(
+                "최근 윈도우에서 임계값을 넘는 지연 로그 {}건 (기준 {}건)\n최근 로그: {}",
+                self.observed, self.threshold, self.detail
+            ),
+        }
+    }
+}
+
+/// 로그 이상 탐지기
+pub struct LogAnomalyDetector {
+    config: LogAnomalyConfig,
+    /// 첫 로그 시각
+    started_at: Option<u64>,
+    /// 지금까지 본 가장 늦은 로그 시각 (순서가 뒤바뀐 로그 대비)
+    latest: u64,
+    /// 모듈별 에러 시각
+    module_errors: HashMap<String, VecDeque<u64>>,
+    /// 학습된 에러 시그니처
+    signatures: HashSet<String>,
+    /// 모듈별 느린 로그 시각
+    module_slow: HashMap<String, VecDeque<u64>>,
+    /// 마지막 보고 시각
+    last_alerted: HashMap<(LogAnomalyKind, String), u64>,
+}
+
+impl LogAnomalyDetector {
+    pub fn new(config: LogAnomalyConfig) -> Self {
+        Self {
+            config,
+            started_at: None,
+            latest: 0,
+            module_errors: HashMap::new(),
+            signatures: HashSet::new(),
+            module_slow: HashMap::new(),
+            last_alerted: HashMap::new(),
+        }
+    }
+
+    /// 로그 관찰 후 새로 탐지된 이상 반환
+    pub fn observe(&mut self, entry: &LogEntry) -> Vec<LogAnomaly> {
+        let now = self.latest.max(entry.timestamp);
+        self.latest = now;
+        let started_at = *self.started_at.get_or_insert(entry.timestamp);
+
+        let mut anomalies = Vec::new();
+        if matches!(entry.level, LogLevel::Error | LogLevel::Fatal) {
+            anomalies.extend(self.check_error_spike(entry, now, started_at));
+            anomalies.extend(self.check_signature(entry, now, started_at));
+        }
+        anomalies.extend(self.check_latency(entry, now));
+        anomalies
+    }
+
+    /// 학습된 에러 시그니처 수
+    pub fn signature_count(&self) -> usize {
+        self.signatures.len()
+    }
+
+    fn check_error_spike(&mut self, entry: &LogEntry, now: u64, started_at: u64) -> Option<LogAnomaly> {
+        let window = self.config.window_ms.max(1);
+        let history = window * (self.config.baseline_windows as u64 + 1);
+
+        let errors = self.module_errors.entry(entry.module.clone()).or_default();
+        errors.push_back(entry.timestamp);
+        while errors.front().is_some_and(|t| *t + history <= now) {
+            errors.pop_front();
+        }
+
+        let current = errors.iter().filter(|t| **t + window > now).count();
+        // 관측 기간이 기준선보다 짧으면 실제 관측한 윈도우 수로 평균
+        let observed_windows = ((now.saturating_sub(started_at)) / window).min(self.config.baseline_windows as u64);
+        let baseline = if observed_windows == 0 {
+            0.0
+        } else {
+            (errors.len() - current) as f64 / observed_windows as f64
+        };
+
+        let threshold = (self.config.spike_min_errors as f64).max(baseline * self.config.spike_ratio);
+        if (current as f64) < threshold || !self.cooled_down(LogAnomalyKind::ErrorSpike, &entry.module, now) {
+            return None;
+        }
+
+        Some(LogAnomaly {
+            kind: LogAnomalyKind::ErrorSpike,
+            module: entry.module.clone(),
+            detail: entry.message.clone(),
+            observed: current as f64,
+            threshold,
+            detected_at: now,
+        })
+    }
+
+    fn check_signature(&mut self, entry: &LogEntry, now: u64, started_at: u64) -> Option<LogAnomaly> {
+        let signature = error_signature(entry);
+        if self.signatures.contains(&signature) || self.signatures.len() >= self.config.max_signatures {
+            return None;
+        }
+        self.signatures.insert(signature.clone());
+
+        if now.saturating_sub(started_at) < self.config.signature_warmup_ms {
+            return None;
+        }
+
+        Some(LogAnomaly {
+            kind: LogAnomalyKind::NewErrorSignature,
+            module: entry.module.clone(),
+            detail: signature,
+            observed: 1.0,
+            threshold: 1.0,
+            detected_at: now,
+        })
+    }
+
+    fn check_latency(&mut self, entry: &LogEntry, now: u64) -> Option<LogAnomaly> {
+        let latency_ms = extract_latency_ms(&entry.message)?;
+        if latency_ms < self.config.latency_threshold_ms {
+            return None;
+        }
+
+        let window = self.config.window_ms.max(1);
+        let slow = self.module_slow.entry(entry.module.clone()).or_default();
+        slow.push_back(entry.timestamp);
+        while slow.front().is_some_and(|t| *t + window <= now) {
+            slow.pop_front();
+        }
+
+        let hits = slow.len();
+        if hits < self.config.latency_min_hits || !self.cooled_down(LogAnomalyKind::SlowLatency, &entry.module, now) {
+            return None;
+        }
+
+        Some(LogAnomaly {
+            kind: LogAnomalyKind::SlowLatency,
+            module: entry.module.clone(),
+            detail: entry.message.clone(),
+            observed: hits as f64,
+            threshold: self.config.latency_min_hits as f64,
+            detected_at: now,
+        })
+    }
+
+    /// 쿨다운이 지났으면 보고 시각을 기록하고 true
+    fn cooled_down(&mut self, kind: LogAnomalyKind, key: &str, now: u64) -> bool {
+        let last = self.last_alerted.entry((kind, key.to_string())).or_insert(0);
+        if *last != 0 && now.saturating_sub(*last) < self.config.alert_cooldown_ms {
+            return false;
+        }
+        *last = now.max(1);
+        true
+    }
+}
+
+/// 에러 시그니처 (모듈 + 정규화된 메시지)
+pub fn error_signature(entry: &LogEntry) -> String {
+    format!("{}: {}", entry.module, normalize_message(&entry.message))
+}
+
+/// 숫자와 ID 성격의 토큰을 지워 같은 유형의 메시지가 같은 문자열이 되도록 정규화
+pub fn normalize_message(message: &str) -> String {
+    message
+        .split_whitespace()
+        .map(|token| {
+            let mut normalized = String::with_capacity(token.len());
+            let mut in_digits = false;
+            for c in token.chars().flat_map(char::to_lowercase) {
+                if c.is_ascii_digit() {
+                    if !in_digits {
+                        normalized.push('#');
+                    }
+                    in_digits = true;
+                } else {
+                    normalized.push(c);
+                    in_digits = false;
+                }
+            }
+
+            // UUID, 해시 등 16진수 ID
+            let is_id = normalized.len() >= 8
+                && normalized.contains('#')
+                && normalized.chars().all(|c| c == '#' || c == '-' || c.is_ascii_hexdigit());
+            if is_id {
+                "*".to_string()
+            } else {
+                normalized
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 지연 키워드가 있는 메시지에서 가장 큰 시간 값을 밀리초로 추출
+///
+/// `took 1250ms`, `latency=1.5s`, `응답 지연 800 밀리초` 같은 형식을 인식합니다.
+pub fn extract_latency_ms(message: &str) -> Option<f64> {
+    let lower = message.to_lowercase();
+    if !LATENCY_KEYWORDS.iter().any(|k| lower.contains(k)) {
+        return None;
+    }
+
+    let chars: Vec<char> = lower.chars().collect();
+    let mut max_ms: Option<f64> = None;
+    let mut i = 0;
+    while i < chars.len() {
+        // 다른 토큰의 일부인 숫자(예: v2, id123)는 제외
+        let starts_number = chars[i].is_ascii_digit() && (i == 0 || !chars[i - 1].is_alphanumeric());
+        if !starts_number {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+            i += 1;
+        }
+        let number: String = chars[start..i].iter().collect();
+
+        let mut j = i;
+        while j < chars.len() && chars[j] == ' ' {
+            j += 1;
+        }
+        let unit_start = j;
+        while j < chars.len() && chars[j].is_alphabetic() {
+            j += 1;
+        }
+        let unit: String = chars[unit_start..j].iter().collect();
+
+        let scale = match unit.as_str() {
+            "ms" | "msec" | "millis" | "밀리초" => Some(1.0),
+            "s" | "sec" | "secs" | "second" | "seconds" | "초" => Some(1000.0),
+            "us" | "µs" | "μs" | "마이크로초" => Some(0.001),
+            "ns" => Some(0.000_001),
+            _ => None,
+        };
+        if let (Some(scale), Ok(value)) = (scale, number.parse::<f64>()) {
+            let ms = value * scale;
+            max_ms = Some(max_ms.map_or(ms, |m: f64| m.max(ms)));
+        }
+    }
+    max_ms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: u64, level: LogLevel, module: &str, message: &str) -> LogEntry {
+        LogEntry {
+            id: format!("{}-{}", module, timestamp),
+            timestamp,
+            level,
+            message: message.to_string(),
+            module: module.to_string(),
+            thread_id: "main".to_string(),
+            file: "test.rs".to_string(),
+            line: 1,
+            metadata: HashMap::new(),
+            tags: Vec::new(),
+        }
+    }
+
+    fn config() -> LogAnomalyConfig {
+        LogAnomalyConfig {
+            window_ms: 1_000,
+            baseline_windows: 5,
+            spike_min_errors: 5,
+            spike_ratio: 3.0,
+            signature_warmup_ms: 2_000,
+            latency_min_hits: 3,
+            alert_cooldown_ms: 10_000,
+            ..LogAnomalyConfig::default()
+        }
+    }
+
+    fn kinds(anomalies: &[LogAnomaly]) -> Vec<LogAnomalyKind> {
+        anomalies.iter().map(|a| a.kind).collect()
+    }
+
+    #[test]
+    fn test_error_spike_per_module_with_cooldown() {
+        let mut detector = LogAnomalyDetector::new(config());
+
+        // 기준선: 5초 동안 초당 1건
+        for t in 0..5u64 {
+            detector.observe(&entry(t * 1_000, LogLevel::Error, "db", "query failed"));
+        }
+
+        // 다른 모듈 에러는 db 급증에 포함되지 않음
+        let other = detector.observe(&entry(5_000, LogLevel::Error, "api", "query failed"));
+        assert!(!kinds(&other).contains(&LogAnomalyKind::ErrorSpike));
+
+        let mut spikes = 0;
+        for i in 0..6u64 {
+            let found = detector.observe(&entry(5_000 + i * 10, LogLevel::Error, "db", "query failed"));
+            spikes += found.iter().filter(|a| a.kind == LogAnomalyKind::ErrorSpike).count();
+        }
+        // 5건째에서 한 번만 보고 (쿨다운)
+        assert_eq!(spikes, 1);
+    }
+
+    #[test]
+    fn test_new_signature_after_warmup() {
+        let mut detector = LogAnomalyDetector::new(config());
+
+        // 학습 기간 중에는 보고하지 않음
+        let found = detector.observe(&entry(0, LogLevel::Error, "db", "order 123 not found"));
+        assert!(found.is_empty());
+
+        // 숫자만 다른 메시지는 같은 시그니처
+        let found = detector.observe(&entry(3_000, LogLevel::Error, "db", "order 456 not found"));
+        assert!(!kinds(&found).contains(&LogAnomalyKind::NewErrorSignature));
+
+        let found = detector.observe(&entry(3_100, LogLevel::Error, "db", "disk full"));
+        assert_eq!(kinds(&found), vec![LogAnomalyKind::NewErrorSignature]);
+        assert_eq!(found[0].detail, "db: disk full");
+
+        // 경고 로그는 시그니처 대상 아님
+        let found = detector.observe(&entry(3_200, LogLevel::Warn, "db", "cache miss"));
+        assert!(found.is_empty());
+        assert_eq!(detector.signature_count(), 2);
+    }
+
+    #[test]
+    fn test_slow_latency_hits_in_window() {
+        let mut detector = LogAnomalyDetector::new(config());
+
+        detector.observe(&entry(0, LogLevel::Info, "api", "request took 1500ms"));
+        detector.observe(&entry(100, LogLevel::Info, "api", "request took 20ms"));
+        // 윈도우 밖으로 밀려남
+        detector.observe(&entry(1_500, LogLevel::Info, "api", "request took 2s"));
+        let found = detector.observe(&entry(1_600, LogLevel::Warn, "api", "응답 지연 1200 밀리초"));
+        assert!(found.is_empty());
+
+        let found = detector.observe(&entry(1_700, LogLevel::Info, "api", "latency=1.1s"));
+        assert_eq!(kinds(&found), vec![LogAnomalyKind::SlowLatency]);
+        assert_eq!(found[0].observed, 3.0);
+    }
+
+    #[test]
+    fn test_latency_extraction_and_normalization() {
+        assert_eq!(extract_latency_ms("request took 1250ms"), Some(1250.0));
+        assert_eq!(extract_latency_ms("latency=1.5s p99"), Some(1500.0));
+        assert_eq!(extract_latency_ms("elapsed 300 us, then took 2 ms"), Some(2.0));
+        assert_eq!(extract_latency_ms("응답 지연 800 밀리초"), Some(800.0));
+        // 키워드가 없거나 단위가 없으면 추출하지 않음
+        assert_eq!(extract_latency_ms("processed 500 orders in 3s"), None);
+        assert_eq!(extract_latency_ms("took 5 samples"), None);
+
+        assert_eq!(
+            normalize_message("Order 550e8400-e29b-41d4-a716-446655440000 failed after 3 retries"),
+            "order * failed after # retries"
+        );
+    }
+}
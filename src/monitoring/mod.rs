@@ -4,11 +4,23 @@
 //! 종합적인 모니터링 시스템을 제공합니다.
 
 pub mod system_health;
+pub mod health_probes;
+pub mod auto_recovery;
+pub mod readiness;
 pub mod notification_system;
+pub mod notification_routing;
+pub mod incident_tracker;
 pub mod dashboard;
 pub mod log_analyzer;
+pub mod log_anomaly;
 
 pub use system_health::*;
+pub use health_probes::*;
+pub use auto_recovery::*;
+pub use readiness::*;
 pub use notification_system::*;
+pub use notification_routing::*;
+pub use incident_tracker::*;
 pub use dashboard::*;
-pub use log_analyzer::*;
\ No newline at end of file
+pub use log_analyzer::*;
+pub use log_anomaly::*;
\ No newline at end of file
@@ -0,0 +1,329 @@
+//! 알림 라우팅 규칙
+//!
+//! (알림 타입, 우선순위, 발신 서비스) 조합을 채널 목록으로 매핑합니다.
+//! 규칙마다 조용한 시간(quiet hours)과 에스컬레이션 정책을 둘 수 있습니다.
+//!
+//! - 조건이 맞는 규칙의 채널을 모두 합쳐 발송합니다.
+//! - 맞는 규칙이 없으면 요청한 채널로 그대로 보냅니다.
+//! - 조용한 시간인 규칙은 건너뛰며, 맞는 규칙이 모두 조용한 시간이면 발송하지 않습니다.
+//! - 에스컬레이션 정책이 있는 규칙에 걸린 알림은 정해진 시간 안에 확인(ack)되지 않으면
+//!   에스컬레이션 채널(예: SMS)로 다시 보냅니다.
+
+use log::error;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::db::models::NotificationRoutingRuleRecord;
+use crate::db::repository::NotificationRoutingRuleRepository;
+
+use super::notification_system::{NotificationChannel, NotificationMessage, NotificationPriority, NotificationType};
+
+/// 발신 서비스를 담는 알림 메타데이터 키
+pub const SOURCE_METADATA_KEY: &str = "source";
+/// 규칙 변경 감사 로그 이벤트
+pub const RULE_UPDATED_AUDIT_EVENT: &str = "NOTIFICATION_RULE_UPDATED";
+pub const RULE_DELETED_AUDIT_EVENT: &str = "NOTIFICATION_RULE_DELETED";
+pub const RULE_AUDIT_ENTITY: &str = "notification_rule";
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_utc_offset_minutes() -> i32 {
+    540 // KST
+}
+
+fn default_escalation_priority() -> NotificationPriority {
+    NotificationPriority::Critical
+}
+
+/// 라우팅 규칙 오류
+#[derive(Debug, thiserror::Error)]
+pub enum RoutingRuleError {
+    #[error("규칙 {0}: {1}")]
+    Invalid(String, String),
+}
+
+/// 조용한 시간 (현지 시각 `HH:MM`, 자정을 넘는 구간 가능)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct QuietHours {
+    /// 시작 시각 (예: "22:00")
+    pub start: String,
+    /// 종료 시각 (예: "07:00", 이 시각부터 다시 발송)
+    pub end: String,
+    /// UTC 기준 시차 (분, 기본 540 = KST)
+    #[serde(default = "default_utc_offset_minutes")]
+    pub utc_offset_minutes: i32,
+    /// Critical 알림은 조용한 시간에도 발송
+    #[serde(default = "default_true")]
+    pub allow_critical: bool,
+}
+
+fn parse_minute_of_day(value: &str) -> Option<i64> {
+    let (hour, minute) = value.split_once(':')?;
+    let (hour, minute) = (hour.parse::<i64>().ok()?, minute.parse::<i64>().ok()?);
+    ((0..24).contains(&hour) && (0..60).contains(&minute)).then_some(hour * 60 + minute)
+}
+
+impl QuietHours {
+    /// `now_ms`(Unix 밀리초)가 조용한 시간인지 여부
+    pub fn is_active(&self, now_ms: u64) -> bool {
+        let (Some(start), Some(end)) = (parse_minute_of_day(&self.start), parse_minute_of_day(&self.end)) else {
+            return false;
+        };
+        let minute = ((now_ms / 60_000) as i64 + self.utc_offset_minutes as i64).rem_euclid(1440);
+        if start <= end {
+            start <= minute && minute < end
+        } else {
+            minute >= start || minute < end
+        }
+    }
+}
+
+/// 에스컬레이션 정책
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EscalationPolicy {
+    /// 확인되지 않은 채 이 시간(초)이 지나면 재발송
+    pub after_secs: u64,
+    /// 재발송 채널
+    pub channels: Vec<NotificationChannel>,
+    /// 이 우선순위 이상만 에스컬레이션 (기본 Critical)
+    #[serde(default = "default_escalation_priority")]
+    pub min_priority: NotificationPriority,
+}
+
+/// 알림 라우팅 규칙
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RoutingRule {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// 대상 알림 타입 (비어 있으면 전체)
+    #[serde(default)]
+    pub notification_types: Vec<NotificationType>,
+    /// 최소 우선순위 (없으면 전체)
+    #[serde(default)]
+    pub min_priority: Option<NotificationPriority>,
+    /// 대상 발신 서비스 (메타데이터 `source`, 비어 있으면 전체)
+    #[serde(default)]
+    pub sources: Vec<String>,
+    /// 발송 채널
+    pub channels: Vec<NotificationChannel>,
+    #[serde(default)]
+    pub quiet_hours: Option<QuietHours>,
+    #[serde(default)]
+    pub escalation: Option<EscalationPolicy>,
+}
+
+impl RoutingRule {
+    /// 규칙 정의 검증
+    pub fn validate(&self) -> Result<(), RoutingRuleError> {
+        let invalid = |message: &str| Err(RoutingRuleError::Invalid(self.id.clone(), message.to_string()));
+
+        if self.id.is_empty() || self.id.len() > 64 {
+            return invalid("ID는 1~64자여야 합니다");
+        }
+        if self.channels.is_empty() {
+            return invalid("발송 채널이 하나 이상 필요합니다");
+        }
+        if let Some(quiet_hours) = &self.quiet_hours {
+            if parse_minute_of_day(&quiet_hours.start).is_none() || parse_minute_of_day(&quiet_hours.end).is_none() {
+                return invalid("조용한 시간은 HH:MM 형식이어야 합니다");
+            }
+        }
+        if let Some(escalation) = &self.escalation {
+            if escalation.after_secs == 0 {
+                return invalid("에스컬레이션 대기 시간은 0보다 커야 합니다");
+            }
+            if escalation.channels.is_empty() {
+                return invalid("에스컬레이션 채널이 하나 이상 필요합니다");
+            }
+        }
+        Ok(())
+    }
+
+    /// 알림이 규칙 조건에 맞는지 여부 (조용한 시간 제외)
+    pub fn matches(&self, message: &NotificationMessage) -> bool {
+        self.enabled
+            && (self.notification_types.is_empty() || self.notification_types.contains(&message.notification_type))
+            && self.min_priority.as_ref().is_none_or(|min| message.priority >= *min)
+            && (self.sources.is_empty()
+                || message
+                    .metadata
+                    .get(SOURCE_METADATA_KEY)
+                    .is_some_and(|source| self.sources.contains(source)))
+    }
+
+    /// DB 레코드로 변환
+    pub fn to_record(&self, updated_by: &str, updated_at: i64) -> NotificationRoutingRuleRecord {
+        NotificationRoutingRuleRecord {
+            id: self.id.clone(),
+            definition: serde_json::to_string(self).unwrap_or_default(),
+            updated_by: updated_by.to_string(),
+            updated_at,
+        }
+    }
+
+    fn is_quiet(&self, message: &NotificationMessage, now_ms: u64) -> bool {
+        self.quiet_hours.as_ref().is_some_and(|quiet_hours| {
+            quiet_hours.is_active(now_ms)
+                && !(quiet_hours.allow_critical && message.priority == NotificationPriority::Critical)
+        })
+    }
+}
+
+/// 라우팅 결과
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RouteDecision {
+    /// 발송 채널 (비어 있으면 조용한 시간으로 발송하지 않음)
+    pub channels: Vec<NotificationChannel>,
+    /// 적용할 에스컬레이션 (규칙 ID, 정책)
+    pub escalation: Option<(String, EscalationPolicy)>,
+    /// 조용한 시간으로 건너뛴 규칙
+    pub quieted_rules: Vec<String>,
+}
+
+/// 저장된 라우팅 규칙 로드 (형식이 잘못된 규칙은 건너뜀)
+pub async fn load_rules(repository: &NotificationRoutingRuleRepository) -> Result<Vec<RoutingRule>, sqlx::Error> {
+    let records = repository.find_all().await?;
+    let mut rules = Vec::with_capacity(records.len());
+    for record in records {
+        match serde_json::from_str::<RoutingRule>(&record.definition) {
+            Ok(rule) if rule.validate().is_ok() => rules.push(rule),
+            Ok(_) | Err(_) => error!("알림 라우팅 규칙 {} 형식 오류, 건너뜀", record.id),
+        }
+    }
+    Ok(rules)
+}
+
+/// 확인을 기다리는 에스컬레이션
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PendingEscalation {
+    pub notification_id: String,
+    pub rule_id: String,
+    pub title: String,
+    pub priority: NotificationPriority,
+    /// 최초 발송 시각 (Unix 밀리초)
+    pub created_at: u64,
+    /// 다음 에스컬레이션 예정 시각 (Unix 밀리초)
+    pub due_at: u64,
+    /// 에스컬레이션 간격 (초)
+    pub after_secs: u64,
+    /// 지금까지 에스컬레이션한 횟수
+    pub escalation_count: u32,
+    pub channels: Vec<NotificationChannel>,
+}
+
+/// 알림 라우팅 (맞는 규칙이 없으면 None)
+pub fn route(rules: &[RoutingRule], message: &NotificationMessage, now_ms: u64) -> Option<RouteDecision> {
+    let mut decision = RouteDecision::default();
+    let mut matched = false;
+
+    for rule in rules.iter().filter(|rule| rule.matches(message)) {
+        matched = true;
+        if rule.is_quiet(message, now_ms) {
+            decision.quieted_rules.push(rule.id.clone());
+            continue;
+        }
+        for channel in &rule.channels {
+            if !decision.channels.contains(channel) {
+                decision.channels.push(channel.clone());
+            }
+        }
+        if let Some(escalation) = &rule.escalation {
+            if decision.escalation.is_none() && message.priority >= escalation.min_priority {
+                decision.escalation = Some((rule.id.clone(), escalation.clone()));
+            }
+        }
+    }
+
+    matched.then_some(decision)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn message(priority: NotificationPriority, source: &str) -> NotificationMessage {
+        NotificationMessage {
+            id: "n1".to_string(),
+            title: "알림".to_string(),
+            content: "내용".to_string(),
+            channel: NotificationChannel::Log,
+            priority,
+            notification_type: NotificationType::ErrorAlert,
+            timestamp: 0,
+            retry_count: 0,
+            max_retries: 3,
+            metadata: HashMap::from([(SOURCE_METADATA_KEY.to_string(), source.to_string())]),
+            next_attempt_at: 0,
+        }
+    }
+
+    fn rule(id: &str, channels: Vec<NotificationChannel>) -> RoutingRule {
+        RoutingRule {
+            id: id.to_string(),
+            name: id.to_string(),
+            enabled: true,
+            notification_types: vec![NotificationType::ErrorAlert],
+            min_priority: None,
+            sources: Vec::new(),
+            channels,
+            quiet_hours: None,
+            escalation: None,
+        }
+    }
+
+    #[test]
+    fn test_route_by_priority_and_source() {
+        let mut critical = rule("critical-oncall", vec![NotificationChannel::Slack, NotificationChannel::Email]);
+        critical.min_priority = Some(NotificationPriority::Critical);
+        critical.escalation = Some(EscalationPolicy {
+            after_secs: 300,
+            channels: vec![NotificationChannel::SMS],
+            min_priority: NotificationPriority::Critical,
+        });
+        let mut matching = rule("matching-team", vec![NotificationChannel::Slack]);
+        matching.sources = vec!["matching_engine".to_string()];
+        let rules = vec![critical, matching];
+
+        let decision = route(&rules, &message(NotificationPriority::Critical, "matching_engine"), 0).unwrap();
+        assert_eq!(decision.channels, vec![NotificationChannel::Slack, NotificationChannel::Email]);
+        assert_eq!(decision.escalation.unwrap().0, "critical-oncall");
+
+        let decision = route(&rules, &message(NotificationPriority::High, "matching_engine"), 0).unwrap();
+        assert_eq!(decision.channels, vec![NotificationChannel::Slack]);
+        assert!(decision.escalation.is_none());
+
+        // 맞는 규칙 없음 → 요청 채널 그대로
+        assert!(route(&rules, &message(NotificationPriority::High, "order_router"), 0).is_none());
+    }
+
+    #[test]
+    fn test_quiet_hours() {
+        let quiet_hours = QuietHours {
+            start: "22:00".to_string(),
+            end: "07:00".to_string(),
+            utc_offset_minutes: 540,
+            allow_critical: true,
+        };
+        // 2023-11-14 13:30 UTC = 22:30 KST
+        let night = 1_699_968_600_000;
+        assert!(quiet_hours.is_active(night));
+        assert!(!quiet_hours.is_active(night + 9 * 3_600_000)); // 07:30 KST
+
+        let mut slack = rule("slack-night-quiet", vec![NotificationChannel::Slack]);
+        slack.quiet_hours = Some(quiet_hours);
+        let rules = vec![slack];
+
+        let decision = route(&rules, &message(NotificationPriority::High, "x"), night).unwrap();
+        assert!(decision.channels.is_empty());
+        assert_eq!(decision.quieted_rules, vec!["slack-night-quiet"]);
+
+        let decision = route(&rules, &message(NotificationPriority::Critical, "x"), night).unwrap();
+        assert_eq!(decision.channels, vec![NotificationChannel::Slack]);
+    }
+}
@@ -3,17 +3,25 @@
 //! 이 모듈은 시스템 장애, 성능 이슈, 상태 변경에 대한
 //! 다양한 채널을 통한 알림을 제공합니다.
 
+use async_trait::async_trait;
+use lettre::message::{header::ContentType, Mailbox, Message};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use log::{info, error, warn, debug};
-use tokio::time::{sleep, interval};
-use std::sync::atomic::{AtomicUsize, AtomicU64, Ordering};
+use tokio::time::interval;
+use utoipa::ToSchema;
+
+use super::incident_tracker::IncidentTracker;
+use super::notification_routing::{self, PendingEscalation, RoutingRule, RoutingRuleError};
 
 /// 알림 채널 타입
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 pub enum NotificationChannel {
     Email,
     Slack,
@@ -25,8 +33,8 @@ pub enum NotificationChannel {
     Console,
 }
 
-/// 알림 우선순위
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// 알림 우선순위 (선언 순서대로 Low < Critical)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
 pub enum NotificationPriority {
     Low,      // 낮음
     Normal,   // 보통
@@ -34,8 +42,20 @@ pub enum NotificationPriority {
     Critical, // 긴급
 }
 
+impl fmt::Display for NotificationChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl fmt::Display for NotificationPriority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 /// 알림 타입
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub enum NotificationType {
     HealthCheck,      // 헬스체크
     PerformanceAlert, // 성능 경고
@@ -43,6 +63,7 @@ pub enum NotificationType {
     StatusChange,     // 상태 변경
     Maintenance,      // 유지보수
     SecurityAlert,    // 보안 경고
+    ArbitrageAlert,   // 차익거래 기회
     Custom,           // 사용자 정의
 }
 
@@ -59,6 +80,9 @@ pub struct NotificationMessage {
     pub retry_count: u32,
     pub max_retries: u32,
     pub metadata: HashMap<String, String>,
+    /// 재시도 가능 시각 (밀리초, 0이면 즉시)
+    #[serde(default)]
+    pub next_attempt_at: u64,
 }
 
 /// 알림 전송 결과
@@ -92,6 +116,10 @@ pub struct ChannelStats {
     pub failed: u64,
     pub success_rate: f64,
     pub average_response_time_ms: u64,
+    /// 연속 실패 횟수 (성공 시 0, 재시도 대기 시간에 반영)
+    pub consecutive_failures: u32,
+    pub last_error: Option<String>,
+    pub last_failure_at: Option<u64>,
 }
 
 /// 알림 설정
@@ -105,6 +133,12 @@ pub struct NotificationConfig {
     pub rate_limit_per_minute: u32,
     pub enable_deduplication: bool,
     pub deduplication_window_ms: u64,
+    /// 재시도 대기 시간 상한
+    pub max_retry_delay_ms: u64,
+    /// Slack 수신 웹훅 (None이면 Slack 채널 비활성화)
+    pub slack: Option<SlackConfig>,
+    /// SMTP 이메일 (None이면 이메일 채널 비활성화)
+    pub email: Option<EmailConfig>,
 }
 
 impl Default for NotificationConfig {
@@ -121,10 +155,154 @@ impl Default for NotificationConfig {
             rate_limit_per_minute: 100,
             enable_deduplication: true,
             deduplication_window_ms: 60000, // 1분
+            max_retry_delay_ms: 60000,
+            slack: None,
+            email: None,
+        }
+    }
+}
+
+/// Slack 수신 웹훅 설정
+#[derive(Debug, Clone)]
+pub struct SlackConfig {
+    pub webhook_url: String,
+    /// 채널 재지정 (None이면 웹훅에 연결된 채널)
+    pub channel: Option<String>,
+    /// 표시 이름 재지정
+    pub username: Option<String>,
+    pub timeout: Duration,
+}
+
+impl SlackConfig {
+    pub fn new(webhook_url: impl Into<String>) -> Self {
+        Self {
+            webhook_url: webhook_url.into(),
+            channel: None,
+            username: None,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// 환경 변수에서 읽기 (`XTRADER_SLACK_WEBHOOK_URL`이 없으면 None)
+    pub fn from_env() -> Option<Self> {
+        let webhook_url = std::env::var("XTRADER_SLACK_WEBHOOK_URL").ok().filter(|v| !v.is_empty())?;
+        Some(Self {
+            channel: std::env::var("XTRADER_SLACK_CHANNEL").ok(),
+            username: std::env::var("XTRADER_SLACK_USERNAME").ok(),
+            ..Self::new(webhook_url)
+        })
+    }
+}
+
+/// SMTP 연결 보안 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpTls {
+    /// 평문 (내부 릴레이 전용)
+    None,
+    /// 평문 연결 후 STARTTLS 업그레이드 (필수)
+    StartTls,
+    /// 처음부터 TLS (SMTPS)
+    Tls,
+}
+
+impl SmtpTls {
+    fn default_port(&self) -> u16 {
+        match self {
+            SmtpTls::None => 25,
+            SmtpTls::StartTls => 587,
+            SmtpTls::Tls => 465,
+        }
+    }
+}
+
+/// SMTP 이메일 설정
+#[derive(Debug, Clone)]
+pub struct EmailConfig {
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub tls: SmtpTls,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// 발신자 (`이름 <주소>` 또는 주소)
+    pub from: String,
+    /// 수신자 목록
+    pub to: Vec<String>,
+    pub timeout: Duration,
+}
+
+impl EmailConfig {
+    /// 환경 변수에서 읽기 (`XTRADER_SMTP_HOST`가 없으면 None)
+    ///
+    /// `XTRADER_SMTP_TLS`는 `starttls`(기본), `tls`, `none` 중 하나이며,
+    /// 포트를 지정하지 않으면 방식별 기본 포트(587, 465, 25)를 씁니다.
+    pub fn from_env() -> Result<Option<Self>, String> {
+        let Some(smtp_host) = std::env::var("XTRADER_SMTP_HOST").ok().filter(|v| !v.is_empty()) else {
+            return Ok(None);
+        };
+        let tls = match std::env::var("XTRADER_SMTP_TLS").unwrap_or_default().to_ascii_lowercase().as_str() {
+            "" | "starttls" => SmtpTls::StartTls,
+            "tls" => SmtpTls::Tls,
+            "none" => SmtpTls::None,
+            other => return Err(format!("XTRADER_SMTP_TLS 값이 올바르지 않습니다: {}", other)),
+        };
+        let smtp_port = match std::env::var("XTRADER_SMTP_PORT") {
+            Ok(port) => port.parse().map_err(|_| format!("XTRADER_SMTP_PORT 값이 올바르지 않습니다: {}", port))?,
+            Err(_) => tls.default_port(),
+        };
+        let from = std::env::var("XTRADER_ALERT_EMAIL_FROM").map_err(|_| "XTRADER_ALERT_EMAIL_FROM이 필요합니다".to_string())?;
+        let to: Vec<String> = std::env::var("XTRADER_ALERT_EMAIL_TO")
+            .unwrap_or_default()
+            .split(',')
+            .map(|addr| addr.trim().to_string())
+            .filter(|addr| !addr.is_empty())
+            .collect();
+        if to.is_empty() {
+            return Err("XTRADER_ALERT_EMAIL_TO가 필요합니다".to_string());
         }
+
+        Ok(Some(Self {
+            smtp_host,
+            smtp_port,
+            tls,
+            username: std::env::var("XTRADER_SMTP_USERNAME").ok(),
+            password: std::env::var("XTRADER_SMTP_PASSWORD").ok(),
+            from,
+            to,
+            timeout: Duration::from_secs(15),
+        }))
     }
 }
 
+/// 알림 전송 오류
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{message}")]
+pub struct NotificationError {
+    pub message: String,
+    /// 재시도해도 성공할 수 없는 오류 (잘못된 웹훅, 수신자 거부 등)
+    pub permanent: bool,
+    /// 수신 측이 요구한 재시도 대기 시간 (Slack 429 `Retry-After`)
+    pub retry_after: Option<Duration>,
+}
+
+impl NotificationError {
+    /// 일시적 오류 (재시도 대상)
+    pub fn transient(message: impl Into<String>) -> Self {
+        Self { message: message.into(), permanent: false, retry_after: None }
+    }
+
+    /// 영구 오류 (재시도하지 않음)
+    pub fn permanent(message: impl Into<String>) -> Self {
+        Self { message: message.into(), permanent: true, retry_after: None }
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
 /// 알림 시스템
 pub struct NotificationSystem {
     config: NotificationConfig,
@@ -134,11 +312,15 @@ pub struct NotificationSystem {
     channel_handlers: Arc<RwLock<HashMap<NotificationChannel, Arc<dyn NotificationChannelHandler + Send + Sync>>>>,
     rate_limiter: Arc<Mutex<RateLimiter>>,
     deduplicator: Arc<Mutex<Deduplicator>>,
+    routing_rules: Arc<RwLock<Vec<RoutingRule>>>,
+    escalations: Arc<Mutex<HashMap<String, (PendingEscalation, NotificationMessage)>>>,
+    incidents: Option<Arc<IncidentTracker>>,
 }
 
 /// 알림 채널 핸들러 트레이트
+#[async_trait]
 pub trait NotificationChannelHandler: Send + Sync {
-    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, String>;
+    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, NotificationError>;
     fn get_channel(&self) -> NotificationChannel;
     fn is_enabled(&self) -> bool;
 }
@@ -146,8 +328,9 @@ pub trait NotificationChannelHandler: Send + Sync {
 /// 콘솔 알림 핸들러
 pub struct ConsoleNotificationHandler;
 
+#[async_trait]
 impl NotificationChannelHandler for ConsoleNotificationHandler {
-    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, String> {
+    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, NotificationError> {
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
@@ -177,8 +360,9 @@ impl NotificationChannelHandler for ConsoleNotificationHandler {
 /// 로그 알림 핸들러
 pub struct LogNotificationHandler;
 
+#[async_trait]
 impl NotificationChannelHandler for LogNotificationHandler {
-    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, String> {
+    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, NotificationError> {
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
@@ -209,35 +393,87 @@ impl NotificationChannelHandler for LogNotificationHandler {
     }
 }
 
-/// 이메일 알림 핸들러 (Mock)
+/// 이메일 알림 핸들러 (SMTP)
 pub struct EmailNotificationHandler {
-    smtp_server: String,
-    from_email: String,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Vec<Mailbox>,
 }
 
 impl EmailNotificationHandler {
-    pub fn new(smtp_server: String, from_email: String) -> Self {
-        Self { smtp_server, from_email }
+    /// SMTP 연결 설정과 주소 검증 (연결은 발송 시점에 맺음)
+    pub fn new(config: EmailConfig) -> Result<Self, String> {
+        let builder = match config.tls {
+            SmtpTls::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.smtp_host),
+            SmtpTls::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)
+                .map_err(|e| format!("SMTP TLS 설정 실패: {}", e))?,
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.smtp_host)
+                .map_err(|e| format!("SMTP TLS 설정 실패: {}", e))?,
+        };
+        let mut builder = builder.port(config.smtp_port).timeout(Some(config.timeout));
+        if let (Some(username), Some(password)) = (config.username, config.password) {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        let from = config.from.parse::<Mailbox>().map_err(|e| format!("발신 주소 오류 ({}): {}", config.from, e))?;
+        let to = config
+            .to
+            .iter()
+            .map(|addr| addr.parse::<Mailbox>().map_err(|e| format!("수신 주소 오류 ({}): {}", addr, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { transport: builder.build(), from, to })
+    }
+
+    fn build_email(&self, message: &NotificationMessage) -> Result<Message, NotificationError> {
+        let mut body = format!(
+            "{}\n\n우선순위: {}\n유형: {:?}\n시각: {}\n",
+            message.content,
+            message.priority,
+            message.notification_type,
+            chrono::DateTime::from_timestamp_millis(message.timestamp as i64)
+                .map(|t| t.to_rfc3339())
+                .unwrap_or_default(),
+        );
+        let mut metadata: Vec<_> = message.metadata.iter().collect();
+        metadata.sort();
+        for (key, value) in metadata {
+            body.push_str(&format!("{}: {}\n", key, value));
+        }
+
+        let mut builder = Message::builder()
+            .from(self.from.clone())
+            .subject(format!("[xTrader][{}] {}", message.priority, message.title))
+            .header(ContentType::TEXT_PLAIN);
+        for to in &self.to {
+            builder = builder.to(to.clone());
+        }
+        builder
+            .body(body)
+            .map_err(|e| NotificationError::permanent(format!("이메일 작성 실패: {}", e)))
     }
 }
 
+#[async_trait]
 impl NotificationChannelHandler for EmailNotificationHandler {
-    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, String> {
-        // Mock: 실제로는 SMTP를 통한 이메일 발송
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, NotificationError> {
+        let email = self.build_email(message)?;
+        match self.transport.send(email).await {
+            Ok(_) => {}
+            // 5xx 응답 (수신자 없음, 인증 실패 등)은 재시도해도 같은 결과
+            Err(e) if e.is_permanent() => {
+                return Err(NotificationError::permanent(format!("SMTP 거부: {}", e)));
+            }
+            Err(e) => return Err(NotificationError::transient(format!("SMTP 발송 실패: {}", e))),
+        }
 
-        debug!("📧 이메일 발송: {} -> {}", self.from_email, message.title);
+        debug!("📧 이메일 발송: {} -> {}", self.from, message.title);
 
         Ok(NotificationResult {
             message_id: message.id.clone(),
             success: true,
             error_message: None,
-            sent_at: timestamp,
+            sent_at: now_millis(),
             channel: NotificationChannel::Email,
         })
     }
@@ -247,39 +483,103 @@ impl NotificationChannelHandler for EmailNotificationHandler {
     }
 
     fn is_enabled(&self) -> bool {
-        !self.smtp_server.is_empty()
+        !self.to.is_empty()
     }
 }
 
-/// Slack 알림 핸들러 (Mock)
+/// Slack 알림 핸들러 (수신 웹훅)
 pub struct SlackNotificationHandler {
-    webhook_url: String,
-    channel: String,
+    config: SlackConfig,
+    client: reqwest::Client,
 }
 
 impl SlackNotificationHandler {
-    pub fn new(webhook_url: String, channel: String) -> Self {
-        Self { webhook_url, channel }
+    pub fn new(config: SlackConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .unwrap_or_default();
+        Self { config, client }
+    }
+
+    /// 웹훅 본문 (우선순위별 색상, 메타데이터는 필드로 표시)
+    fn payload(&self, message: &NotificationMessage) -> serde_json::Value {
+        let color = match message.priority {
+            NotificationPriority::Critical => "#d00000",
+            NotificationPriority::High => "#ff9900",
+            NotificationPriority::Normal => "#2eb886",
+            NotificationPriority::Low => "#cccccc",
+        };
+        let mut metadata: Vec<_> = message.metadata.iter().collect();
+        metadata.sort();
+        let fields: Vec<_> = metadata
+            .into_iter()
+            .map(|(key, value)| serde_json::json!({ "title": key, "value": value, "short": true }))
+            .collect();
+
+        let mut payload = serde_json::json!({
+            "text": format!("[{}] {}", message.priority, message.title),
+            "attachments": [{
+                "color": color,
+                "title": message.title,
+                "text": message.content,
+                "fields": fields,
+                "footer": format!("xTrader · {:?}", message.notification_type),
+                "ts": message.timestamp / 1000,
+            }],
+        });
+        if let Some(channel) = &self.config.channel {
+            payload["channel"] = serde_json::json!(channel);
+        }
+        if let Some(username) = &self.config.username {
+            payload["username"] = serde_json::json!(username);
+        }
+        payload
     }
 }
 
+#[async_trait]
 impl NotificationChannelHandler for SlackNotificationHandler {
-    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, String> {
-        // Mock: 실제로는 Slack Webhook API 호출
-        tokio::time::sleep(Duration::from_millis(200)).await;
-        
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, NotificationError> {
+        let response = self
+            .client
+            .post(&self.config.webhook_url)
+            .json(&self.payload(message))
+            .send()
+            .await
+            .map_err(|e| NotificationError::transient(format!("Slack 요청 실패: {}", e)))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok())
+                .map(Duration::from_secs);
+            return Err(NotificationError {
+                retry_after,
+                ..NotificationError::transient("Slack 요청 한도 초과 (429)")
+            });
+        }
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            let error_message = format!("Slack 응답 {}: {}", status, body);
+            // 4xx (invalid_payload, channel_not_found 등)는 설정 오류
+            return Err(if status.is_server_error() {
+                NotificationError::transient(error_message)
+            } else {
+                NotificationError::permanent(error_message)
+            });
+        }
 
-        debug!("💬 Slack 발송: #{} - {}", self.channel, message.title);
+        debug!("💬 Slack 발송: {}", message.title);
 
         Ok(NotificationResult {
             message_id: message.id.clone(),
             success: true,
             error_message: None,
-            sent_at: timestamp,
+            sent_at: now_millis(),
             channel: NotificationChannel::Slack,
         })
     }
@@ -289,7 +589,7 @@ impl NotificationChannelHandler for SlackNotificationHandler {
     }
 
     fn is_enabled(&self) -> bool {
-        !self.webhook_url.is_empty()
+        !self.config.webhook_url.is_empty()
     }
 }
 
@@ -304,8 +604,9 @@ impl WebhookNotificationHandler {
     }
 }
 
+#[async_trait]
 impl NotificationChannelHandler for WebhookNotificationHandler {
-    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, String> {
+    async fn send(&self, message: &NotificationMessage) -> Result<NotificationResult, NotificationError> {
         // Mock: 실제로는 HTTP POST 요청
         tokio::time::sleep(Duration::from_millis(150)).await;
         
@@ -393,11 +694,11 @@ impl Deduplicator {
 
         let key = format!("{}:{}:{}", message.title, message.content, message.channel);
         
-        if self.messages.contains_key(&key) {
-            true
-        } else {
-            self.messages.insert(key, now);
+        if let std::collections::hash_map::Entry::Vacant(e) = self.messages.entry(key) {
+            e.insert(now);
             false
+        } else {
+            true
         }
     }
 }
@@ -405,6 +706,7 @@ impl Deduplicator {
 impl NotificationSystem {
     /// 새 알림 시스템 생성
     pub fn new(config: NotificationConfig) -> Self {
+        let rate_limit_per_minute = config.rate_limit_per_minute;
         let mut system = Self {
             config,
             message_queue: Arc::new(Mutex::new(Vec::new())),
@@ -420,8 +722,11 @@ impl NotificationSystem {
             })),
             is_running: Arc::new(Mutex::new(false)),
             channel_handlers: Arc::new(RwLock::new(HashMap::new())),
-            rate_limiter: Arc::new(Mutex::new(RateLimiter::new(100, 60000))), // 1분에 100개
+            rate_limiter: Arc::new(Mutex::new(RateLimiter::new(rate_limit_per_minute, 60000))), // 1분 윈도우
             deduplicator: Arc::new(Mutex::new(Deduplicator::new(60000))), // 1분 윈도우
+            routing_rules: Arc::new(RwLock::new(Vec::new())),
+            escalations: Arc::new(Mutex::new(HashMap::new())),
+            incidents: None,
         };
 
         // 기본 핸들러 등록
@@ -429,6 +734,12 @@ impl NotificationSystem {
         system
     }
 
+    /// 확인이 필요한 알림을 인시던트로 기록
+    pub fn with_incident_tracker(mut self, incidents: Arc<IncidentTracker>) -> Self {
+        self.incidents = Some(incidents);
+        self
+    }
+
     /// 기본 핸들러 등록
     fn register_default_handlers(&mut self) {
         let mut handlers = self.channel_handlers.try_write().unwrap();
@@ -442,6 +753,22 @@ impl NotificationSystem {
             NotificationChannel::Log,
             Arc::new(LogNotificationHandler),
         );
+
+        if let Some(slack) = self.config.slack.clone() {
+            handlers.insert(
+                NotificationChannel::Slack,
+                Arc::new(SlackNotificationHandler::new(slack)),
+            );
+        }
+
+        if let Some(email) = self.config.email.clone() {
+            match EmailNotificationHandler::new(email) {
+                Ok(handler) => {
+                    handlers.insert(NotificationChannel::Email, Arc::new(handler));
+                }
+                Err(e) => error!("이메일 알림 설정 오류: {}", e),
+            }
+        }
     }
 
     /// 알림 시스템 시작
@@ -460,9 +787,10 @@ impl NotificationSystem {
         let stats = self.stats.clone();
         let channel_handlers = self.channel_handlers.clone();
         let rate_limiter = self.rate_limiter.clone();
-        let deduplicator = self.deduplicator.clone();
         let config = self.config.clone();
         let is_running = self.is_running.clone();
+        let escalations = self.escalations.clone();
+        let incidents = self.incidents.clone();
 
         // 알림 처리 태스크
         tokio::spawn(async move {
@@ -479,13 +807,15 @@ impl NotificationSystem {
                     }
                 }
 
+                // 확인되지 않은 알림 에스컬레이션
+                Self::process_escalations(&escalations, &message_queue, incidents.as_deref()).await;
+
                 // 배치 알림 처리
                 Self::process_notification_batch(
                     &message_queue,
                     &stats,
                     &channel_handlers,
                     &rate_limiter,
-                    &deduplicator,
                     &config,
                 ).await;
             }
@@ -509,6 +839,20 @@ impl NotificationSystem {
         channel: NotificationChannel,
         priority: NotificationPriority,
         notification_type: NotificationType,
+    ) -> Result<String, String> {
+        self.send_notification_with_metadata(title, content, channel, priority, notification_type, HashMap::new())
+            .await
+    }
+
+    /// 메타데이터를 포함한 알림 발송 (구조화된 알림)
+    pub async fn send_notification_with_metadata(
+        &self,
+        title: String,
+        content: String,
+        channel: NotificationChannel,
+        priority: NotificationPriority,
+        notification_type: NotificationType,
+        metadata: HashMap<String, String>,
     ) -> Result<String, String> {
         let message_id = uuid::Uuid::new_v4().to_string();
         let timestamp = SystemTime::now()
@@ -526,7 +870,8 @@ impl NotificationSystem {
             timestamp,
             retry_count: 0,
             max_retries: self.config.retry_attempts,
-            metadata: HashMap::new(),
+            metadata,
+            next_attempt_at: 0,
         };
 
         // 중복 체크
@@ -545,34 +890,192 @@ impl NotificationSystem {
             }
         }
 
+        // 라우팅 규칙 적용
+        let decision = {
+            let rules = self.routing_rules.read().await;
+            notification_routing::route(&rules, &message, timestamp)
+        };
+
+        // 확인이 필요한 알림은 인시던트로 기록 (조용한 시간에 발송을 생략해도 기록)
+        if let Some(incidents) = &self.incidents {
+            let escalates = decision.as_ref().is_some_and(|d| d.escalation.is_some());
+            if incidents.should_track(&message, escalates) {
+                if let Err(e) = incidents.open(&message).await {
+                    error!("인시던트 기록 실패: {} - {}", message_id, e);
+                }
+            }
+        }
+        let messages = match decision {
+            None => vec![message],
+            Some(decision) if decision.channels.is_empty() => {
+                info!(
+                    "조용한 시간으로 알림 발송 생략: {} (규칙: {})",
+                    message.title,
+                    decision.quieted_rules.join(", ")
+                );
+                return Ok(message_id);
+            }
+            Some(decision) => {
+                if let Some((rule_id, policy)) = decision.escalation {
+                    let pending = PendingEscalation {
+                        notification_id: message_id.clone(),
+                        rule_id,
+                        title: message.title.clone(),
+                        priority: message.priority.clone(),
+                        created_at: timestamp,
+                        due_at: timestamp + policy.after_secs * 1000,
+                        after_secs: policy.after_secs,
+                        escalation_count: 0,
+                        channels: policy.channels,
+                    };
+                    let mut escalations = self.escalations.lock().await;
+                    escalations.insert(message_id.clone(), (pending, message.clone()));
+                }
+                decision
+                    .channels
+                    .into_iter()
+                    .map(|channel| NotificationMessage { channel, ..message.clone() })
+                    .collect()
+            }
+        };
+
         // 큐에 추가
         {
             let mut queue = self.message_queue.lock().await;
-            queue.push(message);
+            queue.extend(messages);
         }
 
         Ok(message_id)
     }
 
+    /// 알림 확인 (대기 중인 에스컬레이션 취소)
+    pub async fn acknowledge(&self, notification_id: &str, acknowledged_by: &str) -> Option<PendingEscalation> {
+        let mut escalations = self.escalations.lock().await;
+        let (pending, _) = escalations.remove(notification_id)?;
+        info!("알림 확인: {} (확인자: {})", notification_id, acknowledged_by);
+        Some(pending)
+    }
+
+    /// 확인을 기다리는 에스컬레이션 목록
+    pub async fn pending_escalations(&self) -> Vec<PendingEscalation> {
+        let escalations = self.escalations.lock().await;
+        let mut pending: Vec<PendingEscalation> = escalations.values().map(|(pending, _)| pending.clone()).collect();
+        pending.sort_by_key(|pending| pending.due_at);
+        pending
+    }
+
+    /// 라우팅 규칙 목록
+    pub async fn routing_rules(&self) -> Vec<RoutingRule> {
+        self.routing_rules.read().await.clone()
+    }
+
+    /// 라우팅 규칙 전체 교체 (시작 시 DB에서 로드)
+    pub async fn set_routing_rules(&self, rules: Vec<RoutingRule>) -> Result<(), RoutingRuleError> {
+        for rule in &rules {
+            rule.validate()?;
+        }
+        *self.routing_rules.write().await = rules;
+        Ok(())
+    }
+
+    /// 라우팅 규칙 추가 또는 교체
+    pub async fn upsert_routing_rule(&self, rule: RoutingRule) -> Result<(), RoutingRuleError> {
+        rule.validate()?;
+        let mut rules = self.routing_rules.write().await;
+        match rules.iter_mut().find(|existing| existing.id == rule.id) {
+            Some(existing) => *existing = rule,
+            None => rules.push(rule),
+        }
+        Ok(())
+    }
+
+    /// 라우팅 규칙 삭제
+    pub async fn remove_routing_rule(&self, rule_id: &str) -> bool {
+        let mut rules = self.routing_rules.write().await;
+        let before = rules.len();
+        rules.retain(|rule| rule.id != rule_id);
+        rules.len() != before
+    }
+
+    /// 예정 시각이 지난 에스컬레이션을 큐에 추가
+    ///
+    /// Critical 알림은 확인될 때까지 `after_secs` 간격으로 계속 에스컬레이션합니다.
+    async fn process_escalations(
+        escalations: &Arc<Mutex<HashMap<String, (PendingEscalation, NotificationMessage)>>>,
+        message_queue: &Arc<Mutex<Vec<NotificationMessage>>>,
+        incidents: Option<&IncidentTracker>,
+    ) {
+        let now = now_millis();
+        let due: Vec<(PendingEscalation, NotificationMessage)> = {
+            let mut escalations = escalations.lock().await;
+            let due_ids: Vec<String> = escalations
+                .iter()
+                .filter(|(_, (pending, _))| pending.due_at <= now)
+                .map(|(id, _)| id.clone())
+                .collect();
+            due_ids.iter().filter_map(|id| escalations.remove(id)).collect()
+        };
+        if due.is_empty() {
+            return;
+        }
+
+        for (mut pending, message) in due {
+            pending.escalation_count += 1;
+            warn!(
+                "알림 {} 미확인 → {}차 에스컬레이션 (규칙: {}, 채널: {:?})",
+                pending.notification_id, pending.escalation_count, pending.rule_id, pending.channels
+            );
+            {
+                let mut queue = message_queue.lock().await;
+                for channel in &pending.channels {
+                    queue.push(NotificationMessage {
+                        channel: channel.clone(),
+                        title: format!("[에스컬레이션] {}", message.title),
+                        retry_count: 0,
+                        next_attempt_at: 0,
+                        ..message.clone()
+                    });
+                }
+            }
+            if let Some(incidents) = incidents {
+                if let Err(e) = incidents.record_escalation(&pending.notification_id).await {
+                    error!("인시던트 에스컬레이션 기록 실패: {} - {}", pending.notification_id, e);
+                }
+            }
+
+            if pending.priority == NotificationPriority::Critical {
+                pending.due_at = now + pending.after_secs * 1000;
+                let mut escalations = escalations.lock().await;
+                escalations.insert(pending.notification_id.clone(), (pending, message));
+            }
+        }
+    }
+
+    /// 예정 시각이 지난 에스컬레이션 처리 (처리 루프 밖에서 호출)
+    #[cfg(test)]
+    async fn escalate_due(&self) {
+        Self::process_escalations(&self.escalations, &self.message_queue, self.incidents.as_deref()).await;
+    }
+
     /// 배치 알림 처리
     async fn process_notification_batch(
         message_queue: &Arc<Mutex<Vec<NotificationMessage>>>,
         stats: &Arc<RwLock<NotificationStats>>,
         channel_handlers: &Arc<RwLock<HashMap<NotificationChannel, Arc<dyn NotificationChannelHandler + Send + Sync>>>>,
         rate_limiter: &Arc<Mutex<RateLimiter>>,
-        deduplicator: &Arc<Mutex<Deduplicator>>,
         config: &NotificationConfig,
     ) {
-        // 배치 메시지 수집
+        // 배치 메시지 수집 (재시도 대기 중인 메시지는 남겨 둠)
+        let now = now_millis();
         let batch_messages = {
             let mut queue = message_queue.lock().await;
             let mut batch = Vec::new();
-            
-            for _ in 0..config.batch_size {
-                if let Some(message) = queue.pop() {
-                    batch.push(message);
-                } else {
-                    break;
+            let mut index = queue.len();
+
+            while index > 0 && batch.len() < config.batch_size {
+                index -= 1;
+                if queue[index].next_attempt_at <= now {
+                    batch.push(queue.remove(index));
                 }
             }
             
@@ -597,48 +1100,66 @@ impl NotificationSystem {
                 }
             }
 
-            // 중복 체크
-            if config.enable_deduplication {
-                let mut deduplicator = deduplicator.lock().await;
-                if deduplicator.is_duplicate(&message) {
-                    continue;
-                }
-            }
+            // 중복은 큐에 넣을 때 걸러지므로 여기서는 재시도 메시지를 다시 검사하지 않음
 
             // 채널 핸들러 찾기
             if let Some(handler) = handlers.get(&message.channel) {
                 if handler.is_enabled() {
-                    match handler.send(&message).await {
+                    let started = std::time::Instant::now();
+                    let outcome = handler.send(&message).await;
+                    let elapsed_ms = started.elapsed().as_millis() as u64;
+
+                    match outcome {
                         Ok(result) => {
-                            Self::update_stats_on_success(stats, &message, &result).await;
+                            Self::update_stats_on_success(stats, &message, &result, elapsed_ms).await;
                             debug!("알림 발송 성공: {} -> {}", message.title, message.channel);
                         }
                         Err(e) => {
-                            Self::update_stats_on_failure(stats, &message, &e).await;
+                            let consecutive_failures =
+                                Self::update_stats_on_failure(stats, &message, &e.message, elapsed_ms).await;
                             error!("알림 발송 실패: {} - {}", message.title, e);
                             
-                            // 재시도 로직
-                            if message.retry_count < message.max_retries {
+                            // 재시도 로직 (영구 오류는 재시도하지 않고, 채널 연속 실패가 길수록 오래 대기)
+                            if !e.permanent && message.retry_count < message.max_retries {
+                                let delay = e
+                                    .retry_after
+                                    .unwrap_or_else(|| Self::retry_delay(config, consecutive_failures));
                                 let mut retry_message = message.clone();
                                 retry_message.retry_count += 1;
+                                retry_message.next_attempt_at = now_millis() + delay.as_millis() as u64;
                                 
                                 let mut queue = message_queue.lock().await;
-                                queue.push(retry_message);
+                                queue.insert(0, retry_message);
                             }
                         }
                     }
                 }
+            } else {
+                warn!("등록된 핸들러가 없는 채널, 알림 버림: {} -> {}", message.title, message.channel);
             }
         }
     }
 
+    /// 재시도 대기 시간 (채널 연속 실패 횟수에 따라 지수 증가)
+    fn retry_delay(config: &NotificationConfig, consecutive_failures: u32) -> Duration {
+        let exponent = consecutive_failures.saturating_sub(1).min(16);
+        Duration::from_millis(
+            config
+                .retry_delay_ms
+                .saturating_mul(1 << exponent)
+                .min(config.max_retry_delay_ms),
+        )
+    }
+
     /// 성공 통계 업데이트
     async fn update_stats_on_success(
         stats: &Arc<RwLock<NotificationStats>>,
         message: &NotificationMessage,
-        result: &NotificationResult,
+        _result: &NotificationResult,
+        elapsed_ms: u64,
     ) {
-        let mut stats_guard = stats.write().await;
+        let mut guard = stats.write().await;
+        let stats_guard = &mut *guard;
         stats_guard.total_sent += 1;
         stats_guard.last_24h_sent += 1;
 
@@ -650,8 +1171,15 @@ impl NotificationSystem {
             failed: 0,
             success_rate: 0.0,
             average_response_time_ms: 0,
+            consecutive_failures: 0,
+            last_error: None,
+            last_failure_at: None,
         });
+        let attempts = channel_stats.sent + channel_stats.failed;
+        channel_stats.average_response_time_ms =
+            (channel_stats.average_response_time_ms * attempts + elapsed_ms) / (attempts + 1);
         channel_stats.sent += 1;
+        channel_stats.consecutive_failures = 0;
 
         // 우선순위별 통계 업데이트
         let priority_key = Self::priority_to_string(&message.priority);
@@ -678,13 +1206,15 @@ impl NotificationSystem {
         };
     }
 
-    /// 실패 통계 업데이트
+    /// 실패 통계 업데이트 (채널 연속 실패 횟수 반환)
     async fn update_stats_on_failure(
         stats: &Arc<RwLock<NotificationStats>>,
         message: &NotificationMessage,
         error: &str,
-    ) {
-        let mut stats_guard = stats.write().await;
+        elapsed_ms: u64,
+    ) -> u32 {
+        let mut guard = stats.write().await;
+        let stats_guard = &mut *guard;
         stats_guard.total_failed += 1;
         stats_guard.last_24h_failed += 1;
 
@@ -696,8 +1226,19 @@ impl NotificationSystem {
             failed: 0,
             success_rate: 0.0,
             average_response_time_ms: 0,
+            consecutive_failures: 0,
+            last_error: None,
+            last_failure_at: None,
         });
+        let attempts = channel_stats.sent + channel_stats.failed;
+        channel_stats.average_response_time_ms =
+            (channel_stats.average_response_time_ms * attempts + elapsed_ms) / (attempts + 1);
         channel_stats.failed += 1;
+        channel_stats.consecutive_failures += 1;
+        channel_stats.last_error = Some(error.to_string());
+        channel_stats.last_failure_at = Some(now_millis());
+        let consecutive_failures = channel_stats.consecutive_failures;
+        channel_stats.success_rate = channel_stats.sent as f64 / (channel_stats.sent + channel_stats.failed) as f64;
 
         // 성공률 계산
         let total = stats_guard.total_sent + stats_guard.total_failed;
@@ -706,6 +1247,8 @@ impl NotificationSystem {
         } else {
             0.0
         };
+
+        consecutive_failures
     }
 
     /// 채널 핸들러 등록
@@ -758,6 +1301,7 @@ impl NotificationSystem {
             NotificationType::StatusChange => "StatusChange".to_string(),
             NotificationType::Maintenance => "Maintenance".to_string(),
             NotificationType::SecurityAlert => "SecurityAlert".to_string(),
+            NotificationType::ArbitrageAlert => "ArbitrageAlert".to_string(),
             NotificationType::Custom => "Custom".to_string(),
         }
     }
@@ -766,6 +1310,7 @@ impl NotificationSystem {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[tokio::test]
     async fn test_notification_system_creation() {
@@ -870,4 +1415,214 @@ mod tests {
         ).await;
         assert!(result2.is_err());
     }
-}
\ No newline at end of file
+
+    /// 항상 실패하는 핸들러 (호출 횟수 기록)
+    struct FailingHandler {
+        permanent: bool,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl NotificationChannelHandler for FailingHandler {
+        async fn send(&self, _message: &NotificationMessage) -> Result<NotificationResult, NotificationError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.permanent {
+                Err(NotificationError::permanent("channel_not_found"))
+            } else {
+                Err(NotificationError::transient("connection reset"))
+            }
+        }
+
+        fn get_channel(&self) -> NotificationChannel {
+            NotificationChannel::Webhook
+        }
+
+        fn is_enabled(&self) -> bool {
+            true
+        }
+    }
+
+    async fn run_failing(permanent: bool) -> (usize, NotificationStats) {
+        let config = NotificationConfig {
+            retry_attempts: 2,
+            retry_delay_ms: 10,
+            batch_timeout_ms: 5,
+            ..Default::default()
+        };
+        let system = NotificationSystem::new(config);
+        let handler = Arc::new(FailingHandler { permanent, calls: AtomicUsize::new(0) });
+        system.register_handler(handler.clone()).await;
+        system.start().await;
+
+        system
+            .send_notification(
+                "재시도 테스트".to_string(),
+                "내용".to_string(),
+                NotificationChannel::Webhook,
+                NotificationPriority::High,
+                NotificationType::ErrorAlert,
+            )
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        system.stop().await;
+
+        (handler.calls.load(Ordering::SeqCst), system.get_stats().await)
+    }
+
+    #[tokio::test]
+    async fn test_transient_failures_retried_with_metrics() {
+        let (calls, stats) = run_failing(false).await;
+        assert_eq!(calls, 3); // 첫 시도 + 재시도 2회
+
+        let webhook = &stats.channel_stats["Webhook"];
+        assert_eq!(webhook.failed, 3);
+        assert_eq!(webhook.consecutive_failures, 3);
+        assert_eq!(webhook.last_error.as_deref(), Some("connection reset"));
+
+        let (calls, stats) = run_failing(true).await;
+        assert_eq!(calls, 1);
+        assert_eq!(stats.total_failed, 1);
+    }
+
+    fn critical_rule() -> RoutingRule {
+        RoutingRule {
+            id: "critical-oncall".to_string(),
+            name: "긴급 알림 당직".to_string(),
+            enabled: true,
+            notification_types: Vec::new(),
+            min_priority: Some(NotificationPriority::Critical),
+            sources: Vec::new(),
+            channels: vec![NotificationChannel::Slack, NotificationChannel::Log],
+            quiet_hours: None,
+            escalation: Some(notification_routing::EscalationPolicy {
+                after_secs: 300,
+                channels: vec![NotificationChannel::SMS],
+                min_priority: NotificationPriority::Critical,
+            }),
+        }
+    }
+
+    async fn send_critical(system: &NotificationSystem, title: &str) -> String {
+        system
+            .send_notification(
+                title.to_string(),
+                "매칭 엔진 응답 없음".to_string(),
+                NotificationChannel::Console,
+                NotificationPriority::Critical,
+                NotificationType::ErrorAlert,
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_routing_fans_out_and_escalates_unacknowledged() {
+        let system = NotificationSystem::new(NotificationConfig::default());
+        system.upsert_routing_rule(critical_rule()).await.unwrap();
+
+        let id = send_critical(&system, "엔진 장애").await;
+        {
+            let queue = system.message_queue.lock().await;
+            let channels: Vec<_> = queue.iter().map(|m| m.channel.clone()).collect();
+            assert_eq!(channels, vec![NotificationChannel::Slack, NotificationChannel::Log]);
+            assert!(queue.iter().all(|m| m.id == id));
+        }
+
+        // 5분이 지나기 전에는 에스컬레이션하지 않음
+        system.escalate_due().await;
+        assert_eq!(system.queue_size().await, 2);
+
+        // 예정 시각 경과 → SMS로 재발송
+        system.escalations.lock().await.get_mut(&id).unwrap().0.due_at = 0;
+        system.escalate_due().await;
+        {
+            let queue = system.message_queue.lock().await;
+            let escalated = queue.last().unwrap();
+            assert_eq!(escalated.channel, NotificationChannel::SMS);
+            assert!(escalated.title.starts_with("[에스컬레이션]"));
+        }
+
+        // Critical 알림은 확인될 때까지 다시 에스컬레이션 예약
+        let pending = system.pending_escalations().await;
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].escalation_count, 1);
+        assert!(pending[0].due_at > now_millis());
+    }
+
+    #[tokio::test]
+    async fn test_acknowledged_alert_is_not_escalated() {
+        let system = NotificationSystem::new(NotificationConfig::default());
+        system.upsert_routing_rule(critical_rule()).await.unwrap();
+
+        let id = send_critical(&system, "엔진 장애").await;
+        assert_eq!(system.pending_escalations().await.len(), 1);
+        assert!(system.acknowledge(&id, "oncall").await.is_some());
+        assert!(system.acknowledge(&id, "oncall").await.is_none());
+
+        system.escalate_due().await;
+        assert_eq!(system.queue_size().await, 2);
+
+        // 규칙 삭제 후에는 요청 채널로 그대로 발송
+        assert!(system.remove_routing_rule("critical-oncall").await);
+        send_critical(&system, "엔진 장애 재발").await;
+        let queue = system.message_queue.lock().await;
+        assert_eq!(queue.last().unwrap().channel, NotificationChannel::Console);
+    }
+
+    #[test]
+    fn test_retry_delay_grows_with_consecutive_failures() {
+        let config = NotificationConfig {
+            retry_delay_ms: 1000,
+            max_retry_delay_ms: 5000,
+            ..Default::default()
+        };
+        assert_eq!(NotificationSystem::retry_delay(&config, 1), Duration::from_millis(1000));
+        assert_eq!(NotificationSystem::retry_delay(&config, 3), Duration::from_millis(4000));
+        assert_eq!(NotificationSystem::retry_delay(&config, 10), Duration::from_millis(5000));
+    }
+
+    #[test]
+    fn test_slack_payload_and_email_config() {
+        let handler = SlackNotificationHandler::new(SlackConfig {
+            channel: Some("#alerts".to_string()),
+            ..SlackConfig::new("https://hooks.slack.com/services/T000/B000/XXXX")
+        });
+        let message = NotificationMessage {
+            id: "m1".to_string(),
+            title: "차익거래 기회".to_string(),
+            content: "BTC-KRW 0.7%".to_string(),
+            channel: NotificationChannel::Slack,
+            priority: NotificationPriority::Critical,
+            notification_type: NotificationType::ArbitrageAlert,
+            timestamp: 1_700_000_000_000,
+            retry_count: 0,
+            max_retries: 3,
+            metadata: HashMap::from([("symbol".to_string(), "BTC-KRW".to_string())]),
+            next_attempt_at: 0,
+        };
+        let payload = handler.payload(&message);
+        assert_eq!(payload["channel"], "#alerts");
+        assert_eq!(payload["attachments"][0]["color"], "#d00000");
+        assert_eq!(payload["attachments"][0]["fields"][0]["value"], "BTC-KRW");
+        assert_eq!(payload["attachments"][0]["ts"], 1_700_000_000);
+
+        let email = EmailConfig {
+            smtp_host: "smtp.example.com".to_string(),
+            smtp_port: 587,
+            tls: SmtpTls::StartTls,
+            username: Some("alerts".to_string()),
+            password: Some("secret".to_string()),
+            from: "xTrader <alerts@example.com>".to_string(),
+            to: vec!["ops@example.com".to_string()],
+            timeout: Duration::from_secs(5),
+        };
+        let handler = EmailNotificationHandler::new(email.clone()).unwrap();
+        let built = handler.build_email(&message).unwrap();
+        let raw = String::from_utf8(built.formatted()).unwrap();
+        assert!(raw.contains("To: ops@example.com"));
+
+        let invalid = EmailConfig { to: vec!["not an address".to_string()], ..email };
+        assert!(EmailNotificationHandler::new(invalid).is_err());
+    }
+}
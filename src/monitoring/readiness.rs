@@ -0,0 +1,293 @@
+//! 준비 상태(readiness) 점검
+//!
+//! 오케스트레이터(Kubernetes 등)가 트래픽을 보내도 되는지 판단하는 `/readyz` 의 점검 항목입니다.
+//! DB 상태는 [`SystemHealthMonitor`] 의 최근 결과를 쓰고, 엔진은 프로브 채널로 직접 확인하며,
+//! 시퀀서 큐는 게이지 사용률로 판단합니다. 복제 상태가 설정되면 대기(standby) 인스턴스는
+//! 주문을 받지 않으므로 승격 전까지 준비 안 됨으로 보고합니다.
+
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use utoipa::ToSchema;
+
+use super::health_probes::{EngineProbe, ProbeFailure, ProbeFailureKind};
+use super::system_health::{HealthStatus, ServiceHealth, SystemHealthMonitor};
+use crate::matching_engine::engine::EngineHeartbeat;
+use crate::sequencer::backpressure::SequencerQueueMetrics;
+use crate::sequencer::replication::{ReplicationRole, ReplicationState};
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
+/// 준비 상태 점검 설정
+#[derive(Debug, Clone)]
+pub struct ReadinessConfig {
+    /// 시퀀서 큐 사용률 상한 (이상이면 준비 안 됨)
+    pub max_queue_utilization: f64,
+    /// 엔진 하트비트 응답 제한 시간
+    pub engine_timeout_ms: u64,
+    /// 헬스체크 결과를 유효하다고 볼 최대 경과 시간
+    pub max_health_age_ms: u64,
+}
+
+impl Default for ReadinessConfig {
+    fn default() -> Self {
+        Self {
+            max_queue_utilization: 0.9,
+            engine_timeout_ms: 1000,  // 1초
+            max_health_age_ms: 15_000, // 헬스체크 3주기
+        }
+    }
+}
+
+/// 점검 항목 결과
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReadinessCheck {
+    /// 항목 이름 (database, matching_engine, sequencer_queue, order_book, replication)
+    pub name: String,
+    pub ok: bool,
+    pub detail: String,
+}
+
+impl ReadinessCheck {
+    fn new(name: &str, ok: bool, detail: impl Into<String>) -> Self {
+        Self { name: name.to_string(), ok, detail: detail.into() }
+    }
+}
+
+/// 준비 상태 점검 결과
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReadinessReport {
+    /// 모든 항목 통과 여부
+    pub ready: bool,
+    pub checks: Vec<ReadinessCheck>,
+    pub timestamp: u64,
+}
+
+/// 준비 상태 점검기
+pub struct ReadinessChecker {
+    config: ReadinessConfig,
+    health_monitor: Arc<SystemHealthMonitor>,
+    engine_probe: EngineProbe,
+    queue_metrics: SequencerQueueMetrics,
+    expected_symbols: usize,
+    replication: Option<Arc<ReplicationState>>,
+}
+
+impl ReadinessChecker {
+    pub fn new(
+        config: ReadinessConfig,
+        health_monitor: Arc<SystemHealthMonitor>,
+        engine_probe: EngineProbe,
+        queue_metrics: SequencerQueueMetrics,
+        expected_symbols: usize,
+    ) -> Self {
+        Self {
+            config,
+            health_monitor,
+            engine_probe,
+            queue_metrics,
+            expected_symbols,
+            replication: None,
+        }
+    }
+
+    /// 복제 역할 점검 추가 (대기 인스턴스는 준비 안 됨)
+    pub fn with_replication(mut self, replication: Arc<ReplicationState>) -> Self {
+        self.replication = Some(replication);
+        self
+    }
+
+    /// 전체 항목 점검
+    pub async fn check(&self) -> ReadinessReport {
+        let now = now_millis();
+        let database = self.health_monitor.get_service_health("Database").await;
+        let heartbeat = match tokio::time::timeout(
+            Duration::from_millis(self.config.engine_timeout_ms),
+            self.engine_probe.heartbeat(),
+        )
+        .await
+        {
+            Ok(result) => result,
+            Err(_) => Err(ProbeFailure::new(
+                ProbeFailureKind::Timeout,
+                format!("{}ms 내 응답 없음", self.config.engine_timeout_ms),
+            )),
+        };
+
+        let mut checks = vec![
+            Self::check_database(database.as_ref(), now, self.config.max_health_age_ms),
+            Self::check_engine(&heartbeat),
+            self.check_queues(),
+            Self::check_order_book(&heartbeat, self.expected_symbols),
+        ];
+        if let Some(replication) = &self.replication {
+            checks.push(Self::check_replication(replication));
+        }
+
+        ReadinessReport {
+            ready: checks.iter().all(|check| check.ok),
+            checks,
+            timestamp: now,
+        }
+    }
+
+    /// DB: 헬스체크 모니터의 최근 결과가 있고, 오래되지 않았으며, 위험 상태가 아님
+    fn check_database(health: Option<&ServiceHealth>, now: u64, max_age_ms: u64) -> ReadinessCheck {
+        let Some(health) = health else {
+            return ReadinessCheck::new("database", false, "헬스체크 결과 없음");
+        };
+
+        let age_ms = now.saturating_sub(health.last_check);
+        if age_ms > max_age_ms {
+            return ReadinessCheck::new("database", false, format!("헬스체크 결과가 {}ms 전 것입니다", age_ms));
+        }
+        let ok = health.status != HealthStatus::Critical && health.status != HealthStatus::Unknown;
+        ReadinessCheck::new("database", ok, format!("{:?}: {}", health.status, health.message))
+    }
+
+    /// 엔진: 주문 처리 루프가 하트비트에 응답
+    fn check_engine(heartbeat: &Result<EngineHeartbeat, ProbeFailure>) -> ReadinessCheck {
+        match heartbeat {
+            Ok(heartbeat) => ReadinessCheck::new(
+                "matching_engine",
+                true,
+                format!("주문 처리 루프 응답 (보관 주문 {}건)", heartbeat.open_orders),
+            ),
+            Err(failure) => ReadinessCheck::new("matching_engine", false, failure.to_string()),
+        }
+    }
+
+    /// 시퀀서 큐: 모든 큐 사용률이 상한 미만
+    fn check_queues(&self) -> ReadinessCheck {
+        let saturated: Vec<String> = self
+            .queue_metrics
+            .gauges()
+            .iter()
+            .filter(|gauge| gauge.capacity() > 0)
+            .filter_map(|gauge| {
+                let utilization = gauge.depth() as f64 / gauge.capacity() as f64;
+                (utilization >= self.config.max_queue_utilization)
+                    .then(|| format!("{} {}/{}", gauge.name(), gauge.depth(), gauge.capacity()))
+            })
+            .collect();
+
+        if saturated.is_empty() {
+            ReadinessCheck::new(
+                "sequencer_queue",
+                true,
+                format!("큐 {}개 사용률 {:.0}% 미만", self.queue_metrics.gauges().len(), self.config.max_queue_utilization * 100.0),
+            )
+        } else {
+            ReadinessCheck::new("sequencer_queue", false, format!("포화: {}", saturated.join(", ")))
+        }
+    }
+
+    /// 주문장: 설정된 심볼의 주문장이 엔진에 모두 로드됨
+    fn check_order_book(heartbeat: &Result<EngineHeartbeat, ProbeFailure>, expected_symbols: usize) -> ReadinessCheck {
+        match heartbeat {
+            Ok(heartbeat) => ReadinessCheck::new(
+                "order_book",
+                heartbeat.symbols >= expected_symbols,
+                format!("주문장 {}/{}개 로드", heartbeat.symbols, expected_symbols),
+            ),
+            Err(_) => ReadinessCheck::new("order_book", false, "엔진 응답이 없어 확인할 수 없음"),
+        }
+    }
+
+    /// 복제: 주 인스턴스만 주문을 받음
+    fn check_replication(replication: &ReplicationState) -> ReadinessCheck {
+        let status = replication.status();
+        match status.role {
+            ReplicationRole::Primary => {
+                ReadinessCheck::new("replication", true, format!("주 인스턴스 (저널 순번 {})", status.applied_seq))
+            }
+            ReplicationRole::Standby => ReadinessCheck::new(
+                "replication",
+                false,
+                format!("대기 인스턴스 (적용 순번 {}, 지연 {}건)", status.applied_seq, status.lag_entries),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching_engine::engine::EngineProbeRequest;
+    use crate::monitoring::system_health::{HealthCheckConfig, ServiceType};
+    use crate::sequencer::backpressure::{bounded_queue, OverflowPolicy};
+    use std::sync::mpsc;
+
+    fn database_health(status: HealthStatus, last_check: u64) -> ServiceHealth {
+        ServiceHealth {
+            service_type: ServiceType::Database,
+            status,
+            message: "SELECT 1 정상".to_string(),
+            response_time_ms: 1,
+            last_check,
+            consecutive_failures: 0,
+            uptime_percent: 100.0,
+            error_rate: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_database_check_requires_fresh_result() {
+        let now = 100_000;
+        assert!(!ReadinessChecker::check_database(None, now, 15_000).ok);
+        assert!(ReadinessChecker::check_database(Some(&database_health(HealthStatus::Warning, now - 1_000)), now, 15_000).ok);
+        assert!(!ReadinessChecker::check_database(Some(&database_health(HealthStatus::Critical, now)), now, 15_000).ok);
+        assert!(!ReadinessChecker::check_database(Some(&database_health(HealthStatus::Healthy, now - 20_000)), now, 15_000).ok);
+    }
+
+    #[tokio::test]
+    async fn test_not_ready_without_engine_and_with_full_queue() {
+        let monitor = Arc::new(SystemHealthMonitor::new(HealthCheckConfig::default(), |_, _| Ok(())));
+        let (order_tx, _order_rx) = bounded_queue::<u64>("orders", 2, OverflowPolicy::Reject);
+        order_tx.send(1).unwrap();
+        order_tx.send(2).unwrap();
+        let mut queue_metrics = SequencerQueueMetrics::new();
+        queue_metrics.register(order_tx.gauge());
+
+        // 엔진 쪽 수신자가 없으면 하트비트 실패
+        let (probe_tx, probe_rx) = mpsc::channel::<EngineProbeRequest>();
+        drop(probe_rx);
+
+        let checker = ReadinessChecker::new(ReadinessConfig::default(), monitor, EngineProbe::new(probe_tx), queue_metrics, 1);
+        let report = checker.check().await;
+
+        assert!(!report.ready);
+        for name in ["database", "matching_engine", "sequencer_queue", "order_book"] {
+            let check = report.checks.iter().find(|check| check.name == name).unwrap();
+            assert!(!check.ok, "{} 항목이 통과함", name);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_engine_heartbeat_checks_order_books() {
+        let (probe_tx, probe_rx) = mpsc::channel::<EngineProbeRequest>();
+        std::thread::spawn(move || {
+            while let Ok(reply) = probe_rx.recv() {
+                let _ = reply.send(EngineHeartbeat { open_orders: 0, symbols: 2 });
+            }
+        });
+        let probe = EngineProbe::new(probe_tx);
+        let heartbeat = probe.heartbeat().await;
+
+        assert!(ReadinessChecker::check_engine(&heartbeat).ok);
+        assert!(ReadinessChecker::check_order_book(&heartbeat, 2).ok);
+        assert!(!ReadinessChecker::check_order_book(&heartbeat, 3).ok);
+    }
+
+    #[test]
+    fn test_standby_not_ready_until_promoted() {
+        let journal = Arc::new(crate::sequencer::replication::ReplicationJournal::new(10));
+        let replication = ReplicationState::standby(journal, "127.0.0.1:7100");
+        assert!(!ReadinessChecker::check_replication(&replication).ok);
+
+        replication.promote().unwrap();
+        assert!(ReadinessChecker::check_replication(&replication).ok);
+    }
+}
@@ -7,10 +7,12 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
-use std::time::{SystemTime, UNIX_EPOCH, Duration};
+use std::time::Duration;
 use log::{info, error, warn, debug};
-use tokio::time::{sleep, interval};
-use std::sync::atomic::{AtomicUsize, AtomicU64, AtomicBool, Ordering};
+
+use super::auto_recovery::AutoRecoveryManager;
+use super::health_probes::{HealthProbe, ProbeFailure, ProbeFailureKind};
+use crate::util::clock::{Clock, SharedClock, SystemClock};
 
 /// 헬스체크 상태
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -22,7 +24,7 @@ pub enum HealthStatus {
 }
 
 /// 서비스 타입
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ServiceType {
     Database,
     Redis,
@@ -115,11 +117,42 @@ impl Default for HealthCheckConfig {
     }
 }
 
+/// 서비스별 누적 체크 횟수 (가동률/에러율 계산용)
+#[derive(Debug, Default, Clone)]
+struct ProbeStats {
+    total_checks: u64,
+    failed_checks: u64,
+}
+
+impl ProbeStats {
+    fn uptime_percent(&self) -> f64 {
+        if self.total_checks == 0 {
+            return 100.0;
+        }
+        (self.total_checks - self.failed_checks) as f64 / self.total_checks as f64 * 100.0
+    }
+
+    fn error_rate(&self) -> f64 {
+        if self.total_checks == 0 {
+            return 0.0;
+        }
+        self.failed_checks as f64 / self.total_checks as f64 * 100.0
+    }
+}
+
 /// 헬스체크 모니터
+///
+/// 등록된 [`HealthProbe`] 가 있는 서비스만 검사합니다.
 pub struct SystemHealthMonitor {
     config: HealthCheckConfig,
     service_healths: Arc<RwLock<HashMap<String, ServiceHealth>>>,
-    system_start_time: SystemTime,
+    probes: Vec<Arc<dyn HealthProbe>>,
+    probe_stats: Arc<Mutex<HashMap<String, ProbeStats>>>,
+    auto_recovery: Option<Arc<AutoRecoveryManager>>,
+    /// 시작 시각 (Unix 밀리초)
+    system_start_time: u64,
+    /// 시각 소스 (체크 주기, 프로브 타임아웃, 결과 시각)
+    clock: SharedClock,
     is_running: Arc<Mutex<bool>>,
     notification_sender: Arc<dyn Fn(String, String) -> Result<(), String> + Send + Sync>,
 }
@@ -133,12 +166,35 @@ impl SystemHealthMonitor {
         Self {
             config,
             service_healths: Arc::new(RwLock::new(HashMap::new())),
-            system_start_time: SystemTime::now(),
+            probes: Vec::new(),
+            probe_stats: Arc::new(Mutex::new(HashMap::new())),
+            auto_recovery: None,
+            system_start_time: SystemClock.now_millis(),
+            clock: SystemClock::shared(),
             is_running: Arc::new(Mutex::new(false)),
             notification_sender: Arc::new(notification_sender),
         }
     }
 
+    /// 헬스체크 프로브 등록
+    pub fn with_probe<P: HealthProbe + 'static>(mut self, probe: P) -> Self {
+        self.probes.push(Arc::new(probe));
+        self
+    }
+
+    /// 자동 복구 연결 (`enable_auto_recovery` 가 켜져 있을 때만 실행)
+    pub fn with_auto_recovery(mut self, auto_recovery: Arc<AutoRecoveryManager>) -> Self {
+        self.auto_recovery = Some(auto_recovery);
+        self
+    }
+
+    /// 시각 소스 지정 (시뮬레이션 테스트용, 기본값은 시스템 시각)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.system_start_time = clock.now_millis();
+        self.clock = clock;
+        self
+    }
+
     /// 헬스체크 모니터 시작
     pub async fn start(&self) {
         let mut is_running = self.is_running.lock().await;
@@ -149,20 +205,26 @@ impl SystemHealthMonitor {
         *is_running = true;
         drop(is_running);
 
-        info!("헬스체크 모니터 시작: 체크간격={}ms", self.config.check_interval_ms);
+        info!(
+            "헬스체크 모니터 시작: 체크간격={}ms, 프로브 {}개",
+            self.config.check_interval_ms,
+            self.probes.len()
+        );
 
         let service_healths = self.service_healths.clone();
+        let probe_stats = self.probe_stats.clone();
+        let probes = self.probes.clone();
+        let auto_recovery = self.auto_recovery.clone();
         let config = self.config.clone();
         let notification_sender = self.notification_sender.clone();
         let is_running = self.is_running.clone();
+        let clock = self.clock.clone();
 
-        // 헬스체크 태스크
+        // 헬스체크 태스크 (시작 즉시 한 번, 이후 체크 간격마다)
         tokio::spawn(async move {
-            let mut interval = interval(Duration::from_millis(config.check_interval_ms));
+            let check_interval = Duration::from_millis(config.check_interval_ms);
 
             loop {
-                interval.tick().await;
-
                 // 실행 중단 확인
                 {
                     let running = is_running.lock().await;
@@ -172,7 +234,16 @@ impl SystemHealthMonitor {
                 }
 
                 // 서비스별 헬스체크 실행
-                Self::check_all_services(&service_healths, &config, &notification_sender).await;
+                Self::check_all_services(&service_healths, &probe_stats, &probes, &config, &notification_sender, &clock).await;
+
+                // 연속 실패 서비스 자동 복구
+                if config.enable_auto_recovery {
+                    if let Some(auto_recovery) = &auto_recovery {
+                        Self::run_auto_recovery(&service_healths, auto_recovery, &notification_sender).await;
+                    }
+                }
+
+                clock.sleep(check_interval).await;
             }
 
             info!("헬스체크 모니터 종료");
@@ -187,29 +258,63 @@ impl SystemHealthMonitor {
     }
 
     /// 모든 서비스 헬스체크 실행
+    ///
+    /// 등록된 프로브를 동시에 실행하고, 결과를 반영할 때만 쓰기 락을 잡습니다.
     async fn check_all_services(
         service_healths: &Arc<RwLock<HashMap<String, ServiceHealth>>>,
+        probe_stats: &Arc<Mutex<HashMap<String, ProbeStats>>>,
+        probes: &[Arc<dyn HealthProbe>],
         config: &HealthCheckConfig,
         notification_sender: &Arc<dyn Fn(String, String) -> Result<(), String> + Send + Sync>,
+        clock: &SharedClock,
     ) {
+        let timeout = Duration::from_millis(config.timeout_ms);
+        let checks = probes
+            .iter()
+            .filter(|probe| config.services_to_check.contains(&probe.service_type()))
+            .map(|probe| Self::run_probe(probe.clone(), timeout, clock));
+        let results = futures::future::join_all(checks).await;
+
+        let current_time = clock.now_millis();
+        let mut stats = probe_stats.lock().await;
         let mut healths = service_healths.write().await;
-        let current_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
-
-        for service_type in &config.services_to_check {
-            let service_name = Self::service_type_to_string(service_type);
-            let health_result = Self::check_service_health(service_type, config).await;
-            
+
+        for (service_type, outcome, response_time_ms) in results {
+            let service_name = Self::service_type_to_string(&service_type);
+            let previous_failures = healths
+                .get(&service_name)
+                .map(|health| health.consecutive_failures)
+                .unwrap_or(0);
+            let (status, message, consecutive_failures) =
+                Self::classify(config, previous_failures, &outcome, response_time_ms);
+
+            let service_stats = stats.entry(service_name.clone()).or_default();
+            service_stats.total_checks += 1;
+            if outcome.is_err() {
+                service_stats.failed_checks += 1;
+                debug!("{} 헬스체크 실패: {}", service_name, message);
+            }
+
+            let health_result = ServiceHealth {
+                service_type,
+                status,
+                message,
+                response_time_ms,
+                last_check: current_time,
+                consecutive_failures,
+                uptime_percent: service_stats.uptime_percent(),
+                error_rate: service_stats.error_rate(),
+            };
+
             // 이전 상태와 비교하여 알림 발송
             if let Some(previous_health) = healths.get(&service_name) {
                 if previous_health.status != health_result.status {
                     let notification = format!(
-                        "서비스 상태 변경: {} - {} → {}",
+                        "서비스 상태 변경: {} - {} → {} ({})",
                         service_name,
                         Self::status_to_string(&previous_health.status),
-                        Self::status_to_string(&health_result.status)
+                        Self::status_to_string(&health_result.status),
+                        health_result.message
                     );
                     
                     if config.enable_notifications {
@@ -224,224 +329,85 @@ impl SystemHealthMonitor {
         }
     }
 
-    /// 개별 서비스 헬스체크
-    async fn check_service_health(service_type: &ServiceType, config: &HealthCheckConfig) -> ServiceHealth {
-        let start_time = SystemTime::now();
-        let current_time = start_time
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
-
-        let (status, message) = match service_type {
-            ServiceType::Database => Self::check_database().await,
-            ServiceType::Redis => Self::check_redis().await,
-            ServiceType::Kafka => Self::check_kafka().await,
-            ServiceType::RabbitMQ => Self::check_rabbitmq().await,
-            ServiceType::MatchingEngine => Self::check_matching_engine().await,
-            ServiceType::OrderSequencer => Self::check_order_sequencer().await,
-            ServiceType::WebSocketServer => Self::check_websocket_server().await,
-            ServiceType::RestApi => Self::check_rest_api().await,
-            ServiceType::MDPConsumer => Self::check_mdp_consumer().await,
-            ServiceType::ExternalSync => Self::check_external_sync().await,
-            ServiceType::RegulatoryReporting => Self::check_regulatory_reporting().await,
-            ServiceType::AnalyticsIntegration => Self::check_analytics_integration().await,
-            ServiceType::BatchProcessor => Self::check_batch_processor().await,
-            ServiceType::WorkerPool => Self::check_worker_pool().await,
-            ServiceType::CacheOptimizer => Self::check_cache_optimizer().await,
-            ServiceType::MetricsCollector => Self::check_metrics_collector().await,
-        };
-
-        let response_time = start_time.elapsed().unwrap().as_millis() as u64;
-
-        ServiceHealth {
-            service_type: service_type.clone(),
-            status: status.clone(),
-            message,
-            response_time_ms: response_time,
-            last_check: current_time,
-            consecutive_failures: if status == HealthStatus::Healthy { 0 } else { 1 },
-            uptime_percent: Self::calculate_uptime_percent(service_type),
-            error_rate: Self::calculate_error_rate(service_type),
-        }
-    }
-
-    /// 데이터베이스 헬스체크
-    async fn check_database() -> (HealthStatus, String) {
-        // Mock: 실제로는 데이터베이스 연결 및 쿼리 테스트
-        tokio::time::sleep(Duration::from_millis(50)).await;
-        
-        if fastrand::f32() > 0.1 {
-            (HealthStatus::Healthy, "데이터베이스 연결 정상".to_string())
-        } else {
-            (HealthStatus::Critical, "데이터베이스 연결 실패".to_string())
-        }
-    }
-
-    /// Redis 헬스체크
-    async fn check_redis() -> (HealthStatus, String) {
-        // Mock: 실제로는 Redis PING 명령 실행
-        tokio::time::sleep(Duration::from_millis(30)).await;
-        
-        if fastrand::f32() > 0.05 {
-            (HealthStatus::Healthy, "Redis 연결 정상".to_string())
-        } else {
-            (HealthStatus::Warning, "Redis 응답 지연".to_string())
-        }
-    }
-
-    /// Kafka 헬스체크
-    async fn check_kafka() -> (HealthStatus, String) {
-        // Mock: 실제로는 Kafka 브로커 연결 테스트
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        
-        if fastrand::f32() > 0.08 {
-            (HealthStatus::Healthy, "Kafka 브로커 연결 정상".to_string())
-        } else {
-            (HealthStatus::Critical, "Kafka 브로커 연결 실패".to_string())
-        }
-    }
-
-    /// RabbitMQ 헬스체크
-    async fn check_rabbitmq() -> (HealthStatus, String) {
-        // Mock: 실제로는 RabbitMQ 연결 테스트
-        tokio::time::sleep(Duration::from_millis(80)).await;
-        
-        if fastrand::f32() > 0.06 {
-            (HealthStatus::Healthy, "RabbitMQ 연결 정상".to_string())
-        } else {
-            (HealthStatus::Warning, "RabbitMQ 큐 상태 이상".to_string())
-        }
-    }
-
-    /// 매칭 엔진 헬스체크
-    async fn check_matching_engine() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(20)).await;
-        
-        if fastrand::f32() > 0.02 {
-            (HealthStatus::Healthy, "매칭 엔진 정상 동작".to_string())
-        } else {
-            (HealthStatus::Critical, "매칭 엔진 처리 지연".to_string())
-        }
-    }
-
-    /// 주문 시퀀서 헬스체크
-    async fn check_order_sequencer() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(25)).await;
-        
-        if fastrand::f32() > 0.03 {
-            (HealthStatus::Healthy, "주문 시퀀서 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "주문 시퀀서 큐 포화".to_string())
-        }
-    }
-
-    /// WebSocket 서버 헬스체크
-    async fn check_websocket_server() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(15)).await;
-        
-        if fastrand::f32() > 0.04 {
-            (HealthStatus::Healthy, "WebSocket 서버 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "WebSocket 연결 수 과다".to_string())
-        }
-    }
-
-    /// REST API 헬스체크
-    async fn check_rest_api() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(40)).await;
-        
-        if fastrand::f32() > 0.05 {
-            (HealthStatus::Healthy, "REST API 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "REST API 응답 지연".to_string())
-        }
-    }
-
-    /// MDP Consumer 헬스체크
-    async fn check_mdp_consumer() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(35)).await;
-        
-        if fastrand::f32() > 0.07 {
-            (HealthStatus::Healthy, "MDP Consumer 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "MDP Consumer 처리 지연".to_string())
-        }
-    }
-
-    /// 외부 동기화 헬스체크
-    async fn check_external_sync() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(60)).await;
-        
-        if fastrand::f32() > 0.1 {
-            (HealthStatus::Healthy, "외부 동기화 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "외부 API 응답 지연".to_string())
-        }
-    }
-
-    /// 규제 보고 헬스체크
-    async fn check_regulatory_reporting() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(45)).await;
-        
-        if fastrand::f32() > 0.08 {
-            (HealthStatus::Healthy, "규제 보고 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "규제 보고 처리 지연".to_string())
-        }
-    }
-
-    /// 분석 시스템 통합 헬스체크
-    async fn check_analytics_integration() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(70)).await;
-        
-        if fastrand::f32() > 0.09 {
-            (HealthStatus::Healthy, "분석 시스템 통합 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "분석 시스템 응답 지연".to_string())
-        }
-    }
-
-    /// 배치 처리기 헬스체크
-    async fn check_batch_processor() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(30)).await;
-        
-        if fastrand::f32() > 0.05 {
-            (HealthStatus::Healthy, "배치 처리기 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "배치 처리 큐 포화".to_string())
-        }
-    }
-
-    /// 워커 풀 헬스체크
-    async fn check_worker_pool() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(25)).await;
-        
-        if fastrand::f32() > 0.04 {
-            (HealthStatus::Healthy, "워커 풀 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "워커 풀 과부하".to_string())
+    /// 실패 중인 서비스에 복구 작업 실행 (락을 잡지 않은 상태에서 실행)
+    async fn run_auto_recovery(
+        service_healths: &Arc<RwLock<HashMap<String, ServiceHealth>>>,
+        auto_recovery: &Arc<AutoRecoveryManager>,
+        notification_sender: &Arc<dyn Fn(String, String) -> Result<(), String> + Send + Sync>,
+    ) {
+        let failing: Vec<ServiceHealth> = service_healths
+            .read()
+            .await
+            .values()
+            .filter(|health| health.consecutive_failures > 0)
+            .cloned()
+            .collect();
+
+        for health in failing {
+            for record in auto_recovery.recover(&health).await {
+                let notification = format!(
+                    "자동 복구 {}: {} - {} ({})",
+                    if record.success { "성공" } else { "실패" },
+                    record.service,
+                    record.action,
+                    record.message
+                );
+                if let Err(e) = notification_sender(notification, "auto_recovery".to_string()) {
+                    error!("알림 발송 실패: {}", e);
+                }
+            }
         }
     }
 
-    /// 캐시 최적화기 헬스체크
-    async fn check_cache_optimizer() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(20)).await;
-        
-        if fastrand::f32() > 0.03 {
-            (HealthStatus::Healthy, "캐시 최적화기 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "캐시 메모리 부족".to_string())
-        }
+    /// 프로브 1회 실행 (타임아웃 포함)
+    async fn run_probe(
+        probe: Arc<dyn HealthProbe>,
+        timeout: Duration,
+        clock: &SharedClock,
+    ) -> (ServiceType, Result<String, ProbeFailure>, u64) {
+        let started = clock.now_millis();
+        let outcome = tokio::select! {
+            result = probe.probe() => result,
+            _ = clock.sleep(timeout) => Err(ProbeFailure::new(
+                ProbeFailureKind::Timeout,
+                format!("{}ms 내 응답 없음", timeout.as_millis()),
+            )),
+        };
+        (probe.service_type(), outcome, clock.now_millis().saturating_sub(started))
     }
 
-    /// 메트릭 수집기 헬스체크
-    async fn check_metrics_collector() -> (HealthStatus, String) {
-        tokio::time::sleep(Duration::from_millis(15)).await;
-        
-        if fastrand::f32() > 0.02 {
-            (HealthStatus::Healthy, "메트릭 수집기 정상 동작".to_string())
-        } else {
-            (HealthStatus::Warning, "메트릭 수집 지연".to_string())
+    /// 프로브 결과를 상태로 판정
+    ///
+    /// 성공은 응답시간 임계치에 따라 Healthy/Warning/Critical, 실패는 연속 실패 횟수가
+    /// `max_consecutive_failures` 에 도달하기 전까지 Warning, 이후 Critical 입니다.
+    /// 반환값: (상태, 메시지, 연속 실패 횟수)
+    fn classify(
+        config: &HealthCheckConfig,
+        previous_failures: u32,
+        outcome: &Result<String, ProbeFailure>,
+        response_time_ms: u64,
+    ) -> (HealthStatus, String, u32) {
+        match outcome {
+            Ok(message) if response_time_ms >= config.critical_threshold_ms => (
+                HealthStatus::Critical,
+                format!("{} (응답 {}ms, 임계치 초과)", message, response_time_ms),
+                0,
+            ),
+            Ok(message) if response_time_ms >= config.warning_threshold_ms => (
+                HealthStatus::Warning,
+                format!("{} (응답 지연 {}ms)", message, response_time_ms),
+                0,
+            ),
+            Ok(message) => (HealthStatus::Healthy, message.clone(), 0),
+            Err(failure) => {
+                let failures = previous_failures.saturating_add(1);
+                let status = if failures >= config.max_consecutive_failures {
+                    HealthStatus::Critical
+                } else {
+                    HealthStatus::Warning
+                };
+                (status, format!("{} (연속 실패 {}회)", failure, failures), failures)
+            }
         }
     }
 
@@ -477,47 +443,11 @@ impl SystemHealthMonitor {
         }
     }
 
-    /// 가동률 계산 (Mock)
-    fn calculate_uptime_percent(service_type: &ServiceType) -> f64 {
-        // Mock: 실제로는 서비스별 가동률 계산
-        match service_type {
-            ServiceType::Database => 99.9,
-            ServiceType::Redis => 99.8,
-            ServiceType::Kafka => 99.7,
-            ServiceType::RabbitMQ => 99.6,
-            ServiceType::MatchingEngine => 99.95,
-            ServiceType::OrderSequencer => 99.9,
-            ServiceType::WebSocketServer => 99.8,
-            ServiceType::RestApi => 99.7,
-            ServiceType::MDPConsumer => 99.6,
-            ServiceType::ExternalSync => 99.5,
-            ServiceType::RegulatoryReporting => 99.4,
-            ServiceType::AnalyticsIntegration => 99.3,
-            ServiceType::BatchProcessor => 99.9,
-            ServiceType::WorkerPool => 99.8,
-            ServiceType::CacheOptimizer => 99.7,
-            ServiceType::MetricsCollector => 99.9,
-        }
-    }
-
-    /// 에러율 계산 (Mock)
-    fn calculate_error_rate(service_type: &ServiceType) -> f64 {
-        // Mock: 실제로는 서비스별 에러율 계산
-        fastrand::f32() * 0.1 // 0-0.1%
-    }
-
     /// 전체 시스템 헬스체크 결과 조회
     pub async fn get_system_health(&self) -> SystemHealth {
         let service_healths = self.service_healths.read().await;
-        let current_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
-        
-        let system_uptime = SystemTime::now()
-            .duration_since(self.system_start_time)
-            .unwrap()
-            .as_secs();
+        let current_time = self.clock.now_millis();
+        let system_uptime = current_time.saturating_sub(self.system_start_time) / 1000;
 
         let mut healthy_count = 0;
         let mut warning_count = 0;
@@ -607,10 +537,7 @@ impl SystemHealthMonitor {
             service_name,
             Self::status_to_string(old_status),
             Self::status_to_string(new_status),
-            SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_millis()
+            self.clock.now_millis()
         );
 
         if let Err(e) = (self.notification_sender)(notification, "status_change".to_string()) {
@@ -619,24 +546,6 @@ impl SystemHealthMonitor {
     }
 }
 
-// fastrand 의존성을 위해 간단한 랜덤 함수 구현
-mod fastrand {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-    use std::time::{SystemTime, UNIX_EPOCH};
-
-    pub fn f32() -> f32 {
-        let mut hasher = DefaultHasher::new();
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos()
-            .hash(&mut hasher);
-        let hash = hasher.finish();
-        (hash % 1000) as f32 / 1000.0
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -654,12 +563,38 @@ mod tests {
         assert_eq!(system_health.healthy_services, 0);
     }
 
+    struct StubProbe {
+        service_type: ServiceType,
+        delay_ms: u64,
+        result: Result<String, ProbeFailure>,
+    }
+
+    #[async_trait::async_trait]
+    impl HealthProbe for StubProbe {
+        fn service_type(&self) -> ServiceType {
+            self.service_type.clone()
+        }
+
+        async fn probe(&self) -> Result<String, ProbeFailure> {
+            // 지연이 없으면 바로 응답 (SimClock 테스트에서 실제 시간 sleep 이 끼어들지 않도록)
+            if self.delay_ms > 0 {
+                tokio::time::sleep(Duration::from_millis(self.delay_ms)).await;
+            }
+            self.result.clone()
+        }
+    }
+
     #[tokio::test]
     async fn test_service_health_check() {
         let config = HealthCheckConfig::default();
         let monitor = SystemHealthMonitor::new(config, |msg, _| {
             println!("Notification: {}", msg);
             Ok(())
+        })
+        .with_probe(StubProbe {
+            service_type: ServiceType::Database,
+            delay_ms: 0,
+            result: Ok("SELECT 1 정상".to_string()),
         });
         
         monitor.start().await;
@@ -668,11 +603,155 @@ mod tests {
         tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
         
         let system_health = monitor.get_system_health().await;
-        assert!(system_health.total_services > 0);
+        assert_eq!(system_health.total_services, 1);
+        assert_eq!(system_health.healthy_services, 1);
         
         monitor.stop().await;
     }
 
+    #[tokio::test]
+    async fn test_consecutive_failures_escalate_to_critical() {
+        let config = HealthCheckConfig {
+            timeout_ms: 50,
+            max_consecutive_failures: 2,
+            ..HealthCheckConfig::default()
+        };
+        let notifications = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = notifications.clone();
+        let monitor = SystemHealthMonitor::new(config.clone(), move |msg, _| {
+            sink.lock().unwrap().push(msg);
+            Ok(())
+        })
+        .with_probe(StubProbe {
+            service_type: ServiceType::Redis,
+            delay_ms: 0,
+            result: Err(ProbeFailure::new(ProbeFailureKind::Unreachable, "Connection refused")),
+        })
+        .with_probe(StubProbe {
+            service_type: ServiceType::Kafka,
+            delay_ms: 500,
+            result: Ok("Metadata 정상".to_string()),
+        });
+
+        for _ in 0..2 {
+            SystemHealthMonitor::check_all_services(
+                &monitor.service_healths,
+                &monitor.probe_stats,
+                &monitor.probes,
+                &config,
+                &monitor.notification_sender,
+                &monitor.clock,
+            )
+            .await;
+        }
+
+        let redis = monitor.get_service_health("Redis").await.unwrap();
+        assert_eq!(redis.status, HealthStatus::Critical);
+        assert_eq!(redis.consecutive_failures, 2);
+        assert!(redis.message.starts_with("[unreachable]"));
+        assert_eq!(redis.error_rate, 100.0);
+
+        // 타임아웃은 실패로 분류
+        let kafka = monitor.get_service_health("Kafka").await.unwrap();
+        assert!(kafka.message.starts_with("[timeout]"));
+        assert_eq!(kafka.uptime_percent, 0.0);
+
+        assert_eq!(notifications.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_auto_recovery_runs_for_failing_service() {
+        use crate::monitoring::auto_recovery::{AutoRecoveryConfig, FnRecoveryAction};
+
+        let config = HealthCheckConfig { max_consecutive_failures: 1, ..HealthCheckConfig::default() };
+        let auto_recovery = Arc::new(
+            AutoRecoveryManager::new(AutoRecoveryConfig { trigger_after_failures: 1, ..AutoRecoveryConfig::default() })
+                .with_action(
+                    ServiceType::Redis,
+                    Arc::new(FnRecoveryAction::new("reconnect_redis_producer", || async { Ok("재연결".to_string()) })),
+                ),
+        );
+        let notifications = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = notifications.clone();
+        let monitor = SystemHealthMonitor::new(config.clone(), move |msg, kind| {
+            sink.lock().unwrap().push((msg, kind));
+            Ok(())
+        })
+        .with_probe(StubProbe {
+            service_type: ServiceType::Redis,
+            delay_ms: 0,
+            result: Err(ProbeFailure::new(ProbeFailureKind::Unreachable, "Connection refused")),
+        })
+        .with_auto_recovery(auto_recovery.clone());
+
+        SystemHealthMonitor::check_all_services(
+            &monitor.service_healths,
+            &monitor.probe_stats,
+            &monitor.probes,
+            &config,
+            &monitor.notification_sender,
+            &monitor.clock,
+        )
+        .await;
+        SystemHealthMonitor::run_auto_recovery(&monitor.service_healths, &auto_recovery, &monitor.notification_sender).await;
+
+        let history = auto_recovery.get_history().await;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].action, "reconnect_redis_producer");
+        assert!(notifications.lock().unwrap().iter().any(|(_, kind)| kind == "auto_recovery"));
+    }
+
+    #[tokio::test]
+    async fn test_checks_run_on_sim_clock_interval() {
+        use crate::util::clock::SimClock;
+
+        let clock = SimClock::new(1_700_000_000_000);
+        let config = HealthCheckConfig::default();
+        let monitor = SystemHealthMonitor::new(config.clone(), |_, _| Ok(()))
+            .with_probe(StubProbe {
+                service_type: ServiceType::Database,
+                delay_ms: 0,
+                result: Ok("SELECT 1 정상".to_string()),
+            })
+            .with_clock(clock.shared());
+        let checks = || async { monitor.probe_stats.lock().await.get("Database").map_or(0, |stats| stats.total_checks) };
+
+        // 시작 즉시 한 번 검사
+        monitor.start().await;
+        clock.settle().await;
+        assert_eq!(checks().await, 1);
+
+        clock.advance(Duration::from_millis(config.check_interval_ms - 1));
+        clock.settle().await;
+        assert_eq!(checks().await, 1);
+
+        clock.advance(Duration::from_millis(1));
+        clock.settle().await;
+        assert_eq!(checks().await, 2);
+
+        let health = monitor.get_system_health().await;
+        assert_eq!(health.last_check, clock.now_millis());
+        assert_eq!(health.system_uptime_seconds, config.check_interval_ms / 1000);
+        monitor.stop().await;
+    }
+
+    #[test]
+    fn test_classify_slow_success() {
+        let config = HealthCheckConfig::default();
+        let ok = Ok("정상".to_string());
+
+        let (status, _, failures) = SystemHealthMonitor::classify(&config, 2, &ok, 10);
+        assert_eq!(status, HealthStatus::Healthy);
+        assert_eq!(failures, 0);
+
+        let (status, message, _) = SystemHealthMonitor::classify(&config, 0, &ok, config.warning_threshold_ms);
+        assert_eq!(status, HealthStatus::Warning);
+        assert!(message.contains("응답 지연"));
+
+        let (status, _, _) = SystemHealthMonitor::classify(&config, 0, &ok, config.critical_threshold_ms);
+        assert_eq!(status, HealthStatus::Critical);
+    }
+
     #[tokio::test]
     async fn test_health_status_conversion() {
         assert_eq!(SystemHealthMonitor::status_to_string(&HealthStatus::Healthy), "Healthy");
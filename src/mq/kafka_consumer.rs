@@ -1,370 +0,0 @@
-//! Apache Kafka Consumer 구현 (Mock 버전)
-//!
-//! 이 모듈은 Kafka Topic에서 메시지를 소비하는 기능을 Mock으로 구현합니다.
-//! 실제 Kafka 라이브러리 대신 로깅과 시뮬레이션을 사용합니다.
-
-use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use tokio::sync::Mutex;
-use log::{info, error, warn};
-use crate::mq::kafka_producer::{MarketDataMessage, MarketStatisticsMessage, OrderBookUpdateMessage};
-
-/// Kafka Consumer Worker (Mock 구현)
-pub struct KafkaConsumerWorker {
-    topic_name: String,
-    consumer_group: String,
-    worker_id: String,
-    batch_size: usize,
-    processing_interval_ms: u64,
-    messages_processed: Arc<Mutex<u64>>,
-}
-
-/// Consumer Worker 설정
-pub struct KafkaConsumerConfig {
-    pub kafka_brokers: Vec<String>,
-    pub topic_name: String,
-    pub consumer_group: String,
-    pub worker_id: String,
-    pub batch_size: usize,
-    pub processing_interval_ms: u64,
-}
-
-impl KafkaConsumerWorker {
-    /// 새 Kafka Consumer Worker 생성 (Mock)
-    pub async fn new(config: KafkaConsumerConfig) -> Result<Self, Box<dyn std::error::Error>> {
-        info!("Kafka Consumer Worker 초기화 완료 (Mock): {} - {}", config.consumer_group, config.worker_id);
-        
-        Ok(Self {
-            topic_name: config.topic_name,
-            consumer_group: config.consumer_group,
-            worker_id: config.worker_id,
-            batch_size: config.batch_size,
-            processing_interval_ms: config.processing_interval_ms,
-            messages_processed: Arc::new(Mutex::new(0)),
-        })
-    }
-
-    /// Consumer Worker 실행 (메인 루프) (Mock)
-    pub async fn run(&self) -> Result<(), String> {
-        info!("Kafka Consumer Worker 시작 (Mock): {}", self.worker_id);
-        
-        loop {
-            match self.process_batch().await {
-                Ok(processed_count) => {
-                    if processed_count > 0 {
-                        info!("Worker {} 처리 완료 (Mock): {}개 메시지", self.worker_id, processed_count);
-                    }
-                }
-                Err(e) => {
-                    error!("Worker {} 처리 오류 (Mock): {}", self.worker_id, e);
-                    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
-                }
-            }
-            
-            // 처리 간격 대기
-            tokio::time::sleep(tokio::time::Duration::from_millis(self.processing_interval_ms)).await;
-        }
-    }
-
-    /// 메시지 배치 처리 (Mock)
-    async fn process_batch(&self) -> Result<usize, String> {
-        // Mock: 실제로는 Kafka에서 메시지를 폴링하지만, 여기서는 시뮬레이션
-        let mut count = self.messages_processed.lock().await;
-        let processed_count = (*count % 10) + 1; // 1-10개 사이의 랜덤한 메시지 처리
-        *count += processed_count;
-        
-        // Mock 메시지 생성 및 처리
-        for i in 0..processed_count {
-            let mock_message = self.create_mock_message(i as usize);
-            self.process_message(&mock_message).await?;
-        }
-        
-        Ok(processed_count as usize)
-    }
-
-    /// Mock 메시지 생성
-    fn create_mock_message(&self, index: usize) -> MarketDataMessage {
-        MarketDataMessage {
-            execution_id: format!("mock_exec_{}", index),
-            symbol: "BTC-KRW".to_string(),
-            side: if index % 2 == 0 { "Buy".to_string() } else { "Sell".to_string() },
-            price: 50000 + (index as u64 * 100),
-            quantity: 100 + (index as u64 * 10),
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_millis() as u64,
-            order_id: format!("mock_order_{}", index),
-            user_id: format!("mock_user_{}", index % 5),
-            message_type: "execution".to_string(),
-        }
-    }
-
-    /// 개별 메시지 처리
-    async fn process_message(&self, message: &MarketDataMessage) -> Result<(), String> {
-        info!("메시지 처리 (Mock): {} - {} ({} {})", 
-              message.symbol, message.execution_id, message.price, message.quantity);
-        
-        // TODO: 실제 비즈니스 로직 구현
-        // 예: 외부 거래소 가격 동기화, 규제 보고서 생성, 분석 시스템 업데이트
-        
-        Ok(())
-    }
-}
-
-/// MDP Consumer (Market Data Publisher Consumer) (Mock)
-pub struct MDPConsumer {
-    worker: KafkaConsumerWorker,
-}
-
-impl MDPConsumer {
-    /// 새 MDP Consumer 생성 (Mock)
-    pub async fn new(config: KafkaConsumerConfig) -> Result<Self, Box<dyn std::error::Error>> {
-        let worker = KafkaConsumerWorker::new(config).await?;
-        
-        info!("MDP Consumer 초기화 완료 (Mock)");
-        
-        Ok(Self { worker })
-    }
-
-    /// MDP Consumer 실행 (Mock)
-    pub async fn run(&self) -> Result<(), String> {
-        info!("MDP Consumer 시작 (Mock)");
-        
-        // MDP 전용 처리 로직
-        self.worker.run().await
-    }
-
-    /// 봉차트 데이터 처리 (Mock)
-    async fn process_candlestick_data(&self, message: &MarketDataMessage) -> Result<(), Box<dyn std::error::Error>> {
-        info!("봉차트 데이터 처리 (Mock): {} - {} at {}", 
-              message.symbol, message.price, message.timestamp);
-        
-        // TODO: 봉차트 계산 로직 구현
-        // 1분, 5분, 15분, 1시간, 4시간, 1일 봉차트 업데이트
-        
-        Ok(())
-    }
-
-    /// 시장 통계 계산 (Mock)
-    async fn calculate_market_statistics(&self, symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
-        info!("시장 통계 계산 (Mock): {}", symbol);
-        
-        // TODO: 시장 통계 계산 로직 구현
-        // 24시간 거래량, 가격 변동률, 고가/저가 등
-        
-        Ok(())
-    }
-}
-
-/// 외부 거래소 Consumer (Mock)
-pub struct ExternalExchangeConsumer {
-    worker: KafkaConsumerWorker,
-}
-
-impl ExternalExchangeConsumer {
-    /// 새 외부 거래소 Consumer 생성 (Mock)
-    pub async fn new(config: KafkaConsumerConfig) -> Result<Self, Box<dyn std::error::Error>> {
-        let worker = KafkaConsumerWorker::new(config).await?;
-        
-        info!("외부 거래소 Consumer 초기화 완료 (Mock)");
-        
-        Ok(Self { worker })
-    }
-
-    /// 외부 거래소 Consumer 실행 (Mock)
-    pub async fn run(&self) -> Result<(), String> {
-        info!("외부 거래소 Consumer 시작 (Mock)");
-        
-        // 외부 거래소 전용 처리 로직
-        self.worker.run().await
-    }
-
-    /// 가격 동기화 처리 (Mock)
-    async fn sync_prices_with_external_exchanges(&self, message: &MarketDataMessage) -> Result<(), Box<dyn std::error::Error>> {
-        info!("외부 거래소 가격 동기화 (Mock): {} - {}", message.symbol, message.price);
-        
-        // TODO: 외부 거래소 API 호출하여 가격 비교
-        // Binance, Coinbase, Kraken 등과 가격 동기화
-        
-        Ok(())
-    }
-
-    /// 차익거래 기회 탐지 (Mock)
-    async fn detect_arbitrage_opportunities(&self, symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
-        info!("차익거래 기회 탐지 (Mock): {}", symbol);
-        
-        // TODO: 차익거래 기회 탐지 로직 구현
-        // 여러 거래소 간 가격 차이 분석
-        
-        Ok(())
-    }
-}
-
-/// 규제 기관 Consumer (Mock)
-pub struct RegulatoryConsumer {
-    worker: KafkaConsumerWorker,
-}
-
-impl RegulatoryConsumer {
-    /// 새 규제 기관 Consumer 생성 (Mock)
-    pub async fn new(config: KafkaConsumerConfig) -> Result<Self, Box<dyn std::error::Error>> {
-        let worker = KafkaConsumerWorker::new(config).await?;
-        
-        info!("규제 기관 Consumer 초기화 완료 (Mock)");
-        
-        Ok(Self { worker })
-    }
-
-    /// 규제 기관 Consumer 실행 (Mock)
-    pub async fn run(&self) -> Result<(), String> {
-        info!("규제 기관 Consumer 시작 (Mock)");
-        
-        // 규제 기관 전용 처리 로직
-        self.worker.run().await
-    }
-
-    /// 대량 거래 감지 (Mock)
-    async fn detect_large_trades(&self, message: &MarketDataMessage) -> Result<(), Box<dyn std::error::Error>> {
-        // 대량 거래 임계값 (예: 1억원 이상)
-        let large_trade_threshold = 100_000_000;
-        
-        if message.price * message.quantity >= large_trade_threshold {
-            info!("대량 거래 감지 (Mock): {} - {}원", message.execution_id, message.price * message.quantity);
-            
-            // TODO: 규제 기관 보고서 생성
-            // 금융감독원, 과세청 등에 보고
-        }
-        
-        Ok(())
-    }
-
-    /// 실시간 보고서 생성 (Mock)
-    async fn generate_regulatory_report(&self, symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
-        info!("규제 보고서 생성 (Mock): {}", symbol);
-        
-        // TODO: 규제 보고서 생성 로직 구현
-        // 거래 내역, 거래량, 가격 변동 등 정기 보고
-        
-        Ok(())
-    }
-}
-
-/// 분석 시스템 Consumer (Mock)
-pub struct AnalyticsConsumer {
-    worker: KafkaConsumerWorker,
-}
-
-impl AnalyticsConsumer {
-    /// 새 분석 시스템 Consumer 생성 (Mock)
-    pub async fn new(config: KafkaConsumerConfig) -> Result<Self, Box<dyn std::error::Error>> {
-        let worker = KafkaConsumerWorker::new(config).await?;
-        
-        info!("분석 시스템 Consumer 초기화 완료 (Mock)");
-        
-        Ok(Self { worker })
-    }
-
-    /// 분석 시스템 Consumer 실행 (Mock)
-    pub async fn run(&self) -> Result<(), String> {
-        info!("분석 시스템 Consumer 시작 (Mock)");
-        
-        // 분석 시스템 전용 처리 로직
-        self.worker.run().await
-    }
-
-    /// 고래 거래 패턴 분석 (Mock)
-    async fn analyze_whale_trading_patterns(&self, message: &MarketDataMessage) -> Result<(), Box<dyn std::error::Error>> {
-        // 고래 거래 임계값 (예: 10억원 이상)
-        let whale_trade_threshold = 1_000_000_000;
-        
-        if message.price * message.quantity >= whale_trade_threshold {
-            info!("고래 거래 패턴 분석 (Mock): {} - {}원", message.execution_id, message.price * message.quantity);
-            
-            // TODO: 고래 거래 패턴 분석 로직 구현
-            // 거래 시간대, 가격 패턴, 수량 패턴 등 분석
-        }
-        
-        Ok(())
-    }
-
-    /// 시장 조작 감지 (Mock)
-    async fn detect_market_manipulation(&self, symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
-        info!("시장 조작 감지 (Mock): {}", symbol);
-        
-        // TODO: 시장 조작 감지 로직 구현
-        // 가격 조작, 거래량 조작, 스푸핑 등 감지
-        
-        Ok(())
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test]
-    async fn test_kafka_consumer_config() {
-        let config = KafkaConsumerConfig {
-            kafka_brokers: vec!["localhost:9092".to_string()],
-            topic_name: "test-market-data".to_string(),
-            consumer_group: "test-group".to_string(),
-            worker_id: "test-worker-1".to_string(),
-            batch_size: 100,
-            processing_interval_ms: 1000,
-        };
-        
-        assert_eq!(config.worker_id, "test-worker-1");
-        assert_eq!(config.batch_size, 100);
-        assert_eq!(config.topic_name, "test-market-data");
-    }
-
-    #[tokio::test]
-    async fn test_kafka_consumer_worker_mock() {
-        let config = KafkaConsumerConfig {
-            kafka_brokers: vec!["localhost:9092".to_string()],
-            topic_name: "test-market-data".to_string(),
-            consumer_group: "test-group".to_string(),
-            worker_id: "test-worker-1".to_string(),
-            batch_size: 100,
-            processing_interval_ms: 1000,
-        };
-        
-        let worker = KafkaConsumerWorker::new(config).await.unwrap();
-        assert_eq!(worker.worker_id, "test-worker-1");
-        assert_eq!(worker.topic_name, "test-market-data");
-    }
-
-    #[tokio::test]
-    async fn test_market_data_message_processing() {
-        let message = MarketDataMessage {
-            execution_id: "exec_001".to_string(),
-            symbol: "BTC-KRW".to_string(),
-            side: "Buy".to_string(),
-            price: 50000,
-            quantity: 100,
-            timestamp: 1234567890,
-            order_id: "order_001".to_string(),
-            user_id: "user_001".to_string(),
-            message_type: "execution".to_string(),
-        };
-        
-        assert_eq!(message.symbol, "BTC-KRW");
-        assert_eq!(message.message_type, "execution");
-    }
-
-    #[tokio::test]
-    async fn test_mdp_consumer_mock() {
-        let config = KafkaConsumerConfig {
-            kafka_brokers: vec!["localhost:9092".to_string()],
-            topic_name: "market-data".to_string(),
-            consumer_group: "mdp-group".to_string(),
-            worker_id: "mdp-worker".to_string(),
-            batch_size: 100,
-            processing_interval_ms: 1000,
-        };
-        
-        let consumer = MDPConsumer::new(config).await.unwrap();
-        // Mock 구현이므로 실제 실행은 하지 않음
-    }
-}
\ No newline at end of file
@@ -0,0 +1,369 @@
+//! 캐시 계층 크기 자동 조정
+//!
+//! `CacheOptimizer`의 L1/L2 용량을 구간별 적중률과 메모리 사용량을 보고 조정합니다. 하위 계층 적중이 많으면
+//! L1을 키우고, 미스가 많으면 L2를 키우며, 메모리 예산에 가까워지면 L2부터 줄입니다. L3는 고정 크기입니다.
+//!
+//! 하위 계층에서 찾은 키를 L1로 올릴 때는 TinyLFU 방식으로 빈도 스케치에서 추정한 접근 빈도가
+//! L1에서 밀려날 항목보다 높을 때만 올려, 한 번 스치고 지나가는 키가 자주 쓰는 키를 밀어내지 않게 합니다.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+use super::cache_optimizer::{CacheLevel, CacheStats};
+
+/// 빈도 스케치 행 수
+const SKETCH_DEPTH: usize = 4;
+/// 카운터 상한 (4비트)
+const SKETCH_MAX_COUNT: u8 = 15;
+
+/// 접근 빈도 스케치 (count-min, 4비트 카운터)
+///
+/// 기록 횟수가 표본 크기에 이르면 모든 카운터를 절반으로 줄여 오래된 인기도가 남지 않게 합니다.
+pub struct FrequencySketch {
+    width: usize,
+    table: Vec<u8>,
+    additions: usize,
+    sample_size: usize,
+}
+
+impl FrequencySketch {
+    /// 캐시 전체 용량 기준 (너비는 용량 이상의 2의 거듭제곱, 표본은 용량의 10배)
+    pub fn new(capacity: usize) -> Self {
+        let width = capacity.max(16).next_power_of_two();
+        Self {
+            width,
+            table: vec![0; width * SKETCH_DEPTH],
+            additions: 0,
+            sample_size: capacity.max(16) * 10,
+        }
+    }
+
+    fn slots(&self, key: &str) -> [usize; SKETCH_DEPTH] {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let hash = hasher.finish();
+        let (h1, h2) = (hash as u32 as usize, (hash >> 32) as usize);
+        let mut slots = [0; SKETCH_DEPTH];
+        for (row, slot) in slots.iter_mut().enumerate() {
+            *slot = row * self.width + (h1.wrapping_add(row.wrapping_mul(h2)) & (self.width - 1));
+        }
+        slots
+    }
+
+    /// 접근 기록
+    pub fn increment(&mut self, key: &str) {
+        let slots = self.slots(key);
+        let mut added = false;
+        for slot in slots {
+            if self.table[slot] < SKETCH_MAX_COUNT {
+                self.table[slot] += 1;
+                added = true;
+            }
+        }
+        if added {
+            self.additions += 1;
+            if self.additions >= self.sample_size {
+                self.reset();
+            }
+        }
+    }
+
+    /// 추정 접근 빈도 (행별 카운터 최솟값)
+    pub fn estimate(&self, key: &str) -> u8 {
+        self.slots(key).iter().map(|&slot| self.table[slot]).min().unwrap_or(0)
+    }
+
+    /// 모든 카운터 절반으로 감쇠
+    fn reset(&mut self) {
+        for counter in &mut self.table {
+            *counter >>= 1;
+        }
+        self.additions /= 2;
+    }
+}
+
+/// 자동 조정 설정
+#[derive(Debug, Clone)]
+pub struct AdaptiveCacheConfig {
+    /// 조정 주기
+    pub interval_ms: u64,
+    /// 구간 요청이 이보다 적으면 조정하지 않음
+    pub min_requests: u64,
+    /// 목표 적중률 (0~1, 미달이면 L2 확대)
+    pub target_hit_rate: f64,
+    /// 요청 중 L2/L3 적중 비율이 이 이상이면 L1 확대
+    pub lower_tier_hit_ratio: f64,
+    /// 한 번에 바꾸는 비율 (현재 용량 대비)
+    pub step_ratio: f64,
+    pub l1_min: usize,
+    pub l1_max: usize,
+    pub l2_min: usize,
+    pub l2_max: usize,
+    /// L1+L2 항목 메모리 예산 (바이트)
+    pub memory_budget_bytes: usize,
+    /// 예산 대비 사용률이 이 이상이면 축소
+    pub memory_high_watermark: f64,
+}
+
+impl Default for AdaptiveCacheConfig {
+    fn default() -> Self {
+        Self {
+            interval_ms: 30000, // 30초
+            min_requests: 1000,
+            target_hit_rate: 0.9,
+            lower_tier_hit_ratio: 0.1,
+            step_ratio: 0.25,
+            l1_min: 100,
+            l1_max: 10000,
+            l2_min: 1000,
+            l2_max: 100000,
+            memory_budget_bytes: 256 * 1024 * 1024, // 256MB
+            memory_high_watermark: 0.9,
+        }
+    }
+}
+
+impl AdaptiveCacheConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.target_hit_rate) || !(0.0..=1.0).contains(&self.lower_tier_hit_ratio) {
+            return Err("적중률 기준은 0~1 사이여야 합니다".to_string());
+        }
+        if self.step_ratio <= 0.0 || self.step_ratio > 1.0 {
+            return Err("조정 비율은 0 초과 1 이하여야 합니다".to_string());
+        }
+        if self.l1_min == 0 || self.l1_min > self.l1_max || self.l2_min == 0 || self.l2_min > self.l2_max {
+            return Err("계층 최소/최대 용량이 잘못되었습니다".to_string());
+        }
+        if self.memory_high_watermark <= 0.0 || self.memory_high_watermark > 1.0 {
+            return Err("메모리 사용률 기준은 0 초과 1 이하여야 합니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 조정 사유
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResizeReason {
+    /// L2/L3 적중이 많아 L1 확대
+    LowerTierHits,
+    /// 적중률이 목표 미달이라 L2 확대
+    LowHitRate,
+    /// 메모리 예산 근접
+    MemoryPressure,
+}
+
+impl ResizeReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResizeReason::LowerTierHits => "lower_tier_hits",
+            ResizeReason::LowHitRate => "low_hit_rate",
+            ResizeReason::MemoryPressure => "memory_pressure",
+        }
+    }
+}
+
+/// 계층 크기 조정 결정
+#[derive(Debug, Clone, Serialize)]
+pub struct TierResizeDecision {
+    pub tier: CacheLevel,
+    pub from: usize,
+    pub to: usize,
+    pub reason: ResizeReason,
+    /// 결정 근거가 된 구간 적중률
+    pub hit_rate: f64,
+    pub timestamp: u64,
+}
+
+/// 조정에 쓰는 현재 상태
+#[derive(Debug, Clone)]
+pub struct TierSnapshot {
+    pub l1_capacity: usize,
+    pub l1_len: usize,
+    pub l2_capacity: usize,
+    pub l2_len: usize,
+    pub memory_bytes: usize,
+}
+
+/// 계층 크기 조정기 (누적 통계의 직전 조정 이후 증가분으로 판단)
+pub struct AdaptiveTierController {
+    config: AdaptiveCacheConfig,
+    last: Option<CacheStats>,
+    /// 조정 횟수 (확대, 축소)
+    grown: u64,
+    shrunk: u64,
+    last_window_hit_rate: f64,
+}
+
+impl AdaptiveTierController {
+    pub fn new(config: AdaptiveCacheConfig) -> Self {
+        Self {
+            config,
+            last: None,
+            grown: 0,
+            shrunk: 0,
+            last_window_hit_rate: 0.0,
+        }
+    }
+
+    pub fn config(&self) -> &AdaptiveCacheConfig {
+        &self.config
+    }
+
+    /// 확대/축소 횟수
+    pub fn resize_counts(&self) -> (u64, u64) {
+        (self.grown, self.shrunk)
+    }
+
+    /// 직전 구간 적중률
+    pub fn last_window_hit_rate(&self) -> f64 {
+        self.last_window_hit_rate
+    }
+
+    fn step(&self, capacity: usize) -> usize {
+        ((capacity as f64 * self.config.step_ratio).ceil() as usize).max(1)
+    }
+
+    fn decision(&mut self, tier: CacheLevel, from: usize, to: usize, reason: ResizeReason, hit_rate: f64) -> Option<TierResizeDecision> {
+        if from == to {
+            return None;
+        }
+        if to > from {
+            self.grown += 1;
+        } else {
+            self.shrunk += 1;
+        }
+        Some(TierResizeDecision {
+            tier,
+            from,
+            to,
+            reason,
+            hit_rate,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64,
+        })
+    }
+
+    /// 누적 통계와 현재 상태로 조정 결정 (구간당 최대 한 계층)
+    pub fn evaluate(&mut self, stats: &CacheStats, tiers: &TierSnapshot) -> Option<TierResizeDecision> {
+        let previous = self.last.replace(stats.clone());
+        let delta = |f: fn(&CacheStats) -> u64| f(stats).saturating_sub(previous.as_ref().map(f).unwrap_or(0));
+        let requests = delta(|s| s.total_requests);
+        let l1_hits = delta(|s| s.l1_hits);
+        let lower_hits = delta(|s| s.l2_hits) + delta(|s| s.l3_hits);
+        let hit_rate = if requests > 0 { (l1_hits + lower_hits) as f64 / requests as f64 } else { 0.0 };
+        self.last_window_hit_rate = hit_rate;
+        let config = self.config.clone();
+
+        // 메모리 압박이면 적중률과 관계없이 L2부터 축소
+        let budget = config.memory_budget_bytes as f64 * config.memory_high_watermark;
+        if config.memory_budget_bytes > 0 && tiers.memory_bytes as f64 >= budget {
+            if tiers.l2_capacity > config.l2_min {
+                let to = tiers.l2_capacity.saturating_sub(self.step(tiers.l2_capacity)).max(config.l2_min);
+                return self.decision(CacheLevel::L2, tiers.l2_capacity, to, ResizeReason::MemoryPressure, hit_rate);
+            }
+            let to = tiers.l1_capacity.saturating_sub(self.step(tiers.l1_capacity)).max(config.l1_min);
+            return self.decision(CacheLevel::L1, tiers.l1_capacity, to, ResizeReason::MemoryPressure, hit_rate);
+        }
+
+        if requests < config.min_requests {
+            return None;
+        }
+
+        // 확대는 계층이 가득 찼을 때만 (비어 있는 계층을 키워도 적중률이 오르지 않음)
+        if lower_hits as f64 / requests as f64 >= config.lower_tier_hit_ratio && tiers.l1_len >= tiers.l1_capacity {
+            let to = (tiers.l1_capacity + self.step(tiers.l1_capacity)).min(config.l1_max);
+            return self.decision(CacheLevel::L1, tiers.l1_capacity, to, ResizeReason::LowerTierHits, hit_rate);
+        }
+        if hit_rate < config.target_hit_rate && tiers.l2_len >= tiers.l2_capacity {
+            let to = (tiers.l2_capacity + self.step(tiers.l2_capacity)).min(config.l2_max);
+            return self.decision(CacheLevel::L2, tiers.l2_capacity, to, ResizeReason::LowHitRate, hit_rate);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(requests: u64, l1_hits: u64, l2_hits: u64) -> CacheStats {
+        CacheStats {
+            l1_hits,
+            l2_hits,
+            l3_hits: 0,
+            misses: requests - l1_hits - l2_hits,
+            total_requests: requests,
+            hit_rate: 0.0,
+            l1_size: 0,
+            l2_size: 0,
+            l3_size: 0,
+            total_size_bytes: 0,
+            compression_ratio: 0.0,
+        }
+    }
+
+    fn full_tiers(memory_bytes: usize) -> TierSnapshot {
+        TierSnapshot { l1_capacity: 100, l1_len: 100, l2_capacity: 1000, l2_len: 1000, memory_bytes }
+    }
+
+    fn config() -> AdaptiveCacheConfig {
+        AdaptiveCacheConfig { min_requests: 100, l1_min: 50, l2_min: 500, memory_budget_bytes: 10_000, ..Default::default() }
+    }
+
+    #[test]
+    fn test_sketch_estimates_and_ages() {
+        let mut sketch = FrequencySketch::new(16);
+        for _ in 0..5 {
+            sketch.increment("hot");
+        }
+        sketch.increment("cold");
+        assert!(sketch.estimate("hot") >= 5);
+        assert!(sketch.estimate("hot") > sketch.estimate("cold"));
+        assert_eq!(sketch.estimate("never"), 0);
+
+        // 감쇠하면 카운터가 절반으로
+        let hot = sketch.estimate("hot");
+        sketch.reset();
+        assert_eq!(sketch.estimate("hot"), hot / 2);
+
+        // 카운터는 15에서 멈춤
+        for _ in 0..40 {
+            sketch.increment("hot");
+        }
+        assert_eq!(sketch.estimate("hot"), SKETCH_MAX_COUNT);
+    }
+
+    #[test]
+    fn test_grow_l1_on_lower_tier_hits_then_l2_on_misses() {
+        let mut controller = AdaptiveTierController::new(config());
+
+        let decision = controller.evaluate(&stats(1000, 500, 300), &full_tiers(0)).unwrap();
+        assert_eq!((decision.tier, decision.from, decision.to), (CacheLevel::L1, 100, 125));
+        assert_eq!(decision.reason, ResizeReason::LowerTierHits);
+
+        // 다음 구간 (증가분 1000건 중 L1 적중 600, 하위 계층 적중 없음) → L2 확대
+        let decision = controller.evaluate(&stats(2000, 1100, 300), &full_tiers(0)).unwrap();
+        assert_eq!((decision.tier, decision.to, decision.reason), (CacheLevel::L2, 1250, ResizeReason::LowHitRate));
+        assert!((controller.last_window_hit_rate() - 0.6).abs() < 1e-9);
+
+        // 요청이 적은 구간은 판단하지 않음
+        assert!(controller.evaluate(&stats(2010, 1110, 300), &full_tiers(0)).is_none());
+        assert_eq!(controller.resize_counts(), (2, 0));
+    }
+
+    #[test]
+    fn test_memory_pressure_shrinks_l2_first() {
+        let mut controller = AdaptiveTierController::new(config());
+        let decision = controller.evaluate(&stats(10, 0, 0), &full_tiers(9_500)).unwrap();
+        assert_eq!((decision.tier, decision.to, decision.reason), (CacheLevel::L2, 750, ResizeReason::MemoryPressure));
+
+        let at_min = TierSnapshot { l2_capacity: 500, ..full_tiers(9_500) };
+        let decision = controller.evaluate(&stats(20, 0, 0), &at_min).unwrap();
+        assert_eq!((decision.tier, decision.to), (CacheLevel::L1, 75));
+        assert_eq!(controller.resize_counts(), (0, 2));
+        assert!(AdaptiveCacheConfig { step_ratio: 0.0, ..config() }.validate().is_err());
+    }
+}
@@ -2,16 +2,62 @@
 //!
 //! 이 모듈은 메시지 배치 처리, 병렬 처리, 메모리 풀 관리를 통해
 //! 고성능 메시지 처리를 제공합니다.
+//!
+//! 메시지는 우선순위 클래스(체결 등 긴급, 일반, 분석 등 대량)별 큐에 쌓이며 배치는 긴급 클래스부터 채웁니다.
+//! 클래스마다 최대 대기 시간(SLA)이 있어 가장 오래된 메시지가 이를 넘기 전에 배치를 앞당겨 처리하고,
+//! 하위 클래스는 배치의 최소 몫을 보장받아 긴급 메시지가 계속 들어와도 굶지 않습니다.
 
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock, Semaphore};
-use std::time::{SystemTime, UNIX_EPOCH, Duration};
+use std::time::{SystemTime, Duration, Instant};
 use log::{info, error, warn, debug};
-use tokio::time::{sleep, interval, timeout};
+use tokio::time::interval;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use super::metrics_collector::MetricsCollector;
+
+/// 우선순위 클래스 (배치는 앞 클래스부터 채움)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PriorityClass {
+    /// 체결 등 지연에 민감한 메시지
+    Critical,
+    #[default]
+    Normal,
+    /// 분석 등 대량 메시지
+    Bulk,
+}
+
+impl PriorityClass {
+    pub const ALL: [PriorityClass; 3] = [PriorityClass::Critical, PriorityClass::Normal, PriorityClass::Bulk];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriorityClass::Critical => "critical",
+            PriorityClass::Normal => "normal",
+            PriorityClass::Bulk => "bulk",
+        }
+    }
+}
+
+/// 클래스별 처리 목표
+#[derive(Debug, Clone)]
+pub struct ClassSla {
+    /// 큐 대기 최대 시간 (가장 오래된 메시지가 이를 넘기 전에 배치를 앞당김)
+    pub max_latency_ms: u64,
+    /// 큐에 메시지가 있으면 배치에서 보장하는 최소 비율 (0~1, 굶주림 방지)
+    pub min_batch_share: f64,
+}
+
+/// 배치 처리 함수
+type ProcessorFn<T, R> = Arc<dyn Fn(Vec<T>) -> Vec<Result<R, String>> + Send + Sync>;
+
 /// 배치 처리 설정
 #[derive(Debug, Clone)]
 pub struct BatchProcessorConfig {
@@ -22,6 +68,25 @@ pub struct BatchProcessorConfig {
     pub enable_compression: bool,
     pub enable_parallel_processing: bool,
     pub memory_pool_size: usize,
+    pub critical: ClassSla,
+    pub normal: ClassSla,
+    pub bulk: ClassSla,
+}
+
+impl BatchProcessorConfig {
+    pub fn sla(&self, class: PriorityClass) -> &ClassSla {
+        match class {
+            PriorityClass::Critical => &self.critical,
+            PriorityClass::Normal => &self.normal,
+            PriorityClass::Bulk => &self.bulk,
+        }
+    }
+
+    /// 배치 확인 주기 (배치 타임아웃과 가장 짧은 SLA의 절반 중 작은 값)
+    fn tick_ms(&self) -> u64 {
+        let min_sla = PriorityClass::ALL.iter().map(|c| self.sla(*c).max_latency_ms).min().unwrap_or(u64::MAX);
+        self.batch_timeout_ms.min(min_sla / 2).max(1)
+    }
 }
 
 impl Default for BatchProcessorConfig {
@@ -34,6 +99,9 @@ impl Default for BatchProcessorConfig {
             enable_compression: true,
             enable_parallel_processing: true,
             memory_pool_size: 1000,
+            critical: ClassSla { max_latency_ms: 10, min_batch_share: 0.0 },
+            normal: ClassSla { max_latency_ms: 100, min_batch_share: 0.2 },
+            bulk: ClassSla { max_latency_ms: 1000, min_batch_share: 0.1 },
         }
     }
 }
@@ -43,6 +111,10 @@ impl Default for BatchProcessorConfig {
 pub struct BatchMessage<T> {
     pub id: String,
     pub data: T,
+    /// 우선순위 클래스 (없으면 일반)
+    #[serde(default)]
+    pub class: PriorityClass,
+    /// 같은 클래스 안에서의 우선순위 (높을수록 먼저)
     pub priority: u8,
     pub timestamp: u64,
     pub retry_count: u32,
@@ -72,6 +144,83 @@ pub struct BatchStats {
     pub queue_size: usize,
 }
 
+/// 클래스별 처리 통계
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ClassStats {
+    pub class: PriorityClass,
+    pub processed: u64,
+    pub failed: u64,
+    pub queued: usize,
+    /// 큐 대기+처리 시간 (밀리초)
+    pub average_latency_ms: f64,
+    pub max_latency_ms: u64,
+    /// SLA를 넘겨 처리된 메시지 수
+    pub sla_violations: u64,
+    /// 이 클래스의 SLA 때문에 앞당긴 배치 수
+    pub sla_flushes: u64,
+    /// 초당 처리 메시지 (지수 이동 평균)
+    pub throughput_per_second: f64,
+}
+
+/// 큐에 들어간 메시지 (SLA 기준 시각 포함)
+struct QueuedMessage<T> {
+    message: BatchMessage<T>,
+    enqueued_at: Instant,
+}
+
+/// 클래스별 메시지 큐
+struct ClassQueues<T> {
+    queues: [VecDeque<QueuedMessage<T>>; 3],
+}
+
+impl<T> ClassQueues<T> {
+    fn new() -> Self {
+        Self { queues: [VecDeque::new(), VecDeque::new(), VecDeque::new()] }
+    }
+
+    fn len(&self) -> usize {
+        self.queues.iter().map(|q| q.len()).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.queues.iter().all(|q| q.is_empty())
+    }
+
+    fn class_len(&self, class: PriorityClass) -> usize {
+        self.queues[class.index()].len()
+    }
+
+    /// 클래스 안에서는 우선순위 내림차순, 같은 우선순위는 들어온 순
+    fn push(&mut self, message: BatchMessage<T>) {
+        let queue = &mut self.queues[message.class.index()];
+        let position = queue.iter().position(|q| q.message.priority < message.priority).unwrap_or(queue.len());
+        queue.insert(position, QueuedMessage { message, enqueued_at: Instant::now() });
+    }
+
+    /// 가장 오래 기다린 메시지의 대기 시간
+    fn oldest_wait(&self, class: PriorityClass, now: Instant) -> Option<Duration> {
+        self.queues[class.index()].iter().map(|q| now.duration_since(q.enqueued_at)).max()
+    }
+
+    /// 기한이 가까운 메시지를 오래된 순으로 꺼냄
+    fn take_due(&mut self, class: PriorityClass, due_before: Instant, limit: usize, out: &mut Vec<QueuedMessage<T>>) {
+        let queue = &mut self.queues[class.index()];
+        let mut due: Vec<usize> = (0..queue.len()).filter(|&i| queue[i].enqueued_at <= due_before).collect();
+        due.sort_by_key(|&i| queue[i].enqueued_at);
+        due.truncate(limit);
+        due.sort_unstable_by(|a, b| b.cmp(a));
+        let mut taken: Vec<QueuedMessage<T>> = due.into_iter().filter_map(|i| queue.remove(i)).collect();
+        taken.sort_by_key(|q| q.enqueued_at);
+        out.extend(taken);
+    }
+
+    fn take_front(&mut self, class: PriorityClass, limit: usize, out: &mut Vec<QueuedMessage<T>>) {
+        let queue = &mut self.queues[class.index()];
+        let count = limit.min(queue.len());
+        out.extend(queue.drain(..count));
+    }
+}
+
 /// 메모리 풀 관리자
 pub struct MemoryPool<T> {
     pool: Arc<Mutex<VecDeque<T>>>,
@@ -119,12 +268,15 @@ impl<T: Default> MemoryPool<T> {
 /// 배치 처리기
 pub struct BatchProcessor<T, R> {
     config: BatchProcessorConfig,
-    message_queue: Arc<Mutex<VecDeque<BatchMessage<T>>>>,
+    message_queue: Arc<Mutex<ClassQueues<T>>>,
     worker_semaphore: Arc<Semaphore>,
     memory_pool: Arc<MemoryPool<Vec<T>>>,
     stats: Arc<RwLock<BatchStats>>,
+    class_stats: Arc<RwLock<[ClassStats; 3]>>,
+    /// 직전 배치 처리 시각 (배치 타임아웃 기준)
+    last_flush: Arc<Mutex<Instant>>,
     is_running: Arc<Mutex<bool>>,
-    processor_fn: Arc<dyn Fn(Vec<T>) -> Vec<Result<R, String>> + Send + Sync>,
+    processor_fn: ProcessorFn<T, R>,
 }
 
 impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> BatchProcessor<T, R> {
@@ -134,8 +286,7 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> BatchPr
         F: Fn(Vec<T>) -> Vec<Result<R, String>> + Send + Sync + 'static,
     {
         Self {
-            config,
-            message_queue: Arc::new(Mutex::new(VecDeque::new())),
+            message_queue: Arc::new(Mutex::new(ClassQueues::new())),
             worker_semaphore: Arc::new(Semaphore::new(config.max_workers)),
             memory_pool: Arc::new(MemoryPool::new(config.memory_pool_size)),
             stats: Arc::new(RwLock::new(BatchStats {
@@ -148,8 +299,26 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> BatchPr
                 active_workers: 0,
                 queue_size: 0,
             })),
+            class_stats: Arc::new(RwLock::new(PriorityClass::ALL.map(|class| ClassStats { class, ..Default::default() }))),
+            last_flush: Arc::new(Mutex::new(Instant::now())),
             is_running: Arc::new(Mutex::new(false)),
             processor_fn: Arc::new(processor_fn),
+            config,
+        }
+    }
+
+    /// 태스크에 넘길 공유 핸들 (같은 큐와 통계를 가리킴)
+    fn handle(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            message_queue: self.message_queue.clone(),
+            worker_semaphore: self.worker_semaphore.clone(),
+            memory_pool: self.memory_pool.clone(),
+            stats: self.stats.clone(),
+            class_stats: self.class_stats.clone(),
+            last_flush: self.last_flush.clone(),
+            is_running: self.is_running.clone(),
+            processor_fn: self.processor_fn.clone(),
         }
     }
 
@@ -163,41 +332,29 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> BatchPr
         *is_running = true;
         drop(is_running);
 
-        info!("배치 처리기 시작: 배치크기={}, 워커={}개", 
-              self.config.batch_size, self.config.max_workers);
+        info!("배치 처리기 시작: 배치크기={}, 워커={}개, SLA 긴급/일반/대량={}/{}/{}ms", 
+              self.config.batch_size, self.config.max_workers,
+              self.config.critical.max_latency_ms, self.config.normal.max_latency_ms, self.config.bulk.max_latency_ms);
 
-        let message_queue = self.message_queue.clone();
-        let worker_semaphore = self.worker_semaphore.clone();
-        let memory_pool = self.memory_pool.clone();
-        let stats = self.stats.clone();
-        let processor_fn = self.processor_fn.clone();
-        let config = self.config.clone();
-        let is_running = self.is_running.clone();
+        let processor = self.handle();
 
-        // 배치 처리 태스크
+        // 배치 처리 태스크 (SLA를 지키도록 배치 타임아웃보다 자주 확인)
         tokio::spawn(async move {
-            let mut interval = interval(Duration::from_millis(config.batch_timeout_ms));
+            let mut interval = interval(Duration::from_millis(processor.config.tick_ms()));
 
             loop {
                 interval.tick().await;
 
                 // 실행 중단 확인
                 {
-                    let running = is_running.lock().await;
+                    let running = processor.is_running.lock().await;
                     if !*running {
                         break;
                     }
                 }
 
                 // 배치 처리 시도
-                if let Err(e) = Self::process_batch(
-                    &message_queue,
-                    &worker_semaphore,
-                    &memory_pool,
-                    &stats,
-                    &processor_fn,
-                    &config,
-                ).await {
+                if let Err(e) = processor.process_batch().await {
                     error!("배치 처리 실패: {}", e);
                 }
             }
@@ -221,78 +378,105 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> BatchPr
             return Err("큐가 가득참".to_string());
         }
 
-        queue.push_back(message);
-        
-        // 우선순위 기준으로 정렬
-        queue.make_contiguous().sort_by(|a, b| b.priority.cmp(&a.priority));
+        queue.push(message);
         
         Ok(())
     }
 
+    /// 이번 확인에서 배치를 만들지 결정하고 메시지 선택
+    ///
+    /// 배치 크기가 찼거나, 배치 타임아웃이 지났거나, 어느 클래스든 다음 확인 전에 SLA를 넘길 메시지가 있으면 만듭니다.
+    /// 선택 순서는 SLA 임박 메시지(긴급 클래스부터) → 클래스별 최소 몫 → 남은 자리를 긴급 클래스부터.
+    async fn select_batch(&self) -> (Vec<QueuedMessage<T>>, Option<PriorityClass>) {
+        let now = Instant::now();
+        let tick = Duration::from_millis(self.config.tick_ms());
+        let batch_size = self.config.batch_size.max(1);
+        let mut queue = self.message_queue.lock().await;
+        if queue.is_empty() {
+            return (Vec::new(), None);
+        }
+
+        // 다음 확인 전에 SLA를 넘길 클래스
+        let due_class = PriorityClass::ALL.into_iter().find(|class| {
+            let max_latency = Duration::from_millis(self.config.sla(*class).max_latency_ms);
+            queue.oldest_wait(*class, now).is_some_and(|wait| wait + tick >= max_latency)
+        });
+        let mut last_flush = self.last_flush.lock().await;
+        let timed_out = now.duration_since(*last_flush) >= Duration::from_millis(self.config.batch_timeout_ms);
+        let full = queue.len() >= batch_size;
+        if !full && !timed_out && due_class.is_none() {
+            return (Vec::new(), None);
+        }
+        *last_flush = now;
+
+        let mut batch = Vec::with_capacity(batch_size.min(queue.len()));
+        for class in PriorityClass::ALL {
+            let max_latency = Duration::from_millis(self.config.sla(class).max_latency_ms);
+            if let Some(due_before) = (now + tick).checked_sub(max_latency) {
+                let limit = batch_size - batch.len();
+                queue.take_due(class, due_before, limit, &mut batch);
+            }
+        }
+        for class in PriorityClass::ALL {
+            let share = (self.config.sla(class).min_batch_share.clamp(0.0, 1.0) * batch_size as f64).ceil() as usize;
+            let already = batch.iter().filter(|q| q.message.class == class).count();
+            let limit = share.saturating_sub(already).min(batch_size - batch.len());
+            queue.take_front(class, limit, &mut batch);
+        }
+        for class in PriorityClass::ALL {
+            let limit = batch_size - batch.len();
+            queue.take_front(class, limit, &mut batch);
+        }
+
+        // SLA 때문에 앞당긴 배치만 기록
+        (batch, due_class.filter(|_| !full && !timed_out))
+    }
+
     /// 배치 처리 실행
-    async fn process_batch(
-        message_queue: &Arc<Mutex<VecDeque<BatchMessage<T>>>>,
-        worker_semaphore: &Arc<Semaphore>,
-        memory_pool: &Arc<MemoryPool<Vec<T>>>,
-        stats: &Arc<RwLock<BatchStats>>,
-        processor_fn: &Arc<dyn Fn(Vec<T>) -> Vec<Result<R, String>> + Send + Sync>,
-        config: &BatchProcessorConfig,
-    ) -> Result<(), String> {
+    async fn process_batch(&self) -> Result<(), String> {
         // 배치 메시지 수집
-        let batch_messages = {
-            let mut queue = message_queue.lock().await;
-            let mut batch = Vec::new();
-            
-            for _ in 0..config.batch_size {
-                if let Some(message) = queue.pop_front() {
-                    batch.push(message);
-                } else {
-                    break;
-                }
-            }
-            
-            batch
-        };
+        let (batch_messages, sla_flush) = self.select_batch().await;
 
         if batch_messages.is_empty() {
             return Ok(());
         }
 
         // 워커 세마포어 획득
-        let _permit = worker_semaphore.acquire().await
+        let _permit = self.worker_semaphore.acquire().await
             .map_err(|e| format!("워커 세마포어 획득 실패: {}", e))?;
 
         // 메모리 풀에서 벡터 가져오기
-        let mut batch_data = memory_pool.get().await;
+        let mut batch_data = self.memory_pool.get().await;
         batch_data.clear();
 
         // 배치 데이터 준비
-        for message in &batch_messages {
-            batch_data.push(message.data.clone());
+        for queued in &batch_messages {
+            batch_data.push(queued.message.data.clone());
         }
 
-        let batch_id = uuid::Uuid::new_v4().to_string();
         let start_time = SystemTime::now();
 
         // 병렬 처리 활성화된 경우 별도 태스크에서 처리
-        let result = if config.enable_parallel_processing {
-            let processor_fn = processor_fn.clone();
+        let result = if self.config.enable_parallel_processing {
+            let processor_fn = self.processor_fn.clone();
             let batch_data = batch_data.clone();
             
             tokio::task::spawn_blocking(move || {
                 processor_fn(batch_data)
             }).await.map_err(|e| format!("병렬 처리 실패: {}", e))?
         } else {
-            processor_fn(batch_data)
+            (self.processor_fn)(batch_data.clone())
         };
 
         let processing_time = start_time.elapsed().unwrap().as_millis() as u64;
 
         // 메모리 풀에 벡터 반환
-        memory_pool.put(batch_data).await;
+        self.memory_pool.put(batch_data).await;
 
         // 통계 업데이트
-        Self::update_stats(stats, &batch_messages, &result, processing_time).await;
+        let messages: Vec<BatchMessage<T>> = batch_messages.iter().map(|q| q.message.clone()).collect();
+        Self::update_stats(&self.stats, &messages, &result, processing_time).await;
+        self.update_class_stats(&batch_messages, &result, processing_time, sla_flush).await;
 
         debug!("배치 처리 완료: {}개 메시지, {}ms", 
                batch_messages.len(), processing_time);
@@ -300,6 +484,44 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> BatchPr
         Ok(())
     }
 
+    /// 클래스별 통계 업데이트 (지연은 큐에 들어온 시각부터 처리 완료까지)
+    async fn update_class_stats(
+        &self,
+        batch: &[QueuedMessage<T>],
+        results: &[Result<R, String>],
+        processing_time_ms: u64,
+        sla_flush: Option<PriorityClass>,
+    ) {
+        let now = Instant::now();
+        let mut class_stats = self.class_stats.write().await;
+        if let Some(class) = sla_flush {
+            class_stats[class.index()].sla_flushes += 1;
+        }
+        let mut counts = [0u64; 3];
+        for (i, queued) in batch.iter().enumerate() {
+            let class = queued.message.class;
+            let latency_ms = now.duration_since(queued.enqueued_at).as_millis() as u64;
+            let stats = &mut class_stats[class.index()];
+            stats.processed += 1;
+            if matches!(results.get(i), Some(Err(_))) {
+                stats.failed += 1;
+            }
+            stats.average_latency_ms += (latency_ms as f64 - stats.average_latency_ms) / stats.processed as f64;
+            stats.max_latency_ms = stats.max_latency_ms.max(latency_ms);
+            if latency_ms > self.config.sla(class).max_latency_ms {
+                stats.sla_violations += 1;
+            }
+            counts[class.index()] += 1;
+        }
+        if processing_time_ms > 0 {
+            for class in PriorityClass::ALL {
+                let throughput = (counts[class.index()] as f64 * 1000.0) / processing_time_ms as f64;
+                let stats = &mut class_stats[class.index()];
+                stats.throughput_per_second = (stats.throughput_per_second * 0.9) + (throughput * 0.1); // 지수 이동 평균
+            }
+        }
+    }
+
     /// 통계 업데이트
     async fn update_stats(
         stats: &Arc<RwLock<BatchStats>>,
@@ -344,6 +566,31 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> BatchPr
         stats
     }
 
+    /// 클래스별 통계 조회 (긴급, 일반, 대량 순)
+    pub async fn get_class_stats(&self) -> Vec<ClassStats> {
+        let mut class_stats = self.class_stats.read().await.to_vec();
+        let queue = self.message_queue.lock().await;
+        for stats in &mut class_stats {
+            stats.queued = queue.class_len(stats.class);
+        }
+        class_stats
+    }
+
+    /// MetricsCollector로 클래스별 게이지 발행 (`batch.{class}.*`)
+    pub async fn publish(&self, collector: &MetricsCollector) {
+        for stats in self.get_class_stats().await {
+            let prefix = format!("batch.{}", stats.class.as_str());
+            collector.set_gauge(&format!("{}.queued", prefix), stats.queued as u64).await;
+            collector.set_gauge(&format!("{}.processed", prefix), stats.processed).await;
+            collector.set_gauge(&format!("{}.failed", prefix), stats.failed).await;
+            collector.set_gauge(&format!("{}.latency.avg_ms", prefix), stats.average_latency_ms.round() as u64).await;
+            collector.set_gauge(&format!("{}.latency.max_ms", prefix), stats.max_latency_ms).await;
+            collector.set_gauge(&format!("{}.sla_violations", prefix), stats.sla_violations).await;
+            collector.set_gauge(&format!("{}.sla_flushes", prefix), stats.sla_flushes).await;
+            collector.set_gauge(&format!("{}.throughput_per_sec", prefix), stats.throughput_per_second.round() as u64).await;
+        }
+    }
+
     /// 큐 크기 조회
     pub async fn queue_size(&self) -> usize {
         let queue = self.message_queue.lock().await;
@@ -389,6 +636,12 @@ pub struct PerformanceMonitor {
     total_time_ms: AtomicUsize,
 }
 
+impl Default for PerformanceMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PerformanceMonitor {
     pub fn new() -> Self {
         Self {
@@ -406,7 +659,8 @@ impl PerformanceMonitor {
     pub fn get_metrics(&self) -> (usize, f64, f64) {
         let operations = self.operation_counts.load(Ordering::Relaxed);
         let total_time = self.total_time_ms.load(Ordering::Relaxed);
-        let uptime_ms = self.start_time.elapsed().unwrap().as_millis() as u64;
+        // 밀리초 단위로 자르면 생성 직후 조회에서 0이 되므로 초 단위 실수로 계산
+        let uptime_secs = self.start_time.elapsed().unwrap_or_default().as_secs_f64();
         
         let avg_time_ms = if operations > 0 {
             total_time as f64 / operations as f64
@@ -414,8 +668,8 @@ impl PerformanceMonitor {
             0.0
         };
         
-        let ops_per_second = if uptime_ms > 0 {
-            (operations as f64 * 1000.0) / uptime_ms as f64
+        let ops_per_second = if uptime_secs > 0.0 {
+            operations as f64 / uptime_secs
         } else {
             0.0
         };
@@ -427,11 +681,13 @@ impl PerformanceMonitor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::UNIX_EPOCH;
+    use tokio::time::sleep;
 
     #[tokio::test]
     async fn test_batch_processor_creation() {
         let config = BatchProcessorConfig::default();
-        let processor = BatchProcessor::new(config, |data| {
+        let processor = BatchProcessor::new(config, |data: Vec<String>| {
             data.into_iter().map(|x| Ok(format!("processed_{}", x))).collect()
         });
         
@@ -469,9 +725,10 @@ mod tests {
             enable_compression: false,
             enable_parallel_processing: false,
             memory_pool_size: 10,
+            ..Default::default()
         };
         
-        let processor = BatchProcessor::new(config, |data| {
+        let processor = BatchProcessor::new(config, |data: Vec<String>| {
             data.into_iter().map(|x| Ok(format!("processed_{}", x))).collect()
         });
         
@@ -480,6 +737,7 @@ mod tests {
             let message = BatchMessage {
                 id: format!("msg_{}", i),
                 data: format!("data_{}", i),
+                class: PriorityClass::Normal,
                 priority: 5,
                 timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64,
                 retry_count: 0,
@@ -501,6 +759,83 @@ mod tests {
         processor.stop().await;
     }
 
+    fn message(id: &str, class: PriorityClass, priority: u8) -> BatchMessage<String> {
+        BatchMessage {
+            id: id.to_string(),
+            data: id.to_string(),
+            class,
+            priority,
+            timestamp: 0,
+            retry_count: 0,
+        }
+    }
+
+    fn echo_processor(config: BatchProcessorConfig) -> BatchProcessor<String, String> {
+        BatchProcessor::new(config, |data: Vec<String>| data.into_iter().map(Ok).collect())
+    }
+
+    #[tokio::test]
+    async fn test_class_order_and_min_share() {
+        let relaxed = |min_batch_share| ClassSla { max_latency_ms: 60_000, min_batch_share };
+        let config = BatchProcessorConfig {
+            batch_size: 10,
+            critical: relaxed(0.0),
+            normal: relaxed(0.2),
+            bulk: relaxed(0.1),
+            ..Default::default()
+        };
+        let processor = echo_processor(config);
+        for i in 0..5 {
+            processor.add_message(message(&format!("bulk_{}", i), PriorityClass::Bulk, 0)).await.unwrap();
+        }
+        for i in 0..20 {
+            processor.add_message(message(&format!("critical_{}", i), PriorityClass::Critical, 0)).await.unwrap();
+        }
+        processor.add_message(message("critical_urgent", PriorityClass::Critical, 9)).await.unwrap();
+
+        // 긴급 클래스가 자리를 채우더라도 대량 클래스는 최소 몫(10%)을 받음, 클래스 안에서는 우선순위 순
+        let (batch, sla_flush) = processor.select_batch().await;
+        let ids: Vec<&str> = batch.iter().map(|q| q.message.id.as_str()).collect();
+        assert_eq!(ids.len(), 10);
+        assert_eq!(ids.iter().filter(|id| id.starts_with("bulk")).count(), 1);
+        assert_eq!(ids[0], "bulk_0");
+        assert_eq!(ids[1], "critical_urgent");
+        assert_eq!(sla_flush, None);
+        assert_eq!(processor.queue_size().await, 16);
+    }
+
+    #[tokio::test]
+    async fn test_sla_forces_early_flush() {
+        let config = BatchProcessorConfig {
+            batch_timeout_ms: 10_000,
+            enable_parallel_processing: false,
+            critical: ClassSla { max_latency_ms: 50, min_batch_share: 0.0 },
+            ..Default::default()
+        };
+        let processor = echo_processor(config);
+        processor.add_message(message("fill", PriorityClass::Critical, 0)).await.unwrap();
+        processor.add_message(message("report", PriorityClass::Bulk, 0)).await.unwrap();
+
+        // 배치도 차지 않았고 타임아웃 전이며 SLA까지 여유가 있으면 보류
+        processor.process_batch().await.unwrap();
+        assert_eq!(processor.queue_size().await, 2);
+
+        // 긴급 메시지가 SLA에 가까워지면 배치를 앞당기고, 남는 자리는 다른 클래스로 채움
+        sleep(Duration::from_millis(30)).await;
+        processor.process_batch().await.unwrap();
+        assert_eq!(processor.queue_size().await, 0);
+
+        let stats = processor.get_class_stats().await;
+        let critical = &stats[PriorityClass::Critical.index()];
+        assert_eq!((critical.processed, critical.sla_flushes, critical.sla_violations), (1, 1, 0));
+        assert_eq!(stats[PriorityClass::Bulk.index()].processed, 1);
+
+        let collector = MetricsCollector::new(Default::default());
+        processor.publish(&collector).await;
+        assert_eq!(collector.get_gauge("batch.critical.sla_flushes").await, Some(1));
+        assert_eq!(collector.get_gauge("batch.bulk.processed").await, Some(1));
+    }
+
     #[tokio::test]
     async fn test_performance_monitor() {
         let monitor = PerformanceMonitor::new();
@@ -524,6 +859,6 @@ mod tests {
         assert_eq!(data, decompressed.as_slice());
         
         let ratio = CompressionUtils::compression_ratio(data.len(), compressed.len());
-        assert!(ratio >= 0.0 && ratio <= 1.0);
+        assert!((0.0..=1.0).contains(&ratio));
     }
 }
\ No newline at end of file
@@ -3,15 +3,20 @@
 //! 이 모듈은 다단계 캐시, 압축, LRU/LFU 알고리즘을 통해
 //! 고성능 캐시 시스템을 제공합니다.
 
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use std::collections::{HashMap, VecDeque, BTreeMap};
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
-use log::{info, error, warn, debug};
-use tokio::time::{sleep, interval};
-use std::sync::atomic::{AtomicUsize, AtomicU64, Ordering};
-use std::hash::{Hash, Hasher};
+use log::{info, warn, debug};
+use tokio::time::interval;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::adaptive_cache::{AdaptiveCacheConfig, AdaptiveTierController, FrequencySketch, TierResizeDecision, TierSnapshot};
+use super::metrics_collector::{MetricData, MetricType, MetricsCollector};
+
+/// 보관하는 최근 크기 조정 결정 수
+const MAX_RESIZE_DECISIONS: usize = 100;
 
 /// 캐시 설정
 #[derive(Debug, Clone)]
@@ -25,6 +30,8 @@ pub struct CacheOptimizerConfig {
     pub enable_lru: bool,         // LRU 활성화
     pub enable_lfu: bool,         // LFU 활성화
     pub cleanup_interval_ms: u64, // 정리 간격
+    /// L1/L2 크기 자동 조정 (None이면 고정 크기)
+    pub adaptive: Option<AdaptiveCacheConfig>,
 }
 
 impl Default for CacheOptimizerConfig {
@@ -39,6 +46,7 @@ impl Default for CacheOptimizerConfig {
             enable_lru: true,
             enable_lfu: false,
             cleanup_interval_ms: 60000, // 1분
+            adaptive: None,
         }
     }
 }
@@ -56,7 +64,7 @@ pub struct CacheItem<T> {
 }
 
 /// 캐시 레벨
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum CacheLevel {
     L1, // 메모리 (가장 빠름)
     L2, // 메모리 (중간)
@@ -128,7 +136,7 @@ impl<T: Clone> LRUCache<T> {
             self.move_to_head(index);
         } else {
             // 새 키 추가
-            if self.nodes.len() >= self.capacity {
+            if self.is_full() {
                 self.evict_tail();
             }
             
@@ -150,11 +158,59 @@ impl<T: Clone> LRUCache<T> {
     }
 
     pub fn size(&self) -> usize {
-        self.nodes.len()
+        self.key_to_index.len()
     }
 
     pub fn is_full(&self) -> bool {
-        self.nodes.len() >= self.capacity
+        self.key_to_index.len() >= self.capacity
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.key_to_index.contains_key(key)
+    }
+
+    /// 다음에 밀려날 (가장 오래 쓰지 않은) 키
+    pub fn peek_lru_key(&self) -> Option<&str> {
+        self.tail.map(|index| self.nodes[index].key.as_str())
+    }
+
+    /// 가장 오래 쓰지 않은 항목 꺼내기
+    pub fn pop_lru(&mut self) -> Option<(String, T)> {
+        let index = self.tail?;
+        let key = self.nodes[index].key.clone();
+        let value = self.nodes[index].value.clone();
+        self.remove_node(index);
+        self.key_to_index.remove(&key);
+        Some((key, value))
+    }
+
+    /// 용량 변경, 줄어든 만큼 오래된 항목부터 꺼내 반환
+    pub fn set_capacity(&mut self, capacity: usize) -> Vec<(String, T)> {
+        self.capacity = capacity;
+        let mut evicted = Vec::new();
+        while self.key_to_index.len() > self.capacity {
+            match self.pop_lru() {
+                Some(entry) => evicted.push(entry),
+                None => break,
+            }
+        }
+        evicted
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.key_to_index.values().map(|&index| &self.nodes[index].value)
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.key_to_index.clear();
+        self.free_indices.clear();
+        self.head = None;
+        self.tail = None;
     }
 
     fn allocate_node(&mut self, key: String, value: T) -> usize {
@@ -261,7 +317,7 @@ impl<T: Clone> LFUCache<T> {
             
             self.cache.insert(key.clone(), value);
             self.frequencies.insert(key.clone(), 1);
-            self.frequency_groups.entry(1).or_insert_with(Vec::new).push(key);
+            self.frequency_groups.entry(1).or_default().push(key);
             self.min_frequency = 1;
         }
     }
@@ -306,13 +362,15 @@ impl<T: Clone> LFUCache<T> {
             
             // 새 빈도 그룹에 추가
             self.frequencies.insert(key.to_string(), new_freq);
-            self.frequency_groups.entry(new_freq).or_insert_with(Vec::new).push(key.to_string());
+            self.frequency_groups.entry(new_freq).or_default().push(key.to_string());
         }
     }
 
     fn evict_least_frequent(&mut self) {
         if let Some(group) = self.frequency_groups.get_mut(&self.min_frequency) {
-            if let Some(key_to_remove) = group.pop() {
+            // 빈도가 같으면 그룹에 가장 먼저 들어온 키(가장 오래 쓰이지 않은 키) 제거
+            if !group.is_empty() {
+                let key_to_remove = group.remove(0);
                 self.cache.remove(&key_to_remove);
                 self.frequencies.remove(&key_to_remove);
                 
@@ -332,14 +390,23 @@ pub struct CacheOptimizer<T> {
     l2_cache: Arc<Mutex<LRUCache<CacheItem<T>>>>,
     l3_cache: Arc<Mutex<HashMap<String, CacheItem<T>>>>,
     stats: Arc<RwLock<CacheStats>>,
+    /// 키별 접근 빈도 (하위 계층 → L1 승격 판단)
+    sketch: Arc<Mutex<FrequencySketch>>,
+    controller: Option<Arc<Mutex<AdaptiveTierController>>>,
+    decisions: Arc<RwLock<VecDeque<TierResizeDecision>>>,
+    /// 메트릭으로 내보낸 마지막 결정 시각
+    published_decisions_ms: Arc<AtomicU64>,
+    /// 승격 허용/거부 횟수
+    admitted: Arc<AtomicU64>,
+    rejected: Arc<AtomicU64>,
     is_running: Arc<Mutex<bool>>,
 }
 
 impl<T: Clone + Send + Sync + 'static> CacheOptimizer<T> {
     /// 새 캐시 최적화기 생성
     pub fn new(config: CacheOptimizerConfig) -> Self {
+        let sketch_capacity = config.adaptive.as_ref().map_or(config.l1_size + config.l2_size, |a| a.l1_max + a.l2_max);
         Self {
-            config,
             l1_cache: Arc::new(Mutex::new(LRUCache::new(config.l1_size))),
             l2_cache: Arc::new(Mutex::new(LRUCache::new(config.l2_size))),
             l3_cache: Arc::new(Mutex::new(HashMap::new())),
@@ -356,7 +423,14 @@ impl<T: Clone + Send + Sync + 'static> CacheOptimizer<T> {
                 total_size_bytes: 0,
                 compression_ratio: 0.0,
             })),
+            sketch: Arc::new(Mutex::new(FrequencySketch::new(sketch_capacity))),
+            controller: config.adaptive.clone().map(|a| Arc::new(Mutex::new(AdaptiveTierController::new(a)))),
+            decisions: Arc::new(RwLock::new(VecDeque::new())),
+            published_decisions_ms: Arc::new(AtomicU64::new(0)),
+            admitted: Arc::new(AtomicU64::new(0)),
+            rejected: Arc::new(AtomicU64::new(0)),
             is_running: Arc::new(Mutex::new(false)),
+            config,
         }
     }
 
@@ -380,6 +454,23 @@ impl<T: Clone + Send + Sync + 'static> CacheOptimizer<T> {
         let config = self.config.clone();
         let is_running = self.is_running.clone();
 
+        // 계층 크기 자동 조정 태스크
+        if let Some(controller) = self.controller.clone() {
+            let optimizer = self.handle();
+            let interval_ms = controller.lock().await.config().interval_ms.max(1);
+            tokio::spawn(async move {
+                let mut interval = interval(Duration::from_millis(interval_ms));
+                interval.tick().await;
+                loop {
+                    interval.tick().await;
+                    if !*optimizer.is_running.lock().await {
+                        break;
+                    }
+                    optimizer.adjust_tiers(&controller).await;
+                }
+            });
+        }
+
         // 캐시 정리 태스크
         tokio::spawn(async move {
             let mut interval = interval(Duration::from_millis(config.cleanup_interval_ms));
@@ -416,12 +507,14 @@ impl<T: Clone + Send + Sync + 'static> CacheOptimizer<T> {
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_secs();
+        self.sketch.lock().await.increment(key);
 
         // L1 캐시 확인
         {
             let mut l1 = self.l1_cache.lock().await;
             if let Some(item) = l1.get(key) {
                 if current_time - item.created_at < self.config.ttl_seconds {
+                    drop(l1);
                     self.update_stats(CacheLevel::L1).await;
                     return Some(item.value);
                 } else {
@@ -431,51 +524,119 @@ impl<T: Clone + Send + Sync + 'static> CacheOptimizer<T> {
         }
 
         // L2 캐시 확인
-        {
+        let found = {
             let mut l2 = self.l2_cache.lock().await;
-            if let Some(item) = l2.get(key) {
-                if current_time - item.created_at < self.config.ttl_seconds {
-                    // L1로 승격
-                    let mut l1 = self.l1_cache.lock().await;
-                    l1.put(key.to_string(), item.clone());
-                    l2.remove(key);
-                    
-                    self.update_stats(CacheLevel::L2).await;
-                    return Some(item.value);
-                } else {
+            match l2.get(key) {
+                Some(item) if current_time - item.created_at < self.config.ttl_seconds => Some(item),
+                Some(_) => {
                     l2.remove(key);
+                    None
                 }
+                None => None,
             }
+        };
+        if let Some(item) = found {
+            let value = item.value.clone();
+            self.promote(key, item, CacheLevel::L2).await;
+            self.update_stats(CacheLevel::L2).await;
+            return Some(value);
         }
 
         // L3 캐시 확인
-        {
+        let found = {
             let mut l3 = self.l3_cache.lock().await;
-            if let Some(item) = l3.get(key).cloned() {
-                if current_time - item.created_at < self.config.ttl_seconds {
-                    // L1로 승격
-                    let mut l1 = self.l1_cache.lock().await;
-                    l1.put(key.to_string(), item.clone());
-                    l3.remove(key);
-                    
-                    self.update_stats(CacheLevel::L3).await;
-                    return Some(item.value);
-                } else {
+            match l3.get(key).cloned() {
+                Some(item) if current_time - item.created_at < self.config.ttl_seconds => Some(item),
+                Some(_) => {
                     l3.remove(key);
+                    None
                 }
+                None => None,
             }
+        };
+        if let Some(item) = found {
+            let value = item.value.clone();
+            self.promote(key, item, CacheLevel::L3).await;
+            self.update_stats(CacheLevel::L3).await;
+            return Some(value);
         }
 
         self.update_stats(CacheLevel::Miss).await;
         None
     }
 
+    /// 하위 계층 적중 항목을 L1로 승격 (L1이 가득 차면 밀려날 항목보다 자주 쓰일 때만, TinyLFU)
+    async fn promote(&self, key: &str, item: CacheItem<T>, from: CacheLevel) {
+        // 잠금 순서는 항상 L1 → L2 → L3
+        let mut l1 = self.l1_cache.lock().await;
+        let admit = match l1.peek_lru_key() {
+            Some(victim) if l1.is_full() => {
+                let sketch = self.sketch.lock().await;
+                sketch.estimate(key) > sketch.estimate(victim)
+            }
+            _ => true,
+        };
+        if !admit {
+            self.rejected.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        self.admitted.fetch_add(1, Ordering::Relaxed);
+
+        let mut l2 = self.l2_cache.lock().await;
+        let mut l3 = self.l3_cache.lock().await;
+        match from {
+            CacheLevel::L2 => {
+                l2.remove(key);
+            }
+            CacheLevel::L3 => {
+                l3.remove(key);
+            }
+            _ => {}
+        }
+        let victim = if l1.is_full() { l1.pop_lru() } else { None };
+        l1.put(key.to_string(), item);
+        if let Some((victim_key, victim_item)) = victim {
+            Self::demote_to_l2(&mut l2, &mut l3, victim_key, victim_item, self.config.l3_size);
+        }
+    }
+
+    /// L1에서 밀려난 항목을 L2로 (L2가 가득 차면 L2의 가장 오래된 항목은 L3로)
+    fn demote_to_l2(
+        l2: &mut LRUCache<CacheItem<T>>,
+        l3: &mut HashMap<String, CacheItem<T>>,
+        key: String,
+        item: CacheItem<T>,
+        l3_capacity: usize,
+    ) {
+        if l2.is_full() && !l2.contains(&key) {
+            if let Some((victim_key, victim_item)) = l2.pop_lru() {
+                Self::demote_to_l3(l3, victim_key, victim_item, l3_capacity);
+            }
+        }
+        l2.put(key, item);
+    }
+
+    /// L3에 저장 (가득 차면 가장 오래 접근하지 않은 항목 제거)
+    fn demote_to_l3(l3: &mut HashMap<String, CacheItem<T>>, key: String, item: CacheItem<T>, l3_capacity: usize) {
+        if l3_capacity == 0 {
+            return;
+        }
+        if l3.len() >= l3_capacity && !l3.contains_key(&key) {
+            let oldest = l3.iter().min_by_key(|(_, item)| item.last_accessed).map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                l3.remove(&oldest);
+            }
+        }
+        l3.insert(key, item);
+    }
+
     /// 캐시에 값 저장
     pub async fn put(&self, key: String, value: T) {
         let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_secs();
+        self.sketch.lock().await.increment(&key);
 
         let size_bytes = std::mem::size_of_val(&value);
         let compressed = self.config.enable_compression && size_bytes > self.config.compression_threshold;
@@ -490,9 +651,17 @@ impl<T: Clone + Send + Sync + 'static> CacheOptimizer<T> {
             size_bytes,
         };
 
-        // L1 캐시에 저장
+        // L1 캐시에 저장 (하위 계층의 이전 값은 제거, 밀려난 항목은 L2로)
         let mut l1 = self.l1_cache.lock().await;
+        let mut l2 = self.l2_cache.lock().await;
+        let mut l3 = self.l3_cache.lock().await;
+        l2.remove(&key);
+        l3.remove(&key);
+        let victim = if l1.is_full() && !l1.contains(&key) { l1.pop_lru() } else { None };
         l1.put(key, item);
+        if let Some((victim_key, victim_item)) = victim {
+            Self::demote_to_l2(&mut l2, &mut l3, victim_key, victim_item, self.config.l3_size);
+        }
     }
 
     /// 캐시에서 값 제거
@@ -517,10 +686,10 @@ impl<T: Clone + Send + Sync + 'static> CacheOptimizer<T> {
 
     /// 만료된 항목 정리
     async fn cleanup_expired_items(
-        l1_cache: &Arc<Mutex<LRUCache<CacheItem<T>>>>,
-        l2_cache: &Arc<Mutex<LRUCache<CacheItem<T>>>>,
+        _l1_cache: &Arc<Mutex<LRUCache<CacheItem<T>>>>,
+        _l2_cache: &Arc<Mutex<LRUCache<CacheItem<T>>>>,
         l3_cache: &Arc<Mutex<HashMap<String, CacheItem<T>>>>,
-        stats: &Arc<RwLock<CacheStats>>,
+        _stats: &Arc<RwLock<CacheStats>>,
     ) {
         let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -577,10 +746,134 @@ impl<T: Clone + Send + Sync + 'static> CacheOptimizer<T> {
         (l1_size, l2_size, l3_size)
     }
 
+    /// 태스크에 넘길 공유 핸들 (같은 캐시 계층과 통계를 가리킴)
+    fn handle(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            l1_cache: self.l1_cache.clone(),
+            l2_cache: self.l2_cache.clone(),
+            l3_cache: self.l3_cache.clone(),
+            stats: self.stats.clone(),
+            sketch: self.sketch.clone(),
+            controller: self.controller.clone(),
+            decisions: self.decisions.clone(),
+            published_decisions_ms: self.published_decisions_ms.clone(),
+            admitted: self.admitted.clone(),
+            rejected: self.rejected.clone(),
+            is_running: self.is_running.clone(),
+        }
+    }
+
+    /// 계층 용량 (L1, L2)
+    pub async fn get_tier_capacities(&self) -> (usize, usize) {
+        let l1 = self.l1_cache.lock().await.capacity();
+        let l2 = self.l2_cache.lock().await.capacity();
+        (l1, l2)
+    }
+
+    /// 자동 조정 1회 실행 (자동 조정이 꺼져 있으면 None)
+    pub async fn adjust_now(&self) -> Option<TierResizeDecision> {
+        let controller = self.controller.clone()?;
+        self.adjust_tiers(&controller).await
+    }
+
+    async fn adjust_tiers(&self, controller: &Mutex<AdaptiveTierController>) -> Option<TierResizeDecision> {
+        let mut l1 = self.l1_cache.lock().await;
+        let mut l2 = self.l2_cache.lock().await;
+        let memory_bytes: usize = l1.values().chain(l2.values()).map(|item| item.size_bytes).sum();
+        let tiers = TierSnapshot {
+            l1_capacity: l1.capacity(),
+            l1_len: l1.size(),
+            l2_capacity: l2.capacity(),
+            l2_len: l2.size(),
+            memory_bytes,
+        };
+        let stats = {
+            let mut stats = self.stats.write().await;
+            stats.total_size_bytes = memory_bytes;
+            stats.clone()
+        };
+
+        let decision = controller.lock().await.evaluate(&stats, &tiers)?;
+        let mut l3 = self.l3_cache.lock().await;
+        if decision.tier == CacheLevel::L1 {
+            for (key, item) in l1.set_capacity(decision.to) {
+                Self::demote_to_l2(&mut l2, &mut l3, key, item, self.config.l3_size);
+            }
+        } else {
+            for (key, item) in l2.set_capacity(decision.to) {
+                Self::demote_to_l3(&mut l3, key, item, self.config.l3_size);
+            }
+        }
+        info!(
+            "캐시 {:?} 용량 조정: {} → {} ({}, 구간 적중률 {:.1}%)",
+            decision.tier, decision.from, decision.to, decision.reason.as_str(), decision.hit_rate * 100.0
+        );
+
+        let mut decisions = self.decisions.write().await;
+        decisions.push_back(decision.clone());
+        if decisions.len() > MAX_RESIZE_DECISIONS {
+            decisions.pop_front();
+        }
+        Some(decision)
+    }
+
+    /// 최근 크기 조정 결정 (오래된 순)
+    pub async fn get_resize_decisions(&self) -> Vec<TierResizeDecision> {
+        self.decisions.read().await.iter().cloned().collect()
+    }
+
+    /// MetricsCollector로 계층 용량/승격 지표 발행 (`cache.*`), 새 조정 결정은 `cache.resize` 커스텀 메트릭으로
+    pub async fn publish(&self, collector: &MetricsCollector) {
+        let (l1_capacity, l2_capacity) = self.get_tier_capacities().await;
+        let (l1_size, l2_size, l3_size) = self.get_cache_sizes().await;
+        collector.set_gauge("cache.l1.capacity", l1_capacity as u64).await;
+        collector.set_gauge("cache.l2.capacity", l2_capacity as u64).await;
+        collector.set_gauge("cache.l1.size", l1_size as u64).await;
+        collector.set_gauge("cache.l2.size", l2_size as u64).await;
+        collector.set_gauge("cache.l3.size", l3_size as u64).await;
+        collector.set_gauge("cache.memory_bytes", self.stats.read().await.total_size_bytes as u64).await;
+        collector.set_gauge("cache.tinylfu.admitted", self.admitted.load(Ordering::Relaxed)).await;
+        collector.set_gauge("cache.tinylfu.rejected", self.rejected.load(Ordering::Relaxed)).await;
+
+        let Some(controller) = &self.controller else {
+            return;
+        };
+        {
+            let controller = controller.lock().await;
+            let (grown, shrunk) = controller.resize_counts();
+            collector.set_gauge("cache.resize.grown", grown).await;
+            collector.set_gauge("cache.resize.shrunk", shrunk).await;
+            // 게이지는 정수라 베이시스 포인트로
+            collector.set_gauge("cache.window_hit_rate_bp", (controller.last_window_hit_rate() * 10000.0).round() as u64).await;
+        }
+
+        let published = self.published_decisions_ms.load(Ordering::Relaxed);
+        let new_decisions: Vec<TierResizeDecision> =
+            self.decisions.read().await.iter().filter(|d| d.timestamp > published).cloned().collect();
+        for decision in new_decisions {
+            self.published_decisions_ms.fetch_max(decision.timestamp, Ordering::Relaxed);
+            let tags = HashMap::from([
+                ("tier".to_string(), format!("{:?}", decision.tier)),
+                ("reason".to_string(), decision.reason.as_str().to_string()),
+                ("from".to_string(), decision.from.to_string()),
+            ]);
+            collector
+                .add_custom_metric(MetricData {
+                    name: "cache.resize".to_string(),
+                    value: decision.to as f64,
+                    timestamp: decision.timestamp,
+                    tags,
+                    metric_type: MetricType::Gauge,
+                })
+                .await;
+        }
+    }
+
     /// 캐시 비우기
     pub async fn clear(&self) {
-        self.l1_cache.lock().await.nodes.clear();
-        self.l2_cache.lock().await.nodes.clear();
+        self.l1_cache.lock().await.clear();
+        self.l2_cache.lock().await.clear();
         self.l3_cache.lock().await.clear();
         info!("캐시 비우기 완료");
     }
@@ -675,6 +968,62 @@ mod tests {
         optimizer.stop().await;
     }
 
+    fn small_config(adaptive: Option<AdaptiveCacheConfig>) -> CacheOptimizerConfig {
+        CacheOptimizerConfig { l1_size: 2, l2_size: 2, l3_size: 2, adaptive, ..Default::default() }
+    }
+
+    #[tokio::test]
+    async fn test_tinylfu_promotion() {
+        let optimizer = CacheOptimizer::new(small_config(None));
+        for key in ["a", "b", "c"] {
+            optimizer.put(key.to_string(), key.to_string()).await;
+        }
+        // L1이 가득 차 가장 오래된 a는 L2로 밀려남
+        assert_eq!(optimizer.get_cache_sizes().await, (2, 1, 0));
+
+        // L1의 b, c를 자주 쓰면 한 번 조회된 a는 L1로 올라가지 못함
+        for _ in 0..3 {
+            optimizer.get("b").await;
+            optimizer.get("c").await;
+        }
+        assert_eq!(optimizer.get("a").await, Some("a".to_string()));
+        assert_eq!(optimizer.get_cache_sizes().await, (2, 1, 0));
+        assert_eq!(optimizer.rejected.load(Ordering::Relaxed), 1);
+
+        // a가 더 자주 쓰이면 승격되고 밀려난 항목은 L2로
+        for _ in 0..6 {
+            optimizer.get("a").await;
+        }
+        assert!(optimizer.admitted.load(Ordering::Relaxed) >= 1);
+        assert_eq!(optimizer.get_cache_sizes().await, (2, 1, 0));
+        assert!(optimizer.get_stats().await.l1_hits > 6);
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_resize_and_publish() {
+        let adaptive = AdaptiveCacheConfig { min_requests: 4, l1_min: 1, l2_min: 1, ..Default::default() };
+        let optimizer = CacheOptimizer::new(small_config(Some(adaptive)));
+        for key in ["a", "b", "c", "d"] {
+            optimizer.put(key.to_string(), key.to_string()).await;
+        }
+        // 모두 L2에서 찾게 되는 접근 패턴 → L1 확대
+        for key in ["a", "b", "a", "b"] {
+            optimizer.get(key).await;
+        }
+        let decision = optimizer.adjust_now().await.unwrap();
+        assert_eq!((decision.tier, decision.from, decision.to), (CacheLevel::L1, 2, 3));
+        assert_eq!(optimizer.get_tier_capacities().await, (3, 2));
+
+        let collector = MetricsCollector::new(Default::default());
+        optimizer.publish(&collector).await;
+        assert_eq!(collector.get_gauge("cache.l1.capacity").await, Some(3));
+        assert_eq!(collector.get_gauge("cache.resize.grown").await, Some(1));
+        assert_eq!(collector.get_metrics_summary().await.total_custom_metrics, 1);
+
+        // 자동 조정이 꺼져 있으면 결정 없음
+        assert!(CacheOptimizer::<String>::new(small_config(None)).adjust_now().await.is_none());
+    }
+
     #[tokio::test]
     async fn test_compression_utils() {
         let data = b"test data for compression";
@@ -684,6 +1033,6 @@ mod tests {
         assert_eq!(data, decompressed.as_slice());
         
         let ratio = CompressionUtils::compression_ratio(data.len(), compressed.len());
-        assert!(ratio >= 0.0 && ratio <= 1.0);
+        assert!((0.0..=1.0).contains(&ratio));
     }
 }
\ No newline at end of file
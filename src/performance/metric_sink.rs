@@ -0,0 +1,482 @@
+//! 메트릭 외부 전송 (싱크)
+//!
+//! `MetricsCollector`가 주기적으로 현재 값을 `MetricData` 묶음으로 만들어 등록된 모든 싱크에 보냅니다.
+//! 운영 환경의 관측 스택에 맞춰 StatsD(UDP), OTLP(HTTP/JSON), Prometheus remote-write를 함께 쓸 수 있으며,
+//! 싱크 하나가 실패해도 나머지 전송은 계속됩니다.
+//!
+//! 카운터는 누적값으로 전달되며 StatsD 싱크만 직전 전송과의 차이를 보냅니다. 타이머와 커스텀 메트릭은
+//! 직전 전송 이후 기록된 샘플이 그대로 전달됩니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::net::UdpSocket;
+use tokio::sync::Mutex;
+
+use super::metrics_collector::{MetricData, MetricType};
+
+/// HTTP 싱크 요청 제한 시간
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 메트릭 전송 대상
+#[async_trait]
+pub trait MetricSink: Send + Sync {
+    /// 로그에 쓰는 싱크 이름
+    fn name(&self) -> &str;
+    /// 메트릭 묶음 전송
+    async fn push(&self, batch: &[MetricData]) -> Result<(), String>;
+}
+
+/// 싱크 설정 (`XTRADER_METRIC_SINKS`에 JSON 배열로 지정)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MetricSinkConfig {
+    /// StatsD/DogStatsD UDP (태그는 `|#키:값`)
+    Statsd {
+        address: String,
+        #[serde(default)]
+        prefix: String,
+        /// UDP 패킷 최대 크기 (여러 줄을 묶어 보냄)
+        #[serde(default = "default_statsd_packet_size")]
+        max_packet_size: usize,
+    },
+    /// OTLP HTTP/JSON (`.../v1/metrics`)
+    Otlp {
+        url: String,
+        #[serde(default = "default_service_name")]
+        service_name: String,
+        /// 추가 요청 헤더 (인증 등)
+        #[serde(default)]
+        headers: HashMap<String, String>,
+    },
+    /// Prometheus remote-write (protobuf + snappy)
+    PrometheusRemoteWrite {
+        url: String,
+        #[serde(default)]
+        bearer_token: Option<String>,
+    },
+}
+
+fn default_statsd_packet_size() -> usize {
+    1432
+}
+
+fn default_service_name() -> String {
+    "xtrader".to_string()
+}
+
+impl MetricSinkConfig {
+    /// 설정으로 싱크 생성
+    pub async fn build(&self) -> Result<Arc<dyn MetricSink>, String> {
+        Ok(match self {
+            MetricSinkConfig::Statsd { address, prefix, max_packet_size } => {
+                Arc::new(StatsdSink::connect(address, prefix.clone(), *max_packet_size).await?)
+            }
+            MetricSinkConfig::Otlp { url, service_name, headers } => {
+                Arc::new(OtlpSink::new(url.clone(), service_name.clone(), headers.clone())?)
+            }
+            MetricSinkConfig::PrometheusRemoteWrite { url, bearer_token } => {
+                Arc::new(PrometheusRemoteWriteSink::new(url.clone(), bearer_token.clone())?)
+            }
+        })
+    }
+
+    /// JSON 배열 파싱 (`[{"type":"statsd","address":"127.0.0.1:8125"}, ...]`)
+    pub fn parse_list(s: &str) -> Result<Vec<MetricSinkConfig>, String> {
+        serde_json::from_str(s).map_err(|e| format!("메트릭 싱크 설정 형식 오류: {}", e))
+    }
+}
+
+fn sorted_tags(tags: &HashMap<String, String>) -> BTreeMap<&str, &str> {
+    tags.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect()
+}
+
+/// StatsD UDP 싱크
+pub struct StatsdSink {
+    socket: UdpSocket,
+    prefix: String,
+    max_packet_size: usize,
+    /// 카운터별 직전 전송 누적값 (이름+태그 기준)
+    last_counters: Mutex<HashMap<String, f64>>,
+}
+
+impl StatsdSink {
+    /// 주소를 해석해 같은 주소 체계의 임의 포트로 바인드
+    pub async fn connect(address: &str, prefix: String, max_packet_size: usize) -> Result<Self, String> {
+        let target: SocketAddr = tokio::net::lookup_host(address)
+            .await
+            .map_err(|e| format!("StatsD 주소 해석 실패 {}: {}", address, e))?
+            .next()
+            .ok_or_else(|| format!("StatsD 주소 해석 실패: {}", address))?;
+        let local = if target.is_ipv6() { "[::]:0" } else { "0.0.0.0:0" };
+        let socket = UdpSocket::bind(local).await.map_err(|e| format!("StatsD 소켓 바인드 실패: {}", e))?;
+        socket.connect(target).await.map_err(|e| format!("StatsD 연결 실패 {}: {}", target, e))?;
+        Ok(Self {
+            socket,
+            prefix,
+            max_packet_size: max_packet_size.max(64),
+            last_counters: Mutex::new(HashMap::new()),
+        })
+    }
+
+    async fn lines(&self, batch: &[MetricData]) -> Vec<String> {
+        let mut last_counters = self.last_counters.lock().await;
+        let mut lines = Vec::with_capacity(batch.len());
+        for metric in batch {
+            let tags = sorted_tags(&metric.tags);
+            let tag_suffix = if tags.is_empty() {
+                String::new()
+            } else {
+                let joined: Vec<String> = tags.iter().map(|(k, v)| format!("{}:{}", k, v)).collect();
+                format!("|#{}", joined.join(","))
+            };
+            let (value, kind) = match metric.metric_type {
+                MetricType::Counter => {
+                    let key = format!("{}{}", metric.name, tag_suffix);
+                    let previous = last_counters.insert(key, metric.value).unwrap_or(0.0);
+                    // 재시작 등으로 누적값이 줄면 현재 값 전체를 증가분으로
+                    let delta = if metric.value >= previous { metric.value - previous } else { metric.value };
+                    if delta == 0.0 {
+                        continue;
+                    }
+                    (delta, "c")
+                }
+                MetricType::Gauge => (metric.value, "g"),
+                MetricType::Timer => (metric.value, "ms"),
+                MetricType::Histogram => (metric.value, "h"),
+            };
+            lines.push(format!("{}{}:{}|{}{}", self.prefix, metric.name, value, kind, tag_suffix));
+        }
+        lines
+    }
+}
+
+#[async_trait]
+impl MetricSink for StatsdSink {
+    fn name(&self) -> &str {
+        "statsd"
+    }
+
+    async fn push(&self, batch: &[MetricData]) -> Result<(), String> {
+        let mut packet = String::new();
+        for line in self.lines(batch).await {
+            if !packet.is_empty() && packet.len() + 1 + line.len() > self.max_packet_size {
+                self.socket.send(packet.as_bytes()).await.map_err(|e| format!("StatsD 전송 실패: {}", e))?;
+                packet.clear();
+            }
+            if !packet.is_empty() {
+                packet.push('\n');
+            }
+            packet.push_str(&line);
+        }
+        if !packet.is_empty() {
+            self.socket.send(packet.as_bytes()).await.map_err(|e| format!("StatsD 전송 실패: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+fn http_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .timeout(HTTP_TIMEOUT)
+        .build()
+        .map_err(|e| format!("HTTP 클라이언트 생성 실패: {}", e))
+}
+
+async fn check_response(sink: &str, response: Result<reqwest::Response, reqwest::Error>) -> Result<(), String> {
+    let response = response.map_err(|e| format!("{} 전송 실패: {}", sink, e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or_default();
+        return Err(format!("{} 전송 실패: HTTP {}: {}", sink, status, text));
+    }
+    Ok(())
+}
+
+fn now_nanos() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64
+}
+
+/// OTLP HTTP/JSON 싱크
+pub struct OtlpSink {
+    client: reqwest::Client,
+    url: String,
+    service_name: String,
+    headers: HashMap<String, String>,
+    /// 누적 카운터의 시작 시각
+    start_time_nanos: u64,
+}
+
+impl OtlpSink {
+    pub fn new(url: String, service_name: String, headers: HashMap<String, String>) -> Result<Self, String> {
+        Ok(Self {
+            client: http_client()?,
+            url,
+            service_name,
+            headers,
+            start_time_nanos: now_nanos(),
+        })
+    }
+
+    /// `ExportMetricsServiceRequest` JSON 본문 (같은 이름은 데이터 포인트 하나로 묶음)
+    pub fn encode(&self, batch: &[MetricData]) -> serde_json::Value {
+        let mut by_name: BTreeMap<&str, (&MetricType, Vec<serde_json::Value>)> = BTreeMap::new();
+        for metric in batch {
+            let attributes: Vec<serde_json::Value> = sorted_tags(&metric.tags)
+                .into_iter()
+                .map(|(k, v)| json!({ "key": k, "value": { "stringValue": v } }))
+                .collect();
+            let point = json!({
+                "asDouble": metric.value,
+                "startTimeUnixNano": self.start_time_nanos.to_string(),
+                "timeUnixNano": (metric.timestamp * 1_000_000).to_string(),
+                "attributes": attributes,
+            });
+            by_name.entry(&metric.name).or_insert_with(|| (&metric.metric_type, Vec::new())).1.push(point);
+        }
+
+        let metrics: Vec<serde_json::Value> = by_name
+            .into_iter()
+            .map(|(name, (metric_type, points))| match metric_type {
+                MetricType::Counter => json!({
+                    "name": name,
+                    "sum": { "aggregationTemporality": 2, "isMonotonic": true, "dataPoints": points },
+                }),
+                MetricType::Timer => json!({ "name": name, "unit": "ms", "gauge": { "dataPoints": points } }),
+                MetricType::Gauge | MetricType::Histogram => json!({ "name": name, "gauge": { "dataPoints": points } }),
+            })
+            .collect();
+
+        json!({
+            "resourceMetrics": [{
+                "resource": {
+                    "attributes": [{ "key": "service.name", "value": { "stringValue": self.service_name } }],
+                },
+                "scopeMetrics": [{
+                    "scope": { "name": "xtrader.metrics_collector" },
+                    "metrics": metrics,
+                }],
+            }],
+        })
+    }
+}
+
+#[async_trait]
+impl MetricSink for OtlpSink {
+    fn name(&self) -> &str {
+        "otlp"
+    }
+
+    async fn push(&self, batch: &[MetricData]) -> Result<(), String> {
+        let mut request = self.client.post(&self.url).json(&self.encode(batch));
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        check_response("OTLP", request.send().await).await
+    }
+}
+
+/// Prometheus 이름 규칙(`[a-zA-Z_:][a-zA-Z0-9_:]*`)에 맞게 변환
+fn prometheus_name(name: &str, allow_colon: bool) -> String {
+    let mut out: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' || (allow_colon && c == ':') { c } else { '_' })
+        .collect();
+    if out.is_empty() || out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    out
+}
+
+fn put_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn put_bytes(buf: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    put_varint(buf, field << 3 | 2);
+    put_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+/// Prometheus remote-write 싱크
+pub struct PrometheusRemoteWriteSink {
+    client: reqwest::Client,
+    url: String,
+    bearer_token: Option<String>,
+}
+
+impl PrometheusRemoteWriteSink {
+    pub fn new(url: String, bearer_token: Option<String>) -> Result<Self, String> {
+        Ok(Self { client: http_client()?, url, bearer_token })
+    }
+
+    /// `prometheus.WriteRequest` protobuf (압축 전). 레이블은 이름순, 샘플은 시각순
+    pub fn encode(batch: &[MetricData]) -> Vec<u8> {
+        // 레이블 목록 → (값, 시각) 샘플
+        type Series = BTreeMap<Vec<(String, String)>, Vec<(f64, i64)>>;
+        let mut series: Series = BTreeMap::new();
+        for metric in batch {
+            let mut labels: Vec<(String, String)> = metric
+                .tags
+                .iter()
+                .map(|(k, v)| (prometheus_name(k, false), v.clone()))
+                .collect();
+            labels.push(("__name__".to_string(), prometheus_name(&metric.name, true)));
+            labels.sort();
+            series.entry(labels).or_default().push((metric.value, metric.timestamp as i64));
+        }
+
+        let mut request = Vec::new();
+        for (labels, mut samples) in series {
+            samples.sort_by_key(|(_, timestamp)| *timestamp);
+            let mut time_series = Vec::new();
+            for (name, value) in &labels {
+                let mut label = Vec::new();
+                put_bytes(&mut label, 1, name.as_bytes());
+                put_bytes(&mut label, 2, value.as_bytes());
+                put_bytes(&mut time_series, 1, &label);
+            }
+            for (value, timestamp) in samples {
+                let mut sample = Vec::new();
+                put_varint(&mut sample, 1 << 3 | 1);
+                sample.extend_from_slice(&value.to_le_bytes());
+                put_varint(&mut sample, 2 << 3);
+                put_varint(&mut sample, timestamp as u64);
+                put_bytes(&mut time_series, 2, &sample);
+            }
+            put_bytes(&mut request, 1, &time_series);
+        }
+        request
+    }
+}
+
+#[async_trait]
+impl MetricSink for PrometheusRemoteWriteSink {
+    fn name(&self) -> &str {
+        "prometheus_remote_write"
+    }
+
+    async fn push(&self, batch: &[MetricData]) -> Result<(), String> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+        let body = snap::raw::Encoder::new()
+            .compress_vec(&Self::encode(batch))
+            .map_err(|e| format!("remote-write 압축 실패: {}", e))?;
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/x-protobuf")
+            .header(reqwest::header::CONTENT_ENCODING, "snappy")
+            .header("X-Prometheus-Remote-Write-Version", "0.1.0")
+            .body(body);
+        if let Some(token) = &self.bearer_token {
+            request = request.bearer_auth(token);
+        }
+        check_response("Prometheus remote-write", request.send().await).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metric(name: &str, value: f64, metric_type: MetricType, tags: &[(&str, &str)]) -> MetricData {
+        MetricData {
+            name: name.to_string(),
+            value,
+            timestamp: 1_700_000_000_000,
+            tags: tags.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            metric_type,
+        }
+    }
+
+    #[test]
+    fn test_parse_sink_list() {
+        let sinks = MetricSinkConfig::parse_list(
+            r#"[{"type":"statsd","address":"127.0.0.1:8125"},
+                {"type":"otlp","url":"http://collector:4318/v1/metrics"},
+                {"type":"prometheus_remote_write","url":"http://prom:9090/api/v1/write","bearer_token":"t"}]"#,
+        )
+        .unwrap();
+        assert_eq!(sinks.len(), 3);
+        assert!(matches!(&sinks[0], MetricSinkConfig::Statsd { max_packet_size: 1432, .. }));
+        assert!(matches!(&sinks[1], MetricSinkConfig::Otlp { service_name, .. } if service_name == "xtrader"));
+        assert!(MetricSinkConfig::parse_list(r#"[{"type":"graphite"}]"#).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_statsd_sends_counter_deltas() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let address = server.local_addr().unwrap().to_string();
+        let sink = StatsdSink::connect(&address, "xtrader.".to_string(), 1432).await.unwrap();
+
+        sink.push(&[
+            metric("orders", 10.0, MetricType::Counter, &[]),
+            metric("queue.depth", 3.0, MetricType::Gauge, &[("symbol", "BTC-KRW")]),
+            metric("api.latency", 1.5, MetricType::Timer, &[]),
+        ])
+        .await
+        .unwrap();
+        let mut buf = [0u8; 2048];
+        let n = server.recv(&mut buf).await.unwrap();
+        assert_eq!(
+            std::str::from_utf8(&buf[..n]).unwrap(),
+            "xtrader.orders:10|c\nxtrader.queue.depth:3|g|#symbol:BTC-KRW\nxtrader.api.latency:1.5|ms"
+        );
+
+        // 누적값 변화가 없는 카운터는 생략, 늘어난 만큼만 전송
+        sink.push(&[metric("orders", 10.0, MetricType::Counter, &[])]).await.unwrap();
+        sink.push(&[metric("orders", 14.0, MetricType::Counter, &[])]).await.unwrap();
+        let n = server.recv(&mut buf).await.unwrap();
+        assert_eq!(std::str::from_utf8(&buf[..n]).unwrap(), "xtrader.orders:4|c");
+    }
+
+    #[test]
+    fn test_otlp_groups_points_by_name() {
+        let sink = OtlpSink::new("http://localhost:4318/v1/metrics".to_string(), "xtrader".to_string(), HashMap::new()).unwrap();
+        let body = sink.encode(&[
+            metric("orders", 5.0, MetricType::Counter, &[]),
+            metric("api.latency", 1.0, MetricType::Timer, &[("route", "/v1/order")]),
+            metric("api.latency", 2.0, MetricType::Timer, &[("route", "/v1/order")]),
+        ]);
+        let metrics = &body["resourceMetrics"][0]["scopeMetrics"][0]["metrics"];
+        assert_eq!(metrics[0]["name"], "api.latency");
+        assert_eq!(metrics[0]["gauge"]["dataPoints"].as_array().unwrap().len(), 2);
+        assert_eq!(metrics[0]["gauge"]["dataPoints"][0]["attributes"][0]["key"], "route");
+        assert_eq!(metrics[1]["sum"]["isMonotonic"], true);
+        assert_eq!(metrics[1]["sum"]["dataPoints"][0]["timeUnixNano"], "1700000000000000000");
+    }
+
+    #[test]
+    fn test_remote_write_encoding() {
+        let encoded = PrometheusRemoteWriteSink::encode(&[metric("api.latency-ms", 2.5, MetricType::Gauge, &[("zone", "a")])]);
+
+        let mut expected_series = Vec::new();
+        for (name, value) in [("__name__", "api_latency_ms"), ("zone", "a")] {
+            let mut label = Vec::new();
+            put_bytes(&mut label, 1, name.as_bytes());
+            put_bytes(&mut label, 2, value.as_bytes());
+            put_bytes(&mut expected_series, 1, &label);
+        }
+        let mut sample = vec![0x09];
+        sample.extend_from_slice(&2.5f64.to_le_bytes());
+        sample.push(0x10);
+        put_varint(&mut sample, 1_700_000_000_000);
+        put_bytes(&mut expected_series, 2, &sample);
+        let mut expected = Vec::new();
+        put_bytes(&mut expected, 1, &expected_series);
+
+        assert_eq!(encoded, expected);
+        assert_eq!(prometheus_name("1st.metric", true), "_1st_metric");
+    }
+}
@@ -9,8 +9,10 @@ use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use log::{info, error, warn, debug};
-use tokio::time::{sleep, interval};
-use std::sync::atomic::{AtomicUsize, AtomicU64, AtomicBool, Ordering};
+use tokio::time::interval;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::metric_sink::{MetricSink, MetricSinkConfig};
 
 /// 메트릭 타입
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -87,6 +89,10 @@ pub struct MetricsCollectorConfig {
     pub max_metrics_per_type: usize,
     pub enable_aggregation: bool,
     pub aggregation_window_ms: u64,
+    /// 외부 메트릭 싱크 (여러 개 동시 사용 가능)
+    pub sinks: Vec<MetricSinkConfig>,
+    /// 싱크 전송 주기
+    pub sink_flush_interval_ms: u64,
 }
 
 impl Default for MetricsCollectorConfig {
@@ -100,6 +106,8 @@ impl Default for MetricsCollectorConfig {
             max_metrics_per_type: 10000,
             enable_aggregation: true,
             aggregation_window_ms: 60000, // 1분
+            sinks: Vec::new(),
+            sink_flush_interval_ms: 10000, // 10초
         }
     }
 }
@@ -113,9 +121,87 @@ pub struct MetricsCollector {
     timers: Arc<RwLock<HashMap<String, Vec<TimerMetric>>>>,
     custom_metrics: Arc<RwLock<HashMap<String, Vec<MetricData>>>>,
     performance_metrics: Arc<RwLock<Vec<PerformanceMetrics>>>,
+    sinks: Arc<RwLock<Vec<Arc<dyn MetricSink>>>>,
+    /// 직전 싱크 전송 시각 (이후 기록된 타이머/커스텀 샘플만 다음 전송에 포함)
+    last_flush_ms: Arc<AtomicU64>,
     is_running: Arc<Mutex<bool>>,
 }
 
+/// 싱크 전송 태스크가 공유하는 수집기 저장소
+#[derive(Clone)]
+struct SinkExporter {
+    counters: Arc<RwLock<HashMap<String, AtomicU64>>>,
+    gauges: Arc<RwLock<HashMap<String, AtomicU64>>>,
+    histograms: Arc<RwLock<HashMap<String, Vec<HistogramMetric>>>>,
+    timers: Arc<RwLock<HashMap<String, Vec<TimerMetric>>>>,
+    custom_metrics: Arc<RwLock<HashMap<String, Vec<MetricData>>>>,
+    sinks: Arc<RwLock<Vec<Arc<dyn MetricSink>>>>,
+    last_flush_ms: Arc<AtomicU64>,
+}
+
+impl SinkExporter {
+    /// 카운터 누적값, 게이지, 히스토그램 최근 창 요약, 구간 (after_ms, to_ms] 의 타이머/커스텀 샘플
+    async fn snapshot(&self, after_ms: u64, to_ms: u64) -> Vec<MetricData> {
+        let mut batch = Vec::new();
+        let point = |name: String, value: f64, metric_type: MetricType| MetricData {
+            name,
+            value,
+            timestamp: to_ms,
+            tags: HashMap::new(),
+            metric_type,
+        };
+
+        for (name, counter) in self.counters.read().await.iter() {
+            batch.push(point(name.clone(), counter.load(Ordering::Relaxed) as f64, MetricType::Counter));
+        }
+        for (name, gauge) in self.gauges.read().await.iter() {
+            batch.push(point(name.clone(), gauge.load(Ordering::Relaxed) as f64, MetricType::Gauge));
+        }
+        for (name, list) in self.histograms.read().await.iter() {
+            if let Some(h) = list.last() {
+                for (suffix, value) in [("count", h.count as f64), ("sum", h.sum), ("min", h.min), ("max", h.max)] {
+                    batch.push(point(format!("{}.{}", name, suffix), value, MetricType::Gauge));
+                }
+            }
+        }
+        for list in self.timers.read().await.values() {
+            batch.extend(list.iter().filter(|t| t.timestamp > after_ms && t.timestamp <= to_ms).map(|t| MetricData {
+                name: t.name.clone(),
+                value: t.duration_ms,
+                timestamp: t.timestamp,
+                tags: t.tags.clone(),
+                metric_type: MetricType::Timer,
+            }));
+        }
+        for list in self.custom_metrics.read().await.values() {
+            batch.extend(list.iter().filter(|m| m.timestamp > after_ms && m.timestamp <= to_ms).cloned());
+        }
+        batch
+    }
+
+    /// 등록된 모든 싱크로 전송 (싱크별 실패는 로그만 남기고 계속)
+    async fn flush(&self) {
+        let sinks = self.sinks.read().await.clone();
+        if sinks.is_empty() {
+            return;
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let after = self.last_flush_ms.swap(now, Ordering::Relaxed);
+        let batch = self.snapshot(after, now).await;
+
+        let results = futures::future::join_all(sinks.iter().map(|sink| sink.push(&batch))).await;
+        for (sink, result) in sinks.iter().zip(results) {
+            match result {
+                Ok(()) => debug!("메트릭 싱크 전송: {} ({}개)", sink.name(), batch.len()),
+                Err(e) => warn!("메트릭 싱크 전송 실패: {}: {}", sink.name(), e),
+            }
+        }
+    }
+}
+
 impl MetricsCollector {
     /// 새 메트릭 수집기 생성
     pub fn new(config: MetricsCollectorConfig) -> Self {
@@ -127,10 +213,45 @@ impl MetricsCollector {
             timers: Arc::new(RwLock::new(HashMap::new())),
             custom_metrics: Arc::new(RwLock::new(HashMap::new())),
             performance_metrics: Arc::new(RwLock::new(Vec::new())),
+            sinks: Arc::new(RwLock::new(Vec::new())),
+            last_flush_ms: Arc::new(AtomicU64::new(0)),
             is_running: Arc::new(Mutex::new(false)),
         }
     }
 
+    fn exporter(&self) -> SinkExporter {
+        SinkExporter {
+            counters: self.counters.clone(),
+            gauges: self.gauges.clone(),
+            histograms: self.histograms.clone(),
+            timers: self.timers.clone(),
+            custom_metrics: self.custom_metrics.clone(),
+            sinks: self.sinks.clone(),
+            last_flush_ms: self.last_flush_ms.clone(),
+        }
+    }
+
+    /// 외부 메트릭 싱크 등록
+    pub async fn add_sink(&self, sink: Arc<dyn MetricSink>) {
+        info!("메트릭 싱크 등록: {}", sink.name());
+        self.sinks.write().await.push(sink);
+    }
+
+    /// 설정의 싱크를 생성해 등록 (생성에 실패한 싱크는 건너뜀)
+    pub async fn connect_sinks(&self) {
+        for sink_config in &self.config.sinks {
+            match sink_config.build().await {
+                Ok(sink) => self.add_sink(sink).await,
+                Err(e) => error!("메트릭 싱크 생성 실패: {}", e),
+            }
+        }
+    }
+
+    /// 등록된 싱크로 즉시 전송 (직전 전송 이후 샘플)
+    pub async fn flush_sinks(&self) {
+        self.exporter().flush().await;
+    }
+
     /// 메트릭 수집기 시작
     pub async fn start(&self) {
         let mut is_running = self.is_running.lock().await;
@@ -143,8 +264,8 @@ impl MetricsCollector {
 
         info!("메트릭 수집기 시작: 수집간격={}ms", self.config.collection_interval_ms);
 
-        let counters = self.counters.clone();
-        let gauges = self.gauges.clone();
+        let _counters = self.counters.clone();
+        let _gauges = self.gauges.clone();
         let histograms = self.histograms.clone();
         let timers = self.timers.clone();
         let custom_metrics = self.custom_metrics.clone();
@@ -152,6 +273,22 @@ impl MetricsCollector {
         let config = self.config.clone();
         let is_running = self.is_running.clone();
 
+        // 싱크 전송 태스크
+        let exporter = self.exporter();
+        let sink_running = self.is_running.clone();
+        let flush_interval_ms = config.sink_flush_interval_ms.max(1);
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_millis(flush_interval_ms));
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                if !*sink_running.lock().await {
+                    break;
+                }
+                exporter.flush().await;
+            }
+        });
+
         // 메트릭 수집 태스크
         tokio::spawn(async move {
             let mut interval = interval(Duration::from_millis(config.collection_interval_ms));
@@ -328,17 +465,17 @@ impl MetricsCollector {
 
         // Mock 시스템 메트릭
         let metrics = PerformanceMetrics {
-            cpu_usage_percent: fastrand::f32() * 100.0,
-            memory_usage_bytes: fastrand::u32(0..8_000_000_000) as u64, // 0-8GB
-            memory_usage_percent: fastrand::f32() * 100.0,
-            disk_usage_bytes: fastrand::u32(0..1_000_000_000_000) as u64, // 0-1TB
-            disk_usage_percent: fastrand::f32() * 100.0,
-            network_bytes_sent: fastrand::u32(0..1_000_000) as u64,
-            network_bytes_received: fastrand::u32(0..1_000_000) as u64,
+            cpu_usage_percent: fastrand::f64() * 100.0,
+            memory_usage_bytes: fastrand::u64(0..8_000_000_000), // 0-8GB
+            memory_usage_percent: fastrand::f64() * 100.0,
+            disk_usage_bytes: fastrand::u64(0..1_000_000_000_000), // 0-1TB
+            disk_usage_percent: fastrand::f64() * 100.0,
+            network_bytes_sent: fastrand::u64(0..1_000_000),
+            network_bytes_received: fastrand::u64(0..1_000_000),
             active_connections: fastrand::usize(0..1000),
-            request_rate_per_second: fastrand::f32() * 1000.0,
-            response_time_ms: fastrand::f32() * 100.0,
-            error_rate_percent: fastrand::f32() * 10.0,
+            request_rate_per_second: fastrand::f64() * 1000.0,
+            response_time_ms: fastrand::f64() * 100.0,
+            error_rate_percent: fastrand::f64() * 10.0,
             timestamp: current_time,
         };
 
@@ -347,7 +484,8 @@ impl MetricsCollector {
 
         // 최대 개수 제한 (1시간치 데이터)
         if perf_metrics.len() > 3600 {
-            perf_metrics.drain(0..perf_metrics.len() - 3600);
+            let excess = perf_metrics.len() - 3600;
+            perf_metrics.drain(0..excess);
         }
     }
 
@@ -426,7 +564,7 @@ impl MetricsCollector {
             let count = durations.len();
             let avg = sum / count as f64;
             let min = durations.iter().fold(f64::INFINITY, |a, &b| a.min(b));
-            let max = durations.iter().fold(0.0, |a, &b| a.max(b));
+            let max = durations.iter().fold(0.0_f64, |a, &b| a.max(b));
 
             Some((avg, min, max, count))
         } else {
@@ -434,6 +572,37 @@ impl MetricsCollector {
         }
     }
 
+    /// 타이머 백분위수 조회 (보관 중인 최근 샘플 기준, nearest-rank)
+    pub async fn get_timer_percentiles(&self, name: &str, percentiles: &[f64]) -> Option<Vec<f64>> {
+        let timers = self.timers.read().await;
+        let timer_list = timers.get(name)?;
+        if timer_list.is_empty() {
+            return None;
+        }
+
+        let mut durations: Vec<f64> = timer_list.iter().map(|t| t.duration_ms).collect();
+        durations.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        Some(percentiles.iter().map(|p| {
+            let rank = ((p.clamp(0.0, 100.0) / 100.0) * durations.len() as f64).ceil() as usize;
+            durations[rank.saturating_sub(1).min(durations.len() - 1)]
+        }).collect())
+    }
+
+    /// 구간 [from_ms, to_ms) 에 기록된 타이머 샘플 조회 (보관 중인 샘플 기준)
+    pub async fn get_timer_samples(&self, name: &str, from_ms: u64, to_ms: u64) -> Vec<TimerMetric> {
+        let timers = self.timers.read().await;
+        timers
+            .get(name)
+            .map(|list| {
+                list.iter()
+                    .filter(|t| t.timestamp >= from_ms && t.timestamp < to_ms)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// 성능 메트릭 조회
     pub async fn get_performance_metrics(&self) -> Vec<PerformanceMetrics> {
         self.performance_metrics.read().await.clone()
@@ -466,6 +635,14 @@ impl MetricsCollector {
     }
 }
 
+/// MQ Consumer 지연, 발행 재시도 카운터 게이지 수집
+#[async_trait::async_trait]
+impl xtrader_mq::GaugeSink for MetricsCollector {
+    async fn set_gauge(&self, name: &str, value: u64) {
+        MetricsCollector::set_gauge(self, name, value).await;
+    }
+}
+
 /// 메트릭 요약
 #[derive(Debug, Clone)]
 pub struct MetricsSummary {
@@ -492,7 +669,7 @@ impl PerformanceAnalyzer {
     pub async fn generate_performance_report(&self) -> PerformanceReport {
         let perf_metrics = self.metrics_collector.get_performance_metrics().await;
         let counters = self.metrics_collector.get_all_counters().await;
-        let gauges = self.metrics_collector.get_all_gauges().await;
+        let _gauges = self.metrics_collector.get_all_gauges().await;
 
         let mut avg_cpu = 0.0;
         let mut avg_memory = 0.0;
@@ -510,9 +687,9 @@ impl PerformanceAnalyzer {
             avg_memory = memory_values.iter().sum::<f64>() / memory_values.len() as f64;
             avg_response_time = response_values.iter().sum::<f64>() / response_values.len() as f64;
 
-            max_cpu = cpu_values.iter().fold(0.0, |a, &b| a.max(b));
-            max_memory = memory_values.iter().fold(0.0, |a, &b| a.max(b));
-            max_response_time = response_values.iter().fold(0.0, |a, &b| a.max(b));
+            max_cpu = cpu_values.iter().fold(0.0_f64, |a, &b| a.max(b));
+            max_memory = memory_values.iter().fold(0.0_f64, |a, &b| a.max(b));
+            max_response_time = response_values.iter().fold(0.0_f64, |a, &b| a.max(b));
         }
 
         PerformanceReport {
@@ -594,7 +771,7 @@ mod fastrand {
         (hash % 1000) as f32 / 1000.0
     }
 
-    pub fn u32(range: std::ops::Range<u32>) -> u32 {
+    pub fn usize(range: std::ops::Range<usize>) -> usize {
         let mut hasher = DefaultHasher::new();
         SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -602,10 +779,14 @@ mod fastrand {
             .as_nanos()
             .hash(&mut hasher);
         let hash = hasher.finish();
-        range.start + ((hash % (range.end - range.start) as u64) as u32)
+        range.start + ((hash % (range.end - range.start) as u64) as usize)
     }
 
-    pub fn usize(range: std::ops::Range<usize>) -> usize {
+    pub fn f64() -> f64 {
+        f32() as f64
+    }
+
+    pub fn u64(range: std::ops::Range<u64>) -> u64 {
         let mut hasher = DefaultHasher::new();
         SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -613,13 +794,14 @@ mod fastrand {
             .as_nanos()
             .hash(&mut hasher);
         let hash = hasher.finish();
-        range.start + ((hash % (range.end - range.start) as u64) as usize)
+        range.start + hash % (range.end - range.start)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tokio::time::sleep;
 
     #[tokio::test]
     async fn test_metrics_collector_creation() {
@@ -693,6 +875,71 @@ mod tests {
         assert_eq!(count, 1);
     }
 
+    #[tokio::test]
+    async fn test_timer_percentiles() {
+        let config = MetricsCollectorConfig::default();
+        let collector = MetricsCollector::new(config);
+
+        assert!(collector.get_timer_percentiles("latency", &[50.0]).await.is_none());
+
+        for ms in (1..=100).rev() {
+            collector.record_timer("latency", ms as f64, HashMap::new()).await;
+        }
+
+        let p = collector.get_timer_percentiles("latency", &[50.0, 95.0, 99.0, 100.0]).await.unwrap();
+        assert_eq!(p, vec![50.0, 95.0, 99.0, 100.0]);
+    }
+
+    /// 받은 묶음을 보관하는 테스트 싱크
+    struct RecordingSink {
+        name: String,
+        batches: Mutex<Vec<Vec<MetricData>>>,
+        fail: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl MetricSink for RecordingSink {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        async fn push(&self, batch: &[MetricData]) -> Result<(), String> {
+            self.batches.lock().await.push(batch.to_vec());
+            if self.fail { Err("unavailable".to_string()) } else { Ok(()) }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flush_to_multiple_sinks() {
+        let collector = MetricsCollector::new(MetricsCollectorConfig::default());
+        let sink = |name: &str, fail: bool| Arc::new(RecordingSink { name: name.to_string(), batches: Mutex::new(Vec::new()), fail });
+        let failing = sink("failing", true);
+        let healthy = sink("healthy", false);
+        collector.add_sink(failing.clone()).await;
+        collector.add_sink(healthy.clone()).await;
+
+        collector.increment_counter("orders", 3).await;
+        collector.set_gauge("queue.depth", 7).await;
+        collector.record_timer("api.latency", 2.0, HashMap::new()).await;
+        collector.flush_sinks().await;
+
+        // 실패한 싱크가 있어도 다른 싱크는 같은 묶음을 받음
+        let batch = healthy.batches.lock().await[0].clone();
+        assert_eq!(failing.batches.lock().await[0].len(), batch.len());
+        let find = |name: &str| batch.iter().find(|m| m.name == name).cloned().unwrap();
+        assert_eq!(find("orders").metric_type, MetricType::Counter);
+        assert_eq!(find("orders").value, 3.0);
+        assert_eq!(find("queue.depth").value, 7.0);
+        assert_eq!(find("api.latency").metric_type, MetricType::Timer);
+
+        // 타이머 샘플은 다음 전송에 다시 포함되지 않고, 카운터는 누적값
+        collector.increment_counter("orders", 2).await;
+        collector.flush_sinks().await;
+        let batch = healthy.batches.lock().await[1].clone();
+        assert!(batch.iter().all(|m| m.name != "api.latency"));
+        assert_eq!(batch.iter().find(|m| m.name == "orders").unwrap().value, 5.0);
+    }
+
     #[tokio::test]
     async fn test_performance_analyzer() {
         let config = MetricsCollectorConfig::default();
@@ -707,7 +954,7 @@ mod tests {
         let report = analyzer.generate_performance_report().await;
         
         assert!(report.analysis_period_ms > 0);
-        assert!(report.recommendations.len() > 0);
+        assert!(!report.recommendations.is_empty());
         
         collector.stop().await;
     }
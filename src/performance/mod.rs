@@ -1,14 +1,19 @@
 //! 성능 최적화 모듈
 //!
-//! 이 모듈은 배치 처리, 병렬 소비, 캐시 최적화, 메트릭 수집을 통해
+//! 이 모듈은 배치 처리, 병렬 소비, 캐시 최적화, 메트릭 수집/외부 전송을 통해
 //! 고성능 시스템을 구축합니다.
 
 pub mod batch_processor;
 pub mod parallel_consumer;
 pub mod cache_optimizer;
+pub mod adaptive_cache;
 pub mod metrics_collector;
+pub mod metric_sink;
 
 pub use batch_processor::*;
+pub use batch_processor::CompressionUtils;
 pub use parallel_consumer::*;
 pub use cache_optimizer::*;
-pub use metrics_collector::*;
\ No newline at end of file
+pub use adaptive_cache::*;
+pub use metrics_collector::*;
+pub use metric_sink::*;
\ No newline at end of file
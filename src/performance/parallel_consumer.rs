@@ -2,15 +2,24 @@
 //!
 //! 이 모듈은 워커 스레드 풀, 로드 밸런싱, 백프레셔를 통해
 //! 고성능 병렬 메시지 소비를 제공합니다.
+//! 큐 깊이와 처리 지연을 기준으로 워커 수를 자동 조정할 수 있습니다.
 
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
+use std::marker::PhantomData;
 use std::sync::Arc;
-use tokio::sync::{Mutex, RwLock, Semaphore, mpsc};
-use std::time::{SystemTime, UNIX_EPOCH, Duration};
+use tokio::sync::{Mutex, RwLock, mpsc};
+use std::time::{SystemTime, UNIX_EPOCH, Duration, Instant};
 use log::{info, error, warn, debug};
-use tokio::time::{sleep, interval};
-use std::sync::atomic::{AtomicUsize, AtomicU64, Ordering};
+use tokio::time::interval;
+use std::sync::atomic::{AtomicBool, AtomicUsize, AtomicU64, Ordering};
+
+use super::metrics_collector::{MetricData, MetricType, MetricsCollector};
+use crate::monitoring::notification_routing::SOURCE_METADATA_KEY;
+use crate::monitoring::notification_system::{NotificationChannel, NotificationPriority, NotificationSystem, NotificationType};
+
+/// 보관하는 최근 스케일링 이벤트 수
+const MAX_SCALING_EVENTS: usize = 100;
 
 /// 병렬 소비 설정
 #[derive(Debug, Clone)]
@@ -23,6 +32,8 @@ pub struct ParallelConsumerConfig {
     pub enable_backpressure: bool,
     pub max_retries: u32,
     pub retry_delay_ms: u64,
+    /// 워커 수 자동 조정 (None이면 worker_count 고정)
+    pub autoscale: Option<AutoscaleConfig>,
 }
 
 impl Default for ParallelConsumerConfig {
@@ -36,10 +47,258 @@ impl Default for ParallelConsumerConfig {
             enable_backpressure: true,
             max_retries: 3,
             retry_delay_ms: 1000,
+            autoscale: None,
         }
     }
 }
 
+/// 워커 수 자동 조정 설정
+#[derive(Debug, Clone)]
+pub struct AutoscaleConfig {
+    pub min_workers: usize,
+    pub max_workers: usize,
+    /// 조정 판단 주기
+    pub check_interval_ms: u64,
+    /// 워커당 대기 메시지가 이 이상이면 확장
+    pub scale_up_queue_per_worker: usize,
+    /// 워커당 대기 메시지가 이 이하이면 축소 후보
+    pub scale_down_queue_per_worker: usize,
+    /// 구간 평균 지연(큐 대기 포함)이 이 이상이면 확장, 절반 미만이어야 축소
+    pub target_latency_ms: u64,
+    pub scale_up_step: usize,
+    pub scale_down_step: usize,
+    /// 마지막 조정 후 다음 조정까지 최소 간격
+    pub cooldown_ms: u64,
+    /// 축소 조건이 연속으로 유지되어야 하는 판단 횟수
+    pub scale_down_stable_checks: u32,
+    /// 스케일링 알림 채널
+    pub notification_channel: NotificationChannel,
+}
+
+impl Default for AutoscaleConfig {
+    fn default() -> Self {
+        Self {
+            min_workers: 2,
+            max_workers: 32,
+            check_interval_ms: 1000,
+            scale_up_queue_per_worker: 100,
+            scale_down_queue_per_worker: 5,
+            target_latency_ms: 500,
+            scale_up_step: 2,
+            scale_down_step: 1,
+            cooldown_ms: 5000,
+            scale_down_stable_checks: 5,
+            notification_channel: NotificationChannel::Log,
+        }
+    }
+}
+
+impl AutoscaleConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_workers == 0 || self.min_workers > self.max_workers {
+            return Err("워커 수 범위가 올바르지 않습니다 (1 <= min <= max)".to_string());
+        }
+        if self.scale_down_queue_per_worker >= self.scale_up_queue_per_worker {
+            return Err("축소 기준 큐 깊이는 확장 기준보다 작아야 합니다".to_string());
+        }
+        if self.scale_up_step == 0 || self.scale_down_step == 0 || self.check_interval_ms == 0 {
+            return Err("조정 단위와 판단 주기는 0보다 커야 합니다".to_string());
+        }
+        Ok(())
+    }
+
+    /// 설정된 범위로 제한한 워커 수
+    pub fn clamp(&self, workers: usize) -> usize {
+        workers.clamp(self.min_workers, self.max_workers)
+    }
+}
+
+/// 스케일링 방향
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScaleDirection {
+    Up,
+    Down,
+}
+
+impl ScaleDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScaleDirection::Up => "up",
+            ScaleDirection::Down => "down",
+        }
+    }
+}
+
+/// 스케일링 사유
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScaleReason {
+    /// 워커당 대기 메시지 과다
+    QueueDepth,
+    /// 처리 지연이 목표 초과
+    Latency,
+    /// 큐와 지연이 연속으로 낮음
+    Idle,
+}
+
+impl ScaleReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScaleReason::QueueDepth => "queue_depth",
+            ScaleReason::Latency => "latency",
+            ScaleReason::Idle => "idle",
+        }
+    }
+}
+
+/// 스케일링 이벤트
+#[derive(Debug, Clone, Serialize)]
+pub struct ScalingEvent {
+    pub direction: ScaleDirection,
+    pub from: usize,
+    pub to: usize,
+    pub reason: ScaleReason,
+    pub queue_depth: usize,
+    /// 판단 구간 평균 지연 (완료된 메시지가 없으면 None)
+    pub avg_latency_ms: Option<f64>,
+    pub timestamp: u64,
+}
+
+/// 조정 판단 입력
+#[derive(Debug, Clone)]
+pub struct ScalingSample {
+    pub workers: usize,
+    /// 풀 큐와 워커 채널에 쌓인 메시지 수
+    pub queue_depth: usize,
+    pub avg_latency_ms: Option<f64>,
+}
+
+/// 워커 수 자동 조정 판단기
+#[derive(Debug)]
+pub struct Autoscaler {
+    config: AutoscaleConfig,
+    last_scaled_ms: Option<u64>,
+    calm_checks: u32,
+    scale_ups: u64,
+    scale_downs: u64,
+    /// 직전 판단 시점의 누적 (완료 수, 지연 합계)
+    last_totals: (u64, u64),
+    last_window_latency_ms: Option<f64>,
+}
+
+impl Autoscaler {
+    pub fn new(config: AutoscaleConfig) -> Self {
+        Self {
+            config,
+            last_scaled_ms: None,
+            calm_checks: 0,
+            scale_ups: 0,
+            scale_downs: 0,
+            last_totals: (0, 0),
+            last_window_latency_ms: None,
+        }
+    }
+
+    pub fn config(&self) -> &AutoscaleConfig {
+        &self.config
+    }
+
+    /// 누적 완료 수/지연 합계로 직전 판단 이후 구간의 평균 지연 계산
+    pub fn observe_latency(&mut self, completed: u64, total_latency_ms: u64) -> Option<f64> {
+        let (last_completed, last_latency) = std::mem::replace(&mut self.last_totals, (completed, total_latency_ms));
+        let window_completed = completed.saturating_sub(last_completed);
+        self.last_window_latency_ms = if window_completed > 0 {
+            Some(total_latency_ms.saturating_sub(last_latency) as f64 / window_completed as f64)
+        } else {
+            None
+        };
+        self.last_window_latency_ms
+    }
+
+    /// 확장은 즉시, 축소는 조건이 연속으로 유지될 때만 (둘 다 쿨다운 적용)
+    pub fn evaluate(&mut self, sample: &ScalingSample, now_ms: u64) -> Option<ScalingEvent> {
+        let per_worker = sample.queue_depth / sample.workers.max(1);
+        let target = self.config.target_latency_ms as f64;
+        let queue_high = per_worker >= self.config.scale_up_queue_per_worker;
+        let latency_high = sample.avg_latency_ms.is_some_and(|latency| latency > target);
+        let calm = per_worker <= self.config.scale_down_queue_per_worker
+            && sample.avg_latency_ms.is_none_or(|latency| latency < target / 2.0);
+        let cooled_down = self
+            .last_scaled_ms
+            .is_none_or(|last| now_ms.saturating_sub(last) >= self.config.cooldown_ms);
+
+        let (direction, reason, to) = if (queue_high || latency_high) && sample.workers < self.config.max_workers {
+            self.calm_checks = 0;
+            let reason = if queue_high { ScaleReason::QueueDepth } else { ScaleReason::Latency };
+            let to = (sample.workers + self.config.scale_up_step).min(self.config.max_workers);
+            (ScaleDirection::Up, reason, to)
+        } else if calm && sample.workers > self.config.min_workers {
+            self.calm_checks += 1;
+            if self.calm_checks < self.config.scale_down_stable_checks {
+                return None;
+            }
+            let to = sample.workers.saturating_sub(self.config.scale_down_step).max(self.config.min_workers);
+            (ScaleDirection::Down, ScaleReason::Idle, to)
+        } else {
+            self.calm_checks = 0;
+            return None;
+        };
+
+        if !cooled_down {
+            return None;
+        }
+
+        self.calm_checks = 0;
+        self.last_scaled_ms = Some(now_ms);
+        match direction {
+            ScaleDirection::Up => self.scale_ups += 1,
+            ScaleDirection::Down => self.scale_downs += 1,
+        }
+
+        Some(ScalingEvent {
+            direction,
+            from: sample.workers,
+            to,
+            reason,
+            queue_depth: sample.queue_depth,
+            avg_latency_ms: sample.avg_latency_ms,
+            timestamp: now_ms,
+        })
+    }
+
+    /// (확장 횟수, 축소 횟수)
+    pub fn scale_counts(&self) -> (u64, u64) {
+        (self.scale_ups, self.scale_downs)
+    }
+
+    pub fn last_window_latency_ms(&self) -> Option<f64> {
+        self.last_window_latency_ms
+    }
+}
+
+impl ScalingEvent {
+    fn title(&self) -> String {
+        match self.direction {
+            ScaleDirection::Up => format!("워커 풀 확장: {} → {}", self.from, self.to),
+            ScaleDirection::Down => format!("워커 풀 축소: {} → {}", self.from, self.to),
+        }
+    }
+
+    fn content(&self) -> String {
+        let latency = self
+            .avg_latency_ms
+            .map(|latency| format!("{:.1}ms", latency))
+            .unwrap_or_else(|| "-".to_string());
+        format!(
+            "사유: {}, 대기 메시지 {}개, 평균 지연 {}",
+            self.reason.as_str(),
+            self.queue_depth,
+            latency
+        )
+    }
+}
+
 /// 소비 메시지
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsumerMessage<T> {
@@ -51,11 +310,19 @@ pub struct ConsumerMessage<T> {
     pub retry_count: u32,
 }
 
+/// 큐 대기 시간을 지연에 포함하기 위한 큐 항목
+struct QueuedMessage<T> {
+    message: ConsumerMessage<T>,
+    enqueued_at: Instant,
+}
+
 /// 워커 상태
 #[derive(Debug, Clone)]
 pub enum WorkerStatus {
     Idle,
     Processing,
+    /// 축소 대상: 새 메시지를 받지 않고 남은 메시지만 처리
+    Draining,
     Error,
     Shutdown,
 }
@@ -79,6 +346,7 @@ pub struct ParallelConsumerStats {
     pub active_workers: usize,
     pub idle_workers: usize,
     pub error_workers: usize,
+    pub draining_workers: usize,
     pub total_processed: u64,
     pub total_failed: u64,
     pub average_throughput_per_second: f64,
@@ -86,26 +354,73 @@ pub struct ParallelConsumerStats {
     pub worker_stats: Vec<WorkerStats>,
 }
 
+/// 풀 전체 누적 지연 (워커가 교체되어도 유지)
+#[derive(Debug, Default)]
+struct LatencyTotals {
+    completed: AtomicU64,
+    total_latency_ms: AtomicU64,
+}
+
+/// 워커 ID → 워커
+type WorkerMap<T, R> = HashMap<usize, Arc<Worker<T, R>>>;
+
 /// 워커 풀 관리자
 pub struct WorkerPool<T, R> {
     config: ParallelConsumerConfig,
-    workers: Arc<RwLock<HashMap<usize, Arc<Worker<T, R>>>>>,
-    message_queue: Arc<Mutex<VecDeque<ConsumerMessage<T>>>>,
+    workers: Arc<RwLock<WorkerMap<T, R>>>,
+    draining: Arc<RwLock<Vec<Arc<WorkerShared>>>>,
+    next_worker_id: Arc<AtomicUsize>,
+    message_queue: Arc<Mutex<VecDeque<QueuedMessage<T>>>>,
     stats: Arc<RwLock<ParallelConsumerStats>>,
+    stats_tx: mpsc::UnboundedSender<WorkerStats>,
+    stats_rx: Arc<Mutex<Option<mpsc::UnboundedReceiver<WorkerStats>>>>,
+    latency: Arc<LatencyTotals>,
+    autoscaler: Option<Arc<Mutex<Autoscaler>>>,
+    scaling_events: Arc<RwLock<VecDeque<ScalingEvent>>>,
+    published_events_ms: Arc<AtomicU64>,
+    notifications: Option<Arc<NotificationSystem>>,
     is_running: Arc<Mutex<bool>>,
     processor_fn: Arc<dyn Fn(T) -> Result<R, String> + Send + Sync>,
 }
 
-/// 개별 워커
-pub struct Worker<T, R> {
+/// 워커 태스크와 풀이 공유하는 상태
+struct WorkerShared {
     worker_id: usize,
-    status: Arc<RwLock<WorkerStatus>>,
+    status: RwLock<WorkerStatus>,
+    draining: AtomicBool,
+    /// 채널에 전달되었지만 아직 처리되지 않은 메시지 수
+    in_flight: AtomicUsize,
     processed_count: AtomicU64,
     failed_count: AtomicU64,
     total_processing_time: AtomicU64,
     last_activity: AtomicU64,
-    message_tx: mpsc::UnboundedSender<ConsumerMessage<T>>,
-    stats_tx: mpsc::UnboundedSender<WorkerStats>,
+}
+
+impl WorkerShared {
+    fn stats(&self, status: WorkerStatus) -> WorkerStats {
+        WorkerStats {
+            worker_id: self.worker_id,
+            status,
+            processed_count: self.processed_count.load(Ordering::Relaxed),
+            failed_count: self.failed_count.load(Ordering::Relaxed),
+            average_processing_time_ms: {
+                let total = self.total_processing_time.load(Ordering::Relaxed);
+                let count = self.processed_count.load(Ordering::Relaxed);
+                if count > 0 { total as f64 / count as f64 } else { 0.0 }
+            },
+            last_activity: self.last_activity.load(Ordering::Relaxed),
+            queue_size: self.in_flight.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// 개별 워커
+///
+/// 워커를 드롭하면 송신 채널이 닫히고, 워커 태스크는 이미 받은 메시지를 모두 처리한 뒤 종료됩니다.
+pub struct Worker<T, R> {
+    shared: Arc<WorkerShared>,
+    message_tx: mpsc::UnboundedSender<QueuedMessage<T>>,
+    _result: PhantomData<fn() -> R>,
 }
 
 impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> Worker<T, R> {
@@ -113,131 +428,125 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> Worker<
         worker_id: usize,
         processor_fn: Arc<dyn Fn(T) -> Result<R, String> + Send + Sync>,
         stats_tx: mpsc::UnboundedSender<WorkerStats>,
-    ) -> (Self, mpsc::UnboundedReceiver<ConsumerMessage<T>>) {
+        latency: Arc<LatencyTotals>,
+    ) -> Self {
         let (message_tx, message_rx) = mpsc::unbounded_channel();
-        
-        let worker = Self {
+        let shared = Arc::new(WorkerShared {
             worker_id,
-            status: Arc::new(RwLock::new(WorkerStatus::Idle)),
+            status: RwLock::new(WorkerStatus::Idle),
+            draining: AtomicBool::new(false),
+            in_flight: AtomicUsize::new(0),
             processed_count: AtomicU64::new(0),
             failed_count: AtomicU64::new(0),
             total_processing_time: AtomicU64::new(0),
             last_activity: AtomicU64::new(
                 SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
             ),
-            message_tx,
-            stats_tx,
-        };
-        
-        // 워커 태스크 시작
-        let worker_clone = worker.clone();
-        tokio::spawn(async move {
-            worker_clone.run(message_rx, processor_fn).await;
         });
-        
-        (worker, message_rx)
+
+        // 워커 태스크 시작 (송신 채널은 워커만 보유)
+        tokio::spawn(Self::run(shared.clone(), message_rx, processor_fn, stats_tx, latency));
+
+        Self {
+            shared,
+            message_tx,
+            _result: PhantomData,
+        }
     }
-    
+
     async fn run(
-        &self,
-        mut message_rx: mpsc::UnboundedReceiver<ConsumerMessage<T>>,
+        shared: Arc<WorkerShared>,
+        mut message_rx: mpsc::UnboundedReceiver<QueuedMessage<T>>,
         processor_fn: Arc<dyn Fn(T) -> Result<R, String> + Send + Sync>,
+        stats_tx: mpsc::UnboundedSender<WorkerStats>,
+        latency: Arc<LatencyTotals>,
     ) {
-        info!("워커 {} 시작", self.worker_id);
-        
-        while let Some(message) = message_rx.recv().await {
+        info!("워커 {} 시작", shared.worker_id);
+
+        while let Some(queued) = message_rx.recv().await {
+            let message = queued.message;
+
             // 상태를 Processing으로 변경
             {
-                let mut status = self.status.write().await;
+                let mut status = shared.status.write().await;
                 *status = WorkerStatus::Processing;
             }
-            
-            let start_time = SystemTime::now();
-            
+
+            let start_time = Instant::now();
+
             // 메시지 처리
-            match processor_fn(message.data.clone()).await {
+            match processor_fn(message.data.clone()) {
                 Ok(_) => {
-                    self.processed_count.fetch_add(1, Ordering::Relaxed);
-                    debug!("워커 {} 메시지 처리 성공: {}", self.worker_id, message.id);
+                    shared.processed_count.fetch_add(1, Ordering::Relaxed);
+                    debug!("워커 {} 메시지 처리 성공: {}", shared.worker_id, message.id);
                 }
                 Err(e) => {
-                    self.failed_count.fetch_add(1, Ordering::Relaxed);
-                    error!("워커 {} 메시지 처리 실패: {} - {}", self.worker_id, message.id, e);
+                    shared.failed_count.fetch_add(1, Ordering::Relaxed);
+                    error!("워커 {} 메시지 처리 실패: {} - {}", shared.worker_id, message.id, e);
                 }
             }
-            
-            let processing_time = start_time.elapsed().unwrap().as_millis() as u64;
-            self.total_processing_time.fetch_add(processing_time, Ordering::Relaxed);
-            self.last_activity.store(
+
+            let processing_time = start_time.elapsed().as_millis() as u64;
+            shared.total_processing_time.fetch_add(processing_time, Ordering::Relaxed);
+            shared.last_activity.store(
                 SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64,
                 Ordering::Relaxed
             );
-            
-            // 상태를 Idle로 변경
+            shared.in_flight.fetch_sub(1, Ordering::Relaxed);
+            latency.completed.fetch_add(1, Ordering::Relaxed);
+            latency.total_latency_ms.fetch_add(queued.enqueued_at.elapsed().as_millis() as u64, Ordering::Relaxed);
+
+            // 축소 대상이면 Draining 유지
+            let next_status = if shared.draining.load(Ordering::Relaxed) {
+                WorkerStatus::Draining
+            } else {
+                WorkerStatus::Idle
+            };
             {
-                let mut status = self.status.write().await;
-                *status = WorkerStatus::Idle;
+                let mut status = shared.status.write().await;
+                *status = next_status.clone();
             }
-            
+
             // 통계 전송
-            let stats = WorkerStats {
-                worker_id: self.worker_id,
-                status: WorkerStatus::Idle,
-                processed_count: self.processed_count.load(Ordering::Relaxed),
-                failed_count: self.failed_count.load(Ordering::Relaxed),
-                average_processing_time_ms: {
-                    let total = self.total_processing_time.load(Ordering::Relaxed);
-                    let count = self.processed_count.load(Ordering::Relaxed);
-                    if count > 0 { total as f64 / count as f64 } else { 0.0 }
-                },
-                last_activity: self.last_activity.load(Ordering::Relaxed),
-                queue_size: 0, // 개별 워커는 큐 크기를 추적하지 않음
-            };
-            
-            let _ = self.stats_tx.send(stats);
+            let _ = stats_tx.send(shared.stats(next_status));
         }
-        
-        info!("워커 {} 종료", self.worker_id);
+
+        *shared.status.write().await = WorkerStatus::Shutdown;
+        let _ = stats_tx.send(shared.stats(WorkerStatus::Shutdown));
+        info!("워커 {} 종료", shared.worker_id);
+    }
+
+    /// 메시지 전달 (실패 시 메시지를 돌려줌)
+    fn dispatch(&self, queued: QueuedMessage<T>) -> Result<(), QueuedMessage<T>> {
+        self.shared.in_flight.fetch_add(1, Ordering::Relaxed);
+        self.message_tx.send(queued).map_err(|e| {
+            self.shared.in_flight.fetch_sub(1, Ordering::Relaxed);
+            e.0
+        })
     }
-    
+
     pub async fn send_message(&self, message: ConsumerMessage<T>) -> Result<(), String> {
-        self.message_tx.send(message)
-            .map_err(|e| format!("워커 {} 메시지 전송 실패: {}", self.worker_id, e))
+        self.dispatch(QueuedMessage { message, enqueued_at: Instant::now() })
+            .map_err(|_| format!("워커 {} 메시지 전송 실패: 채널 닫힘", self.shared.worker_id))
     }
-    
+
     pub async fn get_status(&self) -> WorkerStatus {
-        self.status.read().await.clone()
+        self.shared.status.read().await.clone()
     }
-    
+
     pub fn get_stats(&self) -> WorkerStats {
-        WorkerStats {
-            worker_id: self.worker_id,
-            status: WorkerStatus::Idle, // Mock
-            processed_count: self.processed_count.load(Ordering::Relaxed),
-            failed_count: self.failed_count.load(Ordering::Relaxed),
-            average_processing_time_ms: {
-                let total = self.total_processing_time.load(Ordering::Relaxed);
-                let count = self.processed_count.load(Ordering::Relaxed);
-                if count > 0 { total as f64 / count as f64 } else { 0.0 }
-            },
-            last_activity: self.last_activity.load(Ordering::Relaxed),
-            queue_size: 0,
-        }
+        let status = self
+            .shared
+            .status
+            .try_read()
+            .map(|status| status.clone())
+            .unwrap_or(WorkerStatus::Processing);
+        self.shared.stats(status)
     }
-}
 
-impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> Clone for Worker<T, R> {
-    fn clone(&self) -> Self {
-        Self {
-            worker_id: self.worker_id,
-            status: self.status.clone(),
-            processed_count: AtomicU64::new(self.processed_count.load(Ordering::Relaxed)),
-            failed_count: AtomicU64::new(self.failed_count.load(Ordering::Relaxed)),
-            total_processing_time: AtomicU64::new(self.total_processing_time.load(Ordering::Relaxed)),
-            last_activity: AtomicU64::new(self.last_activity.load(Ordering::Relaxed)),
-            message_tx: self.message_tx.clone(),
-            stats_tx: self.stats_tx.clone(),
-        }
+    /// 채널에 대기 중인 메시지 수
+    pub fn in_flight(&self) -> usize {
+        self.shared.in_flight.load(Ordering::Relaxed)
     }
 }
 
@@ -247,26 +556,72 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> WorkerP
     where
         F: Fn(T) -> Result<R, String> + Send + Sync + 'static,
     {
+        let autoscaler = config.autoscale.clone().and_then(|autoscale| match autoscale.validate() {
+            Ok(()) => Some(Arc::new(Mutex::new(Autoscaler::new(autoscale)))),
+            Err(e) => {
+                warn!("워커 자동 조정 설정 무시: {}", e);
+                None
+            }
+        });
+        let (stats_tx, stats_rx) = mpsc::unbounded_channel();
+
         Self {
             config,
             workers: Arc::new(RwLock::new(HashMap::new())),
+            draining: Arc::new(RwLock::new(Vec::new())),
+            next_worker_id: Arc::new(AtomicUsize::new(0)),
             message_queue: Arc::new(Mutex::new(VecDeque::new())),
             stats: Arc::new(RwLock::new(ParallelConsumerStats {
                 total_workers: 0,
                 active_workers: 0,
                 idle_workers: 0,
                 error_workers: 0,
+                draining_workers: 0,
                 total_processed: 0,
                 total_failed: 0,
                 average_throughput_per_second: 0.0,
                 queue_size: 0,
                 worker_stats: Vec::new(),
             })),
+            stats_tx,
+            stats_rx: Arc::new(Mutex::new(Some(stats_rx))),
+            latency: Arc::new(LatencyTotals::default()),
+            autoscaler,
+            scaling_events: Arc::new(RwLock::new(VecDeque::new())),
+            published_events_ms: Arc::new(AtomicU64::new(0)),
+            notifications: None,
             is_running: Arc::new(Mutex::new(false)),
             processor_fn: Arc::new(processor_fn),
         }
     }
 
+    /// 스케일링 이벤트를 알림 시스템으로 보고
+    pub fn with_notifications(mut self, notifications: Arc<NotificationSystem>) -> Self {
+        self.notifications = Some(notifications);
+        self
+    }
+
+    /// 백그라운드 태스크용 핸들 (상태 공유)
+    fn handle(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            workers: self.workers.clone(),
+            draining: self.draining.clone(),
+            next_worker_id: self.next_worker_id.clone(),
+            message_queue: self.message_queue.clone(),
+            stats: self.stats.clone(),
+            stats_tx: self.stats_tx.clone(),
+            stats_rx: self.stats_rx.clone(),
+            latency: self.latency.clone(),
+            autoscaler: self.autoscaler.clone(),
+            scaling_events: self.scaling_events.clone(),
+            published_events_ms: self.published_events_ms.clone(),
+            notifications: self.notifications.clone(),
+            is_running: self.is_running.clone(),
+            processor_fn: self.processor_fn.clone(),
+        }
+    }
+
     /// 워커 풀 시작
     pub async fn start(&self) {
         let mut is_running = self.is_running.lock().await;
@@ -277,57 +632,52 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> WorkerP
         *is_running = true;
         drop(is_running);
 
-        info!("워커 풀 시작: {}개 워커", self.config.worker_count);
-
-        // 통계 수집 채널 생성
-        let (stats_tx, mut stats_rx) = mpsc::unbounded_channel();
+        let initial_workers = match &self.autoscaler {
+            Some(autoscaler) => autoscaler.lock().await.config().clamp(self.config.worker_count),
+            None => self.config.worker_count,
+        };
+        info!("워커 풀 시작: {}개 워커", initial_workers);
 
         // 워커 생성
-        let mut workers = self.workers.write().await;
-        for worker_id in 0..self.config.worker_count {
-            let (worker, _message_rx) = Worker::new(
-                worker_id,
-                self.processor_fn.clone(),
-                stats_tx.clone(),
-            );
-            workers.insert(worker_id, Arc::new(worker));
-        }
-        drop(workers);
+        self.scale_to(initial_workers).await;
 
         // 통계 수집 태스크
-        let stats = self.stats.clone();
-        tokio::spawn(async move {
-            while let Some(worker_stats) = stats_rx.recv().await {
-                let mut stats_guard = stats.write().await;
-                
-                // 워커 통계 업데이트
-                if let Some(existing_stats) = stats_guard.worker_stats.iter_mut()
-                    .find(|s| s.worker_id == worker_stats.worker_id) {
-                    *existing_stats = worker_stats;
-                } else {
-                    stats_guard.worker_stats.push(worker_stats);
+        if let Some(mut stats_rx) = self.stats_rx.lock().await.take() {
+            let stats = self.stats.clone();
+            tokio::spawn(async move {
+                // 종료된 워커의 누적치
+                let mut retired_processed = 0;
+                let mut retired_failed = 0;
+
+                while let Some(worker_stats) = stats_rx.recv().await {
+                    let mut stats_guard = stats.write().await;
+
+                    // 워커 통계 업데이트 (종료된 워커는 누적치로 합산 후 제거)
+                    stats_guard.worker_stats.retain(|s| s.worker_id != worker_stats.worker_id);
+                    if matches!(worker_stats.status, WorkerStatus::Shutdown) {
+                        retired_processed += worker_stats.processed_count;
+                        retired_failed += worker_stats.failed_count;
+                    } else {
+                        stats_guard.worker_stats.push(worker_stats);
+                    }
+
+                    // 전체 통계 업데이트
+                    stats_guard.total_processed = retired_processed + stats_guard.worker_stats.iter()
+                        .map(|s| s.processed_count).sum::<u64>();
+                    stats_guard.total_failed = retired_failed + stats_guard.worker_stats.iter()
+                        .map(|s| s.failed_count).sum::<u64>();
+                    stats_guard.active_workers = stats_guard.worker_stats.iter()
+                        .filter(|s| matches!(s.status, WorkerStatus::Processing)).count();
+                    stats_guard.idle_workers = stats_guard.worker_stats.iter()
+                        .filter(|s| matches!(s.status, WorkerStatus::Idle)).count();
+                    stats_guard.error_workers = stats_guard.worker_stats.iter()
+                        .filter(|s| matches!(s.status, WorkerStatus::Error)).count();
                 }
-                
-                // 전체 통계 업데이트
-                stats_guard.total_processed = stats_guard.worker_stats.iter()
-                    .map(|s| s.processed_count).sum();
-                stats_guard.total_failed = stats_guard.worker_stats.iter()
-                    .map(|s| s.failed_count).sum();
-                stats_guard.active_workers = stats_guard.worker_stats.iter()
-                    .filter(|s| matches!(s.status, WorkerStatus::Processing)).count();
-                stats_guard.idle_workers = stats_guard.worker_stats.iter()
-                    .filter(|s| matches!(s.status, WorkerStatus::Idle)).count();
-                stats_guard.error_workers = stats_guard.worker_stats.iter()
-                    .filter(|s| matches!(s.status, WorkerStatus::Error)).count();
-            }
-        });
+            });
+        }
 
         // 메시지 분배 태스크
-        let message_queue = self.message_queue.clone();
-        let workers = self.workers.clone();
-        let config = self.config.clone();
-        let is_running = self.is_running.clone();
-
+        let pool = self.handle();
         tokio::spawn(async move {
             let mut interval = interval(Duration::from_millis(10)); // 10ms마다 체크
             let mut round_robin_index = 0;
@@ -336,26 +686,36 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> WorkerP
                 interval.tick().await;
 
                 // 실행 중단 확인
-                {
-                    let running = is_running.lock().await;
-                    if !*running {
-                        break;
-                    }
+                if !*pool.is_running.lock().await {
+                    break;
                 }
 
                 // 메시지 분배
-                if let Err(e) = Self::distribute_messages(
-                    &message_queue,
-                    &workers,
-                    &mut round_robin_index,
-                    &config,
-                ).await {
+                if let Err(e) = pool.distribute_messages(&mut round_robin_index).await {
                     error!("메시지 분배 실패: {}", e);
                 }
             }
 
             info!("워커 풀 종료");
         });
+
+        // 워커 수 자동 조정 태스크
+        if let Some(autoscaler) = &self.autoscaler {
+            let check_interval_ms = autoscaler.lock().await.config().check_interval_ms;
+            let pool = self.handle();
+            tokio::spawn(async move {
+                let mut interval = interval(Duration::from_millis(check_interval_ms));
+                interval.tick().await;
+
+                loop {
+                    interval.tick().await;
+                    if !*pool.is_running.lock().await {
+                        break;
+                    }
+                    pool.autoscale_now().await;
+                }
+            });
+        }
     }
 
     /// 워커 풀 중단
@@ -368,35 +728,30 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> WorkerP
     /// 메시지 추가
     pub async fn add_message(&self, message: ConsumerMessage<T>) -> Result<(), String> {
         let mut queue = self.message_queue.lock().await;
-        
+
         if queue.len() >= self.config.queue_capacity {
             return Err("큐가 가득참".to_string());
         }
 
-        queue.push_back(message);
+        queue.push_back(QueuedMessage { message, enqueued_at: Instant::now() });
         Ok(())
     }
 
     /// 메시지 분배
-    async fn distribute_messages(
-        message_queue: &Arc<Mutex<VecDeque<ConsumerMessage<T>>>>,
-        workers: &Arc<RwLock<HashMap<usize, Arc<Worker<T, R>>>>>,
-        round_robin_index: &mut usize,
-        config: &ParallelConsumerConfig,
-    ) -> Result<(), String> {
-        let mut queue = message_queue.lock().await;
-        let workers_guard = workers.read().await;
-        
+    async fn distribute_messages(&self, round_robin_index: &mut usize) -> Result<(), String> {
+        let mut queue = self.message_queue.lock().await;
+        let workers_guard = self.workers.read().await;
+
         if queue.is_empty() || workers_guard.is_empty() {
             return Ok(());
         }
 
         let mut distributed_count = 0;
         let worker_ids: Vec<usize> = workers_guard.keys().cloned().collect();
-        
-        while !queue.is_empty() && distributed_count < config.batch_size {
+
+        while !queue.is_empty() && distributed_count < self.config.batch_size {
             // 로드 밸런싱 활성화된 경우 가장 유휴한 워커 선택
-            let worker_id = if config.enable_load_balancing {
+            let worker_id = if self.config.enable_load_balancing {
                 Self::select_least_busy_worker(&workers_guard)?
             } else {
                 // 라운드 로빈
@@ -405,52 +760,237 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> WorkerP
                 id
             };
 
-            if let Some(message) = queue.pop_front() {
-                if let Some(worker) = workers_guard.get(&worker_id) {
-                    if let Err(e) = worker.send_message(message).await {
-                        error!("워커 {} 메시지 전송 실패: {}", worker_id, e);
-                        // 실패한 메시지를 큐에 다시 추가
-                        queue.push_front(ConsumerMessage {
+            let Some(worker) = workers_guard.get(&worker_id) else {
+                break;
+            };
+            if let Some(queued) = queue.pop_front() {
+                if let Err(queued) = worker.dispatch(queued) {
+                    error!("워커 {} 메시지 전송 실패", worker_id);
+                    // 실패한 메시지를 큐에 다시 추가
+                    let message = queued.message;
+                    queue.push_front(QueuedMessage {
+                        message: ConsumerMessage {
                             id: uuid::Uuid::new_v4().to_string(),
                             data: message.data,
                             partition: message.partition,
                             offset: message.offset,
                             timestamp: message.timestamp,
                             retry_count: message.retry_count + 1,
-                        });
-                    } else {
-                        distributed_count += 1;
-                    }
+                        },
+                        enqueued_at: queued.enqueued_at,
+                    });
+                    break;
                 }
+                distributed_count += 1;
             }
         }
 
         Ok(())
     }
 
-    /// 가장 유휴한 워커 선택
+    /// 가장 유휴한 워커 선택 (채널 대기 메시지가 가장 적은 워커)
     fn select_least_busy_worker(
-        workers: &HashMap<usize, Arc<Worker<T, R>>>,
+        workers: &WorkerMap<T, R>,
     ) -> Result<usize, String> {
-        let mut best_worker_id = 0;
-        let mut min_processed = u64::MAX;
-
-        for (worker_id, worker) in workers.iter() {
-            let processed = worker.processed_count.load(Ordering::Relaxed);
-            if processed < min_processed {
-                min_processed = processed;
-                best_worker_id = *worker_id;
+        workers
+            .iter()
+            .min_by_key(|(worker_id, worker)| (worker.in_flight(), **worker_id))
+            .map(|(worker_id, _)| *worker_id)
+            .ok_or_else(|| "사용 가능한 워커 없음".to_string())
+    }
+
+    /// 워커 수를 목표치로 조정 (축소 시 대기 메시지가 적은 워커부터 드레인)
+    async fn scale_to(&self, target: usize) {
+        let mut workers = self.workers.write().await;
+
+        while workers.len() < target {
+            let worker_id = self.next_worker_id.fetch_add(1, Ordering::Relaxed);
+            let worker = Worker::new(
+                worker_id,
+                self.processor_fn.clone(),
+                self.stats_tx.clone(),
+                self.latency.clone(),
+            );
+            workers.insert(worker_id, Arc::new(worker));
+        }
+
+        if workers.len() > target {
+            let mut candidates: Vec<(usize, usize)> =
+                workers.iter().map(|(worker_id, worker)| (worker.in_flight(), *worker_id)).collect();
+            // 대기 메시지가 적고 최근에 생성된 워커 우선
+            candidates.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+            let excess = workers.len() - target;
+            let mut draining = self.draining.write().await;
+            for (_, worker_id) in candidates.into_iter().take(excess) {
+                if let Some(worker) = workers.remove(&worker_id) {
+                    // 워커가 드롭되면 채널이 닫히고 남은 메시지 처리 후 종료
+                    worker.shared.draining.store(true, Ordering::Relaxed);
+                    if let Ok(mut status) = worker.shared.status.try_write() {
+                        if matches!(*status, WorkerStatus::Idle) {
+                            *status = WorkerStatus::Draining;
+                        }
+                    }
+                    info!("워커 {} 드레인 시작 (대기 {}개)", worker_id, worker.in_flight());
+                    draining.push(worker.shared.clone());
+                }
+            }
+        }
+    }
+
+    /// 종료된 드레인 워커 정리 후 남은 드레인 워커 수
+    async fn prune_draining(&self) -> usize {
+        let mut draining = self.draining.write().await;
+        let mut remaining = Vec::with_capacity(draining.len());
+        for shared in draining.drain(..) {
+            if !matches!(*shared.status.read().await, WorkerStatus::Shutdown) {
+                remaining.push(shared);
+            }
+        }
+        *draining = remaining;
+        draining.len()
+    }
+
+    /// 풀 큐와 워커 채널에 쌓인 메시지 수
+    pub async fn queue_depth(&self) -> usize {
+        let queued = self.message_queue.lock().await.len();
+        let in_flight: usize = self.workers.read().await.values().map(|worker| worker.in_flight()).sum();
+        queued + in_flight
+    }
+
+    /// 현재 상태로 즉시 한 번 조정 판단 (자동 조정이 꺼져 있으면 None)
+    pub async fn autoscale_now(&self) -> Option<ScalingEvent> {
+        let autoscaler = self.autoscaler.as_ref()?;
+        self.prune_draining().await;
+
+        let sample = ScalingSample {
+            workers: self.workers.read().await.len(),
+            queue_depth: self.queue_depth().await,
+            avg_latency_ms: None,
+        };
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let event = {
+            let mut autoscaler = autoscaler.lock().await;
+            let avg_latency_ms = autoscaler.observe_latency(
+                self.latency.completed.load(Ordering::Relaxed),
+                self.latency.total_latency_ms.load(Ordering::Relaxed),
+            );
+            autoscaler.evaluate(&ScalingSample { avg_latency_ms, ..sample }, now)?
+        };
+
+        self.scale_to(event.to).await;
+        info!("{} ({})", event.title(), event.content());
+
+        {
+            let mut events = self.scaling_events.write().await;
+            events.push_back(event.clone());
+            if events.len() > MAX_SCALING_EVENTS {
+                events.pop_front();
+            }
+        }
+        self.notify_scaling(&event).await;
+        Some(event)
+    }
+
+    async fn notify_scaling(&self, event: &ScalingEvent) {
+        let Some(notifications) = &self.notifications else {
+            return;
+        };
+        let Some(autoscaler) = &self.autoscaler else {
+            return;
+        };
+        let channel = autoscaler.lock().await.config().notification_channel.clone();
+
+        let (priority, notification_type) = match event.direction {
+            ScaleDirection::Up => (NotificationPriority::Normal, NotificationType::PerformanceAlert),
+            ScaleDirection::Down => (NotificationPriority::Low, NotificationType::StatusChange),
+        };
+        let metadata = HashMap::from([
+            (SOURCE_METADATA_KEY.to_string(), "worker_pool".to_string()),
+            ("direction".to_string(), event.direction.as_str().to_string()),
+            ("reason".to_string(), event.reason.as_str().to_string()),
+            ("workers".to_string(), event.to.to_string()),
+        ]);
+
+        if let Err(e) = notifications
+            .send_notification_with_metadata(
+                event.title(),
+                event.content(),
+                channel,
+                priority,
+                notification_type,
+                metadata,
+            )
+            .await
+        {
+            error!("워커 풀 스케일링 알림 발송 실패: {}", e);
+        }
+    }
+
+    /// 최근 스케일링 이벤트 (오래된 순)
+    pub async fn get_scaling_events(&self) -> Vec<ScalingEvent> {
+        self.scaling_events.read().await.iter().cloned().collect()
+    }
+
+    /// 활성 워커 수
+    pub async fn worker_count(&self) -> usize {
+        self.workers.read().await.len()
+    }
+
+    /// MetricsCollector로 워커 풀 지표 발행 (`consumer.*`), 새 스케일링 이벤트는 `consumer.scale` 커스텀 메트릭으로
+    pub async fn publish(&self, collector: &MetricsCollector) {
+        let draining = self.prune_draining().await;
+        collector.set_gauge("consumer.workers", self.worker_count().await as u64).await;
+        collector.set_gauge("consumer.workers.draining", draining as u64).await;
+        collector.set_gauge("consumer.queue_depth", self.queue_depth().await as u64).await;
+
+        let completed = self.latency.completed.load(Ordering::Relaxed);
+        let average_latency = self.latency.total_latency_ms.load(Ordering::Relaxed)
+            .checked_div(completed)
+            .unwrap_or(0);
+        collector.set_gauge("consumer.latency.avg_ms", average_latency).await;
+
+        let Some(autoscaler) = &self.autoscaler else {
+            return;
+        };
+        {
+            let autoscaler = autoscaler.lock().await;
+            let (scale_ups, scale_downs) = autoscaler.scale_counts();
+            collector.set_gauge("consumer.scale_ups", scale_ups).await;
+            collector.set_gauge("consumer.scale_downs", scale_downs).await;
+            if let Some(latency) = autoscaler.last_window_latency_ms() {
+                collector.set_gauge("consumer.latency.window_ms", latency.round() as u64).await;
             }
         }
 
-        Ok(best_worker_id)
+        let published = self.published_events_ms.load(Ordering::Relaxed);
+        let new_events: Vec<ScalingEvent> =
+            self.scaling_events.read().await.iter().filter(|e| e.timestamp > published).cloned().collect();
+        for event in new_events {
+            self.published_events_ms.fetch_max(event.timestamp, Ordering::Relaxed);
+            let tags = HashMap::from([
+                ("direction".to_string(), event.direction.as_str().to_string()),
+                ("reason".to_string(), event.reason.as_str().to_string()),
+                ("from".to_string(), event.from.to_string()),
+            ]);
+            collector
+                .add_custom_metric(MetricData {
+                    name: "consumer.scale".to_string(),
+                    value: event.to as f64,
+                    timestamp: event.timestamp,
+                    tags,
+                    metric_type: MetricType::Gauge,
+                })
+                .await;
+        }
     }
 
     /// 통계 조회
     pub async fn get_stats(&self) -> ParallelConsumerStats {
         let mut stats = self.stats.read().await.clone();
-        let queue = self.message_queue.lock().await;
-        stats.queue_size = queue.len();
+        stats.queue_size = self.message_queue.lock().await.len();
+        stats.total_workers = self.workers.read().await.len();
+        stats.draining_workers = self.prune_draining().await;
         stats
     }
 
@@ -462,11 +1002,8 @@ impl<T: Clone + Send + Sync + 'static, R: Clone + Send + Sync + 'static> WorkerP
 
     /// 워커 상태 조회
     pub async fn get_worker_status(&self, worker_id: usize) -> Option<WorkerStatus> {
-        let workers = self.workers.read().await;
-        workers.get(&worker_id).map(|worker| {
-            // 비동기 함수를 동기적으로 호출할 수 없으므로 Mock 상태 반환
-            WorkerStatus::Idle
-        })
+        let worker = self.workers.read().await.get(&worker_id).cloned()?;
+        Some(worker.get_status().await)
     }
 }
 
@@ -494,9 +1031,10 @@ impl BackpressureManager {
         self.current_load.fetch_sub(1, Ordering::Relaxed);
     }
 
+    /// 부하가 임계값을 넘으면 제한 (임계값과 같을 때는 허용)
     pub fn should_throttle(&self) -> bool {
         let load = self.current_load.load(Ordering::Relaxed);
-        if load >= self.threshold {
+        if load > self.threshold {
             self.is_throttling.store(1, Ordering::Relaxed);
             true
         } else {
@@ -517,11 +1055,12 @@ impl BackpressureManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tokio::time::sleep;
 
     #[tokio::test]
     async fn test_worker_pool_creation() {
         let config = ParallelConsumerConfig::default();
-        let pool = WorkerPool::new(config, |data| Ok(format!("processed_{}", data)));
+        let pool = WorkerPool::new(config, |data: String| Ok(format!("processed_{}", data)));
         
         let stats = pool.get_stats().await;
         assert_eq!(stats.total_workers, 0);
@@ -539,9 +1078,10 @@ mod tests {
             enable_backpressure: false,
             max_retries: 3,
             retry_delay_ms: 100,
+            autoscale: None,
         };
         
-        let pool = WorkerPool::new(config, |data| Ok(format!("processed_{}", data)));
+        let pool = WorkerPool::new(config, |data: String| Ok(format!("processed_{}", data)));
         
         // 워커 풀 시작
         pool.start().await;
@@ -600,7 +1140,7 @@ mod tests {
     #[tokio::test]
     async fn test_worker_stats() {
         let config = ParallelConsumerConfig::default();
-        let pool = WorkerPool::new(config, |data| Ok(format!("processed_{}", data)));
+        let pool = WorkerPool::new(config, |data: String| Ok(format!("processed_{}", data)));
         
         pool.start().await;
         
@@ -610,4 +1150,120 @@ mod tests {
         
         pool.stop().await;
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_autoscaler_decisions() {
+        let mut autoscaler = Autoscaler::new(AutoscaleConfig {
+            min_workers: 2,
+            max_workers: 6,
+            scale_up_queue_per_worker: 10,
+            scale_down_queue_per_worker: 1,
+            target_latency_ms: 100,
+            scale_up_step: 3,
+            scale_down_step: 1,
+            cooldown_ms: 1000,
+            scale_down_stable_checks: 2,
+            ..AutoscaleConfig::default()
+        });
+        let sample = |workers, queue_depth, avg_latency_ms| ScalingSample { workers, queue_depth, avg_latency_ms };
+
+        // 큐 깊이 초과 → 확장 (최대치로 제한)
+        let event = autoscaler.evaluate(&sample(4, 40, None), 1_000).unwrap();
+        assert_eq!((event.direction, event.reason, event.from, event.to), (ScaleDirection::Up, ScaleReason::QueueDepth, 4, 6));
+        // 최대치에서는 더 확장하지 않음
+        assert!(autoscaler.evaluate(&sample(6, 600, None), 3_000).is_none());
+
+        // 쿨다운 중에는 지연 초과도 보류
+        assert!(autoscaler.evaluate(&sample(3, 0, Some(150.0)), 1_500).is_none());
+        let event = autoscaler.evaluate(&sample(3, 0, Some(150.0)), 4_000).unwrap();
+        assert_eq!((event.reason, event.to), (ScaleReason::Latency, 6));
+
+        // 축소는 조건이 연속으로 유지되어야 함
+        assert!(autoscaler.evaluate(&sample(6, 0, Some(10.0)), 6_000).is_none());
+        assert!(autoscaler.evaluate(&sample(6, 0, Some(80.0)), 7_000).is_none());
+        assert!(autoscaler.evaluate(&sample(6, 0, Some(10.0)), 8_000).is_none());
+        let event = autoscaler.evaluate(&sample(6, 0, None), 9_000).unwrap();
+        assert_eq!((event.direction, event.reason, event.to), (ScaleDirection::Down, ScaleReason::Idle, 5));
+        assert_eq!(autoscaler.scale_counts(), (2, 1));
+
+        // 구간 평균 지연
+        assert_eq!(autoscaler.observe_latency(10, 500), Some(50.0));
+        assert_eq!(autoscaler.observe_latency(10, 500), None);
+        assert_eq!(autoscaler.observe_latency(14, 900), Some(100.0));
+
+        assert!(AutoscaleConfig { min_workers: 4, max_workers: 2, ..AutoscaleConfig::default() }.validate().is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_pool_autoscale_and_drain() {
+        let config = ParallelConsumerConfig {
+            worker_count: 1,
+            batch_size: 100,
+            autoscale: Some(AutoscaleConfig {
+                min_workers: 1,
+                max_workers: 3,
+                check_interval_ms: 60_000,
+                scale_up_queue_per_worker: 10,
+                scale_down_queue_per_worker: 1,
+                target_latency_ms: 60_000,
+                scale_up_step: 2,
+                scale_down_step: 2,
+                cooldown_ms: 0,
+                scale_down_stable_checks: 1,
+                ..AutoscaleConfig::default()
+            }),
+            ..ParallelConsumerConfig::default()
+        };
+        let pool = WorkerPool::new(config, |data: String| {
+            std::thread::sleep(Duration::from_millis(2));
+            Ok(data)
+        });
+        pool.start().await;
+        assert_eq!(pool.worker_count().await, 1);
+
+        for i in 0..100 {
+            pool.add_message(ConsumerMessage {
+                id: format!("msg_{}", i),
+                data: format!("data_{}", i),
+                partition: None,
+                offset: None,
+                timestamp: 0,
+                retry_count: 0,
+            }).await.unwrap();
+        }
+
+        let event = pool.autoscale_now().await.unwrap();
+        assert_eq!((event.direction, event.to), (ScaleDirection::Up, 3));
+        assert_eq!(pool.worker_count().await, 3);
+
+        for _ in 0..250 {
+            if pool.get_stats().await.total_processed == 100 {
+                break;
+            }
+            sleep(Duration::from_millis(20)).await;
+        }
+        assert_eq!(pool.queue_depth().await, 0);
+
+        // 유휴 → 축소, 드레인된 워커는 종료 후 정리되고 처리 건수는 유지
+        let event = pool.autoscale_now().await.unwrap();
+        assert_eq!((event.direction, event.to), (ScaleDirection::Down, 1));
+        assert_eq!(pool.worker_count().await, 1);
+        for _ in 0..50 {
+            if pool.get_stats().await.draining_workers == 0 {
+                break;
+            }
+            sleep(Duration::from_millis(20)).await;
+        }
+        let stats = pool.get_stats().await;
+        assert_eq!((stats.total_workers, stats.draining_workers, stats.total_processed), (1, 0, 100));
+
+        let collector = MetricsCollector::new(Default::default());
+        pool.publish(&collector).await;
+        assert_eq!(collector.get_gauge("consumer.workers").await, Some(1));
+        assert_eq!(collector.get_gauge("consumer.scale_ups").await, Some(1));
+        assert_eq!(collector.get_gauge("consumer.scale_downs").await, Some(1));
+        assert_eq!(pool.get_scaling_events().await.len(), 2);
+
+        pool.stop().await;
+    }
+}
@@ -361,7 +361,7 @@ pub struct QueueStatus {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::matching_engine::model::{ExecutionReport, Side};
+    use crate::matching_engine::model::{ExecutionReport, ExecType, Side};
     use uuid::Uuid;
     
     fn create_test_execution() -> ExecutionReport {
@@ -379,6 +379,9 @@ mod tests {
                 .as_secs(),
             counterparty_id: "order2".to_string(),
             is_maker: false,
+            exec_type: ExecType::Trade,
+            sequence: 0,
+            tag: None,
         }
     }
     
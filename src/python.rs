@@ -0,0 +1,276 @@
+//! 매칭 엔진 Python 바인딩 (PyO3, `pyo3` 기능)
+//!
+//! 퀀트 리서치에서 Python으로 운영과 같은 매칭 엔진을 구동할 수 있도록 `xtrader` 확장 모듈을 제공합니다.
+//! 엔진은 호출한 스레드에서 동기적으로 매칭하며(MQ, DB, HTTP 없음), 주문 한 건을 처리할 때마다 나온
+//! 체결 보고서를 반환하고 등록된 콜백에도 순서대로 전달합니다.
+//!
+//! ```python
+//! import xtrader
+//!
+//! engine = xtrader.MatchingEngine(["BTC-KRW"], on_execution=lambda r: print(r))
+//! engine.submit_order("s1", "BTC-KRW", "sell", 1000, 5)
+//! fills = engine.submit_order("b1", "BTC-KRW", "buy", 1000, 3)
+//! book = engine.order_book("BTC-KRW")
+//! print(book.best_ask)  # (1000, 2)
+//! ```
+//!
+//! 빌드: `maturin develop --features pyo3,pyo3/extension-module` (`pyproject.toml` 참고)
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::matching_engine::model::{ExecutionReport, Order, OrderBookSnapshot, OrderType, Side};
+use crate::matching_engine::MatchingEngine;
+use crate::sequencer::backpressure::{bounded_queue, BoundedReceiver, OverflowPolicy};
+
+/// 체결 보고서 큐 용량 (주문 한 건이 만드는 보고서를 매번 모두 꺼내므로 넉넉하게)
+const EXEC_QUEUE_CAPACITY: usize = 1 << 16;
+
+/// 체결 보고서 (Python `xtrader.ExecutionReport`)
+#[pyclass(name = "ExecutionReport", module = "xtrader", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct PyExecutionReport {
+    pub execution_id: String,
+    pub order_id: String,
+    pub symbol: String,
+    /// "Buy" / "Sell"
+    pub side: String,
+    pub price: u64,
+    pub quantity: u64,
+    pub remaining_quantity: u64,
+    pub timestamp: u64,
+    pub counterparty_id: String,
+    pub is_maker: bool,
+    /// "Trade" / "Canceled" / "Expired" / "Rejected"
+    pub exec_type: String,
+    /// 엔진 인스턴스 안에서 증가하는 보고서 번호
+    pub sequence: u64,
+}
+
+impl From<ExecutionReport> for PyExecutionReport {
+    fn from(report: ExecutionReport) -> Self {
+        Self {
+            execution_id: report.execution_id,
+            order_id: report.order_id,
+            symbol: report.symbol,
+            side: format!("{:?}", report.side),
+            price: report.price,
+            quantity: report.quantity,
+            remaining_quantity: report.remaining_quantity,
+            timestamp: report.timestamp,
+            counterparty_id: report.counterparty_id,
+            is_maker: report.is_maker,
+            exec_type: format!("{:?}", report.exec_type),
+            sequence: report.sequence,
+        }
+    }
+}
+
+#[pymethods]
+impl PyExecutionReport {
+    fn __repr__(&self) -> String {
+        format!(
+            "ExecutionReport(order_id={:?}, exec_type={:?}, side={:?}, price={}, quantity={}, remaining_quantity={}, sequence={})",
+            self.order_id, self.exec_type, self.side, self.price, self.quantity, self.remaining_quantity, self.sequence
+        )
+    }
+}
+
+/// 호가창 스냅샷 (Python `xtrader.OrderBook`, 가격 레벨별 `(가격, 수량)`)
+#[pyclass(name = "OrderBook", module = "xtrader", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct PyOrderBook {
+    pub symbol: String,
+    /// 매수 호가 (높은 가격부터)
+    pub bids: Vec<(u64, u64)>,
+    /// 매도 호가 (낮은 가격부터)
+    pub asks: Vec<(u64, u64)>,
+}
+
+impl From<OrderBookSnapshot> for PyOrderBook {
+    fn from(snapshot: OrderBookSnapshot) -> Self {
+        Self { symbol: snapshot.symbol, bids: snapshot.bids, asks: snapshot.asks }
+    }
+}
+
+#[pymethods]
+impl PyOrderBook {
+    /// 최우선 매수 호가
+    #[getter]
+    fn best_bid(&self) -> Option<(u64, u64)> {
+        self.bids.first().copied()
+    }
+
+    /// 최우선 매도 호가
+    #[getter]
+    fn best_ask(&self) -> Option<(u64, u64)> {
+        self.asks.first().copied()
+    }
+
+    /// 호가 스프레드 (한쪽이 비었으면 None)
+    #[getter]
+    fn spread(&self) -> Option<u64> {
+        Some(self.best_ask()?.0.saturating_sub(self.best_bid()?.0))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("OrderBook(symbol={:?}, bids={:?}, asks={:?})", self.symbol, self.bids, self.asks)
+    }
+}
+
+/// 매칭 엔진 (Python `xtrader.MatchingEngine`)
+///
+/// 엔진 상태는 생성한 스레드에 묶입니다 (다른 스레드에서 호출하면 Python 예외).
+#[pyclass(name = "MatchingEngine", module = "xtrader", unsendable)]
+pub struct PyMatchingEngine {
+    engine: MatchingEngine,
+    exec_rx: BoundedReceiver<ExecutionReport>,
+    callbacks: Vec<PyObject>,
+    next_sequence: u64,
+}
+
+#[pymethods]
+impl PyMatchingEngine {
+    /// 심볼별 주문장을 만들고, `on_execution`이 있으면 체결 보고서 콜백으로 등록
+    #[new]
+    #[pyo3(signature = (symbols, on_execution=None))]
+    fn new(symbols: Vec<String>, on_execution: Option<PyObject>) -> Self {
+        let (exec_tx, exec_rx) = bounded_queue("python_executions", EXEC_QUEUE_CAPACITY, OverflowPolicy::Block);
+        Self {
+            engine: MatchingEngine::new(symbols, exec_tx, None),
+            exec_rx,
+            callbacks: on_execution.into_iter().collect(),
+            next_sequence: 0,
+        }
+    }
+
+    /// 체결 보고서 콜백 추가 (`callback(report)`, 등록 순서대로 호출)
+    fn on_execution(&mut self, callback: PyObject) {
+        self.callbacks.push(callback);
+    }
+
+    /// 주문 제출 후 이 주문으로 나온 체결 보고서 반환
+    ///
+    /// `side`는 "buy"/"sell", `order_type`은 "limit"/"market" (대소문자 무시).
+    #[pyo3(signature = (order_id, symbol, side, price, quantity, order_type="limit", client_id="python"))]
+    #[allow(clippy::too_many_arguments)]
+    fn submit_order(
+        &mut self,
+        py: Python<'_>,
+        order_id: String,
+        symbol: String,
+        side: &str,
+        price: u64,
+        quantity: u64,
+        order_type: &str,
+        client_id: &str,
+    ) -> PyResult<Vec<PyExecutionReport>> {
+        let side = parse_side(side)?;
+        let order_type = parse_order_type(order_type)?;
+        if quantity == 0 {
+            return Err(PyValueError::new_err("quantity는 0보다 커야 합니다"));
+        }
+        if order_type == OrderType::Limit && price == 0 {
+            return Err(PyValueError::new_err("지정가 주문은 price가 0보다 커야 합니다"));
+        }
+
+        self.engine.submit(Order::new(order_id, symbol, side, order_type, price, quantity, client_id.to_string()));
+        self.dispatch(py)
+    }
+
+    /// 주문 취소 후 체결 보고서 반환 (없는 주문이면 빈 목록)
+    fn cancel_order(&mut self, py: Python<'_>, order_id: String) -> PyResult<Vec<PyExecutionReport>> {
+        self.engine.submit(Order::new_cancel(order_id));
+        self.dispatch(py)
+    }
+
+    /// 호가창 스냅샷 (`depth`: 방향별 가격 레벨 수, 없는 심볼이면 None)
+    #[pyo3(signature = (symbol, depth=10))]
+    fn order_book(&self, symbol: &str, depth: usize) -> Option<PyOrderBook> {
+        self.engine.get_order_book_snapshot(symbol, depth).map(PyOrderBook::from)
+    }
+
+    /// 주문장에 남아 있는 주문의 잔량 (없으면 None)
+    fn remaining_quantity(&self, order_id: &str) -> Option<u64> {
+        self.engine.get_order(order_id).map(|order| order.remaining_quantity)
+    }
+}
+
+impl PyMatchingEngine {
+    /// 쌓인 체결 보고서에 번호를 붙여 콜백에 전달하고 반환 (콜백 예외는 그대로 전파)
+    fn dispatch(&mut self, py: Python<'_>) -> PyResult<Vec<PyExecutionReport>> {
+        let mut reports = Vec::new();
+        while let Ok(mut report) = self.exec_rx.try_recv() {
+            self.next_sequence += 1;
+            report.sequence = self.next_sequence;
+            reports.push(PyExecutionReport::from(report));
+        }
+        for report in &reports {
+            for callback in &self.callbacks {
+                callback.call1(py, (report.clone(),))?;
+            }
+        }
+        Ok(reports)
+    }
+}
+
+fn parse_side(side: &str) -> PyResult<Side> {
+    match side.to_ascii_lowercase().as_str() {
+        "buy" => Ok(Side::Buy),
+        "sell" => Ok(Side::Sell),
+        _ => Err(PyValueError::new_err(format!("side는 buy/sell 중 하나여야 합니다: {}", side))),
+    }
+}
+
+fn parse_order_type(order_type: &str) -> PyResult<OrderType> {
+    match order_type.to_ascii_lowercase().as_str() {
+        "limit" => Ok(OrderType::Limit),
+        "market" => Ok(OrderType::Market),
+        _ => Err(PyValueError::new_err(format!("order_type은 limit/market 중 하나여야 합니다: {}", order_type))),
+    }
+}
+
+/// `import xtrader` 진입점
+#[pymodule]
+fn xtrader(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyMatchingEngine>()?;
+    m.add_class::<PyOrderBook>()?;
+    m.add_class::<PyExecutionReport>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyList;
+
+    #[test]
+    fn test_submit_cancel_and_callbacks() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let received = PyList::empty_bound(py);
+            let callback = received.getattr("append").unwrap().unbind();
+            let mut engine = PyMatchingEngine::new(vec!["BTC-KRW".to_string()], Some(callback));
+
+            assert!(engine.submit_order(py, "s1".into(), "BTC-KRW".into(), "SELL", 1_000, 5, "limit", "maker").unwrap().is_empty());
+            let fills = engine.submit_order(py, "b1".into(), "BTC-KRW".into(), "buy", 1_000, 3, "limit", "taker").unwrap();
+            assert_eq!(fills.len(), 2);
+            assert!(fills.iter().all(|fill| fill.exec_type == "Trade" && fill.quantity == 3));
+            assert_eq!((fills[0].sequence, fills[1].sequence), (1, 2));
+
+            let book = engine.order_book("BTC-KRW", 5).unwrap();
+            assert_eq!((book.best_ask(), book.best_bid(), book.spread()), (Some((1_000, 2)), None, None));
+            assert_eq!(engine.remaining_quantity("s1"), Some(2));
+
+            let canceled = engine.cancel_order(py, "s1".into()).unwrap();
+            assert_eq!(canceled[0].exec_type, "Canceled");
+            assert!(engine.order_book("BTC-KRW", 5).unwrap().asks.is_empty());
+
+            // 콜백은 반환값과 같은 보고서를 순서대로 받음
+            assert_eq!(received.len(), 3);
+
+            assert!(engine.submit_order(py, "x".into(), "BTC-KRW".into(), "hold", 1, 1, "limit", "bot").is_err());
+            assert!(engine.submit_order(py, "x".into(), "BTC-KRW".into(), "buy", 1, 0, "limit", "bot").is_err());
+        });
+    }
+}
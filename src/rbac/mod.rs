@@ -0,0 +1,609 @@
+//! 역할 기반 접근 제어 (RBAC)
+//!
+//! API 계정(`client_id`)과 관리자(`X-Admin-User`)에 역할(trader, market-maker, ops, compliance, admin)을
+//! 지정하고, 역할마다 권한 묶음을 DB에 둡니다. REST 라우터는 경로 묶음마다 필요한 권한을 미들웨어로
+//! 확인하므로 핸들러의 계정 본인 확인(`AuthContext::authorize`)과 관리자 토큰 확인은 그대로 유지됩니다.
+//!
+//! 역할 권한은 처음 시작할 때 기본값으로 채우고 이후에는 관리자 API로 바꿉니다. 역할을 지정하지 않은
+//! 대상은 서버 기본 역할(계정 trader, 관리자 admin)을 받고, 기본 역할을 끄면 지정된 대상만 허용합니다.
+//! 권한 거부, 권한 변경, 역할 지정/해제는 모두 감사 로그(`audit_logs`)에 남깁니다.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::RwLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use utoipa::ToSchema;
+
+use crate::db::models::{RoleAssignmentRecord, RolePermissionsRecord};
+use crate::db::repository::{AuditLogRepository, RbacRepository};
+
+/// 감사 로그 이벤트 타입 (권한 거부)
+pub const PERMISSION_DENIED_EVENT: &str = "PERMISSION_DENIED";
+/// 계정을 알 수 없는 요청의 감사 로그 대상 (인증 꺼짐, 자격 증명 없음)
+pub const ANONYMOUS_SUBJECT: &str = "anonymous";
+/// 감사 로그 이벤트 타입 (역할 권한 변경)
+pub const ROLE_PERMISSIONS_UPDATED_EVENT: &str = "ROLE_PERMISSIONS_UPDATED";
+/// 감사 로그 이벤트 타입 (역할 지정)
+pub const ROLE_ASSIGNED_EVENT: &str = "ROLE_ASSIGNED";
+/// 감사 로그 이벤트 타입 (역할 지정 해제)
+pub const ROLE_UNASSIGNED_EVENT: &str = "ROLE_UNASSIGNED";
+/// 감사 로그 엔티티 타입 (역할 권한 변경, 엔티티 ID는 역할)
+pub const ROLE_AUDIT_ENTITY: &str = "role";
+
+/// 기본 권한을 채울 때 기록하는 변경자
+const SEED_ACTOR: &str = "system";
+
+/// 역할
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum Role {
+    /// 일반 거래 계정
+    Trader,
+    /// 호가 제공 계정
+    MarketMaker,
+    /// 운영 (장애 대응, 복구, 백업, 리스크 조치)
+    Ops,
+    /// 컴플라이언스 (KYC, 수수료 등급, 계정 통계)
+    Compliance,
+    /// 전체 권한 (권한 변경 불가)
+    Admin,
+}
+
+impl Role {
+    pub const ALL: [Role; 5] = [Role::Trader, Role::MarketMaker, Role::Ops, Role::Compliance, Role::Admin];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Trader => "trader",
+            Role::MarketMaker => "market-maker",
+            Role::Ops => "ops",
+            Role::Compliance => "compliance",
+            Role::Admin => "admin",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Role::ALL.into_iter().find(|role| role.as_str() == name)
+    }
+
+    /// 처음 시작할 때 채우는 기본 권한
+    pub fn default_permissions(&self) -> BTreeSet<Permission> {
+        match self {
+            Role::Trader | Role::MarketMaker => BTreeSet::from([Permission::OrdersWrite, Permission::AccountRead]),
+            Role::Ops => BTreeSet::from([Permission::OpsManage, Permission::RiskManage]),
+            Role::Compliance => BTreeSet::from([Permission::ComplianceManage]),
+            Role::Admin => Permission::ALL.into_iter().collect(),
+        }
+    }
+}
+
+/// 권한 (REST 경로 묶음 단위)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+pub enum Permission {
+    /// 주문 제출/취소
+    #[serde(rename = "orders:write")]
+    OrdersWrite,
+    /// 포트폴리오, 잔고, 개인 체결 스트림 조회
+    #[serde(rename = "account:read")]
+    AccountRead,
+    /// 킬 스위치, 계정 리스크 한도, 계정 주문 일괄 취소
+    #[serde(rename = "risk:manage")]
+    RiskManage,
+    /// KYC, 수수료 등급, 계정별 거래 통계
+    #[serde(rename = "compliance:manage")]
+    ComplianceManage,
+    /// 심볼/엔진 상태, 알림, 인시던트, 복제, 백업, 복구, DLQ
+    #[serde(rename = "ops:manage")]
+    OpsManage,
+    /// API 키, 역할과 권한 관리
+    #[serde(rename = "access:manage")]
+    AccessManage,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 6] = [
+        Permission::OrdersWrite,
+        Permission::AccountRead,
+        Permission::RiskManage,
+        Permission::ComplianceManage,
+        Permission::OpsManage,
+        Permission::AccessManage,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::OrdersWrite => "orders:write",
+            Permission::AccountRead => "account:read",
+            Permission::RiskManage => "risk:manage",
+            Permission::ComplianceManage => "compliance:manage",
+            Permission::OpsManage => "ops:manage",
+            Permission::AccessManage => "access:manage",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Permission::ALL.into_iter().find(|permission| permission.as_str() == name)
+    }
+
+    /// 이 권한을 확인할 대상 (계정 API는 인증된 계정, 관리자 API는 `X-Admin-User`)
+    pub fn subject_kind(&self) -> SubjectKind {
+        match self {
+            Permission::OrdersWrite | Permission::AccountRead => SubjectKind::Client,
+            _ => SubjectKind::Admin,
+        }
+    }
+}
+
+fn join_permissions(permissions: &BTreeSet<Permission>) -> String {
+    permissions.iter().map(Permission::as_str).collect::<Vec<_>>().join(",")
+}
+
+/// 역할 지정 대상 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SubjectKind {
+    /// API 계정 (`client_id`)
+    Client,
+    /// 관리자 (`X-Admin-User`)
+    Admin,
+}
+
+impl SubjectKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubjectKind::Client => "client",
+            SubjectKind::Admin => "admin",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "client" => Some(SubjectKind::Client),
+            "admin" => Some(SubjectKind::Admin),
+            _ => None,
+        }
+    }
+
+    /// 감사 로그 엔티티 타입 (엔티티 ID는 계정 ID 또는 관리자 이름)
+    fn audit_entity(&self) -> &'static str {
+        match self {
+            SubjectKind::Client => "rbac_client",
+            SubjectKind::Admin => "rbac_admin",
+        }
+    }
+}
+
+/// 역할과 권한
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RolePermissions {
+    pub role: Role,
+    pub permissions: Vec<Permission>,
+    pub updated_by: String,
+    /// 변경 시각 (밀리초)
+    pub updated_at: u64,
+}
+
+/// 역할 지정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RoleAssignment {
+    pub subject_kind: SubjectKind,
+    /// 계정 ID 또는 관리자 이름
+    pub subject: String,
+    pub role: Role,
+    pub assigned_by: String,
+    /// 지정 시각 (밀리초)
+    pub assigned_at: u64,
+}
+
+impl RoleAssignment {
+    fn to_record(&self) -> RoleAssignmentRecord {
+        RoleAssignmentRecord {
+            subject_kind: self.subject_kind.as_str().to_string(),
+            subject: self.subject.clone(),
+            role: self.role.as_str().to_string(),
+            assigned_by: self.assigned_by.clone(),
+            assigned_at: self.assigned_at as i64,
+        }
+    }
+
+    fn from_record(record: RoleAssignmentRecord) -> Result<Self, RbacError> {
+        let subject_kind = SubjectKind::from_name(&record.subject_kind)
+            .ok_or_else(|| RbacError::InvalidRecord(format!("{}: 알 수 없는 대상 종류 '{}'", record.subject, record.subject_kind)))?;
+        let role = Role::from_name(&record.role)
+            .ok_or_else(|| RbacError::InvalidRecord(format!("{}: 알 수 없는 역할 '{}'", record.subject, record.role)))?;
+        Ok(Self {
+            subject_kind,
+            subject: record.subject,
+            role,
+            assigned_by: record.assigned_by,
+            assigned_at: record.assigned_at as u64,
+        })
+    }
+}
+
+/// RBAC 오류
+#[derive(Debug, thiserror::Error)]
+pub enum RbacError {
+    #[error("{subject} ({}) 역할에는 {} 권한이 없습니다", role.map(|r| r.as_str()).unwrap_or("역할 없음"), permission.as_str())]
+    PermissionDenied { subject: String, role: Option<Role>, permission: Permission },
+    #[error("알 수 없는 역할입니다: {0}")]
+    UnknownRole(String),
+    #[error("알 수 없는 대상 종류입니다: {0} (client, admin)")]
+    UnknownSubjectKind(String),
+    #[error("admin 역할의 권한은 바꿀 수 없습니다")]
+    ImmutableRole,
+    #[error("역할이 지정되지 않은 대상입니다: {0}")]
+    AssignmentNotFound(String),
+    #[error("RBAC 기록 오류: {0}")]
+    InvalidRecord(String),
+    #[error("RBAC 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 역할을 지정하지 않은 대상의 기본 역할 (None이면 거부)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RbacConfig {
+    pub default_client_role: Option<Role>,
+    pub default_admin_role: Option<Role>,
+}
+
+impl Default for RbacConfig {
+    /// 기존 배포가 그대로 동작하도록 계정은 trader, 관리자 토큰 사용자는 admin
+    fn default() -> Self {
+        Self {
+            default_client_role: Some(Role::Trader),
+            default_admin_role: Some(Role::Admin),
+        }
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
+/// 역할 권한과 역할 지정 (메모리 + DB)
+///
+/// 요청마다 확인하므로 `std::sync::RwLock`을 쓰고 잠금은 await 전에 풉니다.
+pub struct Rbac {
+    config: RbacConfig,
+    roles: RwLock<HashMap<Role, RolePermissions>>,
+    assignments: RwLock<HashMap<(SubjectKind, String), RoleAssignment>>,
+    repository: RbacRepository,
+    audit: AuditLogRepository,
+}
+
+impl Rbac {
+    /// DB에 저장된 역할 권한과 지정을 읽어 생성 (권한이 없는 역할은 기본 권한으로 채움)
+    pub async fn load(config: RbacConfig, pool: SqlitePool) -> Result<Self, RbacError> {
+        let repository = RbacRepository::new(pool.clone());
+        let now = now_millis() as i64;
+        for role in Role::ALL {
+            let record = RolePermissionsRecord {
+                role: role.as_str().to_string(),
+                permissions: join_permissions(&role.default_permissions()),
+                updated_by: SEED_ACTOR.to_string(),
+                updated_at: now,
+            };
+            if repository.insert_role_if_missing(&record).await? {
+                info!("역할 기본 권한 저장: {} [{}]", role.as_str(), record.permissions);
+            }
+        }
+
+        let mut roles = HashMap::new();
+        for record in repository.find_roles().await? {
+            let Some(role) = Role::from_name(&record.role) else {
+                warn!("알 수 없는 역할 권한 무시: {}", record.role);
+                continue;
+            };
+            let mut permissions = BTreeSet::new();
+            for name in record.permissions.split(',').filter(|name| !name.is_empty()) {
+                match Permission::from_name(name) {
+                    Some(permission) => {
+                        permissions.insert(permission);
+                    }
+                    None => warn!("{} 역할의 알 수 없는 권한 무시: {}", record.role, name),
+                }
+            }
+            // admin 역할은 항상 모든 권한 (관리 권한을 잃어 복구할 수 없게 되는 것을 막음)
+            if role == Role::Admin {
+                permissions = role.default_permissions();
+            }
+            let entry = RolePermissions {
+                role,
+                permissions: permissions.into_iter().collect(),
+                updated_by: record.updated_by,
+                updated_at: record.updated_at as u64,
+            };
+            roles.insert(role, entry);
+        }
+
+        let assignments = repository
+            .find_assignments()
+            .await?
+            .into_iter()
+            .map(|record| RoleAssignment::from_record(record).map(|a| ((a.subject_kind, a.subject.clone()), a)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        info!("역할 지정 {}개 로드", assignments.len());
+
+        Ok(Self {
+            config,
+            roles: RwLock::new(roles),
+            assignments: RwLock::new(assignments),
+            repository,
+            audit: AuditLogRepository::new(pool),
+        })
+    }
+
+    /// 기본 역할 설정
+    pub fn config(&self) -> &RbacConfig {
+        &self.config
+    }
+
+    /// 대상의 역할 (지정이 없으면 기본 역할)
+    pub fn role_of(&self, kind: SubjectKind, subject: &str) -> Option<Role> {
+        let assigned = self.assignments.read().unwrap().get(&(kind, subject.to_string())).map(|a| a.role);
+        assigned.or(match kind {
+            SubjectKind::Client => self.config.default_client_role,
+            SubjectKind::Admin => self.config.default_admin_role,
+        })
+    }
+
+    fn role_has(&self, role: Role, permission: Permission) -> bool {
+        self.roles
+            .read()
+            .unwrap()
+            .get(&role)
+            .is_some_and(|entry| entry.permissions.contains(&permission))
+    }
+
+    /// 권한 확인 (감사 로그 없이 판정만)
+    pub fn check(&self, subject: &str, permission: Permission) -> Result<Role, RbacError> {
+        let role = self.role_of(permission.subject_kind(), subject);
+        match role {
+            Some(role) if self.role_has(role, permission) => Ok(role),
+            _ => Err(RbacError::PermissionDenied { subject: subject.to_string(), role, permission }),
+        }
+    }
+
+    /// 권한 확인 (거부하면 감사 로그 기록, `request`는 `METHOD 경로`)
+    pub async fn authorize(&self, subject: &str, permission: Permission, request: &str) -> Result<Role, RbacError> {
+        let role = match self.check(subject, permission) {
+            Ok(role) => return Ok(role),
+            Err(RbacError::PermissionDenied { role, .. }) => role,
+            Err(e) => return Err(e),
+        };
+
+        self.log_denied(subject, role, permission, request, None).await;
+        Err(RbacError::PermissionDenied { subject: subject.to_string(), role, permission })
+    }
+
+    /// 인증이 꺼져 계정을 알 수 없는 요청의 권한 확인
+    ///
+    /// 역할 지정은 보지 않고 기본 계정 역할로만 판정합니다. 기본 역할이 없거나 권한이 없으면 거부하고 감사 로그를 남깁니다.
+    pub async fn authorize_anonymous(&self, permission: Permission, request: &str) -> Result<Role, RbacError> {
+        let role = match permission.subject_kind() {
+            SubjectKind::Client => self.config.default_client_role,
+            SubjectKind::Admin => self.config.default_admin_role,
+        };
+        match role {
+            Some(role) if self.role_has(role, permission) => Ok(role),
+            _ => {
+                self.log_denied(ANONYMOUS_SUBJECT, role, permission, request, None).await;
+                Err(RbacError::PermissionDenied { subject: ANONYMOUS_SUBJECT.to_string(), role, permission })
+            }
+        }
+    }
+
+    /// 자격 증명 확인에 실패해 권한을 판정하지 못한 요청을 권한 거부로 감사 로그에 기록 (`reason`은 거부 사유)
+    pub async fn record_unauthenticated(&self, subject: Option<&str>, permission: Permission, request: &str, reason: &str) {
+        self.log_denied(subject.unwrap_or(ANONYMOUS_SUBJECT), None, permission, request, Some(reason)).await;
+    }
+
+    async fn log_denied(&self, subject: &str, role: Option<Role>, permission: Permission, request: &str, reason: Option<&str>) {
+        let kind = permission.subject_kind();
+        let role_name = role.map(|r| r.as_str()).unwrap_or("-");
+        warn!("권한 거부: {} {} ({}) {} - {}", kind.as_str(), subject, role_name, permission.as_str(), request);
+        let details =
+            serde_json::json!({ "role": role, "permission": permission, "request": request, "reason": reason }).to_string();
+        // 감사 로그 저장에 실패해도 요청은 거부
+        if let Err(e) = self.audit.log(PERMISSION_DENIED_EVENT, kind.audit_entity(), subject, Some(&details)).await {
+            error!("권한 거부 감사 로그 기록 실패: {}", e);
+        }
+    }
+
+    /// 역할별 권한 (역할 순)
+    pub fn roles(&self) -> Vec<RolePermissions> {
+        let roles = self.roles.read().unwrap();
+        Role::ALL.iter().filter_map(|role| roles.get(role).cloned()).collect()
+    }
+
+    /// 역할 권한 교체 (admin 역할은 바꿀 수 없음)
+    pub async fn set_role_permissions(
+        &self,
+        role: Role,
+        permissions: BTreeSet<Permission>,
+        actor: &str,
+    ) -> Result<RolePermissions, RbacError> {
+        if role == Role::Admin {
+            return Err(RbacError::ImmutableRole);
+        }
+        let entry = RolePermissions {
+            role,
+            permissions: permissions.iter().copied().collect(),
+            updated_by: actor.to_string(),
+            updated_at: now_millis(),
+        };
+        let record = RolePermissionsRecord {
+            role: role.as_str().to_string(),
+            permissions: join_permissions(&permissions),
+            updated_by: actor.to_string(),
+            updated_at: entry.updated_at as i64,
+        };
+
+        self.repository.upsert_role(&record).await?;
+        let previous = self.roles.write().unwrap().insert(role, entry.clone());
+        info!("역할 권한 변경: {} [{}] (by {})", role.as_str(), record.permissions, actor);
+
+        let details = serde_json::json!({
+            "before": previous.map(|p| p.permissions),
+            "after": entry.permissions,
+            "by": actor,
+        })
+        .to_string();
+        self.audit.log(ROLE_PERMISSIONS_UPDATED_EVENT, ROLE_AUDIT_ENTITY, role.as_str(), Some(&details)).await?;
+        Ok(entry)
+    }
+
+    /// 역할 지정 목록 (대상 종류, 이름순)
+    pub fn assignments(&self) -> Vec<RoleAssignment> {
+        let mut assignments: Vec<RoleAssignment> = self.assignments.read().unwrap().values().cloned().collect();
+        assignments.sort_by(|a, b| (a.subject_kind, &a.subject).cmp(&(b.subject_kind, &b.subject)));
+        assignments
+    }
+
+    /// 역할 지정 (이미 있으면 교체)
+    pub async fn assign(&self, kind: SubjectKind, subject: &str, role: Role, actor: &str) -> Result<RoleAssignment, RbacError> {
+        let assignment = RoleAssignment {
+            subject_kind: kind,
+            subject: subject.to_string(),
+            role,
+            assigned_by: actor.to_string(),
+            assigned_at: now_millis(),
+        };
+
+        self.repository.upsert_assignment(&assignment.to_record()).await?;
+        let previous = self.assignments.write().unwrap().insert((kind, subject.to_string()), assignment.clone());
+        info!("역할 지정: {} {} → {} (by {})", kind.as_str(), subject, role.as_str(), actor);
+
+        let details = serde_json::json!({
+            "before": previous.map(|p| p.role),
+            "after": role,
+            "by": actor,
+        })
+        .to_string();
+        self.audit.log(ROLE_ASSIGNED_EVENT, kind.audit_entity(), subject, Some(&details)).await?;
+        Ok(assignment)
+    }
+
+    /// 역할 지정 해제 (이후 기본 역할 적용)
+    pub async fn unassign(&self, kind: SubjectKind, subject: &str, actor: &str) -> Result<RoleAssignment, RbacError> {
+        if !self.repository.delete_assignment(kind.as_str(), subject).await? {
+            return Err(RbacError::AssignmentNotFound(subject.to_string()));
+        }
+        let removed = self
+            .assignments
+            .write()
+            .unwrap()
+            .remove(&(kind, subject.to_string()))
+            .ok_or_else(|| RbacError::AssignmentNotFound(subject.to_string()))?;
+        info!("역할 지정 해제: {} {} ({}) (by {})", kind.as_str(), subject, removed.role.as_str(), actor);
+
+        let details = serde_json::json!({ "role": removed.role, "by": actor }).to_string();
+        self.audit.log(ROLE_UNASSIGNED_EVENT, kind.audit_entity(), subject, Some(&details)).await?;
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_default_roles_and_assignment() {
+        let pool = test_pool().await;
+        let rbac = Rbac::load(RbacConfig::default(), pool.clone()).await.unwrap();
+        assert_eq!(rbac.roles().len(), Role::ALL.len());
+
+        // 지정하지 않은 계정은 trader, 관리자는 admin
+        assert_eq!(rbac.check("alice", Permission::OrdersWrite).unwrap(), Role::Trader);
+        assert_eq!(rbac.check("root", Permission::AccessManage).unwrap(), Role::Admin);
+
+        rbac.assign(SubjectKind::Admin, "carol", Role::Compliance, "root").await.unwrap();
+        assert!(rbac.check("carol", Permission::ComplianceManage).is_ok());
+        let denied = rbac.authorize("carol", Permission::OpsManage, "POST /v1/admin/db/backups").await;
+        assert!(matches!(denied, Err(RbacError::PermissionDenied { role: Some(Role::Compliance), .. })));
+
+        // 재시작 후에도 유지
+        let reloaded = Rbac::load(RbacConfig::default(), pool.clone()).await.unwrap();
+        assert_eq!(reloaded.assignments(), rbac.assignments());
+        assert_eq!(reloaded.role_of(SubjectKind::Admin, "carol"), Some(Role::Compliance));
+
+        rbac.unassign(SubjectKind::Admin, "carol", "root").await.unwrap();
+        assert!(matches!(rbac.unassign(SubjectKind::Admin, "carol", "root").await, Err(RbacError::AssignmentNotFound(_))));
+        assert!(rbac.check("carol", Permission::OpsManage).is_ok());
+
+        let events: Vec<String> = AuditLogRepository::new(pool)
+            .find_by_entity("carol")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|log| log.event_type)
+            .collect();
+        assert_eq!(events.len(), 3);
+        assert!(events.contains(&PERMISSION_DENIED_EVENT.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_role_permissions_persist_and_admin_is_immutable() {
+        let pool = test_pool().await;
+        let config = RbacConfig { default_client_role: Some(Role::Trader), default_admin_role: None };
+        let rbac = Rbac::load(config.clone(), pool.clone()).await.unwrap();
+
+        // 기본 역할이 꺼져 있으면 지정된 관리자만 허용
+        assert!(matches!(rbac.check("dave", Permission::OpsManage), Err(RbacError::PermissionDenied { role: None, .. })));
+
+        rbac.set_role_permissions(Role::Trader, BTreeSet::from([Permission::AccountRead]), "root").await.unwrap();
+        assert!(rbac.check("alice", Permission::OrdersWrite).is_err());
+        assert!(matches!(
+            rbac.set_role_permissions(Role::Admin, BTreeSet::new(), "root").await,
+            Err(RbacError::ImmutableRole)
+        ));
+
+        // 저장된 권한은 기본값으로 덮어쓰지 않음
+        let reloaded = Rbac::load(config, pool).await.unwrap();
+        assert!(reloaded.check("alice", Permission::OrdersWrite).is_err());
+        assert!(reloaded.check("alice", Permission::AccountRead).is_ok());
+        assert_eq!(reloaded.roles()[0].updated_by, "root");
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_and_unauthenticated_denials_are_audited() {
+        let pool = test_pool().await;
+        let rbac = Rbac::load(RbacConfig::default(), pool.clone()).await.unwrap();
+
+        // 인증이 꺼져 있으면 기본 계정 역할로 판정 (trader는 주문 가능)
+        assert_eq!(rbac.authorize_anonymous(Permission::OrdersWrite, "POST /v1/order").await.unwrap(), Role::Trader);
+
+        // 기본 계정 역할을 끄면 거부
+        let closed = RbacConfig { default_client_role: None, default_admin_role: Some(Role::Admin) };
+        let rbac_closed = Rbac::load(closed, pool.clone()).await.unwrap();
+        assert!(matches!(
+            rbac_closed.authorize_anonymous(Permission::OrdersWrite, "POST /v1/order").await,
+            Err(RbacError::PermissionDenied { role: None, .. })
+        ));
+
+        rbac.record_unauthenticated(Some("mallory"), Permission::OpsManage, "GET /v1/admin/symbols", "invalid admin token")
+            .await;
+
+        let repository = AuditLogRepository::new(pool);
+        let anonymous = repository.find_by_entity(ANONYMOUS_SUBJECT).await.unwrap();
+        assert_eq!(anonymous.len(), 1);
+        assert_eq!(anonymous[0].event_type, PERMISSION_DENIED_EVENT);
+        let mallory = repository.find_by_entity("mallory").await.unwrap();
+        assert_eq!(mallory.len(), 1);
+        assert!(mallory[0].details.as_deref().unwrap().contains("invalid admin token"));
+    }
+}
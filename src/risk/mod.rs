@@ -0,0 +1,502 @@
+//! 사전 거래 리스크 한도 (미체결 주문 수, 1회 주문 금액, 심볼별 순포지션)
+//!
+//! 주문 접수 시 계정(`client_id`)별 한도를 넘는 신규 주문을 거부합니다. 계정별 한도를 설정하지 않은
+//! 항목은 서버 기본값을 쓰고, 기본값도 없으면 제한하지 않습니다. 순포지션은 체결 보고서로 갱신하며
+//! 시작 시 저장된 체결로 다시 계산합니다. 한도 변경은 DB와 감사 로그(`audit_logs`)에 남기고,
+//! 한도 위반은 보안 알림으로 발송합니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use utoipa::ToSchema;
+
+use crate::db::models::RiskLimitRecord;
+use crate::db::repository::{AuditLogRepository, RiskLimitRepository};
+use crate::matching_engine::model::Side;
+use crate::monitoring::notification_routing::SOURCE_METADATA_KEY;
+use crate::monitoring::{NotificationChannel, NotificationPriority, NotificationSystem, NotificationType};
+
+/// 감사 로그 이벤트 타입
+pub const RISK_LIMITS_AUDIT_EVENT: &str = "RISK_LIMITS_UPDATED";
+/// 감사 로그 엔티티 타입
+pub const RISK_LIMITS_AUDIT_ENTITY: &str = "risk_limits";
+
+/// 리스크 한도 (None, 목록에 없는 심볼은 제한 없음)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RiskLimits {
+    /// 최대 미체결 주문 수
+    pub max_open_orders: Option<u64>,
+    /// 1회 주문 금액 한도 (보고 통화 최소 단위)
+    pub max_order_notional: Option<u64>,
+    /// 심볼별 최대 순포지션 (절댓값, 기준 자산 수량)
+    #[serde(default)]
+    pub max_net_position: BTreeMap<String, u64>,
+}
+
+impl RiskLimits {
+    /// 설정한 항목은 이 한도를, 나머지는 `defaults`를 적용
+    pub fn or(&self, defaults: &RiskLimits) -> RiskLimits {
+        let mut max_net_position = defaults.max_net_position.clone();
+        max_net_position.extend(self.max_net_position.iter().map(|(symbol, limit)| (symbol.clone(), *limit)));
+        RiskLimits {
+            max_open_orders: self.max_open_orders.or(defaults.max_open_orders),
+            max_order_notional: self.max_order_notional.or(defaults.max_order_notional),
+            max_net_position,
+        }
+    }
+}
+
+/// 계정별 한도 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ClientRiskLimits {
+    pub client_id: String,
+    pub limits: RiskLimits,
+    /// 마지막 변경 사유
+    pub reason: Option<String>,
+    pub updated_by: String,
+    /// 마지막 변경 시각 (밀리초)
+    pub updated_at: u64,
+}
+
+impl ClientRiskLimits {
+    fn to_record(&self) -> Result<RiskLimitRecord, RiskError> {
+        Ok(RiskLimitRecord {
+            client_id: self.client_id.clone(),
+            max_open_orders: self.limits.max_open_orders.map(|n| n as i64),
+            max_order_notional: self.limits.max_order_notional.map(|n| n as i64),
+            max_net_position: serde_json::to_string(&self.limits.max_net_position)
+                .map_err(|e| RiskError::InvalidRecord(e.to_string()))?,
+            updated_by: self.updated_by.clone(),
+            updated_at: self.updated_at as i64,
+        })
+    }
+
+    fn from_record(record: RiskLimitRecord) -> Result<Self, RiskError> {
+        let max_net_position = serde_json::from_str(&record.max_net_position)
+            .map_err(|e| RiskError::InvalidRecord(format!("{}: 순포지션 한도 형식 오류 ({})", record.client_id, e)))?;
+        Ok(Self {
+            client_id: record.client_id,
+            limits: RiskLimits {
+                max_open_orders: record.max_open_orders.map(|n| n as u64),
+                max_order_notional: record.max_order_notional.map(|n| n as u64),
+                max_net_position,
+            },
+            reason: None,
+            updated_by: record.updated_by,
+            updated_at: record.updated_at as u64,
+        })
+    }
+}
+
+/// 감사 로그에 남는 변경 내역 (None이면 계정별 한도 없음)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskLimitsChange {
+    pub before: Option<ClientRiskLimits>,
+    pub after: Option<ClientRiskLimits>,
+    pub updated_by: String,
+}
+
+/// 사전 리스크 확인 대상 신규 주문
+#[derive(Debug, Clone)]
+pub struct RiskCheck<'a> {
+    pub client_id: &'a str,
+    pub symbol: &'a str,
+    pub side: Side,
+    pub quantity: u64,
+    /// 주문 금액 (보고 통화 최소 단위)
+    pub notional: u64,
+    /// 계정의 현재 미체결 주문 수
+    pub open_orders: usize,
+    /// 같은 심볼·방향 미체결 주문의 남은 수량 (체결되면 포지션에 더해짐)
+    pub pending_quantity: u64,
+}
+
+/// 리스크 한도 오류
+#[derive(Debug, thiserror::Error)]
+pub enum RiskError {
+    #[error("미체결 주문 수 한도 초과: {client_id} ({open_orders}개, 한도 {limit}개)")]
+    OpenOrdersExceeded { client_id: String, open_orders: usize, limit: u64 },
+    #[error("리스크 1회 주문 금액 한도 초과: {client_id} ({notional} > {limit})")]
+    OrderNotionalExceeded { client_id: String, notional: u64, limit: u64 },
+    #[error("{symbol} 순포지션 한도 초과: {client_id} (예상 {projected}, 한도 {limit})")]
+    PositionExceeded { client_id: String, symbol: String, projected: i64, limit: u64 },
+    #[error("리스크 한도 기록 오류: {0}")]
+    InvalidRecord(String),
+    #[error("리스크 한도 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+impl RiskError {
+    /// 알림 메타데이터용 위반 종류
+    fn violation(&self) -> Option<&'static str> {
+        match self {
+            RiskError::OpenOrdersExceeded { .. } => Some("max_open_orders"),
+            RiskError::OrderNotionalExceeded { .. } => Some("max_order_notional"),
+            RiskError::PositionExceeded { .. } => Some("max_net_position"),
+            RiskError::InvalidRecord(_) | RiskError::Storage(_) => None,
+        }
+    }
+}
+
+/// 계정별 리스크 한도와 순포지션 (메모리 + DB)
+///
+/// 체결 처리 태스크가 보고서마다 동기로 포지션을 갱신하므로 `std::sync::RwLock`을 씁니다.
+pub struct RiskManager {
+    defaults: RiskLimits,
+    limits: RwLock<HashMap<String, ClientRiskLimits>>,
+    /// (계정, 심볼) → 순포지션 (매수 체결 - 매도 체결)
+    positions: RwLock<HashMap<(String, String), i64>>,
+    repository: RiskLimitRepository,
+    audit: AuditLogRepository,
+    notifications: Option<Arc<NotificationSystem>>,
+}
+
+impl RiskManager {
+    /// DB에 저장된 계정별 한도와 체결 순포지션을 읽어 생성
+    pub async fn load(defaults: RiskLimits, pool: SqlitePool) -> Result<Self, RiskError> {
+        let repository = RiskLimitRepository::new(pool.clone());
+        let limits = repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|record| ClientRiskLimits::from_record(record).map(|l| (l.client_id.clone(), l)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        let positions: HashMap<(String, String), i64> = repository
+            .net_positions()
+            .await?
+            .into_iter()
+            .filter(|record| record.net_quantity != 0)
+            .map(|record| ((record.client_id, record.symbol), record.net_quantity))
+            .collect();
+        info!("리스크 한도 {}개 계정, 순포지션 {}건 로드", limits.len(), positions.len());
+
+        Ok(Self {
+            defaults,
+            limits: RwLock::new(limits),
+            positions: RwLock::new(positions),
+            repository,
+            audit: AuditLogRepository::new(pool),
+            notifications: None,
+        })
+    }
+
+    /// 한도 위반 알림 발송
+    pub fn with_notifications(mut self, notifications: Arc<NotificationSystem>) -> Self {
+        self.notifications = Some(notifications);
+        self
+    }
+
+    /// 계정별 한도 설정 (없으면 None)
+    pub fn client_limits(&self, client_id: &str) -> Option<ClientRiskLimits> {
+        self.limits.read().unwrap().get(client_id).cloned()
+    }
+
+    /// 계정에 실제 적용되는 한도
+    pub fn effective_limits(&self, client_id: &str) -> RiskLimits {
+        match self.client_limits(client_id) {
+            Some(client) => client.limits.or(&self.defaults),
+            None => self.defaults.clone(),
+        }
+    }
+
+    /// 계정·심볼 순포지션
+    pub fn position(&self, client_id: &str, symbol: &str) -> i64 {
+        self.positions
+            .read()
+            .unwrap()
+            .get(&(client_id.to_string(), symbol.to_string()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// 계정의 심볼별 순포지션 (0 제외, 심볼순)
+    pub fn positions(&self, client_id: &str) -> BTreeMap<String, i64> {
+        self.positions
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|((client, _), quantity)| client == client_id && **quantity != 0)
+            .map(|((_, symbol), quantity)| (symbol.clone(), *quantity))
+            .collect()
+    }
+
+    /// 심볼의 계정별 순포지션 (0 제외, 계정순)
+    pub fn symbol_positions(&self, symbol: &str) -> BTreeMap<String, i64> {
+        self.positions
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|((_, position_symbol), quantity)| position_symbol == symbol && **quantity != 0)
+            .map(|((client, _), quantity)| (client.clone(), *quantity))
+            .collect()
+    }
+
+    /// 체결 한 건을 계정 순포지션에 반영 (테이커/메이커 보고서마다 자기 주문 방향으로 호출)
+    pub fn record_fill(&self, client_id: &str, symbol: &str, side: &Side, quantity: u64) {
+        let delta = match side {
+            Side::Buy => quantity as i64,
+            Side::Sell => -(quantity as i64),
+        };
+        let mut positions = self.positions.write().unwrap();
+        let position = positions.entry((client_id.to_string(), symbol.to_string())).or_insert(0);
+        *position += delta;
+    }
+
+    /// 신규 주문 한도 확인 (위반 시 보안 알림)
+    ///
+    /// 순포지션은 현재 포지션에 같은 방향 미체결 수량과 이 주문 수량이 모두 체결된 경우로 계산하며,
+    /// 포지션 절댓값을 줄이는 주문은 한도를 넘어도 허용합니다.
+    pub async fn check_order(&self, order: &RiskCheck<'_>) -> Result<(), RiskError> {
+        let result = self.evaluate(order);
+        if let Err(e) = &result {
+            warn!("리스크 한도 위반으로 주문 거부: {}", e);
+            self.notify_violation(order, e).await;
+        }
+        result
+    }
+
+    fn evaluate(&self, order: &RiskCheck<'_>) -> Result<(), RiskError> {
+        let limits = self.effective_limits(order.client_id);
+
+        if let Some(limit) = limits.max_open_orders {
+            if order.open_orders as u64 >= limit {
+                return Err(RiskError::OpenOrdersExceeded {
+                    client_id: order.client_id.to_string(),
+                    open_orders: order.open_orders,
+                    limit,
+                });
+            }
+        }
+        if let Some(limit) = limits.max_order_notional {
+            if order.notional > limit {
+                return Err(RiskError::OrderNotionalExceeded {
+                    client_id: order.client_id.to_string(),
+                    notional: order.notional,
+                    limit,
+                });
+            }
+        }
+        if let Some(&limit) = limits.max_net_position.get(order.symbol) {
+            let position = self.position(order.client_id, order.symbol);
+            let exposure = order.pending_quantity.saturating_add(order.quantity).min(i64::MAX as u64) as i64;
+            let projected = match order.side {
+                Side::Buy => position.saturating_add(exposure),
+                Side::Sell => position.saturating_sub(exposure),
+            };
+            if projected.unsigned_abs() > limit && projected.unsigned_abs() > position.unsigned_abs() {
+                return Err(RiskError::PositionExceeded {
+                    client_id: order.client_id.to_string(),
+                    symbol: order.symbol.to_string(),
+                    projected,
+                    limit,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// 계정별 한도 설정 (DB 저장 후 감사 로그 기록)
+    pub async fn update(
+        &self,
+        client_id: &str,
+        limits: RiskLimits,
+        reason: Option<String>,
+        actor: &str,
+    ) -> Result<ClientRiskLimits, RiskError> {
+        let after = ClientRiskLimits {
+            client_id: client_id.to_string(),
+            limits,
+            reason,
+            updated_by: actor.to_string(),
+            updated_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64,
+        };
+        self.repository.upsert(&after.to_record()?).await?;
+        let before = self.limits.write().unwrap().insert(client_id.to_string(), after.clone());
+        info!("리스크 한도 변경: {} {:?} (by {})", client_id, after.limits, actor);
+
+        self.record_change(client_id, before, Some(after.clone()), actor).await?;
+        Ok(after)
+    }
+
+    /// 계정별 한도 삭제 (기본값으로 되돌림, 설정이 없었으면 None)
+    pub async fn reset(&self, client_id: &str, actor: &str) -> Result<Option<ClientRiskLimits>, RiskError> {
+        if self.client_limits(client_id).is_none() {
+            return Ok(None);
+        }
+        self.repository.delete(client_id).await?;
+        let before = self.limits.write().unwrap().remove(client_id);
+        info!("리스크 한도 기본값 복원: {} (by {})", client_id, actor);
+
+        self.record_change(client_id, before.clone(), None, actor).await?;
+        Ok(before)
+    }
+
+    async fn record_change(
+        &self,
+        client_id: &str,
+        before: Option<ClientRiskLimits>,
+        after: Option<ClientRiskLimits>,
+        actor: &str,
+    ) -> Result<(), RiskError> {
+        let change = RiskLimitsChange { before, after, updated_by: actor.to_string() };
+        let details = serde_json::to_string(&change).map_err(|e| RiskError::InvalidRecord(e.to_string()))?;
+        self.audit.log(RISK_LIMITS_AUDIT_EVENT, RISK_LIMITS_AUDIT_ENTITY, client_id, Some(&details)).await?;
+        Ok(())
+    }
+
+    async fn notify_violation(&self, order: &RiskCheck<'_>, violation: &RiskError) {
+        let (Some(notifications), Some(kind)) = (&self.notifications, violation.violation()) else {
+            return;
+        };
+        let metadata = HashMap::from([
+            (SOURCE_METADATA_KEY.to_string(), "risk".to_string()),
+            ("client_id".to_string(), order.client_id.to_string()),
+            ("symbol".to_string(), order.symbol.to_string()),
+            ("violation".to_string(), kind.to_string()),
+        ]);
+        if let Err(e) = notifications
+            .send_notification_with_metadata(
+                format!("리스크 한도 위반: {}", order.client_id),
+                violation.to_string(),
+                NotificationChannel::Log,
+                NotificationPriority::High,
+                NotificationType::SecurityAlert,
+                metadata,
+            )
+            .await
+        {
+            error!("리스크 한도 알림 발송 실패: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    fn order<'a>(client_id: &'a str, side: Side, quantity: u64) -> RiskCheck<'a> {
+        RiskCheck {
+            client_id,
+            symbol: "BTC-KRW",
+            side,
+            quantity,
+            notional: quantity * 1_000,
+            open_orders: 0,
+            pending_quantity: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_limits_fall_back_to_defaults_and_gate_orders() {
+        let defaults = RiskLimits {
+            max_open_orders: Some(2),
+            max_order_notional: Some(50_000),
+            max_net_position: BTreeMap::from([("BTC-KRW".to_string(), 10)]),
+        };
+        let risk = RiskManager::load(defaults, test_pool().await).await.unwrap();
+
+        assert!(risk.check_order(&order("alice", Side::Buy, 10)).await.is_ok());
+        assert!(matches!(
+            risk.check_order(&RiskCheck { open_orders: 2, ..order("alice", Side::Buy, 1) }).await,
+            Err(RiskError::OpenOrdersExceeded { limit: 2, .. })
+        ));
+        assert!(matches!(
+            risk.check_order(&order("alice", Side::Buy, 51)).await,
+            Err(RiskError::OrderNotionalExceeded { limit: 50_000, .. })
+        ));
+
+        // 미체결 매수 수량까지 체결된다고 보고 순포지션 계산
+        risk.record_fill("alice", "BTC-KRW", &Side::Buy, 8);
+        assert!(matches!(
+            risk.check_order(&RiskCheck { pending_quantity: 2, ..order("alice", Side::Buy, 1) }).await,
+            Err(RiskError::PositionExceeded { projected: 11, limit: 10, .. })
+        ));
+        // 포지션을 줄이는 주문은 허용, 반대편으로 한도를 넘기면 거부
+        assert!(risk.check_order(&order("alice", Side::Sell, 18)).await.is_ok());
+        assert!(risk.check_order(&order("alice", Side::Sell, 19)).await.is_err());
+
+        // 계정별 한도는 설정한 항목만 기본값을 대체
+        let limits = RiskLimits {
+            max_open_orders: None,
+            max_order_notional: Some(100_000),
+            max_net_position: BTreeMap::from([("BTC-KRW".to_string(), 100)]),
+        };
+        risk.update("alice", limits, Some("마켓 메이커".to_string()), "risk_ops").await.unwrap();
+        assert!(risk.check_order(&order("alice", Side::Buy, 90)).await.is_ok());
+        assert_eq!(risk.effective_limits("alice").max_open_orders, Some(2));
+        assert_eq!(risk.effective_limits("bob").max_order_notional, Some(50_000));
+    }
+
+    #[tokio::test]
+    async fn test_limits_and_positions_restored_on_load() {
+        use crate::db::models::{ExecutionRecord, OrderRecord};
+        use crate::db::repository::{ExecutionRepository, OrderRepository};
+
+        let pool = test_pool().await;
+        let orders = OrderRepository::new(pool.clone());
+        for (order_id, client_id, side) in [("t1", "alice", "Buy"), ("m1", "bob", "Sell"), ("t2", "bob", "Buy"), ("m2", "alice", "Sell")] {
+            orders
+                .save(&OrderRecord {
+                    order_id: order_id.to_string(),
+                    client_id: client_id.to_string(),
+                    symbol: "BTC-KRW".to_string(),
+                    side: side.to_string(),
+                    order_type: "Limit".to_string(),
+                    price: Some(1_000),
+                    quantity: 10,
+                    filled_quantity: 0,
+                    status: "Filled".to_string(),
+                    tag: None,
+                })
+                .await
+                .unwrap();
+        }
+        let executions = ExecutionRepository::new(pool.clone());
+        for (exec_id, taker, maker, quantity) in [("e1", "t1", "m1", 7), ("e2", "t2", "m2", 3)] {
+            executions
+                .save(&ExecutionRecord {
+                    exec_id: exec_id.to_string(),
+                    taker_order_id: taker.to_string(),
+                    maker_order_id: maker.to_string(),
+                    symbol: "BTC-KRW".to_string(),
+                    side: "Buy".to_string(),
+                    price: 1_000,
+                    quantity,
+                    taker_fee: 0,
+                    maker_fee: 0,
+                    transaction_time: 0,
+                })
+                .await
+                .unwrap();
+        }
+
+        let risk = RiskManager::load(RiskLimits::default(), pool.clone()).await.unwrap();
+        let limits = RiskLimits { max_open_orders: Some(5), ..RiskLimits::default() };
+        risk.update("alice", limits.clone(), None, "admin").await.unwrap();
+
+        let reloaded = RiskManager::load(RiskLimits::default(), pool.clone()).await.unwrap();
+        assert_eq!(reloaded.position("alice", "BTC-KRW"), 4);
+        assert_eq!(reloaded.position("bob", "BTC-KRW"), -4);
+        assert_eq!(reloaded.client_limits("alice").map(|l| l.limits), Some(limits));
+
+        assert!(reloaded.reset("alice", "admin").await.unwrap().is_some());
+        assert!(reloaded.reset("alice", "admin").await.unwrap().is_none());
+        assert_eq!(reloaded.effective_limits("alice"), RiskLimits::default());
+        let events = AuditLogRepository::new(pool).find_by_entity("alice").await.unwrap();
+        assert_eq!(events.iter().filter(|log| log.event_type == RISK_LIMITS_AUDIT_EVENT).count(), 2);
+    }
+}
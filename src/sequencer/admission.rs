@@ -0,0 +1,265 @@
+//! 주문 큐 포화 시 서버 재시도 대기열
+//!
+//! 주문 큐가 가득 차면 기본적으로 바로 503(`QUEUE_FULL`)으로 거부합니다. 주문에 `max_wait_ms`를 지정한
+//! 클라이언트는 대신 이 대기열에 들어가 서버가 큐에 다시 넣어 봅니다. 대기 중인 주문은 도착순으로 한 건씩
+//! 재시도하므로 먼저 기다린 주문이 먼저 들어가고, 기한 안에 넣지 못하면 `Timeout`으로 거부합니다.
+//! 대기열 자체도 `max_waiting` 건으로 제한해 포화가 길어져도 요청이 끝없이 쌓이지 않습니다.
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use log::warn;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use crate::matching_engine::model::Order;
+use crate::performance::MetricsCollector;
+use crate::sequencer::backpressure::{BoundedSender, QueueError};
+use crate::sequencer::global_sequence::GlobalSequence;
+
+/// 재시도 대기열 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdmissionConfig {
+    /// 클라이언트가 요청할 수 있는 최대 대기 시간 (0이면 대기열 사용 안 함)
+    pub max_wait: Duration,
+    /// 동시에 기다릴 수 있는 주문 수 (넘으면 바로 `QUEUE_FULL`)
+    pub max_waiting: usize,
+    /// 첫 재시도 간격 (재시도마다 두 배)
+    pub retry_interval: Duration,
+    /// 최대 재시도 간격
+    pub max_retry_interval: Duration,
+}
+
+impl Default for AdmissionConfig {
+    fn default() -> Self {
+        Self {
+            max_wait: Duration::from_secs(2),
+            max_waiting: 1_000,
+            retry_interval: Duration::from_millis(1),
+            max_retry_interval: Duration::from_millis(20),
+        }
+    }
+}
+
+/// 대기열 제출 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdmissionError {
+    /// 큐 오류 (대기하지 않는 주문의 포화, 대기열 포화, 수신측 종료)
+    Queue(QueueError),
+    /// 기한 안에 큐에 넣지 못함
+    Timeout { waited: Duration },
+}
+
+impl From<QueueError> for AdmissionError {
+    fn from(e: QueueError) -> Self {
+        AdmissionError::Queue(e)
+    }
+}
+
+/// 대기 중인 주문 수 (취소된 요청도 빠지도록 drop에서 감소)
+struct Waiting<'a>(&'a AtomicUsize);
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 주문 큐 재시도 대기열 (API 핸들러가 공유)
+#[derive(Debug)]
+pub struct AdmissionQueue {
+    config: AdmissionConfig,
+    /// 재시도 차례 (tokio Mutex는 기다린 순서대로 넘겨줌)
+    turn: Mutex<()>,
+    waiting: AtomicUsize,
+    admitted: AtomicU64,
+    timed_out: AtomicU64,
+    overflowed: AtomicU64,
+}
+
+impl AdmissionQueue {
+    pub fn new(config: AdmissionConfig) -> Self {
+        Self {
+            config,
+            turn: Mutex::new(()),
+            waiting: AtomicUsize::new(0),
+            admitted: AtomicU64::new(0),
+            timed_out: AtomicU64::new(0),
+            overflowed: AtomicU64::new(0),
+        }
+    }
+
+    /// 주문에 적용할 대기 시간 (요청값을 설정 상한으로 제한)
+    pub fn wait_for(&self, requested_ms: Option<u64>) -> Duration {
+        requested_ms.map_or(Duration::ZERO, |ms| Duration::from_millis(ms).min(self.config.max_wait))
+    }
+
+    /// 번호를 붙여 주문 큐에 넣음 (큐가 가득 차면 `max_wait` 동안 재시도)
+    ///
+    /// `max_wait`이 0이면 `GlobalSequence::submit`과 같습니다. 다른 주문이 기다리고 있으면
+    /// 바로 넣어 보지 않고 뒤에 줄을 서므로, 기다린 주문보다 먼저 들어가지 않습니다.
+    pub async fn submit(
+        &self,
+        sequence: &GlobalSequence,
+        order: Order,
+        tx: &BoundedSender<Order>,
+        max_wait: Duration,
+    ) -> Result<Option<u64>, AdmissionError> {
+        if max_wait.is_zero() {
+            return Ok(sequence.submit(order, tx)?);
+        }
+        let started = Instant::now();
+        let deadline = started + max_wait;
+
+        if self.waiting.load(Ordering::SeqCst) == 0 {
+            match sequence.submit(order.clone(), tx) {
+                Err(QueueError::Full(_)) => {}
+                result => return Ok(result?),
+            }
+        }
+
+        if self.waiting.fetch_add(1, Ordering::SeqCst) >= self.config.max_waiting {
+            self.waiting.fetch_sub(1, Ordering::SeqCst);
+            self.overflowed.fetch_add(1, Ordering::Relaxed);
+            return Err(QueueError::Full(tx.gauge().name().to_string()).into());
+        }
+        let _waiting = Waiting(&self.waiting);
+
+        let Ok(_turn) = tokio::time::timeout_at(deadline, self.turn.lock()).await else {
+            return Err(self.timeout(&order, started));
+        };
+        let gauge = tx.gauge();
+        let mut interval = self.config.retry_interval;
+        loop {
+            // 자리가 보일 때만 제출 (실패한 제출도 시퀀스 번호와 거부 수를 소모하므로)
+            if gauge.depth() < gauge.capacity() {
+                match sequence.submit(order.clone(), tx) {
+                    Err(QueueError::Full(_)) => {}
+                    result => {
+                        self.admitted.fetch_add(1, Ordering::Relaxed);
+                        return Ok(result?);
+                    }
+                }
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(self.timeout(&order, started));
+            }
+            tokio::time::sleep(interval.min(deadline - now)).await;
+            interval = (interval * 2).min(self.config.max_retry_interval);
+        }
+    }
+
+    fn timeout(&self, order: &Order, started: Instant) -> AdmissionError {
+        let waited = started.elapsed();
+        self.timed_out.fetch_add(1, Ordering::Relaxed);
+        warn!("주문 큐 대기 시간 초과로 거부: {} ({}ms)", order.id, waited.as_millis());
+        AdmissionError::Timeout { waited }
+    }
+
+    /// 지금 기다리는 주문 수
+    pub fn waiting(&self) -> usize {
+        self.waiting.load(Ordering::SeqCst)
+    }
+
+    /// 기다린 끝에 큐에 들어간 주문 수
+    pub fn admitted(&self) -> u64 {
+        self.admitted.load(Ordering::Relaxed)
+    }
+
+    /// 기한 안에 들어가지 못해 거부된 주문 수
+    pub fn timed_out(&self) -> u64 {
+        self.timed_out.load(Ordering::Relaxed)
+    }
+
+    /// 대기열이 가득 차 거부된 주문 수
+    pub fn overflowed(&self) -> u64 {
+        self.overflowed.load(Ordering::Relaxed)
+    }
+
+    /// MetricsCollector로 게이지 값 발행 (`sequencer.admission.*`)
+    pub async fn publish(&self, collector: &MetricsCollector) {
+        collector.set_gauge("sequencer.admission.waiting", self.waiting() as u64).await;
+        collector.set_gauge("sequencer.admission.admitted", self.admitted()).await;
+        collector.set_gauge("sequencer.admission.timed_out", self.timed_out()).await;
+        collector.set_gauge("sequencer.admission.overflowed", self.overflowed()).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use crate::matching_engine::model::{OrderType, Side};
+    use crate::sequencer::backpressure::{bounded_queue, OverflowPolicy};
+
+    fn new_order(id: &str) -> Order {
+        Order::new(id.to_string(), "BTC-KRW".to_string(), Side::Buy, OrderType::Limit, 1000, 1, "test".to_string())
+    }
+
+    fn queue(max_waiting: usize) -> Arc<AdmissionQueue> {
+        Arc::new(AdmissionQueue::new(AdmissionConfig { max_waiting, ..AdmissionConfig::default() }))
+    }
+
+    #[tokio::test]
+    async fn test_waiting_orders_admitted_in_arrival_order() {
+        let admission = queue(10);
+        let sequence = Arc::new(GlobalSequence::new());
+        let (tx, rx) = bounded_queue("orders", 1, OverflowPolicy::Reject);
+        admission.submit(&sequence, new_order("first"), &tx, Duration::ZERO).await.unwrap();
+
+        // 대기하지 않는 주문은 바로 거부
+        let rejected = admission.submit(&sequence, new_order("x"), &tx, Duration::ZERO).await;
+        assert!(matches!(rejected, Err(AdmissionError::Queue(QueueError::Full(_)))));
+
+        let mut waiters = Vec::new();
+        for id in ["a", "b"] {
+            let (admission, sequence, tx) = (admission.clone(), sequence.clone(), tx.clone());
+            waiters.push(tokio::spawn(async move {
+                admission.submit(&sequence, new_order(id), &tx, Duration::from_secs(1)).await
+            }));
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(admission.waiting(), 2);
+
+        let mut received = Vec::new();
+        while received.len() < 3 {
+            if let Ok(order) = rx.try_recv() {
+                received.push(order.id);
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(received, vec!["first", "a", "b"]);
+        for waiter in waiters {
+            assert!(waiter.await.unwrap().unwrap().is_some());
+        }
+        assert_eq!((admission.waiting(), admission.admitted()), (0, 2));
+    }
+
+    #[tokio::test]
+    async fn test_deadline_and_waiting_limit() {
+        let admission = queue(1);
+        let sequence = Arc::new(GlobalSequence::new());
+        let (tx, _rx) = bounded_queue("orders", 1, OverflowPolicy::Reject);
+        admission.submit(&sequence, new_order("first"), &tx, Duration::ZERO).await.unwrap();
+
+        let waiter = {
+            let (admission, sequence, tx) = (admission.clone(), sequence.clone(), tx.clone());
+            tokio::spawn(async move { admission.submit(&sequence, new_order("a"), &tx, Duration::from_millis(50)).await })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        // 대기열이 가득 차면 기다리지 않고 거부
+        let overflow = admission.submit(&sequence, new_order("b"), &tx, Duration::from_millis(50)).await;
+        assert!(matches!(overflow, Err(AdmissionError::Queue(QueueError::Full(_)))));
+
+        let Err(AdmissionError::Timeout { waited }) = waiter.await.unwrap() else {
+            panic!("기한 안에 큐에 넣지 못하면 시간 초과");
+        };
+        assert!(waited >= Duration::from_millis(50));
+        assert_eq!((admission.timed_out(), admission.overflowed(), admission.waiting()), (1, 1, 0));
+
+        // 요청한 대기 시간은 설정 상한으로 제한
+        assert_eq!(admission.wait_for(Some(60_000)), AdmissionConfig::default().max_wait);
+        assert_eq!(admission.wait_for(None), Duration::ZERO);
+    }
+}
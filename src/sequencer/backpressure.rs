@@ -0,0 +1,443 @@
+//! 시퀀서 백프레셔 구현
+//!
+//! 무제한 채널 대신 용량이 정해진 큐를 사용하고, 큐가 가득 찼을 때의
+//! 처리 방식(오버플로 정책)을 큐마다 지정합니다.
+//! - 주문: 절대 버리지 않음 (API는 503으로 거부, 내부 전달은 대기)
+//! - 시장 데이터: 가장 먼저 버림
+//!
+//! 각 큐는 깊이 게이지를 가지며 MetricsCollector로 노출됩니다.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender, TryRecvError, TrySendError};
+use std::sync::Arc;
+use std::time::Duration;
+use log::warn;
+
+use crate::performance::MetricsCollector;
+use crate::sequencer::priority_lanes::LaneWeights;
+use crate::sequencer::wait_strategy::WaitStrategyConfig;
+
+/// 큐 오버플로 정책
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// 공간이 생길 때까지 대기 (버리지 않음)
+    Block,
+    /// 즉시 거부하고 호출자에게 알림 (API 503 등)
+    Reject,
+    /// 새 메시지를 버림 (시장 데이터 등 손실 허용)
+    DropNewest,
+}
+
+/// 큐 전송 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueueError {
+    /// 큐가 가득 참 (Reject 정책)
+    Full(String),
+    /// 수신측이 종료됨
+    Disconnected(String),
+}
+
+impl fmt::Display for QueueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueueError::Full(name) => write!(f, "큐 용량 초과: {}", name),
+            QueueError::Disconnected(name) => write!(f, "큐 수신측 종료: {}", name),
+        }
+    }
+}
+
+impl std::error::Error for QueueError {}
+
+/// 큐 깊이 게이지
+#[derive(Debug)]
+pub struct QueueGauge {
+    /// 큐 이름
+    name: String,
+    /// 큐 용량
+    capacity: usize,
+    /// 현재 큐 깊이
+    depth: AtomicUsize,
+    /// 최대 관측 깊이
+    high_watermark: AtomicUsize,
+    /// 버려진 메시지 수
+    dropped: AtomicU64,
+    /// 거부된 메시지 수
+    rejected: AtomicU64,
+    /// 누적 적재 메시지 수 (버려지거나 거부된 메시지 제외)
+    enqueued: AtomicU64,
+}
+
+impl QueueGauge {
+    fn new(name: &str, capacity: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            capacity,
+            depth: AtomicUsize::new(0),
+            high_watermark: AtomicUsize::new(0),
+            dropped: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
+            enqueued: AtomicU64::new(0),
+        }
+    }
+
+    /// 전송 전에 깊이를 먼저 올림 (수신측 감소와의 경합 방지). 반환값은 예약 후 깊이
+    fn reserve(&self) -> usize {
+        self.depth.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// 전송 성공 시 적재 기록 (동시에 실패한 예약이 섞일 수 있어 최대 깊이는 용량까지만)
+    fn on_enqueue(&self, reserved_depth: usize) {
+        self.high_watermark.fetch_max(reserved_depth.min(self.capacity), Ordering::Relaxed);
+        self.enqueued.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_dequeue(&self) {
+        // 음수 방지 (enqueue 이전 수신 경합)
+        let _ = self.depth.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |d| Some(d.saturating_sub(1)));
+    }
+
+    /// 큐 이름
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 큐 용량
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 현재 큐 깊이
+    pub fn depth(&self) -> usize {
+        self.depth.load(Ordering::Relaxed)
+    }
+
+    /// 최대 관측 깊이
+    pub fn high_watermark(&self) -> usize {
+        self.high_watermark.load(Ordering::Relaxed)
+    }
+
+    /// 버려진 메시지 수
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// 거부된 메시지 수
+    pub fn rejected(&self) -> u64 {
+        self.rejected.load(Ordering::Relaxed)
+    }
+
+    /// 누적 적재 메시지 수 (처리량 계산용)
+    pub fn enqueued(&self) -> u64 {
+        self.enqueued.load(Ordering::Relaxed)
+    }
+}
+
+/// 용량 제한 큐 송신측
+pub struct BoundedSender<T> {
+    inner: SyncSender<T>,
+    policy: OverflowPolicy,
+    gauge: Arc<QueueGauge>,
+}
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            policy: self.policy,
+            gauge: self.gauge.clone(),
+        }
+    }
+}
+
+impl<T> BoundedSender<T> {
+    /// 오버플로 정책에 따라 메시지 전송
+    ///
+    /// DropNewest 정책에서 메시지를 버린 경우에도 `Ok(false)`를 반환합니다.
+    pub fn send(&self, item: T) -> Result<bool, QueueError> {
+        match self.policy {
+            OverflowPolicy::Block => {
+                let depth = self.gauge.reserve();
+                match self.inner.send(item) {
+                    Ok(()) => {
+                        self.gauge.on_enqueue(depth);
+                        Ok(true)
+                    }
+                    Err(_) => {
+                        self.gauge.on_dequeue();
+                        Err(QueueError::Disconnected(self.gauge.name.clone()))
+                    }
+                }
+            }
+            OverflowPolicy::Reject | OverflowPolicy::DropNewest => {
+                let depth = self.gauge.reserve();
+                match self.inner.try_send(item) {
+                    Ok(()) => {
+                        self.gauge.on_enqueue(depth);
+                        Ok(true)
+                    }
+                    Err(TrySendError::Full(_)) => {
+                        self.gauge.on_dequeue();
+                        if self.policy == OverflowPolicy::Reject {
+                            self.gauge.rejected.fetch_add(1, Ordering::Relaxed);
+                            warn!("큐 용량 초과로 거부: {} (용량: {})", self.gauge.name, self.gauge.capacity);
+                            Err(QueueError::Full(self.gauge.name.clone()))
+                        } else {
+                            self.gauge.dropped.fetch_add(1, Ordering::Relaxed);
+                            Ok(false)
+                        }
+                    }
+                    Err(TrySendError::Disconnected(_)) => {
+                        self.gauge.on_dequeue();
+                        Err(QueueError::Disconnected(self.gauge.name.clone()))
+                    }
+                }
+            }
+        }
+    }
+
+    /// 큐 게이지 조회
+    pub fn gauge(&self) -> Arc<QueueGauge> {
+        self.gauge.clone()
+    }
+}
+
+impl<T: Send + 'static> BoundedSender<T> {
+    /// 비동기 태스크에서 전송
+    ///
+    /// Block 정책에서 큐가 가득 차면 블로킹 스레드(`spawn_blocking`)에서 기다리므로,
+    /// 대기 중에도 tokio 워커 스레드를 막지 않습니다. 다른 정책은 `send`와 같습니다.
+    pub async fn send_async(&self, item: T) -> Result<bool, QueueError> {
+        if self.policy != OverflowPolicy::Block {
+            return self.send(item);
+        }
+        let depth = self.gauge.reserve();
+        let sent = match self.inner.try_send(item) {
+            Ok(()) => true,
+            Err(TrySendError::Full(item)) => {
+                let inner = self.inner.clone();
+                tokio::task::spawn_blocking(move || inner.send(item).is_ok()).await.unwrap_or(false)
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        };
+        if sent {
+            self.gauge.on_enqueue(depth);
+            Ok(true)
+        } else {
+            self.gauge.on_dequeue();
+            Err(QueueError::Disconnected(self.gauge.name.clone()))
+        }
+    }
+}
+
+/// 용량 제한 큐 수신측
+pub struct BoundedReceiver<T> {
+    inner: Receiver<T>,
+    gauge: Arc<QueueGauge>,
+}
+
+impl<T> BoundedReceiver<T> {
+    /// 메시지 수신 (대기)
+    pub fn recv(&self) -> Result<T, mpsc::RecvError> {
+        let item = self.inner.recv()?;
+        self.gauge.on_dequeue();
+        Ok(item)
+    }
+
+    /// 제한 시간 동안 메시지 수신
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        let item = self.inner.recv_timeout(timeout)?;
+        self.gauge.on_dequeue();
+        Ok(item)
+    }
+
+    /// 대기 없이 메시지 수신
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let item = self.inner.try_recv()?;
+        self.gauge.on_dequeue();
+        Ok(item)
+    }
+
+    /// 현재 큐에 있는 메시지를 모두 수신
+    pub fn try_iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(move || self.try_recv().ok())
+    }
+
+    /// 큐 게이지 조회
+    pub fn gauge(&self) -> Arc<QueueGauge> {
+        self.gauge.clone()
+    }
+}
+
+/// 용량 제한 큐 생성
+pub fn bounded_queue<T>(name: &str, capacity: usize, policy: OverflowPolicy) -> (BoundedSender<T>, BoundedReceiver<T>) {
+    let (tx, rx) = mpsc::sync_channel(capacity);
+    let gauge = Arc::new(QueueGauge::new(name, capacity));
+    (
+        BoundedSender { inner: tx, policy, gauge: gauge.clone() },
+        BoundedReceiver { inner: rx, gauge },
+    )
+}
+
+/// 시퀀서 큐 설정
+#[derive(Debug, Clone)]
+pub struct SequencerQueueConfig {
+    /// API → 시퀀서 주문 큐 용량
+    pub order_queue_capacity: usize,
+    /// API → 시퀀서 취소 큐 용량
+    pub cancel_queue_capacity: usize,
+    /// 시퀀서 → 매칭 엔진 주문 큐 용량
+    pub engine_queue_capacity: usize,
+    /// 매칭 엔진 → 시퀀서 체결 보고서 큐 용량
+    pub execution_queue_capacity: usize,
+    /// 시퀀서 → MDP 시장 데이터 큐 용량
+    pub market_data_queue_capacity: usize,
+    /// API 주문 큐 오버플로 정책 (Reject 또는 Block, 주문은 버리지 않음)
+    pub order_overflow_policy: OverflowPolicy,
+    /// 취소/신규 레인 가중치
+    pub lane_weights: LaneWeights,
+    /// 시퀀서/매칭 엔진 수신 대기 전략 (기본값은 블로킹 수신)
+    pub wait_strategy: WaitStrategyConfig,
+}
+
+impl Default for SequencerQueueConfig {
+    fn default() -> Self {
+        Self {
+            order_queue_capacity: 10_000,
+            cancel_queue_capacity: 10_000,
+            engine_queue_capacity: 10_000,
+            execution_queue_capacity: 50_000,
+            market_data_queue_capacity: 10_000,
+            order_overflow_policy: OverflowPolicy::Reject,
+            lane_weights: LaneWeights::default(),
+            wait_strategy: WaitStrategyConfig::default(),
+        }
+    }
+}
+
+/// 시퀀서 큐 게이지 모음
+#[derive(Debug, Clone, Default)]
+pub struct SequencerQueueMetrics {
+    gauges: Vec<Arc<QueueGauge>>,
+}
+
+impl SequencerQueueMetrics {
+    /// 새 게이지 모음 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 게이지 등록 (같은 이름의 게이지가 있으면 교체)
+    pub fn register(&mut self, gauge: Arc<QueueGauge>) {
+        match self.gauges.iter_mut().find(|existing| existing.name() == gauge.name()) {
+            Some(existing) => *existing = gauge,
+            None => self.gauges.push(gauge),
+        }
+    }
+
+    /// 등록된 게이지 조회
+    pub fn gauges(&self) -> &[Arc<QueueGauge>] {
+        &self.gauges
+    }
+
+    /// MetricsCollector로 게이지 값 발행
+    pub async fn publish(&self, collector: &MetricsCollector) {
+        for gauge in &self.gauges {
+            let prefix = format!("sequencer.queue.{}", gauge.name());
+            collector.set_gauge(&format!("{}.depth", prefix), gauge.depth() as u64).await;
+            collector.set_gauge(&format!("{}.capacity", prefix), gauge.capacity() as u64).await;
+            collector.set_gauge(&format!("{}.high_watermark", prefix), gauge.high_watermark() as u64).await;
+            collector.set_gauge(&format!("{}.dropped", prefix), gauge.dropped()).await;
+            collector.set_gauge(&format!("{}.rejected", prefix), gauge.rejected()).await;
+            collector.set_gauge(&format!("{}.enqueued", prefix), gauge.enqueued()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reject_policy_when_full() {
+        let (tx, rx) = bounded_queue::<u32>("orders", 2, OverflowPolicy::Reject);
+
+        assert_eq!(tx.send(1), Ok(true));
+        assert_eq!(tx.send(2), Ok(true));
+        assert_eq!(tx.send(3), Err(QueueError::Full("orders".to_string())));
+
+        let gauge = tx.gauge();
+        assert_eq!(gauge.depth(), 2);
+        assert_eq!(gauge.rejected(), 1);
+        assert_eq!(gauge.enqueued(), 2);
+
+        assert_eq!(rx.recv().unwrap(), 1);
+        assert_eq!(gauge.depth(), 1);
+        assert_eq!(tx.send(4), Ok(true));
+    }
+
+    #[test]
+    fn test_drop_newest_policy_sheds_messages() {
+        let (tx, rx) = bounded_queue::<u32>("market_data", 1, OverflowPolicy::DropNewest);
+
+        assert_eq!(tx.send(1), Ok(true));
+        assert_eq!(tx.send(2), Ok(false));
+        assert_eq!(tx.gauge().dropped(), 1);
+        assert_eq!(tx.gauge().enqueued(), 1);
+
+        let received: Vec<u32> = rx.try_iter().collect();
+        assert_eq!(received, vec![1]);
+        assert_eq!(rx.gauge().depth(), 0);
+        assert_eq!(rx.gauge().high_watermark(), 1);
+    }
+
+    #[test]
+    fn test_block_policy_waits_for_space() {
+        let (tx, rx) = bounded_queue::<u32>("engine", 1, OverflowPolicy::Block);
+        tx.send(1).unwrap();
+
+        let sender = tx.clone();
+        let handle = std::thread::spawn(move || sender.send(2));
+
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(rx.recv().unwrap(), 1);
+        assert_eq!(handle.join().unwrap(), Ok(true));
+        assert_eq!(rx.recv().unwrap(), 2);
+        assert_eq!(tx.gauge().dropped(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_send_async_waits_without_blocking_runtime() {
+        // 단일 스레드 런타임: 전송 대기가 워커를 막으면 아래 sleep이 끝나지 않음
+        let (tx, rx) = bounded_queue::<u32>("engine", 1, OverflowPolicy::Block);
+        tx.send(1).unwrap();
+
+        let sender = tx.clone();
+        let handle = tokio::spawn(async move { sender.send_async(2).await });
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!handle.is_finished());
+        assert_eq!(rx.recv().unwrap(), 1);
+        assert_eq!(handle.await.unwrap(), Ok(true));
+        assert_eq!(rx.recv().unwrap(), 2);
+        assert_eq!(tx.gauge().enqueued(), 2);
+
+        drop(rx);
+        assert_eq!(tx.send_async(3).await, Err(QueueError::Disconnected("engine".to_string())));
+    }
+
+    #[test]
+    fn test_register_replaces_gauge_with_same_name() {
+        let mut metrics = SequencerQueueMetrics::new();
+        let (orders, _orders_rx) = bounded_queue::<u32>("orders", 10, OverflowPolicy::Reject);
+        let (small, _small_rx) = bounded_queue::<u32>("market_data", 10, OverflowPolicy::DropNewest);
+        let (large, _large_rx) = bounded_queue::<u32>("market_data", 100, OverflowPolicy::DropNewest);
+
+        metrics.register(orders.gauge());
+        metrics.register(small.gauge());
+        metrics.register(large.gauge());
+
+        let gauges: Vec<(&str, usize)> = metrics.gauges().iter().map(|gauge| (gauge.name(), gauge.capacity())).collect();
+        assert_eq!(gauges, vec![("orders", 10), ("market_data", 100)]);
+    }
+}
@@ -0,0 +1,74 @@
+//! 전역 시퀀스 번호
+//!
+//! 시퀀서가 받아들인 모든 주문과 체결에 하나의 단조 증가 번호를 붙입니다.
+//! 클라이언트와 하위 시스템은 이 번호로 이벤트 전체 순서를 맞추고, 번호가 줄어들면 재시작을 감지합니다.
+//! 번호는 프로세스가 시작될 때 1부터 다시 시작합니다.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::matching_engine::model::Order;
+use crate::sequencer::backpressure::{BoundedSender, QueueError};
+
+/// 전역 시퀀스 번호 발급기 (API와 시퀀서가 공유)
+#[derive(Debug, Default)]
+pub struct GlobalSequence {
+    last: AtomicU64,
+    /// 주문 제출 직렬화 (번호순 = 큐 도착순)
+    submit_lock: Mutex<()>,
+}
+
+impl GlobalSequence {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 마지막으로 발급한 번호 (없으면 0)
+    pub fn last(&self) -> u64 {
+        self.last.load(Ordering::SeqCst)
+    }
+
+    /// 다음 번호 발급
+    pub fn next(&self) -> u64 {
+        self.last.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// 번호를 붙여 시퀀서 큐에 넣음
+    ///
+    /// 제출을 직렬화하므로 주문 번호순과 큐 도착순이 같습니다. 큐에 들어가지 못한 주문의 번호는
+    /// 다시 쓰지 않습니다 (체결 번호 발급은 막지 않으므로 큐가 가득 차 대기해도 체결 처리가 멈추지 않음).
+    /// 큐에 들어가지 못하고 버려지면 `Ok(None)` 입니다.
+    pub fn submit(&self, mut order: Order, tx: &BoundedSender<Order>) -> Result<Option<u64>, QueueError> {
+        let _guard = self.submit_lock.lock().unwrap();
+        let sequence = self.next();
+        order.sequence = sequence;
+        Ok(tx.send(order)?.then_some(sequence))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching_engine::model::{OrderType, Side};
+    use crate::sequencer::backpressure::{bounded_queue, OverflowPolicy};
+
+    fn new_order(id: &str) -> Order {
+        Order::new(id.to_string(), "BTC-KRW".to_string(), Side::Buy, OrderType::Limit, 1000, 1, "test".to_string())
+    }
+
+    #[test]
+    fn test_submit_stamps_in_queue_order() {
+        let sequence = GlobalSequence::new();
+        let (tx, rx) = bounded_queue("orders", 2, OverflowPolicy::DropNewest);
+
+        assert_eq!(sequence.submit(new_order("a"), &tx).unwrap(), Some(1));
+        assert_eq!(sequence.next(), 2);
+        assert_eq!(sequence.submit(new_order("b"), &tx).unwrap(), Some(3));
+        // 가득 차서 버려진 주문은 번호를 받지 못함
+        assert_eq!(sequence.submit(new_order("c"), &tx).unwrap(), None);
+        assert_eq!(sequence.next(), 5);
+
+        let received: Vec<(String, u64)> = rx.try_iter().map(|o| (o.id, o.sequence)).collect();
+        assert_eq!(received, vec![("a".to_string(), 1), ("b".to_string(), 3)]);
+    }
+}
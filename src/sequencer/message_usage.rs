@@ -0,0 +1,374 @@
+//! 계정별 분당 메시지 사용량 집계와 사용량 상한
+//!
+//! REST 주문/취소 핸들러와 조회 미들웨어가 계정별 메시지(주문, 취소, 조회)를 분 단위 버킷에 셉니다.
+//! 버킷은 메모리에서 세다가 `flush_interval`마다 증가분을 `client_message_usage` 테이블에 더하고,
+//! 보존 기간(`retention`)이 지난 버킷은 지웁니다. 공정 사용 정책과 과금 집계에 씁니다.
+//!
+//! 분당 메시지 합계가 소프트 상한을 넘으면 분마다 한 번 경고를 남기고, 하드 상한을 넘은 신규 주문과 조회는
+//! 취소 비율 스로틀과 같은 `RATE_LIMITED`로 거부합니다. 취소는 노출을 줄이는 요청이므로 세기만 하고 거부하지 않습니다.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use utoipa::ToSchema;
+
+use crate::db::models::{MessageUsageRecord, MessageUsageSummaryRecord};
+use crate::db::repository::MessageUsageRepository;
+use crate::util::clock::{SharedClock, SystemClock};
+
+const MINUTE_MS: u64 = 60_000;
+
+/// 메시지 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKind {
+    /// 신규 주문
+    Order,
+    /// 취소 (하드 상한에서도 거부하지 않음)
+    Cancel,
+    /// 조회 (인증된 계정의 GET 요청)
+    Query,
+}
+
+/// 사용량 상한과 저장 주기
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageUsageConfig {
+    /// 분당 메시지 소프트 상한 (넘으면 경고만, None이면 없음)
+    pub soft_cap_per_minute: Option<u64>,
+    /// 분당 메시지 하드 상한 (넘는 주문/조회 거부, None이면 없음)
+    pub hard_cap_per_minute: Option<u64>,
+    /// DB 저장 주기
+    pub flush_interval: Duration,
+    /// 분당 버킷 보존 기간
+    pub retention: Duration,
+}
+
+impl Default for MessageUsageConfig {
+    fn default() -> Self {
+        Self {
+            soft_cap_per_minute: None,
+            hard_cap_per_minute: None,
+            flush_interval: Duration::from_secs(10),
+            retention: Duration::from_secs(7 * 24 * 3600),
+        }
+    }
+}
+
+/// 메시지 수
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub struct MessageCounts {
+    pub orders: u64,
+    pub cancels: u64,
+    pub queries: u64,
+    /// 하드 상한으로 거부한 메시지 수 (위 세 항목에도 포함)
+    pub rejected: u64,
+}
+
+impl MessageCounts {
+    /// 주문 + 취소 + 조회
+    pub fn total(&self) -> u64 {
+        self.orders + self.cancels + self.queries
+    }
+
+    fn add(&mut self, other: &MessageCounts) {
+        self.orders += other.orders;
+        self.cancels += other.cancels;
+        self.queries += other.queries;
+        self.rejected += other.rejected;
+    }
+
+    fn sub(&self, other: &MessageCounts) -> MessageCounts {
+        MessageCounts {
+            orders: self.orders - other.orders,
+            cancels: self.cancels - other.cancels,
+            queries: self.queries - other.queries,
+            rejected: self.rejected - other.rejected,
+        }
+    }
+}
+
+/// 분당 메시지 수
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MinuteUsage {
+    /// 분 시작 시각 (밀리초)
+    pub minute: u64,
+    pub orders: u64,
+    pub cancels: u64,
+    pub queries: u64,
+    pub rejected: u64,
+    /// 소프트 상한 초과 여부
+    pub soft_cap_exceeded: bool,
+}
+
+/// 계정별 기간 사용량 합계
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ClientMessageUsage {
+    pub client_id: String,
+    pub orders: u64,
+    pub cancels: u64,
+    pub queries: u64,
+    pub rejected: u64,
+    /// 주문 + 취소 + 조회
+    pub total: u64,
+    /// 분당 최대 메시지 수
+    pub peak_per_minute: u64,
+}
+
+impl From<MessageUsageSummaryRecord> for ClientMessageUsage {
+    fn from(record: MessageUsageSummaryRecord) -> Self {
+        let (orders, cancels, queries) = (record.orders as u64, record.cancels as u64, record.queries as u64);
+        Self {
+            client_id: record.client_id,
+            orders,
+            cancels,
+            queries,
+            rejected: record.rejected as u64,
+            total: orders + cancels + queries,
+            peak_per_minute: record.peak_per_minute as u64,
+        }
+    }
+}
+
+/// 사용량 오류
+#[derive(Debug, thiserror::Error)]
+pub enum UsageError {
+    #[error("분당 메시지 한도를 넘었습니다: {client_id} (분당 {cap}건)")]
+    HardCapExceeded { client_id: String, cap: u64 },
+    #[error("메시지 사용량 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 계정·분 버킷 (DB에 더한 만큼은 `flushed`)
+#[derive(Default)]
+struct MinuteBucket {
+    counts: MessageCounts,
+    flushed: MessageCounts,
+    soft_warned: bool,
+}
+
+/// 분 시작 시각 (밀리초)
+pub fn minute_start(now_ms: u64) -> u64 {
+    now_ms / MINUTE_MS * MINUTE_MS
+}
+
+/// 계정별 분당 메시지 집계 (메모리 + DB)
+pub struct MessageUsage {
+    config: MessageUsageConfig,
+    buckets: Mutex<HashMap<(String, u64), MinuteBucket>>,
+    flush_lock: tokio::sync::Mutex<()>,
+    repository: MessageUsageRepository,
+    clock: SharedClock,
+}
+
+impl MessageUsage {
+    pub fn new(config: MessageUsageConfig, pool: SqlitePool) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+            flush_lock: tokio::sync::Mutex::new(()),
+            repository: MessageUsageRepository::new(pool),
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// 시각 소스 지정 (시뮬레이션 테스트용, 기본값은 시스템 시각)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 집계 기준 현재 시각 (Unix 밀리초)
+    pub fn now_millis(&self) -> u64 {
+        self.clock.now_millis()
+    }
+
+    pub fn config(&self) -> &MessageUsageConfig {
+        &self.config
+    }
+
+    /// 메시지 기록 (하드 상한을 넘은 주문/조회는 거부로 세고 오류 반환)
+    pub fn record(&self, client_id: &str, kind: MessageKind, now_ms: u64) -> Result<(), UsageError> {
+        let minute = minute_start(now_ms);
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry((client_id.to_string(), minute)).or_default();
+        match kind {
+            MessageKind::Order => bucket.counts.orders += 1,
+            MessageKind::Cancel => bucket.counts.cancels += 1,
+            MessageKind::Query => bucket.counts.queries += 1,
+        }
+
+        let total = bucket.counts.total();
+        if let Some(cap) = self.config.hard_cap_per_minute.filter(|cap| total > *cap && kind != MessageKind::Cancel) {
+            bucket.counts.rejected += 1;
+            return Err(UsageError::HardCapExceeded { client_id: client_id.to_string(), cap });
+        }
+        if let Some(cap) = self.config.soft_cap_per_minute.filter(|cap| total > *cap && !bucket.soft_warned) {
+            bucket.soft_warned = true;
+            warn!("분당 메시지 소프트 상한 초과: {} (분당 {}건 초과)", client_id, cap);
+        }
+        Ok(())
+    }
+
+    /// 증가분을 DB에 더하고 지난 분 버킷과 보존 기간이 지난 행 정리 (저장한 버킷 수)
+    pub async fn flush(&self, now_ms: u64) -> Result<usize, UsageError> {
+        // 같은 증가분을 두 번 더하지 않도록 저장은 한 번에 하나씩
+        let _flushing = self.flush_lock.lock().await;
+        let current = minute_start(now_ms);
+        let pending: Vec<(String, u64, MessageCounts)> = self
+            .buckets
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, bucket)| bucket.counts != bucket.flushed)
+            .map(|((client_id, minute), bucket)| (client_id.clone(), *minute, bucket.counts.sub(&bucket.flushed)))
+            .collect();
+
+        if !pending.is_empty() {
+            let records: Vec<MessageUsageRecord> = pending
+                .iter()
+                .map(|(client_id, minute, delta)| MessageUsageRecord {
+                    client_id: client_id.clone(),
+                    minute: *minute as i64,
+                    orders: delta.orders as i64,
+                    cancels: delta.cancels as i64,
+                    queries: delta.queries as i64,
+                    rejected: delta.rejected as i64,
+                })
+                .collect();
+            self.repository.add(&records).await?;
+        }
+
+        // 저장한 만큼 표시하고, 저장 이후 새 메시지가 없는 지난 분 버킷은 메모리에서 제거
+        {
+            let mut buckets = self.buckets.lock().unwrap();
+            for (client_id, minute, delta) in &pending {
+                if let Some(bucket) = buckets.get_mut(&(client_id.clone(), *minute)) {
+                    bucket.flushed.add(delta);
+                }
+            }
+            buckets.retain(|(_, minute), bucket| *minute >= current || bucket.counts != bucket.flushed);
+        }
+
+        let cutoff = now_ms.saturating_sub(self.config.retention.as_millis() as u64);
+        let removed = self.repository.delete_before(minute_start(cutoff) as i64).await?;
+        if removed > 0 {
+            info!("보존 기간이 지난 분당 메시지 집계 {}행 삭제", removed);
+        }
+        Ok(pending.len())
+    }
+
+    /// 계정의 기간 [from, to) 분당 사용량 (DB 저장 분 + 아직 저장하지 않은 증가분)
+    pub async fn usage(&self, client_id: &str, from_ms: u64, to_ms: u64) -> Result<Vec<MinuteUsage>, UsageError> {
+        let mut minutes: HashMap<u64, MessageCounts> = self
+            .repository
+            .find_by_client(client_id, from_ms as i64, to_ms as i64)
+            .await?
+            .into_iter()
+            .map(|record| {
+                let counts = MessageCounts {
+                    orders: record.orders as u64,
+                    cancels: record.cancels as u64,
+                    queries: record.queries as u64,
+                    rejected: record.rejected as u64,
+                };
+                (record.minute as u64, counts)
+            })
+            .collect();
+        for ((id, minute), bucket) in self.buckets.lock().unwrap().iter() {
+            if id == client_id && (from_ms..to_ms).contains(minute) {
+                minutes.entry(*minute).or_default().add(&bucket.counts.sub(&bucket.flushed));
+            }
+        }
+
+        let mut usage: Vec<MinuteUsage> = minutes
+            .into_iter()
+            .map(|(minute, counts)| MinuteUsage {
+                minute,
+                orders: counts.orders,
+                cancels: counts.cancels,
+                queries: counts.queries,
+                rejected: counts.rejected,
+                soft_cap_exceeded: self.config.soft_cap_per_minute.is_some_and(|cap| counts.total() > cap),
+            })
+            .collect();
+        usage.sort_by_key(|minute| minute.minute);
+        Ok(usage)
+    }
+
+    /// 기간 [from, to) 계정별 사용량 합계 (많은 순, 저장 후 집계)
+    pub async fn summary(&self, from_ms: u64, to_ms: u64, limit: usize) -> Result<Vec<ClientMessageUsage>, UsageError> {
+        self.flush(self.now_millis()).await?;
+        let records = self.repository.summarize(from_ms as i64, to_ms as i64, limit as i64).await?;
+        Ok(records.into_iter().map(ClientMessageUsage::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_hard_cap_rejects_orders_but_not_cancels() {
+        let config = MessageUsageConfig { soft_cap_per_minute: Some(2), hard_cap_per_minute: Some(3), ..Default::default() };
+        let usage = MessageUsage::new(config, test_pool().await);
+        let now = 1_700_000_000_000;
+
+        for i in 0..3 {
+            usage.record("trader", MessageKind::Order, now + i).unwrap();
+        }
+        assert!(matches!(usage.record("trader", MessageKind::Query, now + 3), Err(UsageError::HardCapExceeded { cap: 3, .. })));
+        usage.record("trader", MessageKind::Cancel, now + 4).unwrap();
+        // 다른 계정과 다음 분은 따로 셈
+        usage.record("other", MessageKind::Order, now).unwrap();
+        usage.record("trader", MessageKind::Order, minute_start(now) + MINUTE_MS).unwrap();
+
+        let minutes = usage.usage("trader", 0, u64::MAX >> 1).await.unwrap();
+        assert_eq!(minutes.len(), 2);
+        assert_eq!((minutes[0].orders, minutes[0].cancels, minutes[0].queries, minutes[0].rejected), (3, 1, 1, 1));
+        assert!(minutes[0].soft_cap_exceeded);
+        assert!(!minutes[1].soft_cap_exceeded);
+    }
+
+    #[tokio::test]
+    async fn test_flush_accumulates_and_applies_retention() {
+        let pool = test_pool().await;
+        let config = MessageUsageConfig { retention: Duration::from_secs(3600), ..Default::default() };
+        let usage = MessageUsage::new(config.clone(), pool.clone());
+        let now = 1_700_000_000_000;
+
+        usage.record("trader", MessageKind::Order, now).unwrap();
+        usage.record("trader", MessageKind::Query, now).unwrap();
+        assert_eq!(usage.flush(now).await.unwrap(), 1);
+        // 같은 분의 추가 메시지는 증가분만 더함
+        usage.record("trader", MessageKind::Order, now + 1).unwrap();
+        assert_eq!(usage.flush(now + 1).await.unwrap(), 1);
+        assert_eq!(usage.flush(now + 2).await.unwrap(), 0);
+
+        // 재시작해도 DB에 저장된 값에 이어서 셈
+        let clock = crate::util::clock::SimClock::new(now + 3);
+        let restarted = MessageUsage::new(config, pool).with_clock(clock.shared());
+        restarted.record("trader", MessageKind::Cancel, now + 2).unwrap();
+        let minutes = restarted.usage("trader", minute_start(now), minute_start(now) + MINUTE_MS).await.unwrap();
+        assert_eq!((minutes[0].orders, minutes[0].cancels, minutes[0].queries), (2, 1, 1));
+
+        let summary = restarted.summary(0, now + MINUTE_MS, 10).await.unwrap();
+        assert_eq!((summary[0].total, summary[0].peak_per_minute), (4, 4));
+
+        // 보존 기간이 지나면 삭제
+        restarted.flush(now + 2 * 3600 * 1000).await.unwrap();
+        assert!(restarted.usage("trader", 0, now + MINUTE_MS).await.unwrap().is_empty());
+    }
+}
@@ -1,3 +1,26 @@
+#[allow(clippy::module_inception)]
 pub mod sequencer;
+pub mod backpressure;
+pub mod priority_lanes;
+pub mod global_sequence;
+pub mod order_lifecycle;
+pub mod private_events;
+pub mod symbol_lanes;
+pub mod replication;
+pub mod throttle;
+pub mod message_usage;
+pub mod admission;
+pub mod wait_strategy;
 
-pub use sequencer::*;
\ No newline at end of file
+pub use sequencer::*;
+pub use backpressure::*;
+pub use priority_lanes::*;
+pub use global_sequence::*;
+pub use order_lifecycle::*;
+pub use private_events::*;
+pub use symbol_lanes::*;
+pub use replication::*;
+pub use throttle::*;
+pub use message_usage::*;
+pub use admission::*;
+pub use wait_strategy::*;
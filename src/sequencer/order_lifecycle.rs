@@ -0,0 +1,490 @@
+//! 주문 생애주기 상태 기계
+//!
+//! 시퀀서는 매칭 엔진으로 보낸 주문(New)과 엔진이 돌려준 체결 보고서로 주문 상태를 추적합니다.
+//! 허용되지 않는 전이(종료된 주문의 체결, 주문 수량을 넘는 체결 등)는 적용하지 않고 오류로 남기며,
+//! 적용된 전이는 모두 `AsyncCommitManager` 를 거쳐 orders 테이블에 기록됩니다.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use log::error;
+use serde::{Deserialize, Serialize};
+
+use crate::db::models::OrderRecord;
+use crate::db::AsyncCommitManager;
+use crate::matching_engine::model::{ExecType, ExecutionReport, Order, OrderType};
+
+/// 종료 상태를 기억하는 최근 주문 수 (종료 후 도착한 보고서를 잘못된 전이로 판별)
+pub const CLOSED_ORDER_CAPACITY: usize = 100_000;
+
+/// 주문 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderState {
+    /// 매칭 엔진으로 전달됨 (체결 없음)
+    New,
+    /// 일부 체결
+    PartiallyFilled,
+    /// 전량 체결
+    Filled,
+    /// 취소 (사용자 요청, 시장가 잔량)
+    Canceled,
+    /// 매칭 엔진이 거부
+    Rejected,
+    /// 만료 (GTD 또는 장기 미체결)
+    Expired,
+}
+
+impl OrderState {
+    /// orders 테이블 status 값
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderState::New => "New",
+            OrderState::PartiallyFilled => "PartiallyFilled",
+            OrderState::Filled => "Filled",
+            OrderState::Canceled => "Canceled",
+            OrderState::Rejected => "Rejected",
+            OrderState::Expired => "Expired",
+        }
+    }
+
+    /// 더 이상 전이가 없는 상태
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, OrderState::New | OrderState::PartiallyFilled)
+    }
+
+    /// `next` 로 전이할 수 있는지 (거부는 엔진이 받아들이기 전에만 가능)
+    pub fn can_transition_to(&self, next: OrderState) -> bool {
+        match self {
+            OrderState::New => next != OrderState::New,
+            OrderState::PartiallyFilled => matches!(
+                next,
+                OrderState::PartiallyFilled | OrderState::Filled | OrderState::Canceled | OrderState::Expired
+            ),
+            _ => false,
+        }
+    }
+}
+
+/// 상태 전이 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleError {
+    /// 이미 추적 중이거나 종료된 주문 ID로 다시 New
+    DuplicateOrder(String),
+    /// 추적하지 않는 주문의 보고서
+    UnknownOrder(String),
+    /// 허용되지 않는 상태 전이
+    IllegalTransition { order_id: String, from: OrderState, to: OrderState },
+    /// 주문 수량을 넘는 체결
+    Overfill { order_id: String, quantity: u64, filled: u64 },
+}
+
+impl fmt::Display for LifecycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LifecycleError::DuplicateOrder(order_id) => write!(f, "이미 접수된 주문 ID: {}", order_id),
+            LifecycleError::UnknownOrder(order_id) => write!(f, "추적하지 않는 주문: {}", order_id),
+            LifecycleError::IllegalTransition { order_id, from, to } => {
+                write!(f, "허용되지 않는 주문 상태 전이: {} {:?} -> {:?}", order_id, from, to)
+            }
+            LifecycleError::Overfill { order_id, quantity, filled } => {
+                write!(f, "주문 수량 초과 체결: {} (주문 {}, 체결 {})", order_id, quantity, filled)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LifecycleError {}
+
+/// 적용된 상태 전이
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderTransition {
+    pub order_id: String,
+    /// 주문 고객 ID
+    pub client_id: String,
+    /// 이전 상태 (New 이면 없음)
+    pub from: Option<OrderState>,
+    pub to: OrderState,
+    /// 누적 체결 수량
+    pub filled_quantity: u64,
+}
+
+struct OpenOrder {
+    client_id: String,
+    state: OrderState,
+    quantity: u64,
+    filled: u64,
+}
+
+/// 주문 상태 추적기
+pub struct OrderLifecycle {
+    open: HashMap<String, OpenOrder>,
+    /// 최근 종료된 주문의 최종 상태와 주문 고객
+    closed: HashMap<String, (OrderState, String)>,
+    /// 종료 순서 (오래된 것부터 잊음)
+    closed_order: VecDeque<String>,
+    closed_capacity: usize,
+    rejected_events: u64,
+}
+
+impl OrderLifecycle {
+    pub fn new(closed_capacity: usize) -> Self {
+        Self {
+            open: HashMap::new(),
+            closed: HashMap::new(),
+            closed_order: VecDeque::new(),
+            closed_capacity: closed_capacity.max(1),
+            rejected_events: 0,
+        }
+    }
+
+    /// 현재 상태 (종료 후 잊은 주문은 None)
+    pub fn state(&self, order_id: &str) -> Option<OrderState> {
+        self.open
+            .get(order_id)
+            .map(|order| order.state)
+            .or_else(|| self.closed.get(order_id).map(|(state, _)| *state))
+    }
+
+    /// 주문 고객 ID (종료 후 잊은 주문은 None)
+    pub fn client_id(&self, order_id: &str) -> Option<&str> {
+        self.open
+            .get(order_id)
+            .map(|order| order.client_id.as_str())
+            .or_else(|| self.closed.get(order_id).map(|(_, client_id)| client_id.as_str()))
+    }
+
+    /// 종료되지 않은 주문 수
+    pub fn open_count(&self) -> usize {
+        self.open.len()
+    }
+
+    /// 거부한 (적용하지 않은) 이벤트 수
+    pub fn rejected_events(&self) -> u64 {
+        self.rejected_events
+    }
+
+    /// 매칭 엔진으로 보낸 주문을 New 로 등록
+    pub fn on_new(&mut self, order: &Order) -> Result<OrderTransition, LifecycleError> {
+        if self.state(&order.id).is_some() {
+            self.rejected_events += 1;
+            return Err(LifecycleError::DuplicateOrder(order.id.clone()));
+        }
+        self.open.insert(
+            order.id.clone(),
+            OpenOrder {
+                client_id: order.client_id.clone(),
+                state: OrderState::New,
+                quantity: order.quantity,
+                filled: 0,
+            },
+        );
+        Ok(OrderTransition {
+            order_id: order.id.clone(),
+            client_id: order.client_id.clone(),
+            from: None,
+            to: OrderState::New,
+            filled_quantity: 0,
+        })
+    }
+
+    /// 백업에서 복원한 미체결 주문 등록 (체결된 수량이 있으면 PartiallyFilled)
+    pub fn restore_open(&mut self, order: &Order) {
+        let filled = order.quantity - order.remaining_quantity;
+        let state = if filled > 0 { OrderState::PartiallyFilled } else { OrderState::New };
+        self.open.insert(
+            order.id.clone(),
+            OpenOrder { client_id: order.client_id.clone(), state, quantity: order.quantity, filled },
+        );
+    }
+
+    /// 체결 보고서 적용
+    pub fn on_report(&mut self, report: &ExecutionReport) -> Result<OrderTransition, LifecycleError> {
+        let result = self.apply(report);
+        if result.is_err() {
+            self.rejected_events += 1;
+        }
+        result
+    }
+
+    fn apply(&mut self, report: &ExecutionReport) -> Result<OrderTransition, LifecycleError> {
+        let order_id = &report.order_id;
+        let Some(order) = self.open.get_mut(order_id) else {
+            return Err(match self.closed.get(order_id) {
+                // 종료된 주문은 누적 체결을 모르므로 보고서의 남은 수량으로 판단
+                Some(&(from, _)) => LifecycleError::IllegalTransition {
+                    order_id: order_id.clone(),
+                    from,
+                    to: target_state(report, report.remaining_quantity, 0),
+                },
+                None => LifecycleError::UnknownOrder(order_id.clone()),
+            });
+        };
+
+        let filled = match report.exec_type {
+            ExecType::Trade => order.filled + report.quantity,
+            _ => order.filled,
+        };
+        if filled > order.quantity {
+            return Err(LifecycleError::Overfill {
+                order_id: order_id.clone(),
+                quantity: order.quantity,
+                filled,
+            });
+        }
+        let to = target_state(report, order.quantity, filled);
+        let from = order.state;
+        if !from.can_transition_to(to) {
+            return Err(LifecycleError::IllegalTransition { order_id: order_id.clone(), from, to });
+        }
+
+        order.state = to;
+        order.filled = filled;
+        let client_id = order.client_id.clone();
+        if to.is_terminal() {
+            self.open.remove(order_id);
+            self.close(order_id, to, client_id.clone());
+        }
+        Ok(OrderTransition {
+            order_id: order_id.clone(),
+            client_id,
+            from: Some(from),
+            to,
+            filled_quantity: filled,
+        })
+    }
+
+    fn close(&mut self, order_id: &str, state: OrderState, client_id: String) {
+        if self.closed.insert(order_id.to_string(), (state, client_id)).is_none() {
+            self.closed_order.push_back(order_id.to_string());
+        }
+        while self.closed_order.len() > self.closed_capacity {
+            if let Some(oldest) = self.closed_order.pop_front() {
+                self.closed.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// 보고서가 가리키는 다음 상태
+fn target_state(report: &ExecutionReport, quantity: u64, filled: u64) -> OrderState {
+    match report.exec_type {
+        ExecType::Trade if filled >= quantity => OrderState::Filled,
+        ExecType::Trade => OrderState::PartiallyFilled,
+        ExecType::Canceled => OrderState::Canceled,
+        ExecType::Expired => OrderState::Expired,
+        ExecType::Rejected => OrderState::Rejected,
+    }
+}
+
+/// 상태 전이를 검증하고 orders 테이블에 기록 (시퀀서 파이프라인과 체결 브로드캐스트가 공유)
+pub struct OrderLifecycleRecorder {
+    lifecycle: Mutex<OrderLifecycle>,
+    commit_mgr: Arc<AsyncCommitManager>,
+}
+
+impl OrderLifecycleRecorder {
+    pub fn new(commit_mgr: Arc<AsyncCommitManager>) -> Self {
+        Self {
+            lifecycle: Mutex::new(OrderLifecycle::new(CLOSED_ORDER_CAPACITY)),
+            commit_mgr,
+        }
+    }
+
+    /// 현재 상태
+    pub fn state(&self, order_id: &str) -> Option<OrderState> {
+        self.lifecycle.lock().unwrap().state(order_id)
+    }
+
+    /// 거부한 이벤트 수
+    pub fn rejected_events(&self) -> u64 {
+        self.lifecycle.lock().unwrap().rejected_events()
+    }
+
+    /// 주문 고객 ID (체결 상대 주문의 계정 조회용)
+    pub fn client_id(&self, order_id: &str) -> Option<String> {
+        self.lifecycle.lock().unwrap().client_id(order_id).map(str::to_string)
+    }
+
+    /// 백업에서 복원한 미체결 주문 등록 (DB에 이미 있으므로 다시 기록하지 않음)
+    pub fn restore_open(&self, orders: &[Order]) {
+        let mut lifecycle = self.lifecycle.lock().unwrap();
+        for order in orders {
+            lifecycle.restore_open(order);
+        }
+    }
+
+    /// 매칭 엔진으로 보내는 주문 기록 (취소 주문은 대상 주문의 보고서로 반영되므로 제외)
+    pub async fn record_new(&self, order: &Order) -> Option<OrderTransition> {
+        if order.is_cancel {
+            return None;
+        }
+        let result = self.lifecycle.lock().unwrap().on_new(order);
+        match result {
+            Ok(transition) => {
+                self.commit_mgr.enqueue_order(order_record(order)).await;
+                Some(transition)
+            }
+            Err(e) => {
+                error!("주문 상태 전이 거부: {}", e);
+                None
+            }
+        }
+    }
+
+    /// 체결 보고서 반영
+    pub async fn record_report(&self, report: &ExecutionReport) -> Option<OrderTransition> {
+        let result = self.lifecycle.lock().unwrap().on_report(report);
+        match result {
+            Ok(transition) => {
+                self.commit_mgr
+                    .enqueue_order_status(&transition.order_id, transition.filled_quantity as i64, transition.to.as_str())
+                    .await;
+                Some(transition)
+            }
+            Err(e) => {
+                error!("주문 상태 전이 거부: {} (보고서 {})", e, report.execution_id);
+                None
+            }
+        }
+    }
+}
+
+fn order_record(order: &Order) -> OrderRecord {
+    OrderRecord {
+        order_id: order.id.clone(),
+        client_id: order.client_id.clone(),
+        symbol: order.symbol.clone(),
+        side: format!("{:?}", order.side),
+        order_type: format!("{:?}", order.order_type),
+        price: match order.order_type {
+            OrderType::Limit => Some(order.price as i64),
+            OrderType::Market => None,
+        },
+        quantity: order.quantity as i64,
+        filled_quantity: 0,
+        status: OrderState::New.as_str().to_string(),
+        tag: order.tag.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching_engine::model::Side;
+
+    fn new_order(id: &str, quantity: u64) -> Order {
+        Order::new(id.to_string(), "BTC-KRW".to_string(), Side::Buy, OrderType::Limit, 1000, quantity, "test".to_string())
+    }
+
+    fn report(order_id: &str, exec_type: ExecType, quantity: u64) -> ExecutionReport {
+        ExecutionReport {
+            execution_id: format!("exec-{}", order_id),
+            order_id: order_id.to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: Side::Buy,
+            price: 1000,
+            quantity,
+            remaining_quantity: 0,
+            timestamp: 0,
+            counterparty_id: "other".to_string(),
+            is_maker: false,
+            exec_type,
+            sequence: 0,
+            tag: None,
+        }
+    }
+
+    #[test]
+    fn test_fills_drive_order_to_filled() {
+        let mut lifecycle = OrderLifecycle::new(10);
+        lifecycle.on_new(&new_order("a", 100)).unwrap();
+
+        let partial = lifecycle.on_report(&report("a", ExecType::Trade, 40)).unwrap();
+        assert_eq!((partial.from, partial.to, partial.filled_quantity), (Some(OrderState::New), OrderState::PartiallyFilled, 40));
+        let filled = lifecycle.on_report(&report("a", ExecType::Trade, 60)).unwrap();
+        assert_eq!((filled.to, filled.filled_quantity), (OrderState::Filled, 100));
+        assert_eq!(lifecycle.state("a"), Some(OrderState::Filled));
+        assert_eq!(lifecycle.open_count(), 0);
+        // 종료된 주문도 체결 상대 수수료 계산을 위해 고객 ID를 기억
+        assert_eq!(lifecycle.client_id("a"), Some("test"));
+    }
+
+    #[test]
+    fn test_restored_order_continues_from_filled_quantity() {
+        let mut lifecycle = OrderLifecycle::new(10);
+        let mut order = new_order("a", 100);
+        order.remaining_quantity = 30;
+        lifecycle.restore_open(&order);
+        assert_eq!(lifecycle.state("a"), Some(OrderState::PartiallyFilled));
+
+        let filled = lifecycle.on_report(&report("a", ExecType::Trade, 30)).unwrap();
+        assert_eq!((filled.from, filled.to, filled.filled_quantity), (Some(OrderState::PartiallyFilled), OrderState::Filled, 100));
+    }
+
+    #[test]
+    fn test_illegal_transitions_are_rejected() {
+        let mut lifecycle = OrderLifecycle::new(10);
+        lifecycle.on_new(&new_order("a", 100)).unwrap();
+        lifecycle.on_new(&new_order("b", 10)).unwrap();
+
+        // 주문 수량을 넘는 체결
+        assert!(matches!(
+            lifecycle.on_report(&report("b", ExecType::Trade, 11)),
+            Err(LifecycleError::Overfill { quantity: 10, filled: 11, .. })
+        ));
+        assert_eq!(lifecycle.state("b"), Some(OrderState::New));
+
+        // 체결 후에는 거부될 수 없음
+        lifecycle.on_report(&report("a", ExecType::Trade, 10)).unwrap();
+        assert_eq!(
+            lifecycle.on_report(&report("a", ExecType::Rejected, 0)),
+            Err(LifecycleError::IllegalTransition {
+                order_id: "a".to_string(),
+                from: OrderState::PartiallyFilled,
+                to: OrderState::Rejected,
+            })
+        );
+
+        // 종료된 주문에는 더 이상 전이 없음
+        lifecycle.on_report(&report("a", ExecType::Canceled, 0)).unwrap();
+        assert!(matches!(
+            lifecycle.on_report(&report("a", ExecType::Trade, 10)),
+            Err(LifecycleError::IllegalTransition { from: OrderState::Canceled, to: OrderState::Filled, .. })
+        ));
+        assert_eq!(lifecycle.on_new(&new_order("a", 100)), Err(LifecycleError::DuplicateOrder("a".to_string())));
+        assert_eq!(
+            lifecycle.on_report(&report("x", ExecType::Expired, 0)),
+            Err(LifecycleError::UnknownOrder("x".to_string()))
+        );
+        assert_eq!(lifecycle.rejected_events(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_recorder_persists_transitions() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        let commit_mgr = Arc::new(AsyncCommitManager::new(pool.clone()));
+        let recorder = OrderLifecycleRecorder::new(commit_mgr.clone());
+
+        recorder.record_new(&new_order("a", 100)).await.unwrap();
+        assert!(recorder.record_new(&Order::new_cancel("a".to_string())).await.is_none());
+        recorder.record_report(&report("a", ExecType::Trade, 30)).await.unwrap();
+        recorder.record_report(&report("a", ExecType::Expired, 0)).await.unwrap();
+        assert!(recorder.record_report(&report("a", ExecType::Trade, 30)).await.is_none());
+        commit_mgr.flush().await.unwrap();
+
+        let (filled, status): (i64, String) =
+            sqlx::query_as("SELECT filled_quantity, status FROM orders WHERE order_id = 'a'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!((filled, status.as_str()), (30, "Expired"));
+        assert_eq!(recorder.state("a"), Some(OrderState::Expired));
+        assert_eq!(recorder.rejected_events(), 1);
+    }
+}
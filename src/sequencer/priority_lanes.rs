@@ -0,0 +1,231 @@
+//! 시퀀서 우선순위 레인 구현
+//!
+//! 취소 주문이 대량의 신규 주문 뒤에 밀리지 않도록 취소 레인과 신규 주문 레인을
+//! 분리하고, 가중치에 따라 번갈아 꺼냅니다.
+//!
+//! 단, 같은 주문 ID에 대한 순서는 보장합니다. 대상 신규 주문이 아직 신규 레인에
+//! 대기 중인 취소는 그 주문이 전달될 때까지 보류됩니다.
+//! 레인에는 주문 자체나 주문을 감싼 값(전역 시퀀스가 붙은 주문 등)을 넣을 수 있습니다.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use log::debug;
+
+use crate::matching_engine::model::Order;
+
+/// 레인별 가중치 (한 라운드에 꺼낼 최대 개수)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LaneWeights {
+    /// 취소 레인 가중치
+    pub cancel_weight: usize,
+    /// 신규 주문 레인 가중치
+    pub new_order_weight: usize,
+}
+
+impl Default for LaneWeights {
+    fn default() -> Self {
+        Self {
+            cancel_weight: 4,
+            new_order_weight: 1,
+        }
+    }
+}
+
+/// 현재 서비스 중인 레인
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lane {
+    Cancel,
+    NewOrder,
+}
+
+/// 2단 우선순위 레인
+#[derive(Debug)]
+pub struct PriorityLanes<T: AsRef<Order> = Order> {
+    /// 취소 레인
+    cancels: VecDeque<T>,
+    /// 신규 주문 레인
+    new_orders: VecDeque<T>,
+    /// 신규 레인에 대기 중인 주문 ID
+    pending_new: HashSet<String>,
+    /// 대상 주문 전달 전까지 보류된 취소 (대상 주문 ID → 취소 목록)
+    deferred: HashMap<String, Vec<T>>,
+    /// 레인 가중치
+    weights: LaneWeights,
+    /// 현재 서비스 중인 레인
+    current: Lane,
+    /// 현재 라운드에서 꺼낸 개수
+    served: usize,
+}
+
+impl<T: AsRef<Order>> PriorityLanes<T> {
+    /// 새 우선순위 레인 생성
+    pub fn new(weights: LaneWeights) -> Self {
+        Self {
+            cancels: VecDeque::new(),
+            new_orders: VecDeque::new(),
+            pending_new: HashSet::new(),
+            deferred: HashMap::new(),
+            weights: LaneWeights {
+                cancel_weight: weights.cancel_weight.max(1),
+                new_order_weight: weights.new_order_weight.max(1),
+            },
+            current: Lane::Cancel,
+            served: 0,
+        }
+    }
+
+    /// 주문 추가 (취소 여부에 따라 레인 결정)
+    pub fn push(&mut self, item: T) {
+        let order = item.as_ref();
+        if order.is_cancel {
+            self.cancels.push_back(item);
+        } else {
+            self.pending_new.insert(order.id.clone());
+            self.new_orders.push_back(item);
+        }
+    }
+
+    /// 가중치에 따라 다음에 전달할 주문 꺼내기
+    ///
+    /// 현재 레인 → 다른 레인 → 현재 레인 순으로 시도하며, 레인을 바꿀 때마다 라운드를 새로 시작합니다.
+    /// 따라서 `None`은 두 레인 모두 전달할 주문이 없을 때만 반환합니다.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<T> {
+        for _ in 0..3 {
+            let item = match self.current {
+                Lane::Cancel if self.served < self.weights.cancel_weight => self.pop_ready_cancel(),
+                Lane::NewOrder if self.served < self.weights.new_order_weight => self.pop_new_order(),
+                _ => None,
+            };
+            if item.is_some() {
+                self.served += 1;
+                return item;
+            }
+            // 라운드를 다 썼거나 레인이 비었으면 다른 레인으로 전환 (새 라운드)
+            let other = match self.current {
+                Lane::Cancel => Lane::NewOrder,
+                Lane::NewOrder => Lane::Cancel,
+            };
+            self.switch_to(other);
+        }
+        None
+    }
+
+    /// 레인에 남은 주문이 없는지 확인 (보류된 취소 포함)
+    pub fn is_empty(&self) -> bool {
+        self.cancels.is_empty() && self.new_orders.is_empty() && self.deferred.is_empty()
+    }
+
+    /// 취소 레인 깊이 (보류된 취소 포함)
+    pub fn cancel_depth(&self) -> usize {
+        self.cancels.len() + self.deferred.values().map(|v| v.len()).sum::<usize>()
+    }
+
+    /// 신규 주문 레인 깊이
+    pub fn new_order_depth(&self) -> usize {
+        self.new_orders.len()
+    }
+
+    fn switch_to(&mut self, lane: Lane) {
+        self.current = lane;
+        self.served = 0;
+    }
+
+    /// 전달 가능한 취소 꺼내기 (대상이 신규 레인에 있으면 보류)
+    fn pop_ready_cancel(&mut self) -> Option<T> {
+        while let Some(cancel) = self.cancels.pop_front() {
+            match &cancel.as_ref().target_order_id {
+                Some(target) if self.pending_new.contains(target) => {
+                    debug!("취소 보류 - 대상 주문이 아직 신규 레인에 있음: {}", target);
+                    self.deferred.entry(target.clone()).or_default().push(cancel);
+                }
+                _ => return Some(cancel),
+            }
+        }
+        None
+    }
+
+    /// 신규 주문 꺼내기 (보류된 취소가 있으면 함께 해제)
+    fn pop_new_order(&mut self) -> Option<T> {
+        let item = self.new_orders.pop_front()?;
+        self.release_deferred(&item.as_ref().id);
+        Some(item)
+    }
+
+    /// 신규 주문 전달 후 보류된 취소를 취소 레인 앞쪽으로 복귀
+    fn release_deferred(&mut self, order_id: &str) {
+        self.pending_new.remove(order_id);
+        if let Some(cancels) = self.deferred.remove(order_id) {
+            for cancel in cancels.into_iter().rev() {
+                self.cancels.push_front(cancel);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching_engine::model::{OrderType, Side};
+
+    fn new_order(id: &str) -> Order {
+        Order::new(id.to_string(), "BTC-KRW".to_string(), Side::Buy, OrderType::Limit, 1000, 1, "test".to_string())
+    }
+
+    #[test]
+    fn test_cancels_overtake_new_order_flood() {
+        let mut lanes = PriorityLanes::new(LaneWeights { cancel_weight: 2, new_order_weight: 1 });
+
+        // 기존에 전달된 주문들에 대한 취소가 신규 주문 뒤에 도착
+        for i in 0..5 {
+            lanes.push(new_order(&format!("new{}", i)));
+        }
+        lanes.push(Order::new_cancel("old1".to_string()));
+        lanes.push(Order::new_cancel("old2".to_string()));
+
+        let first_three: Vec<String> = (0..3).map(|_| lanes.next().unwrap().id).collect();
+        assert_eq!(first_three, vec!["cancel-old1", "cancel-old2", "new0"]);
+    }
+
+    #[test]
+    fn test_weighted_draining() {
+        let mut lanes = PriorityLanes::new(LaneWeights { cancel_weight: 1, new_order_weight: 2 });
+
+        for i in 0..4 {
+            lanes.push(new_order(&format!("new{}", i)));
+            lanes.push(Order::new_cancel(format!("old{}", i)));
+        }
+
+        let order: Vec<bool> = std::iter::from_fn(|| lanes.next()).map(|o| o.is_cancel).collect();
+        assert_eq!(order, vec![true, false, false, true, false, false, true, true]);
+        assert!(lanes.is_empty());
+    }
+
+    #[test]
+    fn test_single_lane_drains_past_its_weight() {
+        let mut lanes = PriorityLanes::new(LaneWeights { cancel_weight: 1, new_order_weight: 1 });
+
+        // 신규 레인이 비어 있으면 취소 레인 라운드를 다 써도 계속 꺼냄
+        for i in 0..3 {
+            lanes.push(Order::new_cancel(format!("old{}", i)));
+        }
+
+        assert_eq!(std::iter::from_fn(|| lanes.next()).count(), 3);
+        assert!(lanes.is_empty());
+    }
+
+    #[test]
+    fn test_cancel_waits_for_its_own_new_order() {
+        let mut lanes = PriorityLanes::new(LaneWeights::default());
+
+        lanes.push(new_order("a"));
+        lanes.push(new_order("b"));
+        lanes.push(Order::new_cancel("b".to_string()));
+
+        // 취소 레인이 우선이지만 b가 전달되기 전에는 취소를 내보내지 않음
+        assert_eq!(lanes.next().unwrap().id, "a");
+        assert_eq!(lanes.cancel_depth(), 1);
+        assert_eq!(lanes.next().unwrap().id, "b");
+        assert_eq!(lanes.next().unwrap().id, "cancel-b");
+        assert!(lanes.next().is_none());
+    }
+}
@@ -0,0 +1,260 @@
+//! 계정별 주문/체결 이벤트 기록
+//!
+//! 시퀀서는 상태 전이를 적용한 체결 보고서마다 주문 고객의 이벤트 시퀀스(계정별로 1부터 빈틈없이 증가)를
+//! 붙여 비공개 WebSocket 채널로 보내고, 계정별 최근 이벤트는 메모리에, 전체는 DB(`private_events`)에 남깁니다.
+//! 재연결한 클라이언트가 마지막으로 받은 시퀀스를 보내면 그 뒤 이벤트를 메모리 버퍼에서,
+//! 버퍼가 덮지 못하는 구간은 DB에서 찾아 다시 보냅니다.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use log::warn;
+
+use crate::api::models::PrivateEvent;
+use crate::db::models::PrivateEventRecord;
+use crate::db::repository::PrivateEventRepository;
+use crate::matching_engine::model::ExecutionReport;
+
+/// 계정별로 메모리에 남기는 최근 이벤트 수
+pub const PRIVATE_EVENT_BUFFER_CAPACITY: usize = 1024;
+/// 재연결 한 번에 다시 보내는 최대 이벤트 수 (넘으면 이어서 다시 요청)
+pub const PRIVATE_REPLAY_LIMIT: usize = 10_000;
+
+#[derive(Default)]
+struct ClientEvents {
+    last_sequence: u64,
+    recent: VecDeque<PrivateEvent>,
+}
+
+/// 계정별 이벤트 시퀀스와 최근 이벤트 버퍼 (시퀀서와 WebSocket 핸들러가 공유)
+pub struct PrivateEventLog {
+    clients: Mutex<HashMap<String, ClientEvents>>,
+    capacity: usize,
+}
+
+impl Default for PrivateEventLog {
+    fn default() -> Self {
+        Self::new(PRIVATE_EVENT_BUFFER_CAPACITY)
+    }
+}
+
+impl PrivateEventLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            clients: Mutex::new(HashMap::new()),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// DB에 기록된 계정별 마지막 시퀀스에서 이어가기 (재시작 시)
+    pub fn restore_sequences(&self, sequences: impl IntoIterator<Item = (String, u64)>) {
+        let mut clients = self.clients.lock().unwrap();
+        for (client_id, sequence) in sequences {
+            let client = clients.entry(client_id).or_default();
+            client.last_sequence = client.last_sequence.max(sequence);
+        }
+    }
+
+    /// 계정의 마지막 이벤트 시퀀스 (없으면 0)
+    pub fn last_sequence(&self, client_id: &str) -> u64 {
+        self.clients
+            .lock()
+            .unwrap()
+            .get(client_id)
+            .map_or(0, |client| client.last_sequence)
+    }
+
+    /// 다음 시퀀스를 붙여 버퍼에 기록
+    pub fn record(&self, client_id: &str, execution_report: ExecutionReport, order_status: &str) -> PrivateEvent {
+        let mut clients = self.clients.lock().unwrap();
+        let client = clients.entry(client_id.to_string()).or_default();
+        client.last_sequence += 1;
+        let event = PrivateEvent {
+            client_id: client_id.to_string(),
+            sequence: client.last_sequence,
+            execution_report,
+            order_status: order_status.to_string(),
+        };
+        client.recent.push_back(event.clone());
+        while client.recent.len() > self.capacity {
+            client.recent.pop_front();
+        }
+        event
+    }
+
+    /// 버퍼에서 `after_sequence` 이후 이벤트 (버퍼가 그 구간을 모두 덮지 못하면 None)
+    pub fn buffered_after(&self, client_id: &str, after_sequence: u64) -> Option<Vec<PrivateEvent>> {
+        let clients = self.clients.lock().unwrap();
+        let Some(client) = clients.get(client_id) else {
+            return Some(Vec::new());
+        };
+        if after_sequence >= client.last_sequence {
+            return Some(Vec::new());
+        }
+        let oldest = client.recent.front()?.sequence;
+        if oldest > after_sequence + 1 {
+            return None;
+        }
+        Some(
+            client
+                .recent
+                .iter()
+                .filter(|event| event.sequence > after_sequence)
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+/// 재전송할 이벤트
+#[derive(Debug, Clone)]
+pub struct PrivateReplay {
+    /// 시퀀스순 이벤트
+    pub events: Vec<PrivateEvent>,
+    /// 요청 이후 이벤트를 빠짐없이 담았는지
+    pub complete: bool,
+}
+
+/// `after_sequence` 이후 이벤트를 버퍼, 부족하면 DB에서 구성
+pub async fn replay_after(
+    log: &PrivateEventLog,
+    repository: &PrivateEventRepository,
+    client_id: &str,
+    after_sequence: u64,
+) -> PrivateReplay {
+    let target = log.last_sequence(client_id);
+    if let Some(events) = log.buffered_after(client_id, after_sequence) {
+        return PrivateReplay { events, complete: true };
+    }
+
+    // 버퍼보다 오래된 구간은 DB에서 (아직 커밋되지 않은 최근 이벤트는 버퍼에서 이어 붙임)
+    let mut events: Vec<PrivateEvent> =
+        match repository.find_after(client_id, after_sequence as i64, PRIVATE_REPLAY_LIMIT as i64).await {
+            Ok(records) => records
+                .iter()
+                .filter_map(|record| match serde_json::from_str(&record.payload) {
+                    Ok(event) => Some(event),
+                    Err(e) => {
+                        warn!("계정 이벤트 역직렬화 실패 ({} #{}): {}", record.client_id, record.sequence, e);
+                        None
+                    }
+                })
+                .collect(),
+            Err(e) => {
+                warn!("계정 이벤트 조회 실패 ({}): {}", client_id, e);
+                Vec::new()
+            }
+        };
+    if events.len() < PRIVATE_REPLAY_LIMIT {
+        let stored_until = events.last().map_or(after_sequence, |event| event.sequence);
+        if let Some(recent) = log.buffered_after(client_id, stored_until) {
+            events.extend(recent);
+        }
+    }
+    events.truncate(PRIVATE_REPLAY_LIMIT);
+
+    let contiguous = events
+        .iter()
+        .zip(after_sequence + 1..)
+        .all(|(event, expected)| event.sequence == expected);
+    let reached = events.last().map_or(after_sequence, |event| event.sequence) >= target;
+    PrivateReplay { events, complete: contiguous && reached }
+}
+
+/// DB 기록용 행
+pub fn private_event_record(event: &PrivateEvent) -> Option<PrivateEventRecord> {
+    match serde_json::to_string(event) {
+        Ok(payload) => Some(PrivateEventRecord {
+            client_id: event.client_id.clone(),
+            sequence: event.sequence as i64,
+            payload,
+        }),
+        Err(e) => {
+            warn!("계정 이벤트 직렬화 실패 ({} #{}): {}", event.client_id, event.sequence, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching_engine::model::{ExecType, Side};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    fn report(order_id: &str) -> ExecutionReport {
+        ExecutionReport {
+            execution_id: format!("e-{}", order_id),
+            order_id: order_id.to_string(),
+            symbol: "BTC-KRW".to_string(),
+            side: Side::Buy,
+            price: 1000,
+            quantity: 1,
+            remaining_quantity: 0,
+            timestamp: 0,
+            counterparty_id: "maker".to_string(),
+            is_maker: false,
+            exec_type: ExecType::Trade,
+            sequence: 0,
+            tag: None,
+        }
+    }
+
+    fn sequences(events: &[PrivateEvent]) -> Vec<u64> {
+        events.iter().map(|event| event.sequence).collect()
+    }
+
+    #[test]
+    fn test_sequences_are_per_client_and_buffer_is_bounded() {
+        let log = PrivateEventLog::new(2);
+        log.restore_sequences(vec![("alice".to_string(), 7)]);
+
+        assert_eq!(log.record("alice", report("o1"), "Filled").sequence, 8);
+        assert_eq!(log.record("bob", report("o2"), "Filled").sequence, 1);
+        log.record("alice", report("o3"), "Filled");
+        log.record("alice", report("o4"), "Filled");
+
+        assert_eq!(sequences(&log.buffered_after("alice", 8).unwrap()), vec![9, 10]);
+        // 버퍼에서 밀려난 구간은 DB에서 찾아야 함
+        assert!(log.buffered_after("alice", 7).is_none());
+        assert!(log.buffered_after("alice", 10).unwrap().is_empty());
+        assert!(log.buffered_after("carol", 0).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replay_falls_back_to_db_for_evicted_events() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        let repository = PrivateEventRepository::new(pool.clone());
+
+        let log = PrivateEventLog::new(2);
+        for i in 1..=4 {
+            let event = log.record("alice", report(&format!("o{}", i)), "Filled");
+            // 마지막 이벤트는 아직 DB에 커밋되지 않은 상태
+            if i < 4 {
+                let record = private_event_record(&event).unwrap();
+                sqlx::query("INSERT INTO private_events (client_id, sequence, payload) VALUES (?, ?, ?)")
+                    .bind(&record.client_id)
+                    .bind(record.sequence)
+                    .bind(&record.payload)
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+            }
+        }
+
+        let replay = replay_after(&log, &repository, "alice", 1).await;
+        assert_eq!(sequences(&replay.events), vec![2, 3, 4]);
+        assert!(replay.complete);
+
+        // DB에도 버퍼에도 없는 구간이 있으면 불완전
+        sqlx::query("DELETE FROM private_events WHERE sequence = 2").execute(&pool).await.unwrap();
+        let replay = replay_after(&log, &repository, "alice", 0).await;
+        assert_eq!(sequences(&replay.events), vec![1, 3, 4]);
+        assert!(!replay.complete);
+    }
+}
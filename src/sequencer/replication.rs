@@ -0,0 +1,673 @@
+//! 엔진 상태 복제 (핫-웜 대기 인스턴스)
+//!
+//! 매칭 엔진은 같은 순서의 주문을 받으면 같은 상태가 되므로, 주(primary) 인스턴스의
+//! 시퀀서가 엔진으로 전달한 주문을 저널에 순번과 함께 기록하고 TCP로 대기(standby)
+//! 인스턴스에 스트리밍합니다. 대기 인스턴스는 받은 주문을 자신의 엔진 큐에 그대로
+//! 넣어 그림자 주문장을 유지하다가, 관리자 명령으로 주 인스턴스로 승격됩니다.
+//!
+//! 프로토콜은 줄 단위 JSON([`ReplicationFrame`])입니다.
+//! 1. 대기 → 주: `subscribe` (받고 싶은 첫 순번)
+//! 2. 주 → 대기: 저널에 남아 있는 이후 항목 `entry`, 이어서 실시간 `entry` 와 주기적 `heartbeat`
+//! 3. 요청한 순번이 저널에서 이미 밀려났으면 `error` 후 연결 종료
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+use utoipa::ToSchema;
+
+use crate::matching_engine::model::Order;
+use crate::performance::MetricsCollector;
+use crate::sequencer::backpressure::BoundedSender;
+
+/// 승격 감사 로그 엔티티 유형
+pub const PROMOTION_AUDIT_ENTITY: &str = "replication";
+/// 승격 감사 로그 이벤트
+pub const PROMOTION_AUDIT_EVENT: &str = "REPLICATION_PROMOTED";
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
+/// 복제 설정
+#[derive(Debug, Clone)]
+pub struct ReplicationConfig {
+    /// 저널 스트림 수신 포트 (주 인스턴스, None이면 대기 인스턴스 접속을 받지 않음)
+    pub listen_port: Option<u16>,
+    /// 따라갈 주 인스턴스 주소 (`host:port`, 설정 시 대기 인스턴스로 시작)
+    pub standby_of: Option<String>,
+    /// 메모리에 보관하는 저널 항목 수 (이보다 뒤처진 대기 인스턴스는 재동기화 불가)
+    pub journal_capacity: usize,
+    /// 하트비트 주기
+    pub heartbeat_interval: Duration,
+    /// 주 인스턴스 연결이 끊겼을 때 재연결 대기 시간
+    pub reconnect_delay: Duration,
+}
+
+impl Default for ReplicationConfig {
+    fn default() -> Self {
+        Self {
+            listen_port: None,
+            standby_of: None,
+            journal_capacity: 100_000,
+            heartbeat_interval: Duration::from_secs(1),
+            reconnect_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+/// 저널 항목 (엔진으로 전달된 주문 한 건)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// 순번 (1부터 연속)
+    pub seq: u64,
+    /// 주 인스턴스에서 기록한 시각 (ms)
+    pub timestamp: u64,
+    pub order: Order,
+}
+
+/// 복제 스트림 프레임 (줄 단위 JSON)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum ReplicationFrame {
+    /// 대기 → 주: `from_seq` 부터 스트리밍 요청
+    Subscribe { from_seq: u64 },
+    /// 주 → 대기: 저널 항목
+    Entry { entry: JournalEntry },
+    /// 주 → 대기: 주 인스턴스의 최신 순번
+    Heartbeat { head_seq: u64, timestamp: u64 },
+    /// 주 → 대기: 스트리밍 불가 (연결 종료)
+    Error { message: String },
+}
+
+impl ReplicationFrame {
+    fn to_line(&self) -> String {
+        let mut line = serde_json::to_string(self).expect("복제 프레임 직렬화 실패");
+        line.push('\n');
+        line
+    }
+}
+
+struct JournalInner {
+    entries: VecDeque<JournalEntry>,
+    head_seq: u64,
+}
+
+/// 복제 저널 (최근 항목 링 버퍼 + 실시간 구독)
+pub struct ReplicationJournal {
+    capacity: usize,
+    inner: Mutex<JournalInner>,
+    live_tx: broadcast::Sender<JournalEntry>,
+}
+
+impl ReplicationJournal {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        let (live_tx, _) = broadcast::channel(capacity);
+        Self {
+            capacity,
+            inner: Mutex::new(JournalInner { entries: VecDeque::new(), head_seq: 0 }),
+            live_tx,
+        }
+    }
+
+    /// 주문 기록, 부여된 순번 반환
+    pub fn append(&self, order: &Order) -> u64 {
+        let mut inner = self.inner.lock().unwrap();
+        inner.head_seq += 1;
+        let entry = JournalEntry { seq: inner.head_seq, timestamp: now_millis(), order: order.clone() };
+        if inner.entries.len() >= self.capacity {
+            inner.entries.pop_front();
+        }
+        inner.entries.push_back(entry.clone());
+        // 잠금 안에서 보내야 구독자가 받는 순서가 순번 순서와 같음
+        let _ = self.live_tx.send(entry);
+        inner.head_seq
+    }
+
+    /// 마지막으로 기록된 순번 (없으면 0)
+    pub fn head_seq(&self) -> u64 {
+        self.inner.lock().unwrap().head_seq
+    }
+
+    /// `from_seq` 이후 보관 중인 항목, 이미 밀려났으면 가장 오래된 보관 순번을 Err로 반환
+    pub fn entries_from(&self, from_seq: u64) -> Result<Vec<JournalEntry>, u64> {
+        let inner = self.inner.lock().unwrap();
+        let oldest = inner.entries.front().map_or(inner.head_seq + 1, |entry| entry.seq);
+        if from_seq < oldest && from_seq <= inner.head_seq {
+            return Err(oldest);
+        }
+        Ok(inner.entries.iter().filter(|entry| entry.seq >= from_seq).cloned().collect())
+    }
+
+    /// 실시간 항목 구독
+    pub fn subscribe(&self) -> broadcast::Receiver<JournalEntry> {
+        self.live_tx.subscribe()
+    }
+
+    /// 승격 시 대기 인스턴스가 적용한 순번부터 이어서 기록
+    fn resume_from(&self, seq: u64) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.entries.clear();
+        inner.head_seq = seq;
+    }
+}
+
+/// 복제 역할
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReplicationRole {
+    Primary,
+    Standby,
+}
+
+/// 복제 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplicationError {
+    /// 이미 주 인스턴스 (승격 불가)
+    AlreadyPrimary,
+}
+
+impl fmt::Display for ReplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplicationError::AlreadyPrimary => write!(f, "이미 주(primary) 인스턴스입니다"),
+        }
+    }
+}
+
+impl std::error::Error for ReplicationError {}
+
+/// 복제 상태 조회 결과
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReplicationStatus {
+    pub role: ReplicationRole,
+    /// 따라가는 주 인스턴스 주소 (대기 인스턴스였던 경우)
+    pub primary_addr: Option<String>,
+    /// 주 인스턴스 연결 여부 (대기 인스턴스)
+    pub connected: bool,
+    /// 엔진에 적용한 마지막 순번 (주 인스턴스는 저널 최신 순번)
+    pub applied_seq: u64,
+    /// 주 인스턴스의 최신 순번
+    pub primary_head_seq: u64,
+    /// 복제 지연 (항목 수)
+    pub lag_entries: u64,
+    /// 복제 지연 (ms, 주 인스턴스 시각 기준)
+    pub lag_ms: u64,
+    /// 주 인스턴스에서 마지막으로 프레임을 받은 뒤 경과 시간 (ms)
+    pub last_contact_age_ms: Option<u64>,
+    /// 승격 시각 (ms)
+    pub promoted_at: Option<u64>,
+    /// 마지막 복제 오류
+    pub last_error: Option<String>,
+}
+
+/// 적용 위치 (승격과 적용이 겹치지 않도록 함께 잠금)
+#[derive(Default)]
+struct AppliedPosition {
+    seq: u64,
+    /// 마지막 적용 항목의 주 인스턴스 기록 시각
+    timestamp: u64,
+}
+
+/// 인스턴스 복제 상태 (역할, 적용 위치, 지연 지표)
+pub struct ReplicationState {
+    journal: Arc<ReplicationJournal>,
+    role_tx: watch::Sender<ReplicationRole>,
+    primary_addr: Option<String>,
+    applied: Mutex<AppliedPosition>,
+    primary_head_seq: AtomicU64,
+    /// 주 인스턴스가 보낸 최신 시각 (주 인스턴스 시계)
+    primary_timestamp: AtomicU64,
+    /// 마지막 프레임 수신 시각 (로컬 시계, 0이면 없음)
+    last_contact: AtomicU64,
+    connected: AtomicBool,
+    promoted_at: AtomicU64,
+    last_error: Mutex<Option<String>>,
+}
+
+impl ReplicationState {
+    /// 주 인스턴스 상태
+    pub fn primary(journal: Arc<ReplicationJournal>) -> Self {
+        Self::new(journal, ReplicationRole::Primary, None)
+    }
+
+    /// `primary_addr` 를 따라가는 대기 인스턴스 상태
+    pub fn standby(journal: Arc<ReplicationJournal>, primary_addr: impl Into<String>) -> Self {
+        Self::new(journal, ReplicationRole::Standby, Some(primary_addr.into()))
+    }
+
+    fn new(journal: Arc<ReplicationJournal>, role: ReplicationRole, primary_addr: Option<String>) -> Self {
+        Self {
+            journal,
+            role_tx: watch::Sender::new(role),
+            primary_addr,
+            applied: Mutex::new(AppliedPosition::default()),
+            primary_head_seq: AtomicU64::new(0),
+            primary_timestamp: AtomicU64::new(0),
+            last_contact: AtomicU64::new(0),
+            connected: AtomicBool::new(false),
+            promoted_at: AtomicU64::new(0),
+            last_error: Mutex::new(None),
+        }
+    }
+
+    pub fn role(&self) -> ReplicationRole {
+        *self.role_tx.borrow()
+    }
+
+    pub fn is_standby(&self) -> bool {
+        self.role() == ReplicationRole::Standby
+    }
+
+    /// 복제 저널
+    pub fn journal(&self) -> &Arc<ReplicationJournal> {
+        &self.journal
+    }
+
+    /// 시퀀서가 엔진으로 전달한 주문 기록 (주 인스턴스만)
+    pub fn record_sequenced(&self, order: &Order) {
+        if !self.is_standby() {
+            self.journal.append(order);
+        }
+    }
+
+    /// 대기 인스턴스를 주 인스턴스로 승격
+    ///
+    /// 복제를 멈추고, 이후 시퀀서가 기록하는 저널은 마지막으로 적용한 순번에서 이어집니다.
+    pub fn promote(&self) -> Result<ReplicationStatus, ReplicationError> {
+        {
+            let applied = self.applied.lock().unwrap();
+            if !self.is_standby() {
+                return Err(ReplicationError::AlreadyPrimary);
+            }
+            self.role_tx.send_replace(ReplicationRole::Primary);
+            self.journal.resume_from(applied.seq);
+        }
+        self.connected.store(false, Ordering::Relaxed);
+        self.promoted_at.store(now_millis(), Ordering::Relaxed);
+        info!("주(primary) 인스턴스로 승격: 순번 {}부터 이어서 기록", self.journal.head_seq());
+        Ok(self.status())
+    }
+
+    /// DB 백업을 복원한 뒤 백업 시점 저널 순번으로 맞춤 (엔진 시작 전)
+    ///
+    /// 대기 인스턴스는 `seq` 다음 순번부터 구독해 백업 이후 주문을 재생하고,
+    /// 주 인스턴스는 `seq`에서 이어서 기록합니다.
+    pub fn resume_at(&self, seq: u64) {
+        let mut applied = self.applied.lock().unwrap();
+        applied.seq = seq;
+        self.journal.resume_from(seq);
+    }
+
+    /// 복제 항목을 엔진 큐로 적용 (대기 중일 때만)
+    ///
+    /// 승격과 동시에 호출돼도 승격 이후 항목이 엔진에 들어가지 않도록 적용 위치를 잠근 채 보냅니다.
+    fn apply(&self, entry: JournalEntry, engine_tx: &BoundedSender<Order>) -> Result<bool, String> {
+        let mut applied = self.applied.lock().unwrap();
+        if !self.is_standby() {
+            return Ok(false);
+        }
+        if entry.seq <= applied.seq {
+            return Ok(true);
+        }
+        if entry.seq != applied.seq + 1 {
+            return Err(format!("복제 순번 누락: {} 다음에 {} 수신", applied.seq, entry.seq));
+        }
+        engine_tx.send(entry.order).map_err(|e| e.to_string())?;
+        applied.seq = entry.seq;
+        applied.timestamp = entry.timestamp;
+        drop(applied);
+
+        self.primary_head_seq.fetch_max(entry.seq, Ordering::Relaxed);
+        self.primary_timestamp.fetch_max(entry.timestamp, Ordering::Relaxed);
+        Ok(true)
+    }
+
+    fn record_heartbeat(&self, head_seq: u64, timestamp: u64) {
+        self.primary_head_seq.fetch_max(head_seq, Ordering::Relaxed);
+        self.primary_timestamp.fetch_max(timestamp, Ordering::Relaxed);
+    }
+
+    fn record_contact(&self) {
+        self.last_contact.store(now_millis(), Ordering::Relaxed);
+    }
+
+    fn record_error(&self, message: String) {
+        *self.last_error.lock().unwrap() = Some(message);
+    }
+
+    /// 현재 복제 상태
+    pub fn status(&self) -> ReplicationStatus {
+        let role = self.role();
+        let (applied_seq, applied_timestamp) = match role {
+            ReplicationRole::Primary => (self.journal.head_seq(), 0),
+            ReplicationRole::Standby => {
+                let applied = self.applied.lock().unwrap();
+                (applied.seq, applied.timestamp)
+            }
+        };
+        let primary_head_seq = match role {
+            ReplicationRole::Primary => applied_seq,
+            ReplicationRole::Standby => self.primary_head_seq.load(Ordering::Relaxed).max(applied_seq),
+        };
+        let lag_entries = primary_head_seq - applied_seq;
+        let lag_ms = if lag_entries > 0 {
+            self.primary_timestamp.load(Ordering::Relaxed).saturating_sub(applied_timestamp)
+        } else {
+            0
+        };
+        let last_contact = self.last_contact.load(Ordering::Relaxed);
+        let promoted_at = self.promoted_at.load(Ordering::Relaxed);
+
+        ReplicationStatus {
+            role,
+            primary_addr: self.primary_addr.clone(),
+            connected: self.connected.load(Ordering::Relaxed),
+            applied_seq,
+            primary_head_seq,
+            lag_entries,
+            lag_ms,
+            last_contact_age_ms: (last_contact > 0).then(|| now_millis().saturating_sub(last_contact)),
+            promoted_at: (promoted_at > 0).then_some(promoted_at),
+            last_error: self.last_error.lock().unwrap().clone(),
+        }
+    }
+
+    /// 복제 지표를 MetricsCollector 게이지로 발행
+    pub async fn publish(&self, collector: &MetricsCollector) {
+        let status = self.status();
+        let is_standby = (status.role == ReplicationRole::Standby) as u64;
+        collector.set_gauge("replication.standby", is_standby).await;
+        collector.set_gauge("replication.connected", status.connected as u64).await;
+        collector.set_gauge("replication.applied_seq", status.applied_seq).await;
+        collector.set_gauge("replication.primary_head_seq", status.primary_head_seq).await;
+        collector.set_gauge("replication.lag.entries", status.lag_entries).await;
+        collector.set_gauge("replication.lag.ms", status.lag_ms).await;
+    }
+}
+
+/// 주 인스턴스의 저널 스트리밍 서버
+pub struct ReplicationServer {
+    journal: Arc<ReplicationJournal>,
+    heartbeat_interval: Duration,
+}
+
+impl ReplicationServer {
+    pub fn new(journal: Arc<ReplicationJournal>, heartbeat_interval: Duration) -> Self {
+        Self { journal, heartbeat_interval }
+    }
+
+    /// 대기 인스턴스 접속 수락 (연결마다 태스크 하나)
+    pub async fn run(self: Arc<Self>, listener: TcpListener) {
+        loop {
+            match listener.accept().await {
+                Ok((stream, peer)) => {
+                    info!("복제: 대기 인스턴스 접속 - {}", peer);
+                    let server = self.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = server.serve_standby(stream).await {
+                            warn!("복제: 대기 인스턴스 {} 스트리밍 중단 - {}", peer, e);
+                        }
+                        info!("복제: 대기 인스턴스 연결 종료 - {}", peer);
+                    });
+                }
+                Err(e) => error!("복제: 접속 수락 실패 - {}", e),
+            }
+        }
+    }
+
+    async fn serve_standby(&self, stream: TcpStream) -> std::io::Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+
+        let from_seq = match lines.next_line().await?.map(|line| serde_json::from_str(&line)) {
+            Some(Ok(ReplicationFrame::Subscribe { from_seq })) => from_seq.max(1),
+            _ => {
+                let frame = ReplicationFrame::Error { message: "subscribe 프레임이 필요합니다".to_string() };
+                return writer.write_all(frame.to_line().as_bytes()).await;
+            }
+        };
+
+        // 보관 항목을 읽기 전에 구독해야 그 사이에 기록된 항목을 놓치지 않음
+        let mut live_rx = self.journal.subscribe();
+        let backlog = match self.journal.entries_from(from_seq) {
+            Ok(backlog) => backlog,
+            Err(oldest) => {
+                let frame = ReplicationFrame::Error {
+                    message: format!("순번 {}은(는) 저널에서 밀려났습니다 (보관 중인 최소 순번 {})", from_seq, oldest),
+                };
+                return writer.write_all(frame.to_line().as_bytes()).await;
+            }
+        };
+
+        let mut last_sent = from_seq - 1;
+        for entry in backlog {
+            last_sent = entry.seq;
+            writer.write_all(ReplicationFrame::Entry { entry }.to_line().as_bytes()).await?;
+        }
+
+        let mut heartbeat = tokio::time::interval(self.heartbeat_interval);
+        loop {
+            tokio::select! {
+                received = live_rx.recv() => match received {
+                    Ok(entry) if entry.seq <= last_sent => {}
+                    Ok(entry) => {
+                        last_sent = entry.seq;
+                        writer.write_all(ReplicationFrame::Entry { entry }.to_line().as_bytes()).await?;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        let frame = ReplicationFrame::Error {
+                            message: format!("대기 인스턴스가 느려 {}건을 전달하지 못했습니다", skipped),
+                        };
+                        return writer.write_all(frame.to_line().as_bytes()).await;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return Ok(()),
+                },
+                _ = heartbeat.tick() => {
+                    let frame = ReplicationFrame::Heartbeat { head_seq: self.journal.head_seq(), timestamp: now_millis() };
+                    writer.write_all(frame.to_line().as_bytes()).await?;
+                }
+                line = lines.next_line() => {
+                    // 대기 인스턴스는 구독 이후 보내는 것이 없으므로 EOF/오류만 처리
+                    if !matches!(line, Ok(Some(_))) {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 대기 인스턴스의 복제 수신기
+///
+/// 주 인스턴스 저널을 받아 엔진 큐에 넣고, 연결이 끊기면 마지막 적용 순번 다음부터 다시 구독합니다.
+/// 승격되면 종료합니다.
+pub struct StandbyReplicator {
+    state: Arc<ReplicationState>,
+    engine_tx: BoundedSender<Order>,
+    reconnect_delay: Duration,
+}
+
+impl StandbyReplicator {
+    pub fn new(state: Arc<ReplicationState>, engine_tx: BoundedSender<Order>, reconnect_delay: Duration) -> Self {
+        Self { state, engine_tx, reconnect_delay }
+    }
+
+    pub async fn run(self) {
+        let Some(primary_addr) = self.state.primary_addr.clone() else {
+            error!("복제: 주 인스턴스 주소가 없어 대기 복제를 시작하지 않습니다");
+            return;
+        };
+        let mut role_rx = self.state.role_tx.subscribe();
+
+        while self.state.is_standby() {
+            let result = tokio::select! {
+                result = self.follow(&primary_addr) => result,
+                _ = role_rx.wait_for(|role| *role == ReplicationRole::Primary) => break,
+            };
+            self.state.connected.store(false, Ordering::Relaxed);
+            match result {
+                Ok(()) => warn!("복제: 주 인스턴스 {} 연결 종료, 재연결 대기", primary_addr),
+                Err(e) => {
+                    warn!("복제: 주 인스턴스 {} 복제 실패 - {}", primary_addr, e);
+                    self.state.record_error(e);
+                }
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(self.reconnect_delay) => {}
+                _ = role_rx.wait_for(|role| *role == ReplicationRole::Primary) => break,
+            }
+        }
+        info!("복제: 승격되어 대기 복제를 종료합니다");
+    }
+
+    async fn follow(&self, primary_addr: &str) -> Result<(), String> {
+        let stream = TcpStream::connect(primary_addr).await.map_err(|e| e.to_string())?;
+        let (reader, mut writer) = stream.into_split();
+        let from_seq = self.state.applied.lock().unwrap().seq + 1;
+        writer
+            .write_all(ReplicationFrame::Subscribe { from_seq }.to_line().as_bytes())
+            .await
+            .map_err(|e| e.to_string())?;
+        self.state.connected.store(true, Ordering::Relaxed);
+        info!("복제: 주 인스턴스 {} 구독 (순번 {}부터)", primary_addr, from_seq);
+
+        let mut lines = BufReader::new(reader).lines();
+        while let Some(line) = lines.next_line().await.map_err(|e| e.to_string())? {
+            let frame: ReplicationFrame = serde_json::from_str(&line).map_err(|e| format!("잘못된 복제 프레임: {}", e))?;
+            self.state.record_contact();
+            match frame {
+                ReplicationFrame::Entry { entry } => {
+                    if !self.state.apply(entry, &self.engine_tx)? {
+                        return Ok(());
+                    }
+                }
+                ReplicationFrame::Heartbeat { head_seq, timestamp } => self.state.record_heartbeat(head_seq, timestamp),
+                ReplicationFrame::Error { message } => return Err(message),
+                ReplicationFrame::Subscribe { .. } => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching_engine::model::{OrderType, Side};
+    use crate::sequencer::backpressure::{bounded_queue, OverflowPolicy};
+
+    fn order(id: &str) -> Order {
+        Order::new(id.to_string(), "BTC-KRW".to_string(), Side::Buy, OrderType::Limit, 100, 1, "client".to_string())
+    }
+
+    async fn wait_until(mut condition: impl FnMut() -> bool) {
+        for _ in 0..200 {
+            if condition() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("조건을 기다리다 시간 초과");
+    }
+
+    #[test]
+    fn test_journal_keeps_recent_entries() {
+        let journal = ReplicationJournal::new(2);
+        for id in ["a", "b", "c"] {
+            journal.append(&order(id));
+        }
+
+        assert_eq!(journal.head_seq(), 3);
+        assert_eq!(journal.entries_from(1).unwrap_err(), 2);
+        let entries = journal.entries_from(2).unwrap();
+        assert_eq!(entries.iter().map(|entry| entry.order.id.as_str()).collect::<Vec<_>>(), ["b", "c"]);
+        assert!(journal.entries_from(4).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_standby_follows_primary_and_promotes() {
+        let primary_journal = Arc::new(ReplicationJournal::new(100));
+        let primary = ReplicationState::primary(primary_journal.clone());
+        primary.record_sequenced(&order("before-connect"));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let server = Arc::new(ReplicationServer::new(primary_journal.clone(), Duration::from_millis(20)));
+        tokio::spawn(server.run(listener));
+
+        let standby = Arc::new(ReplicationState::standby(Arc::new(ReplicationJournal::new(100)), addr));
+        let (engine_tx, engine_rx) = bounded_queue("engine", 100, OverflowPolicy::Block);
+        tokio::spawn(StandbyReplicator::new(standby.clone(), engine_tx, Duration::from_millis(20)).run());
+
+        wait_until(|| standby.status().applied_seq == 1).await;
+        primary.record_sequenced(&order("after-connect"));
+        wait_until(|| standby.status().applied_seq == 2).await;
+
+        let received: Vec<String> = engine_rx.try_iter().map(|order| order.id).collect();
+        assert_eq!(received, ["before-connect", "after-connect"]);
+        wait_until(|| standby.status().last_contact_age_ms.is_some()).await;
+        assert_eq!(standby.status().lag_entries, 0);
+
+        // 대기 중에는 시퀀서 기록을 저널에 남기지 않음
+        standby.record_sequenced(&order("ignored"));
+        assert_eq!(standby.journal().head_seq(), 0);
+
+        let status = standby.promote().unwrap();
+        assert_eq!(status.role, ReplicationRole::Primary);
+        assert_eq!(status.applied_seq, 2);
+        assert_eq!(standby.promote().unwrap_err(), ReplicationError::AlreadyPrimary);
+
+        // 승격 후에는 주 인스턴스 항목을 적용하지 않고, 자신의 저널을 이어서 기록
+        primary.record_sequenced(&order("after-promote"));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(engine_rx.try_recv().is_err());
+        standby.record_sequenced(&order("new-primary"));
+        assert_eq!(standby.journal().head_seq(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_lag_reported_from_heartbeat() {
+        let standby = ReplicationState::standby(Arc::new(ReplicationJournal::new(10)), "127.0.0.1:1");
+        let (engine_tx, _engine_rx) = bounded_queue("engine", 10, OverflowPolicy::Block);
+        let entry = JournalEntry { seq: 1, timestamp: 1_000, order: order("a") };
+
+        assert!(standby.apply(entry, &engine_tx).unwrap());
+        standby.record_heartbeat(5, 1_400);
+        let status = standby.status();
+        assert_eq!(status.lag_entries, 4);
+        assert_eq!(status.lag_ms, 400);
+
+        let gap = JournalEntry { seq: 3, timestamp: 1_500, order: order("c") };
+        assert!(standby.apply(gap, &engine_tx).is_err());
+    }
+
+    #[test]
+    fn test_resume_at_backup_sequence() {
+        let standby = ReplicationState::standby(Arc::new(ReplicationJournal::new(10)), "127.0.0.1:1");
+        let (engine_tx, engine_rx) = bounded_queue("engine", 10, OverflowPolicy::Block);
+        standby.resume_at(7);
+
+        // 백업에 반영된 순번은 건너뛰고 다음 순번부터 적용
+        let replayed = JournalEntry { seq: 7, timestamp: 1_000, order: order("in-backup") };
+        assert!(standby.apply(replayed, &engine_tx).unwrap());
+        let next = JournalEntry { seq: 8, timestamp: 1_100, order: order("after-backup") };
+        assert!(standby.apply(next, &engine_tx).unwrap());
+        assert_eq!(engine_rx.try_iter().map(|order| order.id).collect::<Vec<_>>(), ["after-backup"]);
+
+        // 주 인스턴스는 백업 순번에서 이어서 기록
+        let primary = ReplicationState::primary(Arc::new(ReplicationJournal::new(10)));
+        primary.resume_at(7);
+        primary.record_sequenced(&order("a"));
+        assert_eq!(primary.journal().head_seq(), 8);
+    }
+}
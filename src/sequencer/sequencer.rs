@@ -1,32 +1,59 @@
 //! 주문 시퀀서 구현
 //!
 //! 이 모듈은 주문의 순서를 보장하고 매칭 엔진으로 전달하는 역할을 담당합니다.
-//! FIFO(First-In-First-Out) 방식으로 주문을 처리하여 공정한 거래 환경을 제공합니다.
+//! 같은 심볼 안에서는 FIFO(First-In-First-Out) 순서를 지키고, 심볼끼리는 별도 파이프라인으로 동시에 전달합니다.
 
-use std::sync::mpsc::{Receiver, Sender};
+use std::sync::mpsc::{RecvTimeoutError, TryRecvError};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use log::{debug, info, warn, error};
 use uuid::Uuid;
-use sqlx::sqlite::SqlitePool;
 
-use crate::matching_engine::model::{Order, ExecutionReport, OrderBookSnapshot};
+use crate::matching_engine::model::{Order, ExecutionReport, ExecType, Side};
 use crate::api::models::WebSocketMessage;
-use crate::mdp::model::{MarketStatistics, CandlestickData};
 use crate::mdp::MarketDataPublisher;
 use crate::db::models::ExecutionRecord;
-use crate::db::repository::ExecutionRepository;
 use crate::db::AsyncCommitManager;
-use crate::mq::{RedisStreamsProducer, KafkaProducer, RabbitMQProducer};
+use crate::fee::{ExecutionFees, FeeEngine};
+use crate::risk::RiskManager;
+use crate::mq::{RedisStreamsProducer, KafkaProducer, RabbitMQProducer, WebSocketNotificationMessage, BackupMessage, BackupMessageBuilder, MQType, PublishOutcome, PublishRetry};
+use crate::sequencer::backpressure::{bounded_queue, BoundedReceiver, BoundedSender, OverflowPolicy, SequencerQueueMetrics};
+use crate::sequencer::priority_lanes::LaneWeights;
+use crate::sequencer::global_sequence::GlobalSequence;
+use crate::sequencer::order_lifecycle::OrderLifecycleRecorder;
+use crate::sequencer::private_events::{private_event_record, PrivateEventLog};
+use crate::sequencer::symbol_lanes::{PipelineContext, SequenceAudit, SymbolPipelines, SEQUENCE_AUDIT_CAPACITY};
+use crate::sequencer::replication::ReplicationState;
+use crate::sequencer::throttle::OrderThrottle;
+use crate::sequencer::wait_strategy::{WaitStrategy, WaitStrategyConfig, WaitStrategyMetrics};
+
+/// 한 번에 수집하여 심볼 파이프라인으로 분배하는 최대 신규 주문 수
+const LANE_BATCH_SIZE: usize = 256;
+
+/// MDP 시장 데이터 큐 기본 용량
+const DEFAULT_MARKET_DATA_CAPACITY: usize = 10_000;
+
+/// MDP 시장 데이터 큐 (느린 MDP로 체결 처리가 막히지 않도록 가득 차면 버림)
+fn market_data_queue(capacity: usize) -> (BoundedSender<ExecutionReport>, BoundedReceiver<ExecutionReport>) {
+    bounded_queue("market_data", capacity, OverflowPolicy::DropNewest)
+}
 
 /// 주문 시퀀서
 pub struct OrderSequencer {
-    /// 주문 수신 채널 (API에서 받음)
-    order_rx: Receiver<Order>,
-    /// 매칭 엔진으로의 주문 전송 채널
-    engine_tx: Sender<Order>,
-    /// 체결 보고서 수신 채널 (매칭 엔진에서 받음)
-    exec_rx: Receiver<ExecutionReport>,
+    /// 주문 수신 큐 (API에서 받음, `run()`이 가져감)
+    order_rx: Option<BoundedReceiver<Order>>,
+    /// 취소 전용 수신 큐 (없으면 주문 큐의 취소만 취소 레인으로 분리)
+    cancel_rx: Option<BoundedReceiver<Order>>,
+    /// 취소/신규 레인 가중치
+    lane_weights: LaneWeights,
+    /// 매칭 엔진으로의 주문 전송 큐
+    engine_tx: BoundedSender<Order>,
+    /// 체결 보고서 수신 큐 (매칭 엔진에서 받음, `run()`이 가져감)
+    exec_rx: Option<BoundedReceiver<ExecutionReport>>,
+    /// MDP 시장 데이터 큐 (가득 차면 시장 데이터부터 버림, `run()`이 가져감)
+    market_data_queue: Option<(BoundedSender<ExecutionReport>, BoundedReceiver<ExecutionReport>)>,
+    /// 큐 깊이 게이지
+    queue_metrics: SequencerQueueMetrics,
     /// WebSocket 메시지 브로드캐스트 채널
     broadcast_tx: tokio::sync::broadcast::Sender<WebSocketMessage>,
     /// 시장 데이터 발행자 참조
@@ -39,18 +66,41 @@ pub struct OrderSequencer {
     kafka_producer: Option<Arc<KafkaProducer>>,
     /// RabbitMQ Producer (WebSocket 알림 발행)
     rabbitmq_producer: Option<Arc<RabbitMQProducer>>,
+    /// 복제 상태 (주 인스턴스는 저널 기록, 대기 인스턴스는 외부 발행 생략)
+    replication: Option<Arc<ReplicationState>>,
     /// 시퀀서 ID (로깅용)
     sequencer_id: String,
     /// 처리된 주문 수
     processed_orders: Arc<Mutex<u64>>,
+    /// 전역 시퀀스 감사 기록
+    audit: Arc<SequenceAudit>,
+    /// 전역 시퀀스 번호 (API와 공유, 주문/체결에 부여)
+    global_sequence: Arc<GlobalSequence>,
+    /// 주문 상태 기계 (전이 검증, orders 테이블 기록)
+    lifecycle: Arc<OrderLifecycleRecorder>,
+    /// 계정별 이벤트 기록 (비공개 WebSocket 채널, 재연결 재전송)
+    private_events: Option<Arc<PrivateEventLog>>,
+    /// 계정별 수수료 등급 (없으면 수수료 0)
+    fee_engine: Option<Arc<FeeEngine>>,
+    /// 계정별 순포지션 (사전 리스크 한도 확인용)
+    risk_manager: Option<Arc<RiskManager>>,
+    /// 취소 비율 과다 계정 주문 속도 제한 (주문/취소 수 집계, 시작/해제 알림)
+    order_throttle: Option<Arc<OrderThrottle>>,
+    /// MQ 발행 재시도 정책 (없으면 한 번만 시도)
+    publish_retry: Option<Arc<PublishRetry>>,
+    /// 주문 수신 대기 전략
+    order_wait: WaitStrategy,
+    /// 체결 보고서 수신 대기 전략
+    exec_wait: WaitStrategy,
 }
 
 impl OrderSequencer {
     /// 새 시퀀서 생성
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        order_rx: Receiver<Order>,
-        engine_tx: Sender<Order>,
-        exec_rx: Receiver<ExecutionReport>,
+        order_rx: BoundedReceiver<Order>,
+        engine_tx: BoundedSender<Order>,
+        exec_rx: BoundedReceiver<ExecutionReport>,
         broadcast_tx: tokio::sync::broadcast::Sender<WebSocketMessage>,
         mdp: Arc<Mutex<MarketDataPublisher>>,
         async_commit_mgr: Arc<AsyncCommitManager>,
@@ -58,93 +108,371 @@ impl OrderSequencer {
         kafka_producer: Option<Arc<KafkaProducer>>,
         rabbitmq_producer: Option<Arc<RabbitMQProducer>>,
     ) -> Self {
+        let mut queue_metrics = SequencerQueueMetrics::new();
+        queue_metrics.register(order_rx.gauge());
+        queue_metrics.register(engine_tx.gauge());
+        queue_metrics.register(exec_rx.gauge());
+        let market_data_queue = market_data_queue(DEFAULT_MARKET_DATA_CAPACITY);
+        queue_metrics.register(market_data_queue.0.gauge());
+        let lifecycle = Arc::new(OrderLifecycleRecorder::new(async_commit_mgr.clone()));
+
         Self {
-            order_rx,
+            order_rx: Some(order_rx),
+            cancel_rx: None,
+            lane_weights: LaneWeights::default(),
             engine_tx,
-            exec_rx,
+            exec_rx: Some(exec_rx),
+            market_data_queue: Some(market_data_queue),
+            queue_metrics,
             broadcast_tx,
             mdp,
             async_commit_mgr,
             redis_producer,
             kafka_producer,
             rabbitmq_producer,
+            replication: None,
             sequencer_id: Uuid::new_v4().to_string(),
             processed_orders: Arc::new(Mutex::new(0)),
+            audit: Arc::new(SequenceAudit::new(SEQUENCE_AUDIT_CAPACITY)),
+            global_sequence: Arc::new(GlobalSequence::new()),
+            lifecycle,
+            private_events: None,
+            fee_engine: None,
+            risk_manager: None,
+            order_throttle: None,
+            publish_retry: None,
+            order_wait: WaitStrategy::blocking("orders"),
+            exec_wait: WaitStrategy::blocking("executions"),
         }
     }
 
+    /// MDP 시장 데이터 큐 용량 설정
+    pub fn with_market_data_capacity(mut self, capacity: usize) -> Self {
+        let queue = market_data_queue(capacity);
+        self.queue_metrics.register(queue.0.gauge());
+        self.market_data_queue = Some(queue);
+        self
+    }
+
+    /// 취소 전용 레인 설정
+    pub fn with_cancel_lane(mut self, cancel_rx: BoundedReceiver<Order>, lane_weights: LaneWeights) -> Self {
+        self.queue_metrics.register(cancel_rx.gauge());
+        self.cancel_rx = Some(cancel_rx);
+        self.lane_weights = lane_weights;
+        self
+    }
+
+    /// 엔진 상태 복제 설정
+    pub fn with_replication(mut self, replication: Arc<ReplicationState>) -> Self {
+        self.replication = Some(replication);
+        self
+    }
+
+    /// 전역 시퀀스 번호 발급기 설정 (API가 주문을 큐에 넣을 때 같은 발급기로 번호를 붙임)
+    pub fn with_global_sequence(mut self, global_sequence: Arc<GlobalSequence>) -> Self {
+        self.global_sequence = global_sequence;
+        self
+    }
+
+    /// 계정별 이벤트 기록 설정
+    pub fn with_private_events(mut self, private_events: Arc<PrivateEventLog>) -> Self {
+        self.private_events = Some(private_events);
+        self
+    }
+
+    /// 수수료 등급 설정 (체결 기록 시 양쪽 계정 등급 요율로 수수료 계산)
+    pub fn with_fee_engine(mut self, fee_engine: Arc<FeeEngine>) -> Self {
+        self.fee_engine = Some(fee_engine);
+        self
+    }
+
+    /// 사전 리스크 한도 설정 (체결마다 계정 순포지션 갱신)
+    pub fn with_risk_manager(mut self, risk_manager: Arc<RiskManager>) -> Self {
+        self.risk_manager = Some(risk_manager);
+        self
+    }
+
+    /// 주문 속도 제한 설정 (매칭 엔진과 같은 인스턴스를 공유해야 제한이 적용됨)
+    pub fn with_order_throttle(mut self, order_throttle: Arc<OrderThrottle>) -> Self {
+        self.order_throttle = Some(order_throttle);
+        self
+    }
+
+    /// MQ 발행 재시도 정책 설정 (소진되면 로컬 백업 큐에 보관)
+    pub fn with_publish_retry(mut self, publish_retry: Arc<PublishRetry>) -> Self {
+        self.publish_retry = Some(publish_retry);
+        self
+    }
+
+    /// 수신 대기 전략 설정 (주문/체결 보고서 수신 루프)
+    pub fn with_wait_strategy(mut self, config: WaitStrategyConfig) -> Self {
+        self.order_wait = WaitStrategy::new("orders", config);
+        self.exec_wait = WaitStrategy::new("executions", config);
+        self
+    }
+
+    /// 주문 상태 기계
+    pub fn order_lifecycle(&self) -> Arc<OrderLifecycleRecorder> {
+        self.lifecycle.clone()
+    }
+
+    /// 큐 깊이 게이지 조회 (MetricsCollector 발행용)
+    pub fn queue_metrics(&self) -> SequencerQueueMetrics {
+        self.queue_metrics.clone()
+    }
+
+    /// 수신 대기 전략 통계 조회 (MetricsCollector 발행용)
+    pub fn wait_metrics(&self) -> WaitStrategyMetrics {
+        let mut metrics = WaitStrategyMetrics::new();
+        metrics.register(self.order_wait.stats());
+        metrics.register(self.exec_wait.stats());
+        metrics
+    }
+
+    /// 전역 시퀀스 감사 기록 (심볼 간 도착 순서 복원용)
+    pub fn sequence_audit(&self) -> Arc<SequenceAudit> {
+        self.audit.clone()
+    }
+
     /// 시퀀서 실행
     pub async fn run(&mut self) {
         info!("시퀀서 시작: {}", self.sequencer_id);
 
-        // 주문 처리 태스크
+        // 주문 처리 태스크 (수신 순서대로 전역 시퀀스를 붙여 심볼별 파이프라인으로 분배)
         let order_task = {
-            let processed_orders = self.processed_orders.clone();
+            let context = PipelineContext {
+                sequencer_id: self.sequencer_id.clone(),
+                lane_weights: self.lane_weights,
+                engine_tx: self.engine_tx.clone(),
+                replication: self.replication.clone(),
+                audit: self.audit.clone(),
+                global_sequence: self.global_sequence.clone(),
+                lifecycle: Some(self.lifecycle.clone()),
+                processed_orders: self.processed_orders.clone(),
+            };
             let sequencer_id = self.sequencer_id.clone();
-            let engine_tx = self.engine_tx.clone();
-            let mut order_rx = std::mem::replace(&mut self.order_rx, unsafe { std::mem::zeroed() });
-
-            tokio::spawn(async move {
-                while let Ok(order) = order_rx.recv() {
-                    debug!("시퀀서 {}: 주문 수신 - {}", sequencer_id, order.id);
-                    
-                    // 매칭 엔진으로 주문 전달
-                    match engine_tx.send(order.clone()) {
-                        Ok(_) => {
-                            let mut count = processed_orders.lock().await;
-                            *count += 1;
-                            debug!("시퀀서 {}: 주문 전달 완료 - {} (총 처리: {})", 
-                                   sequencer_id, order.id, *count);
+            let order_throttle = self.order_throttle.clone();
+            let lifecycle = self.lifecycle.clone();
+            let broadcast_tx = self.broadcast_tx.clone();
+            let order_wait = self.order_wait.clone();
+            let order_rx = self.order_rx.take().expect("시퀀서는 한 번만 실행할 수 있음");
+            let cancel_rx = self.cancel_rx.take();
+            let runtime = tokio::runtime::Handle::current();
+
+            // 대기 전략의 스핀/블로킹 수신이 런타임 워커를 붙잡지 않도록 블로킹 스레드에서 실행
+            // (심볼 파이프라인은 dispatch가 런타임 워커에 spawn)
+            tokio::task::spawn_blocking(move || runtime.block_on(async move {
+                let mut pipelines = SymbolPipelines::new(context);
+                let mut order_open = true;
+                let mut cancel_open = cancel_rx.is_some();
+                let mut last_release = 0;
+
+                while order_open || cancel_open {
+                    // 취소 수집 (항상 전부, 심볼 파이프라인 안에서도 취소 레인이 우선)
+                    let mut received = Vec::new();
+                    if let Some(cancel_rx) = &cancel_rx {
+                        loop {
+                            match cancel_rx.try_recv() {
+                                Ok(cancel) => received.push(cancel),
+                                Err(TryRecvError::Empty) => break,
+                                Err(TryRecvError::Disconnected) => {
+                                    cancel_open = false;
+                                    break;
+                                }
+                            }
                         }
-                        Err(e) => {
-                            error!("시퀀서 {}: 주문 전달 실패 - {}: {}", 
-                                   sequencer_id, order.id, e);
+                    }
+
+                    // 신규 주문 수집 (큐 용량 제한이 유지되도록 배치 단위로만)
+                    let cancels = received.len();
+                    // 첫 주문만 대기 전략으로 기다리고, 이후는 큐에 있는 만큼만 수집
+                    while order_open && received.len() - cancels < LANE_BATCH_SIZE {
+                        let result = if received.is_empty() {
+                            order_wait.recv_timeout(&order_rx, std::time::Duration::from_millis(1))
+                        } else {
+                            order_rx.recv_timeout(std::time::Duration::ZERO)
+                        };
+                        match result {
+                            Ok(order) => {
+                                debug!("시퀀서 {}: 주문 수신 - {}", sequencer_id, order.id);
+                                received.push(order);
+                            }
+                            Err(RecvTimeoutError::Timeout) => break,
+                            Err(RecvTimeoutError::Disconnected) => order_open = false,
+                        }
+                    }
+
+                    for order in received {
+                        // 계정별 주문/취소 수 집계 (취소는 대상 주문의 계정으로)
+                        if let Some(throttle) = &order_throttle {
+                            let now = throttle.now_millis();
+                            let update = if order.is_cancel {
+                                order
+                                    .target_order_id
+                                    .as_deref()
+                                    .and_then(|target| lifecycle.client_id(target))
+                                    .and_then(|client_id| throttle.record_cancel(&client_id, now))
+                            } else {
+                                throttle.record_order(&order.client_id, now)
+                            };
+                            if let Some(update) = update {
+                                warn!(
+                                    "시퀀서 {}: 주문 속도 제한 시작 - {} (취소 {}/주문 {}, 초당 {}건)",
+                                    sequencer_id, update.client_id, update.cancels, update.orders, update.max_orders_per_sec
+                                );
+                                let _ = broadcast_tx.send(WebSocketMessage::ThrottleUpdate(update));
+                            }
+                        }
+                        pipelines.dispatch(order).await;
+                    }
+
+                    // 유지 시간이 지난 주문 속도 제한 해제 (1초마다 확인)
+                    if let Some(throttle) = &order_throttle {
+                        let now = throttle.now_millis();
+                        if now >= last_release + 1_000 {
+                            last_release = now;
+                            for update in throttle.release_expired(now) {
+                                info!("시퀀서 {}: 주문 속도 제한 해제 - {}", sequencer_id, update.client_id);
+                                let _ = broadcast_tx.send(WebSocketMessage::ThrottleUpdate(update));
+                            }
                         }
                     }
                 }
+
+                // 심볼 파이프라인에 남은 주문까지 전달한 뒤 종료
+                pipelines.shutdown().await;
                 info!("시퀀서 {}: 주문 처리 종료", sequencer_id);
-            })
+            }))
         };
 
+    // MDP 시장 데이터 큐 (생성자에서 만들어 게이지를 run() 전에 등록)
+    let (market_data_tx, market_data_rx) = self.market_data_queue.take().expect("시퀀서는 한 번만 실행할 수 있음");
+
+    // MDP 처리 태스크
+    let market_data_task = {
+      let sequencer_id = self.sequencer_id.clone();
+      let mdp = self.mdp.clone();
+
+      tokio::spawn(async move {
+        loop {
+          match market_data_rx.try_recv() {
+            Ok(report) => {
+              let mdp_guard = mdp.lock().await;
+              mdp_guard.process_execution(report).await;
+            }
+            Err(TryRecvError::Empty) => {
+              tokio::time::sleep(tokio::time::Duration::from_millis(1)).await;
+            }
+            Err(TryRecvError::Disconnected) => break,
+          }
+        }
+        info!("시퀀서 {}: 시장 데이터 처리 종료", sequencer_id);
+      })
+    };
+
     // 체결 보고서 브로드캐스트 태스크
     let broadcast_task = {
       let sequencer_id = self.sequencer_id.clone();
       let broadcast_tx = self.broadcast_tx.clone();
-      let mdp = self.mdp.clone();
       let async_commit_mgr = self.async_commit_mgr.clone();
       let redis_producer = self.redis_producer.clone();
       let kafka_producer = self.kafka_producer.clone();
-      let mut exec_rx = std::mem::replace(&mut self.exec_rx, unsafe { std::mem::zeroed() });
-
-      tokio::spawn(async move {
-        while let Ok(report) = exec_rx.recv() {
+      let rabbitmq_producer = self.rabbitmq_producer.clone();
+      let publish_retry = self.publish_retry.clone();
+      let replication = self.replication.clone();
+      let global_sequence = self.global_sequence.clone();
+      let lifecycle = self.lifecycle.clone();
+      let private_events = self.private_events.clone();
+      let fee_engine = self.fee_engine.clone();
+      let risk_manager = self.risk_manager.clone();
+      let exec_wait = self.exec_wait.clone();
+      let exec_rx = self.exec_rx.take().expect("시퀀서는 한 번만 실행할 수 있음");
+      let runtime = tokio::runtime::Handle::current();
+
+      // 주문 처리 태스크와 같은 이유로 블로킹 스레드에서 수신
+      tokio::task::spawn_blocking(move || runtime.block_on(async move {
+        while let Ok(mut report) = exec_wait.recv(&exec_rx) {
           debug!("시퀀서 {}: 체결 보고서 수신 - {}", sequencer_id, report.execution_id);
 
-          // 🚀 초고성능: 체결 내역을 비차단 큐에 추가 (즉시 반환)
-          let exec_record = ExecutionRecord {
-            exec_id: report.execution_id.clone(),
-            taker_order_id: report.order_id.clone(),
-            maker_order_id: "maker_placeholder".to_string(), // TODO: 실제 maker order id 추가 필요
-            symbol: report.symbol.clone(),
-            side: format!("{:?}", report.side),
-            price: report.price as i64,
-            quantity: report.quantity as i64,
-            taker_fee: 0, // TODO: 수수료 계산 로직 추가 필요
-            maker_fee: 0, // TODO: 수수료 계산 로직 추가 필요
-            transaction_time: report.timestamp as i64,
-          };
+          // 대기 인스턴스: 복제된 주문의 체결은 주 인스턴스가 이미 저장/발행했으므로 시장 데이터만 갱신
+          if replication.as_ref().is_some_and(|replication| replication.is_standby()) {
+            let _ = market_data_tx.send(report);
+            continue;
+          }
+
+          // 발행 전에 전역 시퀀스 번호 부여 (Redis/Kafka/RabbitMQ/WebSocket 모두 같은 번호)
+          report.sequence = global_sequence.next();
+
+          // 주문 상태 전이 검증 후 orders 테이블에 기록 (허용되지 않는 전이는 오류 로그만 남김)
+          let transition = lifecycle.record_report(&report).await;
 
-          // 비동기 큐에 추가 (마이크로초 단위 지연)
-          async_commit_mgr.enqueue(exec_record).await;
-          debug!("시퀀서 {}: 체결 내역 비동기 큐 추가 - {}", sequencer_id, report.execution_id);
+          // 체결 계정 순포지션 갱신 (테이커/메이커 보고서마다 자기 주문 방향으로)
+          if let Some(risk_manager) = &risk_manager {
+            if report.exec_type == ExecType::Trade {
+              if let Some(client_id) = lifecycle.client_id(&report.order_id) {
+                risk_manager.record_fill(&client_id, &report.symbol, &report.side, report.quantity);
+              }
+            }
+          }
+
+          // 🚀 초고성능: 체결 내역을 비차단 큐에 추가 (즉시 반환)
+          // 취소/만료/거부 보고서는 체결이 아니므로 executions 테이블에 남기지 않음 (수량은 해제된 잔량)
+          if report.exec_type == ExecType::Trade {
+            // 테이커/메이커 보고서는 체결 ID가 같으므로 어느 쪽이든 테이커 기준 같은 행으로 기록
+            let (taker_order_id, maker_order_id, taker_side) = if report.is_maker {
+              let taker_side = match report.side {
+                Side::Buy => Side::Sell,
+                Side::Sell => Side::Buy,
+              };
+              (report.counterparty_id.clone(), report.order_id.clone(), taker_side)
+            } else {
+              (report.order_id.clone(), report.counterparty_id.clone(), report.side.clone())
+            };
+            // 수수료는 체결 금액(호가 자산 최소 단위)에 양쪽 계정 등급 요율 적용 (두 보고서가 같은 값으로 기록)
+            let fees = match &fee_engine {
+              Some(fee_engine) => fee_engine.execution_fees(
+                lifecycle.client_id(&maker_order_id).as_deref(),
+                lifecycle.client_id(&taker_order_id).as_deref(),
+                report.price.saturating_mul(report.quantity),
+              ),
+              None => ExecutionFees::default(),
+            };
+            let exec_record = ExecutionRecord {
+              exec_id: report.execution_id.clone(),
+              taker_order_id,
+              maker_order_id,
+              symbol: report.symbol.clone(),
+              side: format!("{:?}", taker_side),
+              price: report.price as i64,
+              quantity: report.quantity as i64,
+              taker_fee: fees.taker_fee,
+              maker_fee: fees.maker_fee,
+              transaction_time: report.timestamp as i64,
+            };
+
+            // 비동기 큐에 추가 (마이크로초 단위 지연)
+            async_commit_mgr.enqueue(exec_record).await;
+            debug!("시퀀서 {}: 체결 내역 비동기 큐 추가 - {}", sequencer_id, report.execution_id);
+          }
 
           // 🚀 Redis Streams에 체결 내역 발행 (영속화)
           if let Some(redis_prod) = &redis_producer {
-            match redis_prod.publish_execution(&report).await {
-              Ok(message_id) => {
+            let result = match &publish_retry {
+              Some(retry) => {
+                let backup = || execution_backup(MQType::RedisStreams, redis_prod.stream_name(), None, &report);
+                retry.send_or_backup(&MQType::RedisStreams, backup, || redis_prod.publish_execution(&report)).await
+              }
+              None => redis_prod.publish_execution(&report).await.map(PublishOutcome::Published).map_err(|e| e.to_string()),
+            };
+            match result {
+              Ok(PublishOutcome::Published(message_id)) => {
                 debug!("시퀀서 {}: Redis Streams 발행 완료 - {} -> {}", 
                        sequencer_id, report.execution_id, message_id);
               }
+              Ok(PublishOutcome::BackedUp(e)) => {
+                warn!("시퀀서 {}: Redis Streams 발행 실패, 백업 큐 보관 - {}: {}",
+                      sequencer_id, report.execution_id, e);
+              }
               Err(e) => {
                 error!("시퀀서 {}: Redis Streams 발행 실패 - {}: {}", 
                        sequencer_id, report.execution_id, e);
@@ -154,11 +482,22 @@ impl OrderSequencer {
 
           // 🚀 Kafka에 시장 데이터 발행 (외부 시스템 연동)
           if let Some(kafka_prod) = &kafka_producer {
-            match kafka_prod.publish_execution(&report).await {
-              Ok(_) => {
+            let result = match &publish_retry {
+              Some(retry) => {
+                let backup = || execution_backup(MQType::Kafka, kafka_prod.topic_name(), Some(report.symbol.clone()), &report);
+                retry.send_or_backup(&MQType::Kafka, backup, || kafka_prod.publish_execution(&report)).await
+              }
+              None => kafka_prod.publish_execution(&report).await.map(PublishOutcome::Published).map_err(|e| e.to_string()),
+            };
+            match result {
+              Ok(PublishOutcome::Published(_)) => {
                 debug!("시퀀서 {}: Kafka 발행 완료 - {} -> {}", 
                        sequencer_id, report.execution_id, report.symbol);
               }
+              Ok(PublishOutcome::BackedUp(e)) => {
+                warn!("시퀀서 {}: Kafka 발행 실패, 백업 큐 보관 - {}: {}",
+                      sequencer_id, report.execution_id, e);
+              }
               Err(e) => {
                 error!("시퀀서 {}: Kafka 발행 실패 - {}: {}", 
                        sequencer_id, report.execution_id, e);
@@ -166,14 +505,27 @@ impl OrderSequencer {
             }
           }
 
-          // MDP로 체결 데이터 전달
-          {
-            let mut mdp_guard = mdp.lock().await;
-            mdp_guard.process_execution(report.clone()).await;
+          // MDP로 체결 데이터 전달 (시장 데이터 큐가 가득 차면 버림)
+          match market_data_tx.send(report.clone()) {
+            Ok(true) => {}
+            Ok(false) => {
+              warn!("시퀀서 {}: 시장 데이터 큐 포화, 업데이트 폐기 - {}",
+                    sequencer_id, report.execution_id);
+            }
+            Err(e) => {
+              error!("시퀀서 {}: 시장 데이터 전달 실패 - {}: {}",
+                     sequencer_id, report.execution_id, e);
+            }
           }
 
           // 하이브리드 방식: 체결 상태 결정
-          let order_status = if report.remaining_quantity == 0 {
+          let order_status = if report.exec_type == ExecType::Canceled {
+            "Cancelled"
+          } else if report.exec_type == ExecType::Expired {
+            "Expired"
+          } else if report.exec_type == ExecType::Rejected {
+            "Rejected"
+          } else if report.remaining_quantity == 0 {
             "Filled"
           } else if report.remaining_quantity < report.quantity {
             "PartiallyFilled"
@@ -181,6 +533,15 @@ impl OrderSequencer {
             "Pending"
           };
 
+          // 주문 고객의 비공개 채널로 전달 (계정별 시퀀스를 붙여 버퍼와 DB에 남김)
+          if let (Some(private_events), Some(transition)) = (&private_events, &transition) {
+            let event = private_events.record(&transition.client_id, report.clone(), order_status);
+            if let Some(record) = private_event_record(&event) {
+              async_commit_mgr.enqueue_private_event(record).await;
+            }
+            let _ = broadcast_tx.send(WebSocketMessage::PrivateEvent(event));
+          }
+
           // WebSocket 메시지로 변환하여 RabbitMQ로 발행 (확장성 확보)
           let message = WebSocketMessage::Execution {
             execution_report: report.clone(),
@@ -188,12 +549,33 @@ impl OrderSequencer {
           };
           
           // RabbitMQ Producer를 통한 WebSocket 알림 발행
-          if let Some(rabbitmq_prod) = &self.rabbitmq_producer {
-            match rabbitmq_prod.publish_websocket_message(&message).await {
-              Ok(message_id) => {
+          if let Some(rabbitmq_prod) = &rabbitmq_producer {
+            let result = match &publish_retry {
+              Some(retry) => {
+                let backup = || {
+                  let notification = WebSocketNotificationMessage::from(&message);
+                  BackupMessageBuilder::new(MQType::RabbitMQ, rabbitmq_prod.exchange_name().to_string())
+                    .routing_key(notification.routing_key.clone())
+                    .message_data(serde_json::to_value(&notification).unwrap_or_default())
+                    .build()
+                };
+                retry.send_or_backup(&MQType::RabbitMQ, backup, || rabbitmq_prod.publish_websocket_message(&message)).await
+              }
+              None => rabbitmq_prod
+                .publish_websocket_message(&message)
+                .await
+                .map(PublishOutcome::Published)
+                .map_err(|e| e.to_string()),
+            };
+            match result {
+              Ok(PublishOutcome::Published(message_id)) => {
                 debug!("시퀀서 {}: RabbitMQ WebSocket 알림 발행 완료 - {} -> {} (상태: {})",
                        sequencer_id, report.execution_id, message_id, order_status);
               }
+              Ok(PublishOutcome::BackedUp(e)) => {
+                warn!("시퀀서 {}: RabbitMQ WebSocket 알림 발행 실패, 백업 큐 보관 - {}: {}",
+                      sequencer_id, report.execution_id, e);
+              }
               Err(e) => {
                 error!("시퀀서 {}: RabbitMQ WebSocket 알림 발행 실패 - {}: {}",
                        sequencer_id, report.execution_id, e);
@@ -214,7 +596,7 @@ impl OrderSequencer {
           }
         }
         info!("시퀀서 {}: 체결 보고서 브로드캐스트 종료", sequencer_id);
-      })
+      }))
     };
 
         // 두 태스크 모두 완료될 때까지 대기
@@ -225,6 +607,9 @@ impl OrderSequencer {
             _ = broadcast_task => {
                 info!("시퀀서 {}: 브로드캐스트 태스크 완료", self.sequencer_id);
             }
+            _ = market_data_task => {
+                info!("시퀀서 {}: 시장 데이터 태스크 완료", self.sequencer_id);
+            }
         }
 
         info!("시퀀서 종료: {}", self.sequencer_id);
@@ -236,10 +621,21 @@ impl OrderSequencer {
     }
 }
 
+/// 발행 재시도를 소진한 체결 보고서의 백업 메시지
+fn execution_backup(mq_type: MQType, topic: &str, routing_key: Option<String>, report: &ExecutionReport) -> BackupMessage {
+    let mut builder = BackupMessageBuilder::new(mq_type, topic.to_string())
+        .message_data(serde_json::to_value(report).unwrap_or_default());
+    if let Some(routing_key) = routing_key {
+        builder = builder.routing_key(routing_key);
+    }
+    builder.build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::mpsc;
+    use crate::sequencer::backpressure::{bounded_queue, OverflowPolicy};
+    use std::time::Duration;
     use tokio::sync::broadcast;
     use crate::matching_engine::model::{Order, OrderType, Side};
 
@@ -256,28 +652,58 @@ mod tests {
             timestamp: 0,
             is_cancel: false,
             target_order_id: None,
+            protection: None,
+            expire_time: None,
+            sequence: 0,
+            tag: None,
         }
     }
 
+    /// 테스트용 시퀀서 (메모리 DB, MQ 발행자 없음)
+    async fn create_test_sequencer(
+        order_rx: BoundedReceiver<Order>,
+        engine_tx: BoundedSender<Order>,
+        exec_rx: BoundedReceiver<ExecutionReport>,
+        broadcast_tx: broadcast::Sender<WebSocketMessage>,
+    ) -> OrderSequencer {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        let mdp = Arc::new(Mutex::new(MarketDataPublisher::new(1000)));
+        let async_commit_mgr = Arc::new(AsyncCommitManager::new(pool));
+        OrderSequencer::new(order_rx, engine_tx, exec_rx, broadcast_tx, mdp, async_commit_mgr, None, None, None)
+    }
+
+    #[tokio::test]
+    async fn test_market_data_gauge_registered_before_run() {
+        let (_order_tx, order_rx) = bounded_queue("orders", 100, OverflowPolicy::Reject);
+        let (engine_tx, _engine_rx) = bounded_queue("engine", 100, OverflowPolicy::Block);
+        let (_exec_tx, exec_rx) = bounded_queue("executions", 100, OverflowPolicy::Block);
+        let (broadcast_tx, _broadcast_rx) = broadcast::channel(100);
+
+        let sequencer = create_test_sequencer(order_rx, engine_tx, exec_rx, broadcast_tx)
+            .await
+            .with_market_data_capacity(10);
+
+        // 실행 전에도 시장 데이터 큐 게이지가 한 번만 등록되어 있음
+        let metrics = sequencer.queue_metrics();
+        let names: Vec<&str> = metrics.gauges().iter().map(|gauge| gauge.name()).collect();
+        assert_eq!(names, ["orders", "engine", "executions", "market_data"]);
+    }
+
     #[tokio::test]
     async fn test_sequencer_order_processing() {
         // 채널 생성
-        let (order_tx, order_rx) = mpsc::channel();
-        let (engine_tx, engine_rx) = mpsc::channel();
-        let (exec_tx, exec_rx) = mpsc::channel();
+        let (order_tx, order_rx) = bounded_queue("orders", 100, OverflowPolicy::Reject);
+        let (engine_tx, engine_rx) = bounded_queue("engine", 100, OverflowPolicy::Block);
+        let (_exec_tx, exec_rx) = bounded_queue("executions", 100, OverflowPolicy::Block);
         let (broadcast_tx, _broadcast_rx) = broadcast::channel(100);
 
-        // MDP 생성 (테스트용)
-        let mdp = Arc::new(Mutex::new(MarketDataPublisher::new(1000)));
-
         // 시퀀서 생성
-        let mut sequencer = OrderSequencer::new(
-            order_rx,
-            engine_tx,
-            exec_rx,
-            broadcast_tx,
-            mdp,
-        );
+        let mut sequencer = create_test_sequencer(order_rx, engine_tx, exec_rx, broadcast_tx).await;
 
         // 테스트 주문 전송
         let order1 = create_test_order("order1");
@@ -291,9 +717,15 @@ mod tests {
             sequencer.run().await;
         });
 
-        // 매칭 엔진에서 주문 수신 확인
-        let received_order1 = engine_rx.recv().unwrap();
-        let received_order2 = engine_rx.recv().unwrap();
+        // 매칭 엔진에서 주문 수신 확인 (단일 스레드 런타임에서도 파이프라인이 돌도록 블로킹 수신은 따로)
+        let (received_order1, received_order2) = tokio::task::spawn_blocking(move || {
+            (
+                engine_rx.recv_timeout(Duration::from_secs(5)).unwrap(),
+                engine_rx.recv_timeout(Duration::from_secs(5)).unwrap(),
+            )
+        })
+        .await
+        .unwrap();
 
         assert_eq!(received_order1.id, "order1");
         assert_eq!(received_order2.id, "order2");
@@ -302,25 +734,16 @@ mod tests {
         sequencer_task.abort();
     }
 
-    #[tokio::test]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     async fn test_sequencer_execution_broadcast() {
         // 채널 생성
-        let (order_tx, order_rx) = mpsc::channel();
-        let (engine_tx, _engine_rx) = mpsc::channel();
-        let (exec_tx, exec_rx) = mpsc::channel();
+        let (_order_tx, order_rx) = bounded_queue("orders", 100, OverflowPolicy::Reject);
+        let (engine_tx, _engine_rx) = bounded_queue("engine", 100, OverflowPolicy::Block);
+        let (exec_tx, exec_rx) = bounded_queue("executions", 100, OverflowPolicy::Block);
         let (broadcast_tx, mut broadcast_rx) = broadcast::channel(100);
 
-        // MDP 생성 (테스트용)
-        let mdp = Arc::new(Mutex::new(MarketDataPublisher::new(1000)));
-
         // 시퀀서 생성
-        let mut sequencer = OrderSequencer::new(
-            order_rx,
-            engine_tx,
-            exec_rx,
-            broadcast_tx,
-            mdp,
-        );
+        let mut sequencer = create_test_sequencer(order_rx, engine_tx, exec_rx, broadcast_tx).await;
 
         // 테스트 체결 보고서 전송
         let execution_report = ExecutionReport {
@@ -334,6 +757,9 @@ mod tests {
             timestamp: 0,
             counterparty_id: "order2".to_string(),
             is_maker: false,
+            exec_type: ExecType::Trade,
+            sequence: 0,
+            tag: None,
         };
 
         exec_tx.send(execution_report.clone()).unwrap();
@@ -344,10 +770,16 @@ mod tests {
         });
 
         // 브로드캐스트 수신 확인
-        let received_message = broadcast_rx.recv().await.unwrap();
+        let received_message = tokio::time::timeout(Duration::from_secs(5), broadcast_rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
         match received_message {
-            WebSocketMessage::Execution(report) => {
-                assert_eq!(report.execution_id, "exec1");
+            WebSocketMessage::Execution { execution_report, order_status } => {
+                assert_eq!(execution_report.execution_id, "exec1");
+                // 발행 전에 전역 시퀀스 번호가 붙음
+                assert_eq!(execution_report.sequence, 1);
+                assert_eq!(order_status, "Filled");
             }
             _ => panic!("Expected Execution message"),
         }
@@ -0,0 +1,392 @@
+//! 심볼별 시퀀서 파이프라인
+//!
+//! 매칭 결과는 심볼 안의 순서에만 좌우되므로, 시퀀서는 심볼마다 FIFO 레인(취소 우선순위 포함)과
+//! 전달 태스크를 두고 심볼별로 따로 매칭 엔진에 넘깁니다. 같은 심볼의 주문은 (대상 주문과 그 취소 포함)
+//! 도착 순서를 지키고, 한 심볼에 주문이 몰려도 다른 심볼은 그 뒤에서 기다리지 않습니다.
+//!
+//! 모든 주문은 전역 시퀀스 번호를 가집니다 (API가 큐에 넣을 때 붙이고, 번호 없이 들어온 주문은 시퀀서 도착 시 부여).
+//! 심볼 간 전달 순서는 번호순과 달라질 수 있으므로, 감사 기록에 시퀀스 번호와 전달 시각을 남겨
+//! 전체 순서를 복원할 수 있게 합니다.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{debug, error};
+use serde::Serialize;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+use crate::matching_engine::model::Order;
+use crate::sequencer::backpressure::BoundedSender;
+use crate::sequencer::global_sequence::GlobalSequence;
+use crate::sequencer::order_lifecycle::OrderLifecycleRecorder;
+use crate::sequencer::priority_lanes::{LaneWeights, PriorityLanes};
+use crate::sequencer::replication::ReplicationState;
+
+/// 심볼 파이프라인 입력 큐 용량 (가득 차면 시퀀서 수신이 대기하여 앞단 큐 용량 제한 유지)
+pub const SYMBOL_LANE_CAPACITY: usize = 256;
+
+/// 취소 주문의 심볼을 찾기 위해 기억하는 최근 주문 수
+pub const ORDER_SYMBOL_CAPACITY: usize = 100_000;
+
+/// 감사 기록 보관 개수
+pub const SEQUENCE_AUDIT_CAPACITY: usize = 10_000;
+
+/// 대상 주문의 심볼을 모르는 취소가 모이는 레인
+///
+/// 기억하지 못하는 주문은 이미 오래전에 전달되었으므로 순서를 지킬 대상이 없습니다.
+const UNKNOWN_SYMBOL_LANE: &str = "";
+
+/// 한 번에 매칭 엔진으로 전달하는 최대 주문 수 (이후 다른 심볼 파이프라인에 양보)
+const PIPELINE_BATCH_SIZE: usize = 256;
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
+/// 전역 시퀀스 번호가 붙은 주문
+#[derive(Debug, Clone)]
+pub struct SequencedOrder {
+    /// 전역 시퀀스 번호 (`order.sequence` 와 같음)
+    pub sequence: u64,
+    pub order: Order,
+}
+
+impl AsRef<Order> for SequencedOrder {
+    fn as_ref(&self) -> &Order {
+        &self.order
+    }
+}
+
+/// 매칭 엔진 전달 감사 기록
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SequenceRecord {
+    pub sequence: u64,
+    pub order_id: String,
+    /// 레인 심볼 (취소는 대상 주문의 심볼)
+    pub symbol: String,
+    pub is_cancel: bool,
+    /// 매칭 엔진 전달 시각 (밀리초)
+    pub forwarded_at: u64,
+}
+
+/// 전역 시퀀스 감사 기록 (최근 전달분)
+pub struct SequenceAudit {
+    records: std::sync::Mutex<VecDeque<SequenceRecord>>,
+    capacity: usize,
+    last_sequence: AtomicU64,
+}
+
+impl SequenceAudit {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: std::sync::Mutex::new(VecDeque::new()),
+            capacity: capacity.max(1),
+            last_sequence: AtomicU64::new(0),
+        }
+    }
+
+    /// 시퀀서가 받은 가장 큰 전역 시퀀스 번호
+    pub fn last_sequence(&self) -> u64 {
+        self.last_sequence.load(Ordering::Relaxed)
+    }
+
+    /// 최근 전달 기록 (전달순)
+    pub fn recent(&self, limit: usize) -> Vec<SequenceRecord> {
+        let records = self.records.lock().unwrap();
+        records.iter().skip(records.len().saturating_sub(limit)).cloned().collect()
+    }
+
+    fn stamp(&self, sequence: u64) {
+        self.last_sequence.fetch_max(sequence, Ordering::Relaxed);
+    }
+
+    fn record(&self, symbol: &str, sequenced: &SequencedOrder) {
+        let mut records = self.records.lock().unwrap();
+        if records.len() >= self.capacity {
+            records.pop_front();
+        }
+        records.push_back(SequenceRecord {
+            sequence: sequenced.sequence,
+            order_id: sequenced.order.id.clone(),
+            symbol: symbol.to_string(),
+            is_cancel: sequenced.order.is_cancel,
+            forwarded_at: now_millis(),
+        });
+    }
+}
+
+/// 주문 → 심볼 레인 라우터 (전역 시퀀스 부여, 취소 주문의 심볼 결정)
+pub struct SymbolRouter {
+    global_sequence: Arc<GlobalSequence>,
+    /// 최근 주문 ID → 심볼 (취소 주문은 심볼 없이 들어옴)
+    order_symbols: HashMap<String, String>,
+    /// 기억한 순서 (오래된 것부터 잊음)
+    remembered: VecDeque<String>,
+    capacity: usize,
+}
+
+impl SymbolRouter {
+    pub fn new(capacity: usize, global_sequence: Arc<GlobalSequence>) -> Self {
+        Self {
+            global_sequence,
+            order_symbols: HashMap::new(),
+            remembered: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// 시퀀스 번호를 확인하고 (없으면 부여) 보낼 레인 심볼 결정
+    pub fn route(&mut self, mut order: Order) -> (String, SequencedOrder) {
+        if order.sequence == 0 {
+            order.sequence = self.global_sequence.next();
+        }
+        let sequence = order.sequence;
+
+        let symbol = if order.is_cancel {
+            order
+                .target_order_id
+                .as_ref()
+                .and_then(|target| self.order_symbols.get(target))
+                .cloned()
+                .unwrap_or_else(|| UNKNOWN_SYMBOL_LANE.to_string())
+        } else {
+            self.remember(&order);
+            order.symbol.clone()
+        };
+        (symbol, SequencedOrder { sequence, order })
+    }
+
+    fn remember(&mut self, order: &Order) {
+        if self.order_symbols.insert(order.id.clone(), order.symbol.clone()).is_none() {
+            self.remembered.push_back(order.id.clone());
+        }
+        while self.remembered.len() > self.capacity {
+            if let Some(oldest) = self.remembered.pop_front() {
+                self.order_symbols.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// 심볼 파이프라인 공용 설정
+#[derive(Clone)]
+pub struct PipelineContext {
+    pub sequencer_id: String,
+    pub lane_weights: LaneWeights,
+    pub engine_tx: BoundedSender<Order>,
+    pub replication: Option<Arc<ReplicationState>>,
+    pub audit: Arc<SequenceAudit>,
+    pub global_sequence: Arc<GlobalSequence>,
+    /// 주문 상태 기록 (None이면 추적하지 않음)
+    pub lifecycle: Option<Arc<OrderLifecycleRecorder>>,
+    pub processed_orders: Arc<Mutex<u64>>,
+}
+
+/// 심볼별 파이프라인 (처음 보는 심볼은 파이프라인을 새로 띄움)
+pub struct SymbolPipelines {
+    router: SymbolRouter,
+    context: PipelineContext,
+    lanes: HashMap<String, mpsc::Sender<SequencedOrder>>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl SymbolPipelines {
+    pub fn new(context: PipelineContext) -> Self {
+        Self {
+            router: SymbolRouter::new(ORDER_SYMBOL_CAPACITY, context.global_sequence.clone()),
+            context,
+            lanes: HashMap::new(),
+            handles: Vec::new(),
+        }
+    }
+
+    /// 실행 중인 심볼 파이프라인 수
+    pub fn pipeline_count(&self) -> usize {
+        self.lanes.len()
+    }
+
+    /// 주문을 심볼 파이프라인으로 보냄 (파이프라인 입력 큐가 가득 차면 대기)
+    pub async fn dispatch(&mut self, order: Order) {
+        let (symbol, sequenced) = self.router.route(order);
+        self.context.audit.stamp(sequenced.sequence);
+
+        let lane = match self.lanes.get(&symbol) {
+            Some(lane) => lane.clone(),
+            None => {
+                let (lane_tx, lane_rx) = mpsc::channel(SYMBOL_LANE_CAPACITY);
+                debug!("시퀀서 {}: 심볼 파이프라인 시작 - {}", self.context.sequencer_id, symbol);
+                self.handles
+                    .push(tokio::spawn(run_pipeline(symbol.clone(), lane_rx, self.context.clone())));
+                self.lanes.insert(symbol.clone(), lane_tx.clone());
+                lane_tx
+            }
+        };
+        if let Err(e) = lane.send(sequenced).await {
+            error!(
+                "시퀀서 {}: 심볼 파이프라인 종료됨, 주문 전달 실패 - {} ({})",
+                self.context.sequencer_id, e.0.order.id, symbol
+            );
+        }
+    }
+
+    /// 입력을 닫고 모든 파이프라인이 남은 주문을 전달할 때까지 대기
+    pub async fn shutdown(mut self) {
+        self.lanes.clear();
+        for handle in self.handles.drain(..) {
+            let _ = handle.await;
+        }
+    }
+}
+
+/// 심볼 파이프라인: 레인 수집 → 가중치에 따라 매칭 엔진으로 전달
+async fn run_pipeline(symbol: String, mut lane_rx: mpsc::Receiver<SequencedOrder>, context: PipelineContext) {
+    let mut lanes: PriorityLanes<SequencedOrder> = PriorityLanes::new(context.lane_weights);
+    let mut open = true;
+
+    while open || !lanes.is_empty() {
+        if lanes.is_empty() {
+            match lane_rx.recv().await {
+                Some(sequenced) => lanes.push(sequenced),
+                None => {
+                    open = false;
+                    continue;
+                }
+            }
+        }
+        loop {
+            match lane_rx.try_recv() {
+                Ok(sequenced) => lanes.push(sequenced),
+                Err(mpsc::error::TryRecvError::Empty) => break,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    open = false;
+                    break;
+                }
+            }
+        }
+
+        for _ in 0..PIPELINE_BATCH_SIZE {
+            let Some(sequenced) = lanes.next() else {
+                break;
+            };
+            forward(&symbol, &sequenced, &context).await;
+        }
+        tokio::task::yield_now().await;
+    }
+    debug!("시퀀서 {}: 심볼 파이프라인 종료 - {}", context.sequencer_id, symbol);
+}
+
+/// 매칭 엔진으로 주문 전달 (큐가 가득 차면 블로킹 스레드에서 대기, 주문은 버리지 않음)
+async fn forward(symbol: &str, sequenced: &SequencedOrder, context: &PipelineContext) {
+    let order = &sequenced.order;
+    // 엔진이 체결 보고서를 내기 전에 New 로 등록
+    if let Some(lifecycle) = &context.lifecycle {
+        lifecycle.record_new(order).await;
+    }
+    match context.engine_tx.send_async(order.clone()).await {
+        Ok(_) => {
+            // 엔진에 들어간 순서대로 저널에 기록 (심볼 안의 순서는 엔진과 같음)
+            if let Some(replication) = &context.replication {
+                replication.record_sequenced(order);
+            }
+            context.audit.record(symbol, sequenced);
+            let mut count = context.processed_orders.lock().await;
+            *count += 1;
+            debug!(
+                "시퀀서 {}: 주문 전달 완료 - #{} {} (총 처리: {})",
+                context.sequencer_id, sequenced.sequence, order.id, *count
+            );
+        }
+        Err(e) => {
+            error!("시퀀서 {}: 주문 전달 실패 - #{} {}: {}", context.sequencer_id, sequenced.sequence, order.id, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching_engine::model::{OrderType, Side};
+    use crate::sequencer::backpressure::{bounded_queue, OverflowPolicy};
+
+    fn new_order(id: &str, symbol: &str) -> Order {
+        Order::new(id.to_string(), symbol.to_string(), Side::Buy, OrderType::Limit, 1000, 1, "test".to_string())
+    }
+
+    #[test]
+    fn test_router_stamps_and_resolves_cancel_symbol() {
+        let mut router = SymbolRouter::new(2, Arc::new(GlobalSequence::new()));
+
+        let (symbol, first) = router.route(new_order("a", "BTC-KRW"));
+        assert_eq!((symbol.as_str(), first.sequence), ("BTC-KRW", 1));
+        router.route(new_order("b", "ETH-KRW"));
+
+        let (symbol, cancel) = router.route(Order::new_cancel("a".to_string()));
+        assert_eq!((symbol.as_str(), cancel.sequence), ("BTC-KRW", 3));
+        assert_eq!(cancel.order.sequence, 3);
+
+        // 용량을 넘으면 오래된 주문부터 잊음
+        router.route(new_order("c", "XRP-KRW"));
+        let (symbol, _) = router.route(Order::new_cancel("a".to_string()));
+        assert_eq!(symbol, UNKNOWN_SYMBOL_LANE);
+        let (symbol, _) = router.route(Order::new_cancel("b".to_string()));
+        assert_eq!(symbol, "ETH-KRW");
+
+        // API에서 번호를 받은 주문은 그대로 유지
+        let mut stamped = new_order("d", "BTC-KRW");
+        stamped.sequence = 42;
+        let (_, stamped) = router.route(stamped);
+        assert_eq!(stamped.sequence, 42);
+    }
+
+    #[tokio::test]
+    async fn test_pipelines_preserve_per_symbol_order() {
+        let (engine_tx, engine_rx) = bounded_queue("engine", 1000, OverflowPolicy::Block);
+        let audit = Arc::new(SequenceAudit::new(SEQUENCE_AUDIT_CAPACITY));
+        let processed_orders = Arc::new(Mutex::new(0));
+        let mut pipelines = SymbolPipelines::new(PipelineContext {
+            sequencer_id: "test".to_string(),
+            lane_weights: LaneWeights::default(),
+            engine_tx,
+            replication: None,
+            audit: audit.clone(),
+            global_sequence: Arc::new(GlobalSequence::new()),
+            lifecycle: None,
+            processed_orders: processed_orders.clone(),
+        });
+
+        for i in 0..50 {
+            pipelines.dispatch(new_order(&format!("btc{}", i), "BTC-KRW")).await;
+            pipelines.dispatch(new_order(&format!("eth{}", i), "ETH-KRW")).await;
+        }
+        pipelines.dispatch(Order::new_cancel("btc49".to_string())).await;
+        assert_eq!(pipelines.pipeline_count(), 2);
+        pipelines.shutdown().await;
+
+        let received: Vec<Order> = engine_rx.try_iter().collect();
+        assert_eq!(received.len(), 101);
+        assert_eq!(*processed_orders.lock().await, 101);
+        let ids = |prefix: &str| -> Vec<String> {
+            received.iter().filter(|o| o.id.starts_with(prefix)).map(|o| o.id.clone()).collect()
+        };
+        assert_eq!(ids("btc"), (0..50).map(|i| format!("btc{}", i)).collect::<Vec<_>>());
+        assert_eq!(ids("eth"), (0..50).map(|i| format!("eth{}", i)).collect::<Vec<_>>());
+        // 취소는 같은 심볼의 대상 주문 뒤에 전달됨
+        let position = |id: &str| received.iter().position(|o| o.id == id).unwrap();
+        assert!(position("cancel-btc49") > position("btc49"));
+
+        // 모든 주문이 중복 없는 전역 시퀀스로 기록되고, 심볼 안에서는 시퀀스순
+        assert_eq!(audit.last_sequence(), 101);
+        let records = audit.recent(usize::MAX);
+        let mut sequences: Vec<u64> = records.iter().map(|r| r.sequence).collect();
+        sequences.sort();
+        assert_eq!(sequences, (1..=101).collect::<Vec<_>>());
+        for symbol in ["BTC-KRW", "ETH-KRW"] {
+            let per_symbol: Vec<u64> = records.iter().filter(|r| r.symbol == symbol).map(|r| r.sequence).collect();
+            assert!(per_symbol.windows(2).all(|w| w[0] < w[1]));
+        }
+        assert_eq!(audit.recent(1)[0].sequence, records.last().unwrap().sequence);
+    }
+}
@@ -0,0 +1,330 @@
+//! 주문 대비 취소 비율이 과도한 계정 자동 스로틀
+//!
+//! 시퀀서는 신규 주문과 취소가 들어올 때마다 계정별로 최근 구간(`window`)의 주문/취소 수를 셉니다.
+//! 신규 주문이 `min_orders` 건 이상이고 취소/주문 비율이 `max_cancel_ratio`를 넘으면 그 계정을
+//! `duration` 동안 초당 `max_orders_per_sec` 건으로 제한하고, 매칭 엔진은 한도를 넘는 신규 주문을 거부합니다.
+//! 취소는 노출을 줄이는 요청이므로 제한하지 않습니다. 스로틀 시작/해제는 계정 비공개 채널로 알립니다.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::api::models::ThrottleUpdate;
+use crate::util::clock::{SharedClock, SystemClock};
+
+/// 스로틀 판정 기준
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThrottleConfig {
+    /// 주문/취소 수를 세는 구간
+    pub window: Duration,
+    /// 판정에 필요한 구간 내 최소 신규 주문 수
+    pub min_orders: u64,
+    /// 허용하는 최대 취소/주문 비율
+    pub max_cancel_ratio: f64,
+    /// 스로틀 중 초당 허용하는 신규 주문 수
+    pub max_orders_per_sec: u32,
+    /// 스로틀 유지 시간
+    pub duration: Duration,
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(60),
+            min_orders: 100,
+            max_cancel_ratio: 0.95,
+            max_orders_per_sec: 5,
+            duration: Duration::from_secs(300),
+        }
+    }
+}
+
+/// 초 단위 주문/취소 집계
+struct Bucket {
+    second: u64,
+    orders: u64,
+    cancels: u64,
+}
+
+/// 스로틀 중인 계정의 초당 한도 상태
+struct ActiveThrottle {
+    started_at: u64,
+    until: u64,
+    cancel_ratio: f64,
+    orders: u64,
+    cancels: u64,
+    /// 현재 초와 그 초에 받아들인 신규 주문 수
+    second: u64,
+    admitted: u32,
+    rejected: u64,
+}
+
+#[derive(Default)]
+struct ClientActivity {
+    buckets: VecDeque<Bucket>,
+    orders: u64,
+    cancels: u64,
+    throttle: Option<ActiveThrottle>,
+}
+
+impl ClientActivity {
+    /// 구간을 벗어난 집계 제거
+    fn prune(&mut self, now_second: u64, window_secs: u64) {
+        while let Some(bucket) = self.buckets.front() {
+            if bucket.second + window_secs > now_second {
+                break;
+            }
+            self.orders -= bucket.orders;
+            self.cancels -= bucket.cancels;
+            self.buckets.pop_front();
+        }
+    }
+
+    fn add(&mut self, now_second: u64, is_cancel: bool) {
+        match self.buckets.back_mut() {
+            Some(bucket) if bucket.second == now_second => {}
+            _ => self.buckets.push_back(Bucket { second: now_second, orders: 0, cancels: 0 }),
+        }
+        let bucket = self.buckets.back_mut().unwrap();
+        if is_cancel {
+            bucket.cancels += 1;
+            self.cancels += 1;
+        } else {
+            bucket.orders += 1;
+            self.orders += 1;
+        }
+    }
+}
+
+/// 계정별 주문/취소 비율 감시와 스로틀 (시퀀서가 판정하고 매칭 엔진이 적용)
+pub struct OrderThrottle {
+    config: ThrottleConfig,
+    clients: Mutex<HashMap<String, ClientActivity>>,
+    clock: SharedClock,
+}
+
+impl OrderThrottle {
+    pub fn new(config: ThrottleConfig) -> Self {
+        Self {
+            config,
+            clients: Mutex::new(HashMap::new()),
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// 시각 소스 지정 (시뮬레이션 테스트용, 기본값은 시스템 시각)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 스로틀 기준 현재 시각 (Unix 밀리초)
+    pub fn now_millis(&self) -> u64 {
+        self.clock.now_millis()
+    }
+
+    pub fn config(&self) -> &ThrottleConfig {
+        &self.config
+    }
+
+    /// 신규 주문 기록 (새로 스로틀되면 알림 반환)
+    pub fn record_order(&self, client_id: &str, now_ms: u64) -> Option<ThrottleUpdate> {
+        self.record(client_id, now_ms, false)
+    }
+
+    /// 취소 기록 (새로 스로틀되면 알림 반환)
+    pub fn record_cancel(&self, client_id: &str, now_ms: u64) -> Option<ThrottleUpdate> {
+        self.record(client_id, now_ms, true)
+    }
+
+    fn record(&self, client_id: &str, now_ms: u64, is_cancel: bool) -> Option<ThrottleUpdate> {
+        let now_second = now_ms / 1000;
+        let mut clients = self.clients.lock().unwrap();
+        let client = clients.entry(client_id.to_string()).or_default();
+        client.prune(now_second, self.config.window.as_secs().max(1));
+        client.add(now_second, is_cancel);
+
+        if client.throttle.as_ref().is_some_and(|throttle| throttle.until > now_ms) {
+            return None;
+        }
+        if client.orders == 0 || client.orders < self.config.min_orders {
+            return None;
+        }
+        let cancel_ratio = client.cancels as f64 / client.orders as f64;
+        if cancel_ratio <= self.config.max_cancel_ratio {
+            return None;
+        }
+
+        // 해제 후 지난 활동으로 바로 다시 걸리지 않도록 집계는 비움
+        let throttle = ActiveThrottle {
+            started_at: now_ms,
+            until: now_ms + self.config.duration.as_millis() as u64,
+            cancel_ratio,
+            orders: client.orders,
+            cancels: client.cancels,
+            second: now_second,
+            admitted: 0,
+            rejected: 0,
+        };
+        client.buckets.clear();
+        client.orders = 0;
+        client.cancels = 0;
+        let update = self.update(client_id, &throttle, true, now_ms);
+        client.throttle = Some(throttle);
+        Some(update)
+    }
+
+    /// 신규 주문을 받아들일지 확인 (스로틀 중이면 초당 한도 안에서만 허용)
+    pub fn admit(&self, client_id: &str, now_ms: u64) -> bool {
+        let mut clients = self.clients.lock().unwrap();
+        let Some(throttle) = clients.get_mut(client_id).and_then(|client| client.throttle.as_mut()) else {
+            return true;
+        };
+        if throttle.until <= now_ms {
+            return true;
+        }
+        let now_second = now_ms / 1000;
+        if throttle.second != now_second {
+            throttle.second = now_second;
+            throttle.admitted = 0;
+        }
+        if throttle.admitted < self.config.max_orders_per_sec {
+            throttle.admitted += 1;
+            true
+        } else {
+            throttle.rejected += 1;
+            false
+        }
+    }
+
+    /// 유지 시간이 지난 스로틀 해제 (해제 알림 반환), 활동이 없는 계정 정리
+    pub fn release_expired(&self, now_ms: u64) -> Vec<ThrottleUpdate> {
+        let window_secs = self.config.window.as_secs().max(1);
+        let mut released = Vec::new();
+        let mut clients = self.clients.lock().unwrap();
+        clients.retain(|client_id, client| {
+            if client.throttle.as_ref().is_some_and(|throttle| throttle.until <= now_ms) {
+                let throttle = client.throttle.take().unwrap();
+                released.push(self.update(client_id, &throttle, false, now_ms));
+            }
+            client.prune(now_ms / 1000, window_secs);
+            client.throttle.is_some() || !client.buckets.is_empty()
+        });
+        released
+    }
+
+    /// 계정의 현재 스로틀 상태 (스로틀 중이 아니면 None)
+    pub fn status(&self, client_id: &str, now_ms: u64) -> Option<ThrottleUpdate> {
+        let clients = self.clients.lock().unwrap();
+        let throttle = clients.get(client_id)?.throttle.as_ref().filter(|throttle| throttle.until > now_ms)?;
+        Some(self.update(client_id, throttle, true, now_ms))
+    }
+
+    fn update(&self, client_id: &str, throttle: &ActiveThrottle, throttled: bool, now_ms: u64) -> ThrottleUpdate {
+        ThrottleUpdate {
+            client_id: client_id.to_string(),
+            throttled,
+            max_orders_per_sec: self.config.max_orders_per_sec,
+            orders: throttle.orders,
+            cancels: throttle.cancels,
+            cancel_ratio: throttle.cancel_ratio,
+            started_at: throttle.started_at,
+            until: throttle.until,
+            rejected_orders: throttle.rejected,
+            timestamp: now_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn throttle() -> OrderThrottle {
+        OrderThrottle::new(ThrottleConfig {
+            window: Duration::from_secs(10),
+            min_orders: 10,
+            max_cancel_ratio: 0.8,
+            max_orders_per_sec: 2,
+            duration: Duration::from_secs(30),
+        })
+    }
+
+    #[test]
+    fn test_throttles_client_over_cancel_ratio() {
+        let throttle = throttle();
+        let now = 1_000_000;
+
+        // 주문 10건에 취소 8건은 한도 이내
+        for i in 0..10 {
+            assert!(throttle.record_order("trader", now + i).is_none());
+        }
+        for i in 0..8 {
+            assert!(throttle.record_cancel("trader", now + 10 + i).is_none());
+        }
+        // 다른 계정 활동은 따로 셈
+        assert!(throttle.record_cancel("other", now).is_none());
+
+        let update = throttle.record_cancel("trader", now + 100).unwrap();
+        assert!(update.throttled);
+        assert_eq!((update.orders, update.cancels, update.until), (10, 9, now + 100 + 30_000));
+        assert!(throttle.record_cancel("trader", now + 101).is_none());
+        assert!(throttle.status("other", now).is_none());
+
+        // 초당 2건까지만 허용
+        let at = now + 1_000;
+        assert!(throttle.admit("trader", at));
+        assert!(throttle.admit("trader", at + 1));
+        assert!(!throttle.admit("trader", at + 2));
+        assert!(throttle.admit("trader", at + 1_000));
+        assert!(throttle.admit("other", at));
+        assert_eq!(throttle.status("trader", at).unwrap().rejected_orders, 1);
+    }
+
+    #[test]
+    fn test_releases_after_duration_and_prunes_window() {
+        let throttle = throttle();
+        let now = 5_000_000;
+
+        // 구간이 지난 취소는 비율에서 빠짐
+        for _ in 0..20 {
+            throttle.record_cancel("trader", now);
+        }
+        for i in 0..10 {
+            assert!(throttle.record_order("trader", now + 11_000 + i).is_none());
+        }
+
+        for _ in 0..9 {
+            throttle.record_cancel("trader", now + 12_000);
+        }
+        assert!(throttle.status("trader", now + 12_000).is_some());
+
+        assert!(throttle.release_expired(now + 20_000).is_empty());
+        let released = throttle.release_expired(now + 12_000 + 30_000);
+        assert_eq!(released.len(), 1);
+        assert!(!released[0].throttled);
+        assert!(throttle.status("trader", now + 50_000).is_none());
+        assert!(throttle.admit("trader", now + 50_000));
+    }
+
+    #[test]
+    fn test_clock_drives_release() {
+        use crate::util::clock::SimClock;
+
+        let clock = SimClock::new(1_000_000);
+        let throttle = throttle().with_clock(clock.shared());
+        for _ in 0..10 {
+            throttle.record_order("trader", throttle.now_millis());
+        }
+        for _ in 0..9 {
+            throttle.record_cancel("trader", throttle.now_millis());
+        }
+        assert!(throttle.status("trader", throttle.now_millis()).is_some());
+
+        // 유지 시간을 기다리지 않고 가상 시각으로 해제
+        clock.advance(Duration::from_secs(29));
+        assert!(throttle.release_expired(throttle.now_millis()).is_empty());
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(throttle.release_expired(throttle.now_millis()).len(), 1);
+    }
+}
@@ -0,0 +1,391 @@
+//! 수신 대기 전략 구현
+//!
+//! 시퀀서와 매칭 엔진의 수신 루프가 큐를 기다리는 방식을 정합니다.
+//! 블로킹 수신은 스레드를 재우고 깨우는 데 수십 마이크로초가 걸리므로,
+//! 부하가 몰릴 때는 잠깐 스핀하고 그다음 양보(yield)한 뒤에야 잠드는(park) 혼합 방식을 씁니다.
+//! - 스핀: `spin` 동안 바쁜 대기 (가장 빠르게 깨어나지만 CPU 한 코어를 씀)
+//! - 양보: 다음 `yield_for` 동안 다른 스레드에 CPU를 양보하며 확인
+//! - 대기: 남은 시간은 블로킹 수신 (기존 방식)
+//!
+//! 두 시간이 모두 0이면 기존 블로킹 수신과 같습니다.
+//! 단계별 수신 수와 단계 전이 수는 MetricsCollector로 노출됩니다.
+
+use std::hint;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{RecvError, RecvTimeoutError, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::performance::MetricsCollector;
+use crate::sequencer::backpressure::BoundedReceiver;
+
+/// 수신 대기 전략 설정
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WaitStrategyConfig {
+    /// 바쁜 대기 시간 (0이면 스핀 생략)
+    pub spin: Duration,
+    /// 스핀 후 양보하며 확인하는 시간 (0이면 양보 생략)
+    pub yield_for: Duration,
+}
+
+impl WaitStrategyConfig {
+    /// 혼합 대기 설정 (스핀 → 양보 → 대기)
+    pub fn hybrid(spin: Duration, yield_for: Duration) -> Self {
+        Self { spin, yield_for }
+    }
+
+    /// 블로킹 수신만 사용하는지 여부
+    pub fn is_blocking(&self) -> bool {
+        self.spin.is_zero() && self.yield_for.is_zero()
+    }
+}
+
+/// 메시지를 받은 대기 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitPhase {
+    /// 바쁜 대기 중 수신
+    Spin,
+    /// 양보 중 수신
+    Yield,
+    /// 블로킹 수신
+    Park,
+}
+
+impl WaitPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WaitPhase::Spin => "spin",
+            WaitPhase::Yield => "yield",
+            WaitPhase::Park => "park",
+        }
+    }
+}
+
+/// 대기 전략 통계
+#[derive(Debug)]
+pub struct WaitStats {
+    /// 수신 루프 이름 (메트릭 이름에 사용)
+    name: String,
+    /// 스핀 중 수신 수
+    spin_wakeups: AtomicU64,
+    /// 양보 중 수신 수
+    yield_wakeups: AtomicU64,
+    /// 블로킹 수신 수
+    park_wakeups: AtomicU64,
+    /// 제한 시간 초과 수
+    timeouts: AtomicU64,
+    /// 스핀 → 양보 전이 수
+    spin_to_yield: AtomicU64,
+    /// 양보(또는 스핀) → 대기 전이 수
+    to_park: AtomicU64,
+}
+
+impl WaitStats {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            spin_wakeups: AtomicU64::new(0),
+            yield_wakeups: AtomicU64::new(0),
+            park_wakeups: AtomicU64::new(0),
+            timeouts: AtomicU64::new(0),
+            spin_to_yield: AtomicU64::new(0),
+            to_park: AtomicU64::new(0),
+        }
+    }
+
+    fn record_wakeup(&self, phase: WaitPhase) {
+        let counter = match phase {
+            WaitPhase::Spin => &self.spin_wakeups,
+            WaitPhase::Yield => &self.yield_wakeups,
+            WaitPhase::Park => &self.park_wakeups,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 단계별 수신 수
+    pub fn wakeups(&self, phase: WaitPhase) -> u64 {
+        match phase {
+            WaitPhase::Spin => self.spin_wakeups.load(Ordering::Relaxed),
+            WaitPhase::Yield => self.yield_wakeups.load(Ordering::Relaxed),
+            WaitPhase::Park => self.park_wakeups.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn timeouts(&self) -> u64 {
+        self.timeouts.load(Ordering::Relaxed)
+    }
+
+    pub fn spin_to_yield(&self) -> u64 {
+        self.spin_to_yield.load(Ordering::Relaxed)
+    }
+
+    pub fn to_park(&self) -> u64 {
+        self.to_park.load(Ordering::Relaxed)
+    }
+
+    /// 전체 단계 전이 수
+    pub fn transitions(&self) -> u64 {
+        self.spin_to_yield() + self.to_park()
+    }
+}
+
+/// 바쁜 대기 단계 결과
+enum Poll<T> {
+    Ready(T),
+    Disconnected,
+    Elapsed,
+}
+
+/// 수신 대기 전략 (복제본은 통계를 공유)
+#[derive(Debug, Clone)]
+pub struct WaitStrategy {
+    config: WaitStrategyConfig,
+    stats: Arc<WaitStats>,
+}
+
+impl WaitStrategy {
+    /// 새 대기 전략 생성
+    pub fn new(name: &str, config: WaitStrategyConfig) -> Self {
+        Self { config, stats: Arc::new(WaitStats::new(name)) }
+    }
+
+    /// 블로킹 수신만 사용하는 대기 전략
+    pub fn blocking(name: &str) -> Self {
+        Self::new(name, WaitStrategyConfig::default())
+    }
+
+    pub fn config(&self) -> WaitStrategyConfig {
+        self.config
+    }
+
+    /// 통계 조회 (MetricsCollector 발행용)
+    pub fn stats(&self) -> Arc<WaitStats> {
+        self.stats.clone()
+    }
+
+    /// 메시지 수신 (스핀 → 양보 → 대기)
+    pub fn recv<T>(&self, rx: &BoundedReceiver<T>) -> Result<T, RecvError> {
+        let start = Instant::now();
+        match self.poll(rx, start, None) {
+            Poll::Ready(item) => return Ok(item),
+            Poll::Disconnected => return Err(RecvError),
+            Poll::Elapsed => {}
+        }
+        let item = rx.recv()?;
+        self.stats.record_wakeup(WaitPhase::Park);
+        Ok(item)
+    }
+
+    /// 제한 시간 동안 메시지 수신 (스핀 → 양보 → 대기)
+    ///
+    /// 스핀/양보 시간도 제한 시간에 포함됩니다.
+    pub fn recv_timeout<T>(&self, rx: &BoundedReceiver<T>, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        let start = Instant::now();
+        match self.poll(rx, start, Some(timeout)) {
+            Poll::Ready(item) => return Ok(item),
+            Poll::Disconnected => return Err(RecvTimeoutError::Disconnected),
+            Poll::Elapsed => {}
+        }
+        let remaining = timeout.saturating_sub(start.elapsed());
+        match rx.recv_timeout(remaining) {
+            Ok(item) => {
+                self.stats.record_wakeup(WaitPhase::Park);
+                Ok(item)
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                self.stats.timeouts.fetch_add(1, Ordering::Relaxed);
+                Err(RecvTimeoutError::Timeout)
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(RecvTimeoutError::Disconnected),
+        }
+    }
+
+    /// 스핀/양보 단계 (대기 단계로 넘어가면 Elapsed)
+    fn poll<T>(&self, rx: &BoundedReceiver<T>, start: Instant, timeout: Option<Duration>) -> Poll<T> {
+        if self.config.is_blocking() {
+            return Poll::Elapsed;
+        }
+        let limit = |phase_end: Duration| timeout.map_or(phase_end, |timeout| phase_end.min(timeout));
+
+        let spin_end = limit(self.config.spin);
+        let yield_end = limit(self.config.spin + self.config.yield_for);
+        let mut phase = if self.config.spin.is_zero() { WaitPhase::Yield } else { WaitPhase::Spin };
+        loop {
+            match rx.try_recv() {
+                Ok(item) => {
+                    self.stats.record_wakeup(phase);
+                    return Poll::Ready(item);
+                }
+                Err(TryRecvError::Disconnected) => return Poll::Disconnected,
+                Err(TryRecvError::Empty) => {}
+            }
+
+            let elapsed = start.elapsed();
+            if phase == WaitPhase::Spin && elapsed >= spin_end {
+                if self.config.yield_for.is_zero() || elapsed >= yield_end {
+                    break;
+                }
+                self.stats.spin_to_yield.fetch_add(1, Ordering::Relaxed);
+                phase = WaitPhase::Yield;
+            } else if phase == WaitPhase::Yield && elapsed >= yield_end {
+                break;
+            }
+
+            match phase {
+                WaitPhase::Spin => hint::spin_loop(),
+                _ => thread::yield_now(),
+            }
+        }
+        self.stats.to_park.fetch_add(1, Ordering::Relaxed);
+        Poll::Elapsed
+    }
+}
+
+/// 대기 전략 통계 모음
+#[derive(Debug, Clone, Default)]
+pub struct WaitStrategyMetrics {
+    stats: Vec<Arc<WaitStats>>,
+}
+
+impl WaitStrategyMetrics {
+    /// 새 통계 모음 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 통계 등록
+    pub fn register(&mut self, stats: Arc<WaitStats>) {
+        self.stats.push(stats);
+    }
+
+    /// 등록된 통계 조회
+    pub fn stats(&self) -> &[Arc<WaitStats>] {
+        &self.stats
+    }
+
+    /// MetricsCollector로 통계 발행
+    pub async fn publish(&self, collector: &MetricsCollector) {
+        for stats in &self.stats {
+            let prefix = format!("sequencer.wait.{}", stats.name());
+            for phase in [WaitPhase::Spin, WaitPhase::Yield, WaitPhase::Park] {
+                collector.set_gauge(&format!("{}.wakeups.{}", prefix, phase.as_str()), stats.wakeups(phase)).await;
+            }
+            collector.set_gauge(&format!("{}.timeouts", prefix), stats.timeouts()).await;
+            collector.set_gauge(&format!("{}.transitions.spin_to_yield", prefix), stats.spin_to_yield()).await;
+            collector.set_gauge(&format!("{}.transitions.to_park", prefix), stats.to_park()).await;
+            collector.set_gauge(&format!("{}.transitions", prefix), stats.transitions()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sequencer::backpressure::{bounded_queue, OverflowPolicy};
+
+    #[test]
+    fn test_blocking_strategy_parks_without_transitions() {
+        let (tx, rx) = bounded_queue::<u32>("orders", 4, OverflowPolicy::Block);
+        let strategy = WaitStrategy::blocking("orders");
+
+        tx.send(1).unwrap();
+        assert_eq!(strategy.recv_timeout(&rx, Duration::from_millis(10)), Ok(1));
+        assert_eq!(strategy.recv_timeout(&rx, Duration::from_millis(1)), Err(RecvTimeoutError::Timeout));
+
+        let stats = strategy.stats();
+        assert_eq!(stats.wakeups(WaitPhase::Park), 1);
+        assert_eq!(stats.timeouts(), 1);
+        assert_eq!(stats.transitions(), 0);
+    }
+
+    #[test]
+    fn test_hybrid_strategy_receives_while_spinning() {
+        let (tx, rx) = bounded_queue::<u32>("orders", 4, OverflowPolicy::Block);
+        let strategy = WaitStrategy::new("orders", WaitStrategyConfig::hybrid(Duration::from_millis(50), Duration::from_millis(50)));
+
+        tx.send(7).unwrap();
+        assert_eq!(strategy.recv(&rx), Ok(7));
+        assert_eq!(rx.gauge().depth(), 0);
+
+        let stats = strategy.stats();
+        assert_eq!(stats.wakeups(WaitPhase::Spin), 1);
+        assert_eq!(stats.transitions(), 0);
+    }
+
+    #[test]
+    fn test_hybrid_strategy_transitions_to_park() {
+        let (tx, rx) = bounded_queue::<u32>("orders", 4, OverflowPolicy::Block);
+        let strategy = WaitStrategy::new("orders", WaitStrategyConfig::hybrid(Duration::from_micros(200), Duration::from_micros(200)));
+
+        let sender = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            tx.send(3).unwrap();
+        });
+        assert_eq!(strategy.recv_timeout(&rx, Duration::from_secs(5)), Ok(3));
+        sender.join().unwrap();
+
+        let stats = strategy.stats();
+        assert_eq!(stats.wakeups(WaitPhase::Park), 1);
+        assert_eq!(stats.spin_to_yield(), 1);
+        assert_eq!(stats.to_park(), 1);
+        assert_eq!(stats.transitions(), 2);
+
+        // 송신측이 끊기면 스핀 중에도 바로 종료
+        assert_eq!(strategy.recv_timeout(&rx, Duration::from_secs(5)), Err(RecvTimeoutError::Disconnected));
+        assert_eq!(strategy.recv(&rx), Err(RecvError));
+    }
+
+    #[test]
+    fn test_timeout_shorter_than_spin() {
+        let (_tx, rx) = bounded_queue::<u32>("orders", 4, OverflowPolicy::Block);
+        let strategy = WaitStrategy::new("orders", WaitStrategyConfig::hybrid(Duration::from_secs(10), Duration::ZERO));
+
+        let start = Instant::now();
+        assert_eq!(strategy.recv_timeout(&rx, Duration::from_millis(2)), Err(RecvTimeoutError::Timeout));
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(strategy.stats().timeouts(), 1);
+        assert_eq!(strategy.stats().spin_to_yield(), 0);
+    }
+
+    /// 대기 전략별 깨어남 지연 측정
+    ///
+    /// 실행: `cargo test --release wait_strategy::tests::bench -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_wakeup_latency() {
+        const ROUNDS: usize = 2_000;
+        let configs = [
+            ("blocking", WaitStrategyConfig::default()),
+            ("hybrid", WaitStrategyConfig::hybrid(Duration::from_micros(50), Duration::from_micros(200))),
+        ];
+        for (label, config) in configs {
+            let (tx, rx) = bounded_queue::<Instant>("orders", 16, OverflowPolicy::Block);
+            let strategy = WaitStrategy::new(label, config);
+            let receiver = thread::spawn(move || {
+                let mut latencies = Vec::with_capacity(ROUNDS);
+                while let Ok(sent_at) = strategy.recv(&rx) {
+                    latencies.push(sent_at.elapsed());
+                }
+                latencies
+            });
+            for _ in 0..ROUNDS {
+                thread::sleep(Duration::from_micros(100));
+                tx.send(Instant::now()).unwrap();
+            }
+            drop(tx);
+            let mut latencies = receiver.join().unwrap();
+            latencies.sort();
+            println!(
+                "{}: p50 {:?}, p99 {:?}",
+                label,
+                latencies[latencies.len() / 2],
+                latencies[latencies.len() * 99 / 100]
+            );
+        }
+    }
+}
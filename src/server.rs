@@ -1,49 +1,154 @@
 use std::sync::Arc;
-use std::sync::mpsc;
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
 use tokio::sync::Mutex;
 use tokio::sync::broadcast;
-use axum::Router;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use sqlx::sqlite::SqlitePool;
-use log::{info, warn, debug, error};
+use log::{info, warn, error};
 
-use crate::api::create_api_router;
+use crate::api::{count_client_queries, create_api_router, track_request_latency, OrderValidator, WebSocketConfig, WebSocketMetrics, REQUEST_LATENCY_TIMER};
+use crate::data::{load_seed_orders, seed_order_book};
 use crate::matching_engine::engine::MatchingEngine;
-use crate::matching_engine::model::{Order, ExecutionReport};
-use crate::mdp::MarketDataPublisher;
-use crate::sequencer::OrderSequencer;
+use crate::matching_engine::book_memory::BookLifecycleConfig;
+use crate::matching_engine::order_ack::{OrderAckRegistry, DEFAULT_ORDER_ACK_TIMEOUT};
+use crate::matching_engine::model::{Order, ExecutionReport, MarketProtection};
+use crate::mdp::{CandleBackfillConfig, MarketDataPlayer, MarketDataPublisher, MarketDataRecorder, PlaybackConfig, RecorderConfig};
+use crate::sequencer::{OrderSequencer, SequencerQueueConfig, BoundedSender, OverflowPolicy, bounded_queue, ReplicationConfig, ReplicationJournal, ReplicationServer, ReplicationState, StandbyReplicator, GlobalSequence, PrivateEventLog, OrderThrottle, ThrottleConfig, AdmissionConfig, AdmissionQueue, MessageUsage, MessageUsageConfig, WaitStrategy};
 use crate::api::models::WebSocketMessage;
-use crate::db::AsyncCommitManager;
-use crate::mq::{RedisStreamsProducer, RedisConsumerManager, ConsumerConfig, KafkaProducer, KafkaConsumerConfig, MDPConsumer, ExternalExchangeConsumer, RegulatoryConsumer, AnalyticsConsumer, RabbitMQProducer, RabbitMQConsumerConfig, WebSocketServerConsumer, LoadBalancerConsumer, DeadLetterQueueConsumer, LocalBackupQueue, MQHealthMonitor, RecoveryManager, HealthCheckConfig, RecoveryConfig};
-use crate::mdp::{MDPConsumer as MDPConsumerType, MDPConsumerConfig, MDPApiServerBuilder, MDPCacheManager, CacheConfig};
-use crate::external::{ExternalPriceSyncManager, PriceSyncConfig, RegulatoryReportingManager, RegulatoryReportingConfig, AnalyticsIntegrationManager, AnalyticsIntegrationConfig};
-use crate::performance::{BatchProcessor, BatchProcessorConfig, WorkerPool, ParallelConsumerConfig, CacheOptimizer, CacheOptimizerConfig, MetricsCollector, MetricsCollectorConfig, PerformanceAnalyzer};
-use crate::monitoring::{SystemHealthMonitor, HealthCheckConfig as MonitoringHealthCheckConfig, NotificationSystem, NotificationConfig, DashboardServer, DashboardConfig, DashboardDataProvider, LogAnalyzer, LogAnalyzerConfig};
-
-/// 사용자 잔고 정보
-#[derive(Debug, Clone)]
-pub struct UserBalance {
-    pub krw: u64,
-    pub btc: u64,
-}
+use crate::api::tls::{self, TlsConfig};
+use crate::auth::{self, AuthConfig, AuthService};
+use crate::totp::{TotpConfig, TotpRegistry};
+use crate::db::{backup, ArchiveConfig, Archiver, AsyncCommitManager, BackupManager, BackupMetadata, MetricsHistoryConfig, MetricsHistoryService, RebateConfig, RebateService, RetentionConfig, RetentionJob};
+use crate::db::repository::{AmlRuleSetRepository, AuditLogRepository, ExecutionRepository, NotificationRoutingRuleRepository, PrivateEventRepository};
+use crate::mq::{RedisStreamsProducer, RedisConsumerManager, ConsumerConfig, PendingClaimConfig, PendingClaimMetrics, KafkaProducer, BBO_TOPIC, FUNDING_TOPIC, KafkaConsumerConfig, ConsumerSource, ConsumerLagRegistry, MDPConsumer, ExternalExchangeConsumer, RegulatoryConsumer, AnalyticsConsumer, RabbitMQProducer, RabbitMQConsumerConfig, LoadBalancerConsumer, DeadLetterQueueConsumer, QuarantineStore, LocalBackupQueue, BackupQueueConfig, PublishRetry, PublishRetryConfig, KafkaBatchConfig, WireFormat, OrderBookUpdateMessage, MQHealthMonitor, RecoveryManager, HealthCheckConfig, RecoveryConfig, MQType};
+use crate::mdp::{MDPConsumer as MDPConsumerType, MDPConsumerConfig, MDPApiServerBuilder, MDPCacheManager, CacheConfig, ExecutionSnapshotRecovery};
+use crate::kill_switch::KillSwitch;
+use crate::rbac::{Rbac, RbacConfig};
+use crate::kyc::{KycConfig, KycRegistry};
+use crate::risk::{RiskLimits, RiskManager};
+use crate::fee::{FeeConfig, FeeEngine};
+use crate::funding::{FundingConfig, FundingInputs, FundingService};
+use crate::futures::{FuturesConfig, FuturesService};
+use crate::currency::{CurrencyConfig, CurrencyConverter};
+use crate::external::{ExternalPriceSyncManager, PriceSyncConfig, RegulatoryReportingManager, RegulatoryReportingConfig, AnalyticsIntegrationManager, AnalyticsIntegrationConfig, MockExchangeAdapter, RouterConfig, SmartOrderRouter, ArbitrageAlertConfig, ArbitrageAlertService, IndexPriceConfig, IndexPriceService, AmlRuleSet, ReportDeliveryConfig, ReportDeliveryService};
+use crate::performance::{BatchProcessor, BatchProcessorConfig, WorkerPool, ParallelConsumerConfig, AutoscaleConfig, CacheOptimizer, CacheOptimizerConfig, AdaptiveCacheConfig, MetricsCollector, MetricsCollectorConfig, PerformanceAnalyzer};
+use crate::monitoring::{SystemHealthMonitor, HealthCheckConfig as MonitoringHealthCheckConfig, SqliteProbe, RedisProbe, KafkaProbe, RabbitMqProbe, EngineProbe, RestProbe, ServiceType, AutoRecoveryManager, AutoRecoveryConfig, FnRecoveryAction, FlushBackupQueueAction, ReopenDbPoolAction, SupervisedTask, ReadinessChecker, ReadinessConfig, NotificationSystem, NotificationConfig, notification_routing, IncidentTracker, DashboardServer, DashboardConfig, DashboardDataProvider, QueueSample, LogAnalyzer, LogAnalyzerConfig};
+use crate::util::clock::{SharedClock, SystemClock};
 
-impl Default for UserBalance {
-    fn default() -> Self {
-        Self {
-            krw: 50_000_000_000, // 500억원 (테스트용)
-            btc: 100_000_000,    // 100 BTC (테스트용)
-        }
-    }
-}
+#[cfg(test)]
+pub(crate) mod test_harness;
 
 /// 서버 설정
 #[derive(Clone)]
 pub struct ServerConfig {
     pub rest_port: u16,
     pub ws_port: u16,
+    /// MDP API 서버 포트 (0이면 임의 포트)
+    pub mdp_api_port: u16,
+    /// Redis Streams 주소 (None이면 Redis 체결 스트림과 Consumer 비활성화)
+    pub redis_url: Option<String>,
+    /// RabbitMQ 주소 (None이면 WebSocket 알림을 RabbitMQ 대신 직접 브로드캐스트)
+    pub rabbitmq_url: Option<String>,
     pub symbols: Vec<String>,
+    /// 심볼별 1회 최대 주문 수량 (없으면 제한 없음)
+    pub max_order_size: HashMap<String, u64>,
+    /// 심볼 공통 시장가 주문 보호 기본값
+    pub market_protection: MarketProtection,
+    /// 배치 경매로 매칭할 심볼과 경매 주기 (없는 심볼은 연속 매칭)
+    pub batch_auctions: HashMap<String, Duration>,
+    /// 만료 시간 없는 주문의 최대 대기 시간 (초, None이면 무기한)
+    pub max_order_age_secs: Option<u64>,
+    /// 주문장 가격 레벨 용량 정리 (None이면 정리하지 않음)
+    pub book_lifecycle: Option<BookLifecycleConfig>,
+    /// WebSocket 호가창 키프레임(전체 호가) 간격 (그 사이에는 바뀐 레벨만 발행)
+    pub orderbook_keyframe_interval: Duration,
+    /// 시퀀서 큐 용량 및 오버플로 정책
+    pub queue_config: SequencerQueueConfig,
+    /// 주문 큐 포화 시 서버 재시도 대기열 (주문에 `max_wait_ms`를 지정한 경우만)
+    pub order_admission: AdmissionConfig,
+    /// WebSocket 연결 관리 설정
+    pub websocket: WebSocketConfig,
+    /// 티커 발행 주기
+    pub ticker_interval: Duration,
+    /// 진행 중인 봉 WebSocket 프레임 최소 전송 간격 (0이면 체결마다)
+    pub candle_throttle: Duration,
+    /// 시작 시 저장된 체결로 봉차트를 재구성할 기간
+    pub candle_backfill: CandleBackfillConfig,
+    /// 시장 데이터 녹화 (None이면 녹화 안 함)
+    pub recording: Option<RecorderConfig>,
+    /// 녹화 데이터 재생 모드 (설정 시 녹화 파일을 WebSocket/Kafka로 재발행)
+    pub playback: Option<PlaybackConfig>,
+    /// 외부 거래소 주문 라우팅 (None이면 `route_external` 요청도 로컬에서만 처리)
+    pub order_routing: Option<RouterConfig>,
+    /// 차익거래 기회 알림 규칙 (스프레드 기준, 연속 틱 수)
+    pub arbitrage_alert: ArbitrageAlertConfig,
+    /// 외부 거래소 시세 기반 인덱스/공정 가격 (계산 주기, 시세 지연/이상치 기준, 인덱스 괴리 서킷 브레이커)
+    pub index_price: IndexPriceConfig,
+    /// AML 규칙 (None이면 기본 규칙, DB에 더 높은 버전이 있으면 그 버전 사용)
+    pub aml_rules: Option<AmlRuleSet>,
+    /// 규제 보고서 전송 (None이면 모의 제출)
+    pub report_delivery: Option<ReportDeliveryConfig>,
+    /// 인증 단계별 1회 주문 금액 한도
+    pub kyc: KycConfig,
+    /// 계정 공통 사전 리스크 한도 기본값 (계정별 한도가 없는 항목에 적용)
+    pub risk_limits: RiskLimits,
+    /// 취소 비율 과다 계정 주문 속도 제한 기준 (None이면 제한 안 함)
+    pub order_throttle: Option<ThrottleConfig>,
+    /// 계정별 분당 메시지 집계 (소프트/하드 상한, 저장 주기, 보존 기간)
+    pub message_usage: MessageUsageConfig,
+    /// 마켓 메이커 리베이트 (심볼별 월 예산, 최소 최우선 호가 체류율, 유동성 수집 주기)
+    pub rebate: RebateConfig,
+    /// 메트릭 수집기 (외부 싱크 목록, 싱크 전송 주기)
+    pub metrics: MetricsCollectorConfig,
+    /// 성능 지표 시간별 롤업 (수집 주기, 보관 기간)
+    pub metrics_history: MetricsHistoryConfig,
+    /// 30일 거래대금 기준 수수료 등급표와 재산정 주기
+    pub fee: FeeConfig,
+    /// 무기한 상품 펀딩 정산 (대상 심볼, 정산 주기, 이자율, 비율 상한)
+    pub funding: FundingConfig,
+    /// 만기 선물 상품 (심볼별 만기, 기초 인덱스 심볼, 정산 방식)
+    pub futures: FuturesConfig,
+    /// 체결/감사 로그 보존 정책 (None이면 정리하지 않음)
+    pub retention: Option<RetentionConfig>,
+    /// 마감된 날짜의 체결/1분봉/감사 로그 콜드 스토리지 보관 (None이면 보관하지 않음)
+    pub archive: Option<ArchiveConfig>,
+    /// DB 백업 디렉터리 (None이면 관리자 백업 API 비활성화)
+    pub backup_dir: Option<PathBuf>,
+    /// 시작 전에 복원한 DB 백업 (주문장과 복제 저널 순번을 백업 시점에 맞춤)
+    pub restored_backup: Option<BackupMetadata>,
+    /// 관리자 API 토큰 (None이면 관리자 API 비활성화)
+    pub admin_token: Option<String>,
+    /// API 키/세션 토큰 인증 (None이면 인증 없이 주문/계좌 API 허용)
+    pub auth: Option<AuthConfig>,
+    /// 민감한 관리자 작업 2단계 인증 (None이면 관리자 토큰만 확인)
+    pub admin_totp: Option<TotpConfig>,
+    /// 역할을 지정하지 않은 계정/관리자의 기본 역할 (RBAC)
+    pub rbac: RbacConfig,
+    /// 알림 채널 (Slack 웹훅, SMTP 이메일)
+    pub notification: NotificationConfig,
+    /// 엔진 상태 복제 (저널 스트림 포트, 대기 인스턴스로 따라갈 주 인스턴스)
+    pub replication: ReplicationConfig,
+    /// 자산 레지스트리, 보고 통화, 고정 환율
+    pub currency: CurrencyConfig,
+    /// MQ 장애 시 로컬 백업 큐 (세그먼트 디렉터리, 보관 기간, 디스크 한도)
+    pub backup_queue: BackupQueueConfig,
+    /// MQ 발행 재시도 정책 (전송 타임아웃, 지수 백오프, 전체 제한 시간, 소진 시 백업 큐)
+    pub publish_retry: PublishRetryConfig,
+    /// Kafka 호가창 업데이트 배치/압축 (심볼별 최대 메시지 수, linger, 압축 방식)
+    pub kafka_batch: KafkaBatchConfig,
+    /// Redis Streams 체결 메시지 형식 (내부 MQ는 기본 rkyv, Kafka 배치 형식은 `kafka_batch.wire_format`)
+    pub mq_wire_format: WireFormat,
+    /// REST/WebSocket TLS 종단 (None이면 평문 HTTP, 관리자 CA 설정 시 관리자 엔드포인트 mTLS)
+    pub tls: Option<TlsConfig>,
+    /// 주문 제출 시 매칭 엔진 처리 결과 대기 시간 (넘기면 PENDING 응답)
+    pub order_ack_timeout: Duration,
+    /// 시작 시 초기 호가로 넣을 데이터셋 (None이면 빈 호가창으로 시작)
+    pub seed_dataset: Option<PathBuf>,
+    /// 시각 소스 (GTD 만료, 주문 속도 제한, 봉 마감/티커, 헬스체크 주기가 따름)
+    pub clock: SharedClock,
 }
 
 impl Default for ServerConfig {
@@ -51,7 +156,65 @@ impl Default for ServerConfig {
         Self {
             rest_port: 7000,
             ws_port: 7001,
+            mdp_api_port: 3001,
+            redis_url: Some("redis://localhost:6379".to_string()),
+            rabbitmq_url: Some("amqp://localhost:5672".to_string()),
             symbols: vec!["BTC-KRW".into(), "ETH-KRW".into(), "AAPL".into()],
+            max_order_size: HashMap::from([
+                ("BTC-KRW".into(), 10_000),
+                ("ETH-KRW".into(), 100_000),
+                ("AAPL".into(), 1_000_000),
+            ]),
+            market_protection: MarketProtection {
+                max_slippage_pct: Some(5.0),
+                max_levels: Some(50),
+            },
+            batch_auctions: HashMap::new(),
+            max_order_age_secs: None,
+            book_lifecycle: Some(BookLifecycleConfig::default()),
+            orderbook_keyframe_interval: Duration::from_secs(30),
+            queue_config: SequencerQueueConfig::default(),
+            order_admission: AdmissionConfig::default(),
+            websocket: WebSocketConfig::default(),
+            ticker_interval: Duration::from_secs(1),
+            candle_throttle: Duration::ZERO,
+            candle_backfill: CandleBackfillConfig::default(),
+            recording: None,
+            playback: None,
+            order_routing: None,
+            arbitrage_alert: ArbitrageAlertConfig::default(),
+            index_price: IndexPriceConfig::default(),
+            aml_rules: None,
+            report_delivery: None,
+            kyc: KycConfig::default(),
+            risk_limits: RiskLimits::default(),
+            order_throttle: None,
+            message_usage: MessageUsageConfig::default(),
+            rebate: RebateConfig::default(),
+            metrics: MetricsCollectorConfig::default(),
+            metrics_history: MetricsHistoryConfig::default(),
+            fee: FeeConfig::default(),
+            funding: FundingConfig::default(),
+            futures: FuturesConfig::default(),
+            retention: None,
+            archive: None,
+            backup_dir: None,
+            restored_backup: None,
+            admin_token: None,
+            auth: None,
+            admin_totp: None,
+            rbac: RbacConfig::default(),
+            notification: NotificationConfig::default(),
+            replication: ReplicationConfig::default(),
+            currency: CurrencyConfig::default(),
+            backup_queue: BackupQueueConfig::new("/tmp/mq_backup"),
+            publish_retry: PublishRetryConfig::default(),
+            kafka_batch: KafkaBatchConfig::default(),
+            mq_wire_format: WireFormat::default(),
+            tls: None,
+            order_ack_timeout: DEFAULT_ORDER_ACK_TIMEOUT,
+            seed_dataset: None,
+            clock: SystemClock::shared(),
         }
     }
 }
@@ -61,18 +224,125 @@ impl Default for ServerConfig {
 pub struct ServerState {
     pub engine: Arc<Mutex<MatchingEngine>>,
     pub execution_tx: broadcast::Sender<WebSocketMessage>,
-    pub order_tx: mpsc::Sender<Order>,
+    pub order_tx: BoundedSender<Order>,
+    pub cancel_tx: BoundedSender<Order>,
     pub mdp: Arc<Mutex<MarketDataPublisher>>,
     pub db_pool: SqlitePool,
+    /// 주문 요청 검증기
+    pub order_validator: Arc<OrderValidator>,
+    pub ws_config: WebSocketConfig,
+    pub ws_metrics: Arc<WebSocketMetrics>,
+    /// 스마트 주문 라우터 (외부 거래소 라우팅 비활성화 시 None)
+    pub order_router: Option<Arc<SmartOrderRouter>>,
+    /// 계정별 KYC 상태 (주문 제한)
+    pub kyc: Arc<KycRegistry>,
+    /// 계정별 수수료 등급 (체결 수수료 계산)
+    pub fees: Arc<FeeEngine>,
+    /// 관리자 킬 스위치 (계정 차단, 심볼 거래 중단)
+    pub kill_switch: Arc<KillSwitch>,
+    pub risk: Arc<RiskManager>,
+    /// 관리자 API 토큰
+    pub admin_token: Option<String>,
+    /// API 키/세션 토큰 인증 (None이면 인증 비활성화)
+    pub auth: Option<Arc<AuthService>>,
+    /// 관리자 2단계 인증 (None이면 비활성화)
+    pub admin_totp: Option<Arc<TotpRegistry>>,
+    /// 역할 기반 접근 제어 (경로 묶음별 권한 확인)
+    pub rbac: Arc<Rbac>,
+    /// 알림 시스템 (라우팅 규칙, 에스컬레이션)
+    pub notifications: Arc<NotificationSystem>,
+    /// 알림 인시던트 (확인, 메모, 해결)
+    pub incidents: Arc<IncidentTracker>,
+    /// 관리자 대시보드 (실시간 위젯 업데이트)
+    pub dashboard: Arc<DashboardServer>,
+    /// 준비 상태 점검 (`/readyz`)
+    pub readiness: Arc<ReadinessChecker>,
+    /// 엔진 상태 복제 (대기 인스턴스는 주문 거부)
+    pub replication: Arc<ReplicationState>,
+    /// DB 온라인 백업 (백업 디렉터리를 설정하지 않으면 None)
+    pub backups: Option<Arc<BackupManager>>,
+    /// 보고 통화 환산 (KYC 한도, 수수료, 포트폴리오 평가)
+    pub currency: Arc<CurrencyConverter>,
+    /// MQ 백업 메시지 재발행 (관리자 부분 복구 작업)
+    pub recovery: Arc<RecoveryManager>,
+    /// DLQ 격리 메시지 (관리자 재발행/폐기)
+    pub dead_letters: Arc<QuarantineStore>,
+    /// 전역 시퀀스 번호 (시퀀서와 공유, 주문 접수 시 부여)
+    pub global_sequence: Arc<GlobalSequence>,
+    /// 매칭 엔진 처리 결과를 기다리는 주문
+    pub order_acks: Arc<OrderAckRegistry>,
+    /// 계정별 주문/체결 이벤트 (비공개 채널 재연결 재전송)
+    pub private_events: Arc<PrivateEventLog>,
+    /// 취소 비율 과다 계정 주문 속도 제한 (비공개 채널 연결 시 현재 상태 알림)
+    pub order_throttle: Option<Arc<OrderThrottle>>,
+    /// 계정별 분당 메시지 사용량 (주문/취소/조회, 하드 상한 초과 시 거부)
+    pub message_usage: Arc<MessageUsage>,
+    /// 마켓 메이커 유동성 리베이트 보고서
+    pub rebates: Arc<RebateService>,
+    /// 시간별 성능 지표 추이 (REST 지연, 주문 처리량, 오류율)
+    pub metrics_history: Arc<MetricsHistoryService>,
+    /// 심볼별 인덱스/공정 가격
+    pub index_prices: Arc<IndexPriceService>,
+    /// 펀딩 정산 (이력 조회)
+    pub funding: Arc<FundingService>,
+    /// 만기 상품 상태 (목록 조회)
+    pub futures: Arc<FuturesService>,
+    /// 주문 큐 포화 시 서버 재시도 대기열
+    pub order_admission: Arc<AdmissionQueue>,
 }
 
 /// 서버 시작
 pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    run_server(config, db_pool, None).await
+}
+
+/// 서버 시작 (`listener`가 있으면 REST 포트를 새로 바인딩하지 않고 사용)
+///
+/// 인프로세스 테스트 서버가 임의 포트로 띄울 때 쓰며, `config.rest_port`는 리스너 포트와 같게
+/// 맞춰 두어야 REST 헬스 프로브가 같은 서버를 확인합니다.
+pub(crate) async fn run_server(
+    config: ServerConfig,
+    db_pool: SqlitePool,
+    listener: Option<tokio::net::TcpListener>,
+) -> Result<(), Box<dyn std::error::Error>> {
     println!("xTrader 서버 시작 중...");
 
-    // 채널 생성
-    let (order_tx, order_rx) = mpsc::channel::<Order>();
-    let (exec_tx, exec_rx) = mpsc::channel::<ExecutionReport>();
+    if let Some(tls_config) = &config.tls {
+        tls::validate(tls_config)?;
+    }
+
+    // 만기 상품은 매칭 엔진 심볼이어야 하고 자산 쌍은 기초 심볼을 따름
+    let mut currency_config = config.currency.clone();
+    for contract in &config.futures.contracts {
+        if !config.symbols.contains(&contract.symbol) {
+            return Err(format!("만기 상품 {}이 거래 심볼 목록에 없습니다", contract.symbol).into());
+        }
+        currency_config.registry = currency_config.registry.with_symbol_alias(&contract.symbol, &contract.underlying);
+    }
+
+    // 모든 심볼의 기초/호가 자산이 자산 레지스트리에 있어야 함
+    for symbol in &config.symbols {
+        currency_config.registry.pair(symbol)?;
+    }
+    let currency = Arc::new(CurrencyConverter::new(currency_config));
+
+    // 용량 제한 큐 생성 (주문은 API에서 거부, 체결 보고서는 대기)
+    let queue_config = config.queue_config.clone();
+    let (order_tx, order_rx) = bounded_queue::<Order>(
+        "orders",
+        queue_config.order_queue_capacity,
+        queue_config.order_overflow_policy,
+    );
+    let (cancel_tx, cancel_rx) = bounded_queue::<Order>(
+        "cancels",
+        queue_config.cancel_queue_capacity,
+        queue_config.order_overflow_policy,
+    );
+    let (exec_tx, exec_rx) = bounded_queue::<ExecutionReport>(
+        "executions",
+        queue_config.execution_queue_capacity,
+        OverflowPolicy::Block,
+    );
     
     // 브로드캐스트 채널 생성 (WebSocket용)
     let (broadcast_tx, _broadcast_rx) = broadcast::channel(1000);
@@ -84,13 +354,19 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
     );
 
     // 🚀 Redis Streams Producer 초기화
-    let redis_producer = match RedisStreamsProducer::new("redis://localhost:6379", "executions").await {
-        Ok(producer) => {
-            println!("✅ Redis Streams Producer 초기화 완료");
-            Some(Arc::new(producer))
-        }
-        Err(e) => {
-            println!("⚠️ Redis Streams 연결 실패: {} (계속 실행)", e);
+    let redis_producer = match &config.redis_url {
+        Some(redis_url) => match RedisStreamsProducer::new(redis_url, "executions").await {
+            Ok(producer) => {
+                println!("✅ Redis Streams Producer 초기화 완료");
+                Some(Arc::new(producer.with_wire_format(config.mq_wire_format)))
+            }
+            Err(e) => {
+                println!("⚠️ Redis Streams 연결 실패: {} (계속 실행)", e);
+                None
+            }
+        },
+        None => {
+            println!("⏸️  Redis Streams 비활성화");
             None
         }
     };
@@ -99,7 +375,7 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
     let kafka_producer = match KafkaProducer::new(&["localhost:9092".to_string()], "market-data").await {
         Ok(producer) => {
             println!("✅ Kafka Producer 초기화 완료");
-            Some(Arc::new(producer))
+            Some(Arc::new(producer.with_batching(config.kafka_batch.clone())))
         }
         Err(e) => {
             println!("⚠️ Kafka 연결 실패: {} (계속 실행)", e);
@@ -107,33 +383,58 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
         }
     };
 
+    // 🚀 BBO 전용 Kafka Producer 초기화 (최우선 호가 변경만 발행하는 별도 토픽)
+    let bbo_kafka_producer = match KafkaProducer::new(&["localhost:9092".to_string()], BBO_TOPIC).await {
+        Ok(producer) => Some(Arc::new(producer)),
+        Err(e) => {
+            println!("⚠️ BBO Kafka Producer 초기화 실패: {} (계속 실행)", e);
+            None
+        }
+    };
+
+    // 🚀 펀딩 정산 전용 Kafka Producer 초기화
+    let funding_kafka_producer = match KafkaProducer::new(&["localhost:9092".to_string()], FUNDING_TOPIC).await {
+        Ok(producer) => Some(Arc::new(producer)),
+        Err(e) => {
+            println!("⚠️ 펀딩 Kafka Producer 초기화 실패: {} (계속 실행)", e);
+            None
+        }
+    };
+
     // 🚀 RabbitMQ Producer 초기화
-    let rabbitmq_producer = match RabbitMQProducer::new("amqp://localhost:5672", "websocket_notifications").await {
-        Ok(producer) => {
-            println!("✅ RabbitMQ Producer 초기화 완료");
-            
-            // Exchange 및 Dead Letter Queue 설정
-            if let Err(e) = producer.setup_exchange().await {
-                println!("⚠️ RabbitMQ Exchange 설정 실패: {}", e);
+    let rabbitmq_producer = match &config.rabbitmq_url {
+        Some(rabbitmq_url) => match RabbitMQProducer::new(rabbitmq_url, "websocket_notifications").await {
+            Ok(producer) => {
+                println!("✅ RabbitMQ Producer 초기화 완료");
+                
+                // Exchange 및 Dead Letter Queue 설정
+                if let Err(e) = producer.setup_exchange().await {
+                    println!("⚠️ RabbitMQ Exchange 설정 실패: {}", e);
+                }
+                if let Err(e) = producer.setup_dead_letter_queue().await {
+                    println!("⚠️ RabbitMQ Dead Letter Queue 설정 실패: {}", e);
+                }
+                
+                Some(Arc::new(producer))
             }
-            if let Err(e) = producer.setup_dead_letter_queue().await {
-                println!("⚠️ RabbitMQ Dead Letter Queue 설정 실패: {}", e);
+            Err(e) => {
+                println!("⚠️ RabbitMQ 연결 실패: {} (계속 실행)", e);
+                None
             }
-            
-            Some(Arc::new(producer))
-        }
-        Err(e) => {
-            println!("⚠️ RabbitMQ 연결 실패: {} (계속 실행)", e);
+        },
+        None => {
+            println!("⏸️  RabbitMQ 비활성화 (WebSocket 알림 직접 브로드캐스트)");
             None
         }
     };
 
     // 🚀 장애 복구 시스템 초기화
-    let backup_queue = Arc::new(LocalBackupQueue::new(
-        "/tmp/mq_backup".to_string(),
-        1000,  // 최대 메모리 큐 크기
-        5000,  // 5초마다 디스크 백업
-    ));
+    let backup_queue = Arc::new(LocalBackupQueue::with_config(config.backup_queue.clone()));
+
+    // 발행 재시도를 모두 소진한 메시지만 백업 큐로 보냄
+    let publish_retry = Arc::new(
+        PublishRetry::new(config.publish_retry.clone()).with_backup_queue(backup_queue.clone()),
+    );
 
     let health_monitor = Arc::new(MQHealthMonitor::new(
         HealthCheckConfig::default(),
@@ -175,7 +476,14 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
 
     // 🚀 MDP Consumer 및 API 서버 초기화
     let mdp_config = MDPConsumerConfig::default();
-    let mdp_consumer = Arc::new(MDPConsumerType::new(mdp_config));
+    // 체결 메시지 누락 복구: Producer 재전송 버퍼 → DB 최근 체결 스냅샷
+    let mut mdp_consumer = MDPConsumerType::new(mdp_config);
+    if let Some(producer) = kafka_producer.clone() {
+        mdp_consumer = mdp_consumer.with_gap_recovery(producer);
+    }
+    let mdp_consumer = Arc::new(
+        mdp_consumer.with_gap_recovery(Arc::new(ExecutionSnapshotRecovery::new(ExecutionRepository::new(db_pool.clone())))),
+    );
     
     // MDP Consumer 실행
     let mdp_consumer_clone = mdp_consumer.clone();
@@ -194,21 +502,32 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
 
     // MDP API 서버 시작
     let mdp_consumer_for_api = mdp_consumer.clone();
+    let cache_manager_for_api = cache_manager.clone();
+    let mdp_api_port = config.mdp_api_port;
     tokio::spawn(async move {
         let builder = MDPApiServerBuilder::new()
             .consumer(mdp_consumer_for_api)
-            .port(3001)
+            .cache_manager(cache_manager_for_api)
+            .port(mdp_api_port)
             .host("0.0.0.0".to_string());
         
         if let Err(e) = builder.run().await {
             error!("MDP API 서버 실행 실패: {}", e);
         }
     });
-    println!("✅ MDP API 서버 시작 (포트: 3001)");
+    println!("✅ MDP API 서버 시작 (포트: {})", mdp_api_port);
 
     // 🚀 외부 시스템 연동 초기화
-    let price_sync_config = PriceSyncConfig::default();
-    let price_sync_manager = Arc::new(ExternalPriceSyncManager::new(price_sync_config));
+    #[allow(unused_mut)]
+    let mut price_sync_config = PriceSyncConfig::default();
+    // 실시간 시세 커넥터가 켜진 거래소는 Mock 대신 실제 시세 사용
+    #[cfg(feature = "live-feed")]
+    {
+        price_sync_config.live_exchanges = crate::external::live_feed::enabled_exchanges();
+    }
+    let price_sync_manager = Arc::new(ExternalPriceSyncManager::new(price_sync_config.clone()));
+    #[cfg(feature = "live-feed")]
+    crate::external::live_feed::spawn_feeds(price_sync_manager.clone(), price_sync_config.symbols.clone());
     
     // 가격 동기화 시작
     let price_sync_manager_clone = price_sync_manager.clone();
@@ -217,9 +536,44 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
     });
     println!("✅ 외부 거래소 가격 동기화 시작");
 
+    // 스마트 주문 라우터 (외부 거래소 Mock 커넥터는 가격 동기화 시세로 호가 구성)
+    let order_router = config.order_routing.clone().map(|router_config| {
+        let router = SmartOrderRouter::new(router_config)
+            .with_adapter(Arc::new(MockExchangeAdapter::binance(price_sync_manager.clone())))
+            .with_adapter(Arc::new(MockExchangeAdapter::upbit(price_sync_manager.clone())));
+        println!("✅ 스마트 주문 라우터 활성화: {:?}", router.exchanges());
+        Arc::new(router)
+    });
+
     // 규제 보고 시스템 초기화
-    let regulatory_config = RegulatoryReportingConfig::default();
-    let regulatory_manager = Arc::new(RegulatoryReportingManager::new(regulatory_config));
+    let mut regulatory_config = RegulatoryReportingConfig::default();
+    if let Some(aml_rules) = config.aml_rules.clone() {
+        regulatory_config.aml_rules = aml_rules;
+    }
+    let mut regulatory_manager = RegulatoryReportingManager::new(regulatory_config).with_converter(currency.clone());
+    if let Some(delivery_config) = config.report_delivery.clone() {
+        match ReportDeliveryService::new(delivery_config) {
+            Ok(delivery) => {
+                regulatory_manager = regulatory_manager.with_delivery(Arc::new(delivery));
+                println!("✅ 규제 보고서 전송 활성화");
+            }
+            Err(e) => error!("규제 보고서 전송 설정 실패 (모의 제출로 대체): {}", e),
+        }
+    }
+    let regulatory_manager = Arc::new(regulatory_manager);
+
+    // AML 규칙: DB에 새 버전이 등록되면 1분 안에 반영
+    let aml_rule_repository = AmlRuleSetRepository::new(db_pool.clone());
+    let regulatory_manager_rules = regulatory_manager.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+            if let Err(e) = regulatory_manager_rules.sync_aml_rules(&aml_rule_repository).await {
+                error!("AML 규칙 동기화 실패: {}", e);
+            }
+        }
+    });
     
     // 규제 보고 시작
     let regulatory_manager_clone = regulatory_manager.clone();
@@ -266,9 +620,37 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
         }
     });
 
+    // 🔍 모니터링 및 헬스체크 시스템 초기화
+    // 대시보드 데이터 제공자 초기화 (인시던트 이력 위젯 포함)
+    let dashboard_data_provider = Arc::new(DashboardDataProvider::new());
+
+    // 알림 인시던트 추적 (해결 이력은 대시보드로)
+    let incident_tracker = Arc::new(
+        IncidentTracker::new(db_pool.clone()).with_dashboard(dashboard_data_provider.clone()),
+    );
+    incident_tracker.load_dashboard_history().await?;
+
+    let notification_config = config.notification.clone();
+    let notification_system = Arc::new(
+        NotificationSystem::new(notification_config).with_incident_tracker(incident_tracker.clone()),
+    );
+
+    // 저장된 알림 라우팅 규칙 로드
+    let routing_rules =
+        notification_routing::load_rules(&NotificationRoutingRuleRepository::new(db_pool.clone())).await?;
+    println!("✅ 알림 라우팅 규칙 {}개 로드", routing_rules.len());
+    notification_system.set_routing_rules(routing_rules).await?;
+    
+    // 알림 시스템 시작
+    let notification_system_clone = notification_system.clone();
+    tokio::spawn(async move {
+        notification_system_clone.start().await;
+    });
+    println!("✅ 알림 시스템 시작");
+
     // 🚀 성능 최적화 시스템 초기화
     let batch_config = BatchProcessorConfig::default();
-    let batch_processor = Arc::new(BatchProcessor::new(batch_config, |data| {
+    let batch_processor = Arc::new(BatchProcessor::new(batch_config, |data: Vec<String>| {
         // Mock 배치 처리 함수
         data.into_iter().map(|x| Ok(format!("processed_{}", x))).collect()
     }));
@@ -280,12 +662,12 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
     });
     println!("✅ 배치 처리기 시작");
 
-    // 병렬 소비 워커 풀 초기화
-    let parallel_config = ParallelConsumerConfig::default();
-    let worker_pool = Arc::new(WorkerPool::new(parallel_config, |data| {
+    // 병렬 소비 워커 풀 초기화 (큐 깊이/지연 기준 워커 수 자동 조정)
+    let parallel_config = ParallelConsumerConfig { autoscale: Some(AutoscaleConfig::default()), ..ParallelConsumerConfig::default() };
+    let worker_pool = Arc::new(WorkerPool::new(parallel_config, |data: String| {
         // Mock 병렬 처리 함수
         Ok(format!("processed_{}", data))
-    }));
+    }).with_notifications(notification_system.clone()));
     
     // 워커 풀 시작
     let worker_pool_clone = worker_pool.clone();
@@ -294,9 +676,9 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
     });
     println!("✅ 병렬 소비 워커 풀 시작");
 
-    // 캐시 최적화기 초기화
-    let cache_config = CacheOptimizerConfig::default();
-    let cache_optimizer = Arc::new(CacheOptimizer::new(cache_config));
+    // 캐시 최적화기 초기화 (L1/L2 크기 자동 조정)
+    let cache_config = CacheOptimizerConfig { adaptive: Some(AdaptiveCacheConfig::default()), ..CacheOptimizerConfig::default() };
+    let cache_optimizer = Arc::new(CacheOptimizer::<serde_json::Value>::new(cache_config));
     
     // 캐시 최적화기 시작
     let cache_optimizer_clone = cache_optimizer.clone();
@@ -306,8 +688,8 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
     println!("✅ 캐시 최적화기 시작");
 
     // 메트릭 수집기 초기화
-    let metrics_config = MetricsCollectorConfig::default();
-    let metrics_collector = Arc::new(MetricsCollector::new(metrics_config));
+    let metrics_collector = Arc::new(MetricsCollector::new(config.metrics.clone()));
+    metrics_collector.connect_sinks().await;
     
     // 메트릭 수집기 시작
     let metrics_collector_clone = metrics_collector.clone();
@@ -335,16 +717,19 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
             let batch_stats = batch_processor_monitor.get_stats().await;
             println!("📊 배치 처리 통계: 총 배치 {}개, 메시지 {}개, 처리량 {:.1}/초", 
                      batch_stats.total_batches, batch_stats.total_messages, batch_stats.throughput_per_second);
+            batch_processor_monitor.publish(&metrics_collector_monitor).await;
             
             // 병렬 소비 통계
             let parallel_stats = worker_pool_monitor.get_stats().await;
             println!("⚡ 병렬 소비 통계: 워커 {}개, 처리 {}개, 실패 {}개", 
                      parallel_stats.total_workers, parallel_stats.total_processed, parallel_stats.total_failed);
+            worker_pool_monitor.publish(&metrics_collector_monitor).await;
             
             // 캐시 통계
             let cache_stats = cache_optimizer_monitor.get_stats().await;
             println!("💾 캐시 통계: 히트율 {:.1}%, L1 {}개, L2 {}개, L3 {}개", 
                      cache_stats.hit_rate * 100.0, cache_stats.l1_size, cache_stats.l2_size, cache_stats.l3_size);
+            cache_optimizer_monitor.publish(&metrics_collector_monitor).await;
             
             // 성능 분석 리포트
             let performance_report = performance_analyzer_monitor.generate_performance_report().await;
@@ -359,23 +744,110 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
         }
     });
 
-    // 🔍 모니터링 및 헬스체크 시스템 초기화
-    let notification_config = NotificationConfig::default();
-    let notification_system = Arc::new(NotificationSystem::new(notification_config));
-    
-    // 알림 시스템 시작
-    let notification_system_clone = notification_system.clone();
-    tokio::spawn(async move {
-        notification_system_clone.start().await;
-    });
-    println!("✅ 알림 시스템 시작");
+    // 차익거래 기회 알림 (가격 동기화 결과 구독)
+    let arbitrage_alerts = Arc::new(ArbitrageAlertService::new(
+        config.arbitrage_alert.clone(),
+        notification_system.clone(),
+        db_pool.clone(),
+    ));
+    arbitrage_alerts.spawn(price_sync_manager.subscribe_results());
+    println!(
+        "✅ 차익거래 알림 시작 (스프레드 {:.2}% 이상, {}틱 연속)",
+        config.arbitrage_alert.min_spread_percent, config.arbitrage_alert.consecutive_ticks
+    );
 
     // 헬스체크 모니터 초기화
+    // 🚀 MQ Consumer 실행 (자동 복구 시 재시작할 수 있도록 감독 태스크로 실행)
+    let mut dead_letter_store = QuarantineStore::new(db_pool.clone(), Arc::new(AuditLogRepository::new(db_pool.clone())));
+    if let Some(producer) = &rabbitmq_producer {
+        dead_letter_store = dead_letter_store.with_producer(producer.clone());
+    }
+    let dead_letters = Arc::new(dead_letter_store);
+    let consumer_lags = Arc::new(ConsumerLagRegistry::new());
+    let redis_claim_metrics = Arc::new(PendingClaimMetrics::new());
+    // Redis Consumer는 Producer가 연결된 경우만 실행
+    let consumer_tasks = spawn_mq_consumers(
+        redis_producer.as_ref().and(config.redis_url.as_deref()),
+        &kafka_producer,
+        rabbitmq_producer.as_ref().and(config.rabbitmq_url.as_deref()),
+        &db_pool,
+        &dead_letters,
+        &consumer_lags,
+        &redis_claim_metrics,
+    );
+
+    // 자동 복구 작업 등록: Producer 재연결 → Consumer 재시작 → 백업 큐 재발행 순으로 실행
+    let mut auto_recovery = AutoRecoveryManager::new(AutoRecoveryConfig::default())
+        .with_audit_log(db_pool.clone())
+        .with_action(ServiceType::Database, Arc::new(ReopenDbPoolAction::new(db_pool.clone())));
+    if let Some(producer) = redis_producer.clone() {
+        auto_recovery = auto_recovery.with_action(
+            ServiceType::Redis,
+            Arc::new(FnRecoveryAction::new("reconnect_redis_producer", move || {
+                let producer = producer.clone();
+                async move { producer.reconnect().await.map(|_| "Redis Producer 재연결".to_string()).map_err(|e| e.to_string()) }
+            })),
+        );
+    }
+    if let Some(producer) = kafka_producer.clone() {
+        auto_recovery = auto_recovery.with_action(
+            ServiceType::Kafka,
+            Arc::new(FnRecoveryAction::new("reconnect_kafka_producer", move || {
+                let producer = producer.clone();
+                async move { producer.reconnect().await.map(|_| "Kafka Producer 재연결".to_string()).map_err(|e| e.to_string()) }
+            })),
+        );
+    }
+    if let Some(producer) = rabbitmq_producer.clone() {
+        auto_recovery = auto_recovery.with_action(
+            ServiceType::RabbitMQ,
+            Arc::new(FnRecoveryAction::new("reconnect_rabbitmq_producer", move || {
+                let producer = producer.clone();
+                async move { producer.reconnect().await.map(|_| "RabbitMQ Producer 재연결".to_string()).map_err(|e| e.to_string()) }
+            })),
+        );
+    }
+    for (service_type, task) in consumer_tasks {
+        auto_recovery = auto_recovery.with_action(service_type, task);
+    }
+    for (service_type, mq_type) in [
+        (ServiceType::Redis, MQType::RedisStreams),
+        (ServiceType::Kafka, MQType::Kafka),
+        (ServiceType::RabbitMQ, MQType::RabbitMQ),
+    ] {
+        auto_recovery = auto_recovery
+            .with_action(service_type, Arc::new(FlushBackupQueueAction::new(recovery_manager.clone(), mq_type)));
+    }
+    let auto_recovery = Arc::new(auto_recovery);
+    println!("✅ 자동 복구 작업 {}개 등록", auto_recovery.action_count());
+
+    // 엔진 프로브 채널 (엔진 생성 시 수신 측 연결)
+    let (engine_probe_tx, engine_probe_rx) = std::sync::mpsc::channel();
     let health_config = MonitoringHealthCheckConfig::default();
-    let health_monitor = Arc::new(SystemHealthMonitor::new(health_config, move |message, _type| {
+    let probe_timeout = Duration::from_millis(health_config.timeout_ms);
+    let mut health_monitor = SystemHealthMonitor::new(health_config, move |message, _type| {
         println!("🚨 헬스체크 알림: {}", message);
         Ok(())
-    }));
+    })
+    .with_clock(config.clock.clone())
+    .with_probe(SqliteProbe::new(db_pool.clone()));
+    if let Some(redis_url) = &config.redis_url {
+        health_monitor = health_monitor.with_probe(RedisProbe::new(redis_url.clone()));
+    }
+    if let Some(rabbitmq_url) = &config.rabbitmq_url {
+        health_monitor = health_monitor.with_probe(RabbitMqProbe::new(rabbitmq_url));
+    }
+    let health_monitor = Arc::new(
+        health_monitor
+            .with_probe(KafkaProbe::new("localhost:9092"))
+            .with_probe(EngineProbe::new(engine_probe_tx.clone()))
+            .with_probe(match config.tls {
+                Some(_) => RestProbe::new(format!("https://127.0.0.1:{}/v1/ticker", config.rest_port), probe_timeout)
+                    .with_loopback_tls(),
+                None => RestProbe::new(format!("http://127.0.0.1:{}/v1/ticker", config.rest_port), probe_timeout),
+            })
+            .with_auto_recovery(auto_recovery.clone()),
+    );
     
     // 헬스체크 모니터 시작
     let health_monitor_clone = health_monitor.clone();
@@ -384,9 +856,6 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
     });
     println!("✅ 헬스체크 모니터 시작");
 
-    // 대시보드 데이터 제공자 초기화
-    let dashboard_data_provider = Arc::new(DashboardDataProvider::new());
-    
     // 대시보드 서버 초기화
     let dashboard_config = DashboardConfig::default();
     let dashboard_server = Arc::new(DashboardServer::new(dashboard_config));
@@ -401,7 +870,7 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
 
     // 로그 분석기 초기화
     let log_analyzer_config = LogAnalyzerConfig::default();
-    let log_analyzer = Arc::new(LogAnalyzer::new(log_analyzer_config));
+    let log_analyzer = Arc::new(LogAnalyzer::new(log_analyzer_config).with_notifications(notification_system.clone()));
     
     // 로그 분석기 시작
     let log_analyzer_clone = log_analyzer.clone();
@@ -455,34 +924,215 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
             
             // 로그 분석 통계
             let log_stats = log_analyzer_report.get_stats().await;
-            println!("📝 로그 분석: 총 {}개, 에러율 {:.2}%, 경고율 {:.2}%, 모듈 {}개", 
+            let log_anomalies = log_analyzer_report.get_anomalies().await;
+            println!("📝 로그 분석: 총 {}개, 에러율 {:.2}%, 경고율 {:.2}%, 모듈 {}개, 최근 이상 {}건", 
                      log_stats.total_entries, 
                      log_stats.error_rate, 
                      log_stats.warning_rate, 
-                     log_stats.unique_modules);
+                     log_stats.unique_modules,
+                     log_anomalies.len());
         }
     });
 
+    // 엔진 상태 복제: 주 인스턴스는 시퀀서 저널을 기록, 대기 인스턴스는 주 인스턴스 저널을 적용
+    let replication_journal = Arc::new(ReplicationJournal::new(config.replication.journal_capacity));
+    let replication = Arc::new(match &config.replication.standby_of {
+        Some(primary_addr) => ReplicationState::standby(replication_journal.clone(), primary_addr.clone()),
+        None => ReplicationState::primary(replication_journal.clone()),
+    });
+
+    // 킬 스위치 로드 (재시작 전 차단/중단 상태 유지)
+    let kill_switch = Arc::new(
+        KillSwitch::load(db_pool.clone())
+            .await?
+            .with_notifications(notification_system.clone()),
+    );
+
+    // 외부 거래소 시세로 인덱스/공정 가격 계산 (내부 최우선 호가 구독, 설정 시 인덱스 괴리 서킷 브레이커)
+    let index_prices = Arc::new(
+        IndexPriceService::new(config.index_price.clone(), price_sync_config.symbols.clone(), price_sync_manager.clone())
+            .with_kill_switch(kill_switch.clone())
+            .with_publisher(broadcast_tx.clone())
+            .with_clock(config.clock.clone()),
+    );
+    index_prices.clone().spawn(broadcast_tx.subscribe());
+    match &config.index_price.circuit_breaker {
+        Some(breaker) => println!(
+            "✅ 인덱스 가격 계산 시작 (최소 {}개 거래소, 괴리 {:.2}% {}틱 연속 시 거래 중단)",
+            config.index_price.min_sources, breaker.max_deviation_percent, breaker.consecutive_ticks
+        ),
+        None => println!("✅ 인덱스 가격 계산 시작 (최소 {}개 거래소)", config.index_price.min_sources),
+    }
+
     // 매칭 엔진 생성 (RabbitMQ Producer 초기화 후)
     let mut engine = MatchingEngine::new(config.symbols.clone(), exec_tx, rabbitmq_producer.clone());
     engine.set_broadcast_channel(broadcast_tx.clone());
+    engine.set_clock(config.clock.clone());
+    for symbol in &config.symbols {
+        engine.set_market_protection(symbol, config.market_protection.clone());
+    }
+    for (symbol, interval) in &config.batch_auctions {
+        engine.set_batch_auction(symbol, Some(*interval));
+    }
+    engine.set_max_order_age(config.max_order_age_secs);
+    engine.set_book_lifecycle(config.book_lifecycle);
+    engine.set_orderbook_keyframe_interval(config.orderbook_keyframe_interval);
+    engine.set_probe_channel(engine_probe_rx);
+    engine.set_replication_state(replication.clone());
+    // 주문 수신 대기 전략 (시퀀서와 같은 설정, 통계는 시퀀서 통계와 함께 발행)
+    let engine_wait = WaitStrategy::new("engine", queue_config.wait_strategy);
+    engine.set_wait_strategy(engine_wait.clone());
+    // DB 백업에서 시작: 미체결 주문을 주문장에 다시 올리고, 백업 이후 저널부터 이어서 적용
+    let restored_orders = match &config.restored_backup {
+        Some(restored) => {
+            let orders = backup::resting_orders(&db_pool).await?;
+            engine.restore_resting_orders(orders.clone());
+            replication.resume_at(restored.journal_seq);
+            println!("♻️  DB 백업 복원: 미체결 주문 {}건, 저널 순번 {} 이후부터 재생", orders.len(), restored.journal_seq);
+            orders
+        }
+        None => Vec::new(),
+    };
+    let order_acks = Arc::new(OrderAckRegistry::new(config.order_ack_timeout));
+    engine.set_order_acks(order_acks.clone());
+    engine.set_kill_switch(kill_switch.clone());
+    // 취소 비율 과다 계정 주문 속도 제한 (시퀀서가 판정, 엔진이 적용)
+    let order_throttle = config
+        .order_throttle
+        .clone()
+        .map(|throttle| Arc::new(OrderThrottle::new(throttle).with_clock(config.clock.clone())));
+    if let Some(throttle) = &order_throttle {
+        engine.set_order_throttle(throttle.clone());
+    }
+    if let Some(producer) = &bbo_kafka_producer {
+        engine.set_bbo_producer(producer.clone());
+    }
     let engine = Arc::new(Mutex::new(engine));
 
     // MDP 생성
     let mut mdp = MarketDataPublisher::new(1000);
     mdp.set_broadcast_channel(broadcast_tx.clone());
+    mdp.set_clock(config.clock.clone());
+    mdp.set_candle_throttle(config.candle_throttle);
+    mdp.register_ticker_symbols(&config.symbols).await;
+
+    // 재시작 전 체결로 최근 봉차트 재구성 (서비스 시작 전)
+    let backfill_now = chrono::Utc::now().timestamp() as u64;
+    match crate::mdp::backfill::backfill_candles(
+        &mdp,
+        &ExecutionRepository::new(db_pool.clone()),
+        &config.symbols,
+        &config.candle_backfill,
+        backfill_now,
+    )
+    .await
+    {
+        Ok(count) => println!("✅ 봉차트 백필 완료 (체결 {}건)", count),
+        Err(e) => error!("봉차트 백필 실패 (빈 봉차트로 시작): {}", e),
+    }
     let mdp = Arc::new(Mutex::new(mdp));
 
+    // 전 심볼 티커 주기적 발행 (최근 체결가는 통화 환산에도 사용)
+    let mdp_ticker = mdp.clone();
+    let ticker_interval = config.ticker_interval;
+    let currency_ticker = currency.clone();
+    let ticker_clock = config.clock.clone();
+    tokio::spawn(async move {
+        loop {
+            let mdp = mdp_ticker.lock().await;
+            mdp.publish_tickers().await;
+            currency_ticker.update_from_tickers(&mdp.get_tickers(ticker_clock.now_secs()).await);
+            drop(mdp);
+            ticker_clock.sleep(ticker_interval).await;
+        }
+    });
+
+    // 체결 없이 기간이 끝난 봉 마감 프레임 발행
+    MarketDataPublisher::spawn_candle_closer(mdp.clone(), Duration::from_secs(1));
+
+    // 시장 데이터 재생 모드 또는 녹화
+    if let Some(playback) = config.playback.clone() {
+        println!("⏯️  시장 데이터 재생 모드: {} ({}배속)", playback.path.display(), playback.speed);
+        let player = MarketDataPlayer::new(playback, broadcast_tx.clone())
+            .with_kafka_producer(kafka_producer.clone());
+        tokio::spawn(async move {
+            if let Err(e) = player.run().await {
+                error!("시장 데이터 재생 실패: {}", e);
+            }
+        });
+    } else if let Some(recording) = config.recording.clone() {
+        println!("⏺️  시장 데이터 녹화: {}", recording.directory.display());
+        let recorder_rx = broadcast_tx.subscribe();
+        tokio::spawn(MarketDataRecorder::new(recording).run(recorder_rx));
+    }
+
+    // 호가창 Delta를 Kafka로 발행 (심볼별 배치, linger가 지난 배치는 주기적으로 발행)
+    if let Some(producer) = kafka_producer.clone() {
+        let mut orderbook_rx = broadcast_tx.subscribe();
+        let flush_period = (config.kafka_batch.linger / 2).max(Duration::from_millis(1));
+        tokio::spawn(async move {
+            let mut flush_interval = tokio::time::interval(flush_period);
+            loop {
+                tokio::select! {
+                    received = orderbook_rx.recv() => match received {
+                        Ok(WebSocketMessage::OrderBookDelta(delta)) => {
+                            if let Err(e) = producer.publish_orderbook_update(&delta.symbol, &OrderBookUpdateMessage::from(&delta)).await {
+                                error!("호가창 업데이트 Kafka 발행 실패: {}", e);
+                            }
+                        }
+                        Ok(_) => {}
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!("호가창 Kafka 발행 지연 - {}개 메시지 누락", skipped);
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    _ = flush_interval.tick() => {
+                        if let Err(e) = producer.flush_expired_batches().await {
+                            error!("호가창 배치 Kafka 발행 실패: {}", e);
+                        }
+                    }
+                }
+            }
+            if let Err(e) = producer.flush_batches().await {
+                error!("호가창 배치 Kafka 발행 실패: {}", e);
+            }
+        });
+    }
+
     // 비동기 커밋 루프 시작 (백그라운드)
     let commit_mgr_clone = async_commit_mgr.clone();
     tokio::spawn(async move {
         commit_mgr_clone.run_batch_commit_loop().await;
     });
 
-    // 시퀀서용 채널 생성
-    let (sequencer_tx, sequencer_rx) = mpsc::channel::<Order>();
+    // 시퀀서 → 매칭 엔진 큐 생성 (주문은 버리지 않음)
+    let (sequencer_tx, sequencer_rx) = bounded_queue::<Order>(
+        "engine",
+        queue_config.engine_queue_capacity,
+        OverflowPolicy::Block,
+    );
+
+    // 대기 인스턴스는 복제된 주문을 시퀀서를 거치지 않고 엔진 큐에 직접 넣음
+    let replica_engine_tx = sequencer_tx.clone();
 
-    // 시퀀서 생성 및 실행
+    // 시퀀서 생성 및 실행 (API와 같은 전역 시퀀스 번호 발급기 공유)
+    let global_sequence = Arc::new(GlobalSequence::new());
+    // 계정별 이벤트 시퀀스는 재시작 후에도 DB에 기록된 번호에서 이어감
+    let private_events = Arc::new(PrivateEventLog::default());
+    match PrivateEventRepository::new(db_pool.clone()).last_sequences().await {
+        Ok(sequences) => private_events.restore_sequences(
+            sequences.into_iter().map(|(client_id, sequence)| (client_id, sequence.max(0) as u64)),
+        ),
+        Err(e) => eprintln!("⚠️  계정 이벤트 시퀀스 복원 실패: {}", e),
+    }
+    // 계정별 수수료 등급 (DB에 기록된 마지막 등급에서 이어감)
+    let fees = Arc::new(FeeEngine::load(config.fee.clone(), db_pool.clone()).await?);
+    // 계정별 리스크 한도와 체결 순포지션 (저장된 체결로 다시 계산)
+    let risk = Arc::new(
+        RiskManager::load(config.risk_limits.clone(), db_pool.clone())
+            .await?
+            .with_notifications(notification_system.clone()),
+    );
     let mut sequencer = OrderSequencer::new(
         order_rx,
         sequencer_tx,
@@ -493,286 +1143,712 @@ pub async fn start_server(config: ServerConfig, db_pool: SqlitePool) -> Result<(
         redis_producer.clone(),
         kafka_producer.clone(),
         rabbitmq_producer.clone(),
-    );
+    )
+    .with_market_data_capacity(queue_config.market_data_queue_capacity)
+    .with_cancel_lane(cancel_rx, queue_config.lane_weights)
+    .with_wait_strategy(queue_config.wait_strategy)
+    .with_replication(replication.clone())
+    .with_global_sequence(global_sequence.clone())
+    .with_private_events(private_events.clone())
+    .with_fee_engine(fees.clone())
+    .with_risk_manager(risk.clone())
+    .with_publish_retry(publish_retry.clone());
+    if let Some(throttle) = &order_throttle {
+        sequencer = sequencer.with_order_throttle(throttle.clone());
+    }
+    sequencer.order_lifecycle().restore_open(&restored_orders);
 
-    // 시퀀서 실행 태스크
+    // 수수료 등급 재산정 (티커로 환율이 채워지도록 시작 1분 뒤 한 번, 이후 하루 주기)
+    let fees_recalc = fees.clone();
+    let currency_recalc = currency.clone();
+    let recalculation_interval = config.fee.recalculation_interval;
     tokio::spawn(async move {
-        sequencer.run().await;
+        let first = tokio::time::Instant::now() + Duration::from_secs(60);
+        let mut interval = tokio::time::interval_at(first, recalculation_interval);
+        loop {
+            interval.tick().await;
+            let now = chrono::Utc::now().timestamp() as u64;
+            let to_reporting = |symbol: &str, notional: u64| currency_recalc.notional_to_reporting(symbol, notional).ok();
+            match fees_recalc.recalculate(now, &to_reporting).await {
+                Ok(changes) => info!("수수료 등급 재산정 완료: {}개 계정 변경", changes.len()),
+                Err(e) => error!("수수료 등급 재산정 실패: {}", e),
+            }
+        }
     });
 
-    // 🚀 Redis Consumer Manager 초기화 및 실행
-    let redis_producer_clone = redis_producer.clone();
-    if redis_producer_clone.is_some() {
-        let consumer_configs = vec![
-            ConsumerConfig {
-                redis_url: "redis://localhost:6379".to_string(),
-                stream_name: "executions".to_string(),
-                consumer_group: "execution_processors".to_string(),
-                worker_id: "worker-1".to_string(),
-                batch_size: 100,
-                processing_interval_ms: 100,
-            },
-            ConsumerConfig {
-                redis_url: "redis://localhost:6379".to_string(),
-                stream_name: "executions".to_string(),
-                consumer_group: "execution_processors".to_string(),
-                worker_id: "worker-2".to_string(),
-                batch_size: 100,
-                processing_interval_ms: 100,
-            },
-            ConsumerConfig {
-                redis_url: "redis://localhost:6379".to_string(),
-                stream_name: "executions".to_string(),
-                consumer_group: "execution_processors".to_string(),
-                worker_id: "worker-3".to_string(),
-                batch_size: 100,
-                processing_interval_ms: 100,
-            },
-        ];
-
-        match RedisConsumerManager::new(consumer_configs, db_pool.clone()).await {
-            Ok(consumer_manager) => {
-                println!("✅ Redis Consumer Manager 초기화 완료 (3개 Worker)");
-                
-                // Consumer Manager 실행 (백그라운드)
-                tokio::spawn(async move {
-                    if let Err(e) = consumer_manager.start_all_workers().await {
-                        println!("❌ Redis Consumer Manager 실행 오류: {}", e);
+    // 콜드 스토리지 보관 (보존 정리보다 먼저 돌도록 시작 5분 뒤 한 번, 이후 설정 주기)
+    if let Some(archive) = config.archive.clone() {
+        let archive_interval = archive.interval;
+        let store = Arc::new(crate::external::S3Client::new(archive.storage.clone())?);
+        let archiver = Archiver::new(db_pool.clone(), store, archive);
+        let metrics_collector_archive = metrics_collector.clone();
+        tokio::spawn(async move {
+            let first = tokio::time::Instant::now() + Duration::from_secs(300);
+            let mut interval = tokio::time::interval_at(first, archive_interval);
+            loop {
+                interval.tick().await;
+                let now = chrono::Utc::now().timestamp() as u64;
+                match archiver.run(now).await {
+                    Ok(report) => {
+                        if report != Default::default() {
+                            info!(
+                                "콜드 스토리지 보관 완료: {}일, 객체 {}개 ({}바이트, {}행), 로컬 정리 {}일 (체결 {}건, 감사 로그 {}건)",
+                                report.days_archived,
+                                report.objects_uploaded,
+                                report.bytes_uploaded,
+                                report.rows_archived,
+                                report.days_pruned,
+                                report.executions_pruned,
+                                report.audit_logs_pruned
+                            );
+                        }
+                        report.publish(&metrics_collector_archive).await;
                     }
-                });
-            }
-            Err(e) => {
-                println!("⚠️ Redis Consumer Manager 초기화 실패: {}", e);
+                    Err(e) => {
+                        error!("콜드 스토리지 보관 실패: {}", e);
+                        metrics_collector_archive.increment_counter("archive.failures", 1).await;
+                    }
+                }
             }
-        }
+        });
     }
 
-    // 🚀 Kafka Consumer Manager 초기화 및 실행
-    if let Some(_kafka_producer) = &kafka_producer {
-        // MDP Consumer (Market Data Publisher)
-        let mdp_config = KafkaConsumerConfig {
-            kafka_brokers: vec!["localhost:9092".to_string()],
-            topic_name: "market-data".to_string(),
-            consumer_group: "mdp-group".to_string(),
-            worker_id: "mdp-worker".to_string(),
-            batch_size: 100,
-            processing_interval_ms: 100,
-        };
-
-        match MDPConsumer::new(mdp_config).await {
-            Ok(mdp_consumer) => {
-                println!("✅ MDP Consumer 초기화 완료");
-                
-                tokio::spawn(async move {
-                    if let Err(e) = mdp_consumer.run().await {
-                        println!("❌ MDP Consumer 실행 오류: {}", e);
-                    }
-                });
-            }
-            Err(e) => {
-                println!("⚠️ MDP Consumer 초기화 실패: {}", e);
+    // 최우선 호가 유동성 공급 수집 (엔진 누적값을 주기적으로 가져가 일별로 저장, 월 리베이트 보고서 기준)
+    let rebates = Arc::new(RebateService::new(db_pool.clone(), config.rebate.clone()));
+    let rebates_collector = rebates.clone();
+    let engine_liquidity = engine.clone();
+    let liquidity_clock = config.clock.clone();
+    tokio::spawn(async move {
+        loop {
+            liquidity_clock.sleep(rebates_collector.config().collect_interval).await;
+            let samples = engine_liquidity.lock().await.take_maker_liquidity();
+            if let Err(e) = rebates_collector.record(&samples, liquidity_clock.now_secs()).await {
+                error!("최우선 호가 유동성 저장 실패 ({}건 유실): {}", samples.len(), e);
             }
         }
+    });
 
-        // 외부 거래소 Consumer
-        let external_config = KafkaConsumerConfig {
-            kafka_brokers: vec!["localhost:9092".to_string()],
-            topic_name: "market-data".to_string(),
-            consumer_group: "exchange-sync".to_string(),
-            worker_id: "external-worker".to_string(),
-            batch_size: 100,
-            processing_interval_ms: 100,
-        };
-
-        match ExternalExchangeConsumer::new(external_config).await {
-            Ok(external_consumer) => {
-                println!("✅ 외부 거래소 Consumer 초기화 완료");
-                
-                tokio::spawn(async move {
-                    if let Err(e) = external_consumer.run().await {
-                        println!("❌ 외부 거래소 Consumer 실행 오류: {}", e);
+    // 펀딩 정산 (정산 시각마다 인덱스/공정 가격, 최근 체결가, 순포지션으로 정산, 대기 인스턴스는 건너뜀)
+    let funding = Arc::new(
+        FundingService::new(config.funding.clone(), db_pool.clone())
+            .with_publisher(broadcast_tx.clone())
+            .with_kafka_producer(funding_kafka_producer),
+    );
+    if !config.funding.symbols.is_empty() {
+        let funding_scheduler = funding.clone();
+        let funding_index = index_prices.clone();
+        let funding_mdp = mdp.clone();
+        let funding_risk = risk.clone();
+        let funding_currency = currency.clone();
+        let funding_replication = replication.clone();
+        let funding_clock = config.clock.clone();
+        tokio::spawn(async move {
+            loop {
+                let now = funding_clock.now_secs();
+                let funding_time = funding_scheduler.next_funding_time(now);
+                funding_clock.sleep(Duration::from_secs(funding_time - now)).await;
+                if funding_replication.is_standby() {
+                    continue;
+                }
+                for symbol in &funding_scheduler.config().symbols {
+                    let Some(index) = funding_index.get(symbol) else {
+                        warn!("{} 펀딩 정산 건너뜀: 인덱스 가격 없음", symbol);
+                        continue;
+                    };
+                    let last_price = funding_mdp.lock().await.get_ticker(symbol, funding_time).await.and_then(|t| t.last_price);
+                    let Some(last_price) = last_price else {
+                        warn!("{} 펀딩 정산 건너뜀: 체결가 없음", symbol);
+                        continue;
+                    };
+                    let asset = match funding_currency.registry().quote_asset(symbol) {
+                        Ok(asset) => asset.code.clone(),
+                        Err(e) => {
+                            error!("{} 펀딩 정산 건너뜀: {}", symbol, e);
+                            continue;
+                        }
+                    };
+                    let inputs = FundingInputs {
+                        symbol: symbol.clone(),
+                        funding_time,
+                        last_price,
+                        index_price: index.index_price,
+                        mark_price: index.fair_price,
+                        asset,
+                        positions: funding_risk.symbol_positions(symbol),
+                    };
+                    if let Err(e) = funding_scheduler.settle(inputs).await {
+                        error!("펀딩 정산 실패: {}", e);
                     }
-                });
+                }
             }
-            Err(e) => {
-                println!("⚠️ 외부 거래소 Consumer 초기화 실패: {}", e);
+        });
+        println!(
+            "✅ 펀딩 정산 시작: {} ({}초 주기)",
+            config.funding.symbols.join(", "),
+            config.funding.interval.as_secs()
+        );
+    }
+
+    // 만기 상품 (만기 임박 알림, 만기 거래 중단, 기초 심볼 인덱스로 현금 정산, 대기 인스턴스는 건너뜀)
+    let futures = Arc::new(
+        FuturesService::load(config.futures.clone(), currency.registry(), db_pool.clone(), config.clock.now_millis())
+            .await?
+            .with_kill_switch(kill_switch.clone())
+            .with_publisher(broadcast_tx.clone()),
+    );
+    if !config.futures.contracts.is_empty() {
+        for contract in &config.futures.contracts {
+            if !price_sync_config.symbols.contains(&contract.underlying) {
+                warn!("만기 상품 {}의 기초 심볼 {}에 외부 시세가 없어 정산할 수 없습니다", contract.symbol, contract.underlying);
             }
         }
+        let futures_lifecycle = futures.clone();
+        let futures_index = index_prices.clone();
+        let futures_replication = replication.clone();
+        let futures_clock = config.clock.clone();
+        let check_interval = config.futures.check_interval;
+        tokio::spawn(async move {
+            loop {
+                if !futures_replication.is_standby() {
+                    let index_price = |symbol: &str| futures_index.get(symbol).filter(|index| !index.stale).map(|index| index.index_price);
+                    futures_lifecycle.tick(futures_clock.now_millis(), index_price).await;
+                }
+                futures_clock.sleep(check_interval).await;
+            }
+        });
+        println!(
+            "✅ 만기 상품 관리 시작: {}",
+            config
+                .futures
+                .contracts
+                .iter()
+                .map(|contract| format!("{} ({} 만기 {})", contract.symbol, contract.underlying, contract.expiry))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
 
-        // 규제 기관 Consumer
-        let regulatory_config = KafkaConsumerConfig {
-            kafka_brokers: vec!["localhost:9092".to_string()],
-            topic_name: "market-data".to_string(),
-            consumer_group: "regulatory".to_string(),
-            worker_id: "regulatory-worker".to_string(),
-            batch_size: 100,
-            processing_interval_ms: 100,
-        };
-
-        match RegulatoryConsumer::new(regulatory_config).await {
-            Ok(regulatory_consumer) => {
-                println!("✅ 규제 기관 Consumer 초기화 완료");
-                
-                tokio::spawn(async move {
-                    if let Err(e) = regulatory_consumer.run().await {
-                        println!("❌ 규제 기관 Consumer 실행 오류: {}", e);
+    // 체결/감사 로그 보존 정리 (시작 직후 부하를 피해 10분 뒤 한 번, 이후 설정 주기)
+    if let Some(retention) = config.retention.clone() {
+        let retention_interval = retention.interval;
+        let job = RetentionJob::new(db_pool.clone(), retention);
+        let metrics_collector_retention = metrics_collector.clone();
+        tokio::spawn(async move {
+            let first = tokio::time::Instant::now() + Duration::from_secs(600);
+            let mut interval = tokio::time::interval_at(first, retention_interval);
+            loop {
+                interval.tick().await;
+                let now = chrono::Utc::now().timestamp() as u64;
+                match job.run(now).await {
+                    Ok(report) => {
+                        info!(
+                            "보존 정리 완료: 체결 {}일({}건) 집계, 감사 로그 {}건 삭제, VACUUM {}, {}바이트 회수",
+                            report.rolled_up_days,
+                            report.executions_deleted,
+                            report.audit_logs_deleted,
+                            if report.vacuumed { "실행" } else { "생략" },
+                            report.reclaimed_bytes()
+                        );
+                        report.publish(&metrics_collector_retention).await;
                     }
-                });
+                    Err(e) => error!("보존 정리 실패: {}", e),
+                }
             }
-            Err(e) => {
-                println!("⚠️ 규제 기관 Consumer 초기화 실패: {}", e);
+        });
+    }
+
+    // 저널 스트림 서버 (승격된 대기 인스턴스도 이어서 제공)
+    if let Some(port) = config.replication.listen_port {
+        let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", port)).await?;
+        let server = Arc::new(ReplicationServer::new(replication_journal.clone(), config.replication.heartbeat_interval));
+        tokio::spawn(server.run(listener));
+        println!("✅ 복제 저널 스트림 시작 (포트: {})", port);
+    }
+    if let Some(primary_addr) = &config.replication.standby_of {
+        let replicator = StandbyReplicator::new(replication.clone(), replica_engine_tx, config.replication.reconnect_delay);
+        tokio::spawn(replicator.run());
+        println!("⏸️  대기(standby) 인스턴스로 시작: 주 인스턴스 {} 복제, 승격 전까지 주문 거부", primary_addr);
+    }
+
+    // 큐 깊이 및 WebSocket 연결 게이지를 메트릭 수집기로 주기적 발행
+    // 대시보드에는 헬스 상태, 큐 깊이, 처리량, API 지연 백분위수, 전체 메트릭을 반영
+    let queue_metrics = sequencer.queue_metrics();
+    let mut wait_metrics = sequencer.wait_metrics();
+    wait_metrics.register(engine_wait.stats());
+    let readiness = Arc::new(ReadinessChecker::new(
+        ReadinessConfig::default(),
+        health_monitor.clone(),
+        EngineProbe::new(engine_probe_tx),
+        queue_metrics.clone(),
+        config.symbols.len(),
+    ).with_replication(replication.clone()));
+    let ws_metrics = Arc::new(WebSocketMetrics::new());
+    let ws_metrics_publish = ws_metrics.clone();
+    let replication_metrics = replication.clone();
+    let metrics_collector_queues = metrics_collector.clone();
+    let queue_metrics_history = queue_metrics.clone();
+    let consumer_lags_publish = consumer_lags.clone();
+    let redis_claim_metrics_publish = redis_claim_metrics.clone();
+    let publish_retry_metrics = publish_retry.clone();
+    let engine_memory = engine.clone();
+    let order_admission = Arc::new(AdmissionQueue::new(config.order_admission.clone()));
+    let order_admission_metrics = order_admission.clone();
+    let health_monitor_dashboard = health_monitor.clone();
+    let dashboard_server_feed = dashboard_server.clone();
+    let dashboard_data_provider_feed = dashboard_data_provider.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+        
+        loop {
+            interval.tick().await;
+            queue_metrics.publish(&metrics_collector_queues).await;
+            wait_metrics.publish(&metrics_collector_queues).await;
+            let book_memory = engine_memory.lock().await.book_memory();
+            book_memory.publish(&metrics_collector_queues).await;
+            ws_metrics_publish.publish(&metrics_collector_queues).await;
+            replication_metrics.publish(&metrics_collector_queues).await;
+            consumer_lags_publish.publish(metrics_collector_queues.as_ref()).await;
+            redis_claim_metrics_publish.publish(metrics_collector_queues.as_ref()).await;
+            publish_retry_metrics.publish(metrics_collector_queues.as_ref()).await;
+            order_admission_metrics.publish(&metrics_collector_queues).await;
+            metrics_collector_queues
+                .set_gauge("dashboard.connections.active", dashboard_server_feed.get_connected_clients_count().await as u64)
+                .await;
+
+            let system_health = health_monitor_dashboard.get_system_health().await;
+            if let Ok(health) = serde_json::to_value(&system_health) {
+                dashboard_data_provider_feed.update_system_health(health).await;
             }
-        }
 
-        // 분석 시스템 Consumer
-        let analytics_config = KafkaConsumerConfig {
-            kafka_brokers: vec!["localhost:9092".to_string()],
-            topic_name: "market-data".to_string(),
-            consumer_group: "analytics".to_string(),
-            worker_id: "analytics-worker".to_string(),
-            batch_size: 100,
-            processing_interval_ms: 100,
-        };
+            let samples: Vec<QueueSample> = queue_metrics
+                .gauges()
+                .iter()
+                .map(|gauge| QueueSample {
+                    name: gauge.name().to_string(),
+                    depth: gauge.depth(),
+                    capacity: gauge.capacity(),
+                    high_watermark: gauge.high_watermark(),
+                    enqueued: gauge.enqueued(),
+                })
+                .collect();
+            dashboard_data_provider_feed
+                .update_queue_samples(&samples, chrono::Utc::now().timestamp_millis() as u64)
+                .await;
 
-        match AnalyticsConsumer::new(analytics_config).await {
-            Ok(analytics_consumer) => {
-                println!("✅ 분석 시스템 Consumer 초기화 완료");
-                
-                tokio::spawn(async move {
-                    if let Err(e) = analytics_consumer.run().await {
-                        println!("❌ 분석 시스템 Consumer 실행 오류: {}", e);
-                    }
-                });
+            if let Some(p) = metrics_collector_queues
+                .get_timer_percentiles(REQUEST_LATENCY_TIMER, &[50.0, 95.0, 99.0])
+                .await
+            {
+                dashboard_data_provider_feed
+                    .update_metric_data(
+                        "latency".to_string(),
+                        serde_json::json!({ "p50": p[0], "p95": p[1], "p99": p[2] }),
+                    )
+                    .await;
             }
-            Err(e) => {
-                println!("⚠️ 분석 시스템 Consumer 초기화 실패: {}", e);
+
+            if let Ok(lags) = serde_json::to_value(consumer_lags_publish.snapshot()) {
+                dashboard_data_provider_feed.update_metric_data("kafka_consumer_lag".to_string(), lags).await;
             }
+
+            let metrics = serde_json::json!({
+                "counters": metrics_collector_queues.get_all_counters().await,
+                "gauges": metrics_collector_queues.get_all_gauges().await,
+            });
+            dashboard_data_provider_feed.update_metric_data("metrics".to_string(), metrics).await;
         }
-    }
+    });
 
-    // 🚀 RabbitMQ Consumer Manager 초기화 및 실행
-    if let Some(_rabbitmq_producer) = &rabbitmq_producer {
-        // WebSocket 서버 Consumer들 설정
-        let server_configs = vec![
-            (
-                RabbitMQConsumerConfig {
-                    rabbitmq_url: "amqp://localhost:5672".to_string(),
-                    exchange_name: "websocket_notifications".to_string(),
-                    queue_name: "ws-server-1".to_string(),
-                    routing_patterns: vec!["execution.*".to_string(), "orderbook.*".to_string()],
-                    worker_id: "ws-worker-1".to_string(),
-                    batch_size: 100,
-                    processing_interval_ms: 100,
-                },
-                "ws-server-1".to_string(),
-                1000, // 최대 연결 수
-            ),
-            (
-                RabbitMQConsumerConfig {
-                    rabbitmq_url: "amqp://localhost:5672".to_string(),
-                    exchange_name: "websocket_notifications".to_string(),
-                    queue_name: "ws-server-2".to_string(),
-                    routing_patterns: vec!["execution.*".to_string(), "orderbook.*".to_string()],
-                    worker_id: "ws-worker-2".to_string(),
-                    batch_size: 100,
-                    processing_interval_ms: 100,
-                },
-                "ws-server-2".to_string(),
-                1000, // 최대 연결 수
-            ),
-            (
-                RabbitMQConsumerConfig {
-                    rabbitmq_url: "amqp://localhost:5672".to_string(),
-                    exchange_name: "websocket_notifications".to_string(),
-                    queue_name: "ws-server-3".to_string(),
-                    routing_patterns: vec!["execution.*".to_string(), "orderbook.*".to_string()],
-                    worker_id: "ws-worker-3".to_string(),
-                    batch_size: 100,
-                    processing_interval_ms: 100,
-                },
-                "ws-server-3".to_string(),
-                1000, // 최대 연결 수
-            ),
-        ];
-
-        // 로드밸런서 Consumer 초기화
-        match LoadBalancerConsumer::new(server_configs).await {
-            Ok(load_balancer) => {
-                println!("✅ RabbitMQ 로드밸런서 Consumer 초기화 완료");
-                
-                tokio::spawn(async move {
-                    if let Err(e) = load_balancer.run().await {
-                        println!("❌ RabbitMQ 로드밸런서 Consumer 실행 오류: {}", e);
-                    }
-                });
-            }
-            Err(e) => {
-                println!("⚠️ RabbitMQ 로드밸런서 Consumer 초기화 실패: {}", e);
+    // 성능 지표 시간별 롤업 (REST 지연 샘플과 주문 큐 적재 수를 주기적으로 모아 끝난 시간을 저장, 대시보드 장기 추이)
+    let metrics_history = Arc::new(MetricsHistoryService::new(
+        db_pool.clone(),
+        metrics_collector.clone(),
+        REQUEST_LATENCY_TIMER,
+        config.metrics_history.clone(),
+    ));
+    let metrics_history_collector = metrics_history.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(metrics_history_collector.config().collect_interval);
+        loop {
+            interval.tick().await;
+            let orders_total = queue_metrics_history
+                .gauges()
+                .iter()
+                .find(|gauge| gauge.name() == "orders")
+                .map_or(0, |gauge| gauge.enqueued());
+            let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+            if let Err(e) = metrics_history_collector.collect(now_ms, orders_total).await {
+                error!("성능 지표 시간별 롤업 저장 실패: {}", e);
             }
         }
+    });
 
-        // Dead Letter Queue Consumer 초기화
-        let dlq_config = RabbitMQConsumerConfig {
-            rabbitmq_url: "amqp://localhost:5672".to_string(),
-            exchange_name: "websocket_notifications".to_string(),
-            queue_name: "websocket_notifications.dlq".to_string(),
-            routing_patterns: vec!["*".to_string()],
-            worker_id: "dlq-worker".to_string(),
-            batch_size: 50,
-            processing_interval_ms: 5000, // 5초마다 처리
-        };
+    // 시퀀서 실행 태스크
+    tokio::spawn(async move {
+        sequencer.run().await;
+    });
 
-        match DeadLetterQueueConsumer::new(dlq_config, 3).await {
-            Ok(dlq_consumer) => {
-                println!("✅ RabbitMQ Dead Letter Queue Consumer 초기화 완료");
-                
-                tokio::spawn(async move {
-                    if let Err(e) = dlq_consumer.run().await {
-                        println!("❌ RabbitMQ Dead Letter Queue Consumer 실행 오류: {}", e);
-                    }
-                });
-            }
-            Err(e) => {
-                println!("⚠️ RabbitMQ Dead Letter Queue Consumer 초기화 실패: {}", e);
-            }
+    // 매칭 엔진 실행 태스크 (시퀀서에서 주문을 받음, 주문 대기 중에는 API가 엔진을 조회할 수 있도록 잠금 해제)
+    let engine_clone = engine.clone();
+    tokio::task::spawn_blocking(move || MatchingEngine::run_shared(engine_clone, sequencer_rx));
+
+    // MDP는 이제 시퀀서에서 직접 처리됨
+
+    // 초기 호가 공급 (API를 열기 전, 대기 인스턴스는 주 인스턴스의 호가를 복제하므로 제외)
+    if let Some(path) = &config.seed_dataset {
+        if replication.is_standby() {
+            println!("⏸️  대기 인스턴스: 초기 호가 공급 건너뜀");
+        } else if config.restored_backup.is_some() {
+            println!("⏸️  DB 백업에서 복원: 초기 호가 공급 건너뜀");
+        } else {
+            let seed_orders = load_seed_orders(path);
+            println!("📋 초기 호가 공급 시작: {} ({}개 주문)", path.display(), seed_orders.len());
+            let summary = seed_order_book(seed_orders, &order_tx, &global_sequence, &order_acks).await;
+            println!("✅ 초기 호가 공급 완료: 접수 {}건, 거부 {}건, 시간 초과 {}건",
+                     summary.accepted, summary.rejected, summary.timed_out);
         }
     }
 
-    // 매칭 엔진 실행 태스크 (시퀀서에서 주문을 받음)
-    let engine_clone = engine.clone();
+    // 계정 KYC 상태 로드
+    let kyc = Arc::new(KycRegistry::load(config.kyc.clone(), db_pool.clone()).await?);
+
+    // API 키 로드 (인증 설정 시)
+    let auth = match &config.auth {
+        Some(auth_config) => Some(Arc::new(AuthService::load(auth_config.clone(), db_pool.clone()).await?)),
+        None => None,
+    };
+
+    // 관리자 TOTP 로드 (2단계 인증 설정 시, 실패 시도는 보안 알림)
+    let admin_totp = match &config.admin_totp {
+        Some(totp_config) => Some(Arc::new(
+            TotpRegistry::load(totp_config.clone(), db_pool.clone())
+                .await?
+                .with_notifications(notification_system.clone()),
+        )),
+        None => None,
+    };
+
+    // 역할과 권한 로드 (처음 시작하면 기본 권한 저장)
+    let rbac = Arc::new(Rbac::load(config.rbac.clone(), db_pool.clone()).await?);
+    if auth.is_none() {
+        match config.rbac.default_client_role {
+            Some(role) => warn!("인증이 꺼져 있어 모든 주문/계좌 요청을 기본 계정 역할({})로 판정합니다", role.as_str()),
+            None => warn!("인증이 꺼져 있고 기본 계정 역할이 없어 주문/계좌 API를 모두 거부합니다"),
+        }
+    }
+
+    // 계정별 분당 메시지 집계 (주기적으로 증가분 저장, 보존 기간이 지난 집계 삭제)
+    let message_usage = Arc::new(
+        MessageUsage::new(config.message_usage.clone(), db_pool.clone()).with_clock(config.clock.clone()),
+    );
+    let message_usage_flush = message_usage.clone();
+    let usage_clock = config.clock.clone();
     tokio::spawn(async move {
-        let mut engine_guard = engine_clone.lock().await;
-        engine_guard.run(sequencer_rx);
+        loop {
+            usage_clock.sleep(message_usage_flush.config().flush_interval).await;
+            if let Err(e) = message_usage_flush.flush(message_usage_flush.now_millis()).await {
+                error!("분당 메시지 사용량 저장 실패: {}", e);
+            }
+        }
     });
 
-    // MDP는 이제 시퀀서에서 직접 처리됨
-
     // 서버 상태 생성
     let state = ServerState {
         engine: engine.clone(),
         execution_tx: broadcast_tx,
-        order_tx: order_tx,
+        order_tx,
+        cancel_tx,
         mdp: mdp.clone(),
         db_pool: db_pool.clone(),
+        order_validator: Arc::new(
+            OrderValidator::new(config.symbols.clone())
+                .with_max_order_sizes(config.max_order_size.clone()),
+        ),
+        ws_config: config.websocket.clone(),
+        ws_metrics,
+        order_router,
+        kyc,
+        fees,
+        kill_switch,
+        risk,
+        admin_token: config.admin_token.clone(),
+        auth: auth.clone(),
+        admin_totp,
+        rbac,
+        notifications: notification_system.clone(),
+        incidents: incident_tracker.clone(),
+        dashboard: dashboard_server.clone(),
+        readiness,
+        replication,
+        backups: config
+            .backup_dir
+            .clone()
+            .map(|dir| Arc::new(BackupManager::new(db_pool.clone(), async_commit_mgr.clone(), dir))),
+        currency,
+        recovery: recovery_manager.clone(),
+        dead_letters,
+        global_sequence,
+        order_acks,
+        private_events,
+        order_throttle,
+        message_usage: message_usage.clone(),
+        rebates,
+        metrics_history,
+        index_prices,
+        funding,
+        futures,
+        order_admission,
     };
 
     // REST API 라우터 생성
-    let api_router = create_api_router()
+    let mut api_router = create_api_router(&state)
+        .layer(axum::middleware::from_fn_with_state(metrics_collector.clone(), track_request_latency));
+    if let Some(auth) = &auth {
+        // 인증 미들웨어 안쪽 (인증된 계정의 조회만 셈)
+        api_router = api_router.layer(axum::middleware::from_fn_with_state(message_usage.clone(), count_client_queries));
+        api_router = api_router.layer(axum::middleware::from_fn_with_state(auth.clone(), auth::authenticate));
+    }
+    if config.tls.as_ref().is_some_and(|tls_config| tls_config.admin_client_ca.is_some()) {
+        api_router = api_router.layer(axum::middleware::from_fn(tls::require_admin_client_certificate));
+    }
+    let api_router = api_router
         .layer(CorsLayer::permissive())
         .layer(TraceLayer::new_for_http())
         .with_state(state.clone());
 
-    // REST API 서버 시작
-    let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.rest_port))
-        .await
-        .expect("Failed to bind REST server");
-    
+    let (http, ws) = if config.tls.is_some() { ("https", "wss") } else { ("http", "ws") };
     println!("서버가 성공적으로 시작되었습니다!");
-    println!("REST API: http://localhost:{}", config.rest_port);
-    
-    axum::serve(listener, api_router)
-        .await
-        .expect("REST server failed");
+    println!("REST API: {}://localhost:{}", http, config.rest_port);
+    println!("WebSocket: {}://localhost:{}/ws", ws, config.rest_port);
+    println!("대시보드 WebSocket: {}://localhost:{}/ws/dashboard", ws, config.rest_port);
+    println!("대시보드 UI: {}://localhost:{}/dashboard", http, config.rest_port);
+    println!("생존/준비 상태: {}://localhost:{}/healthz, /readyz", http, config.rest_port);
+    if auth.is_some() {
+        println!("🔑 API 키/세션 토큰 인증 적용 (로그인: {}://localhost:{}/v1/auth/login)", http, config.rest_port);
+    }
+
+    // REST API 서버 시작 (TLS 설정 시 HTTPS/WSS)
+    if let Some(tls_config) = &config.tls {
+        if tls_config.admin_client_ca.is_some() {
+            println!("🔒 관리자 엔드포인트 mTLS 적용 (/v1/admin/*, /ws/dashboard)");
+        }
+        // TLS 서버는 주소로 직접 바인딩하므로 받은 리스너는 닫고 같은 주소 사용
+        let addr = match listener {
+            Some(listener) => listener.local_addr()?,
+            None => std::net::SocketAddr::from(([0, 0, 0, 0], config.rest_port)),
+        };
+        tls::serve(addr, api_router, tls_config).await?;
+    } else {
+        let listener = match listener {
+            Some(listener) => listener,
+            None => tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.rest_port))
+                .await
+                .expect("Failed to bind REST server"),
+        };
+
+        // 연결 주소는 API 키 IP 허용 목록 확인에 사용
+        axum::serve(listener, api_router.into_make_service_with_connect_info::<std::net::SocketAddr>())
+            .await
+            .expect("REST server failed");
+    }
 
     Ok(())
-}
\ No newline at end of file
+}
+/// MQ Consumer를 감독 태스크로 실행
+///
+/// 각 태스크는 재시작할 때마다 Consumer를 새로 만들어 연결을 다시 맺으며,
+/// 반환된 태스크는 해당 MQ 서비스의 자동 복구 작업으로 등록됩니다.
+fn spawn_mq_consumers(
+    redis_url: Option<&str>,
+    kafka_producer: &Option<Arc<KafkaProducer>>,
+    rabbitmq_url: Option<&str>,
+    db_pool: &SqlitePool,
+    dead_letters: &Arc<QuarantineStore>,
+    consumer_lags: &Arc<ConsumerLagRegistry>,
+    redis_claim_metrics: &Arc<PendingClaimMetrics>,
+) -> Vec<(ServiceType, Arc<SupervisedTask>)> {
+    let mut tasks = Vec::new();
+
+    // 🚀 Redis Consumer Manager 초기화 및 실행
+    if let Some(redis_url) = redis_url {
+        let consumer_configs: Vec<ConsumerConfig> = (1..=3)
+            .map(|worker| ConsumerConfig {
+                redis_url: redis_url.to_string(),
+                stream_name: "executions".to_string(),
+                consumer_group: "execution_processors".to_string(),
+                worker_id: format!("worker-{}", worker),
+                batch_size: 100,
+                processing_interval_ms: 100,
+                pending_claim: PendingClaimConfig::default(),
+            })
+            .collect();
+        let db_pool = db_pool.clone();
+        let dead_letters = dead_letters.clone();
+        let claim_metrics = redis_claim_metrics.clone();
+
+        let task = SupervisedTask::spawn("redis_consumers", move || {
+            let consumer_configs = consumer_configs.clone();
+            let db_pool = db_pool.clone();
+            let dead_letters = dead_letters.clone();
+            let claim_metrics = claim_metrics.clone();
+            async move {
+                match RedisConsumerManager::new(consumer_configs, db_pool, dead_letters, claim_metrics).await {
+                    Ok(consumer_manager) => {
+                        println!("✅ Redis Consumer Manager 초기화 완료 (3개 Worker)");
+                        if let Err(e) = consumer_manager.start_all_workers().await {
+                            println!("❌ Redis Consumer Manager 실행 오류: {}", e);
+                        }
+                    }
+                    Err(e) => {
+                        println!("⚠️ Redis Consumer Manager 초기화 실패: {}", e);
+                    }
+                }
+            }
+        });
+        tasks.push((ServiceType::Redis, task));
+    }
+
+    // 🚀 Kafka Consumer 초기화 및 실행 (MDP, 외부 거래소, 규제 기관, 분석 시스템)
+    if let Some(kafka_producer) = kafka_producer {
+        // 오프셋은 DB에 커밋하므로 재시작한 Consumer는 마지막으로 처리한 메시지 다음부터 읽음
+        let source = ConsumerSource::new(kafka_producer.clone())
+            .with_offset_store(db_pool.clone())
+            .with_lag_registry(consumer_lags.clone());
+        let kafka_config = |consumer_group: &str, worker_id: &str| KafkaConsumerConfig {
+            kafka_brokers: vec!["localhost:9092".to_string()],
+            topic_name: "market-data".to_string(),
+            consumer_group: consumer_group.to_string(),
+            worker_id: worker_id.to_string(),
+            batch_size: 100,
+            processing_interval_ms: 100,
+        };
+        let mdp_config = kafka_config("mdp-group", "mdp-worker");
+        let external_config = kafka_config("exchange-sync", "external-worker");
+        let regulatory_config = kafka_config("regulatory", "regulatory-worker");
+        let analytics_config = kafka_config("analytics", "analytics-worker");
+
+        let task = SupervisedTask::spawn("kafka_consumers", move || {
+            let mdp_config = mdp_config.clone();
+            let external_config = external_config.clone();
+            let regulatory_config = regulatory_config.clone();
+            let analytics_config = analytics_config.clone();
+            let source = source.clone();
+            async move {
+                // 생성 오류(Box<dyn Error>)는 Send가 아니므로 문자열로 바꿔 다룸
+                let mdp = async {
+                    match MDPConsumer::new(mdp_config).await.map_err(|e| e.to_string()) {
+                        Ok(consumer) => {
+                            let consumer = consumer.with_source(source.clone());
+                            println!("✅ MDP Consumer 초기화 완료");
+                            if let Err(e) = consumer.run().await {
+                                println!("❌ MDP Consumer 실행 오류: {}", e);
+                            }
+                        }
+                        Err(e) => println!("⚠️ MDP Consumer 초기화 실패: {}", e),
+                    }
+                };
+                let external = async {
+                    match ExternalExchangeConsumer::new(external_config).await.map_err(|e| e.to_string()) {
+                        Ok(consumer) => {
+                            let consumer = consumer.with_source(source.clone());
+                            println!("✅ 외부 거래소 Consumer 초기화 완료");
+                            if let Err(e) = consumer.run().await {
+                                println!("❌ 외부 거래소 Consumer 실행 오류: {}", e);
+                            }
+                        }
+                        Err(e) => println!("⚠️ 외부 거래소 Consumer 초기화 실패: {}", e),
+                    }
+                };
+                let regulatory = async {
+                    match RegulatoryConsumer::new(regulatory_config).await.map_err(|e| e.to_string()) {
+                        Ok(consumer) => {
+                            let consumer = consumer.with_source(source.clone());
+                            println!("✅ 규제 기관 Consumer 초기화 완료");
+                            if let Err(e) = consumer.run().await {
+                                println!("❌ 규제 기관 Consumer 실행 오류: {}", e);
+                            }
+                        }
+                        Err(e) => println!("⚠️ 규제 기관 Consumer 초기화 실패: {}", e),
+                    }
+                };
+                let analytics = async {
+                    match AnalyticsConsumer::new(analytics_config).await.map_err(|e| e.to_string()) {
+                        Ok(consumer) => {
+                            let consumer = consumer.with_source(source.clone());
+                            println!("✅ 분석 시스템 Consumer 초기화 완료");
+                            if let Err(e) = consumer.run().await {
+                                println!("❌ 분석 시스템 Consumer 실행 오류: {}", e);
+                            }
+                        }
+                        Err(e) => println!("⚠️ 분석 시스템 Consumer 초기화 실패: {}", e),
+                    }
+                };
+                tokio::join!(mdp, external, regulatory, analytics);
+            }
+        });
+        tasks.push((ServiceType::Kafka, task));
+    }
+
+    // 🚀 RabbitMQ Consumer 초기화 및 실행 (WebSocket 서버 로드밸런서, Dead Letter Queue)
+    if let Some(rabbitmq_url) = rabbitmq_url {
+        let ws_config = |worker: u32| RabbitMQConsumerConfig {
+            rabbitmq_url: rabbitmq_url.to_string(),
+            exchange_name: "websocket_notifications".to_string(),
+            queue_name: format!("ws-server-{}", worker),
+            routing_patterns: vec!["execution.*".to_string(), "orderbook.*".to_string()],
+            worker_id: format!("ws-worker-{}", worker),
+            batch_size: 100,
+            processing_interval_ms: 100,
+        };
+        // (설정, 서버 ID, 최대 연결 수)
+        let server_configs: Vec<(RabbitMQConsumerConfig, String, u32)> = (1..=3)
+            .map(|worker| (ws_config(worker), format!("ws-server-{}", worker), 1000))
+            .collect();
+        let dlq_config = RabbitMQConsumerConfig {
+            rabbitmq_url: rabbitmq_url.to_string(),
+            exchange_name: "websocket_notifications".to_string(),
+            queue_name: "websocket_notifications.dlq".to_string(),
+            routing_patterns: vec!["*".to_string()],
+            worker_id: "dlq-worker".to_string(),
+            batch_size: 50,
+            processing_interval_ms: 5000, // 5초마다 처리
+        };
+
+        let dead_letters = dead_letters.clone();
+
+        let task = SupervisedTask::spawn("rabbitmq_consumers", move || {
+            let server_configs = server_configs.clone();
+            let dlq_config = dlq_config.clone();
+            let dead_letters = dead_letters.clone();
+            async move {
+                let load_balancer = async {
+                    match LoadBalancerConsumer::new(server_configs).await {
+                        Ok(consumer) => {
+                            println!("✅ RabbitMQ 로드밸런서 Consumer 초기화 완료");
+                            if let Err(e) = consumer.run().await {
+                                println!("❌ RabbitMQ 로드밸런서 Consumer 실행 오류: {}", e);
+                            }
+                        }
+                        Err(e) => println!("⚠️ RabbitMQ 로드밸런서 Consumer 초기화 실패: {}", e),
+                    }
+                };
+                let dead_letter = async {
+                    match DeadLetterQueueConsumer::new(dlq_config, 3).await {
+                        Ok(consumer) => {
+                            let consumer = consumer.with_quarantine(dead_letters);
+                            println!("✅ RabbitMQ Dead Letter Queue Consumer 초기화 완료");
+                            if let Err(e) = consumer.run().await {
+                                println!("❌ RabbitMQ Dead Letter Queue Consumer 실행 오류: {}", e);
+                            }
+                        }
+                        Err(e) => println!("⚠️ RabbitMQ Dead Letter Queue Consumer 초기화 실패: {}", e),
+                    }
+                };
+                tokio::join!(load_balancer, dead_letter);
+            }
+        });
+        tasks.push((ServiceType::RabbitMQ, task));
+    }
+
+    tasks
+}
@@ -0,0 +1,323 @@
+//! 인프로세스 통합 테스트 서버
+//!
+//! 전체 서버를 테스트 프로세스 안에서 임의 포트로 띄웁니다. DB는 메모리 SQLite, Kafka는 모의 구현을 쓰고
+//! Redis Streams와 RabbitMQ는 끄므로(체결 알림은 WebSocket으로 직접 브로드캐스트), 서버를 따로 띄우거나 외부 서비스를 준비하지 않아도
+//! CI에서 주문 → 매칭 → 체결 알림 흐름을 `cargo test`로 확인할 수 있습니다.
+//! `ServerConfig::clock`에 `SimClock`을 넣으면 GTD 만료나 봉 마감을 실제로 기다리지 않고 확인합니다.
+//!
+//! 서버는 전용 스레드의 런타임에서 실행되고, `TestServer`를 버리면 런타임째 종료됩니다.
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use tokio::net::TcpStream;
+use tokio::sync::oneshot;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+use crate::db;
+use crate::server::{run_server, ServerConfig};
+
+/// 서버가 `/healthz`에 응답할 때까지 기다리는 시간
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(15);
+/// 종료 시 남은 태스크를 기다리는 시간
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// 테스트 프로세스 안에서 실행 중인 서버
+pub struct TestServer {
+    addr: SocketAddr,
+    http: reqwest::Client,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+    backup_dir: PathBuf,
+}
+
+impl TestServer {
+    /// 기본 설정으로 서버 시작
+    pub async fn start() -> Self {
+        Self::start_with(ServerConfig::default()).await
+    }
+
+    /// 주어진 설정으로 서버 시작
+    ///
+    /// 포트, Redis, RabbitMQ, 초기 호가, MQ 백업 큐 디렉터리는 테스트끼리 겹치지 않도록 덮어씁니다.
+    pub async fn start_with(mut config: ServerConfig) -> Self {
+        // 서버 런타임에서 다시 등록하도록 표준 리스너로 바인딩
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("테스트 서버 포트 바인딩 실패");
+        listener.set_nonblocking(true).expect("리스너 논블로킹 설정 실패");
+        let addr = listener.local_addr().expect("테스트 서버 주소 조회 실패");
+
+        let backup_dir = std::env::temp_dir().join(format!("xtrader-test-{}", uuid::Uuid::new_v4()));
+        config.rest_port = addr.port();
+        config.mdp_api_port = 0;
+        config.redis_url = None;
+        config.rabbitmq_url = None;
+        config.seed_dataset = None;
+        config.backup_queue.directory = backup_dir.clone();
+
+        let (shutdown, shutdown_rx) = oneshot::channel();
+        let thread = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(2)
+                .enable_all()
+                .build()
+                .expect("테스트 서버 런타임 생성 실패");
+            runtime.block_on(async move {
+                // 메모리 SQLite는 연결마다 별도 DB이므로 연결 하나만 사용
+                let db_pool = SqlitePoolOptions::new()
+                    .max_connections(1)
+                    .connect("sqlite::memory:")
+                    .await
+                    .expect("메모리 SQLite 연결 실패");
+                db::create_tables(&db_pool).await.expect("테이블 생성 실패");
+
+                let listener = tokio::net::TcpListener::from_std(listener).expect("리스너 등록 실패");
+                tokio::select! {
+                    result = run_server(config, db_pool, Some(listener)) => {
+                        if let Err(e) = result {
+                            eprintln!("테스트 서버 실행 실패: {}", e);
+                        }
+                    }
+                    _ = shutdown_rx => {}
+                }
+            });
+            runtime.shutdown_timeout(SHUTDOWN_TIMEOUT);
+        });
+
+        let server = Self {
+            addr,
+            http: reqwest::Client::new(),
+            shutdown: Some(shutdown),
+            thread: Some(thread),
+            backup_dir,
+        };
+        server.wait_ready().await;
+        server
+    }
+
+    async fn wait_ready(&self) {
+        let deadline = tokio::time::Instant::now() + STARTUP_TIMEOUT;
+        loop {
+            if let Ok(response) = self.http.get(self.url("/healthz")).send().await {
+                if response.status().is_success() {
+                    return;
+                }
+            }
+            if self.thread.as_ref().is_some_and(|thread| thread.is_finished()) {
+                panic!("테스트 서버가 시작 중에 종료되었습니다");
+            }
+            if tokio::time::Instant::now() >= deadline {
+                panic!("테스트 서버가 {:?} 안에 준비되지 않았습니다", STARTUP_TIMEOUT);
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+
+    /// REST URL
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.addr, path)
+    }
+
+    /// WebSocket URL
+    pub fn ws_url(&self, path: &str) -> String {
+        format!("ws://{}{}", self.addr, path)
+    }
+
+    /// GET 요청 (상태 코드와 JSON 본문, 본문이 JSON이 아니면 Null)
+    pub async fn get(&self, path: &str) -> (StatusCode, Value) {
+        let response = self.http.get(self.url(path)).send().await.expect("GET 요청 실패");
+        let status = response.status();
+        (status, response.json().await.unwrap_or(Value::Null))
+    }
+
+    /// POST 요청 (JSON 본문)
+    pub async fn post(&self, path: &str, body: &Value) -> (StatusCode, Value) {
+        let response = self.http.post(self.url(path)).json(body).send().await.expect("POST 요청 실패");
+        let status = response.status();
+        (status, response.json().await.unwrap_or(Value::Null))
+    }
+
+    /// 주문 제출
+    pub async fn submit_order(&self, order: &Value) -> (StatusCode, Value) {
+        self.post("/v1/order", order).await
+    }
+
+    /// 주문 취소 요청
+    pub async fn cancel_order(&self, order_id: &str) -> (StatusCode, Value) {
+        self.post("/v1/order/cancel", &json!({ "order_id": order_id })).await
+    }
+
+    /// 호가창 조회 (`orderbook` 필드)
+    pub async fn orderbook(&self, symbol: &str) -> Value {
+        let (status, body) = self.get(&format!("/api/v1/orderbook/{}", symbol)).await;
+        assert_eq!(status, StatusCode::OK, "호가창 조회 실패: {}", body);
+        body["orderbook"].clone()
+    }
+
+    /// WebSocket 연결
+    pub async fn connect_ws(&self, path: &str) -> TestWebSocket {
+        let (stream, _) = connect_async(self.ws_url(path)).await.expect("WebSocket 연결 실패");
+        TestWebSocket { stream }
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        let _ = std::fs::remove_dir_all(&self.backup_dir);
+    }
+}
+
+/// 테스트용 WebSocket 클라이언트
+pub struct TestWebSocket {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl TestWebSocket {
+    /// 조건에 맞는 메시지를 받을 때까지 대기 (시간 초과나 연결 종료 시 None)
+    pub async fn wait_for(&mut self, timeout: Duration, predicate: impl Fn(&Value) -> bool) -> Option<Value> {
+        tokio::time::timeout(timeout, async {
+            while let Some(Ok(message)) = self.stream.next().await {
+                let Message::Text(text) = message else { continue };
+                if let Ok(value) = serde_json::from_str::<Value>(&text) {
+                    if predicate(&value) {
+                        return Some(value);
+                    }
+                }
+            }
+            None
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+}
+
+/// 지정가 주문 요청 본문
+pub fn limit_order(client_id: &str, side: &str, price: u64, quantity: u64) -> Value {
+    json!({
+        "symbol": "BTC-KRW",
+        "side": side,
+        "order_type": "Limit",
+        "price": price,
+        "quantity": quantity,
+        "client_id": client_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::clock::{Clock, SimClock, SystemClock};
+
+    const WAIT: Duration = Duration::from_secs(5);
+
+    /// 호가창 조회 결과가 조건을 만족할 때까지 대기
+    async fn wait_for_book(server: &TestServer, predicate: impl Fn(&Value) -> bool) -> Value {
+        let deadline = tokio::time::Instant::now() + WAIT;
+        loop {
+            let book = server.orderbook("BTC-KRW").await;
+            if predicate(&book) || tokio::time::Instant::now() >= deadline {
+                return book;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_crossing_orders_fill() {
+        let server = TestServer::start().await;
+
+        let (status, maker) = server.submit_order(&limit_order("seller", "Sell", 1_000, 10)).await;
+        assert_eq!(status, StatusCode::OK, "{}", maker);
+        assert_eq!(maker["status"], "ACCEPTED");
+        assert_eq!(maker["remaining_quantity"], 10);
+
+        let (status, taker) = server.submit_order(&limit_order("buyer", "Buy", 1_000, 10)).await;
+        assert_eq!(status, StatusCode::OK, "{}", taker);
+        assert_eq!(taker["status"], "FILLED");
+        assert_eq!(taker["filled_quantity"], 10);
+        assert!(taker["sequence"].as_u64() > maker["sequence"].as_u64());
+
+        let book = wait_for_book(&server, |book| book["asks"].as_array().is_some_and(Vec::is_empty)).await;
+        assert_eq!(book["asks"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_resting_order_shown_in_book_until_canceled() {
+        let server = TestServer::start().await;
+
+        let (_, resting) = server.submit_order(&limit_order("maker", "Buy", 990, 5)).await;
+        let order_id = resting["order_id"].as_str().expect("주문 ID").to_string();
+        let book = server.orderbook("BTC-KRW").await;
+        assert_eq!(book["bids"], json!([[990, 5]]));
+
+        let (status, canceled) = server.cancel_order(&order_id).await;
+        assert_eq!(status, StatusCode::OK, "{}", canceled);
+        assert_eq!(canceled["status"], "CANCEL_REQUESTED");
+
+        // 취소는 비동기로 처리되므로 호가에서 빠질 때까지 대기
+        let book = wait_for_book(&server, |book| book["bids"].as_array().is_some_and(Vec::is_empty)).await;
+        assert_eq!(book["bids"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_websocket_receives_execution() {
+        let server = TestServer::start().await;
+        let mut ws = server.connect_ws("/ws").await;
+
+        server.submit_order(&limit_order("seller", "Sell", 1_000, 3)).await;
+        let (_, taker) = server.submit_order(&limit_order("buyer", "Buy", 1_000, 3)).await;
+        let taker_id = taker["order_id"].clone();
+
+        let execution = ws
+            .wait_for(WAIT, |message| {
+                message["type"] == "Execution" && message["execution_report"]["order_id"] == taker_id
+            })
+            .await
+            .expect("체결 메시지를 받지 못했습니다");
+        assert_eq!(execution["execution_report"]["price"], 1_000);
+        assert_eq!(execution["execution_report"]["quantity"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_gtd_order_expires_on_sim_clock() {
+        // 주문 검증은 시스템 시각을 쓰므로 가상 시각도 현재 시각에서 시작
+        let clock = SimClock::new(SystemClock.now_millis());
+        let server = TestServer::start_with(ServerConfig { clock: clock.shared(), ..ServerConfig::default() }).await;
+
+        let mut order = limit_order("maker", "Buy", 990, 5);
+        order["expire_time"] = json!(clock.now_secs() + 3_600);
+        let (status, body) = server.submit_order(&order).await;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+        assert_eq!(server.orderbook("BTC-KRW").await["bids"], json!([[990, 5]]));
+
+        // 한 시간을 기다리지 않고 가상 시각만 넘김
+        clock.advance(Duration::from_secs(3_600));
+        let book = wait_for_book(&server, |book| book["bids"].as_array().is_some_and(Vec::is_empty)).await;
+        assert_eq!(book["bids"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_order_rejected() {
+        let server = TestServer::start().await;
+
+        let (status, body) = server.submit_order(&limit_order("buyer", "Buy", 1_000, 0)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"], "INVALID_QUANTITY");
+
+        let (status, _) = server.get("/api/v1/orderbook/UNKNOWN").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,352 @@
+//! 민감한 관리자 작업 2단계 확인 (TOTP, RFC 6238)
+//!
+//! 관리자(`X-Admin-User`)별로 TOTP 비밀 키를 발급하고, 인증 앱에서 첫 코드를 확인하면 등록이 끝납니다.
+//! 활성화하면 심볼 거래 중단, 계정 차단, 대기 인스턴스 승격 같은 지정된 관리자 API는 관리자 토큰과 함께
+//! `X-Admin-TOTP` 헤더의 현재 코드를 요구합니다. 한 번 받은 코드(시간 단계)는 다시 받지 않으며,
+//! 실패한 시도는 보안 알림(`SecurityAlert`)으로 발송합니다.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use hmac::{Hmac, Mac};
+use log::{error, info, warn};
+use serde::Serialize;
+use sha1::Sha1;
+use sqlx::SqlitePool;
+use utoipa::ToSchema;
+
+use crate::db::models::AdminTotpRecord;
+use crate::db::repository::{AdminTotpRepository, AuditLogRepository};
+use crate::monitoring::notification_routing::SOURCE_METADATA_KEY;
+use crate::monitoring::{NotificationChannel, NotificationPriority, NotificationSystem, NotificationType};
+
+/// TOTP 코드 헤더
+pub const TOTP_HEADER: &str = "x-admin-totp";
+/// 감사 로그 이벤트 타입 (비밀 키 발급)
+pub const TOTP_PROVISIONED_EVENT: &str = "ADMIN_TOTP_PROVISIONED";
+/// 감사 로그 이벤트 타입 (등록 확인)
+pub const TOTP_CONFIRMED_EVENT: &str = "ADMIN_TOTP_CONFIRMED";
+/// 감사 로그 엔티티 타입
+pub const TOTP_AUDIT_ENTITY: &str = "admin_totp";
+
+/// 시간 단계 (초, 인증 앱 기본값)
+pub const TOTP_STEP_SECS: u64 = 30;
+/// 코드 자릿수
+pub const TOTP_DIGITS: u32 = 6;
+
+/// TOTP 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct TotpConfig {
+    /// 인증 앱에 표시할 발급자
+    pub issuer: String,
+    /// 시계 오차 허용 (앞뒤 시간 단계 수)
+    pub window: u64,
+}
+
+impl Default for TotpConfig {
+    fn default() -> Self {
+        Self {
+            issuer: "xTrader".to_string(),
+            window: 1,
+        }
+    }
+}
+
+/// TOTP 오류
+#[derive(Debug, thiserror::Error)]
+pub enum TotpError {
+    #[error("2단계 인증 코드(X-Admin-TOTP)가 필요합니다")]
+    Missing,
+    #[error("2단계 인증 코드가 올바르지 않거나 이미 사용되었습니다")]
+    InvalidCode,
+    #[error("{0}은(는) 2단계 인증을 등록하지 않았습니다")]
+    NotEnrolled(String),
+    #[error("{0}의 등록 대기 중인 2단계 인증이 없습니다")]
+    NotPending(String),
+    #[error("2단계 인증 저장 실패: {0}")]
+    Storage(#[from] sqlx::Error),
+}
+
+/// 발급한 TOTP 비밀 키 (인증 앱 등록용, 발급 응답에서만 보여줌)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TotpProvisioning {
+    pub admin_user: String,
+    /// 비밀 키 (base32)
+    pub secret: String,
+    /// 인증 앱 QR 코드용 URI (`otpauth://totp/...`)
+    pub otpauth_uri: String,
+}
+
+/// HOTP 코드 (RFC 4226, HMAC-SHA1 동적 절단)
+pub fn hotp(secret: &[u8], counter: u64) -> u32 {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("HMAC은 모든 키 길이를 받음");
+    mac.update(&counter.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([digest[offset] & 0x7f, digest[offset + 1], digest[offset + 2], digest[offset + 3]]);
+    binary % 10u32.pow(TOTP_DIGITS)
+}
+
+/// 시각(초)의 TOTP 코드
+pub fn code_at(secret: &[u8], unix_secs: u64) -> String {
+    format!("{:0width$}", hotp(secret, unix_secs / TOTP_STEP_SECS), width = TOTP_DIGITS as usize)
+}
+
+/// 허용 범위 안에서 코드가 맞는 시간 단계
+pub fn matching_step(secret: &[u8], code: &str, unix_secs: u64, window: u64) -> Option<u64> {
+    if code.len() != TOTP_DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let current = unix_secs / TOTP_STEP_SECS;
+    (current.saturating_sub(window)..=current + window).find(|&step| {
+        let expected = format!("{:0width$}", hotp(secret, step), width = TOTP_DIGITS as usize);
+        // 자릿수가 같으므로 바이트별 비교를 끝까지 수행
+        expected.bytes().zip(code.bytes()).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+    })
+}
+
+fn now_secs() -> u64 {
+    chrono::Utc::now().timestamp() as u64
+}
+
+/// 추측할 수 없는 비밀 키 (160비트, UUID v4 두 개의 무작위 바이트)
+fn generate_secret() -> Vec<u8> {
+    let mut secret = uuid::Uuid::new_v4().as_bytes().to_vec();
+    secret.extend_from_slice(&uuid::Uuid::new_v4().as_bytes()[..4]);
+    secret
+}
+
+/// otpauth URI 라벨/파라미터 인코딩
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+struct TotpEntry {
+    secret: Vec<u8>,
+    confirmed: bool,
+    last_used_step: Option<u64>,
+}
+
+/// 관리자별 TOTP 상태 (메모리 + DB)
+pub struct TotpRegistry {
+    config: TotpConfig,
+    entries: Mutex<HashMap<String, TotpEntry>>,
+    repository: AdminTotpRepository,
+    audit: AuditLogRepository,
+    notifications: Option<Arc<NotificationSystem>>,
+}
+
+impl TotpRegistry {
+    /// DB에 저장된 관리자 TOTP를 읽어 생성
+    pub async fn load(config: TotpConfig, pool: SqlitePool) -> Result<Self, TotpError> {
+        let repository = AdminTotpRepository::new(pool.clone());
+        let mut entries = HashMap::new();
+        for record in repository.find_all().await? {
+            let Ok(secret) = data_encoding::BASE32_NOPAD.decode(record.secret.as_bytes()) else {
+                error!("관리자 TOTP 비밀 키 형식 오류: {} (재발급 필요)", record.admin_user);
+                continue;
+            };
+            entries.insert(
+                record.admin_user,
+                TotpEntry {
+                    secret,
+                    confirmed: record.confirmed_at.is_some(),
+                    last_used_step: record.last_used_step.map(|step| step as u64),
+                },
+            );
+        }
+        info!("관리자 TOTP {}개 로드", entries.len());
+
+        Ok(Self {
+            config,
+            entries: Mutex::new(entries),
+            repository,
+            audit: AuditLogRepository::new(pool),
+            notifications: None,
+        })
+    }
+
+    /// 실패 시도 보안 알림 발송
+    pub fn with_notifications(mut self, notifications: Arc<NotificationSystem>) -> Self {
+        self.notifications = Some(notifications);
+        self
+    }
+
+    /// 등록(첫 코드 확인)을 마친 관리자인지
+    pub fn is_enrolled(&self, admin_user: &str) -> bool {
+        self.entries.lock().unwrap().get(admin_user).is_some_and(|entry| entry.confirmed)
+    }
+
+    /// 비밀 키 발급 (이미 등록한 관리자는 현재 코드가 있어야 재발급)
+    pub async fn provision(&self, admin_user: &str, code: Option<&str>) -> Result<TotpProvisioning, TotpError> {
+        if self.is_enrolled(admin_user) {
+            self.verify(admin_user, code, "totp_rotation").await?;
+        }
+
+        let secret = generate_secret();
+        let encoded = data_encoding::BASE32_NOPAD.encode(&secret);
+        let record = AdminTotpRecord {
+            admin_user: admin_user.to_string(),
+            secret: encoded.clone(),
+            created_at: now_secs() as i64,
+            confirmed_at: None,
+            last_used_step: None,
+        };
+        self.repository.upsert(&record).await?;
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(admin_user.to_string(), TotpEntry { secret, confirmed: false, last_used_step: None });
+        self.audit.log(TOTP_PROVISIONED_EVENT, TOTP_AUDIT_ENTITY, admin_user, None).await?;
+        info!("관리자 TOTP 발급: {} (등록 확인 대기)", admin_user);
+
+        let issuer = percent_encode(&self.config.issuer);
+        let otpauth_uri = format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            issuer,
+            percent_encode(admin_user),
+            encoded,
+            issuer,
+            TOTP_DIGITS,
+            TOTP_STEP_SECS
+        );
+        Ok(TotpProvisioning { admin_user: admin_user.to_string(), secret: encoded, otpauth_uri })
+    }
+
+    /// 인증 앱의 첫 코드로 등록 확인
+    pub async fn confirm(&self, admin_user: &str, code: &str) -> Result<(), TotpError> {
+        let pending = self.entries.lock().unwrap().get(admin_user).is_some_and(|entry| !entry.confirmed);
+        if !pending {
+            return Err(TotpError::NotPending(admin_user.to_string()));
+        }
+        self.check_code(admin_user, Some(code), "totp_enrollment").await?;
+        self.audit.log(TOTP_CONFIRMED_EVENT, TOTP_AUDIT_ENTITY, admin_user, None).await?;
+        info!("관리자 TOTP 등록 완료: {}", admin_user);
+        Ok(())
+    }
+
+    /// 민감한 관리자 작업 코드 확인 (등록하지 않은 관리자는 거부)
+    pub async fn verify(&self, admin_user: &str, code: Option<&str>, operation: &str) -> Result<(), TotpError> {
+        if !self.is_enrolled(admin_user) {
+            self.alert(admin_user, operation, "2단계 인증 미등록").await;
+            return Err(TotpError::NotEnrolled(admin_user.to_string()));
+        }
+        self.check_code(admin_user, code, operation).await
+    }
+
+    async fn check_code(&self, admin_user: &str, code: Option<&str>, operation: &str) -> Result<(), TotpError> {
+        let Some(code) = code.map(str::trim).filter(|code| !code.is_empty()) else {
+            self.alert(admin_user, operation, "코드 없음").await;
+            return Err(TotpError::Missing);
+        };
+
+        // 같은 코드로 동시에 들어온 요청 중 하나만 통과하도록 확인과 기록을 한 번에 처리
+        let step = {
+            let mut entries = self.entries.lock().unwrap();
+            entries.get_mut(admin_user).and_then(|entry| {
+                let step = matching_step(&entry.secret, code, now_secs(), self.config.window)?;
+                if entry.last_used_step.is_some_and(|last| step <= last) {
+                    return None;
+                }
+                entry.last_used_step = Some(step);
+                entry.confirmed = true;
+                Some(step)
+            })
+        };
+        let Some(step) = step else {
+            self.alert(admin_user, operation, "코드 불일치 또는 재사용").await;
+            return Err(TotpError::InvalidCode);
+        };
+
+        self.repository.record_use(admin_user, step as i64, now_secs() as i64).await?;
+        Ok(())
+    }
+
+    async fn alert(&self, admin_user: &str, operation: &str, reason: &str) {
+        warn!("관리자 2단계 인증 실패: {} ({}: {})", admin_user, operation, reason);
+        let Some(notifications) = &self.notifications else {
+            return;
+        };
+        let metadata = HashMap::from([
+            (SOURCE_METADATA_KEY.to_string(), "totp".to_string()),
+            ("admin_user".to_string(), admin_user.to_string()),
+            ("operation".to_string(), operation.to_string()),
+        ]);
+        if let Err(e) = notifications
+            .send_notification_with_metadata(
+                format!("관리자 2단계 인증 실패: {}", admin_user),
+                format!("{}: {}", operation, reason),
+                NotificationChannel::Log,
+                NotificationPriority::High,
+                NotificationType::SecurityAlert,
+                metadata,
+            )
+            .await
+        {
+            error!("2단계 인증 알림 발송 실패: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn registry() -> TotpRegistry {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::create_tables(&pool).await.unwrap();
+        TotpRegistry::load(TotpConfig::default(), pool).await.unwrap()
+    }
+
+    #[test]
+    fn test_rfc6238_vectors() {
+        // RFC 6238 부록 B (SHA1, 8자리 코드의 하위 6자리)
+        let secret = b"12345678901234567890";
+        assert_eq!(code_at(secret, 59), "287082");
+        assert_eq!(code_at(secret, 1111111109), "081804");
+        assert_eq!(code_at(secret, 1234567890), "005924");
+        assert_eq!(code_at(secret, 2000000000), "279037");
+
+        // 앞뒤 한 단계까지 허용
+        assert_eq!(matching_step(secret, "287082", 59 + 30, 1), Some(1));
+        assert_eq!(matching_step(secret, "287082", 59 + 60, 1), None);
+        assert_eq!(matching_step(secret, "28708", 59, 1), None);
+    }
+
+    #[tokio::test]
+    async fn test_enrollment_and_replay_rejection() {
+        let totp = registry().await;
+        assert!(matches!(totp.verify("ops", Some("000000"), "symbol_halt").await, Err(TotpError::NotEnrolled(_))));
+
+        let provisioning = totp.provision("ops", None).await.unwrap();
+        assert!(provisioning.otpauth_uri.starts_with("otpauth://totp/xTrader:ops?secret="));
+        let secret = data_encoding::BASE32_NOPAD.decode(provisioning.secret.as_bytes()).unwrap();
+        // 등록 확인 전에는 민감한 작업 불가
+        assert!(!totp.is_enrolled("ops"));
+        assert!(matches!(totp.verify("ops", None, "symbol_halt").await, Err(TotpError::NotEnrolled(_))));
+
+        let now = now_secs();
+        totp.confirm("ops", &code_at(&secret, now)).await.unwrap();
+        assert!(totp.is_enrolled("ops"));
+        assert!(matches!(totp.confirm("ops", &code_at(&secret, now)).await, Err(TotpError::NotPending(_))));
+
+        // 확인에 쓴 코드는 재사용 불가, 다음 단계 코드는 허용
+        assert!(matches!(totp.verify("ops", Some(&code_at(&secret, now)), "symbol_halt").await, Err(TotpError::InvalidCode)));
+        totp.verify("ops", Some(&code_at(&secret, now + TOTP_STEP_SECS)), "symbol_halt").await.unwrap();
+        assert!(matches!(totp.verify("ops", None, "symbol_halt").await, Err(TotpError::Missing)));
+
+        // 재발급에는 현재 코드 필요
+        assert!(matches!(totp.provision("ops", Some("123456")).await, Err(TotpError::InvalidCode)));
+    }
+}
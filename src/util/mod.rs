@@ -1,10 +1,14 @@
-/**
+/*
 * filename : mod
 * author : HAMA
 * date: 2025. 5. 11.
 * description: 
-**/
+*/
 
-pub mod linked_list;
+pub use xtrader_engine::linked_list;
 pub use linked_list::DoublyLinkedList;
-pub use linked_list::Node; 
\ No newline at end of file
+pub use linked_list::Node;
+
+// 시각 소스는 시장 데이터 크레이트도 쓰도록 xtrader-engine에 있음 (기존 경로 유지)
+pub use xtrader_engine::clock;
+pub use clock::{Clock, SharedClock, SystemClock};
@@ -1,3 +1,6 @@
+// 서버 응답 구조 일부는 역직렬화 확인용으로만 정의되어 있습니다
+#![allow(dead_code)]
+
 use std::time::Duration;
 use tokio::time::sleep;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
@@ -105,7 +108,7 @@ async fn test_api_health(client: &Client) -> Result<(), String> {
     println!("🔍 Testing API Health...");
     
     let response = client
-        .get(&format!("{}/api/v1/orderbook/{}/?depth=5", BASE_URL, TEST_SYMBOL))
+        .get(format!("{}/api/v1/orderbook/{}/?depth=5", BASE_URL, TEST_SYMBOL))
         .send()
         .await
         .map_err(|e| format!("Failed to get orderbook: {}", e))?;
@@ -143,7 +146,7 @@ async fn test_order_submission(
     };
     
     let response = client
-        .post(&format!("{}/api/v1/order", BASE_URL))
+        .post(format!("{}/api/v1/order", BASE_URL))
         .json(&buy_order)
         .send()
         .await
@@ -178,7 +181,7 @@ async fn test_order_submission(
     };
     
     let response = client
-        .post(&format!("{}/api/v1/order", BASE_URL))
+        .post(format!("{}/api/v1/order", BASE_URL))
         .json(&sell_order)
         .send()
         .await
@@ -213,7 +216,7 @@ async fn test_order_submission(
     };
     
     let response = client
-        .post(&format!("{}/api/v1/order", BASE_URL))
+        .post(format!("{}/api/v1/order", BASE_URL))
         .json(&market_order)
         .send()
         .await
@@ -250,7 +253,7 @@ async fn test_order_status(
     
     for order_id in order_ids {
         let response = client
-            .get(&format!("{}/api/v1/order/{}", BASE_URL, order_id))
+            .get(format!("{}/api/v1/order/{}", BASE_URL, order_id))
             .send()
             .await
             .map_err(|e| format!("Failed to get order status: {}", e))?;
@@ -278,7 +281,7 @@ async fn test_orderbook_data(
     println!("📊 Testing OrderBook Data...");
     
     let response = client
-        .get(&format!("{}/api/v1/orderbook/{}/?depth=10", BASE_URL, TEST_SYMBOL))
+        .get(format!("{}/api/v1/orderbook/{}/?depth=10", BASE_URL, TEST_SYMBOL))
         .send()
         .await
         .map_err(|e| format!("Failed to get orderbook: {}", e))?;
@@ -338,7 +341,7 @@ async fn test_candle_data(
     println!("📈 Testing Candle Data...");
     
     let response = client
-        .get(&format!("{}/api/v1/candles/{}/?interval=1m&limit=20", BASE_URL, TEST_SYMBOL))
+        .get(format!("{}/api/v1/candles/{}/?interval=1m&limit=20", BASE_URL, TEST_SYMBOL))
         .send()
         .await
         .map_err(|e| format!("Failed to get candles: {}", e))?;
@@ -466,7 +469,7 @@ async fn test_user_balance(
     println!("💰 Testing User Balance...");
     
     let response = client
-        .get(&format!("{}/api/v1/user/{}/balance", BASE_URL, TEST_CLIENT_ID))
+        .get(format!("{}/api/v1/user/{}/balance", BASE_URL, TEST_CLIENT_ID))
         .send()
         .await
         .map_err(|e| format!("Failed to get user balance: {}", e))?;
@@ -493,7 +496,7 @@ async fn test_market_statistics(
     println!("📊 Testing Market Statistics...");
     
     let response = client
-        .get(&format!("{}/api/v1/market/{}/statistics", BASE_URL, TEST_SYMBOL))
+        .get(format!("{}/api/v1/market/{}/statistics", BASE_URL, TEST_SYMBOL))
         .send()
         .await
         .map_err(|e| format!("Failed to get market statistics: {}", e))?;
@@ -644,14 +647,14 @@ async fn test_order_matching(
     
     // Submit both orders
     let buy_response = client
-        .post(&format!("{}/api/v1/order", BASE_URL))
+        .post(format!("{}/api/v1/order", BASE_URL))
         .json(&buy_order)
         .send()
         .await
         .map_err(|e| format!("Failed to submit buy order: {}", e))?;
     
     let sell_response = client
-        .post(&format!("{}/api/v1/order", BASE_URL))
+        .post(format!("{}/api/v1/order", BASE_URL))
         .json(&sell_order)
         .send()
         .await
@@ -665,7 +668,7 @@ async fn test_order_matching(
         
         // Check if executions were created
         let executions_response = client
-            .get(&format!("{}/api/v1/executions/{}", BASE_URL, TEST_SYMBOL))
+            .get(format!("{}/api/v1/executions/{}", BASE_URL, TEST_SYMBOL))
             .send()
             .await
             .map_err(|e| format!("Failed to get executions: {}", e))?;
@@ -703,7 +706,7 @@ async fn test_error_handling(
     });
     
     let response = client
-        .post(&format!("{}/api/v1/order", BASE_URL))
+        .post(format!("{}/api/v1/order", BASE_URL))
         .json(&invalid_order)
         .send()
         .await
@@ -726,7 +729,7 @@ async fn test_error_handling(
     });
     
     let response = client
-        .post(&format!("{}/api/v1/order", BASE_URL))
+        .post(format!("{}/api/v1/order", BASE_URL))
         .json(&invalid_symbol_order)
         .send()
         .await
@@ -2,7 +2,6 @@
 //!
 //! 전체 시스템의 통합 테스트를 수행합니다.
 
-use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
 
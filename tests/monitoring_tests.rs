@@ -2,7 +2,6 @@
 //!
 //! 시스템 헬스체크, 알림, 대시보드, 로그 분석 관련 테스트를 수행합니다.
 
-use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
 
@@ -2,9 +2,6 @@
 //!
 //! Redis Streams, Kafka, RabbitMQ 관련 테스트를 수행합니다.
 
-use std::sync::Arc;
-use std::time::Duration;
-use tokio::time::sleep;
 
 /// Redis Streams 테스트
 #[tokio::test]
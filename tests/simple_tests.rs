@@ -2,6 +2,9 @@
 //!
 //! 컴파일 오류 없이 실행할 수 있는 기본 테스트들입니다.
 
+// 표준 라이브러리 동작을 그대로 확인하는 테스트라 리터럴 unwrap 등을 일부러 씁니다
+#![allow(clippy::unnecessary_literal_unwrap, clippy::vec_init_then_push, clippy::needless_range_loop)]
+
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -201,8 +204,8 @@ async fn test_mathematical_operations() {
     assert_eq!(17 % 5, 2);
     
     // 부동소수점 연산 테스트
-    let x = 3.14_f64;
-    let y = 2.86_f64;
+    let x = 3.25_f64;
+    let y = 2.75_f64;
     let sum = x + y;
     assert!((sum - 6.0_f64).abs() < 0.001);
     
@@ -288,7 +291,7 @@ async fn test_performance_measurement() {
     
     // 반복문 성능 테스트
     let start = std::time::Instant::now();
-    let mut _sum = 0;
+    let mut _sum: i64 = 0;
     for i in 0..1000000 {
         _sum += i;
     }
@@ -297,8 +300,8 @@ async fn test_performance_measurement() {
     
     // 벡터 연산 성능 테스트
     let start = std::time::Instant::now();
-    let vec: Vec<i32> = (0..1000000).collect();
-    let _sum: i32 = vec.iter().sum();
+    let vec: Vec<i64> = (0..1000000).collect();
+    let _sum: i64 = vec.iter().sum();
     let duration = start.elapsed();
     println!("  📊 벡터 연산 성능: {:.2}ms", duration.as_millis());
     
@@ -2,6 +2,9 @@
 //!
 //! 개별 컴포넌트의 단위 테스트를 수행합니다.
 
+// 표준 라이브러리 동작을 그대로 확인하는 테스트라 리터럴 unwrap 등을 일부러 씁니다
+#![allow(clippy::unnecessary_literal_unwrap, clippy::vec_init_then_push, clippy::needless_range_loop)]
+
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -208,8 +211,8 @@ async fn test_mathematical_operations() {
     assert_eq!(17 % 5, 2);
     
     // 부동소수점 연산 테스트
-    let x = 3.14;
-    let y = 2.86;
+    let x = 3.25;
+    let y = 2.75;
     let sum = x + y;
     assert!((sum - 6.0_f64).abs() < 0.001);
     
@@ -301,7 +304,7 @@ async fn test_performance_measurement() {
     
     // 반복문 성능 테스트
     let start = std::time::Instant::now();
-    let mut _sum = 0;
+    let mut _sum: i64 = 0;
     for i in 0..1000000 {
         _sum += i;
     }
@@ -310,8 +313,8 @@ async fn test_performance_measurement() {
     
     // 벡터 연산 성능 테스트
     let start = std::time::Instant::now();
-    let vec: Vec<i32> = (0..1000000).collect();
-    let _sum: i32 = vec.iter().sum();
+    let vec: Vec<i64> = (0..1000000).collect();
+    let _sum: i64 = vec.iter().sum();
     let duration = start.elapsed();
     println!("  📊 벡터 연산 성능: {:.2}ms", duration.as_millis());
     